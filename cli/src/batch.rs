@@ -0,0 +1,396 @@
+//!
+//! Non-interactive (batch) mode of the wallet CLI.
+//!
+//! The batch mode executes a single Wallet API request without the terminal
+//! UI layer, allowing the wallet to be used from scripts and CI pipelines:
+//!
+//! ```text
+//! kaspa-wallet --json [--wallet <name>] [--network <network-id>] [--server <url|public>]
+//!                     [--wallet-secret-fd <fd>] [--payment-secret-fd <fd>]
+//!                     <op> [<json> | <key>=<value> ...]
+//! ```
+//!
+//! `<op>` is the kebab-case name of a [`WalletRequest`] (e.g. `accounts-enumerate`).
+//! The request is supplied as a JSON object or as `key=value` pairs of the (camelCase)
+//! request fields, where values are parsed as JSON falling back to strings.
+//!
+//! The wallet secret is read from the `KASPA_WALLET_SECRET` environment variable or
+//! from the file descriptor supplied with `--wallet-secret-fd` (the payment secret
+//! from `KASPA_PAYMENT_SECRET` or `--payment-secret-fd`) and supplied to requests
+//! that do not carry it. If the wallet secret is available, the wallet (`--wallet`
+//! or the default wallet) is opened before the request is executed.
+//!
+//! The outcome is printed to stdout as a [`BatchResult`] JSON object and the process
+//! exit status reflects the class of the error (see [`ExitCode`]).
+//!
+
+use crate::imports::*;
+use kaspa_wallet_core::error::ErrorCode;
+use kaspa_wrpc_client::Resolver;
+use serde_json::{json, Map};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Environment variable carrying the wallet secret.
+pub const WALLET_SECRET_ENV: &str = "KASPA_WALLET_SECRET";
+/// Environment variable carrying the payment secret.
+pub const PAYMENT_SECRET_ENV: &str = "KASPA_PAYMENT_SECRET";
+/// Time allowed for the node connection to be established.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Requests that do not require an open wallet (the wallet
+/// is not opened even if the wallet secret is available).
+const WALLET_LESS_OPS: &[&str] = &["get-status", "wallet-enumerate", "wallet-create", "wallet-open", "wallet-import"];
+
+/// Process exit status of the batch mode.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(i32)]
+pub enum ExitCode {
+    Success = 0,
+    /// Unclassified error
+    Failure = 1,
+    /// Invalid command line arguments or request
+    Usage = 2,
+    /// RPC connection or node errors (`2xxx` error codes)
+    Network = 3,
+    /// Wallet, storage or secret errors (`3xxx` error codes)
+    Wallet = 4,
+    /// Account and key errors (`4xxx` error codes)
+    Account = 5,
+    /// Transaction errors, e.g. insufficient funds (`5xxx` error codes)
+    Transaction = 6,
+}
+
+impl From<&BatchError> for ExitCode {
+    fn from(error: &BatchError) -> Self {
+        match error.code {
+            code if code == ErrorCode::InvalidArgument as u32 => ExitCode::Usage,
+            2000..=2999 => ExitCode::Network,
+            3000..=3999 => ExitCode::Wallet,
+            4000..=4999 => ExitCode::Account,
+            5000..=5999 => ExitCode::Transaction,
+            _ => ExitCode::Failure,
+        }
+    }
+}
+
+impl From<Error> for BatchError {
+    fn from(error: Error) -> Self {
+        let code = match &error {
+            Error::WalletError(error) => error.code(),
+            Error::InvalidBatchArgument(_) | Error::SerdeJsonError(_) | Error::NetworkId(_) => ErrorCode::InvalidArgument,
+            Error::WrpcError(_) | Error::RpcError(_) => ErrorCode::NetworkError,
+            Error::WalletSecretRequired | Error::PaymentSecretRequired => ErrorCode::SecretRequired,
+            _ => ErrorCode::Unknown,
+        };
+        BatchError { code: code as u32, message: error.to_string() }
+    }
+}
+
+/// Command line arguments of the batch mode.
+#[derive(Debug, Default)]
+pub struct BatchArgs {
+    pub wallet: Option<String>,
+    pub network_id: Option<NetworkId>,
+    pub server: Option<String>,
+    pub wallet_secret_fd: Option<i32>,
+    pub payment_secret_fd: Option<i32>,
+    /// Kebab-case name of the [`WalletRequest`]
+    pub op: String,
+    /// Request fields
+    pub request: Map<String, Value>,
+}
+
+impl BatchArgs {
+    pub fn parse(args: &[String]) -> Result<Self> {
+        let mut batch_args = BatchArgs::default();
+        let mut args = args.iter().filter(|arg| arg.as_str() != "--json");
+
+        let mut op = None;
+        while let Some(arg) = args.next() {
+            if op.is_none() {
+                if let Some(option) = arg.strip_prefix("--") {
+                    let (name, value) = match option.split_once('=') {
+                        Some((name, value)) => (name, value.to_string()),
+                        None => (
+                            option,
+                            args.next()
+                                .cloned()
+                                .ok_or_else(|| Error::InvalidBatchArgument(format!("missing value of '--{option}'")))?,
+                        ),
+                    };
+
+                    match name {
+                        "wallet" => batch_args.wallet = Some(value),
+                        "network" => batch_args.network_id = Some(NetworkId::from_str(&value)?),
+                        "server" => batch_args.server = Some(value),
+                        "wallet-secret-fd" => batch_args.wallet_secret_fd = Some(parse_fd(name, &value)?),
+                        "payment-secret-fd" => batch_args.payment_secret_fd = Some(parse_fd(name, &value)?),
+                        _ => return Err(Error::InvalidBatchArgument(format!("unknown option '--{name}'"))),
+                    }
+                } else {
+                    op = Some(arg.clone());
+                }
+            } else if arg.starts_with('{') {
+                match serde_json::from_str::<Value>(arg)? {
+                    Value::Object(request) => batch_args.request.extend(request),
+                    _ => return Err(Error::InvalidBatchArgument("the request must be a JSON object".to_string())),
+                }
+            } else if let Some((key, value)) = arg.split_once('=') {
+                let value = serde_json::from_str::<Value>(value).unwrap_or_else(|_| Value::String(value.to_string()));
+                batch_args.request.insert(key.to_string(), value);
+            } else {
+                return Err(Error::InvalidBatchArgument(format!("invalid request argument '{arg}', expecting '<key>=<value>'")));
+            }
+        }
+
+        batch_args.op =
+            op.ok_or_else(|| Error::InvalidBatchArgument("missing wallet request (e.g. 'accounts-enumerate')".to_string()))?;
+
+        if batch_args.wallet_secret_fd.is_some() && batch_args.wallet_secret_fd == batch_args.payment_secret_fd {
+            return Err(Error::InvalidBatchArgument(
+                "'--wallet-secret-fd' and '--payment-secret-fd' must use different file descriptors".to_string(),
+            ));
+        }
+
+        Ok(batch_args)
+    }
+
+    fn wallet_secret(&self) -> Result<Option<Secret>> {
+        read_secret(WALLET_SECRET_ENV, self.wallet_secret_fd)
+    }
+
+    fn payment_secret(&self) -> Result<Option<Secret>> {
+        read_secret(PAYMENT_SECRET_ENV, self.payment_secret_fd)
+    }
+}
+
+fn parse_fd(name: &str, value: &str) -> Result<i32> {
+    value.parse::<i32>().map_err(|_| Error::InvalidBatchArgument(format!("invalid file descriptor '{value}' of '--{name}'")))
+}
+
+/// Reads the secret from the file descriptor `fd` if supplied,
+/// otherwise from the environment variable `env`.
+fn read_secret(env: &str, fd: Option<i32>) -> Result<Option<Secret>> {
+    match fd {
+        #[cfg(unix)]
+        Some(fd) => {
+            use std::io::Read;
+            use std::os::unix::io::FromRawFd;
+
+            // SAFETY: the descriptor is supplied by the caller for the exclusive use
+            // of the batch mode and is closed once the secret has been read.
+            let mut file = unsafe { std::fs::File::from_raw_fd(fd) };
+            let mut secret = String::new();
+            file.read_to_string(&mut secret)?;
+            Ok(Some(Secret::from(trim_line_ending(secret))))
+        }
+        #[cfg(not(unix))]
+        Some(_) => {
+            Err(Error::InvalidBatchArgument("reading secrets from file descriptors is not supported on this platform".to_string()))
+        }
+        None => Ok(std::env::var(env).ok().map(Secret::from)),
+    }
+}
+
+/// Removes a single trailing line ending (`\n` or `\r\n`) appended
+/// when the secret is written to the descriptor (e.g. by `echo`).
+fn trim_line_ending(mut secret: String) -> String {
+    if secret.ends_with('\n') {
+        secret.pop();
+        if secret.ends_with('\r') {
+            secret.pop();
+        }
+    }
+    secret
+}
+
+/// Routes the log output to stderr, keeping stdout for the JSON result.
+struct StderrSink;
+
+impl workflow_log::Sink for StderrSink {
+    fn write(&self, _target: Option<&str>, _level: Level, args: &std::fmt::Arguments<'_>) -> bool {
+        eprintln!("{args}");
+        true
+    }
+}
+
+/// Executes the wallet request described by the command line `args`, printing
+/// the [`BatchResult`] to stdout. Returns the process exit status.
+pub async fn kaspa_cli_batch(args: Vec<String>) -> i32 {
+    workflow_log::pipe(Some(Arc::new(StderrSink)));
+
+    let result = match BatchArgs::parse(&args) {
+        Ok(args) => execute(args).await,
+        Err(err) => Err(err),
+    };
+
+    let (result, exit_code) = match result {
+        Ok(response) => (BatchResult::from(Ok(response)), ExitCode::Success),
+        Err(err) => {
+            let error = BatchError::from(err);
+            let exit_code = ExitCode::from(&error);
+            (BatchResult { response: None, error: Some(error) }, exit_code)
+        }
+    };
+
+    match serde_json::to_string(&result) {
+        Ok(json) => println!("{json}"),
+        Err(err) => eprintln!("{err}"),
+    }
+
+    exit_code as i32
+}
+
+async fn execute(args: BatchArgs) -> Result<WalletResponse> {
+    let wallet = Arc::new(Wallet::try_new(Wallet::local_store()?, None, None)?);
+    wallet.load_settings().await.unwrap_or_else(|err| log_warn!("Unable to load settings: {err}"));
+    if let Some(network_id) = args.network_id.as_ref() {
+        wallet.set_network_id(network_id)?;
+    }
+
+    wallet.start().await?;
+    let response = execute_with_wallet(&wallet, args).await;
+    if wallet.is_open() {
+        wallet.close().await.unwrap_or_else(|err| log_warn!("Unable to close the wallet: {err}"));
+    }
+    if let Some(wrpc_client) = wallet.try_wrpc_client() {
+        wrpc_client.disconnect().await.ok();
+    }
+    wallet.stop().await?;
+
+    response
+}
+
+async fn execute_with_wallet(wallet: &Arc<Wallet>, args: BatchArgs) -> Result<WalletResponse> {
+    if let Some(server) = args.server.as_deref() {
+        connect(wallet, server).await?;
+    }
+
+    let wallet_secret = args.wallet_secret()?;
+    let payment_secret = args.payment_secret()?;
+
+    if let Some(wallet_secret) = wallet_secret.as_ref().filter(|_| !WALLET_LESS_OPS.contains(&args.op.as_str())) {
+        let filename = args.wallet.clone().or_else(|| wallet.settings().get(WalletSettings::Wallet));
+        wallet.open(wallet_secret, filename, WalletOpenArgs::default_with_legacy_accounts()).await?;
+        wallet.activate_accounts(None).await?;
+    }
+
+    let mut request = args.request;
+    if let Some(wallet_secret) = wallet_secret.as_ref() {
+        request.entry("walletSecret").or_insert(to_value(wallet_secret)?);
+    }
+    if let Some(payment_secret) = payment_secret.as_ref() {
+        request.entry("paymentSecret").or_insert(to_value(payment_secret)?);
+    }
+
+    let request = serde_json::from_value::<WalletRequest>(json!({ "op" : args.op, "request" : request }))
+        .map_err(|err| Error::InvalidBatchArgument(format!("invalid '{}' request: {err}", args.op)))?;
+
+    Ok(request.execute(wallet.clone()).await?)
+}
+
+/// Connects to the node at `server` (or to a public node if `server` is `public`)
+/// and waits for the wallet UTXO processor to start.
+async fn connect(wallet: &Arc<Wallet>, server: &str) -> Result<()> {
+    let wrpc_client = wallet.try_wrpc_client().ok_or(kaspa_wallet_core::error::Error::NotWrpcClient)?;
+    let network_id = wallet.network_id()?;
+
+    let url = match server {
+        "public" => Resolver::default().fetch(WrpcEncoding::Borsh, network_id).await.map_err(|e| e.to_string())?.url,
+        url => wrpc_client.parse_url_with_network_type(url.to_string(), network_id.into()).map_err(|e| e.to_string())?,
+    };
+
+    let events = wallet.multiplexer().channel();
+    let options =
+        ConnectOptions { block_async_connect: true, strategy: ConnectStrategy::Fallback, url: Some(url), ..Default::default() };
+    wrpc_client.connect(Some(options)).await.map_err(|e| e.to_string())?;
+
+    let started = tokio::time::timeout(CONNECT_TIMEOUT, async {
+        while !wallet.is_connected() {
+            match events.receiver.recv().await {
+                Ok(event) if matches!(*event, Events::UtxoProcStart) => break,
+                Ok(_) => {}
+                Err(err) => return Err(Error::ChannelError(err.to_string())),
+            }
+        }
+        Ok(())
+    })
+    .await;
+    events.close();
+
+    match started {
+        Ok(result) => result,
+        Err(_) => Err(kaspa_wallet_core::error::Error::NotConnected.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_batch_args_parse() {
+        let batch_args = BatchArgs::parse(&args(&[
+            "--json",
+            "--wallet",
+            "test",
+            "--network=testnet-11",
+            "--wallet-secret-fd",
+            "3",
+            "accounts-enumerate",
+            r#"{"accountId":"abc"}"#,
+            "count=10",
+            "name=label",
+        ]))
+        .unwrap();
+        assert_eq!(batch_args.wallet.as_deref(), Some("test"));
+        assert_eq!(batch_args.network_id, Some(NetworkId::from_str("testnet-11").unwrap()));
+        assert_eq!((batch_args.wallet_secret_fd, batch_args.payment_secret_fd), (Some(3), None));
+        assert_eq!(batch_args.op, "accounts-enumerate");
+        assert_eq!(batch_args.request.get("accountId"), Some(&json!("abc")));
+        assert_eq!(batch_args.request.get("count"), Some(&json!(10)));
+        assert_eq!(batch_args.request.get("name"), Some(&json!("label")));
+
+        for invalid in [
+            &["--json"][..],
+            &["--wallet"],
+            &["--unknown", "value", "get-status"],
+            &["--wallet-secret-fd", "abc", "get-status"],
+            &["get-status", "invalid"],
+            &["get-status", "[1]"],
+            &["--wallet-secret-fd", "3", "--payment-secret-fd", "3", "get-status"],
+        ] {
+            let error = BatchArgs::parse(&args(invalid)).expect_err("invalid batch arguments");
+            assert_eq!(ExitCode::from(&BatchError::from(error)), ExitCode::Usage, "{invalid:?}");
+        }
+    }
+
+    #[test]
+    fn test_batch_exit_code() {
+        let exit_code = |code: ErrorCode| ExitCode::from(&BatchError { code: code as u32, message: String::new() });
+        assert_eq!(exit_code(ErrorCode::InvalidArgument), ExitCode::Usage);
+        assert_eq!(exit_code(ErrorCode::NetworkError), ExitCode::Network);
+        assert_eq!(exit_code(ErrorCode::SecretRequired), ExitCode::Wallet);
+        assert_eq!(exit_code(ErrorCode::Unknown), ExitCode::Failure);
+
+        let exit_code = |error: Error| ExitCode::from(&BatchError::from(error));
+        assert_eq!(exit_code(Error::WalletSecretRequired), ExitCode::Wallet);
+        assert_eq!(exit_code(Error::WalletError(kaspa_wallet_core::error::Error::WalletNotOpen)), ExitCode::Wallet);
+        assert_eq!(exit_code(Error::WalletError(kaspa_wallet_core::error::Error::NotConnected)), ExitCode::Network);
+        assert_eq!(exit_code(Error::WalletError(kaspa_wallet_core::error::Error::AccountSelection)), ExitCode::Account);
+        assert_eq!(exit_code(Error::custom("other")), ExitCode::Failure);
+    }
+
+    #[test]
+    fn test_batch_secret_line_ending() {
+        assert_eq!(trim_line_ending("secret\n".to_string()), "secret");
+        assert_eq!(trim_line_ending("secret\r\n".to_string()), "secret");
+        assert_eq!(trim_line_ending("secret\n\n".to_string()), "secret\n");
+        assert_eq!(trim_line_ending(" secret ".to_string()), " secret ");
+    }
+}
@@ -291,6 +291,39 @@ impl KaspaCli {
                                     // log_info!("Kaspa NG - received metrics event {metrics:?}")
                                 }
                                 Events::Error { message } => { terrorln!(this,"{message}"); },
+                                Events::Market { .. } => { },
+                                Events::WalletSync { .. } => { },
+                                Events::TaskDeadline { task } => {
+                                    terrorln!(this, "Warning: {} task {} has been running for {} seconds; use 'tasks abort {}' to cancel it", task.kind, task.id, task.elapsed / 1000, task.id);
+                                },
+                                Events::FeeBump { replaced_transaction_id, transaction_id, fees, .. } => {
+                                    tprintln!(this, "Transaction {replaced_transaction_id} has expired; resubmitted as {transaction_id} with fees {}", sompi_to_kaspa_string(fees));
+                                },
+                                Events::FeeBumpAbandoned { transaction_id, .. } => {
+                                    terrorln!(this, "Warning: unable to resubmit expired transaction {transaction_id} within the maximum fee");
+                                },
+                                Events::PaymentBatchProgress { transaction_id, partition, partitions, payments, total_payments, fees, is_final, .. } => {
+                                    let kind = if is_final { "payments" } else { "compound" };
+                                    let fees = this.wallet().network_id().map(|network_id| sompi_to_kaspa_string_with_suffix(fees, &network_id.into())).unwrap_or_else(|_| format!("{fees} sompi"));
+                                    tprintln!(this, "Batch {}/{partitions} - {kind} transaction {transaction_id} - fees: {fees} - payments: {payments}/{total_payments}", partition + 1);
+                                },
+                                Events::GeneratorProgress { progress, .. } => {
+                                    // skip single transaction operations
+                                    if progress.index > 0 || !progress.is_final {
+                                        let fees = this.wallet().network_id().map(|network_id| sompi_to_kaspa_string_with_suffix(progress.fees, &network_id.into())).unwrap_or_else(|_| format!("{} sompi", progress.fees));
+                                        let transaction = progress.total.map(|total| format!("{}/{total}", progress.index + 1)).unwrap_or_else(|| (progress.index + 1).to_string());
+                                        tprintln!(this, "Transaction {transaction} - {} - fees: {fees}", progress.transaction_id);
+                                    }
+                                },
+                                Events::PaymentReceived { request_id, txid } => {
+                                    tprintln!(this, "Payment request {request_id} fulfilled by transaction {txid}");
+                                },
+                                Events::ScanInterrupted { account_id, message } => {
+                                    terrorln!(this, "Account {account_id}: address scan interrupted ({message}); the scan will resume upon reconnection");
+                                },
+                                Events::ScanResumed { account_id } => {
+                                    tprintln!(this, "Account {account_id}: resuming address scan...");
+                                },
                                 Events::UtxoProcStart => {},
                                 Events::UtxoProcStop => {},
                                 Events::UtxoProcError { message } => {
@@ -361,18 +394,27 @@ impl KaspaCli {
                                 Events::WalletClose => {
                                     this.term().refresh_prompt();
                                 },
+                                Events::WalletLocked { wallet_descriptor } => {
+                                    let title = wallet_descriptor.map(|descriptor| descriptor.filename).unwrap_or_else(|| "wallet".to_string());
+                                    twarnln!(this, "wallet '{title}' has been locked due to inactivity, please use 'open' to re-open the wallet");
+                                    this.term().refresh_prompt();
+                                },
                                 Events::PrvKeyDataCreate { .. } => { },
                                 Events::AccountDeactivation { .. } => { },
                                 Events::AccountActivation { .. } => {
                                     // list all accounts
                                     this.list().await.unwrap_or_else(|err|terrorln!(this, "{err}"));
 
-                                    // load default account if only one account exists
-                                    this.wallet().autoselect_default_account_if_single().await.ok();
+                                    // select the default account or the
+                                    // account if only one account exists
+                                    this.select_default_account().await.ok();
                                     this.term().refresh_prompt();
                                 },
                                 Events::AccountCreate { .. } => { },
                                 Events::AccountUpdate { .. } => { },
+                                Events::AccountArchive { .. } | Events::AccountUnarchive { .. } => {
+                                    this.term().refresh_prompt();
+                                },
                                 Events::DaaScoreChange { current_daa_score } => {
                                     if this.is_mutted() && this.flags.get(Track::Daa) {
                                         tprintln!(this, "{NOTIFY} DAA: {current_daa_score}");
@@ -388,6 +430,24 @@ impl KaspaCli {
                                         tx.iter().for_each(|line|tprintln!(this,"{NOTIFY} {line}"));
                                     }
                                 },
+                                Events::ReorgedOut {
+                                    record
+                                } => {
+                                    if !this.is_mutted() || (this.is_mutted() && this.flags.get(Track::Pending)) {
+                                        let include_utxos = this.flags.get(Track::Utxo);
+                                        let tx = record.format_transaction_with_state(&this.wallet,Some("reorged-out"),include_utxos).await;
+                                        tx.iter().for_each(|line|tprintln!(this,"{NOTIFY} {line}"));
+                                    }
+                                },
+                                Events::Reaccepted {
+                                    record
+                                } => {
+                                    if !this.is_mutted() || (this.is_mutted() && this.flags.get(Track::Pending)) {
+                                        let include_utxos = this.flags.get(Track::Utxo);
+                                        let tx = record.format_transaction_with_state(&this.wallet,Some("reaccepted"),include_utxos).await;
+                                        tx.iter().for_each(|line|tprintln!(this,"{NOTIFY} {line}"));
+                                    }
+                                },
                                 Events::Stasis {
                                     record
                                 } => {
@@ -461,8 +521,9 @@ impl KaspaCli {
                                             format!("({} pending)", pending_utxo_count)
                                         } else { "".to_string() };
                                         let utxo_info = style(format!("{mature_utxo_count} UTXOs {pending_utxo_info}")).dim();
+                                        let fiat_info = balance.as_ref().and_then(|balance| this.fiat_string(balance.mature)).map(|fiat| format!("   {}", style(fiat).dim())).unwrap_or_default();
 
-                                        tprintln!(this, "{NOTIFY} {} {id}: {balance_strings}   {utxo_info}",style("balance".pad_to_width(8)).blue());
+                                        tprintln!(this, "{NOTIFY} {} {id}: {balance_strings}{fiat_info}   {utxo_info}",style("balance".pad_to_width(8)).blue());
                                     }
 
                                     this.term().refresh_prompt();
@@ -487,11 +548,11 @@ impl KaspaCli {
     /// Asks uses for a wallet secret, checks the supplied account's private key info
     /// and if it requires a payment secret, asks for it as well.
     pub(crate) async fn ask_wallet_secret(&self, account: Option<&Arc<dyn Account>>) -> Result<(Secret, Option<Secret>)> {
-        let wallet_secret = Secret::new(self.term().ask(true, "Enter wallet password: ").await?.trim().as_bytes().to_vec());
+        let wallet_secret = Secret::new(self.term().ask(true, &tr("prompt.wallet-password")).await?.trim().as_bytes().to_vec());
 
         let payment_secret = if let Some(account) = account {
             if self.wallet().is_account_key_encrypted(account).await?.is_some_and(|f| f) {
-                Some(Secret::new(self.term().ask(true, "Enter payment password: ").await?.trim().as_bytes().to_vec()))
+                Some(Secret::new(self.term().ask(true, &tr("prompt.payment-password")).await?.trim().as_bytes().to_vec()))
             } else {
                 None
             }
@@ -512,8 +573,37 @@ impl KaspaCli {
         }
     }
 
+    /// Returns all wallet accounts, in the order they are displayed by the `list` command.
+    pub async fn accounts(&self) -> Result<Vec<Arc<dyn Account>>> {
+        let mut list = vec![];
+        let mut keys = self.wallet.keys().await?;
+        while let Some(key) = keys.try_next().await? {
+            let mut accounts = self.wallet.accounts(Some(key.id)).await?;
+            while let Some(account) = accounts.try_next().await? {
+                list.push(account);
+            }
+        }
+        Ok(list)
+    }
+
+    /// Find an account by its ordinal index in the `list` output, name
+    /// or account id hex prefix. An exact name match takes precedence
+    /// over name and id prefix matches.
     pub async fn find_accounts_by_name_or_id(&self, pat: &str) -> Result<Arc<dyn Account>> {
-        let matches = self.wallet().find_accounts_by_name_or_id(pat).await?;
+        let accounts = self.accounts().await?;
+
+        if let Some(account) = pat.parse::<usize>().ok().and_then(|seq| accounts.get(seq)) {
+            return Ok(account.clone());
+        }
+
+        let mut matches = accounts.iter().filter(|account| account.name().as_deref() == Some(pat)).cloned().collect::<Vec<_>>();
+        if matches.is_empty() {
+            matches = accounts
+                .into_iter()
+                .filter(|account| account.name().is_some_and(|name| name.starts_with(pat)) || account.id().to_hex().starts_with(pat))
+                .collect::<Vec<_>>();
+        }
+
         if matches.is_empty() {
             Err(Error::AccountNotFound(pat.to_string()))
         } else if matches.len() > 1 {
@@ -523,6 +613,61 @@ impl KaspaCli {
         }
     }
 
+    /// Find an archived account by its name or account id hex prefix.
+    pub async fn find_archived_account_by_name_or_id(&self, pat: &str) -> Result<AccountDescriptor> {
+        let descriptors = self.wallet.clone().archived_account_descriptors().await?;
+
+        let mut matches =
+            descriptors.iter().filter(|descriptor| descriptor.account_name.as_deref() == Some(pat)).cloned().collect::<Vec<_>>();
+        if matches.is_empty() {
+            matches = descriptors
+                .into_iter()
+                .filter(|descriptor| {
+                    descriptor.account_name.as_ref().is_some_and(|name| name.starts_with(pat))
+                        || descriptor.account_id.to_hex().starts_with(pat)
+                })
+                .collect::<Vec<_>>();
+        }
+
+        if matches.is_empty() {
+            Err(Error::AccountNotFound(pat.to_string()))
+        } else if matches.len() > 1 {
+            Err(Error::AmbiguousAccount(pat.to_string()))
+        } else {
+            Ok(matches.remove(0))
+        }
+    }
+
+    /// Returns the account supplied via the `--account <account>` argument
+    /// (removing the argument from `argv`), or the currently selected account.
+    pub async fn account_from_argv(&self, argv: &mut Vec<String>) -> Result<Arc<dyn Account>> {
+        if let Some(pat) = take_option(argv, "--account")? {
+            self.find_accounts_by_name_or_id(pat.trim()).await
+        } else {
+            self.account().await
+        }
+    }
+
+    /// Select the default account (set using the `use` command) if no
+    /// account is selected and the default account belongs to the open
+    /// wallet; otherwise select the account if the wallet has only one.
+    pub async fn select_default_account(&self) -> Result<()> {
+        if self.wallet.account().is_ok() {
+            return Ok(());
+        }
+
+        let default_account = self.wallet.settings().get::<String>(WalletSettings::Account);
+        let account = default_account
+            .and_then(|id| self.wallet.active_accounts().collect().into_iter().find(|account| account.id().to_hex() == id));
+        if let Some(account) = account {
+            self.wallet.select(Some(&account)).await?;
+        } else {
+            self.wallet.autoselect_default_account_if_single().await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn prompt_account(&self) -> Result<Arc<dyn Account>> {
         self.select_account_with_args(false).await
     }
@@ -573,8 +718,7 @@ impl KaspaCli {
 
             let range = if flat_list.len() > 1 { format!("[{}..{}] ", 0, flat_list.len() - 1) } else { "".to_string() };
 
-            let text =
-                self.term().ask(false, &format!("Please select account {}or <enter> to abort: ", range)).await?.trim().to_string();
+            let text = self.term().ask(false, &t!("prompt.select-account", range = range)).await?.trim().to_string();
             if text.is_empty() {
                 return Err(Error::UserAbort);
             } else {
@@ -624,8 +768,7 @@ impl KaspaCli {
 
             let range = if flat_list.len() > 1 { format!("[{}..{}] ", 0, flat_list.len() - 1) } else { "".to_string() };
 
-            let text =
-                self.term().ask(false, &format!("Please select private key {}or <enter> to abort: ", range)).await?.trim().to_string();
+            let text = self.term().ask(false, &t!("prompt.select-private-key", range = range)).await?.trim().to_string();
             if text.is_empty() {
                 return Err(Error::UserAbort);
             } else {
@@ -644,6 +787,7 @@ impl KaspaCli {
 
     pub async fn list(&self) -> Result<()> {
         let mut keys = self.wallet.keys().await?;
+        let mut seq = 0;
 
         tprintln!(self);
         while let Some(key) = keys.try_next().await? {
@@ -651,8 +795,14 @@ impl KaspaCli {
             let mut accounts = self.wallet.accounts(Some(key.id)).await?;
             while let Some(account) = accounts.try_next().await? {
                 let receive_address = account.receive_address()?;
-                tprintln!(self, "    • {}", account.get_list_string()?);
-                tprintln!(self, "      {}", style(receive_address.to_string()).blue());
+                let fiat_info = account
+                    .balance()
+                    .and_then(|balance| self.fiat_string(balance.mature))
+                    .map(|fiat| format!("   {}", style(fiat).dim()))
+                    .unwrap_or_default();
+                tprintln!(self, "    {}: {}{fiat_info}", style(seq.to_string()).cyan(), account.get_list_string()?);
+                tprintln!(self, "       {}", style(receive_address.to_string()).blue());
+                seq += 1;
             }
         }
         tprintln!(self);
@@ -660,6 +810,24 @@ impl KaspaCli {
         Ok(())
     }
 
+    /// Fiat value of the amount (mainnet only) if the market
+    /// data has been obtained from the configured price feed.
+    pub fn fiat_string(&self, sompi: u64) -> Option<String> {
+        if self.wallet.network_id().ok().map(NetworkType::from) != Some(NetworkType::Mainnet) {
+            return None;
+        }
+
+        let market = self.wallet.market().data()?;
+        let values = market
+            .prices
+            .iter()
+            .filter_map(|price| {
+                market.sompi_to_fiat(sompi, &price.currency).map(|value| format!("{value:.2} {}", price.currency.to_uppercase()))
+            })
+            .collect::<Vec<_>>();
+        (!values.is_empty()).then(|| format!("≈ {}", values.join(", ")))
+    }
+
     pub async fn shutdown(&self) -> Result<()> {
         if !self.shutdown.load(Ordering::SeqCst) {
             self.shutdown.store(true, Ordering::SeqCst);
@@ -758,6 +926,7 @@ impl Cli for KaspaCli {
 
     async fn digest(self: Arc<Self>, term: Arc<Terminal>, cmd: String) -> TerminalResult<()> {
         *self.last_interaction.lock().unwrap() = Instant::now();
+        self.wallet().auto_lock().touch();
         if let Err(err) = self.handlers.execute(&self, &cmd).await {
             term.writeln(style(err.to_string()).red().to_string());
         }
@@ -1,5 +1,6 @@
 use std::net::AddrParseError;
 
+use crate::locale::{tr, tr_args};
 use downcast::DowncastError;
 use kaspa_wallet_core::error::Error as WalletError;
 use workflow_core::channel::ChannelError;
@@ -12,19 +13,19 @@ pub enum Error {
     #[error("{0}")]
     Custom(String),
 
-    #[error("aborting")]
+    #[error("{}", tr("error.user-abort"))]
     UserAbort,
 
-    #[error("platform is not supported")]
+    #[error("{}", tr("error.platform"))]
     Platform,
 
     #[error(transparent)]
     WalletError(#[from] WalletError),
 
-    #[error("Cli error {0}")]
+    #[error("{}", tr_args("error.terminal", &[("error", .0.to_string())]))]
     TerminalError(#[from] TerminalError),
 
-    #[error("Channel error")]
+    #[error("{}", tr("error.channel"))]
     ChannelError(String),
 
     #[error(transparent)]
@@ -42,57 +43,60 @@ pub enum Error {
     #[error(transparent)]
     ParseIntError(#[from] std::num::ParseIntError),
 
-    #[error("invalid hex string: {0}")]
+    #[error("{}", tr_args("error.invalid-hex", &[("error", .0.to_string())]))]
     ParseHexError(#[from] faster_hex::Error),
 
     #[error(transparent)]
     AddrParseError(#[from] AddrParseError),
 
-    #[error("account '{0}' not found")]
+    #[error("{}", tr_args("error.account-not-found", &[("account", .0.clone())]))]
     AccountNotFound(String),
 
-    #[error("ambiguous selection, pattern '{0}' matches too many accounts, please be more specific")]
+    #[error("{}", tr_args("error.ambiguous-account", &[("pattern", .0.clone())]))]
     AmbiguousAccount(String),
 
-    #[error("please create a wallet")]
+    #[error("{}", tr("error.wallet-does-not-exist"))]
     WalletDoesNotExist,
 
-    #[error("please open a wallet")]
+    #[error("{}", tr("error.wallet-is-not-open"))]
     WalletIsNotOpen,
 
-    #[error("unrecognized argument '{0}', accepted arguments are: {1}")]
+    #[error("{}", tr_args("error.unrecognized-argument", &[("argument", .0.clone()), ("accepted", .1.clone())]))]
     UnrecognizedArgument(String, String),
 
-    #[error("multiple matches for argument '{0}'; please be more specific.")]
+    #[error("{}", tr_args("error.multiple-matches", &[("argument", .0.clone())]))]
     MultipleMatches(String),
 
-    #[error("account type must be <bip32|multisig|legacy>")]
+    #[error("{}", tr("error.invalid-account-kind"))]
     InvalidAccountKind,
 
-    #[error("wallet secret is required")]
+    #[error("{}", tr("error.wallet-secret-required"))]
     WalletSecretRequired,
 
-    #[error("wallet secrets do not match")]
+    #[error("{}", tr("error.wallet-secret-match"))]
     WalletSecretMatch,
 
-    #[error("payment secret is required")]
+    #[error("{}", tr("error.payment-secret-required"))]
     PaymentSecretRequired,
 
-    #[error("payment secrets do not match")]
+    #[error("{}", tr("error.payment-secret-match"))]
     PaymentSecretMatch,
 
-    #[error("key data not found")]
+    #[error("{}", tr("error.key-data-not-found"))]
     KeyDataNotFound,
 
-    #[error("no accounts found, please create an account to continue")]
+    #[error("{}", tr("error.no-accounts"))]
     NoAccounts,
 
-    #[error("no private keys found in this wallet, please create a private key to continue")]
+    #[error("{}", tr("error.no-keys"))]
     NoKeys,
 
     #[error(transparent)]
     AddressError(#[from] kaspa_addresses::AddressError),
 
+    #[error("{}", tr_args("error.address-network-mismatch", &[("address", .0.clone()), ("network", .1.to_string())]))]
+    AddressNetworkMismatch(String, kaspa_consensus_core::network::NetworkId),
+
     #[error("{0}")]
     DowncastError(String),
 
@@ -114,7 +118,7 @@ pub enum Error {
     #[error(transparent)]
     Bip32(#[from] kaspa_bip32::Error),
 
-    #[error("private key {0} already exists")]
+    #[error("{}", tr_args("error.private-key-exists", &[("key", .0.clone())]))]
     PrivateKeyAlreadyExists(String),
 
     #[error(transparent)]
@@ -122,6 +126,12 @@ pub enum Error {
 
     #[error(transparent)]
     KaspaWalletKeys(#[from] kaspa_wallet_keys::error::Error),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("{0}")]
+    InvalidBatchArgument(String),
 }
 
 impl Error {
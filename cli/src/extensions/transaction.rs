@@ -105,7 +105,11 @@ impl TransactionExtension for TransactionRecord {
         let maturity = current_daa_score.map(|score| self.maturity(score).to_string()).unwrap_or_default();
 
         let block_daa_score = block_daa_score.separated_string();
-        let state = state.unwrap_or(&maturity);
+        let state = match state {
+            Some(state) => state,
+            None if self.is_reorged() => "reorged-out",
+            None => &maturity,
+        };
         let mut lines = vec![format!("{name} {id} @{block_daa_score} DAA - {kind} {state}")];
 
         let suffix = kaspa_suffix(&self.network_id.network_type);
@@ -2,8 +2,10 @@ pub use crate::cli::KaspaCli;
 pub use crate::error::Error;
 pub use crate::extensions::*;
 pub(crate) use crate::helpers;
+pub use crate::locale::tr;
 pub use crate::notifier::Notification;
 pub use crate::result::Result;
+pub use crate::t;
 pub use crate::utils::*;
 pub use async_trait::async_trait;
 pub use cfg_if::cfg_if;
@@ -1,10 +1,13 @@
 extern crate self as kaspa_cli;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod batch;
 mod cli;
 pub mod error;
 pub mod extensions;
 mod helpers;
 mod imports;
+pub mod locale;
 mod matchers;
 pub mod modules;
 mod notifier;
@@ -12,5 +15,7 @@ pub mod result;
 pub mod utils;
 mod wizards;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use batch::kaspa_cli_batch;
 pub use cli::{kaspa_cli, KaspaCli, Options, TerminalOptions, TerminalTarget};
 pub use workflow_terminal::Terminal;
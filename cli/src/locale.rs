@@ -0,0 +1,223 @@
+//!
+//! Message catalog for user-facing CLI strings (help text, prompts, errors).
+//!
+//! Strings are looked up by key using [`tr()`] or the [`t!`](crate::t) macro
+//! (which supports `{name}` placeholder substitution). Messages are resolved
+//! through the installed [`LocaleProvider`] falling back to the built-in
+//! English catalog. Command help text is resolved using `help.<command>`
+//! keys, falling back to the `#[help]` text declared by the command handler.
+//!
+//! Downstream distributions can ship localized CLIs by installing a custom
+//! provider (or a [`Catalog`] loaded from JSON) via [`set_locale_provider()`]
+//! before the CLI is started.
+//!
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Built-in English message catalog.
+static ENGLISH: &[(&str, &str)] = &[
+    // prompts
+    ("prompt.wallet-password", "Enter wallet password: "),
+    ("prompt.payment-password", "Enter payment password: "),
+    ("prompt.import-account-password", "Enter the password for the account you are importing: "),
+    ("prompt.wallet-encryption-password", "Enter wallet encryption password: "),
+    ("prompt.wallet-encryption-password-confirm", "Re-enter wallet encryption password: "),
+    ("prompt.select-account", "Please select account {range}or <enter> to abort: "),
+    ("prompt.select-private-key", "Please select private key {range}or <enter> to abort: "),
+    ("prompt.message", "Message: "),
+    ("prompt.send-address", "Destination address (or kaspa: payment URI) or <enter> to abort: "),
+    ("prompt.send-amount", "Amount (e.g. '1.5', '1.5 KAS' or '150000000 sompi') or <enter> to abort: "),
+    ("prompt.send-priority-fee", "Priority fee (e.g. '0.001 KAS' or '2/g' sompi per gram, negative to deduct fees from the amount) or <enter> for the default ({default}): "),
+    ("prompt.send-confirm", "Send this transaction? [y/N]: "),
+    ("prompt.send-batch-confirm", "Send this payment batch? [y/N]: "),
+    ("prompt.sign-tx-confirm", "Sign this transaction? [y/N]: "),
+    // help
+    ("help.commands", "Commands:"),
+    // usage
+    ("usage.send", "usage: send [<address> <amount|all> | <kaspa: payment URI> [<amount|all>]] [--fee <priority fee>] [--account <account>] [--dry-run] [--force] [--change-policy <policy>] [--from-utxo <txid:index> ...] [--from-utxo-only] (amounts in KAS or sompi, e.g. '1.5 KAS'; 'all' sends all spendable funds less fees; the priority fee can be a rate in sompi per gram of mass (e.g. '--fee 2/g'); a negative priority fee (e.g. '--fee -0.001') deducts all fees from the amount; the payment URI amount takes the place of <amount>; change policy: fold-to-fee, add-to-recipient or accumulate-until-threshold:<sompi>; --from-utxo spends the UTXO in addition to automatically selected UTXOs, --from-utxo-only spends only the supplied UTXOs; --force sends to a destination that triggers an address warning)"),
+    ("usage.send-batch", "usage: send-batch <file.csv> [--fee <priority fee>] [--account <account>] (CSV records: <address>,<amount>)"),
+    ("usage.sign-tx", "usage: sign-tx <file> [<output file>] [--account <account>] | sign-tx --verify <file> (the transaction is read as SDK JSON or hex and saved to '<file>.signed' by default)"),
+    ("usage.transfer", "usage: transfer <account> <amount> [<priority fee>]"),
+    // warnings
+    ("warning.send-to-change-address", "Destination is this account's own change address #{index}"),
+    (
+        "warning.send-to-distant-address",
+        "Destination is this account's {kind} address #{index}, far beyond the current index #{current} (possibly a stale or mistyped address)",
+    ),
+    // errors
+    ("error.user-abort", "aborting"),
+    ("error.terminal", "Cli error {error}"),
+    ("error.channel", "Channel error"),
+    ("error.invalid-hex", "invalid hex string: {error}"),
+    ("error.account-not-found", "account '{account}' not found"),
+    ("error.ambiguous-account", "ambiguous selection, pattern '{pattern}' matches too many accounts, please be more specific"),
+    ("error.unrecognized-argument", "unrecognized argument '{argument}', accepted arguments are: {accepted}"),
+    ("error.multiple-matches", "multiple matches for argument '{argument}'; please be more specific."),
+    ("error.address-network-mismatch", "address '{address}' does not belong to the '{network}' network"),
+    ("error.private-key-exists", "private key {key} already exists"),
+    ("error.send-destination-force", "refusing to send to this address, use '--force' to send anyway"),
+    ("error.platform", "platform is not supported"),
+    ("error.wallet-does-not-exist", "please create a wallet"),
+    ("error.wallet-is-not-open", "please open a wallet"),
+    ("error.invalid-account-kind", "account type must be <bip32|multisig|legacy>"),
+    ("error.wallet-secret-required", "wallet secret is required"),
+    ("error.wallet-secret-match", "wallet secrets do not match"),
+    ("error.payment-secret-required", "payment secret is required"),
+    ("error.payment-secret-match", "payment secrets do not match"),
+    ("error.key-data-not-found", "key data not found"),
+    ("error.no-accounts", "no accounts found, please create an account to continue"),
+    ("error.no-keys", "no private keys found in this wallet, please create a private key to continue"),
+    ("error.transfer-to-same-account", "Cannot transfer to the same account"),
+];
+
+/// Locale provider supplying translated CLI messages.
+pub trait LocaleProvider: Send + Sync {
+    /// Locale identifier (e.g. `en`, `de-DE`).
+    fn locale(&self) -> &str;
+    /// Returns the translated message for the given `key` or `None`
+    /// if the message should fall back to the English default.
+    fn message(&self, key: &str) -> Option<String>;
+}
+
+/// Default English locale provider.
+pub struct EnglishLocale;
+
+impl LocaleProvider for EnglishLocale {
+    fn locale(&self) -> &str {
+        "en"
+    }
+
+    fn message(&self, key: &str) -> Option<String> {
+        english(key).map(String::from)
+    }
+}
+
+/// Message catalog backed by a key-value map, suitable for
+/// loading translations shipped as JSON files.
+pub struct Catalog {
+    locale: String,
+    messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    pub fn new(locale: &str, messages: HashMap<String, String>) -> Self {
+        Self { locale: locale.to_string(), messages }
+    }
+
+    /// Create a catalog from a JSON object mapping message keys to translations.
+    pub fn try_from_json(locale: &str, json: &str) -> crate::result::Result<Self> {
+        Ok(Self::new(locale, serde_json::from_str(json)?))
+    }
+}
+
+impl LocaleProvider for Catalog {
+    fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    fn message(&self, key: &str) -> Option<String> {
+        self.messages.get(key).cloned()
+    }
+}
+
+static PROVIDER: RwLock<Option<Arc<dyn LocaleProvider>>> = RwLock::new(None);
+
+/// Install a custom locale provider. Messages not supplied by
+/// the provider fall back to the built-in English catalog.
+pub fn set_locale_provider(provider: Arc<dyn LocaleProvider>) {
+    *PROVIDER.write().unwrap() = Some(provider);
+}
+
+/// Returns the identifier of the active locale.
+pub fn locale() -> String {
+    PROVIDER
+        .read()
+        .unwrap()
+        .as_ref()
+        .map(|provider| provider.locale().to_string())
+        .unwrap_or_else(|| EnglishLocale.locale().to_string())
+}
+
+fn english(key: &str) -> Option<&'static str> {
+    ENGLISH.iter().find_map(|(k, v)| (*k == key).then_some(*v))
+}
+
+fn translate(key: &str) -> Option<String> {
+    PROVIDER.read().unwrap().as_ref().and_then(|provider| provider.message(key))
+}
+
+/// Returns the message for the given `key`. If the message is not
+/// present in any catalog, the `key` itself is returned.
+pub fn tr(key: &'static str) -> Cow<'static, str> {
+    translate(key).map(Cow::Owned).or_else(|| english(key).map(Cow::Borrowed)).unwrap_or(Cow::Borrowed(key))
+}
+
+/// Returns the message for the given `key` or the supplied `default`
+/// if the active locale provider does not supply one.
+pub fn tr_or<'a>(key: &str, default: &'a str) -> Cow<'a, str> {
+    translate(key).map(Cow::Owned).or_else(|| english(key).map(Cow::Borrowed)).unwrap_or(Cow::Borrowed(default))
+}
+
+/// Returns the message for the given `key` substituting `{name}` placeholders
+/// with the supplied arguments (placeholders without an argument are retained).
+/// Substituted values are not scanned for further placeholders.
+pub fn tr_args(key: &'static str, args: &[(&str, String)]) -> String {
+    let message = tr(key);
+    let mut result = String::with_capacity(message.len());
+    let mut rest = message.as_ref();
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        let value = tail.find('}').and_then(|end| args.iter().find(|(name, _)| *name == &tail[1..end]).map(|(_, value)| (value, end)));
+        match value {
+            Some((value, end)) => {
+                result.push_str(value);
+                rest = &tail[end + 1..];
+            }
+            None => {
+                result.push('{');
+                rest = &tail[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Localized message lookup: `t!("key")` or `t!("key", name = value, ...)`.
+#[macro_export]
+macro_rules! t {
+    ($key:expr) => {
+        $crate::locale::tr($key)
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::locale::tr_args($key, &[$((stringify!($name), $value.to_string())),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locale_tr_args() {
+        assert_eq!(tr_args("error.account-not-found", &[("account", "{account}".to_string())]), "account '{account}' not found");
+        assert_eq!(
+            tr_args("error.unrecognized-argument", &[("argument", "x".to_string()), ("accepted", "a, b".to_string())]),
+            "unrecognized argument 'x', accepted arguments are: a, b"
+        );
+        assert_eq!(tr_args("error.multiple-matches", &[]), "multiple matches for argument '{argument}'; please be more specific.");
+
+        // parameterized errors are resolved through the locale provider
+        let messages = [("error.account-not-found".to_string(), "Konto '{account}' nicht gefunden".to_string())];
+        set_locale_provider(Arc::new(Catalog::new("de", messages.into_iter().collect())));
+        assert_eq!(crate::error::Error::AccountNotFound("main".to_string()).to_string(), "Konto 'main' nicht gefunden");
+        assert_eq!(
+            crate::error::Error::MultipleMatches("a".to_string()).to_string(),
+            "multiple matches for argument 'a'; please be more specific."
+        );
+        *PROVIDER.write().unwrap() = None;
+    }
+}
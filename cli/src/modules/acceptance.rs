@@ -0,0 +1,81 @@
+use crate::imports::*;
+use kaspa_wallet_core::utxo::AcceptanceExportFormat;
+use std::path::Path;
+use workflow_store::fs;
+
+#[derive(Default, Handler)]
+#[help("Collect transaction acceptance time observations for fee research")]
+pub struct Acceptance;
+
+impl Acceptance {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, mut argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
+        let collector = ctx.wallet().utxo_processor().acceptance_collector().clone();
+
+        if argv.is_empty() {
+            let state = |enabled: bool| if enabled { "on" } else { "off" };
+            tprintln!(ctx, "Acceptance collector: {}", state(collector.is_enabled()));
+            tprintln!(ctx, "Mempool observation: {}", state(collector.observes_mempool()));
+            tprintln!(ctx, "Observations: {} (pending: {})", collector.len(), collector.pending());
+            return Ok(());
+        }
+
+        match argv.remove(0).as_str() {
+            "on" => {
+                collector.enable(true);
+                tprintln!(ctx, "Acceptance collector is enabled");
+            }
+            "off" => {
+                collector.enable(false);
+                tprintln!(ctx, "Acceptance collector is disabled");
+            }
+            "mempool" => match argv.first().map(String::as_str) {
+                Some("on") => {
+                    collector.set_observe_mempool(true);
+                    if !collector.is_enabled() {
+                        tprintln!(ctx, "Mempool observation will take effect once the collector is enabled");
+                    }
+                }
+                Some("off") => collector.set_observe_mempool(false),
+                _ => return self.display_help(ctx).await,
+            },
+            "export" => {
+                let Some(filename) = argv.first() else {
+                    return self.display_help(ctx).await;
+                };
+                // the format is taken from the argument or the file extension
+                let format = match argv.get(1) {
+                    Some(format) => format.parse::<AcceptanceExportFormat>()?,
+                    None if filename.to_lowercase().ends_with(".csv") => AcceptanceExportFormat::Csv,
+                    None => AcceptanceExportFormat::Json,
+                };
+                fs::write_string(Path::new(filename), &collector.export(format)?).await?;
+                tprintln!(ctx, "Exported {} observations to '{filename}'", collector.len());
+            }
+            "clear" => {
+                collector.clear();
+                tprintln!(ctx, "Acceptance observations cleared");
+            }
+            v => {
+                tprintln!(ctx, "unknown command: '{v}'\r\n");
+                return self.display_help(ctx).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn display_help(self: Arc<Self>, ctx: Arc<KaspaCli>) -> Result<()> {
+        ctx.term().help(
+            &[
+                ("on | off", "Enable or disable collection of acceptance times of the wallet transactions"),
+                ("mempool on | off", "Include transactions observed in the node mempool"),
+                ("export <file> [json|csv]", "Export collected observations (format defaults to the file extension)"),
+                ("clear", "Discard collected observations"),
+            ],
+            None,
+        )?;
+
+        Ok(())
+    }
+}
@@ -1,9 +1,13 @@
+use kaspa_wallet_core::account::ScanNotifier;
 use kaspa_wallet_core::account::BIP32_ACCOUNT_KIND;
 use kaspa_wallet_core::account::LEGACY_ACCOUNT_KIND;
 use kaspa_wallet_core::account::MULTISIG_ACCOUNT_KIND;
 
 use crate::imports::*;
 use crate::wizards;
+use kaspa_wallet_core::utxo::scan::DEFAULT_WINDOW_SIZE;
+use std::path::Path;
+use workflow_store::fs;
 
 #[derive(Default, Handler)]
 #[help("Account management operations")]
@@ -42,6 +46,17 @@ impl Account {
                 }
             }
             "create" => {
+                let derivation_path = if let Some(index) = argv.iter().position(|arg| arg == "--path") {
+                    argv.remove(index);
+                    if index >= argv.len() {
+                        tprintln!(ctx, "usage: 'account create bip32 [<name>] --path <derivation path>' (e.g. --path m/44'/972'/0')");
+                        return Ok(());
+                    }
+                    Some(argv.remove(index).parse::<DerivationPathTemplate>()?)
+                } else {
+                    None
+                };
+
                 let account_kind = if argv.is_empty() {
                     BIP32_ACCOUNT_KIND.into()
                 } else {
@@ -61,7 +76,7 @@ impl Account {
                 let prv_key_data_info = ctx.select_private_key().await?;
 
                 let account_name = account_name.as_deref();
-                wizards::account::create(&ctx, prv_key_data_info, account_kind, account_name).await?;
+                wizards::account::create(&ctx, prv_key_data_info, account_kind, account_name, derivation_path).await?;
             }
             "import" => {
                 if argv.is_empty() {
@@ -72,6 +87,11 @@ impl Account {
                     ctx.term().help(
                         &[
                             ("account import legacy-data", "Import KDX keydata file or kaspanet web wallet data on the same domain"),
+                            (
+                                "account import kdx [<keydata file>] [sweep]",
+                                "Import KDX keydata file (local or supplied) or kaspanet web wallet data, \
+                                optionally transferring the funds into a new bip32 account of the same mnemonic",
+                            ),
                             (
                                 "account import mnemonic bip32",
                                 "Import Bip32 (12 or 24 word mnemonics used by kaspawallet, kaspium, onekey, tangem etc.)",
@@ -102,42 +122,12 @@ impl Account {
 
                         if exists_legacy_v0_keydata().await? {
                             let import_secret = Secret::new(
-                                ctx.term()
-                                    .ask(true, "Enter the password for the account you are importing: ")
-                                    .await?
-                                    .trim()
-                                    .as_bytes()
-                                    .to_vec(),
+                                ctx.term().ask(true, &tr("prompt.import-account-password")).await?.trim().as_bytes().to_vec(),
                             );
                             let wallet_secret =
-                                Secret::new(ctx.term().ask(true, "Enter wallet password: ").await?.trim().as_bytes().to_vec());
-                            let ctx_ = ctx.clone();
+                                Secret::new(ctx.term().ask(true, &tr("prompt.wallet-password")).await?.trim().as_bytes().to_vec());
                             wallet
-                                .import_legacy_keydata(
-                                    &import_secret,
-                                    &wallet_secret,
-                                    None,
-                                    Some(Arc::new(move |processed: usize, _, balance, txid| {
-                                        if let Some(txid) = txid {
-                                            tprintln!(
-                                                ctx_,
-                                                "Scan detected {} KAS at index {}; transfer txid: {}",
-                                                sompi_to_kaspa_string(balance),
-                                                processed,
-                                                txid
-                                            );
-                                        } else if processed > 0 {
-                                            tprintln!(
-                                                ctx_,
-                                                "Scanned {} derivations, found {} KAS",
-                                                processed,
-                                                sompi_to_kaspa_string(balance)
-                                            );
-                                        } else {
-                                            tprintln!(ctx_, "Please wait... scanning for account UTXOs...");
-                                        }
-                                    })),
-                                )
+                                .import_legacy_keydata(&import_secret, &wallet_secret, None, Some(legacy_scan_notifier(&ctx)))
                                 .await?;
                         } else if application_runtime::is_web() {
                             return Err("'kaspanet' web wallet storage not found at this domain name".into());
@@ -145,6 +135,52 @@ impl Account {
                             return Err("KDX keydata file not found".into());
                         }
                     }
+                    "kdx" => {
+                        let sweep = argv.last().is_some_and(|arg| arg == "sweep");
+                        if sweep {
+                            argv.pop();
+                        }
+                        if argv.len() > 1 {
+                            tprintln!(ctx, "usage: 'account import kdx [<keydata file>] [sweep]'");
+                            tprintln!(ctx, "too many arguments: {}\r\n", argv.join(" "));
+                            return Ok(());
+                        }
+
+                        let keydata = if let Some(filename) = argv.first() {
+                            Some(fs::read_to_string(Path::new(filename)).await?)
+                        } else if exists_legacy_v0_keydata().await? {
+                            None
+                        } else if application_runtime::is_web() {
+                            return Err("'kaspanet' web wallet storage not found at this domain name".into());
+                        } else {
+                            return Err("KDX keydata file not found".into());
+                        };
+
+                        let import_secret =
+                            Secret::new(ctx.term().ask(true, &tr("prompt.import-account-password")).await?.trim().as_bytes().to_vec());
+                        let wallet_secret =
+                            Secret::new(ctx.term().ask(true, &tr("prompt.wallet-password")).await?.trim().as_bytes().to_vec());
+
+                        let notifier = Some(legacy_scan_notifier(&ctx));
+                        let account = if let Some(keydata) = keydata {
+                            wallet.import_legacy_keydata_with_data(&keydata, &import_secret, &wallet_secret, None, notifier).await?
+                        } else {
+                            wallet.import_legacy_keydata(&import_secret, &wallet_secret, None, notifier).await?
+                        };
+                        tprintln!(ctx, "imported legacy account '{}'", account.name_with_id());
+
+                        if sweep {
+                            let abortable = Abortable::new();
+                            let (destination, transaction_ids) =
+                                wallet.sweep_legacy_account(&account, &wallet_secret, None, &abortable).await?;
+                            if transaction_ids.is_empty() {
+                                tprintln!(ctx, "no mature funds to transfer into account '{}'", destination.name_with_id());
+                            } else {
+                                tprintln!(ctx, "funds are being transferred into account '{}'", destination.name_with_id());
+                                transaction_ids.iter().for_each(|id| tprintln!(ctx, "  {id}"));
+                            }
+                        }
+                    }
                     "mnemonic" => {
                         if argv.is_empty() {
                             tprintln!(ctx, "usage: 'account import mnemonic <bip32|legacy|multisig>'");
@@ -177,16 +213,65 @@ impl Account {
                     }
                     _ => {
                         tprintln!(ctx, "unknown account import type: '{import_kind}'");
-                        tprintln!(ctx, "supported import types are: 'mnemonic' or 'legacy-data'\r\n");
+                        tprintln!(ctx, "supported import types are: 'mnemonic', 'legacy-data' or 'kdx'\r\n");
                         return Ok(());
                     }
                 }
             }
+            "archive" => {
+                if argv.len() != 1 {
+                    tprintln!(ctx, "usage: 'account archive <name or id>'");
+                    return Ok(());
+                }
+
+                let account = ctx.find_accounts_by_name_or_id(argv.remove(0).trim()).await?;
+                let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+                let _ = ctx.notifier().show(Notification::Processing).await;
+                wallet.archive_accounts(&wallet_secret, &[*account.id()]).await?;
+                tprintln!(ctx, "account '{}' has been archived", account.name_with_id());
+            }
+            "unarchive" => {
+                if argv.len() != 1 {
+                    let descriptors = wallet.clone().archived_account_descriptors().await?;
+                    tprintln!(ctx, "usage: 'account unarchive <name or id>'");
+                    if descriptors.is_empty() {
+                        tprintln!(ctx, "no archived accounts");
+                    } else {
+                        tprintln!(ctx, "archived accounts:");
+                        descriptors.iter().for_each(|descriptor| tprintln!(ctx, "  {}", descriptor.name_with_id()));
+                    }
+                    return Ok(());
+                }
+
+                let descriptor = ctx.find_archived_account_by_name_or_id(argv.remove(0).trim()).await?;
+                let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+                let _ = ctx.notifier().show(Notification::Processing).await;
+                wallet.unarchive_accounts(&wallet_secret, &[descriptor.account_id]).await?;
+                wallet.activate_accounts(Some(&[descriptor.account_id])).await?;
+                tprintln!(ctx, "account '{}' has been restored", descriptor.name_with_id());
+            }
+            "gap-limit" => {
+                let account = ctx.account().await?;
+                let Some(gap_limit) = argv.first() else {
+                    let gap_limit = account.gap_limit().map(|gap_limit| gap_limit.to_string());
+                    tprintln!(ctx, "address gap limit: {}", gap_limit.unwrap_or_else(|| format!("{DEFAULT_WINDOW_SIZE} (default)")));
+                    return Ok(());
+                };
+
+                let gap_limit = if gap_limit == "default" { None } else { Some(gap_limit.parse::<u32>()?) };
+                account.clone().set_gap_limit(gap_limit).await?;
+                tprintln!(
+                    ctx,
+                    "address gap limit of account '{}' is set to {}",
+                    account.name_with_id(),
+                    account.gap_limit().unwrap_or(DEFAULT_WINDOW_SIZE as u32)
+                );
+            }
             "scan" | "sweep" => {
                 let len = argv.len();
                 let mut start = 0;
                 let mut count = 100_000;
-                let window = 128;
+                let window = ctx.account().await?.gap_limit().map(|gap_limit| gap_limit as usize).unwrap_or(DEFAULT_WINDOW_SIZE);
                 if len >= 2 {
                     start = argv.remove(0).parse::<usize>()?;
                     count = argv.remove(0).parse::<usize>()?;
@@ -213,12 +298,19 @@ impl Account {
         ctx.term().help(
             &[
                 ("create [<type>] [<name>]", "Create a new account (types: 'bip32' (default), 'legacy', 'multisig')"),
+                (
+                    "create bip32 [<name>] --path <path>",
+                    "Create a bip32 account using a custom derivation path (e.g. m/44'/972'/0' for legacy wallet variants)",
+                ),
                 (
                     "import <import-type> [<key-type> [extra keys]]",
                     "Import accounts from a private key using 24 or 12 word mnemonic or legacy data \
                 (KDX and kaspanet web wallet). Use 'account import' for additional help.",
                 ),
                 ("name <name>", "Name or rename the selected account (use 'remove' to remove the name"),
+                ("archive <name or id>", "Archive an account (hide it from the account list and stop its processing)"),
+                ("unarchive [<name or id>]", "Restore an archived account (lists archived accounts if no account is specified)"),
+                ("gap-limit [<count>|default]", "Show or set the address gap limit of the selected account (the number of unused addresses scanned past the last used address)"),
                 ("scan [<derivations>] or scan [<start>] [<derivations>]", "Scan extended address derivation chain (legacy accounts)"),
                 (
                     "sweep [<derivations>] or sweep [<start>] [<derivations>]",
@@ -276,3 +368,16 @@ impl Account {
         Ok(())
     }
 }
+
+fn legacy_scan_notifier(ctx: &Arc<KaspaCli>) -> ScanNotifier {
+    let ctx = ctx.clone();
+    Arc::new(move |processed: usize, _, balance, txid| {
+        if let Some(txid) = txid {
+            tprintln!(ctx, "Scan detected {} KAS at index {}; transfer txid: {}", sompi_to_kaspa_string(balance), processed, txid);
+        } else if processed > 0 {
+            tprintln!(ctx, "Scanned {} derivations, found {} KAS", processed, sompi_to_kaspa_string(balance));
+        } else {
+            tprintln!(ctx, "Please wait... scanning for account UTXOs...");
+        }
+    })
+}
@@ -1,25 +1,55 @@
 use crate::imports::*;
+use kaspa_wallet_core::api::WalletApi;
 
 #[derive(Default, Handler)]
 #[help("Show or generate a new address for the current wallet account")]
 pub struct Address;
 
 impl Address {
-    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, _cmd: &str) -> Result<()> {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, mut argv: Vec<String>, _cmd: &str) -> Result<()> {
         let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
 
+        let account = ctx.account_from_argv(&mut argv).await?;
+
         if argv.is_empty() {
-            let address = ctx.account().await?.receive_address()?.to_string();
+            let address = account.receive_address()?.to_string();
             tprintln!(ctx, "\n{address}\n");
         } else {
             let op = argv.first().unwrap();
             match op.as_str() {
                 "new" => {
-                    let account = ctx.wallet().account()?.as_derivation_capable()?;
+                    let account = account.as_derivation_capable()?;
                     let ident = account.name_with_id();
-                    let new_address = account.new_receive_address().await?;
+                    let new_address = account.clone().new_receive_address().await?;
                     tprintln!(ctx, "Generating new address for account {}", style(ident).cyan());
                     tprintln!(ctx, "{}", style(new_address).blue());
+                    if account.is_beyond_gap_limit() {
+                        tprintln!(
+                            ctx,
+                            "Warning: the address is {} addresses past the last used address, exceeding the account gap limit; \
+                            funds sent to it may not be discovered when restoring the wallet (see 'account gap-limit')",
+                            account.receive_address_gap()
+                        );
+                    }
+                }
+                "list" => {
+                    let addresses = ctx.wallet().clone().accounts_addresses(*account.id()).await?;
+                    let network_type: NetworkType = ctx.wallet().network_id()?.into();
+                    for account_address in addresses {
+                        let kind = if account_address.change { "change" } else { "receive" };
+                        let index = account_address.index.map(|index| format!("#{index}")).unwrap_or_default();
+                        let status = match account_address.usage.as_ref() {
+                            Some(usage) => format!(
+                                "used - received: {} in {} UTXO(s), DAA: {}..{}",
+                                sompi_to_kaspa_string_with_suffix(usage.total_received, &network_type),
+                                usage.utxo_count,
+                                usage.first_daa_score,
+                                usage.last_daa_score
+                            ),
+                            None => style("unused").green().to_string(),
+                        };
+                        tprintln!(ctx, "{kind:>7} {index:>5} {} {status}", style(&account_address.address).blue());
+                    }
                 }
                 v => {
                     tprintln!(ctx, "unknown command: '{v}'\r\n");
@@ -32,7 +62,14 @@ impl Address {
     }
 
     async fn display_help(self: Arc<Self>, ctx: Arc<KaspaCli>, _argv: Vec<String>) -> Result<()> {
-        ctx.term().help(&[("address [new]", "Show current or generate a new account address")], None)?;
+        ctx.term().help(
+            &[
+                ("address [new]", "Show current or generate a new account address"),
+                ("address list", "List account addresses along with the activity observed on each address"),
+                ("address [new] --account <account>", "Use the account identified by its name, id prefix or 'list' index"),
+            ],
+            None,
+        )?;
 
         Ok(())
     }
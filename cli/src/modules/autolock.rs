@@ -0,0 +1,33 @@
+use crate::imports::*;
+
+#[derive(Default, Handler)]
+#[help("Set wallet auto-lock timeout (in seconds, 'off' to disable)")]
+pub struct Autolock;
+
+impl Autolock {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
+
+        if let Some(timeout) = argv.first() {
+            let timeout = match timeout.trim() {
+                "off" => 0,
+                timeout => timeout.parse::<u64>().map_err(|_| Error::custom(format!("invalid auto-lock timeout: '{timeout}'")))?,
+            };
+
+            ctx.wallet().auto_lock().set_timeout((timeout > 0).then(|| Duration::from_secs(timeout)));
+            ctx.wallet().settings().set(WalletSettings::AutoLock, timeout).await?;
+
+            if timeout > 0 {
+                tprintln!(ctx, "Setting wallet auto-lock timeout to: {timeout} sec");
+            } else {
+                tprintln!(ctx, "Wallet auto-lock is disabled");
+            }
+        } else if let Some(timeout) = ctx.wallet().auto_lock().timeout() {
+            tprintln!(ctx, "Current wallet auto-lock timeout is: {} sec", timeout.as_secs());
+        } else {
+            tprintln!(ctx, "Wallet auto-lock is disabled");
+        }
+
+        Ok(())
+    }
+}
@@ -6,24 +6,27 @@ use kaspa_wallet_core::tx::PaymentDestination;
 pub struct Estimate;
 
 impl Estimate {
-    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, _cmd: &str) -> Result<()> {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, mut argv: Vec<String>, _cmd: &str) -> Result<()> {
         let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
 
         let account = ctx.wallet().account()?;
 
+        let change_policy = take_option(&mut argv, "--change-policy")?.map(|policy| policy.parse::<ChangePolicy>()).transpose()?;
+
         if argv.is_empty() {
-            tprintln!(ctx, "usage: estimate <amount> [<priority fee>]");
+            tprintln!(ctx, "usage: estimate <amount> [<priority fee>] [--change-policy <policy>]");
             return Ok(());
         }
 
         let amount_sompi = try_parse_required_nonzero_kaspa_as_sompi_u64(argv.first())?;
-        let priority_fee_sompi = try_parse_optional_kaspa_as_sompi_i64(argv.get(1))?.unwrap_or(0);
+        let priority_fee =
+            ctx.wallet().priority_fee_or_default(try_parse_optional_kaspa_as_sompi_i64(argv.get(1))?.map(Fees::from))?;
         let abortable = Abortable::default();
 
         // just use any address for an estimate (change address)
         let change_address = account.change_address()?;
         let destination = PaymentDestination::PaymentOutputs(PaymentOutputs::from((change_address.clone(), amount_sompi)));
-        let estimate = account.estimate(destination, priority_fee_sompi.into(), None, &abortable).await?;
+        let estimate = account.estimate(destination, priority_fee, None, change_policy.unwrap_or_default(), &abortable).await?;
 
         tprintln!(ctx, "Estimate - {estimate}");
 
@@ -35,7 +35,7 @@ async fn export_multisig_account(ctx: Arc<KaspaCli>, account: Arc<MultiSig>) ->
         None => Err(Error::KeyDataNotFound),
         Some(v) if v.is_empty() => Err(Error::KeyDataNotFound),
         Some(prv_key_data_ids) => {
-            let wallet_secret = Secret::new(ctx.term().ask(true, "Enter wallet password: ").await?.trim().as_bytes().to_vec());
+            let wallet_secret = Secret::new(ctx.term().ask(true, &tr("prompt.wallet-password")).await?.trim().as_bytes().to_vec());
             if wallet_secret.as_ref().is_empty() {
                 return Err(Error::WalletSecretRequired);
             }
@@ -73,7 +73,7 @@ async fn export_multisig_account(ctx: Arc<KaspaCli>, account: Arc<MultiSig>) ->
 async fn export_single_key_account(ctx: Arc<KaspaCli>, account: Arc<dyn Account>) -> Result<()> {
     let prv_key_data_id = account.prv_key_data_id()?;
 
-    let wallet_secret = Secret::new(ctx.term().ask(true, "Enter wallet password: ").await?.trim().as_bytes().to_vec());
+    let wallet_secret = Secret::new(ctx.term().ask(true, &tr("prompt.wallet-password")).await?.trim().as_bytes().to_vec());
     if wallet_secret.as_ref().is_empty() {
         return Err(Error::WalletSecretRequired);
     }
@@ -81,7 +81,7 @@ async fn export_single_key_account(ctx: Arc<KaspaCli>, account: Arc<dyn Account>
     let prv_key_data = ctx.store().as_prv_key_data_store()?.load_key_data(&wallet_secret, prv_key_data_id).await?;
     let Some(keydata) = prv_key_data else { return Err(Error::KeyDataNotFound) };
     let payment_secret = if keydata.payload.is_encrypted() {
-        let payment_secret = Secret::new(ctx.term().ask(true, "Enter payment password: ").await?.trim().as_bytes().to_vec());
+        let payment_secret = Secret::new(ctx.term().ask(true, &tr("prompt.payment-password")).await?.trim().as_bytes().to_vec());
         if payment_secret.as_ref().is_empty() {
             return Err(Error::PaymentSecretRequired);
         } else {
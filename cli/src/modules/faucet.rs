@@ -0,0 +1,68 @@
+use crate::imports::*;
+use kaspa_wallet_core::faucet::FaucetClient;
+
+#[derive(Default, Handler)]
+#[help("Request testnet funds from a faucet")]
+pub struct Faucet;
+
+impl Faucet {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
+
+        match argv.first().map(String::as_str) {
+            Some("url") => {
+                match argv.get(1) {
+                    Some(url) => {
+                        ctx.wallet().settings().set(WalletSettings::FaucetUrl, url).await?;
+                        tprintln!(ctx, "Faucet url set to {url}");
+                    }
+                    None => match ctx.wallet().settings().get::<String>(WalletSettings::FaucetUrl) {
+                        Some(url) => tprintln!(ctx, "Faucet url: {url}"),
+                        None => tprintln!(ctx, "Faucet url is not configured"),
+                    },
+                }
+                Ok(())
+            }
+            Some("request") => {
+                let Some(url) = ctx.wallet().settings().get::<String>(WalletSettings::FaucetUrl) else {
+                    return Err(Error::custom("Faucet url is not configured, please use 'faucet url <url>'"));
+                };
+
+                let address = match argv.get(1) {
+                    Some(address) => kaspa_addresses::Address::try_from(address.as_str())?,
+                    None => ctx.wallet().account()?.receive_address()?,
+                };
+
+                tprintln!(ctx, "Requesting funds for {}", style(&address).blue());
+                let response = FaucetClient::new(url).request(&address).await?;
+                let network_type = NetworkType::try_from(address.prefix).map_err(|err| Error::custom(err.to_string()))?;
+                match (response.transaction_id, response.amount) {
+                    (Some(transaction_id), Some(amount)) => tprintln!(
+                        ctx,
+                        "Received {} in transaction {transaction_id}",
+                        sompi_to_kaspa_string_with_suffix(amount, &network_type)
+                    ),
+                    (Some(transaction_id), None) => tprintln!(ctx, "Funds sent in transaction {transaction_id}"),
+                    (None, Some(amount)) => {
+                        tprintln!(ctx, "Received {}", sompi_to_kaspa_string_with_suffix(amount, &network_type))
+                    }
+                    (None, None) => tprintln!(ctx, "Funds requested"),
+                }
+                Ok(())
+            }
+            _ => self.display_help(ctx).await,
+        }
+    }
+
+    async fn display_help(self: Arc<Self>, ctx: Arc<KaspaCli>) -> Result<()> {
+        ctx.term().help(
+            &[
+                ("request [<address>]", "Request testnet funds for the address (default: current account receive address)"),
+                ("url [<url>]", "Show or set the faucet url"),
+            ],
+            None,
+        )?;
+
+        Ok(())
+    }
+}
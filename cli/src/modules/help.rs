@@ -7,15 +7,21 @@ pub struct Help;
 impl Help {
     async fn main(self: Arc<Self>, dyn_ctx: &Arc<dyn Context>, _argv: Vec<String>, _cmd: &str) -> Result<()> {
         let term = dyn_ctx.term();
-        term.writeln("\nCommands:".crlf());
+        term.writeln(format!("\n{}", tr("help.commands")).crlf());
 
         let ctx = dyn_ctx.clone().downcast_arc::<KaspaCli>()?;
         let handlers = ctx.handlers().collect();
         let handlers =
-            handlers.into_iter().filter_map(|h| h.verb(dyn_ctx).map(|verb| (verb, get_handler_help(h, dyn_ctx)))).collect::<Vec<_>>();
+            handlers.into_iter().filter_map(|h| h.verb(dyn_ctx).map(|verb| (verb, help_text(verb, h, dyn_ctx)))).collect::<Vec<_>>();
 
         term.help(&handlers, None)?;
 
         Ok(())
     }
 }
+
+/// Resolves the localized help text for the command `verb`
+/// falling back to the help text declared by the handler.
+fn help_text(verb: &str, handler: Arc<dyn Handler>, ctx: &Arc<dyn Context>) -> String {
+    crate::locale::tr_or(&format!("help.{verb}"), &get_handler_help(handler, ctx)).into_owned()
+}
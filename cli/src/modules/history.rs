@@ -1,7 +1,11 @@
 use crate::imports::*;
 use kaspa_consensus_core::tx::TransactionId;
 use kaspa_wallet_core::error::Error as WalletError;
-use kaspa_wallet_core::storage::Binding;
+use kaspa_wallet_core::storage::transaction::date_to_unixtime_msec;
+use kaspa_wallet_core::storage::{Binding, TransactionExportColumn, TransactionExportFormat, TransactionExportOptions};
+use std::path::Path;
+use workflow_store::fs;
+
 #[derive(Default, Handler)]
 #[help("Display transaction history")]
 pub struct History;
@@ -45,6 +49,71 @@ impl History {
 
                 return Ok(());
             }
+            "rescan" => {
+                let start = if argv.is_empty() {
+                    0
+                } else {
+                    argv.remove(0).parse::<u64>().map_err(|_| Error::custom("invalid start DAA score"))?
+                };
+                let end = if argv.is_empty() {
+                    u64::MAX
+                } else {
+                    argv.remove(0).parse::<u64>().map_err(|_| Error::custom("invalid end DAA score"))?
+                };
+
+                tprintln!(ctx, "Rescanning transaction history, this may take a while...");
+                let abortable = Abortable::default();
+                let summary = account.clone().rescan_history(start..end, &abortable).await?;
+                tprintln!(
+                    ctx,
+                    "Processed {} chain blocks, {} account transactions; {} records created, {} already known",
+                    summary.chain_blocks.separated_string(),
+                    summary.accepted_transactions.separated_string(),
+                    summary.records.separated_string(),
+                    summary.existing.separated_string()
+                );
+
+                return Ok(());
+            }
+            "export" => {
+                if argv.is_empty() {
+                    tprintln!(
+                        ctx,
+                        "usage: history export <file> [csv|json] [--from <YYYY-MM-DD>] [--to <YYYY-MM-DD>] [--columns <c1,c2,..>]"
+                    );
+                    return Ok(());
+                }
+                let filename = argv.remove(0);
+                // the format is taken from the argument or the file extension
+                let mut format = if filename.to_lowercase().ends_with(".json") {
+                    TransactionExportFormat::Json
+                } else {
+                    TransactionExportFormat::Csv
+                };
+                let (mut since, mut until, mut columns) = (None, None, vec![]);
+                let mut args = argv.into_iter();
+                while let Some(arg) = args.next() {
+                    let mut value = || args.next().ok_or_else(|| Error::custom(format!("missing value for '{arg}'")));
+                    match arg.as_str() {
+                        "--from" => since = Some(date_to_unixtime_msec(&value()?)?),
+                        "--to" => until = Some(date_to_unixtime_msec(&value()?)?),
+                        "--columns" => {
+                            columns = value()?
+                                .split(',')
+                                .map(|column| column.trim().parse())
+                                .collect::<std::result::Result<Vec<TransactionExportColumn>, _>>()?
+                        }
+                        _ => format = arg.parse()?,
+                    }
+                }
+
+                let options = TransactionExportOptions::new(format).with_columns(columns).with_range(since, until);
+                let data = ctx.wallet().store().as_transaction_record_store()?.export(&binding, &network_id, &options).await?;
+                fs::write_string(Path::new(&filename), &data).await?;
+                tprintln!(ctx, "Transaction history exported to '{filename}'");
+
+                return Ok(());
+            }
             "list" => {
                 let last = if argv.is_empty() { None } else { argv[0].parse::<usize>().ok() };
                 (last, false)
@@ -141,6 +210,14 @@ impl History {
                 ("list [<last N transactions>]", "List transactions"),
                 ("details [<last N transactions>]", "List transactions with UTXO details"),
                 ("lookup <transaction id>", "Lookup transaction in the history"),
+                (
+                    "export <file> [csv|json] [--from <date>] [--to <date>] [--columns <columns>]",
+                    "Export transactions for accounting (dates as YYYY-MM-DD, '--to' is exclusive; columns: timestamp,txid,direction,amount,fee,address,note)",
+                ),
+                (
+                    "rescan [<start DAA score>] [<end DAA score>]",
+                    "Reconstruct the transaction history from the transactions accepted by the network",
+                ),
             ],
             None,
         )?;
@@ -29,13 +29,13 @@ impl Import {
                 if exists_legacy_v0_keydata().await? {
                     let import_secret = Secret::new(
                         ctx.term()
-                            .ask(true, "Enter the password for the account you are importing: ")
+                            .ask(true, &tr("prompt.import-account-password"))
                             .await?
                             .trim()
                             .as_bytes()
                             .to_vec(),
                     );
-                    let wallet_secret = Secret::new(ctx.term().ask(true, "Enter wallet password: ").await?.trim().as_bytes().to_vec());
+                    let wallet_secret = Secret::new(ctx.term().ask(true, &tr("prompt.wallet-password")).await?.trim().as_bytes().to_vec());
                     wallet.import_gen0_keydata(import_secret, wallet_secret, None).await?;
                 } else if application_runtime::is_web() {
                     return Err("'kaspanet' web wallet storage not found at this domain name".into());
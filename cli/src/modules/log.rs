@@ -0,0 +1,61 @@
+use crate::imports::*;
+
+#[derive(Default, Handler)]
+#[help("Show or change the wallet log levels")]
+pub struct Log;
+
+impl Log {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, mut argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
+
+        if argv.is_empty() {
+            let config = kaspa_wallet_core::logging::config();
+            tprintln!(ctx, "default log level: {}", config.level);
+            for target in LogTarget::ALL {
+                tprintln!(ctx, "{}: {}", target.to_string().pad_to_width(12), config.level(target));
+            }
+            return Ok(());
+        }
+
+        match argv.remove(0).as_str() {
+            "level" => {
+                let (target, level) = match argv.as_slice() {
+                    [level] => (None, level.parse::<LogLevel>()?),
+                    [target, level] => (Some(target.parse::<LogTarget>()?), level.parse::<LogLevel>()?),
+                    _ => {
+                        tprintln!(ctx, "usage: 'log level [<module>] <level>'");
+                        return Ok(());
+                    }
+                };
+
+                let config = ctx.wallet().set_log_level(target, level).await?;
+                match target {
+                    Some(target) => tprintln!(ctx, "log level of '{target}' is set to '{}'", config.level(target)),
+                    None => tprintln!(ctx, "default log level is set to '{}'", config.level),
+                }
+            }
+            v => {
+                tprintln!(ctx, "unknown command: '{v}'\r\n");
+                return self.display_help(ctx).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn display_help(self: Arc<Self>, ctx: Arc<KaspaCli>) -> Result<()> {
+        ctx.term().help(
+            &[
+                ("log", "Show the log levels of the wallet modules"),
+                (
+                    "level [<module>] <level>",
+                    "Set the log level of a module ('wallet', 'utxo', 'rpc-client', 'storage') or the default log level \
+                    (levels: 'off', 'error', 'warn', 'info', 'debug', 'trace')",
+                ),
+            ],
+            None,
+        )?;
+
+        Ok(())
+    }
+}
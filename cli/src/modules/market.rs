@@ -0,0 +1,54 @@
+use crate::imports::*;
+use kaspa_wallet_core::market::normalize_currencies;
+
+#[derive(Default, Handler)]
+#[help("Configure the fiat price feed ('off' to disable)")]
+pub struct Market;
+
+impl Market {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
+
+        let Some(feed) = argv.first() else {
+            let Some(feed) = ctx.wallet().market().feed() else {
+                tprintln!(ctx, "Price feed is disabled");
+                return Ok(());
+            };
+
+            if let Some(market) = ctx.wallet().market().data() {
+                tprintln!(ctx, "Market data obtained from {}:", market.source);
+                for price in market.prices.iter() {
+                    let change = price.change_24h.map(|change| format!("   24h: {change:+.2}%")).unwrap_or_default();
+                    let market_cap = price
+                        .market_cap
+                        .map(|market_cap| format!("   market cap: {}", (market_cap as u64).separated_string()))
+                        .unwrap_or_default();
+                    tprintln!(ctx, "{:>6}: {:.6}{change}{market_cap}", price.currency.to_uppercase(), price.price);
+                }
+            } else {
+                tprintln!(ctx, "Market data has not been obtained from {} yet", feed.name());
+            }
+            return Ok(());
+        };
+
+        if feed.trim() == "off" {
+            ctx.wallet().market().configure(None, &[], None)?;
+            ctx.wallet().settings().remove(WalletSettings::PriceFeed).await?;
+            tprintln!(ctx, "Price feed is disabled");
+            return Ok(());
+        }
+
+        let feed = feed.parse::<PriceFeedKind>()?;
+        let currencies = match argv.get(1) {
+            Some(currencies) => normalize_currencies(&currencies.split(',').collect::<Vec<_>>())?,
+            None => ctx.wallet().settings().get::<Vec<String>>(WalletSettings::Currencies).unwrap_or_else(|| vec!["usd".to_string()]),
+        };
+
+        ctx.wallet().market().configure(Some(feed.feed()), &currencies, None)?;
+        ctx.wallet().settings().set(WalletSettings::PriceFeed, feed).await?;
+        ctx.wallet().settings().set(WalletSettings::Currencies, &currencies).await?;
+        tprintln!(ctx, "Using {feed} price feed ({})", currencies.join(", ").to_uppercase());
+
+        Ok(())
+    }
+}
@@ -1,9 +1,5 @@
 use kaspa_addresses::Version;
-use kaspa_bip32::secp256k1::XOnlyPublicKey;
-use kaspa_wallet_core::{
-    account::{BIP32_ACCOUNT_KIND, KEYPAIR_ACCOUNT_KIND},
-    message::{sign_message, verify_message, PersonalMessage},
-};
+use kaspa_wallet_core::message::{verify_message_with_address, PersonalMessage};
 
 use crate::imports::*;
 
@@ -39,7 +35,7 @@ impl Message {
                 }
 
                 let kaspa_address = argv[1].as_str();
-                let asked_message = ctx.term().ask(false, "Message: ").await?;
+                let asked_message = ctx.term().ask(false, &tr("prompt.message")).await?;
                 let message = asked_message.as_str();
 
                 self.sign(ctx, kaspa_address, message).await?;
@@ -50,7 +46,7 @@ impl Message {
                 }
                 let kaspa_address = argv[1].as_str();
                 let signature = argv[2].as_str();
-                let asked_message = ctx.term().ask(false, "Message: ").await?;
+                let asked_message = ctx.term().ask(false, &tr("prompt.message")).await?;
                 let message = asked_message.as_str();
 
                 self.verify(ctx, kaspa_address, signature, message).await?;
@@ -85,19 +81,12 @@ impl Message {
             return Err(Error::custom("Address not supported for message signing. Only supports PubKey addresses"));
         }
 
-        let pm = PersonalMessage(message);
-        let privkey = self.get_address_private_key(&ctx, kaspa_address).await?;
-
-        let sig_result = sign_message(&pm, &privkey);
+        let account = ctx.wallet().account()?;
+        let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
+        let signature = account.sign_message(&kaspa_address, message, wallet_secret, payment_secret).await?;
+        tprintln!(ctx, "Signature: {}", signature);
 
-        match sig_result {
-            Ok(signature) => {
-                let sig_hex = faster_hex::hex_string(signature.as_slice());
-                tprintln!(ctx, "Signature: {}", sig_hex);
-                Ok(())
-            }
-            Err(_) => Err(Error::custom("Message signing failed")),
-        }
+        Ok(())
     }
 
     async fn verify(self: Arc<Self>, ctx: Arc<KaspaCli>, kaspa_address: &str, signature: &str, message: &str) -> Result<()> {
@@ -106,15 +95,8 @@ impl Message {
             return Err(Error::custom("Address not supported for message signing. Only supports PubKey addresses"));
         }
 
-        let pubkey = XOnlyPublicKey::from_slice(&kaspa_address.payload[0..32]).unwrap();
-
-        let mut signature_hex = [0u8; 64];
-        faster_hex::hex_decode(signature.as_bytes(), &mut signature_hex)?;
-
         let pm = PersonalMessage(message);
-        let verify_result = verify_message(&pm, &signature_hex.to_vec(), &pubkey);
-
-        match verify_result {
+        match verify_message_with_address(&pm, signature, &kaspa_address) {
             Ok(()) => {
                 tprintln!(ctx, "Message verified successfully!");
             }
@@ -125,34 +107,4 @@ impl Message {
 
         Ok(())
     }
-
-    async fn get_address_private_key(self: Arc<Self>, ctx: &Arc<KaspaCli>, kaspa_address: Address) -> Result<[u8; 32]> {
-        let account = ctx.wallet().account()?;
-
-        match account.account_kind().as_ref() {
-            BIP32_ACCOUNT_KIND => {
-                let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
-                let keydata = account.prv_key_data(wallet_secret).await?;
-                let account = account.clone().as_derivation_capable().expect("expecting derivation capable");
-
-                let (receive, change) = account.derivation().addresses_indexes(&[&kaspa_address])?;
-                let private_keys = account.create_private_keys(&keydata, &payment_secret, &receive, &change)?;
-                for (address, private_key) in private_keys {
-                    if kaspa_address == *address {
-                        return Ok(private_key.secret_bytes());
-                    }
-                }
-
-                Err(Error::custom("Could not find address in any derivation path in account"))
-            }
-            KEYPAIR_ACCOUNT_KIND => {
-                let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
-                let keydata = account.prv_key_data(wallet_secret).await?;
-                let decrypted_privkey = keydata.payload.decrypt(payment_secret.as_ref()).unwrap();
-                let secretkey = decrypted_privkey.as_secret_key()?.unwrap();
-                Ok(secretkey.secret_bytes())
-            }
-            _ => Err(Error::custom("Unsupported account kind")),
-        }
-    }
 }
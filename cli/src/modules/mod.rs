@@ -1,7 +1,9 @@
 use crate::imports::*;
 
+pub mod acceptance;
 pub mod account;
 pub mod address;
+pub mod autolock;
 pub mod broadcast;
 pub mod close;
 pub mod connect;
@@ -12,12 +14,16 @@ pub mod disconnect;
 pub mod estimate;
 pub mod exit;
 pub mod export;
+#[cfg(feature = "faucet")]
+pub mod faucet;
 pub mod guide;
 pub mod halt;
 pub mod help;
 pub mod history;
 // pub mod import;
 pub mod list;
+pub mod log;
+pub mod market;
 pub mod message;
 pub mod miner;
 pub mod monitor;
@@ -26,20 +32,26 @@ pub mod network;
 pub mod node;
 pub mod open;
 pub mod ping;
+pub mod priority_fee;
+pub mod profile;
 pub mod reload;
 pub mod rpc;
 pub mod select;
 pub mod send;
+pub mod send_batch;
 pub mod server;
 pub mod settings;
 pub mod sign;
+pub mod sign_tx;
 pub mod start;
 pub mod stop;
 pub mod sweep;
+pub mod tasks;
 // pub mod test;
 pub mod theme;
 pub mod track;
 pub mod transfer;
+pub mod use_account;
 pub mod wallet;
 
 // this module is registered manually within
@@ -56,13 +68,54 @@ pub fn register_handlers(cli: &Arc<KaspaCli>) -> Result<()> {
         cli,
         cli.handlers(),
         [
-            account, address, close, connect, details, disconnect, estimate, exit, export, guide, help, history, rpc, list, miner,
-            message, monitor, mute, network, node, open, ping, reload, select, send, server, settings, sweep, track, transfer,
+            acceptance,
+            account,
+            address,
+            autolock,
+            close,
+            connect,
+            details,
+            disconnect,
+            estimate,
+            exit,
+            export,
+            guide,
+            help,
+            history,
+            rpc,
+            list,
+            log,
+            market,
+            miner,
+            message,
+            monitor,
+            mute,
+            network,
+            node,
+            open,
+            ping,
+            priority_fee,
+            profile,
+            reload,
+            select,
+            send,
+            send_batch,
+            server,
+            settings,
+            sign_tx,
+            sweep,
+            tasks,
+            track,
+            transfer,
+            use_account,
             wallet,
             // halt,
             // theme,  start, stop
         ]
     );
 
+    #[cfg(feature = "faucet")]
+    register_handlers!(cli, cli.handlers(), [faucet]);
+
     Ok(())
 }
@@ -1,5 +1,7 @@
 use crate::imports::*;
 use kaspa_daemon::KaspadConfig;
+use kaspa_wrpc_client::benchmark::{benchmark, NodeBenchmark};
+use kaspa_wrpc_client::Resolver;
 use workflow_core::task::sleep;
 use workflow_node::process;
 pub use workflow_node::process::Event;
@@ -48,16 +50,16 @@ impl Default for Node {
 
 #[async_trait]
 impl Handler for Node {
-    fn verb(&self, ctx: &Arc<dyn Context>) -> Option<&'static str> {
-        if let Ok(ctx) = ctx.clone().downcast_arc::<KaspaCli>() {
-            ctx.daemons().clone().kaspad.as_ref().map(|_| "node")
-        } else {
-            None
-        }
+    fn verb(&self, _ctx: &Arc<dyn Context>) -> Option<&'static str> {
+        Some("node")
     }
 
-    fn help(&self, _ctx: &Arc<dyn Context>) -> &'static str {
-        "Manage the local Kaspa node instance"
+    fn help(&self, ctx: &Arc<dyn Context>) -> &'static str {
+        if Self::has_kaspad(ctx) {
+            "Manage the local Kaspa node instance, benchmark nodes"
+        } else {
+            "Benchmark Kaspa nodes"
+        }
     }
 
     async fn start(self: Arc<Self>, _ctx: &Arc<dyn Context>) -> cli::Result<()> {
@@ -75,6 +77,10 @@ impl Handler for Node {
 }
 
 impl Node {
+    fn has_kaspad(ctx: &Arc<dyn Context>) -> bool {
+        ctx.clone().downcast_arc::<KaspaCli>().map(|ctx| ctx.daemons().kaspad.is_some()).unwrap_or(false)
+    }
+
     pub fn is_running(&self) -> bool {
         self.is_running.load(Ordering::SeqCst)
     }
@@ -96,8 +102,18 @@ impl Node {
         if argv.is_empty() {
             return self.display_help(ctx, argv).await;
         }
-        let kaspad = ctx.daemons().kaspad();
-        match argv.remove(0).as_str() {
+
+        let action = argv.remove(0);
+        if action == "benchmark" {
+            return self.benchmark(ctx, argv).await;
+        }
+
+        let Some(kaspad) = ctx.daemons().try_kaspad() else {
+            tprintln!(ctx, "unknown command: '{action}'\r\n");
+            return self.display_help(ctx, argv).await;
+        };
+
+        match action.as_str() {
             "start" => {
                 let mute = self.mute.load(Ordering::SeqCst);
                 if mute {
@@ -183,8 +199,9 @@ impl Node {
     }
 
     async fn display_help(self: Arc<Self>, ctx: Arc<KaspaCli>, _argv: Vec<String>) -> Result<()> {
-        ctx.term().help(
-            &[
+        let mut help = vec![];
+        if ctx.daemons().kaspad.is_some() {
+            help.extend([
                 ("select", "Select Kaspad executable (binary) location"),
                 ("version", "Display Kaspad executable version"),
                 ("start", "Start the local Kaspa node instance"),
@@ -193,9 +210,67 @@ impl Node {
                 ("kill", "Kill the local Kaspa node instance"),
                 ("status", "Get the status of the local Kaspa node instance"),
                 ("mute", "Toggle log output"),
-            ],
-            None,
-        )?;
+            ]);
+        }
+        help.push((
+            "benchmark [<url> ...] [--save]",
+            "Rank nodes by latency (public nodes if no urls are supplied), '--save' sets the best node as the RPC server",
+        ));
+        ctx.term().help(&help, None)?;
+
+        Ok(())
+    }
+
+    async fn benchmark(self: Arc<Self>, ctx: Arc<KaspaCli>, argv: Vec<String>) -> Result<()> {
+        let network_id = ctx.wallet().network_id()?;
+        let (options, urls): (Vec<_>, Vec<_>) = argv.into_iter().partition(|arg| arg.starts_with("--"));
+        let save = options.iter().any(|option| option == "--save");
+        if let Some(option) = options.iter().find(|option| *option != "--save") {
+            return Err(Error::custom(format!("unknown option: '{option}'")));
+        }
+
+        let results = if urls.is_empty() {
+            tprintln!(ctx, "benchmarking public nodes...");
+            Resolver::default().benchmark(WrpcEncoding::Borsh, network_id, None).await?
+        } else {
+            tprintln!(ctx, "benchmarking {} node(s)...", urls.len());
+            benchmark(&urls, WrpcEncoding::Borsh, network_id, None).await
+        };
+
+        if results.is_empty() {
+            tprintln!(ctx, "no nodes to benchmark");
+            return Ok(());
+        }
+
+        let width = results.iter().map(|result| result.url.len()).max().unwrap_or_default();
+        tprintln!(ctx);
+        tprintln!(ctx, "{:>4}  {:<width$}  {:>8}  {:>8}  {:<8}  version", "rank", "url", "ping", "get_info", "synced");
+        for (index, result) in results.iter().enumerate() {
+            let NodeBenchmark { url, ping, get_info, is_synced, server_version, error } = result;
+            let line = format!(
+                "{:>4}  {url:<width$}  {:>8}  {:>8}  {:<8}  {}",
+                index + 1,
+                ping.map(|ping| format!("{ping} ms")).unwrap_or("-".to_string()),
+                get_info.map(|get_info| format!("{get_info} ms")).unwrap_or("-".to_string()),
+                is_synced.map(|is_synced| if is_synced { "yes" } else { "no" }).unwrap_or("-"),
+                server_version.as_deref().unwrap_or("-"),
+            );
+            if let Some(error) = error {
+                tprintln!(ctx, "{} {}", style(line).dim(), style(error).red());
+            } else {
+                tprintln!(ctx, "{line}");
+            }
+        }
+        tprintln!(ctx);
+
+        if save {
+            if let Some(best) = results.first().filter(|result| result.is_reachable()) {
+                ctx.wallet().settings().set(WalletSettings::Server, best.url.as_str()).await?;
+                tprintln!(ctx, "Setting RPC server to: {}", best.url);
+            } else {
+                twarnln!(ctx, "no reachable nodes, the RPC server setting is not changed");
+            }
+        }
 
         Ok(())
     }
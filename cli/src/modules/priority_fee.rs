@@ -0,0 +1,35 @@
+use crate::imports::*;
+
+#[derive(Default, Handler)]
+#[help("Set the default priority fee of the current network ('off' to disable)")]
+pub struct PriorityFee;
+
+impl PriorityFee {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
+
+        let network_id = ctx.wallet().network_id()?;
+        let network_type = NetworkType::from(network_id);
+
+        if let Some(priority_fee) = argv.first() {
+            let priority_fee_sompi = match priority_fee.trim() {
+                "off" => 0,
+                priority_fee => try_parse_amount_as_sompi(priority_fee)?,
+            };
+
+            ctx.wallet().set_default_priority_fee(&network_id, priority_fee_sompi).await?;
+
+            if priority_fee_sompi > 0 {
+                let priority_fee = sompi_to_kaspa_string_with_suffix(priority_fee_sompi, &network_type);
+                tprintln!(ctx, "Setting default priority fee on {network_id} to: {priority_fee}");
+            } else {
+                tprintln!(ctx, "Default priority fee on {network_id} is disabled");
+            }
+        } else {
+            let priority_fee = sompi_to_kaspa_string_with_suffix(ctx.wallet().default_priority_fee(&network_id), &network_type);
+            tprintln!(ctx, "Current default priority fee on {network_id} is: {priority_fee}");
+        }
+
+        Ok(())
+    }
+}
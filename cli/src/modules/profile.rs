@@ -0,0 +1,101 @@
+use crate::imports::*;
+
+#[derive(Default, Handler)]
+#[help("Manage per-network settings profiles")]
+pub struct Profile;
+
+impl Profile {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, mut argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
+
+        let action = if argv.is_empty() { "list".to_string() } else { argv.remove(0) };
+
+        match action.as_str() {
+            "list" => {
+                let wallet = ctx.wallet();
+                let profiles = wallet.settings().profiles();
+                if profiles.is_empty() {
+                    tprintln!(ctx, "No settings profiles; use 'profile switch <mainnet|testnet-10|testnet-11|devnet>' to create one");
+                    return Ok(());
+                }
+
+                let active = wallet.settings().profile();
+                for (name, profile) in profiles.iter() {
+                    let marker = if active.as_deref() == Some(name.as_str()) { "•" } else { " " };
+                    let priority_fee = sompi_to_kaspa_string_with_suffix(
+                        wallet.default_priority_fee(&profile.network),
+                        &NetworkType::from(profile.network),
+                    );
+                    tprintln!(
+                        ctx,
+                        "{marker} {}: network: {}  server: {}  wallet: {}  account: {}  priority fee: {priority_fee}",
+                        style(name).blue(),
+                        profile.network,
+                        profile.server.as_deref().unwrap_or("n/a"),
+                        profile.wallet.as_deref().unwrap_or("n/a"),
+                        profile.account.as_deref().unwrap_or("n/a"),
+                    );
+                }
+            }
+            "switch" => {
+                let Some(name) = argv.first() else {
+                    tprintln!(ctx, "usage: profile switch <name>");
+                    return Ok(());
+                };
+
+                let is_connected = ctx.wallet().is_connected();
+                if is_connected {
+                    ctx.term().exec("disconnect").await?;
+                }
+
+                let profile = ctx.wallet().settings().select_profile(name.trim()).await?;
+                ctx.wallet().apply_settings_profile(&profile)?;
+                tprintln!(
+                    ctx,
+                    "Switched to profile '{}' (network: {}, server: {})",
+                    name.trim(),
+                    profile.network,
+                    profile.server.as_deref().unwrap_or("n/a")
+                );
+
+                if let (Some(descriptor), Some(wallet)) = (ctx.wallet().descriptor(), profile.wallet.as_ref()) {
+                    if &descriptor.filename != wallet {
+                        tprintln!(ctx, "The profile uses wallet '{wallet}', use 'wallet open' to open it");
+                    }
+                }
+
+                if is_connected {
+                    ctx.term().exec("connect").await?;
+                }
+            }
+            "remove" => {
+                let Some(name) = argv.first() else {
+                    tprintln!(ctx, "usage: profile remove <name>");
+                    return Ok(());
+                };
+
+                ctx.wallet().settings().remove_profile(name.trim()).await?;
+                tprintln!(ctx, "Removed profile '{}'", name.trim());
+            }
+            v => {
+                tprintln!(ctx, "unknown command: '{v}'");
+                self.display_help(ctx, argv).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn display_help(self: Arc<Self>, ctx: Arc<KaspaCli>, _argv: Vec<String>) -> Result<()> {
+        ctx.term().help(
+            &[
+                ("list", "List settings profiles"),
+                ("switch <name>", "Switch to a profile (profiles named after a network id are created on first use)"),
+                ("remove <name>", "Remove a settings profile"),
+            ],
+            None,
+        )?;
+
+        Ok(())
+    }
+}
@@ -2,6 +2,20 @@ use crate::imports::*;
 use convert_case::{Case, Casing};
 use kaspa_rpc_core::{api::ops::RpcApiOps, *};
 
+/// Dispatches a JSON-encoded request to the corresponding `RpcApi` method
+/// and produces a pretty-printed JSON response.
+macro_rules! raw_call {
+    ($rpc:expr, $op:expr, $json:expr, [$($variant:ident => $method:ident($request:ident)),* $(,)?]) => {
+        match $op {
+            $(RpcApiOps::$variant => {
+                let request = serde_json::from_str::<kaspa_rpc_core::$request>($json)?;
+                serde_json::to_string_pretty(&$rpc.$method(request).await?)?
+            })*
+            _ => return Err(Error::custom(format!("rpc method '{:?}' can not be invoked with JSON arguments", $op))),
+        }
+    };
+}
+
 #[derive(Default, Handler)]
 #[help("Execute RPC commands against the connected Kaspa node")]
 pub struct Rpc;
@@ -25,13 +39,17 @@ impl Rpc {
 
         let op_str = argv.remove(0);
 
-        let sanitize = regex::Regex::new(r"\s*rpc\s+\S+\s+").unwrap();
-        let _args = sanitize.replace(cmd, "").trim().to_string();
+        let sanitize = regex::Regex::new(r"\s*rpc\s+\S+\s*").unwrap();
+        let args = sanitize.replace(cmd, "").trim().to_string();
         let op_str_uc = op_str.to_case(Case::UpperCamel).to_string();
         // tprintln!(ctx, "uc: '{op_str_uc}'");
 
         let op = RpcApiOps::from_str(op_str_uc.as_str()).ok_or(Error::custom(format!("No such rpc method: '{op_str}'")))?;
 
+        if args.starts_with('{') {
+            return self.raw(&ctx, op, args.as_str()).await;
+        }
+
         match op {
             RpcApiOps::Ping => {
                 rpc.ping().await?;
@@ -241,6 +259,55 @@ impl Rpc {
         Ok(())
     }
 
+    /// Invoke `op` with the request deserialized from `json` and print the JSON response.
+    async fn raw(&self, ctx: &Arc<KaspaCli>, op: RpcApiOps, json: &str) -> Result<()> {
+        let rpc = ctx.wallet().rpc_api().clone();
+
+        let response = raw_call!(rpc, op, json, [
+            Ping => ping_call(PingRequest),
+            GetMetrics => get_metrics_call(GetMetricsRequest),
+            GetServerInfo => get_server_info_call(GetServerInfoRequest),
+            GetSyncStatus => get_sync_status_call(GetSyncStatusRequest),
+            GetCurrentNetwork => get_current_network_call(GetCurrentNetworkRequest),
+            SubmitBlock => submit_block_call(SubmitBlockRequest),
+            GetBlockTemplate => get_block_template_call(GetBlockTemplateRequest),
+            GetPeerAddresses => get_peer_addresses_call(GetPeerAddressesRequest),
+            GetSink => get_sink_call(GetSinkRequest),
+            GetMempoolEntry => get_mempool_entry_call(GetMempoolEntryRequest),
+            GetMempoolEntries => get_mempool_entries_call(GetMempoolEntriesRequest),
+            GetConnectedPeerInfo => get_connected_peer_info_call(GetConnectedPeerInfoRequest),
+            AddPeer => add_peer_call(AddPeerRequest),
+            SubmitTransaction => submit_transaction_call(SubmitTransactionRequest),
+            GetBlock => get_block_call(GetBlockRequest),
+            GetSubnetwork => get_subnetwork_call(GetSubnetworkRequest),
+            GetVirtualChainFromBlock => get_virtual_chain_from_block_call(GetVirtualChainFromBlockRequest),
+            GetBlocks => get_blocks_call(GetBlocksRequest),
+            GetBlockCount => get_block_count_call(GetBlockCountRequest),
+            GetBlockDagInfo => get_block_dag_info_call(GetBlockDagInfoRequest),
+            ResolveFinalityConflict => resolve_finality_conflict_call(ResolveFinalityConflictRequest),
+            Shutdown => shutdown_call(ShutdownRequest),
+            GetHeaders => get_headers_call(GetHeadersRequest),
+            GetUtxosByAddresses => get_utxos_by_addresses_call(GetUtxosByAddressesRequest),
+            GetBalanceByAddress => get_balance_by_address_call(GetBalanceByAddressRequest),
+            GetBalancesByAddresses => get_balances_by_addresses_call(GetBalancesByAddressesRequest),
+            GetSinkBlueScore => get_sink_blue_score_call(GetSinkBlueScoreRequest),
+            Ban => ban_call(BanRequest),
+            Unban => unban_call(UnbanRequest),
+            GetInfo => get_info_call(GetInfoRequest),
+            EstimateNetworkHashesPerSecond => estimate_network_hashes_per_second_call(EstimateNetworkHashesPerSecondRequest),
+            GetMempoolEntriesByAddresses => get_mempool_entries_by_addresses_call(GetMempoolEntriesByAddressesRequest),
+            GetCoinSupply => get_coin_supply_call(GetCoinSupplyRequest),
+            GetDaaScoreTimestampEstimate => get_daa_score_timestamp_estimate_call(GetDaaScoreTimestampEstimateRequest),
+            ValidateTransaction => validate_transaction_call(ValidateTransactionRequest),
+            GetUtxosByAddressesPage => get_utxos_by_addresses_page_call(GetUtxosByAddressesPageRequest),
+            GetWrpcServerStatus => get_wrpc_server_status_call(GetWrpcServerStatusRequest),
+        ]);
+
+        ctx.term().writeln(response.crlf());
+
+        Ok(())
+    }
+
     async fn display_help(self: Arc<Self>, ctx: Arc<KaspaCli>, _argv: Vec<String>) -> Result<()> {
         // RpcApiOps that do not contain docs are not displayed
         let help = RpcApiOps::list()
@@ -252,6 +319,8 @@ impl Rpc {
 
         tprintln!(ctx);
         tprintln!(ctx, "Please note that not all listed RPC methods are currently implemented");
+        tprintln!(ctx, "Any method can be invoked with JSON-encoded arguments, for example:");
+        tprintln!(ctx, "  rpc get-block {{\"hash\":\"<hash>\",\"includeTransactions\":false}}");
         tprintln!(ctx);
 
         Ok(())
@@ -1,36 +1,108 @@
 use crate::imports::*;
+use crate::wizards;
 
 #[derive(Default, Handler)]
 #[help("Send a Kaspa transaction to a public address")]
 pub struct Send;
 
 impl Send {
-    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, _cmd: &str) -> Result<()> {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, mut argv: Vec<String>, _cmd: &str) -> Result<()> {
         // address, amount, priority fee
         let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
 
-        let account = ctx.wallet().account()?;
+        let account = ctx.account_from_argv(&mut argv).await?;
 
-        if argv.len() < 2 {
-            tprintln!(ctx, "usage: send <address> <amount> <priority fee>");
-            return Ok(());
+        let dry_run = argv.iter().any(|arg| arg == "--dry-run");
+        argv.retain(|arg| arg != "--dry-run");
+        let force = argv.iter().any(|arg| arg == "--force");
+        argv.retain(|arg| arg != "--force");
+        let fee = take_option(&mut argv, "--fee")?;
+        let change_policy = take_option(&mut argv, "--change-policy")?.map(|policy| policy.parse::<ChangePolicy>()).transpose()?;
+        // coin control: `--from-utxo` can be supplied multiple times
+        let exclusive = argv.iter().any(|arg| arg == "--from-utxo-only");
+        argv.retain(|arg| arg != "--from-utxo-only");
+        let mut utxos = vec![];
+        while let Some(outpoint) = take_option(&mut argv, "--from-utxo")? {
+            utxos.push(try_parse_outpoint(&outpoint)?);
         }
+        if exclusive && utxos.is_empty() {
+            return Err(Error::custom("'--from-utxo-only' requires at least one '--from-utxo <outpoint>'"));
+        }
+        let coin_control = (!utxos.is_empty()).then(|| CoinControl::new(utxos, exclusive));
+
+        let (address, amount_sompi, fees) = if argv.is_empty() && fee.is_none() {
+            // interactive send wizard
+            let payment = wizards::send::prompt(&ctx).await?;
+            if !wizards::send::confirm(&ctx, &account, &payment).await? {
+                return Err(Error::UserAbort);
+            }
+            (payment.address, Some(payment.amount_sompi), payment.fees)
+        } else {
+            if argv.is_empty() {
+                tprintln!(ctx, "{}", tr("usage.send"));
+                return Ok(());
+            }
 
-        let address = Address::try_from(argv.first().unwrap().as_str())?;
-        let amount_sompi = try_parse_required_nonzero_kaspa_as_sompi_u64(argv.get(1))?;
-        let priority_fee_sompi = try_parse_optional_kaspa_as_sompi_i64(argv.get(2))?.unwrap_or(0);
-        let outputs = PaymentOutputs::from((address.clone(), amount_sompi));
+            let network_id = ctx.wallet().network_id()?;
+            // the destination can be an address or a `kaspa:` payment URI
+            let payment_uri = PaymentUri::try_parse(&argv.remove(0), Some(network_id.into()))
+                .map_err(|err| Error::custom(format!("Invalid destination: {err}")))?;
+            let amount_sompi = match payment_uri.amount {
+                // the amount requested by the URI takes the place of the amount argument
+                Some(amount_sompi) => Some(amount_sompi),
+                None if argv.is_empty() => {
+                    tprintln!(ctx, "{}", tr("usage.send"));
+                    return Ok(());
+                }
+                // `all` sends all spendable funds less the transaction fees
+                None if argv[0].eq_ignore_ascii_case("all") => {
+                    argv.remove(0);
+                    None
+                }
+                None => Some(try_parse_amount_as_sompi(&argv.remove(0))?),
+            };
+            if amount_sompi == Some(0) {
+                return Err(Error::custom("Supplied required kaspa amount must not be a zero"));
+            }
+            let request = [payment_uri.label.as_deref(), payment_uri.message.as_deref()].into_iter().flatten().collect::<Vec<_>>();
+            if !request.is_empty() {
+                tprintln!(ctx, "Payment request: {}", request.join(" - "));
+            }
+            let address = payment_uri.address;
+            // the priority fee can be supplied via `--fee` or as a positional argument,
+            // otherwise the default priority fee of the current network is used;
+            // a negative priority fee deducts all fees from the amount
+            let fees = fee.as_deref().or(argv.first().map(String::as_str)).map(Fees::try_from).transpose()?;
+            let fees = ctx.wallet().priority_fee_or_default(fees)?;
+            // scripted sends are never prompted: a suspicious destination
+            // is reported and refused unless `--force` is supplied
+            if let Some(warning) = wizards::send::destination_warning(&account, &address)? {
+                tprintln!(ctx, "{}", style(format!("Warning: {warning}")).yellow());
+                if !force {
+                    return Err(Error::custom(tr("error.send-destination-force")));
+                }
+            }
+            (address, amount_sompi, fees)
+        };
+
+        let destination = match amount_sompi {
+            Some(amount_sompi) => PaymentOutputs::from((address.clone(), amount_sompi)).into(),
+            None => PaymentDestination::SendAll(address.clone()),
+        };
         let abortable = Abortable::default();
         let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
 
         // let ctx_ = ctx.clone();
-        let (summary, _ids) = account
+        let (summary, _ids, validations) = account
             .send(
-                outputs.into(),
-                priority_fee_sompi.into(),
+                destination,
+                fees,
                 None,
+                coin_control,
+                change_policy.unwrap_or_default(),
                 wallet_secret,
                 payment_secret,
+                dry_run,
                 &abortable,
                 Some(Arc::new(move |_ptx| {
                     // tprintln!(ctx_, "Sending transaction: {}", ptx.id());
@@ -38,7 +110,21 @@ impl Send {
             )
             .await?;
 
-        tprintln!(ctx, "Send - {summary}");
+        if let Some(validations) = validations {
+            let network_type = NetworkType::from(ctx.wallet().network_id()?);
+            tprintln!(ctx, "Dry run - {summary}");
+            for validation in validations {
+                let fees = sompi_to_kaspa_string_with_suffix(validation.fees, &network_type);
+                let status = match (validation.validated, validation.rejection) {
+                    (_, Some(rejection)) => style(format!("rejected: {rejection}")).red().to_string(),
+                    (true, None) => style("accepted").green().to_string(),
+                    (false, None) => style("not validated (depends on preceding transactions)").dim().to_string(),
+                };
+                tprintln!(ctx, "  {} - mass: {} fees: {fees} - {status}", validation.id, validation.mass);
+            }
+        } else {
+            tprintln!(ctx, "Send - {summary}");
+        }
         // tprintln!(ctx, "\nSending {} KAS to {address}, tx ids:", sompi_to_kaspa_string(amount_sompi));
         // tprintln!(ctx, "{}\n", ids.into_iter().map(|a| a.to_string()).collect::<Vec<_>>().join("\n"));
 
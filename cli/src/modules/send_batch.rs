@@ -0,0 +1,83 @@
+use crate::imports::*;
+use kaspa_wallet_core::tx::PaymentBatch;
+use std::path::Path;
+use workflow_store::fs;
+
+#[derive(Default, Handler)]
+#[help("Send Kaspa to multiple addresses listed in a CSV file")]
+pub struct SendBatch;
+
+impl SendBatch {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, mut argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
+
+        let account = ctx.account_from_argv(&mut argv).await?;
+        let fee = take_option(&mut argv, "--fee")?;
+
+        if argv.len() != 1 {
+            tprintln!(ctx, "{}", tr("usage.send-batch"));
+            return Ok(());
+        }
+
+        let network_id = ctx.wallet().network_id()?;
+        let network_type = NetworkType::from(network_id);
+        let priority_fee_sompi = fee
+            .as_deref()
+            .map(try_parse_amount_as_sompi)
+            .transpose()?
+            .unwrap_or_else(|| ctx.wallet().default_priority_fee(&network_id));
+
+        let csv = fs::read_to_string(Path::new(&argv[0])).await?;
+        let batch = PaymentBatch::try_new(parse_csv(&csv, &network_id)?, &network_id)?;
+        let partitions = batch.partitions();
+
+        let kaspa = |sompi: u64| sompi_to_kaspa_string_with_suffix(sompi, &network_type);
+        tprintln!(ctx);
+        tprintln!(ctx, "Recipients:   {}", batch.len());
+        tprintln!(ctx, "Amount:       {}", kaspa(batch.amount()));
+        tprintln!(ctx, "Priority fee: {} (per transaction)", kaspa(priority_fee_sompi));
+        tprintln!(ctx, "Transactions: {}", partitions.len());
+        tprintln!(ctx);
+
+        let text = ctx.term().ask(false, &tr("prompt.send-batch-confirm")).await?;
+        if !matches!(text.trim().to_lowercase().as_str(), "y" | "yes") {
+            return Err(Error::UserAbort);
+        }
+
+        let abortable = Abortable::default();
+        let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
+
+        // per-transaction progress is reported via `Events::PaymentBatchProgress`
+        let (summaries, ids) =
+            account.send_batch(batch, Fees::SenderPays(priority_fee_sompi), wallet_secret, payment_secret, &abortable, None).await?;
+
+        let fees = summaries.iter().map(|summary| summary.aggregated_fees()).sum::<u64>();
+        tprintln!(ctx, "Send batch - {} transactions, fees: {}", ids.len(), kaspa(fees));
+
+        Ok(())
+    }
+}
+
+/// Parse `<address>,<amount>` CSV records (amounts in KAS or sompi, e.g. `1.5` or `150000000 sompi`).
+/// Empty lines, `#` comments and a leading `address,amount` header are ignored.
+fn parse_csv(csv: &str, network_id: &NetworkId) -> Result<Vec<PaymentOutput>> {
+    let mut outputs = vec![];
+    for (index, line) in csv.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (address, amount) =
+            line.split_once(',').ok_or_else(|| Error::custom(format!("line {}: expected '<address>,<amount>'", index + 1)))?;
+        if outputs.is_empty() && address.trim().eq_ignore_ascii_case("address") {
+            continue;
+        }
+
+        let address = try_parse_address(address, network_id).map_err(|err| Error::custom(format!("line {}: {err}", index + 1)))?;
+        let amount = try_parse_amount_as_sompi(amount).map_err(|err| Error::custom(format!("line {}: {err}", index + 1)))?;
+        outputs.push(PaymentOutput::new(address, amount));
+    }
+
+    Ok(outputs)
+}
@@ -13,7 +13,15 @@ impl Settings {
         let list = list
             .iter()
             .map(|setting| {
-                let value: String = ctx.wallet().settings().get(setting.clone()).unwrap_or_else(|| "-".to_string());
+                let value = match ctx.wallet().settings().get::<serde_json::Value>(setting.clone()) {
+                    // webhooks carry signing secrets
+                    Some(serde_json::Value::Array(webhooks)) if *setting == WalletSettings::Webhooks => {
+                        format!("{} webhook(s)", webhooks.len())
+                    }
+                    Some(serde_json::Value::String(value)) => value,
+                    Some(value) => value.to_string(),
+                    None => "-".to_string(),
+                };
                 let descr = setting.descr();
                 (setting.as_str().to_lowercase(), value, descr)
             })
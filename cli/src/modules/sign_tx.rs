@@ -0,0 +1,115 @@
+use crate::imports::*;
+use kaspa_addresses::Prefix;
+use kaspa_consensus_core::tx::SignableTransaction;
+use kaspa_txscript::extract_script_pub_key_address;
+use kaspa_wallet_core::tx::{decode_signable_transaction, encode_signable_transaction, verify_signable_transaction};
+use std::path::Path;
+use workflow_store::fs;
+
+#[derive(Default, Handler)]
+#[help("Sign a transaction supplied as SDK JSON or hex (air-gapped signing)")]
+pub struct SignTx;
+
+impl SignTx {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, mut argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
+
+        let verify = argv.iter().any(|arg| arg == "--verify");
+        argv.retain(|arg| arg != "--verify");
+
+        if verify {
+            if argv.len() != 1 {
+                tprintln!(ctx, "{}", tr("usage.sign-tx"));
+                return Ok(());
+            }
+
+            let data = fs::read_to_string(Path::new(&argv[0])).await?;
+            let (transaction, encoding) = decode_signable_transaction(&data)?;
+            let status = verify_signable_transaction(&transaction)?;
+            tprintln!(ctx, "Transaction {} ({encoding})", transaction.id());
+            tprintln!(ctx, "Signatures are valid, {} of {} inputs signed", status.signed_inputs, status.inputs);
+            return Ok(());
+        }
+
+        let account = ctx.account_from_argv(&mut argv).await?;
+        if argv.is_empty() || argv.len() > 2 {
+            tprintln!(ctx, "{}", tr("usage.sign-tx"));
+            return Ok(());
+        }
+
+        let filename = argv.remove(0);
+        let output = argv.first().cloned().unwrap_or_else(|| format!("{filename}.signed"));
+        let data = fs::read_to_string(Path::new(&filename)).await?;
+        let (transaction, encoding) = decode_signable_transaction(&data)?;
+
+        if !confirm(&ctx, &transaction).await? {
+            return Err(Error::UserAbort);
+        }
+
+        let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
+        let (transaction, status) = account.sign_transaction(transaction, wallet_secret, payment_secret).await?;
+        fs::write_string(Path::new(&output), &encode_signable_transaction(&transaction, encoding)?).await?;
+
+        tprintln!(ctx, "Transaction {} ({encoding})", transaction.id());
+        if status.is_fully_signed() {
+            tprintln!(ctx, "Fully signed ({} inputs), saved to '{output}'", status.inputs);
+        } else {
+            tprintln!(
+                ctx,
+                "Partially signed ({} of {} inputs), saved to '{output}'; the remaining inputs must be signed by other signers",
+                status.signed_inputs,
+                status.inputs
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Displays the outputs of the `transaction` (destination address and amount, marking
+/// the outputs paying to the wallet's own addresses) and the transaction fee, and asks
+/// the user to confirm signing the transaction.
+async fn confirm(ctx: &Arc<KaspaCli>, transaction: &SignableTransaction) -> Result<bool> {
+    let network_id = ctx.wallet().network_id()?;
+    let network_type = NetworkType::from(network_id);
+    let prefix = Prefix::from(network_id);
+    let kaspa = |sompi: u64| sompi_to_kaspa_string_with_suffix(sompi, &network_type);
+    let accounts = ctx.wallet().active_accounts().collect();
+
+    tprintln!(ctx);
+    tprintln!(
+        ctx,
+        "Transaction {} ({} inputs, {} outputs)",
+        transaction.id(),
+        transaction.tx.inputs.len(),
+        transaction.tx.outputs.len()
+    );
+    for (index, output) in transaction.tx.outputs.iter().enumerate() {
+        let amount = kaspa(output.value);
+        match extract_script_pub_key_address(&output.script_public_key, prefix) {
+            Ok(address) => {
+                let account = accounts.iter().find(|account| account.utxo_context().addresses().contains(&address));
+                match account {
+                    Some(account) => {
+                        let own = style(format!("(own address, account '{}')", account.name_or_id())).dim();
+                        tprintln!(ctx, "  #{index}: {address} - {amount} {own}");
+                    }
+                    None => tprintln!(ctx, "  #{index}: {address} - {amount}"),
+                }
+            }
+            Err(_) => tprintln!(ctx, "  #{index}: {} - {amount}", style("non-standard script").yellow()),
+        }
+    }
+
+    let outputs = transaction.tx.outputs.iter().map(|output| output.value).sum::<u64>();
+    let inputs = transaction.entries.iter().map(|entry| entry.as_ref().map(|entry| entry.amount)).sum::<Option<u64>>();
+    match inputs.and_then(|inputs| inputs.checked_sub(outputs)) {
+        Some(fees) => tprintln!(ctx, "Fees: {}", kaspa(fees)),
+        None if inputs.is_some() => tprintln!(ctx, "Fees: {}", style("invalid (outputs exceed inputs)").red()),
+        None => tprintln!(ctx, "Fees: {}", style("unknown (missing input UTXO entries)").yellow()),
+    }
+    tprintln!(ctx);
+
+    let text = ctx.term().ask(false, &tr("prompt.sign-tx-confirm")).await?;
+    Ok(matches!(text.trim().to_lowercase().as_str(), "y" | "yes"))
+}
@@ -1,24 +1,67 @@
 use crate::imports::*;
+use std::sync::atomic::AtomicUsize;
 
 #[derive(Default, Handler)]
 #[help("Reduces account UTXO size by re-sending all funds to the account's default address")]
 pub struct Sweep;
 
 impl Sweep {
-    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, _argv: Vec<String>, _cmd: &str) -> Result<()> {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, _cmd: &str) -> Result<()> {
         let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
 
         let account = ctx.wallet().account()?;
+
+        match argv.first().map(String::as_str) {
+            None => {}
+            Some("status") => {
+                match account.checkpoint() {
+                    Some(checkpoint) => tprintln!(
+                        ctx,
+                        "Interrupted {}: {} transaction(s) submitted, {} UTXO(s) spent",
+                        checkpoint.kind,
+                        checkpoint.transaction_count(),
+                        checkpoint.spent_utxos().len()
+                    ),
+                    None => tprintln!(ctx, "No interrupted operation"),
+                }
+                return Ok(());
+            }
+            Some("reset") => {
+                account.clear_checkpoint().await?;
+                tprintln!(ctx, "Operation checkpoint cleared");
+                return Ok(());
+            }
+            Some(_) => return self.display_help(ctx).await,
+        }
+
+        if let Some(checkpoint) = account.checkpoint() {
+            tprintln!(
+                ctx,
+                "Resuming interrupted {} ({} transaction(s) previously submitted)",
+                checkpoint.kind,
+                checkpoint.transaction_count()
+            );
+        }
+
         let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
         let abortable = Abortable::default();
-        // let ctx_ = ctx.clone();
+        let ctx_ = ctx.clone();
+        let submitted = Arc::new(AtomicUsize::new(0));
         let (summary, _ids) = account
             .sweep(
                 wallet_secret,
                 payment_secret,
                 &abortable,
-                Some(Arc::new(move |_ptx| {
-                    // tprintln!(ctx_, "Sending transaction: {}", ptx.id());
+                Some(Arc::new(move |ptx| {
+                    let index = submitted.fetch_add(1, Ordering::SeqCst) + 1;
+                    tprintln!(
+                        ctx_,
+                        "[{index}] {} transaction {} - inputs: {} fees: {}",
+                        if ptx.is_final() { "final" } else { "batch" },
+                        ptx.id(),
+                        ptx.utxo_entries().len(),
+                        sompi_to_kaspa_string(ptx.fees())
+                    );
                 })),
             )
             .await?;
@@ -27,4 +70,17 @@ impl Sweep {
 
         Ok(())
     }
+
+    async fn display_help(self: Arc<Self>, ctx: Arc<KaspaCli>) -> Result<()> {
+        ctx.term().help(
+            &[
+                ("sweep", "Sweep account UTXOs (resumes an interrupted sweep)"),
+                ("status", "Display the progress of an interrupted operation"),
+                ("reset", "Discard the progress of an interrupted operation"),
+            ],
+            None,
+        )?;
+
+        Ok(())
+    }
 }
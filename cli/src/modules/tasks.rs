@@ -0,0 +1,55 @@
+use crate::imports::*;
+
+#[derive(Default, Handler)]
+#[help("List or abort long-running wallet tasks")]
+pub struct Tasks;
+
+impl Tasks {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, mut argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
+
+        let action = if argv.is_empty() { "list".to_string() } else { argv.remove(0) };
+
+        match action.as_str() {
+            "list" => {
+                let tasks = ctx.wallet().watchdog().tasks();
+                if tasks.is_empty() {
+                    tprintln!(ctx, "no running tasks");
+                }
+                for task in tasks {
+                    let deadline = task.deadline.map(|deadline| format!("{} sec", deadline / 1000)).unwrap_or("none".to_string());
+                    let account = task.account_id.map(|id| id.short()).unwrap_or("-".to_string());
+                    tprintln!(
+                        ctx,
+                        "{:>4}: {:<10} account: {account} elapsed: {} sec deadline: {deadline}",
+                        task.id,
+                        task.kind.to_string(),
+                        task.elapsed / 1000
+                    );
+                }
+            }
+            "abort" => {
+                if argv.is_empty() {
+                    tprintln!(ctx, "usage: tasks abort <task id | all>");
+                } else if argv[0] == "all" {
+                    ctx.wallet().watchdog().abort_all();
+                } else {
+                    let id = argv[0].parse::<u64>().map_err(|_| Error::custom(format!("invalid task id: '{}'", argv[0])))?;
+                    ctx.wallet().watchdog().abort(id)?;
+                }
+            }
+            v => {
+                tprintln!(ctx, "unknown command: '{v}'");
+                self.display_help(ctx, argv).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn display_help(self: Arc<Self>, ctx: Arc<KaspaCli>, _argv: Vec<String>) -> Result<()> {
+        ctx.term().help(&[("list", "List running wallet tasks"), ("abort <task id | all>", "Abort a running wallet task")], None)?;
+
+        Ok(())
+    }
+}
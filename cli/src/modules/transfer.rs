@@ -11,17 +11,18 @@ impl Transfer {
         let account = ctx.wallet().account()?;
 
         if argv.len() < 2 {
-            tprintln!(ctx, "usage: transfer <account> <amount> <priority fee>");
+            tprintln!(ctx, "{}", tr("usage.transfer"));
             return Ok(());
         }
 
         let target_account = argv.first().unwrap();
         let target_account = ctx.find_accounts_by_name_or_id(target_account).await?;
         if target_account.id() == account.id() {
-            return Err("Cannot transfer to the same account".into());
+            return Err(Error::custom(tr("error.transfer-to-same-account")));
         }
         let amount_sompi = try_parse_required_nonzero_kaspa_as_sompi_u64(argv.get(1))?;
-        let priority_fee_sompi = try_parse_optional_kaspa_as_sompi_i64(argv.get(2))?.unwrap_or(0);
+        let priority_fee =
+            ctx.wallet().priority_fee_or_default(try_parse_optional_kaspa_as_sompi_i64(argv.get(2))?.map(Fees::from))?;
         let target_address = target_account.receive_address()?;
         let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
 
@@ -29,13 +30,16 @@ impl Transfer {
         let outputs = PaymentOutputs::from((target_address.clone(), amount_sompi));
 
         // let ctx_ = ctx.clone();
-        let (summary, _ids) = account
+        let (summary, _ids, _) = account
             .send(
                 outputs.into(),
-                priority_fee_sompi.into(),
+                priority_fee,
                 None,
+                None,
+                ChangePolicy::default(),
                 wallet_secret,
                 payment_secret,
+                false,
                 &abortable,
                 Some(Arc::new(move |_ptx| {
                     // tprintln!(ctx_, "Sending transaction: {}", ptx.id());
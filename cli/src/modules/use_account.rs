@@ -0,0 +1,39 @@
+use crate::imports::*;
+
+// `use` is a reserved keyword and can not be used as the module
+// name, so the handler is declared manually to expose the verb
+#[derive(Default)]
+pub struct UseAccount;
+
+#[async_trait]
+impl Handler for UseAccount {
+    fn verb(&self, _ctx: &Arc<dyn Context>) -> Option<&'static str> {
+        Some("use")
+    }
+
+    fn help(&self, _ctx: &Arc<dyn Context>) -> &'static str {
+        "Select an account and store it as the default account"
+    }
+
+    async fn handle(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, cmd: &str) -> cli::Result<()> {
+        self.main(ctx, argv, cmd).await.map_err(|e| e.to_string().into())
+    }
+}
+
+impl UseAccount {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
+
+        let account = if let Some(pat) = argv.first() {
+            ctx.find_accounts_by_name_or_id(pat.trim()).await?
+        } else {
+            ctx.prompt_account().await?
+        };
+
+        ctx.wallet().select(Some(&account)).await?;
+        ctx.wallet().settings().set(WalletSettings::Account, account.id().to_hex()).await?;
+        tprintln!(ctx, "default account: {}", style(account.name_with_id()).blue());
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use crate::imports::*;
 use crate::wizards;
+use kaspa_wallet_core::api::WalletApi;
 
 #[derive(Default, Handler)]
 #[help("Wallet management operations")]
@@ -29,6 +30,9 @@ impl Wallet {
                         } else {
                             tprintln!(ctx, "  {}", wallet.filename);
                         }
+                        if let Some(notes) = wallet.notes {
+                            tprintln!(ctx, "    {}", style(notes).dim());
+                        }
                     }
                     tprintln!(ctx, "");
                 }
@@ -73,6 +77,166 @@ impl Wallet {
             "close" => {
                 ctx.wallet().close().await?;
             }
+            "verify" => {
+                let repair = match argv.first().map(String::as_str) {
+                    None => false,
+                    Some("repair") => true,
+                    Some(v) => {
+                        tprintln!(ctx, "unknown option: '{v}'");
+                        tprintln!(ctx, "usage: 'wallet verify [repair]'");
+                        return Ok(());
+                    }
+                };
+
+                let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+                let report = ctx.wallet().check_storage(&wallet_secret, repair).await?;
+
+                tprintln!(ctx, "");
+                tprintln!(
+                    ctx,
+                    "Checked {} private key data, {} accounts, {} account metadata and {} transaction records",
+                    report.prv_key_data,
+                    report.accounts,
+                    report.metadata,
+                    report.transactions
+                );
+                if report.is_ok() {
+                    tprintln!(ctx, "No issues found");
+                } else {
+                    tprintln!(ctx, "");
+                    for issue in report.issues.iter() {
+                        tprintln!(ctx, "  {issue}");
+                    }
+                    tprintln!(ctx, "");
+                    if !repair {
+                        tprintln!(ctx, "Use 'wallet verify repair' to remove orphaned entries and invalid transaction records");
+                    }
+                }
+                tprintln!(ctx, "");
+            }
+            "migrate" => {
+                let name = if let Some(name) = argv.first().cloned() {
+                    Some(name)
+                } else {
+                    ctx.wallet().settings().get(WalletSettings::Wallet).clone()
+                };
+
+                let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+                let report = ctx.wallet().migrate_storage(&wallet_secret, name.as_deref()).await?;
+
+                if report.is_migrated() {
+                    tprintln!(ctx, "Wallet migrated from storage version {} to {}", report.from_version, report.to_version);
+                    for migration in report.applied.iter() {
+                        tprintln!(ctx, "  {migration}");
+                    }
+                    if let Some(backup) = report.backup {
+                        tprintln!(ctx, "Previous wallet file saved as '{backup}'");
+                    }
+                } else {
+                    tprintln!(ctx, "Wallet storage is up to date (version {})", report.to_version);
+                }
+            }
+            "rename" => {
+                let (title, filename) = match argv.first().map(String::as_str) {
+                    None => {
+                        tprintln!(ctx, "usage:\n'wallet rename <title>' or 'wallet rename file <filename>'");
+                        return Ok(());
+                    }
+                    Some("file") => {
+                        let Some(filename) = argv.get(1).cloned() else {
+                            tprintln!(ctx, "usage: 'wallet rename file <filename>'");
+                            return Ok(());
+                        };
+                        (None, Some(filename))
+                    }
+                    Some(_) => {
+                        let re = regex::Regex::new(r"wallet\s+rename\s+").unwrap();
+                        (Some(re.replace(cmd, "").trim().to_string()), None)
+                    }
+                };
+
+                let previous = ctx.wallet().descriptor().ok_or(Error::WalletIsNotOpen)?;
+                let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+                let descriptor =
+                    ctx.wallet().clone().wallet_rename(title.as_deref(), None, filename.as_deref(), wallet_secret).await?;
+
+                if filename.is_some() {
+                    // keep opening the renamed wallet by default
+                    if ctx.wallet().settings().get(WalletSettings::Wallet) == Some(previous.filename.clone()) {
+                        ctx.wallet().settings().set(WalletSettings::Wallet, descriptor.filename.clone()).await?;
+                    }
+                    tprintln!(ctx, "Wallet file renamed from '{}' to '{}'", previous.filename, descriptor.filename);
+                } else {
+                    tprintln!(ctx, "Wallet title changed to '{}'", descriptor.title.unwrap_or_default());
+                }
+            }
+            "notes" => {
+                if argv.is_empty() {
+                    let descriptor = ctx.wallet().descriptor().ok_or(Error::WalletIsNotOpen)?;
+                    match descriptor.notes {
+                        Some(notes) => tprintln!(ctx, "{notes}"),
+                        None => tprintln!(ctx, "usage:\n'wallet notes <text>' or 'wallet notes remove' to remove the notes"),
+                    }
+                    return Ok(());
+                }
+
+                let re = regex::Regex::new(r"wallet\s+notes\s+").unwrap();
+                let notes = re.replace(cmd, "");
+                let notes = if notes.trim() == "remove" { "" } else { notes.trim() };
+                let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+                ctx.wallet().clone().wallet_rename(None, Some(notes), None, wallet_secret).await?;
+                tprintln!(ctx, "{}", if notes.is_empty() { "Wallet notes removed" } else { "Wallet notes updated" });
+            }
+            "payment-secret" => {
+                let prv_key_data_info = ctx.select_private_key().await?;
+                let wallet_secret = Secret::new(ctx.term().ask(true, &tr("prompt.wallet-password")).await?.trim().as_bytes().to_vec());
+                if wallet_secret.as_ref().is_empty() {
+                    return Err(Error::WalletSecretRequired);
+                }
+                let old_payment_secret = if prv_key_data_info.is_encrypted() {
+                    Some(Secret::new(ctx.term().ask(true, &tr("prompt.payment-password")).await?.trim().as_bytes().to_vec()))
+                } else {
+                    None
+                };
+
+                let new_payment_secret = ctx.term().ask(true, "Enter new payment password (empty to remove): ").await?;
+                let new_payment_secret = new_payment_secret.trim();
+                let confirmation = ctx.term().ask(true, "Re-enter new payment password: ").await?;
+                if confirmation.trim() != new_payment_secret {
+                    return Err(Error::PaymentSecretMatch);
+                }
+                let new_payment_secret = (!new_payment_secret.is_empty()).then(|| Secret::from(new_payment_secret));
+
+                let report = ctx
+                    .wallet()
+                    .clone()
+                    .prv_key_data_change_secret(wallet_secret, Some(prv_key_data_info.id), old_payment_secret, new_payment_secret)
+                    .await?;
+                tprintln!(ctx, "Payment password of the private key {} changed", prv_key_data_info.name_or_id());
+                if let Some(backup) = report.backup {
+                    tprintln!(ctx, "Previous wallet data saved to '{backup}'");
+                }
+            }
+            "strength" => {
+                if let Some(min_score) = argv.first() {
+                    let min_score =
+                        match min_score.trim() {
+                            "off" => 0,
+                            score => score.parse::<u8>().ok().filter(|score| *score <= MAXIMUM_SECRET_STRENGTH).ok_or_else(|| {
+                                Error::custom(format!("invalid secret strength: '{score}' (expecting 0-4 or 'off')"))
+                            })?,
+                        };
+                    ctx.wallet().set_secret_strength_policy(SecretStrengthPolicy::new(min_score));
+                    ctx.wallet().settings().set(WalletSettings::SecretStrength, min_score).await?;
+                }
+
+                let policy = ctx.wallet().secret_strength_policy();
+                if policy.is_enabled() {
+                    tprintln!(ctx, "Minimum wallet secret strength: {} of {MAXIMUM_SECRET_STRENGTH}", policy.min_score);
+                } else {
+                    tprintln!(ctx, "Wallet secret strength policy is disabled");
+                }
+            }
             "hint" => {
                 if !argv.is_empty() {
                     let re = regex::Regex::new(r"wallet\s+hint\s+").unwrap();
@@ -114,6 +278,18 @@ impl Wallet {
                 ("open [<name>]", "Open an existing wallet (shorthand: 'open [<name>]')"),
                 ("close", "Close an opened wallet (shorthand: 'close')"),
                 ("hint", "Change the wallet phishing hint"),
+                (
+                    "payment-secret",
+                    "Change the payment password (not available for bip39 mnemonic keys, where it is the bip39 passphrase)",
+                ),
+                (
+                    "strength [<0-4> | off]",
+                    "Display or change the minimum wallet secret strength required for new wallets and secrets",
+                ),
+                ("rename <title> | file <filename>", "Change the wallet title or rename the wallet file"),
+                ("notes [<text> | remove]", "Display or change the wallet notes"),
+                ("verify [repair]", "Check the wallet data integrity, optionally removing orphaned entries"),
+                ("migrate [<name>]", "Upgrade the wallet file to the current storage version (keeps a backup of the previous file)"),
             ],
             None,
         )?;
@@ -1,6 +1,10 @@
 use crate::error::Error;
 use crate::result::Result;
+use kaspa_addresses::{Address, Prefix};
 use kaspa_consensus_core::constants::SOMPI_PER_KASPA;
+use kaspa_consensus_core::network::NetworkId;
+use kaspa_wallet_core::utils::Amount;
+use kaspa_wallet_core::utxo::UtxoEntryId;
 use std::fmt::Display;
 
 pub fn try_parse_required_nonzero_kaspa_as_sompi_u64<S: ToString + Display>(kaspa_amount: Option<S>) -> Result<u64> {
@@ -58,3 +62,49 @@ pub fn try_parse_optional_kaspa_as_sompi_i64<S: ToString + Display>(kaspa_amount
         Ok(None)
     }
 }
+
+/// Parses an amount expressed in KAS (the default) or SOMPI units
+/// (e.g. `1.5`, `1.5 KAS`, `1.5tkas` or `150000000 sompi`) as SOMPI.
+/// KAS amounts are parsed exactly, accepting up to 8 decimal places
+/// (see [`Amount`]).
+pub fn try_parse_amount_as_sompi(amount: &str) -> Result<u64> {
+    Ok(amount.parse::<Amount>()?.sompi())
+}
+
+/// Parses an address, verifying its checksum and that
+/// it belongs to the network identified by `network_id`.
+pub fn try_parse_address(address: &str, network_id: &NetworkId) -> Result<Address> {
+    let address = Address::try_from(address.trim())?;
+    if address.prefix != Prefix::from(*network_id) {
+        return Err(Error::AddressNetworkMismatch(address.to_string(), *network_id));
+    }
+    Ok(address)
+}
+
+/// Parses a UTXO outpoint supplied as `<transaction id>:<index>`
+/// (or `<transaction id>-<index>`).
+pub fn try_parse_outpoint(outpoint: &str) -> Result<UtxoEntryId> {
+    let invalid = || Error::custom(format!("invalid outpoint '{outpoint}' (expecting <transaction id>:<index>)"));
+    let (transaction_id, index) = outpoint.trim().rsplit_once([':', '-']).ok_or_else(invalid)?;
+    let transaction_id = transaction_id.parse().map_err(|_| invalid())?;
+    let index = index.parse().map_err(|_| invalid())?;
+    Ok(UtxoEntryId::new(transaction_id, index))
+}
+
+/// Removes `--<name> <value>` (or `--<name>=<value>`) from the
+/// argument list, returning the supplied value.
+pub fn take_option(argv: &mut Vec<String>, name: &str) -> Result<Option<String>> {
+    let prefix = format!("{name}=");
+    if let Some(index) = argv.iter().position(|arg| arg == name) {
+        if index + 1 >= argv.len() {
+            return Err(Error::custom(format!("missing value for '{name}'")));
+        }
+        let value = argv.remove(index + 1);
+        argv.remove(index);
+        Ok(Some(value))
+    } else if let Some(index) = argv.iter().position(|arg| arg.starts_with(&prefix)) {
+        Ok(Some(argv.remove(index)[prefix.len()..].to_string()))
+    } else {
+        Ok(None)
+    }
+}
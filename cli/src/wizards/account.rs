@@ -2,7 +2,7 @@ use crate::cli::KaspaCli;
 use crate::imports::*;
 use crate::result::Result;
 use kaspa_bip32::{Language, Mnemonic, WordCount};
-use kaspa_wallet_core::account::MULTISIG_ACCOUNT_KIND;
+use kaspa_wallet_core::account::{BIP32_ACCOUNT_KIND, MULTISIG_ACCOUNT_KIND};
 // use kaspa_wallet_core::runtime::wallet::AccountCreateArgsBip32;
 // use kaspa_wallet_core::runtime::{PrvKeyDataArgs, PrvKeyDataCreateArgs};
 // use kaspa_wallet_core::storage::AccountKind;
@@ -12,6 +12,7 @@ pub(crate) async fn create(
     prv_key_data_info: Arc<PrvKeyDataInfo>,
     account_kind: AccountKind,
     name: Option<&str>,
+    derivation_path: Option<DerivationPathTemplate>,
 ) -> Result<()> {
     let term = ctx.term();
     let wallet = ctx.wallet();
@@ -25,17 +26,21 @@ pub(crate) async fn create(
         Some(term.ask(false, "Please enter account name (optional, press <enter> to skip): ").await?.trim().to_string())
     };
 
+    if derivation_path.is_some() && account_kind != BIP32_ACCOUNT_KIND {
+        return Err(Error::custom("custom derivation paths are supported only by bip32 accounts"));
+    }
+
     if account_kind == MULTISIG_ACCOUNT_KIND {
         return create_multisig(ctx, name, word_count).await;
     }
 
-    let wallet_secret = Secret::new(term.ask(true, "Enter wallet password: ").await?.trim().as_bytes().to_vec());
+    let wallet_secret = Secret::new(term.ask(true, &tr("prompt.wallet-password")).await?.trim().as_bytes().to_vec());
     if wallet_secret.as_ref().is_empty() {
         return Err(Error::WalletSecretRequired);
     }
 
     let payment_secret = if prv_key_data_info.is_encrypted() {
-        let payment_secret = Secret::new(term.ask(true, "Enter payment password: ").await?.trim().as_bytes().to_vec());
+        let payment_secret = Secret::new(term.ask(true, &tr("prompt.payment-password")).await?.trim().as_bytes().to_vec());
         if payment_secret.as_ref().is_empty() {
             return Err(Error::PaymentSecretRequired);
         } else {
@@ -45,7 +50,10 @@ pub(crate) async fn create(
         None
     };
 
-    let account_create_args_bip32 = AccountCreateArgsBip32::new(name, None);
+    let mut account_create_args_bip32 = AccountCreateArgsBip32::new(name, None);
+    if let Some(derivation_path) = derivation_path {
+        account_create_args_bip32 = account_create_args_bip32.with_derivation_path(derivation_path);
+    }
     let account =
         wallet.create_account_bip32(&wallet_secret, prv_key_data_info.id, payment_secret.as_ref(), account_create_args_bip32).await?;
 
@@ -1,3 +1,4 @@
 pub mod account;
 pub mod import;
+pub mod send;
 pub mod wallet;
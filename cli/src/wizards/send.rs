@@ -0,0 +1,157 @@
+use crate::error::Error;
+use crate::imports::*;
+use crate::result::Result;
+use kaspa_wallet_core::tx::{Generator, GeneratorSettings};
+
+/// Account addresses further than this past the current address index
+/// are not expected to be handed out and are reported as suspicious.
+const DESTINATION_INDEX_GAP: u32 = 20;
+
+/// Payment parameters collected by the send wizard.
+pub(crate) struct Payment {
+    pub address: Address,
+    pub amount_sompi: u64,
+    pub fees: Fees,
+}
+
+/// Interactively prompts for the payment destination, amount and
+/// priority fee. Invalid input is reported and the prompt repeated;
+/// an empty input aborts the wizard.
+pub(crate) async fn prompt(ctx: &Arc<KaspaCli>) -> Result<Payment> {
+    let term = ctx.term();
+    let network_id = ctx.wallet().network_id()?;
+
+    // the destination can be an address or a `kaspa:` payment URI
+    let payment_uri = loop {
+        let text = term.ask(false, &tr("prompt.send-address")).await?;
+        if text.trim().is_empty() {
+            return Err(Error::UserAbort);
+        }
+        match PaymentUri::try_parse(&text, Some(network_id.into())) {
+            Ok(payment_uri) if payment_uri.amount == Some(0) => tprintln!(ctx, "Amount must not be zero"),
+            Ok(payment_uri) => break payment_uri,
+            Err(err) => tprintln!(ctx, "{err}"),
+        }
+    };
+    let address = payment_uri.address;
+
+    let amount_sompi = if let Some(amount_sompi) = payment_uri.amount {
+        tprintln!(ctx, "Requested amount: {}", sompi_to_kaspa_string_with_suffix(amount_sompi, &NetworkType::from(network_id)));
+        amount_sompi
+    } else {
+        loop {
+            let text = term.ask(false, &tr("prompt.send-amount")).await?;
+            if text.trim().is_empty() {
+                return Err(Error::UserAbort);
+            }
+            match try_parse_amount_as_sompi(&text) {
+                Ok(0) => tprintln!(ctx, "Amount must not be zero"),
+                Ok(amount_sompi) => break amount_sompi,
+                Err(err) => tprintln!(ctx, "{err}"),
+            }
+        }
+    };
+
+    let default_priority_fee_sompi = ctx.wallet().default_priority_fee(&network_id);
+    let default_priority_fee = sompi_to_kaspa_string_with_suffix(default_priority_fee_sompi, &NetworkType::from(network_id));
+    let fees = loop {
+        let text = term.ask(false, &t!("prompt.send-priority-fee", default = default_priority_fee)).await?;
+        if text.trim().is_empty() {
+            break Fees::SenderPays(default_priority_fee_sompi);
+        }
+        // a negative priority fee deducts all fees from the amount
+        match Fees::try_from(text.as_str()) {
+            Ok(fees) => break fees,
+            Err(err) => tprintln!(ctx, "{err}"),
+        }
+    };
+
+    Ok(Payment { address, amount_sompi, fees })
+}
+
+/// Generates the transactions for the payment without submitting them,
+/// displays the resulting amount, fees (including their fiat value), mass and
+/// fee rate and asks the user to confirm the payment.
+pub(crate) async fn confirm(ctx: &Arc<KaspaCli>, account: &Arc<dyn Account>, payment: &Payment) -> Result<bool> {
+    let network_type = NetworkType::from(ctx.wallet().network_id()?);
+    let abortable = Abortable::default();
+
+    let destination = PaymentDestination::from(PaymentOutputs::from((payment.address.clone(), payment.amount_sompi)));
+    let settings = GeneratorSettings::try_new_with_account(account.clone(), destination, payment.fees.clone(), None)?;
+    let generator = Generator::try_new(settings, None, Some(&abortable))?;
+
+    let mut stream = generator.stream();
+    while stream.try_next().await?.is_some() {}
+    let summary = generator.summary();
+    let mass = summary.aggregated_mass();
+
+    // amounts are followed by their fiat value when market data is available
+    let kaspa = |sompi: u64| {
+        let kaspa = sompi_to_kaspa_string_with_suffix(sompi, &network_type);
+        match ctx.fiat_string(sompi) {
+            Some(fiat) => format!("{kaspa}   {}", style(fiat).dim()),
+            None => kaspa,
+        }
+    };
+    let priority_fee = match &payment.fees {
+        Fees::SenderPaysRate(rate) | Fees::ReceiverPaysRate(rate) => format!("{rate} sompi/g"),
+        fees => sompi_to_kaspa_string_with_suffix(fees.priority_fee(0)?, &network_type),
+    };
+    // fees paid by the receiver are deducted from the amount
+    let (priority_fee, total) = if payment.fees.receiver_pays() {
+        (format!("{priority_fee} (deducted from the amount)"), payment.amount_sompi)
+    } else {
+        (priority_fee, payment.amount_sompi.saturating_add(summary.aggregated_fees()))
+    };
+    tprintln!(ctx);
+    tprintln!(ctx, "Destination:  {}", payment.address);
+    if let Some(warning) = destination_warning(account, &payment.address)? {
+        tprintln!(ctx, "Warning:      {}", style(warning).yellow());
+    }
+    tprintln!(ctx, "Amount:       {}", kaspa(payment.amount_sompi));
+    tprintln!(ctx, "Priority fee: {priority_fee}");
+    tprintln!(ctx, "Fees:         {}", kaspa(summary.aggregated_fees()));
+    tprintln!(ctx, "Total:        {}", kaspa(total));
+    if summary.number_of_generated_transactions() > 1 {
+        tprintln!(ctx, "Mass:         {mass} ({} transactions)", summary.number_of_generated_transactions());
+    } else {
+        tprintln!(ctx, "Mass:         {mass}");
+    }
+    tprintln!(ctx, "Fee rate:     {:.2} sompi/g", summary.fee_rate());
+    tprintln!(ctx);
+
+    let text = ctx.term().ask(false, &tr("prompt.send-confirm")).await?;
+    Ok(matches!(text.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Checks whether the payment destination is one of the account's own
+/// change addresses or an account address derived far beyond the current
+/// address indexes (typically a stale clipboard entry or a copy/paste
+/// error). Only addresses the account has already derived (handed out or
+/// covered by address discovery) are checked. Returns a warning describing
+/// the problem, if any.
+pub(crate) fn destination_warning(account: &Arc<dyn Account>, address: &Address) -> Result<Option<String>> {
+    let Ok(account) = account.clone().as_derivation_capable() else {
+        return Ok(None);
+    };
+    let derivation = account.derivation();
+
+    let change_address_manager = derivation.change_address_manager();
+    let current = change_address_manager.index();
+    let index = change_address_manager.inner().address_to_index_map.get(address).copied();
+    if let Some(index) = index {
+        if index > current.saturating_add(DESTINATION_INDEX_GAP) {
+            return Ok(Some(t!("warning.send-to-distant-address", kind = "change", index = index, current = current)));
+        }
+        return Ok(Some(t!("warning.send-to-change-address", index = index)));
+    }
+
+    let receive_address_manager = derivation.receive_address_manager();
+    let current = receive_address_manager.index();
+    let index = receive_address_manager.inner().address_to_index_map.get(address).copied();
+    if let Some(index) = index.filter(|index| *index > current.saturating_add(DESTINATION_INDEX_GAP)) {
+        return Ok(Some(t!("warning.send-to-distant-address", kind = "receive", index = index, current = current)));
+    }
+
+    Ok(None)
+}
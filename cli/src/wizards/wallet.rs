@@ -52,12 +52,16 @@ pub(crate) async fn create(ctx: &Arc<KaspaCli>, name: Option<&str>, import_with_
     let hint = hint.is_not_empty().then_some(hint).map(Hint::from);
     //if hint.is_empty() { None } else { Some(hint) };
 
-    let wallet_secret = Secret::new(term.ask(true, "Enter wallet encryption password: ").await?.trim().as_bytes().to_vec());
+    let wallet_secret = Secret::new(term.ask(true, &tr("prompt.wallet-encryption-password")).await?.trim().as_bytes().to_vec());
     if wallet_secret.as_ref().is_empty() {
         return Err(Error::WalletSecretRequired);
     }
+    wallet.secret_strength_policy().validate(&wallet_secret)?;
+    if let Some(warning) = estimate_secret_strength(&wallet_secret).warning {
+        tprintln!(ctx, "{}", style(format!("WARNING - Weak wallet password: {warning}")).yellow().to_string());
+    }
     let wallet_secret_validate =
-        Secret::new(term.ask(true, "Re-enter wallet encryption password: ").await?.trim().as_bytes().to_vec());
+        Secret::new(term.ask(true, &tr("prompt.wallet-encryption-password-confirm")).await?.trim().as_bytes().to_vec());
     if wallet_secret_validate.as_ref() != wallet_secret.as_ref() {
         return Err(Error::WalletSecretMatch);
     }
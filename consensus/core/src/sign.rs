@@ -1,7 +1,7 @@
 use crate::{
     hashing::{
-        sighash::{calc_schnorr_signature_hash, SigHashReusedValues},
-        sighash_type::SIG_HASH_ALL,
+        sighash::{calc_ecdsa_signature_hash, calc_schnorr_signature_hash, SigHashReusedValues},
+        sighash_type::{SigHashType, SIG_HASH_ALL},
     },
     tx::SignableTransaction,
 };
@@ -123,25 +123,74 @@ pub fn sign_with_multiple(mut mutable_tx: SignableTransaction, privkeys: Vec<[u8
 /// TODO (aspect) - merge this with `v1` fn above or refactor wallet core to use the script engine.
 /// Sign a transaction using schnorr
 #[allow(clippy::result_large_err)]
-pub fn sign_with_multiple_v2(mut mutable_tx: SignableTransaction, privkeys: &[[u8; 32]]) -> Signed {
-    let mut map = BTreeMap::new();
-    for privkey in privkeys {
-        let schnorr_key = secp256k1::Keypair::from_seckey_slice(secp256k1::SECP256K1, privkey).unwrap();
-        let schnorr_public_key = schnorr_key.public_key().x_only_public_key().0;
-        let script_pub_key_script = once(0x20).chain(schnorr_public_key.serialize().into_iter()).chain(once(0xac)).collect_vec();
-        map.insert(script_pub_key_script, schnorr_key);
+pub fn sign_with_multiple_v2(mutable_tx: SignableTransaction, privkeys: &[[u8; 32]]) -> Signed {
+    sign_with_sighash_types(mutable_tx, privkeys, &[])
+}
+
+/// Private key matched to the kind of pay-to-pubkey script it can sign.
+#[derive(Clone, Copy)]
+pub enum InputSigningKey {
+    Schnorr(secp256k1::Keypair),
+    Ecdsa(secp256k1::Keypair),
+}
+
+impl InputSigningKey {
+    /// Map the pay-to-pubkey (schnorr) and pay-to-pubkey-ecdsa scripts
+    /// spendable by `privkeys` to the corresponding signing keys.
+    pub fn map_by_script(privkeys: &[[u8; 32]]) -> BTreeMap<Vec<u8>, InputSigningKey> {
+        let mut map = BTreeMap::new();
+        for privkey in privkeys {
+            let keypair = secp256k1::Keypair::from_seckey_slice(secp256k1::SECP256K1, privkey).unwrap();
+            let schnorr_public_key = keypair.public_key().x_only_public_key().0;
+            let schnorr_script = once(0x20).chain(schnorr_public_key.serialize()).chain(once(0xac)).collect_vec();
+            map.insert(schnorr_script, InputSigningKey::Schnorr(keypair));
+            let ecdsa_script = once(0x21).chain(keypair.public_key().serialize()).chain(once(0xab)).collect_vec();
+            map.insert(ecdsa_script, InputSigningKey::Ecdsa(keypair));
+        }
+        map
+    }
+
+    /// Produce the signature script of the input at `input_index`.
+    pub fn sign(
+        &self,
+        tx: &impl crate::tx::VerifiableTransaction,
+        input_index: usize,
+        sighash_type: SigHashType,
+        reused_values: &mut SigHashReusedValues,
+    ) -> Vec<u8> {
+        let sig: [u8; 64] = match self {
+            InputSigningKey::Schnorr(keypair) => {
+                let sig_hash = calc_schnorr_signature_hash(tx, input_index, sighash_type, reused_values);
+                let msg = secp256k1::Message::from_digest_slice(sig_hash.as_bytes().as_slice()).unwrap();
+                *keypair.sign_schnorr(msg).as_ref()
+            }
+            InputSigningKey::Ecdsa(keypair) => {
+                let sig_hash = calc_ecdsa_signature_hash(tx, input_index, sighash_type, reused_values);
+                let msg = secp256k1::Message::from_digest_slice(sig_hash.as_bytes().as_slice()).unwrap();
+                secp256k1::SECP256K1.sign_ecdsa(&msg, &keypair.secret_key()).serialize_compact()
+            }
+        };
+        // This represents OP_DATA_65 <SIGNATURE+SIGHASH_TYPE> (since both schnorr and compact ecdsa
+        // signatures are 64 bytes long and SIGHASH_TYPE is one byte)
+        once(65u8).chain(sig).chain([sighash_type.to_u8()]).collect()
     }
+}
+
+/// Sign a transaction using schnorr or ecdsa (depending on the UTXO script),
+/// signing each input with the sighash type at the input index in
+/// `sighash_types` (inputs without an entry are signed using `SIG_HASH_ALL`).
+#[allow(clippy::result_large_err)]
+pub fn sign_with_sighash_types(mut mutable_tx: SignableTransaction, privkeys: &[[u8; 32]], sighash_types: &[SigHashType]) -> Signed {
+    let map = InputSigningKey::map_by_script(privkeys);
 
     let mut reused_values = SigHashReusedValues::new();
     let mut additional_signatures_required = false;
     for i in 0..mutable_tx.tx.inputs.len() {
         let script = mutable_tx.entries[i].as_ref().unwrap().script_public_key.script();
-        if let Some(schnorr_key) = map.get(script) {
-            let sig_hash = calc_schnorr_signature_hash(&mutable_tx.as_verifiable(), i, SIG_HASH_ALL, &mut reused_values);
-            let msg = secp256k1::Message::from_digest_slice(sig_hash.as_bytes().as_slice()).unwrap();
-            let sig: [u8; 64] = *schnorr_key.sign_schnorr(msg).as_ref();
-            // This represents OP_DATA_65 <SIGNATURE+SIGHASH_TYPE> (since signature length is 64 bytes and SIGHASH_TYPE is one byte)
-            mutable_tx.tx.inputs[i].signature_script = std::iter::once(65u8).chain(sig).chain([SIG_HASH_ALL.to_u8()]).collect();
+        if let Some(signing_key) = map.get(script) {
+            let sighash_type = sighash_types.get(i).copied().unwrap_or(SIG_HASH_ALL);
+            let signature_script = signing_key.sign(&mutable_tx.as_verifiable(), i, sighash_type, &mut reused_values);
+            mutable_tx.tx.inputs[i].signature_script = signature_script;
         } else {
             additional_signatures_required = true;
         }
@@ -159,12 +208,25 @@ pub fn verify(tx: &impl crate::tx::VerifiableTransaction) -> Result<(), Error> {
         if input.signature_script.is_empty() {
             return Err(Error::Message(format!("Signature is empty for input: {i}")));
         }
-        let pk = &entry.script_public_key.script()[1..33];
-        let pk = secp256k1::XOnlyPublicKey::from_slice(pk)?;
-        let sig = secp256k1::schnorr::Signature::from_slice(&input.signature_script[1..65])?;
-        let sig_hash = calc_schnorr_signature_hash(tx, i, SIG_HASH_ALL, &mut reused_values);
-        let msg = secp256k1::Message::from_digest_slice(sig_hash.as_bytes().as_slice())?;
-        sig.verify(&msg, &pk)?;
+        let sighash_type = input
+            .signature_script
+            .get(65)
+            .and_then(|sighash_type| SigHashType::from_u8(*sighash_type).ok())
+            .ok_or_else(|| Error::Message(format!("Invalid sighash type for input: {i}")))?;
+        let script = entry.script_public_key.script();
+        if script.len() == 35 && script[0] == 0x21 && script[34] == 0xab {
+            let pk = secp256k1::PublicKey::from_slice(&script[1..34])?;
+            let sig = secp256k1::ecdsa::Signature::from_compact(&input.signature_script[1..65])?;
+            let sig_hash = calc_ecdsa_signature_hash(tx, i, sighash_type, &mut reused_values);
+            let msg = secp256k1::Message::from_digest_slice(sig_hash.as_bytes().as_slice())?;
+            secp256k1::SECP256K1.verify_ecdsa(&msg, &sig, &pk)?;
+        } else {
+            let pk = secp256k1::XOnlyPublicKey::from_slice(&script[1..33])?;
+            let sig = secp256k1::schnorr::Signature::from_slice(&input.signature_script[1..65])?;
+            let sig_hash = calc_schnorr_signature_hash(tx, i, sighash_type, &mut reused_values);
+            let msg = secp256k1::Message::from_digest_slice(sig_hash.as_bytes().as_slice())?;
+            sig.verify(&msg, &pk)?;
+        }
     }
 
     Ok(())
@@ -246,4 +308,66 @@ mod tests {
 
         assert!(verify(&signed_tx.as_verifiable()).is_ok());
     }
+
+    #[test]
+    fn test_sign_with_sighash_types() {
+        use crate::hashing::sighash_type::{SIG_HASH_ANY_ONE_CAN_PAY, SIG_HASH_NONE, SIG_HASH_SINGLE};
+
+        let secp = Secp256k1::new();
+        let (secret_key, _) = secp.generate_keypair(&mut rand::thread_rng());
+        let public_key = secret_key.x_only_public_key(&secp).0;
+        let script = ScriptVec::from_iter(once(0x20).chain(public_key.serialize()).chain(once(0xac)));
+
+        let prev_tx_id = TransactionId::from_str("880eb9819a31821d9d2399e2f35e2433b72637e393d71ecc9b8d0250f49153c3").unwrap();
+        let inputs = (0..2).map(|index| TransactionInput::new(TransactionOutpoint::new(prev_tx_id, index), vec![], 0, 1)).collect();
+        let outputs = (0..2).map(|_| TransactionOutput::new(100, ScriptPublicKey::new(0, script.clone()))).collect();
+        let unsigned_tx = Transaction::new(0, inputs, outputs, 0, SubnetworkId::default(), 0, vec![]);
+        let entries = (0..2).map(|_| UtxoEntry::new(150, ScriptPublicKey::new(0, script.clone()), 0, false)).collect();
+
+        let sighash_types = [SigHashType::from_u8(SIG_HASH_SINGLE.to_u8() | SIG_HASH_ANY_ONE_CAN_PAY.to_u8()).unwrap(), SIG_HASH_NONE];
+        let mut signed_tx = sign_with_sighash_types(
+            SignableTransaction::with_entries(unsigned_tx, entries),
+            &[secret_key.secret_bytes()],
+            &sighash_types,
+        )
+        .fully_signed()
+        .unwrap();
+        for (input, sighash_type) in signed_tx.tx.inputs.iter().zip(sighash_types) {
+            assert_eq!(input.signature_script[65], sighash_type.to_u8());
+        }
+        assert!(verify(&signed_tx.as_verifiable()).is_ok());
+
+        // the second output is not committed to by any of the signatures
+        signed_tx.tx.outputs[1].value = 50;
+        assert!(verify(&signed_tx.as_verifiable()).is_ok());
+        signed_tx.tx.outputs[0].value = 50;
+        assert!(verify(&signed_tx.as_verifiable()).is_err());
+    }
+
+    #[test]
+    fn test_sign_ecdsa() {
+        let secp = Secp256k1::new();
+        let (secret_key, public_key) = secp.generate_keypair(&mut rand::thread_rng());
+        let schnorr_script = ScriptVec::from_iter(once(0x20).chain(public_key.x_only_public_key().0.serialize()).chain(once(0xac)));
+        let ecdsa_script = ScriptVec::from_iter(once(0x21).chain(public_key.serialize()).chain(once(0xab)));
+
+        let prev_tx_id = TransactionId::from_str("880eb9819a31821d9d2399e2f35e2433b72637e393d71ecc9b8d0250f49153c3").unwrap();
+        let inputs = (0..2).map(|index| TransactionInput::new(TransactionOutpoint::new(prev_tx_id, index), vec![], 0, 1)).collect();
+        let outputs = vec![TransactionOutput::new(100, ScriptPublicKey::new(0, ecdsa_script.clone()))];
+        let unsigned_tx = Transaction::new(0, inputs, outputs, 0, SubnetworkId::default(), 0, vec![]);
+        let entries = vec![
+            UtxoEntry::new(150, ScriptPublicKey::new(0, ecdsa_script), 0, false),
+            UtxoEntry::new(150, ScriptPublicKey::new(0, schnorr_script), 0, false),
+        ];
+
+        let mut signed_tx =
+            sign_with_multiple_v2(SignableTransaction::with_entries(unsigned_tx, entries), &[secret_key.secret_bytes()])
+                .fully_signed()
+                .unwrap();
+        assert_eq!(signed_tx.tx.inputs[0].signature_script.len(), signed_tx.tx.inputs[1].signature_script.len());
+        assert!(verify(&signed_tx.as_verifiable()).is_ok());
+
+        signed_tx.tx.outputs[0].value = 50;
+        assert!(verify(&signed_tx.as_verifiable()).is_err());
+    }
 }
@@ -1,5 +1,5 @@
 use kaspa_consensus_core::{
-    tx::{ScriptPublicKeys, TransactionOutpoint},
+    tx::{ScriptPublicKey, ScriptPublicKeys, TransactionOutpoint},
     utxo::utxo_diff::UtxoDiff,
     BlockHashSet,
 };
@@ -12,7 +12,7 @@ use std::{collections::HashSet, fmt::Debug, sync::Arc};
 
 use crate::{
     errors::UtxoIndexResult,
-    model::{UtxoChanges, UtxoSetByScriptPublicKey},
+    model::{UtxoChanges, UtxoIndexCursor, UtxoSetByScriptPublicKey},
 };
 
 ///Utxoindex API targeted at retrieval calls.
@@ -27,6 +27,20 @@ pub trait UtxoIndexApi: Send + Sync + Debug {
     /// Note: Use a read lock when accessing this method
     fn get_utxos_by_script_public_keys(&self, script_public_keys: ScriptPublicKeys) -> StoreResult<UtxoSetByScriptPublicKey>;
 
+    /// Retrieve a page of at most `limit` utxos by script public keys from the utxoindex db.
+    ///
+    /// Script public keys are iterated in the supplied order, resuming right after `start_after` if supplied.
+    /// Returns the page along with the cursor to resume from, `None` once the query is exhausted.
+    /// Note that an empty last page may be returned when the total entry count is a multiple of `limit`.
+    ///
+    /// Note: Use a read lock when accessing this method
+    fn get_utxos_page_by_script_public_keys(
+        &self,
+        script_public_keys: &[ScriptPublicKey],
+        start_after: Option<UtxoIndexCursor>,
+        limit: usize,
+    ) -> StoreResult<(UtxoSetByScriptPublicKey, Option<UtxoIndexCursor>)>;
+
     fn get_balance_by_script_public_keys(&self, script_public_keys: ScriptPublicKeys) -> StoreResult<BalanceByScriptPublicKey>;
 
     // This can have a big memory footprint, so it should be used only for tests.
@@ -74,6 +88,17 @@ impl UtxoIndexProxy {
         spawn_blocking(move || self.inner.read().get_utxos_by_script_public_keys(script_public_keys)).await.unwrap()
     }
 
+    pub async fn get_utxos_page_by_script_public_keys(
+        self,
+        script_public_keys: Vec<ScriptPublicKey>,
+        start_after: Option<UtxoIndexCursor>,
+        limit: usize,
+    ) -> StoreResult<(UtxoSetByScriptPublicKey, Option<UtxoIndexCursor>)> {
+        spawn_blocking(move || self.inner.read().get_utxos_page_by_script_public_keys(&script_public_keys, start_after, limit))
+            .await
+            .unwrap()
+    }
+
     pub async fn get_balance_by_script_public_keys(
         self,
         script_public_keys: ScriptPublicKeys,
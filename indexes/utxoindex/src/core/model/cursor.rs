@@ -0,0 +1,4 @@
+use kaspa_consensus_core::tx::{ScriptPublicKey, TransactionOutpoint};
+
+/// Position of a UTXO entry within the utxoindex, used to resume paginated queries
+pub type UtxoIndexCursor = (ScriptPublicKey, TransactionOutpoint);
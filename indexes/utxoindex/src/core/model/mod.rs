@@ -1,3 +1,4 @@
+mod cursor;
 mod supply;
 
-pub use {kaspa_index_core::indexed_utxos::*, supply::*};
+pub use {cursor::*, kaspa_index_core::indexed_utxos::*, supply::*};
@@ -1,12 +1,16 @@
 use crate::{
     api::UtxoIndexApi,
     errors::{UtxoIndexError, UtxoIndexResult},
-    model::{CirculatingSupply, UtxoChanges, UtxoSetByScriptPublicKey},
+    model::{CirculatingSupply, UtxoChanges, UtxoIndexCursor, UtxoSetByScriptPublicKey},
     stores::store_manager::Store,
     update_container::UtxoIndexChanges,
     IDENT,
 };
-use kaspa_consensus_core::{tx::ScriptPublicKeys, utxo::utxo_diff::UtxoDiff, BlockHashSet};
+use kaspa_consensus_core::{
+    tx::{ScriptPublicKey, ScriptPublicKeys},
+    utxo::utxo_diff::UtxoDiff,
+    BlockHashSet,
+};
 use kaspa_consensusmanager::{ConsensusManager, ConsensusResetHandler};
 use kaspa_core::{info, trace};
 use kaspa_database::prelude::{StoreError, StoreResult, DB};
@@ -57,6 +61,18 @@ impl UtxoIndexApi for UtxoIndex {
         self.store.get_utxos_by_script_public_key(script_public_keys)
     }
 
+    /// Retrieve a page of utxos by script public keys from the utxoindex db.
+    fn get_utxos_page_by_script_public_keys(
+        &self,
+        script_public_keys: &[ScriptPublicKey],
+        start_after: Option<UtxoIndexCursor>,
+        limit: usize,
+    ) -> StoreResult<(UtxoSetByScriptPublicKey, Option<UtxoIndexCursor>)> {
+        trace!("[{0}] retrieving a page of up to {1} utxos from {2} script public keys", IDENT, limit, script_public_keys.len());
+
+        self.store.get_utxos_page_by_script_public_keys(script_public_keys, start_after, limit)
+    }
+
     /// Retrieve utxos by script public keys from the utxoindex db.
     fn get_balance_by_script_public_keys(&self, script_public_keys: ScriptPublicKeys) -> StoreResult<BalanceByScriptPublicKey> {
         trace!("[{0}] retrieving utxos from {1} script public keys", IDENT, script_public_keys.len());
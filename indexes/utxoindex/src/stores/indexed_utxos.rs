@@ -1,4 +1,4 @@
-use crate::core::model::{CompactUtxoCollection, CompactUtxoEntry, UtxoSetByScriptPublicKey};
+use crate::core::model::{CompactUtxoCollection, CompactUtxoEntry, UtxoIndexCursor, UtxoSetByScriptPublicKey};
 
 use kaspa_consensus_core::tx::{
     ScriptPublicKey, ScriptPublicKeyVersion, ScriptPublicKeys, ScriptVec, TransactionIndexType, TransactionOutpoint,
@@ -126,6 +126,15 @@ pub trait UtxoSetByScriptPublicKeyStoreReader {
     /// Get [UtxoSetByScriptPublicKey] set by queried [ScriptPublicKeys],
     fn get_utxos_from_script_public_keys(&self, script_public_keys: ScriptPublicKeys) -> StoreResult<UtxoSetByScriptPublicKey>;
     fn get_balance_from_script_public_keys(&self, script_public_keys: ScriptPublicKeys) -> StoreResult<BalanceByScriptPublicKey>;
+    /// Get a page of at most `limit` entries of the [UtxoSetByScriptPublicKey] set by queried script public keys.
+    /// Script public keys are iterated in the supplied order, resuming right after `start_after` if supplied.
+    /// Returns the page along with the [UtxoIndexCursor] of its last entry if the page is full, `None` otherwise.
+    fn get_utxos_page_from_script_public_keys(
+        &self,
+        script_public_keys: &[ScriptPublicKey],
+        start_after: Option<UtxoIndexCursor>,
+        limit: usize,
+    ) -> StoreResult<(UtxoSetByScriptPublicKey, Option<UtxoIndexCursor>)>;
     fn get_all_outpoints(&self) -> StoreResult<HashSet<TransactionOutpoint>>; // This can have a big memory footprint, so it should be used only for tests.
 }
 
@@ -177,6 +186,64 @@ impl UtxoSetByScriptPublicKeyStoreReader for DbUtxoSetByScriptPublicKeyStore {
         Ok(utxos_by_script_public_keys)
     }
 
+    fn get_utxos_page_from_script_public_keys(
+        &self,
+        script_public_keys: &[ScriptPublicKey],
+        start_after: Option<UtxoIndexCursor>,
+        limit: usize,
+    ) -> StoreResult<(UtxoSetByScriptPublicKey, Option<UtxoIndexCursor>)> {
+        let (start, mut seek_from) = match start_after {
+            Some((script_public_key, outpoint)) => {
+                let start = script_public_keys.iter().position(|spk| *spk == script_public_key).unwrap_or(script_public_keys.len());
+                (start, Some(outpoint))
+            }
+            None => (0, None),
+        };
+
+        let mut entries_count: usize = 0;
+        let mut last = None;
+        let mut utxos_by_script_public_keys = UtxoSetByScriptPublicKey::new();
+        for script_public_key in script_public_keys[start..].iter() {
+            if entries_count == limit {
+                break;
+            }
+            let script_public_key_bucket = ScriptPublicKeyBucket::from(script_public_key);
+            // the cursor entry may have been spent since the previous page was
+            // produced, so it is filtered out rather than blindly skipped
+            let seek_from = seek_from.take();
+            let seek_key = seek_from
+                .as_ref()
+                .map(|outpoint| UtxoEntryFullAccessKey::new(script_public_key_bucket.clone(), TransactionOutpointKey::from(outpoint)));
+            let utxos_by_script_public_keys_inner = CompactUtxoCollection::from_iter(
+                self.access
+                    .seek_iterator(Some(script_public_key_bucket.as_ref()), seek_key, usize::MAX, false)
+                    .map(|res| {
+                        let (key, entry) = res.unwrap();
+                        (
+                            TransactionOutpoint::from(TransactionOutpointKey(
+                                <[u8; TRANSACTION_OUTPOINT_KEY_SIZE]>::try_from(&key[..]).unwrap(),
+                            )),
+                            entry,
+                        )
+                    })
+                    .filter(|(outpoint, _)| seek_from.as_ref() != Some(outpoint))
+                    .take(limit - entries_count)
+                    .inspect(|(outpoint, _)| last = Some((script_public_key.clone(), *outpoint))),
+            );
+            entries_count += utxos_by_script_public_keys_inner.len();
+            if !utxos_by_script_public_keys_inner.is_empty() {
+                utxos_by_script_public_keys.insert(script_public_key.clone(), utxos_by_script_public_keys_inner);
+            }
+        }
+        debug!(
+            "IDXPRC, Executed a paged query for the utxo set of {} script public keys yielding {} entries",
+            script_public_keys.len() - start,
+            entries_count
+        );
+        let next = if entries_count == limit { last } else { None };
+        Ok((utxos_by_script_public_keys, next))
+    }
+
     fn get_balance_from_script_public_keys(&self, script_public_keys: ScriptPublicKeys) -> StoreResult<BalanceByScriptPublicKey> {
         let script_count = script_public_keys.len();
         let mut entries_count: usize = 0;
@@ -257,3 +324,107 @@ impl UtxoSetByScriptPublicKeyStore for DbUtxoSetByScriptPublicKeyStore {
         self.access.delete_all(DirectDbWriter::new(&self.db))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_database::{create_temp_db, prelude::ConnBuilder};
+
+    fn utxo_set(script_public_keys: &[ScriptPublicKey], entries_per_key: u64) -> UtxoSetByScriptPublicKey {
+        script_public_keys
+            .iter()
+            .enumerate()
+            .map(|(i, script_public_key)| {
+                let collection = CompactUtxoCollection::from_iter((0..entries_per_key).map(|j| {
+                    let outpoint = TransactionOutpoint::new(Hash::from_u64_word(i as u64 * 1_000 + j), j as TransactionIndexType);
+                    (outpoint, CompactUtxoEntry::new(j + 1, i as u64, false))
+                }));
+                (script_public_key.clone(), collection)
+            })
+            .collect()
+    }
+
+    fn collect_pages(
+        store: &DbUtxoSetByScriptPublicKeyStore,
+        script_public_keys: &[ScriptPublicKey],
+        mut cursor: Option<UtxoIndexCursor>,
+        limit: usize,
+    ) -> (Vec<usize>, HashSet<TransactionOutpoint>) {
+        let mut page_sizes = vec![];
+        let mut outpoints = HashSet::new();
+        loop {
+            let (page, next) = store.get_utxos_page_from_script_public_keys(script_public_keys, cursor, limit).unwrap();
+            let page_size = page.values().map(|collection| collection.len()).sum::<usize>();
+            assert!(page_size <= limit);
+            for outpoint in page.values().flat_map(|collection| collection.keys()) {
+                assert!(outpoints.insert(*outpoint), "outpoint {outpoint} was returned twice");
+            }
+            page_sizes.push(page_size);
+            match next {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        (page_sizes, outpoints)
+    }
+
+    #[test]
+    fn test_utxos_pagination() {
+        let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let mut store = DbUtxoSetByScriptPublicKeyStore::new(db, CachePolicy::Empty);
+
+        let script_public_keys =
+            (0..3u8).map(|i| ScriptPublicKey::new(0, ScriptVec::from_slice(&[i; 34]))).collect::<Vec<ScriptPublicKey>>();
+        let utxos = utxo_set(&script_public_keys, 5);
+        store.add_utxo_entries(&utxos).unwrap();
+        let all_outpoints = utxos.values().flat_map(|collection| collection.keys().copied()).collect::<HashSet<_>>();
+
+        // pages span script public key boundaries and the last partial page ends the walk
+        let (page_sizes, outpoints) = collect_pages(&store, &script_public_keys, None, 4);
+        assert_eq!(page_sizes, vec![4, 4, 4, 3]);
+        assert_eq!(outpoints, all_outpoints);
+
+        // a full last page yields a cursor followed by an empty page
+        let (page_sizes, outpoints) = collect_pages(&store, &script_public_keys, None, 5);
+        assert_eq!(page_sizes, vec![5, 5, 5, 0]);
+        assert_eq!(outpoints, all_outpoints);
+
+        // a single page covering everything yields no cursor
+        let (page, next) = store.get_utxos_page_from_script_public_keys(&script_public_keys, None, 100).unwrap();
+        assert_eq!(page.len(), script_public_keys.len());
+        for (script_public_key, collection) in page.iter() {
+            let expected = &utxos[script_public_key];
+            assert_eq!(collection.len(), expected.len());
+            assert!(collection.iter().all(|(outpoint, entry)| expected[outpoint].amount == entry.amount));
+        }
+        assert!(next.is_none());
+
+        // resuming from a cursor skips everything up to and including it
+        let (first, cursor) = store.get_utxos_page_from_script_public_keys(&script_public_keys, None, 7).unwrap();
+        let cursor = cursor.expect("a full page returns a cursor");
+        assert_eq!(cursor.0, script_public_keys[1]);
+        assert!(first[&script_public_keys[1]].contains_key(&cursor.1));
+        let first_outpoints = first.values().flat_map(|collection| collection.keys().copied()).collect::<HashSet<_>>();
+        let (page_sizes, rest) = collect_pages(&store, &script_public_keys, Some(cursor.clone()), 7);
+        assert_eq!(page_sizes, vec![7, 1]);
+        assert!(rest.is_disjoint(&first_outpoints));
+        assert_eq!(rest.union(&first_outpoints).copied().collect::<HashSet<_>>(), all_outpoints);
+
+        // the cursor entry being spent between pages does not affect resumption
+        let (cursor_script_public_key, cursor_outpoint) = cursor.clone();
+        let spent = UtxoSetByScriptPublicKey::from_iter([(
+            cursor_script_public_key.clone(),
+            CompactUtxoCollection::from_iter([(cursor_outpoint, utxos[&cursor_script_public_key][&cursor_outpoint])]),
+        )]);
+        store.remove_utxo_entries(&spent).unwrap();
+        let (page_sizes, resumed) = collect_pages(&store, &script_public_keys, Some(cursor), 7);
+        assert_eq!(page_sizes, vec![7, 1]);
+        assert_eq!(resumed, rest);
+
+        // a cursor for a script public key outside of the query yields an empty page
+        let unknown = (ScriptPublicKey::new(0, ScriptVec::from_slice(&[0xff; 34])), cursor_outpoint);
+        let (page, next) = store.get_utxos_page_from_script_public_keys(&script_public_keys, Some(unknown), 7).unwrap();
+        assert!(page.is_empty());
+        assert!(next.is_none());
+    }
+}
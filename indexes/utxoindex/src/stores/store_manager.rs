@@ -1,7 +1,7 @@
 use std::{collections::HashSet, sync::Arc};
 
 use kaspa_consensus_core::{
-    tx::{ScriptPublicKeys, TransactionOutpoint},
+    tx::{ScriptPublicKey, ScriptPublicKeys, TransactionOutpoint},
     BlockHashSet,
 };
 use kaspa_core::trace;
@@ -9,7 +9,7 @@ use kaspa_database::prelude::{CachePolicy, StoreResult, DB};
 use kaspa_index_core::indexed_utxos::BalanceByScriptPublicKey;
 
 use crate::{
-    model::UtxoSetByScriptPublicKey,
+    model::{UtxoIndexCursor, UtxoSetByScriptPublicKey},
     stores::{
         indexed_utxos::{DbUtxoSetByScriptPublicKeyStore, UtxoSetByScriptPublicKeyStore, UtxoSetByScriptPublicKeyStoreReader},
         supply::{CirculatingSupplyStore, CirculatingSupplyStoreReader, DbCirculatingSupplyStore},
@@ -38,6 +38,15 @@ impl Store {
         self.utxos_by_script_public_key_store.get_utxos_from_script_public_keys(script_public_keys)
     }
 
+    pub fn get_utxos_page_by_script_public_keys(
+        &self,
+        script_public_keys: &[ScriptPublicKey],
+        start_after: Option<UtxoIndexCursor>,
+        limit: usize,
+    ) -> StoreResult<(UtxoSetByScriptPublicKey, Option<UtxoIndexCursor>)> {
+        self.utxos_by_script_public_key_store.get_utxos_page_from_script_public_keys(script_public_keys, start_after, limit)
+    }
+
     pub fn get_balance_by_script_public_key(&self, script_public_keys: ScriptPublicKeys) -> StoreResult<BalanceByScriptPublicKey> {
         self.utxos_by_script_public_key_store.get_balance_from_script_public_keys(script_public_keys)
     }
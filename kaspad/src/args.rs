@@ -39,6 +39,10 @@ pub struct Args {
     #[serde(rename = "unsaferpc")]
     pub unsafe_rpc: bool,
     pub wrpc_verbose: bool,
+    #[serde(rename = "rpccert")]
+    pub rpc_cert: Option<String>,
+    #[serde(rename = "rpckey")]
+    pub rpc_key: Option<String>,
     #[serde(rename = "loglevel")]
     pub log_level: String,
     pub async_threads: usize,
@@ -118,6 +122,8 @@ impl Default for Args {
             logdir: None,
             rpclisten: None,
             wrpc_verbose: false,
+            rpc_cert: None,
+            rpc_key: None,
             log_level: "INFO".into(),
             connect_peers: vec![],
             add_peers: vec![],
@@ -250,6 +256,8 @@ pub fn cli() -> Command {
                 .value_parser(clap::value_parser!(WrpcNetAddress))
                 .help("Interface:port to listen for wRPC JSON connections (default port: 18110, testnet: 18210)."),
         )
+        .arg(arg!(--rpccert <FILE> "PEM certificate file enabling TLS (wss) on the wRPC listeners (requires --rpckey)."))
+        .arg(arg!(--rpckey <FILE> "PEM private key file of the wRPC TLS certificate (requires --rpccert)."))
         .arg(arg!(--unsaferpc "Enable RPC commands which affect the state of the node"))
         .arg(
             Arg::new("connect-peers")
@@ -418,6 +426,8 @@ impl Args {
             rpclisten_json: m.get_one::<WrpcNetAddress>("rpclisten-json").cloned().or(defaults.rpclisten_json),
             unsafe_rpc: arg_match_unwrap_or::<bool>(&m, "unsaferpc", defaults.unsafe_rpc),
             wrpc_verbose: false,
+            rpc_cert: m.get_one::<String>("rpccert").cloned().or(defaults.rpc_cert),
+            rpc_key: m.get_one::<String>("rpckey").cloned().or(defaults.rpc_key),
             log_level: arg_match_unwrap_or::<String>(&m, "log_level", defaults.log_level),
             async_threads: arg_match_unwrap_or::<usize>(&m, "async_threads", defaults.async_threads),
             connect_peers: arg_match_many_unwrap_or::<ContextualNetAddress>(&m, "connect-peers", defaults.connect_peers),
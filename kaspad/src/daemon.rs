@@ -34,6 +34,7 @@ use kaspa_p2p_flows::{flow_context::FlowContext, service::P2pService};
 use kaspa_perf_monitor::{builder::Builder as PerfMonitorBuilder, counters::CountersSnapshot};
 use kaspa_utxoindex::{api::UtxoIndexProxy, UtxoIndex};
 use kaspa_wrpc_server::service::{Options as WrpcServerOptions, WebSocketCounters as WrpcServerCounters, WrpcEncoding, WrpcService};
+use kaspa_wrpc_server::tls::TlsOptions;
 
 /// Desired soft FD limit that needs to be configured
 /// for the kaspad process.
@@ -95,6 +96,9 @@ pub fn validate_args(args: &Args) -> ConfigResult<()> {
     if args.max_tracked_addresses > Tracker::MAX_ADDRESS_UPPER_BOUND {
         return Err(ConfigError::MaxTrackedAddressesTooHigh(Tracker::MAX_ADDRESS_UPPER_BOUND));
     }
+    if args.rpc_cert.is_some() ^ args.rpc_key.is_some() {
+        return Err(ConfigError::MissingRpcCertOrKey);
+    }
     Ok(())
 }
 
@@ -499,7 +503,8 @@ do you confirm? (answer y/n or pass --yes to the Kaspad command line to confirm
     async_runtime.register(mining_monitor);
     async_runtime.register(perf_monitor);
     let wrpc_service_tasks: usize = 2; // num_cpus::get() / 2;
-                                       // Register wRPC servers based on command line arguments
+    let wrpc_tls = args.rpc_cert.as_ref().zip(args.rpc_key.as_ref()).map(|(cert, key)| TlsOptions::new(cert.into(), key.into()));
+    // Register wRPC servers based on command line arguments
     [
         (args.rpclisten_borsh.clone(), WrpcEncoding::Borsh, wrpc_borsh_counters),
         (args.rpclisten_json.clone(), WrpcEncoding::SerdeJson, wrpc_json_counters),
@@ -515,6 +520,7 @@ do you confirm? (answer y/n or pass --yes to the Kaspad command line to confirm
                 WrpcServerOptions {
                     listen_address: listen_address.to_address(&network.network_type, &encoding).to_string(), // TODO: use a normalized ContextualNetAddress instead of a String
                     verbose: args.wrpc_verbose,
+                    tls: wrpc_tls.clone(),
                     ..WrpcServerOptions::default()
                 },
             ))
@@ -256,6 +256,25 @@ impl MiningManager {
         }
     }
 
+    /// validate_transaction runs the full mempool acceptance check on the given
+    /// transaction without inserting it into the mempool.
+    ///
+    /// Returns the validated transaction populated with its calculated fee and compute mass.
+    pub fn validate_transaction(
+        &self,
+        consensus: &dyn ConsensusApi,
+        transaction: MutableTransaction,
+        orphan: Orphan,
+    ) -> MiningManagerResult<MutableTransaction> {
+        // read lock on mempool
+        let mut transaction = self.mempool.read().pre_validate_and_populate_transaction(consensus, transaction)?;
+        // no lock on mempool
+        let validation_result = validate_mempool_transaction(consensus, &mut transaction);
+        // read lock on mempool
+        self.mempool.read().post_validate_transaction(validation_result, &transaction, orphan)?;
+        Ok(transaction)
+    }
+
     fn validate_and_insert_unorphaned_transactions(
         &self,
         consensus: &dyn ConsensusApi,
@@ -770,6 +789,20 @@ impl MiningManagerProxy {
         consensus.clone().spawn_blocking(move |c| self.inner.validate_and_insert_transaction(c, transaction, priority, orphan)).await
     }
 
+    /// Validates a transaction against the mempool acceptance rules without
+    /// adding it to the set of known transactions.
+    pub async fn validate_transaction(
+        self,
+        consensus: &ConsensusProxy,
+        transaction: Transaction,
+        orphan: Orphan,
+    ) -> MiningManagerResult<MutableTransaction> {
+        consensus
+            .clone()
+            .spawn_blocking(move |c| self.inner.validate_transaction(c, MutableTransaction::from_tx(transaction), orphan))
+            .await
+    }
+
     /// Validates a batch of transactions, handling iteratively only the independent ones, and
     /// adds those to the set of known transactions that have not yet been added to any block.
     ///
@@ -110,6 +110,39 @@ mod tests {
         );
     }
 
+    // test_validate_transaction verifies that a valid transaction passes the mempool
+    // acceptance check without being inserted into the mempool.
+    #[test]
+    fn test_validate_transaction() {
+        let consensus = Arc::new(ConsensusMock::new());
+        let counters = Arc::new(MiningCounters::default());
+        let mining_manager = MiningManager::new(TARGET_TIME_PER_BLOCK, false, MAX_BLOCK_MASS, None, counters);
+        let transaction = create_transaction_with_utxo_entry(0, 0);
+
+        let validated = mining_manager.validate_transaction(consensus.as_ref(), transaction.clone(), Orphan::Forbidden);
+        assert!(validated.is_ok(), "validating a valid transaction failed");
+        let validated = validated.unwrap();
+        assert!(validated.calculated_fee.is_some(), "validated transaction is missing the calculated fee");
+        assert!(validated.calculated_compute_mass.is_some(), "validated transaction is missing the calculated compute mass");
+        assert!(
+            !mining_manager.has_transaction(&transaction.id(), TransactionQuery::All),
+            "validated transaction was inserted into the mempool"
+        );
+
+        let result = mining_manager.validate_and_insert_mutable_transaction(
+            consensus.as_ref(),
+            transaction.clone(),
+            Priority::Low,
+            Orphan::Forbidden,
+        );
+        assert!(result.is_ok(), "inserting a valid transaction failed");
+        let result = mining_manager.validate_transaction(consensus.as_ref(), transaction.clone(), Orphan::Forbidden);
+        assert!(
+            matches!(result, Err(MiningManagerError::MempoolError(RuleError::RejectDuplicate(id))) if id == transaction.id()),
+            "validating a transaction already in the mempool should fail with a duplicate rejection"
+        );
+    }
+
     /// test_simulated_error_in_consensus verifies that a predefined result is actually
     /// returned by the consensus mock as expected when the mempool tries to validate and
     /// insert a transaction.
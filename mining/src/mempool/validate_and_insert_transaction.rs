@@ -81,6 +81,31 @@ impl Mempool {
         Ok(Some(accepted_transaction))
     }
 
+    /// Performs the contextual checks of [`Self::post_validate_and_insert_transaction`]
+    /// without inserting the transaction into the mempool.
+    pub(crate) fn post_validate_transaction(
+        &self,
+        validation_result: RuleResult<()>,
+        transaction: &MutableTransaction,
+        orphan: Orphan,
+    ) -> RuleResult<()> {
+        match validation_result {
+            Ok(_) => {}
+            Err(RuleError::RejectMissingOutpoint) if orphan == Orphan::Allowed => {
+                // The transaction would be accepted into the orphan pool
+                return Ok(());
+            }
+            Err(RuleError::RejectMissingOutpoint) => {
+                return Err(RuleError::RejectDisallowedOrphan(transaction.id()));
+            }
+            Err(err) => {
+                return Err(err);
+            }
+        }
+
+        self.validate_transaction_in_context(transaction)
+    }
+
     /// Validates that the transaction wasn't already accepted into the DAG
     fn validate_transaction_unacceptance(&self, transaction: &MutableTransaction) -> RuleResult<()> {
         // Reject if the transaction is registered as an accepted transaction
@@ -1,5 +1,8 @@
 use crate::error::RpcResult;
-use std::sync::{Arc, Mutex};
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex,
+};
 use workflow_core::channel::Multiplexer;
 
 /// RPC channel control operations
@@ -10,6 +13,10 @@ pub enum RpcState {
     /// RpcApi channel close (disconnected)
     #[default]
     Disconnected,
+    /// Notification subscriptions have been re-submitted to the server
+    /// after the channel has been (re)opened. This is an event rather than
+    /// a state: the channel state remains [`RpcState::Connected`].
+    SubscriptionsRestored,
 }
 
 #[derive(Default)]
@@ -20,6 +27,10 @@ struct Inner {
     multiplexer: Multiplexer<RpcState>,
     // Optional Connection descriptor such as a connection URL.
     descriptor: Mutex<Option<String>>,
+    // Notifications discarded by the client notification overflow policy.
+    notifications_dropped: AtomicU64,
+    // Notifications superseded by a more recent notification of the same type.
+    notifications_coalesced: AtomicU64,
 }
 
 /// RPC channel control helper. This is a companion
@@ -81,6 +92,11 @@ impl RpcCtl {
         Ok(self.inner.multiplexer.try_broadcast(RpcState::Disconnected)?)
     }
 
+    /// Signal restored notification subscriptions to all listeners (async)
+    pub async fn signal_subscriptions_restored(&self) -> RpcResult<()> {
+        Ok(self.inner.multiplexer.broadcast(RpcState::SubscriptionsRestored).await?)
+    }
+
     /// Set the connection descriptor (URL, peer address, etc.)
     pub fn set_descriptor(&self, descriptor: Option<String>) {
         *self.inner.descriptor.lock().unwrap() = descriptor;
@@ -90,4 +106,26 @@ impl RpcCtl {
     pub fn descriptor(&self) -> Option<String> {
         self.inner.descriptor.lock().unwrap().clone()
     }
+
+    /// Register notifications discarded by the client notification overflow policy.
+    pub fn register_notifications_dropped(&self, count: u64) {
+        self.inner.notifications_dropped.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Register notifications coalesced by the client notification overflow policy.
+    pub fn register_notifications_coalesced(&self, count: u64) {
+        self.inner.notifications_coalesced.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Number of notifications discarded because the notification consumer
+    /// did not keep up with the incoming notifications.
+    pub fn notifications_dropped(&self) -> u64 {
+        self.inner.notifications_dropped.load(Ordering::Relaxed)
+    }
+
+    /// Number of notifications superseded by a more recent notification
+    /// of the same type while the notification consumer was stalled.
+    pub fn notifications_coalesced(&self) -> u64 {
+        self.inner.notifications_coalesced.load(Ordering::Relaxed)
+    }
 }
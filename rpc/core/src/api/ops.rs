@@ -86,6 +86,12 @@ pub enum RpcApiOps {
     GetCoinSupply,
     /// Get DAA Score timestamp estimate
     GetDaaScoreTimestampEstimate,
+    /// Validates a transaction against the mempool acceptance rules without submitting it
+    ValidateTransaction,
+    /// Get a page of available UTXOs for a given set of addresses
+    GetUtxosByAddressesPage,
+    /// Get wRPC server connection statistics
+    GetWrpcServerStatus,
 
     // Subscription commands for starting/stopping notifications
     NotifyBlockAdded,
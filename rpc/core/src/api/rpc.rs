@@ -231,6 +231,33 @@ pub trait RpcApi: Sync + Send + AnySync {
     }
     async fn get_utxos_by_addresses_call(&self, request: GetUtxosByAddressesRequest) -> RpcResult<GetUtxosByAddressesResponse>;
 
+    /// Requests a page of at most `limit` current UTXOs for the given node addresses,
+    /// resuming right after the `start_after` cursor returned with the previous page.
+    ///
+    /// Use this call instead of [`RpcApi::get_utxos_by_addresses`] for addresses holding
+    /// a large number of UTXOs. A limit of `0` requests the largest page the node allows.
+    ///
+    /// This call is only available when this node was started with `--utxoindex`.
+    async fn get_utxos_by_addresses_page(
+        &self,
+        addresses: Vec<RpcAddress>,
+        start_after: Option<RpcUtxosByAddressesCursor>,
+        limit: u32,
+    ) -> RpcResult<GetUtxosByAddressesPageResponse> {
+        self.get_utxos_by_addresses_page_call(GetUtxosByAddressesPageRequest::new(addresses, start_after, limit)).await
+    }
+    async fn get_utxos_by_addresses_page_call(
+        &self,
+        request: GetUtxosByAddressesPageRequest,
+    ) -> RpcResult<GetUtxosByAddressesPageResponse>;
+
+    /// Requests statistics of the connections to the wRPC server (peer address, connection
+    /// time, message counters and subscriptions). Served by the wRPC server only.
+    async fn get_wrpc_server_status(&self) -> RpcResult<GetWrpcServerStatusResponse> {
+        self.get_wrpc_server_status_call(GetWrpcServerStatusRequest {}).await
+    }
+    async fn get_wrpc_server_status_call(&self, request: GetWrpcServerStatusRequest) -> RpcResult<GetWrpcServerStatusResponse>;
+
     /// Requests the blue score of the current selected parent of the virtual block.
     async fn get_sink_blue_score(&self) -> RpcResult<u64> {
         Ok(self.get_sink_blue_score_call(GetSinkBlueScoreRequest {}).await?.blue_score)
@@ -304,6 +331,13 @@ pub trait RpcApi: Sync + Send + AnySync {
         request: GetDaaScoreTimestampEstimateRequest,
     ) -> RpcResult<GetDaaScoreTimestampEstimateResponse>;
 
+    /// Validates a transaction against the mempool acceptance rules
+    /// without inserting it into the mempool or relaying it.
+    async fn validate_transaction(&self, transaction: RpcTransaction, allow_orphan: bool) -> RpcResult<ValidateTransactionResponse> {
+        self.validate_transaction_call(ValidateTransactionRequest { transaction, allow_orphan }).await
+    }
+    async fn validate_transaction_call(&self, request: ValidateTransactionRequest) -> RpcResult<ValidateTransactionResponse>;
+
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Notification API
 
@@ -13,6 +13,15 @@ pub struct RpcUtxosByAddressesEntry {
     pub utxo_entry: RpcUtxoEntry,
 }
 
+/// Position of a UTXO entry within a `GetUtxosByAddressesPage` RPC query,
+/// used to resume the query right after this entry.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcUtxosByAddressesCursor {
+    pub address: RpcAddress,
+    pub outpoint: RpcTransactionOutpoint,
+}
+
 /// Represents a balance of an address returned by the `GetBalancesByAddresses` RPC.
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
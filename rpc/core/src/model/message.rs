@@ -825,6 +825,94 @@ impl GetDaaScoreTimestampEstimateResponse {
     }
 }
 
+/// ValidateTransactionRequest runs the mempool acceptance check on a transaction
+/// without inserting it into the mempool or relaying it to the network.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidateTransactionRequest {
+    pub transaction: RpcTransaction,
+    pub allow_orphan: bool,
+}
+
+impl ValidateTransactionRequest {
+    pub fn new(transaction: RpcTransaction, allow_orphan: bool) -> Self {
+        Self { transaction, allow_orphan }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidateTransactionResponse {
+    pub transaction_id: RpcTransactionId,
+    /// Transaction fee calculated by the node (in SOMPI)
+    pub fee: u64,
+    /// Transaction compute mass calculated by the node
+    pub compute_mass: u64,
+}
+
+impl ValidateTransactionResponse {
+    pub fn new(transaction_id: RpcTransactionId, fee: u64, compute_mass: u64) -> Self {
+        Self { transaction_id, fee, compute_mass }
+    }
+}
+
+/// Maximum number of UTXO entries returned in a single `GetUtxosByAddressesPage` response
+pub const MAX_UTXOS_BY_ADDRESSES_PAGE_SIZE: u32 = 100_000;
+
+/// GetUtxosByAddressesPageRequest requests a page of the current UTXOs for the given addresses.
+///
+/// Addresses are traversed in the supplied order. The first page is requested with
+/// `start_after` set to `None`, subsequent pages with the cursor returned in the
+/// previous response. Pages do not form a consistent snapshot of the UTXO set:
+/// clients should subscribe to `UtxosChanged` notifications before paging.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetUtxosByAddressesPageRequest {
+    pub addresses: Vec<RpcAddress>,
+    pub start_after: Option<RpcUtxosByAddressesCursor>,
+    /// Maximum number of entries to return, `0` for [`MAX_UTXOS_BY_ADDRESSES_PAGE_SIZE`]
+    pub limit: u32,
+}
+
+impl GetUtxosByAddressesPageRequest {
+    pub fn new(addresses: Vec<RpcAddress>, start_after: Option<RpcUtxosByAddressesCursor>, limit: u32) -> Self {
+        Self { addresses, start_after, limit }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetUtxosByAddressesPageResponse {
+    pub entries: Vec<RpcUtxosByAddressesEntry>,
+    /// Cursor to request the next page with, `None` once all entries have been returned
+    pub next: Option<RpcUtxosByAddressesCursor>,
+}
+
+impl GetUtxosByAddressesPageResponse {
+    pub fn new(entries: Vec<RpcUtxosByAddressesEntry>, next: Option<RpcUtxosByAddressesCursor>) -> Self {
+        Self { entries, next }
+    }
+}
+
+/// GetWrpcServerStatusRequest requests statistics of the connections to the
+/// wRPC server serving the request. The method is served by the wRPC server only.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetWrpcServerStatusRequest {}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetWrpcServerStatusResponse {
+    /// Currently open connections
+    pub connections: Vec<RpcWrpcConnectionInfo>,
+}
+
+impl GetWrpcServerStatusResponse {
+    pub fn new(connections: Vec<RpcWrpcConnectionInfo>) -> Self {
+        Self { connections }
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Subscriptions & notifications
 // ----------------------------------------------------------------------------
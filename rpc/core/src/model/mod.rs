@@ -11,6 +11,7 @@ pub mod peer;
 pub mod script_class;
 pub mod subnets;
 pub mod tx;
+pub mod wrpc;
 
 pub use address::*;
 pub use block::*;
@@ -24,3 +25,4 @@ pub use network::*;
 pub use peer::*;
 pub use subnets::*;
 pub use tx::*;
+pub use wrpc::*;
@@ -0,0 +1,23 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+
+/// Statistics of a connection to the wRPC server.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcWrpcConnectionInfo {
+    pub id: u64,
+    /// Socket address of the connected peer
+    pub peer: String,
+    /// Message encoding of the connection (`borsh` or `json`)
+    pub encoding: String,
+    /// Connection time (unix time in milliseconds)
+    pub connected_at: u64,
+    /// Number of RPC requests received from the peer
+    pub messages_in: u64,
+    /// Number of RPC responses and notifications sent to the peer
+    pub messages_out: u64,
+    /// Notification events the peer is subscribed to
+    pub subscriptions: Vec<String>,
+    /// Number of addresses the peer is tracking via `UtxosChanged` notifications
+    pub utxos_changed_addresses: u64,
+}
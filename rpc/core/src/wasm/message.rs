@@ -478,6 +478,55 @@ try_from! ( args: GetSyncStatusResponse, IGetSyncStatusResponse, {
     Ok(to_value(&args)?.into())
 });
 
+declare! {
+    IGetWrpcServerStatusRequest,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetWrpcServerStatusRequest { }
+    "#,
+}
+
+try_from! ( args: IGetWrpcServerStatusRequest, GetWrpcServerStatusRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetWrpcServerStatusResponse,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetWrpcServerStatusResponse {
+        connections : IWrpcConnectionInfo[];
+    }
+
+    /**
+     * Statistics of a connection to the wRPC server.
+     *
+     * @category Node RPC
+     */
+    export interface IWrpcConnectionInfo {
+        id : bigint;
+        peer : string;
+        encoding : string;
+        /**
+         * Connection time (unix time in milliseconds).
+         */
+        connectedAt : bigint;
+        messagesIn : bigint;
+        messagesOut : bigint;
+        subscriptions : string[];
+        utxosChangedAddresses : bigint;
+    }
+    "#,
+}
+
+try_from! ( args: GetWrpcServerStatusResponse, IGetWrpcServerStatusResponse, {
+    Ok(to_value(&args)?.into())
+});
+
 /*
     Interfaces for methods with arguments
 */
@@ -1347,6 +1396,133 @@ try_from! ( args: SubmitTransactionResponse, ISubmitTransactionResponse, {
 
 // ---
 
+declare! {
+    IValidateTransactionRequest,
+    r#"
+    /**
+     * Validate transaction against the node mempool acceptance
+     * rules without submitting it to the network.
+     * 
+     * @category Node RPC
+     */
+    export interface IValidateTransactionRequest {
+        transaction : Transaction,
+        allowOrphan? : boolean
+    }
+    "#,
+}
+
+try_from! ( args: IValidateTransactionRequest, ValidateTransactionRequest, {
+    let (transaction, allow_orphan) = if let Some(transaction) = args.try_get_value("transaction")? {
+        let allow_orphan = args.try_get_bool("allowOrphan")?.unwrap_or(false);
+        (transaction, allow_orphan)
+    } else {
+        (args.into(), false)
+    };
+
+    let request = if let Ok(transaction) = Transaction::try_owned_from(&transaction) {
+        ValidateTransactionRequest {
+            transaction : transaction.into(),
+            allow_orphan,
+        }
+    } else {
+        from_value(transaction)?
+    };
+    Ok(request)
+});
+
+declare! {
+    IValidateTransactionResponse,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IValidateTransactionResponse {
+        transactionId : HexString;
+        fee : bigint;
+        computeMass : bigint;
+    }
+    "#,
+}
+
+try_from! ( args: ValidateTransactionResponse, IValidateTransactionResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IGetUtxosByAddressesPageRequest,
+    r#"
+    /**
+     * Request a page of UTXOs for the given addresses. The first page
+     * is requested without `startAfter`, subsequent pages with the
+     * `next` cursor returned in the previous response.
+     * 
+     * @category Node RPC
+     */
+    export interface IGetUtxosByAddressesPageRequest {
+        addresses : Address[] | string[];
+        startAfter? : IUtxosByAddressesCursor;
+        /**
+         * Maximum number of entries to return (0 or omitted for the node maximum).
+         */
+        limit? : number;
+    }
+    "#,
+}
+
+try_from! ( args: IGetUtxosByAddressesPageRequest, GetUtxosByAddressesPageRequest, {
+    let addresses = Vec::<Address>::try_from(AddressOrStringArrayT::from(args.get_value("addresses")?))?;
+    let start_after = args.try_get_value("startAfter")?.map(from_value).transpose()?;
+    let limit = if args.try_get_value("limit")?.is_some() { args.get_u32("limit")? } else { 0 };
+    Ok(GetUtxosByAddressesPageRequest { addresses, start_after, limit })
+});
+
+declare! {
+    IGetUtxosByAddressesPageResponse,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IGetUtxosByAddressesPageResponse {
+        entries : IUtxoEntry[];
+        /**
+         * Cursor of the next page, `undefined` once all entries have been returned.
+         */
+        next? : IUtxosByAddressesCursor;
+    }
+
+    /**
+     * Position of a UTXO entry within a paginated UTXO query.
+     * 
+     * @category Node RPC
+     */
+    export interface IUtxosByAddressesCursor {
+        address : string;
+        outpoint : ITransactionOutpoint;
+    }
+    "#,
+}
+
+try_from! ( args: GetUtxosByAddressesPageResponse, IGetUtxosByAddressesPageResponse, {
+    let GetUtxosByAddressesPageResponse { entries, next } = args;
+    let entries = entries.into_iter().map(UtxoEntryReference::from).collect::<Vec<UtxoEntryReference>>();
+    let entries = js_sys::Array::from_iter(entries.into_iter().map(JsValue::from));
+    let response = IGetUtxosByAddressesPageResponse::default();
+    response.set("entries", entries.as_ref())?;
+    if let Some(next) = next {
+        response.set("next", &to_value(&next)?)?;
+    }
+    Ok(response)
+});
+
+// ---
+
 declare! {
     IUnbanRequest,
     r#"
@@ -271,6 +271,12 @@ impl RpcApi for GrpcClient {
     route!(get_mempool_entries_by_addresses_call, GetMempoolEntriesByAddresses);
     route!(get_coin_supply_call, GetCoinSupply);
     route!(get_daa_score_timestamp_estimate_call, GetDaaScoreTimestampEstimate);
+    route!(validate_transaction_call, ValidateTransaction);
+    route!(get_utxos_by_addresses_page_call, GetUtxosByAddressesPage);
+
+    async fn get_wrpc_server_status_call(&self, _request: GetWrpcServerStatusRequest) -> RpcResult<GetWrpcServerStatusResponse> {
+        Err(RpcError::WrpcServerOnly)
+    }
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Notification API
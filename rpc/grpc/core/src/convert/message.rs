@@ -394,6 +394,25 @@ from!(item: RpcResult<&kaspa_rpc_core::GetDaaScoreTimestampEstimateResponse>, pr
     Self { timestamps: item.timestamps.clone(), error: None }
 });
 
+from!(item: &kaspa_rpc_core::ValidateTransactionRequest, protowire::ValidateTransactionRequestMessage, {
+    Self { transaction: Some((&item.transaction).into()), allow_orphan: item.allow_orphan }
+});
+from!(item: RpcResult<&kaspa_rpc_core::ValidateTransactionResponse>, protowire::ValidateTransactionResponseMessage, {
+    Self { transaction_id: item.transaction_id.to_string(), fee: item.fee, compute_mass: item.compute_mass, error: None }
+});
+
+from!(item: &kaspa_rpc_core::GetUtxosByAddressesPageRequest, protowire::GetUtxosByAddressesPageRequestMessage, {
+    Self {
+        addresses: item.addresses.iter().map(|x| x.into()).collect(),
+        start_after: item.start_after.as_ref().map(|x| x.into()),
+        limit: item.limit,
+    }
+});
+from!(item: RpcResult<&kaspa_rpc_core::GetUtxosByAddressesPageResponse>, protowire::GetUtxosByAddressesPageResponseMessage, {
+    debug!("GRPC, Creating GetUtxosByAddressesPage message with {} entries", item.entries.len());
+    Self { entries: item.entries.iter().map(|x| x.into()).collect(), next: item.next.as_ref().map(|x| x.into()), error: None }
+});
+
 from!(&kaspa_rpc_core::PingRequest, protowire::PingRequestMessage);
 from!(RpcResult<&kaspa_rpc_core::PingResponse>, protowire::PingResponseMessage);
 
@@ -791,6 +810,34 @@ try_from!(item: &protowire::GetDaaScoreTimestampEstimateResponseMessage, RpcResu
     Self { timestamps: item.timestamps.clone() }
 });
 
+try_from!(item: &protowire::ValidateTransactionRequestMessage, kaspa_rpc_core::ValidateTransactionRequest, {
+    Self {
+        transaction: item
+            .transaction
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("ValidateTransactionRequestMessage".to_string(), "transaction".to_string()))?
+            .try_into()?,
+        allow_orphan: item.allow_orphan,
+    }
+});
+try_from!(item: &protowire::ValidateTransactionResponseMessage, RpcResult<kaspa_rpc_core::ValidateTransactionResponse>, {
+    Self { transaction_id: RpcHash::from_str(&item.transaction_id)?, fee: item.fee, compute_mass: item.compute_mass }
+});
+
+try_from!(item: &protowire::GetUtxosByAddressesPageRequestMessage, kaspa_rpc_core::GetUtxosByAddressesPageRequest, {
+    Self {
+        addresses: item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()?,
+        start_after: item.start_after.as_ref().map(|x| x.try_into()).transpose()?,
+        limit: item.limit,
+    }
+});
+try_from!(item: &protowire::GetUtxosByAddressesPageResponseMessage, RpcResult<kaspa_rpc_core::GetUtxosByAddressesPageResponse>, {
+    Self {
+        entries: item.entries.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+        next: item.next.as_ref().map(|x| x.try_into()).transpose()?,
+    }
+});
+
 try_from!(&protowire::PingRequestMessage, kaspa_rpc_core::PingRequest);
 try_from!(&protowire::PingResponseMessage, RpcResult<kaspa_rpc_core::PingResponse>);
 
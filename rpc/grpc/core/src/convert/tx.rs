@@ -90,6 +90,10 @@ from!(item: &kaspa_rpc_core::RpcUtxosByAddressesEntry, protowire::RpcUtxosByAddr
     }
 });
 
+from!(item: &kaspa_rpc_core::RpcUtxosByAddressesCursor, protowire::RpcUtxosByAddressesCursor, {
+    Self { address: (&item.address).into(), outpoint: Some((&item.outpoint).into()) }
+});
+
 // ----------------------------------------------------------------------------
 // protowire to rpc_core
 // ----------------------------------------------------------------------------
@@ -205,3 +209,14 @@ try_from!(item: &protowire::RpcUtxosByAddressesEntry, kaspa_rpc_core::RpcUtxosBy
             .try_into()?,
     }
 });
+
+try_from!(item: &protowire::RpcUtxosByAddressesCursor, kaspa_rpc_core::RpcUtxosByAddressesCursor, {
+    Self {
+        address: item.address.as_str().try_into()?,
+        outpoint: item
+            .outpoint
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("UtxosByAddressesCursor".to_string(), "outpoint".to_string()))?
+            .try_into()?,
+    }
+});
@@ -81,6 +81,8 @@ pub enum KaspadPayloadOps {
     GetServerInfo,
     GetSyncStatus,
     GetDaaScoreTimestampEstimate,
+    ValidateTransaction,
+    GetUtxosByAddressesPage,
 
     // Subscription commands for starting/stopping notifications
     NotifyBlockAdded,
@@ -228,6 +228,21 @@ impl RpcApi for RpcCoreMock {
         Err(RpcError::NotImplemented)
     }
 
+    async fn validate_transaction_call(&self, _request: ValidateTransactionRequest) -> RpcResult<ValidateTransactionResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_utxos_by_addresses_page_call(
+        &self,
+        _request: GetUtxosByAddressesPageRequest,
+    ) -> RpcResult<GetUtxosByAddressesPageResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_wrpc_server_status_call(&self, _request: GetWrpcServerStatusRequest) -> RpcResult<GetWrpcServerStatusResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Notification API
 
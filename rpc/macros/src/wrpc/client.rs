@@ -44,7 +44,7 @@ impl ToTokens for RpcTable {
             let Handler { fn_call, request_type, response_type, .. } = Handler::new(handler);
 
             // async fn #fn_call(&self, request : #request_type) -> RpcResult<#response_type> {
-            //     let response: ClientResult<#response_type> = self.inner.rpc.call(#rpc_api_ops::#handler, request).await;
+            //     let response: ClientResult<#response_type> = self.inner.call(#rpc_api_ops::#handler, request).await;
             //     Ok(response.map_err(|e| e.to_string())?)
             // }
 
@@ -67,7 +67,7 @@ impl ToTokens for RpcTable {
                         let __self = self;
                         //let request = request;
                         let __ret: RpcResult<#response_type> = {
-                            let resp: ClientResult<#response_type> = __self.inner.rpc_client.call(#rpc_api_ops::#handler, request).await;
+                            let resp: ClientResult<#response_type> = __self.inner.call(#rpc_api_ops::#handler, request).await;
                             Ok(resp.map_err(|e| kaspa_rpc_core::error::RpcError::RpcSubsystem(e.to_string()))?)
                         };
                         #[allow(unreachable_code)]
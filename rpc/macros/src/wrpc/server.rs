@@ -51,10 +51,12 @@ impl ToTokens for RpcTable {
             targets.push(quote! {
                 #rpc_api_ops::#handler => {
                     interface.method(#rpc_api_ops::#handler, method!(|server_ctx: #server_ctx_type, connection_ctx: #connection_ctx_type, request: #request_type| async move {
+                        server_ctx.check_request_quota(&connection_ctx).map_err(|e|ServerError::Text(e.to_string()))?;
                         let verbose = server_ctx.verbose();
                         if verbose { workflow_log::log_info!("request: {:?}",request); }
-                        let response: #response_type = server_ctx.rpc_service(&connection_ctx).#fn_call(request).await
-                            .map_err(|e|ServerError::Text(e.to_string()))?;
+                        let response = server_ctx.rpc_service(&connection_ctx).#fn_call(request).await;
+                        connection_ctx.record_message_out();
+                        let response: #response_type = response.map_err(|e|ServerError::Text(e.to_string()))?;
                         if verbose { workflow_log::log_info!("response: {:?}",response); }
                         Ok(response)
                     }));
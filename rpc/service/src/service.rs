@@ -226,6 +226,11 @@ impl RpcCoreService {
         self.notifier.clone()
     }
 
+    /// Returns `true` if the node runs in the unsafe RPC mode (`--unsaferpc`).
+    pub fn is_unsafe_rpc(&self) -> bool {
+        self.config.unsafe_rpc
+    }
+
     #[inline(always)]
     pub fn subscription_context(&self) -> SubscriptionContext {
         self.notifier.subscription_context().clone()
@@ -551,6 +556,49 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         Ok(GetUtxosByAddressesResponse::new(self.index_converter.get_utxos_by_addresses_entries(&entry_map)))
     }
 
+    async fn get_utxos_by_addresses_page_call(
+        &self,
+        request: GetUtxosByAddressesPageRequest,
+    ) -> RpcResult<GetUtxosByAddressesPageResponse> {
+        if !self.config.utxoindex {
+            return Err(RpcError::NoUtxoIndex);
+        }
+        let limit = match request.limit {
+            0 => MAX_UTXOS_BY_ADDRESSES_PAGE_SIZE,
+            limit => limit.min(MAX_UTXOS_BY_ADDRESSES_PAGE_SIZE),
+        };
+        if let Some(cursor) = request.start_after.as_ref() {
+            if !request.addresses.contains(&cursor.address) {
+                return Err(RpcError::InvalidUtxosByAddressesCursor(cursor.address.clone()));
+            }
+        }
+
+        // Duplicate addresses would restart the traversal, so only their first occurrence is kept
+        let mut addresses = HashMap::with_capacity(request.addresses.len());
+        let script_public_keys = request
+            .addresses
+            .iter()
+            .filter_map(|address| {
+                let script_public_key = pay_to_address_script(address);
+                addresses.insert(script_public_key.clone(), address).is_none().then_some(script_public_key)
+            })
+            .collect::<Vec<_>>();
+        let start_after = request.start_after.as_ref().map(|cursor| (pay_to_address_script(&cursor.address), cursor.outpoint));
+
+        let (entry_map, next) = self
+            .utxoindex
+            .clone()
+            .unwrap()
+            .get_utxos_page_by_script_public_keys(script_public_keys, start_after, limit as usize)
+            .await
+            .map_err(|err| RpcError::General(err.to_string()))?;
+        let next = next.map(|(script_public_key, outpoint)| RpcUtxosByAddressesCursor {
+            address: (*addresses.get(&script_public_key).expect("cursor script public key is part of the query")).clone(),
+            outpoint,
+        });
+        Ok(GetUtxosByAddressesPageResponse::new(self.index_converter.get_utxos_by_addresses_entries(&entry_map), next))
+    }
+
     async fn get_balance_by_address_call(&self, request: GetBalanceByAddressRequest) -> RpcResult<GetBalanceByAddressResponse> {
         if !self.config.utxoindex {
             return Err(RpcError::NoUtxoIndex);
@@ -647,6 +695,36 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         Ok(GetDaaScoreTimestampEstimateResponse::new(timestamps))
     }
 
+    async fn validate_transaction_call(&self, request: ValidateTransactionRequest) -> RpcResult<ValidateTransactionResponse> {
+        let allow_orphan = self.config.unsafe_rpc && request.allow_orphan;
+        if !self.config.unsafe_rpc && request.allow_orphan {
+            warn!("ValidateTransaction RPC command called with AllowOrphan enabled while node in safe RPC mode -- switching to ForbidOrphan.");
+        }
+
+        let transaction: Transaction = (&request.transaction).try_into()?;
+        let transaction_id = transaction.id();
+        let session = self.consensus_manager.consensus().unguarded_session();
+        let orphan = match allow_orphan {
+            true => Orphan::Allowed,
+            false => Orphan::Forbidden,
+        };
+        let transaction = self.mining_manager.clone().validate_transaction(&session, transaction, orphan).await.map_err(|err| {
+            let err = RpcError::RejectedTransaction(transaction_id, err.to_string());
+            debug!("{err}");
+            err
+        })?;
+        Ok(ValidateTransactionResponse::new(
+            transaction_id,
+            transaction.calculated_fee.unwrap_or_default(),
+            transaction.calculated_compute_mass.unwrap_or_default(),
+        ))
+    }
+
+    async fn get_wrpc_server_status_call(&self, _: GetWrpcServerStatusRequest) -> RpcResult<GetWrpcServerStatusResponse> {
+        // Connection statistics are maintained and reported by the wRPC server
+        Err(RpcError::WrpcServerOnly)
+    }
+
     async fn ping_call(&self, _: PingRequest) -> RpcResult<PingResponse> {
         Ok(PingResponse {})
     }
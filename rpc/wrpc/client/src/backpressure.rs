@@ -0,0 +1,218 @@
+//!
+//! Bounded notification channel and overflow policies.
+//!
+//! Notifications received from the wRPC server are buffered in the client
+//! notification channel until consumed by the client notifier or by a direct
+//! consumer of [`KaspaRpcClient::notification_channel_receiver()`]. The channel
+//! is unbounded by default and grows without bound if the consumer stalls.
+//! [`NotificationBackpressure`] bounds the channel and selects the
+//! [`NotificationOverflowPolicy`] applied once the channel is full.
+//! Notifications discarded by the policy are counted by the client
+//! [`RpcCtl`](kaspa_rpc_core::api::ctl::RpcCtl).
+//!
+
+use crate::error::Error;
+use crate::imports::*;
+use kaspa_notify::events::EventType;
+use kaspa_notify::notification::Notification as _;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use workflow_core::channel::TrySendError;
+
+/// Handling of incoming notifications when the notification channel is full.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NotificationOverflowPolicy {
+    /// Drop the oldest buffered notification.
+    #[default]
+    DropOldest,
+    /// Coalesce buffered notifications carrying the latest state of the node
+    /// (`SinkBlueScoreChanged`, `VirtualDaaScoreChanged`, `NewBlockTemplate`
+    /// and `PruningPointUtxoSetOverride`) keeping only the most recent
+    /// notification of each type. If coalescing does not free up space,
+    /// the oldest notification is dropped.
+    Coalesce,
+    /// Drop the notification and disconnect from the server, re-establishing
+    /// the connection (and the subscriptions) so that the consumer can re-sync.
+    Disconnect,
+}
+
+impl NotificationOverflowPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::DropOldest => "drop-oldest",
+            Self::Coalesce => "coalesce",
+            Self::Disconnect => "disconnect",
+        }
+    }
+}
+
+impl Display for NotificationOverflowPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for NotificationOverflowPolicy {
+    type Err = Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "drop-oldest" => Ok(Self::DropOldest),
+            "coalesce" => Ok(Self::Coalesce),
+            "disconnect" => Ok(Self::Disconnect),
+            _ => {
+                Err(Error::custom(format!("invalid notification overflow policy: '{s}' (expecting drop-oldest|coalesce|disconnect)")))
+            }
+        }
+    }
+}
+
+/// Capacity of the client notification channel and the policy applied
+/// when the channel is full (see [`KaspaRpcClient::set_notification_backpressure()`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationBackpressure {
+    pub capacity: usize,
+    pub policy: NotificationOverflowPolicy,
+}
+
+impl NotificationBackpressure {
+    pub fn new(capacity: usize, policy: NotificationOverflowPolicy) -> Self {
+        Self { capacity, policy }
+    }
+}
+
+/// Outcome of the notification delivery to a full channel.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct Overflow {
+    /// Notifications discarded to make room for the incoming notification
+    /// (or the incoming notification itself).
+    pub dropped: u64,
+    /// Notifications superseded by a more recent notification of the same type.
+    pub coalesced: u64,
+    /// The consumer stalled and the client should disconnect.
+    pub disconnect: bool,
+}
+
+/// Post the notification to the channel applying the overflow `policy` if the channel is full.
+pub(crate) fn deliver(
+    channel: &Channel<Notification>,
+    notification: Notification,
+    policy: NotificationOverflowPolicy,
+) -> std::result::Result<Overflow, TrySendError<Notification>> {
+    let notification = match channel.sender.try_send(notification) {
+        Ok(()) => return Ok(Overflow::default()),
+        Err(TrySendError::Full(notification)) => notification,
+        Err(err) => return Err(err),
+    };
+
+    let mut overflow = Overflow::default();
+    match policy {
+        NotificationOverflowPolicy::DropOldest => {
+            if channel.receiver.try_recv().is_ok() {
+                overflow.dropped += 1;
+            }
+        }
+        NotificationOverflowPolicy::Coalesce => {
+            let mut pending = std::iter::from_fn(|| channel.receiver.try_recv().ok()).collect::<Vec<_>>();
+            let len = pending.len();
+            let event_type = notification.event_type();
+            if is_coalescable(event_type) {
+                pending.retain(|pending| pending.event_type() != event_type);
+            }
+            let mut retained = Vec::with_capacity(pending.len());
+            for pending in pending.into_iter().rev() {
+                let event_type = pending.event_type();
+                if !is_coalescable(event_type) || !retained.iter().any(|retained: &Notification| retained.event_type() == event_type) {
+                    retained.push(pending);
+                }
+            }
+            retained.reverse();
+            overflow.coalesced = (len - retained.len()) as u64;
+            if overflow.coalesced == 0 && !retained.is_empty() {
+                retained.remove(0);
+                overflow.dropped += 1;
+            }
+            for pending in retained {
+                if channel.sender.try_send(pending).is_err() {
+                    overflow.dropped += 1;
+                }
+            }
+        }
+        NotificationOverflowPolicy::Disconnect => {
+            overflow.dropped += 1;
+            overflow.disconnect = true;
+            return Ok(overflow);
+        }
+    }
+
+    if channel.sender.try_send(notification).is_err() {
+        overflow.dropped += 1;
+    }
+    Ok(overflow)
+}
+
+/// Notifications superseded by more recent notifications of the same type.
+fn is_coalescable(event_type: EventType) -> bool {
+    matches!(
+        event_type,
+        EventType::SinkBlueScoreChanged
+            | EventType::VirtualDaaScoreChanged
+            | EventType::NewBlockTemplate
+            | EventType::PruningPointUtxoSetOverride
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn daa_score(virtual_daa_score: u64) -> Notification {
+        Notification::VirtualDaaScoreChanged(VirtualDaaScoreChangedNotification { virtual_daa_score })
+    }
+
+    fn blue_score(sink_blue_score: u64) -> Notification {
+        Notification::SinkBlueScoreChanged(SinkBlueScoreChangedNotification { sink_blue_score })
+    }
+
+    fn drain(channel: &Channel<Notification>) -> Vec<String> {
+        std::iter::from_fn(|| channel.receiver.try_recv().ok()).map(|notification| notification.to_string()).collect()
+    }
+
+    #[test]
+    fn test_notification_overflow_policy() {
+        let channel = Channel::bounded(3);
+        for score in 0..5 {
+            deliver(&channel, daa_score(score), NotificationOverflowPolicy::DropOldest).unwrap();
+        }
+        assert_eq!(drain(&channel), vec![daa_score(2).to_string(), daa_score(3).to_string(), daa_score(4).to_string()]);
+
+        // the latest notifications of each type are retained, other notifications are not coalesced
+        let channel = Channel::bounded(3);
+        deliver(&channel, daa_score(1), NotificationOverflowPolicy::Coalesce).unwrap();
+        deliver(&channel, blue_score(1), NotificationOverflowPolicy::Coalesce).unwrap();
+        deliver(&channel, daa_score(2), NotificationOverflowPolicy::Coalesce).unwrap();
+        let overflow = deliver(&channel, blue_score(2), NotificationOverflowPolicy::Coalesce).unwrap();
+        assert_eq!(overflow, Overflow { dropped: 0, coalesced: 2, disconnect: false });
+        assert_eq!(drain(&channel), vec![daa_score(2).to_string(), blue_score(2).to_string()]);
+
+        let channel = Channel::bounded(2);
+        let block_template = || Notification::NewBlockTemplate(NewBlockTemplateNotification {});
+        let conflict =
+            || Notification::FinalityConflictResolved(FinalityConflictResolvedNotification { finality_block_hash: 1.into() });
+        deliver(&channel, conflict(), NotificationOverflowPolicy::Coalesce).unwrap();
+        deliver(&channel, daa_score(1), NotificationOverflowPolicy::Coalesce).unwrap();
+        let overflow = deliver(&channel, block_template(), NotificationOverflowPolicy::Coalesce).unwrap();
+        assert_eq!(overflow, Overflow { dropped: 1, coalesced: 0, disconnect: false });
+        assert_eq!(drain(&channel), vec![daa_score(1).to_string(), block_template().to_string()]);
+
+        let channel = Channel::bounded(1);
+        deliver(&channel, daa_score(1), NotificationOverflowPolicy::Disconnect).unwrap();
+        let overflow = deliver(&channel, daa_score(2), NotificationOverflowPolicy::Disconnect).unwrap();
+        assert_eq!(overflow, Overflow { dropped: 1, coalesced: 0, disconnect: true });
+        assert_eq!(drain(&channel), vec![daa_score(1).to_string()]);
+
+        assert_eq!("coalesce".parse::<NotificationOverflowPolicy>().unwrap(), NotificationOverflowPolicy::Coalesce);
+        assert!("drop".parse::<NotificationOverflowPolicy>().is_err());
+    }
+}
@@ -0,0 +1,163 @@
+//!
+//! Node latency benchmark.
+//!
+//! Measures the round-trip latency of `ping()` and `get_info()` calls
+//! and obtains the sync status of a list of candidate wRPC endpoints
+//! (or of the nodes provided by the [`Resolver`]), ranking them by
+//! latency and sync status.
+//!
+
+use crate::error::Error;
+use crate::imports::*;
+use crate::node::UNSYNCED_PENALTY_MSEC;
+use crate::resolver::join_all;
+use std::time::Duration;
+use workflow_core::time::Instant;
+
+/// Default benchmark connection timeout.
+pub const BENCHMARK_TIMEOUT: Duration = Duration::from_secs(5);
+
+///
+/// Benchmark result of a single wRPC endpoint.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeBenchmark {
+    /// wRPC URL of the node.
+    pub url: String,
+    /// Round-trip latency of the `ping()` call in milliseconds.
+    pub ping: Option<u64>,
+    /// Round-trip latency of the `get_info()` call in milliseconds.
+    pub get_info: Option<u64>,
+    /// Node sync status.
+    pub is_synced: Option<bool>,
+    /// Node software version.
+    pub server_version: Option<String>,
+    /// Error that occurred while benchmarking the node (if any).
+    pub error: Option<String>,
+}
+
+impl NodeBenchmark {
+    fn new(url: String) -> Self {
+        Self { url, ping: None, get_info: None, is_synced: None, server_version: None, error: None }
+    }
+
+    /// Returns `true` if the node responded to all benchmark calls.
+    pub fn is_reachable(&self) -> bool {
+        self.error.is_none() && self.ping.is_some() && self.get_info.is_some()
+    }
+
+    /// Node score; lower is better. Unreachable nodes
+    /// are always ranked last.
+    pub fn score(&self) -> u64 {
+        match (self.ping, self.get_info) {
+            (Some(ping), Some(get_info)) if self.error.is_none() => {
+                let unsynced_penalty = if self.is_synced.unwrap_or(false) { 0 } else { UNSYNCED_PENALTY_MSEC };
+                ping.saturating_add(get_info).saturating_add(unsynced_penalty)
+            }
+            _ => u64::MAX,
+        }
+    }
+}
+
+/// Connect to the node and measure the round-trip latency of the
+/// `ping()` and `get_info()` calls. The node is expected to belong
+/// to the network identified by `network_id`.
+pub async fn benchmark_node(url: &str, encoding: WrpcEncoding, network_id: NetworkId, timeout: Duration) -> NodeBenchmark {
+    let mut benchmark = NodeBenchmark::new(url.to_string());
+
+    let result = async {
+        let url = KaspaRpcClient::parse_url(url.to_string(), encoding, network_id.into())?;
+        let client = KaspaRpcClient::new(encoding, Some(url.as_str()), None, Some(network_id), None)?;
+        let options = ConnectOptions {
+            block_async_connect: true,
+            strategy: ConnectStrategy::Fallback,
+            connect_timeout: Some(timeout),
+            ..Default::default()
+        };
+        client.connect(Some(options)).await?;
+
+        let result = async {
+            let ts = Instant::now();
+            client.ping().await?;
+            benchmark.ping = Some(ts.elapsed().as_millis() as u64);
+
+            let ts = Instant::now();
+            let info = client.get_info().await?;
+            benchmark.get_info = Some(ts.elapsed().as_millis() as u64);
+            benchmark.is_synced = Some(info.is_synced);
+            benchmark.server_version = Some(info.server_version);
+
+            let server_info = client.get_server_info().await?;
+            if server_info.network_id != network_id {
+                return Err(Error::custom(format!("network id mismatch: {}", server_info.network_id)));
+            }
+            Ok::<_, Error>(())
+        }
+        .await;
+
+        client.disconnect().await?;
+        result
+    }
+    .await;
+
+    if let Err(err) = result {
+        benchmark.error = Some(err.to_string());
+    }
+
+    benchmark
+}
+
+/// Benchmark the supplied wRPC endpoints concurrently. Returns the
+/// results ranked by latency and sync status (best node first).
+pub async fn benchmark(
+    urls: &[String],
+    encoding: WrpcEncoding,
+    network_id: NetworkId,
+    timeout: Option<Duration>,
+) -> Vec<NodeBenchmark> {
+    let timeout = timeout.unwrap_or(BENCHMARK_TIMEOUT);
+    let mut results = join_all(urls.iter().map(|url| benchmark_node(url, encoding, network_id, timeout))).await;
+    results.sort_by_key(|benchmark| benchmark.score());
+    results
+}
+
+impl Resolver {
+    /// Fetch all nodes available via the resolver and benchmark them
+    /// (see [`benchmark()`]).
+    pub async fn benchmark(
+        &self,
+        encoding: WrpcEncoding,
+        network_id: NetworkId,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<NodeBenchmark>> {
+        let urls = self.fetch_all(encoding, network_id).await?.into_iter().map(|node| node.url).collect::<Vec<_>>();
+        Ok(benchmark(&urls, encoding, network_id, timeout).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_benchmark_score() {
+        let result = |url: &str, latency: Option<(u64, u64)>, is_synced: bool| NodeBenchmark {
+            ping: latency.map(|(ping, _)| ping),
+            get_info: latency.map(|(_, get_info)| get_info),
+            is_synced: latency.map(|_| is_synced),
+            ..NodeBenchmark::new(url.to_string())
+        };
+
+        let mut results = [
+            result("unreachable", None, false),
+            result("unsynced", Some((10, 10)), false),
+            result("slow", Some((200, 300)), true),
+            result("fast", Some((20, 30)), true),
+        ];
+        results.sort_by_key(|benchmark| benchmark.score());
+        let urls = results.iter().map(|benchmark| benchmark.url.as_str()).collect::<Vec<_>>();
+        assert_eq!(urls, ["fast", "slow", "unsynced", "unreachable"]);
+        assert!(!results[3].is_reachable());
+    }
+}
@@ -0,0 +1,147 @@
+//!
+//! Node capability detection.
+//!
+//! Nodes running older (or newer) software versions may not implement
+//! all RPC methods relied upon by the client. [`NodeCapabilities::probe()`]
+//! determines which optional methods are available on the connected node,
+//! allowing dependent features to be disabled and reported with a typed
+//! [`Error::NotSupportedByNode`] instead of opaque RPC call failures.
+//!
+
+use crate::error::Error;
+use crate::imports::*;
+use crate::result::Result;
+use kaspa_consensus_core::subnets::SUBNETWORK_ID_NATIVE;
+use kaspa_rpc_core::RpcError;
+use std::collections::HashSet;
+use workflow_rpc::error::ServerError;
+
+/// Optional node features (RPC methods) that may be
+/// unavailable on nodes running a different software version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NodeFeature {
+    /// Paginated UTXO queries (`GetUtxosByAddressesPage`)
+    UtxosByAddressesPage,
+    /// Dry-run transaction validation (`ValidateTransaction`)
+    ValidateTransaction,
+    /// DAA score to unix time conversion (`GetDaaScoreTimestampEstimate`)
+    DaaScoreTimestampEstimate,
+}
+
+impl NodeFeature {
+    pub const ALL: [NodeFeature; 3] =
+        [NodeFeature::UtxosByAddressesPage, NodeFeature::ValidateTransaction, NodeFeature::DaaScoreTimestampEstimate];
+
+    /// RPC method providing the feature.
+    pub fn op(&self) -> RpcApiOps {
+        match self {
+            NodeFeature::UtxosByAddressesPage => RpcApiOps::GetUtxosByAddressesPage,
+            NodeFeature::ValidateTransaction => RpcApiOps::ValidateTransaction,
+            NodeFeature::DaaScoreTimestampEstimate => RpcApiOps::GetDaaScoreTimestampEstimate,
+        }
+    }
+
+    /// Issue a lightweight call to the RPC method providing the feature.
+    async fn probe(&self, rpc: &dyn RpcApi) -> RpcResult<()> {
+        match self {
+            NodeFeature::UtxosByAddressesPage => {
+                rpc.get_utxos_by_addresses_page(vec![], None, 1).await?;
+            }
+            NodeFeature::ValidateTransaction => {
+                // the node is expected to reject the empty transaction
+                let transaction = RpcTransaction {
+                    version: 0,
+                    inputs: vec![],
+                    outputs: vec![],
+                    lock_time: 0,
+                    subnetwork_id: SUBNETWORK_ID_NATIVE,
+                    gas: 0,
+                    payload: vec![],
+                    mass: 0,
+                    verbose_data: None,
+                };
+                rpc.validate_transaction(transaction, false).await?;
+            }
+            NodeFeature::DaaScoreTimestampEstimate => {
+                rpc.get_daa_score_timestamp_estimate(vec![]).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for NodeFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.op())
+    }
+}
+
+/// Returns `true` if the error indicates that the node does not
+/// implement the called RPC method (or does not recognize its request).
+pub fn is_not_supported_error(err: &RpcError) -> bool {
+    match err {
+        RpcError::NotImplemented | RpcError::UnsupportedFeature => true,
+        RpcError::RpcSubsystem(message) => [ServerError::NotFound, ServerError::ReqDeserialize]
+            .iter()
+            .any(|server_error| message.contains(&server_error.to_string()) || message.ends_with(&format!("{server_error:?}"))),
+        _ => false,
+    }
+}
+
+/// Optional features available on the connected node.
+#[derive(Debug, Clone, Default)]
+pub struct NodeCapabilities {
+    unsupported: HashSet<NodeFeature>,
+}
+
+impl NodeCapabilities {
+    /// Probe the node for each of the optional [`NodeFeature`]s. A feature
+    /// is considered unavailable only if the node reports the method as
+    /// unknown; any other error (such as a missing UTXO index or a rejected
+    /// probe request) indicates that the method is implemented.
+    pub async fn probe(rpc: &dyn RpcApi) -> NodeCapabilities {
+        let mut unsupported = HashSet::new();
+        for feature in NodeFeature::ALL {
+            if let Err(err) = feature.probe(rpc).await {
+                if is_not_supported_error(&err) {
+                    unsupported.insert(feature);
+                }
+            }
+        }
+
+        NodeCapabilities { unsupported }
+    }
+
+    pub fn supports(&self, feature: NodeFeature) -> bool {
+        !self.unsupported.contains(&feature)
+    }
+
+    /// Features not available on the node.
+    pub fn unsupported(&self) -> Vec<NodeFeature> {
+        NodeFeature::ALL.into_iter().filter(|feature| self.unsupported.contains(feature)).collect()
+    }
+
+    /// Returns [`Error::NotSupportedByNode`] if the feature is not available on the node.
+    pub fn check(&self, feature: NodeFeature) -> Result<()> {
+        if self.supports(feature) {
+            Ok(())
+        } else {
+            Err(Error::NotSupportedByNode(feature))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_supported_error() {
+        assert!(is_not_supported_error(&RpcError::NotImplemented));
+        assert!(is_not_supported_error(&RpcError::RpcSubsystem(ServerError::NotFound.to_string())));
+        assert!(is_not_supported_error(&RpcError::RpcSubsystem(format!("RPC response error {:?}", ServerError::NotFound))));
+        assert!(!is_not_supported_error(&RpcError::NoUtxoIndex));
+        assert!(!is_not_supported_error(&RpcError::RpcSubsystem(ServerError::Timeout.to_string())));
+    }
+}
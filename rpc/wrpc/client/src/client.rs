@@ -1,5 +1,11 @@
+use crate::backpressure::{deliver, NotificationBackpressure};
 use crate::imports::*;
 use crate::parse::parse_host;
+use crate::proxy::ProxyConfig;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::proxy::ProxyRelay;
+use crate::subscriptions::SubscriptionRegistry;
+use crate::transport::{RpcTransport, RpcTransportSink};
 use crate::{error::Error, node::NodeDescriptor};
 use kaspa_consensus_core::network::NetworkType;
 use kaspa_notify::{
@@ -11,20 +17,77 @@ use kaspa_rpc_core::{
     notify::collector::{RpcCoreCollector, RpcCoreConverter},
 };
 pub use kaspa_rpc_macros::build_wrpc_client_interface;
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, AtomicUsize};
+use std::time::Duration;
+use workflow_core::time::Instant;
 use workflow_core::{channel::Multiplexer, runtime as application_runtime};
 use workflow_dom::utils::window;
 use workflow_rpc::client::Ctl as WrpcCtl;
 pub use workflow_rpc::client::{
     ConnectOptions, ConnectResult, ConnectStrategy, Resolver as RpcResolver, ResolverResult, WebSocketConfig, WebSocketError,
 };
+use workflow_rpc::types::MsgT;
 
 type RpcClientNotifier = Arc<Notifier<Notification, ChannelConnection>>;
 
+/// Default number of consecutive connection failures after
+/// which the client switches to the next-best node.
+pub const DEFAULT_FAILOVER_THRESHOLD: usize = 3;
+/// Connections lasting longer than this period are considered
+/// stable and reset the consecutive failure counter.
+const STABLE_CONNECTION_PERIOD: Duration = Duration::from_secs(60);
+/// Delay before retrying a failed subscription replay, doubled
+/// after each failed attempt (up to the maximum delay).
+const SUBSCRIPTIONS_RESTORE_RETRY_DELAY: Duration = Duration::from_secs(1);
+const SUBSCRIPTIONS_RESTORE_MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Events emitted by the client node failover subsystem.
+/// Use [`KaspaRpcClient::failover_multiplexer()`] to receive these events.
+#[derive(Clone, Debug)]
+pub enum FailoverEvent {
+    /// Connection to the node failed repeatedly and the node has been demoted in the resolver ranking.
+    NodeFailure { node: Arc<NodeDescriptor>, failures: u32 },
+    /// The client has switched to a different node.
+    NodeSwitched { from: Option<Arc<NodeDescriptor>>, to: Arc<NodeDescriptor> },
+}
+
+#[derive(Default)]
+struct FailoverState {
+    // consecutive connection failures of the current node
+    failures: usize,
+    // time of the last successful connection
+    connected_at: Option<Instant>,
+    // url has been resolved, but the connection has not been established yet
+    awaiting_connect: bool,
+    // the failure threshold has been reached, switch to the next node
+    switch_pending: bool,
+}
+
+/// State of a single [`KaspaRpcClient`] handle (see [`KaspaRpcClient::handle()`]).
+/// Clones of a client share the handle state, handles created from the
+/// same client share the underlying connection.
+#[derive(Default)]
+struct HandleState {
+    // listeners registered via this handle, mapping the handle-local
+    // listener ids to the ids of the shared notifier
+    listeners: Mutex<HashMap<ListenerId, ListenerId>>,
+    // last handle-local listener id
+    last_listener_id: AtomicU64,
+    // the handle holds a reference to the background services
+    started: AtomicBool,
+    // the handle holds a reference to the connection
+    connected: AtomicBool,
+}
+
 struct Inner {
     rpc_client: Arc<RpcClient<RpcApiOps>>,
     notification_relay_channel: Channel<Notification>,
     notification_intake_channel: Mutex<Channel<Notification>>,
+    notification_backpressure: Mutex<Option<NotificationBackpressure>>,
+    // set when the overflow policy requested a disconnect, cleared on (re)connection
+    notification_overflow_disconnect: AtomicBool,
     notifier: Arc<Mutex<Option<RpcClientNotifier>>>,
     encoding: Encoding,
     wrpc_ctl_multiplexer: Multiplexer<WrpcCtl>,
@@ -34,15 +97,44 @@ struct Inner {
     connect_guard: AsyncMutex<()>,
     disconnect_guard: AsyncMutex<()>,
     // ---
+    lifecycle_guard: AsyncMutex<()>,
+    // number of handles holding a reference to the background services
+    started_handles: AtomicUsize,
+    // number of handles holding a reference to the connection
+    connected_handles: AtomicUsize,
+    // ---
     default_url: Mutex<Option<String>>,
     current_url: Mutex<Option<String>>,
     resolver: Mutex<Option<Resolver>>,
     network_id: Mutex<Option<NetworkId>>,
     node_descriptor: Mutex<Option<Arc<NodeDescriptor>>>,
+    failover_threshold: Mutex<Option<usize>>,
+    failover_state: Mutex<FailoverState>,
+    failover_multiplexer: Multiplexer<FailoverEvent>,
+    // ---
+    subscriptions: SubscriptionRegistry,
+    // serializes subscription changes with their replay on (re)connection
+    subscriptions_guard: AsyncMutex<()>,
+    // ---
+    // proxy used by default
+    proxy: Mutex<Option<ProxyConfig>>,
+    // proxy used by the current connection
+    connection_proxy: Mutex<Option<ProxyConfig>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    proxy_relay: Mutex<Option<ProxyRelay>>,
+    // ---
+    transport: Option<Arc<dyn RpcTransport>>,
+    transport_connected: Arc<AtomicBool>,
 }
 
 impl Inner {
-    pub fn new(encoding: Encoding, url: Option<&str>, resolver: Option<Resolver>, network_id: Option<NetworkId>) -> Result<Inner> {
+    pub fn new(
+        encoding: Encoding,
+        url: Option<&str>,
+        resolver: Option<Resolver>,
+        network_id: Option<NetworkId>,
+        transport: Option<Arc<dyn RpcTransport>>,
+    ) -> Result<Inner> {
         // log_trace!("Kaspa wRPC::{encoding} connecting to: {url}");
         let rpc_ctl = RpcCtl::with_descriptor(url);
         let wrpc_ctl_multiplexer = Multiplexer::<WrpcCtl>::new();
@@ -95,6 +187,8 @@ impl Inner {
             rpc_client: rpc,
             notification_relay_channel,
             notification_intake_channel,
+            notification_backpressure: Mutex::new(None),
+            notification_overflow_disconnect: AtomicBool::new(false),
             notifier: Default::default(),
             encoding,
             wrpc_ctl_multiplexer,
@@ -104,11 +198,29 @@ impl Inner {
             connect_guard: async_std::sync::Mutex::new(()),
             disconnect_guard: async_std::sync::Mutex::new(()),
             // ---
+            lifecycle_guard: async_std::sync::Mutex::new(()),
+            started_handles: AtomicUsize::new(0),
+            connected_handles: AtomicUsize::new(0),
+            // ---
             default_url: Mutex::new(url.map(|s| s.to_string())),
             current_url: Mutex::new(None),
             resolver: Mutex::new(resolver),
             network_id: Mutex::new(network_id),
             node_descriptor: Mutex::new(None),
+            failover_threshold: Mutex::new(None),
+            failover_state: Mutex::new(FailoverState::default()),
+            failover_multiplexer: Multiplexer::new(),
+            // ---
+            subscriptions: SubscriptionRegistry::new(),
+            subscriptions_guard: AsyncMutex::new(()),
+            // ---
+            proxy: Mutex::new(None),
+            connection_proxy: Mutex::new(None),
+            #[cfg(not(target_arch = "wasm32"))]
+            proxy_relay: Mutex::new(None),
+            // ---
+            transport,
+            transport_connected: Arc::new(AtomicBool::new(false)),
         };
         Ok(client)
     }
@@ -116,19 +228,119 @@ impl Inner {
     pub fn reset_notification_intake_channel(&self) {
         let mut intake = self.notification_intake_channel.lock().unwrap();
         intake.sender.close();
-        *intake = Channel::unbounded();
+        *intake = match *self.notification_backpressure.lock().unwrap() {
+            Some(backpressure) => Channel::bounded(backpressure.capacity),
+            None => Channel::unbounded(),
+        };
+    }
+
+    /// Post a notification to the intake channel applying the
+    /// notification overflow policy if the channel is bounded.
+    fn post_notification(self: &Arc<Self>, notification: Notification) {
+        let policy = self.notification_backpressure.lock().unwrap().map(|backpressure| backpressure.policy).unwrap_or_default();
+        let overflow = match deliver(&self.notification_intake_channel.lock().unwrap(), notification, policy) {
+            Ok(overflow) => overflow,
+            Err(err) => {
+                log_error!("notification_intake_channel.sender.try_send() error: {err}");
+                return;
+            }
+        };
+
+        if overflow.dropped > 0 {
+            self.rpc_ctl.register_notifications_dropped(overflow.dropped);
+        }
+        if overflow.coalesced > 0 {
+            self.rpc_ctl.register_notifications_coalesced(overflow.coalesced);
+        }
+        if overflow.disconnect && !self.notification_overflow_disconnect.swap(true, Ordering::SeqCst) {
+            log_warn!("Kaspa RPC notification channel is full, reconnecting to the server");
+            let this = self.clone();
+            spawn(async move {
+                if let Err(err) = this.reconnect().await {
+                    log_error!("Error reconnecting on notification overflow: {err}");
+                }
+            });
+        }
+    }
+
+    /// Drop the current connection and re-establish it (used by the
+    /// [`Disconnect`](crate::backpressure::NotificationOverflowPolicy::Disconnect)
+    /// notification overflow policy). The background services and the handles
+    /// sharing the connection are retained, the subscriptions are restored
+    /// once the connection is re-established.
+    async fn reconnect(&self) -> Result<()> {
+        let _guard = self.disconnect_guard.lock().await;
+        // the connection has been closed by the client handles in the meantime
+        if self.connected_handles.load(Ordering::SeqCst) == 0 {
+            return Ok(());
+        }
+
+        if let Some(transport) = self.transport.as_ref() {
+            transport.disconnect().await?;
+            self.transport_sink().disconnected()?;
+            transport.connect(self.transport_sink()).await
+        } else {
+            // the shutdown disables the reconnection of the websocket,
+            // which is re-enabled by the (non-blocking) connection
+            self.rpc_client.shutdown().await?;
+            let options = ConnectOptions { block_async_connect: false, ..Default::default() };
+            self.rpc_client.connect(options).await?;
+            Ok(())
+        }
+    }
+
+    /// Issue an RPC call over the custom transport if one is configured,
+    /// otherwise over the wRPC WebSocket connection.
+    async fn call<Req, Resp>(&self, op: RpcApiOps, request: Req) -> ClientResult<Resp>
+    where
+        Req: MsgT,
+        Resp: MsgT,
+    {
+        let Some(transport) = self.transport.as_ref() else {
+            return self.rpc_client.call(op, request).await;
+        };
+
+        if !self.transport_connected.load(Ordering::SeqCst) {
+            return Err(WebSocketError::NotConnected.into());
+        }
+
+        let payload = request.try_to_vec().map_err(WebSocketError::custom)?;
+        let response = transport.call(op, payload).await.map_err(WebSocketError::custom)?;
+        Ok(Resp::try_from_slice(&response).map_err(WebSocketError::custom)?)
+    }
+
+    fn transport_sink(&self) -> RpcTransportSink {
+        RpcTransportSink::new(
+            self.transport_connected.clone(),
+            self.wrpc_ctl_multiplexer.clone(),
+            self.notification_relay_channel.sender.clone(),
+        )
     }
 
     /// Start sending notifications of some type to the client.
+    ///
+    /// The scope is recorded even if the call fails (i.e. while disconnected)
+    /// since the notifier considers it active and it has to be restored
+    /// on (re)connection. Only the part of the scope that has not been
+    /// submitted over the current connection is sent to the server.
     async fn start_notify_to_client(&self, scope: Scope) -> RpcResult<()> {
-        let _response: SubscribeResponse = self.rpc_client.call(RpcApiOps::Subscribe, scope).await.map_err(|err| err.to_string())?;
+        let _guard = self.subscriptions_guard.lock().await;
+        if let Some(scope) = self.subscriptions.register(&scope) {
+            let _response: SubscribeResponse = self.call(RpcApiOps::Subscribe, scope.clone()).await.map_err(|err| err.to_string())?;
+            self.subscriptions.submitted(&scope);
+        }
         Ok(())
     }
 
     /// Stop sending notifications of some type to the client.
+    ///
+    /// Only the part of the scope that has been submitted over
+    /// the current connection is sent to the server.
     async fn stop_notify_to_client(&self, scope: Scope) -> RpcResult<()> {
-        let _response: UnsubscribeResponse =
-            self.rpc_client.call(RpcApiOps::Unsubscribe, scope).await.map_err(|err| err.to_string())?;
+        let _guard = self.subscriptions_guard.lock().await;
+        if let Some(scope) = self.subscriptions.unregister(&scope) {
+            let _response: UnsubscribeResponse = self.call(RpcApiOps::Unsubscribe, scope).await.map_err(|err| err.to_string())?;
+        }
         Ok(())
     }
 
@@ -156,6 +368,133 @@ impl Inner {
         *self.network_id.lock().unwrap()
     }
 
+    fn failover_threshold(&self) -> Option<usize> {
+        *self.failover_threshold.lock().unwrap()
+    }
+
+    fn handle_connect(&self) {
+        let mut state = self.failover_state.lock().unwrap();
+        state.connected_at = Some(Instant::now());
+        state.awaiting_connect = false;
+    }
+
+    fn handle_disconnect(&self) {
+        let mut state = self.failover_state.lock().unwrap();
+        if state.connected_at.take().map(|ts| ts.elapsed() >= STABLE_CONNECTION_PERIOD).unwrap_or(false) {
+            state.failures = 0;
+        }
+        self.register_failure(&mut state);
+    }
+
+    fn register_failure(&self, state: &mut FailoverState) {
+        state.failures += 1;
+        if let Some(threshold) = self.failover_threshold() {
+            if state.failures >= threshold {
+                state.failures = 0;
+                state.switch_pending = true;
+            }
+        }
+    }
+
+    /// Resolve the next node url using the resolver node ranking,
+    /// switching to the next-best node if the current node has
+    /// failed repeatedly.
+    async fn resolve_with_failover(&self, resolver: &Resolver, network_id: NetworkId) -> Result<NodeDescriptor> {
+        let switch_pending = {
+            let mut state = self.failover_state.lock().unwrap();
+            // the previous connection attempt did not succeed
+            if state.awaiting_connect {
+                self.register_failure(&mut state);
+            }
+            state.awaiting_connect = true;
+            std::mem::take(&mut state.switch_pending)
+        };
+
+        let current = self.node_descriptor.lock().unwrap().clone();
+        let node = match current.as_ref() {
+            // keep using the current node until the failure threshold is reached
+            Some(current) if !switch_pending => current.as_ref().clone(),
+            _ => {
+                let exclude = current.as_ref().filter(|_| switch_pending).map(|node| {
+                    let failures = resolver.report_failure(&node.url);
+                    self.failover_multiplexer.try_broadcast(FailoverEvent::NodeFailure { node: node.clone(), failures }).ok();
+                    node.url.clone()
+                });
+                resolver.get_best_node(self.encoding, network_id, exclude.as_deref()).await?
+            }
+        };
+
+        if current.as_ref().map(|current| current.url != node.url).unwrap_or(false) {
+            let to = Arc::new(node.clone());
+            self.failover_multiplexer.try_broadcast(FailoverEvent::NodeSwitched { from: current, to }).ok();
+        }
+
+        Ok(node)
+    }
+
+    /// Returns the url the WebSocket connects to: the loopback url of
+    /// the proxy relay if the connection uses a proxy, `url` otherwise.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn proxy_url(&self, url: String) -> Result<String> {
+        let Some(proxy) = self.connection_proxy.lock().unwrap().clone() else {
+            self.release_proxy_relay();
+            return Ok(url);
+        };
+
+        // relays accept a single connection, each connection attempt uses a new relay
+        let relay = ProxyRelay::bind(proxy, &url).await?;
+        let local_url = relay.local_url().to_string();
+        self.proxy_relay.lock().unwrap().replace(relay);
+        Ok(local_url)
+    }
+
+    fn release_proxy_relay(&self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        self.proxy_relay.lock().unwrap().take();
+    }
+
+    /// Re-submit the active subscriptions that have not been submitted over
+    /// the connection identified by `generation` (see [`SubscriptionRegistry::reset()`])
+    /// and signal [`RpcState::SubscriptionsRestored`](kaspa_rpc_core::api::ctl::RpcState::SubscriptionsRestored).
+    /// Failed submissions are retried until they succeed or the connection changes.
+    async fn restore_subscriptions(&self, generation: u64) {
+        if self.subscriptions.is_empty() {
+            return;
+        }
+
+        let mut delay = SUBSCRIPTIONS_RESTORE_RETRY_DELAY;
+        loop {
+            let restored = {
+                let _guard = self.subscriptions_guard.lock().await;
+                if self.subscriptions.generation() != generation {
+                    return;
+                }
+
+                let mut restored = true;
+                for scope in self.subscriptions.pending() {
+                    let result: ClientResult<SubscribeResponse> = self.call(RpcApiOps::Subscribe, scope.clone()).await;
+                    match result {
+                        Ok(_) => self.subscriptions.submitted(&scope),
+                        Err(err) => {
+                            log_error!("Unable to restore the {scope} subscription: {err}");
+                            restored = false;
+                        }
+                    }
+                }
+                restored
+            };
+
+            if restored {
+                break;
+            }
+
+            workflow_core::task::sleep(delay).await;
+            delay = (delay * 2).min(SUBSCRIPTIONS_RESTORE_MAX_RETRY_DELAY);
+        }
+
+        self.rpc_ctl.signal_subscriptions_restored().await.unwrap_or_else(|err| log_error!("{err}"));
+    }
+
     fn build_notifier(self: &Arc<Self>, subscription_context: Option<SubscriptionContext>) -> Result<RpcClientNotifier> {
         let receiver = self.notification_intake_channel.lock().unwrap().receiver.clone();
 
@@ -217,7 +556,12 @@ impl RpcResolver for Inner {
             url
         } else if let Some(resolver) = self.resolver().as_ref() {
             let network_id = self.network_id().expect("Resolver requires network id in RPC client configuration");
-            let node = resolver.get_node(self.encoding, network_id).await.map_err(WebSocketError::custom)?;
+            let node = if self.failover_threshold().is_some() {
+                self.resolve_with_failover(resolver, network_id).await
+            } else {
+                resolver.get_node(self.encoding, network_id).await
+            }
+            .map_err(WebSocketError::custom)?;
             let url = node.url.clone();
             self.node_descriptor.lock().unwrap().replace(Arc::new(node));
             url
@@ -227,6 +571,8 @@ impl RpcResolver for Inner {
 
         self.rpc_ctl.set_descriptor(Some(url.clone()));
         self.set_current_url(Some(&url));
+        #[cfg(not(target_arch = "wasm32"))]
+        let url = self.proxy_url(url).await.map_err(WebSocketError::custom)?;
         Ok(url)
     }
 }
@@ -242,9 +588,14 @@ const WRPC_CLIENT: &str = "wrpc-client";
 /// notifications and the [`RpcClient::call`] method that allows async
 /// method invocation server-side.
 ///
+/// A single client (and its WebSocket connection) can be shared by multiple
+/// independent consumers (such as multiple wallets and their UTXO processors)
+/// via lightweight handles created with [`KaspaRpcClient::handle()`].
+///
 #[derive(Clone)]
 pub struct KaspaRpcClient {
     inner: Arc<Inner>,
+    handle: Arc<HandleState>,
 }
 
 impl Debug for KaspaRpcClient {
@@ -276,9 +627,9 @@ impl KaspaRpcClient {
         network_id: Option<NetworkId>,
         subscription_context: Option<SubscriptionContext>,
     ) -> Result<KaspaRpcClient> {
-        let inner = Arc::new(Inner::new(encoding, url, resolver, network_id)?);
+        let inner = Arc::new(Inner::new(encoding, url, resolver, network_id, None)?);
         inner.build_notifier(subscription_context)?;
-        let client = KaspaRpcClient { inner };
+        let client = KaspaRpcClient { inner, handle: Default::default() };
         //     notification_mode: NotificationMode,
         //     url: &str,
         //     subscription_context: Option<SubscriptionContext>,
@@ -308,6 +659,18 @@ impl KaspaRpcClient {
         Ok(client)
     }
 
+    /// Create a new `KaspaRpcClient` relaying RPC calls over the supplied
+    /// [`RpcTransport`] instead of connecting to the wRPC server directly.
+    pub fn new_with_transport(
+        transport: Arc<dyn RpcTransport>,
+        network_id: Option<NetworkId>,
+        subscription_context: Option<SubscriptionContext>,
+    ) -> Result<KaspaRpcClient> {
+        let inner = Arc::new(Inner::new(Encoding::Borsh, None, None, network_id, Some(transport))?);
+        inner.build_notifier(subscription_context)?;
+        Ok(KaspaRpcClient { inner, handle: Default::default() })
+    }
+
     async fn start_notifier(&self) -> Result<()> {
         let notifier = self.inner.build_notifier(None)?;
         notifier.start();
@@ -317,6 +680,8 @@ impl KaspaRpcClient {
     async fn stop_notifier(&self) -> Result<()> {
         self.inner.reset_notification_intake_channel();
         self.notifier().join().await?;
+        // the notifier is rebuilt on start, dropping all subscriptions
+        self.inner.subscriptions.clear();
         Ok(())
     }
 
@@ -324,6 +689,47 @@ impl KaspaRpcClient {
         self.inner.notifier.lock().unwrap().clone().expect("Rpc client is not correctly initialized")
     }
 
+    /// Create a lightweight handle sharing the connection of this client.
+    ///
+    /// Each handle owns the notification listeners it registers and has its
+    /// own listener id space: listeners of other handles can not be started,
+    /// stopped or unregistered through it. Subscriptions of all listeners are
+    /// compounded, i.e. a `UtxosChanged` address remains subscribed on the
+    /// node for as long as any listener tracks it. Background services and the connection are reference
+    /// counted - [`stop()`](Self::stop) and [`disconnect()`](Self::disconnect)
+    /// release the handle's listeners and references, and only take effect
+    /// on the shared connection once released by the last handle.
+    pub fn handle(&self) -> KaspaRpcClient {
+        KaspaRpcClient { inner: self.inner.clone(), handle: Default::default() }
+    }
+
+    /// Returns `true` if `self` and `other` share the same underlying connection.
+    pub fn shares_connection_with(&self, other: &KaspaRpcClient) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Returns the notifier listener id of the handle-local listener `id`.
+    fn notifier_listener_id(&self, id: ListenerId) -> RpcResult<ListenerId> {
+        self.handle
+            .listeners
+            .lock()
+            .unwrap()
+            .get(&id)
+            .copied()
+            .ok_or_else(|| format!("listener {id} is not registered by this client handle").into())
+    }
+
+    /// Unregister all listeners registered via this handle.
+    fn release_listeners(&self, notifier_running: bool) {
+        let listeners = std::mem::take(&mut *self.handle.listeners.lock().unwrap());
+        if notifier_running {
+            let notifier = self.notifier();
+            for id in listeners.into_values() {
+                notifier.unregister_listener(id).unwrap_or_else(|err| log_warn!("Unable to unregister listener {id}: {err}"));
+            }
+        }
+    }
+
     pub fn url(&self) -> Option<String> {
         self.inner.current_url()
     }
@@ -334,7 +740,16 @@ impl KaspaRpcClient {
     }
 
     pub fn is_connected(&self) -> bool {
-        self.inner.rpc_client.is_connected()
+        if self.inner.transport.is_some() {
+            self.inner.transport_connected.load(Ordering::SeqCst)
+        } else {
+            self.inner.rpc_client.is_connected()
+        }
+    }
+
+    /// Returns the custom transport used by this client (if any).
+    pub fn transport(&self) -> Option<&Arc<dyn RpcTransport>> {
+        self.inner.transport.as_ref()
     }
 
     pub fn encoding(&self) -> Encoding {
@@ -359,6 +774,64 @@ impl KaspaRpcClient {
         self.inner.node_descriptor.lock().unwrap().clone()
     }
 
+    /// Set the proxy used by subsequent connections (`None` for direct
+    /// connections). Proxies are not supported on wasm32 targets.
+    pub fn set_proxy(&self, proxy: Option<ProxyConfig>) -> Result<()> {
+        if proxy.is_some() && cfg!(target_arch = "wasm32") {
+            return Err(Error::Proxy("proxy connections are not supported on wasm32 targets".to_string()));
+        }
+        *self.inner.proxy.lock().unwrap() = proxy;
+        Ok(())
+    }
+
+    /// Returns the proxy used by default by the client connections.
+    pub fn proxy(&self) -> Option<ProxyConfig> {
+        self.inner.proxy.lock().unwrap().clone()
+    }
+
+    /// Enable automatic node failover when connecting via the [`Resolver`].
+    /// When enabled, nodes are ranked by latency and sync status and the
+    /// client switches to the next-best node after `threshold` consecutive
+    /// connection failures. Supplying `None` disables failover.
+    pub fn set_failover_threshold(&self, threshold: Option<usize>) -> Result<()> {
+        if threshold == Some(0) {
+            return Err(Error::custom("failover threshold must be greater than zero"));
+        }
+        *self.inner.failover_threshold.lock().unwrap() = threshold;
+        *self.inner.failover_state.lock().unwrap() = FailoverState::default();
+        Ok(())
+    }
+
+    pub fn failover_threshold(&self) -> Option<usize> {
+        self.inner.failover_threshold()
+    }
+
+    /// Bound the notification channel to `capacity` notifications and apply
+    /// the [`NotificationOverflowPolicy`](crate::backpressure::NotificationOverflowPolicy) when the consumer does not keep up.
+    /// Supplying `None` restores the default unbounded channel. Notifications
+    /// discarded by the policy are counted by the [`RpcCtl`]. This setting
+    /// can only be changed while background services are stopped.
+    pub fn set_notification_backpressure(&self, backpressure: Option<NotificationBackpressure>) -> Result<()> {
+        if backpressure.is_some_and(|backpressure| backpressure.capacity == 0) {
+            return Err(Error::custom("notification channel capacity must be greater than zero"));
+        }
+        if self.inner.background_services_running.load(Ordering::SeqCst) {
+            return Err(Error::custom("notification backpressure can not be changed while the client is running"));
+        }
+        *self.inner.notification_backpressure.lock().unwrap() = backpressure;
+        self.inner.reset_notification_intake_channel();
+        Ok(())
+    }
+
+    pub fn notification_backpressure(&self) -> Option<NotificationBackpressure> {
+        *self.inner.notification_backpressure.lock().unwrap()
+    }
+
+    /// Obtain the multiplexer emitting [`FailoverEvent`] notifications.
+    pub fn failover_multiplexer(&self) -> &Multiplexer<FailoverEvent> {
+        &self.inner.failover_multiplexer
+    }
+
     pub fn rpc_client(&self) -> &Arc<RpcClient<RpcApiOps>> {
         &self.inner.rpc_client
     }
@@ -373,6 +846,12 @@ impl KaspaRpcClient {
 
     /// Start background RPC services.
     pub async fn start(&self) -> Result<()> {
+        let _guard = self.inner.lifecycle_guard.lock().await;
+
+        if !self.handle.started.swap(true, Ordering::SeqCst) {
+            self.inner.started_handles.fetch_add(1, Ordering::SeqCst);
+        }
+
         if !self.inner.background_services_running.load(Ordering::SeqCst) {
             self.inner.background_services_running.store(true, Ordering::SeqCst);
             self.start_notifier().await?;
@@ -382,9 +861,20 @@ impl KaspaRpcClient {
         Ok(())
     }
 
-    /// Stop background RPC services.
+    /// Stop background RPC services. If the client is shared with other
+    /// handles, only the listeners of this handle are released and the
+    /// services keep running until stopped by the last handle.
     pub async fn stop(&self) -> Result<()> {
-        if self.inner.background_services_running.load(Ordering::SeqCst) {
+        let _guard = self.inner.lifecycle_guard.lock().await;
+
+        if self.handle.started.swap(false, Ordering::SeqCst) {
+            self.inner.started_handles.fetch_sub(1, Ordering::SeqCst);
+        }
+
+        let running = self.inner.background_services_running.load(Ordering::SeqCst);
+        let shared = self.inner.started_handles.load(Ordering::SeqCst) > 0;
+        self.release_listeners(running && shared);
+        if running && !shared {
             self.stop_rpc_ctl_service().await?;
             self.stop_notifier().await?;
             self.inner.background_services_running.store(false, Ordering::SeqCst);
@@ -400,9 +890,55 @@ impl KaspaRpcClient {
     ///
     /// This method starts background RPC services if they are not running and
     /// attempts to connect to the RPC endpoint.
+    ///
+    /// If the connection is already held by another handle of this client,
+    /// the handle joins the existing connection instead of reconnecting.
+    ///
+    /// Connections are established via the proxy set using
+    /// [`set_proxy()`](Self::set_proxy), if any.
     pub async fn connect(&self, options: Option<ConnectOptions>) -> ConnectResult<Error> {
+        let proxy = self.proxy();
+        self.connect_impl(options, proxy).await
+    }
+
+    /// Same as [`connect()`](Self::connect), but the connection is established
+    /// via the supplied `proxy` (or directly if `None`), overriding the proxy
+    /// set using [`set_proxy()`](Self::set_proxy). The override applies to this
+    /// connection, including its reconnection attempts.
+    pub async fn connect_with_proxy(&self, options: Option<ConnectOptions>, proxy: Option<ProxyConfig>) -> ConnectResult<Error> {
+        self.connect_impl(options, proxy).await
+    }
+
+    async fn connect_impl(&self, options: Option<ConnectOptions>, proxy: Option<ProxyConfig>) -> ConnectResult<Error> {
+        if proxy.is_some() && cfg!(target_arch = "wasm32") {
+            return Err(Error::Proxy("proxy connections are not supported on wasm32 targets".to_string()));
+        }
+
         let _guard = self.inner.connect_guard.lock().await;
 
+        if !self.handle.connected.load(Ordering::SeqCst) && self.inner.connected_handles.load(Ordering::SeqCst) > 0 {
+            let url = options.as_ref().and_then(|options| options.url.as_deref());
+            if url.is_some() && url.map(String::from) != self.url() {
+                return Err(Error::custom("Unable to change the url of a connection shared with other client handles"));
+            }
+            if self.inner.transport.is_none() && proxy != *self.inner.connection_proxy.lock().unwrap() {
+                return Err(Error::custom("Unable to change the proxy of a connection shared with other client handles"));
+            }
+            self.start().await?;
+            self.acquire_connection();
+            return Ok(None);
+        }
+
+        if let Some(transport) = self.inner.transport.as_ref() {
+            self.start().await?;
+            if let Err(err) = transport.connect(self.inner.transport_sink()).await {
+                self.stop().await?;
+                return Err(err);
+            }
+            self.acquire_connection();
+            return Ok(None);
+        }
+
         let mut options = options.unwrap_or_default();
         let strategy = options.strategy;
 
@@ -419,14 +955,20 @@ impl KaspaRpcClient {
             ..Default::default()
         };
 
+        *self.inner.connection_proxy.lock().unwrap() = proxy;
+
         self.start().await?;
         self.inner.rpc_client.configure(ws_config);
         match self.inner.rpc_client.connect(options).await {
-            Ok(v) => Ok(v),
+            Ok(v) => {
+                self.acquire_connection();
+                Ok(v)
+            }
             Err(err) => {
                 if strategy == ConnectStrategy::Fallback {
                     let _guard = self.inner.disconnect_guard.lock().await;
                     self.inner.rpc_client.shutdown().await?;
+                    self.inner.release_proxy_relay();
                     self.stop().await?;
                 }
                 Err(err.into())
@@ -435,11 +977,26 @@ impl KaspaRpcClient {
     }
 
     /// This method stops background RPC services and disconnects
-    /// from the RPC endpoint.
+    /// from the RPC endpoint. If the connection is shared with other
+    /// handles, only the references held by this handle are released.
     pub async fn disconnect(&self) -> Result<()> {
         let _guard = self.inner.disconnect_guard.lock().await;
 
-        self.inner.rpc_client.shutdown().await?;
+        if self.handle.connected.swap(false, Ordering::SeqCst) {
+            self.inner.connected_handles.fetch_sub(1, Ordering::SeqCst);
+        }
+        if self.inner.connected_handles.load(Ordering::SeqCst) > 0 {
+            self.stop().await?;
+            return Ok(());
+        }
+
+        if let Some(transport) = self.inner.transport.as_ref() {
+            transport.disconnect().await?;
+            self.inner.transport_sink().disconnected()?;
+        } else {
+            self.inner.rpc_client.shutdown().await?;
+            self.inner.release_proxy_relay();
+        }
         self.stop().await?;
         Ok(())
     }
@@ -450,12 +1007,22 @@ impl KaspaRpcClient {
     //     Ok(self.inner.rpc_client.shutdown().await?)
     // }
 
+    fn acquire_connection(&self) {
+        if !self.handle.connected.swap(true, Ordering::SeqCst) {
+            self.inner.connected_handles.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
     /// A helper function that is not `async`, allowing connection
     /// process to be initiated from non-async contexts.
     pub fn connect_as_task(&self) -> Result<()> {
         let self_ = self.clone();
         workflow_core::task::spawn(async move {
-            self_.inner.rpc_client.connect(ConnectOptions::default()).await.ok();
+            if self_.inner.transport.is_some() {
+                self_.connect(None).await.ok();
+            } else {
+                self_.inner.rpc_client.connect(ConnectOptions::default()).await.ok();
+            }
         });
         Ok(())
     }
@@ -528,9 +1095,7 @@ impl KaspaRpcClient {
                     msg = notification_relay_channel.receiver.recv().fuse() => {
                         if let Ok(msg) = msg {
                             // inner.rpc_ctl.notify(msg).await.expect("(KaspaRpcClient) rpc_ctl.notify() error");
-                            if let Err(err) = inner.notification_intake_channel.lock().unwrap().sender.try_send(msg) {
-                                log_error!("notification_intake_channel.sender.try_send() error: {err}");
-                            }
+                            inner.post_notification(msg);
                         } else {
                             log_error!("notification_relay_channel receiver error");
                         }
@@ -539,9 +1104,19 @@ impl KaspaRpcClient {
                         if let Ok(msg) = msg {
                             match msg {
                                 WrpcCtl::Connect => {
+                                    inner.notification_overflow_disconnect.store(false, Ordering::SeqCst);
+                                    inner.handle_connect();
+                                    // server-side subscriptions do not survive the connection, replay them
+                                    let generation = inner.subscriptions.reset();
                                     inner.rpc_ctl.signal_open().await.expect("(KaspaRpcClient) rpc_ctl.signal_open() error");
+                                    let inner = inner.clone();
+                                    spawn(async move {
+                                        inner.restore_subscriptions(generation).await;
+                                    });
                                 }
                                 WrpcCtl::Disconnect => {
+                                    inner.subscriptions.reset();
+                                    inner.handle_disconnect();
                                     inner.rpc_ctl.signal_close().await.expect("(KaspaRpcClient) rpc_ctl.signal_close() error");
                                 }
                             }
@@ -566,6 +1141,9 @@ impl KaspaRpcClient {
     /// This is intended for debug purposes only.
     /// Can be used to test application reconnection logic.
     pub fn trigger_abort(&self) -> Result<()> {
+        if self.inner.transport.is_some() {
+            return Err(Error::custom("trigger_abort() is not supported by custom RPC transports"));
+        }
         Ok(self.inner.rpc_client.trigger_abort()?)
     }
 }
@@ -618,6 +1196,9 @@ impl RpcApi for KaspaRpcClient {
             SubmitBlock,
             SubmitTransaction,
             Unban,
+            ValidateTransaction,
+            GetUtxosByAddressesPage,
+            GetWrpcServerStatus,
         ]
     );
 
@@ -626,7 +1207,10 @@ impl RpcApi for KaspaRpcClient {
 
     /// Register a new listener and returns an id and a channel receiver.
     fn register_new_listener(&self, connection: ChannelConnection) -> ListenerId {
-        self.notifier().register_new_listener(connection, ListenerLifespan::Dynamic)
+        let notifier_id = self.notifier().register_new_listener(connection, ListenerLifespan::Dynamic);
+        let id = self.handle.last_listener_id.fetch_add(1, Ordering::SeqCst) + 1;
+        self.handle.listeners.lock().unwrap().insert(id, notifier_id);
+        id
         // match self.notification_mode {
         //     NotificationMode::MultiListeners => {
         //         self.notifier.as_ref().unwrap().register_new_listener(connection, ListenerLifespan::Dynamic)
@@ -639,19 +1223,210 @@ impl RpcApi for KaspaRpcClient {
     ///
     /// Stop all notifications for this listener and drop its channel.
     async fn unregister_listener(&self, id: ListenerId) -> RpcResult<()> {
-        self.notifier().unregister_listener(id)?;
+        // listeners of other handles (or already released ones) are left untouched
+        let notifier_id = self.handle.listeners.lock().unwrap().remove(&id);
+        if let Some(notifier_id) = notifier_id {
+            self.notifier().unregister_listener(notifier_id)?;
+        }
         Ok(())
     }
 
     /// Start sending notifications of some type to a listener.
     async fn start_notify(&self, id: ListenerId, scope: Scope) -> RpcResult<()> {
+        let id = self.notifier_listener_id(id)?;
         self.notifier().try_start_notify(id, scope)?;
         Ok(())
     }
 
     /// Stop sending notifications of some type to a listener.
     async fn stop_notify(&self, id: ListenerId, scope: Scope) -> RpcResult<()> {
+        let id = self.notifier_listener_id(id)?;
         self.notifier().try_stop_notify(id, scope)?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backpressure::NotificationOverflowPolicy;
+    use crate::transport::RpcTransportSink;
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use kaspa_addresses::{Address, Prefix, Version};
+    use kaspa_notify::connection::ChannelType;
+    use kaspa_rpc_core::api::ctl::RpcState;
+    use std::sync::atomic::AtomicUsize;
+    use workflow_core::channel::MultiplexerChannel;
+
+    /// Transport recording the submitted scopes, failing the
+    /// requested number of `Subscribe` calls.
+    #[derive(Default)]
+    struct SubscriptionTransport {
+        sink: Mutex<Option<RpcTransportSink>>,
+        calls: Mutex<Vec<(RpcApiOps, Scope)>>,
+        failures: AtomicUsize,
+        connections: AtomicUsize,
+    }
+
+    impl SubscriptionTransport {
+        fn sink(&self) -> RpcTransportSink {
+            self.sink.lock().unwrap().clone().unwrap()
+        }
+
+        fn take_calls(&self) -> Vec<(RpcApiOps, Scope)> {
+            std::mem::take(&mut *self.calls.lock().unwrap())
+        }
+    }
+
+    #[async_trait]
+    impl RpcTransport for SubscriptionTransport {
+        async fn connect(&self, sink: RpcTransportSink) -> Result<()> {
+            self.connections.fetch_add(1, Ordering::SeqCst);
+            self.sink.lock().unwrap().replace(sink.clone());
+            sink.connected()
+        }
+
+        async fn disconnect(&self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn call(&self, op: RpcApiOps, payload: Vec<u8>) -> Result<Vec<u8>> {
+            match op {
+                RpcApiOps::Subscribe => {
+                    if self.failures.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |failures| failures.checked_sub(1)).is_ok() {
+                        return Err(Error::custom("subscription rejected"));
+                    }
+                    self.calls.lock().unwrap().push((op, Scope::try_from_slice(&payload)?));
+                    Ok(SubscribeResponse::new(0).try_to_vec()?)
+                }
+                RpcApiOps::Unsubscribe => {
+                    self.calls.lock().unwrap().push((op, Scope::try_from_slice(&payload)?));
+                    Ok(UnsubscribeResponse {}.try_to_vec()?)
+                }
+                _ => Err(Error::custom(format!("RPC method {op:?} is not supported"))),
+            }
+        }
+    }
+
+    async fn wait_for_state(channel: &MultiplexerChannel<RpcState>, state: RpcState) {
+        tokio::time::timeout(Duration::from_secs(10), async { while channel.receiver.recv().await.unwrap() != state {} })
+            .await
+            .expect("rpc state");
+    }
+
+    async fn wait_until(condition: impl Fn() -> bool) {
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while !condition() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("condition");
+    }
+
+    #[tokio::test]
+    async fn test_subscriptions_reconnect() -> Result<()> {
+        let address = |n: u8| Address::new(Prefix::Testnet, Version::PubKey, &[n; 32]);
+        let utxos_changed = |addresses: Vec<Address>| Scope::UtxosChanged(UtxosChangedScope::new(addresses));
+        let daa_score_changed = Scope::VirtualDaaScoreChanged(VirtualDaaScoreChangedScope {});
+
+        let transport = Arc::new(SubscriptionTransport::default());
+        let client = KaspaRpcClient::new_with_transport(transport.clone(), None, None)?;
+        let ctl = client.rpc_ctl().multiplexer().channel();
+        client.connect(None).await?;
+        wait_for_state(&ctl, RpcState::Connected).await;
+
+        let channel = Channel::unbounded();
+        let listener_id = client.register_new_listener(ChannelConnection::new("test", channel.sender, ChannelType::Persistent));
+        client.start_notify(listener_id, daa_score_changed.clone()).await?;
+        client.start_notify(listener_id, utxos_changed(vec![address(1), address(2)])).await?;
+        wait_until(|| transport.calls.lock().unwrap().len() == 2).await;
+        transport.take_calls();
+
+        // an address unregistered while disconnected is not replayed
+        transport.sink().disconnected()?;
+        wait_for_state(&ctl, RpcState::Disconnected).await;
+        client.stop_notify(listener_id, utxos_changed(vec![address(2)])).await?;
+        wait_until(|| client.inner.subscriptions.pending().contains(&utxos_changed(vec![address(1)]))).await;
+        assert!(transport.take_calls().is_empty());
+
+        transport.sink().connected()?;
+        wait_for_state(&ctl, RpcState::SubscriptionsRestored).await;
+        let calls = transport.take_calls();
+        assert_eq!(calls.len(), 2);
+        assert!(calls.contains(&(RpcApiOps::Subscribe, daa_score_changed.clone())));
+        assert!(calls.contains(&(RpcApiOps::Subscribe, utxos_changed(vec![address(1)]))));
+
+        // scopes already submitted over the connection are not submitted again
+        client.inner.start_notify_to_client(daa_score_changed.clone()).await?;
+        client.inner.start_notify_to_client(utxos_changed(vec![address(1), address(3)])).await?;
+        assert_eq!(transport.take_calls(), vec![(RpcApiOps::Subscribe, utxos_changed(vec![address(3)]))]);
+
+        // failed submissions are retried
+        transport.failures.store(1, Ordering::SeqCst);
+        transport.sink().disconnected()?;
+        wait_for_state(&ctl, RpcState::Disconnected).await;
+        transport.sink().connected()?;
+        wait_for_state(&ctl, RpcState::SubscriptionsRestored).await;
+        let calls = transport.take_calls();
+        assert_eq!(calls.len(), 2);
+        assert!(calls.contains(&(RpcApiOps::Subscribe, daa_score_changed)));
+        assert!(calls.iter().any(|(_, scope)| matches!(scope, Scope::UtxosChanged(scope) if scope.addresses.len() == 2)));
+        assert!(client.inner.subscriptions.pending().is_empty());
+
+        client.disconnect().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_notification_overflow_reconnect() -> Result<()> {
+        let daa_score_changed = Scope::VirtualDaaScoreChanged(VirtualDaaScoreChangedScope {});
+        let transport = Arc::new(SubscriptionTransport::default());
+        let client = KaspaRpcClient::new_with_transport(transport.clone(), None, None)?;
+        client.set_notification_backpressure(Some(NotificationBackpressure::new(2, NotificationOverflowPolicy::Disconnect)))?;
+        let ctl = client.rpc_ctl().multiplexer().channel();
+        client.connect(None).await?;
+        wait_for_state(&ctl, RpcState::Connected).await;
+
+        let channel = Channel::unbounded();
+        let listener_id = client.register_new_listener(ChannelConnection::new("test", channel.sender, ChannelType::Persistent));
+        client.start_notify(listener_id, daa_score_changed.clone()).await?;
+        wait_until(|| transport.calls.lock().unwrap().len() == 1).await;
+        transport.take_calls();
+
+        // overflow the intake channel (the notifier does not run in between)
+        for virtual_daa_score in 0..3 {
+            client
+                .inner
+                .post_notification(Notification::VirtualDaaScoreChanged(VirtualDaaScoreChangedNotification { virtual_daa_score }));
+        }
+        assert!(client.inner.notification_overflow_disconnect.load(Ordering::SeqCst));
+
+        // the connection is re-established and the subscriptions are restored
+        wait_for_state(&ctl, RpcState::Disconnected).await;
+        wait_for_state(&ctl, RpcState::SubscriptionsRestored).await;
+        assert!(client.is_connected());
+        assert_eq!(transport.connections.load(Ordering::SeqCst), 2);
+        assert_eq!(transport.take_calls(), vec![(RpcApiOps::Subscribe, daa_score_changed)]);
+        assert!(!client.inner.notification_overflow_disconnect.load(Ordering::SeqCst));
+
+        // notifications are delivered over the new connection (following
+        // the notifications queued prior to the reconnection)
+        let notification = Notification::VirtualDaaScoreChanged(VirtualDaaScoreChangedNotification { virtual_daa_score: 7 });
+        transport.sink().notify_serialized(&notification.try_to_vec()?)?;
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while !matches!(
+                channel.receiver.recv().await.unwrap(),
+                Notification::VirtualDaaScoreChanged(notification) if notification.virtual_daa_score == 7
+            ) {}
+        })
+        .await
+        .expect("notification");
+
+        client.disconnect().await?;
+        assert!(!client.is_connected());
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use crate::capabilities::NodeFeature;
 use thiserror::Error;
 use wasm_bindgen::JsError;
 use wasm_bindgen::JsValue;
@@ -61,12 +62,25 @@ pub enum Error {
 
     #[error(transparent)]
     NetworkId(#[from] kaspa_consensus_core::network::NetworkIdError),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("{0} is not supported by the connected node")]
+    NotSupportedByNode(NodeFeature),
+
+    #[error("Proxy -> {0}")]
+    Proxy(String),
 }
 
 impl Error {
     pub fn custom<T: std::fmt::Display>(msg: T) -> Self {
         Error::Custom(msg.to_string())
     }
+
+    pub fn proxy<T: std::fmt::Display>(err: T) -> Self {
+        Error::Proxy(err.to_string())
+    }
 }
 
 impl From<String> for Error {
@@ -1,3 +1,6 @@
+pub mod backpressure;
+pub mod benchmark;
+pub mod capabilities;
 pub mod client;
 pub mod error;
 mod imports;
@@ -6,4 +9,7 @@ pub use imports::{KaspaRpcClient, Resolver, WrpcEncoding};
 pub mod node;
 pub mod parse;
 pub mod prelude;
+pub mod proxy;
 pub mod resolver;
+pub mod subscriptions;
+pub mod transport;
@@ -42,3 +42,49 @@ impl NodeDescriptor {
         self.url.clone()
     }
 }
+
+/// Latency penalty (in milliseconds) applied to nodes that are not synced.
+pub(crate) const UNSYNCED_PENALTY_MSEC: u64 = 10_000;
+/// Latency penalty (in milliseconds) applied for each recorded node failure.
+const FAILURE_PENALTY_MSEC: u64 = 5_000;
+
+///
+/// Health record of a node obtained by probing the node
+/// returned by the {@link Resolver}.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeHealth {
+    /// Node descriptor as provided by the resolver.
+    pub node: NodeDescriptor,
+    /// Round-trip latency of the probe in milliseconds
+    /// (`None` if the node was unreachable).
+    pub latency: Option<u64>,
+    /// Node sync status (`None` if the node was unreachable).
+    pub is_synced: Option<bool>,
+    /// Number of connection failures recorded against this node.
+    pub failures: u32,
+}
+
+impl NodeHealth {
+    pub fn new(node: NodeDescriptor, latency: Option<u64>, is_synced: Option<bool>) -> Self {
+        Self { node, latency, is_synced, failures: 0 }
+    }
+
+    /// Returns `true` if the node responded to the probe.
+    pub fn is_reachable(&self) -> bool {
+        self.latency.is_some()
+    }
+
+    /// Node score; lower is better. Unreachable nodes
+    /// are always ranked last.
+    pub fn score(&self) -> u64 {
+        match self.latency {
+            Some(latency) => {
+                let unsynced_penalty = if self.is_synced.unwrap_or(false) { 0 } else { UNSYNCED_PENALTY_MSEC };
+                latency.saturating_add(unsynced_penalty).saturating_add(self.failures as u64 * FAILURE_PENALTY_MSEC)
+            }
+            None => u64::MAX,
+        }
+    }
+}
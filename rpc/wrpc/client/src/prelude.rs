@@ -1,3 +1,4 @@
+pub use crate::backpressure::{NotificationBackpressure, NotificationOverflowPolicy};
 pub use crate::client::{ConnectOptions, ConnectStrategy};
 pub use crate::{KaspaRpcClient, Resolver, WrpcEncoding};
 pub use kaspa_consensus_core::network::{NetworkId, NetworkType};
@@ -1,12 +1,18 @@
 use crate::error::Error;
 use crate::imports::*;
-use crate::node::NodeDescriptor;
+use crate::node::{NodeDescriptor, NodeHealth};
 pub use futures::future::join_all;
 use rand::seq::SliceRandom;
 use rand::thread_rng;
+use std::time::Duration;
+use workflow_core::time::Instant;
 use workflow_http::get_json;
 
 const DEFAULT_VERSION: usize = 1;
+/// Node probe connection timeout.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Period after which the cached node ranking is considered stale.
+const RANKING_TTL: Duration = Duration::from_secs(5 * 60);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResolverRecord {
@@ -27,14 +33,34 @@ fn try_parse_resolvers(toml: &str) -> Result<Vec<Arc<String>>> {
         .collect::<Vec<_>>())
 }
 
+/// Cached node ranking for a specific encoding and network.
+#[derive(Debug)]
+struct Ranking {
+    encoding: Encoding,
+    network_id: NetworkId,
+    nodes: Vec<NodeHealth>,
+    timestamp: Instant,
+}
+
+impl Ranking {
+    fn is_valid(&self, encoding: Encoding, network_id: NetworkId) -> bool {
+        self.encoding == encoding && self.network_id == network_id && self.timestamp.elapsed() < RANKING_TTL
+    }
+
+    fn sort(&mut self) {
+        self.nodes.sort_by_key(|health| health.score());
+    }
+}
+
 #[derive(Debug)]
 struct Inner {
     pub urls: Vec<Arc<String>>,
+    ranking: Mutex<Option<Ranking>>,
 }
 
 impl Inner {
     pub fn new(urls: Vec<Arc<String>>) -> Self {
-        Self { urls }
+        Self { urls, ranking: Mutex::new(None) }
     }
 }
 
@@ -117,4 +143,99 @@ impl Resolver {
         let nodes = self.fetch(encoding, network_id).await?;
         Ok(nodes.url.clone())
     }
+
+    /// Connect to the node, measure the round-trip latency of the
+    /// `get_server_info()` call and obtain the node sync status.
+    async fn probe(node: NodeDescriptor, encoding: Encoding, network_id: NetworkId) -> NodeHealth {
+        let probe = async {
+            let client = KaspaRpcClient::new(encoding, Some(node.url.as_str()), None, Some(network_id), None)?;
+            let options = ConnectOptions {
+                block_async_connect: true,
+                strategy: ConnectStrategy::Fallback,
+                connect_timeout: Some(PROBE_TIMEOUT),
+                ..Default::default()
+            };
+            client.connect(Some(options)).await?;
+            let ts = Instant::now();
+            let server_info = client.get_server_info().await;
+            let latency = ts.elapsed().as_millis() as u64;
+            client.disconnect().await?;
+            let server_info = server_info?;
+            if server_info.network_id != network_id {
+                return Err(Error::custom(format!("node {} network id mismatch: {}", node.url, server_info.network_id)));
+            }
+            Ok::<_, Error>((latency, server_info.is_synced))
+        };
+
+        match probe.await {
+            Ok((latency, is_synced)) => NodeHealth::new(node, Some(latency), Some(is_synced)),
+            Err(err) => {
+                log_trace!("Resolver: unable to probe node {}: {err}", node.url);
+                NodeHealth::new(node, None, None)
+            }
+        }
+    }
+
+    /// Fetch all available nodes, probe them and rank them by latency and
+    /// sync status. The resulting ranking is cached and can be obtained
+    /// via [`Resolver::ranking()`].
+    pub async fn rank(&self, encoding: Encoding, network_id: NetworkId) -> Result<Vec<NodeHealth>> {
+        let nodes = self.fetch_all(encoding, network_id).await?;
+        let nodes = join_all(nodes.into_iter().map(|node| Self::probe(node, encoding, network_id))).await;
+
+        let mut ranking = Ranking { encoding, network_id, nodes, timestamp: Instant::now() };
+        ranking.sort();
+        let nodes = ranking.nodes.clone();
+        self.inner.ranking.lock().unwrap().replace(ranking);
+        Ok(nodes)
+    }
+
+    /// Returns the cached node ranking, re-ranking nodes if the
+    /// cache is stale or was created for a different network.
+    pub async fn ranking(&self, encoding: Encoding, network_id: NetworkId) -> Result<Vec<NodeHealth>> {
+        if let Some(ranking) = self.inner.ranking.lock().unwrap().as_ref() {
+            if ranking.is_valid(encoding, network_id) {
+                return Ok(ranking.nodes.clone());
+            }
+        }
+
+        self.rank(encoding, network_id).await
+    }
+
+    /// Discard the cached node ranking.
+    pub fn clear_ranking(&self) {
+        self.inner.ranking.lock().unwrap().take();
+    }
+
+    /// Record a connection failure against the node with the given `url`,
+    /// lowering its position in the cached ranking. Returns the total number
+    /// of failures recorded against the node.
+    pub fn report_failure(&self, url: &str) -> u32 {
+        let mut ranking = self.inner.ranking.lock().unwrap();
+        if let Some(ranking) = ranking.as_mut() {
+            if let Some(health) = ranking.nodes.iter_mut().find(|health| health.node.url == url) {
+                health.failures += 1;
+                let failures = health.failures;
+                ranking.sort();
+                return failures;
+            }
+        }
+        0
+    }
+
+    /// Returns the best ranked reachable node, optionally excluding the node
+    /// with the given `url`. Falls back to [`Resolver::get_node()`] if no
+    /// ranked node is available.
+    pub async fn get_best_node(&self, encoding: Encoding, network_id: NetworkId, exclude: Option<&str>) -> Result<NodeDescriptor> {
+        let ranking = self.ranking(encoding, network_id).await?;
+        let node = ranking
+            .into_iter()
+            .find(|health| health.is_reachable() && exclude.map(|url| url != health.node.url).unwrap_or(true))
+            .map(|health| health.node);
+
+        match node {
+            Some(node) => Ok(node),
+            None => self.get_node(encoding, network_id).await,
+        }
+    }
 }
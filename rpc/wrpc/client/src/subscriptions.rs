@@ -0,0 +1,220 @@
+//!
+//! Registry of the notification subscriptions active on the wRPC server.
+//!
+//! Server-side subscriptions are bound to the WebSocket connection and
+//! are lost when the connection drops, while the client-side notifier
+//! keeps considering them active. [`SubscriptionRegistry`] records the
+//! scopes submitted to the server, along with whether they have been
+//! submitted over the current connection, so that [`KaspaRpcClient`]
+//! can replay the missing ones once the connection is re-established.
+//!
+
+use crate::imports::*;
+use kaspa_addresses::Address;
+use kaspa_notify::events::EventType;
+use std::collections::HashMap;
+
+// Each active scope is tracked with a flag indicating if
+// it has been submitted over the current connection.
+#[derive(Default)]
+struct Inner {
+    // active scopes of all events except `UtxosChanged`
+    events: HashMap<EventType, (Scope, bool)>,
+    // `UtxosChanged` subscription covering all addresses
+    utxos_changed_all: Option<bool>,
+    // `UtxosChanged` subscription addresses
+    addresses: HashMap<Address, bool>,
+    // incremented each time the connection changes
+    generation: u64,
+}
+
+/// Notification scopes submitted to the wRPC server via
+/// `Subscribe` and `Unsubscribe` calls.
+#[derive(Default)]
+pub struct SubscriptionRegistry {
+    inner: Mutex<Inner>,
+}
+
+impl SubscriptionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a scope requested by the notifier. Returns the part of the scope
+    /// that has not been submitted over the current connection yet (if any).
+    pub fn register(&self, scope: &Scope) -> Option<Scope> {
+        let mut inner = self.inner.lock().unwrap();
+        match scope {
+            Scope::UtxosChanged(utxos_changed) if utxos_changed.addresses.is_empty() => {
+                let submitted = *inner.utxos_changed_all.get_or_insert(false);
+                (!submitted).then(|| scope.clone())
+            }
+            Scope::UtxosChanged(utxos_changed) => {
+                let addresses = utxos_changed
+                    .addresses
+                    .iter()
+                    .filter(|address| !*inner.addresses.entry((*address).clone()).or_default())
+                    .cloned()
+                    .collect::<Vec<_>>();
+                (!addresses.is_empty()).then(|| Scope::UtxosChanged(UtxosChangedScope::new(addresses)))
+            }
+            _ => match inner.events.get(&scope.event_type()) {
+                Some((active, true)) if active == scope => None,
+                _ => {
+                    inner.events.insert(scope.event_type(), (scope.clone(), false));
+                    Some(scope.clone())
+                }
+            },
+        }
+    }
+
+    /// Record a scope removed by the notifier. Returns the part of the scope
+    /// that has been submitted over the current connection (if any).
+    pub fn unregister(&self, scope: &Scope) -> Option<Scope> {
+        let mut inner = self.inner.lock().unwrap();
+        match scope {
+            Scope::UtxosChanged(utxos_changed) if utxos_changed.addresses.is_empty() => {
+                let submitted = inner.utxos_changed_all.take() == Some(true);
+                let addresses = std::mem::take(&mut inner.addresses);
+                (submitted || addresses.values().any(|submitted| *submitted)).then(|| scope.clone())
+            }
+            Scope::UtxosChanged(utxos_changed) => {
+                let addresses = utxos_changed
+                    .addresses
+                    .iter()
+                    .filter(|address| inner.addresses.remove(*address).unwrap_or_default())
+                    .cloned()
+                    .collect::<Vec<_>>();
+                (!addresses.is_empty()).then(|| Scope::UtxosChanged(UtxosChangedScope::new(addresses)))
+            }
+            _ => inner.events.remove(&scope.event_type()).and_then(|(_, submitted)| submitted.then(|| scope.clone())),
+        }
+    }
+
+    /// Mark a scope as submitted over the current connection (if it is still active).
+    pub fn submitted(&self, scope: &Scope) {
+        let mut inner = self.inner.lock().unwrap();
+        match scope {
+            Scope::UtxosChanged(utxos_changed) if utxos_changed.addresses.is_empty() => {
+                if let Some(submitted) = inner.utxos_changed_all.as_mut() {
+                    *submitted = true;
+                }
+            }
+            Scope::UtxosChanged(utxos_changed) => utxos_changed.addresses.iter().for_each(|address| {
+                if let Some(submitted) = inner.addresses.get_mut(address) {
+                    *submitted = true;
+                }
+            }),
+            _ => {
+                if let Some((active, submitted)) = inner.events.get_mut(&scope.event_type()) {
+                    if active == scope {
+                        *submitted = true;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the scopes of the active subscriptions that have
+    /// not been submitted over the current connection.
+    pub fn pending(&self) -> Vec<Scope> {
+        let inner = self.inner.lock().unwrap();
+        let mut scopes = inner.events.values().filter(|(_, submitted)| !submitted).map(|(scope, _)| scope.clone()).collect::<Vec<_>>();
+        if inner.utxos_changed_all == Some(false) {
+            scopes.push(Scope::UtxosChanged(UtxosChangedScope::new(vec![])));
+        } else if inner.utxos_changed_all.is_none() {
+            let addresses =
+                inner.addresses.iter().filter(|(_, submitted)| !**submitted).map(|(address, _)| address.clone()).collect::<Vec<_>>();
+            if !addresses.is_empty() {
+                scopes.push(Scope::UtxosChanged(UtxosChangedScope::new(addresses)));
+            }
+        }
+        scopes
+    }
+
+    /// Mark all active subscriptions as not submitted as the connection
+    /// has changed. Returns the generation identifying the connection.
+    pub fn reset(&self) -> u64 {
+        let mut inner = self.inner.lock().unwrap();
+        inner.events.values_mut().for_each(|(_, submitted)| *submitted = false);
+        if let Some(submitted) = inner.utxos_changed_all.as_mut() {
+            *submitted = false;
+        }
+        inner.addresses.values_mut().for_each(|submitted| *submitted = false);
+        inner.generation += 1;
+        inner.generation
+    }
+
+    /// Returns the generation identifying the current connection (see [`reset()`](Self::reset)).
+    pub fn generation(&self) -> u64 {
+        self.inner.lock().unwrap().generation
+    }
+
+    pub fn is_empty(&self) -> bool {
+        let inner = self.inner.lock().unwrap();
+        inner.events.is_empty() && inner.utxos_changed_all.is_none() && inner.addresses.is_empty()
+    }
+
+    pub fn clear(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        *inner = Inner { generation: inner.generation, ..Inner::default() };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_addresses::{Prefix, Version};
+
+    #[test]
+    fn test_subscription_registry() {
+        let address = |n: u8| Address::new(Prefix::Testnet, Version::PubKey, &[n; 32]);
+        let utxos_changed = |addresses: Vec<Address>| Scope::UtxosChanged(UtxosChangedScope::new(addresses));
+        let daa_score_changed = Scope::VirtualDaaScoreChanged(VirtualDaaScoreChangedScope {});
+        let chain_changed = |include_accepted: bool| Scope::VirtualChainChanged(VirtualChainChangedScope::new(include_accepted));
+        let submit = |registry: &SubscriptionRegistry, scope: &Scope| {
+            let pending = registry.register(scope);
+            if let Some(pending) = pending.as_ref() {
+                registry.submitted(pending);
+            }
+            pending
+        };
+
+        let registry = SubscriptionRegistry::new();
+        assert_eq!(submit(&registry, &daa_score_changed), Some(daa_score_changed.clone()));
+        assert_eq!(submit(&registry, &chain_changed(false)), Some(chain_changed(false)));
+        // the latest scope of an event replaces the previous one
+        assert_eq!(submit(&registry, &chain_changed(true)), Some(chain_changed(true)));
+        assert_eq!(submit(&registry, &utxos_changed(vec![address(1), address(2)])), Some(utxos_changed(vec![address(1), address(2)])));
+        // only the addresses that have not been submitted are submitted
+        assert_eq!(submit(&registry, &utxos_changed(vec![address(1), address(3)])), Some(utxos_changed(vec![address(3)])));
+        assert_eq!(submit(&registry, &daa_score_changed), None);
+        assert!(registry.pending().is_empty());
+
+        assert_eq!(registry.unregister(&utxos_changed(vec![address(2), address(4)])), Some(utxos_changed(vec![address(2)])));
+        assert_eq!(registry.unregister(&daa_score_changed), Some(daa_score_changed.clone()));
+
+        // all active scopes are pending once the connection changes
+        let generation = registry.generation();
+        assert_eq!(registry.reset(), generation + 1);
+        let pending = registry.pending();
+        assert_eq!(pending.len(), 2);
+        assert!(pending.contains(&chain_changed(true)));
+        assert!(
+            pending.contains(&utxos_changed(vec![address(3), address(1)]))
+                || pending.contains(&utxos_changed(vec![address(1), address(3)]))
+        );
+
+        // scopes that have not been submitted over the connection are not unsubscribed
+        assert_eq!(registry.unregister(&utxos_changed(vec![address(3)])), None);
+        registry.submitted(&chain_changed(true));
+        assert_eq!(registry.pending(), vec![utxos_changed(vec![address(1)])]);
+
+        // subscription to all addresses
+        assert_eq!(submit(&registry, &utxos_changed(vec![])), Some(utxos_changed(vec![])));
+        assert!(registry.pending().is_empty());
+        assert_eq!(registry.unregister(&utxos_changed(vec![])), Some(utxos_changed(vec![])));
+        assert_eq!(registry.unregister(&chain_changed(true)), Some(chain_changed(true)));
+        assert!(registry.is_empty());
+    }
+}
@@ -0,0 +1,256 @@
+//!
+//! Custom RPC transport support.
+//!
+//! By default [`KaspaRpcClient`] owns a WebSocket connection to the
+//! wRPC server. A client created using [`KaspaRpcClient::new_with_transport()`]
+//! instead relays all RPC calls over a user-supplied [`RpcTransport`]
+//! (for example a `postMessage()` bridge to a browser extension background
+//! service worker). The other end of the transport uses [`RpcTransportHost`]
+//! to execute the relayed calls against an RPC client that owns the actual
+//! node connection and to relay node notifications back.
+//!
+//! RPC payloads passed over the transport are always Borsh-serialized,
+//! regardless of the encoding used by the node connection.
+//!
+
+use crate::error::Error;
+use crate::imports::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use kaspa_notify::connection::ChannelType;
+use workflow_core::channel::{Multiplexer, Sender};
+use workflow_rpc::client::Ctl as WrpcCtl;
+
+/// Custom transport relaying RPC calls made by [`KaspaRpcClient`].
+#[async_trait]
+pub trait RpcTransport: Send + Sync + 'static {
+    /// Open the transport. The transport must signal the connection state
+    /// and relay node notifications using the supplied [`RpcTransportSink`].
+    async fn connect(&self, sink: RpcTransportSink) -> Result<()>;
+
+    /// Close the transport.
+    async fn disconnect(&self) -> Result<()>;
+
+    /// Relay a Borsh-serialized RPC request, returning the Borsh-serialized
+    /// response produced by [`RpcTransportHost::call()`].
+    async fn call(&self, op: RpcApiOps, payload: Vec<u8>) -> Result<Vec<u8>>;
+}
+
+/// Handle given to the [`RpcTransport`] on connection, used to relay the
+/// connection state and node notifications to the [`KaspaRpcClient`].
+#[derive(Clone)]
+pub struct RpcTransportSink {
+    connected: Arc<AtomicBool>,
+    ctl_multiplexer: Multiplexer<WrpcCtl>,
+    notification_sender: Sender<Notification>,
+}
+
+impl RpcTransportSink {
+    pub(crate) fn new(
+        connected: Arc<AtomicBool>,
+        ctl_multiplexer: Multiplexer<WrpcCtl>,
+        notification_sender: Sender<Notification>,
+    ) -> Self {
+        Self { connected, ctl_multiplexer, notification_sender }
+    }
+
+    /// Signal that the node connection has been established.
+    pub fn connected(&self) -> Result<()> {
+        if !self.connected.swap(true, Ordering::SeqCst) {
+            self.ctl_multiplexer.try_broadcast(WrpcCtl::Connect)?;
+        }
+        Ok(())
+    }
+
+    /// Signal that the node connection has been lost.
+    pub fn disconnected(&self) -> Result<()> {
+        if self.connected.swap(false, Ordering::SeqCst) {
+            self.ctl_multiplexer.try_broadcast(WrpcCtl::Disconnect)?;
+        }
+        Ok(())
+    }
+
+    /// Relay a node notification.
+    pub fn notify(&self, notification: Notification) -> Result<()> {
+        self.notification_sender.try_send(notification).map_err(|err| Error::ChannelError(err.to_string()))?;
+        Ok(())
+    }
+
+    /// Relay a Borsh-serialized node notification produced by the [`RpcTransportHost`].
+    pub fn notify_serialized(&self, data: &[u8]) -> Result<()> {
+        self.notify(Notification::try_from_slice(data)?)
+    }
+}
+
+/// Envelope carrying an RPC request over transports that relay opaque binary messages.
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct RpcTransportRequest {
+    pub op: RpcApiOps,
+    pub payload: Vec<u8>,
+}
+
+/// Envelope carrying the RPC call result over transports that relay opaque binary messages.
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub enum RpcTransportResponse {
+    Success(Vec<u8>),
+    Error(String),
+}
+
+impl From<Result<Vec<u8>>> for RpcTransportResponse {
+    fn from(result: Result<Vec<u8>>) -> Self {
+        match result {
+            Ok(data) => RpcTransportResponse::Success(data),
+            Err(err) => RpcTransportResponse::Error(err.to_string()),
+        }
+    }
+}
+
+impl From<RpcTransportResponse> for Result<Vec<u8>> {
+    fn from(response: RpcTransportResponse) -> Self {
+        match response {
+            RpcTransportResponse::Success(data) => Ok(data),
+            RpcTransportResponse::Error(err) => Err(Error::Custom(err)),
+        }
+    }
+}
+
+const RPC_TRANSPORT_HOST: &str = "rpc-transport-host";
+
+/// Executes RPC calls relayed by an [`RpcTransport`] against the
+/// supplied [`RpcApi`] and collects node notifications for the
+/// subscriptions made over the transport.
+pub struct RpcTransportHost {
+    rpc_api: Arc<dyn RpcApi>,
+    listener_id: ListenerId,
+    notification_channel: Channel<Notification>,
+}
+
+impl RpcTransportHost {
+    pub fn new(rpc_api: Arc<dyn RpcApi>) -> Self {
+        let notification_channel = Channel::unbounded();
+        let listener_id = rpc_api.register_new_listener(ChannelConnection::new(
+            RPC_TRANSPORT_HOST,
+            notification_channel.sender.clone(),
+            ChannelType::Persistent,
+        ));
+        Self { rpc_api, listener_id, notification_channel }
+    }
+
+    /// Receiver of the node notifications that should be relayed to the
+    /// transport client (see [`RpcTransportSink::notify_serialized()`]).
+    pub fn notification_receiver(&self) -> Receiver<Notification> {
+        self.notification_channel.receiver.clone()
+    }
+
+    /// Execute a relayed Borsh-serialized RPC request,
+    /// returning the Borsh-serialized response.
+    pub async fn call(&self, op: RpcApiOps, payload: &[u8]) -> Result<Vec<u8>> {
+        match op {
+            RpcApiOps::Subscribe => {
+                self.rpc_api.start_notify(self.listener_id, Scope::try_from_slice(payload)?).await?;
+                Ok(SubscribeResponse::new(self.listener_id).try_to_vec()?)
+            }
+            RpcApiOps::Unsubscribe => {
+                self.rpc_api.stop_notify(self.listener_id, Scope::try_from_slice(payload)?).await?;
+                Ok(UnsubscribeResponse {}.try_to_vec()?)
+            }
+            _ => self.dispatch(op, payload).await,
+        }
+    }
+
+    /// Execute a relayed [`RpcTransportRequest`] envelope, returning
+    /// the serialized [`RpcTransportResponse`] envelope.
+    pub async fn handle(&self, message: &[u8]) -> Result<Vec<u8>> {
+        let RpcTransportRequest { op, payload } = RpcTransportRequest::try_from_slice(message)?;
+        let response = RpcTransportResponse::from(self.call(op, &payload).await);
+        Ok(response.try_to_vec()?)
+    }
+
+    /// Stop receiving notifications for the subscriptions made over the transport.
+    pub async fn close(&self) -> Result<()> {
+        self.rpc_api.unregister_listener(self.listener_id).await?;
+        self.notification_channel.sender.close();
+        Ok(())
+    }
+
+    async fn dispatch(&self, op: RpcApiOps, payload: &[u8]) -> Result<Vec<u8>> {
+        // the match is exhaustive so that any op added to `RpcApiOps`
+        // must be either dispatched or explicitly declared unsupported
+        macro_rules! dispatch {
+            ([$($op:ident),* $(,)?], [$($unsupported:ident),* $(,)?]) => {
+                paste::paste! {
+                    match op {
+                        $(RpcApiOps::$op => {
+                            let request = [<$op Request>]::try_from_slice(payload)?;
+                            Ok(self.rpc_api.[<$op:snake _call>](request).await?.try_to_vec()?)
+                        })*
+                        $(RpcApiOps::$unsupported)|* => Err(Error::custom(format!("RPC method {op:?} is not supported by the transport"))),
+                    }
+                }
+            };
+        }
+
+        dispatch!(
+            [
+                AddPeer,
+                Ban,
+                EstimateNetworkHashesPerSecond,
+                GetBalanceByAddress,
+                GetBalancesByAddresses,
+                GetBlock,
+                GetBlockCount,
+                GetBlockDagInfo,
+                GetBlocks,
+                GetBlockTemplate,
+                GetCoinSupply,
+                GetConnectedPeerInfo,
+                GetDaaScoreTimestampEstimate,
+                GetServerInfo,
+                GetCurrentNetwork,
+                GetHeaders,
+                GetInfo,
+                GetMempoolEntries,
+                GetMempoolEntriesByAddresses,
+                GetMempoolEntry,
+                GetPeerAddresses,
+                GetMetrics,
+                GetSink,
+                GetSyncStatus,
+                GetSubnetwork,
+                GetUtxosByAddresses,
+                GetSinkBlueScore,
+                GetVirtualChainFromBlock,
+                Ping,
+                ResolveFinalityConflict,
+                Shutdown,
+                SubmitBlock,
+                SubmitTransaction,
+                Unban,
+                ValidateTransaction,
+                GetUtxosByAddressesPage,
+                GetWrpcServerStatus,
+            ],
+            [
+                Subscribe,
+                Unsubscribe,
+                NotifyBlockAdded,
+                NotifyNewBlockTemplate,
+                NotifyUtxosChanged,
+                NotifyPruningPointUtxoSetOverride,
+                NotifyFinalityConflict,
+                NotifyFinalityConflictResolved,
+                NotifyVirtualDaaScoreChanged,
+                NotifyVirtualChainChanged,
+                NotifySinkBlueScoreChanged,
+                BlockAddedNotification,
+                VirtualChainChangedNotification,
+                FinalityConflictNotification,
+                FinalityConflictResolvedNotification,
+                UtxosChangedNotification,
+                SinkBlueScoreChangedNotification,
+                VirtualDaaScoreChangedNotification,
+                PruningPointUtxoSetOverrideNotification,
+                NewBlockTemplateNotification,
+            ]
+        )
+    }
+}
@@ -213,6 +213,9 @@ impl Listener {
                                             log_error!("Error in disconnect handler: {err}");
                                         }
                                     }
+                                    RpcState::SubscriptionsRestored => {
+                                        log_info!("Subscriptions restored after reconnection");
+                                    }
                                 }
                             }
                             Err(err) => {
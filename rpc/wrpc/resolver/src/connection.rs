@@ -143,6 +143,7 @@ impl Connection {
                                     self.update(false).await?;
                                     log_error!("Disconnected","{}",self.node.address);
                                 }
+                                RpcState::SubscriptionsRestored => {}
                             }
                         }
                         Err(err) => {
@@ -1,15 +1,23 @@
+use kaspa_core::time::unix_now;
 use kaspa_grpc_client::{GrpcClient, GrpcClientNotify};
 use kaspa_notify::{
     connection::Connection as ConnectionT,
     error::{Error as NotifyError, Result as NotifyResult},
+    events::EventType,
     listener::ListenerId,
     notification::Notification as NotificationT,
     notifier::Notify,
+    scope::{Scope, UtxosChangedScope},
 };
-use kaspa_rpc_core::{api::ops::RpcApiOps, notify::mode::NotificationMode, Notification};
+use kaspa_rpc_core::{api::ops::RpcApiOps, notify::mode::NotificationMode, Notification, RpcAddress, RpcWrpcConnectionInfo};
 use std::{
+    collections::HashSet,
     fmt::{Debug, Display},
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
 };
 use workflow_log::log_trace;
 use workflow_rpc::{
@@ -42,6 +50,82 @@ impl From<NotifyEncoding> for Encoding {
     }
 }
 
+/// Fixed one-second window request counter used for per-connection rate limiting.
+#[derive(Debug)]
+struct RequestWindow {
+    start: Instant,
+    requests: u32,
+}
+
+impl Default for RequestWindow {
+    fn default() -> Self {
+        Self { start: Instant::now(), requests: 0 }
+    }
+}
+
+impl RequestWindow {
+    fn register(&mut self, max_requests_per_second: u32) -> bool {
+        if self.start.elapsed() >= Duration::from_secs(1) {
+            *self = RequestWindow::default();
+        }
+        self.requests = self.requests.saturating_add(1);
+        self.requests <= max_requests_per_second
+    }
+}
+
+/// Active notification subscriptions of a connection, tracked for quota
+/// enforcement and connection statistics.
+#[derive(Debug, Default)]
+struct Subscriptions {
+    events: HashSet<EventType>,
+    addresses: HashSet<RpcAddress>,
+}
+
+impl Subscriptions {
+    fn len(&self) -> usize {
+        self.events.len() + self.addresses.len()
+    }
+
+    fn register(&mut self, scope: &Scope, max_subscriptions: usize) -> crate::result::Result<Option<Scope>> {
+        let registered = match scope {
+            Scope::UtxosChanged(scope) => {
+                let addresses =
+                    scope.addresses.iter().filter(|address| !self.addresses.contains(address)).cloned().collect::<Vec<_>>();
+                (!addresses.is_empty()).then(|| Scope::UtxosChanged(UtxosChangedScope::new(addresses)))
+            }
+            _ => (!self.events.contains(&scope.event_type())).then(|| scope.clone()),
+        };
+        let additional = match &registered {
+            Some(Scope::UtxosChanged(scope)) => scope.addresses.len(),
+            Some(_) => 1,
+            None => 0,
+        };
+        if self.len() + additional > max_subscriptions {
+            return Err(crate::error::Error::SubscriptionQuotaExceeded(max_subscriptions));
+        }
+        match &registered {
+            Some(Scope::UtxosChanged(scope)) => self.addresses.extend(scope.addresses.iter().cloned()),
+            Some(scope) => {
+                self.events.insert(scope.event_type());
+            }
+            None => {}
+        }
+        Ok(registered)
+    }
+
+    fn unregister(&mut self, scope: &Scope) {
+        match scope {
+            Scope::UtxosChanged(scope) if scope.addresses.is_empty() => self.addresses.clear(),
+            Scope::UtxosChanged(scope) => scope.addresses.iter().for_each(|address| {
+                self.addresses.remove(address);
+            }),
+            _ => {
+                self.events.remove(&scope.event_type());
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 struct ConnectionInner {
     pub id: u64,
@@ -50,11 +134,19 @@ struct ConnectionInner {
     pub grpc_client: Option<Arc<GrpcClient>>,
     // not using an atomic in case an Id will change type in the future...
     pub listener_id: Mutex<Option<ListenerId>>,
+    request_window: Mutex<RequestWindow>,
+    subscriptions: Mutex<Subscriptions>,
+    /// Connection time (unix time in milliseconds)
+    connected_at: u64,
+    messages_in: AtomicU64,
+    messages_out: AtomicU64,
 }
 
 impl ConnectionInner {
     fn send(&self, message: Message) -> crate::result::Result<()> {
-        Ok(self.messenger.send_raw_message(message)?)
+        self.messenger.send_raw_message(message)?;
+        self.messages_out.fetch_add(1, Ordering::Relaxed);
+        Ok(())
     }
 }
 
@@ -87,7 +179,71 @@ impl Connection {
         assert!(grpc_client.is_none() || grpc_client.as_ref().unwrap().notification_mode() == NotificationMode::Direct);
         // Should a gRPC client be provided, no listener_id is required for subscriptions so the listener id is set to default
         let listener_id = Mutex::new(grpc_client.clone().map(|_| ListenerId::default()));
-        Connection { inner: Arc::new(ConnectionInner { id, peer: *peer, messenger, grpc_client, listener_id }) }
+        Connection {
+            inner: Arc::new(ConnectionInner {
+                id,
+                peer: *peer,
+                messenger,
+                grpc_client,
+                listener_id,
+                request_window: Default::default(),
+                subscriptions: Default::default(),
+                connected_at: unix_now(),
+                messages_in: Default::default(),
+                messages_out: Default::default(),
+            }),
+        }
+    }
+
+    /// Account an RPC request received from the peer.
+    pub fn record_message_in(&self) {
+        self.inner.messages_in.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Account an RPC response sent to the peer (notifications are
+    /// accounted when posted to the connection).
+    pub fn record_message_out(&self) {
+        self.inner.messages_out.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Connection statistics reported by `GetWrpcServerStatus`.
+    pub fn info(&self) -> RpcWrpcConnectionInfo {
+        let subscriptions = self.inner.subscriptions.lock().unwrap();
+        let mut events = subscriptions.events.iter().map(|event| event.to_string()).collect::<Vec<_>>();
+        if !subscriptions.addresses.is_empty() {
+            events.push(EventType::UtxosChanged.to_string());
+        }
+        events.sort();
+        RpcWrpcConnectionInfo {
+            id: self.inner.id,
+            peer: self.inner.peer.to_string(),
+            encoding: self.messenger().encoding().to_string(),
+            connected_at: self.inner.connected_at,
+            messages_in: self.inner.messages_in.load(Ordering::Relaxed),
+            messages_out: self.inner.messages_out.load(Ordering::Relaxed),
+            subscriptions: events,
+            utxos_changed_addresses: subscriptions.addresses.len() as u64,
+        }
+    }
+
+    /// Register an incoming request against the per-second request limit.
+    /// Returns `false` if the limit has been exceeded within the current window.
+    pub fn register_request(&self, max_requests_per_second: u32) -> bool {
+        self.inner.request_window.lock().unwrap().register(max_requests_per_second)
+    }
+
+    /// Register a subscription `scope`, failing (and leaving the active
+    /// subscriptions unchanged) if it would exceed `max_subscriptions`.
+    /// Returns the scope of the subscriptions that were not already active
+    /// (`None` if there are none), to be released should the subscription fail.
+    pub fn register_subscription(&self, scope: &Scope, max_subscriptions: usize) -> crate::result::Result<Option<Scope>> {
+        self.inner.subscriptions.lock().unwrap().register(scope, max_subscriptions)
+    }
+
+    /// Release the subscriptions held by `scope`. An empty `UtxosChanged`
+    /// address set releases all address subscriptions.
+    pub fn unregister_subscription(&self, scope: &Scope) {
+        self.inner.subscriptions.lock().unwrap().unregister(scope)
     }
 
     /// Obtain the connection id
@@ -181,3 +337,100 @@ impl ConnectionT for Connection {
 }
 
 pub type ConnectionReference = Arc<Connection>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_notify::scope::{BlockAddedScope, VirtualDaaScoreChangedScope};
+    use kaspa_rpc_core::VirtualDaaScoreChangedNotification;
+
+    fn address(address: &str) -> RpcAddress {
+        RpcAddress::try_from(address).unwrap()
+    }
+
+    fn utxos_changed(addresses: &[&RpcAddress]) -> Scope {
+        Scope::UtxosChanged(UtxosChangedScope::new(addresses.iter().map(|address| (*address).clone()).collect()))
+    }
+
+    #[test]
+    fn test_request_window() {
+        let mut window = RequestWindow::default();
+        assert!((0..3).all(|_| window.register(3)));
+        assert!(!window.register(3));
+
+        // a new window resets the request count
+        window.start -= Duration::from_secs(1);
+        assert!(window.register(3));
+        assert_eq!(window.requests, 1);
+    }
+
+    #[test]
+    fn test_subscription_quota() {
+        let a = address("kaspa:qz7ulu4c25dh7fzec9zjyrmlhnkzrg4wmf89q7gzr3gfrsj3uz6xjellj43pf");
+        let b = address("kaspa:qzn3qjzf2nzyd3zj303nk4sgv0aae42v3ufutk5xsxckfels57dxjjed4qvlx");
+        let c = address("kaspa:qz0skffpert8cav6h2c9nfndmhzzfhvkrjexclmwgjjwt0sutysnw6lp55ak0");
+        let block_added = Scope::BlockAdded(BlockAddedScope {});
+        let mut subscriptions = Subscriptions::default();
+
+        assert_eq!(subscriptions.register(&block_added, 3).unwrap(), Some(block_added.clone()));
+        // an already active subscription is not registered again
+        assert_eq!(subscriptions.register(&block_added, 3).unwrap(), None);
+        assert_eq!(subscriptions.register(&utxos_changed(&[&a, &b]), 3).unwrap(), Some(utxos_changed(&[&a, &b])));
+        assert_eq!(subscriptions.register(&utxos_changed(&[&a]), 3).unwrap(), None);
+        assert_eq!(subscriptions.len(), 3);
+
+        // exceeding the quota leaves the subscriptions unchanged
+        let err = subscriptions.register(&utxos_changed(&[&a, &c]), 3).unwrap_err();
+        assert!(matches!(err, crate::error::Error::SubscriptionQuotaExceeded(3)));
+        assert!(subscriptions.register(&Scope::VirtualDaaScoreChanged(VirtualDaaScoreChangedScope {}), 3).is_err());
+        assert_eq!(subscriptions.len(), 3);
+
+        // releasing the newly registered part of a failed subscription
+        // keeps the subscriptions that were already active
+        subscriptions.unregister(&block_added);
+        let registered = subscriptions.register(&utxos_changed(&[&a, &c]), 3).unwrap().unwrap();
+        assert_eq!(registered, utxos_changed(&[&c]));
+        subscriptions.unregister(&registered);
+        assert_eq!(subscriptions.addresses, HashSet::from([a.clone(), b.clone()]));
+
+        // an empty address set releases all address subscriptions
+        subscriptions.unregister(&utxos_changed(&[]));
+        assert_eq!(subscriptions.len(), 0);
+    }
+
+    #[test]
+    fn test_connection_info() {
+        let (sink, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let messenger = Arc::new(Messenger::new(Encoding::Borsh, &sink));
+        let peer = "127.0.0.1:17110".parse().unwrap();
+        let connection = Connection::new(7, &peer, messenger, None);
+
+        connection.record_message_in();
+        connection.record_message_in();
+        connection.record_message_out();
+        // notifications are accounted when posted to the connection
+        let notification = Notification::VirtualDaaScoreChanged(VirtualDaaScoreChangedNotification { virtual_daa_score: 1 });
+        connection.inner.notify(notification).unwrap();
+        assert!(receiver.try_recv().is_ok());
+
+        let a = address("kaspa:qz7ulu4c25dh7fzec9zjyrmlhnkzrg4wmf89q7gzr3gfrsj3uz6xjellj43pf");
+        let b = address("kaspa:qzn3qjzf2nzyd3zj303nk4sgv0aae42v3ufutk5xsxckfels57dxjjed4qvlx");
+        connection.register_subscription(&Scope::BlockAdded(BlockAddedScope {}), usize::MAX).unwrap();
+        connection.register_subscription(&utxos_changed(&[&a, &b]), usize::MAX).unwrap();
+
+        let info = connection.info();
+        assert_eq!((info.id, info.messages_in, info.messages_out), (7, 2, 2));
+        assert_eq!(info.peer, "127.0.0.1:17110");
+        assert_eq!(info.encoding, Encoding::Borsh.to_string());
+        let mut subscriptions = vec![EventType::BlockAdded.to_string(), EventType::UtxosChanged.to_string()];
+        subscriptions.sort();
+        assert_eq!(info.subscriptions, subscriptions);
+        assert_eq!(info.utxos_changed_addresses, 2);
+
+        // an empty address set releases the UtxosChanged subscription
+        connection.unregister_subscription(&utxos_changed(&[]));
+        let info = connection.info();
+        assert_eq!(info.subscriptions, vec![EventType::BlockAdded.to_string()]);
+        assert_eq!(info.utxos_changed_addresses, 0);
+    }
+}
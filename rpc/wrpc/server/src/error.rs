@@ -20,6 +20,15 @@ pub enum Error {
 
     #[error("Notify error: {0}")]
     NotifyError(#[from] NotifyError),
+
+    #[error("Rate limit exceeded: maximum of {0} requests per second")]
+    RateLimitExceeded(u32),
+
+    #[error("Subscription quota exceeded: maximum of {0} subscriptions per connection")]
+    SubscriptionQuotaExceeded(usize),
+
+    #[error("TLS error: {0}")]
+    Tls(String),
 }
 
 impl<T> From<PoisonError<T>> for Error {
@@ -67,6 +67,8 @@ impl Router {
                 SubmitBlock,
                 SubmitTransaction,
                 Unban,
+                ValidateTransaction,
+                GetUtxosByAddressesPage,
             ]
         );
 
@@ -74,7 +76,16 @@ impl Router {
             RpcApiOps::Subscribe,
             workflow_rpc::server::Method::new(move |manager: Server, connection: Connection, scope: Scope| {
                 Box::pin(async move {
-                    manager.start_notify(&connection, scope).await.map_err(|err| err.to_string())?;
+                    manager.check_request_quota(&connection).map_err(|err| err.to_string())?;
+                    let registered = manager.check_subscription_quota(&connection, &scope).map_err(|err| err.to_string())?;
+                    manager.start_notify(&connection, scope).await.map_err(|err| {
+                        // release the quota held by the failed subscription
+                        if let Some(registered) = &registered {
+                            connection.unregister_subscription(registered);
+                        }
+                        err.to_string()
+                    })?;
+                    connection.record_message_out();
                     Ok(SubscribeResponse::new(connection.id()))
                 })
             }),
@@ -84,14 +95,29 @@ impl Router {
             RpcApiOps::Unsubscribe,
             workflow_rpc::server::Method::new(move |manager: Server, connection: Connection, scope: Scope| {
                 Box::pin(async move {
+                    connection.record_message_in();
+                    connection.unregister_subscription(&scope);
                     manager.stop_notify(&connection, scope).await.unwrap_or_else(|err| {
                         workflow_log::log_trace!("wRPC server -> error calling stop_notify(): {err}");
                     });
+                    connection.record_message_out();
                     Ok(UnsubscribeResponse {})
                 })
             }),
         );
 
+        interface.method(
+            RpcApiOps::GetWrpcServerStatus,
+            workflow_rpc::server::Method::new(move |manager: Server, connection: Connection, _: GetWrpcServerStatusRequest| {
+                Box::pin(async move {
+                    manager.check_request_quota(&connection).map_err(|err| err.to_string())?;
+                    let response = manager.get_wrpc_server_status();
+                    connection.record_message_out();
+                    Ok(response.map_err(|err| err.to_string())?)
+                })
+            }),
+        );
+
         Router { interface: Arc::new(interface), server_context }
     }
 }
@@ -1,6 +1,7 @@
 use crate::{
     collector::{WrpcServiceCollector, WrpcServiceConverter},
     connection::Connection,
+    error::Error,
     result::Result,
     service::Options,
 };
@@ -17,7 +18,7 @@ use kaspa_notify::{
 use kaspa_rpc_core::{
     api::rpc::{DynRpcService, RpcApi},
     notify::{channel::NotificationChannel, connection::ChannelConnection, mode::NotificationMode},
-    Notification, RpcResult,
+    GetWrpcServerStatusResponse, Notification, RpcError, RpcResult, RpcWrpcConnectionInfo,
 };
 use kaspa_rpc_service::service::RpcCoreService;
 use std::{
@@ -216,6 +217,60 @@ impl Server {
         self.inner.options.verbose
     }
 
+    /// Account the request and enforce the per-connection request rate
+    /// limit (see [`Options::max_requests_per_second`]).
+    pub fn check_request_quota(&self, connection: &Connection) -> Result<()> {
+        connection.record_message_in();
+        match self.inner.options.max_requests_per_second {
+            Some(max_requests_per_second) if !connection.register_request(max_requests_per_second) => {
+                Err(Error::RateLimitExceeded(max_requests_per_second))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Register the subscription and enforce the per-connection subscription
+    /// quota (see [`Options::max_subscriptions_per_connection`]). Returns the
+    /// newly registered subscriptions (see [`Connection::register_subscription`]).
+    pub fn check_subscription_quota(&self, connection: &Connection, scope: &Scope) -> Result<Option<Scope>> {
+        let max_subscriptions = self.inner.options.max_subscriptions_per_connection.unwrap_or(usize::MAX);
+        connection.register_subscription(scope, max_subscriptions)
+    }
+
+    /// Statistics of all active connections, ordered by connection id.
+    pub fn connections(&self) -> Vec<RpcWrpcConnectionInfo> {
+        let mut connections = self.inner.sockets.lock().unwrap().values().map(|connection| connection.info()).collect::<Vec<_>>();
+        connections.sort_by_key(|info| info.id);
+        connections
+    }
+
+    /// Handle the `GetWrpcServerStatus` RPC call. Connection statistics expose
+    /// peer addresses, so a node serves them in the unsafe RPC mode only.
+    pub fn get_wrpc_server_status(&self) -> RpcResult<GetWrpcServerStatusResponse> {
+        if let Some(rpc_core) = &self.inner.rpc_core {
+            if !rpc_core.service.is_unsafe_rpc() {
+                return Err(RpcError::UnavailableInSafeMode);
+            }
+        }
+        Ok(GetWrpcServerStatusResponse::new(self.connections()))
+    }
+
+    /// Log a summary of the active connections (nothing is logged if there are none).
+    pub fn log_status(&self) {
+        let connections = self.connections();
+        if connections.is_empty() {
+            return;
+        }
+        let messages_in = connections.iter().map(|info| info.messages_in).sum::<u64>();
+        let messages_out = connections.iter().map(|info| info.messages_out).sum::<u64>();
+        let subscribed = connections.iter().filter(|info| !info.subscriptions.is_empty()).count();
+        let addresses = connections.iter().map(|info| info.utxos_changed_addresses).sum::<u64>();
+        log_info!(
+            "wRPC server: {} connections ({subscribed} subscribed, {addresses} UtxosChanged addresses), {messages_in} messages in, {messages_out} messages out",
+            connections.len()
+        );
+    }
+
     pub async fn join(&self) -> Result<()> {
         if let Some(rpc_core) = &self.inner.rpc_core {
             // Wait for the internal notifier to stop
@@ -1,30 +1,54 @@
-use crate::{connection::*, router::*, server::*};
+use crate::{
+    connection::*,
+    result::Result,
+    router::*,
+    server::*,
+    tls::{reserve_loopback_address, TlsOptions, TlsPeers, TlsRelay},
+};
 use async_trait::async_trait;
 use kaspa_core::{
-    info,
+    error, info,
     task::service::{AsyncService, AsyncServiceError, AsyncServiceFuture},
     trace, warn,
 };
 use kaspa_rpc_core::api::ops::RpcApiOps;
 use kaspa_rpc_service::service::RpcCoreService;
 use kaspa_utils::triggers::SingleTrigger;
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use tokio::sync::oneshot::{channel as oneshot_channel, Sender as OneshotSender};
 use workflow_rpc::server::prelude::*;
 pub use workflow_rpc::server::{Encoding as WrpcEncoding, WebSocketConfig, WebSocketCounters};
 
 static MAX_WRPC_MESSAGE_SIZE: usize = 1024 * 1024 * 128; // 128MB
+const STATUS_LOG_INTERVAL: Duration = Duration::from_secs(600);
 
 /// Options for configuring the wRPC server
 pub struct Options {
     pub listen_address: String,
     pub grpc_proxy_address: Option<String>,
     pub verbose: bool,
+    /// Maximum number of RPC method calls a single connection can issue per second
+    pub max_requests_per_second: Option<u32>,
+    /// Maximum number of active notification subscriptions per connection
+    /// (each event type and each `UtxosChanged` address counts as one subscription)
+    pub max_subscriptions_per_connection: Option<usize>,
+    /// Serve `wss://` connections using the supplied certificate and private key
+    pub tls: Option<TlsOptions>,
+    /// Interval of the connection statistics log summary (`None` disables the summary)
+    pub status_log_interval: Option<Duration>,
 }
 
 impl Default for Options {
     fn default() -> Self {
-        Options { listen_address: "127.0.0.1:17110".to_owned(), verbose: false, grpc_proxy_address: None }
+        Options {
+            listen_address: "127.0.0.1:17110".to_owned(),
+            verbose: false,
+            grpc_proxy_address: None,
+            max_requests_per_second: None,
+            max_subscriptions_per_connection: None,
+            tls: None,
+            status_log_interval: Some(STATUS_LOG_INTERVAL),
+        }
     }
 }
 
@@ -46,6 +70,8 @@ impl Default for Options {
 pub struct KaspaRpcHandler {
     pub server: Server,
     pub options: Arc<Options>,
+    /// Connections relayed by the [`TlsRelay`] (TLS enabled only)
+    pub tls_peers: Option<Arc<TlsPeers>>,
 }
 
 impl KaspaRpcHandler {
@@ -55,7 +81,20 @@ impl KaspaRpcHandler {
         core_service: Option<Arc<RpcCoreService>>,
         options: Arc<Options>,
     ) -> KaspaRpcHandler {
-        KaspaRpcHandler { server: Server::new(tasks, encoding, core_service, options.clone()), options }
+        let tls_peers = options.tls.is_some().then(Default::default);
+        KaspaRpcHandler { server: Server::new(tasks, encoding, core_service, options.clone()), options, tls_peers }
+    }
+
+    /// Resolve the address of the connecting peer. With TLS enabled the server
+    /// is reachable only through the [`TlsRelay`], connections that did not
+    /// pass through the relay are rejected.
+    fn resolve_peer(&self, peer: &SocketAddr) -> WebSocketResult<SocketAddr> {
+        match &self.tls_peers {
+            Some(tls_peers) => {
+                tls_peers.resolve(peer).ok_or_else(|| format!("wRPC connection from {peer} was not relayed via TLS").into())
+            }
+            None => Ok(*peer),
+        }
     }
 }
 
@@ -63,6 +102,19 @@ impl KaspaRpcHandler {
 impl RpcHandler for KaspaRpcHandler {
     type Context = Connection;
 
+    /// With TLS enabled, only connections registered by the [`TlsRelay`] are accepted
+    /// (confirming the acceptance to the relay, see [`TlsPeers::accept()`]).
+    fn accept(&self, peer: &SocketAddr) -> bool {
+        match &self.tls_peers {
+            Some(tls_peers) => tls_peers.accept(peer),
+            None => true,
+        }
+    }
+
+    async fn connect(self: Arc<Self>, peer: &SocketAddr) -> WebSocketResult<()> {
+        self.resolve_peer(peer).map(|_| ())
+    }
+
     async fn handshake(
         self: Arc<Self>,
         peer: &SocketAddr,
@@ -79,7 +131,8 @@ impl RpcHandler for KaspaRpcHandler {
         // )
         // .await
 
-        let connection = self.server.connect(peer, messenger).await.map_err(|err| err.to_string())?;
+        let peer = self.resolve_peer(peer)?;
+        let connection = self.server.connect(&peer, messenger).await.map_err(|err| err.to_string())?;
         Ok(connection)
     }
 
@@ -99,6 +152,7 @@ pub struct WrpcService {
     options: Arc<Options>,
     server: RpcServer,
     rpc_handler: Arc<KaspaRpcHandler>,
+    counters: Arc<WebSocketCounters>,
     shutdown: SingleTrigger,
 }
 
@@ -122,38 +176,67 @@ impl WrpcService {
             *encoding,
             rpc_handler.clone(),
             router.interface.clone(),
-            Some(counters),
+            Some(counters.clone()),
         );
 
-        WrpcService { options, server, rpc_handler, shutdown: SingleTrigger::default() }
+        WrpcService { options, server, rpc_handler, counters, shutdown: SingleTrigger::default() }
     }
 
-    /// Start listening on the configured address (will panic if the socket listen() fails)
-    pub fn serve(self: Arc<Self>) -> OneshotSender<()> {
-        let (termination_sender, termination_receiver) = oneshot_channel::<()>();
+    /// Start listening on the configured address (will panic if the socket listen() fails).
+    /// Returns an error if the TLS certificate can not be loaded or the TLS relay can not
+    /// be set up, in which case the server is not started.
+    pub async fn serve(self: Arc<Self>) -> Result<OneshotSender<()>> {
         let listen_address = self.options.listen_address.clone();
+
+        // With TLS enabled, the server listens on a loopback
+        // address receiving connections from the TLS relay
+        let (server_address, tls_relay) = match (&self.options.tls, &self.rpc_handler.tls_peers) {
+            (Some(tls), Some(tls_peers)) => {
+                let config = tls.server_config()?;
+                let target = reserve_loopback_address()?;
+                let listener = TlsRelay::bind(&listen_address).await?;
+                let relay = TlsRelay::new(config, target, tls_peers.clone(), self.counters.clone());
+                (target.to_string(), Some((Arc::new(relay), listener)))
+            }
+            _ => (listen_address.clone(), None),
+        };
+        let tls_relay = tls_relay.map(|(relay, listener)| {
+            tokio::spawn(relay.clone().serve(listener));
+            relay
+        });
+
+        let (termination_sender, termination_receiver) = oneshot_channel::<()>();
         self.rpc_handler.server.start();
 
         // Spawn a task stopping the server on termination signal
         let service = self.clone();
+        let relay = tls_relay.clone();
         tokio::spawn(async move {
             let _ = termination_receiver.await;
+            if let Some(relay) = relay {
+                relay.stop();
+            }
             service.server.stop().unwrap_or_else(|err| warn!("wRPC unable to signal shutdown: `{err}`"));
             service.server.join().await.unwrap_or_else(|err| warn!("wRPC error: `{err}"));
         });
 
+        if tls_relay.is_some() {
+            info!("WRPC Server starting on: {} (TLS)", listen_address);
+        } else {
+            info!("WRPC Server starting on: {}", listen_address);
+        }
+
         // Spawn a task running the server
-        info!("WRPC Server starting on: {}", listen_address);
         tokio::spawn(async move {
             let config = WebSocketConfig { max_message_size: Some(MAX_WRPC_MESSAGE_SIZE), ..Default::default() };
-            let serve_result = self.server.listen(&listen_address, Some(config)).await;
+            let serve_result = self.server.listen(&server_address, Some(config)).await;
             match serve_result {
                 Ok(_) => info!("WRPC Server stopped on: {}", listen_address),
                 Err(err) => panic!("WRPC Server {listen_address} stopped with error: {err:?}"),
             }
         });
 
-        termination_sender
+        Ok(termination_sender)
     }
 }
 
@@ -170,11 +253,31 @@ impl AsyncService for WrpcService {
         // Prepare a shutdown signal receiver
         let shutdown_signal = self.shutdown.listener.clone();
 
-        // Run the server
-        trace!("{} running the wRPC server", WRPC_SERVER);
-        let terminate_server = self.clone().serve();
-
         Box::pin(async move {
+            // Run the server
+            trace!("{} running the wRPC server", WRPC_SERVER);
+            let terminate_server = self.clone().serve().await.map_err(|err| {
+                error!("WRPC Server {} failed to start: {err}", self.options.listen_address);
+                AsyncServiceError::Service(format!("wRPC server error: `{err}`"))
+            })?;
+
+            // Periodically log the connection statistics
+            if let Some(interval) = self.options.status_log_interval {
+                let server = self.rpc_handler.server.clone();
+                let shutdown_signal = shutdown_signal.clone();
+                tokio::spawn(async move {
+                    let mut interval = tokio::time::interval(interval);
+                    interval.tick().await;
+                    tokio::pin!(shutdown_signal);
+                    loop {
+                        tokio::select! {
+                            _ = &mut shutdown_signal => break,
+                            _ = interval.tick() => server.log_status(),
+                        }
+                    }
+                });
+            }
+
             // Keep the gRPC server running until a service shutdown signal is received
             shutdown_signal.await;
 
@@ -0,0 +1,304 @@
+//!
+//! Native TLS (`wss://`) support for the wRPC server.
+//!
+//! The underlying websocket server binds its own listener and accepts plain
+//! TCP streams only, so TLS can not be terminated by the server listener.
+//! When TLS is configured, the [`TlsRelay`] listens on the public address,
+//! terminates TLS and relays each decrypted stream to the wRPC server bound
+//! to an ephemeral loopback port.
+//!
+//! The loopback hop is authenticated in both directions by [`TlsPeers`]:
+//! the relay registers the local address of each relayed connection before
+//! connecting and relays no data until the wRPC server has confirmed the
+//! acceptance of this connection (see [`TlsPeers::accept()`]), while the
+//! server rejects connections that were not registered by the relay. A local
+//! process that takes over the loopback port therefore never receives
+//! decrypted data. Failed TLS handshakes are accounted for in the websocket
+//! server `handshake_failures` counter.
+//!
+
+use crate::error::Error;
+use crate::result::Result;
+use kaspa_core::{debug, trace, warn};
+use kaspa_utils::triggers::SingleTrigger;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpSocket, TcpStream};
+use tokio::sync::oneshot;
+use tokio_rustls::rustls::{Certificate, PrivateKey, ServerConfig};
+use tokio_rustls::TlsAcceptor;
+use workflow_rpc::server::WebSocketCounters;
+
+/// Maximum time a client is given to complete the TLS handshake.
+const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Maximum time given to the wRPC server to accept a relayed connection.
+const RELAY_ACCEPT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// TLS certificate configuration of the wRPC server.
+#[derive(Debug, Clone)]
+pub struct TlsOptions {
+    /// PEM file containing the certificate chain
+    pub cert_path: PathBuf,
+    /// PEM file containing the private key (PKCS#8, PKCS#1 or SEC1)
+    pub key_path: PathBuf,
+}
+
+impl TlsOptions {
+    pub fn new(cert_path: PathBuf, key_path: PathBuf) -> Self {
+        Self { cert_path, key_path }
+    }
+
+    /// Load the certificate chain and the private key into a rustls [`ServerConfig`].
+    pub fn server_config(&self) -> Result<Arc<ServerConfig>> {
+        let certs = rustls_pemfile::certs(&mut Self::reader(&self.cert_path)?)
+            .map_err(|err| Error::Tls(format!("unable to parse certificates in `{}`: {err}", self.cert_path.display())))?;
+        if certs.is_empty() {
+            return Err(Error::Tls(format!("no certificates found in `{}`", self.cert_path.display())));
+        }
+
+        let key = rustls_pemfile::read_all(&mut Self::reader(&self.key_path)?)
+            .map_err(|err| Error::Tls(format!("unable to parse private key in `{}`: {err}", self.key_path.display())))?
+            .into_iter()
+            .find_map(|item| match item {
+                rustls_pemfile::Item::PKCS8Key(key) | rustls_pemfile::Item::RSAKey(key) | rustls_pemfile::Item::ECKey(key) => {
+                    Some(key)
+                }
+                _ => None,
+            })
+            .ok_or_else(|| Error::Tls(format!("no private key found in `{}`", self.key_path.display())))?;
+
+        let config = ServerConfig::builder()
+            .with_safe_defaults()
+            .with_no_client_auth()
+            .with_single_cert(certs.into_iter().map(Certificate).collect(), PrivateKey(key))
+            .map_err(|err| Error::Tls(err.to_string()))?;
+
+        Ok(Arc::new(config))
+    }
+
+    fn reader(path: &Path) -> Result<BufReader<File>> {
+        let file = File::open(path).map_err(|err| Error::Tls(format!("unable to open `{}`: {err}", path.display())))?;
+        Ok(BufReader::new(file))
+    }
+}
+
+/// Reserve an ephemeral loopback address for the wRPC server receiving relayed connections.
+/// The address is released before the server binds it: should another process bind it
+/// in the meantime, relayed connections are never confirmed (see [`TlsPeers`]) and no
+/// decrypted data is relayed.
+pub(crate) fn reserve_loopback_address() -> Result<SocketAddr> {
+    let listener =
+        std::net::TcpListener::bind("127.0.0.1:0").map_err(|err| Error::Tls(format!("unable to bind loopback address: {err}")))?;
+    listener.local_addr().map_err(|err| Error::Tls(err.to_string()))
+}
+
+struct RelayedPeer {
+    peer: SocketAddr,
+    accepted: Option<oneshot::Sender<()>>,
+}
+
+/// Maps the loopback addresses of relayed connections to the addresses of the original peers.
+#[derive(Default)]
+pub struct TlsPeers {
+    peers: Mutex<HashMap<SocketAddr, RelayedPeer>>,
+}
+
+impl TlsPeers {
+    /// Resolve the original peer address of a relayed connection.
+    pub fn resolve(&self, relay_address: &SocketAddr) -> Option<SocketAddr> {
+        self.peers.lock().unwrap().get(relay_address).map(|relayed| relayed.peer)
+    }
+
+    /// Called by the wRPC server when accepting the TCP connection from `relay_address`,
+    /// confirming the acceptance to the relay. Returns `false` if the connection was not
+    /// registered by the relay (and should be rejected).
+    pub fn accept(&self, relay_address: &SocketAddr) -> bool {
+        match self.peers.lock().unwrap().get_mut(relay_address) {
+            Some(relayed) => {
+                if let Some(accepted) = relayed.accepted.take() {
+                    accepted.send(()).ok();
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn insert(&self, relay_address: SocketAddr, peer: SocketAddr) -> oneshot::Receiver<()> {
+        let (accepted, receiver) = oneshot::channel();
+        self.peers.lock().unwrap().insert(relay_address, RelayedPeer { peer, accepted: Some(accepted) });
+        receiver
+    }
+
+    fn remove(&self, relay_address: &SocketAddr) {
+        self.peers.lock().unwrap().remove(relay_address);
+    }
+}
+
+/// TLS terminating relay forwarding decrypted streams to the wRPC server.
+pub struct TlsRelay {
+    acceptor: TlsAcceptor,
+    target: SocketAddr,
+    peers: Arc<TlsPeers>,
+    counters: Arc<WebSocketCounters>,
+    shutdown: SingleTrigger,
+}
+
+impl TlsRelay {
+    pub fn new(config: Arc<ServerConfig>, target: SocketAddr, peers: Arc<TlsPeers>, counters: Arc<WebSocketCounters>) -> Self {
+        Self { acceptor: TlsAcceptor::from(config), target, peers, counters, shutdown: SingleTrigger::default() }
+    }
+
+    /// Bind the public `listen_address` the relay accepts TLS connections on.
+    pub async fn bind(listen_address: &str) -> Result<TcpListener> {
+        TcpListener::bind(listen_address).await.map_err(|err| Error::Tls(format!("unable to listen on `{listen_address}`: {err}")))
+    }
+
+    /// Accept TLS connections on the bound `listener` until [`TlsRelay::stop()`] is called.
+    /// Failing handshakes are counted, logged and dropped without affecting other connections.
+    pub async fn serve(self: Arc<Self>, listener: TcpListener) {
+        loop {
+            tokio::select! {
+                _ = self.shutdown.listener.clone() => break,
+                accepted = listener.accept() => match accepted {
+                    Ok((stream, peer)) => {
+                        tokio::spawn(self.clone().relay(stream, peer));
+                    }
+                    Err(err) => warn!("wRPC TLS relay unable to accept connection: {err}"),
+                },
+            }
+        }
+    }
+
+    pub fn stop(&self) {
+        self.shutdown.trigger.trigger();
+    }
+
+    async fn relay(self: Arc<Self>, stream: TcpStream, peer: SocketAddr) {
+        let mut tls_stream = match tokio::time::timeout(TLS_HANDSHAKE_TIMEOUT, self.acceptor.accept(stream)).await {
+            Ok(Ok(tls_stream)) => tls_stream,
+            Ok(Err(err)) => {
+                self.counters.handshake_failures.fetch_add(1, Ordering::Relaxed);
+                debug!("wRPC TLS handshake with {peer} failed: {err}");
+                return;
+            }
+            Err(_) => {
+                self.counters.handshake_failures.fetch_add(1, Ordering::Relaxed);
+                debug!("wRPC TLS handshake with {peer} timed out");
+                return;
+            }
+        };
+
+        // the local address of the relayed connection is registered before connecting,
+        // the wRPC server confirms the acceptance as soon as the connection is accepted
+        let socket = match TcpSocket::new_v4().and_then(|socket| socket.bind("127.0.0.1:0".parse().unwrap()).map(|_| socket)) {
+            Ok(socket) => socket,
+            Err(err) => {
+                warn!("wRPC TLS relay unable to bind a loopback socket: {err}");
+                return;
+            }
+        };
+        let Ok(relay_address) = socket.local_addr() else {
+            return;
+        };
+        let accepted = self.peers.insert(relay_address, peer);
+
+        match socket.connect(self.target).await {
+            Ok(mut target) => match tokio::time::timeout(RELAY_ACCEPT_TIMEOUT, accepted).await {
+                Ok(Ok(())) => {
+                    if let Err(err) = tokio::io::copy_bidirectional(&mut tls_stream, &mut target).await {
+                        trace!("wRPC TLS relay connection with {peer} closed: {err}");
+                    }
+                }
+                _ => warn!("wRPC TLS relay connection to {} was not accepted by the wRPC server", self.target),
+            },
+            Err(err) => warn!("wRPC TLS relay unable to connect to {}: {err}", self.target),
+        }
+
+        self.peers.remove(&relay_address);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio_rustls::rustls::{ClientConfig, RootCertStore, ServerName};
+    use tokio_rustls::TlsConnector;
+
+    fn options() -> TlsOptions {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/testdata");
+        TlsOptions::new(path.join("localhost.crt"), path.join("localhost.key"))
+    }
+
+    fn connector() -> TlsConnector {
+        let certs = rustls_pemfile::certs(&mut TlsOptions::reader(&options().cert_path).unwrap()).unwrap();
+        let mut roots = RootCertStore::empty();
+        roots.add_parsable_certificates(&certs);
+        let config = ClientConfig::builder().with_safe_defaults().with_root_certificates(roots).with_no_client_auth();
+        TlsConnector::from(Arc::new(config))
+    }
+
+    /// Start a relay in front of a stand-in wRPC server echoing the relayed data.
+    /// The stand-in server confirms accepted connections if `confirm` is set.
+    async fn start_relay(confirm: bool) -> (SocketAddr, Arc<WebSocketCounters>) {
+        let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let peers = Arc::new(TlsPeers::default());
+        let counters = Arc::new(WebSocketCounters::default());
+        let relay = TlsRelay::new(options().server_config().unwrap(), server.local_addr().unwrap(), peers.clone(), counters.clone());
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, relay_address)) = server.accept().await {
+                if confirm && !peers.accept(&relay_address) {
+                    continue;
+                }
+                tokio::spawn(async move {
+                    let (mut reader, mut writer) = stream.split();
+                    tokio::io::copy(&mut reader, &mut writer).await.ok();
+                });
+            }
+        });
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(Arc::new(relay).serve(listener));
+        (address, counters)
+    }
+
+    async fn echo(address: SocketAddr) -> std::io::Result<Vec<u8>> {
+        let stream = TcpStream::connect(address).await?;
+        let mut stream = connector().connect(ServerName::try_from("localhost").unwrap(), stream).await?;
+        stream.write_all(b"ping").await?;
+        let mut buffer = vec![0u8; 4];
+        let len = stream.read(&mut buffer).await?;
+        buffer.truncate(len);
+        Ok(buffer)
+    }
+
+    #[tokio::test]
+    async fn test_tls_relay_handshake() {
+        let (address, counters) = start_relay(true).await;
+        assert_eq!(echo(address).await.unwrap(), b"ping");
+
+        // a failing handshake is counted and does not affect other connections
+        let mut stream = TcpStream::connect(address).await.unwrap();
+        stream.write_all(b"GET / HTTP/1.1\r\nHost: localhost\r\n\r\n").await.unwrap();
+        let mut buffer = vec![0u8; 1024];
+        while matches!(stream.read(&mut buffer).await, Ok(len) if len > 0) {}
+        assert_eq!(counters.handshake_failures.load(Ordering::Relaxed), 1);
+        assert_eq!(echo(address).await.unwrap(), b"ping");
+    }
+
+    #[tokio::test]
+    async fn test_tls_relay_unconfirmed_connection() {
+        // no data is relayed to a server that did not confirm the connection
+        let (address, _) = start_relay(false).await;
+        assert!(!matches!(echo(address).await, Ok(data) if !data.is_empty()));
+    }
+}
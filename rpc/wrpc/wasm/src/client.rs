@@ -1,6 +1,8 @@
 #![allow(non_snake_case)]
 
+use crate::filter::{DaaScoreCoalescer, NotificationFilter};
 use crate::imports::*;
+use crate::transport::{IRpcTransport, JsRpcTransport};
 use crate::Resolver;
 use crate::{RpcEventCallback, RpcEventType, RpcEventTypeOrCallback};
 use js_sys::{Function, Object};
@@ -18,6 +20,8 @@ pub use kaspa_rpc_macros::{
 };
 use kaspa_wasm_core::events::{get_event_targets, Sink};
 pub use serde_wasm_bindgen::from_value;
+use workflow_core::task::sleep;
+use workflow_core::time::Instant;
 use workflow_rpc::client::Ctl;
 pub use workflow_rpc::client::IConnectOptions;
 pub use workflow_rpc::encoding::Encoding as WrpcEncoding;
@@ -52,6 +56,19 @@ declare! {
          * `networkId` is required when using a resolver.
          */
         networkId?: NetworkId | string;
+        /**
+         * Custom transport relaying RPC calls (for example to a background
+         * service worker) instead of connecting to the node directly.
+         * If supplying a transport, the `url` and `resolver` properties are ignored.
+         */
+        transport?: IRpcTransport;
+        /**
+         * Maximum number of `virtual-daa-score-changed` events per second
+         * delivered to the event listeners. Notifications received in between
+         * are coalesced and only the latest one is delivered.
+         * If not supplied, all notifications are delivered.
+         */
+        daaScoreNotificationRate?: number;
     }
     "#,
 }
@@ -61,11 +78,20 @@ pub struct RpcConfig {
     pub url: Option<String>,
     pub encoding: Option<Encoding>,
     pub network_id: Option<NetworkId>,
+    pub transport: Option<IRpcTransport>,
+    pub daa_score_notification_rate: Option<u32>,
 }
 
 impl Default for RpcConfig {
     fn default() -> Self {
-        RpcConfig { url: None, encoding: Some(Encoding::Borsh), network_id: None, resolver: None }
+        RpcConfig {
+            url: None,
+            encoding: Some(Encoding::Borsh),
+            network_id: None,
+            resolver: None,
+            transport: None,
+            daa_score_notification_rate: None,
+        }
     }
 }
 
@@ -76,12 +102,19 @@ impl TryFrom<IRpcConfig> for RpcConfig {
         let url = config.try_get_string("url")?;
         let encoding = config.try_get::<Encoding>("encoding")?;
         let network_id = config.try_get::<NetworkId>("networkId")?;
+        let transport = config.try_get_value("transport")?.map(JsCast::unchecked_into::<IRpcTransport>);
+        let daa_score_notification_rate = config
+            .try_get_value("daaScoreNotificationRate")?
+            .map(|rate| {
+                rate.as_f64().map(|rate| rate as u32).ok_or_else(|| Error::custom("daaScoreNotificationRate must be a number"))
+            })
+            .transpose()?;
 
         if resolver.is_some() && network_id.is_none() {
             return Err(Error::custom("networkId is required when using a resolver"));
         }
 
-        Ok(RpcConfig { resolver, url, encoding, network_id })
+        Ok(RpcConfig { resolver, url, encoding, network_id, transport, daa_score_notification_rate })
     }
 }
 
@@ -93,6 +126,8 @@ impl TryFrom<RpcConfig> for IRpcConfig {
         object.set("url", &config.url.into())?;
         object.set("encoding", &config.encoding.into())?;
         object.set("networkId", &config.network_id.into())?;
+        object.set("transport", &config.transport.into())?;
+        object.set("daaScoreNotificationRate", &config.daa_score_notification_rate.into())?;
         Ok(object)
     }
 }
@@ -138,9 +173,39 @@ pub struct Inner {
     callbacks: Arc<Mutex<AHashMap<NotificationEvent, Vec<Sink>>>>,
     listener_id: Arc<Mutex<Option<ListenerId>>>,
     notification_channel: Channel<kaspa_rpc_core::Notification>,
+    notification_filter: NotificationFilter,
+    daa_score_coalescer: Mutex<DaaScoreCoalescer>,
 }
 
 impl Inner {
+    fn new(client: Arc<KaspaRpcClient>, resolver: Option<Resolver>) -> Self {
+        Inner {
+            client,
+            resolver,
+            notification_task: AtomicBool::new(false),
+            notification_ctl: DuplexChannel::oneshot(),
+            callbacks: Arc::new(Mutex::new(Default::default())),
+            listener_id: Arc::new(Mutex::new(None)),
+            notification_channel: Channel::unbounded(),
+            notification_filter: NotificationFilter::default(),
+            daa_score_coalescer: Mutex::new(DaaScoreCoalescer::default()),
+        }
+    }
+
+    /// Update the notification filter after the event listeners have changed.
+    fn update_notification_filter(&self, callbacks: &AHashMap<NotificationEvent, Vec<Sink>>) {
+        let mut all = false;
+        let mut events = vec![];
+        for (event, _) in callbacks.iter().filter(|(_, handlers)| !handlers.is_empty()) {
+            match event {
+                NotificationEvent::All => all = true,
+                NotificationEvent::Notification(event_type) => events.push(*event_type),
+                NotificationEvent::RpcCtl(_) => {}
+            }
+        }
+        self.notification_filter.update(all, events);
+    }
+
     fn notification_callbacks(&self, event: NotificationEvent) -> Option<Vec<Sink>> {
         let notification_callbacks = self.callbacks.lock().unwrap();
         let all = notification_callbacks.get(&NotificationEvent::All).cloned();
@@ -188,6 +253,24 @@ impl Inner {
 /// });
 /// ```
 ///
+/// **Relaying RPC calls over a custom transport**
+///
+/// The RPC client can relay calls over an {@link IRpcTransport} instead of owning
+/// the WebSocket connection (for example, to share a single node connection owned
+/// by a browser extension background service worker). The receiving end executes
+/// the relayed calls using {@link RpcTransportHost}.
+///
+/// ```javascript
+/// let rpc = new RpcClient({
+///     networkId : "mainnet",
+///     transport : {
+///         connect(sink) { port.onMessage.addListener(({ notification }) => notification && sink.notify(notification)); sink.connected(); },
+///         disconnect() { port.disconnect(); },
+///         call(message) { return requestResponse(port, message); },
+///     },
+/// });
+/// ```
+///
 /// **Example usage**
 ///
 /// ```javascript
@@ -272,7 +355,14 @@ cfg_if! {
 
 impl RpcClient {
     pub fn new(config: Option<RpcConfig>) -> Result<RpcClient> {
-        let RpcConfig { resolver, url, encoding, network_id } = config.unwrap_or_default();
+        let RpcConfig { resolver, url, encoding, network_id, transport, daa_score_notification_rate } = config.unwrap_or_default();
+
+        if let Some(transport) = transport {
+            let client = Arc::new(KaspaRpcClient::new_with_transport(Arc::new(JsRpcTransport::new(transport)), network_id, None)?);
+            let rpc_client = Self::new_with_rpc_client(client);
+            rpc_client.inner.daa_score_coalescer.lock().unwrap().set_rate(daa_score_notification_rate);
+            return Ok(rpc_client);
+        }
 
         let encoding = encoding.unwrap_or(Encoding::Borsh);
 
@@ -293,17 +383,8 @@ impl RpcClient {
                 .unwrap_or_else(|err| panic!("{err}")),
         );
 
-        let rpc_client = RpcClient {
-            inner: Arc::new(Inner {
-                client,
-                resolver,
-                notification_task: AtomicBool::new(false),
-                notification_ctl: DuplexChannel::oneshot(),
-                callbacks: Arc::new(Default::default()),
-                listener_id: Arc::new(Mutex::new(None)),
-                notification_channel: Channel::unbounded(),
-            }),
-        };
+        let rpc_client = RpcClient { inner: Arc::new(Inner::new(client, resolver)) };
+        rpc_client.inner.daa_score_coalescer.lock().unwrap().set_rate(daa_score_notification_rate);
 
         Ok(rpc_client)
     }
@@ -538,13 +619,17 @@ impl RpcClient {
     pub fn add_event_listener(&self, event: RpcEventTypeOrCallback, callback: Option<RpcEventCallback>) -> Result<()> {
         if let Ok(sink) = Sink::try_from(&event) {
             let event = NotificationEvent::All;
-            self.inner.callbacks.lock().unwrap().entry(event).or_default().push(sink);
+            let mut callbacks = self.inner.callbacks.lock().unwrap();
+            callbacks.entry(event).or_default().push(sink);
+            self.inner.update_notification_filter(&callbacks);
             Ok(())
         } else if let Some(Ok(sink)) = callback.map(Sink::try_from) {
             let targets: Vec<NotificationEvent> = get_event_targets(event)?;
+            let mut callbacks = self.inner.callbacks.lock().unwrap();
             for event in targets {
-                self.inner.callbacks.lock().unwrap().entry(event).or_default().push(sink.clone());
+                callbacks.entry(event).or_default().push(sink.clone());
             }
+            self.inner.update_notification_filter(&callbacks);
             Ok(())
         } else {
             Err(Error::custom("Invalid event listener callback"))
@@ -581,6 +666,7 @@ impl RpcClient {
                 callbacks.remove(&event);
             }
         }
+        self.inner.update_notification_filter(&callbacks);
         Ok(())
     }
 
@@ -596,6 +682,7 @@ impl RpcClient {
         for (_, handlers) in notification_callbacks.iter_mut() {
             handlers.retain(|handler| handler != &sink);
         }
+        self.inner.update_notification_filter(&notification_callbacks);
         Ok(())
     }
 
@@ -605,24 +692,27 @@ impl RpcClient {
     #[wasm_bindgen(js_name = "removeAllEventListeners")]
     pub fn remove_all_event_listeners(&self) -> Result<()> {
         *self.inner.callbacks.lock().unwrap() = Default::default();
+        self.inner.notification_filter.update(false, []);
         Ok(())
     }
+
+    ///
+    /// Set the maximum number of `virtual-daa-score-changed` events per second
+    /// delivered to the event listeners. Notifications received in between are
+    /// coalesced and only the latest one is delivered. Supplying `undefined`
+    /// or `0` delivers all notifications.
+    ///
+    /// @see {@link IRpcConfig}
+    #[wasm_bindgen(js_name = "setDaaScoreNotificationRate")]
+    pub fn set_daa_score_notification_rate(&self, rate: Option<u32>) {
+        self.inner.daa_score_coalescer.lock().unwrap().set_rate(rate);
+    }
 }
 
 impl RpcClient {
     pub fn new_with_rpc_client(client: Arc<KaspaRpcClient>) -> RpcClient {
         let resolver = client.resolver().map(Into::into);
-        RpcClient {
-            inner: Arc::new(Inner {
-                client,
-                resolver,
-                notification_task: AtomicBool::new(false),
-                notification_ctl: DuplexChannel::oneshot(),
-                callbacks: Arc::new(Mutex::new(Default::default())),
-                listener_id: Arc::new(Mutex::new(None)),
-                notification_channel: Channel::unbounded(),
-            }),
-        }
+        RpcClient { inner: Arc::new(Inner::new(client, resolver)) }
     }
 
     pub fn listener_id(&self) -> Option<ListenerId> {
@@ -641,6 +731,50 @@ impl RpcClient {
         Ok(())
     }
 
+    /// Convert the notification into a JS object and execute
+    /// the event listeners registered for its event type.
+    fn relay_notification(&self, notification: &kaspa_rpc_core::Notification) {
+        match notification {
+            kaspa_rpc_core::Notification::UtxosChanged(utxos_changed_notification) => {
+                let event_type = EventType::UtxosChanged;
+                let notification_event = NotificationEvent::Notification(event_type);
+                if let Some(handlers) = self.inner.notification_callbacks(notification_event) {
+                    let UtxosChangedNotification { added, removed } = utxos_changed_notification;
+                    let added = js_sys::Array::from_iter(added.iter().map(UtxoEntryReference::from).map(JsValue::from));
+                    let removed = js_sys::Array::from_iter(removed.iter().map(UtxoEntryReference::from).map(JsValue::from));
+                    let notification = Object::new();
+                    notification.set("added", &added).unwrap();
+                    notification.set("removed", &removed).unwrap();
+
+                    for handler in handlers.into_iter() {
+                        let event = Object::new();
+                        let event_type_value = to_value(&event_type).unwrap();
+                        event.set("type", &event_type_value).expect("setting event type");
+                        event.set("data", &notification).expect("setting event data");
+                        if let Err(err) = handler.call(&event.into()) {
+                            log_error!("Error while executing RPC notification callback: {:?}", err);
+                        }
+                    }
+                }
+            }
+            _ => {
+                let event_type = notification.event_type();
+                let notification_event = NotificationEvent::Notification(event_type);
+                if let Some(handlers) = self.inner.notification_callbacks(notification_event) {
+                    for handler in handlers.into_iter() {
+                        let event = Object::new();
+                        let event_type_value = to_value(&event_type).unwrap();
+                        event.set("type", &event_type_value).expect("setting event type");
+                        event.set("data", &notification.to_value().unwrap()).expect("setting event data");
+                        if let Err(err) = handler.call(&event.into()) {
+                            log_error!("Error while executing RPC notification callback: {:?}", err);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     /// Notification task receives notifications and executes them on the
     /// user-supplied callback function.
     fn start_notification_task(&self) -> Result<()> {
@@ -659,6 +793,15 @@ impl RpcClient {
 
         spawn(async move {
             loop {
+                // relay the coalesced DAA score notification once its deadline is reached
+                let deadline = this.inner.daa_score_coalescer.lock().unwrap().deadline();
+                let flush = async move {
+                    match deadline {
+                        Some(deadline) => sleep(deadline.saturating_duration_since(Instant::now())).await,
+                        None => future::pending::<()>().await,
+                    }
+                };
+
                 select_biased! {
                     msg = ctl_multiplexer_channel.recv().fuse() => {
                         if let Ok(ctl) = msg {
@@ -673,6 +816,7 @@ impl RpcClient {
                                     *this.inner.listener_id.lock().unwrap() = Some(listener_id);
                                 }
                                 Ctl::Disconnect => {
+                                    this.inner.daa_score_coalescer.lock().unwrap().reset();
                                     let listener_id = this.inner.listener_id.lock().unwrap().take();
                                     if let Some(listener_id) = listener_id {
                                         if let Err(err) = this.inner.client.unregister_listener(listener_id).await {
@@ -696,50 +840,28 @@ impl RpcClient {
                         }
                     },
                     msg = notification_receiver.recv().fuse() => {
-                        if let Ok(notification) = &msg {
-                            match &notification {
-                                kaspa_rpc_core::Notification::UtxosChanged(utxos_changed_notification) => {
-
-                                    let event_type = EventType::UtxosChanged;
-                                    let notification_event = NotificationEvent::Notification(event_type);
-                                    if let Some(handlers) = this.inner.notification_callbacks(notification_event) {
-
-                                        let UtxosChangedNotification { added, removed } = utxos_changed_notification;
-                                        let added = js_sys::Array::from_iter(added.iter().map(UtxoEntryReference::from).map(JsValue::from));
-                                        let removed = js_sys::Array::from_iter(removed.iter().map(UtxoEntryReference::from).map(JsValue::from));
-                                        let notification = Object::new();
-                                        notification.set("added", &added).unwrap();
-                                        notification.set("removed", &removed).unwrap();
-
-                                        for handler in handlers.into_iter() {
-                                            let event = Object::new();
-                                            let event_type_value = to_value(&event_type).unwrap();
-                                            event.set("type", &event_type_value).expect("setting event type");
-                                            event.set("data", &notification).expect("setting event data");
-                                            if let Err(err) = handler.call(&event.into()) {
-                                                log_error!("Error while executing RPC notification callback: {:?}",err);
-                                            }
-                                        }
-                                    }
-                                },
-                                _ => {
-                                    let event_type = notification.event_type();
-                                    let notification_event = NotificationEvent::Notification(event_type);
-                                    if let Some(handlers) = this.inner.notification_callbacks(notification_event) {
-                                        for handler in handlers.into_iter() {
-                                            let event = Object::new();
-                                            let event_type_value = to_value(&event_type).unwrap();
-                                            event.set("type", &event_type_value).expect("setting event type");
-                                            event.set("data", &notification.to_value().unwrap()).expect("setting event data");
-                                            if let Err(err) = handler.call(&event.into()) {
-                                                log_error!("Error while executing RPC notification callback: {:?}",err);
-                                            }
-                                        }
-                                    }
+                        if let Ok(notification) = msg {
+                            // drop notifications without listeners before they cross the wasm/JS boundary
+                            if this.inner.notification_filter.is_active(notification.event_type()) {
+                                let notification = if matches!(notification, kaspa_rpc_core::Notification::VirtualDaaScoreChanged(_)) {
+                                    this.inner.daa_score_coalescer.lock().unwrap().push(notification, Instant::now())
+                                } else {
+                                    Some(notification)
+                                };
+                                if let Some(notification) = notification {
+                                    this.relay_notification(&notification);
                                 }
                             }
                         }
                     }
+                    _ = flush.fuse() => {
+                        let notification = this.inner.daa_score_coalescer.lock().unwrap().flush(Instant::now());
+                        if let Some(notification) = notification {
+                            if this.inner.notification_filter.is_active(notification.event_type()) {
+                                this.relay_notification(&notification);
+                            }
+                        }
+                    }
                     _ = ctl_receiver.recv().fuse() => {
                         break;
                     },
@@ -979,6 +1101,11 @@ build_wrpc_wasm_bindgen_interface!(
         /// Obtains basic information about the synchronization status of the Kaspa node.
         /// Returned information: Syncing status.
         GetSyncStatus,
+        /// Obtains statistics of the connections to the wRPC server
+        /// (available only in the node unsafe RPC mode).
+        /// Returned information: Peer address, connection time,
+        /// message counters and subscriptions of each connection.
+        GetWrpcServerStatus,
     ],
     [
         // functions with `request` argument
@@ -1048,5 +1175,13 @@ build_wrpc_wasm_bindgen_interface!(
         /// to the Kaspa node again.
         /// Returned information: None.
         Unban,
+        /// Validates a transaction against the Kaspa node mempool
+        /// acceptance rules without submitting it to the network.
+        /// Returned information: Calculated transaction fee and mass.
+        ValidateTransaction,
+        /// Retrieves a page of UTXOs for the given set of addresses, resuming
+        /// after the cursor returned with the previous page.
+        /// Returned information: UTXO entries and the next page cursor.
+        GetUtxosByAddressesPage,
     ]
 );
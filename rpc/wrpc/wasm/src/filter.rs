@@ -0,0 +1,172 @@
+//!
+//! Client-side filtering of the node notifications relayed to JS event listeners.
+//!
+//! Converting a notification into a JS object and invoking the JS callbacks
+//! is the most expensive part of the notification processing. [`NotificationFilter`]
+//! tracks the event types that have at least one registered listener, allowing
+//! the notification task to drop all other notifications before they cross
+//! the wasm/JS boundary. [`DaaScoreCoalescer`] limits the rate of the
+//! high-frequency `VirtualDaaScoreChanged` notifications.
+//!
+
+use kaspa_notify::events::{EventSwitches, EventType};
+use kaspa_rpc_core::Notification;
+use std::sync::Mutex;
+use std::time::Duration;
+use workflow_core::time::Instant;
+
+/// Event types with at least one registered event listener.
+#[derive(Default)]
+pub struct NotificationFilter {
+    listeners: Mutex<EventSwitches>,
+}
+
+impl NotificationFilter {
+    /// Replace the set of event types having listeners. If `all` is `true`,
+    /// a listener for all events is registered and every event type passes.
+    pub fn update(&self, all: bool, events: impl IntoIterator<Item = EventType>) {
+        let mut listeners = EventSwitches::from_fn(|_| all);
+        events.into_iter().for_each(|event| listeners[event] = true);
+        *self.listeners.lock().unwrap() = listeners;
+    }
+
+    pub fn is_active(&self, event: EventType) -> bool {
+        self.listeners.lock().unwrap()[event]
+    }
+}
+
+/// Limits the number of `VirtualDaaScoreChanged` notifications relayed per
+/// second. Notifications received within the rate interval are coalesced,
+/// retaining only the latest one, which is relayed once the interval elapses.
+#[derive(Default)]
+pub struct DaaScoreCoalescer {
+    // minimum interval between the relayed notifications (`None` if unlimited)
+    interval: Option<Duration>,
+    // time the last notification has been relayed
+    last: Option<Instant>,
+    // latest notification held back until the interval elapses
+    pending: Option<Notification>,
+}
+
+impl DaaScoreCoalescer {
+    /// Set the maximum number of notifications relayed per second
+    /// (`None` or `0` removes the limit).
+    pub fn set_rate(&mut self, rate: Option<u32>) {
+        self.interval = rate.filter(|rate| *rate > 0).map(|rate| Duration::from_secs(1) / rate);
+    }
+
+    /// Returns the notification if it can be relayed immediately,
+    /// otherwise holds it back replacing any pending notification.
+    pub fn push(&mut self, notification: Notification, now: Instant) -> Option<Notification> {
+        match (self.interval, self.last) {
+            (Some(interval), Some(last)) if now < last + interval => {
+                self.pending = Some(notification);
+                None
+            }
+            _ => {
+                self.last = Some(now);
+                self.pending = None;
+                Some(notification)
+            }
+        }
+    }
+
+    /// Time at which the pending notification is due to be relayed.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.pending.as_ref()?;
+        self.last.map(|last| last + self.interval.unwrap_or_default())
+    }
+
+    /// Take the pending notification if its deadline has been reached.
+    pub fn flush(&mut self, now: Instant) -> Option<Notification> {
+        if self.deadline().is_some_and(|deadline| deadline <= now) {
+            self.last = Some(now);
+            self.pending.take()
+        } else {
+            None
+        }
+    }
+
+    /// Drop the pending notification and reset the rate interval.
+    pub fn reset(&mut self) {
+        self.last = None;
+        self.pending = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_notify::events::EVENT_TYPE_ARRAY;
+    use kaspa_rpc_core::VirtualDaaScoreChangedNotification;
+
+    fn daa_score(virtual_daa_score: u64) -> Notification {
+        Notification::VirtualDaaScoreChanged(VirtualDaaScoreChangedNotification { virtual_daa_score })
+    }
+
+    fn score(notification: Option<Notification>) -> Option<u64> {
+        match notification? {
+            Notification::VirtualDaaScoreChanged(notification) => Some(notification.virtual_daa_score),
+            notification => panic!("unexpected notification {notification:?}"),
+        }
+    }
+
+    #[test]
+    fn test_notification_filter() {
+        let filter = NotificationFilter::default();
+        assert!(EVENT_TYPE_ARRAY.into_iter().all(|event| !filter.is_active(event)));
+
+        filter.update(false, [EventType::VirtualDaaScoreChanged, EventType::UtxosChanged]);
+        assert!(filter.is_active(EventType::VirtualDaaScoreChanged));
+        assert!(filter.is_active(EventType::UtxosChanged));
+        assert!(!filter.is_active(EventType::BlockAdded));
+
+        // the listener set is replaced by each update
+        filter.update(false, [EventType::BlockAdded]);
+        assert!(filter.is_active(EventType::BlockAdded));
+        assert!(!filter.is_active(EventType::VirtualDaaScoreChanged));
+
+        // a listener for all events activates every event type
+        filter.update(true, []);
+        assert!(EVENT_TYPE_ARRAY.into_iter().all(|event| filter.is_active(event)));
+        filter.update(false, []);
+        assert!(EVENT_TYPE_ARRAY.into_iter().all(|event| !filter.is_active(event)));
+    }
+
+    #[test]
+    fn test_daa_score_coalescer() {
+        let start = Instant::now();
+        let at = |millis: u64| start + Duration::from_millis(millis);
+
+        // without a rate limit every notification is relayed
+        let mut coalescer = DaaScoreCoalescer::default();
+        assert_eq!(score(coalescer.push(daa_score(1), at(0))), Some(1));
+        assert_eq!(score(coalescer.push(daa_score(2), at(0))), Some(2));
+        assert!(coalescer.deadline().is_none());
+
+        // 4 notifications per second: notifications within 250 msec are coalesced
+        coalescer.set_rate(Some(4));
+        assert_eq!(score(coalescer.push(daa_score(3), at(10))), None);
+        assert_eq!(score(coalescer.push(daa_score(4), at(100))), None);
+        assert_eq!(coalescer.deadline(), Some(at(250)));
+        assert_eq!(score(coalescer.flush(at(200))), None);
+        assert_eq!(score(coalescer.flush(at(250))), Some(4), "only the latest notification is relayed");
+        assert!(coalescer.deadline().is_none());
+        assert_eq!(score(coalescer.flush(at(600))), None);
+
+        // the interval is measured from the last relayed notification
+        assert_eq!(score(coalescer.push(daa_score(5), at(400))), None);
+        assert_eq!(score(coalescer.push(daa_score(6), at(500))), Some(6));
+        assert!(coalescer.deadline().is_none(), "the relayed notification replaces the pending one");
+
+        // reset drops the pending notification and restarts the interval
+        assert_eq!(score(coalescer.push(daa_score(7), at(600))), None);
+        coalescer.reset();
+        assert!(coalescer.deadline().is_none());
+        assert_eq!(score(coalescer.push(daa_score(8), at(610))), Some(8));
+
+        // a zero rate removes the limit
+        coalescer.set_rate(Some(0));
+        assert_eq!(score(coalescer.push(daa_score(9), at(611))), Some(9));
+    }
+}
@@ -2,6 +2,9 @@
 
 use cfg_if::cfg_if;
 
+#[cfg(any(feature = "wasm32-sdk", test))]
+mod filter;
+
 cfg_if! {
     if #[cfg(feature = "wasm32-sdk")] {
         mod imports;
@@ -11,6 +14,8 @@ cfg_if! {
         pub use resolver::*;
         pub mod notify;
         pub use notify::*;
+        pub mod transport;
+        pub use transport::*;
     }
 
 }
@@ -48,8 +48,10 @@ declare! {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ResolverConnect {
     pub encoding: Option<Encoding>,
+    #[serde(alias = "network_id")]
     pub network_id: NetworkId,
 }
 
@@ -153,7 +155,14 @@ impl Resolver {
     /// @see {@link IResolverConnect}, {@link RpcClient}
     pub async fn connect(&self, options: IResolverConnect) -> Result<RpcClient> {
         let ResolverConnect { encoding, network_id } = options.try_into()?;
-        let config = RpcConfig { resolver: Some(self.clone()), url: None, encoding, network_id: Some(network_id) };
+        let config = RpcConfig {
+            resolver: Some(self.clone()),
+            url: None,
+            encoding,
+            network_id: Some(network_id),
+            transport: None,
+            daa_score_notification_rate: None,
+        };
         let client = RpcClient::new(Some(config))?;
         client.connect(None).await?;
         Ok(client)
@@ -206,3 +215,29 @@ impl From<NativeResolver> for Resolver {
         Self { resolver }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_consensus_core::network::NetworkType;
+
+    #[test]
+    fn test_resolver_connect_serde() {
+        let connect =
+            ResolverConnect { encoding: Some(Encoding::Borsh), network_id: NetworkId::with_suffix(NetworkType::Testnet, 11) };
+
+        let json = serde_json::to_value(&connect).unwrap();
+        assert_eq!(json["networkId"], serde_json::to_value(connect.network_id).unwrap(), "unexpected serialization: {json}");
+        assert!(json.get("network_id").is_none());
+
+        let decoded: ResolverConnect = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.encoding, connect.encoding);
+        assert_eq!(decoded.network_id, connect.network_id);
+
+        // snake_case field names accepted by earlier versions must remain readable
+        let legacy = serde_json::json!({ "network_id": "testnet-11" });
+        let decoded: ResolverConnect = serde_json::from_value(legacy).unwrap();
+        assert_eq!(decoded.encoding, None);
+        assert_eq!(decoded.network_id, connect.network_id);
+    }
+}
@@ -0,0 +1,194 @@
+//!
+//! Custom RPC transport support allowing the {@link RpcClient} to relay RPC
+//! calls over an application-supplied channel (such as `postMessage()`)
+//! instead of owning a WebSocket connection.
+//!
+
+use crate::client::RpcClient;
+use crate::imports::*;
+use async_trait::async_trait;
+use borsh::{BorshDeserialize, BorshSerialize};
+use js_sys::{Object, Promise, Uint8Array};
+pub use kaspa_rpc_macros::declare_typescript_wasm_interface as declare;
+use kaspa_wrpc_client::transport as native;
+use wasm_bindgen_futures::JsFuture;
+use workflow_core::sendable::Sendable;
+use workflow_core::task::call_async_no_send;
+use workflow_wasm::extensions::ObjectExtension;
+
+declare! {
+    IRpcTransport,
+    r#"
+    /**
+     * Custom RPC transport that relays RPC calls made by the {@link RpcClient}
+     * (for example to a browser extension background service worker that owns
+     * the node connection and executes the calls using {@link RpcTransportHost}).
+     *
+     * Messages passed over the transport are opaque binary buffers that should
+     * be delivered as-is to {@link RpcTransportHost.handle} and back.
+     *
+     * @category Node RPC
+     */
+    export interface IRpcTransport {
+        /**
+         * Open the transport. The transport must use the supplied `sink` to signal
+         * the node connection state and to deliver notifications produced by the
+         * {@link RpcTransportHost}.
+         */
+        connect(sink: RpcTransportSink): Promise<void> | void;
+        /**
+         * Close the transport.
+         */
+        disconnect(): Promise<void> | void;
+        /**
+         * Relay an RPC request message, resolving with the response
+         * message produced by {@link RpcTransportHost.handle}.
+         */
+        call(message: Uint8Array): Promise<Uint8Array>;
+    }
+    "#,
+}
+
+/// [`native::RpcTransport`] implementation delegating to a JavaScript {@link IRpcTransport} object.
+pub(crate) struct JsRpcTransport {
+    transport: Sendable<IRpcTransport>,
+}
+
+impl JsRpcTransport {
+    pub fn new(transport: IRpcTransport) -> Self {
+        Self { transport: Sendable(transport) }
+    }
+
+    async fn invoke(transport: &Object, method: &str, args: &JsValue) -> Result<JsValue> {
+        let function = transport
+            .get_value(method)?
+            .dyn_into::<Function>()
+            .map_err(|_| Error::custom(format!("IRpcTransport::{method}() is not a function")))?;
+        let result = function.call1(transport, args)?;
+        Ok(JsFuture::from(Promise::resolve(&result)).await?)
+    }
+}
+
+#[async_trait]
+impl native::RpcTransport for JsRpcTransport {
+    async fn connect(&self, sink: native::RpcTransportSink) -> Result<()> {
+        let transport = Sendable(self.transport.0.clone());
+        call_async_no_send!(async move {
+            JsRpcTransport::invoke(&transport, "connect", &RpcTransportSink { inner: sink }.into()).await?;
+            Ok(())
+        })
+    }
+
+    async fn disconnect(&self) -> Result<()> {
+        let transport = Sendable(self.transport.0.clone());
+        call_async_no_send!(async move {
+            JsRpcTransport::invoke(&transport, "disconnect", &JsValue::UNDEFINED).await?;
+            Ok(())
+        })
+    }
+
+    async fn call(&self, op: RpcApiOps, payload: Vec<u8>) -> Result<Vec<u8>> {
+        let transport = Sendable(self.transport.0.clone());
+        let message = native::RpcTransportRequest { op, payload }.try_to_vec()?;
+        call_async_no_send!(async move {
+            let response = JsRpcTransport::invoke(&transport, "call", &Uint8Array::from(message.as_slice()).into()).await?;
+            let response =
+                response.dyn_into::<Uint8Array>().map_err(|_| Error::custom("IRpcTransport::call() must resolve with Uint8Array"))?;
+            Result::<Vec<u8>>::from(native::RpcTransportResponse::try_from_slice(&response.to_vec())?)
+        })
+    }
+}
+
+///
+/// Handle supplied to {@link IRpcTransport.connect}, used by the transport to
+/// signal the node connection state and to deliver node notifications to the
+/// {@link RpcClient}.
+///
+/// @category Node RPC
+///
+#[wasm_bindgen]
+pub struct RpcTransportSink {
+    inner: native::RpcTransportSink,
+}
+
+#[wasm_bindgen]
+impl RpcTransportSink {
+    /// Signal that the node connection has been established.
+    pub fn connected(&self) -> Result<()> {
+        self.inner.connected()
+    }
+
+    /// Signal that the node connection has been lost.
+    pub fn disconnected(&self) -> Result<()> {
+        self.inner.disconnected()
+    }
+
+    /// Deliver a notification message produced by the {@link RpcTransportHost}.
+    pub fn notify(&self, message: Uint8Array) -> Result<()> {
+        self.inner.notify_serialized(&message.to_vec())
+    }
+}
+
+///
+/// RpcTransportHost executes RPC calls relayed by an {@link IRpcTransport}
+/// using the supplied {@link RpcClient} and delivers node notifications
+/// for the subscriptions made over the transport.
+///
+/// ```javascript
+/// // background service worker
+/// let rpc = new RpcClient({ resolver : new Resolver(), networkId : "mainnet" });
+/// await rpc.connect();
+///
+/// let host = new RpcTransportHost(rpc, (message) => port.postMessage({ notification : message }));
+/// port.onMessage.addListener(async ({ id, request }) => {
+///     port.postMessage({ id, response : await host.handle(request) });
+/// });
+/// ```
+///
+/// @category Node RPC
+///
+#[wasm_bindgen]
+pub struct RpcTransportHost {
+    inner: Arc<native::RpcTransportHost>,
+}
+
+#[wasm_bindgen]
+impl RpcTransportHost {
+    /// Create a new transport host executing RPC calls using the supplied `rpc` client.
+    /// The `notify` callback receives notification messages that should be delivered
+    /// to {@link RpcTransportSink.notify} on the other end of the transport.
+    #[wasm_bindgen(constructor)]
+    pub fn ctor(rpc: &RpcClient, notify: Function) -> RpcTransportHost {
+        let inner = Arc::new(native::RpcTransportHost::new(rpc.client().rpc_api()));
+
+        let receiver = inner.notification_receiver();
+        let notify = Sendable(notify);
+        spawn(async move {
+            while let Ok(notification) = receiver.recv().await {
+                match notification.try_to_vec() {
+                    Ok(message) => {
+                        if let Err(err) = notify.call1(&JsValue::UNDEFINED, &Uint8Array::from(message.as_slice()).into()) {
+                            log_error!("Error while executing RPC transport notification callback: {:?}", err);
+                        }
+                    }
+                    Err(err) => log_error!("Unable to serialize RPC transport notification: {err}"),
+                }
+            }
+        });
+
+        RpcTransportHost { inner }
+    }
+
+    /// Execute an RPC request message received from the {@link IRpcTransport},
+    /// returning the response message.
+    pub async fn handle(&self, message: Uint8Array) -> Result<Uint8Array> {
+        let response = self.inner.handle(&message.to_vec()).await?;
+        Ok(Uint8Array::from(response.as_slice()))
+    }
+
+    /// Cancel the subscriptions made over the transport
+    /// and stop delivering notifications.
+    pub async fn close(&self) -> Result<()> {
+        self.inner.close().await
+    }
+}
@@ -301,6 +301,17 @@ async fn sanity_test() {
                 })
             }
 
+            KaspadPayloadOps::ValidateTransaction => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    // Build an erroneous transaction...
+                    let transaction = Transaction::new(0, vec![], vec![], 0, SubnetworkId::default(), 0, vec![]);
+                    let result = rpc_client.validate_transaction((&transaction).into(), false).await;
+                    // ...that gets rejected by the mempool acceptance check
+                    assert!(result.is_err());
+                })
+            }
+
             KaspadPayloadOps::GetSubnetwork => {
                 let rpc_client = client.clone();
                 tst!(op, {
@@ -363,6 +374,16 @@ async fn sanity_test() {
                 })
             }
 
+            KaspadPayloadOps::GetUtxosByAddressesPage => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let addresses = vec![Address::new(Prefix::Simnet, Version::PubKey, &[0u8; 32])];
+                    let response = rpc_client.get_utxos_by_addresses_page(addresses, None, 0).await.unwrap();
+                    assert!(response.entries.is_empty());
+                    assert!(response.next.is_none());
+                })
+            }
+
             KaspadPayloadOps::GetBalanceByAddress => {
                 let rpc_client = client.clone();
                 tst!(op, {
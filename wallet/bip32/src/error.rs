@@ -95,6 +95,12 @@ pub enum Error {
 
     #[error("Mnemonic word count is not supported ({0})")]
     WordCount(usize),
+
+    #[error("Mnemonic word {0} is not in the word list")]
+    MnemonicWord(usize),
+
+    #[error("Mnemonic checksum is invalid")]
+    MnemonicChecksum,
 }
 
 impl From<ErrorImpl> for Error {
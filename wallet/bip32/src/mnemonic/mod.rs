@@ -10,4 +10,7 @@ mod phrase;
 //#[cfg(feature = "bip39")]
 pub(crate) mod seed;
 
-pub use self::{language::Language, phrase::Mnemonic, phrase::WordCount};
+pub use self::{
+    language::Language,
+    phrase::{InvalidWord, Mnemonic, MnemonicValidation, WordCount},
+};
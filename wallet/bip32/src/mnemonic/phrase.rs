@@ -31,6 +31,24 @@ pub enum WordCount {
     Words24,
 }
 
+impl WordCount {
+    /// Number of words in the mnemonic phrase.
+    pub fn words(&self) -> usize {
+        match self {
+            WordCount::Words12 => 12,
+            WordCount::Words24 => 24,
+        }
+    }
+
+    /// Entropy strength in bits.
+    pub fn strength(&self) -> usize {
+        match self {
+            WordCount::Words12 => 128,
+            WordCount::Words24 => 256,
+        }
+    }
+}
+
 impl TryFrom<usize> for WordCount {
     type Error = Error;
     fn try_from(word_count: usize) -> Result<Self> {
@@ -42,6 +60,94 @@ impl TryFrom<usize> for WordCount {
     }
 }
 
+#[wasm_bindgen(typescript_custom_section)]
+const TS_MNEMONIC_VALIDATION: &'static str = r#"
+/**
+ * Mnemonic phrase word that is not present in the word list.
+ *
+ * @category Wallet SDK
+ */
+export interface IMnemonicInvalidWord {
+    /**
+     * Position of the word in the phrase (starting from 0).
+     */
+    index : number;
+    word : string;
+}
+
+/**
+ * Detailed mnemonic phrase validation result.
+ *
+ * @see {@link Mnemonic.check}
+ * @category Wallet SDK
+ */
+export interface IMnemonicValidation {
+    /**
+     * `true` if the phrase is a valid mnemonic.
+     */
+    valid : boolean;
+    /**
+     * Number of words in the phrase.
+     */
+    wordCount : number;
+    /**
+     * `true` if the word count is supported (12 or 24 words).
+     */
+    wordCountValid : boolean;
+    /**
+     * Words not present in the word list.
+     */
+    invalidWords : IMnemonicInvalidWord[];
+    /**
+     * `true` if the phrase checksum is valid, `false` if the checksum does
+     * not match or can not be verified (due to invalid words or word count).
+     */
+    checksumValid : boolean;
+    /**
+     * Entropy strength in bits (128 for 12 words, 256 for 24 words).
+     */
+    strength? : number;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "IMnemonicValidation")]
+    pub type IMnemonicValidation;
+}
+
+/// Mnemonic phrase word that is not present in the word list.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InvalidWord {
+    /// Position of the word in the phrase
+    pub index: usize,
+    pub word: String,
+}
+
+impl Drop for InvalidWord {
+    fn drop(&mut self) {
+        self.word.zeroize();
+    }
+}
+
+/// Detailed mnemonic phrase validation result (see [`Mnemonic::check()`]).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MnemonicValidation {
+    pub valid: bool,
+    /// Number of words in the phrase
+    pub word_count: usize,
+    /// `true` if the word count is supported (12 or 24 words)
+    pub word_count_valid: bool,
+    /// Words not present in the word list
+    pub invalid_words: Vec<InvalidWord>,
+    /// `true` if the phrase checksum is valid, `false` if the checksum does
+    /// not match or can not be verified (due to invalid words or word count)
+    pub checksum_valid: bool,
+    /// Entropy strength in bits (if the word count is supported)
+    pub strength: Option<usize>,
+}
+
 /// BIP39 mnemonic phrases: sequences of words representing cryptographic keys.
 /// @category Wallet SDK
 #[derive(Clone)]
@@ -69,6 +175,24 @@ impl Mnemonic {
         Mnemonic::new(phrase, language.unwrap_or(Language::English)).is_ok()
     }
 
+    /// Validate mnemonic phrase, reporting the word count, words missing from
+    /// the word list, checksum validity and the entropy strength. Intended for
+    /// checking user input before the phrase is used.
+    ///
+    /// @see {@link IMnemonicValidation}
+    #[wasm_bindgen(js_name = check)]
+    pub fn check_js(phrase: &str, language: Option<Language>) -> Result<IMnemonicValidation> {
+        let validation = Mnemonic::check(phrase, language.unwrap_or(Language::English));
+        let value = validation.serialize(&workflow_wasm::serde::Serializer::new()).map_err(|err| Error::String(err.to_string()))?;
+        Ok(value.unchecked_into())
+    }
+
+    /// Returns `true` if the word is present in the word list.
+    #[wasm_bindgen(js_name = isWord)]
+    pub fn is_word(word: &str, language: Option<Language>) -> bool {
+        language.unwrap_or(Language::English).wordmap().get_bits(word.trim()).is_some()
+    }
+
     #[wasm_bindgen(getter, js_name = entropy)]
     pub fn get_entropy(&self) -> String {
         self.entropy.to_hex()
@@ -84,10 +208,27 @@ impl Mnemonic {
         self.entropy = vec;
     }
 
+    /// Number of words in the mnemonic phrase (12 or 24).
+    #[wasm_bindgen(getter, js_name = wordCount)]
+    pub fn get_word_count(&self) -> usize {
+        self.word_count().words()
+    }
+
+    /// Entropy strength in bits (128 for 12 words, 256 for 24 words).
+    #[wasm_bindgen(getter, js_name = strength)]
+    pub fn get_strength(&self) -> usize {
+        self.word_count().strength()
+    }
+
+    #[wasm_bindgen(getter, js_name = language)]
+    pub fn get_language(&self) -> Language {
+        self.language
+    }
+
     #[wasm_bindgen(js_name = random)]
-    pub fn create_random_js(word_count: JsValue) -> Result<Mnemonic> {
+    pub fn create_random_js(word_count: JsValue, language: Option<Language>) -> Result<Mnemonic> {
         let word_count = word_count.as_f64().unwrap_or(24.0) as usize;
-        Mnemonic::random(word_count.try_into()?, Default::default())
+        Mnemonic::random(word_count.try_into()?, language.unwrap_or_default())
     }
 
     #[wasm_bindgen(getter, js_name = phrase)]
@@ -100,6 +241,8 @@ impl Mnemonic {
         self.phrase = phrase.to_string();
     }
 
+    /// Convert the mnemonic phrase into the BIP39 seed (hex string) using
+    /// an optional passphrase (BIP39 password).
     #[wasm_bindgen(js_name = toSeed)]
     pub fn create_seed(&self, password: Option<String>) -> String {
         let password = password.unwrap_or_default();
@@ -167,11 +310,14 @@ impl Mnemonic {
         let phrase = phrase.as_ref();
         let wordmap = language.wordmap();
 
+        let word_count = phrase.split_whitespace().count();
+        WordCount::try_from(word_count)?;
+
         // Preallocate enough space for the longest possible word list
         let mut bits = BitWriter::with_capacity(264);
 
-        for word in phrase.split(' ') {
-            bits.push(wordmap.get_bits(word).ok_or(Error::Bip39)?);
+        for (index, word) in phrase.split_whitespace().enumerate() {
+            bits.push(wordmap.get_bits(word).ok_or(Error::MnemonicWord(index))?);
         }
 
         let mut entropy = Zeroizing::new(bits.into_bytes());
@@ -192,12 +338,40 @@ impl Mnemonic {
         let expected_checksum = Self::build_checksum(&entropy)?;
 
         if actual_checksum != expected_checksum {
-            return Err(Error::String(format!("BIP39: actual checksum({actual_checksum}) != expected checksum({expected_checksum})")));
+            return Err(Error::MnemonicChecksum);
         }
 
         Self::from_entropy(entropy.to_vec(), language)
     }
 
+    /// Validate the mnemonic phrase producing a detailed [`MnemonicValidation`].
+    pub fn check<S>(phrase: S, language: Language) -> MnemonicValidation
+    where
+        S: AsRef<str>,
+    {
+        let phrase = phrase.as_ref();
+        let wordmap = language.wordmap();
+
+        let words = phrase.split_whitespace().collect::<Vec<_>>();
+        let word_count = WordCount::try_from(words.len()).ok();
+        let invalid_words = words
+            .iter()
+            .enumerate()
+            .filter(|(_, word)| wordmap.get_bits(word).is_none())
+            .map(|(index, word)| InvalidWord { index, word: word.to_string() })
+            .collect::<Vec<_>>();
+        let checksum_valid = word_count.is_some() && invalid_words.is_empty() && Self::new(phrase, language).is_ok();
+
+        MnemonicValidation {
+            valid: checksum_valid,
+            word_count: words.len(),
+            word_count_valid: word_count.is_some(),
+            invalid_words,
+            checksum_valid,
+            strength: word_count.map(|word_count| word_count.strength()),
+        }
+    }
+
     fn build_checksum(entropy: &Zeroizing<Vec<u8>>) -> Result<u8> {
         let binding = Sha256::digest(entropy);
         let bytes = binding.as_slice();
@@ -228,6 +402,15 @@ impl Mnemonic {
         self.language
     }
 
+    /// Word count of this phrase.
+    pub fn word_count(&self) -> WordCount {
+        if self.entropy.len() == 16 {
+            WordCount::Words12
+        } else {
+            WordCount::Words24
+        }
+    }
+
     /// Convert this mnemonic phrase into the BIP39 seed value.
     //#[cfg(feature = "bip39")]
     //#[cfg_attr(docsrs, doc(cfg(feature = "bip39")))]
@@ -248,7 +431,7 @@ impl Drop for Mnemonic {
 
 #[cfg(test)]
 mod tests {
-    use super::Mnemonic;
+    use super::{InvalidWord, Mnemonic, WordCount};
     use crate::ExtendedPrivateKey;
     use crate::Language;
     use crate::Prefix;
@@ -330,4 +513,28 @@ mod tests {
             assert_eq!(&xprv.to_string(prefix).to_string(), xprv_str, "xprv is not valid");
         }
     }
+
+    #[test]
+    pub fn test_mnemonic_check() {
+        let phrase = "social anchor educate fold ancient wheel advice praise file fix attitude ivory";
+
+        let validation = Mnemonic::check(format!("  {}\n", phrase.replace(' ', "  ")), Language::English);
+        assert!(validation.valid);
+        assert_eq!((validation.word_count, validation.strength), (12, Some(128)));
+        let mnemonic = Mnemonic::new(format!("  {}\n", phrase.replace(' ', "  ")), Language::English).unwrap();
+        assert_eq!(mnemonic.phrase(), phrase);
+        assert_eq!(mnemonic.word_count().words(), 12);
+
+        let validation = Mnemonic::check(phrase.replace("anchor", "anchr"), Language::English);
+        assert!(!validation.valid && !validation.checksum_valid && validation.word_count_valid);
+        assert_eq!(validation.invalid_words, vec![InvalidWord { index: 1, word: "anchr".to_string() }]);
+
+        let validation = Mnemonic::check(phrase.replace("ivory", "social"), Language::English);
+        assert!(!validation.valid && !validation.checksum_valid && validation.invalid_words.is_empty());
+        assert!(matches!(Mnemonic::new(phrase.replace("ivory", "social"), Language::English), Err(crate::Error::MnemonicChecksum)));
+
+        let validation = Mnemonic::check("social anchor educate", Language::English);
+        assert!(!validation.word_count_valid && validation.strength.is_none());
+        assert_eq!(WordCount::Words24.strength(), 256);
+    }
 }
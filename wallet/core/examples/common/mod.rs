@@ -0,0 +1,101 @@
+//!
+//! Shared setup for the wallet cookbook examples.
+//!
+//! The examples run against [`MockRpcClient`], an in-memory simulation
+//! of a synced node, so they can be executed (and are executed by
+//! `cargo test`) without network access. To run a scenario against
+//! a real node, replace the mock with a [`KaspaRpcClient`].
+//!
+
+#![allow(dead_code)]
+
+use kaspa_wallet_core::error::Error;
+use kaspa_wallet_core::prelude::*;
+use kaspa_wallet_core::result::Result;
+use kaspa_wallet_core::tests::MockRpcClient;
+use std::sync::Arc;
+use std::time::Duration;
+use workflow_core::channel::MultiplexerChannel;
+
+pub use kaspa_consensus_core::network::{NetworkId, NetworkType};
+
+/// Maximum time to wait for a wallet event.
+pub const EVENT_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub fn network_id() -> NetworkId {
+    NetworkId::with_suffix(NetworkType::Testnet, 10)
+}
+
+/// Creates a mock node and signals the RPC channel as connected.
+pub async fn mock_rpc() -> Result<Arc<MockRpcClient>> {
+    let rpc_mock = Arc::new(MockRpcClient::with_network_id(network_id()));
+    rpc_mock.connect().await?;
+    Ok(rpc_mock)
+}
+
+/// Simulates an incoming (mature) payment of `amount` sompi to `address`.
+pub fn fund(rpc_mock: &MockRpcClient, address: &Address, amount: u64) -> Result<()> {
+    rpc_mock.add_utxo(address, amount).map_err(|err| Error::custom(err.to_string()))?;
+    Ok(())
+}
+
+/// Creates and starts a wallet backed by an in-memory (resident) store.
+pub async fn start_wallet(rpc_mock: &Arc<MockRpcClient>) -> Result<Arc<Wallet>> {
+    let wallet = Arc::new(Wallet::try_with_mock_rpc(rpc_mock, Wallet::resident_store()?)?);
+    wallet.start().await?;
+    Ok(wallet)
+}
+
+/// Creates a new wallet secured by the given wallet secret.
+pub async fn create_wallet(wallet: &Arc<Wallet>, wallet_secret: &Secret) -> Result<()> {
+    let wallet_args = WalletCreateArgs::new(Some("cookbook".to_string()), None, EncryptionKind::XChaCha20Poly1305, None, true);
+    wallet.clone().wallet_create(wallet_secret.clone(), wallet_args).await?;
+    Ok(())
+}
+
+/// Imports the private key of the given mnemonic into the open wallet.
+pub async fn import_mnemonic(wallet: &Arc<Wallet>, wallet_secret: &Secret, mnemonic: &Mnemonic) -> Result<PrvKeyDataId> {
+    let prv_key_data_args = PrvKeyDataCreateArgs::new(None, None, Secret::from(mnemonic.phrase_string()));
+    wallet.clone().prv_key_data_create(wallet_secret.clone(), prv_key_data_args).await
+}
+
+/// Creates a new wallet, imports a private key from the given mnemonic
+/// and creates a BIP32 account from it.
+pub async fn create_wallet_with_account(
+    wallet: &Arc<Wallet>,
+    wallet_secret: &Secret,
+    mnemonic: &Mnemonic,
+) -> Result<AccountDescriptor> {
+    create_wallet(wallet, wallet_secret).await?;
+    let prv_key_data_id = import_mnemonic(wallet, wallet_secret, mnemonic).await?;
+    let account_args = AccountCreateArgs::new_bip32(prv_key_data_id, None, Some("main".to_string()), None);
+    wallet.clone().accounts_create(wallet_secret.clone(), account_args).await
+}
+
+/// Waits for a wallet event matching the supplied predicate.
+pub async fn wait_for_event<F>(channel: &MultiplexerChannel<Box<Events>>, predicate: F) -> Result<Box<Events>>
+where
+    F: Fn(&Events) -> bool,
+{
+    tokio::time::timeout(EVENT_TIMEOUT, async {
+        loop {
+            let event = channel.receiver.recv().await.map_err(|err| Error::custom(err.to_string()))?;
+            if predicate(&event) {
+                return Ok(event);
+            }
+        }
+    })
+    .await
+    .map_err(|_| Error::custom("timeout while waiting for a wallet event"))?
+}
+
+/// Waits until the balance reported by the wallet reaches `mature` sompi.
+pub async fn wait_for_mature_balance(channel: &MultiplexerChannel<Box<Events>>, mature: u64) -> Result<Balance> {
+    let event =
+        wait_for_event(channel, |event| matches!(event, Events::Balance { balance: Some(balance), .. } if balance.mature == mature))
+            .await?;
+    match *event {
+        Events::Balance { balance: Some(balance), .. } => Ok(balance),
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,44 @@
+//!
+//! Create a new wallet with a freshly generated mnemonic
+//! and a default BIP32 account.
+//!
+
+mod common;
+
+use kaspa_wallet_core::prelude::*;
+use kaspa_wallet_core::result::Result;
+
+async fn run() -> Result<()> {
+    let rpc = common::mock_rpc().await?;
+    let wallet = common::start_wallet(&rpc).await?;
+
+    // the mnemonic must be presented to the user for backup
+    let mnemonic = Mnemonic::random(WordCount::Words24, Language::English)?;
+    let wallet_secret = Secret::from("wallet-secret");
+    let account = common::create_wallet_with_account(&wallet, &wallet_secret, &mnemonic).await?;
+
+    let receive_address = account.receive_address.clone().expect("receive address");
+    println!("created account '{}' with receive address {receive_address}", account.account_id);
+    assert_eq!(receive_address.prefix, AddressPrefix::Testnet);
+
+    let accounts = wallet.clone().accounts_enumerate().await?;
+    assert_eq!(accounts.len(), 1);
+    assert_eq!(accounts[0].account_id, account.account_id);
+
+    wallet.clone().wallet_close().await?;
+    wallet.stop().await?;
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    run().await
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn create_wallet() {
+        super::run().await.unwrap();
+    }
+}
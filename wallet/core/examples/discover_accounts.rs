@@ -0,0 +1,85 @@
+//!
+//! Discover the BIP-44 accounts used by a restored private key
+//! and recreate them in the wallet.
+//!
+
+mod common;
+
+use kaspa_wallet_core::api::message::*;
+use kaspa_wallet_core::prelude::*;
+use kaspa_wallet_core::result::Result;
+use kaspa_wallet_core::utils::kaspa_to_sompi;
+
+const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+async fn run() -> Result<()> {
+    let rpc = common::mock_rpc().await?;
+    let mnemonic = Mnemonic::new(MNEMONIC, Language::English)?;
+    let wallet_secret = Secret::from("wallet-secret");
+
+    // simulate funds previously received by accounts #0 and #2
+    // (account #1 has never been used)
+    let previous = common::start_wallet(&rpc).await?;
+    common::create_wallet(&previous, &wallet_secret).await?;
+    let prv_key_data_id = common::import_mnemonic(&previous, &wallet_secret, &mnemonic).await?;
+    for (account_index, amount) in [(0, 5.0), (2, 3.0)] {
+        let account_args = AccountCreateArgs::new_bip32(prv_key_data_id, None, None, Some(account_index));
+        let account = previous.clone().accounts_create(wallet_secret.clone(), account_args).await?;
+        common::fund(&rpc, &account.receive_address.clone().expect("receive address"), kaspa_to_sompi(amount))?;
+    }
+    previous.clone().wallet_close().await?;
+    previous.stop().await?;
+
+    // the wallet is restored from the mnemonic, without any accounts
+    let wallet = common::start_wallet(&rpc).await?;
+    common::create_wallet(&wallet, &wallet_secret).await?;
+    let prv_key_data_id = common::import_mnemonic(&wallet, &wallet_secret, &mnemonic).await?;
+
+    let discover = |account_scan_extent, create_accounts| AccountsDiscoveryRequest {
+        discovery_kind: AccountsDiscoveryKind::Bip44,
+        address_scan_extent: 20,
+        account_scan_extent,
+        source: AccountsDiscoverySource::PrvKeyData { wallet_secret: wallet_secret.clone(), prv_key_data_id, payment_secret: None },
+        create_accounts,
+        gap_limit: None,
+    };
+
+    // a gap of one account stops the discovery after account #0
+    let response = wallet.clone().accounts_discovery_call(discover(1, false)).await?;
+    assert_eq!(response.accounts.iter().map(|account| account.account_index).collect::<Vec<_>>(), vec![0]);
+
+    // a larger gap limit reaches account #2; discovered accounts are created
+    let response = wallet.clone().accounts_discovery_call(discover(2, true)).await?;
+    for account in response.accounts.iter() {
+        println!("discovered account #{} holding {} sompi", account.account_index, account.balance);
+    }
+    assert_eq!(response.last_account_index_found, 2);
+    assert_eq!(
+        response.accounts.iter().map(|account| (account.account_index, account.balance, account.created)).collect::<Vec<_>>(),
+        vec![(0, kaspa_to_sompi(5.0), true), (2, kaspa_to_sompi(3.0), true)]
+    );
+
+    let accounts = wallet.clone().accounts_enumerate().await?;
+    assert_eq!(accounts.len(), 2);
+
+    // repeating the discovery reports the existing accounts without re-creating them
+    let response = wallet.clone().accounts_discovery_call(discover(2, true)).await?;
+    assert!(response.accounts.iter().all(|account| !account.created));
+
+    wallet.clone().wallet_close().await?;
+    wallet.stop().await?;
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    run().await
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn discover_accounts() {
+        super::run().await.unwrap();
+    }
+}
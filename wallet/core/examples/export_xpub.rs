@@ -0,0 +1,68 @@
+//!
+//! Export an account xpub along with a proof of its ownership
+//! and verify the proof on the receiving (service) side.
+//!
+
+mod common;
+
+use kaspa_wallet_core::account::BIP32_ACCOUNT_KIND;
+use kaspa_wallet_core::api::message::*;
+use kaspa_wallet_core::error::Error;
+use kaspa_wallet_core::ownership::XPubOwnershipProof;
+use kaspa_wallet_core::prelude::*;
+use kaspa_wallet_core::result::Result;
+use kaspa_wallet_core::storage::PrvKeyData;
+
+const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+/// Maximum proof age accepted by the service (5 minutes).
+const MAX_PROOF_AGE: u64 = 5 * 60 * 1000;
+
+async fn run() -> Result<()> {
+    let rpc = common::mock_rpc().await?;
+    let wallet = common::start_wallet(&rpc).await?;
+    let mnemonic = Mnemonic::new(MNEMONIC, Language::English)?;
+    let wallet_secret = Secret::from("wallet-secret");
+    let account = common::create_wallet_with_account(&wallet, &wallet_secret, &mnemonic).await?;
+
+    // wallet side: export the xpub signed with the first receive address key
+    let request = AccountsExportXpubRequest { account_id: account.account_id, wallet_secret, payment_secret: None };
+    let AccountsExportXpubResponse { proof } = wallet.clone().accounts_export_xpub_call(request).await?;
+    println!("xpub: {}", proof.xpub);
+    println!("signed by: {}", proof.address);
+    assert_eq!(Some(&proof.address), account.receive_address.as_ref());
+
+    // service side: the proof is typically delivered as JSON
+    let json = serde_json::to_string(&proof).map_err(|err| Error::custom(err.to_string()))?;
+    let received: XPubOwnershipProof = serde_json::from_str(&json).map_err(|err| Error::custom(err.to_string()))?;
+    received.verify(Some(MAX_PROOF_AGE))?;
+
+    // an xpub substituted in transit is rejected
+    let mut substituted = received.clone();
+    substituted.xpub = mnemonic_xpub("zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong").await?;
+    assert!(substituted.verify(Some(MAX_PROOF_AGE)).is_err());
+
+    wallet.clone().wallet_close().await?;
+    wallet.stop().await?;
+    Ok(())
+}
+
+/// Produces the account #0 xpub of another key.
+async fn mnemonic_xpub(phrase: &str) -> Result<String> {
+    let prv_key_data =
+        PrvKeyData::try_from_mnemonic(Mnemonic::new(phrase, Language::English)?, None, EncryptionKind::XChaCha20Poly1305)?;
+    Ok(prv_key_data.create_xpub(None, BIP32_ACCOUNT_KIND.into(), 0).await?.to_string(None))
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    run().await
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn export_xpub() {
+        super::run().await.unwrap();
+    }
+}
@@ -0,0 +1,70 @@
+//!
+//! Create a 2-of-2 multisig account from two private keys held
+//! by the wallet, fund it and re-scan it for funds.
+//!
+//! Spending from multisig accounts requires collecting signatures
+//! from all cosigners, see the `kaspa-wallet-pskt` multisig example.
+//!
+
+mod common;
+
+use kaspa_addresses::Version;
+use kaspa_wallet_core::prelude::*;
+use kaspa_wallet_core::result::Result;
+use kaspa_wallet_core::utils::kaspa_to_sompi;
+
+async fn run() -> Result<()> {
+    let rpc = common::mock_rpc().await?;
+    let wallet = common::start_wallet(&rpc).await?;
+    let events = wallet.multiplexer().channel();
+
+    let wallet_secret = Secret::from("wallet-secret");
+    common::create_wallet(&wallet, &wallet_secret).await?;
+
+    let mut prv_key_data_args = vec![];
+    for _ in 0..2 {
+        let mnemonic = Mnemonic::random(WordCount::Words24, Language::English)?;
+        let prv_key_data_id = common::import_mnemonic(&wallet, &wallet_secret, &mnemonic).await?;
+        prv_key_data_args.push(PrvKeyDataArgs::new(prv_key_data_id, None));
+    }
+
+    let account_args = AccountCreateArgs::new_multisig(prv_key_data_args, vec![], Some("multisig".to_string()), 2);
+    let account = wallet.clone().accounts_create(wallet_secret.clone(), account_args).await?;
+    let account_id = account.account_id;
+    let receive_address = account.receive_address.expect("receive address");
+    println!("multisig receive address: {receive_address}");
+    // multisig accounts receive to P2SH addresses
+    assert_eq!(receive_address.version, Version::ScriptHash);
+
+    wallet.clone().accounts_activate(Some(vec![account_id])).await?;
+    common::fund(&rpc, &receive_address, kaspa_to_sompi(10.0))?;
+    common::wait_for_mature_balance(&events, kaspa_to_sompi(10.0)).await?;
+
+    // deactivate and re-activate the account; the
+    // account is re-scanned and re-discovers its funds
+    wallet.clone().accounts_deactivate(Some(vec![account_id])).await?;
+    let accounts = wallet.clone().accounts_enumerate().await?;
+    let enumerated = accounts.iter().find(|descriptor| descriptor.account_id == account_id).expect("multisig account");
+    assert_eq!(enumerated.receive_address.as_ref(), Some(&receive_address));
+
+    wallet.clone().accounts_activate(Some(vec![account_id])).await?;
+    let balance = common::wait_for_mature_balance(&events, kaspa_to_sompi(10.0)).await?;
+    println!("re-discovered multisig balance: {} sompi", balance.mature);
+
+    wallet.clone().wallet_close().await?;
+    wallet.stop().await?;
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    run().await
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn multisig() {
+        super::run().await.unwrap();
+    }
+}
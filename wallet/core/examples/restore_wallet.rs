@@ -0,0 +1,54 @@
+//!
+//! Restore a wallet from an existing mnemonic and
+//! discover the funds held by its account.
+//!
+
+mod common;
+
+use kaspa_wallet_core::prelude::*;
+use kaspa_wallet_core::result::Result;
+use kaspa_wallet_core::utils::kaspa_to_sompi;
+
+const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+const RECEIVE_ADDRESS: &str = "kaspatest:qqd6e65yefepe9wk0m9vuxdufxd80sphy67gwwd0vdaumzdt4tc9ssxd5s7gn";
+
+async fn run() -> Result<()> {
+    let rpc = common::mock_rpc().await?;
+    let wallet = common::start_wallet(&rpc).await?;
+    let events = wallet.multiplexer().channel();
+
+    let mnemonic = Mnemonic::new(MNEMONIC, Language::English)?;
+    let wallet_secret = Secret::from("wallet-secret");
+    let account = common::create_wallet_with_account(&wallet, &wallet_secret, &mnemonic).await?;
+
+    // restoring the same mnemonic always yields the same addresses
+    let receive_address = account.receive_address.clone().expect("receive address");
+    println!("restored account receive address: {receive_address}");
+    assert_eq!(receive_address.to_string(), RECEIVE_ADDRESS);
+
+    // simulate funds previously received by the wallet
+    common::fund(&rpc, &receive_address, kaspa_to_sompi(5.0))?;
+
+    // activating the account scans its addresses for UTXOs
+    wallet.clone().accounts_activate(Some(vec![account.account_id])).await?;
+    let balance = common::wait_for_mature_balance(&events, kaspa_to_sompi(5.0)).await?;
+    println!("discovered balance: {} sompi in {} UTXOs", balance.mature, balance.mature_utxo_count);
+    assert_eq!(balance.mature_utxo_count, 1);
+
+    wallet.clone().wallet_close().await?;
+    wallet.stop().await?;
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    run().await
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn restore_wallet() {
+        super::run().await.unwrap();
+    }
+}
@@ -0,0 +1,83 @@
+//!
+//! Estimate the fees of a payment and send it.
+//!
+
+mod common;
+
+use kaspa_wallet_core::api::message::*;
+use kaspa_wallet_core::prelude::*;
+use kaspa_wallet_core::result::Result;
+use kaspa_wallet_core::utils::kaspa_to_sompi;
+
+const RECIPIENT: &str = "kaspatest:qqd6e65yefepe9wk0m9vuxdufxd80sphy67gwwd0vdaumzdt4tc9ssxd5s7gn";
+
+async fn run() -> Result<()> {
+    let rpc = common::mock_rpc().await?;
+    let wallet = common::start_wallet(&rpc).await?;
+    let events = wallet.multiplexer().channel();
+
+    let wallet_secret = Secret::from("wallet-secret");
+    let mnemonic = Mnemonic::random(WordCount::Words24, Language::English)?;
+    let account = common::create_wallet_with_account(&wallet, &wallet_secret, &mnemonic).await?;
+    let account_id = account.account_id;
+
+    wallet.clone().accounts_activate(Some(vec![account_id])).await?;
+    common::fund(&rpc, &account.receive_address.expect("receive address"), kaspa_to_sompi(10.0))?;
+    common::wait_for_mature_balance(&events, kaspa_to_sompi(10.0)).await?;
+
+    let recipient = Address::try_from(RECIPIENT)?;
+    let amount = kaspa_to_sompi(1.0);
+    let destination: PaymentDestination = PaymentOutputs::from((recipient.clone(), amount)).into();
+
+    // estimate the fees before committing to the payment
+    let AccountsEstimateResponse { generator_summary: estimate } = wallet
+        .clone()
+        .accounts_estimate_call(AccountsEstimateRequest {
+            account_id,
+            destination: destination.clone(),
+            priority_fee_sompi: Some(Fees::SenderPays(0)),
+            payload: None,
+            change_policy: None,
+        })
+        .await?;
+    println!("estimated fees: {} sompi", estimate.aggregated_fees);
+
+    let AccountsSendResponse { generator_summary, transaction_ids, .. } = wallet
+        .clone()
+        .accounts_send_call(AccountsSendRequest {
+            account_id,
+            wallet_secret,
+            payment_secret: None,
+            destination,
+            priority_fee_sompi: Some(Fees::SenderPays(0)),
+            payload: None,
+            utxos: None,
+            exclusive_utxos: false,
+            dry_run: false,
+            fee_bump: None,
+            change_policy: None,
+        })
+        .await?;
+    println!("submitted transactions: {transaction_ids:?}, fees: {} sompi", generator_summary.aggregated_fees);
+
+    assert_eq!(generator_summary.aggregated_fees, estimate.aggregated_fees);
+    assert_eq!(rpc.transactions().len(), transaction_ids.len());
+    assert_eq!(rpc.utxos(&recipient).iter().map(|entry| entry.utxo_entry.amount).sum::<u64>(), amount);
+
+    wallet.clone().wallet_close().await?;
+    wallet.stop().await?;
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    run().await
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn send_with_fee_estimate() {
+        super::run().await.unwrap();
+    }
+}
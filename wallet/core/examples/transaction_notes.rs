@@ -0,0 +1,87 @@
+//!
+//! Attach a user-visible note and application-specific
+//! JSON metadata to transactions in the account history.
+//!
+
+mod common;
+
+use kaspa_wallet_core::api::message::*;
+use kaspa_wallet_core::error::Error;
+use kaspa_wallet_core::prelude::*;
+use kaspa_wallet_core::result::Result;
+use kaspa_wallet_core::utils::kaspa_to_sompi;
+
+const RECIPIENT: &str = "kaspatest:qqd6e65yefepe9wk0m9vuxdufxd80sphy67gwwd0vdaumzdt4tc9ssxd5s7gn";
+
+async fn run() -> Result<()> {
+    let rpc = common::mock_rpc().await?;
+    let wallet = common::start_wallet(&rpc).await?;
+    let events = wallet.multiplexer().channel();
+    let network_id = common::network_id();
+
+    let wallet_secret = Secret::from("wallet-secret");
+    let mnemonic = Mnemonic::random(WordCount::Words24, Language::English)?;
+    let account = common::create_wallet_with_account(&wallet, &wallet_secret, &mnemonic).await?;
+    let account_id = account.account_id;
+
+    wallet.clone().accounts_activate(Some(vec![account_id])).await?;
+    common::fund(&rpc, &account.receive_address.expect("receive address"), kaspa_to_sompi(10.0))?;
+    common::wait_for_mature_balance(&events, kaspa_to_sompi(10.0)).await?;
+
+    let destination: PaymentDestination = PaymentOutputs::from((Address::try_from(RECIPIENT)?, kaspa_to_sompi(1.0))).into();
+    let AccountsSendResponse { transaction_ids, .. } = wallet
+        .clone()
+        .accounts_send_call(AccountsSendRequest {
+            account_id,
+            wallet_secret,
+            payment_secret: None,
+            destination,
+            priority_fee_sompi: Some(Fees::SenderPays(0)),
+            payload: None,
+            utxos: None,
+            exclusive_utxos: false,
+            dry_run: false,
+            fee_bump: None,
+            change_policy: None,
+        })
+        .await?;
+    let transaction_id = *transaction_ids.last().expect("transaction id");
+    common::wait_for_event(&events, |event| matches!(event, Events::Maturity { record } if *record.id() == transaction_id)).await?;
+
+    // annotate the payment with a memo and invoice details
+    let metadata = serde_json::json!({ "invoice": { "id": 1024, "merchant": "coffee shop" } }).to_string();
+    wallet.clone().transactions_replace_note(account_id, network_id, transaction_id, Some("morning coffee".to_string())).await?;
+    wallet.clone().transactions_replace_metadata(account_id, network_id, transaction_id, Some(metadata.clone())).await?;
+
+    let TransactionsDataGetResponse { transactions, .. } =
+        wallet.clone().transactions_data_get_call(TransactionsDataGetRequest::with_range(account_id, network_id, 0..16)).await?;
+    let record = transactions.iter().find(|record| *record.id() == transaction_id).expect("transaction record");
+    println!("note: {:?}, metadata: {:?}", record.note, record.metadata);
+    assert_eq!(record.note.as_deref(), Some("morning coffee"));
+    assert_eq!(record.metadata.as_deref(), Some(metadata.as_str()));
+
+    // supplying `None` removes the note
+    wallet.clone().transactions_replace_note(account_id, network_id, transaction_id, None).await?;
+
+    // annotating an unknown transaction fails
+    let unknown = TransactionId::from_slice(&[0xff; 32]);
+    let result = wallet.clone().transactions_replace_note(account_id, network_id, unknown, Some("unknown".to_string())).await;
+    assert!(matches!(result, Err(Error::NoRecordsFound)));
+
+    wallet.clone().wallet_close().await?;
+    wallet.stop().await?;
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    run().await
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn transaction_notes() {
+        super::run().await.unwrap();
+    }
+}
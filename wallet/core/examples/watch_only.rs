@@ -0,0 +1,55 @@
+//!
+//! Monitor the balance of a set of addresses without
+//! holding any keys, using `UtxoProcessor` and `UtxoContext`.
+//!
+
+mod common;
+
+use kaspa_wallet_core::prelude::*;
+use kaspa_wallet_core::result::Result;
+use kaspa_wallet_core::utils::kaspa_to_sompi;
+use kaspa_wallet_core::utxo::{UtxoContext, UtxoContextBinding, UtxoProcessor};
+
+const WATCHED_ADDRESS: &str = "kaspatest:qqd6e65yefepe9wk0m9vuxdufxd80sphy67gwwd0vdaumzdt4tc9ssxd5s7gn";
+
+async fn run() -> Result<()> {
+    let rpc = common::mock_rpc().await?;
+
+    // the processor tracks node connectivity and dispatches
+    // UTXO notifications to the contexts bound to it
+    let processor = UtxoProcessor::new(Some(rpc.clone().into()), Some(common::network_id()), None, None);
+    let events = processor.multiplexer().channel();
+    processor.start().await?;
+
+    // the context aggregates UTXOs of the addresses registered with it
+    let context = UtxoContext::new(&processor, UtxoContextBinding::default());
+    let address = Address::try_from(WATCHED_ADDRESS)?;
+    context.register_addresses(std::slice::from_ref(&address)).await?;
+
+    common::fund(&rpc, &address, kaspa_to_sompi(3.0))?;
+    let balance = common::wait_for_mature_balance(&events, kaspa_to_sompi(3.0)).await?;
+    println!("balance after the first payment: {} sompi", balance.mature);
+
+    common::fund(&rpc, &address, kaspa_to_sompi(2.0))?;
+    let balance = common::wait_for_mature_balance(&events, kaspa_to_sompi(5.0)).await?;
+    println!("balance after the second payment: {} sompi", balance.mature);
+    assert_eq!(balance.mature_utxo_count, 2);
+    assert_eq!(context.balance().map(|balance| balance.mature), Some(kaspa_to_sompi(5.0)));
+
+    context.unregister_addresses(vec![address]).await?;
+    processor.stop().await?;
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    run().await
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn watch_only() {
+        super::run().await.unwrap();
+    }
+}
@@ -13,13 +13,21 @@ use std::collections::BTreeMap;
 
 /// @category Wallet API
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AccountDescriptor {
     pub kind: AccountKind,
+    #[serde(alias = "account_id")]
     pub account_id: AccountId,
+    #[serde(alias = "account_name")]
     pub account_name: Option<String>,
+    #[serde(alias = "prv_key_data_ids")]
     pub prv_key_data_ids: AssocPrvKeyDataIds,
+    #[serde(alias = "receive_address")]
     pub receive_address: Option<Address>,
+    #[serde(alias = "change_address")]
     pub change_address: Option<Address>,
+    #[serde(default)]
+    pub archived: bool,
 
     pub properties: BTreeMap<AccountDescriptorProperty, AccountDescriptorValue>,
 }
@@ -33,7 +41,21 @@ impl AccountDescriptor {
         receive_address: Option<Address>,
         change_address: Option<Address>,
     ) -> Self {
-        Self { kind, account_id, account_name, prv_key_data_ids, receive_address, change_address, properties: BTreeMap::default() }
+        Self {
+            kind,
+            account_id,
+            account_name,
+            prv_key_data_ids,
+            receive_address,
+            change_address,
+            archived: false,
+            properties: BTreeMap::default(),
+        }
+    }
+
+    pub fn with_archived(mut self, archived: bool) -> Self {
+        self.archived = archived;
+        self
     }
 
     pub fn with_property(mut self, property: AccountDescriptorProperty, value: AccountDescriptorValue) -> Self {
@@ -49,6 +71,7 @@ pub enum AccountDescriptorProperty {
     XpubKeys,
     Ecdsa,
     DerivationMeta,
+    DerivationPath,
     Other(String),
 }
 
@@ -59,6 +82,7 @@ impl std::fmt::Display for AccountDescriptorProperty {
             AccountDescriptorProperty::XpubKeys => write!(f, "Xpub Keys"),
             AccountDescriptorProperty::Ecdsa => write!(f, "ECDSA"),
             AccountDescriptorProperty::DerivationMeta => write!(f, "Derivation Indexes"),
+            AccountDescriptorProperty::DerivationPath => write!(f, "Derivation Path"),
             AccountDescriptorProperty::Other(other) => write!(f, "{}", other),
         }
     }
@@ -208,6 +232,10 @@ impl AccountDescriptor {
     pub fn receive_address(&self) -> &Option<Address> {
         &self.receive_address
     }
+
+    pub fn is_archived(&self) -> bool {
+        self.archived
+    }
 }
 
 declare! {
@@ -225,6 +253,7 @@ declare! {
         receiveAddress? : Address,
         changeAddress? : Address,
         prvKeyDataIds : HexString[],
+        archived? : boolean,
         [key: string]: any
     }
     "#,
@@ -240,6 +269,9 @@ impl TryFrom<AccountDescriptor> for IAccountDescriptor {
         object.set("accountName", &descriptor.account_name.into())?;
         object.set("receiveAddress", &descriptor.receive_address.into())?;
         object.set("changeAddress", &descriptor.change_address.into())?;
+        if descriptor.archived {
+            object.set("archived", &JsValue::TRUE)?;
+        }
 
         let prv_key_data_ids = js_sys::Array::from_iter(descriptor.prv_key_data_ids.into_iter().map(JsValue::from));
         object.set("prvKeyDataIds", &prv_key_data_ids)?;
@@ -253,3 +285,56 @@ impl TryFrom<AccountDescriptor> for IAccountDescriptor {
         Ok(object)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deterministic::{from_data, make_account_hashes};
+    use crate::storage::PrvKeyDataId;
+
+    fn descriptor() -> AccountDescriptor {
+        let (account_id, _) = make_account_hashes(from_data(&BIP32_ACCOUNT_KIND.into(), &[0x00, 0x01, 0x02, 0x03]));
+        let address = Address::try_from("kaspatest:qz7ulu4c25dh7fzec9zjyrmlhnkzrg4wmf89q7gzr3gfrsj3uz6xjceef60sd").unwrap();
+        AccountDescriptor::new(
+            BIP32_ACCOUNT_KIND.into(),
+            account_id,
+            Some("test".to_string()),
+            PrvKeyDataId::new(0xcafe).into(),
+            Some(address),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_account_descriptor_serde() -> Result<()> {
+        let descriptor = descriptor();
+        let json = serde_json::to_value(&descriptor)?;
+        for key in ["accountId", "accountName", "prvKeyDataIds", "receiveAddress", "changeAddress"] {
+            assert!(json.get(key).is_some(), "missing `{key}` in {json}");
+        }
+
+        let decoded: AccountDescriptor = serde_json::from_value(json)?;
+        assert_eq!(decoded.account_id, descriptor.account_id);
+        assert_eq!(decoded.account_name, descriptor.account_name);
+        assert_eq!(decoded.receive_address, descriptor.receive_address);
+
+        // snake_case field names produced by earlier versions must remain readable
+        let legacy = serde_json::json!({
+            "kind": descriptor.kind,
+            "account_id": descriptor.account_id,
+            "account_name": descriptor.account_name,
+            "prv_key_data_ids": descriptor.prv_key_data_ids,
+            "receive_address": descriptor.receive_address,
+            "change_address": null,
+            "properties": {},
+        });
+        let decoded: AccountDescriptor = serde_json::from_value(legacy)?;
+        assert_eq!(decoded.account_id, descriptor.account_id);
+        assert_eq!(decoded.receive_address, descriptor.receive_address);
+
+        let event = serde_json::to_value(Events::AccountCreate { account_descriptor: descriptor })?;
+        assert!(event["data"].get("accountDescriptor").is_some(), "unexpected event serialization: {event}");
+
+        Ok(())
+    }
+}
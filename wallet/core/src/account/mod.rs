@@ -9,18 +9,30 @@ pub mod variants;
 pub use kind::*;
 pub use variants::*;
 
-use crate::derivation::build_derivate_paths;
-use crate::derivation::AddressDerivationManagerTrait;
+use crate::derivation::{build_derivate_paths, DerivationPathTemplate};
+use crate::derivation::{AddressDerivationManagerTrait, AddressManager};
+use crate::error::ErrorCode;
 use crate::imports::*;
+use crate::logging::log;
+use crate::message::{sign_message, PersonalMessage};
+use crate::ownership::XPubOwnershipProof;
 use crate::storage::account::AccountSettings;
 use crate::storage::AccountMetadata;
 use crate::storage::{PrvKeyData, PrvKeyDataId};
 use crate::tx::PaymentOutput;
-use crate::tx::{Fees, Generator, GeneratorSettings, GeneratorSummary, PaymentDestination, PendingTransaction, Signer};
+use crate::tx::{
+    sign_transaction_inputs_async, verify_signable_transaction, ChangePolicy, CoinControl, Fees, Generator, GeneratorProgress,
+    GeneratorSettings, GeneratorSummary, PaymentBatch, PaymentDestination, PendingTransaction, Signer, SignerT, SigningStatus,
+    TransactionValidation,
+};
 use crate::utxo::balance::{AtomicBalance, BalanceStrings};
-use crate::utxo::UtxoContextBinding;
+use crate::utxo::scan::DEFAULT_WINDOW_SIZE;
+use crate::utxo::{HistoryRescan, HistoryRescanSummary, PaymentRequestId, UtxoContextBinding, UtxoEntryId};
 use kaspa_bip32::{ChildNumber, ExtendedPrivateKey, PrivateKey};
 use kaspa_consensus_client::UtxoEntryReference;
+use kaspa_consensus_core::sign::Signed;
+use kaspa_consensus_core::tx::SignableTransaction;
+use kaspa_txscript::extract_script_pub_key_address;
 use kaspa_wallet_keys::derivation::gen0::WalletDerivationManagerV0;
 use workflow_core::abortable::Abortable;
 
@@ -34,11 +46,15 @@ pub type ScanNotifier = Arc<dyn Fn(usize, usize, u64, Option<TransactionId>) + S
 /// General-purpose wrapper around [`AccountSettings`] (managed by [`Inner`]).
 pub struct Context {
     pub settings: AccountSettings,
+    /// Address gap limit (persisted in the [`AccountMetadata`])
+    pub gap_limit: Option<u32>,
+    /// Progress of an interrupted account operation (persisted in the [`AccountMetadata`])
+    pub checkpoint: Option<OperationCheckpoint>,
 }
 
 impl Context {
     pub fn new(settings: AccountSettings) -> Self {
-        Self { settings }
+        Self { settings, gap_limit: None, checkpoint: None }
     }
 
     pub fn settings(&self) -> &AccountSettings {
@@ -59,7 +75,7 @@ impl Inner {
     pub fn new(wallet: &Arc<Wallet>, id: AccountId, storage_key: AccountStorageKey, settings: AccountSettings) -> Self {
         let utxo_context = UtxoContext::new(wallet.utxo_processor(), UtxoContextBinding::AccountId(id));
 
-        let context = Context { settings };
+        let context = Context::new(settings);
         Inner { context: Mutex::new(context), id, storage_key, wallet: wallet.clone(), utxo_context: utxo_context.clone() }
     }
 
@@ -116,6 +132,11 @@ pub trait Account: AnySync + Send + Sync + 'static {
         self.context().settings.name.clone()
     }
 
+    /// Archived accounts are excluded from account enumeration and activation.
+    fn is_archived(&self) -> bool {
+        self.context().settings.archived
+    }
+
     fn name_or_id(&self) -> String {
         if let Some(name) = self.name() {
             if name.is_empty() {
@@ -153,6 +174,16 @@ pub trait Account: AnySync + Send + Sync + 'static {
         Ok(())
     }
 
+    /// Update the archived flag of the stored account. The storage
+    /// is committed by the caller (see [`Wallet::archive_accounts()`]).
+    async fn set_archived(&self, archived: bool) -> Result<()> {
+        self.context().settings.archived = archived;
+
+        let account = self.to_storage()?;
+        self.wallet().store().as_account_store()?.store_single(&account, None).await?;
+        Ok(())
+    }
+
     fn get_list_string(&self) -> Result<String> {
         let name = style(self.name_with_id()).blue();
         let balance = self.balance_as_strings(None)?;
@@ -195,50 +226,195 @@ pub trait Account: AnySync + Send + Sync + 'static {
     fn metadata(&self) -> Result<Option<AccountMetadata>>;
     fn descriptor(&self) -> Result<descriptor::AccountDescriptor>;
 
+    /// Address gap limit configured for the account (`None` if the
+    /// default gap limit ([`DEFAULT_WINDOW_SIZE`]) is used).
+    fn gap_limit(&self) -> Option<u32> {
+        self.context().gap_limit
+    }
+
+    /// Set the address gap limit: the number of consecutive unused addresses
+    /// scanned past the last used address during the account scan. The gap
+    /// limit is persisted in the account metadata (`None` restores the default).
+    async fn set_gap_limit(self: Arc<Self>, gap_limit: Option<u32>) -> Result<()> {
+        if gap_limit == Some(0) {
+            return Err(Error::InvalidGapLimit);
+        }
+        self.clone().as_derivation_capable().map_err(|_| Error::AccountKindFeature)?;
+
+        self.context().gap_limit = gap_limit;
+        self.store_metadata().await?;
+        self.wallet().notify(Events::AccountUpdate { account_descriptor: self.descriptor()? }).await?;
+        Ok(())
+    }
+
+    /// Progress of an interrupted multi-transaction account operation
+    /// (such as [`sweep`](Self::sweep)) that will be resumed by the
+    /// next checkpointed operation.
+    fn checkpoint(&self) -> Option<OperationCheckpoint> {
+        self.context().checkpoint.clone()
+    }
+
+    /// Update (or clear) the operation checkpoint persisted in the account metadata.
+    async fn store_checkpoint(&self, checkpoint: Option<OperationCheckpoint>) -> Result<()> {
+        self.context().checkpoint = checkpoint;
+        self.store_metadata().await
+    }
+
+    /// Discard the checkpoint of an interrupted operation, causing the
+    /// next invocation of the operation to start from scratch.
+    async fn clear_checkpoint(self: Arc<Self>) -> Result<()> {
+        if self.checkpoint().is_some() {
+            self.store_checkpoint(None).await?;
+        }
+        Ok(())
+    }
+
+    /// Validate the checkpoint of an interrupted operation before it is resumed.
+    /// Recorded transactions that are neither accepted (tracked by the UTXO processor)
+    /// nor present in the node mempool have been rejected or evicted; they are removed
+    /// from the checkpoint, making the UTXOs they spent available to the resumed operation.
+    async fn resume_checkpoint(&self) -> Result<Option<OperationCheckpoint>> {
+        let Some(mut checkpoint) = self.checkpoint() else {
+            return Ok(None);
+        };
+
+        if !self.wallet().is_connected() {
+            return Err(Error::NotConnected);
+        }
+
+        let rpc_api = self.wallet().rpc_api();
+        let utxo_context = self.utxo_context();
+        let mut pending = AHashSet::new();
+        for transaction_id in checkpoint.transaction_ids() {
+            let accepted = utxo_context.processor().outgoing().get(transaction_id).is_some_and(|outgoing| outgoing.is_accepted());
+            if accepted || rpc_api.get_mempool_entry(*transaction_id, true, false).await.is_ok() {
+                pending.insert(*transaction_id);
+            }
+        }
+
+        if pending.len() != checkpoint.transaction_count() {
+            checkpoint.retain(|transaction_id| pending.contains(transaction_id));
+            self.store_checkpoint(Some(checkpoint.clone())).await?;
+        }
+
+        Ok(Some(checkpoint))
+    }
+
+    /// Sign and submit all transactions produced by a multi-transaction account
+    /// operation of the given `kind`. The progress is recorded in an [`OperationCheckpoint`]
+    /// after each submitted transaction. If the operation is aborted or interrupted,
+    /// the next checkpointed operation resumes the checkpoint (see [`resume_checkpoint`](Self::resume_checkpoint)),
+    /// skipping UTXOs spent by the previously submitted transactions that are still pending.
+    /// The checkpoint is cleared once the operation completes.
+    async fn submit_checkpointed(
+        self: Arc<Self>,
+        kind: OperationKind,
+        settings: GeneratorSettings,
+        signer: Arc<dyn SignerT>,
+        abortable: &Abortable,
+        notifier: Option<GenerationNotifier>,
+    ) -> Result<(GeneratorSummary, Vec<kaspa_hashes::Hash>)> {
+        let (mut checkpoint, settings) = match self.resume_checkpoint().await? {
+            Some(checkpoint) => {
+                let spent_utxos = checkpoint.spent_utxos();
+                (checkpoint, settings.without_utxo_entries(spent_utxos))
+            }
+            None => (OperationCheckpoint::new(kind), settings),
+        };
+        let generator = Generator::try_new(settings, Some(signer), Some(abortable))?;
+
+        let mut stream = generator.stream();
+        let mut ids = vec![];
+        while let Some(transaction) = stream.try_next().await? {
+            transaction.try_sign_async().await?;
+            ids.push(transaction.try_submit(&self.wallet().rpc_api()).await?);
+            checkpoint.record(transaction.id(), transaction.utxo_entries().keys().cloned());
+            self.store_checkpoint(Some(checkpoint.clone())).await?;
+            self.notify_generator_progress(&generator, &transaction).await?;
+
+            if let Some(notifier) = notifier.as_ref() {
+                notifier(&transaction);
+            }
+            yield_executor().await;
+        }
+
+        self.store_checkpoint(None).await?;
+
+        Ok((generator.summary(), ids))
+    }
+
+    /// Scan the account addresses for UTXOs. The `window_size` (the number of
+    /// consecutive unused addresses scanned past the last used address) defaults
+    /// to the account [gap limit](Self::gap_limit).
+    ///
+    /// The scan is transactional: if it fails, the account UTXO set is rolled
+    /// back to its state prior to the scan. If the failure is caused by the loss
+    /// of the RPC connection, [`Events::ScanInterrupted`] is emitted and the scan
+    /// is resumed by the wallet upon reconnection ([`Events::ScanResumed`]).
     async fn scan(self: Arc<Self>, window_size: Option<usize>, extent: Option<u32>) -> Result<()> {
-        self.utxo_context().clear().await?;
+        let checkpoint = self.utxo_context().checkpoint();
+
+        let result: Result<()> = async {
+            self.utxo_context().clear().await?;
+            let window_size = window_size.or(self.gap_limit().map(|gap_limit| gap_limit as usize));
+
+            let current_daa_score = self.wallet().current_daa_score().ok_or(Error::NotConnected)?;
+            let balance = Arc::new(AtomicBalance::default());
+            let wallet_sync = self.wallet().wallet_sync();
+
+            match self.clone().as_derivation_capable() {
+                Ok(account) => {
+                    let derivation = account.derivation();
+
+                    let extent = match extent {
+                        Some(depth) => ScanExtent::Depth(depth),
+                        None => ScanExtent::EmptyWindow,
+                    };
+
+                    let scans = [
+                        Scan::new_with_address_manager(
+                            derivation.receive_address_manager(),
+                            &balance,
+                            current_daa_score,
+                            window_size,
+                            Some(extent),
+                        )
+                        .with_wallet_sync(wallet_sync),
+                        Scan::new_with_address_manager(
+                            derivation.change_address_manager(),
+                            &balance,
+                            current_daa_score,
+                            window_size,
+                            Some(extent),
+                        )
+                        .with_wallet_sync(wallet_sync),
+                    ];
+
+                    let futures = scans.iter().map(|scan| scan.scan(self.utxo_context())).collect::<Vec<_>>();
+
+                    join_all(futures).await.into_iter().collect::<Result<Vec<_>>>()?;
+                }
+                Err(_) => {
+                    let mut address_set = HashSet::<Address>::new();
+                    address_set.insert(self.receive_address()?);
+                    address_set.insert(self.change_address()?);
 
-        let current_daa_score = self.wallet().current_daa_score().ok_or(Error::NotConnected)?;
-        let balance = Arc::new(AtomicBalance::default());
-
-        match self.clone().as_derivation_capable() {
-            Ok(account) => {
-                let derivation = account.derivation();
-
-                let extent = match extent {
-                    Some(depth) => ScanExtent::Depth(depth),
-                    None => ScanExtent::EmptyWindow,
-                };
-
-                let scans = [
-                    Scan::new_with_address_manager(
-                        derivation.receive_address_manager(),
-                        &balance,
-                        current_daa_score,
-                        window_size,
-                        Some(extent),
-                    ),
-                    Scan::new_with_address_manager(
-                        derivation.change_address_manager(),
-                        &balance,
-                        current_daa_score,
-                        window_size,
-                        Some(extent),
-                    ),
-                ];
-
-                let futures = scans.iter().map(|scan| scan.scan(self.utxo_context())).collect::<Vec<_>>();
-
-                join_all(futures).await.into_iter().collect::<Result<Vec<_>>>()?;
+                    let scan = Scan::new_with_address_set(address_set, &balance, current_daa_score).with_wallet_sync(wallet_sync);
+                    scan.scan(self.utxo_context()).await?;
+                }
             }
-            Err(_) => {
-                let mut address_set = HashSet::<Address>::new();
-                address_set.insert(self.receive_address()?);
-                address_set.insert(self.change_address()?);
 
-                let scan = Scan::new_with_address_set(address_set, &balance, current_daa_score);
-                scan.scan(self.utxo_context()).await?;
+            Ok(())
+        }
+        .await;
+
+        if let Err(err) = result {
+            self.utxo_context().rollback(checkpoint).await?;
+            if !self.wallet().is_connected() || matches!(err.code(), ErrorCode::NetworkError | ErrorCode::NotConnected) {
+                self.wallet().register_interrupted_scan(*self.id(), window_size, extent);
+                self.wallet().notify(Events::ScanInterrupted { account_id: *self.id(), message: err.to_string() }).await?;
             }
+            return Err(err);
         }
 
         self.utxo_context().update_balance().await?;
@@ -246,6 +422,53 @@ pub trait Account: AnySync + Send + Sync + 'static {
         Ok(())
     }
 
+    /// Reconstruct the account transaction history from the transactions
+    /// accepted by the selected chain within the `range` of DAA scores.
+    async fn rescan_history(self: Arc<Self>, range: std::ops::Range<u64>, abortable: &Abortable) -> Result<HistoryRescanSummary> {
+        if !self.wallet().is_connected() {
+            return Err(Error::NotConnected);
+        }
+
+        HistoryRescan::try_new(self.as_dyn_arc(), range)?.run(abortable).await
+    }
+
+    /// Lock UTXOs identified by `outpoints`, excluding them from spending.
+    /// The lock list is persisted in the account metadata.
+    async fn lock_utxos(self: Arc<Self>, outpoints: &[UtxoEntryId]) -> Result<()> {
+        self.utxo_context().lock(outpoints).await?;
+        self.store_metadata().await
+    }
+
+    /// Unlock UTXOs identified by `outpoints`, making them available for spending.
+    async fn unlock_utxos(self: Arc<Self>, outpoints: &[UtxoEntryId]) -> Result<()> {
+        self.utxo_context().unlock(outpoints).await?;
+        self.store_metadata().await
+    }
+
+    /// Assign `label` to UTXOs identified by `outpoints` (`None` removes the label).
+    /// Labels are persisted in the account metadata.
+    async fn label_utxos(self: Arc<Self>, outpoints: &[UtxoEntryId], label: Option<String>) -> Result<()> {
+        self.utxo_context().set_label(outpoints, label);
+        self.store_metadata().await
+    }
+
+    /// Cancel the payment request identified by `id`, removing it from the account
+    /// metadata. Returns the removed request (`None` if the request was not found).
+    async fn remove_payment_request(self: Arc<Self>, id: &PaymentRequestId) -> Result<Option<PaymentRequest>> {
+        let request = self.utxo_context().remove_payment_request(id);
+        if request.is_some() {
+            self.store_metadata().await?;
+        }
+        Ok(request)
+    }
+
+    async fn store_metadata(&self) -> Result<()> {
+        let metadata = self.metadata()?.ok_or_else(|| Error::custom("account does not support metadata"))?;
+        let store = self.wallet().store().as_account_store()?;
+        store.update_metadata(vec![metadata]).await?;
+        Ok(())
+    }
+
     fn sig_op_count(&self) -> u8;
 
     fn minimum_signatures(&self) -> u16;
@@ -286,6 +509,9 @@ pub trait Account: AnySync + Send + Sync + 'static {
 
     /// Aggregate all account UTXOs into the change address.
     /// Also known as "compounding".
+    ///
+    /// The sweep is checkpointed (see [`submit_checkpointed`](Self::submit_checkpointed)),
+    /// an aborted or interrupted sweep is resumed by the next sweep.
     async fn sweep(
         self: Arc<Self>,
         wallet_secret: Secret,
@@ -295,49 +521,72 @@ pub trait Account: AnySync + Send + Sync + 'static {
     ) -> Result<(GeneratorSummary, Vec<kaspa_hashes::Hash>)> {
         let keydata = self.prv_key_data(wallet_secret).await?;
         let signer = Arc::new(Signer::new(self.clone().as_dyn_arc(), keydata, payment_secret));
+
         let settings =
             GeneratorSettings::try_new_with_account(self.clone().as_dyn_arc(), PaymentDestination::Change, Fees::None, None)?;
-        let generator = Generator::try_new(settings, Some(signer), Some(abortable))?;
-
-        let mut stream = generator.stream();
-        let mut ids = vec![];
-        while let Some(transaction) = stream.try_next().await? {
-            transaction.try_sign()?;
-            ids.push(transaction.try_submit(&self.wallet().rpc_api()).await?);
-
-            if let Some(notifier) = notifier.as_ref() {
-                notifier(&transaction);
-            }
-            yield_executor().await;
-        }
-
-        Ok((generator.summary(), ids))
+        self.submit_checkpointed(OperationKind::Sweep, settings, signer, abortable, notifier).await
     }
 
     /// Send funds to a [`PaymentDestination`] comprised of one or multiple [`PaymentOutputs`](crate::tx::PaymentOutputs)
     /// or [`PaymentDestination::Change`] variant that will forward funds to the change address.
+    ///
+    /// If `coin_control` is supplied, the specified (mature, unlocked) UTXOs are spent
+    /// by the generated transactions and, if [`CoinControl::exclusive`] is set, no
+    /// other UTXOs are selected.
+    ///
+    /// If `dry_run` is `true`, generated transactions are signed and validated by the node
+    /// without being broadcast, and the validation results are returned alongside the summary.
+    /// Transactions spending outputs of preceding (batch) transactions can not be validated
+    /// by the node and are reported as unvalidated. Otherwise the submission is checkpointed
+    /// (see [`submit_checkpointed`](Self::submit_checkpointed)).
+    ///
+    /// The `change_policy` determines the handling of the final transaction change
+    /// that is too small to be issued as a change output.
+    #[allow(clippy::too_many_arguments)]
     async fn send(
         self: Arc<Self>,
         destination: PaymentDestination,
         priority_fee_sompi: Fees,
         payload: Option<Vec<u8>>,
+        coin_control: Option<CoinControl>,
+        change_policy: ChangePolicy,
         wallet_secret: Secret,
         payment_secret: Option<Secret>,
+        dry_run: bool,
         abortable: &Abortable,
         notifier: Option<GenerationNotifier>,
-    ) -> Result<(GeneratorSummary, Vec<kaspa_hashes::Hash>)> {
+    ) -> Result<(GeneratorSummary, Vec<kaspa_hashes::Hash>, Option<Vec<TransactionValidation>>)> {
         let keydata = self.prv_key_data(wallet_secret).await?;
         let signer = Arc::new(Signer::new(self.clone().as_dyn_arc(), keydata, payment_secret));
 
-        let settings = GeneratorSettings::try_new_with_account(self.clone().as_dyn_arc(), destination, priority_fee_sompi, payload)?;
+        let mut settings =
+            GeneratorSettings::try_new_with_account(self.clone().as_dyn_arc(), destination, priority_fee_sompi, payload)?
+                .with_change_policy(change_policy);
+        if let Some(coin_control) = coin_control.as_ref() {
+            settings = settings.with_coin_control(coin_control)?;
+        }
+
+        if !dry_run {
+            let (summary, ids) = self.submit_checkpointed(OperationKind::Send, settings, signer, abortable, notifier).await?;
+            return Ok((summary, ids, None));
+        }
 
         let generator = Generator::try_new(settings, Some(signer), Some(abortable))?;
 
         let mut stream = generator.stream();
         let mut ids = vec![];
+        let mut validations = vec![];
         while let Some(transaction) = stream.try_next().await? {
-            transaction.try_sign()?;
-            ids.push(transaction.try_submit(&self.wallet().rpc_api()).await?);
+            transaction.try_sign_async().await?;
+            let dependencies = ids.iter().cloned().collect::<AHashSet<_>>();
+            let validation = if transaction.depends_on(&dependencies) {
+                TransactionValidation::unvalidated(&transaction)
+            } else {
+                transaction.try_validate(&self.wallet().rpc_api()).await?
+            };
+            ids.push(transaction.id());
+            validations.push(validation);
+            self.notify_generator_progress(&generator, &transaction).await?;
 
             if let Some(notifier) = notifier.as_ref() {
                 notifier(&transaction);
@@ -345,7 +594,72 @@ pub trait Account: AnySync + Send + Sync + 'static {
             yield_executor().await;
         }
 
-        Ok((generator.summary(), ids))
+        Ok((generator.summary(), ids, Some(validations)))
+    }
+
+    /// Send a [`PaymentBatch`] to multiple recipients. Batch payments are
+    /// partitioned into as few transactions as the mass limits allow, each
+    /// partition spending the change of the preceding one (chained transactions).
+    /// [`Events::PaymentBatchProgress`] is emitted for each submitted transaction.
+    async fn send_batch(
+        self: Arc<Self>,
+        batch: PaymentBatch,
+        priority_fee_sompi: Fees,
+        wallet_secret: Secret,
+        payment_secret: Option<Secret>,
+        abortable: &Abortable,
+        notifier: Option<GenerationNotifier>,
+    ) -> Result<(Vec<GeneratorSummary>, Vec<kaspa_hashes::Hash>)> {
+        let keydata = self.prv_key_data(wallet_secret).await?;
+        let signer = Arc::new(Signer::new(self.clone().as_dyn_arc(), keydata, payment_secret));
+
+        let partitions = batch.partitions();
+        let total_partitions = partitions.len();
+        let mut summaries = vec![];
+        let mut ids = vec![];
+        let mut payments = 0;
+        let mut change_utxo_entry = None;
+        for (partition, outputs) in partitions.into_iter().enumerate() {
+            let partition_payments = outputs.outputs.len();
+            let settings =
+                GeneratorSettings::try_new_with_account(self.clone().as_dyn_arc(), outputs.into(), priority_fee_sompi.clone(), None)?
+                    .with_preceding_utxo_entries(change_utxo_entry.take().into_iter().collect());
+            let generator = Generator::try_new(settings, Some(signer.clone()), Some(abortable))?;
+
+            let mut stream = generator.stream();
+            while let Some(transaction) = stream.try_next().await? {
+                transaction.try_sign_async().await?;
+                let id = transaction.try_submit(&self.wallet().rpc_api()).await?;
+                ids.push(id);
+
+                if transaction.is_final() {
+                    payments += partition_payments;
+                    change_utxo_entry = transaction.change_utxo_entry();
+                }
+
+                self.wallet()
+                    .notify(Events::PaymentBatchProgress {
+                        account_id: *self.id(),
+                        transaction_id: id,
+                        partition,
+                        partitions: total_partitions,
+                        payments,
+                        total_payments: batch.len(),
+                        fees: transaction.fees(),
+                        is_final: transaction.is_final(),
+                    })
+                    .await?;
+
+                if let Some(notifier) = notifier.as_ref() {
+                    notifier(&transaction);
+                }
+                yield_executor().await;
+            }
+
+            summaries.push(generator.summary());
+        }
+
+        Ok((summaries, ids))
     }
 
     /// Execute a transfer to another wallet account.
@@ -359,6 +673,10 @@ pub trait Account: AnySync + Send + Sync + 'static {
         abortable: &Abortable,
         notifier: Option<GenerationNotifier>,
     ) -> Result<(GeneratorSummary, Vec<kaspa_hashes::Hash>)> {
+        if destination_account_id == *self.id() {
+            return Err(Error::InvalidArgument("unable to transfer funds to the source account".to_string()));
+        }
+
         let keydata = self.prv_key_data(wallet_secret).await?;
         let signer = Arc::new(Signer::new(self.clone().as_dyn_arc(), keydata, payment_secret));
 
@@ -380,21 +698,19 @@ pub trait Account: AnySync + Send + Sync + 'static {
         )?
         .utxo_context_transfer(destination_account.utxo_context());
 
-        let generator = Generator::try_new(settings, Some(signer), Some(abortable))?;
-
-        let mut stream = generator.stream();
-        let mut ids = vec![];
-        while let Some(transaction) = stream.try_next().await? {
-            transaction.try_sign()?;
-            ids.push(transaction.try_submit(&self.wallet().rpc_api()).await?);
-
-            if let Some(notifier) = notifier.as_ref() {
-                notifier(&transaction);
-            }
-            yield_executor().await;
-        }
+        self.submit_checkpointed(OperationKind::Transfer, settings, signer, abortable, notifier).await
+    }
 
-        Ok((generator.summary(), ids))
+    /// Emit [`Events::GeneratorProgress`] for the last `transaction` produced by the `generator`.
+    /// The total number of transactions is not obtained in advance as that requires an additional
+    /// generator run, the progress reflects the running state of the generator.
+    async fn notify_generator_progress(&self, generator: &Generator, transaction: &PendingTransaction) -> Result<()> {
+        self.wallet()
+            .notify(Events::GeneratorProgress {
+                account_id: *self.id(),
+                progress: GeneratorProgress::new(generator, transaction, None),
+            })
+            .await
     }
 
     async fn estimate(
@@ -402,9 +718,11 @@ pub trait Account: AnySync + Send + Sync + 'static {
         destination: PaymentDestination,
         priority_fee_sompi: Fees,
         payload: Option<Vec<u8>>,
+        change_policy: ChangePolicy,
         abortable: &Abortable,
     ) -> Result<GeneratorSummary> {
-        let settings = GeneratorSettings::try_new_with_account(self.as_dyn_arc(), destination, priority_fee_sompi, payload)?;
+        let settings = GeneratorSettings::try_new_with_account(self.as_dyn_arc(), destination, priority_fee_sompi, payload)?
+            .with_change_policy(change_policy);
 
         let generator = Generator::try_new(settings, None, Some(abortable))?;
 
@@ -416,6 +734,109 @@ pub trait Account: AnySync + Send + Sync + 'static {
         Ok(generator.summary())
     }
 
+    /// Export the account extended public key along with a proof of its
+    /// ownership signed by the key of the first receive address.
+    async fn xpub_ownership_proof(&self, _wallet_secret: Secret, _payment_secret: Option<Secret>) -> Result<XPubOwnershipProof> {
+        Err(Error::AccountKindFeature)
+    }
+
+    /// Private key of an account `address`, used for signing
+    /// on behalf of the address (e.g. message signing).
+    async fn address_private_key(
+        self: Arc<Self>,
+        address: &Address,
+        wallet_secret: Secret,
+        payment_secret: Option<Secret>,
+    ) -> Result<secp256k1::SecretKey> {
+        let account = self.clone().as_derivation_capable().map_err(|_| Error::AccountKindFeature)?;
+        let derivation = account.derivation();
+        // addresses not yet registered by the address managers
+        // are located by deriving the range of used indexes
+        let (receive, change) = derivation.addresses_indexes(&[address]).or_else(|_| {
+            let locate = |manager: Arc<AddressManager>| -> Result<Vec<(&Address, u32)>> {
+                let addresses = manager.get_range_with_args(0..manager.index() + 1, false)?;
+                Ok(addresses
+                    .iter()
+                    .position(|candidate| candidate == address)
+                    .map(|index| (address, index as u32))
+                    .into_iter()
+                    .collect())
+            };
+            Ok::<_, Error>((locate(derivation.receive_address_manager())?, locate(derivation.change_address_manager())?))
+        })?;
+        if receive.is_empty() && change.is_empty() {
+            return Err(Error::AddressNotFound(address.clone()));
+        }
+
+        let keydata = self.prv_key_data(wallet_secret).await?;
+        let private_keys = account.create_private_keys(&keydata, &payment_secret, &receive, &change)?;
+        private_keys
+            .into_iter()
+            .find_map(|(key_address, private_key)| (key_address == address).then_some(private_key))
+            .ok_or_else(|| Error::AddressNotFound(address.clone()))
+    }
+
+    /// Sign a personal message with the key of an account `address`
+    /// (see [`message`](crate::message)). Returns a hex-encoded Schnorr signature.
+    async fn sign_message(
+        self: Arc<Self>,
+        address: &Address,
+        message: &str,
+        wallet_secret: Secret,
+        payment_secret: Option<Secret>,
+    ) -> Result<String> {
+        if address.version != kaspa_addresses::Version::PubKey {
+            return Err(Error::MessageSigningAddressVersion);
+        }
+
+        let private_key = self.address_private_key(address, wallet_secret, payment_secret).await?;
+        let signature = sign_message(&PersonalMessage(message), &private_key.secret_bytes())?;
+        Ok(faster_hex::hex_string(signature.as_slice()))
+    }
+
+    /// Sign the inputs of an externally supplied transaction (e.g. created by an
+    /// online watch-only wallet) that spend UTXOs of the account addresses.
+    /// Inputs that do not belong to the account are left unchanged, resulting
+    /// in a partially signed transaction that can be signed by other signers.
+    /// Only `PubKey` (Schnorr) inputs can be signed.
+    async fn sign_transaction(
+        self: Arc<Self>,
+        transaction: SignableTransaction,
+        wallet_secret: Secret,
+        payment_secret: Option<Secret>,
+    ) -> Result<(SignableTransaction, SigningStatus)> {
+        if !transaction.is_verifiable() {
+            return Err(Error::InvalidSignableTransaction("missing transaction UTXO entries".to_string()));
+        }
+
+        let account = self.clone().as_derivation_capable().map_err(|_| Error::AccountKindFeature)?;
+        let derivation = account.derivation();
+        let prefix = self.wallet().address_prefix()?;
+        let addresses = transaction
+            .entries
+            .iter()
+            .flatten()
+            .filter_map(|entry| extract_script_pub_key_address(&entry.script_public_key, prefix).ok())
+            .filter(|address| address.version == kaspa_addresses::Version::PubKey)
+            .collect::<AHashSet<_>>();
+        let addresses = addresses.iter().filter(|address| derivation.addresses_indexes(&[address]).is_ok()).collect::<Vec<_>>();
+        if addresses.is_empty() {
+            return Err(Error::NoSignableInputs);
+        }
+
+        let keydata = self.prv_key_data(wallet_secret).await?;
+        let (receive, change) = derivation.addresses_indexes(&addresses)?;
+        let private_keys = account.create_private_keys(&keydata, &payment_secret, &receive, &change)?;
+        let mut keys_for_signing = private_keys.iter().map(|(_, private_key)| private_key.secret_bytes()).collect::<Vec<_>>();
+        let transaction = match sign_transaction_inputs_async(transaction, &keys_for_signing, &[]).await {
+            Signed::Fully(transaction) | Signed::Partially(transaction) => transaction,
+        };
+        keys_for_signing.zeroize();
+
+        let status = verify_signable_transaction(&transaction)?;
+        Ok((transaction, status))
+    }
+
     fn as_derivation_capable(self: Arc<Self>) -> Result<Arc<dyn DerivationCapableAccount>> {
         Err(Error::AccountAddressDerivationCaps)
     }
@@ -537,7 +958,7 @@ pub trait DerivationCapableAccount: Account {
 
                     let mut stream = generator.stream();
                     while let Some(transaction) = stream.try_next().await? {
-                        transaction.try_sign_with_keys(&keys)?;
+                        transaction.try_sign_with_keys_async(&keys).await?;
                         let id = transaction.try_submit(&rpc).await?;
                         if let Some(notifier) = notifier {
                             notifier(index, aggregate_utxo_count, balance, Some(id));
@@ -576,9 +997,43 @@ pub trait DerivationCapableAccount: Account {
         Ok(())
     }
 
+    /// Number of receive addresses generated past the last used receive address
+    /// (an address that holds or has held UTXOs). Wallets restored from the mnemonic
+    /// discover funds only within the [gap limit](Account::gap_limit) past the last
+    /// used address.
+    fn receive_address_gap(&self) -> u32 {
+        let derivation = self.derivation();
+        let utxo_context = self.utxo_context();
+        // addresses emptied by spending their UTXOs remain used
+        let used = utxo_context.addresses_usage().into_iter().map(|usage| usage.address);
+        let addresses = utxo_context.utxo_addresses().into_iter().chain(used).collect::<AHashSet<_>>();
+        let addresses = addresses.iter().collect::<Vec<_>>();
+        let last_used = derivation
+            .addresses_indexes(&addresses)
+            .ok()
+            .and_then(|(receive, _)| receive.into_iter().map(|(_, index)| index + 1).max())
+            .unwrap_or_default();
+        derivation.receive_address_manager().index().saturating_sub(last_used)
+    }
+
+    /// `true` if the number of unused receive addresses exceeds the
+    /// account gap limit (see [`receive_address_gap()`](Self::receive_address_gap)).
+    fn is_beyond_gap_limit(&self) -> bool {
+        self.receive_address_gap() >= self.gap_limit().unwrap_or(DEFAULT_WINDOW_SIZE as u32)
+    }
+
     async fn new_receive_address(self: Arc<Self>) -> Result<Address> {
         let address = self.derivation().receive_address_manager().new_address()?;
         self.utxo_context().register_addresses(&[address.clone()]).await?;
+        if self.is_beyond_gap_limit() {
+            log!(
+                Wallet,
+                Warn,
+                "Account {}: receive address {address} is {} addresses past the last used address, exceeding the gap limit",
+                self.name_with_id(),
+                self.receive_address_gap()
+            );
+        }
 
         let metadata = self.metadata()?.expect("derivation accounts must provide metadata");
         let store = self.wallet().store().as_account_store()?;
@@ -589,6 +1044,28 @@ pub trait DerivationCapableAccount: Account {
         Ok(address)
     }
 
+    /// Create a payment request for `amount` SOMPI tied to a new receive address
+    /// and register it with the account [`UtxoContext`]. The request expires
+    /// after `expiry` (if supplied).
+    async fn create_payment_request(
+        self: Arc<Self>,
+        amount: u64,
+        expiry: Option<Duration>,
+        label: Option<String>,
+        message: Option<String>,
+    ) -> Result<PaymentRequest> {
+        if amount == 0 {
+            return Err(Error::custom("payment request amount must be greater than zero"));
+        }
+
+        let address = self.clone().new_receive_address().await?;
+        let request = PaymentRequest::new(address, amount, expiry).with_label(label).with_message(message);
+        self.utxo_context().register_payment_request(request.clone());
+        self.store_metadata().await?;
+
+        Ok(request)
+    }
+
     async fn new_change_address(self: Arc<Self>) -> Result<Address> {
         let address = self.derivation().change_address_manager().new_address()?;
         self.utxo_context().register_addresses(&[address.clone()]).await?;
@@ -606,6 +1083,11 @@ pub trait DerivationCapableAccount: Account {
         0
     }
 
+    /// Custom derivation path used by this account (`None` if the account uses the standard derivation path).
+    fn derivation_path(&self) -> Option<DerivationPathTemplate> {
+        None
+    }
+
     fn create_private_keys<'l>(
         &self,
         key_data: &PrvKeyData,
@@ -615,7 +1097,15 @@ pub trait DerivationCapableAccount: Account {
     ) -> Result<Vec<(&'l Address, secp256k1::SecretKey)>> {
         let payload = key_data.payload.decrypt(payment_secret.as_ref())?;
         let xkey = payload.get_xprv(payment_secret.as_ref())?;
-        create_private_keys(&self.account_kind(), self.cosigner_index(), self.account_index(), &xkey, receive, change)
+        create_private_keys(
+            &self.account_kind(),
+            self.cosigner_index(),
+            self.account_index(),
+            self.derivation_path().as_ref(),
+            &xkey,
+            receive,
+            change,
+        )
     }
 }
 
@@ -625,11 +1115,15 @@ pub(crate) fn create_private_keys<'l>(
     account_kind: &AccountKind,
     cosigner_index: u32,
     account_index: u64,
+    derivation_path: Option<&DerivationPathTemplate>,
     xkey: &ExtendedPrivateKey<secp256k1::SecretKey>,
     receive: &[(&'l Address, u32)],
     change: &[(&'l Address, u32)],
 ) -> Result<Vec<(&'l Address, secp256k1::SecretKey)>> {
-    let paths = build_derivate_paths(account_kind, account_index, cosigner_index)?;
+    let paths = match derivation_path {
+        Some(derivation_path) => derivation_path.address_paths()?,
+        None => build_derivate_paths(account_kind, account_index, cosigner_index)?,
+    };
     let mut private_keys = vec![];
     if matches!(account_kind.as_ref(), LEGACY_ACCOUNT_KIND) {
         let (private_key, attrs) = WalletDerivationManagerV0::derive_key_by_path(xkey, paths.0)?;
@@ -802,14 +1296,14 @@ mod tests {
         let receive_keys = gen0_receive_keys();
         let change_keys = gen0_change_keys();
 
-        let keys = create_private_keys(&LEGACY_ACCOUNT_KIND.into(), 0, 0, &xkey, &receive_addresses, &[]).unwrap();
+        let keys = create_private_keys(&LEGACY_ACCOUNT_KIND.into(), 0, 0, None, &xkey, &receive_addresses, &[]).unwrap();
         for (index, (a, key)) in keys.iter().enumerate() {
             let address = PubkeyDerivationManagerV0::create_address(&key.get_public_key(), Prefix::Testnet, false).unwrap();
             assert_eq!(*a, &address, "receive address at {index} failed");
             assert_eq!(bytes_str(&key.to_bytes()), receive_keys[index], "receive key at {index} failed");
         }
 
-        let keys = create_private_keys(&LEGACY_ACCOUNT_KIND.into(), 0, 0, &xkey, &[], &change_addresses).unwrap();
+        let keys = create_private_keys(&LEGACY_ACCOUNT_KIND.into(), 0, 0, None, &xkey, &[], &change_addresses).unwrap();
         for (index, (a, key)) in keys.iter().enumerate() {
             let address = PubkeyDerivationManagerV0::create_address(&key.get_public_key(), Prefix::Testnet, false).unwrap();
             assert_eq!(*a, &address, "change address at {index} failed");
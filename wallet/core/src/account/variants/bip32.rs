@@ -3,8 +3,9 @@
 //!
 
 use crate::account::Inner;
-use crate::derivation::{AddressDerivationManager, AddressDerivationManagerTrait};
+use crate::derivation::{AddressDerivationManager, AddressDerivationManagerTrait, DerivationPathTemplate};
 use crate::imports::*;
+use crate::ownership::XPubOwnershipProof;
 
 pub const BIP32_ACCOUNT_KIND: &str = "kaspa-bip32-standard";
 
@@ -36,15 +37,21 @@ pub struct Payload {
     pub xpub_keys: Arc<Vec<ExtendedPublicKeySecp256k1>>,
     pub account_index: u64,
     pub ecdsa: bool,
+    pub derivation_path: Option<DerivationPathTemplate>,
 }
 
 impl Payload {
-    pub fn new(account_index: u64, xpub_keys: Arc<Vec<ExtendedPublicKeySecp256k1>>, ecdsa: bool) -> Self {
-        Self { account_index, xpub_keys, ecdsa }
+    pub fn new(
+        account_index: u64,
+        xpub_keys: Arc<Vec<ExtendedPublicKeySecp256k1>>,
+        ecdsa: bool,
+        derivation_path: Option<DerivationPathTemplate>,
+    ) -> Self {
+        Self { account_index, xpub_keys, ecdsa, derivation_path }
     }
 
     pub fn try_load(storage: &AccountStorage) -> Result<Self> {
-        Ok(Self::try_from_slice(storage.serialized.as_slice())?)
+        Ok((*storage.payload::<Self>()?).clone())
     }
 }
 
@@ -53,7 +60,7 @@ impl Storable for Payload {
     // serialization data alignment check
     const STORAGE_MAGIC: u32 = 0x32335042;
     // binary serialization version
-    const STORAGE_VERSION: u32 = 0;
+    const STORAGE_VERSION: u32 = 1;
 }
 
 impl AccountStorable for Payload {}
@@ -64,6 +71,7 @@ impl BorshSerialize for Payload {
         BorshSerialize::serialize(&self.xpub_keys, writer)?;
         BorshSerialize::serialize(&self.account_index, writer)?;
         BorshSerialize::serialize(&self.ecdsa, writer)?;
+        BorshSerialize::serialize(&self.derivation_path, writer)?;
 
         Ok(())
     }
@@ -71,14 +79,15 @@ impl BorshSerialize for Payload {
 
 impl BorshDeserialize for Payload {
     fn deserialize(buf: &mut &[u8]) -> IoResult<Self> {
-        let StorageHeader { version: _, .. } =
+        let StorageHeader { version, .. } =
             StorageHeader::deserialize(buf)?.try_magic(Self::STORAGE_MAGIC)?.try_version(Self::STORAGE_VERSION)?;
 
         let xpub_keys = BorshDeserialize::deserialize(buf)?;
         let account_index = BorshDeserialize::deserialize(buf)?;
         let ecdsa = BorshDeserialize::deserialize(buf)?;
+        let derivation_path = if version > 0 { BorshDeserialize::deserialize(buf)? } else { None };
 
-        Ok(Self { xpub_keys, account_index, ecdsa })
+        Ok(Self { xpub_keys, account_index, ecdsa, derivation_path })
     }
 }
 
@@ -88,6 +97,7 @@ pub struct Bip32 {
     account_index: u64,
     xpub_keys: ExtendedPublicKeys,
     ecdsa: bool,
+    derivation_path: Option<DerivationPathTemplate>,
     derivation: Arc<AddressDerivationManager>,
 }
 
@@ -99,8 +109,9 @@ impl Bip32 {
         account_index: u64,
         xpub_keys: ExtendedPublicKeys,
         ecdsa: bool,
+        derivation_path: Option<DerivationPathTemplate>,
     ) -> Result<Self> {
-        let storable = Payload::new(account_index, xpub_keys.clone(), ecdsa);
+        let storable = Payload::new(account_index, xpub_keys.clone(), ecdsa, derivation_path);
         let settings = AccountSettings { name, ..Default::default() };
         let (id, storage_key) = make_account_hashes(from_bip32(&prv_key_data_id, &storable));
         let inner = Arc::new(Inner::new(wallet, id, storage_key, settings));
@@ -112,12 +123,13 @@ impl Bip32 {
             ecdsa,
             account_index,
             None,
+            derivation_path,
             1,
             Default::default(),
         )
         .await?;
 
-        Ok(Self { inner, prv_key_data_id, account_index, xpub_keys, ecdsa, derivation })
+        Ok(Self { inner, prv_key_data_id, account_index, xpub_keys, ecdsa, derivation_path, derivation })
     }
 
     pub async fn try_load(wallet: &Arc<Wallet>, storage: &AccountStorage, meta: Option<Arc<AccountMetadata>>) -> Result<Self> {
@@ -125,7 +137,7 @@ impl Bip32 {
         let prv_key_data_id: PrvKeyDataId = storage.prv_key_data_ids.clone().try_into()?;
         let inner = Arc::new(Inner::from_storage(wallet, storage));
 
-        let Payload { account_index, xpub_keys, ecdsa, .. } = storable;
+        let Payload { account_index, xpub_keys, ecdsa, derivation_path } = storable;
 
         let address_derivation_indexes = meta.and_then(|meta| meta.address_derivation_indexes()).unwrap_or_default();
 
@@ -136,6 +148,7 @@ impl Bip32 {
             ecdsa,
             account_index,
             None,
+            derivation_path,
             1,
             address_derivation_indexes,
         )
@@ -149,7 +162,7 @@ impl Bip32 {
             .await?
             .ok_or_else(|| Error::PrivateKeyNotFound(prv_key_data_id))?;
 
-        Ok(Self { inner, prv_key_data_id, account_index, xpub_keys, ecdsa, derivation })
+        Ok(Self { inner, prv_key_data_id, account_index, xpub_keys, ecdsa, derivation_path, derivation })
     }
 
     pub fn get_address_range_for_scan(&self, range: std::ops::Range<u32>) -> Result<Vec<Address>> {
@@ -194,7 +207,7 @@ impl Account for Bip32 {
 
     fn to_storage(&self) -> Result<AccountStorage> {
         let settings = self.context().settings.clone();
-        let storable = Payload::new(self.account_index, self.xpub_keys.clone(), self.ecdsa);
+        let storable = Payload::new(self.account_index, self.xpub_keys.clone(), self.ecdsa, self.derivation_path);
         let storage = AccountStorage::try_new(
             BIP32_ACCOUNT_KIND.into(),
             self.id(),
@@ -202,18 +215,26 @@ impl Account for Bip32 {
             self.prv_key_data_id.into(),
             settings,
             storable,
-        )?;
+        )?
+        .with_properties(self.descriptor()?.properties);
 
         Ok(storage)
     }
 
     fn metadata(&self) -> Result<Option<AccountMetadata>> {
-        let metadata = AccountMetadata::new(self.inner.id, self.derivation.address_derivation_meta());
+        let metadata = AccountMetadata::new(self.inner.id, self.derivation.address_derivation_meta())
+            .with_locked_utxos(self.utxo_context().locked())
+            .with_utxo_labels(self.utxo_context().labels())
+            .with_gap_limit(self.gap_limit())
+            .with_checkpoint(self.checkpoint())
+            .with_address_usage(self.utxo_context().addresses_usage())
+            .with_addresses(self.receive_address().ok(), self.change_address().ok())
+            .with_payment_requests(self.utxo_context().payment_requests());
         Ok(Some(metadata))
     }
 
     fn descriptor(&self) -> Result<AccountDescriptor> {
-        let descriptor = AccountDescriptor::new(
+        let mut descriptor = AccountDescriptor::new(
             BIP32_ACCOUNT_KIND.into(),
             *self.id(),
             self.name(),
@@ -226,7 +247,26 @@ impl Account for Bip32 {
         .with_property(AccountDescriptorProperty::Ecdsa, self.ecdsa.into())
         .with_property(AccountDescriptorProperty::DerivationMeta, self.derivation.address_derivation_meta().into());
 
-        Ok(descriptor)
+        if let Some(derivation_path) = self.derivation_path {
+            descriptor = descriptor.with_property(AccountDescriptorProperty::DerivationPath, derivation_path.to_string().into());
+        }
+
+        Ok(descriptor.with_archived(self.is_archived()))
+    }
+
+    async fn xpub_ownership_proof(&self, wallet_secret: Secret, payment_secret: Option<Secret>) -> Result<XPubOwnershipProof> {
+        if self.ecdsa {
+            return Err(Error::AccountKindFeature);
+        }
+
+        let xpub = self.xpub_keys.first().ok_or_else(|| Error::custom("account has no extended public keys"))?.to_string(None);
+        let address = XPubOwnershipProof::derive_address(&xpub, self.wallet().network_id()?.into())?;
+
+        let keydata = self.prv_key_data(wallet_secret).await?;
+        let private_keys = self.create_private_keys(&keydata, &payment_secret, &[(&address, 0)], &[])?;
+        let (_, private_key) = private_keys.first().ok_or_else(|| Error::custom("unable to derive the receive address key"))?;
+
+        XPubOwnershipProof::try_new(&xpub, address.prefix, &private_key.secret_bytes())
     }
 
     fn as_derivation_capable(self: Arc<Self>) -> Result<Arc<dyn DerivationCapableAccount>> {
@@ -242,6 +282,10 @@ impl DerivationCapableAccount for Bip32 {
     fn account_index(&self) -> u64 {
         self.account_index
     }
+
+    fn derivation_path(&self) -> Option<DerivationPathTemplate> {
+        self.derivation_path
+    }
 }
 
 #[cfg(test)]
@@ -251,12 +295,14 @@ mod tests {
 
     #[test]
     fn test_storage_bip32() -> Result<()> {
-        let storable_in = Payload::new(0xbaadf00d, vec![make_xpub()].into(), false);
+        let derivation_path = Some("m/44'/972'/0'".parse::<DerivationPathTemplate>()?);
+        let storable_in = Payload::new(0xbaadf00d, vec![make_xpub()].into(), false, derivation_path);
         let guard = StorageGuard::new(&storable_in);
         let storable_out = guard.validate()?;
 
         assert_eq!(storable_in.account_index, storable_out.account_index);
         assert_eq!(storable_in.ecdsa, storable_out.ecdsa);
+        assert_eq!(storable_in.derivation_path, storable_out.derivation_path);
         assert_eq!(storable_in.xpub_keys.len(), storable_out.xpub_keys.len());
         for idx in 0..storable_in.xpub_keys.len() {
             assert_eq!(storable_in.xpub_keys[idx], storable_out.xpub_keys[idx]);
@@ -44,7 +44,7 @@ impl Payload {
     }
 
     pub fn try_load(storage: &AccountStorage) -> Result<Self> {
-        Ok(Self::try_from_slice(storage.serialized.as_slice())?)
+        Ok((*storage.payload::<Self>()?).clone())
     }
 }
 
@@ -119,6 +119,16 @@ impl Keypair {
         let Payload { public_key, ecdsa, .. } = storable;
         Ok(Self { inner, prv_key_data_id: storage.prv_key_data_ids.clone().try_into()?, public_key, ecdsa })
     }
+    /// Account address, a `PubKeyECDSA` address for ECDSA accounts.
+    fn address(&self) -> Result<Address> {
+        let network_type = self.inner().wallet.network_id()?.into();
+        if self.ecdsa {
+            Ok(Address::new(network_type, Version::PubKeyECDSA, &self.public_key.serialize()))
+        } else {
+            let (xonly_public_key, _) = self.public_key.x_only_public_key();
+            Ok(Address::new(network_type, Version::PubKey, &xonly_public_key.serialize()))
+        }
+    }
 }
 
 #[async_trait]
@@ -148,13 +158,11 @@ impl Account for Keypair {
     }
 
     fn receive_address(&self) -> Result<Address> {
-        let (xonly_public_key, _) = self.public_key.x_only_public_key();
-        Ok(Address::new(self.inner().wallet.network_id()?.into(), Version::PubKey, &xonly_public_key.serialize()))
+        self.address()
     }
 
     fn change_address(&self) -> Result<Address> {
-        let (xonly_public_key, _) = self.public_key.x_only_public_key();
-        Ok(Address::new(self.inner().wallet.network_id()?.into(), Version::PubKey, &xonly_public_key.serialize()))
+        self.address()
     }
 
     fn to_storage(&self) -> Result<AccountStorage> {
@@ -167,13 +175,21 @@ impl Account for Keypair {
             self.prv_key_data_id.into(),
             settings,
             storable,
-        )?;
+        )?
+        .with_properties(self.descriptor()?.properties);
 
         Ok(account_storage)
     }
 
     fn metadata(&self) -> Result<Option<AccountMetadata>> {
-        Ok(None)
+        let metadata = AccountMetadata::new_without_indexes(self.inner.id)
+            .with_locked_utxos(self.utxo_context().locked())
+            .with_utxo_labels(self.utxo_context().labels())
+            .with_checkpoint(self.checkpoint())
+            .with_address_usage(self.utxo_context().addresses_usage())
+            .with_addresses(self.receive_address().ok(), self.change_address().ok())
+            .with_payment_requests(self.utxo_context().payment_requests());
+        Ok(Some(metadata))
     }
 
     fn descriptor(&self) -> Result<AccountDescriptor> {
@@ -187,6 +203,20 @@ impl Account for Keypair {
         )
         .with_property(AccountDescriptorProperty::Ecdsa, self.ecdsa.into());
 
-        Ok(descriptor)
+        Ok(descriptor.with_archived(self.is_archived()))
+    }
+    async fn address_private_key(
+        self: Arc<Self>,
+        address: &Address,
+        wallet_secret: Secret,
+        payment_secret: Option<Secret>,
+    ) -> Result<secp256k1::SecretKey> {
+        if *address != self.receive_address()? {
+            return Err(Error::AddressNotFound(address.clone()));
+        }
+
+        let keydata = self.prv_key_data(wallet_secret).await?;
+        let payload = keydata.payload.decrypt(payment_secret.as_ref())?;
+        payload.as_secret_key()?.ok_or_else(|| Error::custom("unable to obtain the account private key"))
     }
 }
@@ -45,7 +45,7 @@ impl Payload {
     }
 
     pub fn try_load(storage: &AccountStorage) -> Result<Self> {
-        Ok(Self::try_from_slice(storage.serialized.as_slice())?)
+        Ok((*storage.payload::<Self>()?).clone())
     }
 }
 
@@ -115,6 +115,7 @@ impl MultiSig {
             ecdsa,
             0,
             cosigner_index.map(|v| v as u32),
+            None,
             minimum_signatures,
             Default::default(),
         )
@@ -138,6 +139,7 @@ impl MultiSig {
             ecdsa,
             0,
             cosigner_index.map(|v| v as u32),
+            None,
             minimum_signatures,
             address_derivation_indexes,
         )
@@ -207,13 +209,21 @@ impl Account for MultiSig {
             self.prv_key_data_ids.clone().try_into()?,
             settings,
             storable,
-        )?;
+        )?
+        .with_properties(self.descriptor()?.properties);
 
         Ok(account_storage)
     }
 
     fn metadata(&self) -> Result<Option<AccountMetadata>> {
-        let metadata = AccountMetadata::new(self.inner.id, self.derivation.address_derivation_meta());
+        let metadata = AccountMetadata::new(self.inner.id, self.derivation.address_derivation_meta())
+            .with_locked_utxos(self.utxo_context().locked())
+            .with_utxo_labels(self.utxo_context().labels())
+            .with_gap_limit(self.gap_limit())
+            .with_checkpoint(self.checkpoint())
+            .with_address_usage(self.utxo_context().addresses_usage())
+            .with_addresses(self.receive_address().ok(), self.change_address().ok())
+            .with_payment_requests(self.utxo_context().payment_requests());
         Ok(Some(metadata))
     }
 
@@ -230,7 +240,7 @@ impl Account for MultiSig {
         .with_property(AccountDescriptorProperty::Ecdsa, self.ecdsa.into())
         .with_property(AccountDescriptorProperty::DerivationMeta, self.derivation.address_derivation_meta().into());
 
-        Ok(descriptor)
+        Ok(descriptor.with_archived(self.is_archived()))
     }
 
     fn as_derivation_capable(self: Arc<Self>) -> Result<Arc<dyn DerivationCapableAccount>> {
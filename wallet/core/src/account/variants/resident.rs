@@ -82,6 +82,6 @@ impl Account for Resident {
             self.change_address().ok(),
         );
 
-        Ok(descriptor)
+        Ok(descriptor.with_archived(self.is_archived()))
     }
 }
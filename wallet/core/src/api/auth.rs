@@ -0,0 +1,284 @@
+//!
+//! Authentication of Wallet API requests carried over
+//! Serde JSON transports (HTTP, IPC).
+//!
+//! The client wraps each request into a [`SignedRequest`] envelope
+//! containing a random nonce, a timestamp and an HMAC-SHA256 signature
+//! created with a secret shared between the client and the server.
+//! The signature covers the API method name, the timestamp, the nonce
+//! and the canonical JSON serialization of the request (see [`to_canonical_json`]),
+//! making it independent of the JSON formatting produced by either side.
+//!
+//! On the client side, requests are signed by wrapping the transport into
+//! a [`SigningSerdeCodec`]. On the server side, a [`RequestVerifier`]
+//! installed into the [`WalletServer`](super::transport::WalletServer)
+//! rejects requests with an invalid signature, requests whose timestamp is
+//! outside of the accepted window and requests whose nonce has already
+//! been used within this window (replayed requests).
+//!
+
+use super::transport::SerdeCodec;
+use crate::error::Error;
+use crate::imports::*;
+use crate::result::Result;
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use serde_json::Value;
+use sha2::Sha256;
+use workflow_core::time::unixtime_as_millis_u64;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Default window (in milliseconds) within which the request
+/// timestamp must fall relative to the server clock.
+pub const DEFAULT_REQUEST_WINDOW_MSEC: u64 = 30 * 1000;
+
+/// Produces a deterministic JSON serialization of `value`: object keys are
+/// sorted lexicographically and no insignificant whitespace is emitted.
+pub fn to_canonical_json(value: &Value) -> String {
+    let mut out = String::new();
+    write_canonical_json(value, &mut out);
+    out
+}
+
+fn write_canonical_json(value: &Value, out: &mut String) {
+    match value {
+        Value::Array(items) => {
+            out.push('[');
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                write_canonical_json(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries.sort_by_key(|(key, _)| *key);
+            out.push('{');
+            for (index, (key, item)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical_json(item, out);
+            }
+            out.push('}');
+        }
+        // null, booleans, numbers and strings have a single serialization
+        _ => out.push_str(&value.to_string()),
+    }
+}
+
+/// Envelope carrying a signed Wallet API request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedRequest {
+    /// Random hex-encoded nonce, unique for each request.
+    pub nonce: String,
+    /// Request creation time (unix time in milliseconds).
+    pub timestamp: u64,
+    /// Hex-encoded HMAC-SHA256 signature.
+    pub signature: String,
+    /// The wrapped API request.
+    pub request: Value,
+}
+
+fn create_mac(secret: &Secret, op: &str, timestamp: u64, nonce: &str, request: &Value) -> Result<HmacSha256> {
+    let mut mac = HmacSha256::new_from_slice(secret.as_ref()).map_err(|err| Error::RequestAuthentication(err.to_string()))?;
+    mac.update(format!("{op}\n{timestamp}\n{nonce}\n").as_bytes());
+    mac.update(to_canonical_json(request).as_bytes());
+    Ok(mac)
+}
+
+/// Signs Wallet API requests with a shared secret.
+#[derive(Clone)]
+pub struct RequestSigner {
+    secret: Secret,
+}
+
+impl RequestSigner {
+    pub fn new(secret: Secret) -> Self {
+        Self { secret }
+    }
+
+    /// Wraps the JSON `request` for the API method `op` into a [`SignedRequest`]
+    /// envelope and returns its JSON serialization.
+    pub fn sign(&self, op: &str, request: &str) -> Result<String> {
+        let mut nonce = [0u8; 16];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        self.sign_with_args(op, request, &faster_hex::hex_string(&nonce), unixtime_as_millis_u64())
+    }
+
+    fn sign_with_args(&self, op: &str, request: &str, nonce: &str, timestamp: u64) -> Result<String> {
+        let request: Value = serde_json::from_str(request)?;
+        let mac = create_mac(&self.secret, op, timestamp, nonce, &request)?;
+        let signature = faster_hex::hex_string(&mac.finalize().into_bytes());
+        Ok(serde_json::to_string(&SignedRequest { nonce: nonce.to_string(), timestamp, signature, request })?)
+    }
+}
+
+/// Verifies Wallet API requests signed by [`RequestSigner`]
+/// and tracks request nonces to reject replayed requests.
+pub struct RequestVerifier {
+    secret: Secret,
+    window_msec: u64,
+    /// Nonces seen within the accepted window along with their request timestamps.
+    nonces: Mutex<HashMap<String, u64>>,
+}
+
+impl RequestVerifier {
+    pub fn new(secret: Secret) -> Self {
+        Self { secret, window_msec: DEFAULT_REQUEST_WINDOW_MSEC, nonces: Mutex::new(HashMap::new()) }
+    }
+
+    /// Sets the window (in milliseconds) within which the request timestamp
+    /// must fall relative to the server clock (in either direction).
+    pub fn with_window(mut self, window_msec: u64) -> Self {
+        self.window_msec = window_msec;
+        self
+    }
+
+    /// Verifies the JSON serialized [`SignedRequest`] envelope for the API
+    /// method `op` and returns the JSON serialization of the wrapped request.
+    pub fn verify(&self, op: &str, signed_request: &str) -> Result<String> {
+        self.verify_at(op, signed_request, unixtime_as_millis_u64())
+    }
+
+    fn verify_at(&self, op: &str, signed_request: &str, now: u64) -> Result<String> {
+        let SignedRequest { nonce, timestamp, signature, request } = serde_json::from_str(signed_request)
+            .map_err(|err| Error::RequestAuthentication(format!("malformed signed request: {err}")))?;
+
+        if timestamp.abs_diff(now) > self.window_msec {
+            return Err(Error::RequestExpired);
+        }
+
+        let mut signature_bytes = [0u8; 32];
+        faster_hex::hex_decode(signature.as_bytes(), &mut signature_bytes).map_err(|_| Error::InvalidRequestSignature)?;
+        create_mac(&self.secret, op, timestamp, &nonce, &request)?
+            .verify_slice(&signature_bytes)
+            .map_err(|_| Error::InvalidRequestSignature)?;
+
+        let mut nonces = self.nonces.lock().unwrap();
+        // nonces of requests outside of the window are no longer needed
+        // as such requests are rejected by the timestamp check
+        nonces.retain(|_, seen| seen.abs_diff(now) <= self.window_msec);
+        if nonces.insert(nonce, timestamp).is_some() {
+            return Err(Error::RequestReplay);
+        }
+
+        Ok(serde_json::to_string(&request)?)
+    }
+}
+
+/// [`SerdeCodec`] wrapper that signs all outgoing requests
+/// using [`RequestSigner`] before passing them to the underlying transport.
+pub struct SigningSerdeCodec {
+    codec: Arc<dyn SerdeCodec>,
+    signer: RequestSigner,
+}
+
+impl SigningSerdeCodec {
+    pub fn new(codec: Arc<dyn SerdeCodec>, secret: Secret) -> Self {
+        Self { codec, signer: RequestSigner::new(secret) }
+    }
+}
+
+#[async_trait]
+impl SerdeCodec for SigningSerdeCodec {
+    async fn call(&self, op: &str, request: &str) -> Result<String> {
+        let signed_request = self.signer.sign(op, request)?;
+        self.codec.call(op, signed_request.as_str()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::message::{PingRequest, PingResponse};
+    use crate::api::transport::{Codec, EventHandler, WalletClient, WalletServer};
+    use crate::api::WalletApi;
+    use crate::events::Events;
+    use crate::wallet::Wallet;
+
+    #[test]
+    fn test_canonical_json() {
+        let a: Value = serde_json::from_str(r#"{ "b": [1, {"y": null, "x": "é"}], "a": true }"#).unwrap();
+        let b: Value = serde_json::from_str(r#"{"a":true,"b":[1,{"x":"é","y":null}]}"#).unwrap();
+        assert_eq!(to_canonical_json(&a), r#"{"a":true,"b":[1,{"x":"é","y":null}]}"#);
+        assert_eq!(to_canonical_json(&a), to_canonical_json(&b));
+    }
+
+    #[test]
+    fn test_request_signing() {
+        let signer = RequestSigner::new(Secret::from("shared-secret"));
+        let verifier = RequestVerifier::new(Secret::from("shared-secret")).with_window(1_000);
+        let now = 1_000_000;
+
+        let signed = signer.sign_with_args("ping", r#"{"message":"hello"}"#, "01", now).unwrap();
+        assert_eq!(verifier.verify_at("ping", &signed, now + 500).unwrap(), r#"{"message":"hello"}"#);
+
+        // replayed request
+        assert!(matches!(verifier.verify_at("ping", &signed, now + 600), Err(Error::RequestReplay)));
+
+        // request outside of the window
+        let signed = signer.sign_with_args("ping", r#"{"message":"hello"}"#, "02", now).unwrap();
+        assert!(matches!(verifier.verify_at("ping", &signed, now + 1_001), Err(Error::RequestExpired)));
+
+        // signature bound to the method name and the request
+        let signed = signer.sign_with_args("ping", r#"{"message":"hello"}"#, "03", now).unwrap();
+        assert!(matches!(verifier.verify_at("walletOpen", &signed, now), Err(Error::InvalidRequestSignature)));
+        let tampered = signed.replace("hello", "hellO");
+        assert!(matches!(verifier.verify_at("ping", &tampered, now), Err(Error::InvalidRequestSignature)));
+
+        // secret mismatch
+        let signed = RequestSigner::new(Secret::from("other")).sign_with_args("ping", "{}", "04", now).unwrap();
+        assert!(matches!(verifier.verify_at("ping", &signed, now), Err(Error::InvalidRequestSignature)));
+
+        // unsigned request
+        assert!(matches!(verifier.verify_at("ping", r#"{"message":"hello"}"#, now), Err(Error::RequestAuthentication(_))));
+    }
+
+    struct NullEventHandler;
+
+    #[async_trait]
+    impl EventHandler for NullEventHandler {
+        async fn handle_event(&self, _event: &Events) {}
+    }
+
+    struct Loopback {
+        server: WalletServer,
+    }
+
+    #[async_trait]
+    impl SerdeCodec for Loopback {
+        async fn call(&self, op: &str, request: &str) -> Result<String> {
+            self.server.call_with_serde(op, request).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_authentication_middleware() -> Result<()> {
+        let wallet = Arc::new(Wallet::try_new(Wallet::resident_store()?, None, None)?);
+        let server = WalletServer::new(wallet, Arc::new(NullEventHandler))
+            .with_request_verifier(RequestVerifier::new(Secret::from("shared-secret")));
+        let transport: Arc<dyn SerdeCodec> = Arc::new(Loopback { server });
+
+        let client = Arc::new(WalletClient::new(Codec::Serde(Arc::new(SigningSerdeCodec::new(
+            transport.clone(),
+            Secret::from("shared-secret"),
+        )))));
+        let PingResponse { message } = client.ping_call(PingRequest { message: Some("hello".to_string()) }).await?;
+        assert_eq!(message.as_deref(), Some("hello"));
+
+        let unsigned = Arc::new(WalletClient::new(Codec::Serde(transport)));
+        assert!(matches!(
+            unsigned.ping_call(PingRequest { message: Some("hello".to_string()) }).await,
+            Err(Error::RequestAuthentication(_))
+        ));
+
+        Ok(())
+    }
+}
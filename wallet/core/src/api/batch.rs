@@ -0,0 +1,176 @@
+//!
+//! Batch execution of Wallet API requests.
+//!
+//! The [`BatchRequest`] carries an array of typed [`WalletRequest`] messages
+//! executed by a single API call, reducing the overhead of the transport
+//! (wasm boundary or IPC) for clients issuing many requests at once (such
+//! as UIs hydrating multiple accounts). The [`BatchResponse`] contains a
+//! [`BatchResult`] for each request, preserving the order of the requests.
+//!
+
+use super::message::*;
+use super::traits::WalletApi;
+use crate::error::ErrorCode;
+use crate::imports::*;
+use kaspa_wallet_macros::build_wallet_batch_interface;
+
+build_wallet_batch_interface! {[
+    GetStatus,
+    Connect,
+    Disconnect,
+    ChangeNetworkId,
+    SetLogLevel,
+    RetainContext,
+    WalletEnumerate,
+    WalletCreate,
+    WalletOpen,
+    WalletClose,
+    WalletReload,
+    WalletRename,
+    WalletChangeSecret,
+    WalletExport,
+    WalletImport,
+    WalletCheck,
+    PrvKeyDataEnumerate,
+    PrvKeyDataCreate,
+    PrvKeyDataRemove,
+    PrvKeyDataGet,
+    PrvKeyDataChangeSecret,
+    AccountsRename,
+    AccountsArchive,
+    AccountsUnarchive,
+    AccountsSelect,
+    AccountsEnumerate,
+    AccountsDiscovery,
+    AccountsCreate,
+    AccountsEnsureDefault,
+    AccountsImport,
+    AccountsActivate,
+    AccountsDeactivate,
+    AccountsGet,
+    AccountsExportXpub,
+    AccountsSignMessage,
+    AccountsVerifyMessage,
+    AccountsCreateNewAddress,
+    AccountsSend,
+    AccountsSendBatch,
+    AccountsTransfer,
+    AccountsEstimate,
+    TransactionsDataGet,
+    TransactionsReplaceNote,
+    TransactionsReplaceMetadata,
+    TransactionsExport,
+    AddressBookEnumerate,
+    TasksEnumerate,
+    TasksAbort,
+    AccountsGetUtxos,
+    AccountsLabelUtxos,
+    AccountsBalanceHistory,
+    MarketConfigure,
+    MarketDataGet,
+    WebhooksEnumerate,
+    WebhooksCreate,
+    WebhooksRemove,
+    AccountsSignTransaction,
+    AccountsSetGapLimit,
+    AccountsGetPendingIncoming,
+    AccountsCreatePaymentRequest,
+    AccountsGetPaymentRequests,
+    AccountsRemovePaymentRequest,
+    AccountsAddresses,
+]}
+
+impl WalletRequest {
+    /// Returns `true` if the request does not modify the wallet state and
+    /// can be executed concurrently with other read-only requests.
+    pub fn is_read_only(&self) -> bool {
+        matches!(
+            self,
+            WalletRequest::GetStatus(_)
+                | WalletRequest::WalletEnumerate(_)
+                | WalletRequest::PrvKeyDataEnumerate(_)
+                | WalletRequest::AccountsEnumerate(_)
+                | WalletRequest::AccountsGet(_)
+                | WalletRequest::AccountsVerifyMessage(_)
+                | WalletRequest::AccountsEstimate(_)
+                | WalletRequest::TransactionsDataGet(_)
+                | WalletRequest::TransactionsExport(_)
+                | WalletRequest::AddressBookEnumerate(_)
+                | WalletRequest::TasksEnumerate(_)
+                | WalletRequest::AccountsGetUtxos(_)
+                | WalletRequest::AccountsBalanceHistory(_)
+                | WalletRequest::MarketDataGet(_)
+                | WalletRequest::WebhooksEnumerate(_)
+                | WalletRequest::AccountsGetPendingIncoming(_)
+                | WalletRequest::AccountsGetPaymentRequests(_)
+                | WalletRequest::AccountsAddresses(_)
+        )
+    }
+}
+
+/// Error produced by a [`WalletRequest`] executed as a part of the [`BatchRequest`].
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchError {
+    /// Numeric [`ErrorCode`](crate::error::ErrorCode) classifying the error
+    pub code: u32,
+    pub message: String,
+}
+
+impl From<Error> for BatchError {
+    fn from(error: Error) -> Self {
+        Self { code: error.code() as u32, message: error.to_string() }
+    }
+}
+
+/// Outcome of a [`WalletRequest`] executed as a part of the [`BatchRequest`],
+/// containing either the response or the error produced by the request.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchResult {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response: Option<WalletResponse>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<BatchError>,
+}
+
+impl From<Result<WalletResponse>> for BatchResult {
+    fn from(result: Result<WalletResponse>) -> Self {
+        match result {
+            Ok(response) => Self { response: Some(response), error: None },
+            Err(error) => Self { response: None, error: Some(error.into()) },
+        }
+    }
+}
+
+impl BatchResult {
+    pub fn into_result(self) -> std::result::Result<WalletResponse, BatchError> {
+        match (self.response, self.error) {
+            (Some(response), _) => Ok(response),
+            (None, Some(error)) => Err(error),
+            (None, None) => Err(BatchError { code: ErrorCode::Unknown as u32, message: "missing batch response".to_string() }),
+        }
+    }
+}
+
+/// Executes the `requests` preserving their order. Mutating requests are
+/// executed sequentially, while consecutive read-only requests (see
+/// [`WalletRequest::is_read_only()`]) are executed concurrently.
+/// Errors do not interrupt the execution of the remaining requests.
+pub async fn execute_batch(wallet_api: Arc<dyn WalletApi>, requests: Vec<WalletRequest>) -> Vec<BatchResult> {
+    let mut results = Vec::with_capacity(requests.len());
+    let mut requests = requests.into_iter().peekable();
+    while let Some(request) = requests.next() {
+        if request.is_read_only() {
+            let mut read_only = vec![request];
+            while let Some(request) = requests.next_if(WalletRequest::is_read_only) {
+                read_only.push(request);
+            }
+            let responses = join_all(read_only.into_iter().map(|request| request.execute(wallet_api.clone()))).await;
+            results.extend(responses.into_iter().map(BatchResult::from));
+        } else {
+            results.push(request.execute(wallet_api.clone()).await.into());
+        }
+    }
+    results
+}
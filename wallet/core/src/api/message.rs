@@ -5,9 +5,18 @@
 //! `XxxRequest` and `XxxResponse` message.
 //!
 
+use crate::api::batch::{BatchResult, WalletRequest};
 use crate::imports::*;
-use crate::tx::{Fees, GeneratorSummary, PaymentDestination};
+use crate::logging::{LogLevel, LogTarget, LoggingConfig};
+use crate::ownership::XPubOwnershipProof;
+use crate::tx::{
+    ChangePolicy, Fees, GeneratorSummary, PaymentDestination, PaymentOutput, SignableTransactionEncoding, SigningStatus,
+    TransactionValidation,
+};
+use crate::utxo::{AddressUsage, PaymentRequestId, UtxoEntryDescriptor};
+use crate::webhooks::{WebhookDescriptor, WebhookEventKind};
 use kaspa_addresses::Address;
+use kaspa_consensus_core::tx::TransactionOutpoint;
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
@@ -21,13 +30,20 @@ pub struct PingResponse {
     pub message: Option<String>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct BatchRequest {}
+pub struct BatchRequest {
+    /// Requests to execute (if empty, the wallet storage enters the batch mode)
+    #[serde(default)]
+    pub requests: Vec<WalletRequest>,
+}
 
-#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct BatchResponse {}
+pub struct BatchResponse {
+    /// Results of the executed requests (in the order of the requests)
+    pub responses: Vec<BatchResult>,
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
@@ -68,6 +84,20 @@ pub struct ChangeNetworkIdRequest {
 #[serde(rename_all = "camelCase")]
 pub struct ChangeNetworkIdResponse {}
 
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetLogLevelRequest {
+    /// Target to change the level of (the default level if `None`)
+    pub target: Option<LogTarget>,
+    pub level: LogLevel,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetLogLevelResponse {
+    pub config: LoggingConfig,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RetainContextRequest {
@@ -99,6 +129,9 @@ pub struct GetStatusRequest {
 pub struct GetStatusResponse {
     pub is_connected: bool,
     pub is_synced: bool,
+    /// Progress of the wallet synchronization (account scan,
+    /// UTXO reconciliation and transaction history backfill)
+    pub wallet_sync: WalletSyncProgress,
     pub is_open: bool,
     pub url: Option<String>,
     pub is_wrpc_client: bool,
@@ -172,17 +205,18 @@ pub struct WalletReloadResponse {}
 #[serde(rename_all = "camelCase")]
 pub struct WalletRenameRequest {
     pub title: Option<String>,
+    /// Replace the wallet notes (empty notes remove the notes).
+    #[serde(default)]
+    pub notes: Option<String>,
     pub filename: Option<String>,
     pub wallet_secret: Secret,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct WalletRenameResponse {}
-
-#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct WalletRenameFileResponse {}
+pub struct WalletRenameResponse {
+    pub wallet_descriptor: WalletDescriptor,
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
@@ -221,6 +255,19 @@ pub struct WalletImportResponse {
     pub wallet_descriptor: WalletDescriptor,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletCheckRequest {
+    pub wallet_secret: Secret,
+    pub repair: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletCheckResponse {
+    pub report: WalletCheckReport,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrvKeyDataEnumerateRequest {}
@@ -269,9 +316,31 @@ pub struct PrvKeyDataGetResponse {
     pub prv_key_data: Option<PrvKeyData>,
 }
 
+/// Re-encrypt private key data with a new payment secret.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrvKeyDataChangeSecretRequest {
+    pub wallet_secret: Secret,
+    /// Private key to re-encrypt (all private keys if not specified)
+    pub prv_key_data_id: Option<PrvKeyDataId>,
+    pub old_payment_secret: Option<Secret>,
+    /// New payment secret (the payment secret is removed if not specified)
+    pub new_payment_secret: Option<Secret>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct AccountsEnumerateRequest {}
+pub struct PrvKeyDataChangeSecretResponse {
+    pub report: PrvKeyDataSecretChangeReport,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsEnumerateRequest {
+    /// Include archived accounts
+    #[serde(default)]
+    pub include_archived: bool,
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
@@ -291,6 +360,134 @@ pub struct AccountsRenameRequest {
 #[serde(rename_all = "camelCase")]
 pub struct AccountsRenameResponse {}
 
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsSetGapLimitRequest {
+    pub account_id: AccountId,
+    /// Address gap limit (`None` restores the default gap limit)
+    pub gap_limit: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsSetGapLimitResponse {}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsGetPendingIncomingRequest {
+    pub account_id: AccountId,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsGetPendingIncomingResponse {
+    /// Unconfirmed incoming amounts per account address
+    pub incoming: Vec<PendingIncoming>,
+    /// Total unconfirmed incoming amount
+    pub amount: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsCreatePaymentRequestRequest {
+    pub account_id: AccountId,
+    /// Requested amount in SOMPI
+    pub amount: u64,
+    /// Time (in seconds) after which the request expires
+    pub expiry_seconds: Option<u64>,
+    pub label: Option<String>,
+    pub message: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsCreatePaymentRequestResponse {
+    pub request: PaymentRequest,
+    /// Payment URI conveying the request to the payer
+    pub uri: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsGetPaymentRequestsRequest {
+    pub account_id: AccountId,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsGetPaymentRequestsResponse {
+    pub requests: Vec<PaymentRequest>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsRemovePaymentRequestRequest {
+    pub account_id: AccountId,
+    pub request_id: PaymentRequestId,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsRemovePaymentRequestResponse {
+    /// Removed payment request (`None` if the request was not found)
+    pub request: Option<PaymentRequest>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsAddressesRequest {
+    pub account_id: AccountId,
+}
+
+/// Address of an account along with the activity observed on the address.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAddress {
+    pub address: Address,
+    /// Derivation index (`None` for accounts without address derivation)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<u32>,
+    /// Address belongs to the change address chain
+    pub change: bool,
+    /// Activity observed on the address (`None` if the address is unused)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<AddressUsage>,
+}
+
+impl AccountAddress {
+    pub fn is_used(&self) -> bool {
+        self.usage.is_some()
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsAddressesResponse {
+    pub addresses: Vec<AccountAddress>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsArchiveRequest {
+    pub account_ids: Vec<AccountId>,
+    pub wallet_secret: Secret,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsArchiveResponse {}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsUnarchiveRequest {
+    pub account_ids: Vec<AccountId>,
+    pub wallet_secret: Secret,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsUnarchiveResponse {}
+
 /// @category Wallet API
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, CastFromJs)]
 #[serde(rename_all = "camelCase")]
@@ -309,20 +506,50 @@ impl FromStr for AccountsDiscoveryKind {
     }
 }
 
+/// Key material scanned during account discovery.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+#[serde(tag = "type", content = "data")]
+pub enum AccountsDiscoverySource {
+    /// BIP-39 mnemonic (not stored in the wallet)
+    Mnemonic { bip39_mnemonic: Secret, bip39_passphrase: Option<Secret> },
+    /// Private key data stored in the currently open wallet
+    PrvKeyData { wallet_secret: Secret, prv_key_data_id: PrvKeyDataId, payment_secret: Option<Secret> },
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountsDiscoveryRequest {
     pub discovery_kind: AccountsDiscoveryKind,
     pub address_scan_extent: u32,
     pub account_scan_extent: u32,
-    pub bip39_passphrase: Option<Secret>,
-    pub bip39_mnemonic: Secret,
+    pub source: AccountsDiscoverySource,
+    /// Create wallet accounts for the discovered account indexes
+    /// (requires [`AccountsDiscoverySource::PrvKeyData`]).
+    pub create_accounts: bool,
+    /// Address gap limit assigned to the created accounts; the number of
+    /// addresses scanned per account is extended to at least the gap limit.
+    #[serde(default)]
+    pub gap_limit: Option<u32>,
+}
+
+/// Account found during account discovery.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscoveredAccount {
+    pub account_id: AccountId,
+    pub account_index: u64,
+    /// Balance (in SOMPI) held by the scanned address range
+    pub balance: u64,
+    /// `true` if the account has been created by the discovery request
+    pub created: bool,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountsDiscoveryResponse {
     pub last_account_index_found: u32,
+    pub accounts: Vec<DiscoveredAccount>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
@@ -354,14 +581,38 @@ pub struct AccountsEnsureDefaultResponse {
     pub account_descriptor: AccountDescriptor,
 }
 
-// TODO
+/// Legacy (KDX, kaspanet web wallet) account data to import.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+#[serde(tag = "type", content = "data")]
+pub enum AccountsImportSource {
+    /// KDX keydata file or kaspanet web wallet data found in the local storage
+    LegacyStorage { import_secret: Secret },
+    /// Contents of a KDX keydata file or kaspanet web wallet data
+    LegacyKeydata { keydata: String, import_secret: Secret },
+    /// 12-word mnemonic of the legacy account
+    LegacyMnemonic { mnemonic: Secret },
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct AccountsImportRequest {}
+pub struct AccountsImportRequest {
+    pub wallet_secret: Secret,
+    pub payment_secret: Option<Secret>,
+    pub source: AccountsImportSource,
+    /// Transfer the funds of the imported legacy account into the
+    /// first BIP32 account of the same private key data.
+    pub sweep: bool,
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct AccountsImportResponse {}
+pub struct AccountsImportResponse {
+    pub account_descriptor: AccountDescriptor,
+    /// BIP32 account receiving the swept funds (if requested)
+    pub sweep_account_descriptor: Option<AccountDescriptor>,
+    pub transaction_ids: Vec<TransactionId>,
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
@@ -405,6 +656,70 @@ pub struct AccountsGetResponse {
     pub account_descriptor: AccountDescriptor,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsExportXpubRequest {
+    pub account_id: AccountId,
+    pub wallet_secret: Secret,
+    pub payment_secret: Option<Secret>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsExportXpubResponse {
+    pub proof: XPubOwnershipProof,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsSignMessageRequest {
+    pub account_id: AccountId,
+    pub wallet_secret: Secret,
+    pub payment_secret: Option<Secret>,
+    pub address: Address,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsSignMessageResponse {
+    pub signature: String,
+}
+
+/// Transaction serialized as SDK JSON or hex-encoded Borsh
+/// (see [`decode_signable_transaction`](crate::tx::decode_signable_transaction)).
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsSignTransactionRequest {
+    pub account_id: AccountId,
+    pub wallet_secret: Secret,
+    pub payment_secret: Option<Secret>,
+    pub transaction: String,
+}
+
+/// Signed transaction serialized using the encoding of the request.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsSignTransactionResponse {
+    pub transaction: String,
+    pub encoding: SignableTransactionEncoding,
+    pub status: SigningStatus,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsVerifyMessageRequest {
+    pub address: Address,
+    pub message: String,
+    pub signature: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsVerifyMessageResponse {
+    pub valid: bool,
+}
+
 /// Specifies the type of an account address to create.
 /// The address can bea receive address or a change address.
 ///
@@ -440,6 +755,11 @@ pub struct AccountsCreateNewAddressRequest {
 #[serde(rename_all = "camelCase")]
 pub struct AccountsCreateNewAddressResponse {
     pub address: Address,
+    /// `true` if the number of unused receive addresses exceeds the account
+    /// gap limit, i.e. funds sent to the address will not be discovered when
+    /// the wallet is restored from the mnemonic using the same gap limit.
+    #[serde(default)]
+    pub beyond_gap_limit: bool,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
@@ -449,8 +769,30 @@ pub struct AccountsSendRequest {
     pub wallet_secret: Secret,
     pub payment_secret: Option<Secret>,
     pub destination: PaymentDestination,
-    pub priority_fee_sompi: Fees,
+    /// Priority fee; if not supplied, the wallet default
+    /// priority fee for the current network is used.
+    pub priority_fee_sompi: Option<Fees>,
     pub payload: Option<Vec<u8>>,
+    /// Outpoints of UTXOs that must be spent (coin control). If not
+    /// supplied, UTXOs are selected automatically.
+    #[serde(default)]
+    pub utxos: Option<Vec<TransactionOutpoint>>,
+    /// Spend only the supplied `utxos`; otherwise additional UTXOs are
+    /// selected automatically if the supplied UTXOs are insufficient.
+    #[serde(default)]
+    pub exclusive_utxos: bool,
+    /// Validate generated transactions against the node mempool
+    /// acceptance rules without broadcasting them.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Regenerate the final transaction with a higher fee if it is
+    /// not accepted within the policy deadline (see [`FeeBumpPolicy`]).
+    #[serde(default)]
+    pub fee_bump: Option<FeeBumpPolicy>,
+    /// Handling of the final transaction change that is too small to be
+    /// issued as a change output (folded into fees if not supplied).
+    #[serde(default)]
+    pub change_policy: Option<ChangePolicy>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
@@ -458,6 +800,34 @@ pub struct AccountsSendRequest {
 pub struct AccountsSendResponse {
     pub generator_summary: GeneratorSummary,
     pub transaction_ids: Vec<TransactionId>,
+    /// DAA score observed by the wallet upon submission of the final
+    /// transaction (an estimate of the transaction acceptance DAA score).
+    pub daa_score: Option<u64>,
+    /// Transaction validation results (available only in dry-run mode)
+    pub validations: Option<Vec<TransactionValidation>>,
+}
+
+/// Payment batch (multi-recipient payment) request. Payments are validated
+/// and aggregated into a [`PaymentBatch`](crate::tx::PaymentBatch) that is
+/// submitted as a sequence of chained transactions.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsSendBatchRequest {
+    pub account_id: AccountId,
+    pub wallet_secret: Secret,
+    pub payment_secret: Option<Secret>,
+    pub outputs: Vec<PaymentOutput>,
+    /// Priority fee applied to each transaction carrying batch payments;
+    /// if not supplied, the wallet default priority fee is used.
+    pub priority_fee_sompi: Option<Fees>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsSendBatchResponse {
+    /// Generator summaries of each batch partition.
+    pub generator_summaries: Vec<GeneratorSummary>,
+    pub transaction_ids: Vec<TransactionId>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
@@ -468,8 +838,9 @@ pub struct AccountsTransferRequest {
     pub wallet_secret: Secret,
     pub payment_secret: Option<Secret>,
     pub transfer_amount_sompi: u64,
+    /// Priority fee; if not supplied, the wallet default
+    /// priority fee for the current network is used.
     pub priority_fee_sompi: Option<Fees>,
-    // pub priority_fee_sompi: Fees,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
@@ -486,8 +857,14 @@ pub struct AccountsTransferResponse {
 pub struct AccountsEstimateRequest {
     pub account_id: AccountId,
     pub destination: PaymentDestination,
-    pub priority_fee_sompi: Fees,
+    /// Priority fee; if not supplied, the wallet default
+    /// priority fee for the current network is used.
+    pub priority_fee_sompi: Option<Fees>,
     pub payload: Option<Vec<u8>>,
+    /// Handling of the final transaction change that is too small to be
+    /// issued as a change output (folded into fees if not supplied).
+    #[serde(default)]
+    pub change_policy: Option<ChangePolicy>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
@@ -496,6 +873,27 @@ pub struct AccountsEstimateResponse {
     pub generator_summary: GeneratorSummary,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsBalanceHistoryRequest {
+    pub account_id: AccountId,
+    pub network_id: NetworkId,
+    /// Omit the history preceding the supplied unixtime (in milliseconds).
+    #[serde(default)]
+    pub since_unixtime_msec: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsBalanceHistoryResponse {
+    pub account_id: AccountId,
+    /// Account balance in daily buckets, oldest first.
+    pub history: Vec<BalanceHistoryPoint>,
+    /// Number of transaction records not included in the history
+    /// because their timestamp could not be resolved.
+    pub unresolved: u64,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionsDataGetRequest {
@@ -547,6 +945,32 @@ pub struct TransactionsReplaceMetadataRequest {
 #[serde(rename_all = "camelCase")]
 pub struct TransactionsReplaceMetadataResponse {}
 
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionsExportRequest {
+    pub account_id: AccountId,
+    pub network_id: NetworkId,
+    #[serde(default)]
+    pub format: TransactionExportFormat,
+    /// Exported columns (all columns if empty).
+    #[serde(default)]
+    pub columns: Vec<TransactionExportColumn>,
+    /// Export records created at or after the supplied unixtime (in milliseconds).
+    #[serde(default)]
+    pub since_unixtime_msec: Option<u64>,
+    /// Export records created before the supplied unixtime (in milliseconds).
+    #[serde(default)]
+    pub until_unixtime_msec: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionsExportResponse {
+    pub format: TransactionExportFormat,
+    /// Exported CSV or JSON document.
+    pub data: String,
+}
+
 // #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 // #[serde(rename_all = "camelCase")]
 // pub struct TransactionGetRequest {}
@@ -563,6 +987,174 @@ pub struct AddressBookEnumerateRequest {}
 #[serde(rename_all = "camelCase")]
 pub struct AddressBookEnumerateResponse {}
 
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TasksEnumerateRequest {}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TasksEnumerateResponse {
+    pub tasks: Vec<TaskDescriptor>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TasksAbortRequest {
+    /// Task to abort; `None` aborts all running tasks.
+    pub task_id: Option<TaskId>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TasksAbortResponse {}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsGetUtxosRequest {
+    pub account_id: AccountId,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsGetUtxosResponse {
+    pub utxos: Vec<UtxoEntryDescriptor>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsLabelUtxosRequest {
+    pub account_id: AccountId,
+    pub outpoints: Vec<TransactionOutpoint>,
+    /// Label to assign; `None` removes the label.
+    pub label: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsLabelUtxosResponse {}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketConfigureRequest {
+    /// Price feed to poll; `None` disables the price feed.
+    pub feed: Option<PriceFeedKind>,
+    /// Fiat currency codes (i.e. `usd`, `eur`).
+    pub currencies: Vec<String>,
+    /// Polling interval in seconds (default: 300, minimum: 60).
+    #[serde(default)]
+    pub interval_seconds: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketConfigureResponse {}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketDataGetRequest {}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketDataGetResponse {
+    /// Last market data obtained from the price feed.
+    pub data: Option<MarketData>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhooksEnumerateRequest {}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhooksEnumerateResponse {
+    pub webhooks: Vec<WebhookDescriptor>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhooksCreateRequest {
+    /// Wallet secret (the signing secret is stored in the wallet data).
+    pub wallet_secret: Secret,
+    /// Endpoint receiving the payloads (`http://` or `https://`).
+    pub url: String,
+    /// HMAC-SHA256 payload signing secret; payloads are not signed if `None`.
+    #[serde(default)]
+    pub secret: Option<String>,
+    /// Notified event kinds; all events are notified if empty.
+    #[serde(default)]
+    pub events: Vec<WebhookEventKind>,
+    /// Notified accounts; all accounts are notified if `None`.
+    #[serde(default)]
+    pub account_ids: Option<Vec<AccountId>>,
+    /// Confirmation milestones (DAA score units) notified for received transactions.
+    #[serde(default)]
+    pub confirmations: Vec<u64>,
+    /// Number of delivery retries (default: 5, maximum: 16).
+    #[serde(default)]
+    pub max_retries: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhooksCreateResponse {
+    pub webhook: WebhookDescriptor,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhooksRemoveRequest {
+    pub wallet_secret: Secret,
+    pub id: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhooksRemoveResponse {}
+
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WalletNotification {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    /// Collects names of operations declared as `pub struct {Op}{suffix}` in `source`.
+    fn declared_ops(source: &str, suffix: &str) -> BTreeSet<String> {
+        source
+            .lines()
+            .filter_map(|line| line.trim_start().strip_prefix("pub struct "))
+            .filter_map(|decl| decl.split(|c: char| !c.is_alphanumeric()).next())
+            .filter_map(|name| name.strip_suffix(suffix).map(String::from))
+            .collect()
+    }
+
+    /// Collects operation names listed in the `macro_name!([..])` invocation within `source`.
+    fn listed_ops(source: &str, macro_name: &str) -> BTreeSet<String> {
+        let start = source.find(&format!("{macro_name}! {{[")).or_else(|| source.find(&format!("{macro_name}!(["))).unwrap();
+        let list = &source[start..];
+        let list = &list[list.find('[').unwrap() + 1..list.find(']').unwrap()];
+        list.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with("//"))
+            .map(|line| line.trim_end_matches(',').to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_message_coverage() {
+        let requests = declared_ops(include_str!("message.rs"), "Request");
+        let responses = declared_ops(include_str!("message.rs"), "Response");
+        assert_eq!(requests, responses, "every request must have a matching response");
+
+        let transport = listed_ops(include_str!("transport.rs"), "build_wallet_client_transport_interface");
+        assert_eq!(requests, transport, "every request must be exposed via the transport interface");
+
+        let server = listed_ops(include_str!("transport.rs"), "build_wallet_server_transport_interface");
+        assert_eq!(requests, server, "every request must be handled by the server transport interface");
+
+        // `assert_wasm_conversions!` enforces the presence of the conversions at compile time
+        let wasm = listed_ops(include_str!("../wasm/api/mod.rs"), "assert_wasm_conversions");
+        assert_eq!(requests, wasm, "every request must have a TypeScript interface conversion");
+    }
+}
@@ -2,6 +2,11 @@
 //! Wallet API module that provides a unified interface for all wallet operations.
 //!
 
+pub mod auth;
+
+pub mod batch;
+pub use batch::*;
+
 pub mod message;
 pub use message::*;
 
@@ -7,10 +7,14 @@
 //! is implemented by the [`Wallet`] struct.
 //!
 
+use crate::api::batch::{BatchResult, WalletRequest};
 use crate::api::message::*;
 use crate::imports::*;
+use crate::logging::{LogLevel, LogTarget, LoggingConfig};
 use crate::storage::{PrvKeyData, PrvKeyDataId, PrvKeyDataInfo, WalletDescriptor};
 use crate::tx::GeneratorSummary;
+use crate::utxo::PaymentRequestId;
+use crate::webhooks::WebhookDescriptor;
 use workflow_core::channel::Receiver;
 
 ///
@@ -67,6 +71,16 @@ pub trait WalletApi: Send + Sync + AnySync {
     /// Change the current network id of the wallet.
     async fn change_network_id_call(self: Arc<Self>, request: ChangeNetworkIdRequest) -> Result<ChangeNetworkIdResponse>;
 
+    /// Wrapper around [`set_log_level_call()`](Self::set_log_level_call).
+    async fn set_log_level(self: Arc<Self>, target: Option<LogTarget>, level: LogLevel) -> Result<LoggingConfig> {
+        Ok(self.set_log_level_call(SetLogLevelRequest { target, level }).await?.config)
+    }
+
+    /// Change the log level of a wallet subsystem ([`LogTarget`]) or the default
+    /// log level if the target is not specified. Returns the resulting logging
+    /// configuration. Logging is configured process-wide.
+    async fn set_log_level_call(self: Arc<Self>, request: SetLogLevelRequest) -> Result<SetLogLevelResponse>;
+
     // ---
 
     /// Wrapper around `ping_call()`.
@@ -77,16 +91,26 @@ pub trait WalletApi: Send + Sync + AnySync {
     async fn ping_call(self: Arc<Self>, request: PingRequest) -> Result<PingResponse>;
 
     async fn batch(self: Arc<Self>) -> Result<()> {
-        self.batch_call(BatchRequest {}).await?;
+        self.batch_call(BatchRequest::default()).await?;
         Ok(())
     }
-    /// Initiates the wallet storage batch mode. Must be followed by the [`flush_call()`](Self::flush_call)
-    /// after the desired wallet operations have been executed.
+
+    /// Wrapper around [`batch_call()`](Self::batch_call) executing the `requests`.
+    async fn batch_requests(self: Arc<Self>, requests: Vec<WalletRequest>) -> Result<Vec<BatchResult>> {
+        Ok(self.batch_call(BatchRequest { requests }).await?.responses)
+    }
+
+    /// Executes multiple wallet API requests within a single call, returning a
+    /// [`BatchResult`] (response or error) for each request in the order of the
+    /// requests (see [`execute_batch()`](crate::api::batch::execute_batch)).
     ///
-    /// Batch mode allows user to perform multiple wallet operations without storing the
-    /// wallet data into the storage subsystem (disk, localstorage etc). This is helpful
-    /// in web browsers as each time the wallet is stored, it needs to be encrypted,
-    /// which can be costly in low-performance environments such as web browsers.
+    /// If no requests are supplied, initiates the wallet storage batch mode. The batch
+    /// mode must be followed by the [`flush_call()`](Self::flush_call) after the desired
+    /// wallet operations have been executed. Batch mode allows user to perform multiple
+    /// wallet operations without storing the wallet data into the storage subsystem
+    /// (disk, localstorage etc). This is helpful in web browsers as each time the wallet
+    /// is stored, it needs to be encrypted, which can be costly in low-performance
+    /// environments such as web browsers.
     ///
     async fn batch_call(self: Arc<Self>, request: BatchRequest) -> Result<BatchResponse>;
 
@@ -173,19 +197,27 @@ pub trait WalletApi: Send + Sync + AnySync {
     async fn wallet_reload_call(self: Arc<Self>, request: WalletReloadRequest) -> Result<WalletReloadResponse>;
 
     /// Wrapper around [`wallet_rename_call()`](Self::wallet_rename_call)
-    async fn wallet_rename(self: Arc<Self>, title: Option<&str>, filename: Option<&str>, wallet_secret: Secret) -> Result<()> {
-        self.wallet_rename_call(WalletRenameRequest {
+    async fn wallet_rename(
+        self: Arc<Self>,
+        title: Option<&str>,
+        notes: Option<&str>,
+        filename: Option<&str>,
+        wallet_secret: Secret,
+    ) -> Result<WalletDescriptor> {
+        let request = WalletRenameRequest {
             title: title.map(String::from),
+            notes: notes.map(String::from),
             filename: filename.map(String::from),
             wallet_secret,
-        })
-        .await?;
-        Ok(())
+        };
+        Ok(self.wallet_rename_call(request).await?.wallet_descriptor)
     }
-    /// Change the wallet title or rename the file in which the wallet is stored.
+
+    /// Change the wallet title or notes, or rename the file in which the wallet
+    /// is stored (together with the wallet transaction data on native platforms).
     /// This call will produce an error if the destination filename already exists.
-    /// See [`wallet_rename`](Self::wallet_rename) for a convenience wrapper around
-    /// this call.
+    /// Returns the updated [`WalletDescriptor`]. See [`wallet_rename`](Self::wallet_rename)
+    /// for a convenience wrapper around this call.
     async fn wallet_rename_call(self: Arc<Self>, request: WalletRenameRequest) -> Result<WalletRenameResponse>;
 
     /// Return a JSON string that contains raw wallet data. This is available only
@@ -210,6 +242,22 @@ pub trait WalletApi: Send + Sync + AnySync {
     /// this call.
     async fn wallet_change_secret_call(self: Arc<Self>, request: WalletChangeSecretRequest) -> Result<WalletChangeSecretResponse>;
 
+    /// Wrapper around [`wallet_check_call()`](Self::wallet_check_call)
+    async fn wallet_check(self: Arc<Self>, wallet_secret: Secret, repair: bool) -> Result<WalletCheckReport> {
+        Ok(self.wallet_check_call(WalletCheckRequest { wallet_secret, repair }).await?.report)
+    }
+
+    /// Check the integrity of the wallet data: storage headers of private key data,
+    /// accounts and account metadata, accounts referring to missing private key data,
+    /// metadata of missing accounts and transaction records of the wallet accounts
+    /// on the currently selected network. If `repair` is set, orphaned entries and
+    /// invalid transaction records are removed. The returned [`WalletCheckReport`]
+    /// lists the detected issues.
+    ///
+    /// See [`wallet_check`](Self::wallet_check) for a convenience wrapper around
+    /// this call.
+    async fn wallet_check_call(self: Arc<Self>, request: WalletCheckRequest) -> Result<WalletCheckResponse>;
+
     /// Wrapper around [`prv_key_data_enumerate_call()`](Self::prv_key_data_enumerate_call)
     async fn prv_key_data_enumerate(self: Arc<Self>) -> Result<Vec<Arc<PrvKeyDataInfo>>> {
         Ok(self.prv_key_data_enumerate_call(PrvKeyDataEnumerateRequest {}).await?.prv_key_data_list)
@@ -253,6 +301,29 @@ pub trait WalletApi: Send + Sync + AnySync {
     /// Obtain a private key data using [`PrvKeyDataId`].
     async fn prv_key_data_get_call(self: Arc<Self>, request: PrvKeyDataGetRequest) -> Result<PrvKeyDataGetResponse>;
 
+    /// Wrapper around [`prv_key_data_change_secret_call()`](Self::prv_key_data_change_secret_call)
+    async fn prv_key_data_change_secret(
+        self: Arc<Self>,
+        wallet_secret: Secret,
+        prv_key_data_id: Option<PrvKeyDataId>,
+        old_payment_secret: Option<Secret>,
+        new_payment_secret: Option<Secret>,
+    ) -> Result<PrvKeyDataSecretChangeReport> {
+        let request = PrvKeyDataChangeSecretRequest { wallet_secret, prv_key_data_id, old_payment_secret, new_payment_secret };
+        Ok(self.prv_key_data_change_secret_call(request).await?.report)
+    }
+    /// Re-encrypt the private key data (all private keys if `prv_key_data_id` is not
+    /// specified) with a new payment secret. No private key is modified if any of the
+    /// selected keys can not be decrypted with the old payment secret. The previous
+    /// wallet file is preserved as a backup (see [`PrvKeyDataSecretChangeReport::backup`]).
+    ///
+    /// The payment secret of bip39 mnemonics is the bip39 passphrase used to derive the
+    /// account keys; such private keys are rejected with [`Error::PaymentSecretIsPassphrase`].
+    async fn prv_key_data_change_secret_call(
+        self: Arc<Self>,
+        request: PrvKeyDataChangeSecretRequest,
+    ) -> Result<PrvKeyDataChangeSecretResponse>;
+
     /// Wrapper around [`accounts_rename_call()`](Self::accounts_rename_call)
     async fn accounts_rename(self: Arc<Self>, account_id: AccountId, name: Option<String>, wallet_secret: Secret) -> Result<()> {
         self.accounts_rename_call(AccountsRenameRequest { account_id, name, wallet_secret }).await?;
@@ -264,6 +335,105 @@ pub trait WalletApi: Send + Sync + AnySync {
     /// around this call.
     async fn accounts_rename_call(self: Arc<Self>, request: AccountsRenameRequest) -> Result<AccountsRenameResponse>;
 
+    /// Wrapper around [`accounts_set_gap_limit_call()`](Self::accounts_set_gap_limit_call)
+    async fn accounts_set_gap_limit(self: Arc<Self>, account_id: AccountId, gap_limit: Option<u32>) -> Result<()> {
+        self.accounts_set_gap_limit_call(AccountsSetGapLimitRequest { account_id, gap_limit }).await?;
+        Ok(())
+    }
+    /// Set the address gap limit of a derivation-capable account: the number of
+    /// consecutive unused addresses scanned past the last used address. The gap
+    /// limit is persisted in the account metadata and used by the account scan.
+    /// Emits [`Events::AccountUpdate`](crate::events::Events::AccountUpdate).
+    async fn accounts_set_gap_limit_call(self: Arc<Self>, request: AccountsSetGapLimitRequest) -> Result<AccountsSetGapLimitResponse>;
+
+    /// Wrapper around [`accounts_get_pending_incoming_call()`](Self::accounts_get_pending_incoming_call)
+    async fn accounts_get_pending_incoming(self: Arc<Self>, account_id: AccountId) -> Result<Vec<PendingIncoming>> {
+        Ok(self.accounts_get_pending_incoming_call(AccountsGetPendingIncomingRequest { account_id }).await?.incoming)
+    }
+    /// Query the node mempool for unconfirmed transactions sending to the account
+    /// addresses and return the incoming amounts per address. The total is retained
+    /// as the [`Balance::pending_incoming`] figure until the transactions are accepted.
+    /// Emits [`Events::Balance`](crate::events::Events::Balance).
+    async fn accounts_get_pending_incoming_call(
+        self: Arc<Self>,
+        request: AccountsGetPendingIncomingRequest,
+    ) -> Result<AccountsGetPendingIncomingResponse>;
+
+    /// Wrapper around [`accounts_create_payment_request_call()`](Self::accounts_create_payment_request_call)
+    async fn accounts_create_payment_request(
+        self: Arc<Self>,
+        account_id: AccountId,
+        amount: u64,
+        expiry_seconds: Option<u64>,
+    ) -> Result<PaymentRequest> {
+        let request = AccountsCreatePaymentRequestRequest { account_id, amount, expiry_seconds, label: None, message: None };
+        Ok(self.accounts_create_payment_request_call(request).await?.request)
+    }
+    /// Create a payment request for `amount` SOMPI tied to a new receive address
+    /// of the account. When a transaction sending at least the requested amount to
+    /// the address is received, the request is marked as fulfilled and
+    /// [`Events::PaymentReceived`](crate::events::Events::PaymentReceived) is emitted.
+    async fn accounts_create_payment_request_call(
+        self: Arc<Self>,
+        request: AccountsCreatePaymentRequestRequest,
+    ) -> Result<AccountsCreatePaymentRequestResponse>;
+
+    /// Wrapper around [`accounts_get_payment_requests_call()`](Self::accounts_get_payment_requests_call)
+    async fn accounts_get_payment_requests(self: Arc<Self>, account_id: AccountId) -> Result<Vec<PaymentRequest>> {
+        Ok(self.accounts_get_payment_requests_call(AccountsGetPaymentRequestsRequest { account_id }).await?.requests)
+    }
+    /// Return the payment requests of the account (pending, as well as fulfilled
+    /// and expired requests retained for [`PAYMENT_REQUEST_RETENTION`](crate::utxo::PAYMENT_REQUEST_RETENTION)).
+    async fn accounts_get_payment_requests_call(
+        self: Arc<Self>,
+        request: AccountsGetPaymentRequestsRequest,
+    ) -> Result<AccountsGetPaymentRequestsResponse>;
+
+    /// Wrapper around [`accounts_remove_payment_request_call()`](Self::accounts_remove_payment_request_call)
+    async fn accounts_remove_payment_request(
+        self: Arc<Self>,
+        account_id: AccountId,
+        request_id: PaymentRequestId,
+    ) -> Result<Option<PaymentRequest>> {
+        Ok(self.accounts_remove_payment_request_call(AccountsRemovePaymentRequestRequest { account_id, request_id }).await?.request)
+    }
+    /// Cancel a payment request of the account, removing it from the account metadata.
+    async fn accounts_remove_payment_request_call(
+        self: Arc<Self>,
+        request: AccountsRemovePaymentRequestRequest,
+    ) -> Result<AccountsRemovePaymentRequestResponse>;
+
+    /// Wrapper around [`accounts_addresses_call()`](Self::accounts_addresses_call)
+    async fn accounts_addresses(self: Arc<Self>, account_id: AccountId) -> Result<Vec<AccountAddress>> {
+        Ok(self.accounts_addresses_call(AccountsAddressesRequest { account_id }).await?.addresses)
+    }
+    /// List the account addresses (all derived receive and change addresses
+    /// for derivation-capable accounts) along with the activity observed on
+    /// each address, allowing applications to identify fresh (unused) addresses.
+    async fn accounts_addresses_call(self: Arc<Self>, request: AccountsAddressesRequest) -> Result<AccountsAddressesResponse>;
+
+    /// Wrapper around [`accounts_archive_call()`](Self::accounts_archive_call)
+    async fn accounts_archive(self: Arc<Self>, account_ids: Vec<AccountId>, wallet_secret: Secret) -> Result<()> {
+        self.accounts_archive_call(AccountsArchiveRequest { account_ids, wallet_secret }).await?;
+        Ok(())
+    }
+    /// Archive a set of accounts. Archived accounts are deactivated and
+    /// excluded from account enumeration and activation. Account data
+    /// is retained in the wallet storage and can be restored using
+    /// [`accounts_unarchive_call()`](Self::accounts_unarchive_call).
+    /// Emits [`Events::AccountArchive`](crate::events::Events::AccountArchive).
+    async fn accounts_archive_call(self: Arc<Self>, request: AccountsArchiveRequest) -> Result<AccountsArchiveResponse>;
+
+    /// Wrapper around [`accounts_unarchive_call()`](Self::accounts_unarchive_call)
+    async fn accounts_unarchive(self: Arc<Self>, account_ids: Vec<AccountId>, wallet_secret: Secret) -> Result<()> {
+        self.accounts_unarchive_call(AccountsUnarchiveRequest { account_ids, wallet_secret }).await?;
+        Ok(())
+    }
+    /// Restore a set of archived accounts. Restored accounts
+    /// are not activated and need to be activated explicitly.
+    /// Emits [`Events::AccountUnarchive`](crate::events::Events::AccountUnarchive).
+    async fn accounts_unarchive_call(self: Arc<Self>, request: AccountsUnarchiveRequest) -> Result<AccountsUnarchiveResponse>;
+
     async fn accounts_select(self: Arc<Self>, account_id: Option<AccountId>) -> Result<()> {
         self.accounts_select_call(AccountsSelectRequest { account_id }).await?;
         Ok(())
@@ -300,9 +470,11 @@ pub trait WalletApi: Send + Sync + AnySync {
 
     /// Wrapper around [`accounts_enumerate_call()`](Self::accounts_enumerate_call)
     async fn accounts_enumerate(self: Arc<Self>) -> Result<Vec<AccountDescriptor>> {
-        Ok(self.accounts_enumerate_call(AccountsEnumerateRequest {}).await?.account_descriptors)
+        Ok(self.accounts_enumerate_call(AccountsEnumerateRequest { include_archived: false }).await?.account_descriptors)
     }
     /// Returns a list of [`AccountDescriptor`] structs for all accounts stored in the wallet.
+    /// Archived accounts are included only if requested via
+    /// [`AccountsEnumerateRequest::include_archived`].
     async fn accounts_enumerate_call(self: Arc<Self>, request: AccountsEnumerateRequest) -> Result<AccountsEnumerateResponse>;
 
     /// Performs a bip44 account discovery by scanning the account address space.
@@ -357,6 +529,34 @@ pub trait WalletApi: Send + Sync + AnySync {
     /// Get an [`AccountDescriptor`] for a specific account id.
     async fn accounts_get_call(self: Arc<Self>, request: AccountsGetRequest) -> Result<AccountsGetResponse>;
 
+    /// Export the extended public key of an account along with an
+    /// [`XPubOwnershipProof`](crate::ownership::XPubOwnershipProof) signed by the
+    /// key of the account's first receive address. The proof allows third-party
+    /// services receiving the xpub to verify that it was not substituted in transit.
+    /// Currently supported only by BIP32 (Schnorr) accounts.
+    async fn accounts_export_xpub_call(self: Arc<Self>, request: AccountsExportXpubRequest) -> Result<AccountsExportXpubResponse>;
+
+    /// Sign a personal message with the key of an account address, proving the
+    /// ownership of the address. Returns a hex-encoded Schnorr signature.
+    /// Only `PubKey` (Schnorr) addresses are supported.
+    async fn accounts_sign_message_call(self: Arc<Self>, request: AccountsSignMessageRequest) -> Result<AccountsSignMessageResponse>;
+
+    /// Sign the inputs of a transaction supplied as SDK JSON or hex-encoded Borsh
+    /// that spend UTXOs of the account addresses, returning the (partially) signed
+    /// transaction serialized using the same encoding. Enables signing of
+    /// transactions created by an online watch-only wallet in an air-gapped wallet.
+    async fn accounts_sign_transaction_call(
+        self: Arc<Self>,
+        request: AccountsSignTransactionRequest,
+    ) -> Result<AccountsSignTransactionResponse>;
+
+    /// Verify a personal message signature produced by the key of an address
+    /// (see [`accounts_sign_message_call`](Self::accounts_sign_message_call)).
+    async fn accounts_verify_message_call(
+        self: Arc<Self>,
+        request: AccountsVerifyMessageRequest,
+    ) -> Result<AccountsVerifyMessageResponse>;
+
     /// Wrapper around [`accounts_create_new_address`](Self::accounts_create_new_address)
     async fn accounts_create_new_address(
         self: Arc<Self>,
@@ -383,6 +583,13 @@ pub trait WalletApi: Send + Sync + AnySync {
     /// well `transaction_ids` containing a list of submitted transaction ids.
     async fn accounts_send_call(self: Arc<Self>, request: AccountsSendRequest) -> Result<AccountsSendResponse>;
 
+    /// Send funds from an account to a batch of (up to thousands of) external
+    /// addresses. Payments are partitioned into as few chained transactions
+    /// as the transaction mass limits allow. A `payment-batch-progress` event
+    /// is emitted for each submitted transaction. Returns an [`AccountsSendBatchResponse`]
+    /// containing a [`GeneratorSummary`] of each batch partition.
+    async fn accounts_send_batch_call(self: Arc<Self>, request: AccountsSendBatchRequest) -> Result<AccountsSendBatchResponse>;
+
     /// Transfer funds to another account. Returns an [`AccountsTransferResponse`]
     /// struct that contains a [`GeneratorSummary`] as well `transaction_ids`
     /// containing a list of submitted transaction ids. Unlike funds sent to an
@@ -399,6 +606,28 @@ pub trait WalletApi: Send + Sync + AnySync {
     /// an error.
     async fn accounts_estimate_call(self: Arc<Self>, request: AccountsEstimateRequest) -> Result<AccountsEstimateResponse>;
 
+    /// Wrapper around [`accounts_balance_history_call()`](Self::accounts_balance_history_call)
+    async fn accounts_balance_history(
+        self: Arc<Self>,
+        account_id: AccountId,
+        network_id: NetworkId,
+        since_unixtime_msec: Option<u64>,
+    ) -> Result<Vec<BalanceHistoryPoint>> {
+        let request = AccountsBalanceHistoryRequest { account_id, network_id, since_unixtime_msec };
+        Ok(self.accounts_balance_history_call(request).await?.history)
+    }
+
+    /// Returns the balance of an account over time in daily buckets, derived
+    /// from the account transaction records. Record timestamps missing from
+    /// the storage are resolved via the node (records that can not be
+    /// resolved are reported in [`AccountsBalanceHistoryResponse::unresolved`]).
+    /// The balance changes of processed records are cached by the storage,
+    /// making subsequent calls incremental.
+    async fn accounts_balance_history_call(
+        self: Arc<Self>,
+        request: AccountsBalanceHistoryRequest,
+    ) -> Result<AccountsBalanceHistoryResponse>;
+
     /// Get a range of transaction records for a specific account id.
     async fn transactions_data_get_range(
         self: Arc<Self>,
@@ -412,16 +641,44 @@ pub trait WalletApi: Send + Sync + AnySync {
     async fn transactions_data_get_call(self: Arc<Self>, request: TransactionsDataGetRequest) -> Result<TransactionsDataGetResponse>;
     // async fn transaction_get_call(self: Arc<Self>, request: TransactionGetRequest) -> Result<TransactionGetResponse>;
 
+    /// Wrapper around [`transactions_replace_note_call()`](Self::transactions_replace_note_call)
+    async fn transactions_replace_note(
+        self: Arc<Self>,
+        account_id: AccountId,
+        network_id: NetworkId,
+        transaction_id: TransactionId,
+        note: Option<String>,
+    ) -> Result<()> {
+        self.transactions_replace_note_call(TransactionsReplaceNoteRequest { account_id, network_id, transaction_id, note }).await?;
+        Ok(())
+    }
+
     /// Replaces the note of a transaction with a new note. Note is meant
     /// to explicitly store a user-supplied string. The note is treated
     /// as a raw string without any assumptions about the note format.
+    /// The note is retained when the transaction record is subsequently
+    /// updated by the wallet (e.g. on transaction maturity).
     ///
     /// Supply [`Option::None`] in the `note` field to remove the note.
+    /// Returns [`Error::NoRecordsFound`] if the transaction record does not exist.
     async fn transactions_replace_note_call(
         self: Arc<Self>,
         request: TransactionsReplaceNoteRequest,
     ) -> Result<TransactionsReplaceNoteResponse>;
 
+    /// Wrapper around [`transactions_replace_metadata_call()`](Self::transactions_replace_metadata_call)
+    async fn transactions_replace_metadata(
+        self: Arc<Self>,
+        account_id: AccountId,
+        network_id: NetworkId,
+        transaction_id: TransactionId,
+        metadata: Option<String>,
+    ) -> Result<()> {
+        let request = TransactionsReplaceMetadataRequest { account_id, network_id, transaction_id, metadata };
+        self.transactions_replace_metadata_call(request).await?;
+        Ok(())
+    }
+
     /// Replaces the metadata of a transaction with a new metadata.
     /// Metadata is meant to store an application-specific data.
     /// If used, the application and encode custom JSON data into the
@@ -429,16 +686,89 @@ pub trait WalletApi: Send + Sync + AnySync {
     /// without any assumptions about the metadata format.
     ///
     /// Supply [`Option::None`] in the `metadata` field to
-    /// remove the metadata.
+    /// remove the metadata. Returns [`Error::NoRecordsFound`]
+    /// if the transaction record does not exist.
     async fn transactions_replace_metadata_call(
         self: Arc<Self>,
         request: TransactionsReplaceMetadataRequest,
     ) -> Result<TransactionsReplaceMetadataResponse>;
 
+    /// Wrapper around [`transactions_export_call()`](Self::transactions_export_call)
+    async fn transactions_export(
+        self: Arc<Self>,
+        account_id: AccountId,
+        network_id: NetworkId,
+        options: TransactionExportOptions,
+    ) -> Result<String> {
+        let TransactionExportOptions { format, columns, since_unixtime_msec, until_unixtime_msec } = options;
+        let request = TransactionsExportRequest { account_id, network_id, format, columns, since_unixtime_msec, until_unixtime_msec };
+        Ok(self.transactions_export_call(request).await?.data)
+    }
+
+    /// Exports the transaction records of an account as a CSV or JSON
+    /// document for accounting purposes. Records can be filtered by date
+    /// range; records without a timestamp are excluded when a date range
+    /// is supplied. See [`TransactionExportOptions`] for the exported data.
+    async fn transactions_export_call(self: Arc<Self>, request: TransactionsExportRequest) -> Result<TransactionsExportResponse>;
+
     async fn address_book_enumerate_call(
         self: Arc<Self>,
         request: AddressBookEnumerateRequest,
     ) -> Result<AddressBookEnumerateResponse>;
+
+    /// Returns a list of long-running wallet operations (account scans,
+    /// sends, transfers, account discovery) currently monitored by the
+    /// wallet [`Watchdog`](crate::wallet::Watchdog).
+    async fn tasks_enumerate_call(self: Arc<Self>, request: TasksEnumerateRequest) -> Result<TasksEnumerateResponse>;
+
+    /// Aborts a running task (or all running tasks if `task_id` is `None`).
+    /// The aborted operation returns [`Error::Aborted`](crate::error::Error::Aborted).
+    async fn tasks_abort_call(self: Arc<Self>, request: TasksAbortRequest) -> Result<TasksAbortResponse>;
+
+    /// Returns a list of UTXO entries held by the account (see [`UtxoEntryDescriptor`](crate::utxo::UtxoEntryDescriptor))
+    /// including their spendability status and user-assigned labels. UTXO outpoints can be
+    /// supplied to [`AccountsSendRequest`] to spend specific UTXOs (coin control).
+    async fn accounts_get_utxos_call(self: Arc<Self>, request: AccountsGetUtxosRequest) -> Result<AccountsGetUtxosResponse>;
+
+    /// Assigns a label to UTXOs of an account (or removes the label if `label` is `None`).
+    /// Labels are persisted in the account metadata.
+    async fn accounts_label_utxos_call(self: Arc<Self>, request: AccountsLabelUtxosRequest) -> Result<AccountsLabelUtxosResponse>;
+
+    /// Configures the fiat price feed polled by the wallet runtime (see
+    /// [`MarketMonitor`](crate::market::MarketMonitor)). Market data is posted
+    /// via the `market` event. The configuration is not persisted.
+    async fn market_configure_call(self: Arc<Self>, request: MarketConfigureRequest) -> Result<MarketConfigureResponse>;
+
+    /// Wrapper around [`market_data_get_call()`](Self::market_data_get_call)
+    async fn market_data_get(self: Arc<Self>) -> Result<Option<MarketData>> {
+        Ok(self.market_data_get_call(MarketDataGetRequest {}).await?.data)
+    }
+
+    /// Returns the last market data obtained from the configured price feed.
+    async fn market_data_get_call(self: Arc<Self>, request: MarketDataGetRequest) -> Result<MarketDataGetResponse>;
+
+    /// Wrapper around [`webhooks_enumerate_call()`](Self::webhooks_enumerate_call)
+    async fn webhooks_enumerate(self: Arc<Self>) -> Result<Vec<WebhookDescriptor>> {
+        Ok(self.webhooks_enumerate_call(WebhooksEnumerateRequest {}).await?.webhooks)
+    }
+
+    /// Returns the webhooks notified of wallet events (see [`WebhookDispatcher`](crate::webhooks::WebhookDispatcher)).
+    async fn webhooks_enumerate_call(self: Arc<Self>, request: WebhooksEnumerateRequest) -> Result<WebhooksEnumerateResponse>;
+
+    /// Registers a webhook receiving balance, incoming transaction and
+    /// confirmation notifications. The webhook is persisted in the wallet
+    /// settings, while the signing secret is stored in the (encrypted)
+    /// wallet data. Requires the `webhooks` crate feature.
+    async fn webhooks_create_call(self: Arc<Self>, request: WebhooksCreateRequest) -> Result<WebhooksCreateResponse>;
+
+    /// Wrapper around [`webhooks_remove_call()`](Self::webhooks_remove_call)
+    async fn webhooks_remove(self: Arc<Self>, wallet_secret: Secret, id: String) -> Result<()> {
+        self.webhooks_remove_call(WebhooksRemoveRequest { wallet_secret, id }).await?;
+        Ok(())
+    }
+
+    /// Removes a webhook, discarding its pending delivery retries.
+    async fn webhooks_remove_call(self: Arc<Self>, request: WebhooksRemoveRequest) -> Result<WebhooksRemoveResponse>;
 }
 
 /// alias for `Arc<dyn WalletApi + Send + Sync + 'static>`
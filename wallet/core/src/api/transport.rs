@@ -10,11 +10,13 @@
 //! This id is then use to identify the method.
 //!
 
+use super::auth::RequestVerifier;
 use super::message::*;
 use super::traits::WalletApi;
 use crate::error::Error;
 use crate::events::Events;
 use crate::imports::*;
+use crate::logging::log;
 use crate::result::Result;
 use crate::wallet::Wallet;
 use async_trait::async_trait;
@@ -70,6 +72,7 @@ impl WalletApi for WalletClient {
         Connect,
         Disconnect,
         ChangeNetworkId,
+        SetLogLevel,
         RetainContext,
         Batch,
         Flush,
@@ -82,11 +85,15 @@ impl WalletApi for WalletClient {
         WalletChangeSecret,
         WalletExport,
         WalletImport,
+        WalletCheck,
         PrvKeyDataEnumerate,
         PrvKeyDataCreate,
         PrvKeyDataRemove,
         PrvKeyDataGet,
+        PrvKeyDataChangeSecret,
         AccountsRename,
+        AccountsArchive,
+        AccountsUnarchive,
         AccountsSelect,
         AccountsEnumerate,
         AccountsDiscovery,
@@ -96,14 +103,36 @@ impl WalletApi for WalletClient {
         AccountsActivate,
         AccountsDeactivate,
         AccountsGet,
+        AccountsExportXpub,
+        AccountsSignMessage,
+        AccountsVerifyMessage,
         AccountsCreateNewAddress,
         AccountsSend,
+        AccountsSendBatch,
         AccountsTransfer,
         AccountsEstimate,
         TransactionsDataGet,
         TransactionsReplaceNote,
         TransactionsReplaceMetadata,
+        TransactionsExport,
         AddressBookEnumerate,
+        TasksEnumerate,
+        TasksAbort,
+        AccountsGetUtxos,
+        AccountsLabelUtxos,
+        AccountsBalanceHistory,
+        MarketConfigure,
+        MarketDataGet,
+        WebhooksEnumerate,
+        WebhooksCreate,
+        WebhooksRemove,
+        AccountsSignTransaction,
+        AccountsSetGapLimit,
+        AccountsGetPendingIncoming,
+        AccountsCreatePaymentRequest,
+        AccountsGetPaymentRequests,
+        AccountsRemovePaymentRequest,
+        AccountsAddresses,
     ]}
 }
 
@@ -120,10 +149,14 @@ pub trait EventHandler: Send + Sync {
 /// API methods that can be invoked via Borsh or Serde messages containing
 /// serializations created using the [`Transport`] interface. The [`WalletServer`]
 /// is a counter-part to [`WalletClient`].
+///
+/// If a [`RequestVerifier`] is installed (see [`WalletServer::with_request_verifier`]),
+/// Serde requests must be signed (see [`auth`](super::auth)) and Borsh requests are rejected.
 pub struct WalletServer {
     // pub wallet_api: Arc<dyn WalletApi>,
     pub wallet: Arc<Wallet>,
     pub event_handler: Arc<dyn EventHandler>,
+    request_verifier: Option<Arc<RequestVerifier>>,
     task_ctl: DuplexChannel,
 }
 
@@ -131,12 +164,34 @@ impl WalletServer {
     // pub fn new(wallet_api: Arc<dyn WalletApi>, event_handler : Arc<dyn EventHandler>) -> Self {
     //     Self { wallet_api, event_handler }
     pub fn new(wallet: Arc<Wallet>, event_handler: Arc<dyn EventHandler>) -> Self {
-        Self { wallet, event_handler, task_ctl: DuplexChannel::unbounded() }
+        Self { wallet, event_handler, request_verifier: None, task_ctl: DuplexChannel::unbounded() }
+    }
+
+    /// Require all incoming requests to be authenticated by the supplied [`RequestVerifier`].
+    pub fn with_request_verifier(mut self, request_verifier: RequestVerifier) -> Self {
+        self.request_verifier = Some(Arc::new(request_verifier));
+        self
     }
 
     pub fn wallet_api(&self) -> Arc<dyn WalletApi> {
         self.wallet.clone()
     }
+
+    pub async fn call_with_borsh(&self, op: u64, request: &[u8]) -> Result<Vec<u8>> {
+        if self.request_verifier.is_some() {
+            return Err(Error::RequestAuthentication("Borsh requests can not be authenticated".to_string()));
+        }
+        self.dispatch_with_borsh(op, request).await
+    }
+
+    pub async fn call_with_serde(&self, op: &str, request: &str) -> Result<String> {
+        if let Some(request_verifier) = &self.request_verifier {
+            let request = request_verifier.verify(op, request)?;
+            self.dispatch_with_serde(op, request.as_str()).await
+        } else {
+            self.dispatch_with_serde(op, request).await
+        }
+    }
 }
 
 impl WalletServer {
@@ -146,6 +201,7 @@ impl WalletServer {
         Connect,
         Disconnect,
         ChangeNetworkId,
+        SetLogLevel,
         RetainContext,
         Batch,
         Flush,
@@ -158,11 +214,15 @@ impl WalletServer {
         WalletChangeSecret,
         WalletExport,
         WalletImport,
+        WalletCheck,
         PrvKeyDataEnumerate,
         PrvKeyDataCreate,
         PrvKeyDataRemove,
         PrvKeyDataGet,
+        PrvKeyDataChangeSecret,
         AccountsRename,
+        AccountsArchive,
+        AccountsUnarchive,
         AccountsSelect,
         AccountsEnumerate,
         AccountsDiscovery,
@@ -172,14 +232,36 @@ impl WalletServer {
         AccountsActivate,
         AccountsDeactivate,
         AccountsGet,
+        AccountsExportXpub,
+        AccountsSignMessage,
+        AccountsVerifyMessage,
         AccountsCreateNewAddress,
         AccountsSend,
+        AccountsSendBatch,
         AccountsTransfer,
         AccountsEstimate,
         TransactionsDataGet,
         TransactionsReplaceNote,
         TransactionsReplaceMetadata,
+        TransactionsExport,
         AddressBookEnumerate,
+        TasksEnumerate,
+        TasksAbort,
+        AccountsGetUtxos,
+        AccountsLabelUtxos,
+        AccountsBalanceHistory,
+        MarketConfigure,
+        MarketDataGet,
+        WebhooksEnumerate,
+        WebhooksCreate,
+        WebhooksRemove,
+        AccountsSignTransaction,
+        AccountsSetGapLimit,
+        AccountsGetPendingIncoming,
+        AccountsCreatePaymentRequest,
+        AccountsGetPaymentRequests,
+        AccountsRemovePaymentRequest,
+        AccountsAddresses,
     ]}
 }
 
@@ -203,8 +285,8 @@ impl WalletServer {
                                 this.event_handler.handle_event(&event).await;//.unwrap_or_else(|e| log_error!("Wallet::handle_event() error: {}", e));
                             },
                             Err(err) => {
-                                log_error!("Wallet: error while receiving multiplexer message: {err}");
-                                log_error!("Suspending Wallet processing...");
+                                log!(Wallet, Error, "Wallet: error while receiving multiplexer message: {err}");
+                                log!(Wallet, Error, "Suspending Wallet processing...");
 
                                 break;
                             }
@@ -13,13 +13,20 @@ use md5::Md5;
 use pbkdf2::{hmac::Hmac, pbkdf2};
 use serde::{Deserialize, Serialize};
 use sha1::Sha1;
+#[cfg(feature = "fs")]
 use std::path::PathBuf;
+#[cfg(feature = "fs")]
 #[allow(unused_imports)]
 use workflow_core::env;
+#[cfg(feature = "fs")]
 use workflow_core::runtime;
+#[cfg(feature = "fs")]
 use workflow_store::fs;
+#[cfg(feature = "fs")]
 use workflow_store::fs::exists_with_options;
+#[cfg(feature = "fs")]
 use workflow_store::fs::read_json_with_options;
+#[cfg(feature = "fs")]
 use workflow_store::fs::Options;
 use zeroize::Zeroize;
 
@@ -152,6 +159,7 @@ struct Envelope {
     pub wallet: Wallet,
 }
 
+#[cfg(feature = "fs")]
 fn legacy_v0_keydata_location() -> Result<(PathBuf, Options)> {
     let filename = if runtime::is_windows() {
         let appdata = env::var("APPDATA")?;
@@ -167,11 +175,13 @@ fn legacy_v0_keydata_location() -> Result<(PathBuf, Options)> {
     Ok((filename, options))
 }
 
+#[cfg(feature = "fs")]
 pub async fn exists_legacy_v0_keydata() -> Result<bool> {
     let (filename, options) = legacy_v0_keydata_location()?;
     Ok(exists_with_options(&filename, options).await?)
 }
 
+#[cfg(feature = "fs")]
 pub async fn load_v0_keydata(phrase: &Secret) -> Result<PrivateKeyDataV0> {
     let (filename, options) = legacy_v0_keydata_location()?;
 
@@ -184,6 +194,24 @@ pub async fn load_v0_keydata(phrase: &Secret) -> Result<PrivateKeyDataV0> {
     get_v0_keydata(&wallet.mnemonic, phrase)
 }
 
+/// Decrypt legacy keydata supplied as the contents of a KDX keydata file
+/// (`kaspa.kpk`), the kaspanet web wallet storage data or the encrypted
+/// mnemonic itself.
+pub fn parse_v0_keydata(data: &str, phrase: &Secret) -> Result<PrivateKeyDataV0> {
+    let data = data.trim();
+    let mnemonic = if let Ok(envelope) = serde_json::from_str::<Envelope>(data) {
+        envelope.wallet.mnemonic
+    } else if let Ok(wallet) = serde_json::from_str::<Wallet>(data) {
+        wallet.mnemonic
+    } else if !data.is_empty() && data.chars().all(|c| c.is_ascii_hexdigit()) {
+        data.to_string()
+    } else {
+        return Err(Error::custom("Unable to parse the legacy wallet keydata"));
+    };
+
+    get_v0_keydata(&mnemonic, phrase)
+}
+
 // ---
 
 #[test]
@@ -198,6 +226,21 @@ fn test_v0_keydata() {
     assert_eq!(keydata.mnemonic, "interest denial place quick stay suit token shadow side ski knife entire");
 }
 
+#[test]
+fn test_v0_parse_keydata() {
+    let data = "004488a555e6fd87f5c96bc1a735d65ff3e8b9005218ca4bff9f5460c0b75e7c4396c530a3004896b5846de2741cf32b1a367aa581ba8e035f9229e0e24fb383bfc3ea731c842541fbf4f6c0c4e6f7306d08ea28b40dc74d0c60e75c82d94d401d5716a749c42cf0d20c78a43d7e4b97ce2a1dd146e38b080c9fcaff9ec5bdcae09704a9f1cefefe5375f768cc92135adf89018a1ee9b595baaa72729306380e7c221d9000c77d25830e1af566255f0a6213285861b06305ef1f04b4c1de1b897644b26121f81eddb84b54efe88c46bd0b2e38057797ee5552e745759967a856970a900032cb852cabdfe2c6f73a9d84c462067bdb0001631faffd91cb36a3900032f6f66cc22b119ced5b340ab5af961dcd";
+    let secret = Secret::new(b"Hunter44!".to_vec());
+    let mnemonic = "interest denial place quick stay suit token shadow side ski knife entire";
+
+    let kpk =
+        format!(r#"{{"type":"kaspa-wallet","encryption":"default","version":1,"generator":"pwa","wallet":{{"mnemonic":"{data}"}}}}"#);
+    assert_eq!(parse_v0_keydata(&kpk, &secret).unwrap().mnemonic, mnemonic);
+    let web = format!(r#"{{"mnemonic":"{data}"}}"#);
+    assert_eq!(parse_v0_keydata(&web, &secret).unwrap().mnemonic, mnemonic);
+    assert_eq!(parse_v0_keydata(data, &secret).unwrap().mnemonic, mnemonic);
+    assert!(parse_v0_keydata("kaspa", &secret).is_err());
+}
+
 #[test]
 fn test_v0_padding() {
     let secret = Secret::new(b"Hunter44!".to_vec());
@@ -55,6 +55,7 @@ mod test {
                     encryption_kind: EncryptionKind::XChaCha20Poly1305,
                     user_hint: None,
                     overwrite_wallet_storage: false,
+                    account_database: false,
                 },
             )
             .await
@@ -92,6 +93,7 @@ mod test {
                     encryption_kind: EncryptionKind::XChaCha20Poly1305,
                     user_hint: None,
                     overwrite_wallet_storage: false,
+                    account_database: false,
                 },
             )
             .await
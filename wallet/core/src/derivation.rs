@@ -4,6 +4,7 @@
 
 use kaspa_wallet_keys::derivation::gen0::{PubkeyDerivationManagerV0, WalletDerivationManagerV0};
 use kaspa_wallet_keys::derivation::gen1::{PubkeyDerivationManager, WalletDerivationManager};
+use kaspa_wallet_keys::multisig::create_multisig_redeem_script;
 
 pub use kaspa_wallet_keys::derivation::traits::*;
 use kaspa_wallet_keys::publickey::{PublicKey, PublicKeyArrayT, PublicKeyT};
@@ -14,11 +15,9 @@ use crate::account::AccountKind;
 use crate::error::Error;
 use crate::imports::*;
 use crate::result::Result;
-use kaspa_bip32::{AddressType, DerivationPath, ExtendedPrivateKey, ExtendedPublicKey, Language, Mnemonic, SecretKeyExt};
+use kaspa_bip32::{AddressType, ChildNumber, DerivationPath, ExtendedPrivateKey, ExtendedPublicKey, Language, Mnemonic, SecretKeyExt};
 use kaspa_consensus_core::network::NetworkType;
-use kaspa_txscript::{
-    extract_script_pub_key_address, multisig_redeem_script, multisig_redeem_script_ecdsa, pay_to_script_hash_script,
-};
+use kaspa_txscript::{extract_script_pub_key_address, pay_to_script_hash_script};
 
 #[derive(Default, Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 pub struct AddressDerivationMeta([u32; 2]);
@@ -175,6 +174,7 @@ pub struct AddressDerivationManager {
     pub account_kind: AccountKind,
     pub account_index: u64,
     pub cosigner_index: Option<u32>,
+    pub derivation_path: Option<DerivationPathTemplate>,
     pub derivators: Vec<Arc<dyn WalletDerivationManagerTrait>>,
     #[allow(dead_code)]
     wallet: Arc<Wallet>,
@@ -190,6 +190,7 @@ impl AddressDerivationManager {
         ecdsa: bool,
         account_index: u64,
         cosigner_index: Option<u32>,
+        derivation_path: Option<DerivationPathTemplate>,
         minimum_signatures: u16,
         address_derivation_indexes: AddressDerivationMeta,
     ) -> Result<Arc<AddressDerivationManager>> {
@@ -237,6 +238,7 @@ impl AddressDerivationManager {
             account_kind,
             account_index,
             cosigner_index,
+            derivation_path,
             derivators,
             wallet: wallet.clone(),
             receive_address_manager: Arc::new(receive_address_manager),
@@ -276,6 +278,7 @@ impl AddressDerivationManager {
             account_kind,
             account_index,
             cosigner_index: None,
+            derivation_path: None,
             derivators: vec![derivator],
             wallet: wallet.clone(),
             receive_address_manager: Arc::new(receive_address_manager),
@@ -329,8 +332,15 @@ impl AddressDerivationManager {
 
         let (receive, change) = if change_address { (vec![], addresses) } else { (addresses, vec![]) };
 
-        let private_keys =
-            create_private_keys(&self.account_kind, self.cosigner_index.unwrap_or(0), self.account_index, xkey, &receive, &change)?;
+        let private_keys = create_private_keys(
+            &self.account_kind,
+            self.cosigner_index.unwrap_or(0),
+            self.account_index,
+            self.derivation_path.as_ref(),
+            xkey,
+            &receive,
+            &change,
+        )?;
 
         let mut result = vec![];
         for (address, private_key) in private_keys {
@@ -445,11 +455,7 @@ pub fn create_multisig_address(
     prefix: Prefix,
     ecdsa: bool,
 ) -> Result<Address> {
-    let script = if !ecdsa {
-        multisig_redeem_script(keys.iter().map(|pk| pk.x_only_public_key().0.serialize()), minimum_signatures)
-    } else {
-        multisig_redeem_script_ecdsa(keys.iter().map(|pk| pk.serialize()), minimum_signatures)
-    }?;
+    let script = create_multisig_redeem_script(&keys, minimum_signatures, ecdsa)?;
     let script_pub_key = pay_to_script_hash_script(&script);
     let address = extract_script_pub_key_address(&script_pub_key, prefix)?;
     Ok(address)
@@ -566,3 +572,93 @@ pub fn build_derivate_paths(
     let change_path = build_derivate_path(account_kind, account_index, cosigner_index, AddressType::Change)?;
     Ok((receive_path, change_path))
 }
+
+/// Account-level derivation path override (`m/<purpose>'/<coin_type>'/<account_index>'`).
+///
+/// Allows BIP32 accounts to derive keys using non-standard paths, such as
+/// those produced by legacy wallets that did not use the kaspa coin type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DerivationPathTemplate {
+    pub purpose: u32,
+    pub coin_type: u32,
+    pub account_index: u64,
+}
+
+impl DerivationPathTemplate {
+    /// BIP-44 purpose used by standard kaspa accounts.
+    pub const PURPOSE: u32 = 44;
+    /// SLIP-44 coin type used by standard kaspa accounts.
+    pub const COIN_TYPE: u32 = 111111;
+
+    pub fn new(purpose: u32, coin_type: u32, account_index: u64) -> Result<Self> {
+        let template = Self { purpose, coin_type, account_index };
+        template.account_path()?;
+        Ok(template)
+    }
+
+    /// Returns `true` if this template matches the standard kaspa BIP-44 path.
+    pub fn is_standard(&self) -> bool {
+        self.purpose == Self::PURPOSE && self.coin_type == Self::COIN_TYPE
+    }
+
+    /// Account-level (hardened) derivation path.
+    pub fn account_path(&self) -> Result<DerivationPath> {
+        let account_index = u32::try_from(self.account_index)
+            .map_err(|_| Error::InvalidDerivationPath(format!("account index {}", self.account_index)))?;
+        let mut path = DerivationPath::default();
+        for index in [self.purpose, self.coin_type, account_index] {
+            path.push(ChildNumber::new(index, true).map_err(|_| Error::InvalidDerivationPath(self.to_string()))?);
+        }
+        Ok(path)
+    }
+
+    /// Derivation path of the receive or change address chain.
+    pub fn address_path(&self, address_type: AddressType) -> Result<DerivationPath> {
+        let mut path = self.account_path()?;
+        path.push(ChildNumber::new(address_type.index(), false)?);
+        Ok(path)
+    }
+
+    /// Derivation paths of the receive and change address chains.
+    pub fn address_paths(&self) -> Result<(DerivationPath, DerivationPath)> {
+        Ok((self.address_path(AddressType::Receive)?, self.address_path(AddressType::Change)?))
+    }
+}
+
+impl std::fmt::Display for DerivationPathTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "m/{}'/{}'/{}'", self.purpose, self.coin_type, self.account_index)
+    }
+}
+
+impl std::str::FromStr for DerivationPathTemplate {
+    type Err = Error;
+
+    fn from_str(path: &str) -> Result<Self> {
+        let invalid = || Error::InvalidDerivationPath(path.to_string());
+        let children = path.parse::<DerivationPath>().map_err(|_| invalid())?;
+        let children = children.as_ref();
+        if children.len() != 3 || !children.iter().all(|child| child.is_hardened()) {
+            return Err(invalid());
+        }
+
+        Ok(Self { purpose: children[0].index(), coin_type: children[1].index(), account_index: children[2].index() as u64 })
+    }
+}
+
+impl TryFrom<&JsValue> for DerivationPathTemplate {
+    type Error = Error;
+    fn try_from(value: &JsValue) -> Result<Self> {
+        let path = value.as_string().ok_or_else(|| Error::InvalidDerivationPath(format!("{value:?}")))?;
+        path.parse()
+    }
+}
+
+pub async fn create_xpub_from_xprv_with_path(
+    xprv: ExtendedPrivateKey<secp256k1::SecretKey>,
+    derivation_path: &DerivationPathTemplate,
+) -> Result<ExtendedPublicKey<secp256k1::PublicKey>> {
+    let xprv = xprv.derive_path(&derivation_path.account_path()?)?;
+    Ok(xprv.public_key())
+}
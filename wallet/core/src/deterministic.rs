@@ -117,7 +117,9 @@ pub fn from_bip32<const N: usize>(prv_key_data_id: &PrvKeyDataId, data: &bip32::
         ecdsa: Some(data.ecdsa),
         account_index: Some(data.account_index),
         secp256k1_public_key: None,
-        data: None,
+        // custom derivation paths produce distinct accounts while
+        // accounts using the standard path retain their original ids
+        data: data.derivation_path.map(|derivation_path| derivation_path.try_to_vec().unwrap()),
     };
     make_hashes(hashable)
 }
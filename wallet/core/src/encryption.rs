@@ -194,11 +194,14 @@ impl Encrypted {
     where
         T: BorshSerialize + BorshDeserialize,
     {
+        let decrypted = self.decrypt_bytes(secret)?;
+        Ok(Decrypted(T::try_from_slice(decrypted.as_ref())?))
+    }
+
+    /// Decrypts the payload without deserializing it.
+    pub fn decrypt_bytes(&self, secret: &Secret) -> Result<Secret> {
         match self.encryption_kind {
-            EncryptionKind::XChaCha20Poly1305 => {
-                let decrypted = decrypt_xchacha20poly1305(&self.payload, secret)?;
-                Ok(Decrypted(T::try_from_slice(decrypted.as_ref())?))
-            }
+            EncryptionKind::XChaCha20Poly1305 => decrypt_xchacha20poly1305(&self.payload, secret),
         }
     }
 }
@@ -3,21 +3,59 @@
 //!
 
 use crate::imports::{AccountId, AccountKind, AssocPrvKeyDataIds, PrvKeyDataId};
+use crate::utxo::{UtxoEntryId, UtxoStatus};
 use base64::DecodeError;
 use downcast::DowncastError;
 use kaspa_bip32::Error as BIP32Error;
+use kaspa_consensus_core::network::NetworkId;
 use kaspa_consensus_core::sign::Error as CoreSignError;
 use kaspa_rpc_core::RpcError as KaspaRpcError;
+use kaspa_wrpc_client::capabilities::NodeFeature;
 use kaspa_wrpc_client::error::Error as KaspaWorkflowRpcError;
+use serde::{Deserialize, Serialize};
 use std::sync::PoisonError;
 use thiserror::Error;
-use wasm_bindgen::JsValue;
+use wasm_bindgen::prelude::*;
 use workflow_core::abortable::Aborted;
 use workflow_core::sendable::*;
 use workflow_rpc::client::error::Error as RpcError;
 use workflow_wasm::jserror::*;
 use workflow_wasm::printable::*;
 
+/// Additional context accompanying the [`Error::InsufficientFunds`] error.
+/// All amounts are in SOMPI.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InsufficientFundsDetails {
+    /// Amount required by the transaction (including fees).
+    pub required: u64,
+    /// Mature balance available for spending.
+    pub available: u64,
+    /// Pending (immature) balance that can not be spent yet.
+    pub pending: u64,
+    /// Number of immature (pending and coinbase stasis) UTXOs
+    /// excluded from the transaction.
+    pub excluded_utxo_count: usize,
+}
+
+impl InsufficientFundsDetails {
+    /// Returns `true` if the transaction can be created
+    /// once the pending balance matures.
+    pub fn pending_would_suffice(&self) -> bool {
+        self.pending > 0 && self.available.saturating_add(self.pending) >= self.required
+    }
+}
+
+impl std::fmt::Display for InsufficientFundsDetails {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "required: {} sompi, available: {} sompi", self.required, self.available)?;
+        if self.pending > 0 {
+            write!(f, ", pending: {} sompi in {} UTXOs", self.pending, self.excluded_utxo_count)?;
+        }
+        Ok(())
+    }
+}
+
 /// [`Error`](enum@Error) variants emitted by the wallet framework.
 #[derive(Debug, Error)]
 pub enum Error {
@@ -108,6 +146,9 @@ pub enum Error {
     #[error("The server UTXO index is not enabled")]
     MissingUtxoIndex,
 
+    #[error("{0} is not supported by the connected node")]
+    NotSupportedByNode(NodeFeature),
+
     #[error("Invalid filename: {0}")]
     InvalidFilename(String),
 
@@ -165,6 +206,9 @@ pub enum Error {
     #[error("private key {0} already exists")]
     PrivateKeyAlreadyExists(PrvKeyDataId),
 
+    #[error("payment secret of the private key {0} is the bip39 passphrase of the mnemonic and can not be changed")]
+    PaymentSecretIsPassphrase(PrvKeyDataId),
+
     #[error("account {0} already exists")]
     AccountAlreadyExists(AccountId),
 
@@ -180,9 +224,21 @@ pub enum Error {
     #[error("Supplied secret in key '{0}' is empty")]
     SecretIsEmpty(String),
 
+    #[error("Secret is too weak (strength {score} of 4, required {min_score}){}", warning.as_ref().map(|warning| format!(": {warning}")).unwrap_or_default())]
+    WeakSecret { score: u8, min_score: u8, warning: Option<String>, suggestions: Vec<String> },
+
     #[error("task aborted")]
     Aborted,
 
+    #[error("task {0} not found")]
+    TaskNotFound(u64),
+
+    #[error("UTXO {0} not found")]
+    UtxoNotFound(UtxoEntryId),
+
+    #[error("UTXO {0} is not spendable ({1})")]
+    UtxoNotSpendable(UtxoEntryId, UtxoStatus),
+
     #[error("{0}")]
     TryFromEnum(#[from] workflow_core::enums::TryFromError),
 
@@ -192,6 +248,9 @@ pub enum Error {
     #[error("Account not found: {0}")]
     AccountNotFound(AccountId),
 
+    #[error("Account is archived: {0}")]
+    AccountArchived(AccountId),
+
     #[error("Account not active: {0}")]
     AccountNotActive(AccountId),
 
@@ -204,8 +263,8 @@ pub enum Error {
     #[error("Invalid account type (must be one of: bip32|multisig|legacy")]
     InvalidAccountKind,
 
-    #[error("Insufficient funds")]
-    InsufficientFunds { additional_needed: u64, origin: &'static str },
+    #[error("Insufficient funds ({details})")]
+    InsufficientFunds { additional_needed: u64, origin: &'static str, details: Box<InsufficientFundsDetails> },
 
     #[error(transparent)]
     Utf8Error(#[from] std::str::Utf8Error),
@@ -228,6 +287,9 @@ pub enum Error {
     #[error("Not allowed on a resident wallet")]
     ResidentWallet,
 
+    #[error("File storage is not available (wallet framework built without the `fs` feature)")]
+    FileStorageDisabled,
+
     #[error("Not allowed on a resident account")]
     ResidentAccount,
 
@@ -237,6 +299,9 @@ pub enum Error {
     #[error("Address derivation processing is not supported by this account type")]
     AccountAddressDerivationCaps,
 
+    #[error("Invalid derivation path: {0}")]
+    InvalidDerivationPath(String),
+
     #[error("{0}")]
     DowncastError(String),
 
@@ -270,6 +335,21 @@ pub enum Error {
     #[error("Transaction exceeds the maximum allowed mass")]
     GeneratorTransactionIsTooHeavy,
 
+    #[error("Transaction fees exceed the maximum supported value")]
+    GeneratorFeeOverflow,
+
+    #[error("Invalid generator settings: {}", .0.join(", "))]
+    GeneratorInvalidSettings(Vec<String>),
+
+    #[error("Invalid payment batch: {0}")]
+    InvalidPaymentBatch(String),
+
+    #[error("Invalid payment URI: {0}")]
+    InvalidPaymentUri(String),
+
+    #[error("Invalid amount: {0}")]
+    InvalidAmount(String),
+
     #[error("Storage mass exceeds maximum")]
     StorageMassExceedsMaximumTransactionMass { storage_mass: u64 },
 
@@ -326,6 +406,327 @@ pub enum Error {
 
     #[error(transparent)]
     Metrics(#[from] kaspa_metrics_core::error::Error),
+
+    #[error("Invalid sign-in challenge: {0}")]
+    InvalidSignInChallenge(String),
+
+    #[error("Sign-in challenge has expired")]
+    SignInChallengeExpired,
+
+    #[error("Sign-in challenge signature is invalid")]
+    InvalidSignInSignature,
+
+    #[error("Address {0} does not belong to this account")]
+    AddressNotFound(kaspa_addresses::Address),
+
+    #[error("Message signing supports only PubKey (Schnorr) addresses")]
+    MessageSigningAddressVersion,
+
+    #[error("Message signature is invalid")]
+    InvalidMessageSignature,
+
+    #[error("Invalid xpub ownership proof: {0}")]
+    InvalidOwnershipProof(String),
+
+    #[error("Xpub ownership proof has expired")]
+    OwnershipProofExpired,
+
+    #[error("Xpub ownership proof signature is invalid")]
+    InvalidOwnershipProofSignature,
+
+    #[error("Wallet API request authentication failed: {0}")]
+    RequestAuthentication(String),
+
+    #[error("Wallet API request signature is invalid")]
+    InvalidRequestSignature,
+
+    #[error("Wallet API request timestamp is outside of the accepted window")]
+    RequestExpired,
+
+    #[error("Wallet API request has already been processed (replayed nonce)")]
+    RequestReplay,
+
+    #[error("Priority fee ({priority_fee}) exceeds the transaction amount ({amount})")]
+    PriorityFeeExceedsAmount { priority_fee: u64, amount: u64 },
+
+    #[error("Default priority fee can not be configured for network {0}")]
+    PriorityFeeNotConfigurable(NetworkId),
+
+    #[error("Price feed error: {0}")]
+    PriceFeed(String),
+
+    #[error("Faucet error: {0}")]
+    Faucet(String),
+
+    #[error("Webhook error: {0}")]
+    Webhook(String),
+
+    #[error("Webhook '{0}' not found")]
+    WebhookNotFound(String),
+
+    #[error("Unknown price feed '{0}' (must be one of: coingecko|coinpaprika)")]
+    InvalidPriceFeed(String),
+
+    #[error("Invalid signable transaction: {0}")]
+    InvalidSignableTransaction(String),
+
+    #[error("None of the transaction inputs can be signed by this account")]
+    NoSignableInputs,
+
+    #[error("Signature of transaction input {0} is invalid")]
+    InvalidInputSignature(usize),
+
+    #[error("Invalid UTXO snapshot: {0}")]
+    InvalidUtxoSnapshot(String),
+
+    #[error("Address gap limit must be greater than 0")]
+    InvalidGapLimit,
+}
+
+#[cfg(feature = "wasm32-sdk")]
+#[wasm_bindgen(typescript_custom_section)]
+const TS_WALLET_ERROR: &'static str = r#"
+/**
+ * Error rejected by the wallet API. The `code` property contains a stable
+ * {@link WalletErrorCode} that can be used to handle specific error conditions
+ * (such as {@link WalletErrorCode.InsufficientFunds} or {@link WalletErrorCode.WrongSecret})
+ * without matching the error message. The `data` property contains additional
+ * error details when available (for example, required and available amounts
+ * for {@link WalletErrorCode.InsufficientFunds}).
+ *
+ * @category Wallet API
+ */
+export interface IWalletError extends Error {
+    code : WalletErrorCode;
+    message : string;
+    data? : Record<string, any>;
+}
+"#;
+
+/// Stable numeric error codes reported to JavaScript as the `code` property
+/// of errors rejected by the wallet API (see {@link IWalletError}). Codes are
+/// grouped by category: `1xxx` general, `2xxx` network, `3xxx` wallet storage
+/// and secrets, `4xxx` accounts and keys, `5xxx` transactions.
+///
+/// @category Wallet API
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "wasm32-sdk", wasm_bindgen(js_name = "WalletErrorCode"))]
+#[repr(u32)]
+pub enum ErrorCode {
+    /// Unclassified error
+    Unknown = 1000,
+    InvalidArgument = 1001,
+    NotImplemented = 1002,
+    Aborted = 1003,
+    NotFound = 1004,
+    /// Wallet API request authentication failure
+    Unauthorized = 1005,
+    /// RPC or node connection failure
+    NetworkError = 2000,
+    NotConnected = 2001,
+    InvalidNetwork = 2002,
+    MissingUtxoIndex = 2003,
+    /// The connected node does not implement the required RPC method
+    NotSupportedByNode = 2004,
+    WalletNotOpen = 3000,
+    WalletNotFound = 3001,
+    WalletAlreadyExists = 3002,
+    /// Supplied wallet or payment secret is incorrect
+    WrongSecret = 3003,
+    SecretRequired = 3004,
+    StorageError = 3005,
+    /// Supplied secret does not satisfy the secret strength policy
+    WeakSecret = 3006,
+    AccountNotFound = 4000,
+    AccountAlreadyExists = 4001,
+    AccountNotSupported = 4002,
+    PrivateKeyNotFound = 4003,
+    PrivateKeyAlreadyExists = 4004,
+    InvalidKey = 4005,
+    AccountArchived = 4006,
+    InsufficientFunds = 5000,
+    PriorityFeeExceedsAmount = 5001,
+    InvalidTransaction = 5002,
+    TransactionTooHeavy = 5003,
+    UtxoNotFound = 5004,
+    UtxoNotSpendable = 5005,
+    SigningError = 5006,
+}
+
+impl Error {
+    /// Returns the [`ErrorCode`] classifying this error.
+    pub fn code(&self) -> ErrorCode {
+        use ErrorCode as Code;
+
+        match self {
+            Error::InvalidArgument(_)
+            | Error::SecretIsEmpty(_)
+            | Error::InvalidFilename(_)
+            | Error::InvalidRange(..)
+            | Error::InvalidAccountId(_)
+            | Error::InvalidKeyDataId(_)
+            | Error::KeyId(_)
+            | Error::InvalidAccountKind
+            | Error::InvalidTransactionKind(_)
+            | Error::InvalidDerivationPath(_)
+            | Error::BigInt(_)
+            | Error::Address(_)
+            | Error::InvalidPaymentUri(_)
+            | Error::InvalidAmount(_)
+            | Error::InvalidPriceFeed(_)
+            | Error::InvalidSignableTransaction(_)
+            | Error::InvalidUtxoSnapshot(_)
+            | Error::InvalidGapLimit
+            | Error::ParseIntError(_)
+            | Error::ParseFloatError(_)
+            | Error::FasterHexError(_)
+            | Error::DecodeError(_)
+            | Error::TryFromEnum(_) => Code::InvalidArgument,
+            Error::NotImplemented => Code::NotImplemented,
+            Error::Aborted => Code::Aborted,
+            Error::TaskNotFound(_) | Error::WebhookNotFound(_) | Error::NoRecordsFound => Code::NotFound,
+            Error::RequestAuthentication(_) | Error::InvalidRequestSignature | Error::RequestExpired | Error::RequestReplay => {
+                Code::Unauthorized
+            }
+
+            Error::NotSupportedByNode(_) | Error::KaspaWorkflowRpcError(KaspaWorkflowRpcError::NotSupportedByNode(_)) => {
+                Code::NotSupportedByNode
+            }
+            Error::KaspaRpcClientResult(_)
+            | Error::RpcError(_)
+            | Error::KaspaWorkflowRpcError(_)
+            | Error::NotWrpcClient
+            | Error::RpcApiVersion(..)
+            | Error::MissingDaaScore(_)
+            | Error::PriceFeed(_)
+            | Error::Faucet(_)
+            | Error::Webhook(_)
+            | Error::ListenerId => Code::NetworkError,
+            Error::NotConnected => Code::NotConnected,
+            Error::MissingNetworkId
+            | Error::InvalidNetworkId(_)
+            | Error::InvalidNetworkType(..)
+            | Error::InvalidNetworkSuffix(_)
+            | Error::MissingNetworkSuffix(_)
+            | Error::UnexpectedExtraSuffixToken(_)
+            | Error::NetworkTypeConnected
+            | Error::NetworkType(_)
+            | Error::NetworkId(_)
+            | Error::PriorityFeeNotConfigurable(_) => Code::InvalidNetwork,
+            Error::MissingUtxoIndex => Code::MissingUtxoIndex,
+
+            Error::WalletNotOpen => Code::WalletNotOpen,
+            Error::NoWalletInStorage(_) => Code::WalletNotFound,
+            Error::WalletAlreadyExists | Error::WalletNameNotAllowed => Code::WalletAlreadyExists,
+            Error::WalletDecrypt(_) | Error::Chacha20poly1305(_) => Code::WrongSecret,
+            Error::WalletSecretRequired => Code::SecretRequired,
+            Error::WeakSecret { .. } => Code::WeakSecret,
+            Error::Io(_) | Error::WorkflowStore(_) | Error::ResidentWallet | Error::FileStorageDisabled => Code::StorageError,
+
+            Error::AccountNotFound(_) | Error::AccountNotActive(_) | Error::AccountSelection => Code::AccountNotFound,
+            Error::AccountAlreadyExists(_) => Code::AccountAlreadyExists,
+            Error::AccountArchived(_) => Code::AccountArchived,
+            Error::AccountKindFeature
+            | Error::AccountAddressDerivationCaps
+            | Error::AccountFactoryNotFound(_)
+            | Error::ResidentAccount
+            | Error::LegacyAccountNotInitialized
+            | Error::XPrvSupport => Code::AccountNotSupported,
+            Error::PrivateKeyNotFound(_) => Code::PrivateKeyNotFound,
+            Error::PrivateKeyAlreadyExists(_) => Code::PrivateKeyAlreadyExists,
+            Error::PaymentSecretIsPassphrase(_) => Code::AccountNotSupported,
+            Error::WalletKeys(_)
+            | Error::BIP32Error(_)
+            | Error::Secp256k1Error(_)
+            | Error::InvalidExtendedPublicKey(..)
+            | Error::InvalidMnemonicPhrase
+            | Error::InvalidPrivateKeyLength
+            | Error::InvalidPublicKeyLength => Code::InvalidKey,
+
+            Error::InsufficientFunds { .. } => Code::InsufficientFunds,
+            Error::PriorityFeeExceedsAmount { .. } => Code::PriorityFeeExceedsAmount,
+            Error::GeneratorFeesInSweepTransaction
+            | Error::GeneratorNoFeesForFinalTransaction
+            | Error::GeneratorChangeAddressNetworkTypeMismatch
+            | Error::GeneratorPaymentOutputNetworkTypeMismatch
+            | Error::GeneratorPaymentOutputZeroAmount
+            | Error::GeneratorIncludeFeesRequiresOneOutput
+            | Error::GeneratorInvalidSettings(_)
+            | Error::GeneratorFeeOverflow
+            | Error::InvalidPaymentBatch(_)
+            | Error::NoSignableInputs
+            | Error::DuplicateUtxoEntry => Code::InvalidTransaction,
+            Error::GeneratorTransactionOutputsAreTooHeavy { .. }
+            | Error::GeneratorTransactionIsTooHeavy
+            | Error::StorageMassExceedsMaximumTransactionMass { .. }
+            | Error::MassCalculationError => Code::TransactionTooHeavy,
+            Error::UtxoNotFound(_) => Code::UtxoNotFound,
+            Error::UtxoNotSpendable(..) => Code::UtxoNotSpendable,
+            Error::CoreSignError(_)
+            | Error::TxScriptError(_)
+            | Error::MultisigCreateError(_)
+            | Error::ScriptBuilderError(_)
+            | Error::InvalidInputSignature(_) => Code::SigningError,
+
+            _ => Code::Unknown,
+        }
+    }
+
+    /// Additional error details reported to JavaScript as the `data` property.
+    fn data(&self) -> Option<JsValue> {
+        let properties: Vec<(&str, JsValue)> = match self {
+            Error::InsufficientFunds { additional_needed, origin, details } => vec![
+                ("origin", (*origin).into()),
+                ("additionalNeeded", js_sys::BigInt::from(*additional_needed).into()),
+                ("required", js_sys::BigInt::from(details.required).into()),
+                ("available", js_sys::BigInt::from(details.available).into()),
+                ("pending", js_sys::BigInt::from(details.pending).into()),
+                ("excludedUtxoCount", details.excluded_utxo_count.into()),
+            ],
+            Error::PriorityFeeExceedsAmount { priority_fee, amount } => {
+                vec![("priorityFee", js_sys::BigInt::from(*priority_fee).into()), ("amount", js_sys::BigInt::from(*amount).into())]
+            }
+            Error::GeneratorTransactionOutputsAreTooHeavy { mass, kind } => {
+                vec![("mass", js_sys::BigInt::from(*mass).into()), ("kind", (*kind).into())]
+            }
+            Error::StorageMassExceedsMaximumTransactionMass { storage_mass } => {
+                vec![("storageMass", js_sys::BigInt::from(*storage_mass).into())]
+            }
+            Error::GeneratorInvalidSettings(errors) => {
+                vec![("errors", errors.iter().map(JsValue::from).collect::<js_sys::Array>().into())]
+            }
+            Error::UtxoNotFound(outpoint) => vec![("outpoint", outpoint.to_string().into())],
+            Error::UtxoNotSpendable(outpoint, status) => {
+                vec![("outpoint", outpoint.to_string().into()), ("status", status.to_string().into())]
+            }
+            Error::WeakSecret { score, min_score, warning, suggestions } => {
+                let mut properties = vec![
+                    ("score", (*score).into()),
+                    ("minScore", (*min_score).into()),
+                    ("suggestions", suggestions.iter().map(JsValue::from).collect::<js_sys::Array>().into()),
+                ];
+                if let Some(warning) = warning {
+                    properties.push(("warning", warning.into()));
+                }
+                properties
+            }
+            Error::NoWalletInStorage(filename) => vec![("filename", filename.into())],
+            Error::PrivateKeyNotFound(prv_key_data_id) | Error::PaymentSecretIsPassphrase(prv_key_data_id) => {
+                vec![("prvKeyDataId", prv_key_data_id.to_string().into())]
+            }
+            Error::AccountNotFound(account_id) | Error::AccountNotActive(account_id) | Error::AccountAlreadyExists(account_id) => {
+                vec![("accountId", account_id.to_string().into())]
+            }
+            Error::TaskNotFound(task_id) => vec![("taskId", (*task_id as f64).into())],
+            _ => return None,
+        };
+
+        let data = js_sys::Object::new();
+        for (key, value) in properties.iter() {
+            js_sys::Reflect::set(&data, &(*key).into(), value).ok();
+        }
+        Some(data.into())
+    }
 }
 
 impl From<Aborted> for Error {
@@ -346,11 +747,21 @@ impl From<chacha20poly1305::Error> for Error {
     }
 }
 
+/// Errors are surfaced to JavaScript as `Error` instances carrying
+/// the numeric [`ErrorCode`] as `code` and optional details as `data`
+/// (errors originating in JavaScript are passed through unchanged).
 impl From<Error> for JsValue {
     fn from(value: Error) -> Self {
         match value {
             Error::JsValue(js_error_data) => js_error_data.into(),
-            _ => JsValue::from(value.to_string()),
+            _ => {
+                let error = js_sys::Error::new(&value.to_string());
+                js_sys::Reflect::set(&error, &"code".into(), &(value.code() as u32).into()).ok();
+                if let Some(data) = value.data() {
+                    js_sys::Reflect::set(&error, &"data".into(), &data).ok();
+                }
+                error.into()
+            }
         }
     }
 }
@@ -414,3 +825,18 @@ impl<T> From<workflow_core::channel::SendError<T>> for Error {
         Error::Custom(e.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_codes() {
+        assert_eq!(Error::WalletNotOpen.code() as u32, 3000);
+        assert_eq!(Error::PriorityFeeExceedsAmount { priority_fee: 2, amount: 1 }.code(), ErrorCode::PriorityFeeExceedsAmount);
+        assert_eq!(Error::WalletDecrypt(chacha20poly1305::Error).code(), ErrorCode::WrongSecret);
+        assert_eq!(Error::NotConnected.code(), ErrorCode::NotConnected);
+        assert_eq!(Error::RequestReplay.code(), ErrorCode::Unauthorized);
+        assert_eq!(Error::custom("custom error").code(), ErrorCode::Unknown);
+    }
+}
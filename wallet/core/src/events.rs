@@ -6,11 +6,14 @@
 
 use crate::imports::*;
 use crate::storage::{Hint, PrvKeyDataInfo, StorageDescriptor, TransactionRecord, WalletDescriptor};
+use crate::tx::GeneratorProgress;
 use crate::utxo::context::UtxoContextId;
+use crate::utxo::PaymentRequestId;
+use crate::wallet::WalletSyncProgress;
 use transaction::TransactionRecordNotification;
 
 /// Sync state of the kaspad node
-#[derive(Clone, Debug, Serialize, BorshSerialize, BorshDeserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "kebab-case")]
 #[serde(tag = "type", content = "data")]
 pub enum SyncState {
@@ -49,8 +52,8 @@ impl SyncState {
 }
 
 /// Events emitted by the wallet framework
-#[derive(Clone, Debug, Serialize, BorshSerialize, BorshDeserialize)]
-#[serde(rename_all = "kebab-case")]
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "kebab-case", rename_all_fields = "camelCase")]
 #[serde(tag = "type", content = "data")]
 pub enum Events {
     WalletPing,
@@ -81,6 +84,11 @@ pub enum Events {
         #[serde(rename = "syncState")]
         sync_state: SyncState,
     },
+    /// Wallet sync progress posted by the [`WalletSync`](crate::wallet::WalletSync)
+    /// state machine on each phase transition and progress percentage change
+    WalletSync {
+        progress: WalletSyncProgress,
+    },
     /// Emitted after the wallet has loaded and
     /// contains anti-phishing 'hint' set by the user.
     WalletHint {
@@ -88,16 +96,22 @@ pub enum Events {
     },
     /// Wallet has opened
     WalletOpen {
+        #[serde(alias = "wallet_descriptor")]
         wallet_descriptor: Option<WalletDescriptor>,
+        #[serde(alias = "account_descriptors")]
         account_descriptors: Option<Vec<AccountDescriptor>>,
     },
     WalletCreate {
+        #[serde(alias = "wallet_descriptor")]
         wallet_descriptor: WalletDescriptor,
+        #[serde(alias = "storage_descriptor")]
         storage_descriptor: StorageDescriptor,
     },
     /// Wallet reload initiated (development only)
     WalletReload {
+        #[serde(alias = "wallet_descriptor")]
         wallet_descriptor: Option<WalletDescriptor>,
+        #[serde(alias = "account_descriptors")]
         account_descriptors: Option<Vec<AccountDescriptor>>,
     },
     /// Wallet open failure
@@ -106,6 +120,11 @@ pub enum Events {
     },
     /// Wallet has been closed
     WalletClose,
+    /// Wallet has been closed due to inactivity (auto-lock);
+    /// the wallet secret is required to re-open the wallet
+    WalletLocked {
+        wallet_descriptor: Option<WalletDescriptor>,
+    },
     PrvKeyDataCreate {
         #[serde(rename = "prvKeyDataInfo")]
         prv_key_data_info: PrvKeyDataInfo,
@@ -118,17 +137,27 @@ pub enum Events {
     AccountDeactivation {
         ids: Vec<AccountId>,
     },
+    /// Accounts have been archived (removed from enumeration and activation)
+    AccountArchive {
+        ids: Vec<AccountId>,
+    },
+    /// Archived accounts have been restored
+    AccountUnarchive {
+        ids: Vec<AccountId>,
+    },
     /// Account selection change (`None` if no account is selected)
     AccountSelection {
         id: Option<AccountId>,
     },
     /// Account has been created
     AccountCreate {
+        #[serde(alias = "account_descriptor")]
         account_descriptor: AccountDescriptor,
     },
     /// Account has been changed
     /// (emitted on new address generation)
     AccountUpdate {
+        #[serde(alias = "account_descriptor")]
         account_descriptor: AccountDescriptor,
     },
     /// Emitted after successful RPC connection
@@ -172,6 +201,20 @@ pub enum Events {
     Reorg {
         record: TransactionRecord,
     },
+    /// Acceptance of an outgoing transaction has been reverted by
+    /// a chain reorganization; UTXOs consumed by the transaction
+    /// have been returned to the spendable set. The record is
+    /// flagged as reorged (see [`TransactionRecord::is_reorged`]).
+    ReorgedOut {
+        record: TransactionRecord,
+    },
+    /// Outgoing transaction reverted by a chain reorganization
+    /// (see [`Events::ReorgedOut`]) has been accepted again; UTXOs
+    /// consumed by the transaction have been removed from the
+    /// spendable set. The record is no longer flagged as reorged.
+    Reaccepted {
+        record: TransactionRecord,
+    },
     /// Coinbase stasis UTXO has been removed (reorg)
     /// NOTE: These transactions should be ignored by clients.
     Stasis {
@@ -221,6 +264,87 @@ pub enum Events {
         // metrics_data: MetricsData,
         metrics: MetricsUpdate,
     },
+    /// Emitted by the wallet [`Watchdog`] when a long-running wallet
+    /// operation (scan, send, discovery) exceeds its deadline. The task
+    /// can be cancelled using [`Watchdog::abort()`].
+    TaskDeadline {
+        task: TaskDescriptor,
+    },
+    /// A send tagged with a [`FeeBumpPolicy`] has not been accepted within
+    /// the policy deadline and has been regenerated with a higher fee,
+    /// replacing the pending transaction.
+    FeeBump {
+        #[serde(rename = "accountId")]
+        account_id: AccountId,
+        /// Id of the replaced (expired) transaction
+        #[serde(rename = "replacedTransactionId")]
+        replaced_transaction_id: TransactionId,
+        /// Id of the regenerated transaction
+        #[serde(rename = "transactionId")]
+        transaction_id: TransactionId,
+        /// Total fees of the regenerated transaction
+        fees: u64,
+    },
+    /// A send tagged with a [`FeeBumpPolicy`] could not be regenerated
+    /// within the policy maximum fee and is no longer tracked.
+    FeeBumpAbandoned {
+        #[serde(rename = "accountId")]
+        account_id: AccountId,
+        #[serde(rename = "transactionId")]
+        transaction_id: TransactionId,
+    },
+    /// Emitted by the wallet [`MarketMonitor`] each time the market
+    /// data is obtained from the configured price feed.
+    Market {
+        market: MarketData,
+    },
+    /// Emitted by [`Account::send_batch()`] for each transaction
+    /// submitted while processing a [`PaymentBatch`](crate::tx::PaymentBatch).
+    PaymentBatchProgress {
+        account_id: AccountId,
+        transaction_id: TransactionId,
+        /// Index of the batch partition (transaction carrying
+        /// a set of batch payments) being processed
+        partition: usize,
+        /// Total number of batch partitions
+        partitions: usize,
+        /// Number of batch payments submitted so far
+        payments: usize,
+        /// Total number of batch payments
+        total_payments: usize,
+        /// Fees paid by the transaction (in SOMPI)
+        fees: u64,
+        /// `true` if the transaction carries the batch payments,
+        /// `false` if it is a preceding UTXO compound transaction
+        is_final: bool,
+    },
+    /// Emitted by [`Account::send()`], [`Account::sweep()`] and
+    /// [`Account::transfer()`] for each submitted transaction, reporting
+    /// the progress of operations producing multiple transactions.
+    GeneratorProgress {
+        account_id: AccountId,
+        progress: GeneratorProgress,
+    },
+    /// Emitted by [`UtxoContext`] when a transaction fulfilling
+    /// a registered [`PaymentRequest`](crate::utxo::PaymentRequest)
+    /// has been received.
+    PaymentReceived {
+        request_id: PaymentRequestId,
+        txid: TransactionId,
+    },
+    /// Emitted by [`Account::scan()`] when the address scan is interrupted
+    /// by the loss of the RPC connection. The account UTXO set is rolled back
+    /// to its state prior to the scan and the scan is resumed by the wallet
+    /// upon reconnection (see [`Events::ScanResumed`]).
+    ScanInterrupted {
+        account_id: AccountId,
+        message: String,
+    },
+    /// Emitted by the [`Wallet`](crate::wallet::Wallet) when an address scan interrupted
+    /// by a disconnection ([`Events::ScanInterrupted`]) is resumed.
+    ScanResumed {
+        account_id: AccountId,
+    },
     /// A general wallet framework error, emitted when an unexpected
     /// error occurs within the wallet framework.
     Error {
@@ -237,6 +361,8 @@ impl Events {
         match self {
             Events::Pending { record }
             | Events::Reorg { record }
+            | Events::ReorgedOut { record }
+            | Events::Reaccepted { record }
             | Events::Stasis { record }
             | Events::Maturity { record }
             | Events::Discovery { record } => TransactionRecordNotification::new(self.kind(), record.clone()).into(),
@@ -253,6 +379,7 @@ pub enum EventKind {
     Disconnect,
     UtxoIndexNotEnabled,
     SyncState,
+    WalletSync,
     WalletStart,
     WalletHint,
     WalletOpen,
@@ -260,9 +387,12 @@ pub enum EventKind {
     WalletReload,
     WalletError,
     WalletClose,
+    WalletLocked,
     PrvKeyDataCreate,
     AccountActivation,
     AccountDeactivation,
+    AccountArchive,
+    AccountUnarchive,
     AccountSelection,
     AccountCreate,
     AccountUpdate,
@@ -273,11 +403,22 @@ pub enum EventKind {
     DaaScoreChange,
     Pending,
     Reorg,
+    ReorgedOut,
+    Reaccepted,
     Stasis,
     Maturity,
     Discovery,
     Balance,
     Metrics,
+    TaskDeadline,
+    FeeBump,
+    FeeBumpAbandoned,
+    Market,
+    PaymentBatchProgress,
+    GeneratorProgress,
+    PaymentReceived,
+    ScanInterrupted,
+    ScanResumed,
     Error,
 }
 
@@ -290,15 +431,19 @@ impl From<&Events> for EventKind {
             Events::Disconnect { .. } => EventKind::Disconnect,
             Events::UtxoIndexNotEnabled { .. } => EventKind::UtxoIndexNotEnabled,
             Events::SyncState { .. } => EventKind::SyncState,
+            Events::WalletSync { .. } => EventKind::WalletSync,
             Events::WalletHint { .. } => EventKind::WalletHint,
             Events::WalletOpen { .. } => EventKind::WalletOpen,
             Events::WalletCreate { .. } => EventKind::WalletCreate,
             Events::WalletReload { .. } => EventKind::WalletReload,
             Events::WalletError { .. } => EventKind::WalletError,
             Events::WalletClose => EventKind::WalletClose,
+            Events::WalletLocked { .. } => EventKind::WalletLocked,
             Events::PrvKeyDataCreate { .. } => EventKind::PrvKeyDataCreate,
             Events::AccountActivation { .. } => EventKind::AccountActivation,
             Events::AccountDeactivation { .. } => EventKind::AccountDeactivation,
+            Events::AccountArchive { .. } => EventKind::AccountArchive,
+            Events::AccountUnarchive { .. } => EventKind::AccountUnarchive,
             Events::AccountSelection { .. } => EventKind::AccountSelection,
             Events::AccountCreate { .. } => EventKind::AccountCreate,
             Events::AccountUpdate { .. } => EventKind::AccountUpdate,
@@ -309,11 +454,22 @@ impl From<&Events> for EventKind {
             Events::DaaScoreChange { .. } => EventKind::DaaScoreChange,
             Events::Pending { .. } => EventKind::Pending,
             Events::Reorg { .. } => EventKind::Reorg,
+            Events::ReorgedOut { .. } => EventKind::ReorgedOut,
+            Events::Reaccepted { .. } => EventKind::Reaccepted,
             Events::Stasis { .. } => EventKind::Stasis,
             Events::Maturity { .. } => EventKind::Maturity,
             Events::Discovery { .. } => EventKind::Discovery,
             Events::Balance { .. } => EventKind::Balance,
             Events::Metrics { .. } => EventKind::Metrics,
+            Events::TaskDeadline { .. } => EventKind::TaskDeadline,
+            Events::FeeBump { .. } => EventKind::FeeBump,
+            Events::FeeBumpAbandoned { .. } => EventKind::FeeBumpAbandoned,
+            Events::Market { .. } => EventKind::Market,
+            Events::PaymentBatchProgress { .. } => EventKind::PaymentBatchProgress,
+            Events::GeneratorProgress { .. } => EventKind::GeneratorProgress,
+            Events::PaymentReceived { .. } => EventKind::PaymentReceived,
+            Events::ScanInterrupted { .. } => EventKind::ScanInterrupted,
+            Events::ScanResumed { .. } => EventKind::ScanResumed,
             Events::Error { .. } => EventKind::Error,
         }
     }
@@ -328,6 +484,7 @@ impl FromStr for EventKind {
             "disconnect" => Ok(EventKind::Disconnect),
             "utxo-index-not-enabled" => Ok(EventKind::UtxoIndexNotEnabled),
             "sync-state" => Ok(EventKind::SyncState),
+            "wallet-sync" => Ok(EventKind::WalletSync),
             "wallet-start" => Ok(EventKind::WalletStart),
             "wallet-hint" => Ok(EventKind::WalletHint),
             "wallet-open" => Ok(EventKind::WalletOpen),
@@ -335,9 +492,12 @@ impl FromStr for EventKind {
             "wallet-reload" => Ok(EventKind::WalletReload),
             "wallet-error" => Ok(EventKind::WalletError),
             "wallet-close" => Ok(EventKind::WalletClose),
+            "wallet-locked" => Ok(EventKind::WalletLocked),
             "prv-key-data-create" => Ok(EventKind::PrvKeyDataCreate),
             "account-activation" => Ok(EventKind::AccountActivation),
             "account-deactivation" => Ok(EventKind::AccountDeactivation),
+            "account-archive" => Ok(EventKind::AccountArchive),
+            "account-unarchive" => Ok(EventKind::AccountUnarchive),
             "account-selection" => Ok(EventKind::AccountSelection),
             "account-create" => Ok(EventKind::AccountCreate),
             "account-update" => Ok(EventKind::AccountUpdate),
@@ -348,11 +508,22 @@ impl FromStr for EventKind {
             "daa-score-change" => Ok(EventKind::DaaScoreChange),
             "pending" => Ok(EventKind::Pending),
             "reorg" => Ok(EventKind::Reorg),
+            "reorged-out" => Ok(EventKind::ReorgedOut),
+            "reaccepted" => Ok(EventKind::Reaccepted),
             "stasis" => Ok(EventKind::Stasis),
             "maturity" => Ok(EventKind::Maturity),
             "discovery" => Ok(EventKind::Discovery),
             "balance" => Ok(EventKind::Balance),
             "metrics" => Ok(EventKind::Metrics),
+            "task-deadline" => Ok(EventKind::TaskDeadline),
+            "fee-bump" => Ok(EventKind::FeeBump),
+            "fee-bump-abandoned" => Ok(EventKind::FeeBumpAbandoned),
+            "market" => Ok(EventKind::Market),
+            "payment-batch-progress" => Ok(EventKind::PaymentBatchProgress),
+            "generator-progress" => Ok(EventKind::GeneratorProgress),
+            "payment-received" => Ok(EventKind::PaymentReceived),
+            "scan-interrupted" => Ok(EventKind::ScanInterrupted),
+            "scan-resumed" => Ok(EventKind::ScanResumed),
             "error" => Ok(EventKind::Error),
             _ => Err(Error::custom("Invalid event kind")),
         }
@@ -376,15 +547,19 @@ impl std::fmt::Display for EventKind {
             EventKind::Disconnect => "disconnect",
             EventKind::UtxoIndexNotEnabled => "utxo-index-not-enabled",
             EventKind::SyncState => "sync-state",
+            EventKind::WalletSync => "wallet-sync",
             EventKind::WalletHint => "wallet-hint",
             EventKind::WalletOpen => "wallet-open",
             EventKind::WalletCreate => "wallet-create",
             EventKind::WalletReload => "wallet-reload",
             EventKind::WalletError => "wallet-error",
             EventKind::WalletClose => "wallet-close",
+            EventKind::WalletLocked => "wallet-locked",
             EventKind::PrvKeyDataCreate => "prv-key-data-create",
             EventKind::AccountActivation => "account-activation",
             EventKind::AccountDeactivation => "account-deactivation",
+            EventKind::AccountArchive => "account-archive",
+            EventKind::AccountUnarchive => "account-unarchive",
             EventKind::AccountSelection => "account-selection",
             EventKind::AccountCreate => "account-create",
             EventKind::AccountUpdate => "account-update",
@@ -395,14 +570,77 @@ impl std::fmt::Display for EventKind {
             EventKind::DaaScoreChange => "daa-score-change",
             EventKind::Pending => "pending",
             EventKind::Reorg => "reorg",
+            EventKind::ReorgedOut => "reorged-out",
+            EventKind::Reaccepted => "reaccepted",
             EventKind::Stasis => "stasis",
             EventKind::Maturity => "maturity",
             EventKind::Discovery => "discovery",
             EventKind::Balance => "balance",
             EventKind::Metrics => "metrics",
+            EventKind::TaskDeadline => "task-deadline",
+            EventKind::FeeBump => "fee-bump",
+            EventKind::FeeBumpAbandoned => "fee-bump-abandoned",
+            EventKind::Market => "market",
+            EventKind::PaymentBatchProgress => "payment-batch-progress",
+            EventKind::GeneratorProgress => "generator-progress",
+            EventKind::PaymentReceived => "payment-received",
+            EventKind::ScanInterrupted => "scan-interrupted",
+            EventKind::ScanResumed => "scan-resumed",
             EventKind::Error => "error",
         };
 
         write!(f, "{str}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn events() -> Vec<Events> {
+        let wallet_descriptor = WalletDescriptor::new(Some("title".to_string()), "kaspa".to_string());
+        vec![
+            Events::WalletOpen { wallet_descriptor: Some(wallet_descriptor.clone()), account_descriptors: Some(vec![]) },
+            Events::WalletCreate { wallet_descriptor, storage_descriptor: StorageDescriptor::Internal("kaspa.wallet".to_string()) },
+            Events::SyncState { sync_state: SyncState::Headers { headers: 10, progress: 50 } },
+            Events::DaaScoreChange { current_daa_score: 1234 },
+            Events::Balance { balance: Some(Balance::new(1, 2, 3, 4, 5, 6)), id: UtxoContextId::default() },
+            Events::FeeBump {
+                account_id: AccountId::from_hex(&"ab".repeat(32)).unwrap(),
+                replaced_transaction_id: TransactionId::from_u64_word(1),
+                transaction_id: TransactionId::from_u64_word(2),
+                fees: 3000,
+            },
+            Events::WalletClose,
+        ]
+    }
+
+    #[test]
+    fn test_events_serde() {
+        for event in events() {
+            let json = serde_json::to_value(&event).unwrap();
+            assert_eq!(json["type"], event.kind(), "unexpected serialization: {json}");
+            let decoded: Events = serde_json::from_value(json.clone()).unwrap();
+            assert_eq!(serde_json::to_value(&decoded).unwrap(), json);
+
+            let decoded = Events::try_from_slice(&event.try_to_vec().unwrap()).unwrap();
+            assert_eq!(serde_json::to_value(&decoded).unwrap(), json);
+        }
+
+        let json = serde_json::to_value(&events()[1]).unwrap();
+        for key in ["walletDescriptor", "storageDescriptor"] {
+            assert!(json["data"].get(key).is_some(), "missing `{key}` in {json}");
+        }
+
+        // snake_case field names produced by earlier versions must remain readable
+        let legacy = serde_json::json!({
+            "type": "wallet-create",
+            "data": {
+                "wallet_descriptor": json["data"]["walletDescriptor"],
+                "storage_descriptor": json["data"]["storageDescriptor"],
+            },
+        });
+        let decoded: Events = serde_json::from_value(legacy).unwrap();
+        assert_eq!(serde_json::to_value(&decoded).unwrap(), json);
+    }
+}
@@ -55,5 +55,19 @@ pub(crate) async fn try_load_account(
 ) -> Result<Arc<dyn Account>> {
     let factory = factories().get(&storage.kind).ok_or_else(|| Error::AccountFactoryNotFound(storage.kind))?;
 
-    factory.try_load(wallet, &storage, meta).await
+    let account = factory.try_load(wallet, &storage, meta.clone()).await?;
+    if let Some(meta) = meta {
+        account.context().gap_limit = meta.gap_limit();
+        account.context().checkpoint = meta.checkpoint().cloned();
+        account.utxo_context().extend_address_usage(meta.address_usage().to_vec());
+        account.utxo_context().extend_payment_requests(meta.payment_requests().to_vec());
+        if !meta.locked_utxos().is_empty() {
+            account.utxo_context().lock(meta.locked_utxos()).await?;
+        }
+        for (outpoint, label) in meta.utxo_labels() {
+            account.utxo_context().set_label(std::slice::from_ref(outpoint), Some(label.clone()));
+        }
+    }
+
+    Ok(account)
 }
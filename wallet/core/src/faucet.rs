@@ -0,0 +1,71 @@
+//!
+//! Testnet faucet client.
+//!
+//! [`FaucetClient`] requests testnet funds for an address from a faucet
+//! HTTP endpoint. The faucet is expected to respond to a `GET` request
+//! to `<url>/<address>` with a JSON object optionally containing the
+//! id of the funding transaction (`transactionId`) and the granted
+//! amount in SOMPI (`amount`). Mainnet addresses are rejected.
+//!
+
+use crate::imports::*;
+
+/// Response of the faucet to a funding request.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FaucetResponse {
+    /// Id of the transaction funding the address (if reported by the faucet)
+    #[serde(default, alias = "txid")]
+    pub transaction_id: Option<TransactionId>,
+    /// Amount sent to the address in SOMPI (if reported by the faucet)
+    #[serde(default)]
+    pub amount: Option<u64>,
+}
+
+/// Client of a testnet faucet HTTP endpoint.
+#[derive(Debug, Clone)]
+pub struct FaucetClient {
+    url: String,
+}
+
+impl FaucetClient {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into().trim_end_matches('/').to_string() }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Request testnet funds for the `address`.
+    pub async fn request(&self, address: &Address) -> Result<FaucetResponse> {
+        if address.prefix == Prefix::Mainnet {
+            return Err(Error::Faucet(format!("faucet funds can not be requested for mainnet address {address}")));
+        }
+
+        let url = format!("{}/{}", self.url, address);
+        workflow_http::get_json::<FaucetResponse>(url).await.map_err(|err| Error::Faucet(format!("{}: {err}", self.url)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_faucet_response() {
+        let response: FaucetResponse =
+            serde_json::from_str(r#"{"txid":"0101010101010101010101010101010101010101010101010101010101010101","amount":100}"#)
+                .unwrap();
+        assert_eq!(response.transaction_id, Some(TransactionId::from_slice(&[1; 32])));
+        assert_eq!(response.amount, Some(100));
+        assert_eq!(serde_json::from_str::<FaucetResponse>("{}").unwrap(), FaucetResponse::default());
+    }
+
+    #[tokio::test]
+    async fn test_faucet_mainnet_address() {
+        let address = Address::new(Prefix::Mainnet, kaspa_addresses::Version::PubKey, &[0; 32]);
+        let result = FaucetClient::new("http://127.0.0.1:1/").request(&address).await;
+        assert!(matches!(result, Err(Error::Faucet(err)) if err.contains("mainnet")));
+    }
+}
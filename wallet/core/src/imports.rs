@@ -11,6 +11,7 @@ pub use crate::encryption::{Encryptable, EncryptionKind};
 pub use crate::error::Error;
 pub use crate::events::{EventKind, Events, SyncState};
 pub use crate::factory::{factories, Factory};
+pub use crate::market::{MarketData, MarketMonitor, PriceFeedKind};
 pub use crate::metrics::{MetricsUpdate, MetricsUpdateKind};
 pub use crate::result::Result;
 pub use crate::rpc::Rpc;
@@ -18,9 +19,9 @@ pub use crate::rpc::{DynRpcApi, RpcCtl};
 pub use crate::serializer::*;
 pub use crate::storage::*;
 pub use crate::tx::MassCombinationStrategy;
-pub use crate::utxo::balance::Balance;
+pub use crate::utxo::balance::{Balance, PendingIncoming};
 pub use crate::utxo::scan::{Scan, ScanExtent};
-pub use crate::utxo::{Maturity, NetworkParams, OutgoingTransaction, UtxoContext, UtxoEntryReference, UtxoProcessor};
+pub use crate::utxo::{Maturity, NetworkParams, OutgoingTransaction, PaymentRequest, UtxoContext, UtxoEntryReference, UtxoProcessor};
 pub use crate::wallet::*;
 pub use crate::{storage, utils};
 
@@ -82,6 +82,7 @@ extern crate self as kaspa_wallet_core;
 //         pub mod utils;
 //         pub mod utxo;
 //         pub mod wallet;
+pub mod webhooks;
 
 //     } else if #[cfg(any(feature = "wasm32-sdk", not(target_arch = "wasm32")))] {
 pub mod account;
@@ -94,15 +95,22 @@ pub mod encryption;
 pub mod error;
 pub mod events;
 pub mod factory;
+#[cfg(feature = "faucet")]
+pub mod faucet;
 mod imports;
+pub mod logging;
+pub mod market;
 pub mod message;
 pub mod metrics;
+pub mod ownership;
 pub mod prelude;
 pub mod result;
 pub mod rpc;
 pub mod serializer;
 pub mod settings;
+pub mod signin;
 pub mod storage;
+pub mod strength;
 pub mod tx;
 pub mod utils;
 pub mod utxo;
@@ -119,5 +127,4 @@ pub fn version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
 
-#[cfg(test)]
 pub mod tests;
@@ -0,0 +1,335 @@
+//!
+//! Wallet logging subsystem.
+//!
+//! Log messages produced by the wallet framework are attributed to a
+//! [`LogTarget`] (wallet subsystem) using the [`log!`](crate::logging::log)
+//! macro and filtered using the per-target levels of the [`LoggingConfig`].
+//! Messages that pass the filter are relayed to the `workflow_log` output
+//! along with the target name, allowing applications to capture them using
+//! a log sink (for example, the [`JsonLogSink`] in service deployments).
+//!
+//! The configuration is process-wide and can be changed at runtime using
+//! [`configure()`] or [`set_level()`] (also available via the wallet API
+//! `SetLogLevel` request).
+//!
+
+use crate::imports::*;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::atomic::AtomicU8;
+
+/// Wallet subsystem a log message is attributed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LogTarget {
+    /// Wallet runtime, accounts and transaction generation
+    Wallet,
+    /// UTXO processing and tracking
+    Utxo,
+    /// Node RPC connectivity
+    RpcClient,
+    /// Wallet and transaction storage
+    Storage,
+}
+
+impl LogTarget {
+    pub const ALL: [LogTarget; 4] = [LogTarget::Wallet, LogTarget::Utxo, LogTarget::RpcClient, LogTarget::Storage];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogTarget::Wallet => "wallet",
+            LogTarget::Utxo => "utxo",
+            LogTarget::RpcClient => "rpc-client",
+            LogTarget::Storage => "storage",
+        }
+    }
+}
+
+impl fmt::Display for LogTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for LogTarget {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        LogTarget::ALL
+            .into_iter()
+            .find(|target| target.as_str() == s)
+            .ok_or_else(|| Error::custom(format!("Invalid log target '{s}' (supported targets: wallet, utxo, rpc-client, storage)")))
+    }
+}
+
+/// Log level filter.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Off = 0,
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Off => "off",
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => LogLevel::Off,
+            1 => LogLevel::Error,
+            2 => LogLevel::Warn,
+            3 => LogLevel::Info,
+            4 => LogLevel::Debug,
+            _ => LogLevel::Trace,
+        }
+    }
+
+    /// Returns `true` if messages of the given `level` pass this filter.
+    pub fn is_enabled(&self, level: Level) -> bool {
+        *self as usize >= level as usize
+    }
+}
+
+impl From<LogLevel> for LevelFilter {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Off => LevelFilter::Off,
+            LogLevel::Error => LevelFilter::Error,
+            LogLevel::Warn => LevelFilter::Warn,
+            LogLevel::Info => LevelFilter::Info,
+            LogLevel::Debug => LevelFilter::Debug,
+            LogLevel::Trace => LevelFilter::Trace,
+        }
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for LogLevel {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "off" => Ok(LogLevel::Off),
+            "error" => Ok(LogLevel::Error),
+            "warn" => Ok(LogLevel::Warn),
+            "info" => Ok(LogLevel::Info),
+            "debug" => Ok(LogLevel::Debug),
+            "trace" => Ok(LogLevel::Trace),
+            _ => Err(Error::custom(format!("Invalid log level '{s}' (supported levels: off, error, warn, info, debug, trace)"))),
+        }
+    }
+}
+
+/// Logging configuration comprised of the default log level
+/// and per-target level overrides.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoggingConfig {
+    /// Level of the targets without a level override
+    pub level: LogLevel,
+    /// Per-target level overrides
+    #[serde(default)]
+    pub targets: BTreeMap<LogTarget, LogLevel>,
+}
+
+impl LoggingConfig {
+    pub fn new(level: LogLevel) -> Self {
+        Self { level, targets: BTreeMap::new() }
+    }
+
+    pub fn with_target_level(mut self, target: LogTarget, level: LogLevel) -> Self {
+        self.targets.insert(target, level);
+        self
+    }
+
+    /// Effective level of the `target`.
+    pub fn level(&self, target: LogTarget) -> LogLevel {
+        self.targets.get(&target).copied().unwrap_or(self.level)
+    }
+}
+
+/// Parses a comma-separated filter expression comprised of the default
+/// level and `<target>=<level>` overrides (for example `info,utxo=debug`).
+impl FromStr for LoggingConfig {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let mut config = LoggingConfig::default();
+        for directive in s.split(',').map(str::trim).filter(|directive| !directive.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level)) => {
+                    config.targets.insert(target.trim().parse()?, level.trim().parse()?);
+                }
+                None => config.level = directive.parse()?,
+            }
+        }
+        Ok(config)
+    }
+}
+
+impl fmt::Display for LoggingConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.level)?;
+        for (target, level) in self.targets.iter() {
+            write!(f, ",{target}={level}")?;
+        }
+        Ok(())
+    }
+}
+
+// effective levels of each target (indexed by `LogTarget`), kept
+// separately from the configuration to keep the log filtering lock-free
+static LEVELS: [AtomicU8; 4] = [
+    AtomicU8::new(LogLevel::Info as u8),
+    AtomicU8::new(LogLevel::Info as u8),
+    AtomicU8::new(LogLevel::Info as u8),
+    AtomicU8::new(LogLevel::Info as u8),
+];
+
+static CONFIG: Mutex<LoggingConfig> = Mutex::new(LoggingConfig { level: LogLevel::Info, targets: BTreeMap::new() });
+
+/// Apply the logging configuration. The `workflow_log` level filter is
+/// adjusted to pass messages of the most verbose configured level.
+pub fn configure(config: LoggingConfig) {
+    for target in LogTarget::ALL {
+        LEVELS[target as usize].store(config.level(target) as u8, Ordering::Relaxed);
+    }
+    let max_level = config.targets.values().copied().chain([config.level]).max().unwrap_or_default();
+    workflow_log::set_log_level(max_level.into());
+    *CONFIG.lock().unwrap() = config;
+}
+
+/// Current logging configuration.
+pub fn config() -> LoggingConfig {
+    CONFIG.lock().unwrap().clone()
+}
+
+/// Change the level of the `target` (or the default level if `target` is
+/// `None`), returning the resulting configuration.
+pub fn set_level(target: Option<LogTarget>, level: LogLevel) -> LoggingConfig {
+    let mut config = config();
+    match target {
+        Some(target) => {
+            config.targets.insert(target, level);
+        }
+        None => config.level = level,
+    }
+    configure(config.clone());
+    config
+}
+
+/// Returns `true` if messages of the `level` attributed to the `target` are logged.
+pub fn is_enabled(target: LogTarget, level: Level) -> bool {
+    LogLevel::from_u8(LEVELS[target as usize].load(Ordering::Relaxed)).is_enabled(level)
+}
+
+#[doc(hidden)]
+pub fn log_impl(target: LogTarget, level: Level, args: &fmt::Arguments<'_>) {
+    if is_enabled(target, level) {
+        let target = Some(target.as_str());
+        match level {
+            Level::Error => workflow_log::impls::error_impl(target, args),
+            Level::Warn => workflow_log::impls::warn_impl(target, args),
+            Level::Info => workflow_log::impls::info_impl(target, args),
+            Level::Debug => workflow_log::impls::debug_impl(target, args),
+            Level::Trace => workflow_log::impls::trace_impl(target, args),
+        }
+    }
+}
+
+/// Log a message attributed to a [`LogTarget`] with the given level,
+/// for example `log!(Utxo, Warn, "ignoring duplicate utxo entry")`.
+macro_rules! log {
+    ($target:ident, $level:ident, $($t:tt)*) => {
+        $crate::logging::log_impl($crate::logging::LogTarget::$target, workflow_log::Level::$level, &format_args!($($t)*))
+    };
+}
+
+pub(crate) use log;
+
+cfg_if! {
+    if #[cfg(not(target_arch = "wasm32"))] {
+        use std::io::Write;
+
+        /// Log sink writing each log message as a single-line JSON object
+        /// (`time` in unix milliseconds, `level`, `target` and `message`).
+        /// Install using `workflow_log::pipe()`.
+        pub struct JsonLogSink {
+            writer: Mutex<Box<dyn Write + Send>>,
+        }
+
+        impl JsonLogSink {
+            pub fn new(writer: Box<dyn Write + Send>) -> Self {
+                Self { writer: Mutex::new(writer) }
+            }
+
+            /// Create a sink writing to the standard output.
+            pub fn stdout() -> Self {
+                Self::new(Box::new(std::io::stdout()))
+            }
+
+            /// Create a sink appending to the file at `path`.
+            pub fn try_with_file(path: &std::path::Path) -> Result<Self> {
+                let file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+                Ok(Self::new(Box::new(file)))
+            }
+
+            fn format(target: Option<&str>, level: Level, args: &fmt::Arguments<'_>) -> String {
+                serde_json::json!({
+                    "time": workflow_core::time::unixtime_as_millis_u64(),
+                    "level": level.as_str().to_lowercase(),
+                    "target": target,
+                    "message": args.to_string(),
+                })
+                .to_string()
+            }
+        }
+
+        impl workflow_log::Sink for JsonLogSink {
+            fn write(&self, target: Option<&str>, level: Level, args: &fmt::Arguments<'_>) -> bool {
+                let mut writer = self.writer.lock().unwrap();
+                writeln!(writer, "{}", Self::format(target, level, args)).and_then(|_| writer.flush()).is_ok()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_logging_config() -> Result<()> {
+        let config = "debug, utxo=trace,storage=off".parse::<LoggingConfig>()?;
+        assert_eq!(config.level(LogTarget::Wallet), LogLevel::Debug);
+        assert_eq!(config.level(LogTarget::Utxo), LogLevel::Trace);
+        assert_eq!(config.level(LogTarget::Storage), LogLevel::Off);
+        assert_eq!(config.to_string().parse::<LoggingConfig>()?, config);
+        assert!("utxo=verbose".parse::<LoggingConfig>().is_err());
+        assert!("mempool=info".parse::<LoggingConfig>().is_err());
+
+        assert!(LogLevel::Warn.is_enabled(Level::Error));
+        assert!(!LogLevel::Warn.is_enabled(Level::Info));
+        assert!(!LogLevel::Off.is_enabled(Level::Error));
+
+        Ok(())
+    }
+}
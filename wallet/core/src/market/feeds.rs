@@ -0,0 +1,161 @@
+//!
+//! [`PriceFeed`] implementations using public market data APIs.
+//!
+
+use crate::imports::*;
+use crate::market::{MarketData, MarketPrice, PriceFeed};
+use serde_json::Value;
+use workflow_core::time::unixtime_as_millis_u64;
+
+async fn get_json(feed: &str, url: &str) -> Result<Value> {
+    workflow_http::get_json::<Value>(url).await.map_err(|err| Error::PriceFeed(format!("{feed}: {err}")))
+}
+
+/// [`PriceFeed`] using the CoinGecko `simple/price` API.
+#[derive(Debug, Clone)]
+pub struct CoinGecko {
+    url: String,
+}
+
+impl CoinGecko {
+    pub const DEFAULT_URL: &'static str = "https://api.coingecko.com/api/v3";
+
+    /// Create the feed using a custom API url (i.e. a proxy or a paid API plan).
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into() }
+    }
+
+    fn parse(value: &Value, currencies: &[String]) -> Result<Vec<MarketPrice>> {
+        let quotes = value.get("kaspa").ok_or_else(|| Error::PriceFeed("coingecko: missing `kaspa` quotes".to_string()))?;
+        let prices = currencies
+            .iter()
+            .filter_map(|currency| {
+                quotes.get(currency).and_then(Value::as_f64).map(|price| MarketPrice {
+                    currency: currency.clone(),
+                    price,
+                    market_cap: quotes.get(format!("{currency}_market_cap")).and_then(Value::as_f64),
+                    change_24h: quotes.get(format!("{currency}_24h_change")).and_then(Value::as_f64),
+                })
+            })
+            .collect();
+        Ok(prices)
+    }
+}
+
+impl Default for CoinGecko {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_URL)
+    }
+}
+
+#[async_trait]
+impl PriceFeed for CoinGecko {
+    fn name(&self) -> &'static str {
+        "coingecko"
+    }
+
+    async fn fetch(&self, currencies: &[String]) -> Result<MarketData> {
+        let url = format!(
+            "{}/simple/price?ids=kaspa&vs_currencies={}&include_market_cap=true&include_24hr_change=true",
+            self.url,
+            currencies.join(",")
+        );
+        let value = get_json(self.name(), &url).await?;
+        Ok(MarketData {
+            source: self.name().to_string(),
+            unixtime_msec: unixtime_as_millis_u64(),
+            prices: Self::parse(&value, currencies)?,
+        })
+    }
+}
+
+/// [`PriceFeed`] using the CoinPaprika `tickers` API.
+#[derive(Debug, Clone)]
+pub struct CoinPaprika {
+    url: String,
+}
+
+impl CoinPaprika {
+    pub const DEFAULT_URL: &'static str = "https://api.coinpaprika.com/v1";
+
+    /// Create the feed using a custom API url.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into() }
+    }
+
+    fn parse(value: &Value, currencies: &[String]) -> Result<Vec<MarketPrice>> {
+        let quotes = value.get("quotes").ok_or_else(|| Error::PriceFeed("coinpaprika: missing ticker quotes".to_string()))?;
+        let prices = currencies
+            .iter()
+            .filter_map(|currency| {
+                let quote = quotes.get(currency.to_uppercase())?;
+                quote.get("price").and_then(Value::as_f64).map(|price| MarketPrice {
+                    currency: currency.clone(),
+                    price,
+                    market_cap: quote.get("market_cap").and_then(Value::as_f64),
+                    change_24h: quote.get("percent_change_24h").and_then(Value::as_f64),
+                })
+            })
+            .collect();
+        Ok(prices)
+    }
+}
+
+impl Default for CoinPaprika {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_URL)
+    }
+}
+
+#[async_trait]
+impl PriceFeed for CoinPaprika {
+    fn name(&self) -> &'static str {
+        "coinpaprika"
+    }
+
+    async fn fetch(&self, currencies: &[String]) -> Result<MarketData> {
+        let url = format!("{}/tickers/kas-kaspa?quotes={}", self.url, currencies.join(",").to_uppercase());
+        let value = get_json(self.name(), &url).await?;
+        Ok(MarketData {
+            source: self.name().to_string(),
+            unixtime_msec: unixtime_as_millis_u64(),
+            prices: Self::parse(&value, currencies)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_feed_parse() {
+        let currencies = vec!["usd".to_string(), "eur".to_string(), "xyz".to_string()];
+
+        let value = serde_json::json!({
+            "kaspa": { "usd": 0.12, "usd_market_cap": 3000000000.0, "usd_24h_change": -1.5, "eur": 0.11 }
+        });
+        let prices = CoinGecko::parse(&value, &currencies).unwrap();
+        assert_eq!(prices.len(), 2);
+        assert_eq!(
+            prices[0],
+            MarketPrice { currency: "usd".to_string(), price: 0.12, market_cap: Some(3000000000.0), change_24h: Some(-1.5) }
+        );
+        assert_eq!(prices[1], MarketPrice { currency: "eur".to_string(), price: 0.11, market_cap: None, change_24h: None });
+        assert!(CoinGecko::parse(&serde_json::json!({}), &currencies).is_err());
+
+        let value = serde_json::json!({
+            "id": "kas-kaspa",
+            "quotes": { "USD": { "price": 0.12, "market_cap": 3000000000u64, "percent_change_24h": 2.25 } }
+        });
+        let prices = CoinPaprika::parse(&value, &currencies).unwrap();
+        assert_eq!(
+            prices,
+            vec![MarketPrice { currency: "usd".to_string(), price: 0.12, market_cap: Some(3000000000.0), change_24h: Some(2.25) }]
+        );
+
+        let data = MarketData { source: "test".to_string(), unixtime_msec: 0, prices };
+        assert_eq!(data.sompi_to_fiat(250_000_000, "USD"), Some(0.3));
+        assert_eq!(data.sompi_to_fiat(250_000_000, "eur"), None);
+    }
+}
@@ -0,0 +1,125 @@
+//!
+//! Fiat market price feeds.
+//!
+//! A [`PriceFeed`] fetches the KAS market price denominated in one or more
+//! fiat currencies from a public market data API. The wallet runtime polls
+//! the configured feed via the [`MarketMonitor`], caches the last obtained
+//! [`MarketData`] and posts [`Events::Market`] notifications, allowing
+//! clients to display fiat-denominated amounts.
+//!
+
+pub mod feeds;
+pub mod monitor;
+pub use feeds::*;
+pub use monitor::*;
+
+use crate::imports::*;
+use kaspa_consensus_core::constants::SOMPI_PER_KASPA;
+
+/// KAS market price in a single fiat currency.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketPrice {
+    /// Lowercase currency code (e.g. `usd`)
+    pub currency: String,
+    /// Price of 1 KAS
+    pub price: f64,
+    /// Market capitalization (if provided by the feed)
+    pub market_cap: Option<f64>,
+    /// Price change over the last 24 hours in percent (if provided by the feed)
+    pub change_24h: Option<f64>,
+}
+
+/// Market prices obtained from a [`PriceFeed`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketData {
+    /// Name of the price feed the data was obtained from
+    pub source: String,
+    /// Time at which the data was obtained (in milliseconds)
+    pub unixtime_msec: u64,
+    pub prices: Vec<MarketPrice>,
+}
+
+impl MarketData {
+    pub fn price(&self, currency: &str) -> Option<&MarketPrice> {
+        self.prices.iter().find(|price| price.currency.eq_ignore_ascii_case(currency))
+    }
+
+    /// Converts the amount in SOMPI into the fiat currency.
+    pub fn sompi_to_fiat(&self, sompi: u64, currency: &str) -> Option<f64> {
+        self.price(currency).map(|price| sompi as f64 / SOMPI_PER_KASPA as f64 * price.price)
+    }
+}
+
+/// Source of the KAS market prices.
+#[async_trait]
+pub trait PriceFeed: Send + Sync {
+    /// Name of the price feed.
+    fn name(&self) -> &'static str;
+
+    /// Fetch the KAS market price in the supplied (lowercase) currencies.
+    /// Currencies not supported by the feed are omitted from the result.
+    async fn fetch(&self, currencies: &[String]) -> Result<MarketData>;
+}
+
+/// Built-in [`PriceFeed`] implementations.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PriceFeedKind {
+    /// CoinGecko public API
+    CoinGecko,
+    /// CoinPaprika public API
+    CoinPaprika,
+}
+
+impl PriceFeedKind {
+    pub fn list() -> [PriceFeedKind; 2] {
+        [PriceFeedKind::CoinGecko, PriceFeedKind::CoinPaprika]
+    }
+
+    /// Create the price feed.
+    pub fn feed(&self) -> Arc<dyn PriceFeed> {
+        match self {
+            PriceFeedKind::CoinGecko => Arc::new(CoinGecko::default()),
+            PriceFeedKind::CoinPaprika => Arc::new(CoinPaprika::default()),
+        }
+    }
+}
+
+impl std::fmt::Display for PriceFeedKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            PriceFeedKind::CoinGecko => "coingecko",
+            PriceFeedKind::CoinPaprika => "coinpaprika",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl FromStr for PriceFeedKind {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "coingecko" => Ok(PriceFeedKind::CoinGecko),
+            "coinpaprika" => Ok(PriceFeedKind::CoinPaprika),
+            _ => Err(Error::InvalidPriceFeed(s.to_string())),
+        }
+    }
+}
+
+/// Validates the currency codes, converting them to lowercase
+/// and removing duplicates.
+pub fn normalize_currencies<S: AsRef<str>>(currencies: &[S]) -> Result<Vec<String>> {
+    let mut normalized = Vec::<String>::with_capacity(currencies.len());
+    for currency in currencies {
+        let currency = currency.as_ref().trim().to_lowercase();
+        if currency.is_empty() || currency.len() > 8 || !currency.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(Error::InvalidArgument(format!("invalid currency code '{currency}'")));
+        }
+        if !normalized.contains(&currency) {
+            normalized.push(currency);
+        }
+    }
+    Ok(normalized)
+}
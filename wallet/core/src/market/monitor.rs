@@ -0,0 +1,164 @@
+//!
+//! Market monitor periodically polling the configured [`PriceFeed`].
+//!
+
+use crate::imports::*;
+use crate::logging::log;
+use crate::market::{MarketData, PriceFeed};
+use workflow_core::channel::Channel;
+
+/// Default market data polling interval.
+pub const DEFAULT_MARKET_POLL_INTERVAL: Duration = Duration::from_secs(5 * 60);
+/// Minimum market data polling interval (public APIs are rate-limited).
+pub const MIN_MARKET_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+struct Inner {
+    multiplexer: Multiplexer<Box<Events>>,
+    feed: Mutex<Option<Arc<dyn PriceFeed>>>,
+    currencies: Mutex<Vec<String>>,
+    interval: Mutex<Duration>,
+    data: Mutex<Option<MarketData>>,
+    // incremented on each configuration change, discarding
+    // results of fetches issued under the previous configuration
+    generation: AtomicU64,
+    running: AtomicBool,
+    task_ctl: DuplexChannel,
+    update: Channel<()>,
+}
+
+/// Polls the configured [`PriceFeed`], caches the last obtained
+/// [`MarketData`] and posts [`Events::Market`] notifications.
+/// The monitor is idle until a price feed is configured.
+#[derive(Clone)]
+pub struct MarketMonitor {
+    inner: Arc<Inner>,
+}
+
+impl MarketMonitor {
+    pub fn new(multiplexer: Multiplexer<Box<Events>>) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                multiplexer,
+                feed: Mutex::new(None),
+                currencies: Mutex::new(vec!["usd".to_string()]),
+                interval: Mutex::new(DEFAULT_MARKET_POLL_INTERVAL),
+                data: Mutex::new(None),
+                generation: AtomicU64::new(0),
+                running: AtomicBool::new(false),
+                task_ctl: DuplexChannel::oneshot(),
+                update: Channel::bounded(1),
+            }),
+        }
+    }
+
+    /// Returns the configured price feed, `None` if the monitor is disabled.
+    pub fn feed(&self) -> Option<Arc<dyn PriceFeed>> {
+        self.inner.feed.lock().unwrap().clone()
+    }
+
+    pub fn currencies(&self) -> Vec<String> {
+        self.inner.currencies.lock().unwrap().clone()
+    }
+
+    pub fn interval(&self) -> Duration {
+        *self.inner.interval.lock().unwrap()
+    }
+
+    /// Returns the last obtained market data.
+    pub fn data(&self) -> Option<MarketData> {
+        self.inner.data.lock().unwrap().clone()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.inner.running.load(Ordering::SeqCst)
+    }
+
+    /// Configure the price feed (`None` disables the monitor), the fiat
+    /// currencies and the polling interval (clamped to [`MIN_MARKET_POLL_INTERVAL`]).
+    /// The cached market data is discarded and, if the monitor is running,
+    /// the market data is fetched immediately.
+    pub fn configure(&self, feed: Option<Arc<dyn PriceFeed>>, currencies: &[String], interval: Option<Duration>) -> Result<()> {
+        let currencies = crate::market::normalize_currencies(currencies)?;
+        if feed.is_some() && currencies.is_empty() {
+            return Err(Error::InvalidArgument("at least one currency is required".to_string()));
+        }
+
+        self.inner.generation.fetch_add(1, Ordering::SeqCst);
+        *self.inner.feed.lock().unwrap() = feed;
+        *self.inner.currencies.lock().unwrap() = currencies;
+        *self.inner.interval.lock().unwrap() = interval.unwrap_or(DEFAULT_MARKET_POLL_INTERVAL).max(MIN_MARKET_POLL_INTERVAL);
+        self.inner.data.lock().unwrap().take();
+        self.update();
+
+        Ok(())
+    }
+
+    /// Request the market data to be fetched immediately.
+    pub fn update(&self) {
+        // the channel holds a single pending request
+        self.inner.update.try_send(()).ok();
+    }
+
+    /// Fetch the market data from the configured price feed, updating
+    /// the cache and posting [`Events::Market`]. Returns `None` if the
+    /// price feed is not configured.
+    pub async fn fetch(&self) -> Result<Option<MarketData>> {
+        let Some(feed) = self.feed() else {
+            return Ok(None);
+        };
+
+        let generation = self.inner.generation.load(Ordering::SeqCst);
+        let market = feed.fetch(&self.currencies()).await?;
+        if generation == self.inner.generation.load(Ordering::SeqCst) {
+            self.inner.data.lock().unwrap().replace(market.clone());
+            self.inner
+                .multiplexer
+                .try_broadcast(Box::new(Events::Market { market: market.clone() }))
+                .map_err(|_| Error::Custom("multiplexer channel error during market update".to_string()))?;
+        }
+
+        Ok(Some(market))
+    }
+
+    pub async fn start(&self) -> Result<()> {
+        if self.is_running() {
+            return Ok(());
+        }
+
+        let this = self.clone();
+        this.inner.running.store(true, Ordering::SeqCst);
+        let task_ctl_receiver = self.inner.task_ctl.request.receiver.clone();
+        let task_ctl_sender = self.inner.task_ctl.response.sender.clone();
+        let update_receiver = self.inner.update.receiver.clone();
+
+        spawn(async move {
+            loop {
+                if let Err(err) = this.fetch().await {
+                    log!(Wallet, Warn, "Market monitor: {err}");
+                }
+
+                select! {
+                    _ = task_ctl_receiver.recv().fuse() => {
+                        break;
+                    },
+
+                    _ = update_receiver.recv().fuse() => { },
+
+                    _ = sleep(this.interval()).fuse() => { },
+                }
+            }
+
+            this.inner.running.store(false, Ordering::SeqCst);
+            task_ctl_sender.send(()).await.unwrap();
+        });
+
+        Ok(())
+    }
+
+    pub async fn stop(&self) -> Result<()> {
+        if self.is_running() {
+            self.inner.task_ctl.signal(()).await.expect("MarketMonitor::stop() `signal` error");
+        }
+        Ok(())
+    }
+}
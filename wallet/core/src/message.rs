@@ -2,6 +2,7 @@
 //! Message signing and verification functions.
 //!
 
+use kaspa_addresses::{Address, Version};
 use kaspa_hashes::{Hash, PersonalMessageSigningHash};
 use secp256k1::{Error, XOnlyPublicKey};
 
@@ -37,6 +38,24 @@ pub fn verify_message(msg: &PersonalMessage, signature: &Vec<u8>, pubkey: &XOnly
     sig.verify(&msg, pubkey)
 }
 
+/// Verifies a hex-encoded message `signature` against the public key of a `PubKey` `address`.
+///
+/// Produces `Ok(())` if the signature is valid, [`Error::InvalidMessageSignature`](crate::error::Error::InvalidMessageSignature)
+/// if the signature does not match the message or [`Error::MessageSigningAddressVersion`](crate::error::Error::MessageSigningAddressVersion)
+/// if the address is not a `PubKey` address.
+///
+pub fn verify_message_with_address(msg: &PersonalMessage, signature: &str, address: &Address) -> crate::result::Result<()> {
+    if address.version != Version::PubKey {
+        return Err(crate::error::Error::MessageSigningAddressVersion);
+    }
+
+    let pubkey = XOnlyPublicKey::from_slice(&address.payload[0..32])?;
+    let mut signature_bytes = [0u8; 64];
+    faster_hex::hex_decode(signature.as_bytes(), &mut signature_bytes).map_err(|_| crate::error::Error::InvalidMessageSignature)?;
+
+    verify_message(msg, &signature_bytes.to_vec(), &pubkey).map_err(|_| crate::error::Error::InvalidMessageSignature)
+}
+
 fn calc_personal_message_hash(msg: &PersonalMessage) -> Hash {
     let mut hasher = PersonalMessageSigningHash::new();
     hasher.write(msg);
@@ -255,4 +274,31 @@ Ut omnis magnam et accusamus earum rem impedit provident eum commodi repellat qu
 
         verify_message(&pm, &sig_result, &pubkey).expect("verify_message failed");
     }
+
+    #[test]
+    fn test_verify_message_with_address() {
+        let pm = PersonalMessage("Hello Kaspa!");
+        let privkey: [u8; 32] = [
+            0xB7, 0xE1, 0x51, 0x62, 0x8A, 0xED, 0x2A, 0x6A, 0xBF, 0x71, 0x58, 0x80, 0x9C, 0xF4, 0xF3, 0xC7, 0x62, 0xE7, 0x16, 0x0F,
+            0x38, 0xB4, 0xDA, 0x56, 0xA7, 0x84, 0xD9, 0x04, 0x51, 0x90, 0xCF, 0xEF,
+        ];
+        let keypair = secp256k1::Keypair::from_seckey_slice(secp256k1::SECP256K1, &privkey).unwrap();
+        let (xonly, _) = keypair.x_only_public_key();
+        let address = Address::new(kaspa_addresses::Prefix::Mainnet, Version::PubKey, &xonly.serialize());
+
+        let signature = faster_hex::hex_string(&sign_message(&pm, &privkey).expect("sign_message failed"));
+        verify_message_with_address(&pm, &signature, &address).expect("verify_message_with_address failed");
+
+        let other = PersonalMessage("Not Hello Kaspa!");
+        assert!(matches!(
+            verify_message_with_address(&other, &signature, &address),
+            Err(crate::error::Error::InvalidMessageSignature)
+        ));
+
+        let ecdsa_address = Address::new(kaspa_addresses::Prefix::Mainnet, Version::PubKeyECDSA, &[0u8; 33]);
+        assert!(matches!(
+            verify_message_with_address(&pm, &signature, &ecdsa_address),
+            Err(crate::error::Error::MessageSigningAddressVersion)
+        ));
+    }
 }
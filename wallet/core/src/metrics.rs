@@ -1,4 +1,5 @@
 use crate::imports::*;
+use crate::tx::GeneratorProfile;
 // use kaspa_metrics_core::MetricsSnapshot;
 
 #[derive(Debug, Clone, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
@@ -13,6 +14,16 @@ pub enum MetricsUpdate {
         #[serde(rename = "networkTPS")]
         network_tps: f64,
     },
+    /// Transaction generator stage timings aggregated since the
+    /// [`UtxoProcessor`](crate::utxo::UtxoProcessor) was created
+    /// (populated only with the `generator-profile` feature).
+    GeneratorMetrics { profile: GeneratorProfile },
+    /// Account payload deserialization counters aggregated since
+    /// the process start (see [`AccountPayloadMetrics`]).
+    StorageMetrics {
+        #[serde(rename = "accountPayloads")]
+        account_payloads: AccountPayloadMetrics,
+    },
     // NodeMetrics {
     //     snapshot : Box<MetricsSnapshot>
     // }
@@ -21,6 +32,8 @@ pub enum MetricsUpdate {
 #[derive(Debug, Clone, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 pub enum MetricsUpdateKind {
     WalletMetrics,
+    GeneratorMetrics,
+    StorageMetrics,
     // NodeMetrics
 }
 
@@ -28,6 +41,8 @@ impl MetricsUpdate {
     pub fn kind(&self) -> MetricsUpdateKind {
         match self {
             MetricsUpdate::WalletMetrics { .. } => MetricsUpdateKind::WalletMetrics,
+            MetricsUpdate::GeneratorMetrics { .. } => MetricsUpdateKind::GeneratorMetrics,
+            MetricsUpdate::StorageMetrics { .. } => MetricsUpdateKind::StorageMetrics,
             // MetricsUpdate::NodeMetrics { .. } => MetricsUpdateKind::NodeMetrics
         }
     }
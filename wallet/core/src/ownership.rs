@@ -0,0 +1,180 @@
+//!
+//! Account xpub ownership proofs.
+//!
+//! When an account extended public key (xpub) is shared with a third-party
+//! service (e.g. for watch-only mirroring), the wallet attaches an
+//! [`XPubOwnershipProof`] - a [personal message](crate::message) signature
+//! over the xpub and a timestamp, created with the private key of the first
+//! receive address of the account. The service verifies the proof against
+//! the first receive address derived from the supplied xpub, which prevents
+//! the xpub from being substituted in transit.
+//!
+
+use crate::error::Error;
+use crate::message::{sign_message, verify_message, PersonalMessage};
+use crate::result::Result;
+use borsh::{BorshDeserialize, BorshSerialize};
+use kaspa_addresses::{Address, Prefix, Version};
+use kaspa_bip32::{AddressType, ExtendedPublicKey};
+use kaspa_wallet_keys::derivation::gen1::{PubkeyDerivationManager, WalletDerivationManager};
+use secp256k1::XOnlyPublicKey;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use workflow_core::time::unixtime_as_millis_u64;
+
+/// Proof message header, used for domain separation
+/// from arbitrary personal messages.
+const HEADER: &str = "Kaspa xpub ownership proof:";
+
+/// Tolerated clock difference between the signing wallet
+/// and the verifying party (1 minute).
+pub const OWNERSHIP_PROOF_CLOCK_SKEW_MSEC: u64 = 60 * 1000;
+
+/// Extended public key accompanied by a proof that the key
+/// holder controls the corresponding private keys.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XPubOwnershipProof {
+    /// Account extended public key.
+    pub xpub: String,
+    /// First receive address derived from the xpub (signing key address).
+    pub address: Address,
+    /// Proof creation time (unix time in milliseconds).
+    pub timestamp: u64,
+    /// Hex-encoded Schnorr signature of the proof message.
+    pub signature: String,
+}
+
+impl XPubOwnershipProof {
+    /// Create a proof for `xpub` signed with `privkey`, the private key of
+    /// the first receive address derived from the xpub.
+    pub fn try_new(xpub: &str, prefix: Prefix, privkey: &[u8; 32]) -> Result<Self> {
+        Self::try_new_with_timestamp(xpub, prefix, unixtime_as_millis_u64(), privkey)
+    }
+
+    /// Create a proof for `xpub` with the given `timestamp` (unix time in milliseconds).
+    pub fn try_new_with_timestamp(xpub: &str, prefix: Prefix, timestamp: u64, privkey: &[u8; 32]) -> Result<Self> {
+        let address = Self::derive_address(xpub, prefix)?;
+
+        let keypair = secp256k1::Keypair::from_seckey_slice(secp256k1::SECP256K1, privkey)?;
+        let (xonly, _) = keypair.x_only_public_key();
+        if xonly.serialize().as_slice() != address.payload.as_slice() {
+            return Err(Error::InvalidOwnershipProof("signing key does not belong to the xpub".to_string()));
+        }
+
+        let message = Self::make_message(xpub, &address, timestamp);
+        let signature = sign_message(&PersonalMessage(&message), privkey)?;
+
+        Ok(Self { xpub: xpub.to_string(), address, timestamp, signature: faster_hex::hex_string(signature.as_slice()) })
+    }
+
+    /// Derive the first receive address (signing key address) of `xpub`.
+    pub fn derive_address(xpub: &str, prefix: Prefix) -> Result<Address> {
+        let xpub = ExtendedPublicKey::<secp256k1::PublicKey>::from_str(xpub)
+            .map_err(|err| Error::InvalidOwnershipProof(format!("invalid xpub: {err}")))?;
+        let receive = WalletDerivationManager::derive_child_pubkey_manager(xpub, AddressType::Receive, None)?;
+        Ok(PubkeyDerivationManager::create_address(&receive.derive_pubkey(0)?, prefix, false)?)
+    }
+
+    fn make_message(xpub: &str, address: &Address, timestamp: u64) -> String {
+        [HEADER.to_string(), xpub.to_string(), format!("Address: {address}"), format!("Timestamp: {timestamp}")].join("\n")
+    }
+
+    /// Produce the textual message signed by the wallet.
+    pub fn to_message(&self) -> String {
+        Self::make_message(&self.xpub, &self.address, self.timestamp)
+    }
+
+    /// Verify the proof. If `max_age_msec` is supplied, proofs
+    /// older than `max_age_msec` milliseconds are rejected.
+    pub fn verify(&self, max_age_msec: Option<u64>) -> Result<()> {
+        self.verify_at(max_age_msec, unixtime_as_millis_u64())
+    }
+
+    /// Verify the proof at the given time `now` (unix time in milliseconds).
+    pub fn verify_at(&self, max_age_msec: Option<u64>, now: u64) -> Result<()> {
+        if self.address.version != Version::PubKey {
+            return Err(Error::InvalidOwnershipProof("only PubKey addresses are supported".to_string()));
+        }
+        if Self::derive_address(&self.xpub, self.address.prefix)? != self.address {
+            return Err(Error::InvalidOwnershipProof("address does not belong to the xpub".to_string()));
+        }
+        if self.timestamp > now + OWNERSHIP_PROOF_CLOCK_SKEW_MSEC {
+            return Err(Error::InvalidOwnershipProof("proof timestamp is in the future".to_string()));
+        }
+        if let Some(max_age_msec) = max_age_msec {
+            if now > self.timestamp + max_age_msec {
+                return Err(Error::OwnershipProofExpired);
+            }
+        }
+
+        let pubkey = XOnlyPublicKey::from_slice(&self.address.payload[0..32])?;
+        let mut signature_bytes = [0u8; 64];
+        faster_hex::hex_decode(self.signature.as_bytes(), &mut signature_bytes)
+            .map_err(|_| Error::InvalidOwnershipProof("invalid signature encoding".to_string()))?;
+
+        let message = self.to_message();
+        verify_message(&PersonalMessage(&message), &signature_bytes.to_vec(), &pubkey)
+            .map_err(|_| Error::InvalidOwnershipProofSignature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_bip32::{ChildNumber, ExtendedPrivateKey, Prefix as KeyPrefix, SecretKey};
+
+    fn account_keys(seed: u8) -> (String, [u8; 32]) {
+        let xprv = ExtendedPrivateKey::<SecretKey>::new([seed; 64]).unwrap();
+        let xprv = xprv.derive_path(&"m/44'/111111'/0'".parse().unwrap()).unwrap();
+        let xpub = xprv.public_key().to_string(Some(KeyPrefix::KPUB));
+        let receive = xprv.derive_child(ChildNumber::new(0, false).unwrap()).unwrap();
+        let receive = receive.derive_child(ChildNumber::new(0, false).unwrap()).unwrap();
+        (xpub, receive.private_key().secret_bytes())
+    }
+
+    #[test]
+    fn test_xpub_ownership_proof() {
+        let (xpub, privkey) = account_keys(1);
+        let proof = XPubOwnershipProof::try_new(&xpub, Prefix::Mainnet, &privkey).unwrap();
+        proof.verify(Some(60_000)).unwrap();
+
+        let json = serde_json::to_string(&proof).unwrap();
+        let proof: XPubOwnershipProof = serde_json::from_str(&json).unwrap();
+        proof.verify_at(None, proof.timestamp).unwrap();
+    }
+
+    #[test]
+    fn test_xpub_ownership_proof_rejections() {
+        let (xpub, privkey) = account_keys(1);
+        let (other_xpub, other_privkey) = account_keys(2);
+
+        // signing key must belong to the xpub
+        assert!(matches!(XPubOwnershipProof::try_new(&xpub, Prefix::Mainnet, &other_privkey), Err(Error::InvalidOwnershipProof(_))));
+
+        let proof = XPubOwnershipProof::try_new_with_timestamp(&xpub, Prefix::Mainnet, 1_000_000, &privkey).unwrap();
+        let now = proof.timestamp + 1;
+
+        // substituted xpub
+        let mut substituted = proof.clone();
+        substituted.xpub = other_xpub.clone();
+        assert!(matches!(substituted.verify_at(None, now), Err(Error::InvalidOwnershipProof(_))));
+
+        // substituted xpub along with its address
+        let mut substituted = proof.clone();
+        substituted.xpub = other_xpub.clone();
+        substituted.address = XPubOwnershipProof::derive_address(&other_xpub, Prefix::Mainnet).unwrap();
+        assert!(matches!(substituted.verify_at(None, now), Err(Error::InvalidOwnershipProofSignature)));
+
+        // altered timestamp
+        let mut altered = proof.clone();
+        altered.timestamp += 1;
+        assert!(matches!(altered.verify_at(None, now), Err(Error::InvalidOwnershipProofSignature)));
+
+        assert!(matches!(proof.verify_at(Some(1_000), now + 1_000), Err(Error::OwnershipProofExpired)));
+        assert!(matches!(
+            proof.verify_at(None, proof.timestamp - OWNERSHIP_PROOF_CLOCK_SKEW_MSEC - 1),
+            Err(Error::InvalidOwnershipProof(_))
+        ));
+    }
+}
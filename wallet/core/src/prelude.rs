@@ -6,17 +6,22 @@
 pub use crate::account::descriptor::AccountDescriptor;
 pub use crate::account::{Account, AccountKind};
 pub use crate::api::*;
+pub use crate::derivation::DerivationPathTemplate;
 pub use crate::deterministic::{AccountId, AccountStorageKey};
 pub use crate::encryption::EncryptionKind;
 pub use crate::events::{Events, SyncState};
+pub use crate::logging::{LogLevel, LogTarget, LoggingConfig};
+pub use crate::market::{MarketData, MarketPrice, PriceFeed, PriceFeedKind};
 pub use crate::metrics::{MetricsUpdate, MetricsUpdateKind};
 pub use crate::rpc::{ConnectOptions, ConnectStrategy, DynRpcApi};
 pub use crate::settings::WalletSettings;
 pub use crate::storage::{IdT, Interface, PrvKeyDataId, PrvKeyDataInfo, TransactionId, TransactionRecord, WalletDescriptor};
-pub use crate::tx::{Fees, PaymentDestination, PaymentOutput, PaymentOutputs};
+pub use crate::strength::{estimate_secret_strength, SecretStrength, SecretStrengthPolicy, MAXIMUM_SECRET_STRENGTH};
+pub use crate::tx::{ChangePolicy, CoinControl, Fees, PaymentDestination, PaymentOutput, PaymentOutputs, PaymentUri};
+pub use crate::utils::{Amount, AmountFormat, AmountUnit};
 pub use crate::utxo::balance::{Balance, BalanceStrings};
 pub use crate::wallet::args::*;
-pub use crate::wallet::Wallet;
+pub use crate::wallet::{Wallet, WalletSyncPhase, WalletSyncProgress};
 pub use kaspa_addresses::{Address, Prefix as AddressPrefix};
 pub use kaspa_bip32::{Language, Mnemonic, WordCount};
 pub use kaspa_wallet_keys::secret::Secret;
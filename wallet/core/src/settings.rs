@@ -3,14 +3,19 @@
 //!
 
 use crate::imports::*;
+use crate::logging::log;
 use crate::result::Result;
+#[cfg(feature = "fs")]
 use crate::storage::local::Storage;
 use serde::de::DeserializeOwned;
 use serde_json::{from_value, to_value, Map, Value};
+use std::collections::BTreeMap;
 use std::hash::Hash;
 use std::marker::PhantomData;
+#[cfg(feature = "fs")]
 use std::path::PathBuf;
 use workflow_core::enums::Describe;
+#[cfg(feature = "fs")]
 use workflow_store::fs;
 
 #[derive(Describe, Debug, Clone, Serialize, Deserialize, Hash, Eq, PartialEq, Ord, PartialOrd)]
@@ -22,6 +27,49 @@ pub enum WalletSettings {
     Server,
     #[describe("Wallet storage or file name (default 'kaspa')")]
     Wallet,
+    #[describe("Wallet auto-lock timeout in seconds (0 disables auto-lock)")]
+    AutoLock,
+    #[describe("Minimum wallet secret strength score (0-4, 0 disables the policy)")]
+    SecretStrength,
+    #[describe("Default account id, selected when the wallet is opened")]
+    Account,
+    #[describe("Default priority fee in sompi per network (mainnet only)")]
+    PriorityFee,
+    #[describe("Fiat price feed (coingecko|coinpaprika), unset disables the price feed")]
+    PriceFeed,
+    #[describe("Fiat currencies obtained from the price feed (default: usd)")]
+    Currencies,
+    #[describe("Testnet faucet url (used by 'faucet request')")]
+    FaucetUrl,
+    #[describe("Webhook endpoints (managed via the wallet API)")]
+    Webhooks,
+    #[describe("Settings profiles (per-network server, wallet and account)")]
+    Profiles,
+    #[describe("Active settings profile")]
+    Profile,
+}
+
+/// Named settings profile holding the network-specific settings. Profiles
+/// are stored under [`WalletSettings::Profiles`]; selecting a profile
+/// applies its values to the top-level settings. Default priority fees
+/// are kept per network (see [`WalletSettings::PriorityFee`]) and are
+/// therefore selected by the profile network.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsProfile {
+    pub network: NetworkId,
+    /// RPC server address (`public` to use the public node resolver)
+    pub server: Option<String>,
+    /// Wallet storage or file name
+    pub wallet: Option<String>,
+    /// Default account id
+    pub account: Option<String>,
+}
+
+impl SettingsProfile {
+    pub fn new(network: NetworkId) -> Self {
+        Self { network, server: Some("public".to_string()), wallet: None, account: None }
+    }
 }
 
 #[async_trait]
@@ -42,7 +90,9 @@ where
     K: DefaultSettings + Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
 {
     map: DashMap<String, Value>,
-    storage: Storage,
+    /// Settings file (`None` for resident settings)
+    #[cfg(feature = "fs")]
+    storage: Option<Storage>,
     phantom: PhantomData<K>,
 }
 
@@ -50,12 +100,24 @@ impl<K> SettingsStore<K>
 where
     K: DefaultSettings + Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
 {
+    #[cfg(feature = "fs")]
     pub fn try_new(filename: &str) -> Result<Self> {
-        Ok(Self { map: DashMap::default(), storage: Storage::try_new(&format!("{filename}.settings"))?, phantom: PhantomData })
+        Ok(Self::new_with_storage(Storage::try_new(&format!("{filename}.settings"))?))
     }
 
+    #[cfg(feature = "fs")]
     pub fn new_with_storage(storage: Storage) -> Self {
-        Self { map: DashMap::default(), storage, phantom: PhantomData }
+        Self { map: DashMap::default(), storage: Some(storage), phantom: PhantomData }
+    }
+
+    /// Create a settings store that is held in memory and is not persisted.
+    pub fn new_resident() -> Self {
+        Self {
+            map: DashMap::default(),
+            #[cfg(feature = "fs")]
+            storage: None,
+            phantom: PhantomData,
+        }
     }
 
     pub fn get<V>(&self, key: K) -> Option<V>
@@ -69,7 +131,7 @@ where
             match from_value::<V>(s.value().clone()) {
                 Ok(v) => Some(v),
                 Err(err) => {
-                    log_error!("Unable to parse setting key `{ks}`: `{err}`");
+                    log!(Storage, Error, "Unable to parse setting key `{ks}`: `{err}`");
                     None
                 }
             }
@@ -87,17 +149,30 @@ where
 
         let v = to_value(value)?;
         self.map.insert(ks.to_string(), v);
-        self.try_store().await?;
+        self.commit().await?;
         Ok(())
     }
 
-    pub async fn try_load(&self) -> Result<()> {
-        let list: Option<Value> = if self.storage.exists().await? {
-            let v: Result<Value> = workflow_store::fs::read_json(self.storage.filename()).await.map_err(|err| err.into());
+    pub async fn remove(&self, key: K) -> Result<()> {
+        let ks = to_value(key).unwrap();
+        let ks = ks.as_str().expect("Unable to convert key to string");
+
+        self.map.remove(ks);
+        self.commit().await?;
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    async fn try_load_stored(&self) -> Result<Option<Value>> {
+        let Some(storage) = &self.storage else {
+            return Ok(None);
+        };
+        let list: Option<Value> = if storage.exists().await? {
+            let v: Result<Value> = workflow_store::fs::read_json(storage.filename()).await.map_err(|err| err.into());
             match v {
                 Ok(v) => v.is_object().then_some(v),
                 Err(err) => {
-                    log_error!("Unable to read settings file: `{err}`");
+                    log!(Storage, Error, "Unable to read settings file: `{err}`");
                     None
                 }
             }
@@ -105,6 +180,17 @@ where
             None
         };
 
+        Ok(list)
+    }
+
+    #[cfg(not(feature = "fs"))]
+    async fn try_load_stored(&self) -> Result<Option<Value>> {
+        Ok(None)
+    }
+
+    pub async fn try_load(&self) -> Result<()> {
+        let list = self.try_load_stored().await?;
+
         let list = if let Some(value) = list {
             value
         } else {
@@ -126,14 +212,109 @@ where
         Ok(())
     }
 
+    /// Write the settings to the settings file
+    /// (resident settings are not persisted).
+    #[cfg(feature = "fs")]
     pub async fn try_store(&self) -> Result<()> {
+        let Some(storage) = &self.storage else {
+            return Ok(());
+        };
         let map = Map::from_iter(self.map.clone().into_iter());
-        self.storage.ensure_dir().await?;
-        workflow_store::fs::write_json(self.storage.filename(), &Value::Object(map)).await?;
+        storage.ensure_dir().await?;
+        workflow_store::fs::write_json(storage.filename(), &Value::Object(map)).await?;
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    async fn commit(&self) -> Result<()> {
+        self.try_store().await
+    }
+
+    #[cfg(not(feature = "fs"))]
+    async fn commit(&self) -> Result<()> {
         Ok(())
     }
 }
 
+impl SettingsStore<WalletSettings> {
+    /// Returns the stored settings profiles.
+    pub fn profiles(&self) -> BTreeMap<String, SettingsProfile> {
+        self.get(WalletSettings::Profiles).unwrap_or_default()
+    }
+
+    /// Returns the name of the active settings profile.
+    pub fn profile(&self) -> Option<String> {
+        self.get(WalletSettings::Profile)
+    }
+
+    /// Select a settings profile. The current top-level settings are saved
+    /// into the active profile (if any), after which the values of the
+    /// selected profile are applied to the top-level settings. If the profile
+    /// does not exist and its name is a network id (`mainnet`, `testnet-10`,
+    /// `testnet-11`, `devnet` etc.), the profile is created for the network
+    /// using the public node resolver and the current wallet settings.
+    pub async fn select_profile(&self, name: &str) -> Result<SettingsProfile> {
+        let mut profiles = self.profiles();
+
+        if let Some(active) = self.profile().and_then(|active| profiles.get_mut(&active)) {
+            active.server = self.get(WalletSettings::Server);
+            active.wallet = self.get(WalletSettings::Wallet);
+            active.account = self.get(WalletSettings::Account);
+        }
+
+        let profile = match profiles.get(name) {
+            Some(profile) => profile.clone(),
+            None => {
+                let network = NetworkId::from_str(name).map_err(|_| Error::custom(format!("Unknown settings profile '{name}'")))?;
+                SettingsProfile {
+                    wallet: self.get(WalletSettings::Wallet),
+                    account: self.get(WalletSettings::Account),
+                    ..SettingsProfile::new(network)
+                }
+            }
+        };
+        profiles.insert(name.to_string(), profile.clone());
+
+        self.insert(WalletSettings::Network, Some(profile.network));
+        self.insert(WalletSettings::Server, profile.server.as_ref());
+        self.insert(WalletSettings::Wallet, profile.wallet.as_ref());
+        self.insert(WalletSettings::Account, profile.account.as_ref());
+        self.insert(WalletSettings::Profiles, Some(profiles));
+        self.insert(WalletSettings::Profile, Some(name));
+        self.commit().await?;
+
+        Ok(profile)
+    }
+
+    /// Remove a settings profile (the active profile can not be removed).
+    pub async fn remove_profile(&self, name: &str) -> Result<()> {
+        if self.profile().as_deref() == Some(name) {
+            return Err(Error::custom(format!("Unable to remove the active settings profile '{name}'")));
+        }
+
+        let mut profiles = self.profiles();
+        if profiles.remove(name).is_none() {
+            return Err(Error::custom(format!("Unknown settings profile '{name}'")));
+        }
+        self.set(WalletSettings::Profiles, profiles).await
+    }
+
+    // update or remove (if `None`) the setting without storing the settings
+    fn insert<V: Serialize>(&self, key: WalletSettings, value: Option<V>) {
+        let ks = to_value(key).unwrap();
+        let ks = ks.as_str().expect("Unable to convert key to string");
+        match value.map(to_value).transpose() {
+            Ok(Some(value)) => {
+                self.map.insert(ks.to_string(), value);
+            }
+            Ok(None) => {
+                self.map.remove(ks);
+            }
+            Err(err) => log!(Storage, Error, "Unable to serialize setting key `{ks}`: `{err}`"),
+        }
+    }
+}
+
 #[async_trait]
 pub trait SettingsStoreT: Send + Sync + 'static {
     async fn get<V>(&self, key: &str) -> Option<V>
@@ -165,18 +346,64 @@ where
         V: Serialize + DeserializeOwned + Send + Sync + 'static,
     {
         self.map.insert(key.to_string(), to_value(value).unwrap());
-        self.try_store().await?;
+        self.commit().await?;
         Ok(())
     }
 }
 
+#[cfg(feature = "fs")]
 pub fn application_folder() -> Result<PathBuf> {
     Ok(fs::resolve_path(storage::local::default_storage_folder())?)
 }
 
+#[cfg(feature = "fs")]
 pub async fn ensure_application_folder() -> Result<()> {
     let path = application_folder()?;
-    log_info!("Creating application folder: `{}`", path.display());
+    log!(Storage, Info, "Creating application folder: `{}`", path.display());
     fs::create_dir_all(&path).await?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_settings_profiles() -> Result<()> {
+        let settings = SettingsStore::<WalletSettings>::new_resident();
+        settings.set(WalletSettings::Server, "127.0.0.1").await?;
+        settings.set(WalletSettings::Wallet, "kaspa").await?;
+
+        // profiles named after a network id are created on first use
+        let testnet = settings.select_profile("testnet-10").await?;
+        assert_eq!(testnet.network, NetworkId::from_str("testnet-10")?);
+        assert_eq!((testnet.server.as_deref(), testnet.wallet.as_deref()), (Some("public"), Some("kaspa")));
+        assert_eq!(settings.profile().as_deref(), Some("testnet-10"));
+        assert_eq!(settings.get::<NetworkId>(WalletSettings::Network), Some(testnet.network));
+        assert_eq!(settings.get::<String>(WalletSettings::Server).as_deref(), Some("public"));
+        assert!(matches!(settings.select_profile("custom").await, Err(Error::Custom(_))));
+
+        // the settings of the active profile are saved when switching profiles
+        settings.set(WalletSettings::Server, "127.0.0.1:16210").await?;
+        settings.set(WalletSettings::Account, "main").await?;
+        let mainnet = settings.select_profile("mainnet").await?;
+        assert_eq!(mainnet.network, NetworkId::from_str("mainnet")?);
+        assert_eq!(mainnet.account.as_deref(), Some("main"));
+        settings.set(WalletSettings::Account, "savings").await?;
+
+        let testnet = settings.select_profile("testnet-10").await?;
+        assert_eq!(testnet.server.as_deref(), Some("127.0.0.1:16210"));
+        assert_eq!(settings.get::<String>(WalletSettings::Server).as_deref(), Some("127.0.0.1:16210"));
+        assert_eq!(settings.get::<String>(WalletSettings::Account).as_deref(), Some("main"));
+        assert_eq!(settings.profiles()["mainnet"].account.as_deref(), Some("savings"));
+        assert_eq!(settings.profiles().keys().collect::<Vec<_>>(), vec!["mainnet", "testnet-10"]);
+
+        // a profile is removed unless it is active
+        assert!(settings.remove_profile("testnet-10").await.is_err());
+        assert!(settings.remove_profile("devnet").await.is_err());
+        settings.remove_profile("mainnet").await?;
+        assert_eq!(settings.profiles().keys().collect::<Vec<_>>(), vec!["testnet-10"]);
+
+        Ok(())
+    }
+}
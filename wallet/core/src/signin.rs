@@ -0,0 +1,220 @@
+//!
+//! Sign-in with Kaspa - address ownership challenge protocol.
+//!
+//! A server (relying party) issues a [`SignInChallenge`] bound to its
+//! domain, the client address and a short validity window. The client
+//! signs the textual representation of the challenge using the
+//! [personal message signing](crate::message) API and returns the
+//! signature. The server then verifies the signature against the
+//! address public key while checking the domain, the network and
+//! the expiration time.
+//!
+
+use crate::error::Error;
+use crate::message::{sign_message, verify_message, PersonalMessage};
+use crate::result::Result;
+use kaspa_addresses::{Address, Version};
+use rand::RngCore;
+use secp256k1::XOnlyPublicKey;
+use serde::{Deserialize, Serialize};
+use workflow_core::time::unixtime_as_millis_u64;
+
+/// Challenge message header line suffix, used for domain separation
+/// from arbitrary personal messages.
+const HEADER_SUFFIX: &str = " wants you to sign in with your Kaspa account:";
+
+/// Default challenge validity period (5 minutes).
+pub const DEFAULT_CHALLENGE_VALIDITY_MSEC: u64 = 5 * 60 * 1000;
+
+/// Sign-in challenge issued by a relying party to a client
+/// that needs to prove ownership of a Kaspa address.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignInChallenge {
+    /// Domain (host) of the relying party requesting the sign-in.
+    pub domain: String,
+    /// Address the client claims to own.
+    pub address: Address,
+    /// Optional human-readable statement displayed to the user.
+    pub statement: Option<String>,
+    /// Random nonce (hex) generated by the relying party.
+    pub nonce: String,
+    /// Challenge issue time (unix time in milliseconds).
+    pub issued_at: u64,
+    /// Challenge expiration time (unix time in milliseconds).
+    pub expiration_time: u64,
+}
+
+impl SignInChallenge {
+    /// Create a new challenge with a random nonce valid for `validity_msec` milliseconds.
+    pub fn new(domain: &str, address: &Address, statement: Option<String>, validity_msec: Option<u64>) -> Result<Self> {
+        if domain.is_empty() || domain.contains(char::is_whitespace) {
+            return Err(Error::InvalidSignInChallenge(format!("invalid domain '{domain}'")));
+        }
+        if let Some(statement) = statement.as_ref() {
+            if statement.contains('\n') {
+                return Err(Error::InvalidSignInChallenge("statement must not contain line breaks".to_string()));
+            }
+        }
+
+        let mut nonce = [0u8; 16];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let issued_at = unixtime_as_millis_u64();
+        let expiration_time = issued_at + validity_msec.unwrap_or(DEFAULT_CHALLENGE_VALIDITY_MSEC);
+
+        Ok(Self {
+            domain: domain.to_string(),
+            address: address.clone(),
+            statement,
+            nonce: faster_hex::hex_string(&nonce),
+            issued_at,
+            expiration_time,
+        })
+    }
+
+    /// Returns `true` if the challenge has expired relative to `now` (unix time in milliseconds).
+    pub fn is_expired(&self, now: u64) -> bool {
+        now >= self.expiration_time
+    }
+
+    /// Produce the textual message that is signed by the client.
+    pub fn to_message(&self) -> String {
+        let mut lines = vec![format!("{}{HEADER_SUFFIX}", self.domain), self.address.to_string(), String::new()];
+        if let Some(statement) = self.statement.as_ref() {
+            lines.push(statement.clone());
+            lines.push(String::new());
+        }
+        lines.push(format!("Network: {}", self.address.prefix));
+        lines.push(format!("Nonce: {}", self.nonce));
+        lines.push(format!("Issued At: {}", self.issued_at));
+        lines.push(format!("Expiration Time: {}", self.expiration_time));
+        lines.join("\n")
+    }
+
+    /// Parse a challenge from its textual message representation.
+    pub fn from_message(message: &str) -> Result<Self> {
+        let invalid = |msg: &str| Error::InvalidSignInChallenge(msg.to_string());
+
+        let mut lines = message.split('\n');
+        let domain = lines.next().and_then(|line| line.strip_suffix(HEADER_SUFFIX)).ok_or_else(|| invalid("missing header"))?;
+        let address = Address::try_from(lines.next().ok_or_else(|| invalid("missing address"))?)?;
+        if lines.next() != Some("") {
+            return Err(invalid("malformed message"));
+        }
+
+        let mut fields = lines.collect::<Vec<_>>();
+        let statement = if fields.len() == 6 {
+            if !fields[1].is_empty() {
+                return Err(invalid("malformed statement"));
+            }
+            let statement = fields.remove(0).to_string();
+            fields.remove(0);
+            Some(statement)
+        } else {
+            None
+        };
+
+        if fields.len() != 4 {
+            return Err(invalid("malformed message"));
+        }
+
+        let field = |line: &str, name: &str| -> Result<String> {
+            line.strip_prefix(name).and_then(|v| v.strip_prefix(": ")).map(String::from).ok_or_else(|| invalid(name))
+        };
+
+        let network = field(fields[0], "Network")?;
+        if network != address.prefix.to_string() {
+            return Err(invalid("network does not match address"));
+        }
+        let nonce = field(fields[1], "Nonce")?;
+        let issued_at = field(fields[2], "Issued At")?.parse::<u64>().map_err(|_| invalid("Issued At"))?;
+        let expiration_time = field(fields[3], "Expiration Time")?.parse::<u64>().map_err(|_| invalid("Expiration Time"))?;
+
+        Ok(Self { domain: domain.to_string(), address, statement, nonce, issued_at, expiration_time })
+    }
+
+    /// Sign the challenge with the private key of the challenge address.
+    /// Returns a hex-encoded Schnorr signature.
+    pub fn sign(&self, privkey: &[u8; 32]) -> Result<String> {
+        let message = self.to_message();
+        let signature = sign_message(&PersonalMessage(&message), privkey)?;
+        Ok(faster_hex::hex_string(signature.as_slice()))
+    }
+
+    /// Verify a hex-encoded `signature` for this challenge. The relying party
+    /// must supply the `domain` it expects the challenge to be bound to.
+    pub fn verify(&self, signature: &str, domain: &str) -> Result<()> {
+        self.verify_at(signature, domain, unixtime_as_millis_u64())
+    }
+
+    /// Verify a hex-encoded `signature` for this challenge at the given time `now`
+    /// (unix time in milliseconds).
+    pub fn verify_at(&self, signature: &str, domain: &str, now: u64) -> Result<()> {
+        if self.domain != domain {
+            return Err(Error::InvalidSignInChallenge(format!("domain mismatch: expected '{domain}', got '{}'", self.domain)));
+        }
+        if now < self.issued_at {
+            return Err(Error::InvalidSignInChallenge("challenge is not yet valid".to_string()));
+        }
+        if self.is_expired(now) {
+            return Err(Error::SignInChallengeExpired);
+        }
+        if self.address.version != Version::PubKey {
+            return Err(Error::InvalidSignInChallenge("only PubKey addresses are supported".to_string()));
+        }
+
+        let pubkey = XOnlyPublicKey::from_slice(&self.address.payload[0..32])?;
+        let mut signature_bytes = [0u8; 64];
+        faster_hex::hex_decode(signature.as_bytes(), &mut signature_bytes)
+            .map_err(|_| Error::InvalidSignInChallenge("invalid signature encoding".to_string()))?;
+
+        let message = self.to_message();
+        verify_message(&PersonalMessage(&message), &signature_bytes.to_vec(), &pubkey).map_err(|_| Error::InvalidSignInSignature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_addresses::Prefix;
+
+    fn keypair() -> ([u8; 32], Address) {
+        let privkey: [u8; 32] = [
+            0xB7, 0xE1, 0x51, 0x62, 0x8A, 0xED, 0x2A, 0x6A, 0xBF, 0x71, 0x58, 0x80, 0x9C, 0xF4, 0xF3, 0xC7, 0x62, 0xE7, 0x16, 0x0F,
+            0x38, 0xB4, 0xDA, 0x56, 0xA7, 0x84, 0xD9, 0x04, 0x51, 0x90, 0xCF, 0xEF,
+        ];
+        let keypair = secp256k1::Keypair::from_seckey_slice(secp256k1::SECP256K1, &privkey).unwrap();
+        let (xonly, _) = keypair.x_only_public_key();
+        (privkey, Address::new(Prefix::Mainnet, Version::PubKey, &xonly.serialize()))
+    }
+
+    #[test]
+    fn test_signin_challenge_roundtrip() {
+        let (privkey, address) = keypair();
+        let challenge = SignInChallenge::new("example.com", &address, Some("Sign in to Example".to_string()), None).unwrap();
+        let message = challenge.to_message();
+        let parsed = SignInChallenge::from_message(&message).unwrap();
+        assert_eq!(challenge, parsed);
+
+        let signature = challenge.sign(&privkey).unwrap();
+        parsed.verify_at(&signature, "example.com", challenge.issued_at + 1).unwrap();
+
+        let challenge = SignInChallenge::new("example.com", &address, None, None).unwrap();
+        assert_eq!(challenge, SignInChallenge::from_message(&challenge.to_message()).unwrap());
+    }
+
+    #[test]
+    fn test_signin_challenge_rejections() {
+        let (privkey, address) = keypair();
+        let challenge = SignInChallenge::new("example.com", &address, None, Some(1000)).unwrap();
+        let signature = challenge.sign(&privkey).unwrap();
+        let now = challenge.issued_at + 1;
+
+        assert!(matches!(challenge.verify_at(&signature, "evil.com", now), Err(Error::InvalidSignInChallenge(_))));
+        assert!(matches!(challenge.verify_at(&signature, "example.com", now + 1000), Err(Error::SignInChallengeExpired)));
+
+        let mut tampered = challenge.clone();
+        tampered.nonce = "00".to_string();
+        assert!(matches!(tampered.verify_at(&signature, "example.com", now), Err(Error::InvalidSignInSignature)));
+    }
+}
@@ -2,9 +2,11 @@
 //! Storage wrapper for account data.
 //!
 
+use crate::account::descriptor::AccountDescriptorValue;
 use crate::imports::*;
+use std::collections::BTreeMap;
 
-const ACCOUNT_SETTINGS_VERSION: u32 = 0;
+const ACCOUNT_SETTINGS_VERSION: u32 = 1;
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -13,6 +15,10 @@ pub struct AccountSettings {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub meta: Option<Vec<u8>>,
+    /// Archived accounts are excluded from account enumeration
+    /// and activation while their storage is retained.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub archived: bool,
 }
 
 impl BorshSerialize for AccountSettings {
@@ -20,6 +26,7 @@ impl BorshSerialize for AccountSettings {
         BorshSerialize::serialize(&ACCOUNT_SETTINGS_VERSION, writer)?;
         BorshSerialize::serialize(&self.name, writer)?;
         BorshSerialize::serialize(&self.meta, writer)?;
+        BorshSerialize::serialize(&self.archived, writer)?;
 
         Ok(())
     }
@@ -27,17 +34,52 @@ impl BorshSerialize for AccountSettings {
 
 impl BorshDeserialize for AccountSettings {
     fn deserialize(buf: &mut &[u8]) -> IoResult<Self> {
-        let _version: u32 = BorshDeserialize::deserialize(buf)?;
+        let version: u32 = BorshDeserialize::deserialize(buf)?;
         let name = BorshDeserialize::deserialize(buf)?;
         let meta = BorshDeserialize::deserialize(buf)?;
+        let archived = if version > 0 { BorshDeserialize::deserialize(buf)? } else { false };
 
-        Ok(Self { name, meta })
+        Ok(Self { name, meta, archived })
     }
 }
 
 /// A [`Storable`] variant used explicitly for [`Account`] payload storage.
 pub trait AccountStorable: Storable {}
 
+static ACCOUNT_PAYLOAD_DESERIALIZATIONS: AtomicU64 = AtomicU64::new(0);
+static ACCOUNT_PAYLOAD_CACHE_HITS: AtomicU64 = AtomicU64::new(0);
+
+/// Account payload deserialization metrics.
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPayloadMetrics {
+    /// Number of account variant payloads deserialized from storage
+    pub deserializations: u64,
+    /// Number of account variant payload accesses served from cache
+    pub cache_hits: u64,
+}
+
+impl AccountPayloadMetrics {
+    pub fn get() -> Self {
+        Self {
+            deserializations: ACCOUNT_PAYLOAD_DESERIALIZATIONS.load(Ordering::Relaxed),
+            cache_hits: ACCOUNT_PAYLOAD_CACHE_HITS.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Lazily deserialized account variant payload. The payload is deserialized
+/// on first access and cached for the lifetime of the [`AccountStorage`]
+/// instance. The cache is not shared between clones.
+#[derive(Default)]
+struct PayloadCache(std::sync::OnceLock<Arc<dyn std::any::Any + Send + Sync>>);
+
+impl Clone for PayloadCache {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct AccountStorage {
     pub kind: AccountKind,
@@ -46,11 +88,17 @@ pub struct AccountStorage {
     pub prv_key_data_ids: AssocPrvKeyDataIds,
     pub settings: AccountSettings,
     pub serialized: Vec<u8>,
+    /// Account descriptor properties derived from the variant payload,
+    /// allowing account enumeration without payload deserialization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<BTreeMap<AccountDescriptorProperty, AccountDescriptorValue>>,
+    #[serde(skip)]
+    payload: PayloadCache,
 }
 
 impl AccountStorage {
     const STORAGE_MAGIC: u32 = 0x4153414b;
-    const STORAGE_VERSION: u32 = 0;
+    const STORAGE_VERSION: u32 = 1;
 
     pub fn try_new<A>(
         kind: AccountKind,
@@ -63,7 +111,21 @@ impl AccountStorage {
     where
         A: AccountStorable,
     {
-        Ok(Self { id: *id, storage_key: *storage_key, kind, prv_key_data_ids, settings, serialized: serialized.try_to_vec()? })
+        Ok(Self {
+            id: *id,
+            storage_key: *storage_key,
+            kind,
+            prv_key_data_ids,
+            settings,
+            serialized: serialized.try_to_vec()?,
+            properties: None,
+            payload: PayloadCache::default(),
+        })
+    }
+
+    pub fn with_properties(mut self, properties: BTreeMap<AccountDescriptorProperty, AccountDescriptorValue>) -> Self {
+        self.properties = Some(properties);
+        self
     }
 
     pub fn id(&self) -> &AccountId {
@@ -77,6 +139,44 @@ impl AccountStorage {
     pub fn serialized(&self) -> &[u8] {
         &self.serialized
     }
+
+    /// Returns the account variant payload, deserializing it on first access.
+    pub fn payload<A>(&self) -> Result<Arc<A>>
+    where
+        A: AccountStorable + Send + Sync + 'static,
+    {
+        if let Some(payload) = self.payload.0.get() {
+            ACCOUNT_PAYLOAD_CACHE_HITS.fetch_add(1, Ordering::Relaxed);
+            return payload.clone().downcast::<A>().map_err(|_| Error::custom("account payload type mismatch"));
+        }
+
+        ACCOUNT_PAYLOAD_DESERIALIZATIONS.fetch_add(1, Ordering::Relaxed);
+        let payload = Arc::new(A::try_from_slice(self.serialized.as_slice())?);
+        // a concurrent access may have populated the cache in the meantime,
+        // in which case the freshly deserialized payload is simply returned
+        let _ = self.payload.0.set(payload.clone());
+        Ok(payload)
+    }
+
+    /// Produce the account descriptor from the stored descriptor properties
+    /// and the addresses recorded in the account `metadata`, without
+    /// deserializing the account variant payload. Returns `None` if the
+    /// account was stored without this information.
+    pub fn descriptor(&self, metadata: Option<&AccountMetadata>) -> Option<AccountDescriptor> {
+        let properties = self.properties.clone()?;
+        let metadata = metadata?;
+        let AccountAddresses { receive, change } = metadata.addresses()?.clone();
+
+        let mut descriptor =
+            AccountDescriptor::new(self.kind, self.id, self.settings.name.clone(), self.prv_key_data_ids.clone(), receive, change)
+                .with_archived(self.settings.archived);
+        descriptor.properties = properties;
+        if let Some(indexes) = metadata.address_derivation_indexes() {
+            descriptor = descriptor.with_property(AccountDescriptorProperty::DerivationMeta, indexes.into());
+        }
+
+        Some(descriptor)
+    }
 }
 
 impl std::fmt::Debug for AccountStorage {
@@ -88,6 +188,7 @@ impl std::fmt::Debug for AccountStorage {
             .field("prv_key_data_ids", &self.prv_key_data_ids)
             .field("settings", &self.settings)
             .field("serialized", &self.serialized.to_hex())
+            .field("properties", &self.properties)
             .finish()
     }
 }
@@ -101,6 +202,7 @@ impl BorshSerialize for AccountStorage {
         BorshSerialize::serialize(&self.prv_key_data_ids, writer)?;
         BorshSerialize::serialize(&self.settings, writer)?;
         BorshSerialize::serialize(&self.serialized, writer)?;
+        BorshSerialize::serialize(&self.properties, writer)?;
 
         Ok(())
     }
@@ -108,7 +210,7 @@ impl BorshSerialize for AccountStorage {
 
 impl BorshDeserialize for AccountStorage {
     fn deserialize(buf: &mut &[u8]) -> IoResult<Self> {
-        let StorageHeader { version: _, .. } =
+        let StorageHeader { version, .. } =
             StorageHeader::deserialize(buf)?.try_magic(Self::STORAGE_MAGIC)?.try_version(Self::STORAGE_VERSION)?;
 
         let kind = BorshDeserialize::deserialize(buf)?;
@@ -117,21 +219,23 @@ impl BorshDeserialize for AccountStorage {
         let prv_key_data_ids = BorshDeserialize::deserialize(buf)?;
         let settings = BorshDeserialize::deserialize(buf)?;
         let serialized = BorshDeserialize::deserialize(buf)?;
+        let properties = if version > 0 { BorshDeserialize::deserialize(buf)? } else { None };
 
-        Ok(Self { kind, id, storage_key, prv_key_data_ids, settings, serialized })
+        Ok(Self { kind, id, storage_key, prv_key_data_ids, settings, serialized, properties, payload: PayloadCache::default() })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::derivation::AddressDerivationMeta;
     use crate::tests::*;
 
     #[test]
     fn test_storage_account_storage_wrapper() -> Result<()> {
         let (id, storage_key) = make_account_hashes(from_data(&BIP32_ACCOUNT_KIND.into(), &[0x00, 0x01, 0x02, 0x03]));
         let prv_key_data_id = PrvKeyDataId::new(0xcafe);
-        let storable = bip32::Payload::new(0, ExtendedPublicKeys::default(), false);
+        let storable = bip32::Payload::new(0, ExtendedPublicKeys::default(), false, None);
         let storable_in = AccountStorage::try_new(
             BIP32_ACCOUNT_KIND.into(),
             &id,
@@ -148,6 +252,63 @@ mod tests {
         assert_eq!(storable_in.storage_key, storable_out.storage_key);
         assert_eq!(storable_in.serialized, storable_out.serialized);
 
+        let deserializations = AccountPayloadMetrics::get().deserializations;
+        let payload = storable_out.payload::<bip32::Payload>()?;
+        assert_eq!(payload.account_index, 0);
+        let cached = storable_out.payload::<bip32::Payload>()?;
+        assert!(Arc::ptr_eq(&payload, &cached));
+        assert!(AccountPayloadMetrics::get().deserializations > deserializations);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_storage_account_descriptor() -> Result<()> {
+        let (id, storage_key) = make_account_hashes(from_data(&BIP32_ACCOUNT_KIND.into(), &[0x04, 0x05, 0x06, 0x07]));
+        let prv_key_data_id = PrvKeyDataId::new(0xcafe);
+        let storable = bip32::Payload::new(0, ExtendedPublicKeys::default(), false, None);
+        let settings = AccountSettings { name: Some("savings".to_string()), ..Default::default() };
+        let storage =
+            AccountStorage::try_new(BIP32_ACCOUNT_KIND.into(), &id, &storage_key, prv_key_data_id.into(), settings, storable)?;
+        let address = Address::try_from("kaspatest:qz7ulu4c25dh7fzec9zjyrmlhnkzrg4wmf89q7gzr3gfrsj3uz6xjceef60sd")?;
+        let metadata = AccountMetadata::new(id, AddressDerivationMeta::new(3, 1));
+
+        // accounts stored without descriptor properties or addresses must be loaded
+        assert!(storage.descriptor(Some(&metadata)).is_none());
+        let storage = storage.with_properties([(AccountDescriptorProperty::Ecdsa, false.into())].into_iter().collect());
+        assert!(storage.descriptor(Some(&metadata)).is_none());
+        assert!(storage.descriptor(None).is_none());
+
+        let metadata = metadata.with_addresses(Some(address.clone()), None);
+        let storage = AccountStorage::try_from_slice(&storage.try_to_vec()?)?;
+        let metadata = AccountMetadata::try_from_slice(&metadata.try_to_vec()?)?;
+        let descriptor = storage.descriptor(Some(&metadata)).expect("stored account descriptor");
+        assert_eq!(descriptor.account_id, id);
+        assert_eq!(descriptor.account_name.as_deref(), Some("savings"));
+        assert_eq!(descriptor.receive_address, Some(address));
+        assert!(descriptor.change_address.is_none());
+        assert!(descriptor.properties.contains_key(&AccountDescriptorProperty::Ecdsa));
+        assert!(descriptor.properties.contains_key(&AccountDescriptorProperty::DerivationMeta));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_storage_account_settings_archived() -> Result<()> {
+        let settings = AccountSettings { name: Some("savings".to_string()), archived: true, ..Default::default() };
+        let settings_out = AccountSettings::try_from_slice(&settings.try_to_vec()?)?;
+        assert_eq!(settings_out.name, settings.name);
+        assert!(settings_out.archived);
+
+        // settings stored prior to the introduction of the archived flag
+        let mut legacy = vec![];
+        BorshSerialize::serialize(&0u32, &mut legacy)?;
+        BorshSerialize::serialize(&settings.name, &mut legacy)?;
+        BorshSerialize::serialize(&settings.meta, &mut legacy)?;
+        let settings_out = AccountSettings::try_from_slice(&legacy)?;
+        assert_eq!(settings_out.name, settings.name);
+        assert!(!settings_out.archived);
+
         Ok(())
     }
 }
@@ -5,7 +5,7 @@
 use crate::imports::*;
 use crate::utxo::{UtxoContextBinding as UtxoProcessorBinding, UtxoContextId};
 
-#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "kebab-case")]
 #[serde(tag = "type", content = "id")]
 pub enum Binding {
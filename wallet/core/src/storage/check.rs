@@ -0,0 +1,96 @@
+//!
+//! Wallet storage integrity check report.
+//!
+
+use crate::imports::*;
+
+/// Kind of an issue detected by the wallet storage integrity check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WalletCheckIssueKind {
+    /// Private key data that can not be decoded from the stored wallet
+    /// data, or whose id does not match the id of the stored key material.
+    InvalidPrvKeyData,
+    /// Private key data info without the corresponding private key data.
+    OrphanPrvKeyDataInfo,
+    /// Private key data without the corresponding private key data info.
+    MissingPrvKeyDataInfo,
+    /// Account data with an invalid storage header or account payload.
+    InvalidAccount,
+    /// Account referring to private key data that is not present in the wallet.
+    OrphanAccount,
+    /// Account metadata without the corresponding account.
+    OrphanMetadata,
+    /// Transaction record that can not be loaded or that
+    /// is bound to a different account than the one it is stored under.
+    InvalidTransactionRecord,
+}
+
+impl std::fmt::Display for WalletCheckIssueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            WalletCheckIssueKind::InvalidPrvKeyData => "invalid private key data",
+            WalletCheckIssueKind::OrphanPrvKeyDataInfo => "orphan private key data info",
+            WalletCheckIssueKind::MissingPrvKeyDataInfo => "missing private key data info",
+            WalletCheckIssueKind::InvalidAccount => "invalid account",
+            WalletCheckIssueKind::OrphanAccount => "orphan account",
+            WalletCheckIssueKind::OrphanMetadata => "orphan account metadata",
+            WalletCheckIssueKind::InvalidTransactionRecord => "invalid transaction record",
+        };
+        write!(f, "{kind}")
+    }
+}
+
+/// Issue detected by the wallet storage integrity check.
+#[derive(Debug, Clone, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletCheckIssue {
+    pub kind: WalletCheckIssueKind,
+    /// Id of the affected entry (hex-encoded).
+    pub id: String,
+    pub description: String,
+    /// Set if the issue has been repaired.
+    pub repaired: bool,
+}
+
+impl WalletCheckIssue {
+    pub fn new(kind: WalletCheckIssueKind, id: impl ToString, description: impl Into<String>) -> Self {
+        Self { kind, id: id.to_string(), description: description.into(), repaired: false }
+    }
+}
+
+impl std::fmt::Display for WalletCheckIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}: {}", self.kind, self.id, self.description)?;
+        if self.repaired {
+            write!(f, " (repaired)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Result of the wallet storage integrity check.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletCheckReport {
+    /// Number of checked private key data entries.
+    pub prv_key_data: usize,
+    /// Number of checked accounts.
+    pub accounts: usize,
+    /// Number of checked account metadata entries.
+    pub metadata: usize,
+    /// Number of checked transaction records.
+    pub transactions: usize,
+    pub issues: Vec<WalletCheckIssue>,
+}
+
+impl WalletCheckReport {
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns `true` if any of the detected issues has been repaired.
+    pub fn is_repaired(&self) -> bool {
+        self.issues.iter().any(|issue| issue.repaired)
+    }
+}
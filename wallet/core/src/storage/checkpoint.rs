@@ -0,0 +1,155 @@
+//!
+//! [`OperationCheckpoint`] records the progress of long-running
+//! multi-transaction account operations (such as the account sweep),
+//! allowing an aborted or interrupted operation to be resumed.
+//!
+
+use crate::imports::*;
+use crate::utxo::UtxoEntryId;
+use workflow_core::time::unixtime_as_millis_u64;
+
+/// Kind of a checkpointed account operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OperationKind {
+    /// Aggregation of all account UTXOs into the change address
+    /// (see [`Account::sweep`](crate::account::Account::sweep)).
+    Sweep,
+    /// Outgoing payment (see [`Account::send`](crate::account::Account::send)).
+    Send,
+    /// Transfer to another wallet account
+    /// (see [`Account::transfer`](crate::account::Account::transfer)).
+    Transfer,
+}
+
+impl std::fmt::Display for OperationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OperationKind::Sweep => write!(f, "sweep"),
+            OperationKind::Send => write!(f, "send"),
+            OperationKind::Transfer => write!(f, "transfer"),
+        }
+    }
+}
+
+/// Transaction submitted by a checkpointed account operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckpointTransaction {
+    pub id: TransactionId,
+    /// UTXOs spent by the transaction
+    pub spent_utxos: Vec<UtxoEntryId>,
+}
+
+/// Progress of a multi-transaction account operation, persisted in the
+/// [`AccountMetadata`](super::AccountMetadata) after each submitted transaction.
+/// UTXOs spent by the submitted transactions are excluded when the operation
+/// is resumed, preventing double-spending of UTXOs that are still reported by
+/// the node while the submitted transactions are pending. Transactions that
+/// are no longer pending (rejected or evicted from the mempool) are removed
+/// from the checkpoint before it is resumed, releasing the UTXOs they spent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationCheckpoint {
+    pub kind: OperationKind,
+    /// Operation start time (unix time in milliseconds)
+    pub created: u64,
+    /// Submitted transactions
+    pub transactions: Vec<CheckpointTransaction>,
+}
+
+impl OperationCheckpoint {
+    const STORAGE_MAGIC: u32 = 0x4b504b43;
+    const STORAGE_VERSION: u32 = 1;
+
+    pub fn new(kind: OperationKind) -> Self {
+        Self { kind, created: unixtime_as_millis_u64(), transactions: vec![] }
+    }
+
+    /// Record a submitted transaction spending the `spent_utxos`.
+    pub fn record<I>(&mut self, transaction_id: TransactionId, spent_utxos: I)
+    where
+        I: IntoIterator<Item = UtxoEntryId>,
+    {
+        self.transactions.push(CheckpointTransaction { id: transaction_id, spent_utxos: spent_utxos.into_iter().collect() });
+    }
+
+    /// Retain only the transactions specified by the predicate.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&TransactionId) -> bool,
+    {
+        self.transactions.retain(|transaction| f(&transaction.id));
+    }
+
+    pub fn transaction_ids(&self) -> impl Iterator<Item = &TransactionId> {
+        self.transactions.iter().map(|transaction| &transaction.id)
+    }
+
+    pub fn spent_utxos(&self) -> AHashSet<UtxoEntryId> {
+        self.transactions.iter().flat_map(|transaction| transaction.spent_utxos.iter().cloned()).collect()
+    }
+
+    pub fn transaction_count(&self) -> usize {
+        self.transactions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+}
+
+fn serialize_utxo_entry_ids<W: std::io::Write>(utxo_entry_ids: &[UtxoEntryId], writer: &mut W) -> std::io::Result<()> {
+    let outpoints = utxo_entry_ids.iter().map(|outpoint| (outpoint.transaction_id, outpoint.index)).collect::<Vec<_>>();
+    BorshSerialize::serialize(&outpoints, writer)
+}
+
+fn deserialize_utxo_entry_ids(buf: &mut &[u8]) -> IoResult<Vec<UtxoEntryId>> {
+    let outpoints: Vec<(TransactionId, TransactionIndexType)> = BorshDeserialize::deserialize(buf)?;
+    Ok(outpoints.into_iter().map(|(transaction_id, index)| UtxoEntryId::new(transaction_id, index)).collect())
+}
+
+impl BorshSerialize for OperationCheckpoint {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        StorageHeader::new(Self::STORAGE_MAGIC, Self::STORAGE_VERSION).serialize(writer)?;
+        BorshSerialize::serialize(&self.kind, writer)?;
+        BorshSerialize::serialize(&self.created, writer)?;
+        BorshSerialize::serialize(&(self.transactions.len() as u32), writer)?;
+        for transaction in self.transactions.iter() {
+            BorshSerialize::serialize(&transaction.id, writer)?;
+            serialize_utxo_entry_ids(&transaction.spent_utxos, writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for OperationCheckpoint {
+    fn deserialize(buf: &mut &[u8]) -> IoResult<Self> {
+        let StorageHeader { version, .. } =
+            StorageHeader::deserialize(buf)?.try_magic(Self::STORAGE_MAGIC)?.try_version(Self::STORAGE_VERSION)?;
+
+        let kind = BorshDeserialize::deserialize(buf)?;
+        let created = BorshDeserialize::deserialize(buf)?;
+        let transactions = if version == 0 {
+            // version 0 does not associate the spent UTXOs with the transactions,
+            // the UTXOs are attributed to every transaction so that they remain
+            // excluded while any of the transactions is pending
+            let spent_utxos = deserialize_utxo_entry_ids(buf)?;
+            let transaction_ids: Vec<TransactionId> = BorshDeserialize::deserialize(buf)?;
+            transaction_ids.into_iter().map(|id| CheckpointTransaction { id, spent_utxos: spent_utxos.clone() }).collect()
+        } else {
+            let len: u32 = BorshDeserialize::deserialize(buf)?;
+            (0..len)
+                .map(|_| {
+                    Ok(CheckpointTransaction {
+                        id: BorshDeserialize::deserialize(buf)?,
+                        spent_utxos: deserialize_utxo_entry_ids(buf)?,
+                    })
+                })
+                .collect::<IoResult<Vec<_>>>()?
+        };
+
+        Ok(Self { kind, created, transactions })
+    }
+}
@@ -3,6 +3,7 @@
 //!
 
 use crate::imports::*;
+use crate::webhooks::WebhookSecret;
 use async_trait::async_trait;
 use downcast::{downcast_sync, AnySync};
 
@@ -21,6 +22,7 @@ const TS_WALLET_DESCRIPTOR: &'static str = r#"
 export interface IWalletDescriptor {
     title?: string;
     filename: string;
+    notes?: string;
 }
 "#;
 
@@ -32,11 +34,18 @@ pub struct WalletDescriptor {
     pub title: Option<String>,
     #[wasm_bindgen(getter_with_clone)]
     pub filename: String,
+    #[wasm_bindgen(getter_with_clone)]
+    pub notes: Option<String>,
 }
 
 impl WalletDescriptor {
     pub fn new(title: Option<String>, filename: String) -> Self {
-        Self { title, filename }
+        Self { title, filename, notes: None }
+    }
+
+    pub fn with_notes(mut self, notes: Option<String>) -> Self {
+        self.notes = notes;
+        self
     }
 }
 
@@ -153,6 +162,41 @@ pub trait TransactionRecordStore: Send + Sync {
         id: TransactionId,
         metadata: Option<String>,
     ) -> Result<()>;
+
+    /// Loads the balance history cache of the binding. Storage
+    /// implementations that do not persist the cache return `None`,
+    /// in which case the balance history is recomputed on each request.
+    async fn load_balance_history(&self, _binding: &Binding, _network_id: &NetworkId) -> Result<Option<BalanceHistoryCache>> {
+        Ok(None)
+    }
+
+    async fn store_balance_history(&self, _binding: &Binding, _network_id: &NetworkId, _cache: &BalanceHistoryCache) -> Result<()> {
+        Ok(())
+    }
+
+    /// Loads the DAA score timestamp cache of the network. Storage
+    /// implementations that do not persist the cache return `None`,
+    /// in which case the timestamps are resolved via the node.
+    async fn load_daa_timestamps(&self, _network_id: &NetworkId) -> Result<Option<DaaTimestampCache>> {
+        Ok(None)
+    }
+
+    /// Persists the DAA scores modified since the cache was loaded
+    /// (see [`DaaTimestampCache::modified`]).
+    async fn store_daa_timestamps(&self, _network_id: &NetworkId, _cache: &DaaTimestampCache) -> Result<()> {
+        Ok(())
+    }
+
+    /// Exports the transaction records of the binding as CSV or JSON
+    /// (see [`TransactionExportOptions`]).
+    async fn export(&self, binding: &Binding, network_id: &NetworkId, options: &TransactionExportOptions) -> Result<String> {
+        let records = match self.transaction_data_iter(binding, network_id).await {
+            Ok(records) => records.try_collect::<Vec<_>>().await?,
+            Err(Error::NoRecordsFound) => vec![],
+            Err(err) => return Err(err),
+        };
+        options.export(&records)
+    }
 }
 
 #[derive(Debug)]
@@ -162,6 +206,9 @@ pub struct CreateArgs {
     pub encryption_kind: EncryptionKind,
     pub user_hint: Option<Hint>,
     pub overwrite_wallet: bool,
+    /// Store the accounts in the account database instead of the wallet file
+    /// (native platforms with the `kv` feature enabled, ignored otherwise).
+    pub account_database: bool,
 }
 
 impl CreateArgs {
@@ -172,7 +219,15 @@ impl CreateArgs {
         user_hint: Option<Hint>,
         overwrite_wallet: bool,
     ) -> Self {
-        Self { title, filename, encryption_kind, user_hint, overwrite_wallet }
+        Self { title, filename, encryption_kind, user_hint, overwrite_wallet, account_database: false }
+    }
+
+    /// Keep the accounts and the account metadata in a database stored alongside
+    /// the wallet file. The wallet file alone is then not a complete copy of the
+    /// wallet (see the `storage::local::kv` module).
+    pub fn with_account_database(mut self, account_database: bool) -> Self {
+        self.account_database = account_database;
+        self
     }
 }
 
@@ -204,12 +259,25 @@ pub trait Interface: Send + Sync + AnySync {
     /// encryption used by the currently open wallet
     fn encryption_kind(&self) -> Result<EncryptionKind>;
 
-    /// rename the currently open wallet (title or the filename)
-    async fn rename(&self, wallet_secret: &Secret, title: Option<&str>, filename: Option<&str>) -> Result<()>;
+    /// rename the currently open wallet (title or the filename) and replace
+    /// the wallet notes (empty notes remove the notes); fails with
+    /// [`Error::WalletAlreadyExists`] if the destination filename is in use
+    async fn rename(&self, wallet_secret: &Secret, title: Option<&str>, notes: Option<&str>, filename: Option<&str>) -> Result<()>;
 
     /// change the secret of the currently open wallet
     async fn change_secret(&self, old_wallet_secret: &Secret, new_wallet_secret: &Secret) -> Result<()>;
 
+    /// re-encrypt the private key data (all private keys if `prv_key_data_id` is `None`)
+    /// with a new payment secret (`None` removes the payment secret); no private key
+    /// is modified if any of the selected keys can not be re-encrypted
+    async fn change_payment_secret(
+        &self,
+        wallet_secret: &Secret,
+        prv_key_data_id: Option<&PrvKeyDataId>,
+        old_payment_secret: Option<&Secret>,
+        new_payment_secret: Option<&Secret>,
+    ) -> Result<PrvKeyDataSecretChangeReport>;
+
     /// checks if the wallet storage is present
     async fn exists(&self, name: Option<&str>) -> Result<bool>;
 
@@ -237,12 +305,24 @@ pub trait Interface: Send + Sync + AnySync {
     /// import the wallet data
     async fn wallet_import(&self, wallet_secret: &Secret, serialized_wallet_storage: &[u8]) -> Result<WalletDescriptor>;
 
+    /// check the integrity of the currently open wallet data (private key data,
+    /// accounts and account metadata), removing orphaned entries if `repair` is set
+    async fn check(&self, wallet_secret: &Secret, repair: bool) -> Result<WalletCheckReport>;
+
+    /// upgrade the wallet data to the current storage version (wallets are also
+    /// migrated when opened); the original wallet data is backed up if modified
+    async fn migrate(&self, wallet_secret: &Secret, name: Option<&str>) -> Result<WalletMigrationReport>;
+
     // ~~~
 
     // phishing hint (user-created text string identifying authenticity of the wallet)
     async fn get_user_hint(&self) -> Result<Option<Hint>>;
     async fn set_user_hint(&self, hint: Option<Hint>) -> Result<()>;
 
+    // webhook payload signing secrets (stored in the encrypted wallet data)
+    async fn get_webhook_secrets(&self) -> Result<Vec<WebhookSecret>>;
+    async fn set_webhook_secrets(&self, wallet_secret: &Secret, secrets: Vec<WebhookSecret>) -> Result<()>;
+
     // ~~~
     fn as_prv_key_data_store(&self) -> Result<Arc<dyn PrvKeyDataStore>>;
     fn as_account_store(&self) -> Result<Arc<dyn AccountStore>>;
@@ -2,7 +2,7 @@
 //! Private key storage and encryption.
 //!
 
-use crate::derivation::create_xpub_from_xprv;
+use crate::derivation::{create_xpub_from_xprv, create_xpub_from_xprv_with_path, DerivationPathTemplate};
 use crate::imports::*;
 use kaspa_bip32::{ExtendedPrivateKey, ExtendedPublicKey, Language, Mnemonic};
 use kaspa_utils::hex::ToHex;
@@ -207,6 +207,16 @@ impl PrvKeyData {
         create_xpub_from_xprv(xprv, account_kind, account_index).await
     }
 
+    pub async fn create_xpub_with_path(
+        &self,
+        payment_secret: Option<&Secret>,
+        derivation_path: &DerivationPathTemplate,
+    ) -> Result<ExtendedPublicKey<secp256k1::PublicKey>> {
+        let payload = self.payload.decrypt(payment_secret)?;
+        let xprv = payload.get_xprv(payment_secret)?;
+        create_xpub_from_xprv_with_path(xprv, derivation_path).await
+    }
+
     pub fn get_xprv(&self, payment_secret: Option<&Secret>) -> Result<ExtendedPrivateKey<secp256k1::SecretKey>> {
         let payload = self.payload.decrypt(payment_secret)?;
         payload.get_xprv(payment_secret)
@@ -293,6 +303,37 @@ impl PrvKeyData {
         self.payload = self.payload.into_encrypted(secret, encryption_kind)?;
         Ok(())
     }
+
+    /// Re-encrypt the payload with a new payment secret (`None` stores the payload
+    /// unencrypted). The payment secret of bip39 mnemonics is the bip39 passphrase
+    /// used to derive the keys and therefore can not be changed.
+    pub fn change_payment_secret(
+        &mut self,
+        old_payment_secret: Option<&Secret>,
+        new_payment_secret: Option<&Secret>,
+        encryption_kind: EncryptionKind,
+    ) -> Result<()> {
+        let payload = self.payload.decrypt(old_payment_secret)?;
+        if matches!(payload.as_variant().kind(), PrvKeyDataVariantKind::Mnemonic) {
+            return Err(Error::PaymentSecretIsPassphrase(self.id));
+        }
+
+        self.payload = Encryptable::Plain(payload.unwrap());
+        if let Some(new_payment_secret) = new_payment_secret {
+            self.encrypt(new_payment_secret, encryption_kind)?;
+        }
+        Ok(())
+    }
+}
+
+/// Result of the payment secret change.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrvKeyDataSecretChangeReport {
+    /// Private keys re-encrypted with the new payment secret.
+    pub prv_key_data_ids: Vec<PrvKeyDataId>,
+    /// Location of the backup of the wallet file preceding the change.
+    pub backup: Option<String>,
 }
 
 #[cfg(test)]
@@ -311,6 +352,28 @@ mod tests {
             _ => unreachable!("invalid prv key variant storage data"),
         }
 
+        Ok(())
+    }
+    #[tokio::test]
+    async fn test_create_xpub_with_path() -> Result<()> {
+        let mnemonic = Mnemonic::new(
+            "hunt bitter praise lift buyer topic crane leopard uniform network inquiry over grain pass match crush marine strike doll relax fortune trumpet sunny silk",
+            Language::English,
+        )?;
+        let prv_key_data = PrvKeyData::try_from_mnemonic(mnemonic, None, EncryptionKind::XChaCha20Poly1305)?;
+
+        let standard = prv_key_data.create_xpub(None, BIP32_ACCOUNT_KIND.into(), 1).await?;
+        let template = "m/44'/111111'/1'".parse::<DerivationPathTemplate>()?;
+        assert!(template.is_standard());
+        assert_eq!(template.to_string(), "m/44'/111111'/1'");
+        assert_eq!(prv_key_data.create_xpub_with_path(None, &template).await?, standard);
+
+        let custom = DerivationPathTemplate::new(44, 972, 1)?;
+        assert_ne!(prv_key_data.create_xpub_with_path(None, &custom).await?, standard);
+
+        assert!("m/44'/111111'".parse::<DerivationPathTemplate>().is_err());
+        assert!("m/44'/111111'/0".parse::<DerivationPathTemplate>().is_err());
+
         Ok(())
     }
 }
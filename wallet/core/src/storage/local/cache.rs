@@ -5,10 +5,12 @@
 use crate::imports::*;
 use crate::storage::local::wallet::WalletStorage;
 use crate::storage::local::*;
+use crate::webhooks::WebhookSecret;
 use std::collections::HashMap;
 
 pub struct Cache {
     pub wallet_title: Option<String>,
+    pub wallet_notes: Option<String>,
     pub user_hint: Option<Hint>,
     pub encryption_kind: EncryptionKind,
     pub prv_key_data: Encrypted,
@@ -16,6 +18,7 @@ pub struct Cache {
     pub accounts: Collection<AccountId, AccountStorage>,
     pub metadata: Collection<AccountId, AccountMetadata>,
     pub address_book: Vec<AddressBookEntry>,
+    pub webhook_secrets: Vec<WebhookSecret>,
 }
 
 impl Cache {
@@ -33,9 +36,22 @@ impl Cache {
         let metadata: Collection<AccountId, AccountMetadata> = wallet.metadata.try_into()?;
         let user_hint = wallet.user_hint;
         let wallet_title = wallet.title;
+        let wallet_notes = wallet.notes;
         let address_book = payload.0.address_book.into_iter().collect();
+        let webhook_secrets = payload.0.webhook_secrets.clone();
 
-        Ok(Cache { wallet_title, user_hint, encryption_kind, prv_key_data, prv_key_data_info, accounts, metadata, address_book })
+        Ok(Cache {
+            wallet_title,
+            wallet_notes,
+            user_hint,
+            encryption_kind,
+            prv_key_data,
+            prv_key_data_info,
+            accounts,
+            metadata,
+            address_book,
+            webhook_secrets,
+        })
     }
 
     pub fn from_payload(
@@ -53,8 +69,20 @@ impl Cache {
         let accounts: Collection<AccountId, AccountStorage> = payload.accounts.try_into()?;
         let metadata: Collection<AccountId, AccountMetadata> = Collection::default();
         let address_book = payload.address_book.into_iter().collect();
+        let webhook_secrets = payload.webhook_secrets.clone();
 
-        Ok(Cache { wallet_title, user_hint, encryption_kind, prv_key_data, prv_key_data_info, accounts, metadata, address_book })
+        Ok(Cache {
+            wallet_title,
+            wallet_notes: None,
+            user_hint,
+            encryption_kind,
+            prv_key_data,
+            prv_key_data_info,
+            accounts,
+            metadata,
+            address_book,
+            webhook_secrets,
+        })
     }
 
     pub fn to_wallet(
@@ -62,12 +90,30 @@ impl Cache {
         transactions: Option<Encryptable<HashMap<AccountId, Vec<TransactionRecord>>>>,
         secret: &Secret,
     ) -> Result<WalletStorage> {
-        let prv_key_data: Decrypted<PrvKeyDataMap> = self.prv_key_data.decrypt(secret)?;
-        let prv_key_data = prv_key_data.values().cloned().collect::<Vec<_>>();
         let accounts: Vec<AccountStorage> = (&self.accounts).try_into()?;
         let metadata: Vec<AccountMetadata> = (&self.metadata).try_into()?;
+        self.to_wallet_with_accounts(transactions, accounts, metadata, secret)
+    }
+
+    /// Wallet data without the accounts and the account metadata
+    /// (stored separately by the account database).
+    pub fn to_wallet_without_accounts(&self, secret: &Secret) -> Result<WalletStorage> {
+        self.to_wallet_with_accounts(None, vec![], vec![], secret)
+    }
+
+    fn to_wallet_with_accounts(
+        &self,
+        transactions: Option<Encryptable<HashMap<AccountId, Vec<TransactionRecord>>>>,
+        accounts: Vec<AccountStorage>,
+        metadata: Vec<AccountMetadata>,
+        secret: &Secret,
+    ) -> Result<WalletStorage> {
+        let prv_key_data: Decrypted<PrvKeyDataMap> = self.prv_key_data.decrypt(secret)?;
+        let mut prv_key_data = prv_key_data.values().cloned().collect::<Vec<_>>();
+        prv_key_data.sort_by_key(|prv_key_data| prv_key_data.id);
         let address_book = self.address_book.clone();
-        let payload = Payload::new(prv_key_data, accounts, address_book);
+        let mut payload = Payload::new(prv_key_data, accounts, address_book);
+        payload.webhook_secrets = self.webhook_secrets.clone();
         let payload = Decrypted::new(payload).encrypt(secret, self.encryption_kind)?;
 
         Ok(WalletStorage {
@@ -77,6 +123,7 @@ impl Cache {
             user_hint: self.user_hint.clone(),
             title: self.wallet_title.clone(),
             transactions,
+            notes: self.wallet_notes.clone(),
         })
     }
 }
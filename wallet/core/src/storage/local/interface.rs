@@ -4,21 +4,32 @@
 //! extension storage.
 //!
 
+use crate::factory::factories;
 use crate::imports::*;
+#[cfg(feature = "fs")]
+use crate::logging::log;
 use crate::storage::interface::{
     AddressBookStore, CreateArgs, OpenArgs, StorageDescriptor, StorageStream, WalletDescriptor, WalletExportOptions,
 };
 use crate::storage::local::cache::*;
+#[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+use crate::storage::local::kv::AccountDatabase;
+#[cfg(feature = "fs")]
+use crate::storage::local::migration;
 use crate::storage::local::streams::*;
 use crate::storage::local::transaction::*;
+#[cfg(feature = "fs")]
 use crate::storage::local::wallet::WalletStorage;
-use crate::storage::local::Payload;
+#[cfg(feature = "fs")]
 use crate::storage::local::Storage;
+use crate::storage::local::{Payload, PayloadEntries};
+use crate::webhooks::WebhookSecret;
 use slugify_rs::slugify;
 use std::path::PathBuf;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use workflow_core::runtime::is_web;
+#[cfg(feature = "fs")]
 use workflow_store::fs;
 
 pub fn make_filename(title: &Option<String>, filename: &Option<String>) -> String {
@@ -34,6 +45,7 @@ pub fn make_filename(title: &Option<String>, filename: &Option<String>) -> Strin
 #[derive(Clone)]
 pub enum Store {
     Resident,
+    #[cfg(feature = "fs")]
     Storage(Storage),
 }
 
@@ -41,6 +53,7 @@ impl Store {
     fn filename(&self) -> Option<String> {
         match self {
             Store::Resident => None,
+            #[cfg(feature = "fs")]
             Store::Storage(storage) => Some(storage.filename_as_string()),
         }
     }
@@ -54,62 +67,180 @@ pub(crate) struct LocalStoreInner {
     pub cache: Arc<RwLock<Cache>>,
     pub store: RwLock<Arc<Store>>,
     pub transactions: Arc<dyn TransactionRecordStore>,
+    /// Account database storing the accounts and the account metadata of
+    /// wallets created with the database enabled (see [`kv`](super::kv)).
+    #[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+    pub accounts: Option<AccountDatabase>,
+    #[cfg_attr(not(feature = "fs"), allow(dead_code))]
     pub is_modified: AtomicBool,
 }
 
+#[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+fn make_account_database(folder: &str, filename: &str) -> Option<AccountDatabase> {
+    (!is_web()).then(|| AccountDatabase::new(folder, filename))
+}
+
+/// Account database of a newly created (or imported) wallet, if `enabled`.
+/// A database left behind by a previous wallet stored under the same
+/// filename is removed.
+#[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+fn create_account_database(folder: &str, filename: &str, enabled: bool) -> Result<Option<AccountDatabase>> {
+    let Some(database) = make_account_database(folder, filename) else {
+        return Ok(None);
+    };
+    if database.path().exists() {
+        std::fs::remove_file(database.path())?;
+    }
+    Ok(enabled.then_some(database))
+}
+
+/// Account database of an existing wallet, if the wallet has been created with one.
+#[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+fn open_account_database(folder: &str, filename: &str) -> Result<Option<AccountDatabase>> {
+    match make_account_database(folder, filename) {
+        Some(database) if database.is_initialized()? => Ok(Some(database)),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+fn make_native_transaction_store(folder: &str, filename: &str) -> Arc<dyn TransactionRecordStore> {
+    Arc::new(kv::TransactionStore::new(folder, filename))
+}
+
+#[cfg(all(feature = "fs", not(all(feature = "kv", not(target_arch = "wasm32")))))]
+fn make_native_transaction_store(folder: &str, filename: &str) -> Arc<dyn TransactionRecordStore> {
+    Arc::new(fsio::TransactionStore::new(folder, filename))
+}
+
+/// Locations of the wallet data stored alongside the wallet file on native
+/// platforms (the account database and the transaction record stores) under
+/// the `from` wallet filename and the respective locations under the `to`
+/// wallet filename.
+#[cfg(feature = "fs")]
+fn wallet_data_paths(folder: &str, from: &str, to: &str) -> Result<Vec<(PathBuf, PathBuf)>> {
+    if is_web() {
+        return Ok(vec![]);
+    }
+
+    let folder = fs::resolve_path(folder)?;
+    Ok(["accounts.db", "transactions", "transactions.db", "balance-history", "daa-timestamps"]
+        .iter()
+        .map(|suffix| (folder.join(format!("{from}.{suffix}")), folder.join(format!("{to}.{suffix}"))))
+        .collect())
+}
+
+#[cfg(feature = "fs")]
+fn make_transaction_store(folder: &str, filename: &str) -> Arc<dyn TransactionRecordStore> {
+    if !is_web() {
+        make_native_transaction_store(folder, filename)
+    } else {
+        Arc::new(indexdb::TransactionStore::new(filename))
+    }
+}
+
+#[cfg(not(feature = "fs"))]
+fn make_transaction_store(_folder: &str, filename: &str) -> Arc<dyn TransactionRecordStore> {
+    if !is_web() {
+        Arc::new(memory::TransactionStore::new())
+    } else {
+        Arc::new(indexdb::TransactionStore::new(filename))
+    }
+}
+
 impl LocalStoreInner {
     async fn try_create(wallet_secret: &Secret, folder: &str, args: CreateArgs, is_resident: bool) -> Result<Self> {
         let (store, wallet_title, filename) = if is_resident {
             (Store::Resident, Some("Resident Wallet".to_string()), "resident".to_string())
         } else {
             // log_info!("LocalStoreInner::try_create: folder: {}, args: {:?}, is_resident: {}", folder, args, is_resident);
-
-            let title = args.title.clone();
-            let filename = make_filename(&title, &args.filename);
-
-            let storage = Storage::try_new_with_folder(folder, &format!("{filename}.wallet"))?;
-            if storage.exists().await? && !args.overwrite_wallet {
-                return Err(Error::WalletAlreadyExists);
-            }
-            (Store::Storage(storage), title, filename)
+            Self::try_create_storage(folder, &args).await?
         };
 
         let payload = Payload::default();
         let cache =
             Arc::new(RwLock::new(Cache::from_payload(wallet_title, args.user_hint, payload, wallet_secret, args.encryption_kind)?));
         let is_modified = AtomicBool::new(false);
-        let transactions: Arc<dyn TransactionRecordStore> = if !is_web() {
-            Arc::new(fsio::TransactionStore::new(folder, &filename))
-        } else {
-            Arc::new(indexdb::TransactionStore::new(&filename))
-        };
+        let transactions = make_transaction_store(folder, &filename);
+        #[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+        let accounts = if is_resident { None } else { create_account_database(folder, &filename, args.account_database)? };
 
-        Ok(Self { cache, store: RwLock::new(Arc::new(store)), is_modified, transactions })
+        Ok(Self {
+            cache,
+            store: RwLock::new(Arc::new(store)),
+            is_modified,
+            transactions,
+            #[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+            accounts,
+        })
     }
 
+    #[cfg(feature = "fs")]
+    async fn try_create_storage(folder: &str, args: &CreateArgs) -> Result<(Store, Option<String>, String)> {
+        let title = args.title.clone();
+        let filename = make_filename(&title, &args.filename);
+
+        let storage = Storage::try_new_with_folder(folder, &format!("{filename}.wallet"))?;
+        if storage.exists().await? && !args.overwrite_wallet {
+            return Err(Error::WalletAlreadyExists);
+        }
+        Ok((Store::Storage(storage), title, filename))
+    }
+
+    #[cfg(not(feature = "fs"))]
+    async fn try_create_storage(_folder: &str, _args: &CreateArgs) -> Result<(Store, Option<String>, String)> {
+        Err(Error::FileStorageDisabled)
+    }
+
+    #[cfg(feature = "fs")]
     async fn try_load(wallet_secret: &Secret, folder: &str, args: OpenArgs) -> Result<Self> {
         let filename = make_filename(&None, &args.filename);
         let storage = Storage::try_new_with_folder(folder, &format!("{filename}.wallet"))?;
 
-        let wallet = WalletStorage::try_load(&storage).await?;
-        let cache = Arc::new(RwLock::new(Cache::from_wallet(wallet, wallet_secret)?));
+        let (wallet, report) = migration::migrate_storage(&storage, wallet_secret).await?;
+        if report.is_migrated() {
+            log!(
+                Storage,
+                Info,
+                "Wallet storage migrated from version {} to version {}, previous wallet data saved to '{}'",
+                report.from_version,
+                report.to_version,
+                report.backup.as_deref().unwrap_or_default()
+            );
+        }
+        #[cfg_attr(not(all(feature = "kv", not(target_arch = "wasm32"))), allow(unused_mut))]
+        let mut cache = Cache::from_wallet(wallet, wallet_secret)?;
+        #[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+        let accounts = open_account_database(folder, &filename)?;
+        #[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+        if let Some((stored_accounts, stored_metadata)) = accounts.as_ref().map(|db| db.load(wallet_secret)).transpose()?.flatten() {
+            cache.accounts = stored_accounts.try_into()?;
+            cache.metadata = stored_metadata.try_into()?;
+        }
+        let cache = Arc::new(RwLock::new(cache));
         let is_modified = AtomicBool::new(false);
 
-        let transactions: Arc<dyn TransactionRecordStore> = if !is_web() {
-            Arc::new(fsio::TransactionStore::new(folder, &filename))
-        } else {
-            Arc::new(indexdb::TransactionStore::new(&filename))
-        };
+        let transactions = make_transaction_store(folder, &filename);
 
-        Ok(Self { cache, store: RwLock::new(Arc::new(Store::Storage(storage))), is_modified, transactions })
+        Ok(Self {
+            cache,
+            store: RwLock::new(Arc::new(Store::Storage(storage))),
+            is_modified,
+            transactions,
+            #[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+            accounts,
+        })
     }
 
+    #[cfg(feature = "fs")]
     async fn try_import(wallet_secret: &Secret, folder: &str, serialized_wallet_storage: &[u8]) -> Result<Self> {
-        let wallet = WalletStorage::try_from_slice(serialized_wallet_storage)?;
+        let mut wallet = WalletStorage::try_from_slice(serialized_wallet_storage)?;
         // Try to decrypt the wallet payload with the provided
         // secret. This will block import if the secret is
         // not correct.
         let _ = wallet.payload(wallet_secret)?;
+        let version = WalletStorage::try_storage_version(serialized_wallet_storage)?;
+        migration::migrate(&mut wallet, version, wallet_secret)?;
 
         let filename = make_filename(&wallet.title, &None);
         let storage = Storage::try_new_with_folder(folder, &format!("{filename}.wallet"))?;
@@ -120,13 +251,16 @@ impl LocalStoreInner {
         let cache = Arc::new(RwLock::new(Cache::from_wallet(wallet, wallet_secret)?));
         let is_modified = AtomicBool::new(false);
 
-        let transactions: Arc<dyn TransactionRecordStore> = if !is_web() {
-            Arc::new(fsio::TransactionStore::new(folder, &filename))
-        } else {
-            Arc::new(indexdb::TransactionStore::new(&filename))
-        };
+        let transactions = make_transaction_store(folder, &filename);
 
-        Ok(Self { cache, store: RwLock::new(Arc::new(Store::Storage(storage))), is_modified, transactions })
+        Ok(Self {
+            cache,
+            store: RwLock::new(Arc::new(Store::Storage(storage))),
+            is_modified,
+            transactions,
+            #[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+            accounts: create_account_database(folder, &filename, false)?,
+        })
     }
 
     async fn try_export(&self, wallet_secret: &Secret, _options: WalletExportOptions) -> Result<Vec<u8>> {
@@ -138,19 +272,6 @@ impl LocalStoreInner {
         self.store.read().unwrap().clone()
     }
 
-    fn rename(&self, filename: &str) -> Result<()> {
-        let store = (**self.store.read().unwrap()).clone();
-        let filename = make_filename(&None, &Some(filename.to_string()));
-        match store {
-            Store::Resident => Err(Error::ResidentWallet),
-            Store::Storage(mut storage) => {
-                storage.rename_sync(filename.as_str())?;
-                *self.store.write().unwrap() = Arc::new(Store::Storage(storage));
-                Ok(())
-            }
-        }
-    }
-
     async fn change_secret(&self, old_secret: &Secret, new_secret: &Secret) -> Result<()> {
         match &*self.storage() {
             Store::Resident => {
@@ -161,16 +282,25 @@ impl LocalStoreInner {
 
                 Ok(())
             }
+            #[cfg(feature = "fs")]
             Store::Storage(ref storage) => {
-                let wallet = {
+                let previous = {
                     let mut cache = self.cache.write().unwrap();
                     let old_prv_key_data: Decrypted<PrvKeyDataMap> = cache.prv_key_data.decrypt(old_secret)?;
                     let new_prv_key_data = Decrypted::new(old_prv_key_data.unwrap()).encrypt(new_secret, cache.encryption_kind)?;
-                    cache.prv_key_data.replace(new_prv_key_data);
-
-                    cache.to_wallet(None, new_secret)?
+                    std::mem::replace(&mut cache.prv_key_data, new_prv_key_data)
                 };
-                wallet.try_store(storage).await?;
+
+                // accounts are re-encrypted with the new secret
+                #[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+                if let Some(accounts) = self.accounts.as_ref() {
+                    accounts.invalidate();
+                }
+                if let Err(err) = self.store_wallet(storage, new_secret).await {
+                    // the stored wallet data remains encrypted with the old secret
+                    self.cache.write().unwrap().prv_key_data = previous;
+                    return Err(err);
+                }
                 self.set_modified(false);
                 Ok(())
             }
@@ -180,7 +310,13 @@ impl LocalStoreInner {
     pub async fn update_stored_metadata(&self) -> Result<()> {
         match &*self.storage() {
             Store::Resident => Ok(()),
+            #[cfg(feature = "fs")]
             Store::Storage(ref storage) => {
+                #[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+                if let Some(accounts) = self.accounts.as_ref().filter(|accounts| accounts.is_initialized().unwrap_or(false)) {
+                    return accounts.store_metadata(&self.cache.read().unwrap().metadata.vec);
+                }
+
                 // take current metadata, load wallet, replace metadata, store wallet
                 // this bypasses the cache payload and wallet encryption
                 let metadata: Vec<AccountMetadata> = (&self.cache.read().unwrap().metadata).try_into()?;
@@ -192,6 +328,208 @@ impl LocalStoreInner {
         }
     }
 
+    /// Decode the private key data and the accounts of the stored wallet data entry
+    /// by entry. Returns `None` if the wallet data is not stored (resident storage).
+    #[cfg_attr(not(feature = "fs"), allow(unused_variables))]
+    async fn stored_entries(&self, wallet_secret: &Secret) -> Result<Option<PayloadEntries>> {
+        match &*self.storage() {
+            Store::Resident => Ok(None),
+            #[cfg(feature = "fs")]
+            Store::Storage(ref storage) => {
+                let wallet = WalletStorage::try_from_slice(&WalletStorage::try_load_bytes(storage).await?)?;
+                #[allow(unused_mut)]
+                let mut entries = PayloadEntries::try_from_slice(wallet.payload.decrypt_bytes(wallet_secret)?.as_ref())?;
+                #[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+                if let Some(accounts) = self.accounts.as_ref() {
+                    if accounts.is_initialized()? {
+                        entries.accounts = accounts.decode_accounts(wallet_secret)?;
+                    }
+                }
+                Ok(Some(entries))
+            }
+        }
+    }
+
+    /// Checks the private key data and the accounts decoded from the stored wallet data
+    /// (`stored`) or, if the wallet data is not stored, the cached entries.
+    fn check(&self, wallet_secret: &Secret, repair: bool, stored: Option<PayloadEntries>) -> Result<WalletCheckReport> {
+        let mut cache = self.cache.write().unwrap();
+        let mut report = WalletCheckReport::default();
+
+        let prv_key_data_map: Decrypted<PrvKeyDataMap> = cache.prv_key_data.decrypt(wallet_secret)?;
+        report.prv_key_data = prv_key_data_map.len();
+
+        let PayloadEntries { prv_key_data: prv_key_data_entries, accounts: account_entries } = match stored {
+            Some(stored) => stored,
+            None => PayloadEntries {
+                prv_key_data: prv_key_data_map
+                    .values()
+                    .map(|prv_key_data| (prv_key_data.id.to_hex(), Ok(prv_key_data.clone())))
+                    .collect(),
+                accounts: cache.accounts.vec.iter().map(|account| (account.id.to_hex(), Ok((**account).clone()))).collect(),
+            },
+        };
+
+        // invalid private key data is reported, but never removed
+        for (locator, prv_key_data) in prv_key_data_entries {
+            let prv_key_data = match prv_key_data.and_then(|prv_key_data| check_prv_key_data(&prv_key_data).map(|_| prv_key_data)) {
+                Ok(prv_key_data) => prv_key_data,
+                Err(err) => {
+                    report.issues.push(WalletCheckIssue::new(WalletCheckIssueKind::InvalidPrvKeyData, locator, err.to_string()));
+                    continue;
+                }
+            };
+            if !cache.prv_key_data_info.map.contains_key(&prv_key_data.id) {
+                let mut issue =
+                    WalletCheckIssue::new(WalletCheckIssueKind::MissingPrvKeyDataInfo, locator, "private key data info is missing");
+                if repair {
+                    cache.prv_key_data_info.insert(prv_key_data.id, Arc::new((&prv_key_data).into()))?;
+                    issue.repaired = true;
+                }
+                report.issues.push(issue);
+            }
+        }
+        let prv_key_data_ids = prv_key_data_map.keys().cloned().collect::<HashSet<_>>();
+
+        let orphans = cache.prv_key_data_info.map.keys().filter(|id| !prv_key_data_ids.contains(id)).cloned().collect::<Vec<_>>();
+        for id in orphans {
+            let mut issue = WalletCheckIssue::new(
+                WalletCheckIssueKind::OrphanPrvKeyDataInfo,
+                id.to_hex(),
+                "private key data info refers to missing private key data",
+            );
+            if repair {
+                cache.prv_key_data_info.remove(&[&id])?;
+                issue.repaired = true;
+            }
+            report.issues.push(issue);
+        }
+
+        report.accounts = cache.accounts.len();
+        for (locator, account) in account_entries {
+            if let Err(err) = account.and_then(|account| check_account(&account)) {
+                report.issues.push(WalletCheckIssue::new(WalletCheckIssueKind::InvalidAccount, locator, err.to_string()));
+            }
+        }
+
+        let mut orphans = vec![];
+        for account in cache.accounts.vec.iter() {
+            let missing = account.prv_key_data_ids.into_iter().filter(|id| !prv_key_data_ids.contains(id)).collect::<Vec<_>>();
+            if !missing.is_empty() {
+                let missing = missing.iter().map(|id| id.to_hex()).collect::<Vec<_>>().join(", ");
+                let mut issue = WalletCheckIssue::new(
+                    WalletCheckIssueKind::OrphanAccount,
+                    account.id.to_hex(),
+                    format!("account refers to missing private key data {missing}"),
+                );
+                issue.repaired = repair;
+                report.issues.push(issue);
+                orphans.push(account.id);
+            }
+        }
+        if repair && !orphans.is_empty() {
+            let orphans = orphans.iter().collect::<Vec<_>>();
+            cache.accounts.remove(&orphans)?;
+            cache.metadata.remove(&orphans)?;
+        }
+
+        report.metadata = cache.metadata.len();
+        let mut orphans = vec![];
+        for metadata in cache.metadata.vec.iter() {
+            if cache.accounts.map.contains_key(&metadata.id) {
+                continue;
+            }
+            let mut issue = WalletCheckIssue::new(
+                WalletCheckIssueKind::OrphanMetadata,
+                metadata.id.to_hex(),
+                "metadata refers to missing account",
+            );
+            issue.repaired = repair;
+            report.issues.push(issue);
+            orphans.push(metadata.id);
+        }
+        if repair && !orphans.is_empty() {
+            cache.metadata.remove(&orphans.iter().collect::<Vec<_>>())?;
+        }
+
+        if report.is_repaired() {
+            drop(cache);
+            self.set_modified(true);
+        }
+
+        Ok(report)
+    }
+
+    /// Re-encrypt the private key data (all private keys if `prv_key_data_id` is `None`)
+    /// with the new payment secret. The wallet is modified only if all selected private
+    /// keys are re-encrypted; the wallet file is backed up before the change is stored.
+    pub async fn change_payment_secret(
+        &self,
+        wallet_secret: &Secret,
+        prv_key_data_id: Option<&PrvKeyDataId>,
+        old_payment_secret: Option<&Secret>,
+        new_payment_secret: Option<&Secret>,
+    ) -> Result<PrvKeyDataSecretChangeReport> {
+        let (prv_key_data, prv_key_data_info) = {
+            let cache = self.cache.read().unwrap();
+            let mut prv_key_data_map: Decrypted<PrvKeyDataMap> = cache.prv_key_data.decrypt(wallet_secret)?;
+            let mut prv_key_data_info = vec![];
+            for prv_key_data in prv_key_data_map.values_mut().filter(|data| prv_key_data_id.map_or(true, |id| data.id == *id)) {
+                prv_key_data.change_payment_secret(old_payment_secret, new_payment_secret, cache.encryption_kind)?;
+                prv_key_data_info.push(Arc::new(PrvKeyDataInfo::from(&*prv_key_data)));
+            }
+            match prv_key_data_id {
+                Some(id) if prv_key_data_info.is_empty() => return Err(Error::PrivateKeyNotFound(*id)),
+                _ => (prv_key_data_map.encrypt(wallet_secret, cache.encryption_kind)?, prv_key_data_info),
+            }
+        };
+
+        let backup = self.backup(wallet_secret, "payment-secret").await?;
+
+        let (prv_key_data_ids, previous) = {
+            let mut cache = self.cache.write().unwrap();
+            let prv_key_data_ids = prv_key_data_info.iter().map(|info| info.id).collect::<Vec<_>>();
+            let previous_info = cache.prv_key_data_info.load_multiple(&prv_key_data_ids)?;
+            let previous = std::mem::replace(&mut cache.prv_key_data, prv_key_data);
+            for info in prv_key_data_info.iter() {
+                cache.prv_key_data_info.insert(info.id, info.clone())?;
+            }
+            (prv_key_data_ids, (previous, previous_info))
+        };
+        let is_modified = self.is_modified();
+        self.set_modified(true);
+        if let Err(err) = self.store(wallet_secret).await {
+            // the cached keys remain encrypted with the previous payment secret
+            let (previous, previous_info) = previous;
+            let mut cache = self.cache.write().unwrap();
+            cache.prv_key_data = previous;
+            for info in previous_info {
+                cache.prv_key_data_info.insert(info.id, info)?;
+            }
+            drop(cache);
+            self.set_modified(is_modified);
+            return Err(err);
+        }
+
+        Ok(PrvKeyDataSecretChangeReport { prv_key_data_ids, backup })
+    }
+
+    /// Write the wallet data (including the accounts stored in the account
+    /// database) to a backup location (`<filename>.<tag>.backup`).
+    #[cfg_attr(not(feature = "fs"), allow(unused_variables))]
+    async fn backup(&self, wallet_secret: &Secret, tag: &str) -> Result<Option<String>> {
+        match &*self.storage() {
+            Store::Resident => Ok(None),
+            #[cfg(feature = "fs")]
+            Store::Storage(ref storage) => {
+                let backup = storage.backup_filename(tag).await?;
+                let wallet = self.cache.read().unwrap().to_wallet(None, wallet_secret)?;
+                fs::write(&backup, &wallet.try_to_vec()?).await?;
+                Ok(Some(backup.to_string_lossy().to_string()))
+            }
+        }
+    }
+
     // pub fn cache(&self) -> &Cache {
     //     &self.cache
     // }
@@ -204,22 +542,58 @@ impl LocalStoreInner {
     //     self.cache.write().unwrap()
     // }
 
+    #[cfg_attr(not(feature = "fs"), allow(unused_variables))]
     pub async fn store(&self, wallet_secret: &Secret) -> Result<()> {
         match &*self.storage() {
             Store::Resident => Ok(()),
+            #[cfg(feature = "fs")]
             Store::Storage(ref storage) => {
-                let wallet = self.cache.read().unwrap().to_wallet(None, wallet_secret)?;
-                wallet.try_store(storage).await?;
+                self.store_wallet(storage, wallet_secret).await?;
                 self.set_modified(false);
                 Ok(())
             }
         }
     }
 
+    /// Write the wallet file. If the wallet uses the account database, the accounts and
+    /// the account metadata are stored in the database instead: the database changes are
+    /// staged while the wallet file is written and discarded if the write fails. If the
+    /// staged changes can not be committed, the previous wallet file is restored, so that
+    /// the wallet file and the accounts remain encrypted with the same secret.
+    #[cfg(feature = "fs")]
+    async fn store_wallet(&self, storage: &Storage, wallet_secret: &Secret) -> Result<()> {
+        #[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+        if let Some(accounts) = self.accounts.as_ref() {
+            let cache = self.cache.read().unwrap();
+            let staged = accounts.stage(wallet_secret, cache.encryption_kind, &cache.accounts.vec, &cache.metadata.vec)?;
+            let wallet = cache.to_wallet_without_accounts(wallet_secret)?;
+            drop(cache);
+
+            let previous = storage.exists_sync()?.then(|| std::fs::read(storage.filename())).transpose()?;
+            if let Err(err) = wallet.try_store_sync(storage) {
+                staged.abort()?;
+                return Err(err);
+            }
+            if let Err(err) = staged.commit() {
+                match previous {
+                    Some(previous) => std::fs::write(storage.filename(), previous)?,
+                    None => std::fs::remove_file(storage.filename())?,
+                }
+                return Err(err);
+            }
+            return Ok(());
+        }
+
+        let wallet = self.cache.read().unwrap().to_wallet(None, wallet_secret)?;
+        wallet.try_store(storage).await
+    }
+
     #[inline]
+    #[cfg_attr(not(feature = "fs"), allow(unused_variables))]
     pub fn set_modified(&self, modified: bool) {
         match &*self.storage() {
             Store::Resident => (),
+            #[cfg(feature = "fs")]
             Store::Storage(_) => {
                 self.is_modified.store(modified, Ordering::SeqCst);
             }
@@ -230,6 +604,7 @@ impl LocalStoreInner {
     pub fn is_modified(&self) -> bool {
         match &*self.storage() {
             Store::Resident => false,
+            #[cfg(feature = "fs")]
             Store::Storage(_) => self.is_modified.load(Ordering::SeqCst),
         }
     }
@@ -244,18 +619,51 @@ impl LocalStoreInner {
             .filename()
             .and_then(|f| PathBuf::from(f).file_stem().and_then(|f| f.to_str().map(String::from)))
             .unwrap_or_else(|| "resident".to_string());
-        WalletDescriptor { title: self.cache.read().unwrap().wallet_title.clone(), filename }
+        let cache = self.cache.read().unwrap();
+        WalletDescriptor { title: cache.wallet_title.clone(), filename, notes: cache.wallet_notes.clone() }
     }
 
     fn location(&self) -> Result<StorageDescriptor> {
         let store = self.storage();
         match &*store {
             Store::Resident => Ok(StorageDescriptor::Resident),
+            #[cfg(feature = "fs")]
             Store::Storage(storage) => Ok(StorageDescriptor::Internal(storage.filename_as_string())),
         }
     }
 }
 
+/// Validates the private key data id of the decoded private
+/// key data if the key data payload is not encrypted.
+fn check_prv_key_data(prv_key_data: &PrvKeyData) -> Result<()> {
+    if let Encryptable::Plain(payload) = &prv_key_data.payload {
+        if payload.id() != prv_key_data.id {
+            return Err(Error::custom("private key data id does not match the key data"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the account payload of the decoded account
+/// data for the built-in account kinds.
+fn check_account(account: &AccountStorage) -> Result<()> {
+    let serialized = account.serialized();
+    if account.kind == BIP32_ACCOUNT_KIND {
+        bip32::Payload::try_from_slice(serialized)?;
+    } else if account.kind == LEGACY_ACCOUNT_KIND {
+        legacy::Payload::try_from_slice(serialized)?;
+    } else if account.kind == MULTISIG_ACCOUNT_KIND {
+        multisig::Payload::try_from_slice(serialized)?;
+    } else if account.kind == KEYPAIR_ACCOUNT_KIND {
+        keypair::Payload::try_from_slice(serialized)?;
+    } else if !factories().contains_key(&account.kind) {
+        return Err(Error::AccountFactoryNotFound(account.kind));
+    }
+
+    Ok(())
+}
+
 impl Drop for LocalStoreInner {
     fn drop(&mut self) {
         if self.is_modified() {
@@ -289,13 +697,23 @@ pub(crate) struct LocalStore {
 }
 
 impl LocalStore {
+    #[cfg(feature = "fs")]
     pub fn try_new(is_resident: bool) -> Result<Self> {
-        Ok(Self {
+        Ok(Self::new(is_resident))
+    }
+
+    /// Create a store for resident (in-memory) wallets.
+    pub fn new_resident() -> Self {
+        Self::new(true)
+    }
+
+    fn new(is_resident: bool) -> Self {
+        Self {
             location: Arc::new(Mutex::new(Some(Arc::new(Location::default())))),
             inner: Arc::new(Mutex::new(None)),
             is_resident,
             batch: Arc::new(AtomicBool::new(false)),
-        })
+        }
     }
 
     pub fn inner(&self) -> Result<Arc<LocalStoreInner>> {
@@ -311,6 +729,7 @@ impl LocalStore {
         self.inner()?.try_export(wallet_secret, _options).await
     }
 
+    #[cfg(feature = "fs")]
     async fn wallet_import_impl(&self, wallet_secret: &Secret, serialized_wallet_storage: &[u8]) -> Result<WalletDescriptor> {
         let location = self.location().expect("initialized wallet storage location");
         let inner = LocalStoreInner::try_import(wallet_secret, &location.folder, serialized_wallet_storage).await?;
@@ -318,6 +737,81 @@ impl LocalStore {
         let wallet_descriptor = inner.descriptor();
         Ok(wallet_descriptor)
     }
+
+    /// Rename the wallet file (and the transaction data stored alongside the
+    /// wallet file on native platforms). As the transaction record store is bound
+    /// to the wallet filename, the wallet data is re-loaded from the renamed file.
+    #[cfg(feature = "fs")]
+    async fn rename_storage(&self, wallet_secret: &Secret, filename: &str) -> Result<()> {
+        if filename.is_empty() || filename.starts_with('.') || filename.contains(['/', '\\']) {
+            return Err(Error::custom(format!("invalid wallet filename '{filename}'")));
+        }
+
+        let location = self.location().ok_or(Error::WalletNotOpen)?;
+        let inner = self.inner()?;
+        let storage = inner.storage();
+        let Store::Storage(source) = &*storage else {
+            return Err(Error::ResidentWallet);
+        };
+        let current = inner.descriptor().filename;
+        if current == filename {
+            return Ok(());
+        }
+
+        let target = Storage::try_new_with_folder(&location.folder, &format!("{filename}.wallet"))?;
+        let mut renames = vec![(source.filename().clone(), target.filename().clone())];
+        for (from, to) in wallet_data_paths(&location.folder, &current, filename)? {
+            if fs::exists(&to).await? {
+                return Err(Error::WalletAlreadyExists);
+            }
+            if fs::exists(&from).await? {
+                renames.push((from, to));
+            }
+        }
+        if target.exists().await? {
+            return Err(Error::WalletAlreadyExists);
+        }
+
+        inner.store(wallet_secret).await?;
+        drop(inner);
+        self.inner.lock().unwrap().take();
+
+        let mut completed = vec![];
+        let mut result = Ok(());
+        for (from, to) in renames {
+            if let Err(err) = fs::rename(&from, &to).await {
+                result = Err(Error::from(err));
+                break;
+            }
+            completed.push((from, to));
+        }
+
+        // restore the already renamed files if the rename has failed
+        if result.is_err() {
+            for (from, to) in completed.into_iter().rev() {
+                if let Err(err) = fs::rename(&to, &from).await {
+                    log_error!("Unable to restore '{}' as '{}': {err}", to.display(), from.display());
+                }
+            }
+        }
+
+        // re-open the wallet under the original filename if the rename has failed
+        let filename = if result.is_ok() { filename.to_string() } else { current };
+        let inner = LocalStoreInner::try_load(wallet_secret, &location.folder, OpenArgs::new(Some(filename))).await?;
+        self.inner.lock().unwrap().replace(Arc::new(inner));
+
+        result
+    }
+
+    #[cfg(not(feature = "fs"))]
+    async fn rename_storage(&self, _wallet_secret: &Secret, _filename: &str) -> Result<()> {
+        Err(Error::ResidentWallet)
+    }
+
+    #[cfg(not(feature = "fs"))]
+    async fn wallet_import_impl(&self, _wallet_secret: &Secret, _serialized_wallet_storage: &[u8]) -> Result<WalletDescriptor> {
+        Err(Error::FileStorageDisabled)
+    }
 }
 
 #[async_trait]
@@ -346,15 +840,24 @@ impl Interface for LocalStore {
         Ok(self.inner()?.cache.read().unwrap().encryption_kind)
     }
 
-    async fn rename(&self, wallet_secret: &Secret, title: Option<&str>, filename: Option<&str>) -> Result<()> {
-        let inner = self.inner.lock().unwrap().clone().ok_or(Error::WalletNotOpen)?;
-        if let Some(title) = title {
-            inner.cache.write().unwrap().wallet_title = Some(title.to_string());
+    async fn rename(&self, wallet_secret: &Secret, title: Option<&str>, notes: Option<&str>, filename: Option<&str>) -> Result<()> {
+        if title.is_some() || notes.is_some() {
+            let inner = self.inner()?;
+            {
+                let mut cache = inner.cache.write().unwrap();
+                if let Some(title) = title {
+                    cache.wallet_title = Some(title.to_string());
+                }
+                if let Some(notes) = notes {
+                    cache.wallet_notes = (!notes.is_empty()).then(|| notes.to_string());
+                }
+            }
+            inner.set_modified(true);
             self.commit(wallet_secret).await?;
         }
 
         if let Some(filename) = filename {
-            inner.rename(filename)?;
+            self.rename_storage(wallet_secret, filename).await?;
         }
         Ok(())
     }
@@ -366,6 +869,22 @@ impl Interface for LocalStore {
         Ok(())
     }
 
+    async fn change_payment_secret(
+        &self,
+        wallet_secret: &Secret,
+        prv_key_data_id: Option<&PrvKeyDataId>,
+        old_payment_secret: Option<&Secret>,
+        new_payment_secret: Option<&Secret>,
+    ) -> Result<PrvKeyDataSecretChangeReport> {
+        self.inner()?.change_payment_secret(wallet_secret, prv_key_data_id, old_payment_secret, new_payment_secret).await
+    }
+
+    #[cfg(not(feature = "fs"))]
+    async fn exists(&self, _name: Option<&str>) -> Result<bool> {
+        Ok(false)
+    }
+
+    #[cfg(feature = "fs")]
     async fn exists(&self, name: Option<&str>) -> Result<bool> {
         let location = self.location.lock().unwrap().clone().unwrap();
         let store =
@@ -383,6 +902,12 @@ impl Interface for LocalStore {
         Ok(descriptor)
     }
 
+    #[cfg(not(feature = "fs"))]
+    async fn open(&self, _wallet_secret: &Secret, _args: OpenArgs) -> Result<()> {
+        Err(Error::FileStorageDisabled)
+    }
+
+    #[cfg(feature = "fs")]
     async fn open(&self, wallet_secret: &Secret, args: OpenArgs) -> Result<()> {
         if let Some(inner) = self.inner.lock().unwrap().as_ref() {
             if inner.is_modified() {
@@ -396,6 +921,12 @@ impl Interface for LocalStore {
         Ok(())
     }
 
+    #[cfg(not(feature = "fs"))]
+    async fn wallet_list(&self) -> Result<Vec<WalletDescriptor>> {
+        Ok(vec![])
+    }
+
+    #[cfg(feature = "fs")]
     async fn wallet_list(&self) -> Result<Vec<WalletDescriptor>> {
         let location = self.location.lock().unwrap().clone().unwrap();
 
@@ -414,9 +945,9 @@ impl Interface for LocalStore {
             let path = folder.join(format!("{}.wallet", filename));
             // TODO - refactor on native to read directly from file (skip temporary buffer creation)
             let wallet_data = fs::read(&path).await;
-            let title =
-                wallet_data.ok().and_then(|data| WalletStorage::try_from_slice(data.as_slice()).ok()).and_then(|wallet| wallet.title);
-            descriptors.push(WalletDescriptor { title, filename });
+            let wallet = wallet_data.ok().and_then(|data| WalletStorage::try_from_slice(data.as_slice()).ok());
+            let (title, notes) = wallet.map(|wallet| (wallet.title, wallet.notes)).unwrap_or_default();
+            descriptors.push(WalletDescriptor { title, filename, notes });
         }
 
         Ok(descriptors)
@@ -476,6 +1007,17 @@ impl Interface for LocalStore {
         Ok(())
     }
 
+    async fn get_webhook_secrets(&self) -> Result<Vec<WebhookSecret>> {
+        Ok(self.inner()?.cache.read().unwrap().webhook_secrets.clone())
+    }
+
+    async fn set_webhook_secrets(&self, wallet_secret: &Secret, secrets: Vec<WebhookSecret>) -> Result<()> {
+        let inner = self.inner()?;
+        inner.cache.write().unwrap().webhook_secrets = secrets;
+        inner.set_modified(true);
+        self.commit(wallet_secret).await
+    }
+
     async fn wallet_export(&self, wallet_secret: &Secret, options: WalletExportOptions) -> Result<Vec<u8>> {
         self.wallet_export_impl(wallet_secret, options).await
     }
@@ -483,6 +1025,26 @@ impl Interface for LocalStore {
     async fn wallet_import(&self, wallet_secret: &Secret, serialized_wallet_storage: &[u8]) -> Result<WalletDescriptor> {
         self.wallet_import_impl(wallet_secret, serialized_wallet_storage).await
     }
+
+    async fn check(&self, wallet_secret: &Secret, repair: bool) -> Result<WalletCheckReport> {
+        let inner = self.inner()?;
+        let stored = inner.stored_entries(wallet_secret).await?;
+        inner.check(wallet_secret, repair, stored)
+    }
+
+    #[cfg(not(feature = "fs"))]
+    async fn migrate(&self, _wallet_secret: &Secret, _name: Option<&str>) -> Result<WalletMigrationReport> {
+        Err(Error::FileStorageDisabled)
+    }
+
+    #[cfg(feature = "fs")]
+    async fn migrate(&self, wallet_secret: &Secret, name: Option<&str>) -> Result<WalletMigrationReport> {
+        let location = self.location.lock().unwrap().clone().unwrap();
+        let storage =
+            Storage::try_new_with_folder(&location.folder, &format!("{}.wallet", name.unwrap_or(super::default_wallet_file())))?;
+        let (_, report) = migration::migrate_storage(&storage, wallet_secret).await?;
+        Ok(report)
+    }
 }
 
 #[async_trait]
@@ -626,3 +1188,195 @@ impl AddressBookStore for LocalStoreInner {
         Ok(matches)
     }
 }
+
+#[cfg(all(test, feature = "fs"))]
+mod tests {
+    use super::*;
+    use crate::encryption::encrypt_xchacha20poly1305;
+    use kaspa_bip32::{Language, Mnemonic, WordCount};
+
+    #[tokio::test]
+    async fn test_storage_wallet_rename() -> Result<()> {
+        let secret = Secret::from("ABC-L4LXw2F7HEK3wJU-Rk4stbPy6c");
+        let args = |filename: &str| {
+            CreateArgs::new(Some("title".to_string()), Some(filename.to_string()), EncryptionKind::XChaCha20Poly1305, None, true)
+        };
+
+        let existing = LocalStore::try_new(false)?;
+        existing.create(&secret, args("test-wallet-rename-existing")).await?;
+        existing.commit(&secret).await?;
+
+        let store = LocalStore::try_new(false)?;
+        store.create(&secret, args("test-wallet-rename")).await?;
+        store.commit(&secret).await?;
+
+        store.rename(&secret, Some("renamed"), Some("notes"), None).await?;
+        let descriptor = store.descriptor().unwrap();
+        assert_eq!((descriptor.title.as_deref(), descriptor.notes.as_deref()), (Some("renamed"), Some("notes")));
+
+        // renaming to an existing wallet file fails, leaving the wallet open
+        let result = store.rename(&secret, None, None, Some("test-wallet-rename-existing")).await;
+        assert!(matches!(result, Err(Error::WalletAlreadyExists)));
+        assert!(matches!(store.rename(&secret, None, None, Some("../test")).await, Err(Error::Custom(_))));
+
+        store.rename(&secret, None, Some(""), Some("test-wallet-rename-target")).await?;
+        let descriptor = store.descriptor().unwrap();
+        assert_eq!(descriptor.filename, "test-wallet-rename-target");
+        assert_eq!((descriptor.title.as_deref(), descriptor.notes), (Some("renamed"), None));
+        assert!(!store.exists(Some("test-wallet-rename")).await?);
+
+        // the wallet data is re-loaded from the renamed wallet file
+        let reopened = LocalStore::try_new(false)?;
+        reopened.open(&secret, OpenArgs::new(Some("test-wallet-rename-target".to_string()))).await?;
+        assert_eq!(reopened.descriptor().unwrap().title.as_deref(), Some("renamed"));
+
+        let location = store.location().unwrap();
+        for filename in ["test-wallet-rename-existing", "test-wallet-rename-target"] {
+            Storage::try_new_with_folder(&location.folder, &format!("{filename}.wallet"))?.purge().await?;
+            for (path, _) in wallet_data_paths(&location.folder, filename, filename)? {
+                if path.is_file() {
+                    std::fs::remove_file(&path)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_storage_wallet_check() -> Result<()> {
+        let secret = Secret::from("ABC-L4LXw2F7HEK3wJU-Rk4stbPy6c");
+        let encryption_kind = EncryptionKind::XChaCha20Poly1305;
+        let name = format!("test-wallet-check-{}", faster_hex::hex_string(&rand::random::<[u8; 8]>()));
+
+        let (id, storage_key) = make_account_hashes(from_data(&BIP32_ACCOUNT_KIND.into(), name.as_bytes()));
+        let storable = bip32::Payload::new(0, ExtendedPublicKeys::default(), false, None);
+        let account = AccountStorage::try_new(
+            BIP32_ACCOUNT_KIND.into(),
+            &id,
+            &storage_key,
+            AssocPrvKeyDataIds::None,
+            AccountSettings::default(),
+            storable,
+        )?;
+        let header = account.try_to_vec()?[..8].to_vec();
+        let mut payload = Payload::default();
+        payload.accounts.push(account);
+        let storage = Storage::try_new(&format!("{name}.wallet"))?;
+        WalletStorage::try_new(None, None, &secret, encryption_kind, payload.clone(), vec![])?.try_store(&storage).await?;
+
+        let store = LocalStore::try_new(false)?;
+        store.open(&secret, OpenArgs::new(Some(name.clone()))).await?;
+        let report = store.check(&secret, false).await?;
+        assert!(report.is_ok(), "{:?}", report.issues);
+        assert_eq!(report.accounts, 1);
+
+        // the stored account data is validated (not the loaded account)
+        let mut bytes = payload.try_to_vec()?;
+        let offset = bytes.windows(header.len()).position(|window| window == header).expect("account storage header");
+        bytes[offset] ^= 0xff;
+        let mut wallet = WalletStorage::try_new(None, None, &secret, encryption_kind, Payload::default(), vec![])?;
+        wallet.payload = Encrypted::new(encryption_kind, encrypt_xchacha20poly1305(&bytes, &secret)?);
+        wallet.try_store(&storage).await?;
+
+        let report = store.check(&secret, false).await?;
+        let issues = report.issues.iter().map(|issue| (issue.kind, issue.id.as_str())).collect::<Vec<_>>();
+        assert_eq!(issues, vec![(WalletCheckIssueKind::InvalidAccount, "entry 0")]);
+
+        let location = store.location().unwrap();
+        store.close().await?;
+        storage.purge().await?;
+        for (path, _) in wallet_data_paths(&location.folder, &name, &name)? {
+            if path.is_file() {
+                std::fs::remove_file(&path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_storage_payment_secret_change() -> Result<()> {
+        let secret = Secret::from("ABC-L4LXw2F7HEK3wJU-Rk4stbPy6c");
+        let (old, new) = (Secret::from("old payment secret"), Secret::from("new payment secret"));
+        let encryption_kind = EncryptionKind::XChaCha20Poly1305;
+
+        let store = LocalStore::try_new(false)?;
+        let args = CreateArgs::new(None, Some("test-payment-secret".to_string()), encryption_kind, None, true);
+        store.create(&secret, args).await?;
+
+        let secret_key = secp256k1::SecretKey::from_slice(&[0xcd; 32]).unwrap();
+        let key = PrvKeyData::try_new_from_secret_key(secret_key, Some(&old), encryption_kind)?;
+        let mnemonic = Mnemonic::random(WordCount::Words12, Language::English)?;
+        let mnemonic_key = PrvKeyData::try_new_from_mnemonic(mnemonic, None, encryption_kind)?;
+        let (key_id, mnemonic_key_id) = (key.id, mnemonic_key.id);
+        let prv_key_data_store = store.as_prv_key_data_store()?;
+        prv_key_data_store.store(&secret, key).await?;
+        prv_key_data_store.store(&secret, mnemonic_key).await?;
+        store.commit(&secret).await?;
+
+        // the payment secret of mnemonics is the bip39 passphrase, no keys are modified
+        let result = store.change_payment_secret(&secret, None, Some(&old), Some(&new)).await;
+        assert!(matches!(result, Err(Error::PaymentSecretIsPassphrase(id)) if id == mnemonic_key_id));
+        assert!(store.change_payment_secret(&secret, Some(&key_id), Some(&new), Some(&old)).await.is_err());
+        let key = prv_key_data_store.load_key_data(&secret, &key_id).await?.unwrap();
+        assert_eq!(key.payload.decrypt(Some(&old))?.as_secret_key()?, Some(secret_key));
+
+        let report = store.change_payment_secret(&secret, Some(&key_id), Some(&old), Some(&new)).await?;
+        assert_eq!(report.prv_key_data_ids, vec![key_id]);
+        let key = prv_key_data_store.load_key_data(&secret, &key_id).await?.unwrap();
+        assert!(key.payload.decrypt(Some(&old)).is_err());
+        assert_eq!(key.payload.decrypt(Some(&new))?.as_secret_key()?, Some(secret_key));
+
+        // the backup contains the previous wallet data, the change is stored
+        let backup = PathBuf::from(report.backup.expect("wallet backup"));
+        let previous = LocalStore::try_new(false)?;
+        let location = store.location().unwrap();
+        let backup_name = backup.file_name().unwrap().to_string_lossy().to_string();
+        fs::rename(&backup, Storage::try_new_with_folder(&location.folder, &format!("{backup_name}.wallet"))?.filename()).await?;
+        previous.open(&secret, OpenArgs::new(Some(backup_name.clone()))).await?;
+        let key = previous.as_prv_key_data_store()?.load_key_data(&secret, &key_id).await?.unwrap();
+        assert!(key.payload.decrypt(Some(&old)).is_ok());
+        previous.close().await?;
+
+        let reopened = LocalStore::try_new(false)?;
+        reopened.open(&secret, OpenArgs::new(Some("test-payment-secret".to_string()))).await?;
+        let key = reopened.as_prv_key_data_store()?.load_key_data(&secret, &key_id).await?.unwrap();
+        assert!(key.payload.decrypt(Some(&new)).is_ok());
+        reopened.close().await?;
+
+        // a failure to store the change leaves the cached keys unmodified
+        let storage = Storage::try_new_with_folder(&location.folder, "test-payment-secret.wallet")?;
+        let bytes = std::fs::read(storage.filename())?;
+        std::fs::remove_file(storage.filename())?;
+        std::fs::create_dir(storage.filename())?;
+        assert!(store.change_payment_secret(&secret, Some(&key_id), Some(&new), Some(&old)).await.is_err());
+        std::fs::remove_dir(storage.filename())?;
+        std::fs::write(storage.filename(), &bytes)?;
+        let key = prv_key_data_store.load_key_data(&secret, &key_id).await?.unwrap();
+        assert!(key.payload.decrypt(Some(&old)).is_err());
+        assert_eq!(key.payload.decrypt(Some(&new))?.as_secret_key()?, Some(secret_key));
+        assert!(!store.inner()?.is_modified());
+
+        // removing the payment secret
+        store.change_payment_secret(&secret, Some(&key_id), Some(&new), None).await?;
+        assert!(!prv_key_data_store.load_key_info(&key_id).await?.unwrap().is_encrypted());
+
+        for filename in [backup_name.as_str(), "test-payment-secret"] {
+            Storage::try_new_with_folder(&location.folder, &format!("{filename}.wallet"))?.purge().await?;
+        }
+        for (path, _) in wallet_data_paths(&location.folder, "test-payment-secret", "test-payment-secret")? {
+            if path.is_file() {
+                std::fs::remove_file(&path)?;
+            }
+        }
+        for backup in ["payment-secret.backup", "payment-secret.backup.1"] {
+            let backup = Storage::try_new_with_folder(&location.folder, &format!("test-payment-secret.wallet.{backup}"))?;
+            if backup.exists().await? {
+                backup.purge().await?;
+            }
+        }
+
+        Ok(())
+    }
+}
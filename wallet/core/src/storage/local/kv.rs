@@ -0,0 +1,502 @@
+//!
+//! Embedded key-value database storage of the wallet accounts
+//! and account metadata (native platforms, `kv` feature).
+//!
+//! The wallet file is re-written as a whole on each change. Wallets created
+//! with the account database enabled (see
+//! [`CreateArgs::with_account_database()`](crate::storage::interface::CreateArgs::with_account_database))
+//! keep the accounts and the account metadata (updated on each address derivation)
+//! in a [`redb`] database stored alongside the wallet file instead, only the
+//! modified records being written. Accounts are encrypted individually using
+//! the wallet secret. The wallet file retains the private key data, the address
+//! book and the wallet settings.
+//!
+//! The account database is opt-in: the wallet file of such wallets is not
+//! self-contained, the `<name>.accounts.db` file must be copied (or backed up)
+//! together with the `<name>.wallet` file, or the wallet data exported using
+//! [`Interface::wallet_export()`](crate::storage::Interface::wallet_export).
+//! Wallets created without the database keep the accounts in the wallet file.
+//!
+//! The database changes are staged while the wallet file is written and
+//! committed only once the wallet file has been stored, so that the wallet
+//! file and the accounts remain encrypted with the same secret.
+//!
+
+use crate::encryption::*;
+use crate::imports::*;
+use crate::storage::local::transaction::kv::shared_database;
+use redb::{Database, ReadableTable, TableDefinition, TableError, WriteTransaction};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use workflow_store::fs;
+
+/// Encrypted accounts keyed by the account id, holding the account position
+type AccountTable<'a> = TableDefinition<'a, &'static str, (u64, &'static [u8])>;
+/// Account metadata keyed by the account id
+type MetadataTable<'a> = TableDefinition<'a, &'static str, &'static [u8]>;
+
+const ACCOUNTS: AccountTable = TableDefinition::new("accounts");
+const METADATA: MetadataTable = TableDefinition::new("metadata");
+
+fn db_error<E: std::fmt::Display>(err: E) -> Error {
+    Error::Custom(format!("Account database error: {err}"))
+}
+
+/// Digests of the records written (or loaded) during the session,
+/// used to skip writing unmodified records.
+#[derive(Default)]
+struct Digests {
+    accounts: HashMap<AccountId, Secret>,
+    metadata: HashMap<AccountId, Secret>,
+}
+
+pub struct AccountDatabase {
+    path: PathBuf,
+    database: Mutex<Option<Arc<Database>>>,
+    is_initialized: AtomicBool,
+    digests: Mutex<Digests>,
+}
+
+impl AccountDatabase {
+    pub fn new<P: AsRef<Path>>(folder: P, name: &str) -> AccountDatabase {
+        let folder = fs::resolve_path(folder.as_ref().to_str().unwrap()).expect("account database folder is invalid");
+        AccountDatabase {
+            path: folder.join(format!("{name}.accounts.db")),
+            database: Mutex::new(None),
+            is_initialized: AtomicBool::new(false),
+            digests: Mutex::new(Digests::default()),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn database(&self) -> Result<Arc<Database>> {
+        let mut database = self.database.lock().unwrap();
+        if let Some(database) = database.as_ref() {
+            return Ok(database.clone());
+        }
+
+        let db = shared_database(&self.path, db_error, |_| Ok(()))?;
+        database.replace(db.clone());
+        Ok(db)
+    }
+
+    /// Returns `true` if the wallet has been stored in the database
+    /// (the database holds the accounts of the wallet).
+    pub fn is_initialized(&self) -> Result<bool> {
+        if self.is_initialized.load(Ordering::SeqCst) {
+            return Ok(true);
+        }
+        if !self.path.exists() {
+            return Ok(false);
+        }
+
+        let transaction = self.database()?.begin_read().map_err(db_error)?;
+        let is_initialized = match transaction.open_table(ACCOUNTS) {
+            Ok(_) => true,
+            Err(TableError::TableDoesNotExist(_)) => false,
+            Err(err) => return Err(db_error(err)),
+        };
+        self.is_initialized.store(is_initialized, Ordering::SeqCst);
+        Ok(is_initialized)
+    }
+
+    /// Load the accounts (in the stored order) and the account metadata.
+    /// Returns `None` if the database has not been initialized.
+    pub fn load(&self, wallet_secret: &Secret) -> Result<Option<(Vec<AccountStorage>, Vec<AccountMetadata>)>> {
+        if !self.is_initialized()? {
+            return Ok(None);
+        }
+
+        let transaction = self.database()?.begin_read().map_err(db_error)?;
+        let mut digests = Digests::default();
+
+        let mut accounts = vec![];
+        for entry in transaction.open_table(ACCOUNTS).map_err(db_error)?.iter().map_err(db_error)? {
+            let (_, value) = entry.map_err(db_error)?;
+            let (position, data) = value.value();
+            let account = Encrypted::try_from_slice(data)?.decrypt::<AccountStorage>(wallet_secret)?.unwrap();
+            digests.accounts.insert(account.id, account_digest(position, &account)?);
+            accounts.push((position, account));
+        }
+        accounts.sort_by_key(|(position, _)| *position);
+        let accounts = accounts.into_iter().map(|(_, account)| account).collect::<Vec<_>>();
+
+        let mut metadata = vec![];
+        match transaction.open_table(METADATA) {
+            Ok(table) => {
+                for entry in table.iter().map_err(db_error)? {
+                    let (_, value) = entry.map_err(db_error)?;
+                    let data = value.value();
+                    let account_metadata = AccountMetadata::try_from_slice(data)?;
+                    digests.metadata.insert(account_metadata.id, sha256_hash(data));
+                    metadata.push(account_metadata);
+                }
+            }
+            Err(TableError::TableDoesNotExist(_)) => {}
+            Err(err) => return Err(db_error(err)),
+        }
+
+        *self.digests.lock().unwrap() = digests;
+        Ok(Some((accounts, metadata)))
+    }
+
+    /// Decode the stored accounts record by record (used by the storage check).
+    /// Each record is paired with its key (the hex-encoded account id).
+    pub fn decode_accounts(&self, wallet_secret: &Secret) -> Result<Vec<(String, Result<AccountStorage>)>> {
+        let transaction = self.database()?.begin_read().map_err(db_error)?;
+        let mut accounts = vec![];
+        for entry in transaction.open_table(ACCOUNTS).map_err(db_error)?.iter().map_err(db_error)? {
+            let (key, value) = entry.map_err(db_error)?;
+            let key = key.value().to_string();
+            let (_, data) = value.value();
+            let account = Encrypted::try_from_slice(data)
+                .map_err(Error::from)
+                .and_then(|encrypted| Ok(encrypted.decrypt::<AccountStorage>(wallet_secret)?.unwrap()))
+                .and_then(|account| {
+                    if account.id.to_hex() == key {
+                        Ok(account)
+                    } else {
+                        Err(Error::custom(format!("account {} is stored under a different id", account.id.to_hex())))
+                    }
+                });
+            accounts.push((key, account));
+        }
+        Ok(accounts)
+    }
+
+    /// Store the accounts and the account metadata, initializing the database.
+    /// Records that are not present in `accounts` or `metadata` are removed.
+    pub fn store(
+        &self,
+        wallet_secret: &Secret,
+        encryption_kind: EncryptionKind,
+        accounts: &[Arc<AccountStorage>],
+        metadata: &[Arc<AccountMetadata>],
+    ) -> Result<()> {
+        self.stage(wallet_secret, encryption_kind, accounts, metadata)?.commit()
+    }
+
+    /// Stage the storage of the accounts and the account metadata (see [`store()`](Self::store)).
+    /// The changes are written by [`StagedAccounts::commit()`] and discarded if the
+    /// staged changes are dropped or aborted.
+    pub fn stage(
+        &self,
+        wallet_secret: &Secret,
+        encryption_kind: EncryptionKind,
+        accounts: &[Arc<AccountStorage>],
+        metadata: &[Arc<AccountMetadata>],
+    ) -> Result<StagedAccounts<'_>> {
+        let transaction = self.database()?.begin_write().map_err(db_error)?;
+        let account_digests = store_accounts(&transaction, &self.digests.lock().unwrap(), wallet_secret, encryption_kind, accounts)?;
+        let metadata_digests = store_metadata(&transaction, &self.digests.lock().unwrap(), metadata)?;
+        Ok(StagedAccounts { database: self, transaction, digests: Digests { accounts: account_digests, metadata: metadata_digests } })
+    }
+
+    /// Store the account metadata. Metadata that is not present in `metadata` is removed.
+    pub fn store_metadata(&self, metadata: &[Arc<AccountMetadata>]) -> Result<()> {
+        let transaction = self.database()?.begin_write().map_err(db_error)?;
+        let metadata_digests = store_metadata(&transaction, &self.digests.lock().unwrap(), metadata)?;
+        transaction.commit().map_err(db_error)?;
+
+        self.digests.lock().unwrap().metadata = metadata_digests;
+        Ok(())
+    }
+
+    /// Causes all accounts to be re-written on the next store
+    /// (used when the accounts are re-encrypted with a new secret).
+    pub fn invalidate(&self) {
+        self.digests.lock().unwrap().accounts.clear();
+    }
+}
+
+/// Account database changes staged by [`AccountDatabase::stage()`].
+pub struct StagedAccounts<'a> {
+    database: &'a AccountDatabase,
+    transaction: WriteTransaction,
+    digests: Digests,
+}
+
+impl StagedAccounts<'_> {
+    /// Write the staged changes to the database.
+    pub fn commit(self) -> Result<()> {
+        let StagedAccounts { database, transaction, digests } = self;
+        transaction.commit().map_err(db_error)?;
+        *database.digests.lock().unwrap() = digests;
+        database.is_initialized.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Discard the staged changes.
+    pub fn abort(self) -> Result<()> {
+        self.transaction.abort().map_err(db_error)
+    }
+}
+
+fn account_digest(position: u64, account: &AccountStorage) -> Result<Secret> {
+    let mut data = position.to_le_bytes().to_vec();
+    data.extend(account.try_to_vec()?);
+    Ok(sha256_hash(&data))
+}
+
+fn is_modified(digests: &HashMap<AccountId, Secret>, id: &AccountId, digest: &Secret) -> bool {
+    digests.get(id).map_or(true, |stored| stored.as_ref() != digest.as_ref())
+}
+
+fn store_accounts(
+    transaction: &WriteTransaction,
+    digests: &Digests,
+    wallet_secret: &Secret,
+    encryption_kind: EncryptionKind,
+    accounts: &[Arc<AccountStorage>],
+) -> Result<HashMap<AccountId, Secret>> {
+    let mut table = transaction.open_table(ACCOUNTS).map_err(db_error)?;
+    let keys = accounts.iter().map(|account| account.id.to_hex()).collect::<HashSet<_>>();
+    remove_stale(&mut table, &keys)?;
+
+    let mut stored = HashMap::new();
+    for (position, account) in accounts.iter().enumerate() {
+        let position = position as u64;
+        let digest = account_digest(position, account)?;
+        if is_modified(&digests.accounts, &account.id, &digest) {
+            let data = Decrypted::new((**account).clone()).encrypt(wallet_secret, encryption_kind)?.try_to_vec()?;
+            table.insert(account.id.to_hex().as_str(), (position, data.as_slice())).map_err(db_error)?;
+        }
+        stored.insert(account.id, digest);
+    }
+    Ok(stored)
+}
+
+fn store_metadata(
+    transaction: &WriteTransaction,
+    digests: &Digests,
+    metadata: &[Arc<AccountMetadata>],
+) -> Result<HashMap<AccountId, Secret>> {
+    let mut table = transaction.open_table(METADATA).map_err(db_error)?;
+    let keys = metadata.iter().map(|metadata| metadata.id.to_hex()).collect::<HashSet<_>>();
+    remove_stale(&mut table, &keys)?;
+
+    let mut stored = HashMap::new();
+    for metadata in metadata.iter() {
+        let data = metadata.try_to_vec()?;
+        let digest = sha256_hash(&data);
+        if is_modified(&digests.metadata, &metadata.id, &digest) {
+            table.insert(metadata.id.to_hex().as_str(), data.as_slice()).map_err(db_error)?;
+        }
+        stored.insert(metadata.id, digest);
+    }
+    Ok(stored)
+}
+
+fn remove_stale<V>(table: &mut redb::Table<&'static str, V>, keys: &HashSet<String>) -> Result<()>
+where
+    V: redb::Value + 'static,
+{
+    let mut stale = vec![];
+    for entry in table.iter().map_err(db_error)? {
+        let (key, _) = entry.map_err(db_error)?;
+        if !keys.contains(key.value()) {
+            stale.push(key.value().to_string());
+        }
+    }
+    for key in stale {
+        table.remove(key.as_str()).map_err(db_error)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::derivation::AddressDerivationMeta;
+    use crate::storage::interface::{CreateArgs, OpenArgs, WalletExportOptions};
+    use crate::storage::local::interface::LocalStore;
+    use crate::storage::local::{default_storage_folder, Payload, Storage, WalletStorage};
+    use crate::storage::{AccountSettings, Interface};
+
+    fn make_folder() -> PathBuf {
+        let folder =
+            std::env::temp_dir().join(format!("kaspa-account-database-{}", faster_hex::hex_string(&rand::random::<[u8; 8]>())));
+        std::fs::create_dir_all(&folder).unwrap();
+        folder
+    }
+
+    fn make_account(name: &str) -> Result<AccountStorage> {
+        let (id, storage_key) = make_account_hashes(from_data(&BIP32_ACCOUNT_KIND.into(), name.as_bytes()));
+        let settings = AccountSettings { name: Some(name.to_string()), ..Default::default() };
+        let storable = bip32::Payload::new(0, ExtendedPublicKeys::default(), false, None);
+        AccountStorage::try_new(BIP32_ACCOUNT_KIND.into(), &id, &storage_key, PrvKeyDataId::new(0xcafe).into(), settings, storable)
+    }
+
+    fn names(accounts: &[AccountStorage]) -> Vec<Option<String>> {
+        accounts.iter().map(|account| account.settings.name.clone()).collect()
+    }
+
+    #[test]
+    fn test_storage_account_database() -> Result<()> {
+        let folder = make_folder();
+        let secret = Secret::from("ABC-L4LXw2F7HEK3wJU-Rk4stbPy6c");
+        let encryption_kind = EncryptionKind::XChaCha20Poly1305;
+        let accounts =
+            ["b", "a", "c"].into_iter().map(make_account).collect::<Result<Vec<_>>>()?.into_iter().map(Arc::new).collect::<Vec<_>>();
+        let metadata = accounts
+            .iter()
+            .map(|account| Arc::new(AccountMetadata::new(account.id, AddressDerivationMeta::new(1, 1))))
+            .collect::<Vec<_>>();
+
+        let database = AccountDatabase::new(&folder, "test");
+        assert!(database.load(&secret)?.is_none());
+        database.store(&secret, encryption_kind, &accounts, &metadata)?;
+
+        // the accounts are loaded in the stored order
+        let database = AccountDatabase::new(&folder, "test");
+        let (loaded, loaded_metadata) = database.load(&secret)?.expect("initialized database");
+        assert_eq!(names(&loaded), vec![Some("b".to_string()), Some("a".to_string()), Some("c".to_string())]);
+        assert_eq!(loaded_metadata.len(), 3);
+        assert!(AccountDatabase::new(&folder, "test").load(&Secret::from("invalid")).is_err());
+
+        // records are decoded individually by the storage check
+        let decoded = database.decode_accounts(&secret)?;
+        assert_eq!(decoded.len(), 3);
+        assert!(decoded.iter().all(|(key, account)| account.as_ref().is_ok_and(|account| account.id.to_hex() == *key)));
+        let transaction = database.database()?.begin_write().map_err(db_error)?;
+        transaction
+            .open_table(ACCOUNTS)
+            .map_err(db_error)?
+            .insert(accounts[2].id.to_hex().as_str(), (2, [0u8; 8].as_slice()))
+            .map_err(db_error)?;
+        transaction.commit().map_err(db_error)?;
+        let invalid = database
+            .decode_accounts(&secret)?
+            .into_iter()
+            .filter(|(_, account)| account.is_err())
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+        assert_eq!(invalid, vec![accounts[2].id.to_hex()]);
+        database.invalidate();
+        database.store(&secret, encryption_kind, &accounts, &metadata)?;
+
+        // metadata updates and account removal
+        let metadata = vec![Arc::new(AccountMetadata::new(accounts[1].id, AddressDerivationMeta::new(7, 3)))];
+        database.store_metadata(&metadata)?;
+        database.store(&secret, encryption_kind, &accounts[1..], &metadata)?;
+        let (loaded, loaded_metadata) = AccountDatabase::new(&folder, "test").load(&secret)?.expect("initialized database");
+        assert_eq!(names(&loaded), vec![Some("a".to_string()), Some("c".to_string())]);
+        assert_eq!(loaded_metadata.len(), 1);
+        assert_eq!(loaded_metadata[0].address_derivation_indexes().map(|indexes| indexes.receive()), Some(7));
+
+        drop(database);
+        std::fs::remove_dir_all(&folder)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_storage_account_database_wallet() -> Result<()> {
+        let secret = Secret::from("ABC-L4LXw2F7HEK3wJU-Rk4stbPy6c");
+        let encryption_kind = EncryptionKind::XChaCha20Poly1305;
+        let suffix = faster_hex::hex_string(&rand::random::<[u8; 8]>());
+        let (legacy, created) = (format!("test-account-database-{suffix}"), format!("test-account-database-created-{suffix}"));
+        let open = |name: String| {
+            let secret = secret.clone();
+            async move {
+                let store = LocalStore::try_new(false)?;
+                store.open(&secret, OpenArgs::new(Some(name))).await?;
+                Ok::<_, Error>(store)
+            }
+        };
+
+        // a wallet file holding the accounts (created without the account database)
+        let account = make_account("legacy")?;
+        let mut payload = Payload::default();
+        payload.accounts.push(account.clone());
+        let metadata = AccountMetadata::new(account.id, AddressDerivationMeta::new(1, 1));
+        let wallet = WalletStorage::try_new(None, None, &secret, encryption_kind, payload, vec![metadata])?;
+        let storage = Storage::try_new(&format!("{legacy}.wallet"))?;
+        wallet.try_store(&storage).await?;
+
+        // the accounts remain in the wallet file
+        let store = open(legacy.clone()).await?;
+        assert_eq!(store.as_account_store()?.len(None).await?, 1);
+        store.inner()?.set_modified(true);
+        store.commit(&secret).await?;
+        store.close().await?;
+        let wallet = WalletStorage::try_load(&storage).await?;
+        assert_eq!(wallet.payload(&secret)?.accounts.len(), 1);
+        assert!(!AccountDatabase::new(default_storage_folder(), &legacy).path().exists());
+
+        // wallets created with the account database keep the accounts in the database
+        let store = LocalStore::try_new(false)?;
+        let args = CreateArgs::new(None, Some(created.clone()), encryption_kind, None, true).with_account_database(true);
+        store.create(&secret, args).await?;
+        store.as_account_store()?.store_single(&make_account("created")?, Some(&metadata_for("created")?)).await?;
+        store.commit(&secret).await?;
+        store.close().await?;
+        let storage = Storage::try_new(&format!("{created}.wallet"))?;
+        let wallet = WalletStorage::try_load(&storage).await?;
+        assert!(wallet.payload(&secret)?.accounts.is_empty() && wallet.metadata.is_empty());
+        let (accounts, _) = AccountDatabase::new(default_storage_folder(), &created).load(&secret)?.expect("initialized database");
+        assert_eq!(names(&accounts), vec![Some("created".to_string())]);
+        let account_id = accounts[0].id;
+
+        // metadata updates do not re-write the wallet file
+        let store = open(created.clone()).await?;
+        let bytes = std::fs::read(storage.filename())?;
+        let metadata = AccountMetadata::new(account_id, AddressDerivationMeta::new(9, 1));
+        store.as_account_store()?.update_metadata(vec![metadata]).await?;
+        assert_eq!(std::fs::read(storage.filename())?, bytes);
+        store.close().await?;
+
+        let store = open(created.clone()).await?;
+        let (_, metadata) = store.as_account_store()?.load_single(&account_id).await?.expect("stored account");
+        assert_eq!(metadata.and_then(|metadata| metadata.address_derivation_indexes()).map(|indexes| indexes.receive()), Some(9));
+
+        // the exported wallet data holds the accounts
+        let options = WalletExportOptions { include_transactions: false };
+        let exported = WalletStorage::try_from_slice(&store.wallet_export(&secret, options).await?)?;
+        assert_eq!(exported.payload(&secret)?.accounts.len(), 1);
+        assert_eq!(exported.metadata.len(), 1);
+
+        // a failure to write the wallet file discards the re-encrypted accounts
+        let new_secret = Secret::from("new secret");
+        std::fs::remove_file(storage.filename())?;
+        std::fs::create_dir(storage.filename())?;
+        assert!(store.change_secret(&secret, &new_secret).await.is_err());
+        std::fs::remove_dir(storage.filename())?;
+        std::fs::write(storage.filename(), &bytes)?;
+        assert!(AccountDatabase::new(default_storage_folder(), &created).load(&new_secret).is_err());
+        assert_eq!(AccountDatabase::new(default_storage_folder(), &created).load(&secret)?.expect("initialized database").0.len(), 1);
+        store.change_secret(&secret, &new_secret).await?;
+        store.close().await?;
+        assert!(open(created.clone()).await.is_err());
+        let store = LocalStore::try_new(false)?;
+        store.open(&new_secret, OpenArgs::new(Some(created.clone()))).await?;
+        assert_eq!(store.as_account_store()?.len(None).await?, 1);
+        store.close().await?;
+
+        // a database left behind under the filename of a new wallet is removed
+        let store = LocalStore::try_new(false)?;
+        store.create(&secret, CreateArgs::new(None, Some(created.clone()), encryption_kind, None, true)).await?;
+        assert!(!AccountDatabase::new(default_storage_folder(), &created).path().exists());
+        store.as_account_store()?.store_single(&make_account("file")?, None).await?;
+        store.commit(&secret).await?;
+        store.close().await?;
+        let wallet = WalletStorage::try_load(&storage).await?;
+        assert_eq!(wallet.payload(&secret)?.accounts.len(), 1);
+
+        for name in [legacy, created] {
+            Storage::try_new(&format!("{name}.wallet"))?.purge().await?;
+            let database = Storage::try_new(&format!("{name}.accounts.db"))?;
+            if database.exists().await? {
+                database.purge().await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn metadata_for(name: &str) -> Result<AccountMetadata> {
+        Ok(AccountMetadata::new(make_account(name)?.id, AddressDerivationMeta::new(1, 1)))
+    }
+}
@@ -0,0 +1,189 @@
+//!
+//! Versioned wallet storage migrations.
+//!
+//! Each [`Migration`] upgrades the [`WalletStorage`] data from the previous
+//! storage version to [`Migration::version`]. Migrations are applied in order
+//! when a wallet file with an older storage version is opened or imported.
+//! Before the upgraded wallet file is written, the original file is preserved
+//! as `<name>.wallet.v<version>.backup` next to it.
+//!
+
+use crate::imports::*;
+use crate::storage::local::wallet::WalletStorage;
+use crate::storage::local::Payload;
+#[cfg(feature = "fs")]
+use crate::storage::local::Storage;
+#[cfg(feature = "fs")]
+use workflow_store::fs;
+
+/// Wallet storage migration upgrading the wallet data to [`Migration::version`].
+pub struct Migration {
+    /// Storage version produced by this migration.
+    pub version: u32,
+    pub description: &'static str,
+    pub migrate: fn(&mut WalletStorage, &Secret) -> Result<()>,
+}
+
+/// Registry of the wallet storage migrations ordered by version. Storage
+/// versions that only append fields to the wallet data (decoded by the
+/// [`WalletStorage`] deserializer) require no migration.
+pub static MIGRATIONS: &[Migration] = &[Migration { version: 1, description: "compact wallet data", migrate: compact }];
+
+/// Result of the wallet storage migration.
+#[derive(Debug, Clone, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletMigrationReport {
+    pub from_version: u32,
+    pub to_version: u32,
+    /// Descriptions of the applied migrations.
+    pub applied: Vec<String>,
+    /// Location of the backup of the original wallet file.
+    pub backup: Option<String>,
+}
+
+impl WalletMigrationReport {
+    pub fn is_migrated(&self) -> bool {
+        !self.applied.is_empty()
+    }
+}
+
+/// Apply all migrations newer than `version` to the wallet data,
+/// returning the applied migrations.
+pub fn migrate(wallet: &mut WalletStorage, version: u32, secret: &Secret) -> Result<Vec<&'static Migration>> {
+    let mut applied = vec![];
+    for migration in MIGRATIONS.iter().filter(|migration| migration.version > version) {
+        (migration.migrate)(wallet, secret)
+            .map_err(|err| Error::custom(format!("wallet storage migration to version {} failed: {err}", migration.version)))?;
+        applied.push(migration);
+    }
+    Ok(applied)
+}
+
+/// Load the wallet data, migrating it to the current storage version if needed.
+/// If any migrations are applied, the original wallet file is backed up and the
+/// migrated wallet data is written to the storage.
+#[cfg(feature = "fs")]
+pub async fn migrate_storage(storage: &Storage, secret: &Secret) -> Result<(WalletStorage, WalletMigrationReport)> {
+    let bytes = WalletStorage::try_load_bytes(storage).await?;
+    let from_version = WalletStorage::try_storage_version(&bytes)?;
+    let mut wallet = WalletStorage::try_from_slice(&bytes)?;
+    // validate the secret even if there is nothing to migrate
+    wallet.payload(secret)?;
+
+    let applied = migrate(&mut wallet, from_version, secret)?;
+    let backup = if applied.is_empty() {
+        None
+    } else {
+        let backup = storage.backup_filename(&format!("v{from_version}")).await?;
+        fs::write(&backup, &bytes).await?;
+        wallet.try_store(storage).await?;
+        Some(backup.to_string_lossy().to_string())
+    };
+
+    let report = WalletMigrationReport {
+        from_version,
+        to_version: WalletStorage::STORAGE_VERSION.max(from_version),
+        applied: applied.iter().map(|migration| migration.description.to_string()).collect(),
+        backup,
+    };
+
+    Ok((wallet, report))
+}
+
+/// Version 1: deterministic wallet data layout. Removes duplicate private key
+/// data, accounts and account metadata, drops metadata of missing accounts,
+/// orders private key data by id and account metadata by the account order,
+/// and re-encrypts the payload, re-serializing all records at their current
+/// storage versions.
+fn compact(wallet: &mut WalletStorage, secret: &Secret) -> Result<()> {
+    let Payload { prv_key_data, accounts, address_book, encrypt_transactions, webhook_secrets } = wallet.payload(secret)?.unwrap();
+
+    let mut prv_key_data = prv_key_data.into_iter().map(|prv_key_data| (prv_key_data.id, prv_key_data)).collect::<Vec<_>>();
+    prv_key_data.sort_by_key(|(id, _)| *id);
+    prv_key_data.dedup_by_key(|(id, _)| *id);
+    let prv_key_data = prv_key_data.into_iter().map(|(_, prv_key_data)| prv_key_data).collect::<Vec<_>>();
+
+    let mut account_ids = HashSet::new();
+    let accounts = accounts.into_iter().filter(|account| account_ids.insert(account.id)).collect::<Vec<_>>();
+
+    let mut metadata =
+        std::mem::take(&mut wallet.metadata).into_iter().map(|metadata| (metadata.id, metadata)).collect::<HashMap<_, _>>();
+    wallet.metadata = accounts.iter().filter_map(|account| metadata.remove(&account.id)).collect();
+
+    let payload = Payload { prv_key_data, accounts, address_book, encrypt_transactions, webhook_secrets };
+    wallet.payload = Decrypted::new(payload).encrypt(secret, wallet.encryption_kind)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{AccountMetadata, PrvKeyData};
+    use kaspa_bip32::{Language, Mnemonic, WordCount};
+    #[cfg(feature = "fs")]
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_storage_wallet_migration() -> Result<()> {
+        assert!(MIGRATIONS.windows(2).all(|pair| pair[0].version < pair[1].version));
+        assert!(MIGRATIONS.iter().all(|migration| migration.version <= WalletStorage::STORAGE_VERSION));
+
+        let secret = Secret::from("ABC-L4LXw2F7HEK3wJU-Rk4stbPy6c");
+        let mnemonic = Mnemonic::random(WordCount::Words12, Language::English)?;
+        let prv_key_data = PrvKeyData::try_new_from_mnemonic(mnemonic, None, EncryptionKind::XChaCha20Poly1305)?;
+        let orphan = AccountMetadata::new_without_indexes(AccountId::from_hex(&"ff".repeat(32))?);
+
+        let payload = Payload::new(vec![prv_key_data.clone(), prv_key_data.clone()], vec![], vec![]);
+        let mut wallet =
+            WalletStorage::try_new(None, None, &secret, EncryptionKind::XChaCha20Poly1305, payload, vec![orphan.clone(), orphan])?;
+
+        let applied = migrate(&mut wallet, 0, &secret)?;
+        assert_eq!(applied.len(), MIGRATIONS.len());
+        assert!(wallet.metadata.is_empty());
+        let payload = wallet.payload(&secret)?;
+        assert_eq!(payload.prv_key_data.len(), 1);
+        assert_eq!(payload.prv_key_data[0].id, prv_key_data.id);
+
+        // the current storage version requires no migrations
+        assert!(migrate(&mut wallet, WalletStorage::STORAGE_VERSION, &secret)?.is_empty());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[tokio::test]
+    async fn test_storage_wallet_migrate_storage() -> Result<()> {
+        let secret = Secret::from("ABC-L4LXw2F7HEK3wJU-Rk4stbPy6c");
+        let storage = Storage::try_new("test-wallet-migration.wallet")?;
+        let wallet = WalletStorage::try_new(None, None, &secret, EncryptionKind::XChaCha20Poly1305, Payload::default(), vec![])?;
+
+        // store the wallet data with the storage version 0 header
+        // (omitting the wallet notes introduced by the storage version 2)
+        let mut bytes = wallet.try_to_vec()?;
+        bytes[4..8].copy_from_slice(&0u32.to_le_bytes());
+        bytes.pop();
+        storage.ensure_dir().await?;
+        fs::write(storage.filename(), &bytes).await?;
+
+        let (_, report) = migrate_storage(&storage, &secret).await?;
+        assert!(report.is_migrated());
+        assert_eq!(report.from_version, 0);
+        assert_eq!(report.to_version, WalletStorage::STORAGE_VERSION);
+        let backup = PathBuf::from(report.backup.expect("wallet backup"));
+        assert_eq!(fs::read(&backup).await?, bytes);
+        assert_eq!(
+            WalletStorage::try_storage_version(&WalletStorage::try_load_bytes(&storage).await?)?,
+            WalletStorage::STORAGE_VERSION
+        );
+
+        // the migrated wallet is up to date
+        let (_, report) = migrate_storage(&storage, &secret).await?;
+        assert!(!report.is_migrated() && report.backup.is_none());
+
+        fs::remove(&backup).await?;
+        storage.purge().await?;
+
+        Ok(())
+    }
+}
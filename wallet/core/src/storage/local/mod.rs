@@ -9,24 +9,34 @@
 //! `chrome.storage.local` API. The implementation
 //! is backed by the [`workflow_store`](https://docs.rs/workflow-store/)
 //! crate.
+//!
+//! File-backed storage requires the `fs` crate feature;
+//! without it only resident (in-memory) wallets are available.
 
 pub mod cache;
 pub mod collection;
 pub mod interface;
+#[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+pub mod kv;
+pub mod migration;
 pub mod payload;
+#[cfg(feature = "fs")]
 pub mod storage;
 pub mod streams;
 pub mod transaction;
 pub mod wallet;
 
 pub use collection::Collection;
-pub use payload::Payload;
+pub use payload::{Payload, PayloadEntries};
+#[cfg(feature = "fs")]
 pub use storage::Storage;
 pub use wallet::WalletStorage;
 
+#[cfg(feature = "fs")]
 use crate::error::Error;
 use crate::result::Result;
 use wasm_bindgen::prelude::*;
+#[cfg(feature = "fs")]
 use workflow_store::fs::create_dir_all_sync;
 
 static mut DEFAULT_STORAGE_FOLDER: Option<String> = None;
@@ -76,6 +86,7 @@ pub fn default_settings_file() -> &'static str {
 /// the default storage folder once the wallet has been
 /// initialized.
 ///
+#[cfg(feature = "fs")]
 pub unsafe fn set_default_storage_folder(folder: String) -> Result<()> {
     create_dir_all_sync(&folder).map_err(|err| Error::custom(format!("Failed to create storage folder: {err}")))?;
     DEFAULT_STORAGE_FOLDER = Some(folder);
@@ -98,6 +109,7 @@ pub unsafe fn set_default_storage_folder(folder: String) -> Result<()> {
 /// @param {String} folder - the path to the storage folder
 ///
 /// @category Wallet API
+#[cfg(feature = "fs")]
 #[wasm_bindgen(js_name = setDefaultStorageFolder, skip_jsdoc)]
 pub fn js_set_default_storage_folder(folder: String) -> Result<()> {
     // SAFETY: This is unsafe because we are setting a static mut variable
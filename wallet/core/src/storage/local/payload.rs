@@ -4,6 +4,7 @@
 
 use crate::imports::*;
 use crate::storage::{AddressBookEntry, PrvKeyData, PrvKeyDataId};
+use crate::webhooks::WebhookSecret;
 use kaspa_bip32::Mnemonic;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
@@ -13,22 +14,75 @@ pub struct Payload {
     pub accounts: Vec<AccountStorage>,
     pub address_book: Vec<AddressBookEntry>,
     pub encrypt_transactions: Option<EncryptionKind>,
+    /// Webhook payload signing secrets (see [`WebhookSecret`]).
+    pub webhook_secrets: Vec<WebhookSecret>,
 }
 
 impl Payload {
     const STORAGE_MAGIC: u32 = 0x41544144;
-    const STORAGE_VERSION: u32 = 0;
+    const STORAGE_VERSION: u32 = 1;
 
     pub fn new(prv_key_data: Vec<PrvKeyData>, accounts: Vec<AccountStorage>, address_book: Vec<AddressBookEntry>) -> Self {
-        Self { prv_key_data, accounts, address_book, encrypt_transactions: None }
+        Self { prv_key_data, accounts, address_book, encrypt_transactions: None, webhook_secrets: vec![] }
     }
 }
 
+/// Private key data and accounts of a serialized [`Payload`], decoded entry by
+/// entry. Each entry is paired with its locator: the hex-encoded id of the decoded
+/// entry or the position of the entry that could not be decoded.
+#[derive(Default)]
+pub struct PayloadEntries {
+    pub prv_key_data: Vec<(String, Result<PrvKeyData>)>,
+    pub accounts: Vec<(String, Result<AccountStorage>)>,
+}
+
+impl PayloadEntries {
+    /// Decode the entries of the serialized payload. Decoding stops at the first
+    /// entry that can not be decoded, as the following entries can not be located.
+    pub fn try_from_slice(bytes: &[u8]) -> Result<Self> {
+        let mut buf = bytes;
+        StorageHeader::deserialize(&mut buf)?.try_magic(Payload::STORAGE_MAGIC)?.try_version(Payload::STORAGE_VERSION)?;
+
+        let mut entries = Self::default();
+        if decode_entries(&mut buf, &mut entries.prv_key_data, |prv_key_data: &PrvKeyData| prv_key_data.id.to_hex()) {
+            decode_entries(&mut buf, &mut entries.accounts, |account: &AccountStorage| account.id.to_hex());
+        }
+        Ok(entries)
+    }
+}
+
+/// Decode a serialized vector entry by entry, returning `false`
+/// if an entry (or the vector length) could not be decoded.
+fn decode_entries<T, F>(buf: &mut &[u8], entries: &mut Vec<(String, Result<T>)>, locator: F) -> bool
+where
+    T: BorshDeserialize,
+    F: Fn(&T) -> String,
+{
+    let len = match <u32 as BorshDeserialize>::deserialize(buf) {
+        Ok(len) => len,
+        Err(err) => {
+            entries.push(("entries".to_string(), Err(err.into())));
+            return false;
+        }
+    };
+    for position in 0..len {
+        match T::deserialize(buf) {
+            Ok(entry) => entries.push((locator(&entry), Ok(entry))),
+            Err(err) => {
+                entries.push((format!("entry {position}"), Err(err.into())));
+                return false;
+            }
+        }
+    }
+    true
+}
+
 impl ZeroizeOnDrop for Payload {}
 
 impl Zeroize for Payload {
     fn zeroize(&mut self) {
         self.prv_key_data.zeroize();
+        self.webhook_secrets.zeroize();
     }
 }
 
@@ -61,6 +115,7 @@ impl BorshSerialize for Payload {
         BorshSerialize::serialize(&self.accounts, writer)?;
         BorshSerialize::serialize(&self.address_book, writer)?;
         BorshSerialize::serialize(&self.encrypt_transactions, writer)?;
+        BorshSerialize::serialize(&self.webhook_secrets, writer)?;
 
         Ok(())
     }
@@ -68,14 +123,15 @@ impl BorshSerialize for Payload {
 
 impl BorshDeserialize for Payload {
     fn deserialize(buf: &mut &[u8]) -> IoResult<Self> {
-        let StorageHeader { version: _, .. } =
+        let StorageHeader { version, .. } =
             StorageHeader::deserialize(buf)?.try_magic(Self::STORAGE_MAGIC)?.try_version(Self::STORAGE_VERSION)?;
         let prv_key_data = BorshDeserialize::deserialize(buf)?;
         let accounts = BorshDeserialize::deserialize(buf)?;
         let address_book = BorshDeserialize::deserialize(buf)?;
         let encrypt_transactions = BorshDeserialize::deserialize(buf)?;
+        let webhook_secrets = if version >= 1 { BorshDeserialize::deserialize(buf)? } else { vec![] };
 
-        Ok(Self { prv_key_data, accounts, address_book, encrypt_transactions })
+        Ok(Self { prv_key_data, accounts, address_book, encrypt_transactions, webhook_secrets })
     }
 }
 
@@ -86,9 +142,11 @@ mod tests {
 
     #[test]
     fn test_storage_wallet_payload() -> Result<()> {
-        let storable_in = Payload::new(vec![], vec![], vec![]);
+        let mut storable_in = Payload::new(vec![], vec![], vec![]);
+        storable_in.webhook_secrets = WebhookSecret::new("id", "secret").into_iter().collect();
         let guard = StorageGuard::new(&storable_in);
-        let _storable_out = guard.validate()?;
+        let storable_out = guard.validate()?;
+        assert_eq!(storable_out.webhook_secrets.iter().map(|secret| secret.secret.as_str()).collect::<Vec<_>>(), vec!["secret"]);
 
         Ok(())
     }
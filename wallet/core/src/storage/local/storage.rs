@@ -66,6 +66,19 @@ impl Storage {
         &self.filename
     }
 
+    /// Location for a backup of the storage file (`<filename>.<tag>.backup`)
+    /// that does not overwrite existing backups.
+    pub async fn backup_filename(&self, tag: &str) -> Result<PathBuf> {
+        let filename = self.filename().to_string_lossy().to_string();
+        let mut backup = PathBuf::from(format!("{filename}.{tag}.backup"));
+        let mut seq = 1;
+        while fs::exists(&backup).await? {
+            backup = PathBuf::from(format!("{filename}.{tag}.backup.{seq}"));
+            seq += 1;
+        }
+        Ok(backup)
+    }
+
     pub async fn purge(&self) -> Result<()> {
         workflow_store::fs::remove(self.filename()).await?;
         Ok(())
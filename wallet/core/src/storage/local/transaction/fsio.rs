@@ -4,16 +4,20 @@
 
 use crate::encryption::*;
 use crate::imports::*;
+use crate::logging::log;
 use crate::storage::interface::{StorageStream, TransactionRangeResult};
-use crate::storage::TransactionRecord;
+use crate::storage::{BalanceHistoryCache, DaaTimestampCache, TransactionRecord};
 use crate::storage::{Binding, TransactionKind, TransactionRecordStore};
 use kaspa_utils::hex::ToHex;
 use std::{
-    collections::VecDeque,
+    collections::{BTreeSet, VecDeque},
     path::{Path, PathBuf},
 };
 use workflow_store::fs;
 
+/// Number of DAA scores covered by a single DAA timestamp cache file.
+const DAA_TIMESTAMP_BUCKET: u64 = 1_000_000;
+
 pub struct Inner {
     known_folders: HashSet<String>,
 }
@@ -49,6 +53,25 @@ impl TransactionStore {
         self.folder.join(self.make_subfolder(binding, network_id))
     }
 
+    // the balance history cache is kept outside of the `<name>.transactions`
+    // folder, which is expected to contain only transaction records
+    fn make_balance_history_path(&self, binding: &Binding, network_id: &NetworkId) -> PathBuf {
+        let name = self.name.as_str();
+        let binding_hex = binding.to_hex();
+        self.folder.join(format!("{name}.balance-history/{binding_hex}/{network_id}"))
+    }
+
+    // the DAA timestamp cache is stored in files of `DAA_TIMESTAMP_BUCKET`
+    // DAA scores, so that storing the cache only rewrites the modified buckets
+    fn make_daa_timestamps_folder(&self) -> PathBuf {
+        let name = self.name.as_str();
+        self.folder.join(format!("{name}.daa-timestamps"))
+    }
+
+    fn make_daa_timestamps_path(&self, network_id: &NetworkId, bucket: u64) -> PathBuf {
+        self.make_daa_timestamps_folder().join(format!("{network_id}.{bucket}"))
+    }
+
     async fn ensure_folder(&self, binding: &Binding, network_id: &NetworkId) -> Result<PathBuf> {
         let subfolder = self.make_subfolder(binding, network_id);
         let folder = self.folder.join(&subfolder);
@@ -59,8 +82,27 @@ impl TransactionStore {
         Ok(folder)
     }
 
+    async fn update<F>(&self, binding: &Binding, network_id: &NetworkId, id: &TransactionId, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut TransactionRecord),
+    {
+        let path = self.make_folder(binding, network_id).join(id.to_hex());
+        if !fs::exists(&path).await? {
+            return Err(Error::NoRecordsFound);
+        }
+
+        let mut transaction = read(&path, None).await?;
+        f(&mut transaction);
+        write(&path, &transaction, None, EncryptionKind::XChaCha20Poly1305).await?;
+        Ok(())
+    }
+
     async fn enumerate(&self, binding: &Binding, network_id: &NetworkId) -> Result<VecDeque<TransactionId>> {
         let folder = self.make_folder(binding, network_id);
+        if !fs::exists(&folder).await? {
+            return Err(Error::NoRecordsFound);
+        }
+
         let mut transactions = VecDeque::new();
         match fs::readdir(folder, true).await {
             Ok(mut files) => {
@@ -71,7 +113,7 @@ impl TransactionStore {
                     if let Ok(id) = TransactionId::from_hex(file.file_name()) {
                         transactions.push_back(id);
                     } else {
-                        log_error!("TransactionStore::enumerate(): filename {:?} is not a hash (foreign file?)", file);
+                        log!(Storage, Error, "TransactionStore::enumerate(): filename {:?} is not a hash (foreign file?)", file);
                     }
                 }
 
@@ -81,7 +123,7 @@ impl TransactionStore {
                 if e.code() == Some("ENOENT") {
                     Err(Error::NoRecordsFound)
                 } else {
-                    log_info!("TransactionStore::enumerate(): error reading folder: {:?}", e);
+                    log!(Storage, Info, "TransactionStore::enumerate(): error reading folder: {:?}", e);
                     Err(e.into())
                 }
             }
@@ -121,7 +163,7 @@ impl TransactionRecordStore for TransactionStore {
                     transactions.push(Arc::new(tx));
                 }
                 Err(err) => {
-                    log_error!("Error loading transaction {id}: {:?}", err);
+                    log!(Storage, Error, "Error loading transaction {id}: {:?}", err);
                 }
             }
         }
@@ -157,7 +199,7 @@ impl TransactionRecordStore for TransactionStore {
                         }
                     }
                     Err(err) => {
-                        log_error!("Error loading transaction {id}: {:?}", err);
+                        log!(Storage, Error, "Error loading transaction {id}: {:?}", err);
                     }
                 }
             }
@@ -173,7 +215,7 @@ impl TransactionRecordStore for TransactionStore {
                         transactions.push(Arc::new(tx));
                     }
                     Err(err) => {
-                        log_error!("Error loading transaction {id}: {:?}", err);
+                        log!(Storage, Error, "Error loading transaction {id}: {:?}", err);
                     }
                 }
             }
@@ -211,13 +253,9 @@ impl TransactionRecordStore for TransactionStore {
         id: TransactionId,
         note: Option<String>,
     ) -> Result<()> {
-        let folder = self.make_folder(binding, network_id);
-        let path = folder.join(id.to_hex());
-        let mut transaction = read(&path, None).await?;
-        transaction.note = note;
-        write(&path, &transaction, None, EncryptionKind::XChaCha20Poly1305).await?;
-        Ok(())
+        self.update(binding, network_id, &id, |transaction| transaction.note = note).await
     }
+
     async fn store_transaction_metadata(
         &self,
         binding: &Binding,
@@ -225,11 +263,79 @@ impl TransactionRecordStore for TransactionStore {
         id: TransactionId,
         metadata: Option<String>,
     ) -> Result<()> {
-        let folder = self.make_folder(binding, network_id);
-        let path = folder.join(id.to_hex());
-        let mut transaction = read(&path, None).await?;
-        transaction.metadata = metadata;
-        write(&path, &transaction, None, EncryptionKind::XChaCha20Poly1305).await?;
+        self.update(binding, network_id, &id, |transaction| transaction.metadata = metadata).await
+    }
+
+    async fn load_balance_history(&self, binding: &Binding, network_id: &NetworkId) -> Result<Option<BalanceHistoryCache>> {
+        let path = self.make_balance_history_path(binding, network_id);
+        if !fs::exists(&path).await? {
+            return Ok(None);
+        }
+
+        let bytes = fs::read(&path).await?;
+        match BalanceHistoryCache::try_from_slice(bytes.as_slice()) {
+            Ok(cache) => Ok(Some(cache)),
+            Err(err) => {
+                // the cache is rebuilt from the transaction records
+                log!(Storage, Error, "TransactionStore: unable to load balance history cache {}: {err}", path.display());
+                Ok(None)
+            }
+        }
+    }
+
+    async fn store_balance_history(&self, binding: &Binding, network_id: &NetworkId, cache: &BalanceHistoryCache) -> Result<()> {
+        let path = self.make_balance_history_path(binding, network_id);
+        if let Some(folder) = path.parent() {
+            fs::create_dir_all(folder).await?;
+        }
+        fs::write(&path, &cache.try_to_vec()?).await?;
+        Ok(())
+    }
+
+    async fn load_daa_timestamps(&self, network_id: &NetworkId) -> Result<Option<DaaTimestampCache>> {
+        let folder = self.make_daa_timestamps_folder();
+        if !fs::exists(&folder).await? {
+            return Ok(None);
+        }
+
+        let prefix = format!("{network_id}.");
+        let mut cache = DaaTimestampCache::default();
+        for file in fs::readdir(folder, false).await? {
+            let Some(bucket) = file.file_name().strip_prefix(prefix.as_str()).and_then(|bucket| bucket.parse::<u64>().ok()) else {
+                continue;
+            };
+
+            let path = self.make_daa_timestamps_path(network_id, bucket);
+            let bytes = fs::read(&path).await?;
+            match DaaTimestampCache::try_from_slice(bytes.as_slice()) {
+                Ok(timestamps) => cache.merge(timestamps),
+                Err(err) => {
+                    // the timestamps are resolved again via the node
+                    log!(Storage, Error, "TransactionStore: unable to load DAA timestamp cache {}: {err}", path.display());
+                }
+            }
+        }
+
+        Ok((!cache.is_empty()).then_some(cache))
+    }
+
+    async fn store_daa_timestamps(&self, network_id: &NetworkId, cache: &DaaTimestampCache) -> Result<()> {
+        let buckets = cache.modified().iter().map(|daa_score| daa_score / DAA_TIMESTAMP_BUCKET).collect::<BTreeSet<_>>();
+        if buckets.is_empty() {
+            return Ok(());
+        }
+
+        fs::create_dir_all(self.make_daa_timestamps_folder()).await?;
+        for bucket in buckets {
+            let path = self.make_daa_timestamps_path(network_id, bucket);
+            let start = bucket * DAA_TIMESTAMP_BUCKET;
+            let timestamps = cache.range(start..start.saturating_add(DAA_TIMESTAMP_BUCKET));
+            if !timestamps.is_empty() {
+                fs::write(&path, &timestamps.try_to_vec()?).await?;
+            } else if fs::exists(&path).await? {
+                fs::remove(&path).await?;
+            }
+        }
         Ok(())
     }
 }
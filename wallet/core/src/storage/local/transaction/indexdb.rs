@@ -3,6 +3,7 @@
 //!
 
 use crate::imports::*;
+use crate::logging::log;
 use crate::result::Result;
 use crate::storage::interface::{StorageStream, TransactionRangeResult};
 use crate::storage::TransactionRecord;
@@ -250,7 +251,7 @@ impl TransactionRecordStore for TransactionStore {
                 .filter_map(|js_value| match transaction_record_from_js_value(&js_value, None) {
                     Ok(transaction_record) => Some(Arc::new(transaction_record)),
                     Err(err) => {
-                        log_error!("Failed to deserialize transaction record from indexdb {:?}", err);
+                        log!(Storage, Error, "Failed to deserialize transaction record from indexdb {:?}", err);
                         None
                     }
                 })
@@ -366,7 +367,7 @@ impl TransactionRecordStore for TransactionStore {
                 .map_err(|err| Error::Custom(format!("Failed to get transaction record from indexdb {:?}", err)))?
                 .await
                 .map_err(|err| Error::Custom(format!("Failed to get transaction record from indexdb {:?}", err)))?
-                .ok_or_else(|| Error::Custom("Transaction record not found in indexdb".to_string()))?;
+                .ok_or(Error::NoRecordsFound)?;
 
             let mut transaction_record = transaction_record_from_js_value(&js_value, None)
                 .map_err(|err| Error::Custom(format!("Failed to deserialize transaction record from indexdb {:?}", err)))?;
@@ -413,7 +414,7 @@ impl TransactionRecordStore for TransactionStore {
                 .map_err(|err| Error::Custom(format!("Failed to get transaction record from indexdb {:?}", err)))?
                 .await
                 .map_err(|err| Error::Custom(format!("Failed to get transaction record from indexdb {:?}", err)))?
-                .ok_or_else(|| Error::Custom("Transaction record not found in indexdb".to_string()))?;
+                .ok_or(Error::NoRecordsFound)?;
 
             let mut transaction_record = transaction_record_from_js_value(&js_value, None)
                 .map_err(|err| Error::Custom(format!("Failed to deserialize transaction record from indexdb {:?}", err)))?;
@@ -0,0 +1,569 @@
+//!
+//! Embedded key-value database transaction storage (native platforms, `kv` feature).
+//!
+//! Transaction records of a wallet are kept in a single [`redb`] database
+//! file, allowing large transaction histories to be updated incrementally.
+//! Each binding and network has a pair of tables: a record table keyed by
+//! the transaction id and an order table mapping a monotonic sequence
+//! number to the transaction id (used to iterate records newest first).
+//!
+//! When the database is created, records stored by the file system
+//! transaction storage ([`fsio`](super::fsio)) are imported into it.
+//!
+
+use crate::encryption::*;
+use crate::imports::*;
+use crate::logging::log;
+use crate::storage::interface::{StorageStream, TransactionRangeResult};
+use crate::storage::{BalanceHistoryCache, DaaTimestampCache, TransactionRecord};
+use crate::storage::{Binding, TransactionKind, TransactionRecordStore};
+use redb::{Database, ReadTransaction, ReadableTable, TableDefinition, TableError, WriteTransaction};
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, Weak};
+use workflow_store::fs;
+
+type RecordTable<'a> = TableDefinition<'a, &'static str, (u64, &'static [u8])>;
+type OrderTable<'a> = TableDefinition<'a, u64, &'static str>;
+type BalanceHistoryTable<'a> = TableDefinition<'a, &'static str, &'static [u8]>;
+type DaaTimestampsTable<'a> = TableDefinition<'a, u64, u64>;
+
+/// Key of the balance history cache in the balance history table.
+const BALANCE_HISTORY_KEY: &str = "cache";
+
+/// Databases opened by this process; the database file can
+/// only be opened once, so the handle is shared between stores.
+static DATABASES: OnceLock<Mutex<HashMap<PathBuf, Weak<Database>>>> = OnceLock::new();
+
+fn db_error<E: std::fmt::Display>(err: E) -> Error {
+    Error::Custom(format!("Transaction database error: {err}"))
+}
+
+/// Opens (or creates) the database at `path`, sharing the handle with other
+/// stores of this process. The `init` function is invoked if the database
+/// file has been created.
+pub(crate) fn shared_database<F>(path: &Path, db_error: fn(redb::DatabaseError) -> Error, init: F) -> Result<Arc<Database>>
+where
+    F: FnOnce(&Database) -> Result<()>,
+{
+    let mut databases = DATABASES.get_or_init(Default::default).lock().unwrap();
+    if let Some(db) = databases.get(path).and_then(Weak::upgrade) {
+        return Ok(db);
+    }
+
+    if let Some(folder) = path.parent() {
+        std::fs::create_dir_all(folder)?;
+    }
+    let exists = path.exists();
+    let db = Arc::new(Database::create(path).map_err(db_error)?);
+    if !exists {
+        init(&db)?;
+    }
+    databases.insert(path.to_path_buf(), Arc::downgrade(&db));
+    Ok(db)
+}
+
+/// Table names for the given binding and network.
+struct Tables {
+    records: String,
+    order: String,
+    balance_history: String,
+}
+
+impl Tables {
+    fn new(binding: &Binding, network_id: &NetworkId) -> Self {
+        let records = format!("{}/{network_id}", binding.to_hex());
+        let order = format!("{records}/order");
+        let balance_history = format!("{records}/balance-history");
+        Self { records, order, balance_history }
+    }
+
+    fn records(&self) -> RecordTable<'_> {
+        TableDefinition::new(&self.records)
+    }
+
+    fn order(&self) -> OrderTable<'_> {
+        TableDefinition::new(&self.order)
+    }
+
+    fn balance_history(&self) -> BalanceHistoryTable<'_> {
+        TableDefinition::new(&self.balance_history)
+    }
+}
+
+/// Name of the DAA score timestamp table of the network (shared by all bindings),
+/// holding the timestamps keyed by the DAA score.
+fn daa_timestamps_table(network_id: &NetworkId) -> String {
+    format!("daa-timestamps/{network_id}")
+}
+
+pub struct TransactionStore {
+    database: Mutex<Option<Arc<Database>>>,
+    folder: PathBuf,
+    name: String,
+}
+
+impl TransactionStore {
+    pub fn new<P: AsRef<Path>>(folder: P, name: &str) -> TransactionStore {
+        TransactionStore {
+            database: Mutex::new(None),
+            folder: fs::resolve_path(folder.as_ref().to_str().unwrap()).expect("transaction store folder is invalid"),
+            name: name.to_string(),
+        }
+    }
+
+    fn path(&self) -> PathBuf {
+        self.folder.join(format!("{}.transactions.db", self.name))
+    }
+
+    /// Opens the database on first use.
+    fn database(&self) -> Result<Arc<Database>> {
+        let mut database = self.database.lock().unwrap();
+        if let Some(database) = database.as_ref() {
+            return Ok(database.clone());
+        }
+
+        let db = shared_database(&self.path(), db_error, |db| self.import_fsio_records(db))?;
+        database.replace(db.clone());
+        Ok(db)
+    }
+
+    fn read(&self) -> Result<ReadTransaction> {
+        self.database()?.begin_read().map_err(db_error)
+    }
+
+    fn write<F>(&self, f: F) -> Result<()>
+    where
+        F: FnOnce(&WriteTransaction) -> Result<()>,
+    {
+        let transaction = self.database()?.begin_write().map_err(db_error)?;
+        f(&transaction)?;
+        transaction.commit().map_err(db_error)
+    }
+
+    /// Imports records stored in the `<name>.transactions/<binding>/<network>/<txid>`
+    /// folder structure of the file system transaction storage, oldest records first.
+    fn import_fsio_records(&self, database: &Database) -> Result<()> {
+        let folder = self.folder.join(format!("{}.transactions", self.name));
+        if !folder.is_dir() {
+            return Ok(());
+        }
+
+        let mut files = vec![];
+        for binding in std::fs::read_dir(&folder)?.flatten() {
+            for network in std::fs::read_dir(binding.path())?.flatten() {
+                for file in std::fs::read_dir(network.path())?.flatten() {
+                    files.push((file.metadata()?.created().ok(), file.path()));
+                }
+            }
+        }
+        files.sort_by_key(|(created, _)| *created);
+
+        let records = files
+            .into_iter()
+            .filter_map(|(_, path)| match read_fsio_record(&path) {
+                Ok(record) => Some(record),
+                Err(err) => {
+                    log!(Storage, Error, "TransactionStore: unable to import {}: {err}", path.display());
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let transaction = database.begin_write().map_err(db_error)?;
+        store_records(&transaction, records.iter())?;
+        transaction.commit().map_err(db_error)?;
+
+        log!(Storage, Info, "TransactionStore: imported {} transaction records from {}", records.len(), folder.display());
+        Ok(())
+    }
+
+    /// Returns transaction ids, newest first.
+    fn ids(&self, binding: &Binding, network_id: &NetworkId) -> Result<Vec<TransactionId>> {
+        let tables = Tables::new(binding, network_id);
+        let transaction = self.read()?;
+        let order = match transaction.open_table(tables.order()) {
+            Ok(order) => order,
+            Err(TableError::TableDoesNotExist(_)) => return Err(Error::NoRecordsFound),
+            Err(err) => return Err(db_error(err)),
+        };
+
+        let mut ids = vec![];
+        for entry in order.iter().map_err(db_error)?.rev() {
+            let (_, id) = entry.map_err(db_error)?;
+            ids.push(TransactionId::from_hex(id.value())?);
+        }
+        Ok(ids)
+    }
+
+    fn load(&self, binding: &Binding, network_id: &NetworkId, ids: &[TransactionId]) -> Result<Vec<Arc<TransactionRecord>>> {
+        let tables = Tables::new(binding, network_id);
+        let transaction = self.read()?;
+        let records = match transaction.open_table(tables.records()) {
+            Ok(records) => records,
+            Err(TableError::TableDoesNotExist(_)) => return Ok(vec![]),
+            Err(err) => return Err(db_error(err)),
+        };
+
+        let mut transactions = vec![];
+        for id in ids {
+            if let Some(entry) = records.get(id.to_hex().as_str()).map_err(db_error)? {
+                let (_, data) = entry.value();
+                transactions.push(Arc::new(decode(data)?));
+            }
+        }
+        Ok(transactions)
+    }
+
+    fn update<F>(&self, binding: &Binding, network_id: &NetworkId, id: &TransactionId, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut TransactionRecord),
+    {
+        let tables = Tables::new(binding, network_id);
+        let key = id.to_hex();
+        self.write(|transaction| {
+            let mut records = transaction.open_table(tables.records()).map_err(db_error)?;
+            let (sequence, mut record) = match records.get(key.as_str()).map_err(db_error)? {
+                Some(entry) => {
+                    let (sequence, data) = entry.value();
+                    (sequence, decode(data)?)
+                }
+                None => return Err(Error::NoRecordsFound),
+            };
+            f(&mut record);
+            records.insert(key.as_str(), (sequence, encode(&record)?.as_slice())).map_err(db_error)?;
+            Ok(())
+        })
+    }
+}
+
+fn read_fsio_record(path: &Path) -> Result<TransactionRecord> {
+    decode(&std::fs::read(path)?)
+}
+
+fn decode(data: &[u8]) -> Result<TransactionRecord> {
+    let encryptable = Encryptable::<TransactionRecord>::try_from_slice(data)?;
+    Ok(encryptable.decrypt(None)?.unwrap())
+}
+
+fn encode(record: &TransactionRecord) -> Result<Vec<u8>> {
+    Ok(Encryptable::from(record.clone()).try_to_vec()?)
+}
+
+/// Stores records, appending new records to the order table
+/// and replacing existing records in place.
+fn store_records<'r>(transaction: &WriteTransaction, transaction_records: impl Iterator<Item = &'r TransactionRecord>) -> Result<()> {
+    for record in transaction_records {
+        let tables = Tables::new(record.binding(), record.network_id());
+        let mut records = transaction.open_table(tables.records()).map_err(db_error)?;
+        let mut order = transaction.open_table(tables.order()).map_err(db_error)?;
+
+        let key = record.id().to_hex();
+        let existing = records.get(key.as_str()).map_err(db_error)?.map(|entry| entry.value().0);
+        let sequence = match existing {
+            Some(sequence) => sequence,
+            None => {
+                let sequence = order.last().map_err(db_error)?.map(|(sequence, _)| sequence.value() + 1).unwrap_or_default();
+                order.insert(sequence, key.as_str()).map_err(db_error)?;
+                sequence
+            }
+        };
+        records.insert(key.as_str(), (sequence, encode(record)?.as_slice())).map_err(db_error)?;
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl TransactionRecordStore for TransactionStore {
+    async fn transaction_id_iter(&self, binding: &Binding, network_id: &NetworkId) -> Result<StorageStream<Arc<TransactionId>>> {
+        let ids = self.ids(binding, network_id)?.into_iter().map(|id| Ok(Arc::new(id))).collect::<Vec<_>>();
+        Ok(Box::pin(futures::stream::iter(ids)))
+    }
+
+    async fn transaction_data_iter(&self, binding: &Binding, network_id: &NetworkId) -> Result<StorageStream<Arc<TransactionRecord>>> {
+        let ids = self.ids(binding, network_id)?;
+        let records = self.load(binding, network_id, &ids)?.into_iter().map(Ok).collect::<Vec<_>>();
+        Ok(Box::pin(futures::stream::iter(records)))
+    }
+
+    async fn load_single(&self, binding: &Binding, network_id: &NetworkId, id: &TransactionId) -> Result<Arc<TransactionRecord>> {
+        self.load(binding, network_id, std::slice::from_ref(id))?.pop().ok_or(Error::NoRecordsFound)
+    }
+
+    async fn load_multiple(
+        &self,
+        binding: &Binding,
+        network_id: &NetworkId,
+        ids: &[TransactionId],
+    ) -> Result<Vec<Arc<TransactionRecord>>> {
+        self.load(binding, network_id, ids)
+    }
+
+    async fn load_range(
+        &self,
+        binding: &Binding,
+        network_id: &NetworkId,
+        filter: Option<Vec<TransactionKind>>,
+        range: std::ops::Range<usize>,
+    ) -> Result<TransactionRangeResult> {
+        let ids = self.ids(binding, network_id)?;
+
+        let (transactions, total) = if let Some(filter) = filter {
+            let records = self.load(binding, network_id, &ids)?;
+            let filtered = records.into_iter().filter(|record| filter.contains(&record.kind())).collect::<Vec<_>>();
+            let total = filtered.len();
+            (filtered.into_iter().skip(range.start).take(range.len()).collect(), total)
+        } else {
+            let total = ids.len();
+            let ids = ids.into_iter().skip(range.start).take(range.len()).collect::<Vec<_>>();
+            (self.load(binding, network_id, &ids)?, total)
+        };
+
+        Ok(TransactionRangeResult { transactions, total: total as u64 })
+    }
+
+    async fn store(&self, transaction_records: &[&TransactionRecord]) -> Result<()> {
+        self.write(|transaction| store_records(transaction, transaction_records.iter().copied()))
+    }
+
+    async fn remove(&self, binding: &Binding, network_id: &NetworkId, ids: &[&TransactionId]) -> Result<()> {
+        let tables = Tables::new(binding, network_id);
+        self.write(|transaction| {
+            let mut records = transaction.open_table(tables.records()).map_err(db_error)?;
+            let mut order = transaction.open_table(tables.order()).map_err(db_error)?;
+            for id in ids {
+                let removed = records.remove(id.to_hex().as_str()).map_err(db_error)?.map(|entry| entry.value().0);
+                if let Some(sequence) = removed {
+                    order.remove(sequence).map_err(db_error)?;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    async fn store_transaction_note(
+        &self,
+        binding: &Binding,
+        network_id: &NetworkId,
+        id: TransactionId,
+        note: Option<String>,
+    ) -> Result<()> {
+        self.update(binding, network_id, &id, |record| record.note = note)
+    }
+
+    async fn store_transaction_metadata(
+        &self,
+        binding: &Binding,
+        network_id: &NetworkId,
+        id: TransactionId,
+        metadata: Option<String>,
+    ) -> Result<()> {
+        self.update(binding, network_id, &id, |record| record.metadata = metadata)
+    }
+
+    async fn load_balance_history(&self, binding: &Binding, network_id: &NetworkId) -> Result<Option<BalanceHistoryCache>> {
+        let tables = Tables::new(binding, network_id);
+        let transaction = self.read()?;
+        let table = match transaction.open_table(tables.balance_history()) {
+            Ok(table) => table,
+            Err(TableError::TableDoesNotExist(_)) => return Ok(None),
+            Err(err) => return Err(db_error(err)),
+        };
+
+        let entry = table.get(BALANCE_HISTORY_KEY).map_err(db_error)?;
+        match entry.map(|entry| BalanceHistoryCache::try_from_slice(entry.value())).transpose() {
+            Ok(cache) => Ok(cache),
+            Err(err) => {
+                // the cache is rebuilt from the transaction records
+                log!(Storage, Error, "TransactionStore: unable to load balance history cache: {err}");
+                Ok(None)
+            }
+        }
+    }
+
+    async fn store_balance_history(&self, binding: &Binding, network_id: &NetworkId, cache: &BalanceHistoryCache) -> Result<()> {
+        let tables = Tables::new(binding, network_id);
+        let data = cache.try_to_vec()?;
+        self.write(|transaction| {
+            let mut table = transaction.open_table(tables.balance_history()).map_err(db_error)?;
+            table.insert(BALANCE_HISTORY_KEY, data.as_slice()).map_err(db_error)?;
+            Ok(())
+        })
+    }
+
+    async fn load_daa_timestamps(&self, network_id: &NetworkId) -> Result<Option<DaaTimestampCache>> {
+        let name = daa_timestamps_table(network_id);
+        let transaction = self.read()?;
+        let table = match transaction.open_table(DaaTimestampsTable::new(&name)) {
+            Ok(table) => table,
+            Err(TableError::TableDoesNotExist(_)) => return Ok(None),
+            Err(err) => return Err(db_error(err)),
+        };
+
+        let cache = table
+            .iter()
+            .map_err(db_error)?
+            .map(|entry| {
+                let (daa_score, unixtime_msec) = entry.map_err(db_error)?;
+                Ok((daa_score.value(), unixtime_msec.value()))
+            })
+            .collect::<Result<DaaTimestampCache>>()?;
+        Ok((!cache.is_empty()).then_some(cache))
+    }
+
+    async fn store_daa_timestamps(&self, network_id: &NetworkId, cache: &DaaTimestampCache) -> Result<()> {
+        if !cache.is_modified() {
+            return Ok(());
+        }
+
+        let name = daa_timestamps_table(network_id);
+        self.write(|transaction| {
+            let mut table = transaction.open_table(DaaTimestampsTable::new(&name)).map_err(db_error)?;
+            for daa_score in cache.modified().iter().copied() {
+                match cache.get(daa_score) {
+                    Some(unixtime_msec) => {
+                        table.insert(daa_score, unixtime_msec).map_err(db_error)?;
+                    }
+                    None => {
+                        table.remove(daa_score).map_err(db_error)?;
+                    }
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::local::transaction::fsio;
+    use crate::tests::MockRpcClient;
+    use crate::utils::kaspa_to_sompi;
+    use crate::utxo::{UtxoContext, UtxoContextBinding, UtxoProcessor};
+    use futures::StreamExt;
+    use kaspa_consensus_client::UtxoEntryReference;
+
+    fn make_records(count: usize) -> Vec<TransactionRecord> {
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+        let processor = UtxoProcessor::new(Some(Arc::new(MockRpcClient::new()).into()), Some(network_id), None, None);
+        let context = UtxoContext::new(&processor, UtxoContextBinding::default());
+        (0..count)
+            .map(|n| {
+                let utxos = [UtxoEntryReference::simulated(kaspa_to_sompi(n as f64 + 1.0))];
+                TransactionRecord::new_incoming(&context, utxos[0].transaction_id(), &utxos)
+            })
+            .collect()
+    }
+
+    fn make_folder() -> PathBuf {
+        std::env::temp_dir().join(format!("kaspa-wallet-kv-{}", faster_hex::hex_string(&rand::random::<[u8; 8]>())))
+    }
+
+    #[tokio::test]
+    async fn test_kv_transaction_store() -> Result<()> {
+        let folder = make_folder();
+        let store = TransactionStore::new(&folder, "test");
+        let records = make_records(3);
+        let (binding, network_id) = (records[0].binding(), records[0].network_id());
+
+        assert!(matches!(store.transaction_id_iter(binding, network_id).await.err(), Some(Error::NoRecordsFound)));
+
+        store.store(&records.iter().collect::<Vec<_>>()).await?;
+        // re-storing an existing record retains its position
+        store.store(&[&records[0]]).await?;
+
+        let ids = store.transaction_id_iter(binding, network_id).await?.collect::<Vec<_>>().await;
+        let expected = records.iter().rev().map(|record| *record.id()).collect::<Vec<_>>();
+        assert_eq!(ids.into_iter().map(|id| *id.unwrap()).collect::<Vec<_>>(), expected);
+
+        let range = store.load_range(binding, network_id, None, 1..3).await?;
+        assert_eq!(range.total, 3);
+        assert_eq!(range.transactions.iter().map(|record| *record.id()).collect::<Vec<_>>(), expected[1..3]);
+
+        let filtered = store.load_range(binding, network_id, Some(vec![TransactionKind::Outgoing]), 0..3).await?;
+        assert_eq!(filtered.total, 0);
+
+        store.store_transaction_note(binding, network_id, *records[1].id(), Some("note".to_string())).await?;
+        assert_eq!(store.load_single(binding, network_id, records[1].id()).await?.note.as_deref(), Some("note"));
+
+        store.remove(binding, network_id, &[records[2].id()]).await?;
+        assert_eq!(store.load_range(binding, network_id, None, 0..3).await?.total, 2);
+        assert!(matches!(store.load_single(binding, network_id, records[2].id()).await, Err(Error::NoRecordsFound)));
+        assert!(matches!(
+            store.store_transaction_metadata(binding, network_id, *records[2].id(), None).await,
+            Err(Error::NoRecordsFound)
+        ));
+
+        drop(store);
+        std::fs::remove_dir_all(&folder)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_kv_transaction_store_fsio_import() -> Result<()> {
+        let folder = make_folder();
+        let records = make_records(2);
+        let (binding, network_id) = (records[0].binding(), records[0].network_id());
+
+        let fsio_store = fsio::TransactionStore::new(&folder, "test");
+        for record in records.iter() {
+            fsio_store.store(&[record]).await?;
+            // the import is ordered by file creation time, which
+            // has a coarse granularity on some file systems
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        let store = TransactionStore::new(&folder, "test");
+        let range = store.load_range(binding, network_id, None, 0..10).await?;
+        assert_eq!(range.total, 2);
+        assert_eq!(
+            range.transactions.iter().map(|record| *record.id()).collect::<Vec<_>>(),
+            records.iter().rev().map(|record| *record.id()).collect::<Vec<_>>()
+        );
+
+        drop(store);
+        std::fs::remove_dir_all(&folder)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_daa_timestamp_stores() -> Result<()> {
+        let folder = make_folder();
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+        let stores: [Arc<dyn TransactionRecordStore>; 2] =
+            [Arc::new(fsio::TransactionStore::new(&folder, "fsio")), Arc::new(TransactionStore::new(&folder, "kv"))];
+
+        for store in stores.iter() {
+            assert!(store.load_daa_timestamps(&network_id).await?.is_none());
+
+            let mut cache = DaaTimestampCache::default();
+            cache.insert(10, 1_000);
+            cache.insert(5_000_000, 2_000);
+            store.store_daa_timestamps(&network_id, &cache).await?;
+            let mut loaded = store.load_daa_timestamps(&network_id).await?.expect("stored cache");
+            assert_eq!(loaded.iter().collect::<Vec<_>>(), vec![(10, 1_000), (5_000_000, 2_000)]);
+            assert!(!loaded.is_modified());
+            assert!(store.load_daa_timestamps(&NetworkId::with_suffix(NetworkType::Testnet, 11)).await?.is_none());
+
+            // the DAA scores evicted from the cache are removed from the storage
+            for daa_score in 0..DaaTimestampCache::MAX_ENTRIES as u64 {
+                loaded.insert(1_000_000 + daa_score, daa_score);
+            }
+            store.store_daa_timestamps(&network_id, &loaded).await?;
+            let loaded = store.load_daa_timestamps(&network_id).await?.expect("stored cache");
+            assert_eq!(loaded.len(), DaaTimestampCache::MAX_ENTRIES);
+            assert_eq!(loaded.get(10), None);
+            assert_eq!(loaded.get(1_000_000), None);
+            assert_eq!(loaded.get(1_000_001), Some(1));
+            assert_eq!(loaded.get(5_000_000), Some(2_000));
+        }
+
+        // the file system cache buckets emptied by the eviction are removed
+        assert!(!folder.join(format!("fsio.daa-timestamps/{network_id}.0")).exists());
+        assert!(folder.join(format!("fsio.daa-timestamps/{network_id}.5")).exists());
+
+        drop(stores);
+        std::fs::remove_dir_all(&folder)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+//!
+//! In-memory transaction storage used by resident wallets
+//! in builds without file system access (`fs` feature disabled).
+//!
+
+use crate::imports::*;
+use crate::storage::interface::{StorageStream, TransactionRangeResult};
+use crate::storage::{BalanceHistoryCache, DaaTimestampCache, TransactionRecord};
+use crate::storage::{Binding, TransactionKind, TransactionRecordStore};
+use std::collections::VecDeque;
+
+type RecordKey = (String, NetworkId);
+
+#[derive(Default)]
+pub struct Inner {
+    /// Transaction records per binding, newest records first.
+    records: HashMap<RecordKey, VecDeque<Arc<TransactionRecord>>>,
+    balance_history: HashMap<RecordKey, BalanceHistoryCache>,
+    daa_timestamps: HashMap<NetworkId, DaaTimestampCache>,
+}
+
+#[derive(Default)]
+pub struct TransactionStore {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl TransactionStore {
+    pub fn new() -> TransactionStore {
+        TransactionStore::default()
+    }
+
+    #[inline(always)]
+    fn inner(&self) -> MutexGuard<Inner> {
+        self.inner.lock().unwrap()
+    }
+
+    fn records(&self, binding: &Binding, network_id: &NetworkId) -> Result<VecDeque<Arc<TransactionRecord>>> {
+        self.inner().records.get(&(binding.to_hex(), *network_id)).cloned().ok_or(Error::NoRecordsFound)
+    }
+
+    fn update<F>(&self, binding: &Binding, network_id: &NetworkId, id: &TransactionId, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut TransactionRecord),
+    {
+        let mut inner = self.inner();
+        let record = inner
+            .records
+            .get_mut(&(binding.to_hex(), *network_id))
+            .and_then(|records| records.iter_mut().find(|record| record.id() == id))
+            .ok_or(Error::NoRecordsFound)?;
+        f(Arc::make_mut(record));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TransactionRecordStore for TransactionStore {
+    async fn transaction_id_iter(&self, binding: &Binding, network_id: &NetworkId) -> Result<StorageStream<Arc<TransactionId>>> {
+        let ids = self.records(binding, network_id)?.iter().map(|record| Ok(Arc::new(*record.id()))).collect::<Vec<_>>();
+        Ok(Box::pin(futures::stream::iter(ids)))
+    }
+
+    async fn transaction_data_iter(&self, binding: &Binding, network_id: &NetworkId) -> Result<StorageStream<Arc<TransactionRecord>>> {
+        let records = self.records(binding, network_id)?.into_iter().map(Ok).collect::<Vec<_>>();
+        Ok(Box::pin(futures::stream::iter(records)))
+    }
+
+    async fn load_single(&self, binding: &Binding, network_id: &NetworkId, id: &TransactionId) -> Result<Arc<TransactionRecord>> {
+        self.records(binding, network_id)?.into_iter().find(|record| record.id() == id).ok_or(Error::NoRecordsFound)
+    }
+
+    async fn load_multiple(
+        &self,
+        binding: &Binding,
+        network_id: &NetworkId,
+        ids: &[TransactionId],
+    ) -> Result<Vec<Arc<TransactionRecord>>> {
+        let records = self.records(binding, network_id).unwrap_or_default();
+        Ok(ids.iter().filter_map(|id| records.iter().find(|record| record.id() == id).cloned()).collect())
+    }
+
+    async fn load_range(
+        &self,
+        binding: &Binding,
+        network_id: &NetworkId,
+        filter: Option<Vec<TransactionKind>>,
+        range: std::ops::Range<usize>,
+    ) -> Result<TransactionRangeResult> {
+        let records = self.records(binding, network_id)?;
+
+        let (transactions, total) = if let Some(filter) = filter {
+            let filtered = records.into_iter().filter(|record| filter.contains(&record.kind())).collect::<Vec<_>>();
+            let total = filtered.len();
+            (filtered.into_iter().skip(range.start).take(range.len()).collect(), total)
+        } else {
+            let total = records.len();
+            (records.into_iter().skip(range.start).take(range.len()).collect(), total)
+        };
+
+        Ok(TransactionRangeResult { transactions, total: total as u64 })
+    }
+
+    async fn store(&self, transaction_records: &[&TransactionRecord]) -> Result<()> {
+        let mut inner = self.inner();
+        for record in transaction_records {
+            let records = inner.records.entry((record.binding().to_hex(), *record.network_id())).or_default();
+            if let Some(existing) = records.iter_mut().find(|existing| existing.id() == record.id()) {
+                *existing = Arc::new((*record).clone());
+            } else {
+                records.push_front(Arc::new((*record).clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn remove(&self, binding: &Binding, network_id: &NetworkId, ids: &[&TransactionId]) -> Result<()> {
+        if let Some(records) = self.inner().records.get_mut(&(binding.to_hex(), *network_id)) {
+            records.retain(|record| !ids.contains(&record.id()));
+        }
+
+        Ok(())
+    }
+
+    async fn store_transaction_note(
+        &self,
+        binding: &Binding,
+        network_id: &NetworkId,
+        id: TransactionId,
+        note: Option<String>,
+    ) -> Result<()> {
+        self.update(binding, network_id, &id, |record| record.note = note)
+    }
+
+    async fn store_transaction_metadata(
+        &self,
+        binding: &Binding,
+        network_id: &NetworkId,
+        id: TransactionId,
+        metadata: Option<String>,
+    ) -> Result<()> {
+        self.update(binding, network_id, &id, |record| record.metadata = metadata)
+    }
+
+    async fn load_balance_history(&self, binding: &Binding, network_id: &NetworkId) -> Result<Option<BalanceHistoryCache>> {
+        Ok(self.inner().balance_history.get(&(binding.to_hex(), *network_id)).cloned())
+    }
+
+    async fn store_balance_history(&self, binding: &Binding, network_id: &NetworkId, cache: &BalanceHistoryCache) -> Result<()> {
+        self.inner().balance_history.insert((binding.to_hex(), *network_id), cache.clone());
+        Ok(())
+    }
+
+    async fn load_daa_timestamps(&self, network_id: &NetworkId) -> Result<Option<DaaTimestampCache>> {
+        Ok(self.inner().daa_timestamps.get(network_id).cloned())
+    }
+
+    async fn store_daa_timestamps(&self, network_id: &NetworkId, cache: &DaaTimestampCache) -> Result<()> {
+        let mut cache = cache.clone();
+        cache.clear_modified();
+        self.inner().daa_timestamps.insert(*network_id, cache);
+        Ok(())
+    }
+}
@@ -2,5 +2,9 @@
 //! Transaction storage subsystem implementations.
 //!
 
+#[cfg(feature = "fs")]
 pub mod fsio;
 pub mod indexdb;
+#[cfg(all(feature = "kv", not(target_arch = "wasm32")))]
+pub mod kv;
+pub mod memory;
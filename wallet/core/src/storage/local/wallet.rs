@@ -4,10 +4,12 @@
 
 use crate::imports::*;
 use crate::storage::local::Payload;
+#[cfg(feature = "fs")]
 use crate::storage::local::Storage;
 use crate::storage::Encryptable;
 use crate::storage::TransactionRecord;
 use crate::storage::{AccountMetadata, Decrypted, Encrypted, Hint, PrvKeyData, PrvKeyDataId};
+#[cfg(feature = "fs")]
 use workflow_store::fs;
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -21,11 +23,14 @@ pub struct WalletStorage {
     pub metadata: Vec<AccountMetadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transactions: Option<Encryptable<HashMap<AccountId, Vec<TransactionRecord>>>>,
+    /// User notes describing the wallet (stored unencrypted, like the title).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
 }
 
 impl WalletStorage {
     pub const STORAGE_MAGIC: u32 = 0x5753414b;
-    pub const STORAGE_VERSION: u32 = 0;
+    pub const STORAGE_VERSION: u32 = 2;
 
     pub fn try_new(
         title: Option<String>,
@@ -36,7 +41,7 @@ impl WalletStorage {
         metadata: Vec<AccountMetadata>,
     ) -> Result<Self> {
         let payload = Decrypted::new(payload).encrypt(secret, encryption_kind)?;
-        Ok(Self { title, encryption_kind, payload, metadata, user_hint, transactions: None })
+        Ok(Self { title, encryption_kind, payload, metadata, user_hint, transactions: None, notes: None })
     }
 
     pub fn payload(&self, secret: &Secret) -> Result<Decrypted<Payload>> {
@@ -46,16 +51,30 @@ impl WalletStorage {
         })
     }
 
+    #[cfg(feature = "fs")]
     pub async fn try_load(store: &Storage) -> Result<WalletStorage> {
+        let bytes = Self::try_load_bytes(store).await?;
+        Ok(BorshDeserialize::try_from_slice(bytes.as_slice())?)
+    }
+
+    /// Read the serialized wallet data from the storage.
+    #[cfg(feature = "fs")]
+    pub async fn try_load_bytes(store: &Storage) -> Result<Vec<u8>> {
         if fs::exists(store.filename()).await? {
-            let bytes = fs::read(store.filename()).await?;
-            Ok(BorshDeserialize::try_from_slice(bytes.as_slice())?)
+            Ok(fs::read(store.filename()).await?)
         } else {
             let name = store.filename().file_name().unwrap().to_str().unwrap();
             Err(Error::NoWalletInStorage(name.to_string()))
         }
     }
 
+    /// Storage version of the serialized wallet data.
+    pub fn try_storage_version(bytes: &[u8]) -> Result<u32> {
+        let StorageHeader { version, .. } = StorageHeader::deserialize(&mut &bytes[..])?.try_magic(Self::STORAGE_MAGIC)?;
+        Ok(version)
+    }
+
+    #[cfg(feature = "fs")]
     pub async fn try_store(&self, store: &Storage) -> Result<()> {
         store.ensure_dir().await?;
 
@@ -73,6 +92,15 @@ impl WalletStorage {
         Ok(())
     }
 
+    /// Synchronous version of [`try_store()`](Self::try_store) (native platforms).
+    #[cfg(all(feature = "fs", not(target_arch = "wasm32")))]
+    pub fn try_store_sync(&self, store: &Storage) -> Result<()> {
+        store.ensure_dir_sync()?;
+        let mut file = std::fs::File::create(store.filename())?;
+        BorshSerialize::serialize(self, &mut file)?;
+        Ok(())
+    }
+
     /// Obtain [`PrvKeyData`] using [`PrvKeyDataId`]
     pub async fn try_get_prv_key_data(&self, secret: &Secret, prv_key_data_id: &PrvKeyDataId) -> Result<Option<PrvKeyData>> {
         let payload = self.payload.decrypt::<Payload>(secret)?;
@@ -95,6 +123,7 @@ impl BorshSerialize for WalletStorage {
         BorshSerialize::serialize(&self.payload, writer)?;
         BorshSerialize::serialize(&self.metadata, writer)?;
         BorshSerialize::serialize(&self.transactions, writer)?;
+        BorshSerialize::serialize(&self.notes, writer)?;
 
         Ok(())
     }
@@ -124,8 +153,9 @@ impl BorshDeserialize for WalletStorage {
         let payload = BorshDeserialize::deserialize(buf)?;
         let metadata = BorshDeserialize::deserialize(buf)?;
         let transactions = BorshDeserialize::deserialize(buf)?;
+        let notes = if version >= 2 { BorshDeserialize::deserialize(buf)? } else { None };
 
-        Ok(Self { title, user_hint, encryption_kind, payload, metadata, transactions })
+        Ok(Self { title, user_hint, encryption_kind, payload, metadata, transactions, notes })
     }
 }
 
@@ -8,25 +8,143 @@
 use crate::derivation::AddressDerivationMeta;
 use crate::imports::*;
 use crate::storage::IdT;
+use crate::utxo::{AddressUsage, PaymentRequest, UtxoEntryId};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccountMetadata {
     pub id: AccountId,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub indexes: Option<AddressDerivationMeta>,
+    /// Outpoints of UTXOs locked by the user (see [`UtxoContext::lock`](crate::utxo::UtxoContext::lock)).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locked_utxos: Vec<UtxoEntryId>,
+    /// User-assigned UTXO labels (see [`UtxoContext::set_label`](crate::utxo::UtxoContext::set_label)).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub utxo_labels: Vec<(UtxoEntryId, String)>,
+    /// Address gap limit configured by the user (see [`Account::set_gap_limit`](crate::account::Account::set_gap_limit)).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gap_limit: Option<u32>,
+    /// Progress of an interrupted account operation (see [`OperationCheckpoint`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoint: Option<OperationCheckpoint>,
+    /// Activity observed on the account addresses (see [`UtxoContext::address_usage`](crate::utxo::UtxoContext::address_usage)).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub address_usage: Vec<AddressUsage>,
+    /// Current account addresses, allowing account descriptors to be
+    /// produced without loading the account (see [`AccountStorage::descriptor`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub addresses: Option<AccountAddresses>,
+    /// Payment requests created for the account (see [`UtxoContext::register_payment_request`](crate::utxo::UtxoContext::register_payment_request)).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub payment_requests: Vec<PaymentRequest>,
 }
 
 impl AccountMetadata {
     const STORAGE_MAGIC: u32 = 0x4154454d;
-    const STORAGE_VERSION: u32 = 0;
+    const STORAGE_VERSION: u32 = 7;
 
     pub fn new(id: AccountId, indexes: AddressDerivationMeta) -> Self {
-        Self { id, indexes: Some(indexes) }
+        Self {
+            id,
+            indexes: Some(indexes),
+            locked_utxos: vec![],
+            utxo_labels: vec![],
+            gap_limit: None,
+            checkpoint: None,
+            address_usage: vec![],
+            addresses: None,
+            payment_requests: vec![],
+        }
+    }
+
+    /// Create metadata for accounts that do not use address derivation.
+    pub fn new_without_indexes(id: AccountId) -> Self {
+        Self {
+            id,
+            indexes: None,
+            locked_utxos: vec![],
+            utxo_labels: vec![],
+            gap_limit: None,
+            checkpoint: None,
+            address_usage: vec![],
+            addresses: None,
+            payment_requests: vec![],
+        }
+    }
+
+    pub fn with_locked_utxos(mut self, locked_utxos: Vec<UtxoEntryId>) -> Self {
+        self.locked_utxos = locked_utxos;
+        self
+    }
+
+    pub fn with_utxo_labels(mut self, utxo_labels: Vec<(UtxoEntryId, String)>) -> Self {
+        self.utxo_labels = utxo_labels;
+        self
+    }
+
+    pub fn with_gap_limit(mut self, gap_limit: Option<u32>) -> Self {
+        self.gap_limit = gap_limit;
+        self
+    }
+
+    pub fn with_checkpoint(mut self, checkpoint: Option<OperationCheckpoint>) -> Self {
+        self.checkpoint = checkpoint;
+        self
+    }
+
+    pub fn with_address_usage(mut self, address_usage: Vec<AddressUsage>) -> Self {
+        self.address_usage = address_usage;
+        self
+    }
+
+    pub fn with_addresses(mut self, receive: Option<Address>, change: Option<Address>) -> Self {
+        self.addresses = Some(AccountAddresses { receive, change });
+        self
+    }
+
+    pub fn with_payment_requests(mut self, payment_requests: Vec<PaymentRequest>) -> Self {
+        self.payment_requests = payment_requests;
+        self
     }
 
     pub fn address_derivation_indexes(&self) -> Option<AddressDerivationMeta> {
         self.indexes.clone()
     }
+
+    pub fn locked_utxos(&self) -> &[UtxoEntryId] {
+        &self.locked_utxos
+    }
+
+    pub fn utxo_labels(&self) -> &[(UtxoEntryId, String)] {
+        &self.utxo_labels
+    }
+
+    pub fn gap_limit(&self) -> Option<u32> {
+        self.gap_limit
+    }
+
+    pub fn checkpoint(&self) -> Option<&OperationCheckpoint> {
+        self.checkpoint.as_ref()
+    }
+
+    pub fn address_usage(&self) -> &[AddressUsage] {
+        &self.address_usage
+    }
+
+    pub fn addresses(&self) -> Option<&AccountAddresses> {
+        self.addresses.as_ref()
+    }
+
+    pub fn payment_requests(&self) -> &[PaymentRequest] {
+        &self.payment_requests
+    }
+}
+
+/// Receive and change addresses of an account at the time its metadata was stored.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+pub struct AccountAddresses {
+    pub receive: Option<Address>,
+    pub change: Option<Address>,
 }
 
 impl IdT for AccountMetadata {
@@ -41,6 +159,16 @@ impl BorshSerialize for AccountMetadata {
         StorageHeader::new(Self::STORAGE_MAGIC, Self::STORAGE_VERSION).serialize(writer)?;
         BorshSerialize::serialize(&self.id, writer)?;
         BorshSerialize::serialize(&self.indexes, writer)?;
+        let locked_utxos = self.locked_utxos.iter().map(|outpoint| (outpoint.transaction_id, outpoint.index)).collect::<Vec<_>>();
+        BorshSerialize::serialize(&locked_utxos, writer)?;
+        let utxo_labels =
+            self.utxo_labels.iter().map(|(outpoint, label)| ((outpoint.transaction_id, outpoint.index), label)).collect::<Vec<_>>();
+        BorshSerialize::serialize(&utxo_labels, writer)?;
+        BorshSerialize::serialize(&self.gap_limit, writer)?;
+        BorshSerialize::serialize(&self.checkpoint, writer)?;
+        BorshSerialize::serialize(&self.address_usage, writer)?;
+        BorshSerialize::serialize(&self.addresses, writer)?;
+        BorshSerialize::serialize(&self.payment_requests, writer)?;
 
         Ok(())
     }
@@ -48,12 +176,108 @@ impl BorshSerialize for AccountMetadata {
 
 impl BorshDeserialize for AccountMetadata {
     fn deserialize(buf: &mut &[u8]) -> IoResult<Self> {
-        let StorageHeader { version: _, .. } =
+        let StorageHeader { version, .. } =
             StorageHeader::deserialize(buf)?.try_magic(Self::STORAGE_MAGIC)?.try_version(Self::STORAGE_VERSION)?;
 
         let id = BorshDeserialize::deserialize(buf)?;
         let indexes = BorshDeserialize::deserialize(buf)?;
+        let locked_utxos = if version > 0 {
+            let locked_utxos: Vec<(TransactionId, TransactionIndexType)> = BorshDeserialize::deserialize(buf)?;
+            locked_utxos.into_iter().map(|(transaction_id, index)| UtxoEntryId::new(transaction_id, index)).collect()
+        } else {
+            vec![]
+        };
+
+        let utxo_labels = if version > 1 {
+            let utxo_labels: Vec<((TransactionId, TransactionIndexType), String)> = BorshDeserialize::deserialize(buf)?;
+            utxo_labels.into_iter().map(|((transaction_id, index), label)| (UtxoEntryId::new(transaction_id, index), label)).collect()
+        } else {
+            vec![]
+        };
+
+        let gap_limit = if version > 2 { BorshDeserialize::deserialize(buf)? } else { None };
+        let checkpoint = if version > 3 { BorshDeserialize::deserialize(buf)? } else { None };
+        let address_usage = if version > 4 { BorshDeserialize::deserialize(buf)? } else { vec![] };
+        let addresses = if version > 5 { BorshDeserialize::deserialize(buf)? } else { None };
+        let payment_requests = if version > 6 { BorshDeserialize::deserialize(buf)? } else { vec![] };
+
+        Ok(Self { id, indexes, locked_utxos, utxo_labels, gap_limit, checkpoint, address_usage, addresses, payment_requests })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_storage_account_metadata_gap_limit() -> Result<()> {
+        let id = AccountId::from_hex(&"01".repeat(32))?;
+        let metadata = AccountMetadata::new(id, AddressDerivationMeta::new(4, 2)).with_gap_limit(Some(50));
+        let metadata_out = AccountMetadata::try_from_slice(&metadata.try_to_vec()?)?;
+        assert_eq!(metadata_out.gap_limit(), Some(50));
+        assert_eq!(metadata_out.address_derivation_indexes().map(|indexes| indexes.receive()), Some(4));
 
-        Ok(Self { id, indexes })
+        // metadata stored prior to the introduction of the gap limit
+        let mut legacy = vec![];
+        StorageHeader::new(AccountMetadata::STORAGE_MAGIC, 2).serialize(&mut legacy)?;
+        BorshSerialize::serialize(&metadata.id, &mut legacy)?;
+        BorshSerialize::serialize(&metadata.indexes, &mut legacy)?;
+        BorshSerialize::serialize(&Vec::<(TransactionId, TransactionIndexType)>::new(), &mut legacy)?;
+        BorshSerialize::serialize(&Vec::<((TransactionId, TransactionIndexType), String)>::new(), &mut legacy)?;
+        let metadata_out = AccountMetadata::try_from_slice(&legacy)?;
+        assert_eq!(metadata_out.gap_limit(), None);
+        assert!(metadata_out.checkpoint().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_storage_account_metadata_checkpoint() -> Result<()> {
+        let id = AccountId::from_hex(&"01".repeat(32))?;
+        let transaction_id = TransactionId::from_slice(&[2; 32]);
+        let mut checkpoint = OperationCheckpoint::new(OperationKind::Sweep);
+        checkpoint.record(transaction_id, [UtxoEntryId::new(transaction_id, 0), UtxoEntryId::new(transaction_id, 1)]);
+
+        let metadata = AccountMetadata::new_without_indexes(id).with_checkpoint(Some(checkpoint.clone()));
+        let metadata_out = AccountMetadata::try_from_slice(&metadata.try_to_vec()?)?;
+        assert_eq!(metadata_out.checkpoint(), Some(&checkpoint));
+        assert_eq!(metadata_out.checkpoint().unwrap().spent_utxos().len(), 2);
+
+        // version 0 checkpoints (without per-transaction UTXOs) remain readable
+        let other_transaction_id = TransactionId::from_slice(&[3; 32]);
+        let mut buf = vec![];
+        StorageHeader::new(0x4b504b43, 0).serialize(&mut buf)?;
+        BorshSerialize::serialize(&OperationKind::Sweep, &mut buf)?;
+        BorshSerialize::serialize(&checkpoint.created, &mut buf)?;
+        BorshSerialize::serialize(&vec![(transaction_id, 0u32), (other_transaction_id, 0u32)], &mut buf)?;
+        BorshSerialize::serialize(&vec![transaction_id, other_transaction_id], &mut buf)?;
+        let mut checkpoint_v0 = OperationCheckpoint::try_from_slice(&buf)?;
+        assert_eq!(checkpoint_v0.transaction_count(), 2);
+        checkpoint_v0.retain(|id| *id == other_transaction_id);
+        assert_eq!(checkpoint_v0.spent_utxos().len(), 2);
+
+        let mut checkpoint = checkpoint;
+        checkpoint.record(other_transaction_id, [UtxoEntryId::new(other_transaction_id, 0)]);
+        checkpoint.retain(|id| *id == other_transaction_id);
+        assert_eq!(checkpoint.spent_utxos().into_iter().collect::<Vec<_>>(), vec![UtxoEntryId::new(other_transaction_id, 0)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_storage_account_metadata_payment_requests() -> Result<()> {
+        let id = AccountId::from_hex(&"01".repeat(32))?;
+        let address = Address::try_from("kaspatest:qz7ulu4c25dh7fzec9zjyrmlhnkzrg4wmf89q7gzr3gfrsj3uz6xjceef60sd")?;
+        let request = PaymentRequest::new(address.clone(), 100_000, None).with_label(Some("invoice".to_string()));
+        let metadata = AccountMetadata::new(id, AddressDerivationMeta::new(1, 0))
+            .with_addresses(Some(address), None)
+            .with_payment_requests(vec![request.clone()]);
+        let metadata_out = AccountMetadata::try_from_slice(&metadata.try_to_vec()?)?;
+        assert_eq!(metadata_out.payment_requests().len(), 1);
+        assert_eq!(metadata_out.payment_requests()[0].id, request.id);
+        assert_eq!(metadata_out.payment_requests()[0].label, request.label);
+        assert!(metadata_out.addresses().is_some_and(|addresses| addresses.change.is_none()));
+
+        Ok(())
     }
 }
@@ -7,6 +7,8 @@ pub use crate::encryption::{Decrypted, Encryptable, Encrypted};
 pub mod account;
 pub mod address;
 pub mod binding;
+pub mod check;
+pub mod checkpoint;
 pub mod hint;
 pub mod id;
 pub mod interface;
@@ -16,21 +18,27 @@ pub mod metadata;
 pub mod storable;
 pub mod transaction;
 
-pub use account::{AccountSettings, AccountStorable, AccountStorage};
+pub use account::{AccountPayloadMetrics, AccountSettings, AccountStorable, AccountStorage};
 pub use address::AddressBookEntry;
 pub use binding::Binding;
+pub use check::{WalletCheckIssue, WalletCheckIssueKind, WalletCheckReport};
+pub use checkpoint::{CheckpointTransaction, OperationCheckpoint, OperationKind};
 pub use hint::Hint;
 pub use id::IdT;
 pub use interface::{
     AccountStore, Interface, PrvKeyDataStore, StorageDescriptor, TransactionRecordStore, WalletDescriptor, WalletExportOptions,
 };
-pub use keydata::{AssocPrvKeyDataIds, PrvKeyData, PrvKeyDataId, PrvKeyDataInfo, PrvKeyDataMap, PrvKeyDataPayload};
+pub use keydata::{AssocPrvKeyDataIds, PrvKeyData, PrvKeyDataId, PrvKeyDataInfo, PrvKeyDataMap, PrvKeyDataPayload, PrvKeyDataSecretChangeReport};
 pub use local::interface::make_filename;
-pub use metadata::AccountMetadata;
+pub use local::migration::WalletMigrationReport;
+pub use metadata::{AccountAddresses, AccountMetadata};
 pub use storable::Storable;
-pub use transaction::{TransactionData, TransactionId, TransactionKind, TransactionRecord};
+pub use transaction::{
+    BalanceHistoryCache, BalanceHistoryEntry, BalanceHistoryPoint, DaaTimestampCache, TransactionData, TransactionExportColumn, TransactionExportFormat,
+    TransactionExportOptions, TransactionId, TransactionKind, TransactionRecord,
+};
 
-#[cfg(test)]
+#[cfg(all(test, feature = "fs"))]
 mod tests {
 
     use super::*;
@@ -74,7 +82,7 @@ mod tests {
 
         println!("generating accounts...");
         let settings = AccountSettings { name: Some("Wallet-A".to_string()), ..Default::default() };
-        let storable = bip32::Payload::new(0, vec![pub_key_data1.clone()].into(), false);
+        let storable = bip32::Payload::new(0, vec![pub_key_data1.clone()].into(), false, None);
         let (id, storage_key) = make_account_hashes(from_bip32(&prv_key_data1.id, &storable));
         let account1 =
             AccountStorage::try_new(BIP32_ACCOUNT_KIND.into(), &id, &storage_key, prv_key_data1.id.into(), settings, storable)?;
@@ -82,7 +90,7 @@ mod tests {
         payload.accounts.push(account1);
 
         let settings = AccountSettings { name: Some("Wallet-B".to_string()), ..Default::default() };
-        let storable = bip32::Payload::new(0, vec![pub_key_data2.clone()].into(), false);
+        let storable = bip32::Payload::new(0, vec![pub_key_data2.clone()].into(), false, None);
         let (id, storage_key) = make_account_hashes(from_bip32(&prv_key_data2.id, &storable));
         let account2 =
             AccountStorage::try_new(BIP32_ACCOUNT_KIND.into(), &id, &storage_key, prv_key_data2.id.into(), settings, storable)?;
@@ -0,0 +1,323 @@
+//!
+//! Transaction record export (CSV / JSON) for accounting purposes.
+//!
+//! [`TransactionExportOptions`] selects the output format, the exported
+//! columns and the date range of the exported records. Each exported row
+//! represents a change of the account balance: change and stasis records
+//! (that do not affect the account balance) as well as records reverted
+//! by a chain reorganization are not exported. Amounts and fees are
+//! expressed in KAS with 8 decimal places; amounts of outgoing
+//! transactions are negative and exclude the transaction fee.
+//!
+
+use super::*;
+use crate::imports::*;
+use crate::utils::{Amount, AmountFormat};
+use chrono::{DateTime, NaiveDate};
+use kaspa_txscript::extract_script_pub_key_address;
+
+/// Output format of the transaction record export.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TransactionExportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+impl FromStr for TransactionExportFormat {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "csv" => Ok(TransactionExportFormat::Csv),
+            "json" => Ok(TransactionExportFormat::Json),
+            _ => Err(Error::custom(format!("invalid export format '{s}' (expected 'csv' or 'json')"))),
+        }
+    }
+}
+
+impl std::fmt::Display for TransactionExportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransactionExportFormat::Csv => write!(f, "csv"),
+            TransactionExportFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+/// Column of the transaction record export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionExportColumn {
+    /// Transaction time (ISO 8601, UTC)
+    Timestamp,
+    /// Transaction id
+    Txid,
+    /// `in`, `out`, `reorg` (reverted receipt) or `batch` (UTXO compounding)
+    Direction,
+    /// Signed amount in KAS (excluding fees)
+    Amount,
+    /// Fees paid by the account in KAS
+    Fee,
+    /// Receiving addresses of incoming transactions or destination
+    /// addresses of outgoing transactions
+    Address,
+    /// Transaction note
+    Note,
+}
+
+impl TransactionExportColumn {
+    pub fn all() -> Vec<TransactionExportColumn> {
+        use TransactionExportColumn::*;
+        vec![Timestamp, Txid, Direction, Amount, Fee, Address, Note]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TransactionExportColumn::Timestamp => "timestamp",
+            TransactionExportColumn::Txid => "txid",
+            TransactionExportColumn::Direction => "direction",
+            TransactionExportColumn::Amount => "amount",
+            TransactionExportColumn::Fee => "fee",
+            TransactionExportColumn::Address => "address",
+            TransactionExportColumn::Note => "note",
+        }
+    }
+}
+
+impl FromStr for TransactionExportColumn {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        Self::all()
+            .into_iter()
+            .find(|column| column.name() == s.to_lowercase())
+            .ok_or_else(|| Error::custom(format!("invalid export column '{s}'")))
+    }
+}
+
+impl std::fmt::Display for TransactionExportColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Options of the transaction record export.
+#[derive(Debug, Clone, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionExportOptions {
+    pub format: TransactionExportFormat,
+    /// Exported columns (all columns if empty)
+    pub columns: Vec<TransactionExportColumn>,
+    /// Export records created at or after this time (unix time in milliseconds)
+    pub since_unixtime_msec: Option<u64>,
+    /// Export records created before this time (unix time in milliseconds)
+    pub until_unixtime_msec: Option<u64>,
+}
+
+impl TransactionExportOptions {
+    pub fn new(format: TransactionExportFormat) -> Self {
+        Self { format, columns: vec![], since_unixtime_msec: None, until_unixtime_msec: None }
+    }
+
+    pub fn with_columns(mut self, columns: Vec<TransactionExportColumn>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    pub fn with_range(mut self, since_unixtime_msec: Option<u64>, until_unixtime_msec: Option<u64>) -> Self {
+        self.since_unixtime_msec = since_unixtime_msec;
+        self.until_unixtime_msec = until_unixtime_msec;
+        self
+    }
+
+    fn columns(&self) -> Vec<TransactionExportColumn> {
+        if self.columns.is_empty() {
+            TransactionExportColumn::all()
+        } else {
+            self.columns.clone()
+        }
+    }
+
+    /// Records without a timestamp are exported only if the date range is not specified.
+    fn includes(&self, record: &TransactionRecord) -> bool {
+        if record.is_reorged() || matches!(record.kind(), TransactionKind::Change | TransactionKind::Stasis) {
+            return false;
+        }
+
+        if self.since_unixtime_msec.is_none() && self.until_unixtime_msec.is_none() {
+            return true;
+        }
+
+        record.unixtime_msec().is_some_and(|unixtime| {
+            self.since_unixtime_msec.map_or(true, |since| unixtime >= since)
+                && self.until_unixtime_msec.map_or(true, |until| unixtime < until)
+        })
+    }
+
+    /// Export the `records` (ordered by time) in the configured format.
+    pub fn export(&self, records: &[Arc<TransactionRecord>]) -> Result<String> {
+        let mut records = records.iter().filter(|record| self.includes(record)).collect::<Vec<_>>();
+        records.sort_by_key(|record| (record.unixtime_msec().unwrap_or_default(), record.block_daa_score()));
+
+        let columns = self.columns();
+        match self.format {
+            TransactionExportFormat::Csv => {
+                let mut csv = columns.iter().map(|column| column.name()).collect::<Vec<_>>().join(",");
+                csv.push('\n');
+                for record in records {
+                    let row = columns.iter().map(|column| csv_escape(&column_value(record, column).join(" "))).collect::<Vec<_>>();
+                    csv.push_str(&row.join(","));
+                    csv.push('\n');
+                }
+                Ok(csv)
+            }
+            TransactionExportFormat::Json => {
+                let rows = records
+                    .into_iter()
+                    .map(|record| {
+                        columns
+                            .iter()
+                            .map(|column| {
+                                let values = column_value(record, column);
+                                let value = if *column == TransactionExportColumn::Address {
+                                    serde_json::Value::from(values)
+                                } else {
+                                    serde_json::Value::from(values.join(" "))
+                                };
+                                (column.name().to_string(), value)
+                            })
+                            .collect::<serde_json::Map<_, _>>()
+                    })
+                    .collect::<Vec<_>>();
+                Ok(serde_json::to_string_pretty(&rows)?)
+            }
+        }
+    }
+}
+
+fn column_value(record: &TransactionRecord, column: &TransactionExportColumn) -> Vec<String> {
+    let value = match column {
+        TransactionExportColumn::Timestamp => record.unixtime_msec().map(unixtime_to_iso8601).unwrap_or_default(),
+        TransactionExportColumn::Txid => record.id().to_string(),
+        TransactionExportColumn::Direction => match record.kind() {
+            TransactionKind::Incoming | TransactionKind::TransferIncoming => "in".to_string(),
+            TransactionKind::Outgoing | TransactionKind::TransferOutgoing | TransactionKind::External => "out".to_string(),
+            kind => kind.to_string(),
+        },
+        TransactionExportColumn::Amount => match record.transaction_data() {
+            TransactionData::Incoming { .. } | TransactionData::TransferIncoming { .. } => format_amount(record.value(), false),
+            TransactionData::Batch { .. } => format_amount(0, false),
+            // outgoing records without a payment value (such as sweeps) export
+            // the value leaving the account excluding the change and the fees
+            TransactionData::Outgoing { payment_value: None, aggregate_input_value, change_value, fees, .. }
+            | TransactionData::TransferOutgoing { payment_value: None, aggregate_input_value, change_value, fees, .. } => {
+                format_amount(aggregate_input_value.saturating_sub(*change_value).saturating_sub(*fees), true)
+            }
+            _ => format_amount(record.value(), true),
+        },
+        TransactionExportColumn::Fee => match record.transaction_data() {
+            TransactionData::Outgoing { fees, .. }
+            | TransactionData::TransferOutgoing { fees, .. }
+            | TransactionData::Batch { fees, .. } => format_amount(*fees, false),
+            _ => String::new(),
+        },
+        TransactionExportColumn::Address => return addresses(record),
+        TransactionExportColumn::Note => record.note.clone().unwrap_or_default(),
+    };
+
+    vec![value]
+}
+
+/// Receiving addresses of incoming records or destination addresses of outgoing
+/// records (the change output, appended last by the transaction generator,
+/// is excluded).
+fn addresses(record: &TransactionRecord) -> Vec<String> {
+    let mut addresses = match record.transaction_data() {
+        TransactionData::Outgoing { transaction, change_value, .. }
+        | TransactionData::TransferOutgoing { transaction, change_value, .. }
+        | TransactionData::Batch { transaction, change_value, .. } => {
+            let prefix = Prefix::from(record.network_id().network_type());
+            let payments = match transaction.outputs.split_last() {
+                Some((change, payments)) if *change_value > 0 && change.value == *change_value => payments,
+                _ => transaction.outputs.as_slice(),
+            };
+            payments
+                .iter()
+                .filter_map(|output| extract_script_pub_key_address(&output.script_public_key, prefix).ok())
+                .map(|address| address.to_string())
+                .collect::<Vec<_>>()
+        }
+        TransactionData::Reorg { utxo_entries, .. }
+        | TransactionData::Incoming { utxo_entries, .. }
+        | TransactionData::Stasis { utxo_entries, .. }
+        | TransactionData::External { utxo_entries, .. }
+        | TransactionData::TransferIncoming { utxo_entries, .. }
+        | TransactionData::Change { utxo_entries, .. } => {
+            utxo_entries.iter().filter_map(|entry| entry.address.as_ref()).map(|address| address.to_string()).collect::<Vec<_>>()
+        }
+    };
+
+    addresses.dedup();
+    addresses
+}
+
+/// Format the amount in KAS with 8 decimal places (negative amounts are prefixed with `-`).
+fn format_amount(sompi: u64, negative: bool) -> String {
+    let amount = AmountFormat::default().with_precision(8).format(Amount::from_sompi(sompi));
+    if negative && sompi > 0 {
+        format!("-{amount}")
+    } else {
+        amount
+    }
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Format unix time (in milliseconds) as an ISO 8601 UTC timestamp.
+fn unixtime_to_iso8601(unixtime_msec: u64) -> String {
+    i64::try_from(unixtime_msec)
+        .ok()
+        .and_then(DateTime::from_timestamp_millis)
+        .map(|datetime| datetime.format("%Y-%m-%dT%H:%M:%SZ").to_string())
+        .unwrap_or_default()
+}
+
+/// Parse a `YYYY-MM-DD` date as unix time (in milliseconds) of the UTC midnight.
+pub fn date_to_unixtime_msec(date: &str) -> Result<u64> {
+    NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .and_then(|datetime| u64::try_from(datetime.and_utc().timestamp_millis()).ok())
+        .ok_or_else(|| Error::custom(format!("invalid date '{date}' (expected YYYY-MM-DD)")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transaction_export_formatting() {
+        assert_eq!(unixtime_to_iso8601(0), "1970-01-01T00:00:00Z");
+        assert_eq!(unixtime_to_iso8601(1_709_251_199_999), "2024-02-29T23:59:59Z");
+        assert_eq!(date_to_unixtime_msec("2024-02-29").unwrap(), 1_709_164_800_000);
+        assert_eq!(unixtime_to_iso8601(date_to_unixtime_msec("2023-12-31").unwrap()), "2023-12-31T00:00:00Z");
+        assert!(date_to_unixtime_msec("2024-13-01").is_err());
+        assert!(date_to_unixtime_msec("2023-02-29").is_err());
+        assert!(date_to_unixtime_msec("2024-04-31").is_err());
+        assert!(date_to_unixtime_msec("1969-12-31").is_err());
+        assert_eq!(format_amount(150_000_000, true), "-1.50000000");
+        assert_eq!(format_amount(1, false), "0.00000001");
+        assert_eq!(format_amount(0, true), "0.00000000");
+        assert_eq!(csv_escape("plain"), "plain");
+        assert_eq!(csv_escape("a, \"b\""), "\"a, \"\"b\"\"\"");
+        assert_eq!("JSON".parse::<TransactionExportFormat>().unwrap(), TransactionExportFormat::Json);
+        assert_eq!("txid".parse::<TransactionExportColumn>().unwrap(), TransactionExportColumn::Txid);
+        assert!("value".parse::<TransactionExportColumn>().is_err());
+    }
+}
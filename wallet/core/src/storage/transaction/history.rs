@@ -0,0 +1,167 @@
+//!
+//! Account balance history derived from the wallet transaction records.
+//!
+
+use crate::imports::*;
+use kaspa_consensus_core::tx::TransactionId;
+use std::collections::BTreeMap;
+
+/// Duration of a balance history time bucket (1 day).
+pub const BALANCE_HISTORY_BUCKET_MSEC: u64 = 24 * 60 * 60 * 1000;
+
+/// Timestamp and the account balance change of a single transaction record.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct BalanceHistoryEntry {
+    pub unixtime_msec: u64,
+    pub delta: i64,
+}
+
+/// Account balance at the end of a daily time bucket.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceHistoryPoint {
+    /// Start of the time bucket (UTC midnight).
+    pub unixtime_msec: u64,
+    /// Balance at the end of the time bucket.
+    pub balance: u64,
+    /// Funds received during the time bucket.
+    pub received: u64,
+    /// Funds spent (including fees) during the time bucket.
+    pub sent: u64,
+}
+
+/// Balance changes of the processed transaction records of a binding.
+/// The cache is persisted by the transaction record storage, allowing
+/// the balance history to be updated incrementally as new transaction
+/// records are stored.
+///
+/// A transaction id can have multiple entries: records of UTXOs removed
+/// by a reorg or spent externally replace the record of the transaction
+/// that created them, in which case the balance change of the replaced
+/// record is retained by the cache.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BalanceHistoryCache {
+    entries: HashMap<TransactionId, Vec<BalanceHistoryEntry>>,
+}
+
+impl BalanceHistoryCache {
+    const STORAGE_MAGIC: u32 = 0x54534842;
+    const STORAGE_VERSION: u32 = 0;
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn contains(&self, id: &TransactionId) -> bool {
+        self.entries.contains_key(id)
+    }
+
+    /// Sets the entry of the transaction, replacing existing entries.
+    pub fn insert(&mut self, id: TransactionId, entry: BalanceHistoryEntry) {
+        self.entries.insert(id, vec![entry]);
+    }
+
+    /// Adds an entry to the existing entries of the transaction.
+    pub fn append(&mut self, id: TransactionId, entry: BalanceHistoryEntry) {
+        self.entries.entry(id).or_default().push(entry);
+    }
+
+    /// Removes entries of transaction records that no longer exist.
+    /// Returns `true` if any entries were removed.
+    pub fn retain(&mut self, ids: &HashSet<TransactionId>) -> bool {
+        let len = self.entries.len();
+        self.entries.retain(|id, _| ids.contains(id));
+        len != self.entries.len()
+    }
+
+    /// Produces the balance history in daily buckets. Days without
+    /// transactions are included, carrying the balance of the previous day.
+    /// If `since_unixtime_msec` is supplied, buckets preceding it are
+    /// omitted (but are still accounted for in the balance).
+    pub fn history(&self, since_unixtime_msec: Option<u64>) -> Vec<BalanceHistoryPoint> {
+        let mut buckets = BTreeMap::<u64, (u64, u64)>::new();
+        for entry in self.entries.values().flatten() {
+            let bucket = entry.unixtime_msec - entry.unixtime_msec % BALANCE_HISTORY_BUCKET_MSEC;
+            let (received, sent) = buckets.entry(bucket).or_default();
+            if entry.delta >= 0 {
+                *received = received.saturating_add(entry.delta.unsigned_abs());
+            } else {
+                *sent = sent.saturating_add(entry.delta.unsigned_abs());
+            }
+        }
+
+        let (Some(first), Some(last)) = (buckets.keys().next().copied(), buckets.keys().next_back().copied()) else {
+            return vec![];
+        };
+
+        let mut history = vec![];
+        let mut balance = 0i128;
+        let mut bucket = first;
+        while bucket <= last {
+            let (received, sent) = buckets.get(&bucket).copied().unwrap_or_default();
+            balance += received as i128 - sent as i128;
+            if since_unixtime_msec.map_or(true, |since| bucket + BALANCE_HISTORY_BUCKET_MSEC > since) {
+                history.push(BalanceHistoryPoint {
+                    unixtime_msec: bucket,
+                    balance: balance.clamp(0, u64::MAX as i128) as u64,
+                    received,
+                    sent,
+                });
+            }
+            bucket += BALANCE_HISTORY_BUCKET_MSEC;
+        }
+
+        history
+    }
+}
+
+impl BorshSerialize for BalanceHistoryCache {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        StorageHeader::new(Self::STORAGE_MAGIC, Self::STORAGE_VERSION).serialize(writer)?;
+        let entries = self.entries.iter().collect::<Vec<_>>();
+        BorshSerialize::serialize(&entries, writer)?;
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for BalanceHistoryCache {
+    fn deserialize(buf: &mut &[u8]) -> IoResult<Self> {
+        let StorageHeader { version: _, .. } =
+            StorageHeader::deserialize(buf)?.try_magic(Self::STORAGE_MAGIC)?.try_version(Self::STORAGE_VERSION)?;
+        let entries: Vec<(TransactionId, Vec<BalanceHistoryEntry>)> = BorshDeserialize::deserialize(buf)?;
+        Ok(Self { entries: entries.into_iter().collect() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_balance_history() {
+        let day = BALANCE_HISTORY_BUCKET_MSEC;
+        let mut cache = BalanceHistoryCache::default();
+        cache.insert(TransactionId::from_u64_word(1), BalanceHistoryEntry { unixtime_msec: 10 * day + 1, delta: 500 });
+        cache.insert(TransactionId::from_u64_word(2), BalanceHistoryEntry { unixtime_msec: 10 * day + 2, delta: -100 });
+        cache.insert(TransactionId::from_u64_word(3), BalanceHistoryEntry { unixtime_msec: 13 * day, delta: 70 });
+        cache.append(TransactionId::from_u64_word(3), BalanceHistoryEntry { unixtime_msec: 13 * day + 1, delta: -20 });
+
+        let history = cache.history(None);
+        assert_eq!(history.iter().map(|point| point.unixtime_msec).collect::<Vec<_>>(), vec![10 * day, 11 * day, 12 * day, 13 * day]);
+        assert_eq!(history.iter().map(|point| point.balance).collect::<Vec<_>>(), vec![400, 400, 400, 450]);
+        assert_eq!((history[0].received, history[0].sent), (500, 100));
+        assert_eq!((history[3].received, history[3].sent), (70, 20));
+        assert_eq!(cache.history(Some(12 * day + 5)).len(), 2);
+
+        let ids = HashSet::from([TransactionId::from_u64_word(1), TransactionId::from_u64_word(3)]);
+        assert!(cache.retain(&ids));
+        assert!(!cache.retain(&ids));
+
+        let deserialized = BalanceHistoryCache::try_from_slice(&cache.try_to_vec().unwrap()).unwrap();
+        assert_eq!(deserialized, cache);
+    }
+}
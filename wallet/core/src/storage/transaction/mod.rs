@@ -3,11 +3,17 @@
 //!
 
 pub mod data;
+pub mod export;
+pub mod history;
 pub mod kind;
 pub mod record;
+pub mod timestamps;
 pub mod utxo;
 
 pub use data::*;
+pub use export::*;
+pub use history::*;
 pub use kind::*;
 pub use record::*;
+pub use timestamps::*;
 pub use utxo::*;
@@ -277,6 +277,19 @@ export interface ITransactionRecord {
      * and store its own metadata into the value of this key.
      */
     metadata?: string;
+    /**
+     * Set if the transaction has been accepted by the network and
+     * subsequently invalidated by a chain reorganization (its
+     * consumed UTXOs have been returned to the wallet).
+     */
+    reorged?: boolean;
+    /**
+     * Binding of the linked record of an internal transfer between
+     * wallet accounts (the destination of an outgoing transfer or the
+     * source of an incoming transfer). The linked record carries the
+     * same transaction id.
+     */
+    linkedBinding?: HexString;
 
     /**
      * Transaction data type.
@@ -336,11 +349,20 @@ pub struct TransactionRecord {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[wasm_bindgen(getter_with_clone)]
     pub metadata: Option<String>,
+    /// Transaction acceptance has been reverted by a chain reorganization
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub reorged: bool,
+    /// Binding of the counterpart record of an internal transfer
+    /// (both records share the transaction id)
+    #[serde(rename = "linkedBinding")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[wasm_bindgen(skip)]
+    pub linked_binding: Option<Binding>,
 }
 
 impl TransactionRecord {
     const STORAGE_MAGIC: u32 = 0x5854414b;
-    const STORAGE_VERSION: u32 = 0;
+    const STORAGE_VERSION: u32 = 2;
 
     pub fn id(&self) -> &TransactionId {
         &self.id
@@ -421,6 +443,23 @@ impl TransactionRecord {
         matches!(&self.transaction_data, TransactionData::TransferIncoming { .. } | TransactionData::TransferOutgoing { .. })
     }
 
+    /// Returns `true` if the transaction acceptance has been
+    /// reverted by a chain reorganization.
+    pub fn is_reorged(&self) -> bool {
+        self.reorged
+    }
+
+    pub fn set_reorged(&mut self, reorged: bool) {
+        self.reorged = reorged;
+    }
+
+    /// Binding of the linked record of an internal transfer: the destination
+    /// account of an outgoing transfer or the source account of an incoming
+    /// transfer. The linked record is stored under the same transaction id.
+    pub fn linked_binding(&self) -> Option<&Binding> {
+        self.linked_binding.as_ref()
+    }
+
     pub fn transaction_data(&self) -> &TransactionData {
         &self.transaction_data
     }
@@ -460,6 +499,22 @@ impl TransactionRecord {
     pub fn value(&self) -> u64 {
         self.value
     }
+
+    /// Change of the account balance caused by the transaction (outgoing
+    /// transactions account for the spent amount including fees, while
+    /// the change returned to the account is excluded).
+    pub fn balance_delta(&self) -> i64 {
+        match &self.transaction_data {
+            TransactionData::Incoming { .. } | TransactionData::TransferIncoming { .. } => self.value as i64,
+            TransactionData::Reorg { .. } | TransactionData::External { .. } => -(self.value as i64),
+            TransactionData::Outgoing { aggregate_input_value, change_value, .. }
+            | TransactionData::TransferOutgoing { aggregate_input_value, change_value, .. } => {
+                -(aggregate_input_value.saturating_sub(*change_value) as i64)
+            }
+            TransactionData::Batch { fees, .. } => -(*fees as i64),
+            TransactionData::Stasis { .. } | TransactionData::Change { .. } => 0,
+        }
+    }
 }
 
 impl TransactionRecord {
@@ -505,6 +560,8 @@ impl TransactionRecord {
             network_id: utxo_context.processor().network_id().expect("network expected for transaction record generation"),
             metadata: None,
             note: None,
+            reorged: false,
+            linked_binding: None,
         }
     }
 
@@ -530,6 +587,8 @@ impl TransactionRecord {
             network_id: utxo_context.processor().network_id().expect("network expected for transaction record generation"),
             metadata: None,
             note: None,
+            reorged: false,
+            linked_binding: None,
         }
     }
 
@@ -580,6 +639,8 @@ impl TransactionRecord {
             network_id: utxo_context.processor().network_id().expect("network expected for transaction record generation"),
             metadata: None,
             note: None,
+            reorged: false,
+            linked_binding: None,
         })
     }
 
@@ -626,6 +687,8 @@ impl TransactionRecord {
             network_id: utxo_context.processor().network_id().expect("network expected for transaction record generation"),
             metadata: None,
             note: None,
+            reorged: false,
+            linked_binding: None,
         })
     }
 
@@ -678,6 +741,8 @@ impl TransactionRecord {
             network_id: utxo_context.processor().network_id().expect("network expected for transaction record generation"),
             metadata: None,
             note: None,
+            reorged: false,
+            linked_binding: Some(Binding::from(outgoing_tx.originating_context().binding())),
         })
     }
 
@@ -730,6 +795,8 @@ impl TransactionRecord {
             network_id: utxo_context.processor().network_id().expect("network expected for transaction record generation"),
             metadata: None,
             note: None,
+            reorged: false,
+            linked_binding: outgoing_tx.destination_context().as_ref().map(|context| Binding::from(context.binding())),
         })
     }
 
@@ -778,6 +845,58 @@ impl TransactionRecord {
             network_id: utxo_context.processor().network_id().expect("network expected for transaction record generation"),
             metadata: None,
             note: None,
+            reorged: false,
+            linked_binding: None,
+        })
+    }
+
+    /// Outgoing transaction reconstructed from the network history
+    /// (see [`HistoryRescan`](crate::utxo::history::HistoryRescan)).
+    /// `utxos` are the account UTXOs spent by the transaction. Fees
+    /// can only be determined if all transaction inputs are known.
+    pub fn new_recovered_outgoing(
+        utxo_context: &UtxoContext,
+        transaction: kaspa_consensus_core::tx::Transaction,
+        accepted_daa_score: u64,
+        utxos: &[UtxoEntryReference],
+        change_value: u64,
+    ) -> Result<Self> {
+        let binding = Binding::from(utxo_context.binding());
+        let utxo_entries = utxos.iter().map(UtxoRecord::from).collect::<Vec<_>>();
+
+        let aggregate_input_value = utxo_entries.iter().map(|utxo| utxo.amount).sum::<u64>();
+        let aggregate_output_value = transaction.outputs.iter().map(|output| output.value).sum::<u64>();
+        let fees = if utxo_entries.len() == transaction.inputs.len() {
+            aggregate_input_value.saturating_sub(aggregate_output_value)
+        } else {
+            0
+        };
+        let payment_value = aggregate_output_value.saturating_sub(change_value);
+
+        let id = transaction.id();
+        let transaction_data = TransactionData::Outgoing {
+            fees,
+            aggregate_input_value,
+            aggregate_output_value,
+            transaction,
+            payment_value: Some(payment_value),
+            change_value,
+            accepted_daa_score: Some(accepted_daa_score),
+            utxo_entries,
+        };
+
+        Ok(TransactionRecord {
+            id,
+            unixtime_msec: None,
+            value: payment_value,
+            binding,
+            transaction_data,
+            block_daa_score: accepted_daa_score,
+            network_id: utxo_context.processor().network_id()?,
+            metadata: None,
+            note: None,
+            reorged: false,
+            linked_binding: None,
         })
     }
 }
@@ -789,6 +908,11 @@ impl TransactionRecord {
         serde_wasm_bindgen::to_value(&self.binding).unwrap()
     }
 
+    #[wasm_bindgen(getter, js_name = "linkedBinding")]
+    pub fn linked_binding_as_js_value(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.linked_binding).unwrap()
+    }
+
     #[wasm_bindgen(getter, js_name = "data")]
     pub fn data_as_js_value(&self) -> JsValue {
         try_get_js_value_prop(&serde_wasm_bindgen::to_value(&self.transaction_data).unwrap(), "data").unwrap()
@@ -831,6 +955,8 @@ impl BorshSerialize for TransactionRecord {
         BorshSerialize::serialize(&self.transaction_data, writer)?;
         BorshSerialize::serialize(&self.note, writer)?;
         BorshSerialize::serialize(&self.metadata, writer)?;
+        BorshSerialize::serialize(&self.reorged, writer)?;
+        BorshSerialize::serialize(&self.linked_binding, writer)?;
 
         Ok(())
     }
@@ -838,7 +964,7 @@ impl BorshSerialize for TransactionRecord {
 
 impl BorshDeserialize for TransactionRecord {
     fn deserialize(buf: &mut &[u8]) -> IoResult<Self> {
-        let StorageHeader { version: _, .. } =
+        let StorageHeader { version, .. } =
             StorageHeader::deserialize(buf)?.try_magic(Self::STORAGE_MAGIC)?.try_version(Self::STORAGE_VERSION)?;
 
         let id = BorshDeserialize::deserialize(buf)?;
@@ -850,8 +976,22 @@ impl BorshDeserialize for TransactionRecord {
         let transaction_data = BorshDeserialize::deserialize(buf)?;
         let note = BorshDeserialize::deserialize(buf)?;
         let metadata = BorshDeserialize::deserialize(buf)?;
+        let reorged = if version > 0 { BorshDeserialize::deserialize(buf)? } else { false };
+        let linked_binding = if version > 1 { BorshDeserialize::deserialize(buf)? } else { None };
 
-        Ok(Self { id, unixtime_msec: unixtime, value, binding, block_daa_score, network_id, transaction_data, note, metadata })
+        Ok(Self {
+            id,
+            unixtime_msec: unixtime,
+            value,
+            binding,
+            block_daa_score,
+            network_id,
+            transaction_data,
+            note,
+            metadata,
+            reorged,
+            linked_binding,
+        })
     }
 }
 
@@ -0,0 +1,140 @@
+//!
+//! DAA score to unixtime cache used to backfill transaction record timestamps.
+//!
+
+use crate::imports::*;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::RangeBounds;
+
+/// Timestamps of DAA scores resolved via the node (see
+/// [`RpcApi::get_daa_score_timestamp_estimate`](kaspa_rpc_core::api::rpc::RpcApi::get_daa_score_timestamp_estimate)).
+/// The cache is persisted per network by the transaction record storage,
+/// preventing repeated resolution of the same DAA scores.
+///
+/// The cache holds at most [`DaaTimestampCache::MAX_ENTRIES`] timestamps,
+/// evicting the lowest DAA scores. DAA scores inserted or evicted since the
+/// cache was loaded are tracked (see [`DaaTimestampCache::modified`]),
+/// allowing the storage to persist only the changes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DaaTimestampCache {
+    timestamps: BTreeMap<u64, u64>,
+    modified: BTreeSet<u64>,
+}
+
+impl DaaTimestampCache {
+    const STORAGE_MAGIC: u32 = 0x54414444;
+    const STORAGE_VERSION: u32 = 0;
+
+    /// Maximum number of timestamps held by the cache.
+    pub const MAX_ENTRIES: usize = 100_000;
+
+    pub fn len(&self) -> usize {
+        self.timestamps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.timestamps.is_empty()
+    }
+
+    pub fn get(&self, daa_score: u64) -> Option<u64> {
+        self.timestamps.get(&daa_score).copied()
+    }
+
+    pub fn insert(&mut self, daa_score: u64, unixtime_msec: u64) {
+        self.timestamps.insert(daa_score, unixtime_msec);
+        self.modified.insert(daa_score);
+        while self.timestamps.len() > Self::MAX_ENTRIES {
+            if let Some((evicted, _)) = self.timestamps.pop_first() {
+                self.modified.insert(evicted);
+            }
+        }
+    }
+
+    /// DAA scores inserted or evicted since the cache was loaded.
+    pub fn modified(&self) -> &BTreeSet<u64> {
+        &self.modified
+    }
+
+    pub fn is_modified(&self) -> bool {
+        !self.modified.is_empty()
+    }
+
+    pub fn clear_modified(&mut self) {
+        self.modified.clear();
+    }
+
+    /// Returns the timestamps of the DAA scores within the `range`
+    /// (used by the storage to persist the cache in parts).
+    pub fn range<R: RangeBounds<u64>>(&self, range: R) -> DaaTimestampCache {
+        Self { timestamps: self.timestamps.range(range).map(|(k, v)| (*k, *v)).collect(), modified: BTreeSet::default() }
+    }
+
+    /// Merges the timestamps loaded from the storage into the cache
+    /// (without marking them as modified).
+    pub fn merge(&mut self, other: DaaTimestampCache) {
+        self.timestamps.extend(other.timestamps);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u64, u64)> + '_ {
+        self.timestamps.iter().map(|(k, v)| (*k, *v))
+    }
+}
+
+impl FromIterator<(u64, u64)> for DaaTimestampCache {
+    /// Creates the cache from the timestamps loaded from the storage.
+    fn from_iter<I: IntoIterator<Item = (u64, u64)>>(iter: I) -> Self {
+        Self { timestamps: iter.into_iter().collect(), modified: BTreeSet::default() }
+    }
+}
+
+impl BorshSerialize for DaaTimestampCache {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        StorageHeader::new(Self::STORAGE_MAGIC, Self::STORAGE_VERSION).serialize(writer)?;
+        BorshSerialize::serialize(&self.timestamps, writer)?;
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for DaaTimestampCache {
+    fn deserialize(buf: &mut &[u8]) -> IoResult<Self> {
+        let StorageHeader { version: _, .. } =
+            StorageHeader::deserialize(buf)?.try_magic(Self::STORAGE_MAGIC)?.try_version(Self::STORAGE_VERSION)?;
+        let timestamps = BorshDeserialize::deserialize(buf)?;
+        Ok(Self { timestamps, modified: BTreeSet::default() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_daa_timestamp_cache() {
+        let mut cache = DaaTimestampCache::default();
+        assert!(!cache.is_modified());
+
+        cache.insert(20, 2_000);
+        cache.insert(10, 1_000);
+        assert_eq!(cache.modified().iter().copied().collect::<Vec<_>>(), vec![10, 20]);
+
+        // the modified DAA scores are not persisted
+        let mut loaded = DaaTimestampCache::try_from_slice(&cache.try_to_vec().unwrap()).unwrap();
+        assert!(!loaded.is_modified());
+        assert_eq!(loaded.iter().collect::<Vec<_>>(), vec![(10, 1_000), (20, 2_000)]);
+        assert_eq!(loaded.range(..20).iter().collect::<Vec<_>>(), vec![(10, 1_000)]);
+
+        // the lowest DAA scores are evicted once the cache is full
+        for daa_score in 100..(100 + DaaTimestampCache::MAX_ENTRIES as u64) {
+            loaded.insert(daa_score, daa_score * 100);
+        }
+        assert_eq!(loaded.len(), DaaTimestampCache::MAX_ENTRIES);
+        assert_eq!(loaded.get(10), None);
+        assert_eq!(loaded.get(20), None);
+        assert_eq!(loaded.get(100), Some(10_000));
+        assert!(loaded.modified().contains(&10) && loaded.modified().contains(&20));
+        assert_eq!(loaded.modified().len(), DaaTimestampCache::MAX_ENTRIES + 2);
+
+        loaded.clear_modified();
+        assert!(!loaded.is_modified());
+    }
+}
@@ -0,0 +1,462 @@
+//!
+//! Wallet secret (password) strength estimation.
+//!
+//! [`estimate_secret_strength`] produces a zxcvbn-style estimate of the
+//! number of guesses an attacker needs to find the secret. The secret is
+//! split into the weakest sequence of recognizable patterns (common
+//! passwords and words, including capitalized and "l33t" variants, repeated
+//! characters, alphabetic, numeric and keyboard sequences, years) with the
+//! remaining characters estimated as brute-force segments. The estimate is
+//! mapped to a score between `0` (too guessable) and `4` (very unguessable)
+//! accompanied by user-facing feedback.
+//!
+//! [`SecretStrengthPolicy`] enforces a minimum score when wallets are
+//! created or the wallet secret is changed (see [`WalletSettings::SecretStrength`](crate::settings::WalletSettings::SecretStrength)).
+//!
+
+use crate::imports::*;
+
+/// Maximum strength score.
+pub const MAXIMUM_SECRET_STRENGTH: u8 = 4;
+
+/// Passwords and words commonly used in secrets ordered by frequency.
+const COMMON: &[&str] = &[
+    "123456",
+    "password",
+    "12345678",
+    "qwerty",
+    "123456789",
+    "12345",
+    "1234",
+    "111111",
+    "1234567",
+    "dragon",
+    "123123",
+    "baseball",
+    "abc123",
+    "football",
+    "monkey",
+    "letmein",
+    "696969",
+    "shadow",
+    "master",
+    "666666",
+    "qwertyuiop",
+    "123321",
+    "mustang",
+    "1234567890",
+    "michael",
+    "654321",
+    "superman",
+    "1qaz2wsx",
+    "7777777",
+    "121212",
+    "000000",
+    "qazwsx",
+    "123qwe",
+    "killer",
+    "trustno1",
+    "jordan",
+    "jennifer",
+    "zxcvbnm",
+    "asdfgh",
+    "hunter",
+    "buster",
+    "soccer",
+    "harley",
+    "batman",
+    "andrew",
+    "tigger",
+    "sunshine",
+    "iloveyou",
+    "charlie",
+    "robert",
+    "thomas",
+    "hockey",
+    "ranger",
+    "daniel",
+    "starwars",
+    "klaster",
+    "112233",
+    "george",
+    "computer",
+    "michelle",
+    "jessica",
+    "pepper",
+    "zxcvbn",
+    "555555",
+    "131313",
+    "freedom",
+    "777777",
+    "pass",
+    "maggie",
+    "159753",
+    "aaaaaa",
+    "ginger",
+    "princess",
+    "joshua",
+    "cheese",
+    "amanda",
+    "summer",
+    "love",
+    "ashley",
+    "nicole",
+    "chelsea",
+    "biteme",
+    "matthew",
+    "access",
+    "yankees",
+    "987654321",
+    "dallas",
+    "austin",
+    "thunder",
+    "taylor",
+    "matrix",
+    "admin",
+    "welcome",
+    "login",
+    "secret",
+    "hello",
+    "whatever",
+    "dragon",
+    "passw0rd",
+    "flower",
+    "money",
+    "internet",
+    "ninja",
+    "wallet",
+    "kaspa",
+    "bitcoin",
+    "crypto",
+    "satoshi",
+    "blockchain",
+    "ethereum",
+    "hodl",
+    "moon",
+    "lambo",
+    "mnemonic",
+    "seed",
+    "private",
+    "default",
+];
+
+/// Keyboard rows and alphabets recognized as sequences.
+const SEQUENCES: &[&str] =
+    &["abcdefghijklmnopqrstuvwxyz", "0123456789", "qwertyuiop", "asdfghjkl", "zxcvbnm", "1qaz2wsx3edc4rfv5tgb6yhn7ujm8ik9ol0p"];
+
+/// Common character substitutions ("l33t speak").
+const SUBSTITUTIONS: &[(char, char)] = &[
+    ('4', 'a'),
+    ('@', 'a'),
+    ('8', 'b'),
+    ('3', 'e'),
+    ('6', 'g'),
+    ('1', 'i'),
+    ('!', 'i'),
+    ('0', 'o'),
+    ('5', 's'),
+    ('$', 's'),
+    ('7', 't'),
+];
+
+const MIN_COMMON_LENGTH: usize = 3;
+const MIN_YEAR: u32 = 1900;
+const MAX_YEAR: u32 = 2099;
+
+/// Estimated strength of a secret.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretStrength {
+    /// Score between `0` (too guessable) and [`MAXIMUM_SECRET_STRENGTH`] (very unguessable).
+    pub score: u8,
+    /// Estimated number of guesses needed to find the secret (log10).
+    pub guesses_log10: f64,
+    /// Explanation of the main weakness of the secret.
+    pub warning: Option<String>,
+    /// Suggestions helping to choose a stronger secret.
+    pub suggestions: Vec<String>,
+}
+
+/// Minimum strength of the wallet secrets (disabled by default).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretStrengthPolicy {
+    /// Minimum score of the wallet secret (`0` disables the policy).
+    pub min_score: u8,
+}
+
+impl SecretStrengthPolicy {
+    pub fn new(min_score: u8) -> Self {
+        Self { min_score: min_score.min(MAXIMUM_SECRET_STRENGTH) }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.min_score > 0
+    }
+
+    /// Returns [`Error::WeakSecret`] if the strength of the `secret` is below the minimum score.
+    pub fn validate(&self, secret: &Secret) -> Result<()> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+
+        let strength = estimate_secret_strength(secret);
+        if strength.score < self.min_score {
+            Err(Error::WeakSecret {
+                score: strength.score,
+                min_score: self.min_score,
+                warning: strength.warning,
+                suggestions: strength.suggestions,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pattern {
+    Common { rank: usize, l33t: bool, capitalized: bool },
+    Repeat,
+    Sequence,
+    Year,
+    BruteForce,
+}
+
+struct Segment {
+    pattern: Pattern,
+    guesses_log10: f64,
+}
+
+/// Estimate the strength of the `secret`.
+pub fn estimate_secret_strength(secret: &Secret) -> SecretStrength {
+    estimate_strength(&String::from_utf8_lossy(secret.as_ref()))
+}
+
+fn estimate_strength(secret: &str) -> SecretStrength {
+    let chars = secret.chars().collect::<Vec<_>>();
+    let segments = segments(&chars);
+
+    // the attacker needs to guess the number and order of the patterns
+    let combinations = (1..=segments.len()).map(|n| (n as f64).log10()).sum::<f64>();
+    let guesses_log10 = segments.iter().map(|segment| segment.guesses_log10).sum::<f64>() + combinations;
+
+    let score = match guesses_log10 {
+        g if g < 3.0 => 0,
+        g if g < 6.0 => 1,
+        g if g < 8.0 => 2,
+        g if g < 10.0 => 3,
+        _ => 4,
+    };
+
+    let (warning, suggestions) = feedback(score, &chars, &segments);
+    SecretStrength { score, guesses_log10, warning, suggestions }
+}
+
+/// Split the secret into recognizable patterns, using brute-force
+/// segments for characters that do not match any pattern.
+fn segments(chars: &[char]) -> Vec<Segment> {
+    let mut segments = vec![];
+    let mut brute_force = 0.0;
+    let mut index = 0;
+
+    while index < chars.len() {
+        match next_pattern(&chars[index..]) {
+            Some((length, segment)) => {
+                if brute_force > 0.0 {
+                    segments.push(Segment { pattern: Pattern::BruteForce, guesses_log10: brute_force });
+                    brute_force = 0.0;
+                }
+                segments.push(segment);
+                index += length;
+            }
+            None => {
+                brute_force += (cardinality(chars[index]) as f64).log10();
+                index += 1;
+            }
+        }
+    }
+
+    if brute_force > 0.0 {
+        segments.push(Segment { pattern: Pattern::BruteForce, guesses_log10: brute_force });
+    }
+
+    segments
+}
+
+/// Longest pattern at the start of `chars`.
+fn next_pattern(chars: &[char]) -> Option<(usize, Segment)> {
+    let mut best: Option<(usize, Segment)> = None;
+    let mut consider = |length: usize, pattern: Pattern, guesses: f64| {
+        if best.as_ref().map_or(true, |(best_length, _)| length > *best_length) {
+            best = Some((length, Segment { pattern, guesses_log10: guesses.max(1.0).log10() }));
+        }
+    };
+
+    // common passwords and words (including l33t and capitalized variants)
+    for length in MIN_COMMON_LENGTH..=chars.len() {
+        let candidate = &chars[..length];
+        let lower = candidate.iter().flat_map(|c| c.to_lowercase()).collect::<String>();
+        let unl33t = lower.chars().map(|c| SUBSTITUTIONS.iter().find(|(s, _)| *s == c).map_or(c, |(_, r)| *r)).collect::<String>();
+        let rank = COMMON.iter().position(|word| *word == lower).or_else(|| COMMON.iter().position(|word| *word == unl33t));
+        if let Some(rank) = rank {
+            let l33t = !COMMON.contains(&lower.as_str());
+            let capitalized = candidate.iter().any(|c| c.is_uppercase());
+            let mut guesses = (rank + 1) as f64;
+            if capitalized {
+                let uppercase = candidate.iter().filter(|c| c.is_uppercase()).count();
+                guesses *= if candidate[0].is_uppercase() && uppercase == 1 || uppercase == length {
+                    2.0
+                } else {
+                    2f64.powi(uppercase as i32)
+                };
+            }
+            if l33t {
+                guesses *= 2.0;
+            }
+            consider(length, Pattern::Common { rank, l33t, capitalized }, guesses);
+        }
+    }
+
+    // repeated characters
+    let repeat = chars.iter().take_while(|c| **c == chars[0]).count();
+    if repeat >= 3 {
+        consider(repeat, Pattern::Repeat, cardinality(chars[0]) as f64 * repeat as f64);
+    }
+
+    // alphabetic, numeric and keyboard sequences (ascending or descending)
+    let lower = chars.iter().map(|c| c.to_ascii_lowercase()).collect::<Vec<_>>();
+    for sequence in SEQUENCES {
+        let sequence = sequence.chars().collect::<Vec<_>>();
+        for descending in [false, true] {
+            let mut length = 0;
+            if let Some(start) = sequence.iter().position(|c| *c == lower[0]) {
+                while length < lower.len() {
+                    let position = if descending { start.checked_sub(length) } else { Some(start + length) };
+                    match position.and_then(|position| sequence.get(position)) {
+                        Some(c) if *c == lower[length] => length += 1,
+                        _ => break,
+                    }
+                }
+                if length >= 3 {
+                    // obvious starting points are guessed first
+                    let start_guesses =
+                        if matches!(lower[0], 'a' | 'z' | '0' | '1' | '9' | 'q') { 4.0 } else { sequence.len() as f64 };
+                    let direction = if descending { 2.0 } else { 1.0 };
+                    consider(length, Pattern::Sequence, start_guesses * length as f64 * direction);
+                }
+            }
+        }
+    }
+
+    // years
+    if chars.len() >= 4 && chars[..4].iter().all(|c| c.is_ascii_digit()) {
+        let year = chars[..4].iter().collect::<String>().parse::<u32>().unwrap();
+        if (MIN_YEAR..=MAX_YEAR).contains(&year) {
+            consider(4, Pattern::Year, (MAX_YEAR - MIN_YEAR) as f64);
+        }
+    }
+
+    best
+}
+
+/// Size of the character class of `c` assumed by the brute-force estimate.
+fn cardinality(c: char) -> u32 {
+    match c {
+        '0'..='9' => 10,
+        'a'..='z' | 'A'..='Z' => 26,
+        c if c.is_ascii() => 33,
+        _ => 100,
+    }
+}
+
+fn feedback(score: u8, chars: &[char], segments: &[Segment]) -> (Option<String>, Vec<String>) {
+    if chars.is_empty() {
+        return (
+            Some("The secret is empty".to_string()),
+            vec!["Use a few words, avoid common phrases".to_string(), "No need for symbols, digits, or uppercase letters".to_string()],
+        );
+    }
+
+    if score > 2 {
+        return (None, vec![]);
+    }
+
+    let mut suggestions = vec!["Add another word or two. Uncommon words are better.".to_string()];
+
+    // the weakest (most guessable) pattern covering most of the secret
+    let weakest = segments
+        .iter()
+        .filter(|segment| segment.pattern != Pattern::BruteForce)
+        .min_by(|a, b| a.guesses_log10.total_cmp(&b.guesses_log10))
+        .map(|segment| segment.pattern);
+
+    let warning = match weakest {
+        Some(Pattern::Common { rank, l33t, capitalized }) => {
+            if capitalized {
+                suggestions.push("Capitalization doesn't help very much".to_string());
+            }
+            if l33t {
+                suggestions.push("Predictable substitutions like '@' instead of 'a' don't help very much".to_string());
+            }
+            if segments.len() == 1 && rank < 10 {
+                Some("This is a top-10 common password")
+            } else if segments.len() == 1 && rank < 100 {
+                Some("This is a top-100 common password")
+            } else {
+                Some("This is similar to a commonly used password")
+            }
+        }
+        Some(Pattern::Repeat) => {
+            suggestions.push("Avoid repeated words and characters".to_string());
+            Some("Repeats like \"aaa\" are easy to guess")
+        }
+        Some(Pattern::Sequence) => {
+            suggestions.push("Avoid sequences".to_string());
+            Some("Sequences like abc, 6543 or qwerty are easy to guess")
+        }
+        Some(Pattern::Year) => {
+            suggestions.push("Avoid recent years and years associated with you".to_string());
+            Some("Recent years are easy to guess")
+        }
+        Some(Pattern::BruteForce) | None => (chars.len() < 10).then_some("Short secrets are easy to guess"),
+    };
+
+    (warning.map(String::from), suggestions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_strength() {
+        let score = |secret: &str| estimate_strength(secret).score;
+
+        assert_eq!(score(""), 0);
+        assert_eq!(score("password"), 0);
+        assert_eq!(score("P@ssw0rd"), 0);
+        assert_eq!(score("123456789"), 0);
+        assert_eq!(score("aaaaaaaaaaaa"), 0);
+        assert_eq!(score("qwertyuiop"), 0);
+        assert!(score("kaspa2024") <= 1);
+        assert!(score("tr0ub4dour&3") >= 2);
+        assert_eq!(score("correct horse battery staple"), 4);
+        assert_eq!(score("ABC-L4LXw2F7HEK3wJU-Rk4stbPy6c"), 4);
+
+        let strength = estimate_strength("password");
+        assert_eq!(strength.warning.as_deref(), Some("This is a top-10 common password"));
+        assert!(!strength.suggestions.is_empty());
+        assert!(estimate_strength("correct horse battery staple").warning.is_none());
+    }
+
+    #[test]
+    fn test_secret_strength_policy() {
+        assert!(SecretStrengthPolicy::default().validate(&Secret::from("1234")).is_ok());
+
+        let policy = SecretStrengthPolicy::new(3);
+        assert!(matches!(policy.validate(&Secret::from("letmein")), Err(Error::WeakSecret { score: 0, min_score: 3, .. })));
+        assert!(policy.validate(&Secret::from("ABC-L4LXw2F7HEK3wJU-Rk4stbPy6c")).is_ok());
+        assert_eq!(SecretStrengthPolicy::new(10).min_score, MAXIMUM_SECRET_STRENGTH);
+    }
+}
@@ -0,0 +1,683 @@
+use crate::imports::*;
+
+use async_channel::{unbounded, Receiver};
+use async_trait::async_trait;
+use kaspa_consensus_core::tx::Transaction;
+use kaspa_notify::events::EVENT_TYPE_ARRAY;
+use kaspa_notify::listener::{ListenerId, ListenerLifespan};
+use kaspa_notify::notifier::{Notifier, Notify};
+use kaspa_notify::scope::Scope;
+use kaspa_notify::subscription::context::SubscriptionContext;
+use kaspa_notify::subscription::{MutationPolicies, UtxosChangedMutationPolicy};
+use kaspa_rpc_core::api::ctl::RpcCtl;
+use kaspa_rpc_core::api::ops::RPC_API_VERSION;
+use kaspa_rpc_core::{api::rpc::RpcApi, *};
+use kaspa_rpc_core::{notify::connection::ChannelConnection, RpcResult};
+use kaspa_txscript::{extract_script_pub_key_address, pay_to_address_script};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+pub type RpcCoreNotifier = Notifier<Notification, ChannelConnection>;
+
+/// Virtual DAA score reported by the mock (high enough for
+/// UTXOs created at DAA score 0 to be considered mature).
+pub const MOCK_VIRTUAL_DAA_SCORE: u64 = 100_000;
+
+/// Timestamp of DAA score 0 reported by the mock DAA score timestamp
+/// estimate (the mock network produces one block per second).
+pub const MOCK_DAA_SCORE_UNIXTIME_MSEC: u64 = 1_700_000_000_000;
+
+/// Pruning point of the selected chain simulated by the mock
+/// (see [`MockRpcClient::add_chain_block()`]).
+pub const MOCK_PRUNING_POINT_HASH: RpcHash = RpcHash::from_bytes([0xff; 32]);
+
+impl From<Arc<MockRpcClient>> for Rpc {
+    fn from(rpc_mock: Arc<MockRpcClient>) -> Self {
+        Self::new(rpc_mock.clone(), rpc_mock.ctl.clone())
+    }
+}
+
+/// Mock implementation of the node RPC API, simulating a synced node
+/// with a UTXO index. The mock maintains an in-memory UTXO set that
+/// is updated by submitted transactions, allowing wallet scenarios
+/// (send, receive, maturity and reorgs) to run without a node.
+///
+/// The virtual DAA score only advances when requested via
+/// [`advance_virtual_daa_score()`](Self::advance_virtual_daa_score),
+/// which also emits notifications scheduled via
+/// [`schedule_notification()`](Self::schedule_notification).
+/// A wallet runtime backed by the mock can be created using
+/// [`Wallet::try_with_mock_rpc()`](crate::wallet::Wallet::try_with_mock_rpc).
+pub struct MockRpcClient {
+    ctl: RpcCtl,
+    core_notifier: Arc<RpcCoreNotifier>,
+    _sync_receiver: Receiver<()>,
+    network_id: NetworkId,
+    virtual_daa_score: AtomicU64,
+    is_started: AtomicBool,
+    /// Whether the DAA score timestamp estimate is served (simulating a node unable to resolve it otherwise)
+    daa_score_timestamp_estimate: AtomicBool,
+    utxos: Mutex<Vec<RpcUtxosByAddressesEntry>>,
+    transactions: Mutex<Vec<Transaction>>,
+    /// UTXOs spent by the submitted transactions (restored on reorg)
+    spent: Mutex<HashMap<RpcTransactionId, Vec<RpcUtxosByAddressesEntry>>>,
+    mempool: Mutex<Vec<Transaction>>,
+    /// Notifications emitted once the virtual DAA score reaches the key
+    scheduled: Mutex<BTreeMap<u64, Vec<Notification>>>,
+    /// Selected chain blocks following the pruning point and their accepted transactions
+    chain: Mutex<Vec<RpcAcceptedTransactionIds>>,
+    /// Chain blocks and the blocks merged by them
+    blocks: Mutex<HashMap<RpcHash, RpcBlock>>,
+    /// Start hashes of the served `GetVirtualChainFromBlock` requests
+    virtual_chain_requests: Mutex<Vec<RpcHash>>,
+}
+
+impl MockRpcClient {
+    pub fn new() -> Self {
+        Self::with_network_id(NetworkId::with_suffix(NetworkType::Testnet, 10))
+    }
+
+    pub fn with_network_id(network_id: NetworkId) -> Self {
+        let (sync_sender, sync_receiver) = unbounded();
+        let policies = MutationPolicies::new(UtxosChangedMutationPolicy::AddressSet);
+        let core_notifier: Arc<RpcCoreNotifier> = Arc::new(Notifier::with_sync(
+            "rpc-core",
+            EVENT_TYPE_ARRAY[..].into(),
+            vec![],
+            vec![],
+            SubscriptionContext::new(),
+            10,
+            policies,
+            Some(sync_sender),
+        ));
+        Self {
+            core_notifier,
+            _sync_receiver: sync_receiver,
+            ctl: RpcCtl::new(),
+            network_id,
+            virtual_daa_score: AtomicU64::new(MOCK_VIRTUAL_DAA_SCORE),
+            is_started: AtomicBool::new(false),
+            daa_score_timestamp_estimate: AtomicBool::new(true),
+            utxos: Mutex::new(vec![]),
+            transactions: Mutex::new(vec![]),
+            spent: Mutex::new(HashMap::new()),
+            mempool: Mutex::new(vec![]),
+            scheduled: Mutex::new(BTreeMap::new()),
+            chain: Mutex::new(vec![]),
+            blocks: Mutex::new(HashMap::new()),
+            virtual_chain_requests: Mutex::new(vec![]),
+        }
+    }
+
+    /// Creates the mock with the initial UTXO set.
+    pub fn with_utxos(network_id: NetworkId, utxos: Vec<RpcUtxosByAddressesEntry>) -> Self {
+        let mock = Self::with_network_id(network_id);
+        mock.set_utxos(utxos);
+        mock
+    }
+
+    pub fn network_id(&self) -> NetworkId {
+        self.network_id
+    }
+
+    pub fn virtual_daa_score(&self) -> u64 {
+        self.virtual_daa_score.load(Ordering::SeqCst)
+    }
+
+    /// Enables or disables the DAA score timestamp estimate; once disabled,
+    /// `GetDaaScoreTimestampEstimate` requests fail.
+    pub fn set_daa_score_timestamp_estimate(&self, enabled: bool) {
+        self.daa_score_timestamp_estimate.store(enabled, Ordering::SeqCst);
+    }
+
+    /// Advances the virtual DAA score, notifying `VirtualDaaScoreChanged` listeners
+    /// and emitting the notifications scheduled up to the new virtual DAA score.
+    pub fn advance_virtual_daa_score(&self, delta: u64) -> kaspa_notify::error::Result<()> {
+        let virtual_daa_score = self.virtual_daa_score.fetch_add(delta, Ordering::SeqCst) + delta;
+        let notification = Notification::VirtualDaaScoreChanged(VirtualDaaScoreChangedNotification { virtual_daa_score });
+        self.core_notifier.notify(notification)?;
+
+        let scheduled = {
+            let mut scheduled = self.scheduled.lock().unwrap();
+            let pending = scheduled.split_off(&(virtual_daa_score + 1));
+            std::mem::replace(&mut *scheduled, pending)
+        };
+        scheduled.into_values().flatten().try_for_each(|notification| self.core_notifier.notify(notification))
+    }
+
+    /// Schedules the `notification` to be emitted once the virtual DAA score
+    /// reaches `daa_score` (see [`advance_virtual_daa_score()`](Self::advance_virtual_daa_score)).
+    pub fn schedule_notification(&self, daa_score: u64, notification: Notification) {
+        self.scheduled.lock().unwrap().entry(daa_score).or_default().push(notification);
+    }
+
+    /// Emits the `notification` to the registered listeners.
+    pub fn notify(&self, notification: Notification) -> kaspa_notify::error::Result<()> {
+        self.core_notifier.notify(notification)
+    }
+
+    /// Adds a mature UTXO of `amount` sompi to the mock UTXO set,
+    /// notifying `UtxosChanged` listeners.
+    pub fn add_utxo(&self, address: &Address, amount: u64) -> kaspa_notify::error::Result<RpcUtxosByAddressesEntry> {
+        self.add_utxo_with_daa_score(address, amount, 0, false)
+    }
+
+    /// Adds a UTXO of `amount` sompi created at `block_daa_score` to the mock
+    /// UTXO set, notifying `UtxosChanged` listeners. UTXOs created at the current
+    /// virtual DAA score are considered pending by the wallet.
+    pub fn add_utxo_with_daa_score(
+        &self,
+        address: &Address,
+        amount: u64,
+        block_daa_score: u64,
+        is_coinbase: bool,
+    ) -> kaspa_notify::error::Result<RpcUtxosByAddressesEntry> {
+        let outpoint = RpcTransactionOutpoint::new(RpcTransactionId::from_slice(&rand::random::<[u8; 32]>()), 0);
+        let utxo_entry = RpcUtxoEntry::new(amount, pay_to_address_script(address), block_daa_score, is_coinbase);
+        let entry = RpcUtxosByAddressesEntry { address: Some(address.clone()), outpoint, utxo_entry };
+        self.utxos.lock().unwrap().push(entry.clone());
+        self.notify_utxos_changed(vec![entry.clone()], vec![])?;
+        Ok(entry)
+    }
+
+    /// Replaces the mock UTXO set (without notifying listeners).
+    pub fn set_utxos(&self, utxos: Vec<RpcUtxosByAddressesEntry>) {
+        *self.utxos.lock().unwrap() = utxos;
+    }
+
+    /// Removes UTXOs identified by the `outpoints` from the mock UTXO set
+    /// (simulating a reorg of the transactions that created them),
+    /// notifying `UtxosChanged` listeners. Returns the removed UTXOs.
+    pub fn remove_utxos(&self, outpoints: &[RpcTransactionOutpoint]) -> kaspa_notify::error::Result<Vec<RpcUtxosByAddressesEntry>> {
+        let removed = {
+            let mut utxos = self.utxos.lock().unwrap();
+            let (removed, retained) = utxos.drain(..).partition(|entry| outpoints.contains(&entry.outpoint));
+            *utxos = retained;
+            removed
+        };
+        if !removed.is_empty() {
+            self.notify_utxos_changed(vec![], removed.clone())?;
+        }
+        Ok(removed)
+    }
+
+    /// Reverts a transaction submitted to the mock, simulating its reorg:
+    /// UTXOs created by the transaction are removed and UTXOs spent by the
+    /// transaction are restored, notifying `UtxosChanged` listeners.
+    pub fn reorg_transaction(&self, transaction_id: &RpcTransactionId) -> RpcResult<()> {
+        let restored = self.spent.lock().unwrap().remove(transaction_id).ok_or(RpcError::TransactionNotFound(*transaction_id))?;
+        self.transactions.lock().unwrap().retain(|transaction| transaction.id() != *transaction_id);
+
+        let removed = {
+            let mut utxos = self.utxos.lock().unwrap();
+            let (removed, mut retained): (Vec<_>, Vec<_>) =
+                utxos.drain(..).partition(|entry| entry.outpoint.transaction_id == *transaction_id);
+            retained.extend(restored.iter().cloned());
+            *utxos = retained;
+            removed
+        };
+
+        self.notify_utxos_changed(restored, removed)?;
+        Ok(())
+    }
+
+    /// Returns the current UTXO set of the given address.
+    pub fn utxos(&self, address: &Address) -> Vec<RpcUtxosByAddressesEntry> {
+        self.utxos.lock().unwrap().iter().filter(|entry| entry.address.as_ref() == Some(address)).cloned().collect()
+    }
+
+    /// Returns transactions submitted to the mock.
+    pub fn transactions(&self) -> Vec<Transaction> {
+        self.transactions.lock().unwrap().clone()
+    }
+
+    /// Adds an unconfirmed transaction to the mock mempool (the transaction
+    /// is reported by mempool queries but does not affect the UTXO set).
+    pub fn add_mempool_transaction(&self, transaction: Transaction) {
+        self.mempool.lock().unwrap().push(transaction);
+    }
+
+    /// Removes a transaction from the mock mempool (simulating
+    /// the eviction of a double-spent transaction).
+    pub fn remove_mempool_transaction(&self, transaction_id: &RpcTransactionId) {
+        self.mempool.lock().unwrap().retain(|transaction| transaction.id() != *transaction_id);
+    }
+
+    /// Appends a chain block created at `daa_score` to the simulated selected
+    /// chain. The chain block merges a block containing the `transactions`,
+    /// which are reported as accepted by the chain block. Returns the hash
+    /// of the chain block.
+    pub fn add_chain_block(&self, daa_score: u64, transactions: Vec<Transaction>) -> RpcHash {
+        let mut chain = self.chain.lock().unwrap();
+        let mut blocks = self.blocks.lock().unwrap();
+        let selected_parent_hash = chain.last().map(|block| block.accepting_block_hash).unwrap_or(MOCK_PRUNING_POINT_HASH);
+        let accepted_transaction_ids = transactions.iter().map(|transaction| transaction.id()).collect::<Vec<_>>();
+
+        let make_block = |transactions: Vec<Transaction>, merge_set_blues_hashes: Vec<RpcHash>| {
+            let hash = RpcHash::from_bytes(rand::random());
+            let mut header = RpcHeader::from_precomputed_hash(hash, vec![selected_parent_hash]);
+            header.daa_score = daa_score;
+            header.timestamp = MOCK_DAA_SCORE_UNIXTIME_MSEC + daa_score * 1000;
+            let verbose_data = RpcBlockVerboseData {
+                hash,
+                difficulty: 0.0,
+                selected_parent_hash,
+                transaction_ids: transactions.iter().map(|transaction| transaction.id()).collect(),
+                is_header_only: false,
+                blue_score: daa_score,
+                children_hashes: vec![],
+                is_chain_block: !merge_set_blues_hashes.is_empty(),
+                merge_set_blues_hashes,
+                merge_set_reds_hashes: vec![],
+            };
+            RpcBlock {
+                header,
+                transactions: transactions.iter().map(RpcTransaction::from).collect(),
+                verbose_data: Some(verbose_data),
+            }
+        };
+
+        let merged = make_block(transactions, vec![]);
+        let chain_block = make_block(vec![], vec![merged.header.hash]);
+        let hash = chain_block.header.hash;
+        blocks.insert(merged.header.hash, merged);
+        blocks.insert(hash, chain_block);
+        chain.push(RpcAcceptedTransactionIds { accepting_block_hash: hash, accepted_transaction_ids });
+        hash
+    }
+
+    /// Returns the start hashes of the `GetVirtualChainFromBlock` requests served by the mock.
+    pub fn virtual_chain_requests(&self) -> Vec<RpcHash> {
+        self.virtual_chain_requests.lock().unwrap().clone()
+    }
+
+    fn notify_utxos_changed(
+        &self,
+        added: Vec<RpcUtxosByAddressesEntry>,
+        removed: Vec<RpcUtxosByAddressesEntry>,
+    ) -> kaspa_notify::error::Result<()> {
+        let notification = Notification::UtxosChanged(UtxosChangedNotification { added: Arc::new(added), removed: Arc::new(removed) });
+        self.core_notifier.notify(notification)
+    }
+
+    pub fn core_notifier(&self) -> Arc<RpcCoreNotifier> {
+        self.core_notifier.clone()
+    }
+
+    #[allow(dead_code)]
+    pub fn notify_new_block_template(&self) -> kaspa_notify::error::Result<()> {
+        let notification = Notification::NewBlockTemplate(NewBlockTemplateNotification {});
+        self.core_notifier.notify(notification)
+    }
+
+    #[allow(dead_code)]
+    pub async fn notify_complete(&self) {
+        assert!(self._sync_receiver.recv().await.is_ok(), "the notifier sync channel is unexpectedly empty and closed");
+    }
+
+    pub fn start(&self) {
+        if !self.is_started.swap(true, Ordering::SeqCst) {
+            self.core_notifier.clone().start();
+        }
+    }
+
+    pub async fn join(&self) {
+        self.core_notifier.join().await.expect("core notifier shutdown")
+    }
+
+    /// Starts the mock notifier and signals the RPC channel as connected,
+    /// triggering the connection handling of the bound [`UtxoProcessor`](crate::utxo::UtxoProcessor).
+    pub async fn connect(&self) -> RpcResult<()> {
+        self.start();
+        self.ctl.signal_open().await
+    }
+
+    /// Signals the RPC channel as disconnected.
+    pub async fn disconnect(&self) -> RpcResult<()> {
+        self.ctl.signal_close().await
+    }
+
+    // ---
+
+    pub fn ctl(&self) -> RpcCtl {
+        self.ctl.clone()
+    }
+}
+
+impl Default for MockRpcClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl RpcApi for MockRpcClient {
+    // This fn needs to succeed while the client connects
+    async fn get_info_call(&self, _request: GetInfoRequest) -> RpcResult<GetInfoResponse> {
+        Ok(GetInfoResponse {
+            p2p_id: "wallet-mock".to_string(),
+            mempool_size: 1234,
+            server_version: "mock".to_string(),
+            is_utxo_indexed: false,
+            is_synced: false,
+            has_notify_command: false,
+            has_message_id: false,
+        })
+    }
+
+    async fn ping_call(&self, _request: PingRequest) -> RpcResult<PingResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_metrics_call(&self, _request: GetMetricsRequest) -> RpcResult<GetMetricsResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_server_info_call(&self, _request: GetServerInfoRequest) -> RpcResult<GetServerInfoResponse> {
+        Ok(GetServerInfoResponse {
+            rpc_api_version: RPC_API_VERSION,
+            server_version: "mock".to_string(),
+            network_id: self.network_id,
+            has_utxo_index: true,
+            is_synced: true,
+            virtual_daa_score: self.virtual_daa_score(),
+        })
+    }
+
+    async fn get_sync_status_call(&self, _request: GetSyncStatusRequest) -> RpcResult<GetSyncStatusResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_current_network_call(&self, _request: GetCurrentNetworkRequest) -> RpcResult<GetCurrentNetworkResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn submit_block_call(&self, _request: SubmitBlockRequest) -> RpcResult<SubmitBlockResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_block_template_call(&self, _request: GetBlockTemplateRequest) -> RpcResult<GetBlockTemplateResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_peer_addresses_call(&self, _request: GetPeerAddressesRequest) -> RpcResult<GetPeerAddressesResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_sink_call(&self, _request: GetSinkRequest) -> RpcResult<GetSinkResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_mempool_entry_call(&self, request: GetMempoolEntryRequest) -> RpcResult<GetMempoolEntryResponse> {
+        let mempool = self.mempool.lock().unwrap();
+        let transaction = mempool
+            .iter()
+            .find(|transaction| transaction.id() == request.transaction_id)
+            .ok_or(RpcError::TransactionNotFound(request.transaction_id))?;
+        Ok(GetMempoolEntryResponse::new(RpcMempoolEntry::new(0, transaction.into(), false)))
+    }
+
+    async fn get_mempool_entries_call(&self, _request: GetMempoolEntriesRequest) -> RpcResult<GetMempoolEntriesResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_connected_peer_info_call(&self, _request: GetConnectedPeerInfoRequest) -> RpcResult<GetConnectedPeerInfoResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn add_peer_call(&self, _request: AddPeerRequest) -> RpcResult<AddPeerResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn submit_transaction_call(&self, request: SubmitTransactionRequest) -> RpcResult<SubmitTransactionResponse> {
+        let transaction: Transaction = (&request.transaction).try_into()?;
+        let transaction_id = transaction.id();
+
+        let (added, removed) = {
+            let mut utxos = self.utxos.lock().unwrap();
+            let mut removed = vec![];
+            for input in transaction.inputs.iter() {
+                let index = utxos.iter().position(|entry| entry.outpoint == input.previous_outpoint).ok_or_else(|| {
+                    RpcError::RejectedTransaction(transaction_id, format!("missing outpoint {}", input.previous_outpoint))
+                })?;
+                removed.push(utxos.remove(index));
+            }
+
+            let prefix = self.network_id.into();
+            let virtual_daa_score = self.virtual_daa_score();
+            let added = transaction
+                .outputs
+                .iter()
+                .enumerate()
+                .map(|(index, output)| RpcUtxosByAddressesEntry {
+                    address: extract_script_pub_key_address(&output.script_public_key, prefix).ok(),
+                    outpoint: RpcTransactionOutpoint::new(transaction_id, index as u32),
+                    utxo_entry: RpcUtxoEntry::new(output.value, output.script_public_key.clone(), virtual_daa_score, false),
+                })
+                .collect::<Vec<_>>();
+            utxos.extend(added.iter().cloned());
+            (added, removed)
+        };
+
+        self.transactions.lock().unwrap().push(transaction);
+        self.spent.lock().unwrap().insert(transaction_id, removed.clone());
+        self.notify_utxos_changed(added, removed)?;
+        Ok(SubmitTransactionResponse { transaction_id })
+    }
+
+    async fn get_block_call(&self, request: GetBlockRequest) -> RpcResult<GetBlockResponse> {
+        let mut block = self
+            .blocks
+            .lock()
+            .unwrap()
+            .get(&request.hash)
+            .cloned()
+            .ok_or(RpcError::General(format!("block {} not found", request.hash)))?;
+        if !request.include_transactions {
+            block.transactions.clear();
+        }
+        Ok(GetBlockResponse { block })
+    }
+
+    async fn get_subnetwork_call(&self, _request: GetSubnetworkRequest) -> RpcResult<GetSubnetworkResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_virtual_chain_from_block_call(
+        &self,
+        request: GetVirtualChainFromBlockRequest,
+    ) -> RpcResult<GetVirtualChainFromBlockResponse> {
+        self.virtual_chain_requests.lock().unwrap().push(request.start_hash);
+        let chain = self.chain.lock().unwrap();
+        let start = if request.start_hash == MOCK_PRUNING_POINT_HASH {
+            0
+        } else {
+            chain
+                .iter()
+                .position(|block| block.accepting_block_hash == request.start_hash)
+                .ok_or(RpcError::General(format!("block {} not found", request.start_hash)))?
+                + 1
+        };
+        let added = chain[start..].iter().map(|block| block.accepting_block_hash).collect();
+        let accepted = if request.include_accepted_transaction_ids { chain[start..].to_vec() } else { vec![] };
+        Ok(GetVirtualChainFromBlockResponse::new(vec![], added, accepted))
+    }
+
+    async fn get_blocks_call(&self, _request: GetBlocksRequest) -> RpcResult<GetBlocksResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_block_count_call(&self, _request: GetBlockCountRequest) -> RpcResult<GetBlockCountResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_block_dag_info_call(&self, _request: GetBlockDagInfoRequest) -> RpcResult<GetBlockDagInfoResponse> {
+        let chain = self.chain.lock().unwrap();
+        let sink = chain.last().map(|block| block.accepting_block_hash).unwrap_or(MOCK_PRUNING_POINT_HASH);
+        Ok(GetBlockDagInfoResponse::new(
+            self.network_id,
+            self.blocks.lock().unwrap().len() as u64,
+            0,
+            vec![sink],
+            0.0,
+            0,
+            vec![sink],
+            MOCK_PRUNING_POINT_HASH,
+            self.virtual_daa_score(),
+            sink,
+        ))
+    }
+
+    async fn resolve_finality_conflict_call(
+        &self,
+        _request: ResolveFinalityConflictRequest,
+    ) -> RpcResult<ResolveFinalityConflictResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn shutdown_call(&self, _request: ShutdownRequest) -> RpcResult<ShutdownResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_headers_call(&self, _request: GetHeadersRequest) -> RpcResult<GetHeadersResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_balance_by_address_call(&self, _request: GetBalanceByAddressRequest) -> RpcResult<GetBalanceByAddressResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_balances_by_addresses_call(
+        &self,
+        _request: GetBalancesByAddressesRequest,
+    ) -> RpcResult<GetBalancesByAddressesResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_utxos_by_addresses_call(&self, request: GetUtxosByAddressesRequest) -> RpcResult<GetUtxosByAddressesResponse> {
+        let entries = request.addresses.iter().flat_map(|address| self.utxos(address)).collect();
+        Ok(GetUtxosByAddressesResponse { entries })
+    }
+
+    async fn get_sink_blue_score_call(&self, _request: GetSinkBlueScoreRequest) -> RpcResult<GetSinkBlueScoreResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn ban_call(&self, _request: BanRequest) -> RpcResult<BanResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn unban_call(&self, _request: UnbanRequest) -> RpcResult<UnbanResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn estimate_network_hashes_per_second_call(
+        &self,
+        _request: EstimateNetworkHashesPerSecondRequest,
+    ) -> RpcResult<EstimateNetworkHashesPerSecondResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_mempool_entries_by_addresses_call(
+        &self,
+        request: GetMempoolEntriesByAddressesRequest,
+    ) -> RpcResult<GetMempoolEntriesByAddressesResponse> {
+        let mempool = self.mempool.lock().unwrap();
+        let entries = request
+            .addresses
+            .into_iter()
+            .map(|address| {
+                let script_public_key = pay_to_address_script(&address);
+                let receiving = mempool
+                    .iter()
+                    .filter(|transaction| transaction.outputs.iter().any(|output| output.script_public_key == script_public_key))
+                    .map(|transaction| RpcMempoolEntry::new(0, transaction.into(), false))
+                    .collect();
+                RpcMempoolEntryByAddress::new(address, vec![], receiving)
+            })
+            .collect();
+        Ok(GetMempoolEntriesByAddressesResponse::new(entries))
+    }
+
+    async fn get_coin_supply_call(&self, _request: GetCoinSupplyRequest) -> RpcResult<GetCoinSupplyResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_daa_score_timestamp_estimate_call(
+        &self,
+        request: GetDaaScoreTimestampEstimateRequest,
+    ) -> RpcResult<GetDaaScoreTimestampEstimateResponse> {
+        if !self.daa_score_timestamp_estimate.load(Ordering::SeqCst) {
+            return Err(RpcError::NotImplemented);
+        }
+        let timestamps =
+            request.daa_scores.into_iter().map(|daa_score| MOCK_DAA_SCORE_UNIXTIME_MSEC + daa_score * 1000).collect::<Vec<_>>();
+        Ok(GetDaaScoreTimestampEstimateResponse::new(timestamps))
+    }
+
+    async fn validate_transaction_call(&self, _request: ValidateTransactionRequest) -> RpcResult<ValidateTransactionResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_utxos_by_addresses_page_call(
+        &self,
+        request: GetUtxosByAddressesPageRequest,
+    ) -> RpcResult<GetUtxosByAddressesPageResponse> {
+        let limit = match request.limit {
+            0 => MAX_UTXOS_BY_ADDRESSES_PAGE_SIZE,
+            limit => limit.min(MAX_UTXOS_BY_ADDRESSES_PAGE_SIZE),
+        } as usize;
+        let position = |address: &Address, outpoint: &RpcTransactionOutpoint| {
+            request.addresses.iter().position(|a| a == address).map(|index| (index, outpoint.transaction_id, outpoint.index))
+        };
+        let start_after = match request.start_after.as_ref() {
+            Some(cursor) => Some(
+                position(&cursor.address, &cursor.outpoint)
+                    .ok_or_else(|| RpcError::InvalidUtxosByAddressesCursor(cursor.address.clone()))?,
+            ),
+            None => None,
+        };
+
+        let mut entries = request
+            .addresses
+            .iter()
+            .flat_map(|address| self.utxos(address))
+            .map(|entry| (position(entry.address.as_ref().unwrap(), &entry.outpoint).unwrap(), entry))
+            .filter(|(position, _)| start_after.map_or(true, |start_after| *position > start_after))
+            .collect::<Vec<_>>();
+        entries.sort_by_key(|(position, _)| *position);
+        entries.truncate(limit);
+
+        let next = (entries.len() == limit)
+            .then(|| entries.last())
+            .flatten()
+            .map(|(_, entry)| RpcUtxosByAddressesCursor { address: entry.address.clone().unwrap(), outpoint: entry.outpoint });
+        Ok(GetUtxosByAddressesPageResponse { entries: entries.into_iter().map(|(_, entry)| entry).collect(), next })
+    }
+
+    async fn get_wrpc_server_status_call(&self, _request: GetWrpcServerStatusRequest) -> RpcResult<GetWrpcServerStatusResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    // Notification API
+
+    fn register_new_listener(&self, connection: ChannelConnection) -> ListenerId {
+        self.core_notifier.register_new_listener(connection, ListenerLifespan::Dynamic)
+    }
+
+    async fn unregister_listener(&self, id: ListenerId) -> RpcResult<()> {
+        self.core_notifier.unregister_listener(id)?;
+        Ok(())
+    }
+
+    async fn start_notify(&self, id: ListenerId, scope: Scope) -> RpcResult<()> {
+        self.core_notifier.try_start_notify(id, scope)?;
+        Ok(())
+    }
+
+    async fn stop_notify(&self, id: ListenerId, scope: Scope) -> RpcResult<()> {
+        self.core_notifier.try_stop_notify(id, scope)?;
+        Ok(())
+    }
+}
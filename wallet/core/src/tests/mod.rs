@@ -2,9 +2,8 @@
 //! Utilities and helpers for unit and integration testing.
 //!
 
-#[cfg(test)]
-mod rpc_core_mock;
-pub use rpc_core_mock::*;
+mod mock_rpc_client;
+pub use mock_rpc_client::*;
 
 mod keys;
 pub use keys::*;
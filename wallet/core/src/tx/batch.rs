@@ -0,0 +1,202 @@
+//!
+//! [`PaymentBatch`] - multi-recipient payments (such as donation
+//! distributions) partitioned into as few transactions as the
+//! transaction mass limits allow.
+//!
+
+use crate::imports::*;
+use crate::result::Result;
+use crate::tx::{MassCalculator, PaymentOutput, PaymentOutputs, MAXIMUM_STANDARD_TRANSACTION_MASS};
+use crate::utxo::NetworkParams;
+use kaspa_consensus_core::tx::TransactionOutput;
+use kaspa_txscript::pay_to_address_script;
+
+/// Maximum number of payments accepted by a [`PaymentBatch`].
+pub const MAXIMUM_PAYMENT_BATCH_OUTPUTS: usize = 10_000;
+
+/// Mass available to the batch outputs of a single transaction.
+/// The remainder of the maximum standard transaction mass is
+/// reserved for the transaction inputs and the change output.
+pub const PAYMENT_BATCH_OUTPUT_MASS_LIMIT: u64 = MAXIMUM_STANDARD_TRANSACTION_MASS * 3 / 4;
+
+/// A validated set of payments to multiple recipients. Payments to the
+/// same address are aggregated into a single output. The batch is
+/// split into partitions, each fitting into a single transaction
+/// (see [`PaymentBatch::partitions()`]).
+#[derive(Clone, Debug)]
+pub struct PaymentBatch {
+    network_id: NetworkId,
+    outputs: Vec<PaymentOutput>,
+}
+
+impl PaymentBatch {
+    pub fn try_new(outputs: Vec<PaymentOutput>, network_id: &NetworkId) -> Result<Self> {
+        if outputs.is_empty() {
+            return Err(Error::InvalidPaymentBatch("the batch contains no payments".to_string()));
+        }
+        if outputs.len() > MAXIMUM_PAYMENT_BATCH_OUTPUTS {
+            return Err(Error::InvalidPaymentBatch(format!(
+                "the batch contains {} payments, the maximum is {MAXIMUM_PAYMENT_BATCH_OUTPUTS}",
+                outputs.len()
+            )));
+        }
+
+        let calc = Self::mass_calculator(network_id);
+        let network_type = NetworkType::from(*network_id);
+        let mut aggregated = Vec::<PaymentOutput>::with_capacity(outputs.len());
+        let mut positions = HashMap::<Address, usize>::new();
+        for (index, output) in outputs.into_iter().enumerate() {
+            if NetworkType::try_from(output.address.prefix)? != network_type {
+                return Err(Error::InvalidPaymentBatch(format!(
+                    "payment #{}: address {} does not match network {network_id}",
+                    index + 1,
+                    output.address
+                )));
+            }
+            if output.amount == 0 {
+                return Err(Error::InvalidPaymentBatch(format!("payment #{}: amount must not be zero", index + 1)));
+            }
+
+            match positions.get(&output.address) {
+                Some(position) => {
+                    let existing = &mut aggregated[*position];
+                    existing.amount = existing
+                        .amount
+                        .checked_add(output.amount)
+                        .ok_or_else(|| Error::InvalidPaymentBatch(format!("payment #{}: aggregate amount overflow", index + 1)))?;
+                }
+                None => {
+                    positions.insert(output.address.clone(), aggregated.len());
+                    aggregated.push(output);
+                }
+            }
+        }
+
+        // outputs are validated after aggregation as
+        // aggregated amounts have lower storage mass
+        for output in aggregated.iter() {
+            let (compute_mass, storage_mass) = Self::output_mass(&calc, output);
+            if calc.combine_mass(calc.blank_transaction_mass() + compute_mass, storage_mass) > PAYMENT_BATCH_OUTPUT_MASS_LIMIT {
+                return Err(Error::InvalidPaymentBatch(format!(
+                    "payment to {} is too small ({} sompi) and exceeds the storage mass limit",
+                    output.address, output.amount
+                )));
+            }
+        }
+
+        Ok(Self { network_id: *network_id, outputs: aggregated })
+    }
+
+    pub fn network_id(&self) -> NetworkId {
+        self.network_id
+    }
+
+    /// Aggregated batch payments.
+    pub fn outputs(&self) -> &[PaymentOutput] {
+        &self.outputs
+    }
+
+    pub fn len(&self) -> usize {
+        self.outputs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.outputs.is_empty()
+    }
+
+    /// Total amount of all batch payments.
+    pub fn amount(&self) -> u64 {
+        self.outputs.iter().map(|output| output.amount).sum()
+    }
+
+    /// Split the batch payments (preserving their order) into sets
+    /// of outputs whose combined compute and storage mass fits within
+    /// [`PAYMENT_BATCH_OUTPUT_MASS_LIMIT`] of a single transaction.
+    pub fn partitions(&self) -> Vec<PaymentOutputs> {
+        let calc = Self::mass_calculator(&self.network_id);
+        let blank_transaction_mass = calc.blank_transaction_mass();
+
+        let mut partitions = vec![];
+        let mut outputs = vec![];
+        let mut compute_mass = blank_transaction_mass;
+        let mut storage_mass = 0;
+        for output in self.outputs.iter() {
+            let (output_compute_mass, output_storage_mass) = Self::output_mass(&calc, output);
+            if !outputs.is_empty()
+                && calc.combine_mass(compute_mass + output_compute_mass, storage_mass + output_storage_mass)
+                    > PAYMENT_BATCH_OUTPUT_MASS_LIMIT
+            {
+                partitions.push(PaymentOutputs { outputs: std::mem::take(&mut outputs) });
+                compute_mass = blank_transaction_mass;
+                storage_mass = 0;
+            }
+            compute_mass += output_compute_mass;
+            storage_mass += output_storage_mass;
+            outputs.push(output.clone());
+        }
+        if !outputs.is_empty() {
+            partitions.push(PaymentOutputs { outputs });
+        }
+
+        partitions
+    }
+
+    fn mass_calculator(network_id: &NetworkId) -> MassCalculator {
+        MassCalculator::new(&(*network_id).into(), &NetworkParams::from(*network_id))
+    }
+
+    /// Compute mass and storage mass (output harmonic) of a payment output.
+    fn output_mass(calc: &MassCalculator, output: &PaymentOutput) -> (u64, u64) {
+        let transaction_output = TransactionOutput::new(output.amount, pay_to_address_script(&output.address));
+        (calc.calc_mass_for_output(&transaction_output), calc.calc_storage_mass_output_harmonic_single(output.amount))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_addresses::Version;
+    use kaspa_consensus_core::constants::SOMPI_PER_KASPA;
+
+    fn address(n: u32) -> Address {
+        let mut payload = [0u8; 32];
+        payload[..4].copy_from_slice(&n.to_le_bytes());
+        Address::new(Prefix::Testnet, Version::PubKey, &payload)
+    }
+
+    #[test]
+    fn test_payment_batch() {
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 11);
+
+        let outputs = (0..1000).map(|n| PaymentOutput::new(address(n % 900), 100 * SOMPI_PER_KASPA)).collect::<Vec<_>>();
+        let batch = PaymentBatch::try_new(outputs, &network_id).unwrap();
+        // duplicate addresses are aggregated
+        assert_eq!(batch.len(), 900);
+        assert_eq!(batch.outputs()[0].amount, 200 * SOMPI_PER_KASPA);
+        assert_eq!(batch.amount(), 1000 * 100 * SOMPI_PER_KASPA);
+
+        let partitions = batch.partitions();
+        assert!(partitions.len() > 1);
+        assert_eq!(partitions.iter().map(|partition| partition.outputs.len()).sum::<usize>(), batch.len());
+        assert_eq!(partitions.iter().map(|partition| partition.amount()).sum::<u64>(), batch.amount());
+        assert_eq!(partitions[0].outputs[0].address, address(0));
+
+        let calc = PaymentBatch::mass_calculator(&network_id);
+        for partition in partitions.iter() {
+            let (compute_mass, storage_mass) =
+                partition.iter().map(|output| PaymentBatch::output_mass(&calc, output)).fold((0, 0), |a, b| (a.0 + b.0, a.1 + b.1));
+            assert!(calc.combine_mass(calc.blank_transaction_mass() + compute_mass, storage_mass) <= PAYMENT_BATCH_OUTPUT_MASS_LIMIT);
+        }
+
+        // small amounts are limited by the storage mass
+        let outputs = (0..20).map(|n| PaymentOutput::new(address(n), SOMPI_PER_KASPA)).collect::<Vec<_>>();
+        let partitions = PaymentBatch::try_new(outputs, &network_id).unwrap().partitions();
+        assert!(partitions.len() > 1);
+
+        assert!(PaymentBatch::try_new(vec![], &network_id).is_err());
+        assert!(PaymentBatch::try_new(vec![PaymentOutput::new(address(0), 0)], &network_id).is_err());
+        assert!(PaymentBatch::try_new(vec![PaymentOutput::new(address(0), 1000)], &network_id).is_err());
+        let mainnet_address = Address::new(Prefix::Mainnet, Version::PubKey, &[0u8; 32]);
+        assert!(PaymentBatch::try_new(vec![PaymentOutput::new(mainnet_address, SOMPI_PER_KASPA)], &network_id).is_err());
+    }
+}
@@ -10,6 +10,11 @@ use kaspa_consensus_core::{
 };
 
 /// find Consensus parameters for given Address
+///
+/// NOTE: the default params of the address network are returned; networks
+/// running with custom params (such as devnets) should supply their params
+/// explicitly via [`UtxoProcessor::set_consensus_params()`](crate::utxo::UtxoProcessor::set_consensus_params)
+/// or [`GeneratorSettings::with_consensus_params()`](crate::tx::GeneratorSettings::with_consensus_params).
 pub fn get_consensus_params_by_address(address: &Address) -> Params {
     match address.prefix {
         Prefix::Mainnet => MAINNET_PARAMS,
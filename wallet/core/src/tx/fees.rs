@@ -2,7 +2,9 @@
 //! Primitives for declaring transaction fees.
 //!
 
+use crate::error::Error;
 use crate::result::Result;
+use crate::utils::Amount;
 use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
 
@@ -19,6 +21,11 @@ use serde::{Deserialize, Serialize};
 /// NOTE: If priority fees are `0`, fee variants can be used control
 /// who pays the `network` fees.
 ///
+/// Priority fees can be expressed as an absolute value (in SOMPI) or as
+/// a rate (in SOMPI per gram of transaction mass) using the `*Rate` variants.
+/// Rates are converted to absolute values by the transaction generator using
+/// the computed mass of the final transaction (see [`Fees::priority_fee`]).
+///
 /// NOTE: `ReceiverPays` variants can fail during the aggregation process
 /// if there are not enough funds to cover the final transaction.
 /// There are 2 solutions to this problem:
@@ -27,6 +34,7 @@ use serde::{Deserialize, Serialize};
 /// 2. Check balance and ensure that there is a sufficient amount of funds.
 ///
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(try_from = "FeesData")]
 pub enum Fees {
     /// Fee management disabled (sweep transactions, pays all fees)
     None,
@@ -34,6 +42,12 @@ pub enum Fees {
     SenderPays(u64),
     /// all fees are subtracted from the final transaction value
     ReceiverPays(u64),
+    /// all fees are added to the final transaction value,
+    /// priority fees are expressed in SOMPI per gram
+    SenderPaysRate(f64),
+    /// all fees are subtracted from the final transaction value,
+    /// priority fees are expressed in SOMPI per gram
+    ReceiverPaysRate(f64),
 }
 
 impl Fees {
@@ -42,19 +56,84 @@ impl Fees {
     }
 
     pub fn sender_pays(&self) -> bool {
-        matches!(self, Fees::SenderPays(_))
+        matches!(self, Fees::SenderPays(_) | Fees::SenderPaysRate(_))
     }
 
     pub fn receiver_pays(&self) -> bool {
-        matches!(self, Fees::ReceiverPays(_))
+        matches!(self, Fees::ReceiverPays(_) | Fees::ReceiverPaysRate(_))
+    }
+
+    pub fn is_rate(&self) -> bool {
+        matches!(self, Fees::SenderPaysRate(_) | Fees::ReceiverPaysRate(_))
     }
 
+    /// Absolute priority fees added to the final transaction value.
+    /// Returns `0` for rate-based fees as they depend on the transaction mass.
     pub fn additional(&self) -> u64 {
         match self {
             Fees::SenderPays(fee) => *fee,
             _ => 0,
         }
     }
+
+    /// Absolute priority fee for a transaction of the given `mass`
+    /// (rates are rounded up to the next SOMPI). Fails if the resulting
+    /// fee does not fit into `u64`.
+    pub fn priority_fee(&self, mass: u64) -> Result<u64> {
+        match self {
+            Fees::None => Ok(0),
+            Fees::SenderPays(fee) | Fees::ReceiverPays(fee) => Ok(*fee),
+            Fees::SenderPaysRate(rate) | Fees::ReceiverPaysRate(rate) => {
+                let fee = (rate * mass as f64).ceil();
+                // `as` saturates out of range values, reject them instead
+                if (0.0..u64::MAX as f64).contains(&fee) {
+                    Ok(fee as u64)
+                } else {
+                    Err(Error::GeneratorFeeOverflow)
+                }
+            }
+        }
+    }
+
+    /// Creates rate-based fees from a SOMPI per gram `rate`. Similarly to
+    /// `Fees::from(i64)`, a negative rate results in fees that are included
+    /// in the transaction value.
+    pub fn try_from_rate(rate: f64) -> Result<Self> {
+        if !rate.is_finite() {
+            Err(Error::InvalidArgument(format!("invalid fee rate: {rate}")))
+        } else if rate < 0.0 {
+            Ok(Fees::ReceiverPaysRate(-rate))
+        } else {
+            Ok(Fees::SenderPaysRate(rate))
+        }
+    }
+}
+
+/// Serialized representation of [`Fees`], validating
+/// the fee rates when deserializing.
+#[derive(Deserialize)]
+enum FeesData {
+    None,
+    SenderPays(u64),
+    ReceiverPays(u64),
+    SenderPaysRate(f64),
+    ReceiverPaysRate(f64),
+}
+
+impl TryFrom<FeesData> for Fees {
+    type Error = crate::error::Error;
+    fn try_from(fees: FeesData) -> Result<Self> {
+        match fees {
+            FeesData::None => Ok(Fees::None),
+            FeesData::SenderPays(fee) => Ok(Fees::SenderPays(fee)),
+            FeesData::ReceiverPays(fee) => Ok(Fees::ReceiverPays(fee)),
+            FeesData::SenderPaysRate(rate) | FeesData::ReceiverPaysRate(rate) if !rate.is_finite() || rate < 0.0 => {
+                Err(Error::InvalidArgument(format!("invalid fee rate: {rate}")))
+            }
+            FeesData::SenderPaysRate(rate) => Ok(Fees::SenderPaysRate(rate)),
+            FeesData::ReceiverPaysRate(rate) => Ok(Fees::ReceiverPaysRate(rate)),
+        }
+    }
 }
 
 /// This trait converts supplied positive `i64` value as `Exclude` fees
@@ -76,14 +155,22 @@ impl From<u64> for Fees {
     }
 }
 
+/// Parses a KAS amount with an optional unit (i.e. `"0.1"`, `"0.1 KAS"` or
+/// `"10000 sompi"`) or a SOMPI per gram rate suffixed with `/g` (i.e. `"1.5/g"`).
+/// Negative values result in fees that are included in the transaction value.
 impl TryFrom<&str> for Fees {
     type Error = crate::error::Error;
     fn try_from(fee: &str) -> Result<Self> {
+        let fee = fee.trim();
         if fee.is_empty() {
             Ok(Fees::None)
+        } else if let Some(rate) = fee.strip_suffix("/g") {
+            let rate = rate.trim().parse::<f64>().map_err(|_| Error::InvalidArgument(format!("invalid fee rate: `{fee}`")))?;
+            Self::try_from_rate(rate)
+        } else if let Some(fee) = fee.strip_prefix('-') {
+            Ok(Fees::ReceiverPays(fee.trim().parse::<Amount>()?.sompi()))
         } else {
-            let fee = crate::utils::try_kaspa_str_to_sompi_i64(fee)?.unwrap_or(0);
-            Ok(Fees::from(fee))
+            Ok(Fees::SenderPays(fee.parse::<Amount>()?.sompi()))
         }
     }
 }
@@ -94,3 +181,36 @@ impl TryFrom<String> for Fees {
         Self::try_from(fee.as_str())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fees_rate() -> Result<()> {
+        assert_eq!(Fees::SenderPays(1_000).priority_fee(2_000)?, 1_000);
+        assert_eq!(Fees::SenderPaysRate(1.5).priority_fee(2_001)?, 3_002);
+        assert_eq!(Fees::ReceiverPaysRate(0.0).priority_fee(2_000)?, 0);
+        assert_eq!(Fees::None.priority_fee(2_000)?, 0);
+        assert!(Fees::SenderPaysRate(1e300).priority_fee(2_000).is_err());
+        assert_eq!(Fees::SenderPaysRate(1.5).additional(), 0);
+
+        assert!(matches!(Fees::try_from("1.5/g")?, Fees::SenderPaysRate(rate) if rate == 1.5));
+        assert!(matches!(Fees::try_from("-2 /g")?, Fees::ReceiverPaysRate(rate) if rate == 2.0));
+        assert!(matches!(Fees::try_from("0.1")?, Fees::SenderPays(10_000_000)));
+        assert!(matches!(Fees::try_from("-0.1")?, Fees::ReceiverPays(10_000_000)));
+        assert!(matches!(Fees::try_from("- 2500 sompi")?, Fees::ReceiverPays(2_500)));
+        assert!(Fees::try_from("--0.1").is_err());
+        assert!(Fees::try_from("abc/g").is_err());
+        assert!(Fees::try_from_rate(f64::NAN).is_err());
+
+        let fees: Fees = serde_json::from_str(r#"{"SenderPaysRate":1.5}"#).unwrap();
+        assert!(matches!(fees, Fees::SenderPaysRate(rate) if rate == 1.5));
+        assert!(matches!(serde_json::from_str::<Fees>(r#"{"SenderPays":1000}"#).unwrap(), Fees::SenderPays(1_000)));
+        assert!(matches!(serde_json::from_str::<Fees>(r#""None""#).unwrap(), Fees::None));
+        assert!(serde_json::from_str::<Fees>(r#"{"ReceiverPaysRate":-1.5}"#).is_err());
+        assert!(serde_json::from_str::<Fees>(r#"{"SenderPaysRate":1e400}"#).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+//!
+//! [`ChangePolicy`] controlling the handling of the final transaction
+//! change that can not be issued as a standard change output.
+//!
+
+use crate::imports::*;
+use std::str::FromStr;
+
+/// Policy applied by the [`Generator`](crate::tx::Generator) when the change of
+/// the final transaction is dust (or is below the additional fees incurred by the
+/// storage mass of the change output) and as such can not be returned to the
+/// change address.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "kebab-case")]
+#[serde(tag = "type", content = "threshold")]
+pub enum ChangePolicy {
+    /// Add the change to the transaction fees.
+    #[default]
+    FoldToFee,
+    /// Add the change to the first payment output. The
+    /// change is folded into fees in sweep transactions.
+    AddToRecipient,
+    /// Consume additional UTXOs while the change is below the threshold (in SOMPI),
+    /// so that it can be issued as a change output. If the available UTXOs are
+    /// depleted before the threshold is reached, the change is folded into fees.
+    AccumulateUntilThreshold(u64),
+}
+
+impl std::fmt::Display for ChangePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangePolicy::FoldToFee => write!(f, "fold-to-fee"),
+            ChangePolicy::AddToRecipient => write!(f, "add-to-recipient"),
+            ChangePolicy::AccumulateUntilThreshold(threshold) => write!(f, "accumulate-until-threshold:{threshold}"),
+        }
+    }
+}
+
+impl FromStr for ChangePolicy {
+    type Err = Error;
+
+    /// Parses `fold-to-fee`, `add-to-recipient` or `accumulate-until-threshold:<sompi>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            None if s == "fold-to-fee" => Ok(ChangePolicy::FoldToFee),
+            None if s == "add-to-recipient" => Ok(ChangePolicy::AddToRecipient),
+            Some(("accumulate-until-threshold", threshold)) => threshold
+                .trim()
+                .parse::<u64>()
+                .map(ChangePolicy::AccumulateUntilThreshold)
+                .map_err(|_| Error::custom(format!("invalid change policy threshold: '{threshold}'"))),
+            _ => Err(Error::custom(format!(
+                "invalid change policy '{s}' (expected 'fold-to-fee', 'add-to-recipient' or 'accumulate-until-threshold:<sompi>')"
+            ))),
+        }
+    }
+}
+
+/// Outcome of the final transaction change processing reported
+/// in the [`GeneratorSummary`](crate::tx::GeneratorSummary).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "kebab-case")]
+#[serde(tag = "type", content = "amount")]
+pub enum ChangeDisposition {
+    /// Change issued as a change output
+    Output(u64),
+    /// Change added to the transaction fees
+    FoldedToFee(u64),
+    /// Change added to the first payment output
+    AddedToRecipient(u64),
+}
+
+impl ChangeDisposition {
+    pub fn amount(&self) -> u64 {
+        match self {
+            ChangeDisposition::Output(amount)
+            | ChangeDisposition::FoldedToFee(amount)
+            | ChangeDisposition::AddedToRecipient(amount) => *amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_change_policy_parse() {
+        for policy in [ChangePolicy::FoldToFee, ChangePolicy::AddToRecipient, ChangePolicy::AccumulateUntilThreshold(100_000)] {
+            assert_eq!(policy.to_string().parse::<ChangePolicy>().unwrap(), policy);
+        }
+        assert!("accumulate-until-threshold:x".parse::<ChangePolicy>().is_err());
+        assert!("donate".parse::<ChangePolicy>().is_err());
+
+        let json = serde_json::to_value(ChangePolicy::AccumulateUntilThreshold(5)).unwrap();
+        assert_eq!(json, serde_json::json!({ "type": "accumulate-until-threshold", "threshold": 5 }));
+    }
+}
@@ -56,19 +56,22 @@
 //!   UTXO sets will result in a browser UI freezing.
 //!
 
+use crate::error::InsufficientFundsDetails;
 use crate::imports::*;
 use crate::result::Result;
 use crate::tx::{
-    mass::*, Fees, GeneratorSettings, GeneratorSummary, PaymentDestination, PendingTransaction, PendingTransactionIterator,
-    PendingTransactionStream,
+    mass::*, ChangeDisposition, ChangePolicy, Fees, GeneratorProfiler, GeneratorSettings, GeneratorStage, GeneratorSummary,
+    PaymentDestination, PendingTransaction, PendingTransactionIterator, PendingTransactionStream,
 };
-use crate::utxo::{NetworkParams, UtxoContext, UtxoEntryReference};
+use crate::utxo::{NetworkParams, UtxoContext, UtxoEntryId, UtxoEntryReference};
 use kaspa_consensus_client::UtxoEntry;
 use kaspa_consensus_core::constants::UNACCEPTED_DAA_SCORE;
+use kaspa_consensus_core::hashing::sighash_type::SigHashType;
 use kaspa_consensus_core::subnets::SUBNETWORK_ID_NATIVE;
 use kaspa_consensus_core::tx::{Transaction, TransactionInput, TransactionOutpoint, TransactionOutput};
 use kaspa_txscript::pay_to_address_script;
 use std::collections::VecDeque;
+use workflow_core::time::Instant;
 
 use super::SignerT;
 
@@ -89,9 +92,15 @@ struct Context {
     utxo_source_iterator: Box<dyn Iterator<Item = UtxoEntryReference> + Send + Sync + 'static>,
     /// total number of UTXOs consumed by the single generator instance
     aggregated_utxos: usize,
+    /// required UTXO entries (coin control) that have not been consumed yet;
+    /// the final transaction is not produced until all of them are consumed
+    required_utxo_entries: AHashSet<UtxoEntryId>,
     /// total fees of all transactions issued by
     /// the single generator instance
     aggregate_fees: u64,
+    /// total mass of all transactions issued by
+    /// the single generator instance
+    aggregate_mass: u64,
     /// number of generated transactions
     number_of_transactions: usize,
     /// current tree stage
@@ -103,6 +112,16 @@ struct Context {
     utxo_stash: VecDeque<UtxoEntryReference>,
     /// final transaction id
     final_transaction_id: Option<TransactionId>,
+    /// final transaction mass
+    final_transaction_mass: Option<u64>,
+    /// final transaction fees
+    final_transaction_fees: Option<u64>,
+    /// final transaction change output value
+    final_change_amount: Option<u64>,
+    /// amount transferred by the final send-all transaction
+    final_send_all_amount: Option<u64>,
+    /// handling of the final transaction change
+    final_change_disposition: Option<ChangeDisposition>,
     /// signifies that the generator is finished
     /// no more items will be produced in the
     /// iterator or a stream
@@ -205,6 +224,8 @@ struct Data {
     aggregate_input_value: u64,
     /// Optional change output value
     change_output_value: Option<u64>,
+    /// Handling of the change that is not issued as a change output
+    change_disposition: Option<ChangeDisposition>,
 }
 
 impl Data {
@@ -219,6 +240,7 @@ impl Data {
             transaction_fees: 0,
             aggregate_input_value: 0,
             change_output_value: None,
+            change_disposition: None,
         }
     }
 }
@@ -232,6 +254,16 @@ struct FinalTransaction {
     value_with_priority_fee: u64,
 }
 
+/// Helper struct for passing around the send-all destination
+struct SendAllTransaction {
+    /// Destination script public key
+    script_public_key: ScriptPublicKey,
+    /// Compute mass of the destination output
+    output_compute_mass: u64,
+    /// Priority fee deducted from the transferred amount
+    priority_fee: Fees,
+}
+
 /// Helper struct for obtaining properties related to
 /// transaction mass calculations.
 struct MassDisposition {
@@ -266,6 +298,10 @@ struct Inner {
     source_utxo_context: Option<UtxoContext>,
     // Destination Utxo Context (Used only during transfer transactions)
     destination_utxo_context: Option<UtxoContext>,
+    // Stage timings of this generator (populated only with the `generator-profile` feature)
+    profiler: GeneratorProfiler,
+    // Stage timings aggregated across generators by the source [`UtxoProcessor`]
+    aggregate_profiler: Option<Arc<GeneratorProfiler>>,
     // Event multiplexer
     multiplexer: Option<Multiplexer<Box<Events>>>,
     // typically a number of keys required to sign the transaction
@@ -282,6 +318,8 @@ struct Inner {
     // final transaction amount and fees
     // `None` is used for sweep transactions
     final_transaction: Option<FinalTransaction>,
+    // send-all destination (sweep transactions with the final output to the destination)
+    send_all_transaction: Option<SendAllTransaction>,
     // applies only to the final transaction
     final_transaction_priority_fee: Fees,
     // issued only in the final transaction
@@ -294,6 +332,10 @@ struct Inner {
     final_transaction_payload: Vec<u8>,
     // final transaction payload mass
     final_transaction_payload_mass: u64,
+    // handling of the final transaction change that can not be issued as an output
+    change_policy: ChangePolicy,
+    // sighash type used to sign the final transaction inputs
+    final_transaction_sighash_type: SigHashType,
     // execution context
     context: Mutex<Context>,
 }
@@ -318,6 +360,8 @@ impl std::fmt::Debug for Inner {
             .field("final_transaction_outputs_compute_mass", &self.final_transaction_outputs_compute_mass)
             .field("final_transaction_payload", &self.final_transaction_payload)
             .field("final_transaction_payload_mass", &self.final_transaction_payload_mass)
+            .field("change_policy", &self.change_policy)
+            .field("final_transaction_sighash_type", &self.final_transaction_sighash_type)
             // .field("context", &self.context)
             .finish()
     }
@@ -346,13 +390,34 @@ impl Generator {
             final_transaction_destination,
             final_transaction_payload,
             destination_utxo_context,
+            change_policy,
+            final_transaction_sighash_type,
+            consensus_params,
+            required_utxo_entries,
         } = settings;
 
         let network_type = NetworkType::from(network_id);
         let network_params = NetworkParams::from(network_id);
-        let mass_calculator = MassCalculator::new(&network_id.into(), &network_params);
+        let consensus_params = consensus_params.unwrap_or_else(|| network_id.into());
+        if consensus_params.net != network_id {
+            return Err(Error::InvalidNetworkType(network_id.to_string(), consensus_params.net.to_string()));
+        }
+        let mass_calculator = MassCalculator::new(&consensus_params, &network_params);
 
+        let mut send_all_transaction = None;
         let (final_transaction_outputs, final_transaction_amount) = match final_transaction_destination {
+            PaymentDestination::SendAll(address) => {
+                if NetworkType::try_from(address.prefix)? != network_type {
+                    return Err(Error::GeneratorPaymentOutputNetworkTypeMismatch);
+                }
+
+                let script_public_key = pay_to_address_script(&address);
+                let output_compute_mass = mass_calculator.calc_mass_for_output(&TransactionOutput::new(0, script_public_key.clone()));
+                let priority_fee = final_transaction_priority_fee.clone();
+                send_all_transaction = Some(SendAllTransaction { script_public_key, output_compute_mass, priority_fee });
+
+                (vec![], None)
+            }
             PaymentDestination::Change => {
                 if !final_transaction_priority_fee.is_none() {
                     return Err(Error::GeneratorFeesInSweepTransaction);
@@ -385,7 +450,7 @@ impl Generator {
             }
         };
 
-        if final_transaction_outputs.is_empty() && matches!(final_transaction_priority_fee, Fees::ReceiverPays(_)) {
+        if final_transaction_outputs.is_empty() && send_all_transaction.is_none() && final_transaction_priority_fee.receiver_pays() {
             return Err(Error::GeneratorIncludeFeesRequiresOneOutput);
         }
 
@@ -404,10 +469,13 @@ impl Generator {
             mass_calculator.calc_storage_mass_output_harmonic(&final_transaction_outputs).ok_or(Error::MassCalculationError)?;
 
         // reject transactions where the payload and outputs are more than 2/3rds of the maximum tx mass
-        let final_transaction = final_transaction_amount.map(|amount| FinalTransaction {
-            value_no_fees: amount,
-            value_with_priority_fee: amount + final_transaction_priority_fee.additional(),
-        });
+        let final_transaction = final_transaction_amount
+            .map(|amount: u64| {
+                let value_with_priority_fee =
+                    amount.checked_add(final_transaction_priority_fee.additional()).ok_or(Error::GeneratorFeeOverflow)?;
+                Ok::<_, Error>(FinalTransaction { value_no_fees: amount, value_with_priority_fee })
+            })
+            .transpose()?;
 
         let mass_sanity_check = standard_change_output_mass + final_transaction_outputs_compute_mass + final_transaction_payload_mass;
         if mass_sanity_check > MAXIMUM_STANDARD_TRANSACTION_MASS / 5 * 4 {
@@ -418,13 +486,22 @@ impl Generator {
             utxo_source_iterator: utxo_iterator,
             number_of_transactions: 0,
             aggregated_utxos: 0,
+            required_utxo_entries,
             aggregate_fees: 0,
+            aggregate_mass: 0,
             stage: Some(Box::default()),
             utxo_stash: VecDeque::default(),
             final_transaction_id: None,
+            final_transaction_mass: None,
+            final_transaction_fees: None,
+            final_change_amount: None,
+            final_send_all_amount: None,
+            final_change_disposition: None,
             is_done: false,
         });
 
+        let aggregate_profiler = utxo_context.as_ref().map(|utxo_context| utxo_context.processor().generator_profiler().clone());
+
         let inner = Inner {
             network_id,
             network_params,
@@ -440,13 +517,18 @@ impl Generator {
             standard_change_output_compute_mass: standard_change_output_mass,
             signature_mass_per_input,
             final_transaction,
+            send_all_transaction,
             final_transaction_priority_fee,
             final_transaction_outputs,
             final_transaction_outputs_harmonic,
             final_transaction_outputs_compute_mass,
             final_transaction_payload,
             final_transaction_payload_mass,
+            change_policy,
+            final_transaction_sighash_type,
             destination_utxo_context,
+            profiler: GeneratorProfiler::default(),
+            aggregate_profiler,
         };
 
         Ok(Self { inner: Arc::new(inner) })
@@ -467,6 +549,21 @@ impl Generator {
         &self.inner.network_params
     }
 
+    /// Address receiving the change of generated transactions.
+    pub fn change_address(&self) -> &Address {
+        &self.inner.change_address
+    }
+
+    /// Policy applied to the final transaction change that can not be issued as a change output.
+    pub fn change_policy(&self) -> ChangePolicy {
+        self.inner.change_policy
+    }
+
+    /// Sighash type used to sign the final transaction inputs.
+    pub fn final_transaction_sighash_type(&self) -> SigHashType {
+        self.inner.final_transaction_sighash_type
+    }
+
     /// The underlying [`UtxoContext`] (if available).
     pub fn source_utxo_context(&self) -> &Option<UtxoContext> {
         &self.inner.source_utxo_context
@@ -497,6 +594,11 @@ impl Generator {
         self.context().aggregate_fees
     }
 
+    /// The total mass of transactions produced during the transaction generation process.
+    pub fn aggregate_mass(&self) -> u64 {
+        self.context().aggregate_mass
+    }
+
     /// The total number of UTXOs consumed during the transaction generation process.
     pub fn aggregate_utxos(&self) -> usize {
         self.context().aggregated_utxos
@@ -531,11 +633,52 @@ impl Generator {
     /// 2. From the current stage
     /// 3. From the UTXO source iterator
     fn get_utxo_entry(&self, context: &mut Context, stage: &mut Stage) -> Option<UtxoEntryReference> {
-        context
+        let start = GeneratorProfiler::start();
+        let utxo_entry_reference = context
             .utxo_stash
             .pop_front()
             .or_else(|| stage.utxo_iterator.as_mut().and_then(|utxo_stage_iterator| utxo_stage_iterator.next()))
-            .or_else(|| context.utxo_source_iterator.next())
+            .or_else(|| context.utxo_source_iterator.next());
+        self.record_stage(GeneratorStage::UtxoSelection, start);
+        utxo_entry_reference
+    }
+
+    /// Record the time elapsed since `start` against the `stage` in
+    /// the generator profile and in the [`UtxoProcessor`] aggregate.
+    pub(crate) fn record_stage(&self, stage: GeneratorStage, start: Option<Instant>) {
+        if let Some(start) = start {
+            let usec = start.elapsed().as_micros() as u64;
+            self.inner.profiler.add(stage, usec);
+            if let Some(aggregate_profiler) = self.inner.aggregate_profiler.as_ref() {
+                aggregate_profiler.add(stage, usec);
+            }
+        }
+    }
+
+    fn record_transaction(&self) {
+        self.inner.profiler.record_transaction();
+        if let Some(aggregate_profiler) = self.inner.aggregate_profiler.as_ref() {
+            aggregate_profiler.record_transaction();
+        }
+    }
+
+    /// Create [`Error::InsufficientFunds`] populated with the balance
+    /// of the source [`UtxoContext`] (if available).
+    fn insufficient_funds(&self, required: u64, aggregate_input_value: u64, origin: &'static str) -> Error {
+        let details = match self.inner.source_utxo_context.as_ref().and_then(|utxo_context| utxo_context.balance()) {
+            Some(balance) => InsufficientFundsDetails {
+                required,
+                available: balance.mature,
+                pending: balance.pending,
+                excluded_utxo_count: balance.pending_utxo_count + balance.stasis_utxo_count,
+            },
+            None => InsufficientFundsDetails { required, available: aggregate_input_value, ..Default::default() },
+        };
+        Error::InsufficientFunds {
+            additional_needed: required.saturating_sub(aggregate_input_value),
+            origin,
+            details: Box::new(details),
+        }
     }
 
     /// Calculate relay transaction mass for the current transaction `data`
@@ -578,10 +721,11 @@ impl Generator {
                 // UTXO sources are depleted
                 if let Some(final_transaction) = &self.inner.final_transaction {
                     // reject transaction
-                    return Err(Error::InsufficientFunds {
-                        additional_needed: final_transaction.value_with_priority_fee.saturating_sub(stage.aggregate_input_value),
-                        origin: "accumulator",
-                    });
+                    return Err(self.insufficient_funds(
+                        final_transaction.value_with_priority_fee,
+                        stage.aggregate_input_value,
+                        "accumulator",
+                    ));
                 } else {
                     // finish sweep processing
                     return self.finish_relay_stage_processing(context, stage, data);
@@ -592,6 +736,11 @@ impl Generator {
                 return Ok((node, data));
             }
 
+            if !context.required_utxo_entries.is_empty() {
+                // keep consuming the required UTXO entries (coin control)
+                continue;
+            }
+
             if let Some(final_transaction) = &self.inner.final_transaction {
                 // try finish a stage or produce a final transaction with target value
                 // use basic condition checks to avoid unnecessary processing
@@ -633,7 +782,9 @@ impl Generator {
 
         let input = TransactionInput::new(utxo.outpoint.clone().into(), vec![], 0, self.inner.sig_op_count);
         let input_amount = utxo.amount();
+        let start = GeneratorProfiler::start();
         let input_compute_mass = calc.calc_mass_for_input(&input) + self.inner.signature_mass_per_input;
+        self.record_stage(GeneratorStage::MassCalculation, start);
 
         // NOTE: relay transactions have no storage mass
         // mass threshold reached, yield transaction
@@ -655,6 +806,9 @@ impl Generator {
             Some(DataKind::Node)
         } else {
             context.aggregated_utxos += 1;
+            if !context.required_utxo_entries.is_empty() {
+                context.required_utxo_entries.remove(&utxo_entry_reference.id());
+            }
             stage.aggregate_input_value += input_amount;
             data.aggregate_input_value += input_amount;
             data.aggregate_mass += input_compute_mass;
@@ -667,6 +821,12 @@ impl Generator {
 
     /// Check current state and either 1) initiate a new stage or 2) finish stage accumulation processing
     fn finish_relay_stage_processing(&self, context: &mut Context, stage: &mut Stage, mut data: Data) -> Result<(DataKind, Data)> {
+        if let Some(send_all_transaction) = self.inner.send_all_transaction.as_ref() {
+            if stage.number_of_transactions == 0 {
+                return self.finish_send_all_processing(context, stage, data, send_all_transaction);
+            }
+        }
+
         data.transaction_fees = self.calc_relay_transaction_compute_fees(&data);
         stage.aggregate_fees += data.transaction_fees;
         context.aggregate_fees += data.transaction_fees;
@@ -674,11 +834,12 @@ impl Generator {
         if context.aggregated_utxos < 2 {
             Ok((DataKind::NoOp, data))
         } else if stage.number_of_transactions > 0 {
+            // the edge transaction output is created to the change address
+            // from the aggregate input value less the transaction fees
             data.aggregate_mass += self.inner.standard_change_output_compute_mass;
-            data.change_output_value = Some(data.aggregate_input_value - data.transaction_fees);
             Ok((DataKind::Edge, data))
         } else if data.aggregate_input_value < data.transaction_fees {
-            Err(Error::InsufficientFunds { additional_needed: data.transaction_fees - data.aggregate_input_value, origin: "relay" })
+            Err(self.insufficient_funds(data.transaction_fees, data.aggregate_input_value, "relay"))
         } else {
             let change_output_value = data.aggregate_input_value - data.transaction_fees;
 
@@ -693,6 +854,53 @@ impl Generator {
         }
     }
 
+    /// Finish the send-all operation producing the final transaction that transfers
+    /// the aggregate input value, less the transaction fees, to the destination.
+    fn finish_send_all_processing(
+        &self,
+        context: &mut Context,
+        stage: &mut Stage,
+        mut data: Data,
+        send_all_transaction: &SendAllTransaction,
+    ) -> Result<(DataKind, Data)> {
+        let calc = &self.inner.mass_calculator;
+
+        let compute_mass = data.aggregate_mass + send_all_transaction.output_compute_mass + self.inner.final_transaction_payload_mass;
+        let mut transaction_fees = calc.calc_minimum_transaction_fee_from_mass(compute_mass);
+        let mut priority_fee = send_all_transaction.priority_fee.priority_fee(compute_mass)?;
+        // the storage mass depends on the output value that depends on the fees;
+        // the fees grow as the output value decreases, repeat until they settle
+        let transaction_mass = loop {
+            let fees = transaction_fees.checked_add(priority_fee).ok_or(Error::GeneratorFeeOverflow)?;
+            let output_value = data.aggregate_input_value.saturating_sub(fees);
+            if calc.is_dust(output_value) {
+                return Err(self.insufficient_funds(fees + calc.dust_threshold(), data.aggregate_input_value, "send-all"));
+            }
+
+            let storage_mass = self.calc_storage_mass(&data, calc.calc_storage_mass_output_harmonic_single(output_value));
+            let transaction_mass = calc.combine_mass(compute_mass, storage_mass);
+            let fees = calc.calc_minimum_transaction_fee_from_mass(transaction_mass);
+            let priority = send_all_transaction.priority_fee.priority_fee(transaction_mass)?;
+            if fees <= transaction_fees && priority <= priority_fee {
+                break transaction_mass;
+            }
+            transaction_fees = transaction_fees.max(fees);
+            priority_fee = priority_fee.max(priority);
+        };
+
+        if transaction_mass > MAXIMUM_STANDARD_TRANSACTION_MASS {
+            return Err(Error::GeneratorTransactionIsTooHeavy);
+        }
+
+        let transaction_fees = transaction_fees.checked_add(priority_fee).ok_or(Error::GeneratorFeeOverflow)?;
+        data.aggregate_mass = transaction_mass;
+        data.transaction_fees = transaction_fees;
+        stage.aggregate_fees += transaction_fees;
+        context.aggregate_fees += transaction_fees;
+
+        Ok((DataKind::Final, data))
+    }
+
     /// Calculate storage mass using inputs from `Data`
     /// and `output_harmonics` supplied by the user
     fn calc_storage_mass(&self, data: &Data, output_harmonics: u64) -> u64 {
@@ -713,18 +921,43 @@ impl Generator {
         let calc = &self.inner.mass_calculator;
 
         // calculate storage mass
-        let MassDisposition { transaction_mass, storage_mass, transaction_fees, absorb_change_to_fees } =
-            self.calculate_mass(stage, data, final_transaction.value_with_priority_fee)?;
+        let start = GeneratorProfiler::start();
+        let mut disposition = self.calculate_mass(stage, data, final_transaction.value_with_priority_fee)?;
+        let priority_fees = &self.inner.final_transaction_priority_fee;
+        let mut priority_fee = priority_fees.priority_fee(disposition.transaction_mass)?;
+        // rate-based priority fees depend on the transaction mass, which in turn depends
+        // on the change output value that depends on the priority fee; the fee grows as
+        // the change decreases, repeat until the mass and the priority fee settle
+        if priority_fees.is_rate() && priority_fees.sender_pays() {
+            loop {
+                let value = final_transaction.value_no_fees.checked_add(priority_fee).ok_or(Error::GeneratorFeeOverflow)?;
+                let next = self.calculate_mass(stage, data, value)?;
+                let next_priority_fee = priority_fees.priority_fee(next.transaction_mass)?;
+                let settled = next.transaction_mass == disposition.transaction_mass && next_priority_fee <= priority_fee;
+                disposition = next;
+                if settled {
+                    break;
+                }
+                priority_fee = priority_fee.max(next_priority_fee);
+            }
+        }
+        self.record_stage(GeneratorStage::MassCalculation, start);
+        let MassDisposition { transaction_mass, storage_mass, transaction_fees, absorb_change_to_fees } = disposition;
 
         let total_stage_value_needed = if self.inner.final_transaction_priority_fee.sender_pays() {
-            final_transaction.value_with_priority_fee + stage.aggregate_fees + transaction_fees
+            [priority_fee, stage.aggregate_fees, transaction_fees]
+                .into_iter()
+                .try_fold(final_transaction.value_no_fees, u64::checked_add)
+                .ok_or(Error::GeneratorFeeOverflow)?
         } else {
-            final_transaction.value_with_priority_fee
+            final_transaction.value_no_fees
         };
 
-        let reject = match self.inner.final_transaction_priority_fee {
-            Fees::SenderPays(_) => stage.aggregate_input_value < total_stage_value_needed,
-            Fees::ReceiverPays(_) => stage.aggregate_input_value + context.aggregate_fees < total_stage_value_needed,
+        let reject = match &self.inner.final_transaction_priority_fee {
+            Fees::SenderPays(_) | Fees::SenderPaysRate(_) => stage.aggregate_input_value < total_stage_value_needed,
+            Fees::ReceiverPays(_) | Fees::ReceiverPaysRate(_) => {
+                stage.aggregate_input_value + context.aggregate_fees < total_stage_value_needed
+            }
             Fees::None => unreachable!("Fees::None can not occur for final transaction"),
         };
 
@@ -751,15 +984,15 @@ impl Generator {
             }
             // ---
 
-            let (mut transaction_fees, change_output_value) = match self.inner.final_transaction_priority_fee {
-                Fees::SenderPays(priority_fees) => {
-                    let transaction_fees = transaction_fees + priority_fees;
+            let (mut transaction_fees, change_output_value) = match &self.inner.final_transaction_priority_fee {
+                Fees::SenderPays(_) | Fees::SenderPaysRate(_) => {
+                    let transaction_fees = transaction_fees.checked_add(priority_fee).ok_or(Error::GeneratorFeeOverflow)?;
                     let change_output_value = data.aggregate_input_value - final_transaction.value_no_fees - transaction_fees;
                     (transaction_fees, change_output_value)
                 }
                 // TODO - currently unreachable at the API level
-                Fees::ReceiverPays(priority_fees) => {
-                    let transaction_fees = transaction_fees + priority_fees;
+                Fees::ReceiverPays(_) | Fees::ReceiverPaysRate(_) => {
+                    let transaction_fees = transaction_fees.checked_add(priority_fee).ok_or(Error::GeneratorFeeOverflow)?;
                     let change_output_value = data.aggregate_input_value.saturating_sub(final_transaction.value_no_fees);
                     (transaction_fees, change_output_value)
                 }
@@ -769,7 +1002,21 @@ impl Generator {
             // checks output dust threshold in network params
             // if is_dust(&self.inner.network_params, change_output_value) {
             if absorb_change_to_fees || change_output_value == 0 {
-                transaction_fees += change_output_value;
+                if let ChangePolicy::AccumulateUntilThreshold(threshold) = self.inner.change_policy {
+                    // consume additional UTXOs in an attempt to raise the change to an issuable output
+                    if change_output_value > 0 && change_output_value < threshold && self.has_utxo_entries(context, stage) {
+                        return Ok(None);
+                    }
+                }
+
+                let add_to_recipient = change_output_value > 0 && self.inner.change_policy == ChangePolicy::AddToRecipient;
+                let output_harmonic = if add_to_recipient {
+                    let mut outputs = self.inner.final_transaction_outputs.clone();
+                    outputs[0].value += change_output_value;
+                    calc.calc_storage_mass_output_harmonic(&outputs).ok_or(Error::MassCalculationError)?
+                } else {
+                    self.inner.final_transaction_outputs_harmonic
+                };
 
                 // as we might absorb an input as a part of the receiver
                 // pays fee reduction, we should update the mass to make
@@ -777,11 +1024,16 @@ impl Generator {
                 let compute_mass = data.aggregate_mass
                     + self.inner.final_transaction_outputs_compute_mass
                     + self.inner.final_transaction_payload_mass;
-                let storage_mass = self.calc_storage_mass(data, self.inner.final_transaction_outputs_harmonic);
+                let storage_mass = self.calc_storage_mass(data, output_harmonic);
 
                 data.aggregate_mass = calc.combine_mass(compute_mass, storage_mass);
 
-                transaction_fees += change_output_value;
+                if add_to_recipient {
+                    data.change_disposition = Some(ChangeDisposition::AddedToRecipient(change_output_value));
+                } else if change_output_value > 0 {
+                    transaction_fees += change_output_value;
+                    data.change_disposition = Some(ChangeDisposition::FoldedToFee(change_output_value));
+                }
                 data.transaction_fees = transaction_fees;
                 stage.aggregate_fees += transaction_fees;
                 context.aggregate_fees += transaction_fees;
@@ -943,12 +1195,15 @@ impl Generator {
                     addresses,
                     aggregate_input_value,
                     change_output_value,
+                    change_disposition,
                     aggregate_mass,
                     transaction_fees,
                     ..
                 } = data;
 
                 let change_output_value = change_output_value.unwrap_or(0);
+                let change_disposition =
+                    change_disposition.or((change_output_value > 0).then_some(ChangeDisposition::Output(change_output_value)));
 
                 let mut final_outputs = self.inner.final_transaction_outputs.clone();
                 // let mut final_outputs = context.final_transaction_outputs.clone();
@@ -962,19 +1217,27 @@ impl Generator {
                     }
                 }
 
+                if let Some(ChangeDisposition::AddedToRecipient(amount)) = change_disposition {
+                    let output = final_outputs.get_mut(0).expect("adding change to recipient requires one output");
+                    output.value += amount;
+                }
+
                 if change_output_value > 0 {
                     let output = TransactionOutput::new(change_output_value, pay_to_address_script(&self.inner.change_address));
                     final_outputs.push(output);
                 }
 
+                if let Some(send_all_transaction) = self.inner.send_all_transaction.as_ref() {
+                    let output_value = aggregate_input_value - transaction_fees;
+                    final_outputs.push(TransactionOutput::new(output_value, send_all_transaction.script_public_key.clone()));
+                    context.final_send_all_amount = Some(output_value);
+                }
+
                 let aggregate_output_value = final_outputs.iter().map(|output| output.value).sum::<u64>();
                 // TODO - validate that this is still correct
                 // `Fees::ReceiverPays` processing can result in outputs being larger than inputs
                 if aggregate_output_value > aggregate_input_value {
-                    return Err(Error::InsufficientFunds {
-                        additional_needed: aggregate_output_value - aggregate_input_value,
-                        origin: "final",
-                    });
+                    return Err(self.insufficient_funds(aggregate_output_value, aggregate_input_value, "final"));
                 }
 
                 let tx = Transaction::new(
@@ -988,14 +1251,20 @@ impl Generator {
                 );
 
                 context.final_transaction_id = Some(tx.id());
+                context.final_transaction_mass = Some(aggregate_mass);
+                context.final_transaction_fees = Some(transaction_fees);
+                context.final_change_amount = Some(change_output_value);
+                context.final_change_disposition = change_disposition;
                 context.number_of_transactions += 1;
+                context.aggregate_mass += aggregate_mass;
+                self.record_transaction();
 
                 Ok(Some(PendingTransaction::try_new(
                     self,
                     tx,
                     utxo_entry_references,
                     addresses.into_iter().collect(),
-                    self.final_transaction_value_no_fees(),
+                    self.final_transaction_value_no_fees().or(context.final_send_all_amount),
                     change_output_value,
                     aggregate_input_value,
                     aggregate_output_value,
@@ -1023,6 +1292,8 @@ impl Generator {
                 let output = TransactionOutput::new(output_value, script_public_key.clone());
                 let tx = Transaction::new(0, inputs, vec![output], 0, SUBNETWORK_ID_NATIVE, 0, vec![]);
                 context.number_of_transactions += 1;
+                context.aggregate_mass += aggregate_mass;
+                self.record_transaction();
 
                 let utxo_entry_reference =
                     Self::create_batch_utxo_entry_reference(tx.id(), output_value, script_public_key, &self.inner.change_address);
@@ -1061,7 +1332,7 @@ impl Generator {
         }
     }
 
-    fn create_batch_utxo_entry_reference(
+    pub(crate) fn create_batch_utxo_entry_reference(
         txid: TransactionId,
         amount: u64,
         script_public_key: ScriptPublicKey,
@@ -1088,9 +1359,16 @@ impl Generator {
             network_id: self.inner.network_id,
             aggregated_utxos: context.aggregated_utxos,
             aggregated_fees: context.aggregate_fees,
-            final_transaction_amount: self.final_transaction_value_no_fees(),
+            aggregated_mass: context.aggregate_mass,
+            final_transaction_amount: self.final_transaction_value_no_fees().or(context.final_send_all_amount),
             final_transaction_id: context.final_transaction_id,
+            final_transaction_mass: context.final_transaction_mass,
+            final_transaction_fees: context.final_transaction_fees,
+            final_change_amount: context.final_change_amount,
+            change_policy: self.inner.change_policy,
+            change_disposition: context.final_change_disposition,
             number_of_generated_transactions: context.number_of_transactions,
+            profile: GeneratorProfiler::is_enabled().then(|| self.inner.profiler.profile()),
         }
     }
 }
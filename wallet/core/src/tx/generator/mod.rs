@@ -3,18 +3,24 @@
 //! Kaspa transactions.
 //!
 
+pub mod change;
 #[allow(clippy::module_inception)]
 pub mod generator;
 pub mod iterator;
 pub mod pending;
+pub mod profile;
+pub mod progress;
 pub mod settings;
 pub mod signer;
 pub mod stream;
 pub mod summary;
 
+pub use change::*;
 pub use generator::*;
 pub use iterator::*;
 pub use pending::*;
+pub use profile::*;
+pub use progress::*;
 pub use settings::*;
 pub use signer::*;
 pub use stream::*;
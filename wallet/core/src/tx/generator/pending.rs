@@ -4,13 +4,52 @@
 //!
 
 use crate::imports::*;
+use crate::logging::log;
 use crate::result::Result;
 use crate::rpc::DynRpcApi;
-use crate::tx::{DataKind, Generator};
+use crate::tx::{sign_transaction_inputs, sign_transaction_inputs_async, DataKind, Generator, GeneratorProfiler, GeneratorStage};
 use crate::utxo::{UtxoContext, UtxoEntryId, UtxoEntryReference};
-use kaspa_consensus_core::sign::sign_with_multiple_v2;
+use kaspa_consensus_core::hashing::sighash_type::{SigHashType, SIG_HASH_ALL};
 use kaspa_consensus_core::tx::{SignableTransaction, Transaction, TransactionId};
 use kaspa_rpc_core::{RpcTransaction, RpcTransactionId};
+use kaspa_wrpc_client::capabilities::{is_not_supported_error, NodeFeature};
+
+/// Result of a dry-run submission of a [`PendingTransaction`]
+/// produced by [`PendingTransaction::try_validate()`].
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionValidation {
+    pub id: TransactionId,
+    /// Transaction mass calculated by the [`Generator`]
+    pub mass: u64,
+    /// Transaction compute mass calculated by the node
+    pub compute_mass: Option<u64>,
+    /// Transaction fees (as calculated by the node if the transaction was accepted)
+    pub fees: u64,
+    /// `false` if the transaction was not validated by the node because it
+    /// spends outputs of preceding (unsubmitted) transactions.
+    pub validated: bool,
+    /// Rejection reason reported by the node
+    pub rejection: Option<String>,
+}
+
+impl TransactionValidation {
+    /// Validation result for a transaction that could not be validated by the node.
+    pub fn unvalidated(transaction: &PendingTransaction) -> Self {
+        Self {
+            id: transaction.id(),
+            mass: transaction.inner.mass,
+            compute_mass: None,
+            fees: transaction.fees(),
+            validated: false,
+            rejection: None,
+        }
+    }
+
+    pub fn is_rejected(&self) -> bool {
+        self.rejection.is_some()
+    }
+}
 
 pub(crate) struct PendingTransactionInner {
     /// Generator that produced the transaction
@@ -21,6 +60,8 @@ pub(crate) struct PendingTransactionInner {
     pub(crate) id: TransactionId,
     /// Signable transaction (actual transaction that will be signed and sent)
     pub(crate) signable_tx: Mutex<SignableTransaction>,
+    /// Sighash types used to sign the transaction inputs (indexed by input)
+    pub(crate) sighash_types: Mutex<Vec<SigHashType>>,
     /// UTXO addresses used by this transaction
     pub(crate) addresses: Vec<Address>,
     /// Whether the transaction has been committed to the mempool via RPC
@@ -82,6 +123,8 @@ impl PendingTransaction {
         kind: DataKind,
     ) -> Result<Self> {
         let id = transaction.id();
+        let sighash_type = if kind.is_final() { generator.final_transaction_sighash_type() } else { SIG_HASH_ALL };
+        let sighash_types = Mutex::new(vec![sighash_type; transaction.inputs.len()]);
         let entries = utxo_entries.iter().map(|e| e.utxo.as_ref().into()).collect::<Vec<_>>();
         let signable_tx = Mutex::new(SignableTransaction::with_entries(transaction, entries));
         let utxo_entries = utxo_entries.into_iter().map(|entry| (entry.id(), entry)).collect::<AHashMap<_, _>>();
@@ -90,6 +133,7 @@ impl PendingTransaction {
                 generator: generator.clone(),
                 id,
                 signable_tx,
+                sighash_types,
                 utxo_entries,
                 addresses,
                 is_submitted: AtomicBool::new(false),
@@ -134,6 +178,16 @@ impl PendingTransaction {
         self.inner.fees
     }
 
+    /// Transaction mass calculated by the [`Generator`]
+    pub fn mass(&self) -> u64 {
+        self.inner.mass
+    }
+
+    /// Fee rate (in SOMPI per gram of mass) of the transaction
+    pub fn fee_rate(&self) -> f64 {
+        super::summary::fee_rate(self.inner.fees, self.inner.mass)
+    }
+
     pub fn aggregate_input_value(&self) -> u64 {
         self.inner.aggregate_input_value
     }
@@ -150,6 +204,26 @@ impl PendingTransaction {
         self.inner.change_output_value
     }
 
+    /// UTXO entry of the change output (if any), allowing
+    /// subsequent transactions to spend the change before
+    /// this transaction is accepted by the network.
+    pub fn change_utxo_entry(&self) -> Option<UtxoEntryReference> {
+        let change_value = self.change_value();
+        if change_value == 0 {
+            return None;
+        }
+
+        // change output is always the last transaction output
+        let signable_tx = self.inner.signable_tx.lock().unwrap();
+        let output = signable_tx.tx.outputs.last()?;
+        Some(Generator::create_batch_utxo_entry_reference(
+            self.id(),
+            change_value,
+            output.script_public_key.clone(),
+            self.inner.generator.change_address(),
+        ))
+    }
+
     pub fn is_final(&self) -> bool {
         self.inner.kind.is_final()
     }
@@ -184,6 +258,13 @@ impl PendingTransaction {
 
         let rpc_transaction: RpcTransaction = self.rpc_transaction();
 
+        let start = GeneratorProfiler::start();
+        let result = self.submit(rpc, rpc_transaction).await;
+        self.inner.generator.record_stage(GeneratorStage::Submission, start);
+        result
+    }
+
+    async fn submit(&self, rpc: &Arc<DynRpcApi>, rpc_transaction: RpcTransaction) -> Result<RpcTransactionId> {
         // if we are running under UtxoProcessor
         if let Some(utxo_context) = self.inner.generator.source_utxo_context() {
             // lock UtxoProcessor notification ingest
@@ -211,22 +292,103 @@ impl PendingTransaction {
         }
     }
 
+    /// Ask the node to validate the transaction against the mempool
+    /// acceptance rules without broadcasting it (dry-run submission).
+    /// This function does not affect the associated [`UtxoContext`].
+    /// Any error reported by the node is captured as the rejection reason.
+    /// Returns [`Error::NotSupportedByNode`] if the node can not validate transactions.
+    pub async fn try_validate(&self, rpc: &Arc<DynRpcApi>) -> Result<TransactionValidation> {
+        if let Some(utxo_context) = self.inner.generator.source_utxo_context() {
+            utxo_context.processor().check_supported(NodeFeature::ValidateTransaction)?;
+        }
+
+        let validation = match rpc.validate_transaction(self.rpc_transaction(), false).await {
+            Ok(response) => TransactionValidation {
+                id: self.id(),
+                mass: self.inner.mass,
+                compute_mass: Some(response.compute_mass),
+                fees: response.fee,
+                validated: true,
+                rejection: None,
+            },
+            Err(error) if is_not_supported_error(&error) => {
+                return Err(Error::NotSupportedByNode(NodeFeature::ValidateTransaction));
+            }
+            Err(error) => {
+                let rejection = match error {
+                    kaspa_rpc_core::RpcError::RejectedTransaction(_, reason) => reason,
+                    error => error.to_string(),
+                };
+                TransactionValidation { validated: true, rejection: Some(rejection), ..TransactionValidation::unvalidated(self) }
+            }
+        };
+
+        Ok(validation)
+    }
+
+    /// Returns `true` if the transaction spends outputs of any of the supplied transactions.
+    pub fn depends_on(&self, ids: &AHashSet<TransactionId>) -> bool {
+        self.inner.signable_tx.lock().unwrap().tx.inputs.iter().any(|input| ids.contains(&input.previous_outpoint.transaction_id))
+    }
+
     pub async fn log(&self) -> Result<()> {
-        log_info!("pending transaction: {:?}", self.rpc_transaction());
+        log!(Wallet, Info, "pending transaction: {:?}", self.rpc_transaction());
+        Ok(())
+    }
+
+    /// Sighash types used to sign the transaction inputs (indexed by input).
+    pub fn sighash_types(&self) -> Vec<SigHashType> {
+        self.inner.sighash_types.lock().unwrap().clone()
+    }
+
+    /// Set the sighash type used to sign the input at `input_index`.
+    /// Must be called before the transaction is signed.
+    pub fn set_sighash_type(&self, input_index: usize, sighash_type: SigHashType) -> Result<()> {
+        let mut sighash_types = self.inner.sighash_types.lock().unwrap();
+        let input_sighash_type = sighash_types
+            .get_mut(input_index)
+            .ok_or_else(|| Error::custom(format!("Invalid transaction input index {input_index}")))?;
+        *input_sighash_type = sighash_type;
         Ok(())
     }
 
     pub fn try_sign(&self) -> Result<()> {
         let signer = self.inner.generator.signer().as_ref().expect("no signer in tx generator");
-        let signed_tx = signer.try_sign(self.inner.signable_tx.lock()?.clone(), self.addresses())?;
-        *self.inner.signable_tx.lock().unwrap() = signed_tx;
+        let start = GeneratorProfiler::start();
+        let signed_tx = signer.try_sign(self.inner.signable_tx.lock()?.clone(), self.addresses(), &self.sighash_types());
+        self.inner.generator.record_stage(GeneratorStage::Signing, start);
+        *self.inner.signable_tx.lock().unwrap() = signed_tx?;
+        Ok(())
+    }
+
+    /// Async variant of [`PendingTransaction::try_sign()`] that does not block
+    /// the wasm32 executor while signing transactions with a large number of inputs.
+    pub async fn try_sign_async(&self) -> Result<()> {
+        let signer = self.inner.generator.signer().clone().expect("no signer in tx generator");
+        let mutable_tx = self.inner.signable_tx.lock()?.clone();
+        let start = GeneratorProfiler::start();
+        let signed_tx = signer.try_sign_async(mutable_tx, self.addresses(), &self.sighash_types()).await;
+        self.inner.generator.record_stage(GeneratorStage::Signing, start);
+        *self.inner.signable_tx.lock().unwrap() = signed_tx?;
         Ok(())
     }
 
     pub fn try_sign_with_keys(&self, privkeys: &[[u8; 32]]) -> Result<()> {
         let mutable_tx = self.inner.signable_tx.lock()?.clone();
-        let signed_tx = sign_with_multiple_v2(mutable_tx, privkeys).fully_signed()?;
-        *self.inner.signable_tx.lock().unwrap() = signed_tx;
+        let start = GeneratorProfiler::start();
+        let signed_tx = sign_transaction_inputs(mutable_tx, privkeys, &self.sighash_types()).fully_signed();
+        self.inner.generator.record_stage(GeneratorStage::Signing, start);
+        *self.inner.signable_tx.lock().unwrap() = signed_tx?;
+        Ok(())
+    }
+
+    /// Async variant of [`PendingTransaction::try_sign_with_keys()`].
+    pub async fn try_sign_with_keys_async(&self, privkeys: &[[u8; 32]]) -> Result<()> {
+        let mutable_tx = self.inner.signable_tx.lock()?.clone();
+        let start = GeneratorProfiler::start();
+        let signed_tx = sign_transaction_inputs_async(mutable_tx, privkeys, &self.sighash_types()).await.fully_signed();
+        self.inner.generator.record_stage(GeneratorStage::Signing, start);
+        *self.inner.signable_tx.lock().unwrap() = signed_tx?;
         Ok(())
     }
 }
@@ -0,0 +1,140 @@
+//!
+//! Generator pipeline profiling. When the `generator-profile` feature is
+//! enabled, the [`Generator`](crate::tx::Generator) measures the time spent
+//! in UTXO selection, mass calculation, signing and submission stages.
+//! Measurements are available per send in the [`GeneratorSummary`](crate::tx::GeneratorSummary)
+//! and in aggregate via the [`UtxoProcessor`](crate::utxo::UtxoProcessor) metrics.
+//!
+
+use crate::imports::*;
+use std::fmt;
+use workflow_core::time::Instant;
+
+/// Stages of the transaction generation pipeline tracked by the [`GeneratorProfiler`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneratorStage {
+    UtxoSelection = 0,
+    MassCalculation = 1,
+    Signing = 2,
+    Submission = 3,
+}
+
+const GENERATOR_STAGES: usize = 4;
+
+/// Time (in microseconds) spent in each stage of the transaction generation pipeline.
+#[derive(Default, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratorProfile {
+    /// Number of transactions generated
+    pub transactions: u64,
+    pub utxo_selection_usec: u64,
+    pub mass_calculation_usec: u64,
+    pub signing_usec: u64,
+    pub submission_usec: u64,
+}
+
+impl GeneratorProfile {
+    /// Total time (in microseconds) spent in all tracked stages.
+    pub fn total_usec(&self) -> u64 {
+        self.utxo_selection_usec + self.mass_calculation_usec + self.signing_usec + self.submission_usec
+    }
+}
+
+impl fmt::Display for GeneratorProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msec = |usec: u64| usec as f64 / 1000.0;
+        write!(
+            f,
+            "Transactions: {}  UTXO selection: {:.3} ms  Mass calculation: {:.3} ms  Signing: {:.3} ms  Submission: {:.3} ms",
+            self.transactions,
+            msec(self.utxo_selection_usec),
+            msec(self.mass_calculation_usec),
+            msec(self.signing_usec),
+            msec(self.submission_usec),
+        )
+    }
+}
+
+/// Accumulates stage timings of the transaction generation pipeline.
+/// Measurements are taken only if the `generator-profile` feature is
+/// enabled, otherwise [`GeneratorProfiler::start()`] yields `None`
+/// and recording becomes a no-op.
+#[derive(Default)]
+pub struct GeneratorProfiler {
+    transactions: AtomicU64,
+    stages: [AtomicU64; GENERATOR_STAGES],
+}
+
+impl GeneratorProfiler {
+    /// Returns `true` if the crate is built with the `generator-profile` feature.
+    pub const fn is_enabled() -> bool {
+        cfg!(feature = "generator-profile")
+    }
+
+    /// Start measuring a stage; the result should be passed to [`GeneratorProfiler::record()`].
+    #[inline(always)]
+    pub fn start() -> Option<Instant> {
+        Self::is_enabled().then(Instant::now)
+    }
+
+    /// Record the time elapsed since `start` against the `stage`.
+    #[inline(always)]
+    pub fn record(&self, stage: GeneratorStage, start: Option<Instant>) {
+        if let Some(start) = start {
+            self.add(stage, start.elapsed().as_micros() as u64);
+        }
+    }
+
+    pub fn add(&self, stage: GeneratorStage, usec: u64) {
+        self.stages[stage as usize].fetch_add(usec, Ordering::Relaxed);
+    }
+
+    pub fn record_transaction(&self) {
+        if Self::is_enabled() {
+            self.transactions.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Snapshot of the accumulated stage timings.
+    pub fn profile(&self) -> GeneratorProfile {
+        let stage = |stage: GeneratorStage| self.stages[stage as usize].load(Ordering::Relaxed);
+        GeneratorProfile {
+            transactions: self.transactions.load(Ordering::Relaxed),
+            utxo_selection_usec: stage(GeneratorStage::UtxoSelection),
+            mass_calculation_usec: stage(GeneratorStage::MassCalculation),
+            signing_usec: stage(GeneratorStage::Signing),
+            submission_usec: stage(GeneratorStage::Submission),
+        }
+    }
+
+    pub fn reset(&self) {
+        self.transactions.store(0, Ordering::Relaxed);
+        self.stages.iter().for_each(|stage| stage.store(0, Ordering::Relaxed));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generator_profiler() {
+        let profiler = GeneratorProfiler::default();
+        profiler.add(GeneratorStage::UtxoSelection, 10);
+        profiler.add(GeneratorStage::MassCalculation, 20);
+        profiler.add(GeneratorStage::Signing, 30);
+        profiler.add(GeneratorStage::Submission, 40);
+        profiler.add(GeneratorStage::Signing, 5);
+        profiler.record(GeneratorStage::Submission, None);
+
+        let profile = profiler.profile();
+        assert_eq!(profile.utxo_selection_usec, 10);
+        assert_eq!(profile.mass_calculation_usec, 20);
+        assert_eq!(profile.signing_usec, 35);
+        assert_eq!(profile.submission_usec, 40);
+        assert_eq!(profile.total_usec(), 105);
+
+        profiler.reset();
+        assert_eq!(profiler.profile(), GeneratorProfile::default());
+    }
+}
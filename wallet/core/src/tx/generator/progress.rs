@@ -0,0 +1,55 @@
+//!
+//! [`GeneratorProgress`] reporting the progress of [`Generator`]
+//! operations producing multiple (chained) transactions.
+//!
+
+use crate::imports::*;
+use crate::tx::{Generator, GeneratorSettings, PendingTransaction};
+use workflow_core::abortable::Abortable;
+
+/// Progress of a [`Generator`] producing multiple transactions (i.e. a send
+/// spending a large number of UTXOs that requires a chain of compound
+/// transactions), reported for each produced transaction.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratorProgress {
+    pub transaction_id: TransactionId,
+    /// Index of the transaction (starting from 0)
+    pub index: usize,
+    /// Estimated total number of transactions, available only if
+    /// obtained in advance (see [`Generator::estimate_transaction_count`])
+    pub total: Option<usize>,
+    /// Fees of all transactions produced so far (in SOMPI)
+    pub fees: u64,
+    /// The transaction is the final transaction
+    pub is_final: bool,
+}
+
+impl GeneratorProgress {
+    /// Progress of the last `transaction` produced by the `generator`.
+    /// The optional `total` is the estimated total number of transactions (see
+    /// [`Generator::estimate_transaction_count`]), which is adjusted if the
+    /// generator produces more transactions than estimated. If `total` is not
+    /// supplied, it is known only once the final transaction is produced.
+    pub fn new(generator: &Generator, transaction: &PendingTransaction, total: Option<usize>) -> Self {
+        let summary = generator.summary();
+        let index = summary.number_of_generated_transactions().saturating_sub(1);
+        let total = if transaction.is_final() { Some(index + 1) } else { total.map(|total| total.max(index + 2)) };
+        Self { transaction_id: transaction.id(), index, total, fees: summary.aggregated_fees(), is_final: transaction.is_final() }
+    }
+}
+
+impl Generator {
+    /// Produces all transactions using the supplied `settings` (without signing)
+    /// and returns the number of produced transactions. Used to obtain the total
+    /// number of transactions for [`GeneratorProgress`] reporting in advance.
+    /// Note that this performs a complete additional generator run.
+    pub async fn estimate_transaction_count(settings: GeneratorSettings, abortable: Option<&Abortable>) -> Result<usize> {
+        let generator = Generator::try_new(settings, None, abortable)?;
+        let mut stream = generator.stream();
+        while stream.try_next().await?.is_some() {
+            yield_executor().await;
+        }
+        Ok(generator.summary().number_of_generated_transactions())
+    }
+}
@@ -3,14 +3,34 @@
 //! constructing and instance of the [`Generator`](crate::tx::Generator).
 //!
 
+use crate::error::Error;
 use crate::events::Events;
 use crate::imports::*;
 use crate::result::Result;
-use crate::tx::{Fees, PaymentDestination};
-use crate::utxo::{UtxoContext, UtxoEntryReference, UtxoIterator};
+use crate::tx::{ChangePolicy, Fees, PaymentDestination};
+use crate::utxo::{UtxoContext, UtxoEntryId, UtxoEntryReference, UtxoIterator};
 use kaspa_addresses::Address;
+use kaspa_consensus_core::config::params::Params;
+use kaspa_consensus_core::hashing::sighash_type::{SigHashType, SIG_HASH_ALL};
 use workflow_core::channel::Multiplexer;
 
+/// Coin control: UTXOs that must be spent by the generated transactions.
+#[derive(Debug, Clone, Default)]
+pub struct CoinControl {
+    /// UTXOs that must be consumed as transaction inputs
+    pub utxos: Vec<UtxoEntryId>,
+    /// Spend only the supplied UTXOs; if `false`, additional UTXOs
+    /// are selected automatically when the supplied UTXOs are
+    /// insufficient to cover the transaction amount and fees.
+    pub exclusive: bool,
+}
+
+impl CoinControl {
+    pub fn new(utxos: Vec<UtxoEntryId>, exclusive: bool) -> Self {
+        Self { utxos, exclusive }
+    }
+}
+
 pub struct GeneratorSettings {
     // Network type
     pub network_id: NetworkId,
@@ -34,6 +54,14 @@ pub struct GeneratorSettings {
     pub final_transaction_payload: Option<Vec<u8>>,
     // transaction is a transfer between accounts
     pub destination_utxo_context: Option<UtxoContext>,
+    // handling of the final transaction change that can not be issued as an output
+    pub change_policy: ChangePolicy,
+    // sighash type used to sign the final transaction inputs
+    pub final_transaction_sighash_type: SigHashType,
+    // consensus params used for mass calculations (network defaults if not supplied)
+    pub consensus_params: Option<Params>,
+    // UTXO entries that must be consumed by the generated transactions (coin control)
+    pub required_utxo_entries: AHashSet<UtxoEntryId>,
 }
 
 // impl std::fmt::Debug for GeneratorSettings {
@@ -62,6 +90,7 @@ impl GeneratorSettings {
         final_transaction_payload: Option<Vec<u8>>,
     ) -> Result<Self> {
         let network_id = account.utxo_context().processor().network_id()?;
+        let consensus_params = account.utxo_context().processor().consensus_params()?;
         let change_address = account.change_address()?;
         let multiplexer = account.wallet().multiplexer().clone();
         let sig_op_count = account.sig_op_count();
@@ -82,6 +111,10 @@ impl GeneratorSettings {
             final_transaction_destination,
             final_transaction_payload,
             destination_utxo_context: None,
+            change_policy: ChangePolicy::default(),
+            final_transaction_sighash_type: SIG_HASH_ALL,
+            consensus_params: Some(consensus_params),
+            required_utxo_entries: AHashSet::default(),
         };
 
         Ok(settings)
@@ -98,6 +131,7 @@ impl GeneratorSettings {
         multiplexer: Option<Multiplexer<Box<Events>>>,
     ) -> Result<Self> {
         let network_id = utxo_context.processor().network_id()?;
+        let consensus_params = utxo_context.processor().consensus_params()?;
         let utxo_iterator = UtxoIterator::new(&utxo_context);
 
         let settings = GeneratorSettings {
@@ -113,6 +147,10 @@ impl GeneratorSettings {
             final_transaction_destination,
             final_transaction_payload,
             destination_utxo_context: None,
+            change_policy: ChangePolicy::default(),
+            final_transaction_sighash_type: SIG_HASH_ALL,
+            consensus_params: Some(consensus_params),
+            required_utxo_entries: AHashSet::default(),
         };
 
         Ok(settings)
@@ -142,11 +180,95 @@ impl GeneratorSettings {
             final_transaction_destination,
             final_transaction_payload,
             destination_utxo_context: None,
+            change_policy: ChangePolicy::default(),
+            final_transaction_sighash_type: SIG_HASH_ALL,
+            consensus_params: None,
+            required_utxo_entries: AHashSet::default(),
         };
 
         Ok(settings)
     }
 
+    /// Spend only the supplied UTXO entries (coin control) instead of
+    /// performing automatic UTXO selection from the source context.
+    pub fn with_utxo_entries(mut self, utxo_entries: Vec<UtxoEntryReference>) -> Self {
+        self.utxo_iterator = Box::new(utxo_entries.into_iter());
+        self
+    }
+
+    /// Spend all of the supplied UTXO entries (coin control). If `exclusive`
+    /// is `true`, only the supplied UTXO entries are spent; otherwise, the
+    /// UTXO entries yielded by the current UTXO iterator are used to cover
+    /// the remaining amount and fees. The generator fails with
+    /// [`Error::InsufficientFunds`](crate::error::Error::InsufficientFunds)
+    /// if the transaction can not be funded. The supplied entries should be
+    /// validated against the source UTXO context using
+    /// [`UtxoContext::get_spendable_entries()`].
+    pub fn with_required_utxo_entries(mut self, utxo_entries: Vec<UtxoEntryReference>, exclusive: bool) -> Self {
+        let required = utxo_entries.iter().map(|utxo_entry| utxo_entry.id()).collect::<AHashSet<_>>();
+        self.utxo_iterator = if exclusive {
+            Box::new(utxo_entries.into_iter())
+        } else {
+            let excluded = required.clone();
+            Box::new(utxo_entries.into_iter().chain(self.utxo_iterator.filter(move |utxo_entry| !excluded.contains(&utxo_entry.id()))))
+        };
+        self.required_utxo_entries = required;
+        self
+    }
+
+    /// Apply [`CoinControl`] validating the supplied UTXOs against the source
+    /// [`UtxoContext`] (the UTXOs must be present, mature and not locked).
+    pub fn with_coin_control(self, coin_control: &CoinControl) -> Result<Self> {
+        let utxo_entries = self
+            .source_utxo_context
+            .as_ref()
+            .ok_or_else(|| Error::custom("coin control requires a source UTXO context"))?
+            .get_spendable_entries(&coin_control.utxos)?;
+        Ok(self.with_required_utxo_entries(utxo_entries, coin_control.exclusive))
+    }
+
+    /// Spend the supplied UTXO entries before the UTXO entries
+    /// yielded by the current UTXO iterator (used to chain
+    /// transactions spending the change of preceding transactions).
+    pub fn with_preceding_utxo_entries(mut self, utxo_entries: Vec<UtxoEntryReference>) -> Self {
+        self.utxo_iterator = Box::new(utxo_entries.into_iter().chain(self.utxo_iterator));
+        self
+    }
+
+    /// Exclude the supplied UTXO entries from the UTXO entries yielded by
+    /// the current UTXO iterator (used when resuming an interrupted operation
+    /// to skip UTXOs spent by previously submitted transactions).
+    pub fn without_utxo_entries(mut self, utxo_entries: AHashSet<UtxoEntryId>) -> Self {
+        if !utxo_entries.is_empty() {
+            self.utxo_iterator = Box::new(self.utxo_iterator.filter(move |utxo_entry| !utxo_entries.contains(&utxo_entry.id())));
+        }
+        self
+    }
+
+    /// Set the [`ChangePolicy`] applied to the final transaction change
+    /// that can not be issued as a change output.
+    pub fn with_change_policy(mut self, change_policy: ChangePolicy) -> Self {
+        self.change_policy = change_policy;
+        self
+    }
+
+    /// Set the sighash type used to sign the final transaction inputs
+    /// (batch transactions are always signed using `SIG_HASH_ALL`).
+    /// The sighash type of individual inputs can be changed using
+    /// [`PendingTransaction::set_sighash_type()`](crate::tx::PendingTransaction::set_sighash_type).
+    pub fn with_sighash_type(mut self, sighash_type: SigHashType) -> Self {
+        self.final_transaction_sighash_type = sighash_type;
+        self
+    }
+
+    /// Set consensus [`Params`] used for transaction mass calculations
+    /// instead of the default params of the network (for example
+    /// params of a devnet running with non-default settings).
+    pub fn with_consensus_params(mut self, consensus_params: Params) -> Self {
+        self.consensus_params = Some(consensus_params);
+        self
+    }
+
     pub fn utxo_context_transfer(mut self, destination_utxo_context: &UtxoContext) -> Self {
         self.destination_utxo_context = Some(destination_utxo_context.clone());
         self
@@ -3,11 +3,32 @@
 //!
 
 use crate::imports::*;
+use crate::tx::sign::{sign_transaction_inputs, sign_transaction_inputs_async};
 use kaspa_bip32::PrivateKey;
-use kaspa_consensus_core::{sign::sign_with_multiple_v2, tx::SignableTransaction};
+use kaspa_consensus_core::hashing::sighash_type::SigHashType;
+use kaspa_consensus_core::tx::SignableTransaction;
 
+#[async_trait]
 pub trait SignerT: Send + Sync + 'static {
-    fn try_sign(&self, transaction: SignableTransaction, addresses: &[Address]) -> Result<SignableTransaction>;
+    /// Sign the `transaction` inputs using the private keys of the `addresses`,
+    /// signing each input with the sighash type at its index in `sighash_types`.
+    fn try_sign(
+        &self,
+        transaction: SignableTransaction,
+        addresses: &[Address],
+        sighash_types: &[SigHashType],
+    ) -> Result<SignableTransaction>;
+
+    /// Async variant of [`SignerT::try_sign()`] yielding to the executor while
+    /// signing large transactions on wasm32 (see [`sign_transaction_inputs_async()`]).
+    async fn try_sign_async(
+        &self,
+        transaction: SignableTransaction,
+        addresses: &[Address],
+        sighash_types: &[SigHashType],
+    ) -> Result<SignableTransaction> {
+        self.try_sign(transaction, addresses, sighash_types)
+    }
 }
 
 struct Inner {
@@ -41,18 +62,40 @@ impl Signer {
 
         Ok(())
     }
-}
 
-impl SignerT for Signer {
-    fn try_sign(&self, mutable_tx: SignableTransaction, addresses: &[Address]) -> Result<SignableTransaction> {
+    fn keys_for_signing(&self, addresses: &[Address]) -> Result<Vec<[u8; 32]>> {
         self.ingest(addresses)?;
 
         let keys = self.inner.keys.lock().unwrap();
-        let mut keys_for_signing = addresses.iter().map(|address| *keys.get(address).unwrap()).collect::<Vec<_>>();
+        Ok(addresses.iter().map(|address| *keys.get(address).unwrap()).collect())
+    }
+}
+
+#[async_trait]
+impl SignerT for Signer {
+    fn try_sign(
+        &self,
+        mutable_tx: SignableTransaction,
+        addresses: &[Address],
+        sighash_types: &[SigHashType],
+    ) -> Result<SignableTransaction> {
+        let mut keys_for_signing = self.keys_for_signing(addresses)?;
         // TODO - refactor for multisig
-        let signable_tx = sign_with_multiple_v2(mutable_tx, &keys_for_signing).fully_signed()?;
+        let signable_tx = sign_transaction_inputs(mutable_tx, &keys_for_signing, sighash_types).fully_signed();
         keys_for_signing.zeroize();
-        Ok(signable_tx)
+        Ok(signable_tx?)
+    }
+
+    async fn try_sign_async(
+        &self,
+        mutable_tx: SignableTransaction,
+        addresses: &[Address],
+        sighash_types: &[SigHashType],
+    ) -> Result<SignableTransaction> {
+        let mut keys_for_signing = self.keys_for_signing(addresses)?;
+        let signable_tx = sign_transaction_inputs_async(mutable_tx, &keys_for_signing, sighash_types).await.fully_signed();
+        keys_for_signing.zeroize();
+        Ok(signable_tx?)
     }
 }
 
@@ -71,14 +114,36 @@ impl KeydataSigner {
         let keys = keydata.into_iter().map(|(address, key)| (address, key.to_bytes())).collect();
         Self { inner: Arc::new(KeydataSignerInner { keys }) }
     }
+
+    fn keys_for_signing(&self, addresses: &[Address]) -> Vec<[u8; 32]> {
+        addresses.iter().map(|address| *self.inner.keys.get(address).unwrap()).collect()
+    }
 }
 
+#[async_trait]
 impl SignerT for KeydataSigner {
-    fn try_sign(&self, mutable_tx: SignableTransaction, addresses: &[Address]) -> Result<SignableTransaction> {
-        let mut keys_for_signing = addresses.iter().map(|address| *self.inner.keys.get(address).unwrap()).collect::<Vec<_>>();
+    fn try_sign(
+        &self,
+        mutable_tx: SignableTransaction,
+        addresses: &[Address],
+        sighash_types: &[SigHashType],
+    ) -> Result<SignableTransaction> {
+        let mut keys_for_signing = self.keys_for_signing(addresses);
         // TODO - refactor for multisig
-        let signable_tx = sign_with_multiple_v2(mutable_tx, &keys_for_signing).fully_signed()?;
+        let signable_tx = sign_transaction_inputs(mutable_tx, &keys_for_signing, sighash_types).fully_signed();
+        keys_for_signing.zeroize();
+        Ok(signable_tx?)
+    }
+
+    async fn try_sign_async(
+        &self,
+        mutable_tx: SignableTransaction,
+        addresses: &[Address],
+        sighash_types: &[SigHashType],
+    ) -> Result<SignableTransaction> {
+        let mut keys_for_signing = self.keys_for_signing(addresses);
+        let signable_tx = sign_transaction_inputs_async(mutable_tx, &keys_for_signing, sighash_types).await.fully_signed();
         keys_for_signing.zeroize();
-        Ok(signable_tx)
+        Ok(signable_tx?)
     }
 }
@@ -2,9 +2,10 @@
 //! [`GeneratorSummary`] is a struct that holds the summary
 //! of a [`Generator`](crate::tx::Generator) output after transaction generation.
 //! The summary includes total amount, total fees consumed,
-//! total UTXOs consumed etc.
+//! total mass and the resulting fee rate, total UTXOs consumed etc.
 //!
 
+use crate::tx::{ChangeDisposition, ChangePolicy, GeneratorProfile};
 use crate::utils::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use kaspa_consensus_core::network::{NetworkId, NetworkType};
@@ -13,13 +14,40 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct GeneratorSummary {
+    #[serde(alias = "network_id")]
     pub network_id: NetworkId,
+    #[serde(alias = "aggregated_utxos")]
     pub aggregated_utxos: usize,
+    #[serde(alias = "aggregated_fees")]
     pub aggregated_fees: u64,
+    /// Total mass of the generated transactions
+    #[serde(default)]
+    pub aggregated_mass: u64,
+    #[serde(alias = "number_of_generated_transactions")]
     pub number_of_generated_transactions: usize,
+    #[serde(alias = "final_transaction_amount")]
     pub final_transaction_amount: Option<u64>,
+    #[serde(alias = "final_transaction_id")]
     pub final_transaction_id: Option<TransactionId>,
+    /// Mass of the final transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub final_transaction_mass: Option<u64>,
+    /// Fees paid by the final transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub final_transaction_fees: Option<u64>,
+    #[serde(alias = "final_change_amount")]
+    pub final_change_amount: Option<u64>,
+    /// Policy applied to the final transaction change that can not be issued as an output
+    #[serde(default)]
+    pub change_policy: ChangePolicy,
+    /// Handling of the final transaction change (`None` if the transaction has no change)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_disposition: Option<ChangeDisposition>,
+    /// Stage timings (available only with the `generator-profile` feature)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<GeneratorProfile>,
 }
 
 impl GeneratorSummary {
@@ -39,6 +67,15 @@ impl GeneratorSummary {
         self.aggregated_fees
     }
 
+    pub fn aggregated_mass(&self) -> u64 {
+        self.aggregated_mass
+    }
+
+    /// Fee rate (in SOMPI per gram of mass) of all generated transactions.
+    pub fn fee_rate(&self) -> f64 {
+        fee_rate(self.aggregated_fees, self.aggregated_mass)
+    }
+
     pub fn number_of_generated_transactions(&self) -> usize {
         self.number_of_generated_transactions
     }
@@ -50,6 +87,35 @@ impl GeneratorSummary {
     pub fn final_transaction_id(&self) -> Option<TransactionId> {
         self.final_transaction_id
     }
+
+    pub fn final_transaction_mass(&self) -> Option<u64> {
+        self.final_transaction_mass
+    }
+
+    pub fn final_transaction_fees(&self) -> Option<u64> {
+        self.final_transaction_fees
+    }
+
+    /// Fee rate (in SOMPI per gram of mass) of the final transaction.
+    pub fn final_transaction_fee_rate(&self) -> Option<f64> {
+        Some(fee_rate(self.final_transaction_fees?, self.final_transaction_mass?))
+    }
+
+    pub fn final_change_amount(&self) -> Option<u64> {
+        self.final_change_amount
+    }
+
+    pub fn change_policy(&self) -> ChangePolicy {
+        self.change_policy
+    }
+
+    pub fn change_disposition(&self) -> Option<ChangeDisposition> {
+        self.change_disposition
+    }
+
+    pub fn profile(&self) -> Option<&GeneratorProfile> {
+        self.profile.as_ref()
+    }
 }
 
 impl fmt::Display for GeneratorSummary {
@@ -64,22 +130,104 @@ impl fmt::Display for GeneratorSummary {
             let total = final_transaction_amount + self.aggregated_fees;
             write!(
                 f,
-                "Amount: {}  Fees: {}  Total: {}  UTXOs: {}  {}",
+                "Amount: {}  Fees: {} ({:.2} sompi/g)  Total: {}  UTXOs: {}  {}",
                 sompi_to_kaspa_string_with_suffix(final_transaction_amount, &self.network_id),
                 sompi_to_kaspa_string_with_suffix(self.aggregated_fees, &self.network_id),
+                self.fee_rate(),
                 sompi_to_kaspa_string_with_suffix(total, &self.network_id),
                 self.aggregated_utxos,
                 transactions
             )?;
+            self.fmt_change_disposition(f)?;
         } else {
             write!(
                 f,
-                "Fees: {}  UTXOs: {}  {}",
+                "Fees: {} ({:.2} sompi/g)  UTXOs: {}  {}",
                 sompi_to_kaspa_string_with_suffix(self.aggregated_fees, &self.network_id),
+                self.fee_rate(),
                 self.aggregated_utxos,
                 transactions
             )?;
+            self.fmt_change_disposition(f)?;
         }
         Ok(())
     }
 }
+
+/// Fee rate in SOMPI per gram of transaction mass.
+pub(crate) fn fee_rate(fees: u64, mass: u64) -> f64 {
+    if mass > 0 {
+        fees as f64 / mass as f64
+    } else {
+        0.0
+    }
+}
+
+impl GeneratorSummary {
+    fn fmt_change_disposition(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.change_disposition {
+            Some(ChangeDisposition::FoldedToFee(amount)) => {
+                write!(f, "  Change: {} (folded to fees)", sompi_to_kaspa_string_with_suffix(amount, &self.network_id))
+            }
+            Some(ChangeDisposition::AddedToRecipient(amount)) => {
+                write!(f, "  Change: {} (added to recipient)", sompi_to_kaspa_string_with_suffix(amount, &self.network_id))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generator_summary_serde() {
+        let summary = GeneratorSummary {
+            network_id: NetworkId::with_suffix(NetworkType::Testnet, 10),
+            aggregated_utxos: 3,
+            aggregated_fees: 3000,
+            aggregated_mass: 1500,
+            number_of_generated_transactions: 2,
+            final_transaction_amount: Some(1_000_000),
+            final_transaction_id: Some(TransactionId::from_u64_word(1)),
+            final_transaction_mass: Some(1000),
+            final_transaction_fees: Some(2000),
+            final_change_amount: None,
+            change_policy: ChangePolicy::AccumulateUntilThreshold(100_000),
+            change_disposition: Some(ChangeDisposition::FoldedToFee(150)),
+            profile: None,
+        };
+
+        let json = serde_json::to_value(&summary).unwrap();
+        for key in ["networkId", "aggregatedUtxos", "aggregatedFees", "numberOfGeneratedTransactions", "finalTransactionAmount"] {
+            assert!(json.get(key).is_some(), "missing `{key}` in {json}");
+        }
+
+        let decoded: GeneratorSummary = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.network_id, summary.network_id);
+        assert_eq!(decoded.final_transaction_id, summary.final_transaction_id);
+        assert_eq!(decoded.change_policy, summary.change_policy);
+        assert_eq!(decoded.change_disposition, summary.change_disposition);
+        assert_eq!(decoded.fee_rate(), 2.0);
+        assert_eq!(decoded.final_transaction_fee_rate(), Some(2.0));
+
+        // snake_case field names produced by earlier versions must remain readable
+        let legacy = serde_json::json!({
+            "network_id": summary.network_id,
+            "aggregated_utxos": 3,
+            "aggregated_fees": 3000,
+            "number_of_generated_transactions": 2,
+            "final_transaction_amount": 1_000_000,
+            "final_transaction_id": summary.final_transaction_id,
+            "final_change_amount": null,
+        });
+        let decoded: GeneratorSummary = serde_json::from_value(legacy).unwrap();
+        assert_eq!(decoded.aggregated_fees, summary.aggregated_fees);
+        assert_eq!(decoded.number_of_generated_transactions, summary.number_of_generated_transactions);
+        assert_eq!(decoded.change_policy, ChangePolicy::FoldToFee);
+        assert_eq!(decoded.change_disposition, None);
+        assert_eq!(decoded.aggregated_mass, 0);
+        assert_eq!(decoded.final_transaction_fee_rate(), None);
+    }
+}
@@ -2,19 +2,24 @@
 
 use crate::error::Error;
 use crate::result::Result;
-use crate::tx::{Fees, MassCalculator, PaymentDestination};
+use crate::tx::{verify_signable_transaction, ChangePolicy, Fees, MassCalculator, PaymentDestination, PARALLEL_SIGNING_THRESHOLD};
 use crate::utxo::UtxoEntryReference;
 use crate::{tx::PaymentOutputs, utils::kaspa_to_sompi};
+use ahash::AHashSet;
 use kaspa_addresses::Address;
+use kaspa_consensus_core::config::params::Params;
+use kaspa_consensus_core::hashing::sighash_type::SIG_HASH_ALL;
 use kaspa_consensus_core::network::{NetworkId, NetworkType};
 use kaspa_consensus_core::tx::Transaction;
 use rand::prelude::*;
 use std::cell::RefCell;
 use std::fmt::Debug;
 use std::rc::Rc;
+use std::sync::Arc;
 use workflow_log::style;
 
 use super::*;
+use kaspa_txscript::pay_to_address_script;
 
 const DISPLAY_LOGS: bool = true;
 const DISPLAY_EXPECTED: bool = true;
@@ -108,6 +113,12 @@ impl GeneratorSummaryExtension for GeneratorSummary {
         );
         let aggregated_fees = accumulator.list.iter().map(|pt| pt.fees()).sum::<u64>();
         assert_eq!(self.aggregated_fees, aggregated_fees, "aggregated fees");
+        let aggregated_mass = accumulator.list.iter().map(|pt| pt.mass()).sum::<u64>();
+        assert_eq!(self.aggregated_mass, aggregated_mass, "aggregated mass");
+        if let Some(final_transaction) = accumulator.list.last().filter(|pt| pt.is_final()) {
+            assert_eq!(self.final_transaction_mass, Some(final_transaction.mass()), "final transaction mass");
+            assert_eq!(self.final_transaction_fee_rate(), Some(final_transaction.fee_rate()), "final transaction fee rate");
+        }
         self
     }
 }
@@ -352,7 +363,11 @@ impl Harness {
                 panic!("expected insufficient funds, instead received a transaction");
             }
             Err(err) => {
-                assert!(matches!(&err, Error::InsufficientFunds { .. }), "expecting insufficient funds error, received: {:?}", err);
+                let Error::InsufficientFunds { additional_needed, details, .. } = err else {
+                    panic!("expecting insufficient funds error, received: {:?}", err);
+                };
+                assert!(*additional_needed > 0, "expecting non-zero shortfall");
+                assert!(details.required > details.available, "expecting required amount to exceed the available balance");
             }
         }
     }
@@ -381,6 +396,29 @@ pub(crate) fn make_generator<F>(
     change_address: F,
     final_transaction_destination: PaymentDestination,
 ) -> Result<Generator>
+where
+    F: FnOnce(NetworkType) -> Address,
+{
+    make_generator_with_change_policy(
+        network_id,
+        head,
+        tail,
+        fees,
+        change_address,
+        final_transaction_destination,
+        ChangePolicy::default(),
+    )
+}
+
+pub(crate) fn make_generator_with_change_policy<F>(
+    network_id: NetworkId,
+    head: &[f64],
+    tail: &[f64],
+    fees: Fees,
+    change_address: F,
+    final_transaction_destination: PaymentDestination,
+    change_policy: ChangePolicy,
+) -> Result<Generator>
 where
     F: FnOnce(NetworkType) -> Address,
 {
@@ -410,6 +448,10 @@ where
         final_transaction_priority_fee: final_priority_fee,
         final_transaction_destination,
         final_transaction_payload,
+        change_policy,
+        final_transaction_sighash_type: SIG_HASH_ALL,
+        consensus_params: None,
+        required_utxo_entries: AHashSet::default(),
     };
 
     Generator::try_new(settings, None, None)
@@ -680,3 +722,330 @@ fn test_generator_inputs_903_outputs_2_fees_exclude() -> Result<()> {
 
     Ok(())
 }
+
+fn change_policy_generator(utxos: &[f64], change_policy: ChangePolicy) -> Generator {
+    let network_id = test_network_id();
+    let amount = kaspa_to_sompi(9.9999);
+    let outputs = PaymentOutputs::from((output_address(network_id.into()), amount));
+    make_generator_with_change_policy(network_id, utxos, &[], Fees::sender(Sompi(0)), change_address, outputs.into(), change_policy)
+        .unwrap()
+}
+
+#[test]
+fn test_generator_change_policy() -> Result<()> {
+    let amount = kaspa_to_sompi(9.9999);
+
+    // change that can not be issued as an output is folded into fees
+    let generator = change_policy_generator(&[10.0, 0.00002, 0.00002, 5.0], ChangePolicy::FoldToFee);
+    let pt = generator.generate_transaction()?.unwrap();
+    let tx = pt.transaction();
+    let summary = generator.summary();
+    let Some(ChangeDisposition::FoldedToFee(folded)) = summary.change_disposition() else {
+        panic!("expected change folded to fees, got {:?}", summary.change_disposition());
+    };
+    assert_eq!(tx.outputs.len(), 1);
+    assert_eq!(tx.outputs[0].value, amount);
+    assert_eq!(pt.fees(), pt.aggregate_input_value() - amount);
+    assert!(folded < pt.fees());
+    let folded_inputs = tx.inputs.len();
+    assert!(folded_inputs < 4);
+
+    // change is added to the recipient output
+    let generator = change_policy_generator(&[10.0, 0.00002, 0.00002, 5.0], ChangePolicy::AddToRecipient);
+    let pt = generator.generate_transaction()?.unwrap();
+    let tx = pt.transaction();
+    let summary = generator.summary();
+    assert_eq!(summary.change_policy(), ChangePolicy::AddToRecipient);
+    let Some(ChangeDisposition::AddedToRecipient(added)) = summary.change_disposition() else {
+        panic!("expected change added to recipient, got {:?}", summary.change_disposition());
+    };
+    assert_eq!(tx.outputs.len(), 1);
+    assert_eq!(tx.outputs[0].value, amount + added);
+    assert_eq!(pt.fees(), pt.aggregate_input_value() - tx.outputs[0].value);
+
+    // additional inputs are consumed until the change can be issued as an output
+    let generator =
+        change_policy_generator(&[10.0, 0.00002, 0.00002, 5.0], ChangePolicy::AccumulateUntilThreshold(kaspa_to_sompi(1.0)));
+    let tx = generator.generate_transaction()?.unwrap().transaction();
+    assert_eq!(tx.inputs.len(), 4);
+    assert_eq!(tx.outputs.len(), 2);
+    assert!(matches!(generator.summary().change_disposition(), Some(ChangeDisposition::Output(_))));
+
+    // change reaching the threshold is folded into fees
+    let generator = change_policy_generator(&[10.0, 0.00002, 0.00002, 5.0], ChangePolicy::AccumulateUntilThreshold(1_000));
+    let tx = generator.generate_transaction()?.unwrap().transaction();
+    assert_eq!(tx.inputs.len(), folded_inputs);
+    assert!(matches!(generator.summary().change_disposition(), Some(ChangeDisposition::FoldedToFee(_))));
+
+    // change is folded into fees once the UTXOs are depleted
+    let generator = change_policy_generator(&[10.0, 0.00002], ChangePolicy::AccumulateUntilThreshold(kaspa_to_sompi(1.0)));
+    let tx = generator.generate_transaction()?.unwrap().transaction();
+    assert_eq!(tx.outputs.len(), 1);
+    assert!(matches!(generator.summary().change_disposition(), Some(ChangeDisposition::FoldedToFee(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_generator_progress() -> Result<()> {
+    let make =
+        || generator(test_network_id(), &[10.0; 1_000], &[], Fees::sender(Kaspa(5.0)), [(output_address, Kaspa(9_000.0))].as_slice());
+
+    // 10 + 1 compound transactions followed by the final transaction
+    for estimated_total in [Some(12), Some(1), None] {
+        let generator = make()?;
+        let mut fees = 0;
+        for (index, transaction) in generator.iter().enumerate() {
+            let transaction = transaction?;
+            fees += transaction.fees();
+            let progress = GeneratorProgress::new(&generator, &transaction, estimated_total);
+            assert_eq!(progress.index, index);
+            assert_eq!(progress.transaction_id, transaction.id());
+            assert_eq!(progress.fees, fees);
+            assert_eq!(progress.is_final, index == 11);
+            let expected_total = match estimated_total {
+                _ if index == 11 => Some(12),
+                // underestimated total is adjusted to the transactions produced so far
+                Some(estimated_total) => Some(estimated_total.max(index + 2)),
+                // unknown total is available only with the final transaction
+                None => None,
+            };
+            assert_eq!(progress.total, expected_total);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_generator_consensus_params() -> Result<()> {
+    let network_id = test_network_id();
+    let make = |consensus_params: Option<Params>| {
+        let utxo_entries = [10.0, 10.0].into_iter().map(kaspa_to_sompi).map(UtxoEntryReference::simulated).collect::<Vec<_>>();
+        let settings = GeneratorSettings::try_new_with_iterator(
+            network_id,
+            Box::new(utxo_entries.into_iter()),
+            change_address(network_id.into()),
+            1,
+            1,
+            PaymentOutputs::from((output_address(network_id.into()), kaspa_to_sompi(15.0))).into(),
+            Fees::sender(Sompi(0)),
+            None,
+            None,
+        )?;
+        let settings = match consensus_params {
+            Some(consensus_params) => settings.with_consensus_params(consensus_params),
+            None => settings,
+        };
+        Generator::try_new(settings, None, None)?.generate_transaction().map(|tx| tx.unwrap().fees())
+    };
+
+    let default_params: Params = network_id.into();
+    let mut custom_params = default_params.clone();
+    custom_params.mass_per_tx_byte *= 2;
+    custom_params.mass_per_script_pub_key_byte *= 2;
+    assert!(make(Some(custom_params))? > make(Some(default_params.clone()))?);
+    assert_eq!(make(Some(default_params))?, make(None)?);
+
+    let mainnet_params: Params = NetworkId::new(NetworkType::Mainnet).into();
+    assert!(make(Some(mainnet_params)).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_generator_required_utxo_entries() -> Result<()> {
+    let network_id = test_network_id();
+    let utxo_entries = [10.0, 10.0, 1.0, 1.0].into_iter().map(kaspa_to_sompi).map(UtxoEntryReference::simulated).collect::<Vec<_>>();
+    let make = |required: &[usize], exclusive: bool| {
+        let settings = GeneratorSettings::try_new_with_iterator(
+            network_id,
+            Box::new(utxo_entries.clone().into_iter()),
+            change_address(network_id.into()),
+            1,
+            1,
+            PaymentOutputs::from((output_address(network_id.into()), kaspa_to_sompi(5.0))).into(),
+            Fees::sender(Sompi(0)),
+            None,
+            None,
+        )?
+        .with_required_utxo_entries(required.iter().map(|index| utxo_entries[*index].clone()).collect(), exclusive);
+        Generator::try_new(settings, None, None)?.generate_transaction().map(|tx| tx.unwrap())
+    };
+
+    // all required entries are consumed even if the first one covers the amount
+    let tx = make(&[0, 2, 3], false)?;
+    assert_eq!(tx.utxo_entries().len(), 3);
+    assert!([0, 2, 3].iter().all(|index| tx.utxo_entries().contains_key(&utxo_entries[*index].id())));
+
+    // additional entries are selected if the required entries are insufficient
+    let tx = make(&[2, 3], false)?;
+    assert!([2, 3].iter().all(|index| tx.utxo_entries().contains_key(&utxo_entries[*index].id())));
+    assert_eq!(tx.aggregate_input_value(), kaspa_to_sompi(12.0));
+
+    // exclusive coin control does not select additional entries
+    assert!(matches!(make(&[2, 3], true), Err(Error::InsufficientFunds { .. })));
+    let tx = make(&[1, 3], true)?;
+    assert_eq!(tx.aggregate_input_value(), kaspa_to_sompi(11.0));
+
+    Ok(())
+}
+
+#[test]
+fn test_generator_send_all() -> Result<()> {
+    let network_id = test_network_id();
+    let make = |values: &[f64], priority_fee: u64| {
+        let utxo_entries = values.iter().copied().map(kaspa_to_sompi).map(UtxoEntryReference::simulated).collect::<Vec<_>>();
+        let settings = GeneratorSettings::try_new_with_iterator(
+            network_id,
+            Box::new(utxo_entries.into_iter()),
+            change_address(network_id.into()),
+            1,
+            1,
+            PaymentDestination::SendAll(output_address(network_id.into())),
+            Fees::sender(Sompi(priority_fee)),
+            None,
+            None,
+        )?;
+        Generator::try_new(settings, None, None)
+    };
+
+    let destination = pay_to_address_script(&output_address(network_id.into()));
+    for (values, priority_fee) in [(vec![10.0; 5], 0), (vec![1.0; 250], 1_000), (vec![0.5], 0)] {
+        let generator = make(&values, priority_fee)?;
+        let transactions = generator.iter().collect::<Result<Vec<_>>>()?;
+        let last = transactions.last().unwrap();
+        assert!(last.is_final());
+        assert_eq!(last.transaction().outputs.len(), 1, "no change output is created");
+        assert_eq!(last.transaction().outputs[0].script_public_key, destination);
+        assert!(last.fees() >= priority_fee);
+
+        let total = values.iter().copied().map(kaspa_to_sompi).sum::<u64>();
+        let summary = generator.summary();
+        assert_eq!(summary.aggregated_utxos, values.len() + transactions.len() - 1);
+        assert_eq!(summary.final_transaction_amount, Some(total - summary.aggregated_fees));
+        assert_eq!(last.transaction().outputs[0].value, total - summary.aggregated_fees);
+        assert_eq!(last.payment_value(), summary.final_transaction_amount);
+    }
+
+    assert!(matches!(make(&[0.00001], 0)?.generate_transaction(), Err(Error::InsufficientFunds { .. })));
+    assert!(matches!(make(&[], 0)?.generate_transaction(), Err(Error::InsufficientFunds { .. })));
+
+    Ok(())
+}
+
+#[test]
+fn test_generator_priority_fee_rate() -> Result<()> {
+    let network_id = test_network_id();
+    let final_transaction = |fees: Fees| -> Result<PendingTransaction> {
+        let generator = generator(network_id, &[100.0], &[], fees, [(output_address, Kaspa(10.0))].as_slice())?;
+        let transactions = generator.iter().collect::<Result<Vec<_>>>()?;
+        assert_eq!(transactions.len(), 1);
+        Ok(transactions.into_iter().next().unwrap())
+    };
+
+    let rate = 10.5;
+    let base = final_transaction(Fees::SenderPays(0))?;
+    let priority_fee = (rate * base.mass() as f64).ceil() as u64;
+
+    let sender = final_transaction(Fees::SenderPaysRate(rate))?;
+    assert_eq!(sender.mass(), base.mass());
+    assert_eq!(sender.fees(), base.fees() + priority_fee);
+    assert_eq!(sender.transaction().outputs[0].value, kaspa_to_sompi(10.0));
+    assert!(sender.fee_rate() >= rate);
+
+    let receiver = final_transaction(Fees::ReceiverPaysRate(rate))?;
+    assert_eq!(receiver.fees(), base.fees() + priority_fee);
+    assert_eq!(receiver.transaction().outputs[0].value, kaspa_to_sompi(10.0) - receiver.fees());
+
+    // absolute priority fees are applied as is
+    let absolute = final_transaction(Fees::SenderPays(priority_fee))?;
+    assert_eq!(absolute.fees(), sender.fees());
+
+    let send_all = make_generator(
+        network_id,
+        &[100.0],
+        &[],
+        Fees::SenderPaysRate(rate),
+        change_address,
+        PaymentDestination::SendAll(output_address(network_id.into())),
+    )?;
+    let last = send_all.iter().collect::<Result<Vec<_>>>()?.pop().unwrap();
+    assert!(last.fees() >= (rate * last.mass() as f64).ceil() as u64);
+    assert_eq!(last.transaction().outputs[0].value, kaspa_to_sompi(100.0) - last.fees());
+
+    // the priority fee reduces the change output, increasing the storage mass and
+    // the priority fee in turn; the transaction must carry the settled mass and fee
+    let storage_bound = |fees: Fees| -> Result<PendingTransaction> {
+        let generator = generator(network_id, &[1.0], &[], fees, [(output_address, Kaspa(0.5))].as_slice())?;
+        Ok(generator.iter().collect::<Result<Vec<_>>>()?.pop().unwrap())
+    };
+    let rate = 100.0;
+    let base = storage_bound(Fees::SenderPays(0))?;
+    let sender = storage_bound(Fees::SenderPaysRate(rate))?;
+    assert!(sender.mass() > base.mass(), "the priority fee increases the storage mass");
+    let calc = MassCalculator::new(&network_id.into(), sender.generator().network_params());
+    let network_fee = calc.calc_minimum_transaction_fee_from_mass(sender.mass());
+    assert!(sender.fees() - network_fee >= (rate * sender.mass() as f64).ceil() as u64);
+    let tx = sender.transaction();
+    assert_eq!(tx.outputs.len(), 2);
+    assert_eq!(tx.outputs[1].value, kaspa_to_sompi(0.5) - sender.fees());
+    // the generator estimates the storage mass with the change output less the priority fee
+    let mut outputs = tx.outputs.clone();
+    outputs[1].value += network_fee;
+    let utxo_entries = sender.utxo_entries().values().cloned().collect::<Vec<_>>();
+    let storage_mass = calc.calc_storage_mass_for_transaction(false, &utxo_entries, &outputs).unwrap();
+    assert_eq!(sender.mass(), calc.combine_mass(calc.calc_mass_for_signed_transaction(&tx, 1), storage_mass));
+
+    // priority fees exceeding the u64 range are rejected instead of saturating
+    let rate_overflow = generator(network_id, &[100.0], &[], Fees::SenderPaysRate(1e300), [(output_address, Kaspa(10.0))].as_slice())?;
+    assert!(matches!(rate_overflow.iter().next(), Some(Err(Error::GeneratorFeeOverflow))));
+    let fee_overflow = generator(network_id, &[100.0], &[], Fees::SenderPays(u64::MAX), [(output_address, Kaspa(10.0))].as_slice());
+    assert!(matches!(fee_overflow, Err(Error::GeneratorFeeOverflow)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_generator_sign_async() -> Result<()> {
+    use kaspa_addresses::{Prefix, Version};
+
+    let network_id = test_network_id();
+    let secret_key = secp256k1::SecretKey::from_slice(&[1u8; 32]).unwrap();
+    let public_key = secret_key.x_only_public_key(secp256k1::SECP256K1).0.serialize();
+    let address = Address::new(Prefix::Testnet, Version::PubKey, &public_key);
+
+    // transactions with inputs signed in chunks on wasm32
+    let inputs = PARALLEL_SIGNING_THRESHOLD + 1;
+    let make = |signer: Option<Arc<dyn SignerT>>| -> Result<Generator> {
+        let utxo_entries =
+            (0..inputs).map(|_| UtxoEntryReference::simulated_with_address(kaspa_to_sompi(1.0), &address)).collect::<Vec<_>>();
+        let outputs = PaymentOutputs::from([(output_address(network_id.into()), kaspa_to_sompi(inputs as f64 - 1.0))].as_slice());
+        let settings = GeneratorSettings::try_new_with_iterator(
+            network_id,
+            Box::new(utxo_entries.into_iter()),
+            change_address(network_id.into()),
+            1,
+            1,
+            outputs.into(),
+            Fees::SenderPays(0),
+            None,
+            None,
+        )?;
+        Generator::try_new(settings, signer, None)
+    };
+
+    let signer = Arc::new(KeydataSigner::new(vec![(address.clone(), secret_key)]));
+    let generator = make(Some(signer))?;
+    let transaction = generator.iter().next().unwrap()?;
+    assert_eq!(transaction.transaction().inputs.len(), inputs);
+    transaction.try_sign_async().await?;
+    assert!(verify_signable_transaction(&transaction.signable_transaction())?.is_fully_signed());
+
+    let generator = make(None)?;
+    let transaction = generator.iter().next().unwrap()?;
+    transaction.try_sign_with_keys_async(&[secret_key.secret_bytes()]).await?;
+    assert!(verify_signable_transaction(&transaction.signable_transaction())?.is_fully_signed());
+
+    Ok(())
+}
@@ -16,9 +16,12 @@ pub enum MassCombinationStrategy {
     Max,
 }
 
-// pub const ECDSA_SIGNATURE_SIZE: u64 = 64;
-// pub const SCHNORR_SIGNATURE_SIZE: u64 = 64;
-pub const SIGNATURE_SIZE: u64 = 1 + 64 + 1; //1 byte for OP_DATA_65 + 64 (length of signature) + 1 byte for sig hash type
+pub const ECDSA_SIGNATURE_SIZE: u64 = 64;
+pub const SCHNORR_SIGNATURE_SIZE: u64 = 64;
+/// Signature script size of a single signature. ECDSA signatures are encoded in the
+/// compact (64 byte) form, so both schnorr and ECDSA inputs share the same size.
+pub const SIGNATURE_SIZE: u64 = 1 + SCHNORR_SIGNATURE_SIZE + 1; //1 byte for OP_DATA_65 + 64 (length of signature) + 1 byte for sig hash type
+const _: () = assert!(ECDSA_SIGNATURE_SIZE == SCHNORR_SIGNATURE_SIZE);
 
 /// MINIMUM_RELAY_TRANSACTION_FEE specifies the minimum transaction fee for a transaction to be accepted to
 /// the mempool and relayed. It is specified in sompi per 1kg (or 1000 grams) of transaction mass.
@@ -243,6 +246,11 @@ impl MassCalculator {
         }
     }
 
+    /// Smallest output value that is not considered dust.
+    pub fn dust_threshold(&self) -> u64 {
+        (MINIMUM_RELAY_TRANSACTION_FEE * STANDARD_OUTPUT_SIZE_PLUS_INPUT_SIZE_3X).div_ceil(1000)
+    }
+
     pub fn calc_mass_for_transaction(&self, tx: &Transaction) -> u64 {
         self.blank_transaction_mass()
             + self.calc_mass_for_payload(tx.payload.len())
@@ -2,14 +2,24 @@
 //! Transaction generation and processing primitives.
 //!
 
+pub mod batch;
 pub mod consensus;
 pub mod fees;
 pub mod generator;
 pub mod mass;
 pub mod payment;
+pub mod payment_uri;
+pub mod sign;
+pub mod signable;
+pub mod txscript;
 
+pub use self::batch::*;
 pub use self::consensus::*;
 pub use self::fees::*;
 pub use self::generator::*;
 pub use self::mass::*;
 pub use self::payment::*;
+pub use self::payment_uri::*;
+pub use self::sign::*;
+pub use self::signable::*;
+pub use self::txscript::ScriptBuilder;
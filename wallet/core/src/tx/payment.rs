@@ -40,17 +40,45 @@ extern "C" {
 pub enum PaymentDestination {
     Change,
     PaymentOutputs(PaymentOutputs),
+    /// Send all spendable funds to the address. The transferred amount is
+    /// the aggregate value of all consumed UTXOs less the transaction fees
+    /// (no change output is created).
+    SendAll(Address),
 }
 
 impl PaymentDestination {
+    /// Payment amount (not available for [`PaymentDestination::Change`]
+    /// and [`PaymentDestination::SendAll`] as it depends on the fees).
     pub fn amount(&self) -> Option<u64> {
         match self {
-            Self::Change => None,
+            Self::Change | Self::SendAll(_) => None,
             Self::PaymentOutputs(payment_outputs) => Some(payment_outputs.amount()),
         }
     }
 }
 
+impl TryFrom<JsValue> for PaymentDestination {
+    type Error = Error;
+    /// `undefined` results in [`PaymentDestination::Change`], a single output
+    /// with the amount `"all"` results in [`PaymentDestination::SendAll`],
+    /// otherwise an array of payment outputs is expected.
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        if value.is_undefined() {
+            return Ok(Self::Change);
+        }
+
+        if let Some(array) = value.dyn_ref::<Array>().filter(|array| array.length() == 1) {
+            if let Some(object) = Object::try_from(&array.get(0)) {
+                if object.get_value("amount")?.as_string().is_some_and(|amount| amount.eq_ignore_ascii_case("all")) {
+                    return Ok(Self::SendAll(object.get_cast::<Address>("address")?.into_owned()));
+                }
+            }
+        }
+
+        Ok(PaymentOutputs::try_owned_from(value)?.into())
+    }
+}
+
 /// @category Wallet SDK
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, CastFromJs)]
 #[wasm_bindgen(inspectable)]
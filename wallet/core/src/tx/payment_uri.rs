@@ -0,0 +1,235 @@
+//!
+//! [`PaymentUri`] - `kaspa:` payment request URIs
+//! (`kaspa:<address>?amount=<KAS>&label=<label>&message=<message>`)
+//! typically conveyed via QR codes.
+//!
+
+use crate::imports::*;
+use crate::tx::PaymentOutput;
+use crate::utils::{try_kaspa_str_to_sompi, Amount};
+use kaspa_addresses::AddressT;
+use kaspa_consensus_core::network::NetworkIdT;
+
+/// Payment request URI. The URI scheme is the address prefix of the
+/// destination network (`kaspa:`, `kaspatest:`, etc.) followed by the
+/// address payload and optional query parameters:
+///
+/// - `amount` - requested amount in KAS (decimal, up to 8 decimal places)
+/// - `label` - label of the payment recipient
+/// - `message` - payment description
+///
+/// Unknown parameters are ignored, unless prefixed with `req-` (parameters
+/// that must be understood by the client), in which case the URI is rejected.
+///
+/// @category Wallet SDK
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize, CastFromJs)]
+#[serde(rename_all = "camelCase")]
+#[wasm_bindgen(inspectable)]
+pub struct PaymentUri {
+    #[wasm_bindgen(getter_with_clone)]
+    pub address: Address,
+    /// Requested amount in SOMPI
+    pub amount: Option<u64>,
+    #[wasm_bindgen(getter_with_clone)]
+    pub label: Option<String>,
+    #[wasm_bindgen(getter_with_clone)]
+    pub message: Option<String>,
+}
+
+impl PaymentUri {
+    pub fn new(address: Address) -> Self {
+        Self { address, amount: None, label: None, message: None }
+    }
+
+    pub fn with_amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Network type of the destination address.
+    pub fn network_type(&self) -> Result<NetworkType> {
+        Ok(NetworkType::try_from(self.address.prefix)?)
+    }
+
+    /// Parse the URI, ensuring that the destination address belongs to the
+    /// supplied network (if any).
+    pub fn try_parse(uri: &str, network_type: Option<NetworkType>) -> Result<Self> {
+        let uri = uri.trim();
+        let (scheme, rest) = uri.split_once(':').ok_or_else(|| Error::InvalidPaymentUri("missing URI scheme".to_string()))?;
+        let (payload, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+        let prefix = Prefix::try_from(scheme.to_lowercase().as_str())
+            .map_err(|_| Error::InvalidPaymentUri(format!("unsupported URI scheme '{scheme}'")))?;
+        let address = Address::try_from(format!("{prefix}:{}", payload.to_lowercase()).as_str())
+            .map_err(|err| Error::InvalidPaymentUri(format!("invalid address: {err}")))?;
+
+        if let Some(network_type) = network_type {
+            if NetworkType::try_from(address.prefix)? != network_type {
+                return Err(Error::InvalidPaymentUri(format!("address {address} does not belong to the {network_type} network")));
+            }
+        }
+
+        let mut payment_uri = Self::new(address);
+        for param in query.split('&').filter(|param| !param.is_empty()) {
+            let (key, value) = param.split_once('=').unwrap_or((param, ""));
+            let value = percent_decode(value)?;
+            let duplicate = match key {
+                "amount" => payment_uri.amount.replace(parse_amount(&value)?).is_some(),
+                "label" => payment_uri.label.replace(value).is_some(),
+                "message" => payment_uri.message.replace(value).is_some(),
+                key if key.starts_with("req-") => {
+                    return Err(Error::InvalidPaymentUri(format!("unsupported required parameter '{key}'")));
+                }
+                _ => false,
+            };
+            if duplicate {
+                return Err(Error::InvalidPaymentUri(format!("duplicate parameter '{key}'")));
+            }
+        }
+
+        Ok(payment_uri)
+    }
+
+    /// Payment output for the requested amount (if the amount is specified).
+    pub fn payment_output(&self) -> Option<PaymentOutput> {
+        self.amount.map(|amount| PaymentOutput { address: self.address.clone(), amount })
+    }
+}
+
+impl std::fmt::Display for PaymentUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.address)?;
+
+        let mut params = vec![];
+        if let Some(amount) = self.amount {
+            params.push(format!("amount={}", Amount::from_sompi(amount)));
+        }
+        if let Some(label) = &self.label {
+            params.push(format!("label={}", percent_encode(label)));
+        }
+        if let Some(message) = &self.message {
+            params.push(format!("message={}", percent_encode(message)));
+        }
+        if !params.is_empty() {
+            write!(f, "?{}", params.join("&"))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for PaymentUri {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        Self::try_parse(s, None)
+    }
+}
+
+fn parse_amount(amount: &str) -> Result<u64> {
+    let invalid = || Error::InvalidPaymentUri(format!("invalid amount '{amount}'"));
+    if amount.is_empty() || amount.starts_with('.') || !amount.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return Err(invalid());
+    }
+    if amount.split_once('.').is_some_and(|(_, decimals)| decimals.len() > 8) {
+        return Err(invalid());
+    }
+    try_kaspa_str_to_sompi(amount).map_err(|_| invalid())?.ok_or_else(invalid)
+}
+
+fn percent_encode(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+    for byte in s.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+fn percent_decode(s: &str) -> Result<String> {
+    let invalid = || Error::InvalidPaymentUri(format!("invalid percent-encoding in '{s}'"));
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = s.get(i + 1..i + 3).ok_or_else(invalid)?;
+            decoded.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).map_err(|_| invalid())
+}
+
+#[wasm_bindgen]
+impl PaymentUri {
+    /// Create a payment URI (`amount` is in SOMPI).
+    #[wasm_bindgen(constructor)]
+    pub fn constructor(address: AddressT, amount: Option<u64>, label: Option<String>, message: Option<String>) -> Result<PaymentUri> {
+        let address = Address::try_owned_from(JsValue::from(address))?;
+        Ok(Self { address, amount, label, message })
+    }
+
+    /// Parse a `kaspa:` payment URI. If `networkId` is supplied, the
+    /// destination address must belong to the network.
+    #[wasm_bindgen(js_name = parse)]
+    pub fn parse_js(uri: &str, network_id: Option<NetworkIdT>) -> Result<PaymentUri> {
+        let network_type = network_id.map(|network_id| NetworkId::try_owned_from(JsValue::from(network_id))).transpose()?;
+        Self::try_parse(uri, network_type.map(NetworkType::from))
+    }
+
+    /// Serialize the payment URI.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string_js(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns a {@link PaymentOutput} for the requested amount. Throws if the URI does not specify the amount.
+    #[wasm_bindgen(js_name = toPaymentOutput)]
+    pub fn to_payment_output_js(&self) -> Result<PaymentOutput> {
+        self.payment_output().ok_or_else(|| Error::InvalidPaymentUri("the payment amount is not specified".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_addresses::Version;
+
+    #[test]
+    fn test_payment_uri() {
+        let address = Address::new(Prefix::Testnet, Version::PubKey, &[7u8; 32]);
+        let address_str = address.to_string();
+        let uri = PaymentUri::new(address.clone()).with_amount(150_000_000).with_label("Kaspa Café").with_message("order #1 & co");
+        let serialized = uri.to_string();
+        assert_eq!(serialized, format!("{address_str}?amount=1.5&label=Kaspa%20Caf%C3%A9&message=order%20%231%20%26%20co"));
+        assert_eq!(serialized.parse::<PaymentUri>().unwrap(), uri);
+        assert_eq!(PaymentUri::new(address.clone()).to_string(), address_str);
+
+        let uri = PaymentUri::try_parse(&format!("{address_str}?amount=0.00000001&foo=bar"), Some(NetworkType::Testnet)).unwrap();
+        assert_eq!(uri.amount, Some(1));
+        assert_eq!(uri.payment_output().unwrap().address, address);
+
+        assert!(PaymentUri::try_parse(&address_str, Some(NetworkType::Mainnet)).is_err());
+        assert!(format!("{address_str}?amount=1.000000001").parse::<PaymentUri>().is_err());
+        assert!(format!("{address_str}?amount=-1").parse::<PaymentUri>().is_err());
+        assert!(format!("{address_str}?amount=1&amount=2").parse::<PaymentUri>().is_err());
+        assert!(format!("{address_str}?req-unknown=1").parse::<PaymentUri>().is_err());
+        assert!(format!("{address_str}?label=%E").parse::<PaymentUri>().is_err());
+        assert!(address_str.replace("kaspatest:", "bitcoin:").parse::<PaymentUri>().is_err());
+    }
+}
@@ -0,0 +1,217 @@
+//!
+//! Signature hashing and signing of transaction inputs.
+//!
+//! Transactions with a large number of inputs (sweep and compound
+//! transactions can carry hundreds of inputs) are processed in parallel
+//! once the input count reaches [`PARALLEL_SIGNING_THRESHOLD`]: on native
+//! platforms the inputs are distributed across the `rayon` thread pool,
+//! while on wasm32 (single-threaded) [`sign_transaction_inputs_async()`]
+//! processes the inputs in chunks of [`SIGNING_CHUNK_SIZE`], yielding to
+//! the executor between chunks.
+//!
+
+use crate::imports::*;
+use kaspa_consensus_core::hashing::sighash::SigHashReusedValues;
+use kaspa_consensus_core::hashing::sighash_type::{SigHashType, SIG_HASH_ALL};
+use kaspa_consensus_core::sign::{InputSigningKey, Signed};
+use kaspa_consensus_core::tx::SignableTransaction;
+use std::collections::BTreeMap;
+
+/// Minimum number of transaction inputs processed in parallel.
+pub const PARALLEL_SIGNING_THRESHOLD: usize = 32;
+/// Number of inputs signed between yields to the executor (wasm32).
+pub const SIGNING_CHUNK_SIZE: usize = 16;
+
+/// Maps the `inputs` indexes using `f`, in parallel if the number
+/// of inputs reaches [`PARALLEL_SIGNING_THRESHOLD`] (native platforms).
+/// Each worker thread uses its own [`SigHashReusedValues`].
+pub(crate) fn map_inputs<T, F>(inputs: usize, f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(usize, &mut SigHashReusedValues) -> T + Send + Sync,
+{
+    #[cfg(not(target_arch = "wasm32"))]
+    if inputs >= PARALLEL_SIGNING_THRESHOLD {
+        use rayon::prelude::*;
+        return (0..inputs)
+            .into_par_iter()
+            .map_init(SigHashReusedValues::new, |reused_values, index| f(index, reused_values))
+            .collect();
+    }
+
+    let mut reused_values = SigHashReusedValues::new();
+    (0..inputs).map(|index| f(index, &mut reused_values)).collect()
+}
+
+/// Produce the signature script of the input at `index` if it is spendable by one of the `keys`.
+/// Inputs without a UTXO entry are left unsigned.
+fn sign_input(
+    transaction: &SignableTransaction,
+    keys: &BTreeMap<Vec<u8>, InputSigningKey>,
+    sighash_types: &[SigHashType],
+    index: usize,
+    reused_values: &mut SigHashReusedValues,
+) -> Option<Vec<u8>> {
+    let script = transaction.entries.get(index)?.as_ref()?.script_public_key.script();
+    keys.get(script).map(|signing_key| {
+        let sighash_type = sighash_types.get(index).copied().unwrap_or(SIG_HASH_ALL);
+        signing_key.sign(&transaction.as_verifiable(), index, sighash_type, reused_values)
+    })
+}
+
+fn apply_signature_scripts(mut transaction: SignableTransaction, signature_scripts: Vec<Option<Vec<u8>>>) -> Signed {
+    let mut additional_signatures_required = false;
+    for (input, signature_script) in transaction.tx.inputs.iter_mut().zip(signature_scripts) {
+        match signature_script {
+            Some(signature_script) => input.signature_script = signature_script,
+            None => additional_signatures_required = true,
+        }
+    }
+
+    if additional_signatures_required {
+        Signed::Partially(transaction)
+    } else {
+        Signed::Fully(transaction)
+    }
+}
+
+/// Sign the transaction inputs using schnorr or ecdsa (depending on the UTXO
+/// script), signing each input with the sighash type at the input index in
+/// `sighash_types` (inputs without an entry are signed using `SIG_HASH_ALL`).
+/// Produces the same result as [`kaspa_consensus_core::sign::sign_with_sighash_types()`],
+/// signing the inputs in parallel on native platforms (see [`PARALLEL_SIGNING_THRESHOLD`]).
+pub fn sign_transaction_inputs(transaction: SignableTransaction, privkeys: &[[u8; 32]], sighash_types: &[SigHashType]) -> Signed {
+    let keys = InputSigningKey::map_by_script(privkeys);
+    let signature_scripts = map_inputs(transaction.tx.inputs.len(), |index, reused_values| {
+        sign_input(&transaction, &keys, sighash_types, index, reused_values)
+    });
+    apply_signature_scripts(transaction, signature_scripts)
+}
+
+/// Async variant of [`sign_transaction_inputs()`]. On wasm32, transactions
+/// reaching [`PARALLEL_SIGNING_THRESHOLD`] inputs are signed in chunks of
+/// [`SIGNING_CHUNK_SIZE`] inputs, yielding to the executor between chunks
+/// to keep the runtime responsive.
+pub async fn sign_transaction_inputs_async(
+    transaction: SignableTransaction,
+    privkeys: &[[u8; 32]],
+    sighash_types: &[SigHashType],
+) -> Signed {
+    cfg_if! {
+        if #[cfg(target_arch = "wasm32")] {
+            let inputs = transaction.tx.inputs.len();
+            if inputs < PARALLEL_SIGNING_THRESHOLD {
+                return sign_transaction_inputs(transaction, privkeys, sighash_types);
+            }
+
+            let keys = InputSigningKey::map_by_script(privkeys);
+            let mut reused_values = SigHashReusedValues::new();
+            let mut signature_scripts = Vec::with_capacity(inputs);
+            for index in 0..inputs {
+                if index > 0 && index % SIGNING_CHUNK_SIZE == 0 {
+                    yield_executor().await;
+                }
+                signature_scripts.push(sign_input(&transaction, &keys, sighash_types, index, &mut reused_values));
+            }
+            apply_signature_scripts(transaction, signature_scripts)
+        } else {
+            sign_transaction_inputs(transaction, privkeys, sighash_types)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_consensus_core::sign::verify;
+    use kaspa_consensus_core::subnets::SubnetworkId;
+    use kaspa_consensus_core::tx::{ScriptVec, Transaction, TransactionInput, TransactionOutpoint, TransactionOutput, UtxoEntry};
+
+    fn pubkey_script(private_key: &[u8; 32]) -> ScriptPublicKey {
+        let keypair = secp256k1::Keypair::from_seckey_slice(secp256k1::SECP256K1, private_key).unwrap();
+        let public_key = keypair.x_only_public_key().0.serialize();
+        ScriptPublicKey::new(0, ScriptVec::from_iter(std::iter::once(0x20).chain(public_key).chain(std::iter::once(0xac))))
+    }
+
+    fn ecdsa_pubkey_script(private_key: &[u8; 32]) -> ScriptPublicKey {
+        let keypair = secp256k1::Keypair::from_seckey_slice(secp256k1::SECP256K1, private_key).unwrap();
+        let public_key = keypair.public_key().serialize();
+        ScriptPublicKey::new(0, ScriptVec::from_iter(std::iter::once(0x21).chain(public_key).chain(std::iter::once(0xab))))
+    }
+
+    fn signature_scripts(transaction: &SignableTransaction) -> Vec<Vec<u8>> {
+        transaction.tx.inputs.iter().map(|input| input.signature_script.clone()).collect()
+    }
+
+    #[test]
+    fn test_sign_transaction_inputs() {
+        let inputs = PARALLEL_SIGNING_THRESHOLD * 2 + 1;
+        let keys = (0..inputs).map(|index| [(index % 8) as u8 + 1; 32]).collect::<Vec<_>>();
+        let transaction_id = TransactionId::from_slice(&[7u8; 32]);
+        let transaction_inputs = (0..inputs)
+            .map(|index| TransactionInput::new(TransactionOutpoint::new(transaction_id, index as u32), vec![], 0, 1))
+            .collect::<Vec<_>>();
+        let entries = keys.iter().map(|key| UtxoEntry::new(100_000_000, pubkey_script(key), 1000, false)).collect::<Vec<_>>();
+        let outputs = vec![TransactionOutput::new(100_000_000, pubkey_script(&[9u8; 32]))];
+        let transaction = Transaction::new(0, transaction_inputs, outputs, 0, SubnetworkId::default(), 0, vec![]);
+        let transaction = SignableTransaction::with_entries(transaction, entries);
+
+        // signing with a subset of keys produces a partially signed transaction
+        let partial = match sign_transaction_inputs(transaction.clone(), &keys[..4], &[]) {
+            Signed::Partially(transaction) => transaction,
+            Signed::Fully(_) => panic!("expecting a partially signed transaction"),
+        };
+        let signed_inputs = partial.tx.inputs.iter().filter(|input| !input.signature_script.is_empty()).count();
+        assert_eq!(signed_inputs, (0..inputs).filter(|index| index % 8 < 4).count());
+
+        let signed = match sign_transaction_inputs(transaction, &keys[..8], &[]) {
+            Signed::Fully(transaction) => transaction,
+            Signed::Partially(_) => panic!("expecting a fully signed transaction"),
+        };
+        assert!(verify(&signed.as_verifiable()).is_ok());
+    }
+
+    #[test]
+    fn test_sign_transaction_inputs_without_entries() {
+        let key = [1u8; 32];
+        let transaction_id = TransactionId::from_slice(&[7u8; 32]);
+        let transaction_inputs = (0..PARALLEL_SIGNING_THRESHOLD)
+            .map(|index| TransactionInput::new(TransactionOutpoint::new(transaction_id, index as u32), vec![], 0, 1))
+            .collect::<Vec<_>>();
+        let outputs = vec![TransactionOutput::new(100_000_000, pubkey_script(&key))];
+        let transaction = Transaction::new(0, transaction_inputs, outputs, 0, SubnetworkId::default(), 0, vec![]);
+
+        // inputs without utxo entries are left unsigned instead of panicking
+        match sign_transaction_inputs(SignableTransaction::new(transaction), &[key], &[]) {
+            Signed::Partially(transaction) => assert!(signature_scripts(&transaction).iter().all(|script| script.is_empty())),
+            Signed::Fully(_) => panic!("expecting a partially signed transaction"),
+        }
+    }
+
+    #[test]
+    fn test_sign_transaction_inputs_ecdsa() {
+        let inputs = PARALLEL_SIGNING_THRESHOLD + 1;
+        let key = [3u8; 32];
+        let transaction_id = TransactionId::from_slice(&[7u8; 32]);
+        let transaction_inputs = (0..inputs)
+            .map(|index| TransactionInput::new(TransactionOutpoint::new(transaction_id, index as u32), vec![], 0, 1))
+            .collect::<Vec<_>>();
+        // alternate schnorr and ecdsa inputs spendable by the same key
+        let entries = (0..inputs)
+            .map(|index| {
+                let script = if index % 2 == 0 { pubkey_script(&key) } else { ecdsa_pubkey_script(&key) };
+                UtxoEntry::new(100_000_000, script, 1000, false)
+            })
+            .collect::<Vec<_>>();
+        let outputs = vec![TransactionOutput::new(100_000_000, pubkey_script(&[9u8; 32]))];
+        let transaction = Transaction::new(0, transaction_inputs, outputs, 0, SubnetworkId::default(), 0, vec![]);
+        let transaction = SignableTransaction::with_entries(transaction, entries);
+
+        let signed = match sign_transaction_inputs(transaction, &[key], &[]) {
+            Signed::Fully(transaction) => transaction,
+            Signed::Partially(_) => panic!("expecting a fully signed transaction"),
+        };
+        let status = crate::tx::verify_signable_transaction(&signed).unwrap();
+        assert_eq!(status.signed_inputs as usize, inputs);
+    }
+}
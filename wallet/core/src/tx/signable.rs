@@ -0,0 +1,269 @@
+//!
+//! Exchange of [`SignableTransaction`]s with external signers.
+//!
+//! Transactions are exchanged either as JSON produced by the SDK
+//! (`Transaction.serializeToJSON()` or `Transaction.serializeToSafeJSON()`)
+//! or as a hex-encoded Borsh serialization of the transaction and its
+//! UTXO entries. Both carry the UTXO entries required to locate the
+//! signing keys and to produce the signatures, allowing transactions
+//! to be signed by an offline (air-gapped) wallet.
+//!
+
+use crate::imports::*;
+use crate::tx::sign::map_inputs;
+use kaspa_consensus_client::{numeric, string};
+use kaspa_consensus_core::hashing::sighash::{calc_ecdsa_signature_hash, calc_schnorr_signature_hash, SigHashReusedValues};
+use kaspa_consensus_core::hashing::sighash_type::SigHashType;
+use kaspa_consensus_core::tx::{SignableTransaction, Transaction, UtxoEntry, VerifiableTransaction};
+use kaspa_hashes::Hash;
+
+/// Encoding of a serialized [`SignableTransaction`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SignableTransactionEncoding {
+    /// SDK JSON with numeric amounts (`Transaction.serializeToJSON()`)
+    Json,
+    /// SDK JSON with amounts as strings (`Transaction.serializeToSafeJSON()`)
+    SafeJson,
+    /// Hex-encoded Borsh serialization of the transaction and its UTXO entries
+    Hex,
+}
+
+impl std::fmt::Display for SignableTransactionEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            SignableTransactionEncoding::Json => "json",
+            SignableTransactionEncoding::SafeJson => "safe-json",
+            SignableTransactionEncoding::Hex => "hex",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Deserializes a [`SignableTransaction`], detecting its encoding.
+pub fn decode_signable_transaction(data: &str) -> Result<(SignableTransaction, SignableTransactionEncoding)> {
+    let data = data.trim();
+    if data.starts_with('{') {
+        if let Ok(transaction) = numeric::SerializableTransaction::deserialize_from_json(data) {
+            return Ok((transaction.try_into()?, SignableTransactionEncoding::Json));
+        }
+
+        let transaction = string::SerializableTransaction::deserialize_from_json(data)
+            .map_err(|err| Error::InvalidSignableTransaction(err.to_string()))?;
+        Ok((transaction.try_into()?, SignableTransactionEncoding::SafeJson))
+    } else {
+        let bytes = Vec::<u8>::from_hex(data).map_err(|err| Error::InvalidSignableTransaction(err.to_string()))?;
+        let (mut transaction, entries) = <(Transaction, Vec<UtxoEntry>)>::try_from_slice(&bytes)
+            .map_err(|err| Error::InvalidSignableTransaction(err.to_string()))?;
+        if transaction.inputs.len() != entries.len() {
+            return Err(Error::InvalidSignableTransaction(
+                "the number of UTXO entries does not match the number of inputs".to_string(),
+            ));
+        }
+        transaction.finalize();
+        Ok((SignableTransaction::with_entries(transaction, entries), SignableTransactionEncoding::Hex))
+    }
+}
+
+/// Serializes a [`SignableTransaction`] using the supplied encoding.
+/// All UTXO entries of the transaction must be populated.
+pub fn encode_signable_transaction(transaction: &SignableTransaction, encoding: SignableTransactionEncoding) -> Result<String> {
+    if !transaction.is_verifiable() {
+        return Err(Error::InvalidSignableTransaction("missing transaction UTXO entries".to_string()));
+    }
+
+    match encoding {
+        SignableTransactionEncoding::Json => {
+            Ok(numeric::SerializableTransaction::from_signable_transaction(transaction)?.serialize_to_json()?)
+        }
+        SignableTransactionEncoding::SafeJson => {
+            Ok(string::SerializableTransaction::from_signable_transaction(transaction)?.serialize_to_json()?)
+        }
+        SignableTransactionEncoding::Hex => {
+            let entries = transaction.entries.iter().flatten().cloned().collect::<Vec<_>>();
+            Ok(borsh::to_vec(&(&transaction.tx, &entries))?.to_hex())
+        }
+    }
+}
+
+/// Signing state of a [`SignableTransaction`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SigningStatus {
+    /// Total number of transaction inputs
+    pub inputs: u32,
+    /// Number of inputs carrying a signature
+    pub signed_inputs: u32,
+}
+
+impl SigningStatus {
+    pub fn is_fully_signed(&self) -> bool {
+        self.inputs == self.signed_inputs
+    }
+}
+
+/// Verifies the Schnorr signatures of all signed inputs spending `PubKey`
+/// UTXOs (signatures of other script types are not verified) and returns
+/// the [`SigningStatus`] of the transaction. All UTXO entries of the
+/// transaction must be populated.
+pub fn verify_signable_transaction(transaction: &SignableTransaction) -> Result<SigningStatus> {
+    if !transaction.is_verifiable() {
+        return Err(Error::InvalidSignableTransaction("missing transaction UTXO entries".to_string()));
+    }
+
+    let verifiable = transaction.as_verifiable();
+    let mut reused_values = SigHashReusedValues::new();
+    let mut signed_inputs = 0;
+    for (index, (input, entry)) in verifiable.populated_inputs().enumerate() {
+        if input.signature_script.is_empty() {
+            continue;
+        }
+        signed_inputs += 1;
+
+        // PubKey script: OP_DATA_32 <public key> OP_CHECKSIG
+        // PubKeyECDSA script: OP_DATA_33 <public key> OP_CHECKSIGECDSA
+        let script = entry.script_public_key.script();
+        let ecdsa = match script {
+            [0x20, .., 0xac] if script.len() == 34 => false,
+            [0x21, .., 0xab] if script.len() == 35 => true,
+            _ => continue,
+        };
+
+        // signature script: OP_DATA_65 <signature> <sighash type>
+        let signature = &input.signature_script;
+        if signature.len() != 66 || signature[0] != 65 {
+            return Err(Error::InvalidInputSignature(index));
+        }
+        let sighash_type = SigHashType::from_u8(signature[65]).map_err(|_| Error::InvalidInputSignature(index))?;
+
+        if ecdsa {
+            let public_key = secp256k1::PublicKey::from_slice(&script[1..34])?;
+            let signature = secp256k1::ecdsa::Signature::from_compact(&signature[1..65])?;
+            let sig_hash = calc_ecdsa_signature_hash(&verifiable, index, sighash_type, &mut reused_values);
+            let message = secp256k1::Message::from_digest_slice(sig_hash.as_bytes().as_slice())?;
+            secp256k1::SECP256K1.verify_ecdsa(&message, &signature, &public_key).map_err(|_| Error::InvalidInputSignature(index))?;
+        } else {
+            let public_key = secp256k1::XOnlyPublicKey::from_slice(&script[1..33])?;
+            let signature = secp256k1::schnorr::Signature::from_slice(&signature[1..65])?;
+            let sig_hash = calc_schnorr_signature_hash(&verifiable, index, sighash_type, &mut reused_values);
+            let message = secp256k1::Message::from_digest_slice(sig_hash.as_bytes().as_slice())?;
+            signature.verify(&message, &public_key).map_err(|_| Error::InvalidInputSignature(index))?;
+        }
+    }
+
+    Ok(SigningStatus { inputs: transaction.tx.inputs.len() as u32, signed_inputs })
+}
+
+/// Computes the Schnorr signature hashes of all inputs of the `transaction`
+/// for the given `hash_type`, allowing the signatures to be produced by an
+/// external signer. The intermediate hashes shared by the inputs are computed
+/// once per worker ([`SigHashReusedValues`]) and the inputs of large
+/// transactions are hashed in parallel on native platforms. All UTXO entries
+/// of the transaction must be populated and the inputs must carry their final
+/// `sig_op_count` as it is committed to by the signature hash.
+pub fn calc_signature_hashes(transaction: &SignableTransaction, hash_type: SigHashType) -> Result<Vec<Hash>> {
+    signature_hashes(transaction, hash_type, false)
+}
+
+/// Computes the ECDSA signature hashes of all inputs of the `transaction`
+/// (see [`calc_signature_hashes()`]).
+pub fn calc_ecdsa_signature_hashes(transaction: &SignableTransaction, hash_type: SigHashType) -> Result<Vec<Hash>> {
+    signature_hashes(transaction, hash_type, true)
+}
+
+fn signature_hashes(transaction: &SignableTransaction, hash_type: SigHashType, ecdsa: bool) -> Result<Vec<Hash>> {
+    if !transaction.is_verifiable() {
+        return Err(Error::InvalidSignableTransaction("missing transaction UTXO entries".to_string()));
+    }
+
+    let verifiable = transaction.as_verifiable();
+    let hashes = map_inputs(transaction.tx.inputs.len(), |index, reused_values| {
+        if ecdsa {
+            calc_ecdsa_signature_hash(&verifiable, index, hash_type, reused_values)
+        } else {
+            calc_schnorr_signature_hash(&verifiable, index, hash_type, reused_values)
+        }
+    });
+    Ok(hashes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_consensus_core::hashing::sighash_type::SIG_HASH_ALL;
+    use kaspa_consensus_core::sign::sign_with_multiple_v2;
+    use kaspa_consensus_core::subnets::SubnetworkId;
+    use kaspa_consensus_core::tx::{ScriptVec, TransactionInput, TransactionOutpoint, TransactionOutput};
+
+    fn pubkey_script(private_key: &[u8; 32]) -> ScriptPublicKey {
+        let keypair = secp256k1::Keypair::from_seckey_slice(secp256k1::SECP256K1, private_key).unwrap();
+        let public_key = keypair.x_only_public_key().0.serialize();
+        ScriptPublicKey::new(0, ScriptVec::from_iter(std::iter::once(0x20).chain(public_key).chain(std::iter::once(0xac))))
+    }
+
+    #[test]
+    fn test_signable_transaction_encoding() {
+        let keys = [[1u8; 32], [2u8; 32]];
+        let transaction_id = TransactionId::from_slice(&[7u8; 32]);
+        let inputs = (0..2)
+            .map(|index| TransactionInput::new(TransactionOutpoint::new(transaction_id, index), vec![], 0, 1))
+            .collect::<Vec<_>>();
+        let entries = keys.iter().map(|key| UtxoEntry::new(100_000_000, pubkey_script(key), 1000, false)).collect::<Vec<_>>();
+        let outputs = vec![TransactionOutput::new(199_990_000, pubkey_script(&[3u8; 32]))];
+        let transaction = Transaction::new(0, inputs, outputs, 0, SubnetworkId::default(), 0, vec![]);
+        let transaction = SignableTransaction::with_entries(transaction, entries);
+
+        // signing with a single key produces a partially signed transaction
+        let transaction = match sign_with_multiple_v2(transaction, &keys[..1]) {
+            kaspa_consensus_core::sign::Signed::Partially(transaction) => transaction,
+            kaspa_consensus_core::sign::Signed::Fully(_) => panic!("expecting a partially signed transaction"),
+        };
+        let status = verify_signable_transaction(&transaction).unwrap();
+        assert_eq!(status, SigningStatus { inputs: 2, signed_inputs: 1 });
+        assert!(!status.is_fully_signed());
+
+        for encoding in [SignableTransactionEncoding::Json, SignableTransactionEncoding::SafeJson, SignableTransactionEncoding::Hex] {
+            let data = encode_signable_transaction(&transaction, encoding).unwrap();
+            let (decoded, decoded_encoding) = decode_signable_transaction(&data).unwrap();
+            assert_eq!(decoded_encoding, encoding);
+            assert_eq!(decoded.id(), transaction.id());
+            assert_eq!(decoded.tx.inputs, transaction.tx.inputs);
+            assert_eq!(decoded.entries, transaction.entries);
+            assert_eq!(verify_signable_transaction(&decoded).unwrap(), status);
+        }
+
+        let mut tampered = transaction.clone();
+        tampered.tx.inputs[0].signature_script[10] ^= 0xff;
+        assert!(matches!(verify_signable_transaction(&tampered), Err(Error::InvalidInputSignature(0))));
+        assert!(decode_signable_transaction("not a transaction").is_err());
+    }
+
+    #[test]
+    fn test_signature_hashes() {
+        let transaction_id = TransactionId::from_slice(&[7u8; 32]);
+        let inputs = (0..3)
+            .map(|index| TransactionInput::new(TransactionOutpoint::new(transaction_id, index), vec![], 0, 1))
+            .collect::<Vec<_>>();
+        let entries =
+            (0..3).map(|index| UtxoEntry::new(100_000_000, pubkey_script(&[index + 1; 32]), 1000, false)).collect::<Vec<_>>();
+        let outputs = (0..2).map(|index| TransactionOutput::new(100_000_000, pubkey_script(&[index + 4; 32]))).collect::<Vec<_>>();
+        let transaction = Transaction::new(0, inputs, outputs, 0, SubnetworkId::default(), 0, vec![]);
+        let transaction = SignableTransaction::with_entries(transaction, entries);
+
+        for hash_type in [0x01, 0x02, 0x04, 0x81, 0x82, 0x84] {
+            let hash_type = SigHashType::from_u8(hash_type).unwrap();
+            let hashes = calc_signature_hashes(&transaction, hash_type).unwrap();
+            let ecdsa_hashes = calc_ecdsa_signature_hashes(&transaction, hash_type).unwrap();
+            assert_eq!(hashes.len(), 3);
+            // hashes must match the ones computed without reusing intermediate values
+            let verifiable = transaction.as_verifiable();
+            for (index, (hash, ecdsa_hash)) in hashes.iter().zip(ecdsa_hashes.iter()).enumerate() {
+                assert_eq!(*hash, calc_schnorr_signature_hash(&verifiable, index, hash_type, &mut SigHashReusedValues::new()));
+                assert_eq!(*ecdsa_hash, calc_ecdsa_signature_hash(&verifiable, index, hash_type, &mut SigHashReusedValues::new()));
+            }
+        }
+
+        let transaction = SignableTransaction::new(transaction.tx);
+        assert!(calc_signature_hashes(&transaction, SIG_HASH_ALL).is_err());
+    }
+}
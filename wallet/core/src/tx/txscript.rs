@@ -0,0 +1,162 @@
+//!
+//! Construction of custom transaction scripts.
+//!
+//! [`ScriptBuilder`] produces scripts using the canonical encoding and
+//! the script size limits of the consensus script engine. Scripts can
+//! be used directly as output script public keys or as redeem scripts
+//! of pay-to-script-hash (P2SH) outputs, which are unlocked by the
+//! signature script produced by
+//! [`ScriptBuilder::encode_pay_to_script_hash_signature_script`].
+//!
+
+use crate::imports::*;
+use kaspa_txscript::script_builder::ScriptBuilder as Builder;
+use kaspa_txscript::{pay_to_script_hash_script, pay_to_script_hash_signature_script};
+use kaspa_wallet_keys::multisig::create_multisig_redeem_script;
+
+/// Script opcodes (see the consensus `kaspa_txscript::opcodes::codes`).
+pub mod opcodes {
+    pub use kaspa_txscript::opcodes::codes::*;
+}
+
+/// Builder of custom transaction scripts.
+#[derive(Default)]
+pub struct ScriptBuilder {
+    inner: Builder,
+}
+
+impl ScriptBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a builder containing an existing script.
+    pub fn from_script(script: &[u8]) -> Result<Self> {
+        let mut builder = Self::new();
+        builder.add_ops(script)?;
+        Ok(builder)
+    }
+
+    /// Creates a builder containing a `minimum_signatures`-of-N multisig
+    /// redeem script of the supplied public keys.
+    pub fn multisig(pub_keys: &[secp256k1::PublicKey], minimum_signatures: usize, ecdsa: bool) -> Result<Self> {
+        Self::from_script(&multisig_redeem_script(pub_keys, minimum_signatures, ecdsa)?)
+    }
+
+    pub fn script(&self) -> &[u8] {
+        self.inner.script()
+    }
+
+    /// Returns the script, leaving the builder empty.
+    pub fn drain(&mut self) -> Vec<u8> {
+        self.inner.drain()
+    }
+
+    /// Pushes the opcode to the end of the script.
+    pub fn add_op(&mut self, opcode: u8) -> Result<&mut Self> {
+        self.inner.add_op(opcode)?;
+        Ok(self)
+    }
+
+    /// Pushes the opcodes (raw script bytes) to the end of the script.
+    pub fn add_ops(&mut self, opcodes: &[u8]) -> Result<&mut Self> {
+        self.inner.add_ops(opcodes)?;
+        Ok(self)
+    }
+
+    /// Pushes the data using the canonical data push opcode.
+    pub fn add_data(&mut self, data: &[u8]) -> Result<&mut Self> {
+        self.inner.add_data(data)?;
+        Ok(self)
+    }
+
+    /// Pushes the integer using the canonical encoding (small integer opcodes or a data push).
+    pub fn add_i64(&mut self, value: i64) -> Result<&mut Self> {
+        self.inner.add_i64(value)?;
+        Ok(self)
+    }
+
+    /// Pushes the lock time (DAA score or unix time in milliseconds), i.e. for `OpCheckLockTimeVerify`.
+    pub fn add_lock_time(&mut self, lock_time: u64) -> Result<&mut Self> {
+        self.inner.add_lock_time(lock_time)?;
+        Ok(self)
+    }
+
+    /// Pushes the relative lock time, i.e. for `OpCheckSequenceVerify`.
+    pub fn add_sequence(&mut self, sequence: u64) -> Result<&mut Self> {
+        self.inner.add_sequence(sequence)?;
+        Ok(self)
+    }
+
+    /// Returns the number of bytes the canonical encoding of the data push takes.
+    pub fn canonical_data_size(data: &[u8]) -> usize {
+        Builder::canonical_data_size(data)
+    }
+
+    /// Creates a P2SH script public key paying to the hash of the
+    /// script (the script being the redeem script).
+    pub fn create_pay_to_script_hash_script(&self) -> ScriptPublicKey {
+        pay_to_script_hash_script(self.script())
+    }
+
+    /// Creates the signature script spending a P2SH output of the script (the
+    /// script being the redeem script). `signature` contains the data pushes
+    /// satisfying the redeem script (i.e. the signatures, each followed by its
+    /// sighash type).
+    pub fn encode_pay_to_script_hash_signature_script(&self, signature: &[u8]) -> Result<Vec<u8>> {
+        Ok(pay_to_script_hash_signature_script(self.script().to_vec(), signature.to_vec())?)
+    }
+}
+
+/// Creates a `minimum_signatures`-of-N multisig redeem script of the supplied public keys.
+pub fn multisig_redeem_script(pub_keys: &[secp256k1::PublicKey], minimum_signatures: usize, ecdsa: bool) -> Result<Vec<u8>> {
+    Ok(create_multisig_redeem_script(pub_keys, minimum_signatures, ecdsa)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::opcodes::*;
+    use super::*;
+    use kaspa_addresses::Version;
+    use kaspa_txscript::{extract_script_pub_key_address, pay_to_address_script};
+
+    #[test]
+    fn test_script_builder() -> Result<()> {
+        // pay-to-pubkey script matches the standard address script
+        let keypair = secp256k1::Keypair::from_seckey_slice(secp256k1::SECP256K1, &[1u8; 32]).unwrap();
+        let public_key = keypair.x_only_public_key().0.serialize();
+        let mut builder = ScriptBuilder::new();
+        builder.add_data(&public_key)?.add_op(OpCheckSig)?;
+        let address = Address::new(Prefix::Testnet, Version::PubKey, &public_key);
+        assert_eq!(builder.script(), pay_to_address_script(&address).script());
+
+        // time-locked output
+        let mut builder = ScriptBuilder::new();
+        builder.add_lock_time(1_000)?.add_op(OpCheckLockTimeVerify)?.add_data(&public_key)?.add_op(OpCheckSig)?;
+        assert_eq!(&builder.script()[..4], &[OpData2, 0xe8, 0x03, OpCheckLockTimeVerify]);
+        assert_eq!(ScriptBuilder::canonical_data_size(&[0u8; 32]), 33);
+        assert!(ScriptBuilder::new().add_data(&[0u8; 521]).is_err());
+
+        // 2-of-2 multisig P2SH output and its unlocking script
+        let keys = [[1u8; 32], [2u8; 32]]
+            .iter()
+            .map(|key| secp256k1::SecretKey::from_slice(key).unwrap().public_key(secp256k1::SECP256K1))
+            .collect::<Vec<_>>();
+        let redeem_script = ScriptBuilder::multisig(&keys, 2, false)?;
+        assert_eq!(redeem_script.script()[0], Op2);
+        assert_eq!(redeem_script.script()[redeem_script.script().len() - 1], OpCheckMultiSig);
+        let script_public_key = redeem_script.create_pay_to_script_hash_script();
+        let address = extract_script_pub_key_address(&script_public_key, Prefix::Testnet)?;
+        assert_eq!(address.version, Version::ScriptHash);
+        assert_eq!(address, crate::derivation::create_multisig_address(2, keys.clone(), Prefix::Testnet, false)?);
+        assert!(ScriptBuilder::multisig(&keys, 3, false).is_err());
+
+        let mut signatures = ScriptBuilder::new();
+        signatures.add_data(&[0xaa; 65])?.add_data(&[0xbb; 65])?;
+        let signature_script = redeem_script.encode_pay_to_script_hash_signature_script(signatures.script())?;
+        assert!(signature_script.starts_with(signatures.script()));
+        assert!(signature_script.ends_with(redeem_script.script()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,321 @@
+//!
+//! [`Amount`] type providing exact (integer-based) parsing,
+//! arithmetic and formatting of KAS values.
+//!
+
+use crate::imports::*;
+use kaspa_consensus_core::constants::SOMPI_PER_KASPA;
+use std::fmt;
+
+/// Number of decimal places of a KAS value.
+const DECIMALS: usize = 8;
+
+/// Unit of an [`Amount`] representation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AmountUnit {
+    #[default]
+    Kaspa,
+    Sompi,
+}
+
+impl FromStr for AmountUnit {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "kas" | "tkas" | "skas" | "dkas" | "kaspa" => Ok(AmountUnit::Kaspa),
+            "sompi" => Ok(AmountUnit::Sompi),
+            _ => Err(Error::InvalidAmount(format!("unknown unit '{s}' (supported units: KAS, sompi)"))),
+        }
+    }
+}
+
+/// KAS value expressed in SOMPI.
+///
+/// Unlike conversions through `f64`, parsing and formatting of the
+/// `Amount` is exact for the entire `u64` range. Parsing accepts an
+/// optional unit suffix (`1.5`, `1.5 KAS`, `1.5 TKAS` or `150000000 sompi`),
+/// amounts without a suffix are interpreted as KAS.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
+)]
+#[serde(transparent)]
+pub struct Amount(u64);
+
+impl Amount {
+    pub const ZERO: Amount = Amount(0);
+    pub const MAX: Amount = Amount(u64::MAX);
+
+    pub const fn from_sompi(sompi: u64) -> Self {
+        Amount(sompi)
+    }
+
+    /// Create an amount from a whole number of KAS,
+    /// returning `None` if the value does not fit `u64` SOMPI.
+    pub const fn from_kaspa(kaspa: u64) -> Option<Self> {
+        match kaspa.checked_mul(SOMPI_PER_KASPA) {
+            Some(sompi) => Some(Amount(sompi)),
+            None => None,
+        }
+    }
+
+    pub const fn sompi(&self) -> u64 {
+        self.0
+    }
+
+    /// Approximate value in KAS, intended for floating point
+    /// calculations (such as market value estimates) only.
+    pub fn as_kaspa_f64(&self) -> f64 {
+        self.0 as f64 / SOMPI_PER_KASPA as f64
+    }
+
+    pub fn checked_add(self, other: Amount) -> Option<Amount> {
+        self.0.checked_add(other.0).map(Amount)
+    }
+
+    pub fn checked_sub(self, other: Amount) -> Option<Amount> {
+        self.0.checked_sub(other.0).map(Amount)
+    }
+
+    pub fn checked_mul(self, factor: u64) -> Option<Amount> {
+        self.0.checked_mul(factor).map(Amount)
+    }
+
+    pub fn checked_div(self, divisor: u64) -> Option<Amount> {
+        self.0.checked_div(divisor).map(Amount)
+    }
+
+    pub fn saturating_add(self, other: Amount) -> Amount {
+        Amount(self.0.saturating_add(other.0))
+    }
+
+    pub fn saturating_sub(self, other: Amount) -> Amount {
+        Amount(self.0.saturating_sub(other.0))
+    }
+
+    /// Sum of the `amounts`, returning `None` on overflow.
+    pub fn checked_sum<I: IntoIterator<Item = Amount>>(amounts: I) -> Option<Amount> {
+        amounts.into_iter().try_fold(Amount::ZERO, Amount::checked_add)
+    }
+
+    pub fn format(&self, format: &AmountFormat) -> String {
+        format.format(*self)
+    }
+}
+
+impl From<u64> for Amount {
+    fn from(sompi: u64) -> Self {
+        Amount(sompi)
+    }
+}
+
+impl From<Amount> for u64 {
+    fn from(amount: Amount) -> Self {
+        amount.0
+    }
+}
+
+/// Formats the amount in KAS without digit grouping, omitting
+/// insignificant decimal places (for example `1234.5`).
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&AmountFormat::default().format(*self))
+    }
+}
+
+impl FromStr for Amount {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        AmountFormat::default().parse(s)
+    }
+}
+
+/// [`Amount`] formatting and parsing options, allowing the representation
+/// to follow the conventions of the user locale (for example `1.234,5`
+/// using `.` as the group separator and `,` as the decimal separator).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmountFormat {
+    /// Unit of the amount representation
+    pub unit: AmountUnit,
+    /// Number of displayed decimal places (up to 8, truncating the remaining
+    /// decimals); `None` displays significant decimal places only
+    pub precision: Option<usize>,
+    /// Thousands separator, `None` disables digit grouping
+    pub group_separator: Option<char>,
+    pub decimal_separator: char,
+    /// Suffix appended to the formatted amount (such as `KAS`)
+    pub suffix: Option<String>,
+}
+
+impl Default for AmountFormat {
+    fn default() -> Self {
+        Self { unit: AmountUnit::Kaspa, precision: None, group_separator: None, decimal_separator: '.', suffix: None }
+    }
+}
+
+impl AmountFormat {
+    /// Format using `,` as the thousands separator (for example `1,234.5`).
+    pub fn separated() -> Self {
+        Self { group_separator: Some(','), ..Default::default() }
+    }
+
+    pub fn with_unit(mut self, unit: AmountUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision.min(DECIMALS));
+        self
+    }
+
+    pub fn with_separators(mut self, group_separator: Option<char>, decimal_separator: char) -> Self {
+        self.group_separator = group_separator;
+        self.decimal_separator = decimal_separator;
+        self
+    }
+
+    pub fn with_suffix<S: Into<String>>(mut self, suffix: S) -> Self {
+        self.suffix = Some(suffix.into());
+        self
+    }
+
+    /// Append the KAS suffix of the network (for example `TKAS` for testnet).
+    pub fn with_network_suffix(self, network_type: &NetworkType) -> Self {
+        self.with_suffix(super::kaspa_suffix(network_type))
+    }
+
+    pub fn format(&self, amount: Amount) -> String {
+        let sompi = amount.sompi();
+        let (integer, fraction) = match self.unit {
+            AmountUnit::Kaspa => {
+                let fraction = format!("{:0width$}", sompi % SOMPI_PER_KASPA, width = DECIMALS);
+                let fraction = match self.precision {
+                    Some(precision) => fraction[..precision.min(DECIMALS)].to_string(),
+                    None => fraction.trim_end_matches('0').to_string(),
+                };
+                (sompi / SOMPI_PER_KASPA, fraction)
+            }
+            AmountUnit::Sompi => (sompi, String::new()),
+        };
+
+        let mut text = self.group(integer);
+        if !fraction.is_empty() {
+            text.push(self.decimal_separator);
+            text.push_str(&fraction);
+        }
+        if let Some(suffix) = &self.suffix {
+            text.push(' ');
+            text.push_str(suffix);
+        }
+        text
+    }
+
+    /// Parse an amount formatted using this format's separators. A unit
+    /// suffix (`KAS`, `TKAS`, `SKAS`, `DKAS` or `sompi`) is optional and
+    /// takes precedence over the format [`unit`](Self::unit). KAS amounts
+    /// accept up to 8 decimal places.
+    pub fn parse(&self, amount: &str) -> Result<Amount> {
+        let invalid = || Error::InvalidAmount(format!("'{amount}'"));
+
+        let text = amount.trim();
+        let split = text.find(|c: char| c.is_alphabetic()).unwrap_or(text.len());
+        let (value, unit) = (text[..split].trim(), text[split..].trim());
+        let unit = if unit.is_empty() { self.unit } else { unit.parse()? };
+
+        let value = value
+            .chars()
+            .filter(|c| Some(*c) != self.group_separator)
+            .map(|c| if c == self.decimal_separator { '.' } else { c })
+            .collect::<String>();
+
+        match unit {
+            AmountUnit::Sompi => {
+                if value.is_empty() || !value.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(invalid());
+                }
+                value.parse::<u64>().map(Amount).map_err(|_| invalid())
+            }
+            AmountUnit::Kaspa => {
+                let (integer, fraction) = value.split_once('.').unwrap_or((&value, ""));
+                if (integer.is_empty() && fraction.is_empty())
+                    || fraction.len() > DECIMALS
+                    || !integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+                {
+                    return Err(invalid());
+                }
+                let integer = if integer.is_empty() { 0 } else { integer.parse::<u64>().map_err(|_| invalid())? };
+                let fraction =
+                    if fraction.is_empty() { 0 } else { format!("{fraction:0<DECIMALS$}").parse::<u64>().map_err(|_| invalid())? };
+                integer.checked_mul(SOMPI_PER_KASPA).and_then(|sompi| sompi.checked_add(fraction)).map(Amount).ok_or_else(invalid)
+            }
+        }
+    }
+
+    fn group(&self, value: u64) -> String {
+        let digits = value.to_string();
+        let Some(separator) = self.group_separator else {
+            return digits;
+        };
+        let mut text = String::with_capacity(digits.len() + digits.len() / 3);
+        for (index, digit) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index) % 3 == 0 {
+                text.push(separator);
+            }
+            text.push(digit);
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_amount_parse() -> Result<()> {
+        assert_eq!("1.5".parse::<Amount>()?.sompi(), 150_000_000);
+        assert_eq!("1.5 KAS".parse::<Amount>()?.sompi(), 150_000_000);
+        assert_eq!("1.5tkas".parse::<Amount>()?.sompi(), 150_000_000);
+        assert_eq!(".00000001".parse::<Amount>()?.sompi(), 1);
+        assert_eq!("1500000000 sompi".parse::<Amount>()?.sompi(), 1_500_000_000);
+        assert_eq!("184467440737.09551615".parse::<Amount>()?, Amount::MAX);
+
+        assert!("".parse::<Amount>().is_err());
+        assert!("1.000000001".parse::<Amount>().is_err());
+        assert!("-1".parse::<Amount>().is_err());
+        assert!("1.5 sompi".parse::<Amount>().is_err());
+        assert!("1.5 BTC".parse::<Amount>().is_err());
+        assert!("184467440737.09551616".parse::<Amount>().is_err());
+
+        let format = AmountFormat::default().with_separators(Some('.'), ',');
+        assert_eq!(format.parse("1.234,5 KAS")?.sompi(), 123_450_000_000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_amount_format() {
+        let amount = Amount::from_sompi(123_456_789_012_345);
+        assert_eq!(amount.to_string(), "1234567.89012345");
+        assert_eq!(Amount::MAX.to_string(), "184467440737.09551615");
+        assert_eq!(Amount::from_kaspa(1000).unwrap().to_string(), "1000");
+        assert_eq!(amount.format(&AmountFormat::separated()), "1,234,567.89012345");
+        assert_eq!(amount.format(&AmountFormat::separated().with_precision(2)), "1,234,567.89");
+        assert_eq!(amount.format(&AmountFormat::default().with_separators(Some('.'), ',').with_precision(3)), "1.234.567,890");
+        assert_eq!(
+            amount.format(&AmountFormat::separated().with_unit(AmountUnit::Sompi).with_suffix("sompi")),
+            "123,456,789,012,345 sompi"
+        );
+        assert_eq!(
+            Amount::from_sompi(50_000_000).format(&AmountFormat::separated().with_network_suffix(&NetworkType::Testnet)),
+            "0.5 TKAS"
+        );
+
+        let format = AmountFormat::separated().with_precision(8);
+        assert_eq!(format.parse(&amount.format(&format)).unwrap(), amount);
+
+        assert_eq!(Amount::checked_sum([Amount::MAX, Amount::from_sompi(1)]), None);
+        assert_eq!(Amount::from_sompi(1).checked_sub(Amount::from_sompi(2)), None);
+    }
+}
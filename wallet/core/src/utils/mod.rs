@@ -2,11 +2,13 @@
 //! Kaspa value formatting and parsing utilities.
 //!
 
+mod amount;
+pub use amount::*;
+
 use crate::result::Result;
 use kaspa_addresses::Address;
 use kaspa_consensus_core::constants::*;
 use kaspa_consensus_core::network::NetworkType;
-use separator::{separated_float, separated_int, separated_uint_with_output, Separatable};
 use workflow_log::style;
 
 pub fn try_kaspa_str_to_sompi<S: Into<String>>(s: S) -> Result<Option<u64>> {
@@ -16,7 +18,7 @@ pub fn try_kaspa_str_to_sompi<S: Into<String>>(s: S) -> Result<Option<u64>> {
         return Ok(None);
     }
 
-    Ok(Some(str_to_sompi(amount)?))
+    Ok(Some(amount.parse::<Amount>()?.sompi()))
 }
 
 pub fn try_kaspa_str_to_sompi_i64<S: Into<String>>(s: S) -> Result<Option<i64>> {
@@ -42,12 +44,12 @@ pub fn kaspa_to_sompi(kaspa: f64) -> u64 {
 
 #[inline]
 pub fn sompi_to_kaspa_string(sompi: u64) -> String {
-    sompi_to_kaspa(sompi).separated_string()
+    Amount::from_sompi(sompi).format(&AmountFormat::separated())
 }
 
 #[inline]
 pub fn sompi_to_kaspa_string_with_trailing_zeroes(sompi: u64) -> String {
-    separated_float!(format!("{:.8}", sompi_to_kaspa(sompi)))
+    Amount::from_sompi(sompi).format(&AmountFormat::separated().with_precision(8))
 }
 
 pub fn kaspa_suffix(network_type: &NetworkType) -> &'static str {
@@ -89,22 +91,3 @@ pub fn format_address_colors(address: &Address, range: Option<usize>) -> String
 
     format!("{prefix}:{left}:{center}:{right}")
 }
-
-fn str_to_sompi(amount: &str) -> Result<u64> {
-    let Some(dot_idx) = amount.find('.') else {
-        return Ok(amount.parse::<u64>()? * SOMPI_PER_KASPA);
-    };
-    let integer = amount[..dot_idx].parse::<u64>()? * SOMPI_PER_KASPA;
-    let decimal = &amount[dot_idx + 1..];
-    let decimal_len = decimal.len();
-    let decimal = if decimal_len == 0 {
-        0
-    } else if decimal_len <= 8 {
-        decimal.parse::<u64>()? * 10u64.pow(8 - decimal_len as u32)
-    } else {
-        // TODO - discuss how to handle values longer than 8 decimal places
-        // (reject, truncate, ceil(), etc.)
-        decimal[..8].parse::<u64>()?
-    };
-    Ok(integer + decimal)
-}
@@ -0,0 +1,342 @@
+//!
+//! Transaction acceptance time observations intended for fee research.
+//!
+//! When enabled, the [`AcceptanceCollector`] records the feerate, mass and
+//! the time-to-acceptance of transactions submitted by the wallet. It can
+//! optionally sample the node mempool, recording the same data for observed
+//! third-party transactions. Collected [`AcceptanceObservation`] records can
+//! be exported as JSON or CSV.
+//!
+
+use crate::imports::*;
+use crate::result::Result;
+use kaspa_rpc_core::RpcMempoolEntry;
+use std::collections::VecDeque;
+use workflow_core::time::unixtime_as_millis_u64;
+
+/// Maximum number of observations retained by the [`AcceptanceCollector`].
+/// Once reached, the oldest observations are discarded.
+pub const MAXIMUM_ACCEPTANCE_OBSERVATIONS: usize = 100_000;
+
+/// Minimum interval between mempool samples taken by the [`UtxoProcessor`](crate::utxo::UtxoProcessor).
+pub const MEMPOOL_SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Origin of an [`AcceptanceObservation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AcceptanceSource {
+    /// Transaction submitted by the wallet; acceptance is
+    /// detected via the wallet UTXO notifications.
+    Wallet,
+    /// Transaction observed in the node mempool; acceptance is
+    /// approximated by the transaction leaving the mempool
+    /// (evictions are indistinguishable from acceptance).
+    Mempool,
+}
+
+impl std::fmt::Display for AcceptanceSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AcceptanceSource::Wallet => write!(f, "wallet"),
+            AcceptanceSource::Mempool => write!(f, "mempool"),
+        }
+    }
+}
+
+/// Observed transaction acceptance record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptanceObservation {
+    pub transaction_id: TransactionId,
+    pub source: AcceptanceSource,
+    pub mass: u64,
+    pub fees: u64,
+    /// Fees in sompi per gram of mass
+    pub feerate: f64,
+    /// Unix time (msec) of the submission (or first observation in the mempool)
+    pub submission_time: u64,
+    /// Unix time (msec) of the acceptance
+    pub acceptance_time: u64,
+    pub submission_daa_score: u64,
+    pub acceptance_daa_score: u64,
+}
+
+impl AcceptanceObservation {
+    pub fn time_to_acceptance_msec(&self) -> u64 {
+        self.acceptance_time.saturating_sub(self.submission_time)
+    }
+}
+
+/// Export format of the collected observations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AcceptanceExportFormat {
+    Json,
+    Csv,
+}
+
+impl FromStr for AcceptanceExportFormat {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(AcceptanceExportFormat::Json),
+            "csv" => Ok(AcceptanceExportFormat::Csv),
+            _ => Err(Error::custom(format!("invalid acceptance export format: '{s}' (expected 'json' or 'csv')"))),
+        }
+    }
+}
+
+struct Submission {
+    source: AcceptanceSource,
+    mass: u64,
+    fees: u64,
+    time: u64,
+    daa_score: u64,
+}
+
+/// Opt-in collector of transaction acceptance times. The collector is owned
+/// by the [`UtxoProcessor`](crate::utxo::UtxoProcessor) and is disabled by default.
+#[derive(Default)]
+pub struct AcceptanceCollector {
+    enabled: AtomicBool,
+    observe_mempool: AtomicBool,
+    submissions: Mutex<AHashMap<TransactionId, Submission>>,
+    observations: Mutex<VecDeque<AcceptanceObservation>>,
+}
+
+impl AcceptanceCollector {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Enable or disable the collector. Disabling the collector
+    /// discards tracked submissions but retains observations.
+    pub fn enable(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+        if !enabled {
+            self.submissions.lock().unwrap().clear();
+        }
+    }
+
+    pub fn observes_mempool(&self) -> bool {
+        self.is_enabled() && self.observe_mempool.load(Ordering::Relaxed)
+    }
+
+    /// Enable or disable collection of observations for third-party mempool transactions.
+    pub fn set_observe_mempool(&self, observe_mempool: bool) {
+        self.observe_mempool.store(observe_mempool, Ordering::Relaxed);
+        if !observe_mempool {
+            self.submissions.lock().unwrap().retain(|_, submission| submission.source == AcceptanceSource::Wallet);
+        }
+    }
+
+    /// Track a transaction submitted by the wallet.
+    pub fn register_submission(&self, transaction_id: TransactionId, mass: u64, fees: u64, daa_score: u64) {
+        if self.is_enabled() {
+            let submission = Submission { source: AcceptanceSource::Wallet, mass, fees, time: unixtime_as_millis_u64(), daa_score };
+            self.submissions.lock().unwrap().insert(transaction_id, submission);
+        }
+    }
+
+    /// Stop tracking a transaction (e.g. on submission failure).
+    pub fn cancel_submission(&self, transaction_id: &TransactionId) {
+        self.submissions.lock().unwrap().remove(transaction_id);
+    }
+
+    /// Record the acceptance of a tracked transaction.
+    pub fn register_acceptance(&self, transaction_id: &TransactionId, daa_score: u64) {
+        if let Some(submission) = self.submissions.lock().unwrap().remove(transaction_id) {
+            self.push(Self::observation(*transaction_id, submission, unixtime_as_millis_u64(), daa_score));
+        }
+    }
+
+    /// Ingest a mempool snapshot. Previously unseen transactions are
+    /// tracked from the time of the snapshot, tracked mempool transactions
+    /// absent from the snapshot are considered accepted.
+    pub fn ingest_mempool_entries(&self, entries: &[RpcMempoolEntry], daa_score: u64) {
+        if !self.observes_mempool() {
+            return;
+        }
+
+        let time = unixtime_as_millis_u64();
+        let present = entries
+            .iter()
+            .filter(|entry| !entry.is_orphan)
+            .filter_map(|entry| {
+                let verbose_data = entry.transaction.verbose_data.as_ref()?;
+                let mass = if entry.transaction.mass > 0 { entry.transaction.mass } else { verbose_data.mass };
+                Some((verbose_data.transaction_id, mass, entry.fee))
+            })
+            .collect::<Vec<_>>();
+
+        let mut submissions = self.submissions.lock().unwrap();
+
+        let ids = present.iter().map(|(transaction_id, _, _)| *transaction_id).collect::<AHashSet<_>>();
+        let accepted = submissions
+            .iter()
+            .filter(|(transaction_id, submission)| submission.source == AcceptanceSource::Mempool && !ids.contains(*transaction_id))
+            .map(|(transaction_id, _)| *transaction_id)
+            .collect::<Vec<_>>();
+        let observations = accepted
+            .into_iter()
+            .filter_map(|transaction_id| {
+                submissions.remove(&transaction_id).map(|submission| Self::observation(transaction_id, submission, time, daa_score))
+            })
+            .collect::<Vec<_>>();
+
+        for (transaction_id, mass, fees) in present {
+            submissions.entry(transaction_id).or_insert(Submission { source: AcceptanceSource::Mempool, mass, fees, time, daa_score });
+        }
+        drop(submissions);
+
+        observations.into_iter().for_each(|observation| self.push(observation));
+    }
+
+    /// Number of transactions awaiting acceptance.
+    pub fn pending(&self) -> usize {
+        self.submissions.lock().unwrap().len()
+    }
+
+    pub fn observations(&self) -> Vec<AcceptanceObservation> {
+        self.observations.lock().unwrap().iter().cloned().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.observations.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.observations.lock().unwrap().is_empty()
+    }
+
+    pub fn clear(&self) {
+        self.observations.lock().unwrap().clear();
+    }
+
+    /// Serialize the collected observations in the given format.
+    pub fn export(&self, format: AcceptanceExportFormat) -> Result<String> {
+        let observations = self.observations();
+        match format {
+            AcceptanceExportFormat::Json => Ok(serde_json::to_string_pretty(&observations)?),
+            AcceptanceExportFormat::Csv => {
+                let mut csv = String::from(
+                    "transaction_id,source,mass,fees,feerate,submission_time,acceptance_time,time_to_acceptance_msec,submission_daa_score,acceptance_daa_score\n",
+                );
+                for observation in observations.iter() {
+                    csv.push_str(&format!(
+                        "{},{},{},{},{},{},{},{},{},{}\n",
+                        observation.transaction_id,
+                        observation.source,
+                        observation.mass,
+                        observation.fees,
+                        observation.feerate,
+                        observation.submission_time,
+                        observation.acceptance_time,
+                        observation.time_to_acceptance_msec(),
+                        observation.submission_daa_score,
+                        observation.acceptance_daa_score,
+                    ));
+                }
+                Ok(csv)
+            }
+        }
+    }
+
+    fn observation(transaction_id: TransactionId, submission: Submission, time: u64, daa_score: u64) -> AcceptanceObservation {
+        let Submission { source, mass, fees, time: submission_time, daa_score: submission_daa_score } = submission;
+        let feerate = if mass > 0 { fees as f64 / mass as f64 } else { 0.0 };
+        AcceptanceObservation {
+            transaction_id,
+            source,
+            mass,
+            fees,
+            feerate,
+            submission_time,
+            acceptance_time: time.max(submission_time),
+            submission_daa_score,
+            acceptance_daa_score: daa_score,
+        }
+    }
+
+    fn push(&self, observation: AcceptanceObservation) {
+        let mut observations = self.observations.lock().unwrap();
+        if observations.len() >= MAXIMUM_ACCEPTANCE_OBSERVATIONS {
+            observations.pop_front();
+        }
+        observations.push_back(observation);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_rpc_core::{RpcTransaction, RpcTransactionVerboseData};
+
+    fn mempool_entry(transaction_id: TransactionId, mass: u64, fee: u64) -> RpcMempoolEntry {
+        let verbose_data = RpcTransactionVerboseData {
+            transaction_id,
+            hash: Default::default(),
+            mass,
+            block_hash: Default::default(),
+            block_time: 0,
+        };
+        let transaction = RpcTransaction {
+            version: 0,
+            inputs: vec![],
+            outputs: vec![],
+            lock_time: 0,
+            subnetwork_id: Default::default(),
+            gas: 0,
+            payload: vec![],
+            mass: 0,
+            verbose_data: Some(verbose_data),
+        };
+        RpcMempoolEntry::new(fee, transaction, false)
+    }
+
+    #[test]
+    fn test_acceptance_collector() {
+        let collector = AcceptanceCollector::default();
+        let (a, b, c) = (TransactionId::from_u64_word(1), TransactionId::from_u64_word(2), TransactionId::from_u64_word(3));
+
+        // disabled collector ignores submissions
+        collector.register_submission(a, 2000, 4000, 10);
+        collector.register_acceptance(&a, 20);
+        assert!(collector.is_empty());
+
+        collector.enable(true);
+        collector.register_submission(a, 2000, 4000, 10);
+        collector.register_submission(b, 2000, 4000, 10);
+        collector.cancel_submission(&b);
+        collector.register_acceptance(&a, 20);
+        collector.register_acceptance(&b, 20);
+        let observations = collector.observations();
+        assert_eq!(observations.len(), 1);
+        assert_eq!(observations[0].source, AcceptanceSource::Wallet);
+        assert_eq!(observations[0].feerate, 2.0);
+        assert_eq!(observations[0].acceptance_daa_score, 20);
+
+        // mempool entries are ignored unless mempool observation is enabled
+        collector.ingest_mempool_entries(&[mempool_entry(b, 1000, 1000)], 30);
+        assert_eq!(collector.pending(), 0);
+
+        collector.set_observe_mempool(true);
+        collector.ingest_mempool_entries(&[mempool_entry(b, 1000, 1000), mempool_entry(c, 1000, 5000)], 30);
+        assert_eq!(collector.pending(), 2);
+        collector.ingest_mempool_entries(&[mempool_entry(c, 1000, 5000)], 40);
+        assert_eq!(collector.pending(), 1);
+        let observations = collector.observations();
+        assert_eq!(observations.len(), 2);
+        assert_eq!(observations[1].transaction_id, b);
+        assert_eq!(observations[1].source, AcceptanceSource::Mempool);
+        assert_eq!(observations[1].submission_daa_score, 30);
+        assert_eq!(observations[1].acceptance_daa_score, 40);
+
+        let csv = collector.export(AcceptanceExportFormat::Csv).unwrap();
+        assert_eq!(csv.lines().count(), 3);
+        let json = collector.export(AcceptanceExportFormat::Json).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<AcceptanceObservation>>(&json).unwrap().len(), 2);
+
+        collector.clear();
+        assert!(collector.is_empty());
+    }
+}
@@ -88,6 +88,41 @@ export interface IBalance {
      * have arrived.
      */
     stasisUtxoCount: number;
+    /**
+     * Total amount of Kaspa (in SOMPI) held in mature
+     * UTXOs that have been locked by the user and are
+     * excluded from spending (not included in `mature`).
+     */
+    locked: bigint;
+    /**
+     * Number of locked UTXOs (not included in `matureUtxoCount`).
+     */
+    lockedUtxoCount: number;
+    /**
+     * Total amount of Kaspa (in SOMPI) sent to the account
+     * addresses by unconfirmed transactions present in the
+     * node mempool (see {@link IPendingIncoming}). This figure
+     * is updated on request via `accountsGetPendingIncoming()`
+     * and is not included in `pending`.
+     */
+    pendingIncoming: bigint;
+}
+
+/**
+ * Amount of Kaspa sent to an address by unconfirmed transactions
+ * present in the node mempool.
+ * @category Wallet SDK
+ */
+export interface IPendingIncoming {
+    address: string;
+    /**
+     * Total amount of Kaspa (in SOMPI) sent to the address.
+     */
+    amount: bigint;
+    /**
+     * Ids of the mempool transactions sending to the address.
+     */
+    transactionIds: string[];
 }
 "#;
 
@@ -100,6 +135,14 @@ pub struct Balance {
     pub mature_utxo_count: usize,
     pub pending_utxo_count: usize,
     pub stasis_utxo_count: usize,
+    /// Amount held in locked (non-spendable) mature UTXOs.
+    #[serde(default)]
+    pub locked: u64,
+    #[serde(default)]
+    pub locked_utxo_count: usize,
+    /// Amount sent to the context addresses by unconfirmed mempool transactions.
+    #[serde(default)]
+    pub pending_incoming: u64,
     #[serde(skip)]
     mature_delta: Delta,
     #[serde(skip)]
@@ -124,9 +167,23 @@ impl Balance {
             mature_utxo_count,
             pending_utxo_count,
             stasis_utxo_count,
+            locked: 0,
+            locked_utxo_count: 0,
+            pending_incoming: 0,
         }
     }
 
+    pub fn with_locked(mut self, locked: u64, locked_utxo_count: usize) -> Self {
+        self.locked = locked;
+        self.locked_utxo_count = locked_utxo_count;
+        self
+    }
+
+    pub fn with_pending_incoming(mut self, pending_incoming: u64) -> Self {
+        self.pending_incoming = pending_incoming;
+        self
+    }
+
     pub fn is_empty(&self) -> bool {
         self.mature == 0 && self.pending == 0
     }
@@ -153,6 +210,9 @@ pub struct AtomicBalance {
     pub mature_utxos: AtomicUsize,
     pub pending_utxos: AtomicUsize,
     pub stasis_utxos: AtomicUsize,
+    pub locked: AtomicU64,
+    pub locked_utxos: AtomicUsize,
+    pub pending_incoming: AtomicU64,
 }
 
 impl AtomicBalance {
@@ -162,6 +222,9 @@ impl AtomicBalance {
         self.mature_utxos.fetch_add(balance.mature_utxo_count, Ordering::SeqCst);
         self.pending_utxos.fetch_add(balance.pending_utxo_count, Ordering::SeqCst);
         self.stasis_utxos.fetch_add(balance.stasis_utxo_count, Ordering::SeqCst);
+        self.locked.fetch_add(balance.locked, Ordering::SeqCst);
+        self.locked_utxos.fetch_add(balance.locked_utxo_count, Ordering::SeqCst);
+        self.pending_incoming.fetch_add(balance.pending_incoming, Ordering::SeqCst);
     }
 }
 
@@ -174,6 +237,9 @@ impl From<AtomicBalance> for Balance {
             mature_utxo_count: atomic_balance.mature_utxos.load(Ordering::SeqCst),
             pending_utxo_count: atomic_balance.pending_utxos.load(Ordering::SeqCst),
             stasis_utxo_count: atomic_balance.stasis_utxos.load(Ordering::SeqCst),
+            locked: atomic_balance.locked.load(Ordering::SeqCst),
+            locked_utxo_count: atomic_balance.locked_utxos.load(Ordering::SeqCst),
+            pending_incoming: atomic_balance.pending_incoming.load(Ordering::SeqCst),
             mature_delta: Delta::default(),
             pending_delta: Delta::default(),
         }
@@ -183,6 +249,8 @@ impl From<AtomicBalance> for Balance {
 pub struct BalanceStrings {
     pub mature: String,
     pub pending: Option<String>,
+    pub locked: Option<String>,
+    pub incoming: Option<String>,
 }
 
 impl From<(Option<&Balance>, &NetworkType, Option<usize>)> for BalanceStrings {
@@ -191,6 +259,9 @@ impl From<(Option<&Balance>, &NetworkType, Option<usize>)> for BalanceStrings {
         if let Some(balance) = balance {
             let mut mature = utils::sompi_to_kaspa_string(balance.mature);
             let mut pending = if balance.pending > 0 { Some(utils::sompi_to_kaspa_string(balance.pending)) } else { None };
+            let locked = if balance.locked > 0 { Some(utils::sompi_to_kaspa_string(balance.locked)) } else { None };
+            let incoming =
+                if balance.pending_incoming > 0 { Some(utils::sompi_to_kaspa_string(balance.pending_incoming)) } else { None };
             if let Some(padding) = padding {
                 mature = mature.pad_to_width(padding);
                 pending = pending.map(|pending| pending.pad_to_width(padding));
@@ -198,19 +269,37 @@ impl From<(Option<&Balance>, &NetworkType, Option<usize>)> for BalanceStrings {
             Self {
                 mature: format!("{} {}", balance.mature_delta.style(&mature, DeltaStyle::Mature), suffix),
                 pending: pending.map(|pending| format!("{} {}", balance.pending_delta.style(&pending, DeltaStyle::Pending), suffix)),
+                locked: locked.map(|locked| format!("{} {}", style(locked).dim(), suffix)),
+                incoming: incoming.map(|incoming| format!("{} {}", style(incoming).dim(), suffix)),
             }
         } else {
-            Self { mature: format!("N/A {suffix}"), pending: None }
+            Self { mature: format!("N/A {suffix}"), pending: None, locked: None, incoming: None }
         }
     }
 }
 
 impl std::fmt::Display for BalanceStrings {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.mature)?;
         if let Some(pending) = &self.pending {
-            write!(f, "{} ({} pending)", self.mature, pending)
-        } else {
-            write!(f, "{}", self.mature)
+            write!(f, " ({} pending)", pending)?;
+        }
+        if let Some(locked) = &self.locked {
+            write!(f, " ({} locked)", locked)?;
+        }
+        if let Some(incoming) = &self.incoming {
+            write!(f, " ({} incoming)", incoming)?;
         }
+        Ok(())
     }
 }
+
+/// Amount sent to an address by unconfirmed transactions present
+/// in the node mempool (see [`UtxoContext::update_pending_incoming`]).
+#[derive(Debug, Clone, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingIncoming {
+    pub address: Address,
+    pub amount: u64,
+    pub transaction_ids: Vec<TransactionId>,
+}
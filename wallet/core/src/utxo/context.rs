@@ -7,15 +7,19 @@
 use crate::encryption::sha256_hash;
 use crate::events::Events;
 use crate::imports::*;
+use crate::logging::log;
 use crate::result::Result;
 use crate::storage::TransactionRecord;
 use crate::tx::PendingTransaction;
+use crate::utxo::usage::AddressUsageMap;
 use crate::utxo::{
-    Maturity, NetworkParams, OutgoingTransaction, PendingUtxoEntryReference, UtxoContextBinding, UtxoEntryId, UtxoEntryReference,
-    UtxoEntryReferenceExtension, UtxoProcessor,
+    AddressUsage, Maturity, OutgoingTransaction, PaymentRequest, PaymentRequestId, PendingUtxoEntryReference, UtxoContextBinding,
+    UtxoEntryDescriptor, UtxoEntryId, UtxoEntryReference, UtxoEntryReferenceExtension, UtxoProcessor, UtxoSnapshot, UtxoStatus,
 };
 use kaspa_hashes::Hash;
+use kaspa_txscript::pay_to_address_script;
 use sorted_insert::SortedInsertBinaryByKey;
+use workflow_core::time::unixtime_as_millis_u64;
 
 static UTXO_CONTEXT_ID_SEQUENCER: AtomicU64 = AtomicU64::new(0);
 fn next_utxo_context_id() -> Hash {
@@ -79,6 +83,17 @@ pub enum UtxoEntryVariant {
     Stasis(UtxoEntryReference),
 }
 
+/// UTXO entries of a [`UtxoContext`] captured by [`UtxoContext::checkpoint`].
+pub struct UtxoContextCheckpoint {
+    mature: Vec<UtxoEntryReference>,
+    pending: AHashMap<UtxoEntryId, UtxoEntryReference>,
+    stasis: AHashMap<UtxoEntryId, UtxoEntryReference>,
+    map: AHashMap<UtxoEntryId, UtxoEntryReference>,
+    outgoing: AHashMap<TransactionId, OutgoingTransaction>,
+    pending_incoming: AHashMap<TransactionId, u64>,
+    addresses: Vec<Address>,
+}
+
 pub struct Context {
     /// Mature (Confirmed) UTXOs
     pub(crate) mature: Vec<UtxoEntryReference>,
@@ -96,6 +111,21 @@ pub struct Context {
     balance: Option<Balance>,
     /// Addresses monitored by this UTXO context
     addresses: Arc<DashSet<Arc<Address>>>,
+    /// UTXOs locked by the user (excluded from spending). Locks are
+    /// retained when the context is cleared (e.g. on reconnect).
+    pub(crate) locked: AHashSet<UtxoEntryId>,
+    /// User-assigned UTXO labels (retained when the context is cleared).
+    pub(crate) labels: AHashMap<UtxoEntryId, String>,
+    /// Amounts sent to the monitored addresses by unconfirmed mempool
+    /// transactions, retained until the transaction UTXOs are received.
+    pub(crate) pending_incoming: AHashMap<TransactionId, u64>,
+    /// Payment requests awaiting (or fulfilled by) incoming
+    /// transactions (retained when the context is cleared).
+    pub(crate) payment_requests: AHashMap<PaymentRequestId, PaymentRequest>,
+    /// Set when the payment requests have changed since they were last persisted.
+    payment_requests_changed: bool,
+    /// Activity observed on the monitored addresses (retained when the context is cleared).
+    pub(crate) usage: AddressUsageMap,
 }
 
 impl Default for Context {
@@ -108,13 +138,20 @@ impl Default for Context {
             outgoing: AHashMap::default(),
             balance: None,
             addresses: Arc::new(DashSet::new()),
+            locked: AHashSet::default(),
+            labels: AHashMap::default(),
+            pending_incoming: AHashMap::default(),
+            payment_requests: AHashMap::default(),
+            payment_requests_changed: false,
+            usage: AddressUsageMap::default(),
         }
     }
 }
 
 impl Context {
     fn new_with_mature(mature: Vec<UtxoEntryReference>) -> Self {
-        Self { mature, ..Default::default() }
+        let map = mature.iter().map(|entry| (entry.id(), entry.clone())).collect();
+        Self { mature, map, ..Default::default() }
     }
 
     pub fn clear(&mut self) {
@@ -124,25 +161,48 @@ impl Context {
         self.pending.clear();
         self.outgoing.clear();
         self.addresses.clear();
+        self.pending_incoming.clear();
         self.balance = None;
     }
 }
 
+/// Balance event throttling state (see [`UtxoProcessor::set_balance_event_throttle`]).
+#[derive(Default)]
+struct BalanceThrottle {
+    /// Time of the last emitted balance event
+    last_emitted: Option<Instant>,
+    /// Deferred (coalesced) balance event is scheduled
+    scheduled: bool,
+}
+
 struct Inner {
     id: UtxoContextId,
     binding: UtxoContextBinding,
     context: Mutex<Context>,
     processor: UtxoProcessor,
+    balance_throttle: Mutex<BalanceThrottle>,
 }
 
 impl Inner {
     pub fn new(processor: &UtxoProcessor, binding: UtxoContextBinding) -> Self {
-        Self { id: binding.id(), binding, context: Mutex::new(Context::default()), processor: processor.clone() }
+        Self {
+            id: binding.id(),
+            binding,
+            context: Mutex::new(Context::default()),
+            processor: processor.clone(),
+            balance_throttle: Mutex::new(BalanceThrottle::default()),
+        }
     }
 
     pub fn new_with_mature_entries(processor: &UtxoProcessor, binding: UtxoContextBinding, mature: Vec<UtxoEntryReference>) -> Self {
         let context = Context::new_with_mature(mature);
-        Self { id: binding.id(), binding, context: Mutex::new(context), processor: processor.clone() }
+        Self {
+            id: binding.id(),
+            binding,
+            context: Mutex::new(context),
+            processor: processor.clone(),
+            balance_throttle: Mutex::new(BalanceThrottle::default()),
+        }
     }
 }
 
@@ -214,6 +274,254 @@ impl UtxoContext {
         self.context().addresses.clone()
     }
 
+    /// Distinct addresses of the UTXO entries held by the context.
+    pub fn utxo_addresses(&self) -> Vec<Address> {
+        let context = self.context();
+        let addresses = context.map.values().filter_map(|entry| entry.utxo.address.clone()).collect::<AHashSet<_>>();
+        addresses.into_iter().collect()
+    }
+
+    /// Returns `true` if the UTXO identified by `id` is locked.
+    pub fn is_locked(&self, id: &UtxoEntryId) -> bool {
+        self.context().locked.contains(id)
+    }
+
+    /// Returns the list of locked UTXO outpoints.
+    pub fn locked(&self) -> Vec<UtxoEntryId> {
+        self.context().locked.iter().cloned().collect()
+    }
+
+    /// Lock the UTXOs identified by `outpoints`, preventing them from being
+    /// used in transactions. Locked UTXOs are reported separately in the
+    /// [`Balance`]. The outpoints do not need to be present in the context.
+    pub async fn lock(&self, outpoints: &[UtxoEntryId]) -> Result<()> {
+        let update_balance = {
+            let mut context = self.context();
+            context.locked.extend(outpoints.iter().cloned());
+            context.balance.is_some()
+        };
+
+        if update_balance {
+            self.update_balance().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Unlock the UTXOs identified by `outpoints`, making them available for spending.
+    pub async fn unlock(&self, outpoints: &[UtxoEntryId]) -> Result<()> {
+        let update_balance = {
+            let mut context = self.context();
+            outpoints.iter().for_each(|outpoint| {
+                context.locked.remove(outpoint);
+            });
+            context.balance.is_some()
+        };
+
+        if update_balance {
+            self.update_balance().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the label assigned to the UTXO identified by `id`.
+    pub fn label(&self, id: &UtxoEntryId) -> Option<String> {
+        self.context().labels.get(id).cloned()
+    }
+
+    /// Returns the list of labeled UTXO outpoints.
+    pub fn labels(&self) -> Vec<(UtxoEntryId, String)> {
+        self.context().labels.iter().map(|(id, label)| (id.clone(), label.clone())).collect()
+    }
+
+    /// Assign `label` to the UTXOs identified by `outpoints`.
+    /// Supplying `None` (or an empty label) removes the label.
+    pub fn set_label(&self, outpoints: &[UtxoEntryId], label: Option<String>) {
+        let mut context = self.context();
+        match label.filter(|label| !label.is_empty()) {
+            Some(label) => outpoints.iter().for_each(|outpoint| {
+                context.labels.insert(outpoint.clone(), label.clone());
+            }),
+            None => outpoints.iter().for_each(|outpoint| {
+                context.labels.remove(outpoint);
+            }),
+        }
+    }
+
+    /// Returns the activity observed on the `address`
+    /// (`None` if the address has not been used).
+    pub fn address_usage(&self, address: &Address) -> Option<AddressUsage> {
+        self.context().usage.get(address).cloned()
+    }
+
+    /// Returns the activity observed on all used addresses.
+    pub fn addresses_usage(&self) -> Vec<AddressUsage> {
+        self.context().usage.to_vec()
+    }
+
+    /// Restore the address usage (persisted in the account metadata).
+    pub(crate) fn extend_address_usage(&self, usage: Vec<AddressUsage>) {
+        self.context().usage.extend(usage);
+    }
+
+    /// Returns `true` if the address usage has changed since the last call.
+    pub(crate) fn take_address_usage_changed(&self) -> bool {
+        self.context().usage.take_changed()
+    }
+
+    /// Register a payment request. The request is fulfilled when a transaction
+    /// sending at least the requested amount to the request address is received,
+    /// emitting [`Events::PaymentReceived`]. The request address should be
+    /// monitored by this context.
+    pub fn register_payment_request(&self, request: PaymentRequest) {
+        let mut context = self.context();
+        context.payment_requests.insert(request.id, request);
+        context.payment_requests_changed = true;
+    }
+
+    /// Restore the payment requests (persisted in the account metadata).
+    pub(crate) fn extend_payment_requests(&self, requests: Vec<PaymentRequest>) {
+        self.context().payment_requests.extend(requests.into_iter().map(|request| (request.id, request)));
+    }
+
+    /// Returns `true` if the payment requests have changed since the last call.
+    pub(crate) fn take_payment_requests_changed(&self) -> bool {
+        std::mem::take(&mut self.context().payment_requests_changed)
+    }
+
+    /// Update the expiry of the pending payment requests and prune the requests
+    /// settled for longer than [`PAYMENT_REQUEST_RETENTION`](crate::utxo::PAYMENT_REQUEST_RETENTION).
+    fn update_payment_requests(context: &mut Context) {
+        let now = unixtime_as_millis_u64();
+        let len = context.payment_requests.len();
+        context.payment_requests.retain(|_, request| {
+            request.update_expiry(now);
+            !request.is_prunable(now)
+        });
+        if context.payment_requests.len() != len {
+            context.payment_requests_changed = true;
+        }
+    }
+
+    /// Returns the payment request identified by `id`.
+    pub fn payment_request(&self, id: &PaymentRequestId) -> Option<PaymentRequest> {
+        let mut context = self.context();
+        Self::update_payment_requests(&mut context);
+        context.payment_requests.get(id).cloned()
+    }
+
+    /// Returns all payment requests registered with this context ordered by creation time.
+    pub fn payment_requests(&self) -> Vec<PaymentRequest> {
+        let mut context = self.context();
+        Self::update_payment_requests(&mut context);
+        let mut requests = context.payment_requests.values().cloned().collect::<Vec<_>>();
+        requests.sort_by_key(|request| request.created);
+        requests
+    }
+
+    /// Remove the payment request identified by `id`.
+    pub fn remove_payment_request(&self, id: &PaymentRequestId) -> Option<PaymentRequest> {
+        let mut context = self.context();
+        let request = context.payment_requests.remove(id);
+        context.payment_requests_changed |= request.is_some();
+        request
+    }
+
+    /// Fulfill pending payment requests matching the amounts received by the
+    /// transaction `txid` and emit [`Events::PaymentReceived`] for each.
+    async fn handle_payment_requests(&self, txid: TransactionId, utxos: &[UtxoEntryReference]) -> Result<()> {
+        let fulfilled = {
+            let mut context = self.context();
+            if context.payment_requests.is_empty() {
+                return Ok(());
+            }
+
+            let mut received = AHashMap::<&Address, u64>::default();
+            for utxo in utxos.iter() {
+                if let Some(address) = utxo.utxo.address.as_ref() {
+                    *received.entry(address).or_default() += utxo.amount();
+                }
+            }
+
+            let now = unixtime_as_millis_u64();
+            let fulfilled = context
+                .payment_requests
+                .values_mut()
+                .filter_map(|request| {
+                    let amount = received.get(&request.address).copied().unwrap_or_default();
+                    (amount > 0 && request.try_fulfill(txid, amount, now)).then_some(request.id)
+                })
+                .collect::<Vec<_>>();
+            context.payment_requests_changed |= !fulfilled.is_empty();
+            fulfilled
+        };
+
+        for request_id in fulfilled.into_iter() {
+            self.processor().notify(Events::PaymentReceived { request_id, txid }).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns descriptors of all UTXO entries held by this context
+    /// (mature, pending and stasis) for use in coin control.
+    pub fn utxo_descriptors(&self) -> Vec<UtxoEntryDescriptor> {
+        let context = self.context();
+        let descriptor = |entry: &UtxoEntryReference, status: UtxoStatus| {
+            let status = if context.locked.contains(entry.id_as_ref()) { UtxoStatus::Locked } else { status };
+            UtxoEntryDescriptor::new(entry, status, context.labels.get(entry.id_as_ref()).cloned())
+        };
+
+        let mature = context.mature.iter().map(|entry| descriptor(entry, UtxoStatus::Spendable));
+        let pending = context.pending.values().map(|entry| descriptor(entry, UtxoStatus::Pending));
+        let stasis = context.stasis.values().map(|entry| descriptor(entry, UtxoStatus::Stasis));
+        mature.chain(pending).chain(stasis).collect()
+    }
+
+    /// Returns the UTXO entry identified by the transaction id and output
+    /// index if the entry is held by this context (mature, pending or stasis).
+    pub fn get_entry_by_outpoint(&self, transaction_id: &TransactionId, index: u32) -> Option<UtxoEntryReference> {
+        self.context().map.get(&UtxoEntryId::new(*transaction_id, index)).cloned()
+    }
+
+    /// Returns UTXO entries identified by `outpoints`. The resulting list
+    /// matches the order of the supplied outpoints, containing `None` for
+    /// outpoints that are not held by this context.
+    pub fn get_entries_by_outpoints(&self, outpoints: &[UtxoEntryId]) -> Vec<Option<UtxoEntryReference>> {
+        let context = self.context();
+        outpoints.iter().map(|outpoint| context.map.get(outpoint).cloned()).collect()
+    }
+
+    /// Resolve UTXO entries identified by `outpoints` for spending (coin control).
+    /// Fails if any of the entries is not present in the context, is not
+    /// mature, is locked, or is supplied more than once.
+    pub fn get_spendable_entries(&self, outpoints: &[UtxoEntryId]) -> Result<Vec<UtxoEntryReference>> {
+        let context = self.context();
+        let mut unique = AHashSet::with_capacity(outpoints.len());
+        outpoints
+            .iter()
+            .map(|outpoint| {
+                if !unique.insert(outpoint) {
+                    Err(Error::DuplicateUtxoEntry)
+                } else if context.locked.contains(outpoint) {
+                    Err(Error::UtxoNotSpendable(outpoint.clone(), UtxoStatus::Locked))
+                } else if context.pending.contains_key(outpoint) {
+                    Err(Error::UtxoNotSpendable(outpoint.clone(), UtxoStatus::Pending))
+                } else if context.stasis.contains_key(outpoint) {
+                    Err(Error::UtxoNotSpendable(outpoint.clone(), UtxoStatus::Stasis))
+                } else {
+                    context
+                        .mature
+                        .iter()
+                        .find(|entry| entry.id_as_ref() == outpoint)
+                        .cloned()
+                        .ok_or_else(|| Error::UtxoNotFound(outpoint.clone()))
+                }
+            })
+            .collect()
+    }
+
     pub async fn clear(&self) -> Result<()> {
         let local = self.addresses();
         let addresses = local.iter().map(|v| v.clone()).collect::<Vec<_>>();
@@ -227,7 +535,79 @@ impl UtxoContext {
         Ok(())
     }
 
+    /// Capture the UTXO entries and the monitored addresses of this context,
+    /// allowing the context to be restored using [`UtxoContext::rollback`]
+    /// if an address scan is interrupted.
+    pub fn checkpoint(&self) -> UtxoContextCheckpoint {
+        let context = self.context();
+        UtxoContextCheckpoint {
+            mature: context.mature.clone(),
+            pending: context.pending.clone(),
+            stasis: context.stasis.clone(),
+            map: context.map.clone(),
+            outgoing: context.outgoing.clone(),
+            pending_incoming: context.pending_incoming.clone(),
+            addresses: context.addresses.iter().map(|address| address.as_ref().clone()).collect(),
+        }
+    }
+
+    /// Restore the UTXO entries captured by [`UtxoContext::checkpoint`],
+    /// discarding entries obtained since (e.g. by a partial address scan).
+    /// Addresses registered since the checkpoint are unregistered and
+    /// addresses monitored at the time of the checkpoint are re-registered.
+    pub async fn rollback(&self, checkpoint: UtxoContextCheckpoint) -> Result<()> {
+        let UtxoContextCheckpoint { mature, pending, stasis, map, outgoing, pending_incoming, addresses } = checkpoint;
+
+        {
+            let mut context = self.context();
+            for id in context.map.keys().filter(|id| !map.contains_key(*id)) {
+                self.processor().pending().remove(id);
+                self.processor().stasis().remove(id);
+            }
+            context.mature = mature;
+            context.pending = pending;
+            context.stasis = stasis;
+            context.map = map;
+            context.outgoing = outgoing;
+            context.pending_incoming = pending_incoming;
+        }
+
+        let retained = addresses.iter().collect::<AHashSet<_>>();
+        let registered = self
+            .addresses()
+            .iter()
+            .filter(|address| !retained.contains(address.as_ref()))
+            .map(|address| address.as_ref().clone())
+            .collect::<Vec<_>>();
+        if !registered.is_empty() {
+            self.unregister_addresses(registered).await?;
+        }
+
+        if !addresses.is_empty() {
+            self.register_addresses(&addresses).await?;
+        }
+
+        self.update_balance_immediate().await?;
+
+        Ok(())
+    }
+
+    /// Recalculate the balance and emit [`Events::Balance`]. If balance event
+    /// throttling is enabled, events emitted within the throttle interval
+    /// are coalesced and only the latest balance is emitted once the
+    /// interval elapses.
     pub async fn update_balance(&self) -> Result<Balance> {
+        self.update_balance_impl(false).await
+    }
+
+    /// Recalculate the balance and emit [`Events::Balance`] immediately,
+    /// bypassing balance event throttling. This should be used to
+    /// report final states (e.g. upon completion of an address scan).
+    pub async fn update_balance_immediate(&self) -> Result<Balance> {
+        self.update_balance_impl(true).await
+    }
+
+    async fn update_balance_impl(&self, flush: bool) -> Result<Balance> {
         let balance = {
             let previous_balance = self.balance();
             let mut balance = self.calculate_balance().await;
@@ -236,11 +616,63 @@ impl UtxoContext {
             context.balance.replace(balance.clone());
             balance
         };
+
+        if let Some(interval) = self.processor().balance_event_throttle().filter(|_| !flush) {
+            let delay = {
+                let mut throttle = self.inner.balance_throttle.lock().unwrap();
+                if throttle.scheduled {
+                    // a deferred event will pick up the latest balance
+                    return Ok(balance);
+                }
+                match throttle.last_emitted.map(|last| last.elapsed()) {
+                    Some(elapsed) if elapsed < interval => {
+                        throttle.scheduled = true;
+                        Some(interval - elapsed)
+                    }
+                    _ => {
+                        throttle.last_emitted = Some(Instant::now());
+                        None
+                    }
+                }
+            };
+
+            if let Some(delay) = delay {
+                let this = self.clone();
+                spawn(async move {
+                    sleep(delay).await;
+                    if let Err(err) = this.emit_deferred_balance().await {
+                        log!(Utxo, Error, "UtxoContext: unable to emit balance event: {err}");
+                    }
+                });
+                return Ok(balance);
+            }
+        } else {
+            let mut throttle = self.inner.balance_throttle.lock().unwrap();
+            throttle.last_emitted = Some(Instant::now());
+            throttle.scheduled = false;
+        }
+
         self.processor().notify(Events::Balance { balance: Some(balance.clone()), id: self.id() }).await?;
 
         Ok(balance)
     }
 
+    /// Emit the latest balance for a previously coalesced balance update.
+    async fn emit_deferred_balance(&self) -> Result<()> {
+        {
+            let mut throttle = self.inner.balance_throttle.lock().unwrap();
+            if !throttle.scheduled {
+                // flushed in the meantime
+                return Ok(());
+            }
+            throttle.scheduled = false;
+            throttle.last_emitted = Some(Instant::now());
+        }
+
+        let balance = self.balance();
+        self.processor().notify(Events::Balance { balance, id: self.id() }).await
+    }
+
     /// Process pending transaction. Remove mature UTXO entries and add them to the consumed set.
     /// Produces a notification on the even multiplexer.
     pub(crate) async fn register_outgoing_transaction(&self, pending_tx: &PendingTransaction) -> Result<()> {
@@ -254,6 +686,12 @@ impl UtxoContext {
 
             let outgoing_transaction = OutgoingTransaction::new(current_daa_score, self.clone(), pending_tx.clone());
             self.processor().register_outgoing_transaction(outgoing_transaction.clone());
+            self.processor().acceptance_collector().register_submission(
+                pending_tx.id(),
+                pending_tx.mass(),
+                pending_tx.fees(),
+                current_daa_score,
+            );
             context.outgoing.insert(outgoing_transaction.id(), outgoing_transaction);
         }
 
@@ -298,7 +736,7 @@ impl UtxoContext {
             if force_maturity {
                 context.mature.sorted_insert_binary_asc_by_key(utxo_entry.clone(), |entry| entry.amount_as_ref());
             } else {
-                let params = NetworkParams::from(self.processor().network_id()?);
+                let params = self.processor().network_params()?;
                 match utxo_entry.maturity(&params, current_daa_score) {
                     Maturity::Stasis => {
                         context.stasis.insert(utxo_entry.id().clone(), utxo_entry.clone());
@@ -319,11 +757,44 @@ impl UtxoContext {
             }
             Ok(())
         } else {
-            log_warn!("ignoring duplicate utxo entry");
+            log!(Utxo, Warn, "ignoring duplicate utxo entry");
             Ok(())
         }
     }
 
+    /// Export the mature and pending UTXO entries as a [`UtxoSnapshot`].
+    pub fn to_snapshot(&self) -> UtxoSnapshot {
+        let context = self.context();
+        let entries = context.mature.iter().chain(context.pending.values()).cloned().collect::<Vec<_>>();
+        UtxoSnapshot::new(self.processor().network_id().ok(), self.processor().current_daa_score(), &entries)
+    }
+
+    /// Insert UTXO entries of a [`UtxoSnapshot`] (typically exported by an online
+    /// wallet), allowing transactions to be created offline. The entry maturity
+    /// is determined using the current DAA score falling back to the snapshot
+    /// DAA score; if neither is known, all entries are considered mature.
+    pub async fn extend_from_snapshot(&self, snapshot: &UtxoSnapshot) -> Result<()> {
+        if let (Some(snapshot_network_id), Ok(network_id)) = (snapshot.network_id, self.processor().network_id()) {
+            if snapshot_network_id != network_id {
+                return Err(Error::InvalidUtxoSnapshot(format!(
+                    "snapshot network {snapshot_network_id} does not match the network {network_id}"
+                )));
+            }
+        }
+
+        let current_daa_score = self.processor().current_daa_score().or(snapshot.daa_score);
+        for utxo_entry in snapshot.entries() {
+            match current_daa_score {
+                Some(current_daa_score) => self.insert(utxo_entry, current_daa_score, false).await?,
+                None => self.insert(utxo_entry, 0, true).await?,
+            }
+        }
+
+        self.update_balance().await?;
+
+        Ok(())
+    }
+
     pub async fn remove(&self, utxos: Vec<UtxoEntryReference>) -> Result<Vec<UtxoEntryVariant>> {
         let mut context = self.context();
         let mut removed = vec![];
@@ -336,18 +807,18 @@ impl UtxoContext {
                 if let Some(pending) = context.pending.remove(&id) {
                     removed.push(UtxoEntryVariant::Pending(pending));
                     if self.processor().pending().remove(&id).is_none() {
-                        log_error!("Error: unable to remove utxo entry from global pending (with context)");
+                        log!(Utxo, Error, "Error: unable to remove utxo entry from global pending (with context)");
                     }
                 } else if let Some(stasis) = context.stasis.remove(&id) {
                     removed.push(UtxoEntryVariant::Stasis(stasis));
                     if self.processor().stasis().remove(&id).is_none() {
-                        log_error!("Error: unable to remove utxo entry from global pending (with context)");
+                        log!(Utxo, Error, "Error: unable to remove utxo entry from global pending (with context)");
                     }
                 } else {
                     remove_mature_ids.push(id);
                 }
             } else {
-                log_error!("Error: UTXO not found in UtxoContext map!");
+                log!(Utxo, Error, "Error: UTXO not found in UtxoContext map!");
             }
         }
 
@@ -373,7 +844,7 @@ impl UtxoContext {
                 if context.pending.remove(utxo_entry.id_as_ref()).is_some() {
                     context.mature.sorted_insert_binary_asc_by_key(utxo_entry.clone(), |entry| entry.amount_as_ref());
                 } else {
-                    log_error!("Error: non-pending utxo promotion!");
+                    log!(Utxo, Error, "Error: non-pending utxo promotion!");
                     unreachable!("Error: non-pending utxo promotion!");
                 }
             }
@@ -399,7 +870,7 @@ impl UtxoContext {
                 if context.stasis.remove(utxo_entry.id_as_ref()).is_some() {
                     context.pending.insert(utxo_entry.id(), utxo_entry.clone());
                 } else {
-                    log_error!("Error: non-stasis utxo revival!");
+                    log!(Utxo, Error, "Error: non-stasis utxo revival!");
                     panic!("Error: non-stasis utxo revival!");
                 }
             }
@@ -416,14 +887,84 @@ impl UtxoContext {
         context.outgoing.remove(txid)
     }
 
+    /// Handle an outgoing transaction whose acceptance has been reverted by a chain
+    /// reorganization. Returns UTXO entries consumed by the transaction (that have not
+    /// been removed from the context since) back to the mature pool and posts the
+    /// [`Events::ReorgedOut`] notification for the originating and destination contexts.
+    pub(crate) async fn handle_outgoing_reorg(&self, outgoing_transaction: &OutgoingTransaction) -> Result<()> {
+        {
+            let mut context = self.context();
+            context.outgoing.remove(&outgoing_transaction.id());
+            for (id, entry) in outgoing_transaction.utxo_entries().iter() {
+                if context.map.contains_key(id)
+                    && !context.pending.contains_key(id)
+                    && !context.mature.iter().any(|mature| mature.id_as_ref() == id)
+                {
+                    context.mature.sorted_insert_binary_asc_by_key(entry.clone(), |entry| entry.amount_as_ref());
+                }
+            }
+        }
+
+        for mut record in self.outgoing_transaction_records(outgoing_transaction)? {
+            record.set_reorged(true);
+            self.processor().notify(Events::ReorgedOut { record }).await?;
+        }
+
+        self.update_balance().await?;
+
+        Ok(())
+    }
+
+    /// Handle an outgoing transaction reverted by a chain reorganization (see
+    /// [`UtxoContext::handle_outgoing_reorg`]) that has been accepted again.
+    /// Removes UTXO entries consumed by the transaction from the mature pool,
+    /// registers the transaction as outgoing and posts the [`Events::Reaccepted`]
+    /// notification for the originating and destination contexts.
+    pub(crate) async fn handle_outgoing_reacceptance(&self, outgoing_transaction: &OutgoingTransaction) -> Result<()> {
+        {
+            let mut context = self.context();
+            let utxo_entries = outgoing_transaction.utxo_entries();
+            context.mature.retain(|entry| !utxo_entries.contains_key(&entry.id()));
+            context.outgoing.insert(outgoing_transaction.id(), outgoing_transaction.clone());
+        }
+        self.processor().register_outgoing_transaction(outgoing_transaction.clone());
+
+        for record in self.outgoing_transaction_records(outgoing_transaction)? {
+            self.processor().notify(Events::Reaccepted { record }).await?;
+        }
+
+        self.update_balance().await?;
+
+        Ok(())
+    }
+
+    /// Records of an outgoing transaction for the originating context
+    /// and the destination context (in case of a transfer).
+    fn outgoing_transaction_records(&self, outgoing_transaction: &OutgoingTransaction) -> Result<Vec<TransactionRecord>> {
+        let mut records = vec![if outgoing_transaction.is_batch() {
+            TransactionRecord::new_batch(self, outgoing_transaction, None)?
+        } else if outgoing_transaction.destination_context().is_some() {
+            TransactionRecord::new_transfer_outgoing(self, outgoing_transaction, None, &[])?
+        } else {
+            TransactionRecord::new_outgoing(self, outgoing_transaction, None)?
+        }];
+
+        if let Some(destination_context) = outgoing_transaction.destination_context() {
+            records.push(TransactionRecord::new_transfer_incoming(destination_context, outgoing_transaction, None, &[])?);
+        }
+
+        Ok(records)
+    }
+
     pub async fn extend_from_scan(&self, utxo_entries: Vec<UtxoEntryReference>, current_daa_score: u64) -> Result<()> {
         let (pending, mature) = {
             let mut context = self.context();
+            context.usage.update(&utxo_entries);
 
             let mut pending = vec![];
             let mut mature = vec![];
 
-            let params = NetworkParams::from(self.processor().network_id()?);
+            let params = self.processor().network_params()?;
 
             for utxo_entry in utxo_entries.into_iter() {
                 if let std::collections::hash_map::Entry::Vacant(e) = context.map.entry(utxo_entry.id()) {
@@ -448,7 +989,7 @@ impl UtxoContext {
                         }
                     }
                 } else {
-                    log_warn!("ignoring duplicate utxo entry");
+                    log!(Utxo, Warn, "ignoring duplicate utxo entry");
                 }
             }
 
@@ -462,12 +1003,15 @@ impl UtxoContext {
         for (id, utxos) in pending.into_iter() {
             let record = TransactionRecord::new_external(self, id, &utxos);
             self.processor().handle_discovery(record).await?;
+            // payments received while the context was not monitoring the addresses
+            self.handle_payment_requests(id, &utxos).await?;
         }
 
         let mature = HashMap::group_from(mature.into_iter().map(|utxo| (utxo.transaction_id(), utxo)));
         for (id, utxos) in mature.into_iter() {
             let record = TransactionRecord::new_external(self, id, &utxos);
             self.processor().handle_discovery(record).await?;
+            self.handle_payment_requests(id, &utxos).await?;
         }
 
         Ok(())
@@ -475,7 +1019,10 @@ impl UtxoContext {
 
     pub async fn calculate_balance(&self) -> Balance {
         let context = self.context();
-        let mature: u64 = context.mature.iter().map(|e| e.as_ref().amount).sum();
+        let (locked, mature): (Vec<_>, Vec<_>) = context.mature.iter().partition(|e| context.locked.contains(e.id_as_ref()));
+        let mature: u64 = mature.iter().map(|e| e.as_ref().amount).sum();
+        let locked_utxo_count = locked.len();
+        let locked: u64 = locked.iter().map(|e| e.as_ref().amount).sum();
         let pending: u64 = context.pending.values().map(|e| e.as_ref().amount).sum();
 
         // this will aggregate only transactions containing
@@ -503,21 +1050,26 @@ impl UtxoContext {
         // log for a fixed bug, but we want to keep the check
         // just in case.
         if mature + consumed < outgoing {
-            log_error!("Error: outgoing transaction value exceeds available balance");
+            log!(Utxo, Error, "Error: outgoing transaction value exceeds available balance");
         }
 
         let mature = (mature + consumed).saturating_sub(outgoing);
+        let pending_incoming: u64 = context.pending_incoming.values().sum();
 
-        Balance::new(mature, pending, outgoing, context.mature.len(), context.pending.len(), context.stasis.len())
+        Balance::new(mature, pending, outgoing, context.mature.len() - locked_utxo_count, context.pending.len(), context.stasis.len())
+            .with_locked(locked, locked_utxo_count)
+            .with_pending_incoming(pending_incoming)
     }
 
     pub(crate) async fn handle_utxo_added(&self, utxos: Vec<UtxoEntryReference>, current_daa_score: u64) -> Result<()> {
         // add UTXOs to account set
 
-        let params = NetworkParams::from(self.processor().network_id()?);
+        let params = self.processor().network_params()?;
 
         let mut accepted_outgoing_transactions = AHashSet::new();
 
+        self.context().usage.update(&utxos);
+
         let added = HashMap::group_from(utxos.into_iter().map(|utxo| (utxo.transaction_id(), utxo)));
         for (txid, utxos) in added.into_iter() {
             // get outgoing transaction from the processor in case the transaction
@@ -528,11 +1080,14 @@ impl UtxoContext {
             let is_coinbase_stasis =
                 utxos.first().map(|utxo| matches!(utxo.maturity(&params, current_daa_score), Maturity::Stasis)).unwrap_or_default();
 
+            // the transaction is no longer in the mempool
+            self.context().pending_incoming.remove(&txid);
+
             for utxo in utxos.iter() {
                 if let Err(err) = self.insert(utxo.clone(), current_daa_score, force_maturity_if_outgoing).await {
                     // TODO - remove `Result<>` from insert at a later date once
                     // we are confident that the insert will never result in an error.
-                    log_error!("{}", err);
+                    log!(Utxo, Error, "{}", err);
                 }
             }
 
@@ -554,6 +1109,7 @@ impl UtxoContext {
                 // do not notify if coinbase transaction is in stasis
                 let record = TransactionRecord::new_incoming(self, txid, &utxos);
                 self.processor().notify(Events::Pending { record }).await?;
+                self.handle_payment_requests(txid, &utxos).await?;
             }
         }
 
@@ -640,7 +1196,7 @@ impl UtxoContext {
 
     pub async fn register_addresses(&self, addresses: &[Address]) -> Result<()> {
         if addresses.is_empty() {
-            log_error!("utxo processor: register for an empty address set");
+            log!(Utxo, Error, "utxo processor: register for an empty address set");
         }
 
         let local = self.addresses();
@@ -676,12 +1232,104 @@ impl UtxoContext {
                 local.remove(address);
             });
         } else {
-            log_warn!("utxo processor: unregister for an empty address set")
+            log!(Utxo, Warn, "utxo processor: unregister for an empty address set")
+        }
+
+        Ok(())
+    }
+
+    /// Query the node mempool for unconfirmed transactions sending to the
+    /// addresses monitored by this context (excluding outgoing transactions
+    /// originating from the wallet). Returns the incoming amounts per address
+    /// and retains their total as the `pending_incoming` balance figure
+    /// until the transaction UTXOs are received or the transactions leave
+    /// the mempool (see [`UtxoContext::refresh_pending_incoming`]).
+    /// Emits [`Events::Balance`].
+    pub async fn update_pending_incoming(&self) -> Result<Vec<PendingIncoming>> {
+        let (incoming, pending_incoming) = self.fetch_pending_incoming().await?;
+        self.context().pending_incoming = pending_incoming;
+        self.update_balance().await?;
+
+        Ok(incoming)
+    }
+
+    /// Returns `true` if the balance includes pending incoming transactions.
+    pub fn has_pending_incoming(&self) -> bool {
+        !self.context().pending_incoming.is_empty()
+    }
+
+    /// Re-query the node mempool for the retained pending incoming
+    /// transactions, dropping transactions that were evicted from the
+    /// mempool (e.g. double-spent). Emits [`Events::Balance`] if the
+    /// pending incoming transactions have changed.
+    pub async fn refresh_pending_incoming(&self) -> Result<()> {
+        if !self.has_pending_incoming() {
+            return Ok(());
+        }
+
+        let (_, pending_incoming) = self.fetch_pending_incoming().await?;
+        let changed = {
+            let mut context = self.context();
+            // transactions not previously reported are retained
+            // only upon an explicit pending incoming update
+            let pending_incoming = pending_incoming
+                .into_iter()
+                .filter(|(txid, _)| context.pending_incoming.contains_key(txid))
+                .collect::<AHashMap<_, _>>();
+            let changed = pending_incoming.len() != context.pending_incoming.len();
+            context.pending_incoming = pending_incoming;
+            changed
+        };
+
+        if changed {
+            self.update_balance().await?;
         }
 
         Ok(())
     }
 
+    async fn fetch_pending_incoming(&self) -> Result<(Vec<PendingIncoming>, AHashMap<TransactionId, u64>)> {
+        let addresses = self.addresses().iter().map(|address| address.as_ref().clone()).collect::<Vec<_>>();
+        let entries = if addresses.is_empty() {
+            vec![]
+        } else {
+            self.processor().rpc_api().get_mempool_entries_by_addresses(addresses, false, false).await?
+        };
+
+        let mut pending_incoming = AHashMap::<TransactionId, u64>::default();
+        let mut incoming = vec![];
+        for entry in entries.into_iter() {
+            let script_public_key = pay_to_address_script(&entry.address);
+            let mut amount = 0;
+            let mut transaction_ids = vec![];
+            for mempool_entry in entry.receiving.iter() {
+                let transaction = kaspa_consensus_core::tx::Transaction::try_from(&mempool_entry.transaction)?;
+                let txid = transaction.id();
+                if self.processor().outgoing().contains_key(&txid) {
+                    continue;
+                }
+
+                let value: u64 = transaction
+                    .outputs
+                    .iter()
+                    .filter(|output| output.script_public_key == script_public_key)
+                    .map(|output| output.value)
+                    .sum();
+                if value > 0 {
+                    *pending_incoming.entry(txid).or_default() += value;
+                    amount += value;
+                    transaction_ids.push(txid);
+                }
+            }
+
+            if amount > 0 {
+                incoming.push(PendingIncoming { address: entry.address, amount, transaction_ids });
+            }
+        }
+
+        Ok((incoming, pending_incoming))
+    }
+
     pub async fn scan_and_register_addresses(&self, addresses: Vec<Address>, current_daa_score: Option<u64>) -> Result<()> {
         self.register_addresses(&addresses).await?;
         let resp = self.processor().rpc_api().get_utxos_by_addresses(addresses).await?;
@@ -691,7 +1339,7 @@ impl UtxoContext {
                     .current_daa_score()
             }).ok_or(Error::MissingDaaScore("Expecting DAA score or initialized UtxoProcessor when invoking scan_and_register_addresses() - You might be accessing UtxoProcessor APIs before it is initialized (see `utxo-proc-start` event)"))?;
         self.extend_from_scan(refs, current_daa_score).await?;
-        self.update_balance().await?;
+        self.update_balance_immediate().await?;
         Ok(())
     }
 }
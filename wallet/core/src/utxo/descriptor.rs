@@ -0,0 +1,66 @@
+//!
+//! UTXO entry descriptors used for coin control.
+//!
+
+use crate::imports::*;
+use crate::utxo::UtxoEntryId;
+use kaspa_consensus_core::tx::TransactionOutpoint;
+
+/// Spendability status of a UTXO entry.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum UtxoStatus {
+    /// Mature UTXO available for spending.
+    Spendable,
+    /// UTXO locked by the user (see [`UtxoContext::lock`]).
+    Locked,
+    /// UTXO that has not reached its maturity period.
+    Pending,
+    /// Coinbase UTXO that has not reached its stasis period.
+    Stasis,
+}
+
+impl std::fmt::Display for UtxoStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            UtxoStatus::Spendable => "spendable",
+            UtxoStatus::Locked => "locked",
+            UtxoStatus::Pending => "pending",
+            UtxoStatus::Stasis => "stasis",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Information about a UTXO entry held by a [`UtxoContext`],
+/// including its spendability status and the user-assigned label.
+#[derive(Debug, Clone, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UtxoEntryDescriptor {
+    pub outpoint: TransactionOutpoint,
+    pub address: Option<Address>,
+    pub amount: u64,
+    pub block_daa_score: u64,
+    pub is_coinbase: bool,
+    pub status: UtxoStatus,
+    pub label: Option<String>,
+}
+
+impl UtxoEntryDescriptor {
+    pub fn new(entry: &UtxoEntryReference, status: UtxoStatus, label: Option<String>) -> Self {
+        let utxo = entry.as_ref();
+        Self {
+            outpoint: utxo.outpoint.clone().into(),
+            address: utxo.address.clone(),
+            amount: utxo.amount,
+            block_daa_score: utxo.block_daa_score,
+            is_coinbase: utxo.is_coinbase,
+            status,
+            label,
+        }
+    }
+
+    pub fn id(&self) -> UtxoEntryId {
+        self.outpoint.into()
+    }
+}
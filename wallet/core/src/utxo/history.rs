@@ -0,0 +1,315 @@
+//!
+//! Transaction history reconstruction. Walks the accepted transactions
+//! of the selected chain blocks within a DAA score range and rebuilds
+//! the [`TransactionRecord`] history of an account from the network data.
+//! Useful after importing an old wallet, where only the current UTXO set
+//! (and not the history) can be obtained from the node.
+//!
+
+use crate::imports::*;
+use crate::storage::{TransactionData, TransactionRecordStore};
+use kaspa_consensus_core::tx::{Transaction, TransactionOutpoint};
+use kaspa_hashes::Hash;
+use kaspa_rpc_core::RpcBlock;
+use kaspa_txscript::extract_script_pub_key_address;
+
+/// Number of addresses beyond the current derivation index
+/// included in the history rescan for each address manager.
+pub const HISTORY_RESCAN_ADDRESS_WINDOW: u32 = 64;
+
+/// Number of chain blocks processed between the abort
+/// checks and progress updates of the history rescan.
+pub const HISTORY_RESCAN_PAGE_SIZE: usize = 256;
+
+/// Result of a [`HistoryRescan`] run.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryRescanSummary {
+    /// Number of chain blocks processed within the DAA score range
+    pub chain_blocks: u64,
+    /// Number of accepted transactions processed
+    pub accepted_transactions: u64,
+    /// Number of transaction records created
+    pub records: u64,
+    /// Number of transactions already present in the history
+    pub existing: u64,
+}
+
+/// Reconstructs the transaction history of an account by walking the
+/// accepted transactions of chain blocks within a DAA score range.
+pub struct HistoryRescan {
+    account: Arc<dyn Account>,
+    addresses: HashSet<Address>,
+    range: std::ops::Range<u64>,
+}
+
+impl HistoryRescan {
+    /// Create a history rescan for transactions accepted within the `range` of DAA scores.
+    pub fn try_new(account: Arc<dyn Account>, range: std::ops::Range<u64>) -> Result<Self> {
+        let mut addresses = HashSet::new();
+        match account.clone().as_derivation_capable() {
+            Ok(derivation_capable) => {
+                let derivation = derivation_capable.derivation();
+                for manager in [derivation.receive_address_manager(), derivation.change_address_manager()] {
+                    let extent = manager.index() + HISTORY_RESCAN_ADDRESS_WINDOW;
+                    addresses.extend(manager.get_range_with_args(0..extent, false)?);
+                }
+            }
+            Err(_) => {
+                addresses.insert(account.receive_address()?);
+                addresses.insert(account.change_address()?);
+            }
+        }
+
+        Ok(Self { account, addresses, range })
+    }
+
+    pub fn addresses(&self) -> &HashSet<Address> {
+        &self.addresses
+    }
+
+    /// Walk the selected chain blocks within the DAA score range and store
+    /// the reconstructed transaction records in the transaction record store.
+    /// Transactions already present in the history are left unmodified.
+    ///
+    /// The progress is reported as the [`WalletSyncPhase::HistoryBackfill`]
+    /// phase of the [`WalletSync`](crate::wallet::WalletSync) if the wallet
+    /// sync has completed the account scan.
+    pub async fn run(&self, abortable: &Abortable) -> Result<HistoryRescanSummary> {
+        let wallet = self.account.wallet();
+        let rpc = wallet.rpc_api();
+
+        // the selected chain hashes (without the accepted transaction ids)
+        // are used to locate the chain blocks bounding the DAA score range
+        let pruning_point_hash = rpc.get_block_dag_info().await?.pruning_point_hash;
+        let chain = rpc.get_virtual_chain_from_block(pruning_point_hash, false).await?.added_chain_block_hashes;
+        let start = Self::locate(&rpc, &chain, self.range.start).await?;
+        let end = start + Self::locate(&rpc, &chain[start..], self.range.end).await?;
+
+        let wallet_sync = wallet.wallet_sync();
+        let track_sync = wallet_sync.begin(WalletSyncPhase::HistoryBackfill, (end - start) as u64);
+        let summary = if start < end {
+            let low_hash = start.checked_sub(1).map(|index| chain[index]).unwrap_or(pruning_point_hash);
+            self.process(low_hash, end - start, abortable).await
+        } else {
+            Ok(HistoryRescanSummary::default())
+        };
+        if track_sync {
+            wallet_sync.complete();
+        }
+        summary
+    }
+
+    /// Returns the index of the first chain block with a DAA score
+    /// equal to or above `daa_score` (or the length of the `chain`).
+    async fn locate(rpc: &Arc<DynRpcApi>, chain: &[Hash], daa_score: u64) -> Result<usize> {
+        let (mut low, mut high) = (0, chain.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if rpc.get_block(chain[mid], false).await?.header.daa_score < daa_score {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        Ok(low)
+    }
+
+    /// Process `count` chain blocks following the chain block `low_hash`
+    /// in pages of [`HISTORY_RESCAN_PAGE_SIZE`] chain blocks. The accepted
+    /// transaction ids are requested starting from `low_hash`, so chain
+    /// blocks preceding the range are neither transferred nor fetched.
+    async fn process(&self, low_hash: Hash, count: usize, abortable: &Abortable) -> Result<HistoryRescanSummary> {
+        let wallet = self.account.wallet();
+        let rpc = wallet.rpc_api();
+        let network_id = wallet.network_id()?;
+        let prefix = Prefix::from(network_id);
+        let utxo_context = self.account.utxo_context();
+        let binding = Binding::from(utxo_context.binding());
+        let store = wallet.store().as_transaction_record_store()?;
+        let wallet_sync = wallet.wallet_sync();
+
+        let mut summary = HistoryRescanSummary::default();
+        // outputs received by the account, used to identify outgoing transactions;
+        // outputs received before the range are known from the stored history
+        let mut received = self.stored_outputs(&store, &binding, &network_id).await?;
+
+        let mut chain = rpc.get_virtual_chain_from_block(low_hash, true).await?.accepted_transaction_ids;
+        chain.truncate(count);
+
+        for page in chain.chunks(HISTORY_RESCAN_PAGE_SIZE) {
+            abortable.check()?;
+            wallet_sync.advance(WalletSyncPhase::HistoryBackfill, page.len() as u64);
+
+            for accepted in page.iter().filter(|accepted| !accepted.accepted_transaction_ids.is_empty()) {
+                let chain_block = rpc.get_block(accepted.accepting_block_hash, false).await?;
+                let accepted_daa_score = chain_block.header.daa_score;
+                summary.chain_blocks += 1;
+
+                let transactions = Self::fetch_merged_transactions(&rpc, &chain_block).await?;
+
+                for txid in accepted.accepted_transaction_ids.iter().cloned() {
+                    let Some((transaction, block_time)) = transactions.get(&txid) else {
+                        continue;
+                    };
+
+                    let spent =
+                        transaction.inputs.iter().filter_map(|input| received.remove(&input.previous_outpoint)).collect::<Vec<_>>();
+
+                    let mut utxos = vec![];
+                    for (index, output) in transaction.outputs.iter().enumerate() {
+                        let Some(address) = self.account_address(&output.script_public_key, prefix) else {
+                            continue;
+                        };
+                        let outpoint = TransactionOutpoint::new(txid, index as TransactionIndexType);
+                        let utxo = Self::utxo_entry(
+                            outpoint,
+                            address,
+                            output.value,
+                            &output.script_public_key,
+                            accepted_daa_score,
+                            transaction.is_coinbase(),
+                        );
+                        received.insert(outpoint, utxo.clone());
+                        utxos.push(utxo);
+                    }
+
+                    if spent.is_empty() && utxos.is_empty() {
+                        continue;
+                    }
+
+                    summary.accepted_transactions += 1;
+
+                    if store.load_single(&binding, &network_id, &txid).await.is_ok() {
+                        summary.existing += 1;
+                        continue;
+                    }
+
+                    let mut record = if spent.is_empty() {
+                        TransactionRecord::new_incoming(utxo_context, txid, &utxos)
+                    } else {
+                        let change_value = utxos.iter().map(|utxo| utxo.amount()).sum::<u64>();
+                        TransactionRecord::new_recovered_outgoing(
+                            utxo_context,
+                            transaction.clone(),
+                            accepted_daa_score,
+                            &spent,
+                            change_value,
+                        )?
+                    };
+                    record.set_unixtime(*block_time);
+
+                    store.store(&[&record]).await?;
+                    summary.records += 1;
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Outputs received by the account according to the stored transaction history.
+    async fn stored_outputs(
+        &self,
+        store: &Arc<dyn TransactionRecordStore>,
+        binding: &Binding,
+        network_id: &NetworkId,
+    ) -> Result<HashMap<TransactionOutpoint, UtxoEntryReference>> {
+        let records = match store.transaction_data_iter(binding, network_id).await {
+            Ok(records) => records.try_collect::<Vec<_>>().await?,
+            Err(Error::NoRecordsFound) => return Ok(HashMap::new()),
+            Err(err) => return Err(err),
+        };
+
+        let prefix = Prefix::from(*network_id);
+        let mut outputs = HashMap::new();
+        for record in records {
+            let (txid, block_daa_score) = (*record.id(), record.block_daa_score());
+            match record.transaction_data() {
+                // outputs of reorged transactions no longer exist
+                TransactionData::Reorg { .. } => {}
+                TransactionData::Incoming { utxo_entries, .. }
+                | TransactionData::Stasis { utxo_entries, .. }
+                | TransactionData::External { utxo_entries, .. } => {
+                    for utxo in utxo_entries.iter() {
+                        let Some(address) = utxo.address.clone() else {
+                            continue;
+                        };
+                        let outpoint = TransactionOutpoint::new(txid, utxo.index);
+                        let entry = Self::utxo_entry(
+                            outpoint,
+                            address,
+                            utxo.amount,
+                            &utxo.script_public_key,
+                            block_daa_score,
+                            utxo.is_coinbase,
+                        );
+                        outputs.insert(outpoint, entry);
+                    }
+                }
+                TransactionData::Batch { transaction, .. }
+                | TransactionData::Outgoing { transaction, .. }
+                | TransactionData::TransferIncoming { transaction, .. }
+                | TransactionData::TransferOutgoing { transaction, .. }
+                | TransactionData::Change { transaction, .. } => {
+                    for (index, output) in transaction.outputs.iter().enumerate() {
+                        let Some(address) = self.account_address(&output.script_public_key, prefix) else {
+                            continue;
+                        };
+                        let outpoint = TransactionOutpoint::new(txid, index as TransactionIndexType);
+                        let entry =
+                            Self::utxo_entry(outpoint, address, output.value, &output.script_public_key, block_daa_score, false);
+                        outputs.insert(outpoint, entry);
+                    }
+                }
+            }
+        }
+
+        Ok(outputs)
+    }
+
+    /// Returns the address of the script public key if it belongs to the account.
+    fn account_address(&self, script_public_key: &ScriptPublicKey, prefix: Prefix) -> Option<Address> {
+        extract_script_pub_key_address(script_public_key, prefix).ok().filter(|address| self.addresses.contains(address))
+    }
+
+    fn utxo_entry(
+        outpoint: TransactionOutpoint,
+        address: Address,
+        amount: u64,
+        script_public_key: &ScriptPublicKey,
+        block_daa_score: u64,
+        is_coinbase: bool,
+    ) -> UtxoEntryReference {
+        UtxoEntryReference::from(kaspa_consensus_client::UtxoEntry {
+            address: Some(address),
+            outpoint: outpoint.into(),
+            amount,
+            script_public_key: script_public_key.clone(),
+            block_daa_score,
+            is_coinbase,
+        })
+    }
+
+    /// Fetch the transactions of the blocks merged by the chain block,
+    /// which are the blocks whose transactions the chain block accepts.
+    async fn fetch_merged_transactions(
+        rpc: &Arc<DynRpcApi>,
+        chain_block: &RpcBlock,
+    ) -> Result<HashMap<TransactionId, (Transaction, u64)>> {
+        let verbose_data = chain_block.verbose_data.as_ref().ok_or(Error::custom("missing block verbose data"))?;
+
+        let mut transactions = HashMap::new();
+        let merged = verbose_data.merge_set_blues_hashes.iter().chain(verbose_data.merge_set_reds_hashes.iter());
+        for hash in merged.cloned().collect::<Vec<Hash>>() {
+            let block = rpc.get_block(hash, true).await?;
+            let block_time = block.header.timestamp;
+            for rpc_transaction in block.transactions.iter() {
+                let transaction = Transaction::try_from(rpc_transaction)?;
+                transactions.insert(transaction.id(), (transaction, block_time));
+            }
+        }
+
+        Ok(transactions)
+    }
+}
@@ -12,7 +12,9 @@ pub struct UtxoIterator {
 
 impl UtxoIterator {
     pub fn new(utxo_context: &UtxoContext) -> Self {
-        Self { entries: utxo_context.context().mature.clone(), cursor: 0 }
+        let context = utxo_context.context();
+        let entries = context.mature.iter().filter(|entry| !context.locked.contains(entry.id_as_ref())).cloned().collect();
+        Self { entries, cursor: 0 }
     }
 }
 
@@ -2,32 +2,48 @@
 //! UTXO handling primitives.
 //!
 
+pub mod acceptance;
 pub mod balance;
 pub mod binding;
 pub mod context;
+pub mod descriptor;
+pub mod history;
 pub mod iterator;
+pub mod monitor;
 pub mod outgoing;
+pub mod payment_request;
 pub mod pending;
 pub mod processor;
 pub mod reference;
 pub mod scan;
 pub mod settings;
+pub mod snapshot;
 pub mod stream;
+pub mod subscriptions;
 pub mod sync;
+pub mod usage;
 
-pub use balance::Balance;
+pub use acceptance::{AcceptanceCollector, AcceptanceExportFormat, AcceptanceObservation, AcceptanceSource};
+pub use balance::{Balance, PendingIncoming};
 pub use binding::UtxoContextBinding;
-pub use context::{UtxoContext, UtxoContextId};
+pub use context::{UtxoContext, UtxoContextCheckpoint, UtxoContextId};
+pub use descriptor::{UtxoEntryDescriptor, UtxoStatus};
+pub use history::{HistoryRescan, HistoryRescanSummary};
 pub use iterator::UtxoIterator;
 pub use kaspa_consensus_client::UtxoEntryId;
+pub use monitor::{AddressBalance, AddressMonitor};
 pub use outgoing::OutgoingTransaction;
+pub use payment_request::{PaymentRequest, PaymentRequestId, PaymentRequestStatus, PAYMENT_REQUEST_RETENTION};
 pub use pending::PendingUtxoEntryReference;
 pub use processor::UtxoProcessor;
 pub use reference::{Maturity, TryIntoUtxoEntryReferences, UtxoEntryReference, UtxoEntryReferenceExtension};
 pub use scan::{Scan, ScanExtent};
 pub use settings::*;
+pub use snapshot::UtxoSnapshot;
 pub use stream::UtxoStream;
+pub use subscriptions::{AddressSubscriptions, SubscriptionDiff};
 pub use sync::SyncMonitor;
+pub use usage::AddressUsage;
 
 #[cfg(test)]
 pub mod test;
@@ -0,0 +1,362 @@
+//!
+//! Standalone address monitor.
+//!
+//! [`AddressMonitor`] tracks the UTXOs of an arbitrary set of addresses
+//! without wallet storage or accounts, making it suitable for explorers,
+//! payment processors and dashboards. The monitor binds a dedicated
+//! [`UtxoContext`] to a [`UtxoProcessor`] (either owned by the monitor or
+//! shared with other contexts), subscribes the addresses to `UtxosChanged`
+//! notifications and maintains the balance of each address. Transaction
+//! events ([`Events::Pending`], [`Events::Maturity`], [`Events::Balance`]
+//! etc.) carrying the monitor id are posted to the processor multiplexer.
+//!
+//! The UTXO set of the monitored addresses is re-scanned each time the
+//! processor (re)connects to the node. Stopping a monitor bound to a shared
+//! processor unregisters its addresses and UTXO entries from the processor;
+//! the addresses are retained and registered again once the monitor restarts.
+//!
+
+use crate::imports::*;
+use crate::logging::log;
+use crate::utxo::{Balance, UtxoContext, UtxoContextBinding, UtxoContextId, UtxoProcessor};
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_ADDRESS_BALANCE: &'static str = r#"
+/**
+ * Balance of an address tracked by the {@link AddressMonitor}.
+ * @category Wallet SDK
+ */
+export interface IAddressBalance {
+    address: string;
+    /**
+     * Amount of Kaspa (in SOMPI) held in mature UTXOs.
+     */
+    mature: bigint;
+    /**
+     * Amount of Kaspa (in SOMPI) held in UTXOs pending confirmation.
+     */
+    pending: bigint;
+    matureUtxoCount: number;
+    pendingUtxoCount: number;
+    /**
+     * Number of freshly minted coinbase UTXOs (not included in the balance).
+     */
+    stasisUtxoCount: number;
+}
+"#;
+
+/// Balance of an address tracked by the [`AddressMonitor`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBalance {
+    pub address: Address,
+    /// Amount held in mature UTXOs (in SOMPI)
+    pub mature: u64,
+    /// Amount held in UTXOs pending confirmation (in SOMPI)
+    pub pending: u64,
+    pub mature_utxo_count: usize,
+    pub pending_utxo_count: usize,
+    /// Number of coinbase UTXOs in stasis (not included in the balance)
+    pub stasis_utxo_count: usize,
+}
+
+impl AddressBalance {
+    fn new(address: Address) -> Self {
+        Self { address, mature: 0, pending: 0, mature_utxo_count: 0, pending_utxo_count: 0, stasis_utxo_count: 0 }
+    }
+}
+
+struct Inner {
+    processor: UtxoProcessor,
+    context: UtxoContext,
+    // the processor was created by the monitor and
+    // is started and stopped along with the monitor
+    owns_processor: bool,
+    // addresses of a stopped monitor detached from a shared processor
+    retained: Mutex<Option<Vec<Address>>>,
+    running: AtomicBool,
+    task_ctl: DuplexChannel,
+}
+
+/// Monitors the UTXOs and balances of arbitrary addresses
+/// (see the [module documentation](self)).
+#[derive(Clone)]
+pub struct AddressMonitor {
+    inner: Arc<Inner>,
+}
+
+impl AddressMonitor {
+    /// Create a monitor with a dedicated [`UtxoProcessor`] bound to `rpc`.
+    pub fn new(rpc: Rpc, network_id: NetworkId) -> Self {
+        let processor = UtxoProcessor::new(Some(rpc), Some(network_id), None, None);
+        Self::new_with_args(processor, true)
+    }
+
+    /// Create a monitor using an existing [`UtxoProcessor`]. The processor
+    /// must be started by the caller; events are posted to its multiplexer.
+    pub fn with_processor(processor: &UtxoProcessor) -> Self {
+        Self::new_with_args(processor.clone(), false)
+    }
+
+    fn new_with_args(processor: UtxoProcessor, owns_processor: bool) -> Self {
+        let context = UtxoContext::new(&processor, UtxoContextBinding::default());
+        Self {
+            inner: Arc::new(Inner {
+                processor,
+                context,
+                owns_processor,
+                retained: Mutex::new(None),
+                running: AtomicBool::new(false),
+                task_ctl: DuplexChannel::oneshot(),
+            }),
+        }
+    }
+
+    /// Id included in the events posted by the monitor.
+    pub fn id(&self) -> UtxoContextId {
+        self.inner.context.id()
+    }
+
+    pub fn processor(&self) -> &UtxoProcessor {
+        &self.inner.processor
+    }
+
+    /// The [`UtxoContext`] holding the UTXOs of the monitored addresses
+    /// (can be used as a UTXO source by the transaction generator).
+    pub fn context(&self) -> &UtxoContext {
+        &self.inner.context
+    }
+
+    pub fn multiplexer(&self) -> &Multiplexer<Box<Events>> {
+        self.inner.processor.multiplexer()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.inner.running.load(Ordering::SeqCst)
+    }
+
+    /// Returns `true` if the processor is connected and the
+    /// monitored addresses are tracked by the node.
+    pub fn is_active(&self) -> bool {
+        self.is_running() && self.inner.processor.is_connected()
+    }
+
+    /// Start the monitor (and its processor if owned by the monitor).
+    pub async fn start(&self) -> Result<()> {
+        if self.is_running() {
+            return Ok(());
+        }
+
+        let this = self.clone();
+        this.inner.running.store(true, Ordering::SeqCst);
+        let task_ctl_receiver = self.inner.task_ctl.request.receiver.clone();
+        let task_ctl_sender = self.inner.task_ctl.response.sender.clone();
+        // the channel is created prior to starting the processor
+        // to receive the start event of an already connected node
+        let events = self.multiplexer().channel();
+
+        spawn(async move {
+            loop {
+                select! {
+                    _ = task_ctl_receiver.recv().fuse() => {
+                        break;
+                    },
+
+                    msg = events.receiver.recv().fuse() => {
+                        match msg {
+                            Ok(event) => {
+                                if matches!(*event, Events::UtxoProcStart) {
+                                    this.rescan().await.unwrap_or_else(|err| log!(Utxo, Error, "AddressMonitor: unable to scan addresses: {err}"));
+                                }
+                            }
+                            Err(err) => {
+                                log!(Utxo, Error, "AddressMonitor: error while receiving multiplexer message: {err}");
+                                break;
+                            }
+                        }
+                    },
+                }
+            }
+
+            events.close();
+            this.inner.running.store(false, Ordering::SeqCst);
+            task_ctl_sender.send(()).await.unwrap();
+        });
+
+        if self.inner.owns_processor {
+            self.inner.processor.start().await?;
+        } else {
+            // addresses retained by `stop()` are registered with the processor again
+            let retained = self.inner.retained.lock().unwrap().take().filter(|addresses| !addresses.is_empty());
+            match (retained, self.inner.processor.is_connected()) {
+                (Some(addresses), true) => self.inner.context.scan_and_register_addresses(addresses, None).await?,
+                (Some(addresses), false) => self.inner.context.register_addresses(&addresses).await?,
+                (None, true) => self.rescan().await?,
+                (None, false) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Stop the monitor (and its processor if owned by the monitor).
+    /// The monitored addresses are retained. With a shared processor,
+    /// the addresses and UTXO entries of the monitor are unregistered
+    /// from the processor until the monitor is started again.
+    pub async fn stop(&self) -> Result<()> {
+        if self.inner.owns_processor {
+            self.inner.processor.stop().await?;
+        } else {
+            self.detach().await?;
+        }
+        if self.is_running() {
+            self.inner.task_ctl.signal(()).await.expect("AddressMonitor::stop() `signal` error");
+        }
+        Ok(())
+    }
+
+    async fn detach(&self) -> Result<()> {
+        if self.inner.retained.lock().unwrap().is_some() {
+            return Ok(());
+        }
+
+        let addresses = self.addresses();
+        let context = &self.inner.context;
+        let processor = &self.inner.processor;
+        {
+            let ids = context.context().map.keys().cloned().collect::<Vec<_>>();
+            for id in ids.iter() {
+                processor.pending().remove(id);
+                processor.stasis().remove(id);
+            }
+        }
+        processor.outgoing().retain(|_, outgoing| outgoing.originating_context() != context);
+        processor.reorged().retain(|_, (outgoing, _)| outgoing.originating_context() != context);
+        context.clear().await?;
+        self.inner.retained.lock().unwrap().replace(addresses);
+        Ok(())
+    }
+
+    /// Returns the monitored addresses.
+    pub fn addresses(&self) -> Vec<Address> {
+        if let Some(retained) = self.inner.retained.lock().unwrap().as_ref() {
+            return retained.clone();
+        }
+        self.inner.context.addresses().iter().map(|address| address.as_ref().clone()).collect()
+    }
+
+    /// Start monitoring the addresses. If the processor is connected, the
+    /// addresses are scanned immediately, otherwise once the connection
+    /// is established.
+    pub async fn track_addresses(&self, addresses: &[Address]) -> Result<()> {
+        if let Some(retained) = self.inner.retained.lock().unwrap().as_mut() {
+            for address in addresses {
+                if !retained.contains(address) {
+                    retained.push(address.clone());
+                }
+            }
+            return Ok(());
+        }
+
+        let addresses = {
+            let local = self.inner.context.addresses();
+            addresses.iter().filter(|address| !local.contains(*address)).cloned().collect::<Vec<_>>()
+        };
+        if addresses.is_empty() {
+            return Ok(());
+        }
+
+        if self.inner.processor.is_connected() {
+            self.inner.context.scan_and_register_addresses(addresses, None).await
+        } else {
+            self.inner.context.register_addresses(&addresses).await
+        }
+    }
+
+    /// Stop monitoring the addresses, removing their UTXOs from the monitor.
+    pub async fn untrack_addresses(&self, addresses: &[Address]) -> Result<()> {
+        if let Some(retained) = self.inner.retained.lock().unwrap().as_mut() {
+            retained.retain(|address| !addresses.contains(address));
+            return Ok(());
+        }
+
+        let addresses = {
+            let local = self.inner.context.addresses();
+            addresses.iter().filter(|address| local.contains(*address)).cloned().collect::<Vec<_>>()
+        };
+        if addresses.is_empty() {
+            return Ok(());
+        }
+
+        let utxos = {
+            let context = self.inner.context.context();
+            context
+                .map
+                .values()
+                .filter(|utxo| utxo.utxo.address.as_ref().map(|address| addresses.contains(address)).unwrap_or_default())
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        self.inner.context.unregister_addresses(addresses).await?;
+        self.inner.context.remove(utxos).await?;
+        self.inner.context.update_balance_immediate().await?;
+        Ok(())
+    }
+
+    /// Re-scan the UTXOs of the monitored addresses.
+    pub async fn rescan(&self) -> Result<()> {
+        let addresses = self.addresses();
+        self.inner.context.clear().await?;
+        if !addresses.is_empty() {
+            self.inner.context.scan_and_register_addresses(addresses, None).await?;
+        }
+        Ok(())
+    }
+
+    /// Total balance of the monitored addresses.
+    pub fn balance(&self) -> Option<Balance> {
+        self.inner.context.balance()
+    }
+
+    /// Returns the balance of each monitored address
+    /// (including addresses that hold no UTXOs).
+    pub fn address_balances(&self) -> Vec<AddressBalance> {
+        let mut balances = self
+            .inner
+            .context
+            .addresses()
+            .iter()
+            .map(|address| (address.as_ref().clone(), AddressBalance::new(address.as_ref().clone())))
+            .collect::<AHashMap<_, _>>();
+
+        let context = self.inner.context.context();
+        for utxo in context.mature.iter() {
+            if let Some(balance) = utxo.utxo.address.as_ref().and_then(|address| balances.get_mut(address)) {
+                balance.mature += utxo.amount();
+                balance.mature_utxo_count += 1;
+            }
+        }
+        for utxo in context.pending.values() {
+            if let Some(balance) = utxo.utxo.address.as_ref().and_then(|address| balances.get_mut(address)) {
+                balance.pending += utxo.amount();
+                balance.pending_utxo_count += 1;
+            }
+        }
+        for utxo in context.stasis.values() {
+            if let Some(balance) = utxo.utxo.address.as_ref().and_then(|address| balances.get_mut(address)) {
+                balance.stasis_utxo_count += 1;
+            }
+        }
+
+        let mut balances = balances.into_values().collect::<Vec<_>>();
+        balances.sort_by_key(|balance| balance.address.to_string());
+        balances
+    }
+
+    /// Returns the balance of the monitored `address`
+    /// (`None` if the address is not monitored).
+    pub fn address_balance(&self, address: &Address) -> Option<AddressBalance> {
+        self.address_balances().into_iter().find(|balance| &balance.address == address)
+    }
+}
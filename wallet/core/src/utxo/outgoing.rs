@@ -7,6 +7,7 @@
 use crate::imports::*;
 use crate::tx::PendingTransaction;
 use crate::utxo::{UtxoContext, UtxoEntryId, UtxoEntryReference};
+use kaspa_hashes::Hash;
 
 struct Inner {
     pub id: TransactionId,
@@ -16,6 +17,8 @@ struct Inner {
     #[allow(dead_code)]
     pub creation_daa_score: u64,
     pub acceptance_daa_score: AtomicU64,
+    /// Chain block that accepted the transaction (reported by the `VirtualChainChanged` notification)
+    pub accepting_block_hash: Mutex<Option<Hash>>,
 }
 
 /// A wrapper around [`PendingTransaction`] that adds additional context and
@@ -36,6 +39,7 @@ impl OutgoingTransaction {
             destination_context,
             creation_daa_score: current_daa_score,
             acceptance_daa_score: AtomicU64::new(0),
+            accepting_block_hash: Mutex::new(None),
         };
 
         Self { inner: Arc::new(inner) }
@@ -66,7 +70,9 @@ impl OutgoingTransaction {
     }
 
     pub fn tag_as_accepted_at_daa_score(&self, accepted_daa_score: u64) {
-        self.inner.acceptance_daa_score.store(accepted_daa_score, Ordering::Relaxed);
+        if self.inner.acceptance_daa_score.swap(accepted_daa_score, Ordering::Relaxed) == 0 {
+            self.inner.originating_context.processor().acceptance_collector().register_acceptance(&self.id(), accepted_daa_score);
+        }
     }
 
     pub fn acceptance_daa_score(&self) -> u64 {
@@ -77,6 +83,14 @@ impl OutgoingTransaction {
         self.inner.acceptance_daa_score.load(Ordering::Relaxed) != 0
     }
 
+    pub fn set_accepting_block_hash(&self, accepting_block_hash: Hash) {
+        self.inner.accepting_block_hash.lock().unwrap().replace(accepting_block_hash);
+    }
+
+    pub fn accepting_block_hash(&self) -> Option<Hash> {
+        *self.inner.accepting_block_hash.lock().unwrap()
+    }
+
     pub fn is_batch(&self) -> bool {
         self.inner.pending_transaction.is_batch()
     }
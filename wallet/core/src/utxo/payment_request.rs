@@ -0,0 +1,205 @@
+//!
+//! Payment requests tracked by the [`UtxoContext`].
+//!
+//! A [`PaymentRequest`] registers the expectation of a payment (amount and
+//! optional expiry) to a receive address. When a transaction sending at least
+//! the requested amount to the address is received, the request is marked as
+//! fulfilled and [`Events::PaymentReceived`] is emitted. Requests are persisted
+//! in the account metadata and pruned once they have been fulfilled or expired
+//! for longer than [`PAYMENT_REQUEST_RETENTION`].
+//!
+
+use crate::imports::*;
+use crate::tx::PaymentUri;
+use kaspa_hashes::Hash;
+use workflow_core::time::unixtime_as_millis_u64;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_PAYMENT_REQUEST: &'static str = r#"
+/**
+ * Request for a payment to a receive address of an account.
+ * The request is fulfilled when a transaction sending at least
+ * the requested amount to the address is received, at which
+ * point the wallet emits the `payment-received` event.
+ * @category Wallet SDK
+ */
+export interface IPaymentRequest {
+    id: HexString;
+    address: string;
+    /**
+     * Requested amount (in SOMPI).
+     */
+    amount: bigint;
+    label?: string;
+    message?: string;
+    /**
+     * Creation time (unix time in milliseconds).
+     */
+    created: number;
+    /**
+     * Expiration time (unix time in milliseconds).
+     */
+    expires?: number;
+    status: "pending" | "fulfilled" | "expired";
+    /**
+     * Id of the transaction fulfilling the request.
+     */
+    transactionId?: HexString;
+    /**
+     * Fulfillment time (unix time in milliseconds).
+     */
+    fulfilled?: number;
+}
+"#;
+
+/// Period for which fulfilled and expired payment requests are retained.
+pub const PAYMENT_REQUEST_RETENTION: Duration = Duration::from_secs(60 * 60 * 24 * 7);
+
+/// Payment request id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+pub struct PaymentRequestId(pub(crate) Hash);
+
+impl PaymentRequestId {
+    fn new_random() -> Self {
+        PaymentRequestId(Hash::from_bytes(rand::random()))
+    }
+}
+
+impl ToHex for PaymentRequestId {
+    fn to_hex(&self) -> String {
+        self.0.to_hex()
+    }
+}
+
+impl FromHex for PaymentRequestId {
+    type Error = Error;
+    fn from_hex(hex_str: &str) -> Result<Self, Self::Error> {
+        Ok(Self(Hash::from_hex(hex_str)?))
+    }
+}
+
+impl std::fmt::Display for PaymentRequestId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PaymentRequestStatus {
+    /// Awaiting the payment
+    Pending,
+    /// Payment of the requested amount has been received
+    Fulfilled,
+    /// The request has expired before the payment has been received
+    Expired,
+}
+
+impl std::fmt::Display for PaymentRequestStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PaymentRequestStatus::Pending => write!(f, "pending"),
+            PaymentRequestStatus::Fulfilled => write!(f, "fulfilled"),
+            PaymentRequestStatus::Expired => write!(f, "expired"),
+        }
+    }
+}
+
+/// Request for a payment of `amount` to `address`, typically
+/// conveyed to the payer as a [`PaymentUri`] (see [`PaymentRequest::uri()`]).
+#[derive(Debug, Clone, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentRequest {
+    pub id: PaymentRequestId,
+    pub address: Address,
+    /// Requested amount in SOMPI
+    pub amount: u64,
+    pub label: Option<String>,
+    pub message: Option<String>,
+    /// Creation time (unix time in milliseconds)
+    pub created: u64,
+    /// Expiration time (unix time in milliseconds)
+    pub expires: Option<u64>,
+    pub status: PaymentRequestStatus,
+    /// Id of the transaction fulfilling the request
+    pub transaction_id: Option<TransactionId>,
+    /// Fulfillment time (unix time in milliseconds)
+    pub fulfilled: Option<u64>,
+}
+
+impl PaymentRequest {
+    /// Create a payment request for `amount` SOMPI to `address`,
+    /// expiring after `expiry` (if supplied).
+    pub fn new(address: Address, amount: u64, expiry: Option<Duration>) -> Self {
+        let created = unixtime_as_millis_u64();
+        Self {
+            id: PaymentRequestId::new_random(),
+            address,
+            amount,
+            label: None,
+            message: None,
+            created,
+            expires: expiry.map(|expiry| created + expiry.as_millis() as u64),
+            status: PaymentRequestStatus::Pending,
+            transaction_id: None,
+            fulfilled: None,
+        }
+    }
+
+    pub fn with_label(mut self, label: Option<String>) -> Self {
+        self.label = label;
+        self
+    }
+
+    pub fn with_message(mut self, message: Option<String>) -> Self {
+        self.message = message;
+        self
+    }
+
+    /// Payment URI conveying the request to the payer.
+    pub fn uri(&self) -> PaymentUri {
+        PaymentUri {
+            address: self.address.clone(),
+            amount: Some(self.amount),
+            label: self.label.clone(),
+            message: self.message.clone(),
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.status == PaymentRequestStatus::Pending
+    }
+
+    /// Mark the pending request as expired if the expiration time has passed.
+    pub(crate) fn update_expiry(&mut self, now: u64) {
+        if self.is_pending() && self.expires.is_some_and(|expires| expires <= now) {
+            self.status = PaymentRequestStatus::Expired;
+        }
+    }
+
+    /// Mark the request as fulfilled if it is pending and `amount` received
+    /// at the request address by the transaction `transaction_id` covers the
+    /// requested amount. Returns `true` if the request has been fulfilled.
+    pub(crate) fn try_fulfill(&mut self, transaction_id: TransactionId, amount: u64, now: u64) -> bool {
+        self.update_expiry(now);
+        if self.is_pending() && amount >= self.amount {
+            self.status = PaymentRequestStatus::Fulfilled;
+            self.transaction_id = Some(transaction_id);
+            self.fulfilled = Some(now);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns `true` if the request has been fulfilled or has expired
+    /// earlier than [`PAYMENT_REQUEST_RETENTION`] before `now`.
+    pub(crate) fn is_prunable(&self, now: u64) -> bool {
+        let settled = match self.status {
+            PaymentRequestStatus::Pending => None,
+            PaymentRequestStatus::Fulfilled => self.fulfilled,
+            PaymentRequestStatus::Expired => self.expires,
+        };
+        settled.is_some_and(|settled| settled + PAYMENT_REQUEST_RETENTION.as_millis() as u64 <= now)
+    }
+}
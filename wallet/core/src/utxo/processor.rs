@@ -6,27 +6,34 @@
 //!
 
 use crate::imports::*;
+use crate::logging::log;
+use kaspa_consensus_core::config::params::Params;
 // use futures::pin_mut;
 use kaspa_notify::{
     listener::ListenerId,
-    scope::{Scope, UtxosChangedScope, VirtualDaaScoreChangedScope},
+    scope::{Scope, VirtualChainChangedScope, VirtualDaaScoreChangedScope},
 };
 use kaspa_rpc_core::{
     api::{
         ctl::{RpcCtl, RpcState},
         ops::RPC_API_VERSION,
     },
-    message::UtxosChangedNotification,
+    message::{UtxosChangedNotification, VirtualChainChangedNotification},
     GetServerInfoResponse,
 };
+use kaspa_wrpc_client::capabilities::{NodeCapabilities, NodeFeature};
 use kaspa_wrpc_client::KaspaRpcClient;
 use workflow_core::channel::{Channel, DuplexChannel};
 use workflow_core::task::spawn;
+use workflow_core::time::Instant;
 
 use crate::events::Events;
 use crate::result::Result;
+use crate::tx::GeneratorProfiler;
+use crate::utxo::acceptance::MEMPOOL_SAMPLE_INTERVAL;
 use crate::utxo::{
-    Maturity, OutgoingTransaction, PendingUtxoEntryReference, SyncMonitor, UtxoContext, UtxoEntryId, UtxoEntryReference,
+    AcceptanceCollector, AddressSubscriptions, Maturity, MaturityPolicy, OutgoingTransaction, PendingUtxoEntryReference, SyncMonitor,
+    UtxoContext, UtxoEntryId, UtxoEntryReference,
 };
 use crate::wallet::WalletBusMessage;
 use kaspa_rpc_core::{
@@ -36,6 +43,10 @@ use kaspa_rpc_core::{
 // use workflow_core::task;
 // use kaspa_metrics_core::{Metrics,Metric};
 
+/// Minimum interval between the refreshes of the pending incoming
+/// transactions retained by the UTXO contexts (see [`UtxoContext::refresh_pending_incoming`]).
+pub const PENDING_INCOMING_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
 pub struct Inner {
     /// Coinbase UTXOs in stasis
     stasis: DashMap<UtxoEntryId, PendingUtxoEntryReference>,
@@ -43,12 +54,18 @@ pub struct Inner {
     pending: DashMap<UtxoEntryId, PendingUtxoEntryReference>,
     /// Outgoing Transactions
     outgoing: DashMap<TransactionId, OutgoingTransaction>,
+    /// Outgoing Transactions reverted by a chain reorganization
+    /// (with the DAA score of the reorg) that may be accepted again
+    reorged: DashMap<TransactionId, (OutgoingTransaction, u64)>,
     /// Address to UtxoContext map (maps all addresses used by
     /// all UtxoContexts to their respective UtxoContexts)
     address_to_utxo_context_map: DashMap<Arc<Address>, UtxoContext>,
     // ---
     current_daa_score: Arc<AtomicU64>,
     network_id: Arc<Mutex<Option<NetworkId>>>,
+    consensus_params: Mutex<Option<Arc<Params>>>,
+    maturity_policy: Mutex<MaturityPolicy>,
+    balance_event_throttle: Mutex<Option<Duration>>,
     rpc: Mutex<Option<Rpc>>,
     is_connected: AtomicBool,
     listener_id: Mutex<Option<ListenerId>>,
@@ -62,6 +79,12 @@ pub struct Inner {
     connect_disconnect_guard: AsyncMutex<()>,
     metrics: Arc<Metrics>,
     metrics_kinds: Mutex<Vec<MetricsUpdateKind>>,
+    generator_profiler: Arc<GeneratorProfiler>,
+    acceptance_collector: Arc<AcceptanceCollector>,
+    last_mempool_sample: Mutex<Option<Instant>>,
+    last_pending_incoming_refresh: Mutex<Option<Instant>>,
+    node_capabilities: Mutex<Option<Arc<NodeCapabilities>>>,
+    subscriptions: AddressSubscriptions,
 }
 
 impl Inner {
@@ -75,9 +98,13 @@ impl Inner {
             stasis: DashMap::new(),
             pending: DashMap::new(),
             outgoing: DashMap::new(),
+            reorged: DashMap::new(),
             address_to_utxo_context_map: DashMap::new(),
             current_daa_score: Arc::new(AtomicU64::new(0)),
             network_id: Arc::new(Mutex::new(network_id)),
+            consensus_params: Mutex::new(None),
+            maturity_policy: Mutex::new(MaturityPolicy::default()),
+            balance_event_throttle: Mutex::new(None),
             rpc: Mutex::new(rpc.clone()),
             is_connected: AtomicBool::new(false),
             listener_id: Mutex::new(None),
@@ -91,6 +118,12 @@ impl Inner {
             connect_disconnect_guard: Default::default(),
             metrics: Arc::new(Metrics::default()),
             metrics_kinds: Mutex::new(vec![]),
+            generator_profiler: Arc::new(GeneratorProfiler::default()),
+            acceptance_collector: Arc::new(AcceptanceCollector::default()),
+            last_mempool_sample: Mutex::new(None),
+            last_pending_incoming_refresh: Mutex::new(None),
+            node_capabilities: Mutex::new(None),
+            subscriptions: AddressSubscriptions::default(),
         }
     }
 }
@@ -147,6 +180,37 @@ impl UtxoProcessor {
         &self.inner.metrics
     }
 
+    /// Transaction generator stage timings aggregated across all
+    /// generators using this processor (see [`GeneratorProfiler`]).
+    pub fn generator_profiler(&self) -> &Arc<GeneratorProfiler> {
+        &self.inner.generator_profiler
+    }
+
+    /// Collector of transaction acceptance time observations (disabled by default).
+    pub fn acceptance_collector(&self) -> &Arc<AcceptanceCollector> {
+        &self.inner.acceptance_collector
+    }
+
+    /// Optional features of the connected node, probed on connection.
+    /// Returns `None` while the processor is not connected.
+    pub fn node_capabilities(&self) -> Option<Arc<NodeCapabilities>> {
+        self.inner.node_capabilities.lock().unwrap().clone()
+    }
+
+    /// Returns `false` if the connected node is known not to support the feature.
+    pub fn supports(&self, feature: NodeFeature) -> bool {
+        self.node_capabilities().map(|capabilities| capabilities.supports(feature)).unwrap_or(true)
+    }
+
+    /// Returns [`Error::NotSupportedByNode`] if the connected node is known not to support the feature.
+    pub fn check_supported(&self, feature: NodeFeature) -> Result<()> {
+        if self.supports(feature) {
+            Ok(())
+        } else {
+            Err(Error::NotSupportedByNode(feature))
+        }
+    }
+
     pub fn wallet_bus(&self) -> &Option<Channel<WalletBusMessage>> {
         &self.inner.wallet_bus
     }
@@ -171,17 +235,74 @@ impl UtxoProcessor {
         self.inner.listener_id.lock().unwrap().ok_or(Error::ListenerId)
     }
 
-    pub fn set_network_id(&self, network_id: &NetworkId) {
+    /// Set the network id. Fails if the active [`MaturityPolicy`]
+    /// is not valid for the parameters of the new network.
+    pub fn set_network_id(&self, network_id: &NetworkId) -> Result<()> {
+        self.maturity_policy().validate((*network_id).into())?;
         self.inner.network_id.lock().unwrap().replace(*network_id);
+        Ok(())
     }
 
     pub fn network_id(&self) -> Result<NetworkId> {
         (*self.inner.network_id.lock().unwrap()).ok_or(Error::MissingNetworkId)
     }
 
-    pub fn network_params(&self) -> Result<&'static NetworkParams> {
+    /// Returns [`NetworkParams`] for the current network with
+    /// maturity periods adjusted by the active [`MaturityPolicy`].
+    pub fn network_params(&self) -> Result<NetworkParams> {
         let network_id = (*self.inner.network_id.lock().unwrap()).ok_or(Error::MissingNetworkId)?;
-        Ok(network_id.into())
+        let params: &'static NetworkParams = network_id.into();
+        Ok(params.with_maturity_policy(&self.maturity_policy()))
+    }
+
+    /// Returns consensus [`Params`] used for transaction mass and fee calculations:
+    /// custom params supplied via [`UtxoProcessor::set_consensus_params()`] or
+    /// the default params of the current network.
+    pub fn consensus_params(&self) -> Result<Params> {
+        if let Some(params) = self.inner.consensus_params.lock().unwrap().as_ref() {
+            return Ok((**params).clone());
+        }
+        Ok(self.network_id()?.into())
+    }
+
+    /// Set custom consensus [`Params`] (such as params of a devnet running with
+    /// non-default settings) to be used instead of the default params of the
+    /// network. Passing `None` reverts to the network defaults. The params are
+    /// validated against the network of the node on connect.
+    pub fn set_consensus_params(&self, params: Option<Params>) -> Result<()> {
+        if let (Some(params), Ok(network_id)) = (params.as_ref(), self.network_id()) {
+            if params.net != network_id {
+                return Err(Error::InvalidNetworkType(network_id.to_string(), params.net.to_string()));
+            }
+        }
+        *self.inner.consensus_params.lock().unwrap() = params.map(Arc::new);
+        Ok(())
+    }
+
+    pub fn maturity_policy(&self) -> MaturityPolicy {
+        *self.inner.maturity_policy.lock().unwrap()
+    }
+
+    /// Set the maturity policy used to classify incoming UTXOs as pending or mature.
+    /// The policy applies to subsequently processed UTXO entries and to the
+    /// maturity checks performed on each DAA score change. The policy is validated
+    /// against the network parameters and can not be set before the network id.
+    pub fn set_maturity_policy(&self, policy: MaturityPolicy) -> Result<()> {
+        policy.validate(self.network_id()?.into())?;
+        *self.inner.maturity_policy.lock().unwrap() = policy;
+        Ok(())
+    }
+
+    pub fn balance_event_throttle(&self) -> Option<Duration> {
+        *self.inner.balance_event_throttle.lock().unwrap()
+    }
+
+    /// Set the minimum interval between [`Events::Balance`] notifications
+    /// emitted by each [`UtxoContext`]. Balance updates occurring within
+    /// the interval are coalesced and only the latest balance is emitted.
+    /// Passing `None` disables throttling.
+    pub fn set_balance_event_throttle(&self, interval: Option<Duration>) {
+        *self.inner.balance_event_throttle.lock().unwrap() = interval.filter(|interval| !interval.is_zero());
     }
 
     pub fn pending(&self) -> &DashMap<UtxoEntryId, PendingUtxoEntryReference> {
@@ -192,6 +313,10 @@ impl UtxoProcessor {
         &self.inner.outgoing
     }
 
+    pub fn reorged(&self) -> &DashMap<TransactionId, (OutgoingTransaction, u64)> {
+        &self.inner.reorged
+    }
+
     pub fn stasis(&self) -> &DashMap<UtxoEntryId, PendingUtxoEntryReference> {
         &self.inner.stasis
     }
@@ -213,16 +338,13 @@ impl UtxoProcessor {
             self.inner.address_to_utxo_context_map.insert(address.clone(), utxo_context.clone());
         });
 
-        if self.is_connected() {
-            if !addresses.is_empty() {
-                let addresses = addresses.into_iter().map(|address| (*address).clone()).collect::<Vec<_>>();
-                let utxos_changed_scope = UtxosChangedScope::new(addresses);
-                self.rpc_api().start_notify(self.listener_id()?, utxos_changed_scope.into()).await?;
-            } else {
-                log_error!("registering an empty address list!");
-            }
+        if addresses.is_empty() {
+            log!(Utxo, Error, "registering an empty address list!");
+            return Ok(());
         }
-        Ok(())
+
+        self.inner.subscriptions.track(addresses.iter().map(|address| address.as_ref()));
+        self.sync_subscriptions().await
     }
 
     pub async fn unregister_addresses(&self, addresses: Vec<Arc<Address>>) -> Result<()> {
@@ -230,14 +352,27 @@ impl UtxoProcessor {
             self.inner.address_to_utxo_context_map.remove(address);
         });
 
-        if self.is_connected() {
-            if !addresses.is_empty() {
-                let addresses = addresses.into_iter().map(|address| (*address).clone()).collect::<Vec<_>>();
-                let utxos_changed_scope = UtxosChangedScope::new(addresses);
-                self.rpc_api().stop_notify(self.listener_id()?, utxos_changed_scope.into()).await?;
-            } else {
-                log_error!("unregistering empty address list!");
-            }
+        if addresses.is_empty() {
+            log!(Utxo, Error, "unregistering empty address list!");
+            return Ok(());
+        }
+
+        self.inner.subscriptions.untrack(addresses.iter().map(|address| address.as_ref()));
+        self.sync_subscriptions().await
+    }
+
+    /// `UtxosChanged` address subscriptions of the notification listener.
+    pub fn subscriptions(&self) -> &AddressSubscriptions {
+        &self.inner.subscriptions
+    }
+
+    /// Apply changes of the registered address set to the subscriptions of
+    /// the notification listener. While disconnected (or before the listener
+    /// is registered) the changes are retained and applied on connection.
+    async fn sync_subscriptions(&self) -> Result<()> {
+        let listener_id = *self.inner.listener_id.lock().unwrap();
+        if let Some(listener_id) = listener_id.filter(|_| self.is_connected()) {
+            self.inner.subscriptions.sync(&self.rpc_api(), listener_id).await?;
         }
         Ok(())
     }
@@ -261,6 +396,8 @@ impl UtxoProcessor {
         self.notify(Events::DaaScoreChange { current_daa_score }).await?;
         self.handle_pending(current_daa_score).await?;
         self.handle_outgoing(current_daa_score).await?;
+        self.handle_mempool_sample(current_daa_score);
+        self.handle_pending_incoming();
         Ok(())
     }
 
@@ -271,7 +408,7 @@ impl UtxoProcessor {
             // scan and remove any pending entries that gained maturity
             let mut mature_entries = vec![];
             let pending_entries = &self.inner.pending;
-            pending_entries.retain(|_, pending_entry| match pending_entry.maturity(params, current_daa_score) {
+            pending_entries.retain(|_, pending_entry| match pending_entry.maturity(&params, current_daa_score) {
                 Maturity::Confirmed => {
                     mature_entries.push(pending_entry.clone());
                     false
@@ -284,7 +421,7 @@ impl UtxoProcessor {
             let mut revived_entries = vec![];
             let stasis_entries = &self.inner.stasis;
             stasis_entries.retain(|_, stasis_entry| {
-                match stasis_entry.maturity(params, current_daa_score) {
+                match stasis_entry.maturity(&params, current_daa_score) {
                     Maturity::Confirmed => {
                         mature_entries.push(stasis_entry.clone());
                         false
@@ -340,15 +477,89 @@ impl UtxoProcessor {
             }
         });
 
+        self.inner.reorged.retain(|_, (_, reorg_daa_score)| (*reorg_daa_score + longevity) >= current_daa_score);
+
         Ok(())
     }
 
+    /// Periodically sample the node mempool if the [`AcceptanceCollector`]
+    /// observes mempool transactions. Sampling runs in a detached task as
+    /// it must not delay the processing of the DAA score notifications.
+    fn handle_mempool_sample(&self, current_daa_score: u64) {
+        if !self.inner.acceptance_collector.observes_mempool() {
+            return;
+        }
+
+        {
+            let mut last_mempool_sample = self.inner.last_mempool_sample.lock().unwrap();
+            if last_mempool_sample.is_some_and(|last| last.elapsed() < MEMPOOL_SAMPLE_INTERVAL) {
+                return;
+            }
+            last_mempool_sample.replace(Instant::now());
+        }
+
+        if let Some(rpc_api) = self.try_rpc_api() {
+            let acceptance_collector = self.inner.acceptance_collector.clone();
+            spawn(async move {
+                match rpc_api.get_mempool_entries(false, false).await {
+                    Ok(entries) => acceptance_collector.ingest_mempool_entries(&entries, current_daa_score),
+                    Err(err) => log!(RpcClient, Warn, "Unable to sample mempool entries: {err}"),
+                }
+            });
+        }
+    }
+
+    /// Periodically refresh the pending incoming transactions retained by
+    /// the UTXO contexts, so that transactions evicted from the mempool
+    /// are no longer reported by the balance.
+    fn handle_pending_incoming(&self) {
+        if self
+            .inner
+            .last_pending_incoming_refresh
+            .lock()
+            .unwrap()
+            .is_some_and(|last| last.elapsed() < PENDING_INCOMING_REFRESH_INTERVAL)
+        {
+            return;
+        }
+
+        let contexts = self
+            .inner
+            .address_to_utxo_context_map
+            .iter()
+            .filter(|entry| entry.value().has_pending_incoming())
+            .map(|entry| (entry.value().id(), entry.value().clone()))
+            .collect::<HashMap<_, _>>();
+        if !contexts.is_empty() {
+            self.inner.last_pending_incoming_refresh.lock().unwrap().replace(Instant::now());
+            self.refresh_pending_incoming(contexts.into_values().collect());
+        }
+    }
+
+    /// Refresh the pending incoming transactions of the `contexts` in a
+    /// detached task as the mempool query must not delay the processing
+    /// of the node notifications.
+    fn refresh_pending_incoming(&self, contexts: Vec<UtxoContext>) {
+        if contexts.is_empty() {
+            return;
+        }
+
+        spawn(async move {
+            for context in contexts {
+                if let Err(err) = context.refresh_pending_incoming().await {
+                    log!(RpcClient, Warn, "Unable to refresh pending incoming transactions: {err}");
+                }
+            }
+        });
+    }
+
     pub fn register_outgoing_transaction(&self, outgoing_transaction: OutgoingTransaction) {
         self.inner.outgoing.insert(outgoing_transaction.id(), outgoing_transaction);
     }
 
     pub fn cancel_outgoing_transaction(&self, transaction_id: TransactionId) {
         self.inner.outgoing.remove(&transaction_id);
+        self.inner.acceptance_collector.cancel_submission(&transaction_id);
     }
 
     pub async fn handle_discovery(&self, record: TransactionRecord) -> Result<()> {
@@ -361,6 +572,11 @@ impl UtxoProcessor {
         } else {
             // otherwise we fetch the unixtime and broadcast the discovery event
             let transaction_daa_score = record.block_daa_score();
+            if !self.supports(NodeFeature::DaaScoreTimestampEstimate) {
+                // the node can not resolve the unixtime, the record is delivered without it
+                self.notify(Events::Discovery { record }).await?;
+                return Ok(());
+            }
             match self.rpc_api().get_daa_score_timestamp_estimate(vec![transaction_daa_score]).await {
                 Ok(timestamps) => {
                     if let Some(timestamp) = timestamps.first() {
@@ -398,7 +614,7 @@ impl UtxoProcessor {
                 let entries = entries.into_iter().map(|entry| entry.into()).collect::<Vec<_>>();
                 utxo_context.handle_utxo_removed(entries, current_daa_score).await?;
             } else {
-                log_error!("receiving UTXO Changed 'removed' notification for an unknown address: {}", address);
+                log!(Utxo, Error, "receiving UTXO Changed 'removed' notification for an unknown address: {}", address);
             }
         }
 
@@ -410,7 +626,7 @@ impl UtxoProcessor {
                 let entries = entries.into_iter().map(|entry| entry.into()).collect::<Vec<UtxoEntryReference>>();
                 utxo_context.handle_utxo_added(entries, current_daa_score).await?;
             } else {
-                log_error!("receiving UTXO Changed 'added' notification for an unknown address: {}", address);
+                log!(Utxo, Error, "receiving UTXO Changed 'added' notification for an unknown address: {}", address);
             }
         }
 
@@ -420,6 +636,67 @@ impl UtxoProcessor {
             context.update_balance().await?;
         }
 
+        // UTXO changes can result from transactions conflicting
+        // with the pending incoming transactions of the contexts
+        self.refresh_pending_incoming(updated_contexts.into_iter().filter(|context| context.has_pending_incoming()).collect());
+
+        Ok(())
+    }
+
+    /// Track the acceptance of outgoing transactions by the selected parent chain.
+    /// Outgoing transactions accepted by chain blocks removed from the chain (and
+    /// not accepted again by the added blocks) have been invalidated by a reorg;
+    /// their consumed UTXOs are returned to the originating [`UtxoContext`].
+    /// Reorgs are tracked while the outgoing transactions are retained by the
+    /// processor (i.e. during the user transaction maturity period). Reorged
+    /// transactions are tracked for the same period and are registered as
+    /// outgoing again if they are accepted by the new chain.
+    pub async fn handle_virtual_chain_changed(&self, notification: VirtualChainChangedNotification) -> Result<()> {
+        let outgoing = &self.inner.outgoing;
+        let reorged = &self.inner.reorged;
+        if outgoing.is_empty() && reorged.is_empty() {
+            return Ok(());
+        }
+
+        let mut reaccepted = vec![];
+        for acceptance in notification.accepted_transaction_ids.iter() {
+            for transaction_id in acceptance.accepted_transaction_ids.iter() {
+                if let Some(outgoing_transaction) = outgoing.get(transaction_id) {
+                    outgoing_transaction.set_accepting_block_hash(acceptance.accepting_block_hash);
+                } else if let Some((_, (outgoing_transaction, _))) = reorged.remove(transaction_id) {
+                    outgoing_transaction.set_accepting_block_hash(acceptance.accepting_block_hash);
+                    reaccepted.push(outgoing_transaction);
+                }
+            }
+        }
+
+        for outgoing_transaction in reaccepted.into_iter() {
+            log!(Utxo, Info, "Reorged outgoing transaction {} has been accepted again", outgoing_transaction.id());
+            outgoing_transaction.originating_context().handle_outgoing_reacceptance(&outgoing_transaction).await?;
+        }
+
+        if notification.removed_chain_block_hashes.is_empty() {
+            return Ok(());
+        }
+
+        let removed = notification.removed_chain_block_hashes.iter().collect::<AHashSet<_>>();
+        let mut reverted = vec![];
+        outgoing.retain(|_, outgoing_transaction| {
+            if outgoing_transaction.accepting_block_hash().is_some_and(|hash| removed.contains(&hash)) {
+                reverted.push(outgoing_transaction.clone());
+                false
+            } else {
+                true
+            }
+        });
+
+        let current_daa_score = self.inner.current_daa_score.load(Ordering::SeqCst);
+        for outgoing_transaction in reverted.into_iter() {
+            log!(Utxo, Warn, "Outgoing transaction {} has been reorged out", outgoing_transaction.id());
+            reorged.insert(outgoing_transaction.id(), (outgoing_transaction.clone(), current_daa_score));
+            outgoing_transaction.originating_context().handle_outgoing_reorg(&outgoing_transaction).await?;
+        }
+
         Ok(())
     }
 
@@ -455,6 +732,12 @@ impl UtxoProcessor {
             return Err(Error::InvalidNetworkType(network_id.to_string(), server_network_id.to_string()));
         }
 
+        if let Some(params) = self.inner.consensus_params.lock().unwrap().as_ref() {
+            if params.net != server_network_id {
+                return Err(Error::InvalidNetworkType(params.net.to_string(), server_network_id.to_string()));
+            }
+        }
+
         if rpc_api_version[0] > RPC_API_VERSION[0] || rpc_api_version[1] > RPC_API_VERSION[1] {
             let current = RPC_API_VERSION.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(".");
             let connected = rpc_api_version.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(".");
@@ -463,7 +746,11 @@ impl UtxoProcessor {
 
         self.inner.current_daa_score.store(virtual_daa_score, Ordering::SeqCst);
 
-        log_trace!("Connected to kaspad: '{server_version}' on '{server_network_id}';  SYNC: {is_synced}  DAA: {virtual_daa_score}");
+        log!(
+            RpcClient,
+            Trace,
+            "Connected to kaspad: '{server_version}' on '{server_network_id}';  SYNC: {is_synced}  DAA: {virtual_daa_score}"
+        );
         self.notify(Events::ServerStatus { server_version, is_synced, network_id, url: self.rpc_url() }).await?;
 
         Ok(is_synced)
@@ -471,6 +758,7 @@ impl UtxoProcessor {
 
     pub async fn handle_connect_impl(&self) -> Result<()> {
         let is_synced = self.init_state_from_server().await?;
+        self.probe_node_capabilities().await;
         self.inner.is_connected.store(true, Ordering::SeqCst);
         self.register_notification_listener().await?;
         self.notify(Events::UtxoProcStart).await?;
@@ -492,7 +780,7 @@ impl UtxoProcessor {
 
         match self.handle_connect_impl().await {
             Err(err) => {
-                log_error!("UtxoProcessor: error while connecting to node: {err}");
+                log!(RpcClient, Error, "UtxoProcessor: error while connecting to node: {err}");
                 self.notify(Events::UtxoProcError { message: err.to_string() }).await?;
                 if let Some(client) = self.rpc_client() {
                     // try force disconnect the client if we have failed
@@ -509,23 +797,43 @@ impl UtxoProcessor {
         let _ = self.inner.connect_disconnect_guard.lock().await;
 
         self.inner.is_connected.store(false, Ordering::SeqCst);
+        self.inner.node_capabilities.lock().unwrap().take();
         // self.stop_metrics();
 
         self.inner.metrics.unregister_sink();
 
         self.unregister_notification_listener().await?;
         self.notify(Events::UtxoProcStop).await?;
-        self.cleanup().await?;
+        // address registrations are retained and re-subscribed on reconnect
+        self.clear_transient_state();
 
         Ok(())
     }
 
+    /// Determine the optional features supported by the connected node;
+    /// features depending on unsupported RPC methods are disabled.
+    async fn probe_node_capabilities(&self) {
+        let capabilities = NodeCapabilities::probe(self.rpc_api().as_ref()).await;
+        let unsupported = capabilities.unsupported();
+        if !unsupported.is_empty() {
+            let unsupported = unsupported.iter().map(|feature| feature.to_string()).collect::<Vec<_>>().join(", ");
+            log!(RpcClient, Warn, "The node does not support {unsupported}, dependent wallet features are disabled");
+        }
+        self.inner.node_capabilities.lock().unwrap().replace(Arc::new(capabilities));
+    }
+
     pub async fn cleanup(&self) -> Result<()> {
+        self.clear_transient_state();
+        self.inner.address_to_utxo_context_map.clear();
+        self.inner.subscriptions.untrack_all();
+        self.sync_subscriptions().await
+    }
+
+    fn clear_transient_state(&self) {
         self.inner.pending.clear();
         self.inner.stasis.clear();
         self.inner.outgoing.clear();
-        self.inner.address_to_utxo_context_map.clear();
-        Ok(())
+        self.inner.reorged.clear();
     }
 
     async fn register_notification_listener(&self) -> Result<()> {
@@ -536,12 +844,17 @@ impl UtxoProcessor {
         ));
         *self.inner.listener_id.lock().unwrap() = Some(listener_id);
         self.rpc_api().start_notify(listener_id, Scope::VirtualDaaScoreChanged(VirtualDaaScoreChangedScope {})).await?;
+        // accepted transaction ids are used to detect reorgs of outgoing transactions
+        self.rpc_api().start_notify(listener_id, Scope::VirtualChainChanged(VirtualChainChangedScope::new(true))).await?;
+        // re-subscribe addresses registered prior to the connection
+        self.inner.subscriptions.sync(&self.rpc_api(), listener_id).await?;
         Ok(())
     }
 
     async fn unregister_notification_listener(&self) -> Result<()> {
         let listener_id = self.inner.listener_id.lock().unwrap().take();
         if let Some(id) = listener_id {
+            self.inner.subscriptions.remove_listener(id);
             // we do not need this as we are unregister the entire listener here...
             self.rpc_api().unregister_listener(id).await?;
         }
@@ -564,8 +877,12 @@ impl UtxoProcessor {
                 self.handle_utxo_changed(utxos_changed_notification).await?;
             }
 
+            Notification::VirtualChainChanged(virtual_chain_changed_notification) => {
+                self.handle_virtual_chain_changed(virtual_chain_changed_notification).await?;
+            }
+
             _ => {
-                log_warn!("unknown notification: {:?}", notification);
+                log!(RpcClient, Warn, "unknown notification: {:?}", notification);
             }
         }
 
@@ -583,6 +900,14 @@ impl UtxoProcessor {
                     let metrics = MetricsUpdate::WalletMetrics { mempool_size, node_peers, network_tps };
                     self.try_notify(Events::Metrics { network_id: self.network_id()?, metrics })?;
                 }
+                MetricsUpdateKind::GeneratorMetrics => {
+                    let metrics = MetricsUpdate::GeneratorMetrics { profile: self.inner.generator_profiler.profile() };
+                    self.try_notify(Events::Metrics { network_id: self.network_id()?, metrics })?;
+                }
+                MetricsUpdateKind::StorageMetrics => {
+                    let metrics = MetricsUpdate::StorageMetrics { account_payloads: AccountPayloadMetrics::get() };
+                    self.try_notify(Events::Metrics { network_id: self.network_id()?, metrics })?;
+                }
             }
         }
 
@@ -618,7 +943,7 @@ impl UtxoProcessor {
         // clients relying on UtxoProcessor state should monitor
         // for and handle `UtxoProcStart` and `UtxoProcStop` events.
         if this.rpc_ctl().is_connected() {
-            this.handle_connect().await.unwrap_or_else(|err| log_error!("{err}"));
+            this.handle_connect().await.unwrap_or_else(|err| log!(Utxo, Error, "{err}"));
         }
 
         spawn(async move {
@@ -633,12 +958,12 @@ impl UtxoProcessor {
                                     RpcState::Connected => {
                                         if !this.is_connected() {
                                             if let Err(err) = this.handle_connect().await {
-                                                log_error!("UtxoProcessor error: {err}");
+                                                log!(Utxo, Error, "UtxoProcessor error: {err}");
                                             } else {
                                                 this.inner.multiplexer.try_broadcast(Box::new(Events::Connect {
                                                     network_id : this.network_id().expect("network id expected during connection"),
                                                     url : this.rpc_url()
-                                                })).unwrap_or_else(|err| log_error!("{err}"));
+                                                })).unwrap_or_else(|err| log!(Utxo, Error, "{err}"));
                                             }
                                         }
                                     },
@@ -647,15 +972,17 @@ impl UtxoProcessor {
                                             this.inner.multiplexer.try_broadcast(Box::new(Events::Disconnect {
                                                 network_id : this.network_id().expect("network id expected during connection"),
                                                 url : this.rpc_url()
-                                            })).unwrap_or_else(|err| log_error!("{err}"));
-                                            this.handle_disconnect().await.unwrap_or_else(|err| log_error!("{err}"));
+                                            })).unwrap_or_else(|err| log!(Utxo, Error, "{err}"));
+                                            this.handle_disconnect().await.unwrap_or_else(|err| log!(Utxo, Error, "{err}"));
                                         }
                                     }
+                                    // the processor re-registers its listener on connection
+                                    RpcState::SubscriptionsRestored => {}
                                 }
                             }
                             Err(err) => {
-                                log_error!("UtxoProcessor: error while receiving rpc_ctl_channel message: {err}");
-                                log_error!("Suspending UTXO processor...");
+                                log!(RpcClient, Error, "UtxoProcessor: error while receiving rpc_ctl_channel message: {err}");
+                                log!(RpcClient, Error, "Suspending UTXO processor...");
                                 break;
                             }
                         }
@@ -665,12 +992,12 @@ impl UtxoProcessor {
                             Ok(notification) => {
                                 if let Err(err) = this.handle_notification(notification).await {
                                     this.notify(Events::UtxoProcError { message: err.to_string() }).await.ok();
-                                    log_error!("error while handling notification: {err}");
+                                    log!(Utxo, Error, "error while handling notification: {err}");
                                 }
                             }
                             Err(err) => {
-                                log_error!("RPC notification channel error: {err}");
-                                log_error!("Suspending UTXO processor...");
+                                log!(RpcClient, Error, "RPC notification channel error: {err}");
+                                log!(RpcClient, Error, "Suspending UTXO processor...");
                                 break;
                             }
                         }
@@ -688,7 +1015,7 @@ impl UtxoProcessor {
 
             // handle power down on rpc channel that remains connected
             if this.is_connected() {
-                this.handle_disconnect().await.unwrap_or_else(|err| log_error!("{err}"));
+                this.handle_disconnect().await.unwrap_or_else(|err| log!(Utxo, Error, "{err}"));
             }
 
             this.inner.task_is_running.store(false, Ordering::SeqCst);
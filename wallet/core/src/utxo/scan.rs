@@ -6,11 +6,19 @@
 
 use crate::derivation::AddressManager;
 use crate::imports::*;
+use crate::logging::log;
 use crate::utxo::balance::AtomicBalance;
 use crate::utxo::{UtxoContext, UtxoEntryReference, UtxoEntryReferenceExtension};
+use crate::wallet::{WalletSync, WalletSyncPhase};
+use kaspa_rpc_core::{RpcUtxosByAddressesCursor, RpcUtxosByAddressesEntry};
+use kaspa_wrpc_client::capabilities::NodeFeature;
 use std::cmp::max;
 
+/// Default number of consecutive unused addresses scanned past the last
+/// used address (address gap limit), see [`Account::gap_limit()`](crate::account::Account::gap_limit).
 pub const DEFAULT_WINDOW_SIZE: usize = 8;
+/// Maximum number of UTXO entries fetched from the node in a single request
+pub const DEFAULT_PAGE_SIZE: u32 = 10_000;
 
 #[derive(Default, Clone, Copy)]
 pub enum ScanExtent {
@@ -26,12 +34,65 @@ enum Provider {
     AddressSet(HashSet<Address>),
 }
 
+/// Fetches UTXO entries of a set of addresses page by page, allowing
+/// addresses with a large number of UTXOs to be processed incrementally.
+/// Falls back to a single `get_utxos_by_addresses()` call if the node
+/// does not support paginated queries.
+struct UtxoPager {
+    rpc_api: Arc<DynRpcApi>,
+    addresses: Vec<Address>,
+    start_after: Option<RpcUtxosByAddressesCursor>,
+    paginated: bool,
+    first: bool,
+    done: bool,
+}
+
+impl UtxoPager {
+    fn new(rpc_api: Arc<DynRpcApi>, addresses: Vec<Address>, paginated: bool) -> Self {
+        Self { rpc_api, addresses, start_after: None, paginated, first: true, done: false }
+    }
+
+    /// Returns `false` if the node does not support paginated queries.
+    fn paginated(&self) -> bool {
+        self.paginated
+    }
+
+    async fn next(&mut self) -> Result<Option<Vec<RpcUtxosByAddressesEntry>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        if !self.paginated {
+            self.done = true;
+            return Ok(Some(self.rpc_api.get_utxos_by_addresses(self.addresses.clone()).await?));
+        }
+
+        let first = std::mem::replace(&mut self.first, false);
+        match self.rpc_api.get_utxos_by_addresses_page(self.addresses.clone(), self.start_after.take(), DEFAULT_PAGE_SIZE).await {
+            Ok(page) => {
+                self.done = page.next.is_none();
+                self.start_after = page.next;
+                Ok(Some(page.entries))
+            }
+            Err(err) if first => {
+                log!(RpcClient, Warn, "get_utxos_by_addresses_page() is not available ({err}), fetching all entries at once");
+                self.paginated = false;
+                self.done = true;
+                Ok(Some(self.rpc_api.get_utxos_by_addresses(self.addresses.clone()).await?))
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
 pub struct Scan {
     provider: Provider,
     window_size: Option<usize>,
     extent: Option<ScanExtent>,
     balance: Arc<AtomicBalance>,
     current_daa_score: u64,
+    paginated: AtomicBool,
+    wallet_sync: Option<WalletSync>,
 }
 
 impl Scan {
@@ -42,7 +103,15 @@ impl Scan {
         window_size: Option<usize>,
         extent: Option<ScanExtent>,
     ) -> Scan {
-        Scan { provider: Provider::AddressManager(address_manager), window_size, extent, balance: balance.clone(), current_daa_score }
+        Scan {
+            provider: Provider::AddressManager(address_manager),
+            window_size,
+            extent,
+            balance: balance.clone(),
+            current_daa_score,
+            paginated: AtomicBool::new(true),
+            wallet_sync: None,
+        }
     }
     pub fn new_with_address_set(addresses: HashSet<Address>, balance: &Arc<AtomicBalance>, current_daa_score: u64) -> Scan {
         Scan {
@@ -51,9 +120,35 @@ impl Scan {
             extent: None,
             balance: balance.clone(),
             current_daa_score,
+            paginated: AtomicBool::new(true),
+            wallet_sync: None,
+        }
+    }
+
+    /// Report the scanned addresses as the [`WalletSyncPhase::AddressScan`]
+    /// progress of the `wallet_sync`.
+    pub fn with_wallet_sync(mut self, wallet_sync: &WalletSync) -> Self {
+        self.wallet_sync = Some(wallet_sync.clone());
+        self
+    }
+
+    fn extend_sync(&self, count: u32) {
+        if let Some(wallet_sync) = &self.wallet_sync {
+            wallet_sync.extend(WalletSyncPhase::AddressScan, count as u64);
         }
     }
 
+    fn advance_sync(&self, count: u32) {
+        if let Some(wallet_sync) = &self.wallet_sync {
+            wallet_sync.advance(WalletSyncPhase::AddressScan, count as u64);
+        }
+    }
+
+    /// Paginated queries are used unless the node is known not to support them.
+    fn paginated(&self, utxo_context: &UtxoContext) -> bool {
+        self.paginated.load(Ordering::Relaxed) && utxo_context.processor().supports(NodeFeature::UtxosByAddressesPage)
+    }
+
     pub async fn scan(&self, utxo_context: &UtxoContext) -> Result<()> {
         match &self.provider {
             Provider::AddressManager(address_manager) => self.scan_with_address_manager(address_manager, utxo_context).await,
@@ -70,11 +165,23 @@ impl Scan {
         let mut cursor: u32 = 0;
         let mut last_address_index = address_manager.index();
 
+        // number of addresses the scan is expected to cover unless
+        // used addresses are found past the last known address index
+        let mut expected = match extent {
+            ScanExtent::EmptyWindow => max(last_address_index + 1, window_size) + window_size,
+            ScanExtent::Depth(depth) => max(max(last_address_index + 1, window_size), depth.saturating_add(1)),
+        };
+        self.extend_sync(expected);
+
         'scan: loop {
             // scan first up to address index, then in window chunks
             let first = cursor;
             let last = if cursor == 0 { max(last_address_index + 1, window_size) } else { cursor + window_size };
             cursor = last;
+            if last > expected {
+                self.extend_sync(last - expected);
+                expected = last;
+            }
 
             // generate address derivations
             let addresses = address_manager.get_range(first..last)?;
@@ -85,15 +192,16 @@ impl Scan {
             utxo_context.register_addresses(&addresses).await?;
 
             let ts = Instant::now();
-            let resp = utxo_context.processor().rpc_api().get_utxos_by_addresses(addresses).await?;
-            let elapsed_msec = ts.elapsed().as_secs_f32();
-            if elapsed_msec > 1.0 {
-                log_warn!("get_utxos_by_address() fetched {} entries in: {} msec", resp.len(), elapsed_msec);
-            }
-            yield_executor().await;
+            let mut entry_count = 0;
+            let mut pager = UtxoPager::new(utxo_context.processor().rpc_api(), addresses, self.paginated(utxo_context));
+            while let Some(entries) = pager.next().await? {
+                yield_executor().await;
+                if entries.is_empty() {
+                    continue;
+                }
 
-            if !resp.is_empty() {
-                let refs: Vec<UtxoEntryReference> = resp.into_iter().map(UtxoEntryReference::from).collect();
+                entry_count += entries.len();
+                let refs: Vec<UtxoEntryReference> = entries.into_iter().map(UtxoEntryReference::from).collect();
                 for utxo_ref in refs.iter() {
                     if let Some(address) = utxo_ref.utxo.address.as_ref() {
                         if let Some(utxo_address_index) = address_manager.inner().address_to_index_map.get(address) {
@@ -106,20 +214,16 @@ impl Scan {
                     }
                 }
 
-                let balance: Balance = refs.iter().fold(Balance::default(), |mut balance, r| {
-                    let entry_balance = r.balance(params, self.current_daa_score);
-                    balance.mature += entry_balance.mature;
-                    balance.pending += entry_balance.pending;
-                    balance.mature_utxo_count += entry_balance.mature_utxo_count;
-                    balance.pending_utxo_count += entry_balance.pending_utxo_count;
-                    balance.stasis_utxo_count += entry_balance.stasis_utxo_count;
-                    balance
-                });
-
-                utxo_context.extend_from_scan(refs, self.current_daa_score).await?;
+                self.apply(refs, &params, utxo_context).await?;
+            }
+            self.paginated.store(pager.paginated(), Ordering::Relaxed);
+            self.advance_sync(last - first);
+            let elapsed_msec = ts.elapsed().as_secs_f32();
+            if elapsed_msec > 1.0 {
+                log!(RpcClient, Warn, "get_utxos_by_address() fetched {} entries in: {} msec", entry_count, elapsed_msec);
+            }
 
-                self.balance.add(balance);
-            } else {
+            if entry_count == 0 {
                 match &extent {
                     ScanExtent::EmptyWindow => {
                         if cursor > last_address_index + window_size {
@@ -136,6 +240,10 @@ impl Scan {
             yield_executor().await;
         }
 
+        if expected > cursor {
+            self.advance_sync(expected - cursor);
+        }
+
         // update address manager with the last used index
         address_manager.set_index(last_address_index)?;
 
@@ -145,11 +253,22 @@ impl Scan {
     pub async fn scan_with_address_set(&self, address_set: &HashSet<Address>, utxo_context: &UtxoContext) -> Result<()> {
         let params = utxo_context.processor().network_params()?;
         let address_vec = address_set.iter().cloned().collect::<Vec<_>>();
+        self.extend_sync(address_set.len() as u32);
 
         utxo_context.register_addresses(&address_vec).await?;
-        let resp = utxo_context.processor().rpc_api().get_utxos_by_addresses(address_vec).await?;
-        let refs: Vec<UtxoEntryReference> = resp.into_iter().map(UtxoEntryReference::from).collect();
+        let mut pager = UtxoPager::new(utxo_context.processor().rpc_api(), address_vec, self.paginated(utxo_context));
+        while let Some(entries) = pager.next().await? {
+            let refs: Vec<UtxoEntryReference> = entries.into_iter().map(UtxoEntryReference::from).collect();
+            yield_executor().await;
+            self.apply(refs, &params, utxo_context).await?;
+        }
+        self.advance_sync(address_set.len() as u32);
+
+        Ok(())
+    }
 
+    /// Adds a page of scanned UTXO entries to the UTXO context and the scan balance.
+    async fn apply(&self, refs: Vec<UtxoEntryReference>, params: &NetworkParams, utxo_context: &UtxoContext) -> Result<()> {
         let balance: Balance = refs.iter().fold(Balance::default(), |mut balance, r| {
             let entry_balance = r.balance(params, self.current_daa_score);
             balance.mature += entry_balance.mature;
@@ -159,7 +278,6 @@ impl Scan {
             balance.stasis_utxo_count += entry_balance.stasis_utxo_count;
             balance
         });
-        yield_executor().await;
 
         utxo_context.extend_from_scan(refs, self.current_daa_score).await?;
 
@@ -5,7 +5,7 @@
 
 use crate::imports::*;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct NetworkParams {
     pub coinbase_transaction_maturity_period_daa: u64,
     pub coinbase_transaction_stasis_period_daa: u64,
@@ -54,6 +54,51 @@ pub const SIMNET_NETWORK_PARAMS: NetworkParams = NetworkParams {
     additional_compound_transaction_mass: 0,
 };
 
+impl NetworkParams {
+    /// Returns a copy of the network parameters with maturity
+    /// periods overridden by the supplied [`MaturityPolicy`].
+    pub fn with_maturity_policy(&self, policy: &MaturityPolicy) -> NetworkParams {
+        NetworkParams {
+            coinbase_transaction_maturity_period_daa: policy
+                .coinbase_transaction_maturity_period_daa
+                .unwrap_or(self.coinbase_transaction_maturity_period_daa),
+            coinbase_transaction_stasis_period_daa: policy
+                .coinbase_transaction_stasis_period_daa
+                .unwrap_or(self.coinbase_transaction_stasis_period_daa),
+            user_transaction_maturity_period_daa: policy
+                .user_transaction_maturity_period_daa
+                .unwrap_or(self.user_transaction_maturity_period_daa),
+            ..self.clone()
+        }
+    }
+}
+
+/// User-configurable maturity policy. Each field, if present,
+/// overrides the corresponding default [`NetworkParams`] value
+/// (expressed in DAA score units). UTXO entries that have not
+/// reached maturity are reported as pending and are not available
+/// for spending.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaturityPolicy {
+    /// Confirmation depth required for coinbase UTXOs.
+    pub coinbase_transaction_maturity_period_daa: Option<u64>,
+    /// Period during which coinbase UTXOs are not reported.
+    pub coinbase_transaction_stasis_period_daa: Option<u64>,
+    /// Confirmation depth required for regular (incoming) UTXOs.
+    pub user_transaction_maturity_period_daa: Option<u64>,
+}
+
+impl MaturityPolicy {
+    pub fn validate(&self, params: &NetworkParams) -> Result<()> {
+        let params = params.with_maturity_policy(self);
+        if params.coinbase_transaction_stasis_period_daa > params.coinbase_transaction_maturity_period_daa {
+            return Err(Error::InvalidArgument("coinbase stasis period can not exceed coinbase maturity period".to_string()));
+        }
+        Ok(())
+    }
+}
+
 impl From<NetworkId> for &'static NetworkParams {
     fn from(value: NetworkId) -> Self {
         match value.network_type {
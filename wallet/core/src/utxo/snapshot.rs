@@ -0,0 +1,104 @@
+//!
+//! UTXO snapshots allowing transactions to be created offline.
+//!
+//! A [`UtxoSnapshot`] is exported from a [`UtxoContext`](crate::utxo::UtxoContext) of an online wallet
+//! (see [`UtxoContext::to_snapshot`](crate::utxo::UtxoContext::to_snapshot)) and transferred to an offline
+//! machine where it is loaded into a `UtxoContext` (see [`UtxoContext::extend_from_snapshot`](crate::utxo::UtxoContext::extend_from_snapshot))
+//! used as the UTXO source by the transaction [`Generator`](crate::tx::Generator).
+//!
+
+use crate::imports::*;
+use crate::utxo::UtxoEntryReference;
+use kaspa_consensus_core::tx as cctx;
+use kaspa_rpc_core::RpcUtxosByAddressesEntry;
+
+/// Snapshot of UTXO entries. The entries are serialized using the
+/// `GetUtxosByAddresses` RPC response format, as such the response
+/// (`{ "entries": [...] }`) or its bare entry array can be loaded
+/// as a snapshot.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UtxoSnapshot {
+    /// Network the snapshot was taken on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<NetworkId>,
+    /// DAA score at the time the snapshot was taken (used to determine the UTXO maturity)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daa_score: Option<u64>,
+    pub entries: Vec<RpcUtxosByAddressesEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum UtxoSnapshotVariant {
+    Snapshot(UtxoSnapshot),
+    Entries(Vec<RpcUtxosByAddressesEntry>),
+}
+
+impl UtxoSnapshot {
+    pub fn new(network_id: Option<NetworkId>, daa_score: Option<u64>, entries: &[UtxoEntryReference]) -> Self {
+        let entries = entries
+            .iter()
+            .map(|entry| {
+                let entry = entry.as_ref();
+                RpcUtxosByAddressesEntry {
+                    address: entry.address.clone(),
+                    outpoint: entry.outpoint.clone().into(),
+                    utxo_entry: cctx::UtxoEntry::from(entry),
+                }
+            })
+            .collect();
+        Self { network_id, daa_score, entries }
+    }
+
+    /// Deserializes the snapshot from JSON (a snapshot object,
+    /// a `GetUtxosByAddresses` RPC response or an array of its entries).
+    pub fn from_json(json: &str) -> Result<Self> {
+        match serde_json::from_str::<UtxoSnapshotVariant>(json) {
+            Ok(UtxoSnapshotVariant::Snapshot(snapshot)) => Ok(snapshot),
+            Ok(UtxoSnapshotVariant::Entries(entries)) => Ok(Self { entries, ..Default::default() }),
+            Err(err) => Err(Error::InvalidUtxoSnapshot(err.to_string())),
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn entries(&self) -> Vec<UtxoEntryReference> {
+        self.entries.iter().map(UtxoEntryReference::from).collect()
+    }
+
+    /// Total amount of the snapshot UTXO entries in SOMPI.
+    pub fn amount(&self) -> u64 {
+        self.entries.iter().map(|entry| entry.utxo_entry.amount).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utxo_snapshot_json() {
+        let entries = [UtxoEntryReference::simulated(100_000_000), UtxoEntryReference::simulated(250_000_000)];
+        let snapshot = UtxoSnapshot::new(Some(NetworkId::with_suffix(NetworkType::Testnet, 10)), Some(1_000), &entries);
+
+        let json = snapshot.to_json().unwrap();
+        let restored = UtxoSnapshot::from_json(&json).unwrap();
+        assert_eq!(restored.network_id, snapshot.network_id);
+        assert_eq!(restored.daa_score, Some(1_000));
+        assert_eq!(restored.amount(), 350_000_000);
+        assert_eq!(restored.entries(), entries.to_vec());
+
+        // `GetUtxosByAddresses` response and the bare entry array
+        let value = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+        let response = serde_json::json!({ "entries": value["entries"] }).to_string();
+        let restored = UtxoSnapshot::from_json(&response).unwrap();
+        assert_eq!((restored.network_id, restored.daa_score, restored.entries().len()), (None, None, 2));
+        let restored = UtxoSnapshot::from_json(&value["entries"].to_string()).unwrap();
+        assert_eq!(restored.amount(), 350_000_000);
+
+        assert!(UtxoSnapshot::from_json("{}").is_err());
+    }
+}
@@ -19,8 +19,17 @@ impl UtxoStream {
 impl Stream for UtxoStream {
     type Item = UtxoEntryReference;
     fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let entry = self.utxo_context.context().mature.get(self.cursor).cloned();
-        self.cursor += 1;
+        let entry = {
+            let context = self.utxo_context.context();
+            let mut cursor = self.cursor;
+            while context.mature.get(cursor).is_some_and(|entry| context.locked.contains(entry.id_as_ref())) {
+                cursor += 1;
+            }
+            let entry = context.mature.get(cursor).cloned();
+            drop(context);
+            self.cursor = cursor + 1;
+            entry
+        };
         Poll::Ready(entry)
     }
 }
@@ -0,0 +1,161 @@
+//!
+//! [`AddressSubscriptions`] - management of the `UtxosChanged`
+//! address subscriptions of [`UtxoProcessor`](super::UtxoProcessor)
+//! notification listeners.
+//!
+
+use crate::imports::*;
+use crate::result::Result;
+use kaspa_notify::{listener::ListenerId, scope::UtxosChangedScope};
+
+/// Addresses to be subscribed and unsubscribed to bring
+/// the subscriptions of a listener in line with the tracked
+/// address set (see [`AddressSubscriptions::diff()`]).
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SubscriptionDiff {
+    pub added: Vec<Address>,
+    pub removed: Vec<Address>,
+}
+
+impl SubscriptionDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+#[derive(Default)]
+struct State {
+    // addresses the wallet requires notifications for
+    tracked: HashSet<Address>,
+    // addresses subscribed on the node by each listener
+    active: HashMap<ListenerId, HashSet<Address>>,
+}
+
+/// Tracks the full set of addresses monitored by the wallet as well
+/// as the addresses subscribed by each notification listener. Changes
+/// to the tracked set are applied by [`AddressSubscriptions::sync()`]
+/// as a single subscribe and a single unsubscribe call covering all
+/// accumulated changes. Subscriptions of a newly registered listener
+/// (such as after a reconnect) are restored by the same call.
+#[derive(Default)]
+pub struct AddressSubscriptions {
+    state: Mutex<State>,
+    sync_guard: AsyncMutex<()>,
+}
+
+impl AddressSubscriptions {
+    pub fn track<'a>(&self, addresses: impl IntoIterator<Item = &'a Address>) {
+        let mut state = self.state.lock().unwrap();
+        state.tracked.extend(addresses.into_iter().cloned());
+    }
+
+    pub fn untrack<'a>(&self, addresses: impl IntoIterator<Item = &'a Address>) {
+        let mut state = self.state.lock().unwrap();
+        addresses.into_iter().for_each(|address| {
+            state.tracked.remove(address);
+        });
+    }
+
+    /// Stop tracking all addresses. Subscriptions of active
+    /// listeners are removed by the subsequent [`sync()`](Self::sync).
+    pub fn untrack_all(&self) {
+        self.state.lock().unwrap().tracked.clear();
+    }
+
+    pub fn tracked(&self) -> usize {
+        self.state.lock().unwrap().tracked.len()
+    }
+
+    /// Number of addresses subscribed by the listener.
+    pub fn active(&self, listener_id: ListenerId) -> usize {
+        self.state.lock().unwrap().active.get(&listener_id).map(|active| active.len()).unwrap_or_default()
+    }
+
+    /// Compute the changes required to bring the listener subscriptions in line with the tracked addresses.
+    pub fn diff(&self, listener_id: ListenerId) -> SubscriptionDiff {
+        let state = self.state.lock().unwrap();
+        match state.active.get(&listener_id) {
+            Some(active) => SubscriptionDiff {
+                added: state.tracked.difference(active).cloned().collect(),
+                removed: active.difference(&state.tracked).cloned().collect(),
+            },
+            None => SubscriptionDiff { added: state.tracked.iter().cloned().collect(), removed: vec![] },
+        }
+    }
+
+    /// Subscribe the listener to added addresses and unsubscribe it from
+    /// removed addresses. Concurrent invocations are serialized, changes
+    /// accumulated while a sync is in progress are applied by the next one.
+    pub async fn sync(&self, rpc_api: &Arc<DynRpcApi>, listener_id: ListenerId) -> Result<SubscriptionDiff> {
+        let _guard = self.sync_guard.lock().await;
+
+        let diff = self.diff(listener_id);
+        if !diff.added.is_empty() {
+            rpc_api.start_notify(listener_id, UtxosChangedScope::new(diff.added.clone()).into()).await?;
+            self.state.lock().unwrap().active.entry(listener_id).or_default().extend(diff.added.iter().cloned());
+        }
+        if !diff.removed.is_empty() {
+            rpc_api.stop_notify(listener_id, UtxosChangedScope::new(diff.removed.clone()).into()).await?;
+            if let Some(active) = self.state.lock().unwrap().active.get_mut(&listener_id) {
+                diff.removed.iter().for_each(|address| {
+                    active.remove(address);
+                });
+            }
+        }
+
+        Ok(diff)
+    }
+
+    /// Discard the subscriptions of an unregistered listener.
+    pub fn remove_listener(&self, listener_id: ListenerId) {
+        self.state.lock().unwrap().active.remove(&listener_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::MockRpcClient;
+    use kaspa_addresses::Version;
+    use kaspa_rpc_core::notify::connection::{ChannelConnection, ChannelType};
+
+    fn address(n: u8) -> Address {
+        Address::new(Prefix::Testnet, Version::PubKey, &[n; 32])
+    }
+
+    #[tokio::test]
+    async fn test_address_subscriptions() -> Result<()> {
+        let rpc_api: Arc<DynRpcApi> = Arc::new(MockRpcClient::new());
+        let channel = Channel::unbounded();
+        let listener_id = rpc_api.register_new_listener(ChannelConnection::new("test", channel.sender, ChannelType::Persistent));
+
+        let subscriptions = AddressSubscriptions::default();
+        subscriptions.track(&[address(1), address(2)]);
+        subscriptions.track(&[address(3)]);
+        // accumulated changes are applied by a single call
+        let diff = subscriptions.sync(&rpc_api, listener_id).await?;
+        assert_eq!(diff.added.len(), 3);
+        assert!(diff.removed.is_empty());
+        assert!(subscriptions.sync(&rpc_api, listener_id).await?.is_empty());
+
+        subscriptions.untrack(&[address(1)]);
+        subscriptions.track(&[address(4)]);
+        let diff = subscriptions.sync(&rpc_api, listener_id).await?;
+        assert_eq!(diff.added, vec![address(4)]);
+        assert_eq!(diff.removed, vec![address(1)]);
+        assert_eq!(subscriptions.active(listener_id), 3);
+
+        // a new listener (reconnect) is subscribed to all tracked addresses
+        subscriptions.remove_listener(listener_id);
+        rpc_api.unregister_listener(listener_id).await?;
+        let channel = Channel::unbounded();
+        let listener_id = rpc_api.register_new_listener(ChannelConnection::new("test", channel.sender, ChannelType::Persistent));
+        assert_eq!(subscriptions.sync(&rpc_api, listener_id).await?.added.len(), 3);
+
+        subscriptions.untrack_all();
+        assert_eq!(subscriptions.sync(&rpc_api, listener_id).await?.removed.len(), 3);
+        assert_eq!(subscriptions.active(listener_id), 0);
+
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@
 //!
 
 use crate::imports::*;
+use crate::logging::log;
 use crate::result::Result;
 use futures::pin_mut;
 use futures::stream::StreamExt;
@@ -50,7 +51,7 @@ impl SyncMonitor {
                 // log_trace!("sync monitor: node synced state detected");
                 self.inner.is_synced.store(true, Ordering::SeqCst);
                 if self.is_running() {
-                    log_trace!("sync monitor: stopping sync monitor task");
+                    log!(Utxo, Trace, "sync monitor: stopping sync monitor task");
                     self.stop_task().await?;
                 }
                 self.notify(Events::SyncState { sync_state: SyncState::Synced }).await?;
@@ -58,7 +59,7 @@ impl SyncMonitor {
                 self.inner.is_synced.store(false, Ordering::SeqCst);
                 // log_trace!("sync monitor: node is not synced");
                 if !self.is_running() {
-                    log_trace!("sync monitor: starting sync monitor task");
+                    log!(Utxo, Trace, "sync monitor: starting sync monitor task");
                     self.start_task().await?;
                 }
                 self.notify(Events::SyncState { sync_state: SyncState::NotSynced }).await?;
@@ -138,7 +139,7 @@ impl SyncMonitor {
                             if is_synced {
                                 if is_synced != this.is_synced() {
                                     this.inner.is_synced.store(true, Ordering::SeqCst);
-                                    this.notify(Events::SyncState { sync_state : SyncState::Synced }).await.unwrap_or_else(|err|log_error!("SyncProc error dispatching notification event: {err}"));
+                                    this.notify(Events::SyncState { sync_state : SyncState::Synced }).await.unwrap_or_else(|err|log!(Utxo, Error, "SyncProc error dispatching notification event: {err}"));
                                 }
 
                                 break;
@@ -149,11 +150,11 @@ impl SyncMonitor {
                     msg = events.receiver.recv().fuse() => {
                         match msg {
                             Ok(event) => {
-                                this.handle_event(event).await.unwrap_or_else(|e| log_error!("SyncProc::handle_event() error: {}", e));
+                                this.handle_event(event).await.unwrap_or_else(|e| log!(Utxo, Error, "SyncProc::handle_event() error: {}", e));
                             },
                             Err(err) => {
-                                log_error!("SyncProc: error while receiving multiplexer message: {err}");
-                                log_error!("Suspending Wallet processing...");
+                                log!(Utxo, Error, "SyncProc: error while receiving multiplexer message: {err}");
+                                log!(Utxo, Error, "Suspending Wallet processing...");
 
                                 break;
                             }
@@ -162,7 +163,7 @@ impl SyncMonitor {
                 }
             }
 
-            log_trace!("sync monitor task is shutting down...");
+            log!(Utxo, Trace, "sync monitor task is shutting down...");
             this.inner.running.store(false, Ordering::SeqCst);
             task_ctl_sender.send(()).await.unwrap();
         });
@@ -1,6 +1,6 @@
 use crate::imports::*;
 use crate::result::Result;
-use crate::tests::RpcCoreMock;
+use crate::tests::MockRpcClient;
 use crate::tx::generator::test::*;
 use crate::tx::*;
 use crate::utils::*;
@@ -9,7 +9,7 @@ use crate::utxo::*;
 #[tokio::test]
 async fn test_utxo_subsystem_bootstrap() -> Result<()> {
     let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
-    let rpc_api_mock = Arc::new(RpcCoreMock::new());
+    let rpc_api_mock = Arc::new(MockRpcClient::new());
     let processor = UtxoProcessor::new(Some(rpc_api_mock.clone().into()), Some(network_id), None, None);
     let _context = UtxoContext::new(&processor, UtxoContextBinding::default());
 
@@ -32,3 +32,501 @@ fn test_utxo_generator_empty_utxo_noop() -> Result<()> {
     // assert!(tx.is_none());
     Ok(())
 }
+
+#[test]
+fn test_utxo_maturity_policy() -> Result<()> {
+    let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+    let rpc_api_mock = Arc::new(MockRpcClient::new());
+    let processor = UtxoProcessor::new(Some(rpc_api_mock.into()), Some(network_id), None, None);
+
+    let defaults: &NetworkParams = network_id.into();
+    let params = processor.network_params()?;
+    assert_eq!(params.user_transaction_maturity_period_daa, defaults.user_transaction_maturity_period_daa);
+
+    processor.set_maturity_policy(MaturityPolicy { user_transaction_maturity_period_daa: Some(1_000), ..Default::default() })?;
+    let params = processor.network_params()?;
+    assert_eq!(params.user_transaction_maturity_period_daa, 1_000);
+    assert_eq!(params.coinbase_transaction_maturity_period_daa, defaults.coinbase_transaction_maturity_period_daa);
+
+    let invalid = MaturityPolicy {
+        coinbase_transaction_maturity_period_daa: Some(10),
+        coinbase_transaction_stasis_period_daa: Some(20),
+        ..Default::default()
+    };
+    assert!(processor.set_maturity_policy(invalid).is_err());
+
+    // the policy is validated against the parameters of a new network
+    let testnet11 = NetworkId::with_suffix(NetworkType::Testnet, 11);
+    let stasis = MaturityPolicy { coinbase_transaction_stasis_period_daa: Some(200), ..Default::default() };
+    assert!(processor.set_maturity_policy(stasis).is_err());
+    processor.set_network_id(&testnet11)?;
+    processor.set_maturity_policy(stasis)?;
+    assert!(processor.set_network_id(&network_id).is_err());
+    assert_eq!(processor.network_id()?, testnet11);
+
+    // the policy can not be set before the network is known
+    let processor = UtxoProcessor::new(Some(Arc::new(MockRpcClient::new()).into()), None, None, None);
+    assert!(matches!(processor.set_maturity_policy(MaturityPolicy::default()), Err(Error::MissingNetworkId)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_utxo_maturity_policy_classification() -> Result<()> {
+    let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+    let rpc_api_mock = Arc::new(MockRpcClient::new());
+    let processor = UtxoProcessor::new(Some(rpc_api_mock.into()), Some(network_id), None, None);
+    let context = UtxoContext::new(&processor, UtxoContextBinding::default());
+
+    // simulated entries are coinbase UTXOs at DAA score 0, pending
+    // at DAA score 60 under the default maturity period of 100
+    let first = UtxoEntryReference::simulated(kaspa_to_sompi(1.0));
+    context.insert(first.clone(), 60, false).await?;
+    let balance = context.calculate_balance().await;
+    assert_eq!((balance.mature, balance.pending), (0, kaspa_to_sompi(1.0)));
+    assert_eq!(UtxoIterator::new(&context).count(), 0);
+
+    // under a shorter maturity period the same entry is available for spending
+    processor.set_maturity_policy(MaturityPolicy { coinbase_transaction_maturity_period_daa: Some(60), ..Default::default() })?;
+    let second = UtxoEntryReference::simulated(kaspa_to_sompi(2.0));
+    context.insert(second.clone(), 60, false).await?;
+    let balance = context.calculate_balance().await;
+    assert_eq!((balance.mature, balance.pending), (kaspa_to_sompi(2.0), kaspa_to_sompi(1.0)));
+    assert_eq!(UtxoIterator::new(&context).map(|entry| entry.id()).collect::<Vec<_>>(), vec![second.id()]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_utxo_locking() -> Result<()> {
+    let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+    let rpc_api_mock = Arc::new(MockRpcClient::new());
+    let processor = UtxoProcessor::new(Some(rpc_api_mock.into()), Some(network_id), None, None);
+
+    let entries = [1.0, 2.0, 3.0].into_iter().map(kaspa_to_sompi).map(UtxoEntryReference::simulated).collect::<Vec<_>>();
+    let locked = entries[1].id();
+    let context = UtxoContext::new_with_mature_entries(&processor, UtxoContextBinding::default(), entries);
+
+    context.lock(std::slice::from_ref(&locked)).await?;
+    assert!(context.is_locked(&locked));
+
+    let balance = context.calculate_balance().await;
+    assert_eq!(balance.mature, kaspa_to_sompi(4.0));
+    assert_eq!(balance.mature_utxo_count, 2);
+    assert_eq!(balance.locked, kaspa_to_sompi(2.0));
+    assert_eq!(balance.locked_utxo_count, 1);
+    assert!(UtxoIterator::new(&context).all(|entry| entry.id() != locked));
+    assert_eq!(UtxoStream::new(&context).count().await, 2);
+
+    // locks are retained when the context is cleared
+    context.clear().await?;
+    assert_eq!(context.locked(), vec![locked.clone()]);
+
+    context.unlock(std::slice::from_ref(&locked)).await?;
+    assert!(context.locked().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_utxo_scan_rollback() -> Result<()> {
+    let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+    let rpc_api_mock = Arc::new(MockRpcClient::new());
+    let processor = UtxoProcessor::new(Some(rpc_api_mock.into()), Some(network_id), None, None);
+
+    let entries = [1.0, 2.0].into_iter().map(kaspa_to_sompi).map(UtxoEntryReference::simulated).collect::<Vec<_>>();
+    let ids = entries.iter().map(|entry| entry.id()).collect::<Vec<_>>();
+    let context = UtxoContext::new_with_mature_entries(&processor, UtxoContextBinding::default(), entries);
+    let retained = change_address(network_id.into());
+    context.register_addresses(&[retained.clone()]).await?;
+
+    // an interrupted scan clears the context, registers its scan window
+    // and obtains a partial UTXO set
+    let checkpoint = context.checkpoint();
+    context.clear().await?;
+    let scanned = output_address(network_id.into());
+    context.register_addresses(&[scanned.clone()]).await?;
+    let partial = UtxoEntryReference::simulated(kaspa_to_sompi(5.0));
+    context.insert(partial.clone(), 0, true).await?;
+    assert_eq!(context.calculate_balance().await.mature, kaspa_to_sompi(5.0));
+
+    context.rollback(checkpoint).await?;
+    let balance = context.calculate_balance().await;
+    assert_eq!(balance.mature, kaspa_to_sompi(3.0));
+    assert_eq!(balance.mature_utxo_count, 2);
+    assert!(context.get_entries_by_outpoints(&[partial.id()])[0].is_none());
+    assert!(context.get_entries_by_outpoints(&ids).iter().all(Option::is_some));
+
+    // addresses registered by the partial scan are unregistered
+    let addresses = context.addresses().iter().map(|address| address.as_ref().clone()).collect::<Vec<_>>();
+    assert_eq!(addresses, vec![retained.clone()]);
+    assert!(processor.address_to_utxo_context_map().contains_key(&retained));
+    assert!(!processor.address_to_utxo_context_map().contains_key(&scanned));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_utxo_coin_control() -> Result<()> {
+    let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+    let rpc_api_mock = Arc::new(MockRpcClient::new());
+    let processor = UtxoProcessor::new(Some(rpc_api_mock.into()), Some(network_id), None, None);
+
+    let entries = [1.0, 2.0, 3.0].into_iter().map(kaspa_to_sompi).map(UtxoEntryReference::simulated).collect::<Vec<_>>();
+    let ids = entries.iter().map(|entry| entry.id()).collect::<Vec<_>>();
+    let context = UtxoContext::new_with_mature_entries(&processor, UtxoContextBinding::default(), entries);
+
+    context.set_label(&ids[..2], Some("savings".to_string()));
+    context.set_label(&ids[1..2], None);
+    assert_eq!(context.label(&ids[0]).as_deref(), Some("savings"));
+    assert_eq!(context.labels().len(), 1);
+
+    context.lock(&ids[2..]).await?;
+    let descriptors = context.utxo_descriptors();
+    assert_eq!(descriptors.len(), 3);
+    let descriptor = descriptors.iter().find(|descriptor| descriptor.id() == ids[0]).unwrap();
+    assert_eq!(descriptor.status, UtxoStatus::Spendable);
+    assert_eq!(descriptor.label.as_deref(), Some("savings"));
+    let descriptor = descriptors.iter().find(|descriptor| descriptor.id() == ids[2]).unwrap();
+    assert_eq!(descriptor.status, UtxoStatus::Locked);
+
+    let selected = context.get_spendable_entries(&ids[..2])?;
+    assert_eq!(selected.iter().map(|entry| entry.id()).collect::<Vec<_>>(), ids[..2]);
+    assert!(matches!(context.get_spendable_entries(&ids[2..]), Err(Error::UtxoNotSpendable(_, UtxoStatus::Locked))));
+    assert!(matches!(context.get_spendable_entries(&[ids[0].clone(), ids[0].clone()]), Err(Error::DuplicateUtxoEntry)));
+
+    let unknown = UtxoEntryReference::simulated(kaspa_to_sompi(4.0)).id();
+    assert!(matches!(context.get_spendable_entries(&[unknown]), Err(Error::UtxoNotFound(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_utxo_outpoint_lookup() -> Result<()> {
+    let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+    let rpc_api_mock = Arc::new(MockRpcClient::new());
+    let processor = UtxoProcessor::new(Some(rpc_api_mock.into()), Some(network_id), None, None);
+
+    let entries = [1.0, 2.0].into_iter().map(kaspa_to_sompi).map(UtxoEntryReference::simulated).collect::<Vec<_>>();
+    let ids = entries.iter().map(|entry| entry.id()).collect::<Vec<_>>();
+    let context = UtxoContext::new_with_mature_entries(&processor, UtxoContextBinding::default(), entries);
+
+    let entry = context.get_entry_by_outpoint(&ids[1].transaction_id, ids[1].index).unwrap();
+    assert_eq!(entry.amount(), kaspa_to_sompi(2.0));
+
+    let unknown = UtxoEntryReference::simulated(kaspa_to_sompi(3.0)).id();
+    assert!(context.get_entry_by_outpoint(&unknown.transaction_id, unknown.index).is_none());
+
+    let resolved = context.get_entries_by_outpoints(&[ids[0].clone(), unknown, ids[1].clone()]);
+    assert_eq!(
+        resolved.iter().map(|entry| entry.as_ref().map(|entry| entry.amount())).collect::<Vec<_>>(),
+        vec![Some(kaspa_to_sompi(1.0)), None, Some(kaspa_to_sompi(2.0))]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_utxo_outgoing_reorg() -> Result<()> {
+    use kaspa_hashes::Hash;
+    use kaspa_rpc_core::{RpcAcceptedTransactionIds, VirtualChainChangedNotification};
+
+    let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+    let rpc_api_mock = Arc::new(MockRpcClient::new());
+    let processor = UtxoProcessor::new(Some(rpc_api_mock.into()), Some(network_id), None, None);
+    processor.mock_set_connected(true);
+    processor.handle_daa_score_change(1_000).await?;
+
+    let entries = [1.0, 2.0, 3.0].into_iter().map(kaspa_to_sompi).map(UtxoEntryReference::simulated).collect::<Vec<_>>();
+    let context = UtxoContext::new_with_mature_entries(&processor, UtxoContextBinding::default(), entries);
+
+    let payment_output = PaymentOutput::new(output_address(network_id.into()), kaspa_to_sompi(4.0));
+    let settings = GeneratorSettings::try_new_with_context(
+        context.clone(),
+        change_address(network_id.into()),
+        1,
+        1,
+        payment_output.into(),
+        Fees::SenderPays(0),
+        None,
+        None,
+    )?;
+    let transaction = Generator::try_new(settings, None, None)?.generate_transaction()?.expect("transaction");
+    context.register_outgoing_transaction(&transaction).await?;
+    assert_eq!(context.mature_utxo_size(), 0);
+    let outgoing_balance = context.calculate_balance().await;
+
+    let chain_changed = |removed: &[Hash], accepted: &[(Hash, TransactionId)]| VirtualChainChangedNotification {
+        removed_chain_block_hashes: Arc::new(removed.to_vec()),
+        added_chain_block_hashes: Arc::new(accepted.iter().map(|(hash, _)| *hash).collect()),
+        accepted_transaction_ids: Arc::new(
+            accepted
+                .iter()
+                .map(|(hash, id)| RpcAcceptedTransactionIds { accepting_block_hash: *hash, accepted_transaction_ids: vec![*id] })
+                .collect(),
+        ),
+    };
+
+    let (block_a, block_b) = (Hash::from_bytes([1; 32]), Hash::from_bytes([2; 32]));
+    processor.handle_virtual_chain_changed(chain_changed(&[], &[(block_a, transaction.id())])).await?;
+    assert_eq!(processor.outgoing().get(&transaction.id()).unwrap().accepting_block_hash(), Some(block_a));
+
+    // the transaction is accepted again by the new chain
+    processor.handle_virtual_chain_changed(chain_changed(&[block_a], &[(block_b, transaction.id())])).await?;
+    assert!(processor.outgoing().contains_key(&transaction.id()));
+    assert_eq!(context.mature_utxo_size(), 0);
+
+    // the accepting block is removed, consumed UTXOs are returned to the context
+    processor.handle_virtual_chain_changed(chain_changed(&[block_b], &[])).await?;
+    assert!(processor.outgoing().is_empty());
+    assert_eq!(context.mature_utxo_size(), 3);
+    let balance = context.calculate_balance().await;
+    assert_eq!((balance.mature, balance.outgoing), (kaspa_to_sompi(6.0), 0));
+    assert!(processor.reorged().contains_key(&transaction.id()));
+
+    // the reorged transaction is accepted again, consumed UTXOs are removed from the context
+    let block_c = Hash::from_bytes([3; 32]);
+    processor.handle_virtual_chain_changed(chain_changed(&[], &[(block_c, transaction.id())])).await?;
+    assert!(processor.reorged().is_empty());
+    assert_eq!(processor.outgoing().get(&transaction.id()).unwrap().accepting_block_hash(), Some(block_c));
+    assert_eq!(context.mature_utxo_size(), 0);
+    let balance = context.calculate_balance().await;
+    assert_eq!((balance.mature, balance.outgoing), (outgoing_balance.mature, outgoing_balance.outgoing));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_utxo_pending_incoming() -> Result<()> {
+    use kaspa_consensus_core::subnets::SUBNETWORK_ID_NATIVE;
+    use kaspa_consensus_core::tx::{Transaction, TransactionOutput};
+    use kaspa_rpc_core::{RpcTransactionOutpoint, RpcUtxoEntry, RpcUtxosByAddressesEntry};
+    use kaspa_txscript::pay_to_address_script;
+
+    let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+    let rpc_api_mock = Arc::new(MockRpcClient::new());
+    let processor = UtxoProcessor::new(Some(rpc_api_mock.clone().into()), Some(network_id), None, None);
+    processor.mock_set_connected(true);
+    processor.handle_daa_score_change(1_000).await?;
+
+    let address = output_address(network_id.into());
+    let context = UtxoContext::new(&processor, UtxoContextBinding::default());
+    context.register_addresses(&[address.clone()]).await?;
+
+    let outputs = vec![
+        TransactionOutput::new(kaspa_to_sompi(2.0), pay_to_address_script(&address)),
+        TransactionOutput::new(kaspa_to_sompi(1.0), pay_to_address_script(&change_address(network_id.into()))),
+    ];
+    let transaction = Transaction::new(0, vec![], outputs, 0, SUBNETWORK_ID_NATIVE, 0, vec![]);
+    rpc_api_mock.add_mempool_transaction(transaction.clone());
+
+    let incoming = context.update_pending_incoming().await?;
+    assert_eq!(incoming.len(), 1);
+    assert_eq!(incoming[0].amount, kaspa_to_sompi(2.0));
+    assert_eq!(incoming[0].transaction_ids, vec![transaction.id()]);
+    assert_eq!(context.balance().unwrap().pending_incoming, kaspa_to_sompi(2.0));
+
+    // the transaction UTXO is received, the amount is no longer pending incoming
+    let utxo = RpcUtxosByAddressesEntry {
+        address: Some(address.clone()),
+        outpoint: RpcTransactionOutpoint::new(transaction.id(), 0),
+        utxo_entry: RpcUtxoEntry::new(kaspa_to_sompi(2.0), pay_to_address_script(&address), 1_000, false),
+    };
+    context.handle_utxo_added(vec![utxo.into()], 1_000).await?;
+    rpc_api_mock.remove_mempool_transaction(&transaction.id());
+    let balance = context.calculate_balance().await;
+    assert_eq!((balance.pending, balance.pending_incoming), (kaspa_to_sompi(2.0), 0));
+
+    // a transaction evicted from the mempool is dropped by the periodic refresh
+    let outputs = vec![TransactionOutput::new(kaspa_to_sompi(3.0), pay_to_address_script(&address))];
+    let evicted = Transaction::new(0, vec![], outputs, 0, SUBNETWORK_ID_NATIVE, 0, vec![]);
+    rpc_api_mock.add_mempool_transaction(evicted.clone());
+    context.update_pending_incoming().await?;
+    assert_eq!(context.balance().unwrap().pending_incoming, kaspa_to_sompi(3.0));
+
+    rpc_api_mock.remove_mempool_transaction(&evicted.id());
+    processor.handle_daa_score_change(1_001).await?;
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while context.has_pending_incoming() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("pending incoming transactions are not refreshed");
+    assert_eq!(context.balance().unwrap().pending_incoming, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_utxo_payment_request() -> Result<()> {
+    use kaspa_rpc_core::{RpcTransactionOutpoint, RpcUtxoEntry, RpcUtxosByAddressesEntry};
+    use kaspa_txscript::pay_to_address_script;
+    use workflow_core::time::unixtime_as_millis_u64;
+
+    let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+    let rpc_api_mock = Arc::new(MockRpcClient::new());
+    let processor = UtxoProcessor::new(Some(rpc_api_mock.into()), Some(network_id), None, None);
+    processor.mock_set_connected(true);
+    processor.handle_daa_score_change(1_000).await?;
+
+    let address = output_address(network_id.into());
+    let context = UtxoContext::new(&processor, UtxoContextBinding::default());
+    let request = PaymentRequest::new(address.clone(), kaspa_to_sompi(2.0), None);
+    context.register_payment_request(request.clone());
+    let expired = PaymentRequest::new(change_address(network_id.into()), kaspa_to_sompi(1.0), Some(Duration::ZERO));
+    context.register_payment_request(expired.clone());
+    assert_eq!(request.uri().amount, Some(kaspa_to_sompi(2.0)));
+    assert!(context.take_payment_requests_changed());
+
+    let utxo = |txid: u64, amount: f64, address: &Address| -> UtxoEntryReference {
+        RpcUtxosByAddressesEntry {
+            address: Some(address.clone()),
+            outpoint: RpcTransactionOutpoint::new(TransactionId::from_u64_word(txid), 0),
+            utxo_entry: RpcUtxoEntry::new(kaspa_to_sompi(amount), pay_to_address_script(address), 1_000, false),
+        }
+        .into()
+    };
+
+    // an insufficient amount does not fulfill the request
+    context.handle_utxo_added(vec![utxo(1, 1.0, &address)], 1_000).await?;
+    assert_eq!(context.payment_request(&request.id).unwrap().status, PaymentRequestStatus::Pending);
+
+    context.handle_utxo_added(vec![utxo(2, 2.5, &address)], 1_000).await?;
+    let fulfilled = context.payment_request(&request.id).unwrap();
+    assert_eq!(fulfilled.status, PaymentRequestStatus::Fulfilled);
+    assert_eq!(fulfilled.transaction_id, Some(TransactionId::from_u64_word(2)));
+    assert!(fulfilled.fulfilled.is_some());
+    assert!(context.take_payment_requests_changed());
+
+    // payments received after the expiration are not matched
+    context.handle_utxo_added(vec![utxo(3, 1.0, &change_address(network_id.into()))], 1_000).await?;
+    assert_eq!(context.payment_request(&expired.id).unwrap().status, PaymentRequestStatus::Expired);
+
+    // payment requests are retained when the context is cleared
+    context.clear().await?;
+    assert_eq!(context.payment_requests().len(), 2);
+    assert!(!context.take_payment_requests_changed());
+    assert!(context.remove_payment_request(&request.id).is_some());
+    assert!(context.take_payment_requests_changed());
+
+    // requests settled for longer than the retention period are pruned
+    let settled = unixtime_as_millis_u64() - PAYMENT_REQUEST_RETENTION.as_millis() as u64 - 1;
+    let mut stale = PaymentRequest::new(address.clone(), kaspa_to_sompi(1.0), None);
+    stale.status = PaymentRequestStatus::Fulfilled;
+    stale.fulfilled = Some(settled);
+    let mut stale_expired = PaymentRequest::new(address.clone(), kaspa_to_sompi(1.0), Some(Duration::ZERO));
+    stale_expired.expires = Some(settled);
+    context.extend_payment_requests(vec![stale.clone(), stale_expired.clone()]);
+    let requests = context.payment_requests();
+    assert_eq!(requests.iter().map(|request| request.id).collect::<Vec<_>>(), vec![expired.id]);
+    assert!(context.take_payment_requests_changed());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_utxo_address_monitor() -> Result<()> {
+    let rpc_api_mock = Arc::new(MockRpcClient::new());
+    let network_id = rpc_api_mock.network_id();
+    let monitor = AddressMonitor::new(rpc_api_mock.clone().into(), network_id);
+    let events = monitor.multiplexer().channel();
+
+    // waits for a balance event posted by the monitor matching the predicate
+    let (events, monitor_id) = (&events, monitor.id());
+    let wait_for_balance = |predicate: fn(&Balance) -> bool| async move {
+        tokio::time::timeout(Duration::from_secs(10), async {
+            loop {
+                if let Events::Balance { balance: Some(balance), id } = *events.receiver.recv().await.unwrap() {
+                    if id == monitor_id && predicate(&balance) {
+                        break;
+                    }
+                }
+            }
+        })
+        .await
+        .expect("timeout while waiting for a balance event")
+    };
+
+    let address = output_address(network_id.into());
+    let other = change_address(network_id.into());
+    rpc_api_mock.add_utxo(&address, kaspa_to_sompi(1.0)).unwrap();
+    rpc_api_mock.add_utxo(&address, kaspa_to_sompi(2.0)).unwrap();
+
+    // addresses registered prior to the connection are scanned once connected
+    monitor.track_addresses(&[address.clone(), other.clone()]).await?;
+    monitor.start().await?;
+    rpc_api_mock.connect().await?;
+    wait_for_balance(|balance| balance.mature == kaspa_to_sompi(3.0)).await;
+    assert!(monitor.is_active());
+
+    let balances = monitor.address_balances();
+    assert_eq!(balances.len(), 2);
+    let tracked = monitor.address_balance(&address).unwrap();
+    assert_eq!((tracked.mature, tracked.mature_utxo_count), (kaspa_to_sompi(3.0), 2));
+    assert_eq!(monitor.address_balance(&other).unwrap().mature, 0);
+
+    // UTXOs received by a monitored address are pending until mature
+    rpc_api_mock.add_utxo_with_daa_score(&other, kaspa_to_sompi(5.0), rpc_api_mock.virtual_daa_score(), false).unwrap();
+    wait_for_balance(|balance| balance.pending == kaspa_to_sompi(5.0)).await;
+    let pending = monitor.address_balance(&other).unwrap();
+    assert_eq!((pending.pending, pending.pending_utxo_count), (kaspa_to_sompi(5.0), 1));
+
+    // untracked addresses are removed along with their UTXOs
+    monitor.untrack_addresses(std::slice::from_ref(&address)).await?;
+    assert_eq!(monitor.addresses(), vec![other.clone()]);
+    assert!(monitor.address_balance(&address).is_none());
+    assert_eq!(monitor.balance().map(|balance| balance.mature), Some(0));
+
+    monitor.stop().await?;
+    assert!(!monitor.is_running());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_utxo_address_monitor_shared_processor() -> Result<()> {
+    let rpc_api_mock = Arc::new(MockRpcClient::new());
+    let network_id = rpc_api_mock.network_id();
+    let processor = UtxoProcessor::new(Some(rpc_api_mock.clone().into()), Some(network_id), None, None);
+    let events = processor.multiplexer().channel();
+    processor.start().await?;
+    rpc_api_mock.connect().await?;
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while !matches!(*events.receiver.recv().await.unwrap(), Events::UtxoProcStart) {}
+    })
+    .await
+    .expect("timeout while waiting for the processor to start");
+
+    let address = output_address(network_id.into());
+    let entry = rpc_api_mock.add_utxo_with_daa_score(&address, kaspa_to_sompi(1.0), rpc_api_mock.virtual_daa_score(), false).unwrap();
+    let monitor = AddressMonitor::with_processor(&processor);
+    monitor.start().await?;
+    monitor.track_addresses(std::slice::from_ref(&address)).await?;
+    assert_eq!(monitor.balance().map(|balance| balance.pending), Some(kaspa_to_sompi(1.0)));
+    assert!(processor.address_to_utxo_context(&address).is_some());
+    assert_eq!(processor.subscriptions().tracked(), 1);
+    assert_eq!(processor.pending().len(), 1);
+
+    // stopping the monitor unregisters its addresses and UTXO entries from the shared processor
+    monitor.stop().await?;
+    assert!(processor.address_to_utxo_context(&address).is_none());
+    assert_eq!(processor.subscriptions().tracked(), 0);
+    assert!(processor.pending().is_empty());
+    assert!(monitor.balance().is_none());
+    assert_eq!(monitor.addresses(), vec![address.clone()]);
+
+    // the retained addresses are scanned once the monitor is started again
+    let other = change_address(network_id.into());
+    monitor.track_addresses(std::slice::from_ref(&other)).await?;
+    assert_eq!(processor.subscriptions().tracked(), 0);
+    monitor.start().await?;
+    assert_eq!(processor.address_to_utxo_context(&address).map(|context| context.id()), Some(monitor.id()));
+    assert!(processor.address_to_utxo_context(&other).is_some());
+    assert_eq!(processor.subscriptions().tracked(), 2);
+    assert!(processor.pending().contains_key(&UtxoEntryReference::from(entry).id()));
+    assert_eq!(monitor.balance().map(|balance| balance.pending), Some(kaspa_to_sompi(1.0)));
+
+    monitor.stop().await?;
+    processor.stop().await?;
+    Ok(())
+}
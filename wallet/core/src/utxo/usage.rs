@@ -0,0 +1,191 @@
+//!
+//! Address usage tracked by the [`UtxoContext`].
+//!
+//! [`AddressUsage`] records the activity observed on an address (first
+//! and last transaction, total amount received), allowing applications
+//! to distinguish used addresses from fresh addresses. The usage is
+//! derived from UTXOs received via UTXO change notifications and
+//! account scans, as such, amounts received by UTXOs that have been
+//! spent before the wallet observed them are not accounted for.
+//!
+
+use crate::imports::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_ADDRESS_USAGE: &'static str = r#"
+/**
+ * Activity observed on an address monitored by the wallet.
+ * @category Wallet SDK
+ */
+export interface IAddressUsage {
+    address: string;
+    /**
+     * Id of the first transaction received by the address.
+     */
+    firstTransactionId: HexString;
+    /**
+     * DAA score of the first transaction received by the address.
+     */
+    firstDaaScore: bigint;
+    /**
+     * Id of the last transaction received by the address.
+     */
+    lastTransactionId: HexString;
+    /**
+     * DAA score of the last transaction received by the address.
+     */
+    lastDaaScore: bigint;
+    /**
+     * Total amount received by the address (in SOMPI).
+     */
+    totalReceived: bigint;
+    /**
+     * Number of UTXOs received by the address.
+     */
+    utxoCount: number;
+}
+"#;
+
+/// Activity observed on an address monitored by a [`UtxoContext`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressUsage {
+    pub address: Address,
+    pub first_transaction_id: TransactionId,
+    pub first_daa_score: u64,
+    pub last_transaction_id: TransactionId,
+    pub last_daa_score: u64,
+    /// Total amount received by the address (in SOMPI)
+    pub total_received: u64,
+    /// Number of UTXOs received by the address
+    pub utxo_count: u32,
+    /// Index of the last UTXO output (used together with the last
+    /// transaction id and DAA score to skip UTXOs observed previously)
+    #[serde(skip)]
+    last_index: TransactionIndexType,
+}
+
+impl AddressUsage {
+    fn new(address: Address, utxo_entry: &UtxoEntryReference) -> Self {
+        let entry = utxo_entry.as_ref();
+        Self {
+            address,
+            first_transaction_id: utxo_entry.transaction_id(),
+            first_daa_score: entry.block_daa_score,
+            last_transaction_id: utxo_entry.transaction_id(),
+            last_daa_score: entry.block_daa_score,
+            total_received: entry.amount,
+            utxo_count: 1,
+            last_index: utxo_entry.id_as_ref().index,
+        }
+    }
+
+    /// Account for the `utxo_entry` received by the address. UTXO entries are
+    /// expected in the order of their DAA score; UTXO entries that do not follow
+    /// the last observed UTXO entry (such as UTXOs reported again by a rescan)
+    /// are ignored. Returns `true` if the usage has changed.
+    fn update(&mut self, utxo_entry: &UtxoEntryReference) -> bool {
+        let entry = utxo_entry.as_ref();
+        let key = (entry.block_daa_score, utxo_entry.transaction_id(), utxo_entry.id_as_ref().index);
+        if key <= (self.last_daa_score, self.last_transaction_id, self.last_index) {
+            return false;
+        }
+
+        self.last_daa_score = entry.block_daa_score;
+        self.last_transaction_id = utxo_entry.transaction_id();
+        self.last_index = utxo_entry.id_as_ref().index;
+        self.total_received = self.total_received.saturating_add(entry.amount);
+        self.utxo_count = self.utxo_count.saturating_add(1);
+        true
+    }
+}
+
+/// Address usage map of a [`UtxoContext`].
+#[derive(Default)]
+pub(crate) struct AddressUsageMap {
+    map: AHashMap<Address, AddressUsage>,
+    /// Usage changed since the last [`take_changed()`](Self::take_changed)
+    changed: bool,
+}
+
+impl AddressUsageMap {
+    /// Account for the `utxo_entries` (sorted by DAA score prior to processing).
+    pub fn update(&mut self, utxo_entries: &[UtxoEntryReference]) {
+        let mut utxo_entries = utxo_entries.iter().filter(|utxo_entry| utxo_entry.utxo.address.is_some()).collect::<Vec<_>>();
+        utxo_entries.sort_by_key(|utxo_entry| {
+            (utxo_entry.as_ref().block_daa_score, utxo_entry.transaction_id(), utxo_entry.id_as_ref().index)
+        });
+
+        for utxo_entry in utxo_entries {
+            let address = utxo_entry.utxo.address.as_ref().unwrap();
+            match self.map.get_mut(address) {
+                Some(usage) => {
+                    self.changed |= usage.update(utxo_entry);
+                }
+                None => {
+                    self.map.insert(address.clone(), AddressUsage::new(address.clone(), utxo_entry));
+                    self.changed = true;
+                }
+            }
+        }
+    }
+
+    pub fn get(&self, address: &Address) -> Option<&AddressUsage> {
+        self.map.get(address)
+    }
+
+    pub fn to_vec(&self) -> Vec<AddressUsage> {
+        self.map.values().cloned().collect()
+    }
+
+    pub fn extend(&mut self, usage: Vec<AddressUsage>) {
+        self.map.extend(usage.into_iter().map(|usage| (usage.address.clone(), usage)));
+    }
+
+    /// Returns `true` if the usage has changed since the last call.
+    pub fn take_changed(&mut self) -> bool {
+        std::mem::take(&mut self.changed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_consensus_client::{TransactionOutpoint, UtxoEntry};
+    use kaspa_consensus_core::tx::ScriptPublicKey;
+
+    fn utxo(address: &Address, txid: u8, index: u32, amount: u64, daa_score: u64) -> UtxoEntryReference {
+        let outpoint = TransactionOutpoint::new(TransactionId::from_slice(&[txid; 32]), index);
+        let entry = UtxoEntry {
+            address: Some(address.clone()),
+            outpoint,
+            amount,
+            script_public_key: ScriptPublicKey::default(),
+            block_daa_score: daa_score,
+            is_coinbase: false,
+        };
+        UtxoEntryReference::from(entry)
+    }
+
+    #[test]
+    fn test_address_usage() {
+        let address = Address::try_from("kaspatest:qz7ulu4c25dh7fzec9zjyrmlhnkzrg4wmf89q7gzr3gfrsj3uz6xjceef60sd").unwrap();
+        let mut usage = AddressUsageMap::default();
+
+        usage.update(&[utxo(&address, 2, 0, 200, 20), utxo(&address, 1, 0, 100, 10)]);
+        assert!(usage.take_changed());
+        let entry = usage.get(&address).unwrap();
+        assert_eq!((entry.first_daa_score, entry.last_daa_score), (10, 20));
+        assert_eq!(entry.first_transaction_id, TransactionId::from_slice(&[1; 32]));
+        assert_eq!((entry.total_received, entry.utxo_count), (300, 2));
+
+        // UTXOs reported again (e.g. by a rescan) are not accounted for
+        usage.update(&[utxo(&address, 1, 0, 100, 10), utxo(&address, 2, 0, 200, 20)]);
+        assert!(!usage.take_changed());
+
+        usage.update(&[utxo(&address, 2, 1, 50, 20), utxo(&address, 3, 0, 25, 30)]);
+        assert!(usage.take_changed());
+        let entry = usage.get(&address).unwrap();
+        assert_eq!((entry.total_received, entry.utxo_count, entry.last_daa_score), (375, 4, 30));
+    }
+}
@@ -2,12 +2,20 @@
 //! [`WalletApi`] trait implementation for [`Wallet`].
 //!
 
+use crate::account::GenerationNotifier;
+use crate::api::batch::execute_batch;
 use crate::api::{message::*, traits::WalletApi};
 use crate::imports::*;
+use crate::logging::{self, log};
+use crate::message::{verify_message_with_address, PersonalMessage};
 use crate::result::Result;
+use crate::settings::WalletSettings;
 use crate::storage::interface::TransactionRangeResult;
 use crate::storage::Binding;
-use crate::tx::Fees;
+use crate::tx::{decode_signable_transaction, encode_signable_transaction, CoinControl, PaymentBatch, PendingTransaction};
+use crate::utxo::UtxoEntryId;
+use crate::webhooks::{WebhookConfig, WebhookDescriptor, WebhookDispatcher, WebhookSecret};
+use kaspa_bip32::{Language, Mnemonic};
 use workflow_core::channel::Receiver;
 
 #[async_trait]
@@ -25,6 +33,7 @@ impl WalletApi for super::Wallet {
 
         let is_connected = self.is_connected();
         let is_synced = self.is_synced();
+        let wallet_sync = self.wallet_sync().progress();
         let is_open = self.is_open();
         let network_id = self.network_id().ok();
         let (url, is_wrpc_client) =
@@ -43,6 +52,7 @@ impl WalletApi for super::Wallet {
         Ok(GetStatusResponse {
             is_connected,
             is_synced,
+            wallet_sync,
             is_open,
             network_id,
             url,
@@ -111,16 +121,28 @@ impl WalletApi for super::Wallet {
         Ok(ChangeNetworkIdResponse {})
     }
 
+    async fn set_log_level_call(self: Arc<Self>, request: SetLogLevelRequest) -> Result<SetLogLevelResponse> {
+        let SetLogLevelRequest { target, level } = request;
+        let config = logging::set_level(target, level);
+        Ok(SetLogLevelResponse { config })
+    }
+
     // -------------------------------------------------------------------------------------
 
     async fn ping_call(self: Arc<Self>, request: PingRequest) -> Result<PingResponse> {
-        log_info!("Wallet received ping request '{:?}' ...", request.message);
+        log!(Wallet, Info, "Wallet received ping request '{:?}' ...", request.message);
         Ok(PingResponse { message: request.message })
     }
 
-    async fn batch_call(self: Arc<Self>, _request: BatchRequest) -> Result<BatchResponse> {
-        self.store().batch().await?;
-        Ok(BatchResponse {})
+    async fn batch_call(self: Arc<Self>, request: BatchRequest) -> Result<BatchResponse> {
+        let BatchRequest { requests } = request;
+        if requests.is_empty() {
+            self.store().batch().await?;
+            return Ok(BatchResponse::default());
+        }
+
+        let responses = execute_batch(self, requests).await;
+        Ok(BatchResponse { responses })
     }
 
     async fn flush_call(self: Arc<Self>, request: FlushRequest) -> Result<FlushResponse> {
@@ -164,13 +186,14 @@ impl WalletApi for super::Wallet {
     }
 
     async fn wallet_rename_call(self: Arc<Self>, request: WalletRenameRequest) -> Result<WalletRenameResponse> {
-        let WalletRenameRequest { wallet_secret, title, filename } = request;
-        self.rename(title, filename, &wallet_secret).await?;
-        Ok(WalletRenameResponse {})
+        let WalletRenameRequest { wallet_secret, title, notes, filename } = request;
+        let wallet_descriptor = self.rename(title, notes, filename, &wallet_secret).await?;
+        Ok(WalletRenameResponse { wallet_descriptor })
     }
 
     async fn wallet_change_secret_call(self: Arc<Self>, request: WalletChangeSecretRequest) -> Result<WalletChangeSecretResponse> {
         let WalletChangeSecretRequest { old_wallet_secret, new_wallet_secret } = request;
+        self.secret_strength_policy().validate(&new_wallet_secret)?;
         self.store().change_secret(&old_wallet_secret, &new_wallet_secret).await?;
         Ok(WalletChangeSecretResponse {})
     }
@@ -192,6 +215,12 @@ impl WalletApi for super::Wallet {
         Ok(WalletImportResponse { wallet_descriptor })
     }
 
+    async fn wallet_check_call(self: Arc<Self>, request: WalletCheckRequest) -> Result<WalletCheckResponse> {
+        let WalletCheckRequest { wallet_secret, repair } = request;
+        let report = self.check_storage(&wallet_secret, repair).await?;
+        Ok(WalletCheckResponse { report })
+    }
+
     async fn prv_key_data_enumerate_call(
         self: Arc<Self>,
         _request: PrvKeyDataEnumerateRequest,
@@ -219,6 +248,20 @@ impl WalletApi for super::Wallet {
         Ok(PrvKeyDataGetResponse { prv_key_data })
     }
 
+    async fn prv_key_data_change_secret_call(
+        self: Arc<Self>,
+        request: PrvKeyDataChangeSecretRequest,
+    ) -> Result<PrvKeyDataChangeSecretResponse> {
+        let PrvKeyDataChangeSecretRequest { wallet_secret, prv_key_data_id, old_payment_secret, new_payment_secret } = request;
+
+        let report = self
+            .store()
+            .change_payment_secret(&wallet_secret, prv_key_data_id.as_ref(), old_payment_secret.as_ref(), new_payment_secret.as_ref())
+            .await?;
+
+        Ok(PrvKeyDataChangeSecretResponse { report })
+    }
+
     async fn accounts_rename_call(self: Arc<Self>, request: AccountsRenameRequest) -> Result<AccountsRenameResponse> {
         let AccountsRenameRequest { account_id, name, wallet_secret } = request;
 
@@ -228,6 +271,126 @@ impl WalletApi for super::Wallet {
         Ok(AccountsRenameResponse {})
     }
 
+    async fn accounts_set_gap_limit_call(self: Arc<Self>, request: AccountsSetGapLimitRequest) -> Result<AccountsSetGapLimitResponse> {
+        let AccountsSetGapLimitRequest { account_id, gap_limit } = request;
+
+        let account = self.get_account_by_id(&account_id).await?.ok_or(Error::AccountNotFound(account_id))?;
+        account.set_gap_limit(gap_limit).await?;
+
+        Ok(AccountsSetGapLimitResponse {})
+    }
+
+    async fn accounts_get_pending_incoming_call(
+        self: Arc<Self>,
+        request: AccountsGetPendingIncomingRequest,
+    ) -> Result<AccountsGetPendingIncomingResponse> {
+        let AccountsGetPendingIncomingRequest { account_id } = request;
+
+        let account = self.get_account_by_id(&account_id).await?.ok_or(Error::AccountNotFound(account_id))?;
+        let incoming = account.utxo_context().update_pending_incoming().await?;
+        let amount = incoming.iter().map(|incoming| incoming.amount).sum();
+
+        Ok(AccountsGetPendingIncomingResponse { incoming, amount })
+    }
+
+    async fn accounts_create_payment_request_call(
+        self: Arc<Self>,
+        request: AccountsCreatePaymentRequestRequest,
+    ) -> Result<AccountsCreatePaymentRequestResponse> {
+        let AccountsCreatePaymentRequestRequest { account_id, amount, expiry_seconds, label, message } = request;
+
+        let account = self.get_account_by_id(&account_id).await?.ok_or(Error::AccountNotFound(account_id))?;
+        let account = account.as_derivation_capable()?;
+        let expiry = expiry_seconds.map(Duration::from_secs);
+        let request = account.create_payment_request(amount, expiry, label, message).await?;
+        let uri = request.uri().to_string();
+
+        Ok(AccountsCreatePaymentRequestResponse { request, uri })
+    }
+
+    async fn accounts_addresses_call(self: Arc<Self>, request: AccountsAddressesRequest) -> Result<AccountsAddressesResponse> {
+        let AccountsAddressesRequest { account_id } = request;
+
+        let account = self.get_account_by_id(&account_id).await?.ok_or(Error::AccountNotFound(account_id))?;
+        let utxo_context = account.utxo_context();
+
+        let addresses = match account.clone().as_derivation_capable() {
+            Ok(account) => {
+                let derivation = account.derivation();
+                let mut addresses = vec![];
+                for (change, manager) in [(false, derivation.receive_address_manager()), (true, derivation.change_address_manager())] {
+                    let range = manager.get_range_with_args(0..manager.index() + 1, false)?;
+                    addresses.extend(range.into_iter().enumerate().map(|(index, address)| AccountAddress {
+                        usage: utxo_context.address_usage(&address),
+                        address,
+                        index: Some(index as u32),
+                        change,
+                    }));
+                }
+                addresses
+            }
+            Err(_) => {
+                let receive_address = account.receive_address()?;
+                let change_address = account.change_address()?;
+                let mut addresses = vec![(receive_address.clone(), false)];
+                if change_address != receive_address {
+                    addresses.push((change_address, true));
+                }
+                addresses
+                    .into_iter()
+                    .map(|(address, change)| AccountAddress {
+                        usage: utxo_context.address_usage(&address),
+                        address,
+                        index: None,
+                        change,
+                    })
+                    .collect()
+            }
+        };
+
+        Ok(AccountsAddressesResponse { addresses })
+    }
+
+    async fn accounts_get_payment_requests_call(
+        self: Arc<Self>,
+        request: AccountsGetPaymentRequestsRequest,
+    ) -> Result<AccountsGetPaymentRequestsResponse> {
+        let AccountsGetPaymentRequestsRequest { account_id } = request;
+
+        let account = self.get_account_by_id(&account_id).await?.ok_or(Error::AccountNotFound(account_id))?;
+        let requests = account.utxo_context().payment_requests();
+
+        Ok(AccountsGetPaymentRequestsResponse { requests })
+    }
+
+    async fn accounts_remove_payment_request_call(
+        self: Arc<Self>,
+        request: AccountsRemovePaymentRequestRequest,
+    ) -> Result<AccountsRemovePaymentRequestResponse> {
+        let AccountsRemovePaymentRequestRequest { account_id, request_id } = request;
+
+        let account = self.get_account_by_id(&account_id).await?.ok_or(Error::AccountNotFound(account_id))?;
+        let request = account.remove_payment_request(&request_id).await?;
+
+        Ok(AccountsRemovePaymentRequestResponse { request })
+    }
+
+    async fn accounts_archive_call(self: Arc<Self>, request: AccountsArchiveRequest) -> Result<AccountsArchiveResponse> {
+        let AccountsArchiveRequest { account_ids, wallet_secret } = request;
+
+        self.archive_accounts(&wallet_secret, &account_ids).await?;
+
+        Ok(AccountsArchiveResponse {})
+    }
+
+    async fn accounts_unarchive_call(self: Arc<Self>, request: AccountsUnarchiveRequest) -> Result<AccountsUnarchiveResponse> {
+        let AccountsUnarchiveRequest { account_ids, wallet_secret } = request;
+
+        self.unarchive_accounts(&wallet_secret, &account_ids).await?;
+
+        Ok(AccountsUnarchiveResponse {})
+    }
+
     async fn accounts_select_call(self: Arc<Self>, request: AccountsSelectRequest) -> Result<AccountsSelectResponse> {
         let AccountsSelectRequest { account_id } = request;
 
@@ -242,7 +405,7 @@ impl WalletApi for super::Wallet {
         Ok(AccountsSelectResponse {})
     }
 
-    async fn accounts_enumerate_call(self: Arc<Self>, _request: AccountsEnumerateRequest) -> Result<AccountsEnumerateResponse> {
+    async fn accounts_enumerate_call(self: Arc<Self>, request: AccountsEnumerateRequest) -> Result<AccountsEnumerateResponse> {
         // let iter = self.inner.store.as_account_store().unwrap().iter(None).await.unwrap();
         // let wallet = self.clone();
 
@@ -263,7 +426,10 @@ impl WalletApi for super::Wallet {
 
         // let account_descriptors = stream.try_collect::<Vec<_>>().await?;
 
-        let account_descriptors = self.account_descriptors().await?;
+        let mut account_descriptors = self.clone().account_descriptors().await?;
+        if request.include_archived {
+            account_descriptors.extend(self.archived_account_descriptors().await?);
+        }
         Ok(AccountsEnumerateResponse { account_descriptors })
     }
 
@@ -284,13 +450,60 @@ impl WalletApi for super::Wallet {
     }
 
     async fn accounts_discovery_call(self: Arc<Self>, request: AccountsDiscoveryRequest) -> Result<AccountsDiscoveryResponse> {
-        let AccountsDiscoveryRequest { discovery_kind: _, address_scan_extent, account_scan_extent, bip39_passphrase, bip39_mnemonic } =
-            request;
+        let AccountsDiscoveryRequest {
+            discovery_kind: _,
+            address_scan_extent,
+            account_scan_extent,
+            source,
+            create_accounts,
+            gap_limit,
+        } = request;
+        let address_scan_extent = address_scan_extent.max(gap_limit.unwrap_or_default());
 
-        let last_account_index_found =
-            self.scan_bip44_accounts(bip39_mnemonic, bip39_passphrase, address_scan_extent, account_scan_extent).await?;
+        let abortable = Abortable::new();
+        let accounts = match source {
+            AccountsDiscoverySource::Mnemonic { bip39_mnemonic, bip39_passphrase } => {
+                if create_accounts {
+                    return Err(Error::custom("Account creation requires private key data stored in the wallet"));
+                }
+
+                let prv_key_data = Self::bip39_prv_key_data(&bip39_mnemonic, bip39_passphrase.as_ref())?;
+                self.watchdog()
+                    .run(
+                        TaskKind::Discovery,
+                        None,
+                        &abortable,
+                        self.discover_bip44_account_indexes(
+                            &prv_key_data,
+                            bip39_passphrase.as_ref(),
+                            address_scan_extent,
+                            account_scan_extent,
+                        ),
+                    )
+                    .await?
+            }
+            AccountsDiscoverySource::PrvKeyData { wallet_secret, prv_key_data_id, payment_secret } => {
+                self.watchdog()
+                    .run(
+                        TaskKind::Discovery,
+                        None,
+                        &abortable,
+                        self.discover_bip44_accounts(
+                            &wallet_secret,
+                            prv_key_data_id,
+                            payment_secret.as_ref(),
+                            address_scan_extent,
+                            account_scan_extent,
+                            create_accounts,
+                            gap_limit,
+                        ),
+                    )
+                    .await?
+            }
+        };
 
-        Ok(AccountsDiscoveryResponse { last_account_index_found })
+        let last_account_index_found = accounts.last().map(|account| account.account_index as u32).unwrap_or_default();
+        Ok(AccountsDiscoveryResponse { last_account_index_found, accounts })
     }
 
     async fn accounts_create_call(self: Arc<Self>, request: AccountsCreateRequest) -> Result<AccountsCreateResponse> {
@@ -314,9 +527,38 @@ impl WalletApi for super::Wallet {
         Ok(AccountsEnsureDefaultResponse { account_descriptor })
     }
 
-    async fn accounts_import_call(self: Arc<Self>, _request: AccountsImportRequest) -> Result<AccountsImportResponse> {
-        // TODO handle account imports
-        return Err(Error::NotImplemented);
+    async fn accounts_import_call(self: Arc<Self>, request: AccountsImportRequest) -> Result<AccountsImportResponse> {
+        let AccountsImportRequest { wallet_secret, payment_secret, source, sweep } = request;
+
+        let payment_secret = payment_secret.as_ref();
+        let account = match source {
+            #[cfg(feature = "fs")]
+            AccountsImportSource::LegacyStorage { import_secret } => {
+                self.import_legacy_keydata(&import_secret, &wallet_secret, payment_secret, None).await?
+            }
+            #[cfg(not(feature = "fs"))]
+            AccountsImportSource::LegacyStorage { .. } => return Err(Error::NotImplemented),
+            AccountsImportSource::LegacyKeydata { keydata, import_secret } => {
+                self.import_legacy_keydata_with_data(&keydata, &import_secret, &wallet_secret, payment_secret, None).await?
+            }
+            AccountsImportSource::LegacyMnemonic { mnemonic } => {
+                let mnemonic = std::str::from_utf8(mnemonic.as_ref()).map_err(|_| Error::InvalidMnemonicPhrase)?;
+                let mnemonic = Mnemonic::new(mnemonic.trim(), Language::English)?;
+                self.import_legacy_with_mnemonic(&wallet_secret, payment_secret, mnemonic, None).await?
+            }
+        };
+        let account_descriptor = account.descriptor()?;
+
+        let (sweep_account_descriptor, transaction_ids) = if sweep {
+            let abortable = Abortable::new();
+            let (destination, transaction_ids) =
+                self.sweep_legacy_account(&account, &wallet_secret, payment_secret, &abortable).await?;
+            (Some(destination.descriptor()?), transaction_ids)
+        } else {
+            (None, vec![])
+        };
+
+        Ok(AccountsImportResponse { account_descriptor, sweep_account_descriptor, transaction_ids })
     }
 
     async fn accounts_get_call(self: Arc<Self>, request: AccountsGetRequest) -> Result<AccountsGetResponse> {
@@ -326,6 +568,45 @@ impl WalletApi for super::Wallet {
         Ok(AccountsGetResponse { account_descriptor })
     }
 
+    async fn accounts_export_xpub_call(self: Arc<Self>, request: AccountsExportXpubRequest) -> Result<AccountsExportXpubResponse> {
+        let AccountsExportXpubRequest { account_id, wallet_secret, payment_secret } = request;
+        let account = self.get_account_by_id(&account_id).await?.ok_or(Error::AccountNotFound(account_id))?;
+        let proof = account.xpub_ownership_proof(wallet_secret, payment_secret).await?;
+        Ok(AccountsExportXpubResponse { proof })
+    }
+
+    async fn accounts_sign_message_call(self: Arc<Self>, request: AccountsSignMessageRequest) -> Result<AccountsSignMessageResponse> {
+        let AccountsSignMessageRequest { account_id, wallet_secret, payment_secret, address, message } = request;
+        let account = self.get_account_by_id(&account_id).await?.ok_or(Error::AccountNotFound(account_id))?;
+        let signature = account.sign_message(&address, &message, wallet_secret, payment_secret).await?;
+        Ok(AccountsSignMessageResponse { signature })
+    }
+
+    async fn accounts_sign_transaction_call(
+        self: Arc<Self>,
+        request: AccountsSignTransactionRequest,
+    ) -> Result<AccountsSignTransactionResponse> {
+        let AccountsSignTransactionRequest { account_id, wallet_secret, payment_secret, transaction } = request;
+        let account = self.get_account_by_id(&account_id).await?.ok_or(Error::AccountNotFound(account_id))?;
+        let (transaction, encoding) = decode_signable_transaction(&transaction)?;
+        let (transaction, status) = account.sign_transaction(transaction, wallet_secret, payment_secret).await?;
+        let transaction = encode_signable_transaction(&transaction, encoding)?;
+        Ok(AccountsSignTransactionResponse { transaction, encoding, status })
+    }
+
+    async fn accounts_verify_message_call(
+        self: Arc<Self>,
+        request: AccountsVerifyMessageRequest,
+    ) -> Result<AccountsVerifyMessageResponse> {
+        let AccountsVerifyMessageRequest { address, message, signature } = request;
+        let valid = match verify_message_with_address(&PersonalMessage(&message), &signature, &address) {
+            Ok(()) => true,
+            Err(Error::InvalidMessageSignature) => false,
+            Err(err) => return Err(err),
+        };
+        Ok(AccountsVerifyMessageResponse { valid })
+    }
+
     async fn accounts_create_new_address_call(
         self: Arc<Self>,
         request: AccountsCreateNewAddressRequest,
@@ -334,24 +615,95 @@ impl WalletApi for super::Wallet {
 
         let account = self.get_account_by_id(&account_id).await?.ok_or(Error::AccountNotFound(account_id))?;
 
-        let address = match kind {
-            NewAddressKind::Receive => account.as_derivation_capable()?.new_receive_address().await?,
-            NewAddressKind::Change => account.as_derivation_capable()?.new_change_address().await?,
+        let account = account.as_derivation_capable()?;
+        let (address, beyond_gap_limit) = match kind {
+            NewAddressKind::Receive => (account.clone().new_receive_address().await?, account.is_beyond_gap_limit()),
+            NewAddressKind::Change => (account.new_change_address().await?, false),
         };
 
-        Ok(AccountsCreateNewAddressResponse { address })
+        Ok(AccountsCreateNewAddressResponse { address, beyond_gap_limit })
     }
 
     async fn accounts_send_call(self: Arc<Self>, request: AccountsSendRequest) -> Result<AccountsSendResponse> {
-        let AccountsSendRequest { account_id, wallet_secret, payment_secret, destination, priority_fee_sompi, payload } = request;
+        let AccountsSendRequest {
+            account_id,
+            wallet_secret,
+            payment_secret,
+            destination,
+            priority_fee_sompi,
+            payload,
+            utxos,
+            exclusive_utxos,
+            dry_run,
+            fee_bump,
+            change_policy,
+        } = request;
+        let coin_control = utxos.map(|utxos| CoinControl::new(utxos.into_iter().map(UtxoEntryId::from).collect(), exclusive_utxos));
+        let priority_fee_sompi = self.priority_fee_or_default(priority_fee_sompi)?;
+
+        let account = self.get_account_by_id(&account_id).await?.ok_or(Error::AccountNotFound(account_id))?;
+
+        // retain the final transaction of the send for fee-bump tracking
+        let final_transaction = Arc::new(Mutex::new(None));
+        let notifier = (fee_bump.is_some() && !dry_run).then(|| {
+            let final_transaction = final_transaction.clone();
+            Arc::new(move |transaction: &PendingTransaction| {
+                if transaction.is_final() {
+                    final_transaction.lock().unwrap().replace(transaction.clone());
+                }
+            }) as GenerationNotifier
+        });
+
+        let abortable = Abortable::new();
+        let (generator_summary, transaction_ids, validations) = self
+            .watchdog()
+            .run(
+                TaskKind::Send,
+                Some(account_id),
+                &abortable,
+                account.send(
+                    destination.clone(),
+                    priority_fee_sompi.clone(),
+                    payload.clone(),
+                    coin_control,
+                    change_policy.unwrap_or_default(),
+                    wallet_secret,
+                    payment_secret,
+                    dry_run,
+                    &abortable,
+                    notifier,
+                ),
+            )
+            .await?;
+
+        if let (Some(policy), Some(transaction)) = (fee_bump, final_transaction.lock().unwrap().take()) {
+            self.fee_bump().register(FeeBumpEntry::new(account_id, destination, priority_fee_sompi, payload, policy, transaction));
+        }
+
+        let daa_score = self.current_daa_score();
+
+        Ok(AccountsSendResponse { generator_summary, transaction_ids, daa_score, validations })
+    }
+
+    async fn accounts_send_batch_call(self: Arc<Self>, request: AccountsSendBatchRequest) -> Result<AccountsSendBatchResponse> {
+        let AccountsSendBatchRequest { account_id, wallet_secret, payment_secret, outputs, priority_fee_sompi } = request;
 
         let account = self.get_account_by_id(&account_id).await?.ok_or(Error::AccountNotFound(account_id))?;
+        let batch = PaymentBatch::try_new(outputs, &self.network_id()?)?;
+        let priority_fee_sompi = self.priority_fee_or_default(priority_fee_sompi)?;
 
         let abortable = Abortable::new();
-        let (generator_summary, transaction_ids) =
-            account.send(destination, priority_fee_sompi, payload, wallet_secret, payment_secret, &abortable, None).await?;
+        let (generator_summaries, transaction_ids) = self
+            .watchdog()
+            .run(
+                TaskKind::Send,
+                Some(account_id),
+                &abortable,
+                account.send_batch(batch, priority_fee_sompi, wallet_secret, payment_secret, &abortable, None),
+            )
+            .await?;
 
-        Ok(AccountsSendResponse { generator_summary, transaction_ids })
+        Ok(AccountsSendBatchResponse { generator_summaries, transaction_ids })
     }
 
     async fn accounts_transfer_call(self: Arc<Self>, request: AccountsTransferRequest) -> Result<AccountsTransferResponse> {
@@ -365,17 +717,24 @@ impl WalletApi for super::Wallet {
         } = request;
 
         let source_account = self.get_account_by_id(&source_account_id).await?.ok_or(Error::AccountNotFound(source_account_id))?;
+        let priority_fee_sompi = self.priority_fee_or_default(priority_fee_sompi)?;
 
         let abortable = Abortable::new();
-        let (generator_summary, transaction_ids) = source_account
-            .transfer(
-                destination_account_id,
-                transfer_amount_sompi,
-                priority_fee_sompi.unwrap_or(Fees::SenderPays(0)),
-                wallet_secret,
-                payment_secret,
+        let (generator_summary, transaction_ids) = self
+            .watchdog()
+            .run(
+                TaskKind::Transfer,
+                Some(source_account_id),
                 &abortable,
-                None,
+                source_account.transfer(
+                    destination_account_id,
+                    transfer_amount_sompi,
+                    priority_fee_sompi,
+                    wallet_secret,
+                    payment_secret,
+                    &abortable,
+                    None,
+                ),
             )
             .await?;
 
@@ -383,7 +742,8 @@ impl WalletApi for super::Wallet {
     }
 
     async fn accounts_estimate_call(self: Arc<Self>, request: AccountsEstimateRequest) -> Result<AccountsEstimateResponse> {
-        let AccountsEstimateRequest { account_id, destination, priority_fee_sompi, payload } = request;
+        let AccountsEstimateRequest { account_id, destination, priority_fee_sompi, payload, change_policy } = request;
+        let priority_fee_sompi = self.priority_fee_or_default(priority_fee_sompi)?;
 
         let account = self.get_account_by_id(&account_id).await?.ok_or(Error::AccountNotFound(account_id))?;
 
@@ -400,12 +760,24 @@ impl WalletApi for super::Wallet {
 
         let abortable = Abortable::new();
         self.inner.estimation_abortables.lock().unwrap().insert(account_id, abortable.clone());
-        let result = account.estimate(destination, priority_fee_sompi, payload, &abortable).await;
+        let result = account.estimate(destination, priority_fee_sompi, payload, change_policy.unwrap_or_default(), &abortable).await;
         self.inner.estimation_abortables.lock().unwrap().remove(&account_id);
 
         Ok(AccountsEstimateResponse { generator_summary: result? })
     }
 
+    async fn accounts_balance_history_call(
+        self: Arc<Self>,
+        request: AccountsBalanceHistoryRequest,
+    ) -> Result<AccountsBalanceHistoryResponse> {
+        let AccountsBalanceHistoryRequest { account_id, network_id, since_unixtime_msec } = request;
+
+        let binding = Binding::Account(account_id);
+        let (cache, unresolved) = self.update_balance_history(&binding, &network_id).await?;
+
+        Ok(AccountsBalanceHistoryResponse { account_id, history: cache.history(since_unixtime_msec), unresolved })
+    }
+
     async fn transactions_data_get_call(self: Arc<Self>, request: TransactionsDataGetRequest) -> Result<TransactionsDataGetResponse> {
         let TransactionsDataGetRequest { account_id, network_id, filter, start, end } = request;
 
@@ -449,10 +821,111 @@ impl WalletApi for super::Wallet {
         Ok(TransactionsReplaceMetadataResponse {})
     }
 
+    async fn transactions_export_call(self: Arc<Self>, request: TransactionsExportRequest) -> Result<TransactionsExportResponse> {
+        let TransactionsExportRequest { account_id, network_id, format, columns, since_unixtime_msec, until_unixtime_msec } = request;
+
+        let options = TransactionExportOptions::new(format).with_columns(columns).with_range(since_unixtime_msec, until_unixtime_msec);
+        let data = self.store().as_transaction_record_store()?.export(&Binding::Account(account_id), &network_id, &options).await?;
+
+        Ok(TransactionsExportResponse { format, data })
+    }
+
     async fn address_book_enumerate_call(
         self: Arc<Self>,
         _request: AddressBookEnumerateRequest,
     ) -> Result<AddressBookEnumerateResponse> {
         return Err(Error::NotImplemented);
     }
+
+    async fn tasks_enumerate_call(self: Arc<Self>, _request: TasksEnumerateRequest) -> Result<TasksEnumerateResponse> {
+        Ok(TasksEnumerateResponse { tasks: self.watchdog().tasks() })
+    }
+
+    async fn tasks_abort_call(self: Arc<Self>, request: TasksAbortRequest) -> Result<TasksAbortResponse> {
+        let TasksAbortRequest { task_id } = request;
+
+        match task_id {
+            Some(task_id) => self.watchdog().abort(task_id)?,
+            None => self.watchdog().abort_all(),
+        }
+
+        Ok(TasksAbortResponse {})
+    }
+
+    async fn accounts_get_utxos_call(self: Arc<Self>, request: AccountsGetUtxosRequest) -> Result<AccountsGetUtxosResponse> {
+        let AccountsGetUtxosRequest { account_id } = request;
+        let account = self.get_account_by_id(&account_id).await?.ok_or(Error::AccountNotFound(account_id))?;
+        let utxos = account.utxo_context().utxo_descriptors();
+        Ok(AccountsGetUtxosResponse { utxos })
+    }
+
+    async fn accounts_label_utxos_call(self: Arc<Self>, request: AccountsLabelUtxosRequest) -> Result<AccountsLabelUtxosResponse> {
+        let AccountsLabelUtxosRequest { account_id, outpoints, label } = request;
+        let account = self.get_account_by_id(&account_id).await?.ok_or(Error::AccountNotFound(account_id))?;
+        let outpoints = outpoints.into_iter().map(UtxoEntryId::from).collect::<Vec<_>>();
+        account.label_utxos(&outpoints, label).await?;
+        Ok(AccountsLabelUtxosResponse {})
+    }
+
+    async fn market_configure_call(self: Arc<Self>, request: MarketConfigureRequest) -> Result<MarketConfigureResponse> {
+        let MarketConfigureRequest { feed, currencies, interval_seconds } = request;
+        self.market().configure(feed.map(|feed| feed.feed()), &currencies, interval_seconds.map(Duration::from_secs))?;
+        Ok(MarketConfigureResponse {})
+    }
+
+    async fn market_data_get_call(self: Arc<Self>, _request: MarketDataGetRequest) -> Result<MarketDataGetResponse> {
+        Ok(MarketDataGetResponse { data: self.market().data() })
+    }
+
+    async fn webhooks_enumerate_call(self: Arc<Self>, _request: WebhooksEnumerateRequest) -> Result<WebhooksEnumerateResponse> {
+        let webhooks = self.webhooks().webhooks().iter().map(WebhookDescriptor::from).collect();
+        Ok(WebhooksEnumerateResponse { webhooks })
+    }
+
+    async fn webhooks_create_call(self: Arc<Self>, request: WebhooksCreateRequest) -> Result<WebhooksCreateResponse> {
+        let WebhooksCreateRequest { wallet_secret, url, secret, events, account_ids, confirmations, max_retries } = request;
+        if !WebhookDispatcher::is_supported() {
+            return Err(Error::Webhook("webhook delivery is not supported by this build".to_string()));
+        }
+
+        let mut webhook = WebhookConfig::try_new(url, false, events, account_ids, confirmations, max_retries)?;
+        let secret = secret.and_then(|secret| WebhookSecret::new(&webhook.id, &secret));
+        webhook.signed = secret.is_some();
+        if let Some(secret) = secret.as_ref() {
+            self.store_webhook_secret(&wallet_secret, &webhook.id, Some(secret.clone())).await?;
+        }
+
+        // the webhook is registered only once persisted in the settings
+        let mut webhooks = self.webhooks().webhooks();
+        webhooks.push(webhook.clone());
+        if let Err(err) = self.settings().set(WalletSettings::Webhooks, webhooks).await {
+            if secret.is_some() {
+                self.store_webhook_secret(&wallet_secret, &webhook.id, None)
+                    .await
+                    .unwrap_or_else(|err| log!(Wallet, Warn, "Unable to remove the secret of webhook {}: {err}", webhook.id));
+            }
+            return Err(err);
+        }
+
+        let descriptor = WebhookDescriptor::from(&webhook);
+        self.webhooks().insert(webhook, secret.as_ref())?;
+        Ok(WebhooksCreateResponse { webhook: descriptor })
+    }
+
+    async fn webhooks_remove_call(self: Arc<Self>, request: WebhooksRemoveRequest) -> Result<WebhooksRemoveResponse> {
+        let WebhooksRemoveRequest { wallet_secret, id } = request;
+        let mut webhooks = self.webhooks().webhooks();
+        let index = webhooks.iter().position(|webhook| webhook.id == id).ok_or_else(|| Error::WebhookNotFound(id.clone()))?;
+        let webhook = webhooks.remove(index);
+
+        // the webhook is unregistered only once removed from the settings
+        self.settings().set(WalletSettings::Webhooks, webhooks).await?;
+        self.webhooks().remove(&id)?;
+        if webhook.signed {
+            self.store_webhook_secret(&wallet_secret, &id, None)
+                .await
+                .unwrap_or_else(|err| log!(Wallet, Warn, "Unable to remove the secret of webhook {id}: {err}"));
+        }
+        Ok(WebhooksRemoveResponse {})
+    }
 }
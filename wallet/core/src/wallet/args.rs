@@ -2,6 +2,7 @@
 //! Structs used as various arguments for internal wallet operations.
 //!
 
+use crate::derivation::DerivationPathTemplate;
 use crate::imports::*;
 // use crate::secret::Secret;
 use crate::storage::interface::CreateArgs;
@@ -17,6 +18,10 @@ pub struct WalletCreateArgs {
     pub encryption_kind: EncryptionKind,
     pub user_hint: Option<Hint>,
     pub overwrite_wallet_storage: bool,
+    /// Store the accounts in the account database instead of the wallet file
+    /// (see [`CreateArgs::with_account_database()`]).
+    #[serde(default)]
+    pub account_database: bool,
 }
 
 impl WalletCreateArgs {
@@ -27,13 +32,19 @@ impl WalletCreateArgs {
         user_hint: Option<Hint>,
         overwrite_wallet_storage: bool,
     ) -> Self {
-        Self { title, filename, encryption_kind, user_hint, overwrite_wallet_storage }
+        Self { title, filename, encryption_kind, user_hint, overwrite_wallet_storage, account_database: false }
+    }
+
+    pub fn with_account_database(mut self, account_database: bool) -> Self {
+        self.account_database = account_database;
+        self
     }
 }
 
 impl From<WalletCreateArgs> for CreateArgs {
     fn from(args: WalletCreateArgs) -> Self {
         CreateArgs::new(args.title, args.filename, args.encryption_kind, args.user_hint, args.overwrite_wallet_storage)
+            .with_account_database(args.account_database)
     }
 }
 
@@ -89,6 +100,15 @@ export interface IPrvKeyDataArgs {
 export interface IAccountCreateArgsBip32 {
     accountName?: string;
     accountIndex?: number;
+    /**
+     * Custom account derivation path (e.g. `m/44'/972'/0'`).
+     * Overrides `accountIndex` if supplied.
+     */
+    derivationPath?: string;
+    /**
+     * Use ECDSA (instead of Schnorr) addresses and signatures.
+     */
+    ecdsa?: boolean;
 }
 
 /**
@@ -105,11 +125,28 @@ export interface IAccountCreateArgs {
 pub struct AccountCreateArgsBip32 {
     pub account_name: Option<String>,
     pub account_index: Option<u64>,
+    /// Custom derivation path template; when supplied, keys are derived
+    /// using this path instead of the standard kaspa BIP-44 path.
+    #[serde(default)]
+    pub derivation_path: Option<DerivationPathTemplate>,
+    /// Derive ECDSA (instead of Schnorr) addresses and sign using ECDSA.
+    #[serde(default)]
+    pub ecdsa: bool,
 }
 
 impl AccountCreateArgsBip32 {
     pub fn new(account_name: Option<String>, account_index: Option<u64>) -> Self {
-        Self { account_name, account_index }
+        Self { account_name, account_index, derivation_path: None, ecdsa: false }
+    }
+
+    pub fn with_ecdsa(mut self, ecdsa: bool) -> Self {
+        self.ecdsa = ecdsa;
+        self
+    }
+
+    pub fn with_derivation_path(mut self, derivation_path: DerivationPathTemplate) -> Self {
+        self.derivation_path = Some(derivation_path);
+        self
     }
 }
 
@@ -152,7 +189,7 @@ impl AccountCreateArgs {
         account_index: Option<u64>,
     ) -> Self {
         let prv_key_data_args = PrvKeyDataArgs { prv_key_data_id, payment_secret };
-        let account_args = AccountCreateArgsBip32 { account_name, account_index };
+        let account_args = AccountCreateArgsBip32::new(account_name, account_index);
         AccountCreateArgs::Bip32 { prv_key_data_args, account_args }
     }
 
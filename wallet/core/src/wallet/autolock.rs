@@ -0,0 +1,100 @@
+//!
+//! Wallet auto-lock after a period of inactivity.
+//!
+//! Wallet API calls received via the [`WalletServer`](crate::api::transport::WalletServer)
+//! transport or the WASM bindings register user activity with the [`AutoLock`]
+//! (see [`Wallet::register_api_activity`](crate::wallet::Wallet::register_api_activity)).
+//! Once the configured inactivity timeout elapses, the wallet is closed
+//! (dropping the decrypted storage cache and any cached key material)
+//! and [`Events::WalletLocked`] is emitted, signaling the client to
+//! prompt the user for the wallet secret and re-open the wallet.
+//!
+
+use crate::imports::*;
+
+/// Wallet API methods that do not constitute user activity
+/// (status polling and similar passive calls).
+pub const PASSIVE_API_METHODS: &[&str] =
+    &["ping", "get-status", "retain-context", "set-log-level", "accounts-verify-message", "market-data-get"];
+
+struct Inner {
+    timeout: Mutex<Option<Duration>>,
+    last_activity: Mutex<Instant>,
+}
+
+/// Tracks wallet activity against the configured inactivity timeout.
+#[derive(Clone)]
+pub struct AutoLock {
+    inner: Arc<Inner>,
+}
+
+impl Default for AutoLock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AutoLock {
+    pub fn new() -> Self {
+        Self { inner: Arc::new(Inner { timeout: Mutex::new(None), last_activity: Mutex::new(Instant::now()) }) }
+    }
+
+    /// Returns the inactivity timeout, `None` if auto-lock is disabled.
+    pub fn timeout(&self) -> Option<Duration> {
+        *self.inner.timeout.lock().unwrap()
+    }
+
+    /// Set the inactivity timeout. Supplying `None` disables auto-lock.
+    pub fn set_timeout(&self, timeout: Option<Duration>) {
+        *self.inner.timeout.lock().unwrap() = timeout;
+        self.touch();
+    }
+
+    /// Register wallet activity, restarting the inactivity timeout.
+    pub fn touch(&self) {
+        *self.inner.last_activity.lock().unwrap() = Instant::now();
+    }
+
+    /// Returns the time remaining until the wallet is locked,
+    /// `None` if auto-lock is disabled.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.timeout().map(|timeout| timeout.saturating_sub(self.inner.last_activity.lock().unwrap().elapsed()))
+    }
+
+    /// Returns `true` if the inactivity timeout has elapsed.
+    pub fn is_expired(&self) -> bool {
+        self.remaining().is_some_and(|remaining| remaining.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_lock_expiry() {
+        let auto_lock = AutoLock::new();
+        assert!(!auto_lock.is_expired());
+
+        auto_lock.set_timeout(Some(Duration::from_millis(10)));
+        assert!(!auto_lock.is_expired());
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(auto_lock.is_expired());
+
+        auto_lock.touch();
+        assert!(!auto_lock.is_expired());
+
+        auto_lock.set_timeout(None);
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(!auto_lock.is_expired());
+    }
+
+    #[test]
+    fn test_auto_lock_passive_api_methods() {
+        use crate::api::transport::WalletServer;
+
+        for method in PASSIVE_API_METHODS {
+            assert!(WalletServer::METHODS.contains(method), "unknown wallet API method `{method}`");
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//!
+//! Background fee-bump policy for time-sensitive sends.
+//!
+//! A send can be tagged with a [`FeeBumpPolicy`]. If the final transaction
+//! of the send is not accepted within the policy deadline, the wallet
+//! regenerates it from the same UTXO entries with a higher priority fee
+//! (bounded by the policy maximum fee), replaces the pending record and
+//! emits [`Events::FeeBump`].
+//!
+//! Kaspa nodes reject transactions double-spending mempool transactions,
+//! so a transaction is regenerated only once it has left the node mempool
+//! (i.e. has expired unaccepted). While the transaction remains in the
+//! mempool, the deadline is extended by the policy interval.
+//!
+//! Tracked sends retain the signer of the original transaction (and its
+//! private key material) until the transaction is accepted or the wallet
+//! is closed.
+//!
+
+use crate::imports::*;
+use crate::tx::{Fees, PaymentDestination, PendingTransaction};
+
+/// Fee-bump policy attached to a send.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeBumpPolicy {
+    /// Time (in seconds) allowed for the transaction to be accepted
+    /// before it is regenerated with a higher fee.
+    pub deadline: u64,
+    /// Maximum total fees (in sompi) of a regenerated transaction.
+    pub max_fee_sompi: u64,
+}
+
+impl FeeBumpPolicy {
+    pub fn new(deadline: Duration, max_fee_sompi: u64) -> Self {
+        Self { deadline: deadline.as_secs(), max_fee_sompi }
+    }
+
+    pub fn deadline(&self) -> Duration {
+        Duration::from_secs(self.deadline)
+    }
+
+    /// Returns the priority fee of the next regeneration of a transaction paying `fees` in total
+    /// (including `priority_fee`), `None` if the fee can not be raised within the maximum fee.
+    /// Each bump raises the priority fee by at least the network fees of the transaction.
+    pub fn next_priority_fee(&self, priority_fee: u64, fees: u64) -> Option<u64> {
+        let network_fees = fees.saturating_sub(priority_fee);
+        let budget = self.max_fee_sompi.checked_sub(network_fees)?;
+        let next = priority_fee.saturating_add(priority_fee.max(network_fees).max(1)).min(budget);
+        (next > priority_fee).then_some(next)
+    }
+}
+
+/// A send tracked against its [`FeeBumpPolicy`].
+#[derive(Clone)]
+pub struct FeeBumpEntry {
+    pub account_id: AccountId,
+    pub destination: PaymentDestination,
+    pub priority_fee_sompi: Fees,
+    pub payload: Option<Vec<u8>>,
+    pub policy: FeeBumpPolicy,
+    /// Final transaction of the send
+    pub transaction: PendingTransaction,
+    deadline: Instant,
+}
+
+impl FeeBumpEntry {
+    pub fn new(
+        account_id: AccountId,
+        destination: PaymentDestination,
+        priority_fee_sompi: Fees,
+        payload: Option<Vec<u8>>,
+        policy: FeeBumpPolicy,
+        transaction: PendingTransaction,
+    ) -> Self {
+        let deadline = Instant::now() + policy.deadline();
+        Self { account_id, destination, priority_fee_sompi, payload, policy, transaction, deadline }
+    }
+
+    /// Priority fee paid by the tracked transaction (rate-based
+    /// fees are resolved using the mass of the transaction),
+    /// `None` if the fee does not fit into `u64`.
+    pub fn priority_fee(&self) -> Option<u64> {
+        self.priority_fee_sompi.priority_fee(self.transaction.mass()).ok()
+    }
+
+    /// Returns the fees of the regenerated transaction, `None`
+    /// if the fee can not be raised within the policy maximum fee.
+    pub fn bumped_fees(&self) -> Option<Fees> {
+        let priority_fee = self.policy.next_priority_fee(self.priority_fee()?, self.transaction.fees())?;
+        if self.priority_fee_sompi.receiver_pays() {
+            Some(Fees::ReceiverPays(priority_fee))
+        } else {
+            Some(Fees::SenderPays(priority_fee))
+        }
+    }
+
+    /// Extend the deadline by the policy interval.
+    pub fn extend(mut self) -> Self {
+        self.deadline = Instant::now() + self.policy.deadline();
+        self
+    }
+}
+
+/// Tracks sends against their [`FeeBumpPolicy`] deadlines.
+#[derive(Clone, Default)]
+pub struct FeeBump {
+    entries: Arc<Mutex<AHashMap<TransactionId, FeeBumpEntry>>>,
+}
+
+impl FeeBump {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Track the final transaction of a send.
+    pub fn register(&self, entry: FeeBumpEntry) {
+        self.entries.lock().unwrap().insert(entry.transaction.id(), entry);
+    }
+
+    /// Stop tracking the transaction, returning its entry.
+    pub fn remove(&self, id: &TransactionId) -> Option<FeeBumpEntry> {
+        self.entries.lock().unwrap().remove(id)
+    }
+
+    pub fn contains(&self, id: &TransactionId) -> bool {
+        self.entries.lock().unwrap().contains_key(id)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.lock().unwrap().is_empty()
+    }
+
+    /// Remove and return all entries whose deadline has elapsed.
+    pub fn take_expired(&self) -> Vec<FeeBumpEntry> {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap();
+        let expired = entries.iter().filter(|(_, entry)| entry.deadline <= now).map(|(id, _)| *id).collect::<Vec<_>>();
+        expired.into_iter().filter_map(|id| entries.remove(&id)).collect()
+    }
+
+    /// Stop tracking all sends (releasing the retained signers).
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_bump_next_priority_fee() {
+        let policy = FeeBumpPolicy::new(Duration::from_secs(60), 10_000);
+
+        // no priority fee: raised by the network fees
+        assert_eq!(policy.next_priority_fee(0, 2_000), Some(2_000));
+        // priority fee above network fees: doubled
+        assert_eq!(policy.next_priority_fee(3_000, 5_000), Some(6_000));
+        // bounded by the maximum fee
+        assert_eq!(policy.next_priority_fee(6_000, 8_000), Some(8_000));
+        // maximum fee reached
+        assert_eq!(policy.next_priority_fee(8_000, 10_000), None);
+        // network fees alone exceed the maximum fee
+        assert_eq!(policy.next_priority_fee(0, 12_000), None);
+    }
+}
@@ -3,26 +3,46 @@
 //!
 pub mod api;
 pub mod args;
+pub mod autolock;
+pub mod feebump;
 pub mod maps;
+pub mod sync;
+pub mod timestamps;
+pub mod watchdog;
 pub use args::*;
+pub use autolock::*;
+pub use feebump::*;
+pub use sync::*;
+pub use timestamps::*;
+pub use watchdog::*;
 
 use crate::account::ScanNotifier;
+use crate::api::message::DiscoveredAccount;
 use crate::compat::gen1::decrypt_mnemonic;
 use crate::error::Error::Custom;
 use crate::factory::try_load_account;
 use crate::imports::*;
-use crate::settings::{SettingsStore, WalletSettings};
+use crate::logging::log;
+use crate::settings::{SettingsProfile, SettingsStore, WalletSettings};
 use crate::storage::interface::{OpenArgs, StorageDescriptor};
 use crate::storage::local::interface::LocalStore;
+#[cfg(feature = "fs")]
 use crate::storage::local::Storage;
+use crate::strength::SecretStrengthPolicy;
+use crate::tests::MockRpcClient;
+use crate::tx::{Fees, Generator, GeneratorSettings};
 use crate::wallet::maps::ActiveAccountMap;
+use crate::webhooks::{WebhookConfig, WebhookDispatcher, WebhookSecret};
+use futures::pin_mut;
 use kaspa_bip32::{ExtendedKey, Language, Mnemonic, Prefix as KeyPrefix, WordCount};
 use kaspa_notify::{
     listener::ListenerId,
     scope::{Scope, VirtualDaaScoreChangedScope},
 };
+use kaspa_wrpc_client::capabilities::NodeFeature;
 use kaspa_wrpc_client::{KaspaRpcClient, Resolver, WrpcEncoding};
 use workflow_core::task::spawn;
+use workflow_core::time::unixtime_as_millis_u64;
 
 #[derive(Debug)]
 pub struct EncryptedMnemonic<T: AsRef<[u8]>> {
@@ -90,6 +110,16 @@ pub struct Inner {
     wallet_bus: Channel<WalletBusMessage>,
     estimation_abortables: Mutex<HashMap<AccountId, Abortable>>,
     retained_contexts: Mutex<HashMap<String, Arc<Vec<u8>>>>,
+    /// Address scans interrupted by a disconnection (scan window size and extent
+    /// per account), resumed when the wallet reconnects.
+    interrupted_scans: Mutex<HashMap<AccountId, (Option<usize>, Option<u32>)>>,
+    watchdog: Watchdog,
+    wallet_sync: WalletSync,
+    auto_lock: AutoLock,
+    fee_bump: FeeBump,
+    market: MarketMonitor,
+    webhooks: WebhookDispatcher,
+    secret_strength_policy: Mutex<SecretStrengthPolicy>,
 }
 
 ///
@@ -105,12 +135,13 @@ pub struct Wallet {
 }
 
 impl Wallet {
+    #[cfg(feature = "fs")]
     pub fn local_store() -> Result<Arc<dyn Interface>> {
         Ok(Arc::new(LocalStore::try_new(false)?))
     }
 
     pub fn resident_store() -> Result<Arc<dyn Interface>> {
-        Ok(Arc::new(LocalStore::try_new(true)?))
+        Ok(Arc::new(LocalStore::new_resident()))
     }
 
     pub fn try_new(storage: Arc<dyn Interface>, resolver: Option<Resolver>, network_id: Option<NetworkId>) -> Result<Wallet> {
@@ -135,12 +166,39 @@ impl Wallet {
         Self::try_with_rpc(Some(rpc), store, network_id)
     }
 
+    /// Create a wallet sharing the connection of an existing wRPC client with
+    /// other wallets. The wallet operates on its own client handle (see
+    /// [`KaspaRpcClient::handle()`]), so its notification listeners and
+    /// `UtxosChanged` subscriptions do not interfere with other wallets.
+    pub fn try_with_shared_wrpc(
+        rpc_client: &KaspaRpcClient,
+        store: Arc<dyn Interface>,
+        network_id: Option<NetworkId>,
+    ) -> Result<Wallet> {
+        let rpc_client = Arc::new(rpc_client.handle());
+        let rpc_ctl = rpc_client.ctl().clone();
+        let rpc_api: Arc<DynRpcApi> = rpc_client;
+        Self::try_with_rpc(Some(Rpc::new(rpc_api, rpc_ctl)), store, network_id)
+    }
+
+    /// Create a wallet backed by the in-memory [`MockRpcClient`] instead of a
+    /// [`KaspaRpcClient`], allowing wallet flows to be exercised without a node.
+    /// The wallet operates on the network of the mock; the connection is
+    /// established by [`MockRpcClient::connect()`].
+    pub fn try_with_mock_rpc(rpc_client: &Arc<MockRpcClient>, store: Arc<dyn Interface>) -> Result<Wallet> {
+        Self::try_with_rpc(Some(rpc_client.clone().into()), store, Some(rpc_client.network_id()))
+    }
+
     pub fn try_with_rpc(rpc: Option<Rpc>, store: Arc<dyn Interface>, network_id: Option<NetworkId>) -> Result<Wallet> {
         let multiplexer = Multiplexer::<Box<Events>>::new();
         let wallet_bus = Channel::unbounded();
         let utxo_processor =
             Arc::new(UtxoProcessor::new(rpc.clone(), network_id, Some(multiplexer.clone()), Some(wallet_bus.clone())));
 
+        let watchdog = Watchdog::new(multiplexer.clone());
+        let market = MarketMonitor::new(multiplexer.clone());
+        let wallet_sync = WalletSync::new(multiplexer.clone());
+
         let wallet = Wallet {
             inner: Arc::new(Inner {
                 multiplexer,
@@ -150,11 +208,22 @@ impl Wallet {
                 listener_id: Mutex::new(None),
                 task_ctl: DuplexChannel::oneshot(),
                 selected_account: Mutex::new(None),
+                #[cfg(feature = "fs")]
                 settings: SettingsStore::new_with_storage(Storage::default_settings_store()),
+                #[cfg(not(feature = "fs"))]
+                settings: SettingsStore::new_resident(),
                 utxo_processor: utxo_processor.clone(),
                 wallet_bus,
                 estimation_abortables: Mutex::new(HashMap::new()),
                 retained_contexts: Mutex::new(HashMap::new()),
+                interrupted_scans: Mutex::new(HashMap::new()),
+                watchdog,
+                wallet_sync,
+                auto_lock: AutoLock::new(),
+                fee_bump: FeeBump::new(),
+                market,
+                webhooks: WebhookDispatcher::new(),
+                secret_strength_policy: Mutex::new(SecretStrengthPolicy::default()),
             }),
         };
 
@@ -190,6 +259,8 @@ impl Wallet {
 
     pub async fn reset(self: &Arc<Self>, clear_legacy_cache: bool) -> Result<()> {
         self.utxo_processor().cleanup().await?;
+        self.fee_bump().clear();
+        self.wallet_sync().reset();
 
         self.select(None).await?;
 
@@ -239,6 +310,7 @@ impl Wallet {
     pub async fn close(self: &Arc<Wallet>) -> Result<()> {
         if self.is_open() {
             self.reset(true).await?;
+            self.webhooks().set_secrets(&[]);
             self.store().close().await?;
             self.notify(Events::WalletClose).await?;
         }
@@ -246,6 +318,19 @@ impl Wallet {
         Ok(())
     }
 
+    /// Close the wallet due to inactivity, dropping the decrypted
+    /// storage cache, and post [`Events::WalletLocked`]. The wallet
+    /// secret is required to re-open the wallet.
+    pub async fn lock(self: &Arc<Wallet>) -> Result<()> {
+        if self.is_open() {
+            let wallet_descriptor = self.descriptor();
+            self.close().await?;
+            self.notify(Events::WalletLocked { wallet_descriptor }).await?;
+        }
+
+        Ok(())
+    }
+
     cfg_if! {
         if #[cfg(not(feature = "multi-user"))] {
 
@@ -299,6 +384,7 @@ impl Wallet {
         let was_open = self.is_open();
 
         self.store().open(wallet_secret, OpenArgs::new(filename)).await?;
+        self.auto_lock().touch();
         let wallet_name = self.store().descriptor();
 
         if was_open {
@@ -307,41 +393,43 @@ impl Wallet {
 
         // reset current state only after we have successfully opened another wallet
         self.reset(true).await?;
+        self.webhooks().set_secrets(&self.store().get_webhook_secrets().await?);
 
-        let accounts: Option<Vec<Arc<dyn Account>>> = if args.load_account_descriptors() {
+        // only legacy accounts are loaded, descriptors of other accounts are
+        // produced from storage without deserializing the account payloads
+        let account_descriptors = if args.load_account_descriptors() {
             let stored_accounts = self.inner.store.as_account_store().unwrap().iter(None).await?.try_collect::<Vec<_>>().await?;
-            let stored_accounts = if !args.is_legacy_only() {
-                stored_accounts
-            } else {
-                stored_accounts
-                    .into_iter()
-                    .filter(|(account_storage, _)| account_storage.kind.as_ref() == LEGACY_ACCOUNT_KIND)
-                    .collect::<Vec<_>>()
-            };
-            Some(
-                futures::stream::iter(stored_accounts.into_iter())
-                    .then(|(account, meta)| try_load_account(self, account, meta))
-                    .try_collect::<Vec<_>>()
-                    // .try_collect::<Result<Vec<_>>>()
-                    .await?,
-            )
-        } else {
-            None
-        };
-
-        let account_descriptors = accounts
-            .as_ref()
-            .map(|accounts| accounts.iter().map(|account| account.descriptor()).collect::<Result<Vec<_>>>())
-            .transpose()?;
+            let mut account_descriptors = Vec::with_capacity(stored_accounts.len());
+            for (account_storage, meta) in stored_accounts.into_iter() {
+                let is_legacy = account_storage.kind.as_ref() == LEGACY_ACCOUNT_KIND;
+                if args.is_legacy_only() && !is_legacy {
+                    continue;
+                }
 
-        if let Some(accounts) = accounts {
-            for account in accounts.into_iter() {
-                if let Ok(legacy_account) = account.clone().as_legacy_account() {
+                let archived = account_storage.settings.archived;
+                let descriptor = if is_legacy {
+                    let account = try_load_account(self, account_storage, meta).await?;
+                    let descriptor = account.descriptor()?;
+                    let legacy_account = account.clone().as_legacy_account()?;
                     self.legacy_accounts().insert(account);
                     legacy_account.create_private_context(wallet_secret, None, None).await?;
+                    descriptor
+                } else if archived {
+                    continue;
+                } else if let Some(descriptor) = account_storage.descriptor(meta.as_deref()) {
+                    descriptor
+                } else {
+                    try_load_account(self, account_storage, meta).await?.descriptor()?
+                };
+
+                if !archived {
+                    account_descriptors.push(descriptor);
                 }
             }
-        }
+            Some(account_descriptors)
+        } else {
+            None
+        };
 
         self.notify(Events::WalletOpen { wallet_descriptor: wallet_name, account_descriptors: account_descriptors.clone() }).await?;
 
@@ -370,13 +458,29 @@ impl Wallet {
 
     async fn activate_accounts_impl(self: &Arc<Wallet>, account_ids: Option<&[AccountId]>) -> Result<Vec<AccountId>> {
         let stored_accounts = if let Some(ids) = account_ids {
-            self.inner.store.as_account_store().unwrap().load_multiple(ids).await?
+            let stored_accounts = self.inner.store.as_account_store().unwrap().load_multiple(ids).await?;
+            if let Some((account, _)) = stored_accounts.iter().find(|(account, _)| account.settings.archived) {
+                return Err(Error::AccountArchived(*account.id()));
+            }
+            stored_accounts
         } else {
-            self.inner.store.as_account_store().unwrap().iter(None).await?.try_collect::<Vec<_>>().await?
+            self.inner
+                .store
+                .as_account_store()
+                .unwrap()
+                .iter(None)
+                .await?
+                .try_filter(|(account, _)| futures::future::ready(!account.settings.archived))
+                .try_collect::<Vec<_>>()
+                .await?
         };
 
         let ids = stored_accounts.iter().map(|(account, _)| *account.id()).collect::<Vec<_>>();
 
+        // accounts are scanned only if the wallet is connected; the scan
+        // progress is reported per address by the account scans
+        let track_sync = self.is_connected() && self.wallet_sync().begin(WalletSyncPhase::AddressScan, 0);
+
         for (account_storage, meta) in stored_accounts.into_iter() {
             if account_storage.kind.as_ref() == LEGACY_ACCOUNT_KIND {
                 let legacy_account = self
@@ -389,19 +493,74 @@ impl Wallet {
                 legacy_account.clear_private_context().await?;
             } else {
                 let account = try_load_account(self, account_storage, meta).await?;
-                account.clone().start().await?;
+                let abortable = Abortable::new();
+                self.watchdog().run(TaskKind::Scan, Some(*account.id()), &abortable, account.clone().start()).await?;
             }
         }
 
+        if track_sync {
+            self.reconcile_accounts(&ids).await?;
+            self.spawn_timestamp_backfill(ids.clone());
+        }
+
         self.notify(Events::AccountActivation { ids: ids.clone() }).await?;
 
         Ok(ids)
     }
 
+    /// Record an account address scan interrupted by a disconnection
+    /// (see [`Account::scan`]); the scan is resumed upon reconnection.
+    pub(crate) fn register_interrupted_scan(&self, account_id: AccountId, window_size: Option<usize>, extent: Option<u32>) {
+        self.inner.interrupted_scans.lock().unwrap().insert(account_id, (window_size, extent));
+    }
+
+    /// Resume the address scans interrupted by a disconnection, emitting
+    /// [`Events::ScanResumed`] for each account that is still active.
+    fn resume_interrupted_scans(self: &Arc<Self>) {
+        let scans = std::mem::take(&mut *self.inner.interrupted_scans.lock().unwrap());
+        for (account_id, (window_size, extent)) in scans {
+            let Some(account) = self.active_accounts().get(&account_id) else {
+                continue;
+            };
+
+            let this = self.clone();
+            spawn(async move {
+                this.notify(Events::ScanResumed { account_id }).await.ok();
+                let abortable = Abortable::new();
+                let scan = account.scan(window_size, extent);
+                if let Err(err) = this.watchdog().run(TaskKind::Scan, Some(account_id), &abortable, scan).await {
+                    log!(Wallet, Warn, "Account {account_id}: unable to resume the address scan: {err}");
+                }
+            });
+        }
+    }
+
+    /// Reconcile the UTXOs obtained by the address scan of the activated
+    /// accounts with the account balances and the persisted address usage,
+    /// completing the wallet sync.
+    async fn reconcile_accounts(&self, ids: &[AccountId]) -> Result<()> {
+        let wallet_sync = self.wallet_sync();
+        if !wallet_sync.begin(WalletSyncPhase::UtxoReconciliation, ids.len() as u64) {
+            return Ok(());
+        }
+
+        for id in ids {
+            if let Some(account) = self.active_accounts().get(id) {
+                account.utxo_context().update_balance().await?;
+                self.store_metadata_changes(id).await?;
+            }
+            wallet_sync.advance(WalletSyncPhase::UtxoReconciliation, 1);
+        }
+
+        wallet_sync.complete();
+        Ok(())
+    }
+
     /// Activates accounts (performs account address space counts, initializes balance tracking, etc.)
     pub async fn activate_accounts(self: &Arc<Wallet>, account_ids: Option<&[AccountId]>) -> Result<()> {
         // This is a wrapper of activate_accounts_impl() that catches errors and notifies the UI
         if let Err(err) = self.activate_accounts_impl(account_ids).await {
+            self.wallet_sync().reset();
             self.notify(Events::WalletError { message: err.to_string() }).await?;
             Err(err)
         } else {
@@ -424,11 +583,22 @@ impl Wallet {
         Ok(())
     }
 
+    /// Descriptors of the wallet accounts (excluding archived accounts).
     pub async fn account_descriptors(self: Arc<Self>) -> Result<Vec<AccountDescriptor>> {
+        self.account_descriptors_impl(false).await
+    }
+
+    /// Descriptors of the archived wallet accounts.
+    pub async fn archived_account_descriptors(self: Arc<Self>) -> Result<Vec<AccountDescriptor>> {
+        self.account_descriptors_impl(true).await
+    }
+
+    async fn account_descriptors_impl(self: Arc<Self>, archived: bool) -> Result<Vec<AccountDescriptor>> {
         let iter = self.inner.store.as_account_store().unwrap().iter(None).await.unwrap();
         let wallet = self.clone();
 
-        let stream = iter.then(move |stored| {
+        let stream = iter.try_filter(move |(stored_account, _)| futures::future::ready(stored_account.settings.archived == archived));
+        let stream = stream.then(move |stored| {
             let wallet = wallet.clone();
 
             async move {
@@ -437,6 +607,8 @@ impl Wallet {
                     account.descriptor()
                 } else if let Some(account) = wallet.active_accounts().get(&stored_account.id) {
                     account.descriptor()
+                } else if let Some(descriptor) = stored_account.descriptor(stored_metadata.as_deref()) {
+                    Ok(descriptor)
                 } else {
                     try_load_account(&wallet, stored_account, stored_metadata).await?.descriptor()
                 }
@@ -446,6 +618,49 @@ impl Wallet {
         stream.try_collect::<Vec<_>>().await
     }
 
+    /// Archive accounts. Archived accounts are deactivated and excluded from
+    /// account enumeration and activation while their storage is retained.
+    pub async fn archive_accounts(self: &Arc<Self>, wallet_secret: &Secret, ids: &[AccountId]) -> Result<()> {
+        let accounts = self.load_accounts_for_archival(ids).await?;
+
+        let active = ids.iter().filter(|id| self.active_accounts().contains(id)).cloned().collect::<Vec<_>>();
+        if !active.is_empty() {
+            self.deactivate_accounts(Some(&active)).await?;
+        }
+        if self.account().is_ok_and(|account| ids.contains(account.id())) {
+            self.select(None).await?;
+        }
+
+        for account in accounts.iter() {
+            account.set_archived(true).await?;
+        }
+        self.store().commit(wallet_secret).await?;
+        self.notify(Events::AccountArchive { ids: ids.to_vec() }).await?;
+
+        Ok(())
+    }
+
+    /// Restore archived accounts. Restored accounts are not activated by this call.
+    pub async fn unarchive_accounts(self: &Arc<Self>, wallet_secret: &Secret, ids: &[AccountId]) -> Result<()> {
+        let accounts = self.load_accounts_for_archival(ids).await?;
+
+        for account in accounts.iter() {
+            account.set_archived(false).await?;
+        }
+        self.store().commit(wallet_secret).await?;
+        self.notify(Events::AccountUnarchive { ids: ids.to_vec() }).await?;
+
+        Ok(())
+    }
+
+    async fn load_accounts_for_archival(self: &Arc<Self>, ids: &[AccountId]) -> Result<Vec<Arc<dyn Account>>> {
+        let mut accounts = Vec::with_capacity(ids.len());
+        for id in ids {
+            accounts.push(self.get_account_by_id(id).await?.ok_or(Error::AccountNotFound(*id))?);
+        }
+        Ok(accounts)
+    }
+
     pub async fn get_prv_key_data(&self, wallet_secret: &Secret, id: &PrvKeyDataId) -> Result<Option<PrvKeyData>> {
         self.inner.store.as_prv_key_data_store()?.load_key_data(wallet_secret, id).await
     }
@@ -491,6 +706,65 @@ impl Wallet {
         &self.inner.multiplexer
     }
 
+    /// Watchdog monitoring long-running wallet operations.
+    pub fn watchdog(&self) -> &Watchdog {
+        &self.inner.watchdog
+    }
+
+    /// Wallet sync state machine tracking the account scan,
+    /// UTXO reconciliation and transaction history backfill.
+    pub fn wallet_sync(&self) -> &WalletSync {
+        &self.inner.wallet_sync
+    }
+
+    /// Auto-lock monitoring wallet inactivity.
+    pub fn auto_lock(&self) -> &AutoLock {
+        &self.inner.auto_lock
+    }
+
+    /// Register a call of the Wallet API `method` (kebab-case name) as user
+    /// activity, restarting the auto-lock inactivity timeout. Passive calls
+    /// (see [`PASSIVE_API_METHODS`]) do not register activity.
+    pub fn register_api_activity(&self, method: &str) {
+        if !PASSIVE_API_METHODS.contains(&method) {
+            self.auto_lock().touch();
+        }
+    }
+
+    /// Sends tracked against their fee-bump policy.
+    pub fn fee_bump(&self) -> &FeeBump {
+        &self.inner.fee_bump
+    }
+
+    /// Minimum strength of the wallet secrets supplied when creating
+    /// wallets or changing the wallet secret.
+    pub fn secret_strength_policy(&self) -> SecretStrengthPolicy {
+        *self.inner.secret_strength_policy.lock().unwrap()
+    }
+
+    pub fn set_secret_strength_policy(&self, policy: SecretStrengthPolicy) {
+        *self.inner.secret_strength_policy.lock().unwrap() = policy;
+    }
+
+    /// Market monitor polling the configured fiat price feed.
+    pub fn market(&self) -> &MarketMonitor {
+        &self.inner.market
+    }
+
+    /// Webhook dispatcher notifying the configured webhooks of wallet events.
+    pub fn webhooks(&self) -> &WebhookDispatcher {
+        &self.inner.webhooks
+    }
+
+    /// Store (or remove if `None`) the signing secret of the webhook `id`
+    /// in the wallet data (requires an open wallet).
+    pub(crate) async fn store_webhook_secret(&self, wallet_secret: &Secret, id: &str, secret: Option<WebhookSecret>) -> Result<()> {
+        let mut secrets = self.store().get_webhook_secrets().await?;
+        secrets.retain(|secret| secret.id != id);
+        secrets.extend(secret);
+        self.store().set_webhook_secrets(wallet_secret, secrets).await
+    }
+
     pub(crate) fn wallet_bus(&self) -> &Channel<WalletBusMessage> {
         &self.inner.wallet_bus
     }
@@ -509,12 +783,46 @@ impl Wallet {
         let settings = self.settings();
 
         if let Some(network_id) = settings.get(WalletSettings::Network) {
-            self.set_network_id(&network_id).unwrap_or_else(|_| log_error!("Unable to select network type: `{}`", network_id));
+            self.set_network_id(&network_id)
+                .unwrap_or_else(|_| log!(Wallet, Error, "Unable to select network type: `{}`", network_id));
         }
 
         if let Some(url) = settings.get::<String>(WalletSettings::Server) {
             if let Some(wrpc_client) = self.try_wrpc_client() {
-                wrpc_client.set_url(Some(url.as_str())).unwrap_or_else(|_| log_error!("Unable to set rpc url: `{}`", url));
+                wrpc_client.set_url(Some(url.as_str())).unwrap_or_else(|_| log!(RpcClient, Error, "Unable to set rpc url: `{}`", url));
+            }
+        }
+
+        if let Some(timeout) = settings.get::<u64>(WalletSettings::AutoLock) {
+            self.auto_lock().set_timeout((timeout > 0).then(|| Duration::from_secs(timeout)));
+        }
+
+        if let Some(min_score) = settings.get::<u8>(WalletSettings::SecretStrength) {
+            self.set_secret_strength_policy(SecretStrengthPolicy::new(min_score));
+        }
+
+        if let Some(feed) = settings.get::<PriceFeedKind>(WalletSettings::PriceFeed) {
+            let currencies = settings.get::<Vec<String>>(WalletSettings::Currencies).unwrap_or_else(|| vec!["usd".to_string()]);
+            self.market()
+                .configure(Some(feed.feed()), &currencies, None)
+                .unwrap_or_else(|err| log!(Wallet, Error, "Unable to configure price feed `{feed}`: {err}"));
+        }
+
+        if let Some(webhooks) = settings.get::<Vec<WebhookConfig>>(WalletSettings::Webhooks) {
+            self.webhooks().configure(webhooks);
+        }
+
+        Ok(())
+    }
+
+    /// Apply the network and the RPC server of a settings profile selected
+    /// via [`SettingsStore::select_profile`]; the wallet must be disconnected.
+    pub fn apply_settings_profile(&self, profile: &SettingsProfile) -> Result<()> {
+        self.set_network_id(&profile.network)?;
+
+        if let Some(url) = profile.server.as_deref().filter(|server| *server != "public") {
+            if let Some(wrpc_client) = self.try_wrpc_client() {
+                wrpc_client.set_url(Some(url))?;
             }
         }
 
@@ -527,6 +835,7 @@ impl Wallet {
 
         // internal event loop
         self.start_task().await?;
+        self.market().start().await?;
         self.utxo_processor().start().await?;
         // rpc services (notifier)
         if let Some(rpc_client) = self.try_wrpc_client() {
@@ -539,6 +848,7 @@ impl Wallet {
     // intended for stopping async management task
     pub async fn stop(&self) -> Result<()> {
         self.utxo_processor().stop().await?;
+        self.market().stop().await?;
         self.stop_task().await?;
         Ok(())
     }
@@ -570,7 +880,7 @@ impl Wallet {
         if self.is_connected() {
             return Err(Error::NetworkTypeConnected);
         }
-        self.utxo_processor().set_network_id(network_id);
+        self.utxo_processor().set_network_id(network_id)?;
 
         if let Some(wrpc_client) = self.try_wrpc_client() {
             wrpc_client.set_network_id(network_id)?;
@@ -582,6 +892,45 @@ impl Wallet {
         self.utxo_processor().network_id()
     }
 
+    /// Default priority fee (in sompi) applied to outgoing transactions when
+    /// the caller does not supply one. Testnets carry no default priority fee,
+    /// on mainnet the fee configured via [`WalletSettings::PriorityFee`] is used.
+    pub fn default_priority_fee(&self, network_id: &NetworkId) -> u64 {
+        if network_id.network_type() != NetworkType::Mainnet {
+            return 0;
+        }
+
+        self.settings()
+            .get::<HashMap<String, u64>>(WalletSettings::PriorityFee)
+            .and_then(|fees| fees.get(&network_id.to_string()).copied())
+            .unwrap_or(0)
+    }
+
+    /// Configure the default priority fee for the given network (`0` removes it).
+    /// The setting takes effect immediately for all subsequent sends.
+    pub async fn set_default_priority_fee(&self, network_id: &NetworkId, priority_fee_sompi: u64) -> Result<()> {
+        if network_id.network_type() != NetworkType::Mainnet {
+            return Err(Error::PriorityFeeNotConfigurable(*network_id));
+        }
+
+        let mut fees = self.settings().get::<HashMap<String, u64>>(WalletSettings::PriorityFee).unwrap_or_default();
+        if priority_fee_sompi > 0 {
+            fees.insert(network_id.to_string(), priority_fee_sompi);
+        } else {
+            fees.remove(&network_id.to_string());
+        }
+        self.settings().set(WalletSettings::PriorityFee, fees).await
+    }
+
+    /// Resolve the priority fee of a send: a supplied fee overrides
+    /// the default priority fee of the currently selected network.
+    pub fn priority_fee_or_default(&self, priority_fee: Option<Fees>) -> Result<Fees> {
+        match priority_fee {
+            Some(priority_fee) => Ok(priority_fee),
+            None => Ok(Fees::SenderPays(self.default_priority_fee(&self.network_id()?))),
+        }
+    }
+
     pub fn address_prefix(&self) -> Result<kaspa_addresses::Prefix> {
         Ok(self.network_id()?.into())
     }
@@ -696,7 +1045,7 @@ impl Wallet {
             return Err(Error::AccountAlreadyExists(*account.id()));
         }
 
-        self.inner.store.clone().as_account_store()?.store_single(&account.to_storage()?, None).await?;
+        self.inner.store.clone().as_account_store()?.store_single(&account.to_storage()?, account.metadata()?.as_ref()).await?;
         self.inner.store.commit(wallet_secret).await?;
 
         Ok(account)
@@ -719,25 +1068,36 @@ impl Wallet {
             .await?
             .ok_or_else(|| Error::PrivateKeyNotFound(prv_key_data_id))?;
 
-        let AccountCreateArgsBip32 { account_name, account_index } = account_args;
+        let AccountCreateArgsBip32 { account_name, account_index, derivation_path, ecdsa } = account_args;
 
-        let account_index = if let Some(account_index) = account_index {
+        // standard paths are stored without a template so that
+        // the resulting account retains its original account id
+        let derivation_path = derivation_path.filter(|derivation_path| !derivation_path.is_standard());
+
+        let account_index = if let Some(derivation_path) = derivation_path.as_ref() {
+            derivation_path.account_index
+        } else if let Some(account_index) = account_index {
             account_index
         } else {
             account_store.clone().len(Some(prv_key_data_id)).await? as u64
         };
 
-        let xpub_key = prv_key_data.create_xpub(payment_secret, BIP32_ACCOUNT_KIND.into(), account_index).await?;
+        let xpub_key = if let Some(derivation_path) = derivation_path.as_ref() {
+            prv_key_data.create_xpub_with_path(payment_secret, derivation_path).await?
+        } else {
+            prv_key_data.create_xpub(payment_secret, BIP32_ACCOUNT_KIND.into(), account_index).await?
+        };
         let xpub_keys = Arc::new(vec![xpub_key]);
 
-        let account: Arc<dyn Account> =
-            Arc::new(bip32::Bip32::try_new(self, account_name, prv_key_data.id, account_index, xpub_keys, false).await?);
+        let account: Arc<dyn Account> = Arc::new(
+            bip32::Bip32::try_new(self, account_name, prv_key_data.id, account_index, xpub_keys, ecdsa, derivation_path).await?,
+        );
 
         if account_store.load_single(account.id()).await?.is_some() {
             return Err(Error::AccountAlreadyExists(*account.id()));
         }
 
-        self.inner.store.clone().as_account_store()?.store_single(&account.to_storage()?, None).await?;
+        self.inner.store.clone().as_account_store()?.store_single(&account.to_storage()?, account.metadata()?.as_ref()).await?;
         self.inner.store.commit(wallet_secret).await?;
 
         Ok(account)
@@ -765,7 +1125,7 @@ impl Wallet {
             return Err(Error::AccountAlreadyExists(*account.id()));
         }
 
-        self.inner.store.clone().as_account_store()?.store_single(&account.to_storage()?, None).await?;
+        self.inner.store.clone().as_account_store()?.store_single(&account.to_storage()?, account.metadata()?.as_ref()).await?;
         self.inner.store.commit(wallet_secret).await?;
 
         Ok(account)
@@ -776,6 +1136,7 @@ impl Wallet {
         wallet_secret: &Secret,
         args: WalletCreateArgs,
     ) -> Result<(WalletDescriptor, StorageDescriptor)> {
+        self.secret_strength_policy().validate(wallet_secret)?;
         self.close().await?;
 
         let wallet_descriptor = self.inner.store.create(wallet_secret, args.into()).await?;
@@ -822,6 +1183,7 @@ impl Wallet {
         mnemonic_phrase_word_count: WordCount,
         payment_secret: Option<Secret>,
     ) -> Result<(WalletDescriptor, StorageDescriptor, Mnemonic, Arc<dyn Account>)> {
+        self.secret_strength_policy().validate(wallet_secret)?;
         self.close().await?;
 
         let encryption_kind = wallet_args.encryption_kind;
@@ -836,11 +1198,11 @@ impl Wallet {
         let xpub_keys = Arc::new(vec![xpub_key]);
 
         let account: Arc<dyn Account> =
-            Arc::new(bip32::Bip32::try_new(self, account_name, prv_key_data.id, account_index, xpub_keys, false).await?);
+            Arc::new(bip32::Bip32::try_new(self, account_name, prv_key_data.id, account_index, xpub_keys, false, None).await?);
 
         let prv_key_data_store = self.inner.store.as_prv_key_data_store()?;
         prv_key_data_store.store(wallet_secret, prv_key_data).await?;
-        self.inner.store.clone().as_account_store()?.store_single(&account.to_storage()?, None).await?;
+        self.inner.store.clone().as_account_store()?.store_single(&account.to_storage()?, account.metadata()?.as_ref()).await?;
         self.inner.store.commit(wallet_secret).await?;
 
         self.select(Some(&account)).await?;
@@ -882,6 +1244,12 @@ impl Wallet {
 
         if let Err(_err) = transaction_store.load_single(record.binding(), &self.network_id()?, record.id()).await {
             let transaction_daa_score = record.block_daa_score();
+            if !self.utxo_processor().supports(NodeFeature::DaaScoreTimestampEstimate) {
+                // the node can not resolve the unixtime, the record is stored without it
+                transaction_store.store(&[&record]).await?;
+                self.notify(Events::Discovery { record }).await?;
+                return Ok(());
+            }
             match self.rpc_api().get_daa_score_timestamp_estimate(vec![transaction_daa_score]).await {
                 Ok(timestamps) => {
                     if let Some(timestamp) = timestamps.first() {
@@ -919,10 +1287,91 @@ impl Wallet {
     }
 
     async fn handle_event(self: &Arc<Self>, event: Box<Events>) -> Result<()> {
+        self.webhooks().handle_event(&event);
+
+        if let Events::Pending { record } | Events::Maturity { record } | Events::Discovery { record } = &*event {
+            if let Binding::Account(account_id) = record.binding() {
+                self.store_metadata_changes(account_id).await?;
+            }
+        }
+
+        if let Events::PaymentReceived { request_id, .. } = &*event {
+            let accounts = self.active_accounts().collect();
+            if let Some(account) = accounts.iter().find(|account| account.utxo_context().payment_request(request_id).is_some()) {
+                self.store_metadata_changes(account.id()).await?;
+            }
+        }
+
         match &*event {
+            Events::UtxoProcStart => {
+                self.resume_interrupted_scans();
+            }
+
             Events::Pending { record } | Events::Maturity { record } | Events::Reorg { record } => {
                 if !record.is_change() {
-                    self.store().as_transaction_record_store()?.store(&[record]).await?;
+                    let store = self.store().as_transaction_record_store()?;
+                    let existing = store.load_single(record.binding(), record.network_id(), record.id()).await.ok();
+                    if let Some(existing) = existing.as_ref() {
+                        self.handle_balance_history_replacement(existing, record).await?;
+                    }
+                    // retain the user-supplied note and metadata of an existing record
+                    match existing {
+                        Some(existing) if existing.note.is_some() || existing.metadata.is_some() => {
+                            let mut record = record.clone();
+                            record.note.clone_from(&existing.note);
+                            record.metadata.clone_from(&existing.metadata);
+                            store.store(&[&record]).await?;
+                        }
+                        _ => {
+                            store.store(&[record]).await?;
+                        }
+                    }
+                }
+            }
+
+            Events::ReorgedOut { record } => {
+                // flag the stored record, retaining its data, note and metadata
+                let store = self.store().as_transaction_record_store()?;
+                let record = match store.load_single(record.binding(), record.network_id(), record.id()).await {
+                    Ok(existing) => {
+                        let mut existing = (*existing).clone();
+                        existing.set_reorged(true);
+                        existing
+                    }
+                    Err(_) => record.clone(),
+                };
+                store.store(&[&record]).await?;
+
+                // the transaction never happened
+                if let Some(mut cache) = store.load_balance_history(record.binding(), record.network_id()).await? {
+                    if cache.contains(record.id()) {
+                        let unixtime_msec = record.unixtime_msec().unwrap_or_else(unixtime_as_millis_u64);
+                        cache.insert(*record.id(), BalanceHistoryEntry { unixtime_msec, delta: 0 });
+                        store.store_balance_history(record.binding(), record.network_id(), &cache).await?;
+                    }
+                }
+            }
+
+            Events::Reaccepted { record } => {
+                // clear the reorged flag of the stored record
+                let store = self.store().as_transaction_record_store()?;
+                let record = match store.load_single(record.binding(), record.network_id(), record.id()).await {
+                    Ok(existing) => {
+                        let mut existing = (*existing).clone();
+                        existing.set_reorged(false);
+                        existing
+                    }
+                    Err(_) => record.clone(),
+                };
+                store.store(&[&record]).await?;
+
+                // restore the balance change voided by the reorg
+                if let Some(mut cache) = store.load_balance_history(record.binding(), record.network_id()).await? {
+                    if cache.contains(record.id()) {
+                        let unixtime_msec = record.unixtime_msec().unwrap_or_else(unixtime_as_millis_u64);
+                        cache.insert(*record.id(), BalanceHistoryEntry { unixtime_msec, delta: record.balance_delta() });
+                        store.store_balance_history(record.binding(), record.network_id(), &cache).await?;
+                    }
                 }
             }
 
@@ -932,6 +1381,107 @@ impl Wallet {
         Ok(())
     }
 
+    /// Persist the address usage and the payment requests of an active account
+    /// in the account metadata if they have changed (see [`AddressUsage`](crate::utxo::AddressUsage)
+    /// and [`PaymentRequest`]).
+    async fn store_metadata_changes(&self, account_id: &AccountId) -> Result<()> {
+        let Some(account) = self.active_accounts().get(account_id) else {
+            return Ok(());
+        };
+        let usage_changed = account.utxo_context().take_address_usage_changed();
+        let payment_requests_changed = account.utxo_context().take_payment_requests_changed();
+        if usage_changed || payment_requests_changed {
+            if let Ok(Some(metadata)) = account.metadata() {
+                self.store().as_account_store()?.update_metadata(vec![metadata]).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Records of UTXOs removed by a reorg or spent externally replace the
+    /// record of the transaction that created them (the records share the
+    /// transaction id). The balance change of the replacement is recorded in
+    /// the balance history cache, as the replaced record is no longer available.
+    async fn handle_balance_history_replacement(&self, existing: &TransactionRecord, record: &TransactionRecord) -> Result<()> {
+        if existing.kind() == record.kind() || !matches!(record.kind(), TransactionKind::Reorg | TransactionKind::External) {
+            return Ok(());
+        }
+
+        let store = self.store().as_transaction_record_store()?;
+        let Some(mut cache) = store.load_balance_history(record.binding(), record.network_id()).await? else {
+            return Ok(());
+        };
+
+        if cache.contains(record.id()) {
+            let unixtime_msec = record.unixtime_msec().unwrap_or_else(unixtime_as_millis_u64);
+            if record.kind() == TransactionKind::Reorg {
+                // the transaction never happened
+                cache.insert(*record.id(), BalanceHistoryEntry { unixtime_msec, delta: 0 });
+            } else {
+                cache.append(*record.id(), BalanceHistoryEntry { unixtime_msec, delta: record.balance_delta() });
+            }
+            store.store_balance_history(record.binding(), record.network_id(), &cache).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Updates the balance history cache of the binding with the transaction
+    /// records stored since the previous update, resolving missing record
+    /// timestamps via the node. Returns the cache and the number of records
+    /// that were not included because their timestamp could not be resolved.
+    pub async fn update_balance_history(&self, binding: &Binding, network_id: &NetworkId) -> Result<(BalanceHistoryCache, u64)> {
+        let store = self.store().as_transaction_record_store()?;
+        let mut cache = store.load_balance_history(binding, network_id).await?.unwrap_or_default();
+
+        let ids = match store.transaction_id_iter(binding, network_id).await {
+            Ok(ids) => ids.map_ok(|id| *id).try_collect::<HashSet<_>>().await?,
+            Err(Error::NoRecordsFound) => HashSet::default(),
+            Err(err) => return Err(err),
+        };
+
+        let mut modified = cache.retain(&ids);
+
+        let ids = ids.into_iter().filter(|id| !cache.contains(id)).collect::<Vec<_>>();
+        let records = store.load_multiple(binding, network_id, &ids).await?;
+
+        // records stored without a timestamp are backfilled
+        let (backfilled, _) = self.backfill_record_timestamps(network_id, &records).await?;
+        let timestamps =
+            backfilled.iter().filter_map(|record| Some((*record.id(), record.unixtime_msec()?))).collect::<HashMap<_, _>>();
+
+        let mut unresolved = 0;
+        for record in records {
+            let kind = record.kind();
+            if kind == TransactionKind::Stasis {
+                // stasis records are replaced once the coinbase UTXOs become pending
+                continue;
+            }
+
+            let Some(unixtime_msec) = record.unixtime_msec().or_else(|| timestamps.get(record.id()).copied()) else {
+                unresolved += 1;
+                continue;
+            };
+
+            // reorg and external records observed without the record they replaced
+            // offset a receipt that is not accounted for in the history, reorged
+            // outgoing transactions did not change the balance
+            let delta = if matches!(kind, TransactionKind::Reorg | TransactionKind::External) || record.is_reorged() {
+                0
+            } else {
+                record.balance_delta()
+            };
+            cache.insert(*record.id(), BalanceHistoryEntry { unixtime_msec, delta });
+            modified = true;
+        }
+
+        if modified {
+            store.store_balance_history(binding, network_id, &cache).await?;
+        }
+
+        Ok((cache, unresolved))
+    }
+
     async fn start_task(self: &Arc<Self>) -> Result<()> {
         let this = self.clone();
         let task_ctl_receiver = self.inner.task_ctl.request.receiver.clone();
@@ -949,20 +1499,34 @@ impl Wallet {
         // });
 
         spawn(async move {
+            let interval = interval(Duration::from_secs(1));
+            pin_mut!(interval);
+
             loop {
                 select! {
                     _ = task_ctl_receiver.recv().fuse() => {
                         break;
                     },
 
+                    _ = interval.next().fuse() => {
+                        if this.is_open() && this.auto_lock().is_expired() {
+                            log!(Wallet, Info, "Wallet inactivity timeout elapsed, locking wallet...");
+                            this.lock().await.unwrap_or_else(|e| log!(Wallet, Error, "Wallet::lock() error: {}", e));
+                        }
+
+                        if this.is_open() && !this.fee_bump().is_empty() {
+                            this.process_fee_bumps().await.unwrap_or_else(|e| log_error!("Wallet::process_fee_bumps() error: {}", e));
+                        }
+                    },
+
                     msg = events.receiver.recv().fuse() => {
                         match msg {
                             Ok(event) => {
-                                this.handle_event(event).await.unwrap_or_else(|e| log_error!("Wallet::handle_event() error: {}", e));
+                                this.handle_event(event).await.unwrap_or_else(|e| log!(Wallet, Error, "Wallet::handle_event() error: {}", e));
                             },
                             Err(err) => {
-                                log_error!("Wallet: error while receiving multiplexer message: {err}");
-                                log_error!("Suspending Wallet processing...");
+                                log!(Wallet, Error, "Wallet: error while receiving multiplexer message: {err}");
+                                log!(Wallet, Error, "Suspending Wallet processing...");
 
                                 break;
                             }
@@ -972,11 +1536,11 @@ impl Wallet {
                     msg = wallet_bus_receiver.recv().fuse() => {
                         match msg {
                             Ok(message) => {
-                                this.handle_wallet_bus(message).await.unwrap_or_else(|e| log_error!("Wallet::handle_wallet_bus() error: {}", e));
+                                this.handle_wallet_bus(message).await.unwrap_or_else(|e| log!(Wallet, Error, "Wallet::handle_wallet_bus() error: {}", e));
                             },
                             Err(err) => {
-                                log_error!("Wallet: error while receiving wallet bus message: {err}");
-                                log_error!("Suspending Wallet processing...");
+                                log!(Wallet, Error, "Wallet: error while receiving wallet bus message: {err}");
+                                log!(Wallet, Error, "Suspending Wallet processing...");
 
                                 break;
                             }
@@ -990,6 +1554,81 @@ impl Wallet {
         Ok(())
     }
 
+    /// Regenerate tracked sends that have not been accepted within
+    /// their fee-bump policy deadline (see [`FeeBumpPolicy`]).
+    async fn process_fee_bumps(self: &Arc<Self>) -> Result<()> {
+        for entry in self.fee_bump().take_expired() {
+            let id = entry.transaction.id();
+
+            // accepted (or no longer tracked by the utxo processor)
+            if !self.utxo_processor().outgoing().get(&id).is_some_and(|outgoing| !outgoing.is_accepted()) {
+                continue;
+            }
+
+            // the transaction can only be replaced once it leaves the mempool
+            if !self.is_connected() || self.rpc_api().get_mempool_entry(id.into(), false, false).await.is_ok() {
+                self.fee_bump().register(entry.extend());
+                continue;
+            }
+
+            if let Err(err) = self.try_fee_bump(&entry).await {
+                log_warn!("unable to bump fees of transaction {id}: {err}");
+                self.notify(Events::FeeBumpAbandoned { account_id: entry.account_id, transaction_id: id }).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn try_fee_bump(self: &Arc<Self>, entry: &FeeBumpEntry) -> Result<()> {
+        let account = self.get_account_by_id(&entry.account_id).await?.ok_or(Error::AccountNotFound(entry.account_id))?;
+        let fees = entry.bumped_fees().ok_or_else(|| Error::custom("maximum fee-bump fee reached"))?;
+        let signer = entry.transaction.generator().signer().clone().ok_or_else(|| Error::custom("missing transaction signer"))?;
+
+        // return the utxo entries of the expired transaction to the mature pool
+        account.utxo_context().cancel_outgoing_transaction(&entry.transaction).await?;
+
+        let utxo_entries = entry.transaction.utxo_entries().values().cloned().collect::<Vec<_>>();
+        let settings = GeneratorSettings::try_new_with_account(
+            account.clone(),
+            entry.destination.clone(),
+            fees.clone(),
+            entry.payload.clone(),
+        )?
+        .with_utxo_entries(utxo_entries);
+        let generator = Generator::try_new(settings, Some(signer), None)?;
+
+        let mut stream = generator.stream();
+        let mut last = None;
+        while let Some(transaction) = stream.try_next().await? {
+            transaction.try_sign_async().await?;
+            transaction.try_submit(&self.rpc_api()).await?;
+            last = Some(transaction);
+        }
+
+        let transaction = last.ok_or_else(|| Error::custom("no transaction generated"))?;
+        let transaction_id = transaction.id();
+        let fee_bump = FeeBumpEntry::new(
+            entry.account_id,
+            entry.destination.clone(),
+            fees,
+            entry.payload.clone(),
+            entry.policy.clone(),
+            transaction.clone(),
+        );
+        self.fee_bump().register(fee_bump);
+
+        self.notify(Events::FeeBump {
+            account_id: entry.account_id,
+            replaced_transaction_id: entry.transaction.id(),
+            transaction_id,
+            fees: transaction.fees(),
+        })
+        .await?;
+
+        Ok(())
+    }
+
     async fn stop_task(&self) -> Result<()> {
         self.inner.task_ctl.signal(()).await.expect("Wallet::stop_task() `signal` error");
         Ok(())
@@ -1040,6 +1679,8 @@ impl Wallet {
         let iter = self.inner.store.as_account_store().unwrap().iter(filter).await.unwrap();
         let wallet = self.clone();
 
+        // archived accounts are not enumerated (and as such not activated)
+        let iter = iter.try_filter(|(stored_account, _)| futures::future::ready(!stored_account.settings.archived));
         let stream = iter.then(move |stored| {
             let wallet = wallet.clone();
 
@@ -1316,6 +1957,9 @@ impl Wallet {
         Ok(acc)
     }
 
+    /// Import the legacy account from the KDX keydata file or
+    /// the kaspanet web wallet data found in the local storage.
+    #[cfg(feature = "fs")]
     pub async fn import_legacy_keydata(
         self: &Arc<Wallet>,
         import_secret: &Secret,
@@ -1325,10 +1969,39 @@ impl Wallet {
     ) -> Result<Arc<dyn Account>> {
         use crate::compat::gen0::load_v0_keydata;
 
-        let notifier = notifier.as_ref();
         let keydata = load_v0_keydata(import_secret).await?;
+        let mnemonic = Mnemonic::new(keydata.mnemonic.trim(), Language::English)?;
+        self.import_legacy_with_mnemonic(wallet_secret, payment_secret, mnemonic, notifier).await
+    }
+
+    /// Import the legacy account from the supplied contents of a KDX keydata
+    /// file or the kaspanet web wallet data (see [`parse_v0_keydata`](crate::compat::gen0::parse_v0_keydata)).
+    pub async fn import_legacy_keydata_with_data(
+        self: &Arc<Wallet>,
+        keydata: &str,
+        import_secret: &Secret,
+        wallet_secret: &Secret,
+        payment_secret: Option<&Secret>,
+        notifier: Option<ScanNotifier>,
+    ) -> Result<Arc<dyn Account>> {
+        use crate::compat::gen0::parse_v0_keydata;
 
+        let keydata = parse_v0_keydata(keydata, import_secret)?;
         let mnemonic = Mnemonic::new(keydata.mnemonic.trim(), Language::English)?;
+        self.import_legacy_with_mnemonic(wallet_secret, payment_secret, mnemonic, notifier).await
+    }
+
+    /// Import the legacy (KDX, kaspanet web wallet) account using its 12-word
+    /// mnemonic, deriving the legacy (gen0) addresses and scanning them for
+    /// UTXOs if the wallet is connected.
+    pub async fn import_legacy_with_mnemonic(
+        self: &Arc<Wallet>,
+        wallet_secret: &Secret,
+        payment_secret: Option<&Secret>,
+        mnemonic: Mnemonic,
+        notifier: Option<ScanNotifier>,
+    ) -> Result<Arc<dyn Account>> {
+        let notifier = notifier.as_ref();
         let prv_key_data = PrvKeyData::try_new_from_mnemonic(mnemonic, payment_secret, self.store().encryption_kind()?)?;
         let prv_key_data_store = self.inner.store.as_prv_key_data_store()?;
         if prv_key_data_store.load_key_data(wallet_secret, &prv_key_data.id).await?.is_some() {
@@ -1344,7 +2017,7 @@ impl Wallet {
         // store private key and account
         self.inner.store.batch().await?;
         prv_key_data_store.store(wallet_secret, prv_key_data).await?;
-        self.inner.store.clone().as_account_store()?.store_single(&account.to_storage()?, None).await?;
+        self.inner.store.clone().as_account_store()?.store_single(&account.to_storage()?, account.metadata()?.as_ref()).await?;
         self.inner.store.flush(wallet_secret).await?;
 
         let legacy_account = account.clone().as_legacy_account()?;
@@ -1370,6 +2043,61 @@ impl Wallet {
         Ok(account)
     }
 
+    /// Transfer the mature balance of the (imported) legacy account into
+    /// the first BIP32 account of the same private key data, creating the
+    /// BIP32 account if it does not exist. Transaction fees are deducted
+    /// from the transferred amount. Returns the BIP32 account and the ids
+    /// of the submitted transactions.
+    pub async fn sweep_legacy_account(
+        self: &Arc<Wallet>,
+        account: &Arc<dyn Account>,
+        wallet_secret: &Secret,
+        payment_secret: Option<&Secret>,
+        abortable: &Abortable,
+    ) -> Result<(Arc<dyn Account>, Vec<TransactionId>)> {
+        if account.account_kind().as_ref() != LEGACY_ACCOUNT_KIND {
+            return Err(Error::InvalidAccountKind);
+        }
+        if !self.is_connected() {
+            return Err(Error::NotConnected);
+        }
+
+        let prv_key_data_id = *account.prv_key_data_id()?;
+        let account_args = AccountCreateArgsBip32::new(None, Some(0));
+        let destination = match self.create_account_bip32(wallet_secret, prv_key_data_id, payment_secret, account_args).await {
+            Ok(destination) => {
+                self.notify(Events::AccountCreate { account_descriptor: destination.descriptor()? }).await?;
+                destination
+            }
+            Err(Error::AccountAlreadyExists(account_id)) => {
+                self.get_account_by_id(&account_id).await?.ok_or(Error::AccountNotFound(account_id))?
+            }
+            Err(err) => return Err(err),
+        };
+        destination.clone().start().await?;
+
+        let balance = account.balance().map(|balance| balance.mature).unwrap_or_default();
+        let transaction_ids = if balance > 0 {
+            let (_, ids) = account
+                .clone()
+                .transfer(
+                    *destination.id(),
+                    balance,
+                    Fees::ReceiverPays(0),
+                    wallet_secret.clone(),
+                    payment_secret.cloned(),
+                    abortable,
+                    None,
+                )
+                .await?;
+            ids
+        } else {
+            vec![]
+        };
+
+        Ok((destination, transaction_ids))
+    }
+
     pub async fn import_gen1_keydata(self: &Arc<Wallet>, _secret: Secret) -> Result<()> {
         // use crate::derivation::gen1::import::load_v1_keydata;
 
@@ -1398,7 +2126,7 @@ impl Wallet {
                 let xpub_keys = Arc::new(vec![xpub_key]);
                 let ecdsa = false;
                 // ---
-                Arc::new(bip32::Bip32::try_new(self, None, prv_key_data.id, account_index, xpub_keys, ecdsa).await?)
+                Arc::new(bip32::Bip32::try_new(self, None, prv_key_data.id, account_index, xpub_keys, ecdsa, None).await?)
             }
             LEGACY_ACCOUNT_KIND => Arc::new(legacy::Legacy::try_new(self, None, prv_key_data.id).await?),
             _ => {
@@ -1408,7 +2136,7 @@ impl Wallet {
 
         let account_store = self.inner.store.as_account_store()?;
         self.inner.store.batch().await?;
-        account_store.store_single(&account.to_storage()?, None).await?;
+        account_store.store_single(&account.to_storage()?, account.metadata()?.as_ref()).await?;
         self.inner.store.flush(wallet_secret).await?;
 
         if let Ok(legacy_account) = account.clone().as_legacy_account() {
@@ -1449,33 +2177,178 @@ impl Wallet {
         address_scan_extent: u32,
         account_scan_extent: u32,
     ) -> Result<u32> {
+        let prv_key_data = Self::bip39_prv_key_data(&bip39_mnemonic, bip39_passphrase.as_ref())?;
+        let discovered = self
+            .discover_bip44_account_indexes(&prv_key_data, bip39_passphrase.as_ref(), address_scan_extent, account_scan_extent)
+            .await?;
+
+        Ok(discovered.last().map(|account| account.account_index as u32).unwrap_or_default())
+    }
+
+    pub(crate) fn bip39_prv_key_data(bip39_mnemonic: &Secret, bip39_passphrase: Option<&Secret>) -> Result<PrvKeyData> {
         let bip39_mnemonic = std::str::from_utf8(bip39_mnemonic.as_ref()).map_err(|_| Error::InvalidMnemonicPhrase)?;
         let mnemonic = Mnemonic::new(bip39_mnemonic, Language::English)?;
 
         // TODO @aspect - this is not efficient, we need to scan without encrypting prv_key_data
-        let prv_key_data =
-            storage::PrvKeyData::try_new_from_mnemonic(mnemonic, bip39_passphrase.as_ref(), EncryptionKind::XChaCha20Poly1305)?;
+        storage::PrvKeyData::try_new_from_mnemonic(mnemonic, bip39_passphrase, EncryptionKind::XChaCha20Poly1305)
+    }
 
-        let mut last_account_index = 0;
-        let mut account_index = 0;
+    /// Scans BIP-44 account indexes of the supplied private key data in
+    /// ascending order, stopping after `account_scan_extent` consecutive
+    /// accounts without UTXOs (gap limit). Each account is checked for UTXOs
+    /// within the first `address_scan_extent` receive and change addresses.
+    pub(crate) async fn discover_bip44_account_indexes(
+        self: &Arc<Self>,
+        prv_key_data: &PrvKeyData,
+        payment_secret: Option<&Secret>,
+        address_scan_extent: u32,
+        account_scan_extent: u32,
+    ) -> Result<Vec<DiscoveredAccount>> {
+        let mut discovered = vec![];
+        let mut empty_accounts = 0;
+        let mut account_index: u64 = 0;
 
-        while account_index < last_account_index + account_scan_extent {
-            let xpub_key =
-                prv_key_data.create_xpub(bip39_passphrase.as_ref(), BIP32_ACCOUNT_KIND.into(), account_index as u64).await?;
+        while empty_accounts < account_scan_extent {
+            let xpub_key = prv_key_data.create_xpub(payment_secret, BIP32_ACCOUNT_KIND.into(), account_index).await?;
             let xpub_keys = Arc::new(vec![xpub_key]);
             let ecdsa = false;
             // ---
 
-            let addresses = bip32::Bip32::try_new(self, None, prv_key_data.id, account_index as u64, xpub_keys, ecdsa)
-                .await?
-                .get_address_range_for_scan(0..address_scan_extent)?;
-            if self.rpc_api().get_utxos_by_addresses(addresses).await?.is_not_empty() {
-                last_account_index = account_index;
+            let account = bip32::Bip32::try_new(self, None, prv_key_data.id, account_index, xpub_keys, ecdsa, None).await?;
+            let addresses = account.get_address_range_for_scan(0..address_scan_extent)?;
+            let entries = self.rpc_api().get_utxos_by_addresses(addresses).await?;
+            if entries.is_empty() {
+                empty_accounts += 1;
+            } else {
+                empty_accounts = 0;
+                discovered.push(DiscoveredAccount {
+                    account_id: *account.id(),
+                    account_index,
+                    balance: entries.iter().map(|entry| entry.utxo_entry.amount).sum(),
+                    created: false,
+                });
             }
             account_index += 1;
         }
 
-        Ok(last_account_index)
+        Ok(discovered)
+    }
+
+    /// Performs BIP-44 account discovery (see [`Wallet::scan_bip44_accounts`])
+    /// for private key data stored in the wallet, optionally creating wallet
+    /// accounts for the discovered account indexes. Accounts that already
+    /// exist in the wallet are reported but not re-created. The `gap_limit`
+    /// (if supplied) is assigned to the created accounts.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn discover_bip44_accounts(
+        self: &Arc<Self>,
+        wallet_secret: &Secret,
+        prv_key_data_id: PrvKeyDataId,
+        payment_secret: Option<&Secret>,
+        address_scan_extent: u32,
+        account_scan_extent: u32,
+        create_accounts: bool,
+        gap_limit: Option<u32>,
+    ) -> Result<Vec<DiscoveredAccount>> {
+        let prv_key_data = self
+            .inner
+            .store
+            .as_prv_key_data_store()?
+            .load_key_data(wallet_secret, &prv_key_data_id)
+            .await?
+            .ok_or_else(|| Error::PrivateKeyNotFound(prv_key_data_id))?;
+
+        let mut discovered =
+            self.discover_bip44_account_indexes(&prv_key_data, payment_secret, address_scan_extent, account_scan_extent).await?;
+
+        if create_accounts {
+            let account_store = self.inner.store.clone().as_account_store()?;
+            for account in discovered.iter_mut() {
+                if account_store.load_single(&account.account_id).await?.is_some() {
+                    continue;
+                }
+
+                let account_args = AccountCreateArgsBip32::new(None, Some(account.account_index));
+                let prv_key_data_args = PrvKeyDataArgs::new(prv_key_data_id, payment_secret.cloned());
+                let created =
+                    self.create_account(wallet_secret, AccountCreateArgs::Bip32 { prv_key_data_args, account_args }, true).await?;
+                if gap_limit.is_some() {
+                    created.set_gap_limit(gap_limit).await?;
+                }
+                account.created = true;
+            }
+        }
+
+        Ok(discovered)
+    }
+
+    /// Upgrades the wallet file `filename` (or the default wallet file) to the
+    /// current storage version. Wallet files are also migrated when opened; the
+    /// original file is backed up before the migrated wallet data is stored.
+    pub async fn migrate_storage(self: &Arc<Self>, wallet_secret: &Secret, filename: Option<&str>) -> Result<WalletMigrationReport> {
+        self.store().migrate(wallet_secret, filename).await
+    }
+
+    /// Checks the integrity of the wallet storage: private key data, accounts,
+    /// account metadata and (if the wallet network is set) transaction records
+    /// of the wallet accounts. If `repair` is set, orphaned entries and
+    /// transaction records that can not be loaded are removed from the storage
+    /// (along with the transaction records of the removed orphan accounts).
+    pub async fn check_storage(self: &Arc<Self>, wallet_secret: &Secret, repair: bool) -> Result<WalletCheckReport> {
+        let store = self.store();
+        let account_ids =
+            store.as_account_store()?.iter(None).await?.map_ok(|(account, _)| account.id).try_collect::<Vec<_>>().await?;
+        let mut report = store.check(wallet_secret, repair).await?;
+
+        if let Ok(network_id) = self.network_id() {
+            let transaction_store = store.as_transaction_record_store()?;
+            let mut retained = HashSet::new();
+            let mut accounts = store.as_account_store()?.iter(None).await?;
+            while let Some((account, _)) = accounts.try_next().await? {
+                retained.insert(account.id);
+                let binding = Binding::Account(account.id);
+                let ids = match transaction_store.transaction_id_iter(&binding, &network_id).await {
+                    Ok(ids) => ids.try_collect::<Vec<_>>().await?,
+                    Err(Error::NoRecordsFound) => continue,
+                    Err(err) => return Err(err),
+                };
+
+                let mut invalid = vec![];
+                for id in ids.iter() {
+                    report.transactions += 1;
+                    let description = match transaction_store.load_single(&binding, &network_id, id).await {
+                        Ok(record) if record.binding() == &binding => continue,
+                        Ok(_) => "transaction record belongs to a different account".to_string(),
+                        Err(err) => err.to_string(),
+                    };
+                    let mut issue = WalletCheckIssue::new(WalletCheckIssueKind::InvalidTransactionRecord, id.to_hex(), description);
+                    issue.repaired = repair;
+                    report.issues.push(issue);
+                    invalid.push(id.as_ref());
+                }
+
+                if repair && !invalid.is_empty() {
+                    transaction_store.remove(&binding, &network_id, &invalid).await?;
+                }
+            }
+
+            // transaction records of the accounts removed by the repair
+            for id in account_ids.iter().filter(|id| !retained.contains(id)) {
+                let binding = Binding::Account(*id);
+                let ids = match transaction_store.transaction_id_iter(&binding, &network_id).await {
+                    Ok(ids) => ids.try_collect::<Vec<_>>().await?,
+                    Err(Error::NoRecordsFound) => continue,
+                    Err(err) => return Err(err),
+                };
+                transaction_store.remove(&binding, &network_id, &ids.iter().map(|id| id.as_ref()).collect::<Vec<_>>()).await?;
+            }
+        }
+
+        if report.is_repaired() {
+            store.commit(wallet_secret).await?;
+        }
+
+        Ok(report)
     }
 
     pub async fn import_multisig_with_mnemonic(
@@ -1545,10 +2418,16 @@ impl Wallet {
         Ok(account)
     }
 
-    async fn rename(&self, title: Option<String>, filename: Option<String>, wallet_secret: &Secret) -> Result<()> {
+    async fn rename(
+        &self,
+        title: Option<String>,
+        notes: Option<String>,
+        filename: Option<String>,
+        wallet_secret: &Secret,
+    ) -> Result<WalletDescriptor> {
         let store = self.store();
-        store.rename(wallet_secret, title.as_deref(), filename.as_deref()).await?;
-        Ok(())
+        store.rename(wallet_secret, title.as_deref(), notes.as_deref(), filename.as_deref()).await?;
+        store.descriptor().ok_or(Error::WalletNotOpen)
     }
 
     async fn ensure_default_account_impl(
@@ -1613,9 +2492,986 @@ impl Wallet {
 mod test {
     // use hex_literal::hex;
 
-    // use super::*;
+    use super::*;
+    use crate::api::message::{AccountsSignMessageRequest, AccountsSignMessageResponse, AccountsVerifyMessageRequest};
+    use crate::api::WalletApi;
+    use crate::tests::MOCK_DAA_SCORE_UNIXTIME_MSEC;
+    use crate::utils::kaspa_to_sompi;
+    use crate::utxo::{UtxoContext, UtxoContextBinding, UtxoContextId};
+    use kaspa_consensus_client::UtxoEntryReference;
+    use kaspa_notify::connection::ChannelType;
+    use kaspa_rpc_core::{
+        api::ops::RpcApiOps, api::rpc::RpcApi, notify::connection::ChannelConnection, Notification, UtxosChangedNotification,
+    };
+    use kaspa_wrpc_client::transport::{RpcTransport, RpcTransportHost, RpcTransportRequest, RpcTransportResponse, RpcTransportSink};
+    use kaspa_wrpc_client::KaspaRpcClient;
+    use workflow_core::channel::MultiplexerChannel;
     // use kaspa_addresses::Address;
 
+    struct LoopbackTransport {
+        host: Arc<RpcTransportHost>,
+    }
+
+    #[async_trait]
+    impl RpcTransport for LoopbackTransport {
+        async fn connect(&self, sink: RpcTransportSink) -> kaspa_wrpc_client::result::Result<()> {
+            let receiver = self.host.notification_receiver();
+            let sink_ = sink.clone();
+            tokio::spawn(async move {
+                while let Ok(notification) = receiver.recv().await {
+                    sink_.notify_serialized(&notification.try_to_vec().unwrap()).unwrap();
+                }
+            });
+            sink.connected()
+        }
+
+        async fn disconnect(&self) -> kaspa_wrpc_client::result::Result<()> {
+            Ok(())
+        }
+
+        async fn call(&self, op: RpcApiOps, payload: Vec<u8>) -> kaspa_wrpc_client::result::Result<Vec<u8>> {
+            let message = RpcTransportRequest { op, payload }.try_to_vec()?;
+            RpcTransportResponse::try_from_slice(&self.host.handle(&message).await?)?.into()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wallet_rpc_transport() -> Result<()> {
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+        let rpc_mock = Arc::new(MockRpcClient::with_network_id(network_id));
+        rpc_mock.start();
+
+        let host = Arc::new(RpcTransportHost::new(rpc_mock.clone()));
+        let transport = Arc::new(LoopbackTransport { host: host.clone() });
+        let client = Arc::new(KaspaRpcClient::new_with_transport(transport, Some(network_id), None)?);
+        assert!(client.get_server_info().await.is_err());
+
+        client.connect(None).await?;
+        assert!(client.is_connected());
+        assert_eq!(client.get_server_info().await?.network_id, network_id);
+        // errors produced by the host are relayed to the client
+        assert!(client.ping().await.is_err());
+
+        let channel = Channel::unbounded();
+        let listener_id =
+            client.register_new_listener(ChannelConnection::new("test", channel.sender.clone(), ChannelType::Persistent));
+        client.start_notify(listener_id, Scope::VirtualDaaScoreChanged(VirtualDaaScoreChangedScope {})).await?;
+        // the subscription is relayed to the host asynchronously
+        let notification = loop {
+            rpc_mock.advance_virtual_daa_score(1).unwrap();
+            if let Ok(notification) = tokio::time::timeout(Duration::from_millis(100), channel.receiver.recv()).await {
+                break notification.unwrap();
+            }
+        };
+        match notification {
+            Notification::VirtualDaaScoreChanged(notification) => {
+                assert_eq!(notification.virtual_daa_score, rpc_mock.virtual_daa_score())
+            }
+            notification => panic!("unexpected notification {notification:?}"),
+        }
+
+        client.disconnect().await?;
+        assert!(!client.is_connected());
+        host.close().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_rpc_client_handles() -> Result<()> {
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+        let rpc_mock = Arc::new(MockRpcClient::with_network_id(network_id));
+        rpc_mock.start();
+
+        let host = Arc::new(RpcTransportHost::new(rpc_mock.clone()));
+        let transport = Arc::new(LoopbackTransport { host: host.clone() });
+        let client = KaspaRpcClient::new_with_transport(transport, Some(network_id), None)?;
+        let (client_a, client_b) = (Arc::new(client.handle()), Arc::new(client.handle()));
+
+        let processor = |client: &Arc<KaspaRpcClient>| {
+            let rpc = Rpc::new(client.clone(), client.rpc_ctl().clone());
+            UtxoProcessor::new(Some(rpc), Some(network_id), None, None)
+        };
+        let (processor_a, processor_b) = (processor(&client_a), processor(&client_b));
+        processor_a.start().await?;
+        processor_b.start().await?;
+
+        client_a.connect(None).await?;
+        client_b.connect(None).await?;
+        let timeout = Duration::from_secs(10);
+        tokio::time::timeout(timeout, async {
+            while !processor_a.is_connected() || !processor_b.is_connected() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("processors connected");
+
+        // each handle allocates listener ids from its own id space
+        assert_eq!(processor_a.listener_id()?, processor_b.listener_id()?);
+
+        // listeners of a handle are not reachable through another handle
+        let channel = Channel::unbounded();
+        let scope = Scope::VirtualDaaScoreChanged(VirtualDaaScoreChangedScope {});
+        let listener_id =
+            client_a.register_new_listener(ChannelConnection::new("test", channel.sender.clone(), ChannelType::Persistent));
+        assert!(client_b.start_notify(listener_id, scope.clone()).await.is_err());
+        assert!(client_b.stop_notify(listener_id, scope.clone()).await.is_err());
+        client_b.unregister_listener(listener_id).await?;
+        client_a.start_notify(listener_id, scope.clone()).await?;
+        client_a.unregister_listener(listener_id).await?;
+
+        let synced = |processor: &UtxoProcessor| {
+            let processor = processor.clone();
+            let rpc_mock = rpc_mock.clone();
+            async move {
+                tokio::time::timeout(timeout, async {
+                    loop {
+                        rpc_mock.advance_virtual_daa_score(1).unwrap();
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                        if processor.current_daa_score() == Some(rpc_mock.virtual_daa_score()) {
+                            break;
+                        }
+                    }
+                })
+                .await
+                .is_ok()
+            }
+        };
+        assert!(synced(&processor_a).await);
+        assert!(synced(&processor_b).await);
+
+        // stopping one processor and disconnecting its handle leaves the
+        // connection and the listeners of the other handle in place
+        processor_a.stop().await?;
+        client_a.disconnect().await?;
+        assert!(client.is_connected());
+        assert!(processor_b.is_connected());
+        assert!(synced(&processor_b).await);
+
+        // the last handle closes the connection
+        processor_b.stop().await?;
+        client_b.disconnect().await?;
+        assert!(!client.is_connected());
+        host.close().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_transaction_note_retention() -> Result<()> {
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+        let rpc_mock = Arc::new(MockRpcClient::with_network_id(network_id));
+        let wallet = Arc::new(Wallet::try_with_rpc(Some(rpc_mock.into()), Wallet::resident_store()?, Some(network_id))?);
+        let wallet_args = WalletCreateArgs::new(None, None, EncryptionKind::XChaCha20Poly1305, None, true);
+        wallet.clone().wallet_create(Secret::from("secret"), wallet_args).await?;
+
+        let utxo_context = UtxoContext::new(wallet.utxo_processor(), UtxoContextBinding::default());
+        let utxos = [UtxoEntryReference::simulated(kaspa_to_sompi(1.0))];
+        let record = TransactionRecord::new_incoming(&utxo_context, utxos[0].transaction_id(), &utxos);
+        wallet.handle_event(Box::new(Events::Pending { record: record.clone() })).await?;
+
+        let store = wallet.store().as_transaction_record_store()?;
+        let (binding, network_id, id) = (record.binding(), record.network_id(), *record.id());
+        store.store_transaction_note(binding, network_id, id, Some("invoice #1024".to_string())).await?;
+        store.store_transaction_metadata(binding, network_id, id, Some(r#"{"invoice":1024}"#.to_string())).await?;
+
+        // the record is updated on maturity, retaining the note and metadata
+        wallet.handle_event(Box::new(Events::Maturity { record: record.clone() })).await?;
+        let stored = store.load_single(binding, network_id, &id).await?;
+        assert_eq!(stored.note.as_deref(), Some("invoice #1024"));
+        assert_eq!(stored.metadata.as_deref(), Some(r#"{"invoice":1024}"#));
+
+        store.store_transaction_note(binding, network_id, id, None).await?;
+        let stored = store.load_single(binding, network_id, &id).await?;
+        assert!(stored.note.is_none());
+
+        let unknown = TransactionId::from_slice(&[0xff; 32]);
+        assert!(matches!(store.store_transaction_note(binding, network_id, unknown, None).await, Err(Error::NoRecordsFound)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_balance_history() -> Result<()> {
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+        let rpc_mock = Arc::new(MockRpcClient::with_network_id(network_id));
+        rpc_mock.set_daa_score_timestamp_estimate(false);
+        let wallet = Arc::new(Wallet::try_with_rpc(Some(rpc_mock.into()), Wallet::resident_store()?, Some(network_id))?);
+        let wallet_args = WalletCreateArgs::new(None, None, EncryptionKind::XChaCha20Poly1305, None, true);
+        wallet.clone().wallet_create(Secret::from("secret"), wallet_args).await?;
+
+        // the resident transaction storage may be persistent, the test uses a unique binding
+        let context_id = UtxoContextId::new(kaspa_hashes::Hash::from_bytes(rand::random()));
+        let utxo_context = UtxoContext::new(wallet.utxo_processor(), UtxoContextBinding::Id(context_id));
+        let binding = Binding::from(utxo_context.binding());
+        let (cache, unresolved) = wallet.update_balance_history(&binding, &network_id).await?;
+        assert!(cache.is_empty() && unresolved == 0);
+
+        let incoming = [UtxoEntryReference::simulated(kaspa_to_sompi(2.0))];
+        let record = TransactionRecord::new_incoming(&utxo_context, incoming[0].transaction_id(), &incoming);
+        wallet.handle_event(Box::new(Events::Pending { record: record.clone() })).await?;
+
+        // the timestamp of a discovered record can not be resolved without the node
+        // (the DAA score is not resolved by other tests, as the DAA timestamp cache may be persistent)
+        let discovered = [UtxoEntryReference::simulated(kaspa_to_sompi(1.0))];
+        let mut discovered = TransactionRecord::new_incoming(&utxo_context, discovered[0].transaction_id(), &discovered);
+        discovered.block_daa_score = 1;
+        discovered.unixtime_msec = None;
+        wallet.handle_event(Box::new(Events::Pending { record: discovered })).await?;
+
+        let (cache, unresolved) = wallet.update_balance_history(&binding, &network_id).await?;
+        assert_eq!(unresolved, 1);
+        let history = cache.history(None);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].balance, kaspa_to_sompi(2.0));
+
+        // the reorg record replaces the incoming record, voiding the receipt
+        let reorg = TransactionRecord::new_reorg(&utxo_context, incoming[0].transaction_id(), &incoming);
+        wallet.handle_event(Box::new(Events::Reorg { record: reorg })).await?;
+        let (cache, _) = wallet.update_balance_history(&binding, &network_id).await?;
+        assert_eq!(cache.history(None).last().map(|point| point.balance), Some(0));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_reorged_out_reacceptance() -> Result<()> {
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+        let rpc_mock = Arc::new(MockRpcClient::with_network_id(network_id));
+        let wallet = Arc::new(Wallet::try_with_rpc(Some(rpc_mock.into()), Wallet::resident_store()?, Some(network_id))?);
+        let wallet_args = WalletCreateArgs::new(None, None, EncryptionKind::XChaCha20Poly1305, None, true);
+        wallet.clone().wallet_create(Secret::from("secret"), wallet_args).await?;
+
+        // the resident transaction storage may be persistent, the test uses a unique binding
+        let context_id = UtxoContextId::new(kaspa_hashes::Hash::from_bytes(rand::random()));
+        let utxo_context = UtxoContext::new(wallet.utxo_processor(), UtxoContextBinding::Id(context_id));
+        let binding = Binding::from(utxo_context.binding());
+
+        let utxos = [UtxoEntryReference::simulated(kaspa_to_sompi(2.0))];
+        let record = TransactionRecord::new_incoming(&utxo_context, utxos[0].transaction_id(), &utxos);
+        wallet.handle_event(Box::new(Events::Pending { record: record.clone() })).await?;
+        let (cache, _) = wallet.update_balance_history(&binding, &network_id).await?;
+        assert_eq!(cache.history(None).last().map(|point| point.balance), Some(kaspa_to_sompi(2.0)));
+
+        let store = wallet.store().as_transaction_record_store()?;
+        let stored = || async { store.load_single(&binding, &network_id, record.id()).await };
+
+        // the reorged transaction does not change the balance
+        let mut reorged = record.clone();
+        reorged.set_reorged(true);
+        wallet.handle_event(Box::new(Events::ReorgedOut { record: reorged })).await?;
+        assert!(stored().await?.is_reorged());
+        let (cache, _) = wallet.update_balance_history(&binding, &network_id).await?;
+        assert_eq!(cache.history(None).last().map(|point| point.balance), Some(0));
+
+        // the balance change is restored once the transaction is accepted again
+        wallet.handle_event(Box::new(Events::Reaccepted { record: record.clone() })).await?;
+        assert!(!stored().await?.is_reorged());
+        let (cache, _) = wallet.update_balance_history(&binding, &network_id).await?;
+        assert_eq!(cache.history(None).last().map(|point| point.balance), Some(kaspa_to_sompi(2.0)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_balance_history_timestamps() -> Result<()> {
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+        let rpc_mock = Arc::new(MockRpcClient::with_network_id(network_id));
+        let wallet = Arc::new(Wallet::try_with_rpc(Some(rpc_mock.into()), Wallet::resident_store()?, Some(network_id))?);
+        let wallet_args = WalletCreateArgs::new(None, None, EncryptionKind::XChaCha20Poly1305, None, true);
+        wallet.clone().wallet_create(Secret::from("secret"), wallet_args).await?;
+
+        // the resident transaction storage may be persistent, the test uses a unique binding
+        let context_id = UtxoContextId::new(kaspa_hashes::Hash::from_bytes(rand::random()));
+        let utxo_context = UtxoContext::new(wallet.utxo_processor(), UtxoContextBinding::Id(context_id));
+        let binding = Binding::from(utxo_context.binding());
+        let (cache, unresolved) = wallet.update_balance_history(&binding, &network_id).await?;
+        assert!(cache.is_empty() && unresolved == 0);
+
+        let incoming = [UtxoEntryReference::simulated(kaspa_to_sompi(2.0))];
+        let record = TransactionRecord::new_incoming(&utxo_context, incoming[0].transaction_id(), &incoming);
+        wallet.handle_event(Box::new(Events::Pending { record })).await?;
+
+        // the timestamp of a record stored without one is resolved via the node
+        let discovered = [UtxoEntryReference::simulated(kaspa_to_sompi(1.0))];
+        let mut discovered = TransactionRecord::new_incoming(&utxo_context, discovered[0].transaction_id(), &discovered);
+        discovered.unixtime_msec = None;
+        wallet.handle_event(Box::new(Events::Pending { record: discovered.clone() })).await?;
+
+        let (cache, unresolved) = wallet.update_balance_history(&binding, &network_id).await?;
+        assert_eq!(unresolved, 0);
+        let history = cache.history(None);
+        assert_eq!(history.first().map(|point| point.balance), Some(kaspa_to_sompi(1.0)));
+        assert_eq!(history.last().map(|point| point.balance), Some(kaspa_to_sompi(3.0)));
+
+        // the resolved timestamp is backfilled into the stored record
+        let store = wallet.store().as_transaction_record_store()?;
+        let stored = store.load_single(&binding, &network_id, discovered.id()).await?;
+        assert_eq!(stored.unixtime_msec(), Some(MOCK_DAA_SCORE_UNIXTIME_MSEC + discovered.block_daa_score() * 1000));
+
+        // the reorg record replaces the incoming record, voiding the receipt
+        let reorg = TransactionRecord::new_reorg(&utxo_context, incoming[0].transaction_id(), &incoming);
+        wallet.handle_event(Box::new(Events::Reorg { record: reorg })).await?;
+        let (cache, _) = wallet.update_balance_history(&binding, &network_id).await?;
+        assert_eq!(cache.history(None).last().map(|point| point.balance), Some(kaspa_to_sompi(1.0)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_timestamp_backfill() -> Result<()> {
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+        let rpc_mock = Arc::new(MockRpcClient::with_network_id(network_id));
+        let wallet = Arc::new(Wallet::try_with_rpc(Some(rpc_mock.into()), Wallet::resident_store()?, Some(network_id))?);
+        let wallet_args = WalletCreateArgs::new(None, None, EncryptionKind::XChaCha20Poly1305, None, true);
+        wallet.clone().wallet_create(Secret::from("secret"), wallet_args).await?;
+
+        let context_id = UtxoContextId::new(kaspa_hashes::Hash::from_bytes(rand::random()));
+        let utxo_context = UtxoContext::new(wallet.utxo_processor(), UtxoContextBinding::Id(context_id));
+        let binding = Binding::from(utxo_context.binding());
+        assert_eq!(wallet.backfill_transaction_timestamps(&binding, &network_id).await?, TimestampBackfill::default());
+
+        let store = wallet.store().as_transaction_record_store()?;
+        let mut records = Vec::new();
+        for (amount, daa_score) in [(1.0, 10), (2.0, 20), (3.0, 20)] {
+            let utxos = [UtxoEntryReference::simulated(kaspa_to_sompi(amount))];
+            let mut record = TransactionRecord::new_incoming(&utxo_context, utxos[0].transaction_id(), &utxos);
+            record.block_daa_score = daa_score;
+            record.unixtime_msec = None;
+            records.push(record);
+        }
+        store.store(&records.iter().collect::<Vec<_>>()).await?;
+
+        let backfill = wallet.backfill_transaction_timestamps(&binding, &network_id).await?;
+        assert_eq!(backfill, TimestampBackfill { resolved: 3, unresolved: 0 });
+        for record in records.iter() {
+            let stored = store.load_single(&binding, &network_id, record.id()).await?;
+            assert_eq!(stored.unixtime_msec(), Some(MOCK_DAA_SCORE_UNIXTIME_MSEC + record.block_daa_score() * 1000));
+        }
+
+        // resolved DAA scores are served from the storage cache
+        let cache = store.load_daa_timestamps(&network_id).await?.unwrap_or_default();
+        assert_eq!(cache.get(20), Some(MOCK_DAA_SCORE_UNIXTIME_MSEC + 20_000));
+        assert_eq!(wallet.backfill_transaction_timestamps(&binding, &network_id).await?, TimestampBackfill::default());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_check_storage() -> Result<()> {
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+        let rpc_mock = Arc::new(MockRpcClient::with_network_id(network_id));
+        let wallet = Arc::new(Wallet::try_with_rpc(Some(rpc_mock.into()), Wallet::resident_store()?, Some(network_id))?);
+        let wallet_secret = Secret::from("secret");
+        let wallet_args = WalletCreateArgs::new(None, None, EncryptionKind::XChaCha20Poly1305, None, true);
+        wallet.clone().wallet_create(wallet_secret.clone(), wallet_args).await?;
+
+        let mnemonic = Mnemonic::random(WordCount::Words12, Language::English)?;
+        let prv_key_data_args = PrvKeyDataCreateArgs::new(None, None, Secret::from(mnemonic.phrase_string()));
+        let prv_key_data_id = wallet.clone().prv_key_data_create(wallet_secret.clone(), prv_key_data_args).await?;
+        let account_args = AccountCreateArgs::new_bip32(prv_key_data_id, None, None, None);
+        let account = wallet.clone().accounts_create(wallet_secret.clone(), account_args).await?;
+
+        let utxo_context = UtxoContext::new(wallet.utxo_processor(), UtxoContextBinding::AccountId(account.account_id));
+        let utxos = [UtxoEntryReference::simulated(kaspa_to_sompi(1.0))];
+        let record = TransactionRecord::new_incoming(&utxo_context, utxos[0].transaction_id(), &utxos);
+        wallet.store().as_transaction_record_store()?.store(&[&record]).await?;
+
+        let report = wallet.check_storage(&wallet_secret, false).await?;
+        assert!(report.is_ok(), "{:?}", report.issues);
+        assert_eq!((report.prv_key_data, report.accounts, report.transactions), (1, 1, 1));
+
+        // orphan account metadata, private key data info and an account
+        // referring to the removed private key data
+        let orphan_id = AccountId::from_hex(&"ff".repeat(32))?;
+        wallet.store().as_account_store()?.update_metadata(vec![AccountMetadata::new_without_indexes(orphan_id)]).await?;
+        wallet.store().as_prv_key_data_store()?.remove(&wallet_secret, &prv_key_data_id).await?;
+
+        let report = wallet.check_storage(&wallet_secret, false).await?;
+        let mut kinds = report.issues.iter().map(|issue| issue.kind).collect::<Vec<_>>();
+        kinds.sort_by_key(|kind| *kind as u8);
+        assert_eq!(
+            kinds,
+            vec![
+                WalletCheckIssueKind::OrphanPrvKeyDataInfo,
+                WalletCheckIssueKind::OrphanAccount,
+                WalletCheckIssueKind::OrphanMetadata
+            ]
+        );
+        assert!(!report.is_repaired());
+
+        let report = wallet.check_storage(&wallet_secret, true).await?;
+        assert_eq!(report.issues.len(), 3);
+        assert!(report.issues.iter().all(|issue| issue.repaired));
+        // the transaction records of the removed account are removed
+        let binding = Binding::from(utxo_context.binding());
+        assert!(wallet.store().as_transaction_record_store()?.load_single(&binding, &network_id, record.id()).await.is_err());
+
+        let report = wallet.check_storage(&wallet_secret, false).await?;
+        assert!(report.is_ok(), "{:?}", report.issues);
+        assert_eq!((report.prv_key_data, report.accounts, report.metadata), (0, 0, 0));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_account_descriptors_from_storage() -> Result<()> {
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+        let rpc_mock = Arc::new(MockRpcClient::with_network_id(network_id));
+        let wallet = Arc::new(Wallet::try_with_rpc(Some(rpc_mock.into()), Wallet::resident_store()?, Some(network_id))?);
+        let wallet_secret = Secret::from("secret");
+        let wallet_args = WalletCreateArgs::new(None, None, EncryptionKind::XChaCha20Poly1305, None, true);
+        wallet.clone().wallet_create(wallet_secret.clone(), wallet_args).await?;
+
+        let mnemonic = Mnemonic::random(WordCount::Words12, Language::English)?;
+        let prv_key_data_args = PrvKeyDataCreateArgs::new(None, None, Secret::from(mnemonic.phrase_string()));
+        let prv_key_data_id = wallet.clone().prv_key_data_create(wallet_secret.clone(), prv_key_data_args).await?;
+        let account_args = AccountCreateArgs::new_bip32(prv_key_data_id, None, Some("savings".to_string()), None);
+        let account_id = wallet.clone().accounts_create(wallet_secret.clone(), account_args).await?.account_id;
+        let account = wallet.get_account_by_id(&account_id).await?.expect("account");
+        assert!(!wallet.active_accounts().contains(&account_id));
+
+        // the stored account and metadata are sufficient to produce the descriptor
+        let (stored_account, stored_metadata) = wallet.store().as_account_store()?.load_single(&account_id).await?.expect("account");
+        let descriptor = stored_account.descriptor(stored_metadata.as_deref()).expect("stored account descriptor");
+        assert_eq!(descriptor.try_to_vec()?, account.descriptor()?.try_to_vec()?);
+
+        let descriptors = wallet.clone().account_descriptors().await?;
+        assert_eq!(descriptors.len(), 1);
+        assert_eq!(descriptors[0].try_to_vec()?, account.descriptor()?.try_to_vec()?);
+
+        // address derivation updates the stored addresses
+        let address = account.clone().as_derivation_capable()?.new_receive_address().await?;
+        let descriptors = wallet.clone().account_descriptors().await?;
+        assert_eq!(descriptors[0].receive_address, Some(address));
+        assert_eq!(descriptors[0].try_to_vec()?, account.descriptor()?.try_to_vec()?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_payment_request_persistence() -> Result<()> {
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+        let rpc_mock = Arc::new(MockRpcClient::with_network_id(network_id));
+        let wallet = Arc::new(Wallet::try_with_rpc(Some(rpc_mock.into()), Wallet::resident_store()?, Some(network_id))?);
+        let wallet_secret = Secret::from("secret");
+        let wallet_args = WalletCreateArgs::new(None, None, EncryptionKind::XChaCha20Poly1305, None, true);
+        wallet.clone().wallet_create(wallet_secret.clone(), wallet_args).await?;
+
+        let mnemonic = Mnemonic::random(WordCount::Words12, Language::English)?;
+        let prv_key_data_args = PrvKeyDataCreateArgs::new(None, None, Secret::from(mnemonic.phrase_string()));
+        let prv_key_data_id = wallet.clone().prv_key_data_create(wallet_secret.clone(), prv_key_data_args).await?;
+        let account_args = AccountCreateArgs::new_bip32(prv_key_data_id, None, None, None);
+        let account_id = wallet.clone().accounts_create(wallet_secret.clone(), account_args).await?.account_id;
+
+        let request = wallet.clone().accounts_create_payment_request(account_id, kaspa_to_sompi(1.0), Some(3600)).await?;
+        let (_, metadata) = wallet.store().as_account_store()?.load_single(&account_id).await?.expect("account");
+        let stored = metadata.expect("metadata").payment_requests().iter().map(|request| request.id).collect::<Vec<_>>();
+        assert_eq!(stored, vec![request.id]);
+
+        // the request is restored when the account is loaded from storage
+        let account = wallet.get_account_by_id(&account_id).await?.expect("account");
+        assert!(!wallet.active_accounts().contains(&account_id));
+        assert_eq!(account.utxo_context().payment_request(&request.id).map(|request| request.amount), Some(kaspa_to_sompi(1.0)));
+
+        let removed = wallet.clone().accounts_remove_payment_request(account_id, request.id).await?;
+        assert_eq!(removed.map(|removed| removed.id), Some(request.id));
+        assert!(wallet.clone().accounts_get_payment_requests(account_id).await?.is_empty());
+        assert!(wallet.clone().accounts_remove_payment_request(account_id, request.id).await?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_sign_message() -> Result<()> {
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+        let rpc_mock = Arc::new(MockRpcClient::with_network_id(network_id));
+        let wallet = Arc::new(Wallet::try_with_rpc(Some(rpc_mock.into()), Wallet::resident_store()?, Some(network_id))?);
+        let wallet_secret = Secret::from("secret");
+        let wallet_args = WalletCreateArgs::new(None, None, EncryptionKind::XChaCha20Poly1305, None, true);
+        wallet.clone().wallet_create(wallet_secret.clone(), wallet_args).await?;
+
+        let mnemonic = Mnemonic::random(WordCount::Words12, Language::English)?;
+        let prv_key_data_args = PrvKeyDataCreateArgs::new(None, None, Secret::from(mnemonic.phrase_string()));
+        let prv_key_data_id = wallet.clone().prv_key_data_create(wallet_secret.clone(), prv_key_data_args).await?;
+        let account_args = AccountCreateArgs::new_bip32(prv_key_data_id, None, None, None);
+        let account = wallet.clone().accounts_create(wallet_secret.clone(), account_args).await?;
+        let address = account.change_address.clone().expect("change address");
+
+        let message = "Hello Kaspa!".to_string();
+        let request = AccountsSignMessageRequest {
+            account_id: account.account_id,
+            wallet_secret: wallet_secret.clone(),
+            payment_secret: None,
+            address: address.clone(),
+            message: message.clone(),
+        };
+        let AccountsSignMessageResponse { signature } = wallet.clone().accounts_sign_message_call(request).await?;
+
+        let request = AccountsVerifyMessageRequest { address: address.clone(), message, signature: signature.clone() };
+        assert!(wallet.clone().accounts_verify_message_call(request).await?.valid);
+
+        let request = AccountsVerifyMessageRequest { address: address.clone(), message: "Hello!".to_string(), signature };
+        assert!(!wallet.clone().accounts_verify_message_call(request).await?.valid);
+
+        // address not belonging to the account
+        let foreign = Address::new(address.prefix, address.version, &[0x11; 32]);
+        let request = AccountsSignMessageRequest {
+            account_id: account.account_id,
+            wallet_secret,
+            payment_secret: None,
+            address: foreign,
+            message: "Hello Kaspa!".to_string(),
+        };
+        assert!(matches!(wallet.clone().accounts_sign_message_call(request).await, Err(Error::AddressNotFound(_))));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_multisig_address_generator() -> Result<()> {
+        use crate::derivation::create_xpub_from_mnemonic;
+        use kaspa_wallet_keys::multisig::MultisigAddressGenerator;
+
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+        let rpc_mock = Arc::new(MockRpcClient::with_network_id(network_id));
+        let wallet = Arc::new(Wallet::try_with_rpc(Some(rpc_mock.into()), Wallet::resident_store()?, Some(network_id))?);
+        let wallet_secret = Secret::from("secret");
+        let wallet_args = WalletCreateArgs::new(None, None, EncryptionKind::XChaCha20Poly1305, None, true);
+        wallet.clone().wallet_create(wallet_secret.clone(), wallet_args).await?;
+
+        let mnemonics = (0..3).map(|_| Mnemonic::random(WordCount::Words12, Language::English)).collect::<Result<Vec<_>, _>>()?;
+        let mut xpubs = vec![];
+        for mnemonic in mnemonics.iter() {
+            xpubs.push(create_xpub_from_mnemonic(&mnemonic.phrase_string(), MULTISIG_ACCOUNT_KIND.into(), 0).await?);
+        }
+
+        // the wallet holds the first key, the other cosigners are supplied as extended public keys
+        let prv_key_data_args = PrvKeyDataCreateArgs::new(None, None, Secret::from(mnemonics[0].phrase_string()));
+        let prv_key_data_id = wallet.clone().prv_key_data_create(wallet_secret.clone(), prv_key_data_args).await?;
+        let additional_xpub_keys = xpubs[1..].iter().map(|xpub| xpub.to_string(Some(KeyPrefix::XPUB))).collect::<Vec<_>>();
+        let account_args =
+            AccountCreateArgs::new_multisig(vec![PrvKeyDataArgs::new(prv_key_data_id, None)], additional_xpub_keys, None, 2);
+        let account = wallet.clone().accounts_create(wallet_secret, account_args).await?;
+
+        let mut sorted = xpubs.iter().map(|xpub| xpub.to_string(Some(KeyPrefix::XPUB))).collect::<Vec<_>>();
+        sorted.sort_unstable();
+        let cosigner_index =
+            sorted.iter().position(|xpub| *xpub == xpubs[0].to_string(Some(KeyPrefix::XPUB))).map(|index| index as u32);
+
+        let generator = MultisigAddressGenerator::try_new(xpubs, 2, cosigner_index, false)?;
+        let receive = generator.receive_addresses(NetworkType::Testnet, 0..1)?;
+        let change = generator.change_addresses(NetworkType::Testnet, 0..1)?;
+        assert_eq!(Some(receive[0].address()), account.receive_address);
+        assert_eq!(Some(change[0].address()), account.change_address);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_batch() -> Result<()> {
+        use crate::api::batch::{BatchResult, WalletRequest, WalletResponse};
+        use crate::api::message::{AccountsEnumerateRequest, AccountsGetRequest, AccountsRenameRequest};
+
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+        let rpc_mock = Arc::new(MockRpcClient::with_network_id(network_id));
+        let wallet = Arc::new(Wallet::try_with_mock_rpc(&rpc_mock, Wallet::resident_store()?)?);
+        let wallet_secret = Secret::from("secret");
+        let wallet_args = WalletCreateArgs::new(None, None, EncryptionKind::XChaCha20Poly1305, None, true);
+        wallet.clone().wallet_create(wallet_secret.clone(), wallet_args).await?;
+
+        let mnemonic = Mnemonic::random(WordCount::Words12, Language::English)?;
+        let prv_key_data_args = PrvKeyDataCreateArgs::new(None, None, Secret::from(mnemonic.phrase_string()));
+        let prv_key_data_id = wallet.clone().prv_key_data_create(wallet_secret.clone(), prv_key_data_args).await?;
+        let account_args = AccountCreateArgs::new_bip32(prv_key_data_id, None, None, None);
+        let account_id = wallet.clone().accounts_create(wallet_secret.clone(), account_args).await?.account_id;
+
+        let unknown = AccountId::from_hex(&"ff".repeat(32))?;
+        let requests = vec![
+            WalletRequest::AccountsGet(AccountsGetRequest { account_id: unknown }),
+            WalletRequest::AccountsRename(AccountsRenameRequest { account_id, name: Some("renamed".to_string()), wallet_secret }),
+            WalletRequest::AccountsGet(AccountsGetRequest { account_id }),
+            WalletRequest::AccountsEnumerate(AccountsEnumerateRequest { include_archived: false }),
+        ];
+        // requests are transported as `{ "op": <method>, "request": <request> }`
+        let json = serde_json::to_value(&requests[3]).unwrap();
+        assert_eq!(json["op"], "accounts-enumerate");
+        assert_eq!(requests[3].op(), "accounts-enumerate");
+
+        let results = wallet.clone().batch_requests(requests).await?;
+        assert_eq!(results.len(), 4);
+        let mut results = results.into_iter().map(BatchResult::into_result);
+        // errors do not interrupt the batch execution
+        assert_eq!(results.next().unwrap().unwrap_err().code, crate::error::ErrorCode::AccountNotFound as u32);
+        assert!(matches!(results.next().unwrap(), Ok(WalletResponse::AccountsRename(_))));
+        match results.next().unwrap() {
+            Ok(WalletResponse::AccountsGet(response)) => {
+                assert_eq!(response.account_descriptor.account_name.as_deref(), Some("renamed"))
+            }
+            result => panic!("unexpected batch result {result:?}"),
+        }
+        match results.next().unwrap() {
+            Ok(WalletResponse::AccountsEnumerate(response)) => assert_eq!(response.account_descriptors.len(), 1),
+            result => panic!("unexpected batch result {result:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_mock_rpc_reorg() -> Result<()> {
+        async fn wait_for_event<F: Fn(&Events) -> bool>(channel: &MultiplexerChannel<Box<Events>>, predicate: F) -> Box<Events> {
+            tokio::time::timeout(Duration::from_secs(10), async {
+                loop {
+                    let event = channel.receiver.recv().await.unwrap();
+                    if predicate(&event) {
+                        break event;
+                    }
+                }
+            })
+            .await
+            .expect("timeout while waiting for a wallet event")
+        }
+
+        let rpc_mock = Arc::new(MockRpcClient::new());
+        rpc_mock.connect().await?;
+        let wallet = Arc::new(Wallet::try_with_mock_rpc(&rpc_mock, Wallet::resident_store()?)?);
+        wallet.start().await?;
+        let events = wallet.multiplexer().channel();
+
+        let wallet_secret = Secret::from("secret");
+        let wallet_args = WalletCreateArgs::new(None, None, EncryptionKind::XChaCha20Poly1305, None, true);
+        wallet.clone().wallet_create(wallet_secret.clone(), wallet_args).await?;
+        let mnemonic = Mnemonic::random(WordCount::Words12, Language::English)?;
+        let prv_key_data_args = PrvKeyDataCreateArgs::new(None, None, Secret::from(mnemonic.phrase_string()));
+        let prv_key_data_id = wallet.clone().prv_key_data_create(wallet_secret.clone(), prv_key_data_args).await?;
+        let account_args = AccountCreateArgs::new_bip32(prv_key_data_id, None, None, None);
+        let account = wallet.clone().accounts_create(wallet_secret, account_args).await?;
+        wallet.clone().accounts_activate(Some(vec![account.account_id])).await?;
+        let address = account.receive_address.expect("receive address");
+
+        // UTXOs created at the virtual DAA score are pending
+        let entry = rpc_mock.add_utxo_with_daa_score(&address, kaspa_to_sompi(1.0), rpc_mock.virtual_daa_score(), false).unwrap();
+        let txid = entry.outpoint.transaction_id;
+        wait_for_event(&events, |event| matches!(event, Events::Pending { record } if *record.id() == txid)).await;
+
+        // removal of a pending UTXO is reported as a reorg
+        let removed = rpc_mock.remove_utxos(&[entry.outpoint]).unwrap();
+        wait_for_event(&events, |event| matches!(event, Events::Reorg { record } if *record.id() == txid)).await;
+
+        // the scripted notification is emitted once the virtual DAA score reaches the scheduled score
+        let daa_score = rpc_mock.virtual_daa_score() + 10;
+        let notification = UtxosChangedNotification { added: Arc::new(removed), removed: Arc::new(vec![]) };
+        rpc_mock.schedule_notification(daa_score, Notification::UtxosChanged(notification));
+        rpc_mock.advance_virtual_daa_score(5).unwrap();
+        rpc_mock.advance_virtual_daa_score(5).unwrap();
+        wait_for_event(&events, |event| matches!(event, Events::Pending { record } if *record.id() == txid)).await;
+
+        wallet.clone().wallet_close().await?;
+        wallet.stop().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_resume_checkpointed_sweep() -> Result<()> {
+        use crate::storage::{OperationCheckpoint, OperationKind};
+        use crate::utxo::UtxoEntryId;
+        use kaspa_consensus_core::subnets::SUBNETWORK_ID_NATIVE;
+        use kaspa_consensus_core::tx::{Transaction, TransactionInput, TransactionOutput};
+        use kaspa_txscript::pay_to_address_script;
+
+        let rpc_mock = Arc::new(MockRpcClient::new());
+        rpc_mock.connect().await?;
+        let wallet = Arc::new(Wallet::try_with_mock_rpc(&rpc_mock, Wallet::resident_store()?)?);
+        wallet.start().await?;
+
+        let wallet_secret = Secret::from("secret");
+        let wallet_args = WalletCreateArgs::new(None, None, EncryptionKind::XChaCha20Poly1305, None, true);
+        wallet.clone().wallet_create(wallet_secret.clone(), wallet_args).await?;
+        let mnemonic = Mnemonic::random(WordCount::Words12, Language::English)?;
+        let prv_key_data_args = PrvKeyDataCreateArgs::new(None, None, Secret::from(mnemonic.phrase_string()));
+        let prv_key_data_id = wallet.clone().prv_key_data_create(wallet_secret.clone(), prv_key_data_args).await?;
+        let account_args = AccountCreateArgs::new_bip32(prv_key_data_id, None, None, None);
+        let descriptor = wallet.clone().accounts_create(wallet_secret.clone(), account_args).await?;
+        wallet.clone().accounts_activate(Some(vec![descriptor.account_id])).await?;
+        let account = wallet.get_account_by_id(&descriptor.account_id).await?.expect("account");
+        let address = descriptor.receive_address.expect("receive address");
+
+        let entries = (0..4).map(|_| rpc_mock.add_utxo(&address, kaspa_to_sompi(1.0)).unwrap()).collect::<Vec<_>>();
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while account.utxo_context().mature_utxo_size() < entries.len() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("timeout while waiting for the account UTXOs");
+        let utxo_entry_id = |index: usize| UtxoEntryId::new(entries[index].outpoint.transaction_id, entries[index].outpoint.index);
+
+        // interrupted sweep: the first transaction is pending in the mempool,
+        // the second transaction has been evicted (unknown to the node)
+        let pending = Transaction::new(
+            0,
+            vec![TransactionInput::new(entries[0].outpoint.into(), vec![], 0, 1)],
+            vec![TransactionOutput::new(kaspa_to_sompi(0.9), pay_to_address_script(&address))],
+            0,
+            SUBNETWORK_ID_NATIVE,
+            0,
+            vec![],
+        );
+        rpc_mock.add_mempool_transaction(pending.clone());
+        let evicted = TransactionId::from_slice(&[7; 32]);
+        let mut checkpoint = OperationCheckpoint::new(OperationKind::Sweep);
+        checkpoint.record(pending.id(), [utxo_entry_id(0)]);
+        checkpoint.record(evicted, [utxo_entry_id(1)]);
+        account.store_checkpoint(Some(checkpoint)).await?;
+
+        // only the UTXOs spent by the pending transaction remain excluded
+        let checkpoint = account.resume_checkpoint().await?.expect("checkpoint");
+        assert_eq!(checkpoint.transaction_ids().cloned().collect::<Vec<_>>(), vec![pending.id()]);
+        assert_eq!(checkpoint.spent_utxos(), AHashSet::from_iter([utxo_entry_id(0)]));
+        assert_eq!(account.checkpoint(), Some(checkpoint));
+
+        // the resumed sweep spends the released UTXOs and clears the checkpoint
+        let (_summary, ids) = account.clone().sweep(wallet_secret, None, &Abortable::default(), None).await?;
+        assert!(!ids.is_empty());
+        let spent = rpc_mock
+            .transactions()
+            .iter()
+            .flat_map(|transaction| transaction.inputs.iter().map(|input| input.previous_outpoint))
+            .collect::<Vec<_>>();
+        assert!(!spent.contains(&entries[0].outpoint.into()));
+        assert!((1..entries.len()).all(|index| spent.contains(&entries[index].outpoint.into())));
+        assert!(account.checkpoint().is_none());
+
+        wallet.clone().wallet_close().await?;
+        wallet.stop().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_history_rescan() -> Result<()> {
+        use crate::tests::MOCK_PRUNING_POINT_HASH;
+        use kaspa_consensus_core::subnets::SUBNETWORK_ID_NATIVE;
+        use kaspa_consensus_core::tx::{Transaction, TransactionInput, TransactionOutpoint, TransactionOutput};
+        use kaspa_txscript::pay_to_address_script;
+
+        let rpc_mock = Arc::new(MockRpcClient::new());
+        rpc_mock.connect().await?;
+        let wallet = Arc::new(Wallet::try_with_mock_rpc(&rpc_mock, Wallet::resident_store()?)?);
+        wallet.start().await?;
+
+        let wallet_secret = Secret::from("secret");
+        let wallet_args = WalletCreateArgs::new(None, None, EncryptionKind::XChaCha20Poly1305, None, true);
+        wallet.clone().wallet_create(wallet_secret.clone(), wallet_args).await?;
+        let mnemonic = Mnemonic::random(WordCount::Words12, Language::English)?;
+        let prv_key_data_args = PrvKeyDataCreateArgs::new(None, None, Secret::from(mnemonic.phrase_string()));
+        let prv_key_data_id = wallet.clone().prv_key_data_create(wallet_secret.clone(), prv_key_data_args).await?;
+        let account_args = AccountCreateArgs::new_bip32(prv_key_data_id, None, None, None);
+        let descriptor = wallet.clone().accounts_create(wallet_secret, account_args).await?;
+        let account = wallet.get_account_by_id(&descriptor.account_id).await?.expect("account");
+        let receive_address = descriptor.receive_address.expect("receive address");
+        let change_address = descriptor.change_address.expect("change address");
+        let external_address = Address::new(receive_address.prefix, receive_address.version, &[1; 32]);
+
+        let make_transaction = |inputs: Vec<TransactionOutpoint>, outputs: Vec<(&Address, f64)>| {
+            let inputs = inputs.into_iter().map(|outpoint| TransactionInput::new(outpoint, vec![], 0, 1)).collect();
+            let outputs = outputs
+                .into_iter()
+                .map(|(address, amount)| TransactionOutput::new(kaspa_to_sompi(amount), pay_to_address_script(address)))
+                .collect();
+            Transaction::new(0, inputs, outputs, 0, SUBNETWORK_ID_NATIVE, 0, vec![])
+        };
+        let external_outpoint = || TransactionOutpoint::new(TransactionId::from_bytes(rand::random()), 0);
+
+        // incoming transaction preceding the range, spent by an outgoing transaction within the range
+        let received = make_transaction(vec![external_outpoint()], vec![(&receive_address, 5.0)]);
+        let spending =
+            make_transaction(vec![TransactionOutpoint::new(received.id(), 0)], vec![(&external_address, 3.0), (&change_address, 1.9)]);
+        let incoming = make_transaction(vec![external_outpoint()], vec![(&receive_address, 2.0)]);
+        let unrelated = make_transaction(vec![external_outpoint()], vec![(&external_address, 1.0)]);
+        let following = make_transaction(vec![external_outpoint()], vec![(&receive_address, 1.0)]);
+
+        rpc_mock.add_chain_block(100, vec![received.clone()]);
+        let preceding_hash = rpc_mock.add_chain_block(150, vec![]);
+        rpc_mock.add_chain_block(300, vec![spending.clone(), unrelated]);
+        rpc_mock.add_chain_block(400, vec![incoming.clone()]);
+        rpc_mock.add_chain_block(600, vec![following.clone()]);
+
+        let binding = Binding::from(account.utxo_context().binding());
+        let network_id = wallet.network_id()?;
+        let store = wallet.store().as_transaction_record_store()?;
+
+        // without the incoming transaction in the history, the outgoing
+        // transaction is only recognized by the change it has received
+        let summary = account.clone().rescan_history(200..500, &Abortable::default()).await?;
+        assert_eq!((summary.chain_blocks, summary.accepted_transactions, summary.records), (2, 2, 2));
+        // the accepted transactions are requested from the chain block preceding the range
+        assert_eq!(rpc_mock.virtual_chain_requests(), vec![MOCK_PRUNING_POINT_HASH, preceding_hash]);
+        let record = store.load_single(&binding, &network_id, &spending.id()).await?;
+        assert_eq!(record.transaction_data().kind(), TransactionKind::Incoming);
+        assert!(store.load_single(&binding, &network_id, &following.id()).await.is_err());
+        store.remove(&binding, &network_id, &[&spending.id()]).await?;
+
+        let summary = account.clone().rescan_history(0..200, &Abortable::default()).await?;
+        assert_eq!((summary.chain_blocks, summary.accepted_transactions, summary.records), (1, 1, 1));
+        let record = store.load_single(&binding, &network_id, &received.id()).await?;
+        assert_eq!(record.transaction_data().kind(), TransactionKind::Incoming);
+
+        // outputs received before the range are known from the stored history
+        let summary = account.clone().rescan_history(200..500, &Abortable::default()).await?;
+        assert_eq!((summary.accepted_transactions, summary.records, summary.existing), (2, 1, 1));
+        let record = store.load_single(&binding, &network_id, &spending.id()).await?;
+        match record.transaction_data() {
+            TransactionData::Outgoing { fees, payment_value, change_value, .. } => {
+                assert_eq!(*fees, kaspa_to_sompi(0.1));
+                assert_eq!(*payment_value, Some(kaspa_to_sompi(3.0)));
+                assert_eq!(*change_value, kaspa_to_sompi(1.9));
+            }
+            data => panic!("unexpected transaction data {data:?}"),
+        }
+
+        // an empty range does not request the accepted transactions
+        let requests = rpc_mock.virtual_chain_requests().len();
+        let summary = account.clone().rescan_history(700..800, &Abortable::default()).await?;
+        assert_eq!(summary.chain_blocks, 0);
+        assert_eq!(rpc_mock.virtual_chain_requests().len(), requests + 1);
+
+        wallet.clone().wallet_close().await?;
+        wallet.stop().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_resume_interrupted_scans() -> Result<()> {
+        let rpc_mock = Arc::new(MockRpcClient::new());
+        rpc_mock.connect().await?;
+        let wallet = Arc::new(Wallet::try_with_mock_rpc(&rpc_mock, Wallet::resident_store()?)?);
+        wallet.start().await?;
+        let events = wallet.multiplexer().channel();
+
+        let wallet_secret = Secret::from("secret");
+        let wallet_args = WalletCreateArgs::new(None, None, EncryptionKind::XChaCha20Poly1305, None, true);
+        wallet.clone().wallet_create(wallet_secret.clone(), wallet_args).await?;
+        let mnemonic = Mnemonic::random(WordCount::Words12, Language::English)?;
+        let prv_key_data_args = PrvKeyDataCreateArgs::new(None, None, Secret::from(mnemonic.phrase_string()));
+        let prv_key_data_id = wallet.clone().prv_key_data_create(wallet_secret.clone(), prv_key_data_args).await?;
+        let account_args = AccountCreateArgs::new_bip32(prv_key_data_id, None, None, None);
+        let account = wallet.clone().accounts_create(wallet_secret, account_args).await?;
+        wallet.clone().accounts_activate(Some(vec![account.account_id])).await?;
+
+        // scans of accounts that are no longer active are discarded
+        let inactive = AccountId::from_hex(&"ff".repeat(32))?;
+        wallet.register_interrupted_scan(account.account_id, Some(8), Some(16));
+        wallet.register_interrupted_scan(inactive, None, None);
+        wallet.notify(Events::UtxoProcStart).await?;
+
+        let resumed = tokio::time::timeout(Duration::from_secs(10), async {
+            loop {
+                if let Events::ScanResumed { account_id } = *events.receiver.recv().await.unwrap() {
+                    break account_id;
+                }
+            }
+        })
+        .await
+        .expect("timeout while waiting for the scan to resume");
+        assert_eq!(resumed, account.account_id);
+        assert!(wallet.inner.interrupted_scans.lock().unwrap().is_empty());
+
+        wallet.clone().wallet_close().await?;
+        wallet.stop().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_apply_settings_profile() -> Result<()> {
+        let rpc_mock = Arc::new(MockRpcClient::new());
+        let wallet = Arc::new(Wallet::try_with_mock_rpc(&rpc_mock, Wallet::resident_store()?)?);
+
+        let profile = SettingsProfile::new(NetworkId::with_suffix(NetworkType::Testnet, 11));
+        wallet.apply_settings_profile(&profile)?;
+        assert_eq!(wallet.network_id()?, profile.network);
+        // the wallet connects to the network of the applied profile
+        let profile = SettingsProfile::new(rpc_mock.network_id());
+        wallet.apply_settings_profile(&profile)?;
+        rpc_mock.connect().await?;
+        wallet.start().await?;
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while !wallet.is_connected() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("timeout while connecting the wallet");
+
+        // the profile network can not be applied while connected
+        let mainnet = SettingsProfile::new(NetworkId::new(NetworkType::Mainnet));
+        assert!(matches!(wallet.apply_settings_profile(&mainnet), Err(Error::NetworkTypeConnected)));
+        assert_eq!(wallet.network_id()?, profile.network);
+
+        wallet.stop().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_sync_progress() -> Result<()> {
+        let rpc_mock = Arc::new(MockRpcClient::new());
+        rpc_mock.connect().await?;
+        let wallet = Arc::new(Wallet::try_with_mock_rpc(&rpc_mock, Wallet::resident_store()?)?);
+        wallet.start().await?;
+
+        let wallet_secret = Secret::from("secret");
+        let wallet_args = WalletCreateArgs::new(None, None, EncryptionKind::XChaCha20Poly1305, None, true);
+        wallet.clone().wallet_create(wallet_secret.clone(), wallet_args).await?;
+        let mnemonic = Mnemonic::random(WordCount::Words12, Language::English)?;
+        let prv_key_data_args = PrvKeyDataCreateArgs::new(None, None, Secret::from(mnemonic.phrase_string()));
+        let prv_key_data_id = wallet.clone().prv_key_data_create(wallet_secret.clone(), prv_key_data_args).await?;
+        let account_args = AccountCreateArgs::new_bip32(prv_key_data_id, None, None, None);
+        let account = wallet.clone().accounts_create(wallet_secret, account_args).await?;
+        wallet.clone().accounts_deactivate(None).await?;
+
+        // a used address past the first scan window extends the scan by a window
+        let derivation = wallet.get_account_by_id(&account.account_id).await?.unwrap().as_derivation_capable()?.derivation();
+        let address = derivation.receive_address_manager().get_range(10..11)?.remove(0);
+        rpc_mock.add_utxo(&address, kaspa_to_sompi(1.0)).unwrap();
+
+        let events = wallet.multiplexer().channel();
+        wallet.clone().accounts_activate(Some(vec![account.account_id])).await?;
+        assert!(wallet.wallet_sync().is_synced());
+        let progress = std::iter::from_fn(|| events.receiver.try_recv().ok())
+            .filter_map(|event| match *event {
+                Events::WalletSync { progress } => Some(progress),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // the address scan progress is reported per address: two 8-address
+        // windows of the receive and change address managers plus the window
+        // following the used receive address
+        let scan = progress.iter().filter(|progress| progress.phase == WalletSyncPhase::AddressScan).collect::<Vec<_>>();
+        assert!(scan.iter().any(|progress| progress.percentage > 0 && progress.percentage < 100));
+        assert!(scan.windows(2).all(|pair| pair[0].processed <= pair[1].processed));
+        let last = scan.last().unwrap();
+        assert_eq!((last.processed, last.total, last.percentage), (40, 40, 100));
+        let phases = progress.iter().map(|progress| progress.phase).collect::<Vec<_>>();
+        assert_eq!(phases.last(), Some(&WalletSyncPhase::Synced));
+        assert!(phases.contains(&WalletSyncPhase::UtxoReconciliation));
+
+        // an activation error resets the wallet sync
+        wallet.clone().accounts_deactivate(None).await?;
+        let account_store = wallet.store().as_account_store()?;
+        let (stored, metadata) = account_store.load_single(&account.account_id).await?.unwrap();
+        let mut legacy = stored.as_ref().clone();
+        legacy.kind = LEGACY_ACCOUNT_KIND.into();
+        account_store.store_single(&legacy, metadata.as_deref()).await?;
+        assert!(wallet.clone().accounts_activate(Some(vec![account.account_id])).await.is_err());
+        assert_eq!(wallet.wallet_sync().phase(), WalletSyncPhase::Idle);
+
+        wallet.clone().wallet_close().await?;
+        wallet.stop().await?;
+        Ok(())
+    }
+
     /*
     use workflow_rpc::client::ConnectOptions;
     use std::{str::FromStr, thread::sleep, time};
@@ -0,0 +1,248 @@
+//!
+//! Wallet sync state machine.
+//!
+//! Tracks the progress of the wallet's own synchronization with the
+//! network: the account address scan, the reconciliation of the scanned
+//! UTXOs with the account state and the (optional) transaction history
+//! backfill. This is independent of the node sync state reported by
+//! [`SyncState`](crate::events::SyncState). Each progress change is posted
+//! as [`Events::WalletSync`] and the current progress is included in the
+//! `GetStatus` Wallet API response, allowing clients to display a sync bar.
+//!
+
+use crate::imports::*;
+use crate::logging::log;
+
+/// Phase of the wallet synchronization.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WalletSyncPhase {
+    /// The wallet is not synchronizing (wallet is closed,
+    /// not connected or accounts have not been activated)
+    #[default]
+    Idle,
+    /// Scanning the address space of the activated accounts for UTXOs
+    AddressScan,
+    /// Computing the account balances from the scanned UTXOs and
+    /// persisting the address usage discovered by the scan
+    UtxoReconciliation,
+    /// Reconstructing the transaction history from the network data
+    /// (see [`HistoryRescan`](crate::utxo::HistoryRescan))
+    HistoryBackfill,
+    /// The wallet is synchronized with the network
+    Synced,
+}
+
+impl WalletSyncPhase {
+    /// Returns `true` if the wallet sync can transition from `self` to `next`.
+    /// The sync can be reset to [`WalletSyncPhase::Idle`] at any time and
+    /// restarted by an address scan unless the history backfill is running.
+    pub fn can_transition_to(&self, next: WalletSyncPhase) -> bool {
+        use WalletSyncPhase::*;
+        matches!(
+            (self, next),
+            (_, Idle)
+                | (Idle | AddressScan | UtxoReconciliation | Synced, AddressScan)
+                | (AddressScan, UtxoReconciliation)
+                | (UtxoReconciliation | Synced, HistoryBackfill)
+                | (UtxoReconciliation | HistoryBackfill, Synced)
+        )
+    }
+}
+
+impl std::fmt::Display for WalletSyncPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            WalletSyncPhase::Idle => "idle",
+            WalletSyncPhase::AddressScan => "address-scan",
+            WalletSyncPhase::UtxoReconciliation => "utxo-reconciliation",
+            WalletSyncPhase::HistoryBackfill => "history-backfill",
+            WalletSyncPhase::Synced => "synced",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Progress of the wallet synchronization.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletSyncProgress {
+    pub phase: WalletSyncPhase,
+    /// Number of items processed within the phase (addresses
+    /// for the address scan, accounts for the UTXO reconciliation,
+    /// chain blocks for the history backfill)
+    pub processed: u64,
+    /// Total number of items to process within the phase. The total of
+    /// the address scan grows as the scan discovers used addresses.
+    pub total: u64,
+    /// Completion percentage of the phase (0-100)
+    pub percentage: u8,
+}
+
+impl WalletSyncProgress {
+    pub fn new(phase: WalletSyncPhase, processed: u64, total: u64) -> Self {
+        let percentage = match phase {
+            WalletSyncPhase::Idle => 0,
+            WalletSyncPhase::Synced => 100,
+            _ if total == 0 => 0,
+            _ => (processed.min(total) * 100 / total) as u8,
+        };
+        Self { phase, processed, total, percentage }
+    }
+
+    pub fn is_synced(&self) -> bool {
+        self.phase == WalletSyncPhase::Synced
+    }
+}
+
+struct Inner {
+    multiplexer: Multiplexer<Box<Events>>,
+    progress: Mutex<WalletSyncProgress>,
+}
+
+/// Wallet sync state machine posting [`Events::WalletSync`]
+/// on phase transitions and progress percentage changes.
+#[derive(Clone)]
+pub struct WalletSync {
+    inner: Arc<Inner>,
+}
+
+impl WalletSync {
+    pub fn new(multiplexer: Multiplexer<Box<Events>>) -> Self {
+        Self { inner: Arc::new(Inner { multiplexer, progress: Mutex::new(WalletSyncProgress::default()) }) }
+    }
+
+    /// Current wallet sync progress.
+    pub fn progress(&self) -> WalletSyncProgress {
+        self.inner.progress.lock().unwrap().clone()
+    }
+
+    pub fn phase(&self) -> WalletSyncPhase {
+        self.inner.progress.lock().unwrap().phase
+    }
+
+    pub fn is_synced(&self) -> bool {
+        self.inner.progress.lock().unwrap().is_synced()
+    }
+
+    /// Enter the `phase` with `total` items to process. Returns `false`
+    /// (leaving the state unchanged) if the transition is not permitted.
+    pub fn begin(&self, phase: WalletSyncPhase, total: u64) -> bool {
+        let progress = {
+            let mut progress = self.inner.progress.lock().unwrap();
+            if !progress.phase.can_transition_to(phase) {
+                log!(Wallet, Debug, "wallet sync: ignoring transition from {} to {phase}", progress.phase);
+                return false;
+            }
+            *progress = WalletSyncProgress::new(phase, 0, total);
+            progress.clone()
+        };
+        self.post(progress);
+        true
+    }
+
+    /// Register `count` items processed within the `phase`. The update
+    /// is ignored if the sync is no longer in the `phase`.
+    pub fn advance(&self, phase: WalletSyncPhase, count: u64) {
+        let progress = {
+            let mut progress = self.inner.progress.lock().unwrap();
+            if progress.phase != phase {
+                return;
+            }
+            let percentage = progress.percentage;
+            *progress = WalletSyncProgress::new(phase, progress.processed + count, progress.total);
+            if progress.percentage == percentage {
+                return;
+            }
+            progress.clone()
+        };
+        self.post(progress);
+    }
+
+    /// Add `count` items to the total of the `phase`. The update
+    /// is ignored if the sync is no longer in the `phase`.
+    pub fn extend(&self, phase: WalletSyncPhase, count: u64) {
+        let progress = {
+            let mut progress = self.inner.progress.lock().unwrap();
+            if progress.phase != phase || count == 0 {
+                return;
+            }
+            let percentage = progress.percentage;
+            *progress = WalletSyncProgress::new(phase, progress.processed, progress.total + count);
+            if progress.percentage == percentage {
+                return;
+            }
+            progress.clone()
+        };
+        self.post(progress);
+    }
+
+    /// Transition to [`WalletSyncPhase::Synced`].
+    pub fn complete(&self) -> bool {
+        self.begin(WalletSyncPhase::Synced, 0)
+    }
+
+    /// Transition to [`WalletSyncPhase::Idle`].
+    pub fn reset(&self) {
+        if self.phase() != WalletSyncPhase::Idle {
+            self.begin(WalletSyncPhase::Idle, 0);
+        }
+    }
+
+    fn post(&self, progress: WalletSyncProgress) {
+        self.inner.multiplexer.try_broadcast(Box::new(Events::WalletSync { progress })).ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use workflow_core::channel::MultiplexerChannel;
+
+    fn recv(events: &MultiplexerChannel<Box<Events>>) -> Option<WalletSyncProgress> {
+        match *events.receiver.try_recv().ok()? {
+            Events::WalletSync { progress } => Some(progress),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_wallet_sync_progress() {
+        let multiplexer = Multiplexer::<Box<Events>>::new();
+        let events = multiplexer.channel();
+        let sync = WalletSync::new(multiplexer);
+
+        assert!(!sync.begin(WalletSyncPhase::UtxoReconciliation, 1));
+        assert!(!sync.complete());
+        assert!(recv(&events).is_none());
+
+        assert!(sync.begin(WalletSyncPhase::AddressScan, 3));
+        assert_eq!(recv(&events), Some(WalletSyncProgress::new(WalletSyncPhase::AddressScan, 0, 3)));
+        sync.advance(WalletSyncPhase::AddressScan, 1);
+        assert_eq!(recv(&events).map(|progress| progress.percentage), Some(33));
+        sync.advance(WalletSyncPhase::HistoryBackfill, 1);
+        assert!(recv(&events).is_none());
+
+        sync.extend(WalletSyncPhase::AddressScan, 3);
+        assert_eq!(recv(&events), Some(WalletSyncProgress::new(WalletSyncPhase::AddressScan, 1, 6)));
+        sync.extend(WalletSyncPhase::AddressScan, 0);
+        assert!(recv(&events).is_none());
+
+        assert!(sync.begin(WalletSyncPhase::UtxoReconciliation, 200));
+        assert_eq!(recv(&events).map(|progress| progress.phase), Some(WalletSyncPhase::UtxoReconciliation));
+        sync.advance(WalletSyncPhase::UtxoReconciliation, 1);
+        assert!(recv(&events).is_none(), "progress is only posted when the percentage changes");
+        sync.advance(WalletSyncPhase::UtxoReconciliation, 1);
+        assert_eq!(recv(&events).map(|progress| progress.percentage), Some(1));
+
+        assert!(sync.complete());
+        assert!(sync.is_synced());
+        assert_eq!(sync.progress().percentage, 100);
+        assert!(sync.begin(WalletSyncPhase::HistoryBackfill, 10));
+        assert!(!sync.begin(WalletSyncPhase::AddressScan, 1));
+        assert!(sync.complete());
+
+        sync.reset();
+        assert_eq!(sync.progress(), WalletSyncProgress::default());
+    }
+}
@@ -0,0 +1,171 @@
+//!
+//! Transaction record timestamp resolution.
+//!
+//! Transaction records carry the DAA score of the transaction, while the
+//! timestamp is obtained from the node when the record is created. If the
+//! node does not support [`NodeFeature::DaaScoreTimestampEstimate`] or the
+//! estimate fails, the record is stored without the timestamp. The resolver
+//! batches the DAA scores of such records, resolves them via the node in
+//! chunks of [`DAA_TIMESTAMP_BATCH`] scores, caches the results in the
+//! transaction record storage (see [`DaaTimestampCache`](crate::storage::DaaTimestampCache),
+//! bounded to the most recent DAA scores) and backfills the timestamps into
+//! the stored records.
+//!
+
+use crate::imports::*;
+use crate::logging::log;
+use crate::storage::{Binding, TransactionRecord};
+use kaspa_wrpc_client::capabilities::NodeFeature;
+
+/// Maximum number of DAA scores resolved by a single
+/// `GetDaaScoreTimestampEstimate` RPC call.
+pub const DAA_TIMESTAMP_BATCH: usize = 1000;
+
+/// Result of the transaction record timestamp backfill.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimestampBackfill {
+    /// Number of records updated with the resolved timestamp.
+    pub resolved: u64,
+    /// Number of records whose timestamp could not be resolved.
+    pub unresolved: u64,
+}
+
+impl Wallet {
+    /// Resolves the timestamps of the supplied DAA scores using the DAA
+    /// timestamp cache of the network, querying the node for the scores
+    /// missing from the cache. DAA scores that could not be resolved
+    /// (the node does not support the estimate or the RPC call failed)
+    /// are absent from the returned map.
+    pub async fn resolve_daa_timestamps(&self, network_id: &NetworkId, daa_scores: &[u64]) -> Result<HashMap<u64, u64>> {
+        let store = self.store().as_transaction_record_store()?;
+        let mut cache = store.load_daa_timestamps(network_id).await?.unwrap_or_default();
+
+        let mut timestamps = HashMap::new();
+        let mut unresolved = Vec::new();
+        for daa_score in daa_scores {
+            match cache.get(*daa_score) {
+                Some(unixtime_msec) => {
+                    timestamps.insert(*daa_score, unixtime_msec);
+                }
+                None => unresolved.push(*daa_score),
+            }
+        }
+        unresolved.sort_unstable();
+        unresolved.dedup();
+
+        if unresolved.is_empty() || !self.utxo_processor().supports(NodeFeature::DaaScoreTimestampEstimate) {
+            return Ok(timestamps);
+        }
+
+        for chunk in unresolved.chunks(DAA_TIMESTAMP_BATCH) {
+            match self.rpc_api().get_daa_score_timestamp_estimate(chunk.to_vec()).await {
+                Ok(estimates) if estimates.len() == chunk.len() => {
+                    for (daa_score, unixtime_msec) in chunk.iter().copied().zip(estimates) {
+                        cache.insert(daa_score, unixtime_msec);
+                        timestamps.insert(daa_score, unixtime_msec);
+                    }
+                }
+                Ok(estimates) => {
+                    log!(
+                        Wallet,
+                        Warn,
+                        "Unable to resolve DAA to unixtime: expected {} timestamps, received {}",
+                        chunk.len(),
+                        estimates.len()
+                    );
+                    break;
+                }
+                Err(err) => {
+                    log!(Wallet, Warn, "Unable to resolve DAA to unixtime: {err}");
+                    break;
+                }
+            }
+        }
+
+        if cache.is_modified() {
+            store.store_daa_timestamps(network_id, &cache).await?;
+        }
+
+        Ok(timestamps)
+    }
+
+    /// Backfills the timestamps of the supplied transaction records that
+    /// were stored without one, storing the updated records. Returns the
+    /// updated records and the number of records left unresolved.
+    pub async fn backfill_record_timestamps(
+        &self,
+        network_id: &NetworkId,
+        records: &[Arc<TransactionRecord>],
+    ) -> Result<(Vec<TransactionRecord>, u64)> {
+        let daa_scores = records
+            .iter()
+            .filter(|record| record.unixtime_msec().is_none())
+            .map(|record| record.block_daa_score())
+            .collect::<Vec<_>>();
+        if daa_scores.is_empty() {
+            return Ok((vec![], 0));
+        }
+
+        let timestamps = self.resolve_daa_timestamps(network_id, &daa_scores).await?;
+
+        let mut unresolved = 0;
+        let mut updated = Vec::new();
+        for record in records.iter().filter(|record| record.unixtime_msec().is_none()) {
+            if let Some(unixtime_msec) = timestamps.get(&record.block_daa_score()) {
+                let mut record = (**record).clone();
+                record.set_unixtime(*unixtime_msec);
+                updated.push(record);
+            } else {
+                unresolved += 1;
+            }
+        }
+
+        if !updated.is_empty() {
+            let store = self.store().as_transaction_record_store()?;
+            store.store(&updated.iter().collect::<Vec<_>>()).await?;
+        }
+
+        Ok((updated, unresolved))
+    }
+
+    /// Backfills the timestamps of all transaction records of the binding
+    /// that were stored without one.
+    pub async fn backfill_transaction_timestamps(&self, binding: &Binding, network_id: &NetworkId) -> Result<TimestampBackfill> {
+        let store = self.store().as_transaction_record_store()?;
+        let records = match store.transaction_data_iter(binding, network_id).await {
+            Ok(records) => {
+                records.try_filter(|record| futures::future::ready(record.unixtime_msec().is_none())).try_collect::<Vec<_>>().await?
+            }
+            Err(Error::NoRecordsFound) => return Ok(TimestampBackfill::default()),
+            Err(err) => return Err(err),
+        };
+
+        let (updated, unresolved) = self.backfill_record_timestamps(network_id, &records).await?;
+        Ok(TimestampBackfill { resolved: updated.len() as u64, unresolved })
+    }
+
+    /// Backfills the transaction record timestamps of the supplied accounts
+    /// in a background task. Errors are logged as the backfill is retried
+    /// on the next account activation.
+    pub(crate) fn spawn_timestamp_backfill(self: &Arc<Self>, ids: Vec<AccountId>) {
+        let Ok(network_id) = self.network_id() else {
+            return;
+        };
+
+        let this = self.clone();
+        spawn(async move {
+            for id in ids {
+                match this.backfill_transaction_timestamps(&Binding::Account(id), &network_id).await {
+                    Ok(TimestampBackfill { resolved, unresolved }) if resolved > 0 || unresolved > 0 => {
+                        log!(Wallet, Info, "Account {id}: resolved {resolved} transaction timestamps ({unresolved} unresolved)");
+                    }
+                    Ok(_) => {}
+                    Err(err) => {
+                        log!(Wallet, Warn, "Account {id}: unable to backfill transaction timestamps: {err}");
+                    }
+                }
+            }
+        });
+    }
+}
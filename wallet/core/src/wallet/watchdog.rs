@@ -0,0 +1,248 @@
+//!
+//! Watchdog monitoring long-running wallet operations.
+//!
+//! Each monitored operation (account scan, send, transfer, discovery) is
+//! registered with the [`Watchdog`] for the duration of its execution.
+//! If the operation exceeds the deadline configured for its [`TaskKind`],
+//! the watchdog emits [`Events::TaskDeadline`] allowing the client to
+//! cancel the operation via [`Watchdog::abort()`] (or the `tasks_abort`
+//! Wallet API call). Aborting a task resolves the operation with
+//! [`Error::Aborted`] even if it is blocked on an unresponsive RPC call.
+//!
+
+use crate::imports::*;
+use crate::logging::log;
+use std::future::Future;
+use workflow_core::abortable::Abortable;
+use workflow_core::channel::Receiver;
+
+pub type TaskId = u64;
+
+/// Kind of the long-running operation monitored by the [`Watchdog`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TaskKind {
+    /// Account UTXO scan (performed during account activation)
+    Scan,
+    /// Transaction generation and submission to external addresses
+    Send,
+    /// Transaction generation and submission between wallet accounts
+    Transfer,
+    /// BIP44 account discovery
+    Discovery,
+}
+
+impl TaskKind {
+    /// Default deadline for the task kind.
+    pub fn default_deadline(&self) -> Duration {
+        match self {
+            TaskKind::Scan => Duration::from_secs(10 * 60),
+            TaskKind::Send => Duration::from_secs(2 * 60),
+            TaskKind::Transfer => Duration::from_secs(2 * 60),
+            TaskKind::Discovery => Duration::from_secs(10 * 60),
+        }
+    }
+}
+
+impl std::fmt::Display for TaskKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            TaskKind::Scan => "scan",
+            TaskKind::Send => "send",
+            TaskKind::Transfer => "transfer",
+            TaskKind::Discovery => "discovery",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Information about a running task monitored by the [`Watchdog`].
+#[derive(Debug, Clone, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskDescriptor {
+    pub id: TaskId,
+    pub kind: TaskKind,
+    pub account_id: Option<AccountId>,
+    /// Time elapsed since the task start (in milliseconds)
+    pub elapsed: u64,
+    /// Task deadline (in milliseconds), `None` if the task is not subject to a deadline
+    pub deadline: Option<u64>,
+}
+
+struct Task {
+    kind: TaskKind,
+    account_id: Option<AccountId>,
+    started: Instant,
+    deadline: Option<Duration>,
+    abortable: Abortable,
+    abort: Channel<()>,
+}
+
+impl Task {
+    fn descriptor(&self, id: TaskId) -> TaskDescriptor {
+        TaskDescriptor {
+            id,
+            kind: self.kind,
+            account_id: self.account_id,
+            elapsed: self.started.elapsed().as_millis() as u64,
+            deadline: self.deadline.map(|deadline| deadline.as_millis() as u64),
+        }
+    }
+
+    fn abort(&self) {
+        self.abortable.abort();
+        self.abort.try_send(()).ok();
+    }
+}
+
+struct Inner {
+    multiplexer: Multiplexer<Box<Events>>,
+    deadlines: Mutex<AHashMap<TaskKind, Option<Duration>>>,
+    tasks: Mutex<AHashMap<TaskId, Task>>,
+    next_id: AtomicU64,
+}
+
+/// Watchdog monitoring long-running wallet operations against
+/// configurable deadlines and providing cancellation handles.
+#[derive(Clone)]
+pub struct Watchdog {
+    inner: Arc<Inner>,
+}
+
+impl Watchdog {
+    pub fn new(multiplexer: Multiplexer<Box<Events>>) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                multiplexer,
+                deadlines: Mutex::new(AHashMap::new()),
+                tasks: Mutex::new(AHashMap::new()),
+                next_id: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// Returns the deadline configured for the given task kind.
+    pub fn deadline(&self, kind: TaskKind) -> Option<Duration> {
+        self.inner.deadlines.lock().unwrap().get(&kind).cloned().unwrap_or_else(|| Some(kind.default_deadline()))
+    }
+
+    /// Set the deadline for the given task kind. Supplying `None`
+    /// disables deadline monitoring for this task kind.
+    pub fn set_deadline(&self, kind: TaskKind, deadline: Option<Duration>) {
+        self.inner.deadlines.lock().unwrap().insert(kind, deadline);
+    }
+
+    /// Returns descriptors of all currently running tasks.
+    pub fn tasks(&self) -> Vec<TaskDescriptor> {
+        let mut tasks = self.inner.tasks.lock().unwrap().iter().map(|(id, task)| task.descriptor(*id)).collect::<Vec<_>>();
+        tasks.sort_by_key(|task| task.id);
+        tasks
+    }
+
+    /// Abort the task with the given id.
+    pub fn abort(&self, id: TaskId) -> Result<()> {
+        self.inner.tasks.lock().unwrap().get(&id).ok_or(Error::TaskNotFound(id))?.abort();
+        Ok(())
+    }
+
+    /// Abort all currently running tasks.
+    pub fn abort_all(&self) {
+        self.inner.tasks.lock().unwrap().values().for_each(|task| task.abort());
+    }
+
+    fn register(
+        &self,
+        kind: TaskKind,
+        account_id: Option<AccountId>,
+        deadline: Option<Duration>,
+        abortable: &Abortable,
+    ) -> (TaskGuard<'_>, Receiver<()>) {
+        let id = self.inner.next_id.fetch_add(1, Ordering::SeqCst);
+        let abort = Channel::oneshot();
+        let receiver = abort.receiver.clone();
+        let task = Task { kind, account_id, started: Instant::now(), deadline, abortable: abortable.clone(), abort };
+        self.inner.tasks.lock().unwrap().insert(id, task);
+        (TaskGuard { watchdog: self, id }, receiver)
+    }
+
+    fn notify_deadline(&self, id: TaskId) {
+        if let Some(task) = self.inner.tasks.lock().unwrap().get(&id).map(|task| task.descriptor(id)) {
+            log!(Wallet, Warn, "Wallet {} task {} has exceeded its deadline ({} msec elapsed)", task.kind, task.id, task.elapsed);
+            self.inner.multiplexer.try_broadcast(Box::new(Events::TaskDeadline { task })).ok();
+        }
+    }
+
+    /// Execute `future` as a monitored task. The supplied `abortable` is
+    /// triggered if the task is aborted, signaling the cancellation to
+    /// the operation (e.g. the transaction [`Generator`](crate::tx::Generator)).
+    pub async fn run<T, F>(&self, kind: TaskKind, account_id: Option<AccountId>, abortable: &Abortable, future: F) -> Result<T>
+    where
+        F: Future<Output = Result<T>>,
+    {
+        let deadline = self.deadline(kind);
+        let (guard, abort) = self.register(kind, account_id, deadline, abortable);
+
+        let mut future = Box::pin(future.fuse());
+        let mut deadline = Box::pin(
+            async move {
+                match deadline {
+                    Some(deadline) => sleep(deadline).await,
+                    None => futures::future::pending().await,
+                }
+            }
+            .fuse(),
+        );
+
+        loop {
+            select! {
+                result = future => break result,
+                _ = abort.recv().fuse() => break Err(Error::Aborted),
+                _ = deadline => self.notify_deadline(guard.id),
+            }
+        }
+    }
+}
+
+/// Removes the task from the [`Watchdog`] when the monitored
+/// operation completes or its future is dropped.
+struct TaskGuard<'a> {
+    watchdog: &'a Watchdog,
+    id: TaskId,
+}
+
+impl Drop for TaskGuard<'_> {
+    fn drop(&mut self) {
+        self.watchdog.inner.tasks.lock().unwrap().remove(&self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_watchdog_abort() -> Result<()> {
+        let multiplexer = Multiplexer::<Box<Events>>::new();
+        let events = multiplexer.channel();
+        let watchdog = Watchdog::new(multiplexer);
+        watchdog.set_deadline(TaskKind::Send, Some(Duration::from_millis(10)));
+
+        let abortable = Abortable::new();
+        let task = watchdog.run(TaskKind::Send, None, &abortable, futures::future::pending::<Result<()>>());
+
+        let control = async {
+            let event = events.receiver.recv().await.unwrap();
+            let Events::TaskDeadline { task } = *event else { panic!("unexpected event") };
+            assert_eq!(task.kind, TaskKind::Send);
+            assert_eq!(watchdog.tasks().len(), 1);
+            watchdog.abort(task.id).unwrap();
+        };
+
+        let (result, _) = futures::join!(task, control);
+        assert!(matches!(result, Err(Error::Aborted)));
+        assert!(abortable.is_aborted());
+        assert!(watchdog.tasks().is_empty());
+
+        Ok(())
+    }
+}
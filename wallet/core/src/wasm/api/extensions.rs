@@ -1,5 +1,7 @@
 use crate::imports::*;
 use js_sys::Object;
+use kaspa_consensus_client::TransactionOutpointInner;
+use kaspa_consensus_core::tx::TransactionOutpoint;
 use kaspa_consensus_core::Hash;
 
 pub trait WalletApiObjectExtension {
@@ -11,6 +13,8 @@ pub trait WalletApiObjectExtension {
     fn get_account_id(&self, key: &str) -> Result<AccountId>;
     fn try_get_account_id_list(&self, key: &str) -> Result<Option<Vec<AccountId>>>;
     fn get_transaction_id(&self, key: &str) -> Result<Hash>;
+    fn try_get_outpoint_list(&self, key: &str) -> Result<Option<Vec<TransactionOutpoint>>>;
+    fn try_get_u32(&self, key: &str) -> Result<Option<u32>>;
 }
 
 impl WalletApiObjectExtension for Object {
@@ -69,4 +73,26 @@ impl WalletApiObjectExtension for Object {
             Ok(None)
         }
     }
+
+    fn try_get_outpoint_list(&self, key: &str) -> Result<Option<Vec<TransactionOutpoint>>> {
+        if let Ok(array) = self.get_vec(key) {
+            let outpoints = array
+                .into_iter()
+                .map(|js_value| {
+                    let TransactionOutpointInner { transaction_id, index } = TransactionOutpointInner::try_from(&js_value)?;
+                    Ok(TransactionOutpoint::new(transaction_id, index))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Some(outpoints))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn try_get_u32(&self, key: &str) -> Result<Option<u32>> {
+        match self.try_get_value(key)?.filter(|value| !value.is_null()) {
+            Some(value) => Ok(Some(value.as_f64().ok_or(Error::InvalidArgument(key.to_string()))? as u32)),
+            None => Ok(None),
+        }
+    }
 }
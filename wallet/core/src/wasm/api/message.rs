@@ -3,10 +3,11 @@
 use super::extensions::*;
 use crate::account::descriptor::IAccountDescriptor;
 use crate::api::message::*;
+use crate::derivation::DerivationPathTemplate;
 use crate::imports::*;
-use crate::tx::{Fees, PaymentDestination, PaymentOutputs};
+use crate::tx::{ChangePolicy, Fees, PaymentDestination, PaymentOutputs};
 use crate::wasm::tx::fees::IFees;
-use crate::wasm::tx::GeneratorSummary;
+use crate::wasm::tx::{GeneratorSummary, ITransactionValidation};
 use js_sys::Array;
 use serde_wasm_bindgen::from_value;
 use workflow_wasm::serde::to_value;
@@ -34,75 +35,157 @@ const TS_CATEGORY_WALLET: &'static str = r#"
 
 // ---
 
-// declare! {
-//     IPingRequest,
-//     r#"
-//     /**
-//      *
-//      */
-//     export interface IPingRequest {
-//         message?: string;
-//     }
-//     "#,
-// }
+declare! {
+    IPingRequest,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IPingRequest {
+        message?: string;
+    }
+    "#,
+}
 
-// try_from! ( args: IPingRequest, PingRequest, {
-//     let message = args.try_get_string("message")?;
-//     Ok(PingRequest { message })
-// });
+try_from! ( args: IPingRequest, PingRequest, {
+    let message = args.try_get_string("message")?;
+    Ok(PingRequest { message })
+});
 
-// declare! {
-//     IPingResponse,
-//     r#"
-//     /**
-//      *
-//      */
-//     export interface IPingResponse {
-//         message?: string;
-//     }
-//     "#,
-// }
+declare! {
+    IPingResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IPingResponse {
+        message?: string;
+    }
+    "#,
+}
 
-// try_from! ( args: PingResponse, IPingResponse, {
-//     let response = IPingResponse::default();
-//     if let Some(message) = args.message {
-//         response.set("message", &JsValue::from_str(&message))?;
-//     }
-//     Ok(response)
-// });
+try_from! ( args: PingResponse, IPingResponse, {
+    let response = IPingResponse::default();
+    if let Some(message) = args.message {
+        response.set("message", &JsValue::from_str(&message))?;
+    }
+    Ok(response)
+});
 
 // ---
 
+#[wasm_bindgen(typescript_custom_section)]
+const TS_BATCH_ITEMS: &'static str = r#"
+/**
+ * Request executed as a part of the {@link IBatchRequest}.
+ *
+ * @category Wallet API
+ */
+export interface IBatchRequestItem {
+    /**
+     * Name of the {@link Wallet} method (e.g. `accountsGet`).
+     */
+    op : string;
+    /**
+     * Argument of the {@link Wallet} method (e.g. {@link IAccountsGetRequest}).
+     */
+    request : any;
+}
+
+/**
+ * Result of the {@link IBatchRequestItem} execution, containing
+ * either the response or the error produced by the request.
+ *
+ * @category Wallet API
+ */
+export interface IBatchResult {
+    /**
+     * Name of the {@link Wallet} method (available if the request succeeded).
+     */
+    op? : string;
+    /**
+     * Return value of the {@link Wallet} method (e.g. {@link IAccountsGetResponse}).
+     */
+    response? : any;
+    /**
+     * Error produced by the request carrying the {@link WalletErrorCode} as `code`.
+     */
+    error? : Error;
+}
+"#;
+
 declare! {
     IBatchRequest,
     r#"
     /**
-     * Suspend storage operations until invocation of flush().
+     * Executes multiple requests within a single call. Mutating requests are
+     * executed sequentially, consecutive read-only requests are executed
+     * concurrently. If no requests are supplied, suspends storage operations
+     * until invocation of flush().
      * 
      * @category Wallet API
      */
-    export interface IBatchRequest { }
+    export interface IBatchRequest {
+        requests? : IBatchRequestItem[];
+    }
     "#,
 }
 
-try_from! ( _args: IBatchRequest, BatchRequest, {
-    Ok(BatchRequest { })
+try_from! ( args: IBatchRequest, BatchRequest, {
+    let requests = match args.try_get_value("requests")? {
+        Some(requests) => Array::from(&requests)
+            .iter()
+            .map(|item| {
+                let item = Object::from(item);
+                let op = item.get_string("op")?;
+                super::wallet_request_from_js(&op, item.get_value("request")?)
+            })
+            .collect::<Result<Vec<_>>>()?,
+        None => vec![],
+    };
+    Ok(BatchRequest { requests })
 });
 
 declare! {
     IBatchResponse,
     r#"
     /**
-     * 
+     * Results of the executed requests (in the order of the requests).
      * 
      * @category Wallet API
      */
-    export interface IBatchResponse { }
+    export interface IBatchResponse {
+        responses : IBatchResult[];
+    }
     "#,
 }
 
-try_from! ( _args: BatchResponse, IBatchResponse, {
-    Ok(IBatchResponse::default())
+try_from! ( args: BatchResponse, IBatchResponse, {
+    let responses = args
+        .responses
+        .into_iter()
+        .map(|result| {
+            let item = Object::new();
+            if let Some(response) = result.response {
+                let (op, response) = super::wallet_response_to_js(response)?;
+                item.set("op", &op.into())?;
+                item.set("response", &response)?;
+            }
+            if let Some(error) = result.error {
+                let js_error = js_sys::Error::new(&error.message);
+                js_error.set("code", &error.code.into())?;
+                item.set("error", &js_error.into())?;
+            }
+            Ok(item.into())
+        })
+        .collect::<Result<Vec<JsValue>>>()?;
+    let response = IBatchResponse::default();
+    response.set("responses", &Array::from_iter(responses).into())?;
+    Ok(response)
 });
 
 // ---
@@ -251,6 +334,11 @@ declare! {
     export interface IGetStatusResponse {
         isConnected : boolean;
         isSynced : boolean;
+        /**
+         * Progress of the wallet synchronization.
+         * @see {@link IWalletSyncEvent}
+         */
+        walletSync : IWalletSyncProgress;
         isOpen : boolean;
         url? : string;
         networkId? : NetworkId;
@@ -260,10 +348,11 @@ declare! {
 }
 
 try_from! ( args: GetStatusResponse, IGetStatusResponse, {
-    let GetStatusResponse { is_connected, is_synced, is_open, url, network_id, .. } = args;
+    let GetStatusResponse { is_connected, is_synced, wallet_sync, is_open, url, network_id, .. } = args;
     let response = IGetStatusResponse::default();
     response.set("isConnected", &is_connected.into())?;
     response.set("isSynced", &is_synced.into())?;
+    response.set("walletSync", &to_value(&wallet_sync)?)?;
     response.set("isOpen", &is_open.into())?;
     if let Some(url) = url {
         response.set("url", &url.into())?;
@@ -276,6 +365,90 @@ try_from! ( args: GetStatusResponse, IGetStatusResponse, {
 
 // ---
 
+declare! {
+    IChangeNetworkIdRequest,
+    r#"
+    /**
+     * Change the network id of the wallet. The wallet must be
+     * disconnected from the RPC node while the network is changed.
+     *  
+     * @category Wallet API
+     */
+    export interface IChangeNetworkIdRequest {
+        networkId: NetworkId | string;
+    }
+    "#,
+}
+
+try_from! ( args: IChangeNetworkIdRequest, ChangeNetworkIdRequest, {
+    let network_id = args.get_network_id("networkId")?;
+    Ok(ChangeNetworkIdRequest { network_id })
+});
+
+declare! {
+    IChangeNetworkIdResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IChangeNetworkIdResponse { }
+    "#,
+}
+
+try_from! ( _args: ChangeNetworkIdResponse, IChangeNetworkIdResponse, {
+    Ok(IChangeNetworkIdResponse::default())
+});
+
+// ---
+
+declare! {
+    ISetLogLevelRequest,
+    r#"
+    /**
+     * Change the log level of a wallet subsystem (`target`)
+     * or the default log level if `target` is not specified.
+     *
+     * @category Wallet API
+     */
+    export interface ISetLogLevelRequest {
+        target? : "wallet" | "utxo" | "rpc-client" | "storage";
+        level : "off" | "error" | "warn" | "info" | "debug" | "trace";
+    }
+    "#,
+}
+
+try_from! ( args: ISetLogLevelRequest, SetLogLevelRequest, {
+    let target = args.try_get_string("target")?.map(|target| target.parse()).transpose()?;
+    let level = args.get_string("level")?.parse()?;
+    Ok(SetLogLevelRequest { target, level })
+});
+
+declare! {
+    ISetLogLevelResponse,
+    r#"
+    /**
+     * Logging configuration comprised of the default log `level`
+     * and per-target level overrides.
+     *
+     * @category Wallet API
+     */
+    export interface ISetLogLevelResponse {
+        config : {
+            level : string;
+            targets : Record<string, string>;
+        };
+    }
+    "#,
+}
+
+try_from! ( args: SetLogLevelResponse, ISetLogLevelResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
 declare! {
     IRetainContextRequest,
     r#"
@@ -391,7 +564,6 @@ declare! {
     "#,
 }
 
-// TODO
 try_from! ( args: IWalletCreateRequest, WalletCreateRequest, {
 
     let wallet_secret = args.get_secret("walletSecret")?;
@@ -407,6 +579,7 @@ try_from! ( args: IWalletCreateRequest, WalletCreateRequest, {
         user_hint,
         encryption_kind,
         overwrite_wallet_storage,
+        account_database: false,
     };
 
     Ok(WalletCreateRequest { wallet_secret, wallet_args })
@@ -557,6 +730,58 @@ try_from! ( _args: WalletReloadResponse, IWalletReloadResponse, {
 
 // ---
 
+declare! {
+    IWalletRenameRequest,
+    r#"
+    /**
+     * Change the wallet title or notes, or rename the wallet file.
+     * Properties that are not supplied are left unchanged.
+     * 
+     * @category Wallet API
+     */
+    export interface IWalletRenameRequest {
+        walletSecret: string;
+        title?: string;
+        /**
+         * Wallet notes (an empty string removes the notes).
+         */
+        notes?: string;
+        /**
+         * New wallet filename. Fails if a wallet with this filename already exists.
+         */
+        filename?: string;
+    }
+    "#,
+}
+
+try_from! ( args: IWalletRenameRequest, WalletRenameRequest, {
+    let wallet_secret = args.get_secret("walletSecret")?;
+    let title = args.try_get_string("title")?;
+    let notes = args.try_get_string("notes")?;
+    let filename = args.try_get_string("filename")?;
+    Ok(WalletRenameRequest { title, notes, filename, wallet_secret })
+});
+
+declare! {
+    IWalletRenameResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IWalletRenameResponse {
+        walletDescriptor: IWalletDescriptor;
+    }
+    "#,
+}
+
+try_from! ( args: WalletRenameResponse, IWalletRenameResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
 declare! {
     IWalletChangeSecretRequest,
     r#"
@@ -631,7 +856,6 @@ declare! {
     "#,
 }
 
-// TODO
 try_from! ( args: WalletExportResponse, IWalletExportResponse, {
     let response = IWalletExportResponse::default();
     response.set("walletData", &JsValue::from_str(&args.wallet_data.to_hex()))?;
@@ -679,6 +903,59 @@ try_from! ( _args: WalletImportResponse, IWalletImportResponse, {
 
 // ---
 
+declare! {
+    IWalletCheckRequest,
+    r#"
+    /**
+     * If `repair` is set, orphaned entries and invalid
+     * transaction records are removed from the wallet.
+     *
+     * @category Wallet API
+     */
+    export interface IWalletCheckRequest {
+        walletSecret: string;
+        repair?: boolean;
+    }
+    "#,
+}
+
+try_from! ( args: IWalletCheckRequest, WalletCheckRequest, {
+    let wallet_secret = args.get_secret("walletSecret")?;
+    let repair = args.try_get_bool("repair")?.unwrap_or(false);
+    Ok(WalletCheckRequest { wallet_secret, repair })
+});
+
+declare! {
+    IWalletCheckResponse,
+    r#"
+    /**
+     *
+     *
+     * @category Wallet API
+     */
+    export interface IWalletCheckResponse {
+        report: {
+            prvKeyData: number;
+            accounts: number;
+            metadata: number;
+            transactions: number;
+            issues: {
+                kind: string;
+                id: string;
+                description: string;
+                repaired: boolean;
+            }[];
+        };
+    }
+    "#,
+}
+
+try_from! ( args: WalletCheckResponse, IWalletCheckResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
 declare! {
     IPrvKeyDataEnumerateRequest,
     r#"
@@ -759,7 +1036,6 @@ try_from! ( args: IPrvKeyDataCreateRequest, PrvKeyDataCreateRequest, {
     Ok(PrvKeyDataCreateRequest { wallet_secret, prv_key_data_args })
 });
 
-// TODO
 declare! {
     IPrvKeyDataCreateResponse,
     r#"
@@ -813,7 +1089,6 @@ declare! {
     "#,
 }
 
-// TODO
 try_from! ( _args: PrvKeyDataRemoveResponse, IPrvKeyDataRemoveResponse, {
     Ok(IPrvKeyDataRemoveResponse::default())
 });
@@ -850,16 +1125,83 @@ declare! {
      * @category Wallet API
      */
     export interface IPrvKeyDataGetResponse {
-        // prvKeyData: PrvKeyData,
+        /**
+         * Private key data information (the key material
+         * is never exposed to JavaScript). Absent if the
+         * private key data was not found.
+         */
+        prvKeyDataInfo?: IPrvKeyDataInfo;
+    }
+    "#,
+}
+
+try_from! ( args: PrvKeyDataGetResponse, IPrvKeyDataGetResponse, {
+    let response = IPrvKeyDataGetResponse::default();
+    if let Some(prv_key_data) = args.prv_key_data {
+        response.set("prvKeyDataInfo", &to_value(&PrvKeyDataInfo::from(&prv_key_data))?)?;
+    }
+    Ok(response)
+});
+
+// ---
+
+declare! {
+    IPrvKeyDataChangeSecretRequest,
+    r#"
+    /**
+     * Re-encrypt private key data with a new payment secret. The payment secret
+     * of BIP39 mnemonics is the BIP39 passphrase and can not be changed.
+     *
+     * @category Wallet API
+     */
+    export interface IPrvKeyDataChangeSecretRequest {
+        walletSecret: string;
+        /**
+         * Private key to re-encrypt (all private keys if not specified).
+         */
+        prvKeyDataId?: HexString;
+        oldPaymentSecret?: string;
+        /**
+         * New payment secret (the payment secret is removed if not specified).
+         */
+        newPaymentSecret?: string;
+    }
+    "#,
+}
+
+try_from! ( args: IPrvKeyDataChangeSecretRequest, PrvKeyDataChangeSecretRequest, {
+    let wallet_secret = args.get_secret("walletSecret")?;
+    let prv_key_data_id = args.try_get_prv_key_data_id("prvKeyDataId")?;
+    let old_payment_secret = args.try_get_secret("oldPaymentSecret")?;
+    let new_payment_secret = args.try_get_secret("newPaymentSecret")?;
+    Ok(PrvKeyDataChangeSecretRequest { wallet_secret, prv_key_data_id, old_payment_secret, new_payment_secret })
+});
+
+declare! {
+    IPrvKeyDataChangeSecretResponse,
+    r#"
+    /**
+     *
+     *
+     * @category Wallet API
+     */
+    export interface IPrvKeyDataChangeSecretResponse {
+        report : {
+            /**
+             * Private keys re-encrypted with the new payment secret.
+             */
+            prvKeyDataIds : HexString[];
+            /**
+             * Location of the backup of the wallet file preceding the change.
+             */
+            backup? : string;
+        };
     }
     "#,
 }
 
-// TODO
-try_from! ( _args: PrvKeyDataGetResponse, IPrvKeyDataGetResponse, {
-    todo!();
-    // let response = IPrvKeyDataGetResponse::default();
-    // Ok(response)
+try_from! ( args: PrvKeyDataChangeSecretResponse, IPrvKeyDataChangeSecretResponse, {
+    Ok(to_value(&args)?.into())
 });
 
 // ---
@@ -872,12 +1214,18 @@ declare! {
      * 
      * @category Wallet API
      */
-    export interface IAccountsEnumerateRequest { }
+    export interface IAccountsEnumerateRequest {
+        /**
+         * Include archived accounts (default: false)
+         */
+        includeArchived?: boolean;
+    }
     "#,
 }
 
-try_from!(_args: IAccountsEnumerateRequest, AccountsEnumerateRequest, {
-    Ok(AccountsEnumerateRequest { })
+try_from!(args: IAccountsEnumerateRequest, AccountsEnumerateRequest, {
+    let include_archived = args.try_get_bool("includeArchived")?.unwrap_or(false);
+    Ok(AccountsEnumerateRequest { include_archived })
 });
 
 declare! {
@@ -894,7 +1242,6 @@ declare! {
     "#,
 }
 
-// TODO
 try_from! ( args: AccountsEnumerateResponse, IAccountsEnumerateResponse, {
     let response = IAccountsEnumerateResponse::default();
     let account_descriptors = args.account_descriptors.into_iter().map(IAccountDescriptor::try_from).collect::<Result<Vec<IAccountDescriptor>>>()?;
@@ -905,551 +1252,1376 @@ try_from! ( args: AccountsEnumerateResponse, IAccountsEnumerateResponse, {
 // ---
 
 declare! {
-    IAccountsRenameRequest,
+    IAccountsSetGapLimitRequest,
     r#"
     /**
-     * 
-     *  
+     * Set the address gap limit of an account (the number of consecutive
+     * unused addresses scanned past the last used address). If `gapLimit`
+     * is not supplied, the default gap limit is restored.
+     *
      * @category Wallet API
      */
-    export interface IAccountsRenameRequest {
+    export interface IAccountsSetGapLimitRequest {
         accountId: string;
-        name?: string;
-        walletSecret: string;
+        gapLimit?: number;
     }
     "#,
 }
 
-try_from! ( args: IAccountsRenameRequest, AccountsRenameRequest, {
+try_from! ( args: IAccountsSetGapLimitRequest, AccountsSetGapLimitRequest, {
     let account_id = args.get_account_id("accountId")?;
-    let name = args.try_get_string("name")?;
-    let wallet_secret = args.get_secret("walletSecret")?;
-    Ok(AccountsRenameRequest { account_id, name, wallet_secret })
+    let gap_limit = args.try_get_u32("gapLimit")?;
+    Ok(AccountsSetGapLimitRequest { account_id, gap_limit })
 });
 
 declare! {
-    IAccountsRenameResponse,
+    IAccountsSetGapLimitResponse,
     r#"
     /**
-     * 
-     *  
+     *
+     *
      * @category Wallet API
      */
-    export interface IAccountsRenameResponse { }
+    export interface IAccountsSetGapLimitResponse { }
     "#,
 }
 
-try_from! ( _args: AccountsRenameResponse, IAccountsRenameResponse, {
-    Ok(IAccountsRenameResponse::default())
+try_from! ( _args: AccountsSetGapLimitResponse, IAccountsSetGapLimitResponse, {
+    Ok(IAccountsSetGapLimitResponse::default())
 });
 
 // ---
 
-// TODO
 declare! {
-    IAccountsDiscoveryRequest,
+    IAccountsGetPendingIncomingRequest,
     r#"
     /**
-     * 
-     *  
+     * Query the node mempool for unconfirmed transactions sending
+     * to the account addresses.
+     *
      * @category Wallet API
      */
-    export interface IAccountsDiscoveryRequest {
-        discoveryKind: AccountsDiscoveryKind,
-        accountScanExtent: number,
-        addressScanExtent: number,
-        bip39_passphrase?: string,
-        bip39_mnemonic: string,
+    export interface IAccountsGetPendingIncomingRequest {
+        accountId : HexString;
     }
     "#,
 }
 
-// TODO
-try_from! (args: IAccountsDiscoveryRequest, AccountsDiscoveryRequest, {
-
-    let discovery_kind = args.get_value("discoveryKind")?;
-    let discovery_kind = if let Some(discovery_kind) = discovery_kind.as_string() {
-        discovery_kind.parse()?
-    } else {
-        AccountsDiscoveryKind::try_cast_from(&discovery_kind)?
-    };
-    let account_scan_extent = args.get_u32("accountScanExtent")?;
-    let address_scan_extent = args.get_u32("addressScanExtent")?;
-    let bip39_passphrase = args.try_get_secret("bip39_passphrase")?;
-    let bip39_mnemonic = args.get_secret("bip39_mnemonic")?;
-
-    Ok(AccountsDiscoveryRequest {
-        discovery_kind,
-        account_scan_extent,
-        address_scan_extent,
-        bip39_passphrase,
-        bip39_mnemonic,
-    })
+try_from! ( args: IAccountsGetPendingIncomingRequest, AccountsGetPendingIncomingRequest, {
+    Ok(from_value::<AccountsGetPendingIncomingRequest>(args.into())?)
 });
 
 declare! {
-    IAccountsDiscoveryResponse,
+    IAccountsGetPendingIncomingResponse,
     r#"
     /**
-     * 
-     *  
+     * Unconfirmed incoming amounts per account address. The total
+     * `amount` is reported as {@link IBalance.pendingIncoming} until
+     * the transactions are accepted.
+     *
      * @category Wallet API
      */
-    export interface IAccountsDiscoveryResponse {
-        lastAccountIndexFound : number;
+    export interface IAccountsGetPendingIncomingResponse {
+        incoming : IPendingIncoming[];
+        amount : bigint;
     }
     "#,
 }
 
-try_from! ( args: AccountsDiscoveryResponse, IAccountsDiscoveryResponse, {
+try_from! ( args: AccountsGetPendingIncomingResponse, IAccountsGetPendingIncomingResponse, {
     Ok(to_value(&args)?.into())
 });
 
 // ---
 
 declare! {
-    IAccountsCreateRequest,
+    IAccountsCreatePaymentRequestRequest,
     r#"
     /**
-     * 
-     *  
+     * Create a payment request for `amount` SOMPI tied to a new receive
+     * address of the account. The wallet emits the `payment-received`
+     * event once a transaction sending at least the requested amount
+     * to the address is received.
+     *
      * @category Wallet API
      */
-    export type IAccountsCreateRequest = {
-        walletSecret: string;
-        type: "bip32";
-        accountName:string;
-        accountIndex?:number;
-        prvKeyDataId:string;
-        paymentSecret?:string;
-    };
-    //   |{
-    //     walletSecret: string;
-    //     type: "multisig";
-    //     accountName:string;
-    //     accountIndex?:number;
-    //     prvKeyDataId:string;
-    //     pubkeys:HexString[];
-    //     paymentSecret?:string;
-    //   }
-
-    //   |{
-    //     walletSecret: string;
-    //     type: "bip32-readonly";
-    //     accountName:string;
-    //     accountIndex?:number;
-    //     pubkey:HexString;
-    //     paymentSecret?:string;
-    //  }
+    export interface IAccountsCreatePaymentRequestRequest {
+        accountId : HexString;
+        amount : bigint;
+        /**
+         * Time (in seconds) after which the request expires.
+         */
+        expirySeconds? : number;
+        label? : string;
+        message? : string;
+    }
     "#,
 }
 
-try_from! (args: IAccountsCreateRequest, AccountsCreateRequest, {
-    let wallet_secret = args.get_secret("walletSecret")?;
-
-    let kind = AccountKind::try_from(args.try_get_value("type")?.ok_or(Error::custom("type is required"))?)?;
-
-    if kind != crate::account::BIP32_ACCOUNT_KIND {
-        return Err(Error::custom("only BIP32 accounts are currently supported"));
-    }
-
-    let prv_key_data_args = PrvKeyDataArgs {
-        prv_key_data_id: args.try_get_prv_key_data_id("prvKeyDataId")?.ok_or(Error::custom("prvKeyDataId is required"))?,
-        payment_secret: args.try_get_secret("paymentSecret")?,
-    };
-
-    let account_args = AccountCreateArgsBip32 {
-        account_name: args.try_get_string("accountName")?,
-        account_index: args.get_u64("accountIndex").ok(),
-    };
-
-    let account_create_args = AccountCreateArgs::Bip32 { prv_key_data_args, account_args };
-
-    Ok(AccountsCreateRequest { wallet_secret, account_create_args })
+try_from! ( args: IAccountsCreatePaymentRequestRequest, AccountsCreatePaymentRequestRequest, {
+    let account_id = args.get_account_id("accountId")?;
+    let amount = args.get_u64("amount")?;
+    let expiry_seconds = args.try_get_u32("expirySeconds")?.map(u64::from);
+    let label = args.try_get_string("label")?;
+    let message = args.try_get_string("message")?;
+    Ok(AccountsCreatePaymentRequestRequest { account_id, amount, expiry_seconds, label, message })
 });
 
 declare! {
-    IAccountsCreateResponse,
+    IAccountsCreatePaymentRequestResponse,
     r#"
     /**
-     * 
-     *  
+     * Payment request and the `kaspa:` payment URI conveying
+     * the request to the payer.
+     *
      * @category Wallet API
      */
-    export interface IAccountsCreateResponse {
-        accountDescriptor : IAccountDescriptor;
+    export interface IAccountsCreatePaymentRequestResponse {
+        request : IPaymentRequest;
+        uri : string;
     }
     "#,
 }
 
-try_from!(args: AccountsCreateResponse, IAccountsCreateResponse, {
-    let response = IAccountsCreateResponse::default();
-    response.set("accountDescriptor", &IAccountDescriptor::try_from(args.account_descriptor)?.into())?;
-    Ok(response)
+try_from! ( args: AccountsCreatePaymentRequestResponse, IAccountsCreatePaymentRequestResponse, {
+    Ok(to_value(&args)?.into())
 });
 
 // ---
 
 declare! {
-    IAccountsEnsureDefaultRequest,
+    IAccountsGetPaymentRequestsRequest,
     r#"
     /**
-     * 
-     *  
+     * Return the payment requests of the account (pending, as well
+     * as recently fulfilled and expired requests).
+     *
      * @category Wallet API
      */
-    export interface IAccountsEnsureDefaultRequest {
-        walletSecret: string;
-        paymentSecret?: string;
-        type : AccountKind | string;
-        mnemonic? : string;
+    export interface IAccountsGetPaymentRequestsRequest {
+        accountId : HexString;
     }
     "#,
 }
 
-try_from! (args: IAccountsEnsureDefaultRequest, AccountsEnsureDefaultRequest, {
-    let wallet_secret = args.get_secret("walletSecret")?;
-    let payment_secret = args.try_get_secret("paymentSecret")?;
-    let account_kind = AccountKind::try_from(args.get_value("type")?)?;
-    let mnemonic_phrase = args.try_get_secret("mnemonic")?;
-
-    Ok(AccountsEnsureDefaultRequest { wallet_secret, payment_secret, account_kind, mnemonic_phrase })
+try_from! ( args: IAccountsGetPaymentRequestsRequest, AccountsGetPaymentRequestsRequest, {
+    Ok(from_value::<AccountsGetPaymentRequestsRequest>(args.into())?)
 });
 
 declare! {
-    IAccountsEnsureDefaultResponse,
+    IAccountsGetPaymentRequestsResponse,
     r#"
     /**
-     * 
-     *  
+     * Payment requests of the account (pending, fulfilled and expired).
+     *
      * @category Wallet API
      */
-    export interface IAccountsEnsureDefaultResponse {
-        accountDescriptor : IAccountDescriptor;
+    export interface IAccountsGetPaymentRequestsResponse {
+        requests : IPaymentRequest[];
     }
     "#,
 }
 
-try_from!(args: AccountsEnsureDefaultResponse, IAccountsEnsureDefaultResponse, {
-    let response = IAccountsEnsureDefaultResponse::default();
-    response.set("accountDescriptor", &IAccountDescriptor::try_from(args.account_descriptor)?.into())?;
-    Ok(response)
+try_from! ( args: AccountsGetPaymentRequestsResponse, IAccountsGetPaymentRequestsResponse, {
+    Ok(to_value(&args)?.into())
 });
 
 // ---
 
 declare! {
-    IAccountsImportRequest,
+    IAccountsRemovePaymentRequestRequest,
     r#"
     /**
-     * 
-     *  
+     * Cancel a payment request of the account.
+     *
      * @category Wallet API
      */
-    export interface IAccountsImportRequest {
-        walletSecret: string;
-        // TODO
+    export interface IAccountsRemovePaymentRequestRequest {
+        accountId : HexString;
+        requestId : HexString;
     }
     "#,
 }
 
-try_from! ( _args: IAccountsImportRequest, AccountsImportRequest, {
-    unimplemented!();
-    // Ok(AccountsImportRequest { })
+try_from! ( args: IAccountsRemovePaymentRequestRequest, AccountsRemovePaymentRequestRequest, {
+    Ok(from_value::<AccountsRemovePaymentRequestRequest>(args.into())?)
 });
 
 declare! {
-    IAccountsImportResponse,
+    IAccountsRemovePaymentRequestResponse,
     r#"
     /**
-     * 
-     *  
+     * The removed payment request (absent if the request was not found).
+     *
      * @category Wallet API
      */
-    export interface IAccountsImportResponse {
-        // TODO
+    export interface IAccountsRemovePaymentRequestResponse {
+        request? : IPaymentRequest;
     }
     "#,
 }
 
-try_from! ( _args: AccountsImportResponse, IAccountsImportResponse, {
-    unimplemented!();
-    // let response = IAccountsImportResponse::default();
-    // Ok(response)
+try_from! ( args: AccountsRemovePaymentRequestResponse, IAccountsRemovePaymentRequestResponse, {
+    Ok(to_value(&args)?.into())
 });
 
 // ---
 
 declare! {
-    IAccountsActivateRequest,
-    "IAccountsActivateRequest",
+    IAccountsAddressesRequest,
     r#"
     /**
-     * 
-     *  
+     * List the account addresses along with the activity
+     * observed on each address.
+     *
      * @category Wallet API
      */
-    export interface IAccountsActivateRequest {
-        accountIds?: HexString[],
+    export interface IAccountsAddressesRequest {
+        accountId : HexString;
     }
     "#,
 }
 
-try_from! (args: IAccountsActivateRequest, AccountsActivateRequest, {
-    Ok(from_value::<AccountsActivateRequest>(args.into())?)
+try_from! ( args: IAccountsAddressesRequest, AccountsAddressesRequest, {
+    Ok(from_value::<AccountsAddressesRequest>(args.into())?)
 });
 
 declare! {
-    IAccountsActivateResponse,
+    IAccountsAddressesResponse,
     r#"
     /**
-     * 
-     *  
+     * Account addresses (all derived receive and change addresses
+     * for derivation-capable accounts). Unused (fresh) addresses
+     * do not carry the `usage` property.
+     *
      * @category Wallet API
      */
-    export interface IAccountsActivateResponse { }
+    export interface IAccountsAddressesResponse {
+        addresses : {
+            address : string;
+            index? : number;
+            change : boolean;
+            usage? : IAddressUsage;
+        }[];
+    }
     "#,
 }
 
-try_from! ( _args: AccountsActivateResponse, IAccountsActivateResponse, {
-    Ok(IAccountsActivateResponse::default())
+try_from! ( args: AccountsAddressesResponse, IAccountsAddressesResponse, {
+    Ok(to_value(&args)?.into())
 });
 
 // ---
 
 declare! {
-    IAccountsDeactivateRequest,
-    "IAccountsDeactivateRequest",
+    IAccountsRenameRequest,
     r#"
     /**
      * 
      *  
      * @category Wallet API
      */
-    export interface IAccountsDeactivateRequest {
-        accountIds?: string[];
+    export interface IAccountsRenameRequest {
+        accountId: string;
+        name?: string;
+        walletSecret: string;
     }
     "#,
 }
 
-try_from! ( args: IAccountsDeactivateRequest, AccountsDeactivateRequest, {
-    Ok(from_value::<AccountsDeactivateRequest>(args.into())?)
+try_from! ( args: IAccountsRenameRequest, AccountsRenameRequest, {
+    let account_id = args.get_account_id("accountId")?;
+    let name = args.try_get_string("name")?;
+    let wallet_secret = args.get_secret("walletSecret")?;
+    Ok(AccountsRenameRequest { account_id, name, wallet_secret })
 });
 
 declare! {
-    IAccountsDeactivateResponse,
+    IAccountsRenameResponse,
     r#"
     /**
      * 
      *  
      * @category Wallet API
      */
-    export interface IAccountsDeactivateResponse { }
+    export interface IAccountsRenameResponse { }
     "#,
 }
 
-try_from! ( _args: AccountsDeactivateResponse, IAccountsDeactivateResponse, {
-    Ok(IAccountsDeactivateResponse::default())
+try_from! ( _args: AccountsRenameResponse, IAccountsRenameResponse, {
+    Ok(IAccountsRenameResponse::default())
 });
 
 // ---
 
 declare! {
-    IAccountsGetRequest,
+    IAccountsArchiveRequest,
     r#"
     /**
-     * 
+     * Archive accounts. Archived accounts are deactivated and excluded
+     * from account enumeration and activation while their data is
+     * retained in the wallet storage.
      *  
      * @category Wallet API
      */
-    export interface IAccountsGetRequest {
-        accountId: string;
+    export interface IAccountsArchiveRequest {
+        accountIds: HexString[];
+        walletSecret: string;
     }
     "#,
 }
 
-try_from! ( args: IAccountsGetRequest, AccountsGetRequest, {
-    Ok(from_value::<AccountsGetRequest>(args.into())?)
+try_from! ( args: IAccountsArchiveRequest, AccountsArchiveRequest, {
+    let account_ids = from_value::<Vec<AccountId>>(args.get_value("accountIds")?)?;
+    let wallet_secret = args.get_secret("walletSecret")?;
+    Ok(AccountsArchiveRequest { account_ids, wallet_secret })
 });
 
 declare! {
-    IAccountsGetResponse,
+    IAccountsArchiveResponse,
     r#"
     /**
      * 
      *  
      * @category Wallet API
      */
-    export interface IAccountsGetResponse {
-        accountDescriptor: IAccountDescriptor;
-    }
+    export interface IAccountsArchiveResponse { }
     "#,
 }
 
-try_from! ( args: AccountsGetResponse, IAccountsGetResponse, {
-    Ok(to_value(&args)?.into())
+try_from! ( _args: AccountsArchiveResponse, IAccountsArchiveResponse, {
+    Ok(IAccountsArchiveResponse::default())
 });
 
 // ---
 
 declare! {
-    IAccountsCreateNewAddressRequest,
+    IAccountsUnarchiveRequest,
     r#"
     /**
-     * 
+     * Restore archived accounts. Restored accounts are not activated.
      *  
      * @category Wallet API
      */
-    export interface IAccountsCreateNewAddressRequest {
-        accountId: string;
-        addressKind?: NewAddressKind | string,
+    export interface IAccountsUnarchiveRequest {
+        accountIds: HexString[];
+        walletSecret: string;
     }
     "#,
 }
 
-try_from!(args: IAccountsCreateNewAddressRequest, AccountsCreateNewAddressRequest, {
-    let account_id = args.get_account_id("accountId")?;
-    let value = args.get_value("addressKind")?;
-    let kind: NewAddressKind = if let Some(string) = value.as_string() {
-        string.parse()?
-    } else if let Ok(kind) = NewAddressKind::try_cast_from(&value) {
-        kind
-    } else {
-        NewAddressKind::Receive
-    };
-    Ok(AccountsCreateNewAddressRequest { account_id, kind })
+try_from! ( args: IAccountsUnarchiveRequest, AccountsUnarchiveRequest, {
+    let account_ids = from_value::<Vec<AccountId>>(args.get_value("accountIds")?)?;
+    let wallet_secret = args.get_secret("walletSecret")?;
+    Ok(AccountsUnarchiveRequest { account_ids, wallet_secret })
 });
 
 declare! {
-    IAccountsCreateNewAddressResponse,
+    IAccountsUnarchiveResponse,
     r#"
     /**
      * 
      *  
      * @category Wallet API
      */
-    export interface IAccountsCreateNewAddressResponse {
-        address: Address;
-    }
+    export interface IAccountsUnarchiveResponse { }
     "#,
 }
 
-try_from! ( args: AccountsCreateNewAddressResponse, IAccountsCreateNewAddressResponse, {
-    Ok(to_value(&args)?.into())
+try_from! ( _args: AccountsUnarchiveResponse, IAccountsUnarchiveResponse, {
+    Ok(IAccountsUnarchiveResponse::default())
 });
 
 // ---
 
 declare! {
-    IAccountsSendRequest,
+    IAccountsSelectRequest,
     r#"
     /**
-     * 
+     * Select the account used by the wallet as the current account.
+     * The selection is cleared if `accountId` is not supplied.
      *  
      * @category Wallet API
      */
-    export interface IAccountsSendRequest {
-        /**
-         * Hex identifier of the account.
-         */
-        accountId : HexString;
-        /**
-         * Wallet encryption secret.
-         */
-        walletSecret : string;
-        /**
-         * Optional key encryption secret or BIP39 passphrase.
-         */
-        paymentSecret? : string;
-        /**
-         * Priority fee.
-         */
-        priorityFeeSompi? : IFees | bigint;
-        /**
-         * 
-         */
-        payload? : Uint8Array | HexString;
-        /**
-         * If not supplied, the destination will be the change address resulting in a UTXO compound transaction.
-         */
-        destination? : IPaymentOutput[];
+    export interface IAccountsSelectRequest {
+        accountId?: HexString;
     }
     "#,
 }
 
-try_from! ( args: IAccountsSendRequest, AccountsSendRequest, {
-    let account_id = args.get_account_id("accountId")?;
-    let wallet_secret = args.get_secret("walletSecret")?;
-    let payment_secret = args.try_get_secret("paymentSecret")?;
-    let priority_fee_sompi = args.get::<IFees>("priorityFeeSompi")?.try_into()?;
-    let payload = args.try_get_value("payload")?.map(|v| v.try_as_vec_u8()).transpose()?;
-
-    let outputs = args.get_value("destination")?;
-    let destination: PaymentDestination =
-        if outputs.is_undefined() { PaymentDestination::Change } else { PaymentOutputs::try_owned_from(outputs)?.into() };
-
-    Ok(AccountsSendRequest { account_id, wallet_secret, payment_secret, priority_fee_sompi, destination, payload })
+try_from! ( args: IAccountsSelectRequest, AccountsSelectRequest, {
+    let account_id = args.try_get_value("accountId")?.map(|value| AccountId::try_from(&value)).transpose()?;
+    Ok(AccountsSelectRequest { account_id })
 });
 
 declare! {
-    IAccountsSendResponse,
+    IAccountsSelectResponse,
     r#"
     /**
      * 
      *  
      * @category Wallet API
      */
-    export interface IAccountsSendResponse {
-        /**
-         * Summary produced by the transaction generator.
-         */
-        generatorSummary : GeneratorSummary;
-        /**
-         * Hex identifiers of successfully submitted transactions.
-         */
-        transactionIds : HexString[];
-    }
+    export interface IAccountsSelectResponse { }
     "#,
 }
 
-try_from!(args: AccountsSendResponse, IAccountsSendResponse, {
-
-    let response = IAccountsSendResponse::default();
-    response.set("generatorSummary", &GeneratorSummary::from(args.generator_summary).into())?;
-    response.set("transactionIds", &to_value(&args.transaction_ids)?)?;
-    Ok(response)
+try_from! ( _args: AccountsSelectResponse, IAccountsSelectResponse, {
+    Ok(IAccountsSelectResponse::default())
 });
 
 // ---
 
 declare! {
-    IAccountsTransferRequest,
+    IAccountsDiscoveryRequest,
     r#"
     /**
-     * 
-     *  
+     * Account discovery request. Account indexes are scanned in
+     * ascending order until `accountScanExtent` consecutive accounts
+     * without UTXOs are encountered. Accounts are scanned either from
+     * a BIP-39 mnemonic (`bip39_mnemonic`) or from private key data
+     * stored in the wallet (`prvKeyDataId` and `walletSecret`).
+     * If `createAccounts` is set, wallet accounts are created for the
+     * discovered account indexes (requires `prvKeyDataId`) using the
+     * address `gapLimit` (if supplied).
+     *
      * @category Wallet API
      */
-    export interface IAccountsTransferRequest {
-        sourceAccountId : HexString;
-        destinationAccountId : HexString;
-        walletSecret : string;
-        paymentSecret? : string;
-        priorityFeeSompi? : IFees | bigint;
-        transferAmountSompi : bigint;
+    export interface IAccountsDiscoveryRequest {
+        discoveryKind: AccountsDiscoveryKind,
+        accountScanExtent: number,
+        addressScanExtent: number,
+        bip39_passphrase?: string,
+        bip39_mnemonic?: string,
+        prvKeyDataId?: HexString,
+        walletSecret?: string,
+        paymentSecret?: string,
+        createAccounts?: boolean,
+        gapLimit?: number,
     }
     "#,
 }
 
-try_from! ( args: IAccountsTransferRequest, AccountsTransferRequest, {
-    let source_account_id = args.get_account_id("sourceAccountId")?;
-    let destination_account_id = args.get_account_id("destinationAccountId")?;
-    let wallet_secret = args.get_secret("walletSecret")?;
-    let payment_secret = args.try_get_secret("paymentSecret")?;
-    let priority_fee_sompi = args.try_get::<IFees>("priorityFeeSompi")?.map(Fees::try_from).transpose()?;
-    let transfer_amount_sompi = args.get_u64("transferAmountSompi")?;
+try_from! (args: IAccountsDiscoveryRequest, AccountsDiscoveryRequest, {
 
-    Ok(AccountsTransferRequest {
+    let discovery_kind = args.get_value("discoveryKind")?;
+    let discovery_kind = if let Some(discovery_kind) = discovery_kind.as_string() {
+        discovery_kind.parse()?
+    } else {
+        AccountsDiscoveryKind::try_cast_from(&discovery_kind)?
+    };
+    let account_scan_extent = args.get_u32("accountScanExtent")?;
+    let address_scan_extent = args.get_u32("addressScanExtent")?;
+    let create_accounts = args.try_get_bool("createAccounts")?.unwrap_or(false);
+    let gap_limit = args.try_get_u32("gapLimit")?;
+
+    let source = if let Some(prv_key_data_id) = args.try_get_prv_key_data_id("prvKeyDataId")? {
+        let wallet_secret = args.get_secret("walletSecret")?;
+        let payment_secret = args.try_get_secret("paymentSecret")?;
+        AccountsDiscoverySource::PrvKeyData { wallet_secret, prv_key_data_id, payment_secret }
+    } else {
+        let bip39_passphrase = args.try_get_secret("bip39_passphrase")?;
+        let bip39_mnemonic = args.get_secret("bip39_mnemonic")?;
+        AccountsDiscoverySource::Mnemonic { bip39_mnemonic, bip39_passphrase }
+    };
+
+    Ok(AccountsDiscoveryRequest {
+        discovery_kind,
+        account_scan_extent,
+        address_scan_extent,
+        source,
+        create_accounts,
+        gap_limit,
+    })
+});
+
+declare! {
+    IAccountsDiscoveryResponse,
+    r#"
+    /**
+     * Account discovery response. `accounts` contains the accounts
+     * that hold UTXOs along with their balances (in SOMPI).
+     *
+     * @category Wallet API
+     */
+    export interface IAccountsDiscoveryResponse {
+        lastAccountIndexFound : number;
+        accounts : IDiscoveredAccount[];
+    }
+    "#,
+}
+
+declare! {
+    IDiscoveredAccount,
+    r#"
+    /**
+     * Account found during account discovery.
+     *
+     * @category Wallet API
+     */
+    export interface IDiscoveredAccount {
+        accountId : HexString;
+        accountIndex : bigint;
+        balance : bigint;
+        created : boolean;
+    }
+    "#,
+}
+
+try_from! ( args: AccountsDiscoveryResponse, IAccountsDiscoveryResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IAccountsCreateRequest,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export type IAccountsCreateRequest = {
+        walletSecret: string;
+        type: "bip32";
+        accountName:string;
+        accountIndex?:number;
+        prvKeyDataId:string;
+        paymentSecret?:string;
+    };
+    //   |{
+    //     walletSecret: string;
+    //     type: "multisig";
+    //     accountName:string;
+    //     accountIndex?:number;
+    //     prvKeyDataId:string;
+    //     pubkeys:HexString[];
+    //     paymentSecret?:string;
+    //   }
+
+    //   |{
+    //     walletSecret: string;
+    //     type: "bip32-readonly";
+    //     accountName:string;
+    //     accountIndex?:number;
+    //     pubkey:HexString;
+    //     paymentSecret?:string;
+    //  }
+    "#,
+}
+
+try_from! (args: IAccountsCreateRequest, AccountsCreateRequest, {
+    let wallet_secret = args.get_secret("walletSecret")?;
+
+    let kind = AccountKind::try_from(args.try_get_value("type")?.ok_or(Error::custom("type is required"))?)?;
+
+    if kind != crate::account::BIP32_ACCOUNT_KIND {
+        return Err(Error::custom("only BIP32 accounts are currently supported"));
+    }
+
+    let prv_key_data_args = PrvKeyDataArgs {
+        prv_key_data_id: args.try_get_prv_key_data_id("prvKeyDataId")?.ok_or(Error::custom("prvKeyDataId is required"))?,
+        payment_secret: args.try_get_secret("paymentSecret")?,
+    };
+
+    let account_args = AccountCreateArgsBip32 {
+        account_name: args.try_get_string("accountName")?,
+        account_index: args.get_u64("accountIndex").ok(),
+        derivation_path: args.try_get_value("derivationPath")?.map(|value| DerivationPathTemplate::try_from(&value)).transpose()?,
+        ecdsa: args.try_get_bool("ecdsa")?.unwrap_or(false),
+    };
+
+    let account_create_args = AccountCreateArgs::Bip32 { prv_key_data_args, account_args };
+
+    Ok(AccountsCreateRequest { wallet_secret, account_create_args })
+});
+
+declare! {
+    IAccountsCreateResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsCreateResponse {
+        accountDescriptor : IAccountDescriptor;
+    }
+    "#,
+}
+
+try_from!(args: AccountsCreateResponse, IAccountsCreateResponse, {
+    let response = IAccountsCreateResponse::default();
+    response.set("accountDescriptor", &IAccountDescriptor::try_from(args.account_descriptor)?.into())?;
+    Ok(response)
+});
+
+// ---
+
+declare! {
+    IAccountsEnsureDefaultRequest,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsEnsureDefaultRequest {
+        walletSecret: string;
+        paymentSecret?: string;
+        type : AccountKind | string;
+        mnemonic? : string;
+    }
+    "#,
+}
+
+try_from! (args: IAccountsEnsureDefaultRequest, AccountsEnsureDefaultRequest, {
+    let wallet_secret = args.get_secret("walletSecret")?;
+    let payment_secret = args.try_get_secret("paymentSecret")?;
+    let account_kind = AccountKind::try_from(args.get_value("type")?)?;
+    let mnemonic_phrase = args.try_get_secret("mnemonic")?;
+
+    Ok(AccountsEnsureDefaultRequest { wallet_secret, payment_secret, account_kind, mnemonic_phrase })
+});
+
+declare! {
+    IAccountsEnsureDefaultResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsEnsureDefaultResponse {
+        accountDescriptor : IAccountDescriptor;
+    }
+    "#,
+}
+
+try_from!(args: AccountsEnsureDefaultResponse, IAccountsEnsureDefaultResponse, {
+    let response = IAccountsEnsureDefaultResponse::default();
+    response.set("accountDescriptor", &IAccountDescriptor::try_from(args.account_descriptor)?.into())?;
+    Ok(response)
+});
+
+// ---
+
+declare! {
+    IAccountsImportRequest,
+    r#"
+    /**
+     * Legacy (KDX, kaspanet web wallet) account import request.
+     * The account is imported from the contents of a KDX keydata file
+     * or the kaspanet web wallet data (`keydata` decrypted using
+     * `importSecret`), from the legacy 12-word `mnemonic` or, if neither
+     * is supplied, from the keydata found in the local storage.
+     * If `sweep` is set, the account funds are transferred into the
+     * first BIP32 account of the same private key data.
+     *
+     * @category Wallet API
+     */
+    export interface IAccountsImportRequest {
+        walletSecret: string;
+        paymentSecret?: string;
+        importSecret?: string;
+        keydata?: string;
+        mnemonic?: string;
+        sweep?: boolean;
+    }
+    "#,
+}
+
+try_from! ( args: IAccountsImportRequest, AccountsImportRequest, {
+    let wallet_secret = args.get_secret("walletSecret")?;
+    let payment_secret = args.try_get_secret("paymentSecret")?;
+    let sweep = args.try_get_bool("sweep")?.unwrap_or(false);
+
+    let source = if let Some(mnemonic) = args.try_get_secret("mnemonic")? {
+        AccountsImportSource::LegacyMnemonic { mnemonic }
+    } else if let Some(keydata) = args.try_get_string("keydata")? {
+        let import_secret = args.get_secret("importSecret")?;
+        AccountsImportSource::LegacyKeydata { keydata, import_secret }
+    } else {
+        let import_secret = args.get_secret("importSecret")?;
+        AccountsImportSource::LegacyStorage { import_secret }
+    };
+
+    Ok(AccountsImportRequest { wallet_secret, payment_secret, source, sweep })
+});
+
+declare! {
+    IAccountsImportResponse,
+    r#"
+    /**
+     * Legacy account import response. If the funds have been swept,
+     * `sweepAccountDescriptor` describes the receiving BIP32 account.
+     *
+     * @category Wallet API
+     */
+    export interface IAccountsImportResponse {
+        accountDescriptor : IAccountDescriptor;
+        sweepAccountDescriptor? : IAccountDescriptor;
+        transactionIds : HexString[];
+    }
+    "#,
+}
+
+try_from! ( args: AccountsImportResponse, IAccountsImportResponse, {
+    let response = IAccountsImportResponse::default();
+    response.set("accountDescriptor", &IAccountDescriptor::try_from(args.account_descriptor)?.into())?;
+    if let Some(descriptor) = args.sweep_account_descriptor {
+        response.set("sweepAccountDescriptor", &IAccountDescriptor::try_from(descriptor)?.into())?;
+    }
+    response.set("transactionIds", &to_value(&args.transaction_ids)?)?;
+    Ok(response)
+});
+
+// ---
+
+declare! {
+    IAccountsActivateRequest,
+    "IAccountsActivateRequest",
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsActivateRequest {
+        accountIds?: HexString[],
+    }
+    "#,
+}
+
+try_from! (args: IAccountsActivateRequest, AccountsActivateRequest, {
+    Ok(from_value::<AccountsActivateRequest>(args.into())?)
+});
+
+declare! {
+    IAccountsActivateResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsActivateResponse { }
+    "#,
+}
+
+try_from! ( _args: AccountsActivateResponse, IAccountsActivateResponse, {
+    Ok(IAccountsActivateResponse::default())
+});
+
+// ---
+
+declare! {
+    IAccountsDeactivateRequest,
+    "IAccountsDeactivateRequest",
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsDeactivateRequest {
+        accountIds?: string[];
+    }
+    "#,
+}
+
+try_from! ( args: IAccountsDeactivateRequest, AccountsDeactivateRequest, {
+    Ok(from_value::<AccountsDeactivateRequest>(args.into())?)
+});
+
+declare! {
+    IAccountsDeactivateResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsDeactivateResponse { }
+    "#,
+}
+
+try_from! ( _args: AccountsDeactivateResponse, IAccountsDeactivateResponse, {
+    Ok(IAccountsDeactivateResponse::default())
+});
+
+// ---
+
+declare! {
+    IAccountsGetRequest,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsGetRequest {
+        accountId: string;
+    }
+    "#,
+}
+
+try_from! ( args: IAccountsGetRequest, AccountsGetRequest, {
+    Ok(from_value::<AccountsGetRequest>(args.into())?)
+});
+
+declare! {
+    IAccountsGetResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsGetResponse {
+        accountDescriptor: IAccountDescriptor;
+    }
+    "#,
+}
+
+try_from! ( args: AccountsGetResponse, IAccountsGetResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IAccountsExportXpubRequest,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsExportXpubRequest {
+        accountId: string;
+        walletSecret: string;
+        paymentSecret?: string;
+    }
+    "#,
+}
+
+try_from! ( args: IAccountsExportXpubRequest, AccountsExportXpubRequest, {
+    let account_id = args.get_account_id("accountId")?;
+    let wallet_secret = args.get_secret("walletSecret")?;
+    let payment_secret = args.try_get_secret("paymentSecret")?;
+    Ok(AccountsExportXpubRequest { account_id, wallet_secret, payment_secret })
+});
+
+declare! {
+    IAccountsExportXpubResponse,
+    r#"
+    /**
+     * The `proof` can be validated with {@link verifyXPubOwnershipProof}.
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsExportXpubResponse {
+        proof: IXPubOwnershipProof;
+    }
+    "#,
+}
+
+try_from! ( args: AccountsExportXpubResponse, IAccountsExportXpubResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IAccountsSignMessageRequest,
+    r#"
+    /**
+     * Sign a message with the key of an account address.
+     * Only `PubKey` (Schnorr) addresses are supported.
+     *
+     * @category Wallet API
+     */
+    export interface IAccountsSignMessageRequest {
+        accountId: string;
+        walletSecret: string;
+        paymentSecret?: string;
+        address: Address | string;
+        message: string;
+    }
+    "#,
+}
+
+try_from! ( args: IAccountsSignMessageRequest, AccountsSignMessageRequest, {
+    let account_id = args.get_account_id("accountId")?;
+    let wallet_secret = args.get_secret("walletSecret")?;
+    let payment_secret = args.try_get_secret("paymentSecret")?;
+    let address = Address::try_owned_from(args.get_value("address")?)?;
+    let message = args.get_string("message")?;
+    Ok(AccountsSignMessageRequest { account_id, wallet_secret, payment_secret, address, message })
+});
+
+declare! {
+    IAccountsSignMessageResponse,
+    r#"
+    /**
+     * The `signature` can be validated with {@link verifyMessage}
+     * or the {@link IAccountsVerifyMessageRequest} wallet API call.
+     *
+     * @category Wallet API
+     */
+    export interface IAccountsSignMessageResponse {
+        signature: HexString;
+    }
+    "#,
+}
+
+try_from! ( args: AccountsSignMessageResponse, IAccountsSignMessageResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IAccountsSignTransactionRequest,
+    r#"
+    /**
+     * Sign the inputs of a transaction that spend UTXOs of the account addresses.
+     * The `transaction` can be supplied as JSON produced by
+     * {@link Transaction.serializeToJSON} or {@link Transaction.serializeToSafeJSON},
+     * or as a hex-encoded Borsh serialization of the transaction and its UTXO entries.
+     *
+     * @category Wallet API
+     */
+    export interface IAccountsSignTransactionRequest {
+        accountId: string;
+        walletSecret: string;
+        paymentSecret?: string;
+        transaction: string;
+    }
+    "#,
+}
+
+try_from! ( args: IAccountsSignTransactionRequest, AccountsSignTransactionRequest, {
+    let account_id = args.get_account_id("accountId")?;
+    let wallet_secret = args.get_secret("walletSecret")?;
+    let payment_secret = args.try_get_secret("paymentSecret")?;
+    let transaction = args.get_string("transaction")?;
+    Ok(AccountsSignTransactionRequest { account_id, wallet_secret, payment_secret, transaction })
+});
+
+declare! {
+    IAccountsSignTransactionResponse,
+    r#"
+    /**
+     * The signed `transaction` is serialized using the encoding of the request.
+     * Inputs that do not belong to the account are left unsigned.
+     *
+     * @category Wallet API
+     */
+    export interface IAccountsSignTransactionResponse {
+        transaction: string;
+        encoding: "json" | "safe-json" | "hex";
+        status: {
+            inputs: number;
+            signedInputs: number;
+        };
+    }
+    "#,
+}
+
+try_from! ( args: AccountsSignTransactionResponse, IAccountsSignTransactionResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IAccountsVerifyMessageRequest,
+    r#"
+    /**
+     *
+     *
+     * @category Wallet API
+     */
+    export interface IAccountsVerifyMessageRequest {
+        address: Address | string;
+        message: string;
+        signature: HexString;
+    }
+    "#,
+}
+
+try_from! ( args: IAccountsVerifyMessageRequest, AccountsVerifyMessageRequest, {
+    let address = Address::try_owned_from(args.get_value("address")?)?;
+    let message = args.get_string("message")?;
+    let signature = args.get_string("signature")?;
+    Ok(AccountsVerifyMessageRequest { address, message, signature })
+});
+
+declare! {
+    IAccountsVerifyMessageResponse,
+    r#"
+    /**
+     *
+     *
+     * @category Wallet API
+     */
+    export interface IAccountsVerifyMessageResponse {
+        valid: boolean;
+    }
+    "#,
+}
+
+try_from! ( args: AccountsVerifyMessageResponse, IAccountsVerifyMessageResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IAccountsCreateNewAddressRequest,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsCreateNewAddressRequest {
+        accountId: string;
+        addressKind?: NewAddressKind | string,
+    }
+    "#,
+}
+
+try_from!(args: IAccountsCreateNewAddressRequest, AccountsCreateNewAddressRequest, {
+    let account_id = args.get_account_id("accountId")?;
+    let value = args.get_value("addressKind")?;
+    let kind: NewAddressKind = if let Some(string) = value.as_string() {
+        string.parse()?
+    } else if let Ok(kind) = NewAddressKind::try_cast_from(&value) {
+        kind
+    } else {
+        NewAddressKind::Receive
+    };
+    Ok(AccountsCreateNewAddressRequest { account_id, kind })
+});
+
+declare! {
+    IAccountsCreateNewAddressResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsCreateNewAddressResponse {
+        address: Address;
+        /**
+         * `true` if the number of unused receive addresses exceeds
+         * the account address gap limit (funds sent to the address
+         * may not be discovered when restoring the wallet).
+         */
+        beyondGapLimit: boolean;
+    }
+    "#,
+}
+
+try_from! ( args: AccountsCreateNewAddressResponse, IAccountsCreateNewAddressResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IAccountsSendRequest,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsSendRequest {
+        /**
+         * Hex identifier of the account.
+         */
+        accountId : HexString;
+        /**
+         * Wallet encryption secret.
+         */
+        walletSecret : string;
+        /**
+         * Optional key encryption secret or BIP39 passphrase.
+         */
+        paymentSecret? : string;
+        /**
+         * Priority fee. If not supplied, the wallet default
+         * priority fee for the current network is used.
+         */
+        priorityFeeSompi? : IFees | bigint;
+        /**
+         * 
+         */
+        payload? : Uint8Array | HexString;
+        /**
+         * If not supplied, the destination will be the change address resulting in a UTXO compound transaction.
+         * A single output with the amount `"all"` sends all spendable funds less the transaction fees
+         * (no change output is created).
+         */
+        destination? : IPaymentOutput[] | [{ address : Address | string, amount : "all" }];
+        /**
+         * Validate generated transactions against the node mempool acceptance
+         * rules without broadcasting them (default: `false`).
+         */
+        dryRun? : boolean;
+        /**
+         * Outpoints of UTXOs that must be spent (coin control). If not
+         * supplied, UTXOs are selected automatically. Supplied UTXOs
+         * must be mature and not locked.
+         */
+        utxos? : (ITransactionOutpoint | string)[];
+        /**
+         * Fee-bump policy: if the final transaction is not accepted within
+         * the deadline, it is regenerated with a higher fee (bounded by
+         * the maximum fee), emitting the `fee-bump` event.
+         */
+        feeBump? : IFeeBumpPolicy;
+        /**
+         * Spend only the supplied {@link IAccountsSendRequest.utxos};
+         * otherwise additional UTXOs are selected automatically if the
+         * supplied UTXOs are insufficient (default: `false`).
+         */
+        exclusiveUtxos? : boolean;
+        /**
+         * Handling of the final transaction change that is too small to be
+         * issued as a change output (default: `{ type: "fold-to-fee" }`).
+         */
+        changePolicy? : IChangePolicy;
+    }
+
+    /**
+     * Fee-bump policy of a send.
+     * 
+     * @category Wallet API
+     */
+    export interface IFeeBumpPolicy {
+        /**
+         * Time (in seconds) allowed for the transaction to be accepted.
+         */
+        deadline : number;
+        /**
+         * Maximum total fees (in sompi) of a regenerated transaction.
+         */
+        maxFeeSompi : bigint;
+    }
+    "#,
+}
+
+try_from! ( args: IAccountsSendRequest, AccountsSendRequest, {
+    let account_id = args.get_account_id("accountId")?;
+    let wallet_secret = args.get_secret("walletSecret")?;
+    let payment_secret = args.try_get_secret("paymentSecret")?;
+    let priority_fee_sompi = args.try_get::<IFees>("priorityFeeSompi")?.map(Fees::try_from).transpose()?;
+    let payload = args.try_get_value("payload")?.map(|v| v.try_as_vec_u8()).transpose()?;
+
+    let destination = PaymentDestination::try_from(args.get_value("destination")?)?;
+
+    let dry_run = args.try_get_bool("dryRun")?.unwrap_or(false);
+    let utxos = args.try_get_outpoint_list("utxos")?;
+    let fee_bump = args.try_get_value("feeBump")?.map(from_value::<FeeBumpPolicy>).transpose()?;
+    let exclusive_utxos = args.try_get_bool("exclusiveUtxos")?.unwrap_or(false);
+    let change_policy = args.try_get_value("changePolicy")?.map(from_value::<ChangePolicy>).transpose()?;
+
+    Ok(AccountsSendRequest {
+        account_id,
+        wallet_secret,
+        payment_secret,
+        priority_fee_sompi,
+        destination,
+        payload,
+        utxos,
+        exclusive_utxos,
+        dry_run,
+        fee_bump,
+        change_policy,
+    })
+});
+
+declare! {
+    IAccountsSendResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsSendResponse {
+        /**
+         * Summary produced by the transaction generator.
+         */
+        generatorSummary : GeneratorSummary;
+        /**
+         * Hex identifiers of successfully submitted transactions.
+         */
+        transactionIds : HexString[];
+        /**
+         * Aggregate fees paid by all submitted transactions (in SOMPI).
+         */
+        fees : bigint;
+        /**
+         * Change amount returned to the account by the final transaction (in SOMPI).
+         */
+        changeAmount? : bigint;
+        /**
+         * Number of chained (batch and final) transactions submitted.
+         */
+        transactionCount : number;
+        /**
+         * DAA score observed upon submission of the final transaction
+         * (an estimate of the transaction acceptance DAA score).
+         */
+        daaScore? : bigint;
+        /**
+         * Transaction validation results (available only in dry-run mode).
+         */
+        validations? : ITransactionValidation[];
+    }
+    "#,
+}
+
+try_from!(args: AccountsSendResponse, IAccountsSendResponse, {
+    let AccountsSendResponse { generator_summary, transaction_ids, daa_score, validations } = args;
+
+    let response = IAccountsSendResponse::default();
+    response.set("fees", &BigInt::from(generator_summary.aggregated_fees()).into())?;
+    if let Some(change_amount) = generator_summary.final_change_amount() {
+        response.set("changeAmount", &BigInt::from(change_amount).into())?;
+    }
+    response.set("transactionCount", &JsValue::from(generator_summary.number_of_generated_transactions()))?;
+    if let Some(daa_score) = daa_score {
+        response.set("daaScore", &BigInt::from(daa_score).into())?;
+    }
+    response.set("generatorSummary", &GeneratorSummary::from(generator_summary).into())?;
+    response.set("transactionIds", &to_value(&transaction_ids)?)?;
+    if let Some(validations) = validations {
+        let validations = validations.into_iter().map(ITransactionValidation::try_from).collect::<Result<Vec<_>>>()?;
+        response.set("validations", &Array::from_iter(validations).into())?;
+    }
+    Ok(response)
+});
+
+// ---
+
+declare! {
+    IAccountsSendBatchRequest,
+    r#"
+    /**
+     * Payment batch (multi-recipient payment) request. Payments to the
+     * same address are aggregated and the batch is submitted as a sequence
+     * of chained transactions, each carrying as many payments as the
+     * transaction mass limits allow. The wallet emits a `payment-batch-progress`
+     * event for each submitted transaction.
+     *
+     * @category Wallet API
+     */
+    export interface IAccountsSendBatchRequest {
+        /**
+         * Hex identifier of the account.
+         */
+        accountId : HexString;
+        /**
+         * Wallet encryption secret.
+         */
+        walletSecret : string;
+        /**
+         * Optional key encryption secret or BIP39 passphrase.
+         */
+        paymentSecret? : string;
+        /**
+         * Priority fee applied to each transaction carrying batch payments.
+         * If not supplied, the wallet default priority fee is used.
+         */
+        priorityFeeSompi? : IFees | bigint;
+        /**
+         * Batch payments (up to 10,000).
+         */
+        outputs : IPaymentOutput[];
+    }
+    "#,
+}
+
+try_from! ( args: IAccountsSendBatchRequest, AccountsSendBatchRequest, {
+    let account_id = args.get_account_id("accountId")?;
+    let wallet_secret = args.get_secret("walletSecret")?;
+    let payment_secret = args.try_get_secret("paymentSecret")?;
+    let priority_fee_sompi = args.try_get::<IFees>("priorityFeeSompi")?.map(Fees::try_from).transpose()?;
+    let outputs = PaymentOutputs::try_owned_from(args.get_value("outputs")?)?.outputs;
+
+    Ok(AccountsSendBatchRequest { account_id, wallet_secret, payment_secret, outputs, priority_fee_sompi })
+});
+
+declare! {
+    IAccountsSendBatchResponse,
+    r#"
+    /**
+     *
+     *
+     * @category Wallet API
+     */
+    export interface IAccountsSendBatchResponse {
+        /**
+         * Summaries produced by the transaction generator for each batch partition.
+         */
+        generatorSummaries : GeneratorSummary[];
+        /**
+         * Hex identifiers of successfully submitted transactions.
+         */
+        transactionIds : HexString[];
+    }
+    "#,
+}
+
+try_from!(args: AccountsSendBatchResponse, IAccountsSendBatchResponse, {
+    let AccountsSendBatchResponse { generator_summaries, transaction_ids } = args;
+
+    let response = IAccountsSendBatchResponse::default();
+    let generator_summaries = generator_summaries.into_iter().map(|summary| JsValue::from(GeneratorSummary::from(summary)));
+    response.set("generatorSummaries", &Array::from_iter(generator_summaries).into())?;
+    response.set("transactionIds", &to_value(&transaction_ids)?)?;
+    Ok(response)
+});
+
+// ---
+
+declare! {
+    IAccountsTransferRequest,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsTransferRequest {
+        sourceAccountId : HexString;
+        destinationAccountId : HexString;
+        walletSecret : string;
+        paymentSecret? : string;
+        priorityFeeSompi? : IFees | bigint;
+        transferAmountSompi : bigint;
+    }
+    "#,
+}
+
+try_from! ( args: IAccountsTransferRequest, AccountsTransferRequest, {
+    let source_account_id = args.get_account_id("sourceAccountId")?;
+    let destination_account_id = args.get_account_id("destinationAccountId")?;
+    let wallet_secret = args.get_secret("walletSecret")?;
+    let payment_secret = args.try_get_secret("paymentSecret")?;
+    let priority_fee_sompi = args.try_get::<IFees>("priorityFeeSompi")?.map(Fees::try_from).transpose()?;
+    let transfer_amount_sompi = args.get_u64("transferAmountSompi")?;
+
+    Ok(AccountsTransferRequest {
         source_account_id,
         destination_account_id,
         wallet_secret,
@@ -1460,305 +2632,877 @@ try_from! ( args: IAccountsTransferRequest, AccountsTransferRequest, {
 });
 
 declare! {
-    IAccountsTransferResponse,
+    IAccountsTransferResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsTransferResponse {
+        generatorSummary : GeneratorSummary;
+        transactionIds : HexString[];
+    }
+    "#,
+}
+
+try_from! ( args: AccountsTransferResponse, IAccountsTransferResponse, {
+    let response = IAccountsTransferResponse::default();
+    response.set("generatorSummary", &GeneratorSummary::from(args.generator_summary).into())?;
+    response.set("transactionIds", &to_value(&args.transaction_ids)?)?;
+    Ok(response)
+});
+
+// ---
+
+declare! {
+    IAccountsEstimateRequest,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsEstimateRequest {
+        accountId : HexString;
+        destination : IPaymentOutput[] | [{ address : Address | string, amount : "all" }];
+        priorityFeeSompi? : IFees | bigint;
+        payload? : Uint8Array | string;
+        /**
+         * Handling of the final transaction change that is too small to be
+         * issued as a change output (default: `{ type: "fold-to-fee" }`).
+         */
+        changePolicy? : IChangePolicy;
+    }
+    "#,
+}
+
+try_from! ( args: IAccountsEstimateRequest, AccountsEstimateRequest, {
+    let account_id = args.get_account_id("accountId")?;
+    let priority_fee_sompi = args.try_get::<IFees>("priorityFeeSompi")?.map(Fees::try_from).transpose()?;
+    let payload = args.try_get_value("payload")?.map(|v| v.try_as_vec_u8()).transpose()?;
+
+    let destination = PaymentDestination::try_from(args.get_value("destination")?)?;
+
+    let change_policy = args.try_get_value("changePolicy")?.map(from_value::<ChangePolicy>).transpose()?;
+
+    Ok(AccountsEstimateRequest { account_id, priority_fee_sompi, destination, payload, change_policy })
+});
+
+declare! {
+    IAccountsEstimateResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsEstimateResponse {
+        generatorSummary : GeneratorSummary;
+    }
+    "#,
+}
+
+try_from! ( args: AccountsEstimateResponse, IAccountsEstimateResponse, {
+    let response = IAccountsEstimateResponse::default();
+    response.set("generatorSummary", &GeneratorSummary::from(args.generator_summary).into())?;
+    Ok(response)
+});
+
+// ---
+
+declare! {
+    IAccountsBalanceHistoryRequest,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsBalanceHistoryRequest {
+        accountId : HexString;
+        networkId : NetworkId | string;
+        /**
+         * Omit the history preceding the supplied unixtime (in milliseconds).
+         */
+        sinceUnixtimeMsec? : bigint;
+    }
+    "#,
+}
+
+try_from! ( args: IAccountsBalanceHistoryRequest, AccountsBalanceHistoryRequest, {
+    let account_id = args.get_account_id("accountId")?;
+    let network_id = args.get_network_id("networkId")?;
+    let since_unixtime_msec = args.try_get_value("sinceUnixtimeMsec")?.map(|_| args.get_u64("sinceUnixtimeMsec")).transpose()?;
+    Ok(AccountsBalanceHistoryRequest { account_id, network_id, since_unixtime_msec })
+});
+
+declare! {
+    IAccountsBalanceHistoryResponse,
+    r#"
+    /**
+     * Account balance at the end of a daily time bucket.
+     * 
+     * @category Wallet API
+     */
+    export interface IBalanceHistoryPoint {
+        /**
+         * Start of the time bucket (UTC midnight, in milliseconds).
+         */
+        unixtimeMsec : bigint;
+        /**
+         * Balance at the end of the time bucket (in SOMPI).
+         */
+        balance : bigint;
+        /**
+         * Funds received during the time bucket (in SOMPI).
+         */
+        received : bigint;
+        /**
+         * Funds spent (including fees) during the time bucket (in SOMPI).
+         */
+        sent : bigint;
+    }
+
+    /**
+     * 
+     * 
+     * @category Wallet API
+     */
+    export interface IAccountsBalanceHistoryResponse {
+        accountId : HexString;
+        /**
+         * Account balance in daily buckets, oldest first. Days
+         * without transactions carry the balance of the previous day.
+         */
+        history : IBalanceHistoryPoint[];
+        /**
+         * Number of transaction records not included in the history
+         * because their timestamp could not be resolved.
+         */
+        unresolved : bigint;
+    }
+    "#,
+}
+
+try_from! ( args: AccountsBalanceHistoryResponse, IAccountsBalanceHistoryResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    ITransactionsDataGetRequest,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface ITransactionsDataGetRequest {
+        accountId : HexString;
+        networkId : NetworkId | string;
+        filter? : TransactionKind[];
+        start : bigint;
+        end : bigint;
+    }
+    "#,
+}
+
+try_from! ( args: ITransactionsDataGetRequest, TransactionsDataGetRequest, {
+    let account_id = args.get_account_id("accountId")?;
+    let network_id = args.get_network_id("networkId")?;
+    let filter = args.get_vec("filter").ok().map(|filter| {
+        filter.into_iter().map(TransactionKind::try_from).collect::<Result<Vec<TransactionKind>>>()
+    }).transpose()?;
+    let start = args.get_u64("start")?;
+    let end = args.get_u64("end")?;
+
+    let request = TransactionsDataGetRequest {
+        account_id,
+        network_id,
+        filter,
+        start,
+        end,
+    };
+    Ok(request)
+});
+
+declare! {
+    ITransactionsDataGetResponse,
+    r#"
+    /**
+     * 
+     * 
+     * @category Wallet API
+     */
+    export interface ITransactionsDataGetResponse {
+        accountId : HexString;
+        transactions : ITransactionRecord[];
+        start : bigint;
+        total : bigint;
+    }
+    "#,
+}
+
+try_from! ( args: TransactionsDataGetResponse, ITransactionsDataGetResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    ITransactionsReplaceNoteRequest,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface ITransactionsReplaceNoteRequest {
+        /**
+         * The id of account the transaction belongs to.
+         */
+        accountId: HexString,
+        /**
+         * The network id of the transaction.
+         */
+        networkId: NetworkId | string,
+        /**
+         * The id of the transaction.
+         */
+        transactionId: HexString,
+        /**
+         * Optional note string to replace the existing note.
+         * If not supplied, the note will be removed.
+         */
+        note?: string,
+    }
+    "#,
+}
+
+try_from! ( args: ITransactionsReplaceNoteRequest, TransactionsReplaceNoteRequest, {
+
+    let account_id = args.get_account_id("accountId")?;
+    let network_id = args.get_network_id("networkId")?;
+    let transaction_id = args.get_transaction_id("transactionId")?;
+    let note = args.try_get_string("note")?;
+
+    Ok(TransactionsReplaceNoteRequest {
+        account_id,
+        network_id,
+        transaction_id,
+        note,
+    })
+});
+
+declare! {
+    ITransactionsReplaceNoteResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface ITransactionsReplaceNoteResponse { }
+    "#,
+}
+
+try_from! ( _args: TransactionsReplaceNoteResponse, ITransactionsReplaceNoteResponse, {
+    Ok(ITransactionsReplaceNoteResponse::default())
+});
+
+// ---
+
+declare! {
+    ITransactionsReplaceMetadataRequest,
+    r#"
+    /**
+     * Metadata is a wallet-specific string that can be used to store arbitrary data.
+     * It should contain a serialized JSON string with `key` containing the custom
+     * data stored by the wallet.  When interacting with metadata, the wallet should
+     * always deserialize the JSON string and then serialize it again after making
+     * changes, preserving any foreign keys that it might encounter.
+     *  
+     * To preserve foreign metadata, the pattern of access should be:
+     * `Get -> Modify -> Replace`
+     * 
+     * @category Wallet API
+     */
+    export interface ITransactionsReplaceMetadataRequest {
+        /**
+         * The id of account the transaction belongs to.
+         */
+        accountId: HexString,
+        /**
+         * The network id of the transaction.
+         */
+        networkId: NetworkId | string,
+        /**
+         * The id of the transaction.
+         */
+        transactionId: HexString,
+        /**
+         * Optional metadata string to replace the existing metadata.
+         * If not supplied, the metadata will be removed.
+         */
+        metadata?: string,    
+    }
+    "#,
+}
+
+try_from! ( args: ITransactionsReplaceMetadataRequest, TransactionsReplaceMetadataRequest, {
+    let account_id = args.get_account_id("accountId")?;
+    let network_id = args.get_network_id("networkId")?;
+    let transaction_id = args.get_transaction_id("transactionId")?;
+    let metadata = args.try_get_string("metadata")?;
+
+    Ok(TransactionsReplaceMetadataRequest {
+        account_id,
+        network_id,
+        transaction_id,
+        metadata,
+    })
+});
+
+declare! {
+    ITransactionsReplaceMetadataResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface ITransactionsReplaceMetadataResponse { }
+    "#,
+}
+
+try_from! ( _args: TransactionsReplaceMetadataResponse, ITransactionsReplaceMetadataResponse, {
+    Ok(ITransactionsReplaceMetadataResponse::default())
+});
+
+// ---
+
+declare! {
+    ITransactionsExportRequest,
+    r#"
+    /**
+     * Export of the account transaction records for accounting purposes.
+     * Amounts and fees are exported in KAS, timestamps in ISO 8601 (UTC).
+     * 
+     * @category Wallet API
+     */
+    export interface ITransactionsExportRequest {
+        accountId : HexString;
+        networkId : NetworkId | string;
+        /**
+         * Export format (default: `csv`).
+         */
+        format? : "csv" | "json";
+        /**
+         * Exported columns (default: all columns).
+         */
+        columns? : ("timestamp" | "txid" | "direction" | "amount" | "fee" | "address" | "note")[];
+        /**
+         * Export records created at or after the supplied unixtime (in milliseconds).
+         */
+        sinceUnixtimeMsec? : bigint;
+        /**
+         * Export records created before the supplied unixtime (in milliseconds).
+         */
+        untilUnixtimeMsec? : bigint;
+    }
+    "#,
+}
+
+try_from! ( args: ITransactionsExportRequest, TransactionsExportRequest, {
+    let account_id = args.get_account_id("accountId")?;
+    let network_id = args.get_network_id("networkId")?;
+    let format = args.try_get_string("format")?.map(|format| format.parse()).transpose()?.unwrap_or_default();
+    let columns = args.try_get_value("columns")?.map(from_value::<Vec<TransactionExportColumn>>).transpose()?.unwrap_or_default();
+    let since_unixtime_msec = args.try_get_value("sinceUnixtimeMsec")?.map(|_| args.get_u64("sinceUnixtimeMsec")).transpose()?;
+    let until_unixtime_msec = args.try_get_value("untilUnixtimeMsec")?.map(|_| args.get_u64("untilUnixtimeMsec")).transpose()?;
+    Ok(TransactionsExportRequest { account_id, network_id, format, columns, since_unixtime_msec, until_unixtime_msec })
+});
+
+declare! {
+    ITransactionsExportResponse,
+    r#"
+    /**
+     * 
+     * 
+     * @category Wallet API
+     */
+    export interface ITransactionsExportResponse {
+        format : "csv" | "json";
+        /**
+         * Exported CSV or JSON document.
+         */
+        data : string;
+    }
+    "#,
+}
+
+try_from! ( args: TransactionsExportResponse, ITransactionsExportResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IAddressBookEnumerateRequest,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAddressBookEnumerateRequest { }
+    "#,
+}
+
+try_from! ( _args: IAddressBookEnumerateRequest, AddressBookEnumerateRequest, {
+    Ok(AddressBookEnumerateRequest { })
+});
+
+declare! {
+    IAddressBookEnumerateResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAddressBookEnumerateResponse { }
+    "#,
+}
+
+try_from! ( _args: AddressBookEnumerateResponse, IAddressBookEnumerateResponse, {
+    Ok(IAddressBookEnumerateResponse::default())
+});
+
+// ---
+
+declare! {
+    ITasksEnumerateRequest,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface ITasksEnumerateRequest { }
+    "#,
+}
+
+try_from! ( _args: ITasksEnumerateRequest, TasksEnumerateRequest, {
+    Ok(TasksEnumerateRequest { })
+});
+
+declare! {
+    ITasksEnumerateResponse,
     r#"
     /**
      * 
      *  
      * @category Wallet API
      */
-    export interface IAccountsTransferResponse {
-        generatorSummary : GeneratorSummary;
-        transactionIds : HexString[];
+    export interface ITasksEnumerateResponse {
+        tasks : ITaskDescriptor[];
     }
     "#,
 }
 
-try_from! ( args: AccountsTransferResponse, IAccountsTransferResponse, {
-    let response = IAccountsTransferResponse::default();
-    response.set("generatorSummary", &GeneratorSummary::from(args.generator_summary).into())?;
-    response.set("transactionIds", &to_value(&args.transaction_ids)?)?;
-    Ok(response)
+try_from! ( args: TasksEnumerateResponse, ITasksEnumerateResponse, {
+    Ok(to_value(&args)?.into())
 });
 
 // ---
 
 declare! {
-    IAccountsEstimateRequest,
+    ITasksAbortRequest,
     r#"
     /**
      * 
      *  
      * @category Wallet API
      */
-    export interface IAccountsEstimateRequest {
-        accountId : HexString;
-        destination : IPaymentOutput[];
-        priorityFeeSompi : IFees | bigint;
-        payload? : Uint8Array | string;
+    export interface ITasksAbortRequest {
+        /**
+         * Id of the task to abort. If not supplied, all running tasks are aborted.
+         */
+        taskId? : number;
     }
     "#,
 }
 
-try_from! ( args: IAccountsEstimateRequest, AccountsEstimateRequest, {
-    let account_id = args.get_account_id("accountId")?;
-    let priority_fee_sompi = args.get::<IFees>("priorityFeeSompi")?.try_into()?;
-    let payload = args.try_get_value("payload")?.map(|v| v.try_as_vec_u8()).transpose()?;
+try_from! ( args: ITasksAbortRequest, TasksAbortRequest, {
+    Ok(from_value::<TasksAbortRequest>(args.into())?)
+});
 
-    let outputs = args.get_value("destination")?;
-    let destination: PaymentDestination =
-        if outputs.is_undefined() { PaymentDestination::Change } else { PaymentOutputs::try_owned_from(outputs)?.into() };
+declare! {
+    ITasksAbortResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface ITasksAbortResponse { }
+    "#,
+}
 
-    Ok(AccountsEstimateRequest { account_id, priority_fee_sompi, destination, payload })
+try_from! ( _args: TasksAbortResponse, ITasksAbortResponse, {
+    Ok(ITasksAbortResponse::default())
 });
 
+// ---
+
 declare! {
-    IAccountsEstimateResponse,
+    IMarketConfigureRequest,
     r#"
     /**
      * 
      *  
      * @category Wallet API
      */
-    export interface IAccountsEstimateResponse {
-        generatorSummary : GeneratorSummary;
+    export interface IMarketConfigureRequest {
+        /**
+         * Price feed to poll. If not supplied, the price feed is disabled.
+         */
+        feed? : "coingecko" | "coinpaprika";
+        /**
+         * Fiat currency codes (i.e. `usd`, `eur`).
+         */
+        currencies : string[];
+        /**
+         * Polling interval in seconds (default: 300, minimum: 60).
+         */
+        intervalSeconds? : number;
     }
     "#,
 }
 
-try_from! ( args: AccountsEstimateResponse, IAccountsEstimateResponse, {
-    let response = IAccountsEstimateResponse::default();
-    response.set("generatorSummary", &GeneratorSummary::from(args.generator_summary).into())?;
-    Ok(response)
+try_from! ( args: IMarketConfigureRequest, MarketConfigureRequest, {
+    Ok(from_value::<MarketConfigureRequest>(args.into())?)
+});
+
+declare! {
+    IMarketConfigureResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IMarketConfigureResponse { }
+    "#,
+}
+
+try_from! ( _args: MarketConfigureResponse, IMarketConfigureResponse, {
+    Ok(IMarketConfigureResponse::default())
 });
 
 // ---
 
 declare! {
-    ITransactionsDataGetRequest,
+    IMarketDataGetRequest,
     r#"
     /**
      * 
      *  
      * @category Wallet API
      */
-    export interface ITransactionsDataGetRequest {
-        accountId : HexString;
-        networkId : NetworkId | string;
-        filter? : TransactionKind[];
-        start : bigint;
-        end : bigint;
-    }
+    export interface IMarketDataGetRequest { }
     "#,
 }
 
-try_from! ( args: ITransactionsDataGetRequest, TransactionsDataGetRequest, {
-    let account_id = args.get_account_id("accountId")?;
-    let network_id = args.get_network_id("networkId")?;
-    let filter = args.get_vec("filter").ok().map(|filter| {
-        filter.into_iter().map(TransactionKind::try_from).collect::<Result<Vec<TransactionKind>>>()
-    }).transpose()?;
-    let start = args.get_u64("start")?;
-    let end = args.get_u64("end")?;
+try_from! ( _args: IMarketDataGetRequest, MarketDataGetRequest, {
+    Ok(MarketDataGetRequest { })
+});
 
-    let request = TransactionsDataGetRequest {
-        account_id,
-        network_id,
-        filter,
-        start,
-        end,
-    };
-    Ok(request)
+declare! {
+    IMarketDataGetResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IMarketDataGetResponse {
+        /**
+         * Last market data obtained from the price feed
+         * (not present if the price feed is not configured).
+         */
+        data? : IMarketData;
+    }
+    "#,
+}
+
+try_from! ( args: MarketDataGetResponse, IMarketDataGetResponse, {
+    Ok(serde_wasm_bindgen::to_value(&args)?.into())
 });
 
+// ---
+
 declare! {
-    ITransactionsDataGetResponse,
+    IWebhookDescriptor,
     r#"
     /**
+     * Webhook notified of wallet events. The signing secret is not disclosed.
      * 
+     * @category Wallet API
+     */
+    export interface IWebhookDescriptor {
+        id : string;
+        url : string;
+        /**
+         * `true` if payloads are signed (HMAC-SHA256, `X-Kaspa-Signature` header).
+         */
+        signed : boolean;
+        events : ("balance" | "incoming" | "confirmation" | "maturity")[];
+        accountIds? : HexString[];
+        confirmations : number[];
+        maxRetries : number;
+    }
+    "#,
+}
+
+declare! {
+    IWebhooksEnumerateRequest,
+    r#"
+    /**
      * 
+     *  
      * @category Wallet API
      */
-    export interface ITransactionsDataGetResponse {
-        accountId : HexString;
-        transactions : ITransactionRecord[];
-        start : bigint;
-        total : bigint;
+    export interface IWebhooksEnumerateRequest { }
+    "#,
+}
+
+try_from! ( _args: IWebhooksEnumerateRequest, WebhooksEnumerateRequest, {
+    Ok(WebhooksEnumerateRequest { })
+});
+
+declare! {
+    IWebhooksEnumerateResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IWebhooksEnumerateResponse {
+        webhooks : IWebhookDescriptor[];
     }
     "#,
 }
 
-try_from! ( args: TransactionsDataGetResponse, ITransactionsDataGetResponse, {
+try_from! ( args: WebhooksEnumerateResponse, IWebhooksEnumerateResponse, {
     Ok(to_value(&args)?.into())
 });
 
-// ---
-
 declare! {
-    ITransactionsReplaceNoteRequest,
+    IWebhooksCreateRequest,
     r#"
     /**
      * 
      *  
      * @category Wallet API
      */
-    export interface ITransactionsReplaceNoteRequest {
+    export interface IWebhooksCreateRequest {
         /**
-         * The id of account the transaction belongs to.
+         * Wallet secret (the signing secret is stored in the wallet data).
          */
-        accountId: HexString,
+        walletSecret : string;
         /**
-         * The network id of the transaction.
+         * Endpoint receiving the payloads (`http://` or `https://`).
          */
-        networkId: NetworkId | string,
+        url : string;
         /**
-         * The id of the transaction.
+         * HMAC-SHA256 payload signing secret. If not supplied, payloads are not signed.
          */
-        transactionId: HexString,
+        secret? : string;
         /**
-         * Optional note string to replace the existing note.
-         * If not supplied, the note will be removed.
+         * Notified event kinds (all events are notified if empty or not supplied).
          */
-        note?: string,
+        events? : ("balance" | "incoming" | "confirmation" | "maturity")[];
+        /**
+         * Notified accounts (all accounts are notified if not supplied).
+         */
+        accountIds? : HexString[];
+        /**
+         * Confirmation milestones (DAA score units) notified for received transactions.
+         */
+        confirmations? : number[];
+        /**
+         * Number of delivery retries (default: 5, maximum: 16).
+         */
+        maxRetries? : number;
     }
     "#,
 }
 
-try_from! ( args: ITransactionsReplaceNoteRequest, TransactionsReplaceNoteRequest, {
+try_from! ( args: IWebhooksCreateRequest, WebhooksCreateRequest, {
+    Ok(from_value::<WebhooksCreateRequest>(args.into())?)
+});
 
-    let account_id = args.get_account_id("accountId")?;
-    let network_id = args.get_network_id("networkId")?;
-    let transaction_id = args.get_transaction_id("transactionId")?;
-    let note = args.try_get_string("note")?;
+declare! {
+    IWebhooksCreateResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IWebhooksCreateResponse {
+        webhook : IWebhookDescriptor;
+    }
+    "#,
+}
 
-    Ok(TransactionsReplaceNoteRequest {
-        account_id,
-        network_id,
-        transaction_id,
-        note,
-    })
+try_from! ( args: WebhooksCreateResponse, IWebhooksCreateResponse, {
+    Ok(to_value(&args)?.into())
 });
 
 declare! {
-    ITransactionsReplaceNoteResponse,
+    IWebhooksRemoveRequest,
     r#"
     /**
      * 
      *  
      * @category Wallet API
      */
-    export interface ITransactionsReplaceNoteResponse { }
+    export interface IWebhooksRemoveRequest {
+        walletSecret : string;
+        id : string;
+    }
     "#,
 }
 
-try_from! ( _args: TransactionsReplaceNoteResponse, ITransactionsReplaceNoteResponse, {
-    Ok(ITransactionsReplaceNoteResponse::default())
+try_from! ( args: IWebhooksRemoveRequest, WebhooksRemoveRequest, {
+    Ok(from_value::<WebhooksRemoveRequest>(args.into())?)
+});
+
+declare! {
+    IWebhooksRemoveResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IWebhooksRemoveResponse { }
+    "#,
+}
+
+try_from! ( _args: WebhooksRemoveResponse, IWebhooksRemoveResponse, {
+    Ok(IWebhooksRemoveResponse::default())
 });
 
 // ---
 
-// TODO
 declare! {
-    ITransactionsReplaceMetadataRequest,
+    IUtxoEntryDescriptor,
     r#"
     /**
-     * Metadata is a wallet-specific string that can be used to store arbitrary data.
-     * It should contain a serialized JSON string with `key` containing the custom
-     * data stored by the wallet.  When interacting with metadata, the wallet should
-     * always deserialize the JSON string and then serialize it again after making
-     * changes, preserving any foreign keys that it might encounter.
-     *  
-     * To preserve foreign metadata, the pattern of access should be:
-     * `Get -> Modify -> Replace`
+     * UTXO entry information used for coin control.
      * 
      * @category Wallet API
      */
-    export interface ITransactionsReplaceMetadataRequest {
-        /**
-         * The id of account the transaction belongs to.
-         */
-        accountId: HexString,
-        /**
-         * The network id of the transaction.
-         */
-        networkId: NetworkId | string,
-        /**
-         * The id of the transaction.
-         */
-        transactionId: HexString,
+    export interface IUtxoEntryDescriptor {
+        outpoint : ITransactionOutpoint;
+        address? : string;
+        amount : bigint;
+        blockDaaScore : bigint;
+        isCoinbase : boolean;
         /**
-         * Optional metadata string to replace the existing metadata.
-         * If not supplied, the metadata will be removed.
+         * Only `spendable` UTXOs can be supplied to {@link IAccountsSendRequest.utxos}.
          */
-        metadata?: string,    
+        status : "spendable" | "locked" | "pending" | "stasis";
+        label? : string;
     }
     "#,
 }
 
-try_from! ( args: ITransactionsReplaceMetadataRequest, TransactionsReplaceMetadataRequest, {
-    let account_id = args.get_account_id("accountId")?;
-    let network_id = args.get_network_id("networkId")?;
-    let transaction_id = args.get_transaction_id("transactionId")?;
-    let metadata = args.try_get_string("metadata")?;
+declare! {
+    IAccountsGetUtxosRequest,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsGetUtxosRequest {
+        accountId : HexString;
+    }
+    "#,
+}
 
-    Ok(TransactionsReplaceMetadataRequest {
-        account_id,
-        network_id,
-        transaction_id,
-        metadata,
-    })
+try_from! ( args: IAccountsGetUtxosRequest, AccountsGetUtxosRequest, {
+    Ok(from_value::<AccountsGetUtxosRequest>(args.into())?)
 });
 
 declare! {
-    ITransactionsReplaceMetadataResponse,
+    IAccountsGetUtxosResponse,
     r#"
     /**
      * 
      *  
      * @category Wallet API
      */
-    export interface ITransactionsReplaceMetadataResponse { }
+    export interface IAccountsGetUtxosResponse {
+        utxos : IUtxoEntryDescriptor[];
+    }
     "#,
 }
 
-try_from! ( _args: TransactionsReplaceMetadataResponse, ITransactionsReplaceMetadataResponse, {
-    Ok(ITransactionsReplaceMetadataResponse::default())
+try_from! ( args: AccountsGetUtxosResponse, IAccountsGetUtxosResponse, {
+    Ok(to_value(&args)?.into())
 });
 
 // ---
 
 declare! {
-    IAddressBookEnumerateRequest,
+    IAccountsLabelUtxosRequest,
     r#"
     /**
      * 
      *  
      * @category Wallet API
      */
-    export interface IAddressBookEnumerateRequest { }
+    export interface IAccountsLabelUtxosRequest {
+        accountId : HexString;
+        outpoints : (ITransactionOutpoint | string)[];
+        /**
+         * Label to assign. If not supplied, the label is removed.
+         */
+        label? : string;
+    }
     "#,
 }
 
-try_from! ( _args: IAddressBookEnumerateRequest, AddressBookEnumerateRequest, {
-    Ok(AddressBookEnumerateRequest { })
+try_from! ( args: IAccountsLabelUtxosRequest, AccountsLabelUtxosRequest, {
+    let account_id = args.get_account_id("accountId")?;
+    let outpoints = args.try_get_outpoint_list("outpoints")?.ok_or(Error::InvalidArgument("outpoints".to_string()))?;
+    let label = args.try_get_string("label")?;
+    Ok(AccountsLabelUtxosRequest { account_id, outpoints, label })
 });
 
 declare! {
-    IAddressBookEnumerateResponse,
+    IAccountsLabelUtxosResponse,
     r#"
     /**
      * 
      *  
      * @category Wallet API
      */
-    export interface IAddressBookEnumerateResponse {
-        // TODO
-    }
+    export interface IAccountsLabelUtxosResponse { }
     "#,
 }
 
-try_from! ( _args: AddressBookEnumerateResponse, IAddressBookEnumerateResponse, {
-    Err(Error::NotImplemented)
+try_from! ( _args: AccountsLabelUtxosResponse, IAccountsLabelUtxosResponse, {
+    Ok(IAccountsLabelUtxosResponse::default())
 });
 
 // ---
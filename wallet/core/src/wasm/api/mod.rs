@@ -1,8 +1,9 @@
+use crate::api::batch::{WalletRequest, WalletResponse};
 use crate::api::message::*;
 use crate::api::traits::*;
 use crate::imports::*;
 use crate::result::Result;
-use kaspa_wallet_macros::declare_wasm_handlers;
+use kaspa_wallet_macros::{assert_wasm_conversions, declare_wasm_batch_handlers, declare_wasm_handlers};
 
 pub mod extensions;
 pub mod message;
@@ -13,29 +14,36 @@ use super::Wallet;
 
 declare_wasm_handlers!([
     /// Ping backend
-    // Ping,
+    Ping,
     Batch,
     Flush,
     // Connect,
     // Disconnect,
     RetainContext,
     GetStatus,
+    ChangeNetworkId,
+    SetLogLevel,
     WalletEnumerate,
     WalletCreate,
     WalletOpen,
     WalletReload,
     WalletClose,
     // WalletExists,
-    // WalletRename,
+    WalletRename,
     WalletChangeSecret,
     WalletExport,
     WalletImport,
+    WalletCheck,
     PrvKeyDataEnumerate,
     PrvKeyDataCreate,
     PrvKeyDataRemove,
     PrvKeyDataGet,
+    PrvKeyDataChangeSecret,
     AccountsEnumerate,
     AccountsRename,
+    AccountsArchive,
+    AccountsUnarchive,
+    AccountsSelect,
     AccountsDiscovery,
     AccountsCreate,
     AccountsEnsureDefault,
@@ -44,12 +52,169 @@ declare_wasm_handlers!([
     AccountsDeactivate,
     // AccountsRemove,
     AccountsGet,
+    AccountsExportXpub,
+    AccountsSignMessage,
+    AccountsVerifyMessage,
     AccountsCreateNewAddress,
     AccountsSend,
+    AccountsSendBatch,
     AccountsTransfer,
     AccountsEstimate,
     TransactionsDataGet,
     TransactionsReplaceNote,
     TransactionsReplaceMetadata,
+    TransactionsExport,
     AddressBookEnumerate,
+    TasksEnumerate,
+    TasksAbort,
+    AccountsGetUtxos,
+    AccountsLabelUtxos,
+    AccountsBalanceHistory,
+    MarketConfigure,
+    MarketDataGet,
+    WebhooksEnumerate,
+    WebhooksCreate,
+    WebhooksRemove,
+    AccountsSignTransaction,
+    AccountsSetGapLimit,
+    AccountsGetPendingIncoming,
+    AccountsCreatePaymentRequest,
+    AccountsGetPaymentRequests,
+    AccountsRemovePaymentRequest,
+    AccountsAddresses,
+]);
+
+declare_wasm_batch_handlers!([
+    RetainContext,
+    GetStatus,
+    ChangeNetworkId,
+    SetLogLevel,
+    WalletEnumerate,
+    WalletCreate,
+    WalletOpen,
+    WalletReload,
+    WalletClose,
+    WalletRename,
+    WalletChangeSecret,
+    WalletExport,
+    WalletImport,
+    WalletCheck,
+    PrvKeyDataEnumerate,
+    PrvKeyDataCreate,
+    PrvKeyDataRemove,
+    PrvKeyDataGet,
+    PrvKeyDataChangeSecret,
+    AccountsEnumerate,
+    AccountsRename,
+    AccountsArchive,
+    AccountsUnarchive,
+    AccountsSelect,
+    AccountsDiscovery,
+    AccountsCreate,
+    AccountsEnsureDefault,
+    AccountsImport,
+    AccountsActivate,
+    AccountsDeactivate,
+    AccountsGet,
+    AccountsExportXpub,
+    AccountsSignMessage,
+    AccountsVerifyMessage,
+    AccountsCreateNewAddress,
+    AccountsSend,
+    AccountsSendBatch,
+    AccountsTransfer,
+    AccountsEstimate,
+    TransactionsDataGet,
+    TransactionsReplaceNote,
+    TransactionsReplaceMetadata,
+    TransactionsExport,
+    AddressBookEnumerate,
+    TasksEnumerate,
+    TasksAbort,
+    AccountsGetUtxos,
+    AccountsLabelUtxos,
+    AccountsBalanceHistory,
+    MarketConfigure,
+    MarketDataGet,
+    WebhooksEnumerate,
+    WebhooksCreate,
+    WebhooksRemove,
+    AccountsSignTransaction,
+    AccountsSetGapLimit,
+    AccountsGetPendingIncoming,
+    AccountsCreatePaymentRequest,
+    AccountsGetPaymentRequests,
+    AccountsRemovePaymentRequest,
+    AccountsAddresses,
+]);
+
+// Every Wallet API operation available via the transport interface must be
+// convertible to and from its TypeScript interface (see `api::message` tests).
+assert_wasm_conversions!([
+    Ping,
+    GetStatus,
+    Connect,
+    Disconnect,
+    ChangeNetworkId,
+    SetLogLevel,
+    RetainContext,
+    Batch,
+    Flush,
+    WalletEnumerate,
+    WalletCreate,
+    WalletOpen,
+    WalletClose,
+    WalletReload,
+    WalletRename,
+    WalletChangeSecret,
+    WalletExport,
+    WalletImport,
+    WalletCheck,
+    PrvKeyDataEnumerate,
+    PrvKeyDataCreate,
+    PrvKeyDataRemove,
+    PrvKeyDataGet,
+    PrvKeyDataChangeSecret,
+    AccountsRename,
+    AccountsArchive,
+    AccountsUnarchive,
+    AccountsSelect,
+    AccountsEnumerate,
+    AccountsDiscovery,
+    AccountsCreate,
+    AccountsEnsureDefault,
+    AccountsImport,
+    AccountsActivate,
+    AccountsDeactivate,
+    AccountsGet,
+    AccountsExportXpub,
+    AccountsSignMessage,
+    AccountsVerifyMessage,
+    AccountsCreateNewAddress,
+    AccountsSend,
+    AccountsSendBatch,
+    AccountsTransfer,
+    AccountsEstimate,
+    TransactionsDataGet,
+    TransactionsReplaceNote,
+    TransactionsReplaceMetadata,
+    TransactionsExport,
+    AddressBookEnumerate,
+    TasksEnumerate,
+    TasksAbort,
+    AccountsGetUtxos,
+    AccountsLabelUtxos,
+    AccountsBalanceHistory,
+    MarketConfigure,
+    MarketDataGet,
+    WebhooksEnumerate,
+    WebhooksCreate,
+    WebhooksRemove,
+    AccountsSignTransaction,
+    AccountsSetGapLimit,
+    AccountsGetPendingIncoming,
+    AccountsCreatePaymentRequest,
+    AccountsGetPaymentRequests,
+    AccountsRemovePaymentRequest,
+    AccountsAddresses,
 ]);
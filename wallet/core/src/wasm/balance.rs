@@ -35,6 +35,18 @@ impl Balance {
         self.inner.outgoing.into()
     }
 
+    /// Amount of funds held in locked UTXOs that are excluded from spending.
+    #[wasm_bindgen(getter)]
+    pub fn locked(&self) -> BigInt {
+        self.inner.locked.into()
+    }
+
+    /// Amount of funds sent to the account by unconfirmed transactions present in the node mempool.
+    #[wasm_bindgen(getter, js_name = "pendingIncoming")]
+    pub fn pending_incoming(&self) -> BigInt {
+        self.inner.pending_incoming.into()
+    }
+
     #[wasm_bindgen(js_name = "toBalanceStrings")]
     pub fn to_balance_strings(&self, network_type: &NetworkTypeT) -> Result<BalanceStrings> {
         let network_type = NetworkType::try_from(network_type)?;
@@ -71,6 +83,16 @@ impl BalanceStrings {
     pub fn pending(&self) -> Option<String> {
         self.inner.pending.clone()
     }
+
+    #[wasm_bindgen(getter)]
+    pub fn locked(&self) -> Option<String> {
+        self.inner.locked.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn incoming(&self) -> Option<String> {
+        self.inner.incoming.clone()
+    }
 }
 
 impl From<native::BalanceStrings> for BalanceStrings {
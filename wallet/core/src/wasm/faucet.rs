@@ -0,0 +1,43 @@
+use crate::faucet::FaucetClient;
+use crate::imports::*;
+use kaspa_addresses::AddressT;
+use workflow_wasm::serde::to_value;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_FAUCET_RESPONSE: &'static str = r#"
+/**
+ * Response of the testnet faucet to a funding request.
+ *
+ * @see {@link requestFaucetFunds}
+ * @category Wallet SDK
+ */
+export interface IFaucetResponse {
+    /**
+     * Id of the transaction funding the address (if reported by the faucet).
+     */
+    transactionId?: HexString;
+    /**
+     * Amount sent to the address in SOMPI (if reported by the faucet).
+     */
+    amount?: bigint;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "IFaucetResponse")]
+    pub type IFaucetResponse;
+}
+
+///
+/// Request testnet funds for the `address` from the faucet HTTP endpoint
+/// located at `url`. Mainnet addresses are rejected.
+///
+/// @category Wallet SDK
+///
+#[wasm_bindgen(js_name = "requestFaucetFunds")]
+pub async fn request_faucet_funds(url: String, address: AddressT) -> Result<IFaucetResponse> {
+    let address = Address::try_cast_from(address)?;
+    let response = FaucetClient::new(url).request(address.as_ref()).await?;
+    Ok(to_value(&response)?.unchecked_into())
+}
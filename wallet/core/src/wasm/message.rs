@@ -51,7 +51,14 @@ const TS_MESSAGE_TYPES: &'static str = r#"
 export interface IVerifyMessage {
     message: string;
     signature: HexString;
-    publicKey: PublicKey | string;
+    /**
+     * Public key of the signer. Either `publicKey` or `address` must be supplied.
+     */
+    publicKey?: PublicKey | string;
+    /**
+     * `PubKey` address of the signer (see {@link IAccountsSignMessageRequest}).
+     */
+    address?: Address | string;
 }
 "#;
 
@@ -61,16 +68,25 @@ extern "C" {
     pub type IVerifyMessage;
 }
 
-/// Verifies with a public key the signature of the given message
+/// Verifies with a public key or a `PubKey` address the signature of the given message
 /// @category Message Signing
 #[wasm_bindgen(js_name = verifyMessage, skip_jsdoc)]
 pub fn js_verify_message(value: IVerifyMessage) -> Result<bool, Error> {
     if let Some(object) = Object::try_from(&value) {
-        let public_key = object.get_cast::<PublicKey>("publicKey")?;
         let raw_msg = object.get_string("message")?;
         let signature = object.get_string("signature")?;
 
         let pm = PersonalMessage(&raw_msg);
+        if let Some(address) = object.try_get_value("address")? {
+            let address = Address::try_owned_from(address)?;
+            return match verify_message_with_address(&pm, &signature, &address) {
+                Ok(()) => Ok(true),
+                Err(Error::InvalidMessageSignature) => Ok(false),
+                Err(err) => Err(err),
+            };
+        }
+
+        let public_key = object.get_cast::<PublicKey>("publicKey")?;
         let mut signature_bytes = [0u8; 64];
         faster_hex::hex_decode(signature.as_bytes(), &mut signature_bytes)?;
 
@@ -9,7 +9,9 @@ cfg_if! {
         pub mod balance;
         pub mod message;
         pub mod notify;
+        pub mod ownership;
         pub mod signer;
+        pub mod signin;
         pub mod tx;
         pub mod utils;
         pub mod utxo;
@@ -19,7 +21,9 @@ cfg_if! {
         pub use self::balance::*;
         pub use self::message::*;
         pub use self::notify::*;
+        pub use self::ownership::*;
         pub use self::signer::*;
+        pub use self::signin::*;
         pub use self::tx::*;
         pub use self::utils::*;
         pub use self::utxo::*;
@@ -35,3 +39,8 @@ cfg_if! {
         pub use self::wallet::*;
     }
 }
+
+#[cfg(all(feature = "faucet", feature = "wasm32-sdk"))]
+pub mod faucet;
+#[cfg(all(feature = "faucet", feature = "wasm32-sdk"))]
+pub use self::faucet::*;
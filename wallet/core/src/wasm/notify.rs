@@ -24,6 +24,8 @@ cfg_if! {
             DaaScoreChange = "daa-score-change",
             Pending = "pending",
             Reorg = "reorg",
+            ReorgedOut = "reorged-out",
+            Reaccepted = "reaccepted",
             Stasis = "stasis",
             Maturity = "maturity",
             Discovery = "discovery",
@@ -44,6 +46,8 @@ cfg_if! {
             | IDaaScoreChangeEvent
             | IPendingEvent
             | IReorgEvent
+            | IReorgedOutEvent
+            | IReacceptedEvent
             | IStasisEvent
             | IMaturityEvent
             | IDiscoveryEvent
@@ -69,6 +73,8 @@ cfg_if! {
             "daa-score-change": IDaaScoreChangeEvent,
             "pending": IPendingEvent,
             "reorg": IReorgEvent,
+            "reorged-out": IReorgedOutEvent,
+            "reaccepted": IReacceptedEvent,
             "stasis": IStasisEvent,
             "maturity": IMaturityEvent,
             "discovery": IDiscoveryEvent,
@@ -124,15 +130,19 @@ cfg_if! {
             Disconnect = "disconnect",
             UtxoIndexNotEnabled = "utxo-index-not-enabled",
             SyncState = "sync-state",
+            WalletSync = "wallet-sync",
             WalletHint = "wallet-hint",
             WalletOpen = "wallet-open",
             WalletCreate = "wallet-create",
             WalletReload = "wallet-reload",
             WalletError = "wallet-error",
             WalletClose = "wallet-close",
+            WalletLocked = "wallet-locked",
             PrvKeyDataCreate = "prv-key-data-create",
             AccountActivation = "account-activation",
             AccountDeactivation = "account-deactivation",
+            AccountArchive = "account-archive",
+            AccountUnarchive = "account-unarchive",
             AccountSelection = "account-selection",
             AccountCreate = "account-create",
             AccountUpdate = "account-update",
@@ -143,10 +153,21 @@ cfg_if! {
             DaaScoreChange = "daa-score-change",
             Pending = "pending",
             Reorg = "reorg",
+            ReorgedOut = "reorged-out",
+            Reaccepted = "reaccepted",
             Stasis = "stasis",
             Maturity = "maturity",
             Discovery = "discovery",
             Balance = "balance",
+            TaskDeadline = "task-deadline",
+            FeeBump = "fee-bump",
+            FeeBumpAbandoned = "fee-bump-abandoned",
+            Market = "market",
+            PaymentBatchProgress = "payment-batch-progress",
+            GeneratorProgress = "generator-progress",
+            PaymentReceived = "payment-received",
+            ScanInterrupted = "scan-interrupted",
+            ScanResumed = "scan-resumed",
             Error = "error",
         }
 
@@ -159,15 +180,19 @@ cfg_if! {
             | IDisconnectEvent
             | IUtxoIndexNotEnabledEvent
             | ISyncStateEvent
+            | IWalletSyncEvent
             | IWalletHintEvent
             | IWalletOpenEvent
             | IWalletCreateEvent
             | IWalletReloadEvent
             | IWalletErrorEvent
             // | IWalletCloseEvent
+            | IWalletLockedEvent
             | IPrvKeyDataCreateEvent
             | IAccountActivationEvent
             | IAccountDeactivationEvent
+            | IAccountArchiveEvent
+            | IAccountUnarchiveEvent
             | IAccountSelectionEvent
             | IAccountCreateEvent
         | IAccountUpdateEvent
@@ -178,10 +203,21 @@ cfg_if! {
             | IDaaScoreChangeEvent
             | IPendingEvent
             | IReorgEvent
+            | IReorgedOutEvent
+            | IReacceptedEvent
             | IStasisEvent
             | IMaturityEvent
             | IDiscoveryEvent
             | IBalanceEvent
+            | ITaskDeadlineEvent
+            | IFeeBumpEvent
+            | IFeeBumpAbandonedEvent
+            | IMarketEvent
+            | IPaymentBatchProgressEvent
+            | IGeneratorProgressEvent
+            | IPaymentReceivedEvent
+            | IScanInterruptedEvent
+            | IScanResumedEvent
             | IErrorEvent
             | undefined
             ;
@@ -196,15 +232,19 @@ cfg_if! {
              "disconnect": IDisconnectEvent,
              "utxo-index-not-enabled": IUtxoIndexNotEnabledEvent,
              "sync-state": ISyncStateEvent,
+             "wallet-sync": IWalletSyncEvent,
              "wallet-hint": IWalletHintEvent,
              "wallet-open": IWalletOpenEvent,
              "wallet-create": IWalletCreateEvent,
              "wallet-reload": IWalletReloadEvent,
              "wallet-error": IWalletErrorEvent,
              "wallet-close": undefined,
+             "wallet-locked": IWalletLockedEvent,
              "prv-key-data-create": IPrvKeyDataCreateEvent,
              "account-activation": IAccountActivationEvent,
              "account-deactivation": IAccountDeactivationEvent,
+             "account-archive": IAccountArchiveEvent,
+             "account-unarchive": IAccountUnarchiveEvent,
              "account-selection": IAccountSelectionEvent,
              "account-create": IAccountCreateEvent,
              "account-update": IAccountUpdateEvent,
@@ -215,10 +255,22 @@ cfg_if! {
              "daa-score-change": IDaaScoreChangeEvent,
              "pending": IPendingEvent,
              "reorg": IReorgEvent,
+             "reorged-out": IReorgedOutEvent,
+             "reaccepted": IReacceptedEvent,
+            "reaccepted": IReacceptedEvent,
              "stasis": IStasisEvent,
              "maturity": IMaturityEvent,
              "discovery": IDiscoveryEvent,
              "balance": IBalanceEvent,
+             "task-deadline": ITaskDeadlineEvent,
+             "fee-bump": IFeeBumpEvent,
+             "fee-bump-abandoned": IFeeBumpAbandonedEvent,
+             "market": IMarketEvent,
+             "payment-batch-progress": IPaymentBatchProgressEvent,
+             "generator-progress": IGeneratorProgressEvent,
+             "payment-received": IPaymentReceivedEvent,
+             "scan-interrupted": IScanInterruptedEvent,
+             "scan-resumed": IScanResumedEvent,
              "error": IErrorEvent,
         }
         
@@ -322,6 +374,47 @@ declare! {
     "#,
 }
 
+#[cfg(feature = "wasm32-sdk")]
+declare! {
+    IWalletSyncEvent,
+    r#"
+    /**
+     * Progress of the wallet synchronization.
+     * 
+     * @category Wallet Events
+     */
+    export interface IWalletSyncProgress {
+        phase : "idle" | "address-scan" | "utxo-reconciliation" | "history-backfill" | "synced";
+        /**
+         * Number of items processed within the phase (addresses for
+         * the address scan, accounts for the UTXO reconciliation,
+         * chain blocks for the history backfill).
+         */
+        processed : number;
+        /**
+         * Total number of items to process within the phase. The total
+         * of the address scan grows as the scan discovers used addresses.
+         */
+        total : number;
+        /**
+         * Completion percentage of the phase (0-100).
+         */
+        percentage : number;
+    }
+
+    /**
+     * Emitted by {@link Wallet} on each wallet sync phase transition
+     * and progress percentage change. The current progress is also
+     * available via the `getStatus()` Wallet API call.
+     * 
+     * @category Wallet Events
+     */
+    export interface IWalletSyncEvent {
+        progress : IWalletSyncProgress;
+    }
+    "#,
+}
+
 #[cfg(feature = "wasm32-sdk")]
 declare! {
     IWalletHintEvent,
@@ -400,6 +493,22 @@ declare! {
     "#,
 }
 
+#[cfg(feature = "wasm32-sdk")]
+declare! {
+    IWalletLockedEvent,
+    r#"
+    /**
+     * Emitted by {@link Wallet} when the wallet has been closed due to inactivity
+     * (auto-lock). The wallet secret is required to re-open the wallet.
+     * 
+     * @category Wallet Events
+     */
+    export interface IWalletLockedEvent {
+        walletDescriptor? : IWalletDescriptor;
+    }
+    "#,
+}
+
 #[cfg(feature = "wasm32-sdk")]
 declare! {
     IPrvKeyDataCreateEvent,
@@ -445,6 +554,37 @@ declare! {
     "#,
 }
 
+#[cfg(feature = "wasm32-sdk")]
+declare! {
+    IAccountArchiveEvent,
+    r#"
+    /**
+     * Emitted by {@link Wallet} when accounts have been archived.
+     * Archived accounts are excluded from account enumeration.
+     * 
+     * @category Wallet Events
+     */
+    export interface IAccountArchiveEvent {
+        ids : HexString[];
+    }
+    "#,
+}
+
+#[cfg(feature = "wasm32-sdk")]
+declare! {
+    IAccountUnarchiveEvent,
+    r#"
+    /**
+     * Emitted by {@link Wallet} when archived accounts have been restored.
+     * 
+     * @category Wallet Events
+     */
+    export interface IAccountUnarchiveEvent {
+        ids : HexString[];
+    }
+    "#,
+}
+
 #[cfg(feature = "wasm32-sdk")]
 declare! {
     IAccountSelectionEvent,
@@ -573,6 +713,39 @@ declare! {
     "#,
 }
 
+declare! {
+    IReorgedOutEvent,
+    r#"
+    /**
+     * Emitted by {@link UtxoContext} when the acceptance of an outgoing transaction
+     * is reverted by a chain reorganization (detected via the virtual chain changes).
+     * UTXOs consumed by the transaction are returned to the UtxoContext spendable set
+     * and the transaction record is flagged as `reorged`. The transaction may be
+     * accepted again if it remains in the node mempool.
+     * This notification will be followed by the "balance" event.
+     * 
+     * @category Wallet Events
+     */
+    export type IReorgedOutEvent = TransactionRecord;
+    "#,
+}
+
+declare! {
+    IReacceptedEvent,
+    r#"
+    /**
+     * Emitted by {@link UtxoContext} when an outgoing transaction previously reverted
+     * by a chain reorganization (see {@link IReorgedOutEvent}) is accepted again.
+     * UTXOs consumed by the transaction are removed from the UtxoContext spendable set
+     * and the transaction record is no longer flagged as `reorged`.
+     * This notification will be followed by the "balance" event.
+     * 
+     * @category Wallet Events
+     */
+    export type IReacceptedEvent = TransactionRecord;
+    "#,
+}
+
 declare! {
     IStasisEvent,
     r#"
@@ -642,6 +815,243 @@ declare! {
     "#,
 }
 
+#[cfg(feature = "wasm32-sdk")]
+declare! {
+    ITaskDeadlineEvent,
+    r#"
+    /**
+     * Task descriptor of a long-running wallet operation
+     * monitored by the wallet watchdog.
+     * 
+     * @category Wallet API
+     */
+    export interface ITaskDescriptor {
+        id : number;
+        kind : "scan" | "send" | "transfer" | "discovery";
+        accountId? : HexString;
+        /**
+         * Time elapsed since the task start (in milliseconds).
+         */
+        elapsed : number;
+        /**
+         * Task deadline (in milliseconds).
+         */
+        deadline? : number;
+    }
+
+    /**
+     * Emitted by {@link Wallet} when a long-running wallet operation
+     * (account scan, send, transfer or discovery) exceeds its deadline.
+     * The task can be aborted using the `tasksAbort()` Wallet API call.
+     * 
+     * @category Wallet Events
+     */
+    export interface ITaskDeadlineEvent {
+        task : ITaskDescriptor;
+    }
+    "#,
+}
+
+declare! {
+    IFeeBumpEvent,
+    r#"
+    /**
+     * Emitted by {@link Wallet} when a send tagged with a fee-bump policy
+     * has not been accepted within the policy deadline and has been
+     * regenerated with a higher fee, replacing the pending transaction.
+     * 
+     * @category Wallet Events
+     */
+    export interface IFeeBumpEvent {
+        accountId : HexString;
+        replacedTransactionId : HexString;
+        transactionId : HexString;
+        fees : bigint;
+    }
+    "#,
+}
+
+declare! {
+    IFeeBumpAbandonedEvent,
+    r#"
+    /**
+     * Emitted by {@link Wallet} when a send tagged with a fee-bump policy
+     * can not be regenerated within the policy maximum fee.
+     * 
+     * @category Wallet Events
+     */
+    export interface IFeeBumpAbandonedEvent {
+        accountId : HexString;
+        transactionId : HexString;
+    }
+    "#,
+}
+
+declare! {
+    IMarketEvent,
+    r#"
+    /**
+     * KAS market price in a fiat currency.
+     * 
+     * @category Wallet API
+     */
+    export interface IMarketPrice {
+        /**
+         * Lowercase currency code (i.e. `usd`).
+         */
+        currency : string;
+        /**
+         * Price of 1 KAS.
+         */
+        price : number;
+        marketCap? : number;
+        /**
+         * Price change over the last 24 hours (in percent).
+         */
+        change24h? : number;
+    }
+
+    /**
+     * Market data obtained from the wallet price feed.
+     * 
+     * @category Wallet API
+     */
+    export interface IMarketData {
+        /**
+         * Name of the price feed (i.e. `coingecko`).
+         */
+        source : string;
+        /**
+         * Time at which the data was obtained (in milliseconds).
+         */
+        unixtimeMsec : number;
+        prices : IMarketPrice[];
+    }
+
+    /**
+     * Emitted by {@link Wallet} each time the market data is obtained
+     * from the price feed configured via the `marketConfigure()`
+     * Wallet API call.
+     * 
+     * @category Wallet Events
+     */
+    export interface IMarketEvent {
+        market : IMarketData;
+    }
+    "#,
+}
+
+declare! {
+    IPaymentBatchProgressEvent,
+    r#"
+    /**
+     * Emitted by {@link Wallet} for each transaction submitted while
+     * processing a payment batch (see `accountsSendBatch()` Wallet API call).
+     * 
+     * @category Wallet Events
+     */
+    export interface IPaymentBatchProgressEvent {
+        accountId : HexString;
+        transactionId : HexString;
+        /**
+         * Index of the batch partition (a transaction carrying
+         * a set of batch payments) being processed.
+         */
+        partition : number;
+        /**
+         * Total number of batch partitions.
+         */
+        partitions : number;
+        /**
+         * Number of batch payments submitted so far.
+         */
+        payments : number;
+        /**
+         * Total number of batch payments.
+         */
+        totalPayments : number;
+        /**
+         * Fees paid by the transaction (in SOMPI).
+         */
+        fees : number;
+        /**
+         * `true` if the transaction carries the batch payments,
+         * `false` if it is a preceding UTXO compound transaction.
+         */
+        isFinal : boolean;
+    }
+    "#,
+}
+
+declare! {
+    IGeneratorProgressEvent,
+    r#"
+    /**
+     * Emitted by {@link Wallet} for each transaction submitted by the
+     * `accountsSend()` and `accountsTransfer()` Wallet API calls, allowing
+     * to track the progress of operations producing multiple (compound)
+     * transactions. The total number of transactions is not obtained
+     * in advance and is reported only with the final transaction.
+     * 
+     * @category Wallet Events
+     */
+    export interface IGeneratorProgressEvent {
+        accountId : HexString;
+        progress : IGeneratorProgress;
+    }
+    "#,
+}
+
+declare! {
+    IPaymentReceivedEvent,
+    r#"
+    /**
+     * Emitted by {@link Wallet} when a transaction fulfilling a payment
+     * request (see `accountsCreatePaymentRequest()` Wallet API call)
+     * has been received.
+     * 
+     * @category Wallet Events
+     */
+    export interface IPaymentReceivedEvent {
+        requestId : HexString;
+        txid : HexString;
+    }
+    "#,
+}
+
+declare! {
+    IScanInterruptedEvent,
+    r#"
+    /**
+     * Emitted by {@link Wallet} when the account address scan is interrupted
+     * by the loss of the RPC connection. The account UTXO set is rolled back
+     * to its state prior to the scan and the scan is resumed upon reconnection
+     * (see {@link IScanResumedEvent}).
+     * 
+     * @category Wallet Events
+     */
+    export interface IScanInterruptedEvent {
+        accountId : HexString;
+        message : string;
+    }
+    "#,
+}
+
+declare! {
+    IScanResumedEvent,
+    r#"
+    /**
+     * Emitted by {@link Wallet} when an interrupted account
+     * address scan is resumed after reconnection.
+     * 
+     * @category Wallet Events
+     */
+    export interface IScanResumedEvent {
+        accountId : HexString;
+    }
+    "#,
+}
+
 declare! {
     IErrorEvent,
     r#"
@@ -0,0 +1,61 @@
+use crate::imports::*;
+use crate::ownership::*;
+use serde_wasm_bindgen::from_value;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_OWNERSHIP_TYPES: &'static str = r#"
+/**
+ * Extended public key accompanied by a proof of its ownership
+ * signed by the key of the first receive address derived from it.
+ *
+ * @see {@link IAccountsExportXpubResponse}, {@link verifyXPubOwnershipProof}
+ * @category Wallet API
+ */
+export interface IXPubOwnershipProof {
+    xpub: string;
+    address: string;
+    /**
+     * Proof creation time (unix time in milliseconds).
+     */
+    timestamp: number;
+    signature: HexString;
+}
+
+/**
+ * Interface declaration for {@link verifyXPubOwnershipProof} function arguments.
+ *
+ * @category Wallet API
+ */
+export interface IVerifyXPubOwnershipProof {
+    proof: IXPubOwnershipProof;
+    /**
+     * Maximum accepted proof age in milliseconds (unlimited if not supplied).
+     */
+    maxAge?: number;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = js_sys::Object, typescript_type = "IVerifyXPubOwnershipProof")]
+    pub type IVerifyXPubOwnershipProof;
+}
+
+/// Verifies an xpub ownership proof produced by the wallet `accountsExportXpub()` call.
+/// Returns `false` if the signature is invalid and throws if the proof is malformed,
+/// expired or the address does not belong to the supplied xpub.
+/// @category Wallet API
+#[wasm_bindgen(js_name = verifyXPubOwnershipProof)]
+pub fn js_verify_xpub_ownership_proof(value: IVerifyXPubOwnershipProof) -> Result<bool, Error> {
+    if let Some(object) = Object::try_from(&value) {
+        let proof: XPubOwnershipProof = from_value(object.get_value("proof")?)?;
+        let max_age = object.get_u64("maxAge").ok();
+        match proof.verify(max_age) {
+            Ok(()) => Ok(true),
+            Err(Error::InvalidOwnershipProofSignature) => Ok(false),
+            Err(err) => Err(err),
+        }
+    } else {
+        Err(Error::custom("Failed to parse input"))
+    }
+}
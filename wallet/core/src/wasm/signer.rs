@@ -2,8 +2,9 @@ use crate::imports::*;
 use crate::result::Result;
 use js_sys::Array;
 use kaspa_consensus_client::{sign_with_multiple_v3, Transaction};
-use kaspa_consensus_core::tx::PopulatedTransaction;
-use kaspa_consensus_core::{hashing::sighash_type::SIG_HASH_ALL, sign::verify};
+use kaspa_consensus_core::hashing::sighash_type::{SigHashType, SIG_HASH_ALL};
+use kaspa_consensus_core::sign::verify;
+use kaspa_consensus_core::tx::{PopulatedTransaction, SignableTransaction};
 use kaspa_hashes::Hash;
 use kaspa_wallet_keys::privatekey::PrivateKey;
 use serde_wasm_bindgen::from_value;
@@ -79,3 +80,52 @@ pub fn sign_hash(sig_hash: Hash, privkey: &[u8; 32]) -> Result<Vec<u8>> {
     let signature = std::iter::once(65u8).chain(sig).chain([SIG_HASH_ALL.to_u8()]).collect();
     Ok(signature)
 }
+
+/// Signature hash type, determining the parts of the transaction
+/// committed to by an input signature.
+/// @category Wallet SDK
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug)]
+pub enum SighashType {
+    All = 0x01,
+    None = 0x02,
+    Single = 0x04,
+    AllAnyOneCanPay = 0x81,
+    NoneAnyOneCanPay = 0x82,
+    SingleAnyOneCanPay = 0x84,
+}
+
+impl From<SighashType> for SigHashType {
+    fn from(sighash_type: SighashType) -> Self {
+        SigHashType::from_u8(sighash_type as u8).expect("valid sighash type")
+    }
+}
+
+/// Convert a {@link SighashType} value supplied by JavaScript to [`SigHashType`].
+pub fn try_sighash_type_from_js_value(value: &JsValue) -> Result<SigHashType> {
+    let sighash_type = value.as_f64().ok_or_else(|| Error::custom("sighashType should be a number"))?;
+    SigHashType::from_u8(sighash_type as u8).map_err(Error::custom)
+}
+
+/// Computes the signature hashes of all transaction inputs for the given
+/// {@link SighashType} (default: `SighashType.All`), allowing the input
+/// signatures to be produced by an external signer. Schnorr signature hashes
+/// are produced unless `ecdsa` is set. The UTXO entries of all inputs must
+/// be populated and the inputs must carry their final `sigOpCount`.
+/// @category Wallet SDK
+#[wasm_bindgen(js_name = "calcSignatureHashes")]
+pub fn js_calc_signature_hashes(tx: &Transaction, sighash_type: Option<SighashType>, ecdsa: Option<bool>) -> Result<Vec<String>> {
+    if let Some(index) = tx.inner().inputs.iter().position(|input| input.get_utxo().is_none()) {
+        return Err(Error::InvalidSignableTransaction(format!("missing UTXO entry of input {index}")));
+    }
+
+    let (tx, entries) = tx.tx_and_utxos();
+    let transaction = SignableTransaction::with_entries(tx, entries);
+    let hash_type = sighash_type.map(SigHashType::from).unwrap_or(SIG_HASH_ALL);
+    let hashes = if ecdsa.unwrap_or(false) {
+        crate::tx::calc_ecdsa_signature_hashes(&transaction, hash_type)?
+    } else {
+        crate::tx::calc_signature_hashes(&transaction, hash_type)?
+    };
+    Ok(hashes.into_iter().map(|hash| hash.to_hex()).collect())
+}
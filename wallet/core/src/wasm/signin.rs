@@ -0,0 +1,107 @@
+use crate::imports::*;
+use crate::signin::*;
+use kaspa_wallet_keys::privatekey::PrivateKey;
+use kaspa_wasm_core::types::HexString;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_SIGNIN_TYPES: &'static str = r#"
+/**
+ * Interface declaration for {@link createSignInChallenge} function arguments.
+ *
+ * @category Message Signing
+ */
+export interface ICreateSignInChallenge {
+    domain: string;
+    address: Address | string;
+    statement?: string;
+    /**
+     * Challenge validity period in milliseconds (default: 5 minutes).
+     */
+    validity?: number;
+}
+
+/**
+ * Interface declaration for {@link signSignInChallenge} function arguments.
+ *
+ * @category Message Signing
+ */
+export interface ISignSignInChallenge {
+    message: string;
+    privateKey: PrivateKey | string;
+}
+
+/**
+ * Interface declaration for {@link verifySignInChallenge} function arguments.
+ *
+ * @category Message Signing
+ */
+export interface IVerifySignInChallenge {
+    message: string;
+    signature: HexString;
+    domain: string;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = js_sys::Object, typescript_type = "ICreateSignInChallenge")]
+    pub type ICreateSignInChallenge;
+    #[wasm_bindgen(extends = js_sys::Object, typescript_type = "ISignSignInChallenge")]
+    pub type ISignSignInChallenge;
+    #[wasm_bindgen(extends = js_sys::Object, typescript_type = "IVerifySignInChallenge")]
+    pub type IVerifySignInChallenge;
+}
+
+/// Creates a "Sign-in with Kaspa" challenge message for the given address.
+/// The resulting message should be delivered to the client for signing.
+/// @category Message Signing
+#[wasm_bindgen(js_name = createSignInChallenge)]
+pub fn js_create_signin_challenge(value: ICreateSignInChallenge) -> Result<String, Error> {
+    if let Some(object) = Object::try_from(&value) {
+        let domain = object.get_string("domain")?;
+        let address = object.get_cast::<Address>("address")?;
+        let statement = object.try_get_string("statement")?;
+        let validity = object.get_u64("validity").ok();
+        let challenge = SignInChallenge::new(&domain, address.as_ref(), statement, validity)?;
+        Ok(challenge.to_message())
+    } else {
+        Err(Error::custom("Failed to parse input"))
+    }
+}
+
+/// Signs a "Sign-in with Kaspa" challenge message with the given private key.
+/// @category Message Signing
+#[wasm_bindgen(js_name = signSignInChallenge)]
+pub fn js_sign_signin_challenge(value: ISignSignInChallenge) -> Result<HexString, Error> {
+    if let Some(object) = Object::try_from(&value) {
+        let private_key = object.get_cast::<PrivateKey>("privateKey")?;
+        let challenge = SignInChallenge::from_message(&object.get_string("message")?)?;
+        let mut privkey_bytes = [0u8; 32];
+        privkey_bytes.copy_from_slice(&private_key.as_ref().secret_bytes());
+        let signature = challenge.sign(&privkey_bytes);
+        privkey_bytes.zeroize();
+        Ok(signature?.into())
+    } else {
+        Err(Error::custom("Failed to parse input"))
+    }
+}
+
+/// Verifies a signed "Sign-in with Kaspa" challenge message against the
+/// expected relying party domain. Returns `false` if the signature is invalid
+/// and throws if the challenge is malformed, expired or bound to another domain.
+/// @category Message Signing
+#[wasm_bindgen(js_name = verifySignInChallenge)]
+pub fn js_verify_signin_challenge(value: IVerifySignInChallenge) -> Result<bool, Error> {
+    if let Some(object) = Object::try_from(&value) {
+        let challenge = SignInChallenge::from_message(&object.get_string("message")?)?;
+        let signature = object.get_string("signature")?;
+        let domain = object.get_string("domain")?;
+        match challenge.verify(&signature, &domain) {
+            Ok(()) => Ok(true),
+            Err(Error::InvalidSignInSignature) => Ok(false),
+            Err(err) => Err(err),
+        }
+    } else {
+        Err(Error::custom("Failed to parse input"))
+    }
+}
@@ -19,11 +19,16 @@ declare! {
     "IFees | bigint",
     r#"
     /**
+     * Priority fees expressed as an absolute `amount` (in SOMPI)
+     * or as a `rate` (in SOMPI per gram of transaction mass).
+     * Rates are converted to absolute fees by the transaction
+     * generator using the computed transaction mass.
      * 
      * @category Wallet SDK
      */
     export interface IFees {
-        amount: bigint;
+        amount?: bigint;
+        rate?: number;
         source?: FeeSource;
     }
     "#,
@@ -37,15 +42,25 @@ impl TryFrom<IFees> for Fees {
         } else if let Ok(fee) = args.try_as_u64() {
             Ok(Fees::SenderPays(fee))
         } else if let Ok(object) = args.dyn_into::<Object>() {
-            let amount = object.get_u64("amount")?;
-            if let Some(source) = object.try_get_value("source")? {
-                let source = FeeSource::try_cast_from(&source)?;
-                match source {
-                    FeeSource::SenderPays => Ok(Fees::SenderPays(amount)),
-                    FeeSource::ReceiverPays => Ok(Fees::ReceiverPays(amount)),
+            let source = object.try_get_value("source")?.map(|source| FeeSource::try_cast_from(&source)).transpose()?;
+            let receiver_pays = matches!(source, Some(FeeSource::ReceiverPays));
+            if let Some(rate) = object.try_get_value("rate")?.filter(|rate| !rate.is_undefined() && !rate.is_null()) {
+                let rate = rate.as_f64().ok_or_else(|| Error::custom("Invalid fee rate"))?;
+                if !rate.is_finite() || rate < 0.0 {
+                    return Err(Error::custom(format!("Invalid fee rate: {rate}")));
+                }
+                if receiver_pays {
+                    Ok(Fees::ReceiverPaysRate(rate))
+                } else {
+                    Ok(Fees::SenderPaysRate(rate))
                 }
             } else {
-                Ok(Fees::SenderPays(amount))
+                let amount = object.get_u64("amount")?;
+                if receiver_pays {
+                    Ok(Fees::ReceiverPays(amount))
+                } else {
+                    Ok(Fees::SenderPays(amount))
+                }
             }
         } else {
             Err(crate::error::Error::custom("Invalid fee"))
@@ -0,0 +1,309 @@
+//!
+//! [`GeneratorSettingsBuilder`] - typed construction of the
+//! transaction [`Generator`] settings.
+//!
+
+use crate::imports::*;
+use crate::result::Result;
+use crate::tx::{ChangePolicy, CoinControl, Fees, PaymentDestination, PaymentOutputs};
+use crate::utxo::{TryIntoUtxoEntryReferences, UtxoEntryId, UtxoEntryReference};
+use crate::wasm::tx::generator::*;
+use crate::wasm::tx::IFees;
+use crate::wasm::{SighashType, UtxoContext};
+use kaspa_addresses::AddressT;
+use kaspa_consensus_core::hashing::sighash_type::{SigHashType, SIG_HASH_ALL};
+use kaspa_consensus_core::network::NetworkIdT;
+use kaspa_wasm_core::types::BinaryT;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "PaymentOutputs | IPaymentOutput[]")]
+    pub type PaymentOutputsT;
+    #[wasm_bindgen(typescript_type = "IUtxoEntry[] | UtxoEntryReference[]")]
+    pub type UtxoEntriesT;
+    #[wasm_bindgen(typescript_type = "IChangePolicy")]
+    pub type IChangePolicy;
+    #[wasm_bindgen(typescript_type = "(ITransactionOutpoint | string)[]")]
+    pub type OutpointsT;
+}
+
+struct State {
+    network_id: Option<NetworkId>,
+    source: Option<GeneratorSource>,
+    final_transaction_destination: Option<PaymentDestination>,
+    change_address: Option<Address>,
+    final_priority_fee: Option<Fees>,
+    sig_op_count: u8,
+    minimum_signatures: u16,
+    payload: Option<Vec<u8>>,
+    change_policy: ChangePolicy,
+    sighash_type: SigHashType,
+    coin_control: Option<CoinControl>,
+    on_progress: Option<js_sys::Function>,
+    // conversion errors of the supplied values reported by `build()`
+    errors: Vec<String>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            network_id: None,
+            source: None,
+            final_transaction_destination: None,
+            change_address: None,
+            final_priority_fee: None,
+            sig_op_count: 1,
+            minimum_signatures: 1,
+            payload: None,
+            change_policy: ChangePolicy::default(),
+            sighash_type: SIG_HASH_ALL,
+            coin_control: None,
+            on_progress: None,
+            errors: vec![],
+        }
+    }
+}
+
+impl State {
+    fn set<T, E: std::fmt::Display>(&mut self, property: &str, value: std::result::Result<T, E>, f: impl FnOnce(&mut Self, T)) {
+        match value {
+            Ok(value) => f(self, value),
+            Err(err) => self.errors.push(format!("{property}: {err}")),
+        }
+    }
+}
+
+/// Typed builder of the transaction {@link Generator} settings, providing an
+/// alternative to the {@link IGeneratorSettingsObject} object. Values supplied
+/// to the builder are validated as they are set and all detected errors are
+/// reported by {@link GeneratorSettingsBuilder.build} (or by any function
+/// accepting the builder in place of the settings object).
+///
+/// ```javascript
+///
+/// let generator = new GeneratorSettingsBuilder()
+///     .utxoContext(context)
+///     .outputs([{ address : "kaspa:...", amount : kaspaToSompi(10.0) }])
+///     .changeAddress("kaspa:...")
+///     .priorityFee(1000n)
+///     .build();
+///
+/// ```
+///
+/// Setters return the builder, allowing calls to be chained.
+///
+/// @see {@link IGeneratorSettingsObject}, {@link Generator}, {@link createTransactions}, {@link estimateTransactions}
+/// @category Wallet SDK
+#[derive(Clone, Default, CastFromJs)]
+#[wasm_bindgen]
+pub struct GeneratorSettingsBuilder {
+    state: Arc<Mutex<State>>,
+}
+
+#[wasm_bindgen]
+impl GeneratorSettingsBuilder {
+    #[wasm_bindgen(constructor)]
+    pub fn ctor() -> GeneratorSettingsBuilder {
+        Self::default()
+    }
+
+    /// Final transaction outputs (do not supply change outputs). If outputs
+    /// are not supplied, the generator creates a sweep transaction
+    /// compounding all UTXO entries into the change address.
+    pub fn outputs(&self, outputs: PaymentOutputsT) -> GeneratorSettingsBuilder {
+        let outputs = PaymentOutputs::try_owned_from(JsValue::from(outputs));
+        self.state().set("outputs", outputs, |state, outputs| {
+            if outputs.outputs.is_empty() {
+                state.errors.push("outputs: at least one output is required".to_string());
+            } else {
+                state.final_transaction_destination = Some(outputs.into());
+            }
+        });
+        self.clone()
+    }
+
+    /// Address to be used for change.
+    #[wasm_bindgen(js_name = changeAddress)]
+    pub fn change_address(&self, address: AddressT) -> GeneratorSettingsBuilder {
+        let address = Address::try_owned_from(JsValue::from(address));
+        self.state().set("changeAddress", address, |state, address| state.change_address = Some(address));
+        self.clone()
+    }
+
+    /// Priority fee in SOMPI (`bigint` value is interpreted as a sender-pays fee).
+    /// The priority fee is required when outputs are supplied (even if it is zero)
+    /// and must not be set for sweep transactions.
+    ///
+    /// @see {@link IFees}
+    #[wasm_bindgen(js_name = priorityFee)]
+    pub fn priority_fee(&self, fee: IFees) -> GeneratorSettingsBuilder {
+        let fee = Fees::try_from(fee);
+        self.state().set("priorityFee", fee, |state, fee| state.final_priority_fee = Some(fee));
+        self.clone()
+    }
+
+    /// Data payload to be included in the transaction.
+    pub fn payload(&self, payload: BinaryT) -> GeneratorSettingsBuilder {
+        let payload = JsValue::from(payload).try_as_vec_u8();
+        self.state().set("payload", payload, |state, payload| state.payload = Some(payload));
+        self.clone()
+    }
+
+    /// Number of signature operations per input (default: 1).
+    #[wasm_bindgen(js_name = sigOpCount)]
+    pub fn sig_op_count(&self, sig_op_count: u8) -> GeneratorSettingsBuilder {
+        self.state().sig_op_count = sig_op_count;
+        self.clone()
+    }
+
+    /// Minimum number of signatures required per input (default: 1).
+    #[wasm_bindgen(js_name = minimumSignatures)]
+    pub fn minimum_signatures(&self, minimum_signatures: u16) -> GeneratorSettingsBuilder {
+        self.state().minimum_signatures = minimum_signatures;
+        self.clone()
+    }
+
+    /// Use the {@link UtxoContext} as the source of UTXO entries. The network
+    /// id is obtained from the context.
+    #[wasm_bindgen(js_name = utxoContext)]
+    pub fn utxo_context(&self, context: &UtxoContext) -> GeneratorSettingsBuilder {
+        self.state().source = Some(GeneratorSource::UtxoContext(context.clone()));
+        self.clone()
+    }
+
+    /// Use an array of UTXO entries as the source of UTXO entries. Requires
+    /// the network id to be supplied via {@link GeneratorSettingsBuilder.networkId}.
+    #[wasm_bindgen(js_name = utxoEntries)]
+    pub fn utxo_entries(&self, entries: UtxoEntriesT) -> GeneratorSettingsBuilder {
+        let entries = JsValue::from(entries).try_into_utxo_entry_references();
+        self.state().set("utxoEntries", entries, |state, entries: Vec<UtxoEntryReference>| {
+            state.source = Some(GeneratorSource::UtxoEntries(entries))
+        });
+        self.clone()
+    }
+
+    /// Network id (i.e. `mainnet` or `testnet-11`), required when using {@link GeneratorSettingsBuilder.utxoEntries}.
+    #[wasm_bindgen(js_name = networkId)]
+    pub fn network_id(&self, network_id: NetworkIdT) -> GeneratorSettingsBuilder {
+        let network_id = NetworkId::try_owned_from(JsValue::from(network_id));
+        self.state().set("networkId", network_id, |state, network_id| state.network_id = Some(network_id));
+        self.clone()
+    }
+
+    /// Handling of the final transaction change that is too small
+    /// to be issued as a change output (default: `fold-to-fee`).
+    ///
+    /// @see {@link IChangePolicy}
+    #[wasm_bindgen(js_name = changePolicy)]
+    pub fn change_policy(&self, policy: IChangePolicy) -> GeneratorSettingsBuilder {
+        let policy = serde_wasm_bindgen::from_value::<ChangePolicy>(policy.into());
+        self.state().set("changePolicy", policy, |state, policy| state.change_policy = policy);
+        self.clone()
+    }
+
+    /// Sighash type used to sign the final transaction inputs (default: `SighashType.All`).
+    ///
+    /// @see {@link SighashType}
+    #[wasm_bindgen(js_name = sighashType)]
+    pub fn sighash_type(&self, sighash_type: SighashType) -> GeneratorSettingsBuilder {
+        self.state().sighash_type = sighash_type.into();
+        self.clone()
+    }
+
+    /// Outpoints of UTXOs that must be spent by the generated transactions
+    /// (coin control). If `exclusive` is `true`, only the supplied UTXOs are
+    /// spent; otherwise additional UTXO entries are selected if the supplied
+    /// UTXOs are insufficient to cover the transaction amount and fees.
+    #[wasm_bindgen(js_name = coinControl)]
+    pub fn coin_control(&self, utxos: OutpointsT, exclusive: Option<bool>) -> GeneratorSettingsBuilder {
+        let utxos = Array::from(&utxos).iter().map(|utxo| UtxoEntryId::try_from(&utxo)).collect::<std::result::Result<Vec<_>, _>>();
+        self.state()
+            .set("coinControl", utxos, |state, utxos| state.coin_control = Some(CoinControl::new(utxos, exclusive.unwrap_or(false))));
+        self.clone()
+    }
+
+    /// Callback invoked for each transaction produced by the {@link Generator}.
+    ///
+    /// @see {@link IGeneratorProgress}
+    #[wasm_bindgen(js_name = onProgress)]
+    pub fn on_progress(&self, callback: js_sys::Function) -> GeneratorSettingsBuilder {
+        self.state().on_progress = Some(callback);
+        self.clone()
+    }
+
+    /// Validate the settings, throwing an error describing all invalid or missing values.
+    pub fn validate(&self) -> Result<()> {
+        self.validation_errors().map(Error::GeneratorInvalidSettings).map_or(Ok(()), Err)
+    }
+
+    /// Validate the settings and create a {@link Generator}.
+    pub fn build(&self) -> Result<Generator> {
+        Generator::try_new_with_settings(self.try_settings()?)
+    }
+}
+
+impl GeneratorSettingsBuilder {
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap()
+    }
+
+    fn validation_errors(&self) -> Option<Vec<String>> {
+        let state = self.state();
+        let mut errors = state.errors.clone();
+
+        match state.source {
+            None => errors.push("UTXO entries are required (use `utxoContext()` or `utxoEntries()`)".to_string()),
+            Some(GeneratorSource::UtxoEntries(_)) if state.network_id.is_none() => {
+                errors.push("networkId is required when using `utxoEntries()`".to_string())
+            }
+            _ => {}
+        }
+
+        if state.change_address.is_none() {
+            errors.push("changeAddress is required".to_string());
+        }
+
+        match (&state.final_transaction_destination, &state.final_priority_fee) {
+            (Some(_), None) => {
+                errors.push("priorityFee is required for transactions with outputs (use 0n for no priority fee)".to_string())
+            }
+            (None, Some(fees)) if !matches!(fees, Fees::None) => {
+                errors.push("priorityFee can not be set for sweep transactions (transactions without outputs)".to_string())
+            }
+            _ => {}
+        }
+
+        if state.sig_op_count == 0 {
+            errors.push("sigOpCount must be greater than 0".to_string());
+        }
+        if state.minimum_signatures == 0 {
+            errors.push("minimumSignatures must be greater than 0".to_string());
+        }
+
+        (!errors.is_empty()).then_some(errors)
+    }
+
+    /// Validate and convert the builder state into [`GeneratorSettings`].
+    pub(crate) fn try_settings(&self) -> Result<GeneratorSettings> {
+        if let Some(errors) = self.validation_errors() {
+            return Err(Error::GeneratorInvalidSettings(errors));
+        }
+
+        let state = self.state();
+        Ok(GeneratorSettings {
+            network_id: state.network_id,
+            source: state.source.clone().ok_or_else(|| Error::custom("missing generator source"))?,
+            multiplexer: None,
+            final_transaction_destination: state.final_transaction_destination.clone().unwrap_or(PaymentDestination::Change),
+            change_address: state.change_address.clone(),
+            final_priority_fee: state.final_priority_fee.clone().unwrap_or(Fees::None),
+            sig_op_count: state.sig_op_count,
+            minimum_signatures: state.minimum_signatures,
+            payload: state.payload.clone(),
+            change_policy: state.change_policy,
+            sighash_type: state.sighash_type,
+            coin_control: state.coin_control.clone(),
+            on_progress: state.on_progress.clone(),
+        })
+    }
+}
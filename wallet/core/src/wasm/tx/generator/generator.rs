@@ -1,11 +1,12 @@
 use crate::imports::*;
 use crate::result::Result;
-use crate::tx::{generator as native, Fees, PaymentDestination, PaymentOutputs};
-use crate::utxo::{TryIntoUtxoEntryReferences, UtxoEntryReference};
+use crate::tx::{generator as native, ChangePolicy, Fees, PaymentDestination, PaymentOutputs};
+use crate::utxo::{TryIntoUtxoEntryReferences, UtxoEntryId, UtxoEntryReference};
 use crate::wasm::tx::generator::*;
 use crate::wasm::tx::IFees;
 // use crate::wasm::wallet::Account;
-use crate::wasm::UtxoContext;
+use crate::wasm::{try_sighash_type_from_js_value, UtxoContext};
+use kaspa_consensus_core::hashing::sighash_type::{SigHashType, SIG_HASH_ALL};
 
 // TODO-WASM fix outputs
 #[wasm_bindgen(typescript_custom_section)]
@@ -81,16 +82,106 @@ interface IGeneratorSettingsObject {
      * Optional NetworkId or network id as string (i.e. `mainnet` or `testnet-11`). Required when {@link IGeneratorSettingsObject.entries} is array
      */
     networkId?: NetworkId | string
+
+    /**
+     * Optional handling of the final transaction change that is too small to be
+     * issued as a change output (default: `{ type: "fold-to-fee" }`).
+     *
+     * @see {@link IChangePolicy}
+     */
+    changePolicy?: IChangePolicy;
+
+    /**
+     * Optional sighash type used to sign the final transaction inputs
+     * (default: `SighashType.All`). Batch transactions are always signed
+     * using `SighashType.All`. The sighash type of individual inputs can be
+     * changed using {@link PendingTransaction.setSighashType}.
+     *
+     * @see {@link SighashType}
+     */
+    sighashType?: SighashType;
+
+    /**
+     * Optional outpoints of UTXOs (present in {@link IGeneratorSettingsObject.entries})
+     * that must be spent by the generated transactions (coin control). UTXOs
+     * supplied with a {@link UtxoContext} must be mature and not locked.
+     */
+    utxos?: (ITransactionOutpoint | string)[];
+
+    /**
+     * Spend only the supplied {@link IGeneratorSettingsObject.utxos}; otherwise
+     * additional UTXO entries are selected if the supplied UTXOs are insufficient
+     * to cover the transaction amount and fees (default: `false`).
+     */
+    exclusiveUtxos?: boolean;
+
+    /**
+     * Optional callback invoked for each transaction produced by the
+     * {@link Generator}, allowing to render the progress of operations
+     * producing multiple (compound) transactions. The generation can
+     * be cancelled using {@link Generator.abort}.
+     *
+     * @see {@link IGeneratorProgress}
+     */
+    onProgress?: (progress: IGeneratorProgress) => void;
+}
+
+/**
+ * Progress of the {@link Generator} reported to the `onProgress` callback
+ * of the {@link IGeneratorSettingsObject} for each produced transaction.
+ *
+ * @category Wallet SDK
+ */
+interface IGeneratorProgress {
+    /** Id of the produced transaction */
+    transactionId: HexString;
+    /** Index of the transaction (starting from 0) */
+    index: number;
+    /**
+     * Estimated total number of transactions, available if the
+     * total has been obtained in advance or once the final
+     * transaction has been produced
+     */
+    total?: number;
+    /** Fees of all transactions produced so far (in SOMPI) */
+    fees: bigint;
+    /** `true` if the transaction is the final transaction */
+    isFinal: boolean;
 }
+
+/**
+ * Handling of the final transaction change that can not be issued as a change output:
+ * - `fold-to-fee` - the change is added to the transaction fees
+ * - `add-to-recipient` - the change is added to the first transaction output
+ * - `accumulate-until-threshold` - additional UTXOs are consumed while the change
+ *   is below `threshold` (in SOMPI), then the change is added to the transaction fees
+ *
+ * @category Wallet SDK
+ */
+type IChangePolicy =
+    | { type: "fold-to-fee" }
+    | { type: "add-to-recipient" }
+    | { type: "accumulate-until-threshold", threshold: bigint };
 "#;
 
 #[wasm_bindgen]
 extern "C" {
-    #[wasm_bindgen(extends = Object, typescript_type = "IGeneratorSettingsObject")]
+    #[wasm_bindgen(extends = Object, typescript_type = "IGeneratorSettingsObject | GeneratorSettingsBuilder")]
     #[derive(Clone, Debug, PartialEq, Eq)]
     pub type IGeneratorSettingsObject;
 }
 
+#[wasm_bindgen(typescript_custom_section)]
+const TS_GENERATOR_ASYNC_ITERATOR: &'static str = r#"
+interface Generator {
+    /**
+     * Allows the Generator to be used in `for await...of` loops,
+     * yielding {@link PendingTransaction} objects.
+     */
+    [Symbol.asyncIterator](): AsyncIterator<PendingTransaction>;
+}
+"#;
+
 /// Generator is a type capable of generating transactions based on a supplied
 /// set of UTXO entries or a UTXO entry producer (such as {@link UtxoContext}). The Generator
 /// accumulates UTXO entries until it can generate a transaction that meets the
@@ -101,7 +192,8 @@ extern "C" {
 /// Each compound transaction results in a new UTXO, which is immediately reused in the
 /// subsequent transaction.
 ///
-/// The Generator constructor accepts a single {@link IGeneratorSettingsObject} object.
+/// The Generator constructor accepts a single {@link IGeneratorSettingsObject} object
+/// or a {@link GeneratorSettingsBuilder} instance.
 ///
 /// ```javascript
 ///
@@ -126,24 +218,170 @@ extern "C" {
 /// console.log(summary);
 ///
 /// ```
+///
+/// The Generator is also an async iterable, allowing transactions to be
+/// produced, signed and submitted incrementally:
+///
+/// ```javascript
+///
+/// for await (const pendingTransaction of generator) {
+///     await pendingTransaction.sign(privateKeys);
+///     await pendingTransaction.submit(rpc);
+/// }
+///
+/// ```
+///
+/// To obtain the transaction fees and the number of resulting transactions
+/// without producing them, use {@link Generator.estimate} on a separate
+/// Generator instance.
+///
+/// If the `onProgress` callback is supplied in the settings, it is invoked
+/// with {@link IGeneratorProgress} for each produced transaction. Transaction
+/// generation can be cancelled using {@link Generator.abort}.
+///
 /// @see
 ///     {@link IGeneratorSettingsObject},
+///     {@link GeneratorSettingsBuilder},
 ///     {@link PendingTransaction},
 ///     {@link UtxoContext},
 ///     {@link createTransactions},
 ///     {@link estimateTransactions},
 /// @category Wallet SDK
+#[derive(Clone)]
 #[wasm_bindgen]
 pub struct Generator {
     inner: Arc<native::Generator>,
+    abortable: Abortable,
+    progress: Option<ProgressCallback>,
+}
+
+/// `onProgress` callback along with the estimated total number of transactions.
+#[derive(Clone)]
+struct ProgressCallback {
+    callback: js_sys::Function,
+    total: usize,
 }
 
 #[wasm_bindgen]
 impl Generator {
     #[wasm_bindgen(constructor)]
     pub fn ctor(args: IGeneratorSettingsObject) -> Result<Generator> {
-        let settings = GeneratorSettings::try_from(args)?;
+        Self::try_new_with_settings(GeneratorSettings::try_from(args)?)
+    }
+
+    /// Generate next transaction
+    pub async fn next(&self) -> Result<JsValue> {
+        if let Some(transaction) = self.inner.generate_transaction().transpose() {
+            let transaction = transaction?;
+            self.notify_progress(&transaction)?;
+            Ok(PendingTransaction::from(transaction).into())
+        } else {
+            Ok(JsValue::NULL)
+        }
+    }
+
+    /// Returns an async iterator producing {@link PendingTransaction} objects.
+    /// This function is also available as `Generator[Symbol.asyncIterator]`,
+    /// allowing the Generator to be used in `for await...of` loops.
+    #[wasm_bindgen(js_name = "asyncIterator")]
+    pub fn async_iterator(&self) -> GeneratorAsyncIterator {
+        GeneratorAsyncIterator { generator: self.clone() }
+    }
+
+    /// Cancel the transaction generation. Subsequent attempts
+    /// to produce transactions will result in an error.
+    pub fn abort(&self) {
+        self.abortable.abort();
+    }
+
+    /// Process all remaining transactions (discarding them) and return
+    /// the resulting {@link GeneratorSummary}.
+    pub async fn estimate(&self) -> Result<GeneratorSummary> {
+        let mut stream = self.inner.stream();
+        while stream.try_next().await?.is_some() {}
+        Ok(self.summary())
+    }
+
+    /// Returns the {@link GeneratorSummary} for transactions produced so far.
+    pub fn summary(&self) -> GeneratorSummary {
+        self.inner.summary().into()
+    }
+}
+
+static ASYNC_ITERATOR_REGISTERED: AtomicBool = AtomicBool::new(false);
+
+/// Install `Generator.asyncIterator()` as `Generator.prototype[Symbol.asyncIterator]`.
+/// wasm-bindgen does not support symbol-named methods, so the prototype is
+/// obtained from a temporary Generator instance on first construction.
+fn register_async_iterator(generator: &Generator) -> Result<()> {
+    if ASYNC_ITERATOR_REGISTERED.swap(true, Ordering::SeqCst) {
+        return Ok(());
+    }
+
+    let instance = JsValue::from(generator.clone());
+    let prototype = Object::get_prototype_of(&instance);
+    let async_iterator = js_sys::Reflect::get(&prototype, &"asyncIterator".into())?;
+    js_sys::Reflect::set(&prototype, &js_sys::Symbol::async_iterator(), &async_iterator)?;
+
+    // release the temporary instance
+    let free = js_sys::Reflect::get(&instance, &"free".into())?;
+    if let Some(free) = free.dyn_ref::<js_sys::Function>() {
+        free.call0(&instance)?;
+    }
+
+    Ok(())
+}
+
+/// Async iterator over transactions produced by the {@link Generator}
+/// (returned by `Generator[Symbol.asyncIterator]()`).
+/// @category Wallet SDK
+#[wasm_bindgen]
+pub struct GeneratorAsyncIterator {
+    generator: Generator,
+}
+
+#[wasm_bindgen]
+impl GeneratorAsyncIterator {
+    /// Produce the next transaction as an `IteratorResult` object
+    /// (`{ value: PendingTransaction, done: false }` or `{ done: true }`).
+    pub async fn next(&self) -> Result<Object> {
+        let object = Object::new();
+        if let Some(transaction) = self.generator.inner.generate_transaction().transpose() {
+            let transaction = transaction?;
+            self.generator.notify_progress(&transaction)?;
+            object.set("value", &PendingTransaction::from(transaction).into())?;
+            object.set("done", &JsValue::FALSE)?;
+        } else {
+            object.set("done", &JsValue::TRUE)?;
+        }
+        Ok(object)
+    }
+}
 
+impl Generator {
+    pub(crate) fn try_new_with_settings(settings: GeneratorSettings) -> Result<Generator> {
+        let abortable = Abortable::default();
+
+        // the total number of transactions is obtained
+        // by producing all transactions in advance
+        let progress = if let Some(callback) = settings.on_progress.clone() {
+            let generator = native::Generator::try_new(Self::native_settings(settings.clone())?, None, Some(&abortable))?;
+            for transaction in generator.iter() {
+                transaction?;
+            }
+            Some(ProgressCallback { callback, total: generator.summary().number_of_generated_transactions() })
+        } else {
+            None
+        };
+
+        let inner = Arc::new(native::Generator::try_new(Self::native_settings(settings)?, None, Some(&abortable))?);
+        let generator = Self { inner, abortable, progress };
+        register_async_iterator(&generator)?;
+
+        Ok(generator)
+    }
+
+    fn native_settings(settings: GeneratorSettings) -> Result<native::GeneratorSettings> {
         let GeneratorSettings {
             network_id,
             source,
@@ -154,6 +392,10 @@ impl Generator {
             sig_op_count,
             minimum_signatures,
             payload,
+            change_policy,
+            sighash_type,
+            coin_control,
+            on_progress: _,
         } = settings;
 
         let settings = match source {
@@ -164,7 +406,10 @@ impl Generator {
                 let network_id =
                     network_id.ok_or_else(|| Error::custom("networkId is required for Generator constructor with UTXO entries"))?;
 
-                native::GeneratorSettings::try_new_with_iterator(
+                let required_utxo_entries =
+                    coin_control.as_ref().map(|coin_control| select_utxo_entries(&utxo_entries, &coin_control.utxos)).transpose()?;
+
+                let settings = native::GeneratorSettings::try_new_with_iterator(
                     network_id,
                     Box::new(utxo_entries.into_iter()),
                     change_address,
@@ -174,13 +419,20 @@ impl Generator {
                     final_priority_fee,
                     payload,
                     multiplexer,
-                )?
+                )?;
+
+                match (required_utxo_entries, coin_control) {
+                    (Some(utxo_entries), Some(coin_control)) => {
+                        settings.with_required_utxo_entries(utxo_entries, coin_control.exclusive)
+                    }
+                    _ => settings,
+                }
             }
             GeneratorSource::UtxoContext(utxo_context) => {
                 let change_address = change_address
                     .ok_or_else(|| Error::custom("changeAddress is required for Generator constructor with UTXO entries"))?;
 
-                native::GeneratorSettings::try_new_with_context(
+                let settings = native::GeneratorSettings::try_new_with_context(
                     utxo_context.into(),
                     change_address,
                     sig_op_count,
@@ -189,41 +441,40 @@ impl Generator {
                     final_priority_fee,
                     payload,
                     multiplexer,
-                )?
+                )?;
+
+                match coin_control.as_ref() {
+                    Some(coin_control) => settings.with_coin_control(coin_control)?,
+                    None => settings,
+                }
             } // GeneratorSource::Account(account) => {
               //     let account: Arc<dyn crate::account::Account> = account.into();
               //     native::GeneratorSettings::try_new_with_account(account, final_transaction_destination, final_priority_fee, None)?
               // }
-        };
-
-        let abortable = Abortable::default();
-        let generator = native::Generator::try_new(settings, None, Some(&abortable))?;
-
-        Ok(Self { inner: Arc::new(generator) })
-    }
-
-    /// Generate next transaction
-    pub async fn next(&self) -> Result<JsValue> {
-        if let Some(transaction) = self.inner.generate_transaction().transpose() {
-            let transaction = PendingTransaction::from(transaction?);
-            Ok(transaction.into())
-        } else {
-            Ok(JsValue::NULL)
         }
-    }
+        .with_change_policy(change_policy)
+        .with_sighash_type(sighash_type);
 
-    pub async fn estimate(&self) -> Result<GeneratorSummary> {
-        let mut stream = self.inner.stream();
-        while stream.try_next().await?.is_some() {}
-        Ok(self.summary())
+        Ok(settings)
     }
 
-    pub fn summary(&self) -> GeneratorSummary {
-        self.inner.summary().into()
+    /// Invoke the `onProgress` callback (if supplied) for the produced `transaction`.
+    pub(crate) fn notify_progress(&self, transaction: &native::PendingTransaction) -> Result<()> {
+        if let Some(ProgressCallback { callback, total }) = self.progress.as_ref() {
+            let progress = native::GeneratorProgress::new(&self.inner, transaction, Some(*total));
+            let object = Object::new();
+            object.set("transactionId", &progress.transaction_id.to_string().into())?;
+            object.set("index", &JsValue::from(progress.index as u32))?;
+            if let Some(total) = progress.total {
+                object.set("total", &JsValue::from(total as u32))?;
+            }
+            object.set("fees", &BigInt::from(progress.fees).into())?;
+            object.set("isFinal", &JsValue::from(progress.is_final))?;
+            callback.call1(&JsValue::UNDEFINED, &object.into())?;
+        }
+        Ok(())
     }
-}
 
-impl Generator {
     pub fn iter(&self) -> impl Iterator<Item = Result<native::PendingTransaction>> {
         self.inner.iter()
     }
@@ -233,7 +484,22 @@ impl Generator {
     }
 }
 
-enum GeneratorSource {
+/// Select UTXO entries matching the supplied outpoints (coin control).
+fn select_utxo_entries(utxo_entries: &[UtxoEntryReference], outpoints: &[UtxoEntryId]) -> Result<Vec<UtxoEntryReference>> {
+    let mut selected = AHashSet::with_capacity(outpoints.len());
+    outpoints
+        .iter()
+        .map(|outpoint| {
+            if !selected.insert(outpoint) {
+                return Err(Error::DuplicateUtxoEntry);
+            }
+            utxo_entries.iter().find(|utxo_entry| utxo_entry.id() == *outpoint).cloned().ok_or(Error::UtxoNotFound(outpoint.clone()))
+        })
+        .collect()
+}
+
+#[derive(Clone)]
+pub(crate) enum GeneratorSource {
     UtxoEntries(Vec<UtxoEntryReference>),
     UtxoContext(UtxoContext),
     // #[cfg(any(feature = "wasm32-sdk"), not(target_arch = "wasm32"))]
@@ -241,7 +507,8 @@ enum GeneratorSource {
 }
 
 /// Converts [`IGeneratorSettingsObject`] to a series of properties intended for use by the [`Generator`].
-struct GeneratorSettings {
+#[derive(Clone)]
+pub(crate) struct GeneratorSettings {
     pub network_id: Option<NetworkId>,
     pub source: GeneratorSource,
     pub multiplexer: Option<Multiplexer<Box<Events>>>,
@@ -251,11 +518,19 @@ struct GeneratorSettings {
     pub sig_op_count: u8,
     pub minimum_signatures: u16,
     pub payload: Option<Vec<u8>>,
+    pub change_policy: ChangePolicy,
+    pub sighash_type: SigHashType,
+    pub coin_control: Option<native::CoinControl>,
+    pub on_progress: Option<js_sys::Function>,
 }
 
 impl TryFrom<IGeneratorSettingsObject> for GeneratorSettings {
     type Error = Error;
     fn try_from(args: IGeneratorSettingsObject) -> std::result::Result<Self, Self::Error> {
+        if let Ok(builder) = GeneratorSettingsBuilder::try_ref_from_js_value(&args) {
+            return builder.try_settings();
+        }
+
         let network_id = args.try_get::<NetworkId>("networkId")?;
 
         // lack of outputs results in a sweep transaction compounding utxos into the change address
@@ -276,18 +551,42 @@ impl TryFrom<IGeneratorSettingsObject> for GeneratorSettings {
         };
 
         let sig_op_count = args.get_value("sigOpCount")?;
-        let sig_op_count =
-            if !sig_op_count.is_undefined() { sig_op_count.as_f64().expect("sigOpCount should be a number") as u8 } else { 1 };
+        let sig_op_count = if !sig_op_count.is_undefined() {
+            sig_op_count.as_f64().ok_or_else(|| Error::custom("sigOpCount should be a number"))? as u8
+        } else {
+            1
+        };
 
         let minimum_signatures = args.get_value("minimumSignatures")?;
         let minimum_signatures = if !minimum_signatures.is_undefined() {
-            minimum_signatures.as_f64().expect("minimumSignatures should be a number") as u16
+            minimum_signatures.as_f64().ok_or_else(|| Error::custom("minimumSignatures should be a number"))? as u16
         } else {
             1
         };
 
         let payload = args.get_vec_u8("payload").ok();
 
+        let change_policy =
+            args.try_get_value("changePolicy")?.map(serde_wasm_bindgen::from_value::<ChangePolicy>).transpose()?.unwrap_or_default();
+
+        let sighash_type =
+            args.try_get_value("sighashType")?.as_ref().map(try_sighash_type_from_js_value).transpose()?.unwrap_or(SIG_HASH_ALL);
+
+        let coin_control = args
+            .try_get_value("utxos")?
+            .map(|utxos| -> Result<native::CoinControl> {
+                let utxos = utxos.dyn_into::<Array>().map_err(|_| Error::custom("utxos should be an array of outpoints"))?;
+                let utxos = utxos.iter().map(|utxo| UtxoEntryId::try_from(&utxo)).collect::<std::result::Result<Vec<_>, _>>()?;
+                let exclusive = args.try_get_bool("exclusiveUtxos")?.unwrap_or(false);
+                Ok(native::CoinControl::new(utxos, exclusive))
+            })
+            .transpose()?;
+
+        let on_progress = args
+            .try_get_value("onProgress")?
+            .map(|callback| callback.dyn_into::<js_sys::Function>().map_err(|_| Error::custom("onProgress should be a function")))
+            .transpose()?;
+
         let settings = GeneratorSettings {
             network_id,
             source: generator_source,
@@ -298,6 +597,10 @@ impl TryFrom<IGeneratorSettingsObject> for GeneratorSettings {
             sig_op_count,
             minimum_signatures,
             payload,
+            change_policy,
+            sighash_type,
+            coin_control,
+            on_progress,
         };
 
         Ok(settings)
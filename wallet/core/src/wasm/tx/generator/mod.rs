@@ -1,8 +1,10 @@
+pub mod builder;
 #[allow(clippy::module_inception)]
 pub mod generator;
 pub mod pending;
 pub mod summary;
 
+pub use builder::*;
 pub use generator::*;
 pub use pending::*;
 pub use summary::*;
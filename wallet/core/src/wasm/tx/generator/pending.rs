@@ -1,12 +1,74 @@
 use crate::imports::*;
 use crate::result::Result;
 use crate::tx::generator as native;
-use crate::wasm::PrivateKeyArrayT;
+use crate::wasm::{PrivateKeyArrayT, SighashType};
 use kaspa_consensus_client::{numeric, string};
 use kaspa_consensus_client::{ITransaction, Transaction};
 use kaspa_wallet_keys::privatekey::PrivateKey;
 use kaspa_wrpc_wasm::RpcClient;
 
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TRANSACTION_VALIDATION: &'static str = r#"
+/**
+ * Result of a dry-run transaction submission.
+ * @see {@link PendingTransaction.submit}, {@link IAccountsSendRequest}
+ * @category Wallet SDK
+ */
+export interface ITransactionValidation {
+    /**
+     * Transaction id.
+     */
+    id : HexString;
+    /**
+     * Transaction mass calculated by the transaction generator.
+     */
+    mass : bigint;
+    /**
+     * Transaction compute mass calculated by the node.
+     */
+    computeMass? : bigint;
+    /**
+     * Transaction fees (in SOMPI), as calculated by the node
+     * if the transaction was accepted.
+     */
+    fees : bigint;
+    /**
+     * `false` if the transaction was not validated by the node
+     * because it spends outputs of preceding (unsubmitted) transactions.
+     */
+    validated : boolean;
+    /**
+     * Rejection reason reported by the node.
+     */
+    rejection? : string;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = js_sys::Object, typescript_type = "ITransactionValidation")]
+    pub type ITransactionValidation;
+}
+
+impl TryFrom<native::TransactionValidation> for ITransactionValidation {
+    type Error = Error;
+    fn try_from(validation: native::TransactionValidation) -> Result<Self> {
+        let native::TransactionValidation { id, mass, compute_mass, fees, validated, rejection } = validation;
+        let object = Object::new();
+        object.set("id", &id.to_string().into())?;
+        object.set("mass", &BigInt::from(mass).into())?;
+        if let Some(compute_mass) = compute_mass {
+            object.set("computeMass", &BigInt::from(compute_mass).into())?;
+        }
+        object.set("fees", &BigInt::from(fees).into())?;
+        object.set("validated", &validated.into())?;
+        if let Some(rejection) = rejection {
+            object.set("rejection", &rejection.into())?;
+        }
+        Ok(object.unchecked_into())
+    }
+}
+
 /// @category Wallet SDK
 #[wasm_bindgen(inspectable)]
 pub struct PendingTransaction {
@@ -39,6 +101,18 @@ impl PendingTransaction {
         BigInt::from(self.inner.fees())
     }
 
+    /// Transaction mass calculated by the {@link Generator}.
+    #[wasm_bindgen(getter)]
+    pub fn mass(&self) -> BigInt {
+        BigInt::from(self.inner.mass())
+    }
+
+    /// Fee rate of the transaction in SOMPI per gram of mass.
+    #[wasm_bindgen(getter, js_name = feeRate)]
+    pub fn fee_rate(&self) -> f64 {
+        self.inner.fee_rate()
+    }
+
     #[wasm_bindgen(getter, js_name = aggregateInputAmount)]
     pub fn aggregate_input_value(&self) -> BigInt {
         BigInt::from(self.inner.aggregate_input_value())
@@ -70,18 +144,30 @@ impl PendingTransaction {
         self.inner.utxo_entries().values().map(|utxo_entry| JsValue::from(utxo_entry.clone())).collect()
     }
 
+    /// Set the {@link SighashType} used to sign the input at `inputIndex`
+    /// (inputs are signed using the `sighashType` of the {@link Generator}
+    /// settings by default). Must be called before the transaction is signed.
+    #[wasm_bindgen(js_name = setSighashType)]
+    pub fn set_sighash_type(&self, input_index: u32, sighash_type: SighashType) -> Result<()> {
+        self.inner.set_sighash_type(input_index as usize, sighash_type.into())
+    }
+
     /// Sign transaction with supplied [`Array`] or [`PrivateKey`] or an array of
-    /// raw private key bytes (encoded as `Uint8Array` or as hex strings)
-    pub fn sign(&self, js_value: PrivateKeyArrayT) -> Result<()> {
+    /// raw private key bytes (encoded as `Uint8Array` or as hex strings).
+    /// Each input is signed using its {@link SighashType}
+    /// (see {@link PendingTransaction.setSighashType}). Transactions with a
+    /// large number of inputs are signed in chunks, yielding to the event loop
+    /// between chunks.
+    pub async fn sign(&self, js_value: PrivateKeyArrayT) -> Result<()> {
         if let Ok(keys) = js_value.dyn_into::<Array>() {
             let keys = keys
                 .iter()
                 .map(PrivateKey::try_cast_from)
                 .collect::<std::result::Result<Vec<_>, kaspa_wallet_keys::error::Error>>()?;
             let mut keys = keys.iter().map(|key| key.as_ref().secret_bytes()).collect::<Vec<_>>();
-            self.inner.try_sign_with_keys(&keys)?;
+            let result = self.inner.try_sign_with_keys_async(&keys).await;
             keys.zeroize();
-            Ok(())
+            result
         } else {
             Err(Error::custom("Please supply an array of keys"))
         }
@@ -92,11 +178,21 @@ impl PendingTransaction {
     /// {@link UtxoContext} if one was used to create the transaction
     /// and will return UTXOs back to {@link UtxoContext} in case of
     /// a failed submission.
-    /// @see {@link RpcClient.submitTransaction}
-    pub async fn submit(&self, wasm_rpc_client: &RpcClient) -> Result<String> {
+    ///
+    /// If `dryRun` is `true`, the transaction is validated by the node
+    /// without being broadcast and the {@link UtxoContext} is not affected.
+    /// In this case, the method returns {@link ITransactionValidation}
+    /// instead of the transaction id.
+    /// @see {@link RpcClient.submitTransaction}, {@link RpcClient.validateTransaction}
+    pub async fn submit(&self, wasm_rpc_client: &RpcClient, dry_run: Option<bool>) -> Result<JsValue> {
         let rpc: Arc<DynRpcApi> = wasm_rpc_client.client().clone();
-        let txid = self.inner.try_submit(&rpc).await?;
-        Ok(txid.to_string())
+        if dry_run.unwrap_or(false) {
+            let validation = self.inner.try_validate(&rpc).await?;
+            Ok(ITransactionValidation::try_from(validation)?.into())
+        } else {
+            let txid = self.inner.try_submit(&rpc).await?;
+            Ok(txid.to_string().into())
+        }
     }
 
     /// Returns encapsulated network [`Transaction`]
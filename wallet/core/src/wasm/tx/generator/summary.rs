@@ -1,11 +1,12 @@
 use crate::imports::*;
+use crate::result::Result;
 use crate::tx::generator as core;
 
 ///
 /// A class containing a summary produced by transaction {@link Generator}.
 /// This class contains the number of transactions, the aggregated fees,
-/// the aggregated UTXOs and the final transaction amount that includes
-/// both network and QoS (priority) fees.
+/// mass and the resulting fee rate, the aggregated UTXOs and the final
+/// transaction amount that includes both network and QoS (priority) fees.
 ///
 /// @see {@link createTransactions}, {@link IGeneratorSettingsObject}, {@link Generator}
 /// @category Wallet SDK
@@ -32,6 +33,18 @@ impl GeneratorSummary {
         BigInt::from(self.inner.aggregated_fees())
     }
 
+    /// Total mass of the generated transactions.
+    #[wasm_bindgen(getter)]
+    pub fn mass(&self) -> BigInt {
+        BigInt::from(self.inner.aggregated_mass())
+    }
+
+    /// Fee rate of the generated transactions in SOMPI per gram of mass.
+    #[wasm_bindgen(getter, js_name = feeRate)]
+    pub fn fee_rate(&self) -> f64 {
+        self.inner.fee_rate()
+    }
+
     #[wasm_bindgen(getter, js_name = transactions)]
     pub fn number_of_generated_transactions(&self) -> usize {
         self.inner.number_of_generated_transactions()
@@ -46,6 +59,34 @@ impl GeneratorSummary {
     pub fn final_transaction_id(&self) -> Option<String> {
         self.inner.final_transaction_id().map(|id| id.to_string())
     }
+
+    #[wasm_bindgen(getter, js_name = finalTransactionMass)]
+    pub fn final_transaction_mass(&self) -> Option<BigInt> {
+        self.inner.final_transaction_mass().map(BigInt::from)
+    }
+
+    /// Fee rate of the final transaction in SOMPI per gram of mass.
+    #[wasm_bindgen(getter, js_name = finalFeeRate)]
+    pub fn final_transaction_fee_rate(&self) -> Option<f64> {
+        self.inner.final_transaction_fee_rate()
+    }
+
+    #[wasm_bindgen(getter, js_name = finalChangeAmount)]
+    pub fn final_change_amount(&self) -> Option<BigInt> {
+        self.inner.final_change_amount().map(BigInt::from)
+    }
+
+    /// Policy applied to the final transaction change that can not be issued as a change output.
+    #[wasm_bindgen(getter, js_name = changePolicy)]
+    pub fn change_policy(&self) -> String {
+        self.inner.change_policy().to_string()
+    }
+
+    /// Handling of the final transaction change (`undefined` if the transaction has no change).
+    #[wasm_bindgen(getter, js_name = changeDisposition)]
+    pub fn change_disposition(&self) -> Result<JsValue> {
+        Ok(serde_wasm_bindgen::to_value(&self.inner.change_disposition())?)
+    }
 }
 
 impl From<core::GeneratorSummary> for GeneratorSummary {
@@ -2,10 +2,12 @@ pub mod consensus;
 pub mod fees;
 pub mod generator;
 pub mod mass;
+pub mod txscript;
 pub mod utils;
 
 pub use self::consensus::*;
 pub use self::fees::*;
 pub use self::generator::*;
 pub use self::mass::*;
+pub use self::txscript::*;
 pub use self::utils::*;
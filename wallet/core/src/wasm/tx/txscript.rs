@@ -0,0 +1,339 @@
+//!
+//! WASM bindings for the transaction script builder (see [`crate::tx::txscript`]).
+//!
+
+use crate::imports::*;
+use crate::result::Result;
+use crate::tx::txscript as native;
+use kaspa_consensus_core::tx::ScriptPublicKey;
+use kaspa_wallet_keys::publickey::PublicKeyArrayT;
+use kaspa_wasm_core::types::{BinaryT, HexString};
+
+/// Script opcodes supported by the consensus script engine.
+/// @see {@link ScriptBuilder}
+/// @category Wallet SDK
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Opcodes {
+    OpFalse = 0x00,
+    OpData1 = 0x01,
+    OpData2 = 0x02,
+    OpData3 = 0x03,
+    OpData4 = 0x04,
+    OpData5 = 0x05,
+    OpData6 = 0x06,
+    OpData7 = 0x07,
+    OpData8 = 0x08,
+    OpData9 = 0x09,
+    OpData10 = 0x0a,
+    OpData11 = 0x0b,
+    OpData12 = 0x0c,
+    OpData13 = 0x0d,
+    OpData14 = 0x0e,
+    OpData15 = 0x0f,
+    OpData16 = 0x10,
+    OpData17 = 0x11,
+    OpData18 = 0x12,
+    OpData19 = 0x13,
+    OpData20 = 0x14,
+    OpData21 = 0x15,
+    OpData22 = 0x16,
+    OpData23 = 0x17,
+    OpData24 = 0x18,
+    OpData25 = 0x19,
+    OpData26 = 0x1a,
+    OpData27 = 0x1b,
+    OpData28 = 0x1c,
+    OpData29 = 0x1d,
+    OpData30 = 0x1e,
+    OpData31 = 0x1f,
+    OpData32 = 0x20,
+    OpData33 = 0x21,
+    OpData34 = 0x22,
+    OpData35 = 0x23,
+    OpData36 = 0x24,
+    OpData37 = 0x25,
+    OpData38 = 0x26,
+    OpData39 = 0x27,
+    OpData40 = 0x28,
+    OpData41 = 0x29,
+    OpData42 = 0x2a,
+    OpData43 = 0x2b,
+    OpData44 = 0x2c,
+    OpData45 = 0x2d,
+    OpData46 = 0x2e,
+    OpData47 = 0x2f,
+    OpData48 = 0x30,
+    OpData49 = 0x31,
+    OpData50 = 0x32,
+    OpData51 = 0x33,
+    OpData52 = 0x34,
+    OpData53 = 0x35,
+    OpData54 = 0x36,
+    OpData55 = 0x37,
+    OpData56 = 0x38,
+    OpData57 = 0x39,
+    OpData58 = 0x3a,
+    OpData59 = 0x3b,
+    OpData60 = 0x3c,
+    OpData61 = 0x3d,
+    OpData62 = 0x3e,
+    OpData63 = 0x3f,
+    OpData64 = 0x40,
+    OpData65 = 0x41,
+    OpData66 = 0x42,
+    OpData67 = 0x43,
+    OpData68 = 0x44,
+    OpData69 = 0x45,
+    OpData70 = 0x46,
+    OpData71 = 0x47,
+    OpData72 = 0x48,
+    OpData73 = 0x49,
+    OpData74 = 0x4a,
+    OpData75 = 0x4b,
+    OpPushData1 = 0x4c,
+    OpPushData2 = 0x4d,
+    OpPushData4 = 0x4e,
+    Op1Negate = 0x4f,
+    OpReserved = 0x50,
+    OpTrue = 0x51,
+    Op2 = 0x52,
+    Op3 = 0x53,
+    Op4 = 0x54,
+    Op5 = 0x55,
+    Op6 = 0x56,
+    Op7 = 0x57,
+    Op8 = 0x58,
+    Op9 = 0x59,
+    Op10 = 0x5a,
+    Op11 = 0x5b,
+    Op12 = 0x5c,
+    Op13 = 0x5d,
+    Op14 = 0x5e,
+    Op15 = 0x5f,
+    Op16 = 0x60,
+    OpNop = 0x61,
+    OpVer = 0x62,
+    OpIf = 0x63,
+    OpNotIf = 0x64,
+    OpVerIf = 0x65,
+    OpVerNotIf = 0x66,
+    OpElse = 0x67,
+    OpEndIf = 0x68,
+    OpVerify = 0x69,
+    OpReturn = 0x6a,
+    OpToAltStack = 0x6b,
+    OpFromAltStack = 0x6c,
+    Op2Drop = 0x6d,
+    Op2Dup = 0x6e,
+    Op3Dup = 0x6f,
+    Op2Over = 0x70,
+    Op2Rot = 0x71,
+    Op2Swap = 0x72,
+    OpIfDup = 0x73,
+    OpDepth = 0x74,
+    OpDrop = 0x75,
+    OpDup = 0x76,
+    OpNip = 0x77,
+    OpOver = 0x78,
+    OpPick = 0x79,
+    OpRoll = 0x7a,
+    OpRot = 0x7b,
+    OpSwap = 0x7c,
+    OpTuck = 0x7d,
+    OpCat = 0x7e,
+    OpSubStr = 0x7f,
+    OpLeft = 0x80,
+    OpRight = 0x81,
+    OpSize = 0x82,
+    OpInvert = 0x83,
+    OpAnd = 0x84,
+    OpOr = 0x85,
+    OpXor = 0x86,
+    OpEqual = 0x87,
+    OpEqualVerify = 0x88,
+    OpReserved1 = 0x89,
+    OpReserved2 = 0x8a,
+    Op1Add = 0x8b,
+    Op1Sub = 0x8c,
+    Op2Mul = 0x8d,
+    Op2Div = 0x8e,
+    OpNegate = 0x8f,
+    OpAbs = 0x90,
+    OpNot = 0x91,
+    Op0NotEqual = 0x92,
+    OpAdd = 0x93,
+    OpSub = 0x94,
+    OpMul = 0x95,
+    OpDiv = 0x96,
+    OpMod = 0x97,
+    OpLShift = 0x98,
+    OpRShift = 0x99,
+    OpBoolAnd = 0x9a,
+    OpBoolOr = 0x9b,
+    OpNumEqual = 0x9c,
+    OpNumEqualVerify = 0x9d,
+    OpNumNotEqual = 0x9e,
+    OpLessThan = 0x9f,
+    OpGreaterThan = 0xa0,
+    OpLessThanOrEqual = 0xa1,
+    OpGreaterThanOrEqual = 0xa2,
+    OpMin = 0xa3,
+    OpMax = 0xa4,
+    OpWithin = 0xa5,
+    OpSHA256 = 0xa8,
+    OpCheckMultiSigECDSA = 0xa9,
+    OpBlake2b = 0xaa,
+    OpCheckSigECDSA = 0xab,
+    OpCheckSig = 0xac,
+    OpCheckSigVerify = 0xad,
+    OpCheckMultiSig = 0xae,
+    OpCheckMultiSigVerify = 0xaf,
+    OpCheckLockTimeVerify = 0xb0,
+    OpCheckSequenceVerify = 0xb1,
+}
+
+/// Builder of custom transaction scripts. Scripts are produced using the
+/// canonical encoding and the script size limits of the consensus rules.
+///
+/// @example
+/// ```javascript
+/// // 2-of-3 multisig redeem script paid to via a P2SH output
+/// let redeemScript = ScriptBuilder.multisig([publicKey1, publicKey2, publicKey3], 2);
+/// let scriptPublicKey = redeemScript.createPayToScriptHashScript();
+///
+/// // the unlocking (signature) script spending the P2SH output
+/// let signatures = new ScriptBuilder().addData(signature1).addData(signature2);
+/// let signatureScript = redeemScript.encodePayToScriptHashSignatureScript(signatures.toString());
+///
+/// // time-locked output
+/// let script = new ScriptBuilder()
+///     .addLockTime(daaScore)
+///     .addOp(Opcodes.OpCheckLockTimeVerify)
+///     .addData(xOnlyPublicKey)
+///     .addOp(Opcodes.OpCheckSig);
+/// ```
+///
+/// @category Wallet SDK
+#[derive(Clone)]
+#[wasm_bindgen(js_name = ScriptBuilder)]
+pub struct ScriptBuilder {
+    inner: Arc<Mutex<native::ScriptBuilder>>,
+}
+
+impl ScriptBuilder {
+    fn inner(&self) -> MutexGuard<'_, native::ScriptBuilder> {
+        self.inner.lock().unwrap()
+    }
+}
+
+impl From<native::ScriptBuilder> for ScriptBuilder {
+    fn from(builder: native::ScriptBuilder) -> Self {
+        Self { inner: Arc::new(Mutex::new(builder)) }
+    }
+}
+
+#[wasm_bindgen(js_class = ScriptBuilder)]
+impl ScriptBuilder {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        native::ScriptBuilder::new().into()
+    }
+
+    /// Creates a builder containing an existing script.
+    #[wasm_bindgen(js_name = fromScript)]
+    pub fn from_script(script: BinaryT) -> Result<ScriptBuilder> {
+        Ok(native::ScriptBuilder::from_script(&script.try_as_vec_u8()?)?.into())
+    }
+
+    /// Creates a builder containing a `minimum_signatures`-of-N multisig
+    /// redeem script of the supplied public keys.
+    #[wasm_bindgen(js_name = multisig)]
+    pub fn multisig(public_keys: PublicKeyArrayT, minimum_signatures: usize, ecdsa: Option<bool>) -> Result<ScriptBuilder> {
+        let public_keys: Vec<secp256k1::PublicKey> = public_keys.try_into()?;
+        Ok(native::ScriptBuilder::multisig(&public_keys, minimum_signatures, ecdsa.unwrap_or(false))?.into())
+    }
+
+    /// Pushes the opcode to the end of the script.
+    #[wasm_bindgen(js_name = addOp)]
+    pub fn add_op(&self, opcode: Opcodes) -> Result<ScriptBuilder> {
+        self.inner().add_op(opcode as u8)?;
+        Ok(self.clone())
+    }
+
+    /// Pushes the opcodes (raw script bytes) to the end of the script.
+    #[wasm_bindgen(js_name = addOps)]
+    pub fn add_ops(&self, opcodes: BinaryT) -> Result<ScriptBuilder> {
+        self.inner().add_ops(&opcodes.try_as_vec_u8()?)?;
+        Ok(self.clone())
+    }
+
+    /// Pushes the data using the canonical data push opcode.
+    #[wasm_bindgen(js_name = addData)]
+    pub fn add_data(&self, data: BinaryT) -> Result<ScriptBuilder> {
+        self.inner().add_data(&data.try_as_vec_u8()?)?;
+        Ok(self.clone())
+    }
+
+    /// Pushes the integer using the canonical encoding.
+    #[wasm_bindgen(js_name = addI64)]
+    pub fn add_i64(&self, value: i64) -> Result<ScriptBuilder> {
+        self.inner().add_i64(value)?;
+        Ok(self.clone())
+    }
+
+    /// Pushes the lock time, i.e. for `OpCheckLockTimeVerify`.
+    #[wasm_bindgen(js_name = addLockTime)]
+    pub fn add_lock_time(&self, lock_time: u64) -> Result<ScriptBuilder> {
+        self.inner().add_lock_time(lock_time)?;
+        Ok(self.clone())
+    }
+
+    /// Pushes the relative lock time, i.e. for `OpCheckSequenceVerify`.
+    #[wasm_bindgen(js_name = addSequence)]
+    pub fn add_sequence(&self, sequence: u64) -> Result<ScriptBuilder> {
+        self.inner().add_sequence(sequence)?;
+        Ok(self.clone())
+    }
+
+    /// Returns the number of bytes the canonical encoding of the data push takes.
+    #[wasm_bindgen(js_name = canonicalDataSize)]
+    pub fn canonical_data_size(data: BinaryT) -> Result<u32> {
+        Ok(native::ScriptBuilder::canonical_data_size(&data.try_as_vec_u8()?) as u32)
+    }
+
+    /// Returns the script as a {@link HexString}.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string_js(&self) -> HexString {
+        self.inner().script().to_hex().into()
+    }
+
+    /// Returns the script as a {@link HexString}, leaving the builder empty.
+    pub fn drain(&self) -> HexString {
+        self.inner().drain().to_hex().into()
+    }
+
+    /// Creates a P2SH script public key paying to the hash of the script
+    /// (the script being the redeem script).
+    #[wasm_bindgen(js_name = createPayToScriptHashScript)]
+    pub fn create_pay_to_script_hash_script(&self) -> ScriptPublicKey {
+        self.inner().create_pay_to_script_hash_script()
+    }
+
+    /// Creates the signature script spending a P2SH output of the script (the
+    /// script being the redeem script). `signature` contains the data pushes
+    /// satisfying the redeem script (i.e. the signatures, each followed by its
+    /// sighash type).
+    #[wasm_bindgen(js_name = encodePayToScriptHashSignatureScript)]
+    pub fn encode_pay_to_script_hash_signature_script(&self, signature: BinaryT) -> Result<HexString> {
+        let signature_script = self.inner().encode_pay_to_script_hash_signature_script(&signature.try_as_vec_u8()?)?;
+        Ok(signature_script.to_hex().into())
+    }
+}
+
+impl Default for ScriptBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -61,7 +61,7 @@ pub fn create_transaction_js(
         .collect::<Vec<TransactionInput>>();
 
     if priority_fee > total_input_amount {
-        return Err(format!("priority fee({priority_fee}) > amount({total_input_amount})").into());
+        return Err(Error::PriorityFeeExceedsAmount { priority_fee, amount: total_input_amount });
     }
 
     // TODO - Calculate mass and fees
@@ -110,6 +110,7 @@ pub async fn create_transactions_js(settings: IGeneratorSettingsObject) -> Resul
         let mut stream = generator.stream();
         let mut transactions = vec![];
         while let Some(transaction) = stream.try_next().await? {
+            generator.notify_progress(&transaction)?;
             transactions.push(PendingTransaction::from(transaction));
             yield_executor().await;
         }
@@ -120,7 +121,14 @@ pub async fn create_transactions_js(settings: IGeneratorSettingsObject) -> Resul
         object.set("summary", &summary)?;
         Ok(object)
     } else {
-        let transactions = generator.iter().map(|r| r.map(PendingTransaction::from)).collect::<Result<Vec<_>>>()?;
+        let transactions = generator
+            .iter()
+            .map(|r| {
+                let transaction = r?;
+                generator.notify_progress(&transaction)?;
+                Ok(PendingTransaction::from(transaction))
+            })
+            .collect::<Result<Vec<_>>>()?;
         let transactions = Array::from_iter(transactions.into_iter().map(JsValue::from)); //.collect::<Array>();
         let summary = JsValue::from(generator.summary());
         let object = ICreateTransactions::default();
@@ -1,4 +1,7 @@
+use crate::error::Error;
 use crate::result::Result;
+use crate::strength::{estimate_secret_strength, SecretStrength};
+use crate::utils::{Amount, AmountFormat};
 use js_sys::BigInt;
 use kaspa_consensus_core::network::{NetworkType, NetworkTypeT};
 use wasm_bindgen::prelude::*;
@@ -44,3 +47,143 @@ pub fn sompi_to_kaspa_string_with_suffix(sompi: ISompiToKaspa, network: &Network
     let network_type = NetworkType::try_from(network)?;
     Ok(crate::utils::sompi_to_kaspa_string_with_suffix(sompi, &network_type))
 }
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_AMOUNT_FORMAT: &'static str = r#"
+/**
+ * Amount formatting and parsing options used by
+ * {@link formatAmount} and {@link parseAmount}.
+ *
+ * @category Wallet SDK
+ */
+export interface IAmountFormat {
+    /**
+     * Amount unit (default: `"kaspa"`).
+     */
+    unit?: "kaspa" | "sompi";
+    /**
+     * Number of displayed decimal places (up to 8, remaining
+     * decimals are truncated). Significant decimal places
+     * are displayed if not specified.
+     */
+    precision?: number;
+    /**
+     * Thousands separator (default: none).
+     */
+    groupSeparator?: string;
+    /**
+     * Decimal separator (default: `"."`).
+     */
+    decimalSeparator?: string;
+    /**
+     * Appends the KAS suffix of the network (e.g. `TKAS` for testnet).
+     */
+    network?: NetworkType | NetworkId | string;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = js_sys::Object, typescript_type = "IAmountFormat")]
+    pub type IAmountFormat;
+}
+
+impl TryFrom<IAmountFormat> for AmountFormat {
+    type Error = Error;
+    fn try_from(value: IAmountFormat) -> Result<Self> {
+        let mut format = AmountFormat::default();
+        if let Some(unit) = value.try_get_string("unit")? {
+            format = format.with_unit(unit.parse()?);
+        }
+        if let Some(precision) = value.try_get_value("precision")? {
+            format = format.with_precision(precision.try_as_u64()? as usize);
+        }
+        let group_separator = value.try_get_string("groupSeparator")?.and_then(|separator| separator.chars().next());
+        let decimal_separator = value.try_get_string("decimalSeparator")?.and_then(|separator| separator.chars().next());
+        format = format.with_separators(group_separator, decimal_separator.unwrap_or('.'));
+        if let Some(network) = value.try_get_value("network")? {
+            format = format.with_network_suffix(&NetworkType::try_from(&network.unchecked_into::<NetworkTypeT>())?);
+        }
+        Ok(format)
+    }
+}
+
+///
+/// Format a Sompi amount using the supplied {@link IAmountFormat} options
+/// (e.g. `formatAmount(123450000000n, { groupSeparator: "," })` yields `1,234.5`).
+/// Unlike floating point conversions, the formatting is exact.
+///
+/// @category Wallet SDK
+///
+#[wasm_bindgen(js_name = "formatAmount")]
+pub fn format_amount(sompi: ISompiToKaspa, format: Option<IAmountFormat>) -> Result<String> {
+    let amount = Amount::from_sompi(sompi.try_as_u64()?);
+    let format = format.map(AmountFormat::try_from).transpose()?.unwrap_or_default();
+    Ok(amount.format(&format))
+}
+
+///
+/// Parse an amount (e.g. `"1.5"`, `"1.5 KAS"` or `"150000000 sompi"`)
+/// returning its value in Sompi. Throws if the amount is not valid.
+///
+/// @category Wallet SDK
+///
+#[wasm_bindgen(js_name = "parseAmount")]
+pub fn parse_amount(amount: String, format: Option<IAmountFormat>) -> Result<BigInt> {
+    let format = format.map(AmountFormat::try_from).transpose()?.unwrap_or_default();
+    Ok(format.parse(&amount)?.sompi().into())
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_SECRET_STRENGTH: &'static str = r#"
+/**
+ * Estimated strength of a wallet secret.
+ *
+ * @category Wallet SDK
+ */
+export interface ISecretStrength {
+    /**
+     * Strength score between `0` (too guessable) and `4` (very unguessable).
+     */
+    score : number;
+    /**
+     * Estimated number of guesses needed to find the secret (log10).
+     */
+    guessesLog10 : number;
+    /**
+     * Explanation of the main weakness of the secret.
+     */
+    warning? : string;
+    /**
+     * Suggestions helping to choose a stronger secret.
+     */
+    suggestions : string[];
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = js_sys::Object, typescript_type = "ISecretStrength")]
+    pub type ISecretStrength;
+}
+
+impl TryFrom<SecretStrength> for ISecretStrength {
+    type Error = Error;
+    fn try_from(value: SecretStrength) -> Result<Self> {
+        Ok(serde_wasm_bindgen::to_value(&value)?.unchecked_into())
+    }
+}
+
+///
+/// Estimate the strength of a wallet secret (password), returning
+/// the {@link ISecretStrength} score and feedback that can be displayed
+/// while the user is entering the secret. Wallets configured with
+/// {@link IWalletConfig.minSecretStrength} reject secrets scoring
+/// below the configured minimum with {@link WalletErrorCode.WeakSecret}.
+///
+/// @category Wallet SDK
+///
+#[wasm_bindgen(js_name = "estimateSecretStrength")]
+pub fn estimate_secret_strength_js(secret: String) -> Result<ISecretStrength> {
+    estimate_secret_strength(&secret.into()).try_into()
+}
@@ -1,7 +1,7 @@
 use crate::imports::*;
 use crate::result::Result;
 use crate::utxo as native;
-use crate::utxo::{UtxoContextBinding, UtxoContextId};
+use crate::utxo::{UtxoContextBinding, UtxoContextId, UtxoEntryId};
 use crate::wasm::utxo::UtxoProcessor;
 use crate::wasm::{Balance, BalanceStrings};
 use kaspa_addresses::AddressOrStringArrayT;
@@ -37,6 +37,22 @@ declare! {
 }
 
 ///
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Array, typescript_type = "(ITransactionOutpoint | string)[]")]
+    pub type TransactionOutpointArrayT;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Array, typescript_type = "(UtxoEntryReference | undefined)[]")]
+    pub type OptionalUtxoEntryReferenceArrayT;
+}
+
+fn outpoints_from_js(outpoints: TransactionOutpointArrayT) -> Result<Vec<UtxoEntryId>> {
+    outpoints.iter().map(|outpoint| Ok(UtxoEntryId::try_from(&outpoint)?)).collect()
+}
+
 /// UtxoContext is a class that provides a way to track addresses activity
 /// on the Kaspa network.  When an address is registered with UtxoContext
 /// it aggregates all UTXO entries for that address and emits events when
@@ -58,10 +74,11 @@ declare! {
 /// registered against it and provides balance update notification events
 /// when the balance changes.
 ///
-/// The UtxoContext balance is comprised of 3 values:
+/// The UtxoContext balance is comprised of 4 values:
 /// - `mature`: amount of funds available for spending.
 /// - `pending`: amount of funds that are being received.
 /// - `outgoing`: amount of funds that are being sent but are not yet accepted by the network.
+/// - `locked`: amount of funds held in UTXOs locked using {@link UtxoContext.lock} (excluded from spending).
 ///
 /// Please see {@link IBalance} interface for more details.
 ///
@@ -83,6 +100,8 @@ declare! {
 /// {@link IBalanceEvent},
 /// {@link IPendingEvent},
 /// {@link IReorgEvent},
+/// {@link IReorgedOutEvent},
+/// {@link IReacceptedEvent},
 /// {@link IStasisEvent},
 /// {@link IMaturityEvent},
 /// {@link IDiscoveryEvent},
@@ -133,6 +152,24 @@ impl UtxoContext {
         Ok(())
     }
 
+    /// Export the mature and pending UTXO entries as a JSON snapshot that can be
+    /// loaded into a UtxoContext on an offline machine using {@link UtxoContext.importSnapshot}
+    /// (allowing transactions to be created offline and signed there).
+    #[wasm_bindgen(js_name = "exportSnapshot")]
+    pub fn export_snapshot(&self) -> Result<String> {
+        self.inner().to_snapshot().to_json()
+    }
+
+    /// Load UTXO entries from a JSON snapshot produced by {@link UtxoContext.exportSnapshot}.
+    /// The JSON can also contain a `getUtxosByAddresses()` RPC response or the array of its entries.
+    /// If the UtxoProcessor is not connected, the entry maturity is determined using the DAA score
+    /// recorded in the snapshot (entries are considered mature if the snapshot contains no DAA score).
+    #[wasm_bindgen(js_name = "importSnapshot")]
+    pub async fn import_snapshot(&self, json: String) -> Result<()> {
+        let snapshot = native::UtxoSnapshot::from_json(&json)?;
+        self.inner().extend_from_snapshot(&snapshot).await
+    }
+
     /// Unregister a list of addresses from the context. This will stop tracking of these addresses.
     #[wasm_bindgen(js_name = "unregisterAddresses")]
     pub async fn unregister_addresses(&self, addresses: AddressOrStringArrayT) -> Result<()> {
@@ -218,6 +255,50 @@ impl UtxoContext {
         Ok(array.unchecked_into())
     }
 
+    /// Returns the UTXO entry identified by the transaction id and output index
+    /// or `undefined` if the entry is not held by the UtxoContext. This allows
+    /// resolving outpoints referenced by arbitrary transactions against the
+    /// UTXO entries known to the UtxoContext (mature, pending and stasis).
+    #[wasm_bindgen(js_name = "getEntryByOutpoint")]
+    pub fn get_entry_by_outpoint(&self, transaction_id: &str, index: u32) -> Result<Option<UtxoEntryReference>> {
+        let transaction_id = transaction_id.parse::<TransactionId>()?;
+        Ok(self.inner().get_entry_by_outpoint(&transaction_id, index))
+    }
+
+    /// Returns UTXO entries identified by the supplied outpoints (in the order
+    /// of the supplied outpoints). Outpoints can be supplied as {@link ITransactionOutpoint}
+    /// objects or `"<txid>-<index>"` strings. Outpoints of entries that are not held
+    /// by the UtxoContext are resolved as `undefined`.
+    #[wasm_bindgen(js_name = "getEntriesByOutpoints")]
+    pub fn get_entries_by_outpoints(&self, outpoints: TransactionOutpointArrayT) -> Result<OptionalUtxoEntryReferenceArrayT> {
+        let entries = self.inner().get_entries_by_outpoints(&outpoints_from_js(outpoints)?);
+        let array = Array::new();
+        for entry in entries {
+            array.push(&entry.map(JsValue::from).unwrap_or(JsValue::UNDEFINED));
+        }
+        Ok(array.unchecked_into())
+    }
+
+    /// Lock UTXO entries identified by the supplied outpoints, excluding them
+    /// from spending (they will not be used by the {@link Generator}).
+    /// Outpoints can be supplied as {@link ITransactionOutpoint} objects or
+    /// `"<txid>-<index>"` strings. Locked amounts are reported separately in
+    /// the {@link IBalance} `locked` property.
+    pub async fn lock(&self, outpoints: TransactionOutpointArrayT) -> Result<()> {
+        self.inner().lock(&outpoints_from_js(outpoints)?).await
+    }
+
+    /// Unlock UTXO entries identified by the supplied outpoints.
+    pub async fn unlock(&self, outpoints: TransactionOutpointArrayT) -> Result<()> {
+        self.inner().unlock(&outpoints_from_js(outpoints)?).await
+    }
+
+    /// Returns the list of locked UTXO outpoints as `"<txid>-<index>"` strings.
+    #[wasm_bindgen(getter)]
+    pub fn locked(&self) -> Vec<String> {
+        self.inner().locked().iter().map(|outpoint| outpoint.to_string()).collect()
+    }
+
     /// Current {@link Balance} of the UtxoContext.
     #[wasm_bindgen(getter, js_name = "balance")]
     pub fn balance(&self) -> Option<Balance> {
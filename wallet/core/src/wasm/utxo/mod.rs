@@ -1,5 +1,7 @@
 pub mod context;
+pub mod monitor;
 pub mod processor;
 
 pub use context::*;
+pub use monitor::*;
 pub use processor::*;
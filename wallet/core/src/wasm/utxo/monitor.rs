@@ -0,0 +1,172 @@
+use crate::imports::*;
+use crate::result::Result;
+use crate::utxo as native;
+use crate::wasm::utxo::UtxoProcessor;
+use crate::wasm::{Balance, UtxoContext};
+use kaspa_addresses::{AddressOrStringArrayT, AddressT};
+use kaspa_wallet_macros::declare_typescript_wasm_interface as declare;
+use workflow_wasm::serde::to_value;
+
+declare! {
+    IAddressMonitorArgs,
+    r#"
+    /**
+     * AddressMonitor constructor arguments.
+     *
+     * @see {@link UtxoProcessor}, {@link AddressMonitor}
+     * @category Wallet SDK
+     */
+    export interface IAddressMonitorArgs {
+        /**
+         * Associated UtxoProcessor.
+         */
+        processor: UtxoProcessor;
+        /**
+         * Optional list of addresses to monitor.
+         */
+        addresses?: (Address | string)[];
+    }
+    "#,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = js_sys::Object, typescript_type = "IAddressBalance")]
+    pub type IAddressBalance;
+    #[wasm_bindgen(extends = js_sys::Array, typescript_type = "IAddressBalance[]")]
+    pub type IAddressBalanceArray;
+}
+
+///
+/// AddressMonitor tracks the balances of an arbitrary set of addresses
+/// without creating a wallet or accounts, making it suitable for explorers,
+/// payment processors and dashboards.
+///
+/// The monitor uses the supplied {@link UtxoProcessor} to subscribe the
+/// addresses to UTXO change notifications. Events produced by the monitor
+/// ({@link IPendingEvent}, {@link IMaturityEvent}, {@link IBalanceEvent} etc.)
+/// are posted to the {@link UtxoProcessor} event listeners and can be
+/// identified by the {@link AddressMonitor.id} property. UTXO entries of the
+/// monitored addresses are re-scanned each time the processor (re)connects.
+///
+/// @see {@link IAddressMonitorArgs}, {@link IAddressBalance}, {@link UtxoProcessor}
+///
+/// @category Wallet SDK
+///
+#[wasm_bindgen(inspectable)]
+pub struct AddressMonitor {
+    inner: native::AddressMonitor,
+    addresses: Vec<Address>,
+}
+
+impl AddressMonitor {
+    pub fn inner(&self) -> &native::AddressMonitor {
+        &self.inner
+    }
+}
+
+#[wasm_bindgen]
+impl AddressMonitor {
+    #[wasm_bindgen(constructor)]
+    pub fn ctor(js_value: IAddressMonitorArgs) -> Result<AddressMonitor> {
+        let AddressMonitorCreateArgs { processor, addresses } = js_value.try_into()?;
+        let inner = native::AddressMonitor::with_processor(processor.processor());
+        Ok(AddressMonitor { inner, addresses })
+    }
+
+    /// Id included in the events posted by the monitor.
+    #[wasm_bindgen(getter)]
+    pub fn id(&self) -> String {
+        self.inner.id().to_hex()
+    }
+
+    /// {@link UtxoContext} holding the UTXO entries of the monitored addresses
+    /// (can be supplied as a UTXO source to the {@link Generator}).
+    #[wasm_bindgen(getter)]
+    pub fn context(&self) -> UtxoContext {
+        UtxoContext::from(self.inner.context().clone())
+    }
+
+    /// Start the monitor, tracking the addresses supplied to the constructor.
+    /// The associated {@link UtxoProcessor} must be started by the caller.
+    pub async fn start(&self) -> Result<()> {
+        self.inner.start().await?;
+        if !self.addresses.is_empty() {
+            self.inner.track_addresses(&self.addresses).await?;
+        }
+        Ok(())
+    }
+
+    /// Stop the monitor. Monitored addresses are retained.
+    pub async fn stop(&self) -> Result<()> {
+        self.inner.stop().await
+    }
+
+    #[wasm_bindgen(getter, js_name = "isActive")]
+    pub fn is_active(&self) -> bool {
+        self.inner.is_active()
+    }
+
+    /// Start monitoring the supplied addresses.
+    #[wasm_bindgen(js_name = "trackAddresses")]
+    pub async fn track_addresses(&self, addresses: AddressOrStringArrayT) -> Result<()> {
+        let addresses: Vec<Address> = addresses.try_into()?;
+        self.inner.track_addresses(&addresses).await
+    }
+
+    /// Stop monitoring the supplied addresses.
+    #[wasm_bindgen(js_name = "untrackAddresses")]
+    pub async fn untrack_addresses(&self, addresses: AddressOrStringArrayT) -> Result<()> {
+        let addresses: Vec<Address> = addresses.try_into()?;
+        self.inner.untrack_addresses(&addresses).await
+    }
+
+    /// Returns the monitored addresses.
+    #[wasm_bindgen(getter)]
+    pub fn addresses(&self) -> Vec<Address> {
+        self.inner.addresses()
+    }
+
+    /// Total {@link Balance} of the monitored addresses.
+    #[wasm_bindgen(getter)]
+    pub fn balance(&self) -> Option<Balance> {
+        self.inner.balance().map(Balance::from)
+    }
+
+    /// Returns the {@link IAddressBalance} of each monitored address.
+    #[wasm_bindgen(js_name = "addressBalances")]
+    pub fn address_balances(&self) -> Result<IAddressBalanceArray> {
+        Ok(to_value(&self.inner.address_balances())?.unchecked_into())
+    }
+
+    /// Returns the {@link IAddressBalance} of the supplied address
+    /// or `undefined` if the address is not monitored.
+    #[wasm_bindgen(js_name = "addressBalance")]
+    pub fn address_balance(&self, address: AddressT) -> Result<Option<IAddressBalance>> {
+        let address = Address::try_cast_from(address)?;
+        self.inner.address_balance(address.as_ref()).map(|balance| Ok(to_value(&balance)?.unchecked_into())).transpose()
+    }
+}
+
+pub struct AddressMonitorCreateArgs {
+    processor: UtxoProcessor,
+    addresses: Vec<Address>,
+}
+
+impl TryFrom<IAddressMonitorArgs> for AddressMonitorCreateArgs {
+    type Error = Error;
+    fn try_from(value: IAddressMonitorArgs) -> std::result::Result<Self, Self::Error> {
+        if let Some(object) = Object::try_from(&value) {
+            let processor = object.get_cast::<UtxoProcessor>("processor")?;
+            let addresses = if let Some(addresses) = object.try_get_value("addresses")? {
+                AddressOrStringArrayT::from(addresses).try_into()?
+            } else {
+                vec![]
+            };
+
+            Ok(AddressMonitorCreateArgs { processor: processor.into_owned(), addresses })
+        } else {
+            Err(Error::custom("AddressMonitor: supplied value must be an object"))
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::error::Error;
 use crate::events::{EventKind, Events};
 use crate::imports::*;
+use crate::logging::log;
 use crate::result::Result;
 use crate::utxo as native;
 use crate::wasm::notify::{UtxoProcessorEventTarget, UtxoProcessorNotificationCallback, UtxoProcessorNotificationTypeOrCallback};
@@ -8,7 +9,6 @@ use kaspa_consensus_core::network::NetworkIdT;
 use kaspa_wallet_macros::declare_typescript_wasm_interface as declare;
 use kaspa_wasm_core::events::{get_event_targets, Sink};
 use kaspa_wrpc_wasm::RpcClient;
-use workflow_log::log_error;
 
 declare! {
     IUtxoProcessorArgs,
@@ -29,6 +29,26 @@ declare! {
     "#,
 }
 
+declare! {
+    IMaturityPolicy,
+    r#"
+    /**
+     * UTXO maturity policy. Each property, if supplied, overrides
+     * the default network maturity period (in DAA score units).
+     * UTXOs that have not reached maturity are reported as pending
+     * and are not used during transaction generation.
+     * 
+     * @see {@link UtxoProcessor.setMaturityPolicy}
+     * @category Wallet SDK
+     */
+    export interface IMaturityPolicy {
+        coinbaseTransactionMaturityPeriodDaa? : bigint | number;
+        coinbaseTransactionStasisPeriodDaa? : bigint | number;
+        userTransactionMaturityPeriodDaa? : bigint | number;
+    }
+    "#,
+}
+
 pub struct Inner {
     processor: native::UtxoProcessor,
     rpc: RpcClient,
@@ -150,9 +170,38 @@ impl UtxoProcessor {
     #[wasm_bindgen(js_name = "setNetworkId")]
     pub fn set_network_id(&self, network_id: &NetworkIdT) -> Result<()> {
         let network_id = NetworkId::try_cast_from(network_id)?;
-        self.inner.processor.set_network_id(network_id.as_ref());
+        self.inner.processor.set_network_id(network_id.as_ref())?;
         Ok(())
     }
+
+    /// Set the UTXO maturity policy.
+    /// @see {@link IMaturityPolicy}
+    #[wasm_bindgen(js_name = "setMaturityPolicy")]
+    pub fn set_maturity_policy(&self, policy: IMaturityPolicy) -> Result<()> {
+        let object = Object::try_from(&policy).ok_or(Error::custom("maturity policy must be an object"))?;
+        // absent properties retain the network defaults, invalid values are rejected
+        let period = |prop: &str| -> Result<Option<u64>> {
+            object
+                .try_get_value(prop)?
+                .map(|value| value.try_as_u64().map_err(|err| Error::custom(format!("invalid `{prop}` value: {err}"))))
+                .transpose()
+        };
+        let policy = native::MaturityPolicy {
+            coinbase_transaction_maturity_period_daa: period("coinbaseTransactionMaturityPeriodDaa")?,
+            coinbase_transaction_stasis_period_daa: period("coinbaseTransactionStasisPeriodDaa")?,
+            user_transaction_maturity_period_daa: period("userTransactionMaturityPeriodDaa")?,
+        };
+        self.inner.processor.set_maturity_policy(policy)
+    }
+
+    /// Set the minimum interval (in milliseconds) between balance events
+    /// emitted by each {@link UtxoContext}. Balance updates occurring within
+    /// the interval are coalesced and only the latest balance is emitted.
+    /// Passing `0` or `undefined` disables throttling.
+    #[wasm_bindgen(js_name = "setBalanceEventThrottle")]
+    pub fn set_balance_event_throttle(&self, msec: Option<u32>) {
+        self.inner.processor.set_balance_event_throttle(msec.map(|msec| Duration::from_millis(msec as u64)));
+    }
 }
 
 impl TryCastFromJs for UtxoProcessor {
@@ -194,7 +243,7 @@ impl UtxoProcessor {
         let inner = self.inner.clone();
 
         if inner.task_running.load(Ordering::SeqCst) {
-            log_error!("You are calling `UtxoProcessor.start()` twice without calling `UtxoProcessor.stop()`!");
+            log!(Utxo, Error, "You are calling `UtxoProcessor.start()` twice without calling `UtxoProcessor.stop()`!");
             panic!("UtxoProcessor background task is already running");
         } else {
             inner.task_running.store(true, Ordering::SeqCst);
@@ -218,7 +267,7 @@ impl UtxoProcessor {
                                 for handler in handlers.into_iter() {
                                     let value = notification.as_ref().to_js_value();
                                     if let Err(err) = handler.call(&value) {
-                                        log_error!("Error while executing notification callback: {:?}", err);
+                                        log!(Utxo, Error, "Error while executing notification callback: {:?}", err);
                                     }
                                 }
                             }
@@ -1,11 +1,13 @@
 use crate::imports::*;
+use crate::logging::log;
 use crate::storage::local::interface::LocalStore;
 use crate::storage::WalletDescriptor;
+use crate::strength::SecretStrengthPolicy;
 use crate::wallet as native;
 use crate::wasm::notify::{WalletEventTarget, WalletNotificationCallback, WalletNotificationTypeOrCallback};
 use kaspa_wallet_macros::declare_typescript_wasm_interface as declare;
 use kaspa_wasm_core::events::{get_event_targets, Sink};
-use kaspa_wrpc_wasm::{IConnectOptions, Resolver, RpcClient, RpcConfig, WrpcEncoding};
+use kaspa_wrpc_wasm::{IConnectOptions, IRpcTransport, Resolver, RpcClient, RpcConfig, WrpcEncoding};
 
 declare! {
     IWalletConfig,
@@ -24,6 +26,18 @@ declare! {
         encoding?: Encoding | string;
         url?: string;
         resolver?: Resolver;
+        /**
+         * Custom transport relaying node RPC calls (for example to a browser
+         * extension background service worker owning the node connection).
+         * If supplied, `url` and `resolver` are ignored.
+         */
+        transport?: IRpcTransport;
+        /**
+         * Minimum strength score (`0`-`4`) of the wallet secret required when
+         * creating wallets or changing the wallet secret (see {@link estimateSecretStrength}).
+         * The policy is disabled by default.
+         */
+        minSecretStrength?: number;
     }
     "#,
 }
@@ -35,6 +49,8 @@ struct WalletCtorArgs {
     encoding: Option<WrpcEncoding>,
     url: Option<String>,
     resolver: Option<Resolver>,
+    transport: Option<IRpcTransport>,
+    min_secret_strength: Option<u8>,
 }
 
 impl TryFrom<JsValue> for WalletCtorArgs {
@@ -46,8 +62,10 @@ impl TryFrom<JsValue> for WalletCtorArgs {
             let encoding = object.try_get::<WrpcEncoding>("encoding")?;
             let url = object.get_value("url")?.as_string();
             let resolver = object.try_get("resolver")?;
+            let transport = object.try_get_value("transport")?.map(JsCast::unchecked_into::<IRpcTransport>);
+            let min_secret_strength = object.try_get_value("minSecretStrength")?.map(|value| value.try_as_u8()).transpose()?;
 
-            Ok(Self { resident, network_id, encoding, url, resolver })
+            Ok(Self { resident, network_id, encoding, url, resolver, transport, min_secret_strength })
         } else {
             Ok(WalletCtorArgs::default())
         }
@@ -144,17 +162,27 @@ cfg_if! {
 impl Wallet {
     #[wasm_bindgen(constructor)]
     pub fn constructor(config: IWalletConfig) -> Result<Wallet> {
-        let WalletCtorArgs { resident, network_id, encoding, url, resolver } = WalletCtorArgs::try_from(JsValue::from(config))?;
+        let WalletCtorArgs { resident, network_id, encoding, url, resolver, transport, min_secret_strength } =
+            WalletCtorArgs::try_from(JsValue::from(config))?;
 
+        #[cfg(feature = "fs")]
         let store = Arc::new(LocalStore::try_new(resident)?);
+        #[cfg(not(feature = "fs"))]
+        let store = match resident {
+            true => Arc::new(LocalStore::new_resident()),
+            false => return Err(Error::FileStorageDisabled),
+        };
 
-        let rpc_config = RpcConfig { url, resolver, encoding, network_id };
+        let rpc_config = RpcConfig { url, resolver, encoding, network_id, transport, daa_score_notification_rate: None };
 
         let rpc = RpcClient::new(Some(rpc_config))?;
         let rpc_api: Arc<DynRpcApi> = rpc.client().rpc_api().clone();
         let rpc_ctl = rpc.client().rpc_ctl().clone();
         let rpc_binding = Rpc::new(rpc_api, rpc_ctl);
         let wallet = Arc::new(native::Wallet::try_with_rpc(Some(rpc_binding), store, network_id)?);
+        if let Some(min_score) = min_secret_strength {
+            wallet.set_secret_strength_policy(SecretStrengthPolicy::new(min_score));
+        }
 
         Ok(Self {
             inner: Arc::new(Inner {
@@ -299,7 +327,7 @@ impl Wallet {
                                 for handler in handlers.into_iter() {
                                     let value = notification.as_ref().to_js_value();
                                     if let Err(err) = handler.call(&value) {
-                                        log_error!("Error while executing notification callback: {:?}", err);
+                                        log!(Wallet, Error, "Error while executing notification callback: {:?}", err);
                                     }
                                 }
                             }
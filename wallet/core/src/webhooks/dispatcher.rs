@@ -0,0 +1,414 @@
+//!
+//! Webhook dispatcher posting wallet events to the configured webhooks.
+//!
+
+use super::*;
+use crate::logging::log;
+
+/// Delay before the first delivery retry, doubled on each subsequent retry.
+pub const WEBHOOK_RETRY_DELAY: Duration = Duration::from_secs(2);
+/// Maximum delay between delivery retries.
+pub const WEBHOOK_MAX_RETRY_DELAY: Duration = Duration::from_secs(5 * 60);
+/// Timeout of a single delivery attempt.
+pub const WEBHOOK_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Received transaction tracked until its last confirmation milestone.
+struct Tracked {
+    account_id: AccountId,
+    block_daa_score: u64,
+    confirmations: u64,
+}
+
+struct Inner {
+    webhooks: Mutex<Vec<WebhookConfig>>,
+    /// Signing secrets of the webhooks (available while the wallet is open)
+    secrets: Mutex<HashMap<String, String>>,
+    tracked: Mutex<HashMap<TransactionId, Tracked>>,
+    #[cfg(all(feature = "webhooks", not(target_arch = "wasm32")))]
+    client: reqwest::Client,
+}
+
+/// Translates wallet [`Events`] into [`WebhookPayload`] notifications
+/// and delivers them to the configured webhooks. Each delivery runs in
+/// a separate task, retrying failed deliveries with an exponential backoff
+/// (see [`WEBHOOK_RETRY_DELAY`]) up to [`WebhookConfig::max_retries`] times.
+/// Requests rejected by the endpoint with a client error (other than
+/// `408` and `429`) are not retried. Payloads of signed webhooks are
+/// not delivered while the signing secret is not available.
+#[derive(Clone)]
+pub struct WebhookDispatcher {
+    inner: Arc<Inner>,
+}
+
+impl Default for WebhookDispatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WebhookDispatcher {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                webhooks: Mutex::new(Vec::new()),
+                secrets: Mutex::new(HashMap::new()),
+                tracked: Mutex::new(HashMap::new()),
+                #[cfg(all(feature = "webhooks", not(target_arch = "wasm32")))]
+                client: reqwest::Client::builder().timeout(WEBHOOK_REQUEST_TIMEOUT).build().unwrap_or_default(),
+            }),
+        }
+    }
+
+    /// Returns `true` if webhook delivery is supported by this build
+    /// (requires the `webhooks` crate feature on a native platform).
+    pub fn is_supported() -> bool {
+        cfg!(all(feature = "webhooks", not(target_arch = "wasm32")))
+    }
+
+    pub fn webhooks(&self) -> Vec<WebhookConfig> {
+        self.inner.webhooks.lock().unwrap().clone()
+    }
+
+    /// Replace the configured webhooks (used when loading the wallet settings).
+    pub fn configure(&self, webhooks: Vec<WebhookConfig>) {
+        if !webhooks.is_empty() && !Self::is_supported() {
+            log!(Wallet, Warn, "Webhook delivery is not supported by this build, {} webhook(s) will not be notified", webhooks.len());
+        }
+        *self.inner.webhooks.lock().unwrap() = webhooks;
+    }
+
+    /// Replace the webhook signing secrets (loaded from the wallet data
+    /// when the wallet is opened and cleared when the wallet is closed).
+    pub fn set_secrets(&self, secrets: &[WebhookSecret]) {
+        let mut map = self.inner.secrets.lock().unwrap();
+        map.values_mut().for_each(|secret| secret.zeroize());
+        *map = secrets.iter().map(|secret| (secret.id.clone(), secret.secret.clone())).collect();
+    }
+
+    pub fn insert(&self, webhook: WebhookConfig, secret: Option<&WebhookSecret>) -> Result<()> {
+        if !Self::is_supported() {
+            return Err(Error::Webhook("webhook delivery is not supported by this build".to_string()));
+        }
+        if let Some(secret) = secret {
+            self.inner.secrets.lock().unwrap().insert(secret.id.clone(), secret.secret.clone());
+        }
+        self.inner.webhooks.lock().unwrap().push(webhook);
+        Ok(())
+    }
+
+    /// Remove the webhook `id`. Pending delivery retries to this webhook are discarded.
+    pub fn remove(&self, id: &str) -> Result<WebhookConfig> {
+        let mut webhooks = self.inner.webhooks.lock().unwrap();
+        let index = webhooks.iter().position(|webhook| webhook.id == id).ok_or_else(|| Error::WebhookNotFound(id.to_string()))?;
+        if let Some(mut secret) = self.inner.secrets.lock().unwrap().remove(id) {
+            secret.zeroize();
+        }
+        Ok(webhooks.remove(index))
+    }
+
+    #[cfg(all(feature = "webhooks", not(target_arch = "wasm32")))]
+    fn contains(&self, id: &str) -> bool {
+        self.inner.webhooks.lock().unwrap().iter().any(|webhook| webhook.id == id)
+    }
+
+    /// Process a wallet event, notifying the webhooks subscribed to it.
+    pub fn handle_event(&self, event: &Events) {
+        for (webhook, payload) in self.notifications(event) {
+            self.deliver(webhook, payload);
+        }
+    }
+
+    /// Notifications of the webhooks subscribed to the wallet `event`.
+    fn notifications(&self, event: &Events) -> Vec<(WebhookConfig, Arc<WebhookPayload>)> {
+        if self.inner.webhooks.lock().unwrap().is_empty() {
+            return vec![];
+        }
+
+        match event {
+            Events::Balance { balance: Some(balance), id } => self.notify(WebhookEventKind::Balance, &AccountId::from(*id), balance),
+
+            Events::Pending { record } if matches!(record.kind(), TransactionKind::Incoming | TransactionKind::TransferIncoming) => {
+                if let Binding::Account(account_id) = record.binding() {
+                    self.track(account_id, record);
+                    self.notify(WebhookEventKind::Incoming, account_id, record)
+                } else {
+                    vec![]
+                }
+            }
+
+            Events::Maturity { record } if !record.is_change() && !record.is_batch() => {
+                if let Binding::Account(account_id) = record.binding() {
+                    self.notify(WebhookEventKind::Maturity, account_id, record)
+                } else {
+                    vec![]
+                }
+            }
+
+            Events::Reorg { record } => {
+                self.inner.tracked.lock().unwrap().remove(record.id());
+                vec![]
+            }
+
+            Events::DaaScoreChange { current_daa_score } => self.update_confirmations(*current_daa_score),
+
+            _ => vec![],
+        }
+    }
+
+    fn track(&self, account_id: &AccountId, record: &TransactionRecord) {
+        let tracked = self
+            .inner
+            .webhooks
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|webhook| webhook.last_confirmation().is_some() && webhook.accepts(WebhookEventKind::Confirmation, account_id));
+
+        if tracked {
+            self.inner.tracked.lock().unwrap().insert(
+                *record.id(),
+                Tracked { account_id: *account_id, block_daa_score: record.block_daa_score(), confirmations: 0 },
+            );
+        }
+    }
+
+    /// Notifications of the confirmation milestones reached by the tracked
+    /// transactions (each milestone is notified once), discarding transactions
+    /// that have reached the last milestone of all subscribed webhooks.
+    fn update_confirmations(&self, current_daa_score: u64) -> Vec<(WebhookConfig, Arc<WebhookPayload>)> {
+        let mut tracked = self.inner.tracked.lock().unwrap();
+        if tracked.is_empty() {
+            return vec![];
+        }
+
+        let webhooks = self.webhooks();
+        let mut notifications = Vec::new();
+        tracked.retain(|transaction_id, tracked| {
+            let confirmations = current_daa_score.saturating_sub(tracked.block_daa_score);
+            let mut last = 0;
+            for webhook in webhooks.iter().filter(|webhook| webhook.accepts(WebhookEventKind::Confirmation, &tracked.account_id)) {
+                for milestone in
+                    webhook.confirmations.iter().filter(|milestone| (tracked.confirmations + 1..=confirmations).contains(*milestone))
+                {
+                    let data = serde_json::json!({ "transactionId": transaction_id, "confirmations": milestone });
+                    let payload = WebhookPayload::new(WebhookEventKind::Confirmation, tracked.account_id, data);
+                    notifications.push((webhook.clone(), Arc::new(payload)));
+                }
+                last = last.max(webhook.last_confirmation().unwrap_or_default());
+            }
+            tracked.confirmations = confirmations;
+            confirmations < last
+        });
+
+        notifications
+    }
+
+    fn notify<T: Serialize>(
+        &self,
+        kind: WebhookEventKind,
+        account_id: &AccountId,
+        data: &T,
+    ) -> Vec<(WebhookConfig, Arc<WebhookPayload>)> {
+        let webhooks = self.webhooks().into_iter().filter(|webhook| webhook.accepts(kind, account_id)).collect::<Vec<_>>();
+        if webhooks.is_empty() {
+            return vec![];
+        }
+
+        match serde_json::to_value(data) {
+            Ok(data) => {
+                let payload = Arc::new(WebhookPayload::new(kind, *account_id, data));
+                webhooks.into_iter().map(|webhook| (webhook, payload.clone())).collect()
+            }
+            Err(err) => {
+                log!(Wallet, Error, "Unable to serialize `{kind}` webhook payload: {err}");
+                vec![]
+            }
+        }
+    }
+
+    #[cfg(all(feature = "webhooks", not(target_arch = "wasm32")))]
+    fn deliver(&self, webhook: WebhookConfig, payload: Arc<WebhookPayload>) {
+        let this = self.clone();
+        spawn(async move {
+            let body = match serde_json::to_string(&*payload) {
+                Ok(body) => body,
+                Err(err) => {
+                    log!(Wallet, Error, "Unable to serialize `{}` webhook payload: {err}", payload.event);
+                    return;
+                }
+            };
+
+            let mut delay = WEBHOOK_RETRY_DELAY;
+            for attempt in 0..=webhook.max_retries {
+                if attempt > 0 {
+                    sleep(delay).await;
+                    delay = (delay * 2).min(WEBHOOK_MAX_RETRY_DELAY);
+                }
+
+                if !this.contains(&webhook.id) {
+                    return;
+                }
+
+                match this.post(&webhook, &body).await {
+                    Ok(status) if status.is_success() => return,
+                    Ok(status) if status.is_client_error() && status.as_u16() != 408 && status.as_u16() != 429 => {
+                        log!(
+                            Wallet,
+                            Error,
+                            "Webhook {} rejected `{}` notification {}: {status}",
+                            webhook.id,
+                            payload.event,
+                            payload.id
+                        );
+                        return;
+                    }
+                    Ok(status) => {
+                        log!(Wallet, Warn, "Webhook {} delivery attempt {} failed: {status}", webhook.id, attempt + 1);
+                    }
+                    Err(err) => {
+                        log!(Wallet, Warn, "Webhook {} delivery attempt {} failed: {err}", webhook.id, attempt + 1);
+                    }
+                }
+            }
+
+            log!(
+                Wallet,
+                Error,
+                "Webhook {}: discarding `{}` notification {} after {} attempts",
+                webhook.id,
+                payload.event,
+                payload.id,
+                webhook.max_retries + 1
+            );
+        });
+    }
+
+    #[cfg(not(all(feature = "webhooks", not(target_arch = "wasm32"))))]
+    fn deliver(&self, _webhook: WebhookConfig, _payload: Arc<WebhookPayload>) {}
+
+    #[cfg(all(feature = "webhooks", not(target_arch = "wasm32")))]
+    async fn post(&self, webhook: &WebhookConfig, body: &str) -> Result<reqwest::StatusCode> {
+        let timestamp = unixtime_as_millis_u64();
+        let mut request = self
+            .inner
+            .client
+            .post(&webhook.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(WEBHOOK_ID_HEADER, &webhook.id)
+            .header(TIMESTAMP_HEADER, timestamp.to_string());
+
+        if webhook.signed {
+            let secrets = self.inner.secrets.lock().unwrap();
+            let secret = secrets.get(&webhook.id).ok_or_else(|| Error::Webhook("the signing secret is not available".to_string()))?;
+            request = request.header(SIGNATURE_HEADER, format!("sha256={}", sign_payload(secret, timestamp, body)?));
+        }
+
+        let response = request.body(body.to_string()).send().await.map_err(|err| Error::Webhook(err.to_string()))?;
+        Ok(response.status())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::MockRpcClient;
+    use crate::utxo::{UtxoContext, UtxoContextBinding, UtxoProcessor};
+    use kaspa_consensus_client::UtxoEntryReference;
+
+    fn make_record(account_id: AccountId, block_daa_score: u64) -> TransactionRecord {
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+        let processor = UtxoProcessor::new(Some(Arc::new(MockRpcClient::new()).into()), Some(network_id), None, None);
+        let context = UtxoContext::new(&processor, UtxoContextBinding::AccountId(account_id));
+        let utxos = [UtxoEntryReference::simulated(100_000_000)];
+        let mut record = TransactionRecord::new_incoming(&context, utxos[0].transaction_id(), &utxos);
+        record.block_daa_score = block_daa_score;
+        record
+    }
+
+    fn make_webhook(events: Vec<WebhookEventKind>, account_ids: Option<Vec<AccountId>>, confirmations: Vec<u64>) -> WebhookConfig {
+        WebhookConfig::try_new("http://localhost".to_string(), false, events, account_ids, confirmations, None).unwrap()
+    }
+
+    fn kinds(notifications: &[(WebhookConfig, Arc<WebhookPayload>)]) -> Vec<(String, WebhookEventKind)> {
+        notifications.iter().map(|(webhook, payload)| (webhook.id.clone(), payload.event)).collect()
+    }
+
+    #[test]
+    fn test_webhook_dispatcher_handle_event() {
+        let account_id = AccountId::from_hex(&"01".repeat(32)).unwrap();
+        let other_id = AccountId::from_hex(&"02".repeat(32)).unwrap();
+        let dispatcher = WebhookDispatcher::new();
+
+        let balance = Events::Balance { balance: Some(Balance::new(1, 0, 0, 1, 0, 0)), id: account_id.into() };
+        assert!(dispatcher.notifications(&balance).is_empty());
+
+        let all = make_webhook(vec![], None, vec![]);
+        let incoming = make_webhook(vec![WebhookEventKind::Incoming], Some(vec![account_id]), vec![]);
+        let other = make_webhook(vec![], Some(vec![other_id]), vec![]);
+        dispatcher.configure(vec![all.clone(), incoming.clone(), other.clone()]);
+
+        assert_eq!(kinds(&dispatcher.notifications(&balance)), vec![(all.id.clone(), WebhookEventKind::Balance)]);
+
+        let record = make_record(account_id, 100);
+        let notifications = dispatcher.notifications(&Events::Pending { record: record.clone() });
+        assert_eq!(
+            kinds(&notifications),
+            vec![(all.id.clone(), WebhookEventKind::Incoming), (incoming.id.clone(), WebhookEventKind::Incoming)]
+        );
+        // all webhooks receive the same notification
+        assert_eq!(notifications[0].1.id, notifications[1].1.id);
+        assert_eq!(notifications[0].1.data["id"], serde_json::to_value(record.id()).unwrap());
+
+        let notifications = dispatcher.notifications(&Events::Maturity { record: record.clone() });
+        assert_eq!(kinds(&notifications), vec![(all.id.clone(), WebhookEventKind::Maturity)]);
+
+        // events of other accounts are not notified to webhooks bound to an account
+        let notifications = dispatcher.notifications(&Events::Pending { record: make_record(other_id, 100) });
+        assert_eq!(
+            kinds(&notifications),
+            vec![(all.id.clone(), WebhookEventKind::Incoming), (other.id.clone(), WebhookEventKind::Incoming)]
+        );
+
+        dispatcher.remove(&all.id).unwrap();
+        assert!(dispatcher.notifications(&balance).is_empty());
+        assert!(matches!(dispatcher.remove(&all.id), Err(Error::WebhookNotFound(_))));
+    }
+
+    #[test]
+    fn test_webhook_dispatcher_confirmations() {
+        let account_id = AccountId::from_hex(&"01".repeat(32)).unwrap();
+        let dispatcher = WebhookDispatcher::new();
+        let short = make_webhook(vec![WebhookEventKind::Confirmation], None, vec![10]);
+        let long = make_webhook(vec![WebhookEventKind::Confirmation], None, vec![10, 100]);
+        dispatcher.configure(vec![short.clone(), long.clone()]);
+
+        let record = make_record(account_id, 1000);
+        let confirmations = |notifications: Vec<(WebhookConfig, Arc<WebhookPayload>)>| {
+            notifications
+                .into_iter()
+                .map(|(webhook, payload)| (webhook.id, payload.data["confirmations"].as_u64().unwrap()))
+                .collect::<Vec<_>>()
+        };
+
+        // confirmation milestones are not notified before the transaction is received
+        assert!(dispatcher.update_confirmations(2000).is_empty());
+
+        // the incoming transaction is tracked, but not notified (confirmation webhooks only)
+        assert!(dispatcher.notifications(&Events::Pending { record: record.clone() }).is_empty());
+        assert!(confirmations(dispatcher.notifications(&Events::DaaScoreChange { current_daa_score: 1005 })).is_empty());
+        assert_eq!(
+            confirmations(dispatcher.notifications(&Events::DaaScoreChange { current_daa_score: 1050 })),
+            vec![(short.id.clone(), 10), (long.id.clone(), 10)]
+        );
+        // each milestone is notified once
+        assert!(dispatcher.update_confirmations(1060).is_empty());
+        assert_eq!(confirmations(dispatcher.update_confirmations(1200)), vec![(long.id.clone(), 100)]);
+        // the transaction is no longer tracked after the last milestone
+        assert!(dispatcher.inner.tracked.lock().unwrap().is_empty());
+
+        // reorged transactions are no longer tracked
+        dispatcher.notifications(&Events::Pending { record: record.clone() });
+        dispatcher.notifications(&Events::Reorg { record });
+        assert!(dispatcher.update_confirmations(1200).is_empty());
+    }
+}
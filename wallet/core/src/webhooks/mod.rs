@@ -0,0 +1,296 @@
+//!
+//! Webhook notifications for server deployments.
+//!
+//! A webhook is an HTTP endpoint receiving JSON payloads (see [`WebhookPayload`])
+//! posted by the [`WebhookDispatcher`] when the balance of an account changes,
+//! when an account receives a transaction and when received transactions
+//! reach the configured confirmation milestones or maturity.
+//!
+//! Payloads of webhooks configured with a secret are signed using HMAC-SHA256.
+//! Signing secrets are stored in the encrypted wallet data (see [`WebhookSecret`])
+//! and are available only while the wallet is open. The signature is transmitted in the [`SIGNATURE_HEADER`] header (`sha256=<hex>`)
+//! and covers the [`TIMESTAMP_HEADER`] value and the request body joined by `.`
+//! (see [`sign_payload`]). Failed deliveries are retried with an exponential backoff.
+//!
+//! Webhooks are managed via the Wallet API (`WebhooksCreate`, `WebhooksEnumerate`,
+//! `WebhooksRemove`) and persisted (without the signing secrets) in
+//! [`WalletSettings::Webhooks`](crate::settings::WalletSettings::Webhooks).
+//! Delivery requires the `webhooks` crate feature (native platforms only).
+//!
+
+mod dispatcher;
+pub use dispatcher::{WebhookDispatcher, WEBHOOK_MAX_RETRY_DELAY, WEBHOOK_REQUEST_TIMEOUT, WEBHOOK_RETRY_DELAY};
+
+use crate::imports::*;
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use serde_json::Value;
+use sha2::Sha256;
+use workflow_core::time::unixtime_as_millis_u64;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// HTTP header carrying the payload signature (`sha256=<hex>`).
+pub const SIGNATURE_HEADER: &str = "X-Kaspa-Signature";
+/// HTTP header carrying the payload timestamp (unix time in milliseconds).
+pub const TIMESTAMP_HEADER: &str = "X-Kaspa-Timestamp";
+/// HTTP header carrying the id of the webhook the payload is delivered to.
+pub const WEBHOOK_ID_HEADER: &str = "X-Kaspa-Webhook-Id";
+
+/// Default number of delivery retries.
+pub const DEFAULT_WEBHOOK_RETRIES: u32 = 5;
+/// Maximum number of delivery retries.
+pub const MAX_WEBHOOK_RETRIES: u32 = 16;
+
+/// Kind of the event notified to a webhook.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, BorshSerialize, BorshDeserialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum WebhookEventKind {
+    /// Account balance has changed.
+    Balance,
+    /// Account has received a transaction (pending confirmation).
+    Incoming,
+    /// Received transaction has reached a confirmation milestone
+    /// (see [`WebhookConfig::confirmations`]).
+    Confirmation,
+    /// Transaction has reached maturity.
+    Maturity,
+}
+
+impl std::fmt::Display for WebhookEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookEventKind::Balance => write!(f, "balance"),
+            WebhookEventKind::Incoming => write!(f, "incoming"),
+            WebhookEventKind::Confirmation => write!(f, "confirmation"),
+            WebhookEventKind::Maturity => write!(f, "maturity"),
+        }
+    }
+}
+
+/// Webhook configuration, persisted in the wallet settings.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookConfig {
+    pub id: String,
+    /// Endpoint receiving the payloads (`http://` or `https://`).
+    pub url: String,
+    /// Payloads are signed using the [`WebhookSecret`] stored in the wallet.
+    #[serde(default)]
+    pub signed: bool,
+    /// Notified event kinds; all events are notified if empty.
+    #[serde(default)]
+    pub events: Vec<WebhookEventKind>,
+    /// Notified accounts; all accounts are notified if `None`.
+    #[serde(default)]
+    pub account_ids: Option<Vec<AccountId>>,
+    /// Confirmation milestones (in DAA score units elapsed since the
+    /// transaction acceptance) notified for received transactions.
+    #[serde(default)]
+    pub confirmations: Vec<u64>,
+    /// Number of delivery retries.
+    pub max_retries: u32,
+}
+
+impl WebhookConfig {
+    pub fn try_new(
+        url: String,
+        signed: bool,
+        events: Vec<WebhookEventKind>,
+        account_ids: Option<Vec<AccountId>>,
+        confirmations: Vec<u64>,
+        max_retries: Option<u32>,
+    ) -> Result<Self> {
+        let url = url.trim().to_string();
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            return Err(Error::InvalidArgument(format!("webhook url must use http or https: `{url}`")));
+        }
+
+        let mut events = events;
+        events.sort_unstable();
+        events.dedup();
+
+        let mut confirmations = confirmations.into_iter().filter(|confirmations| *confirmations > 0).collect::<Vec<_>>();
+        confirmations.sort_unstable();
+        confirmations.dedup();
+
+        let max_retries = max_retries.unwrap_or(DEFAULT_WEBHOOK_RETRIES).min(MAX_WEBHOOK_RETRIES);
+
+        let mut id = [0u8; 8];
+        rand::thread_rng().fill_bytes(&mut id);
+
+        Ok(Self { id: faster_hex::hex_string(&id), url, signed, events, account_ids, confirmations, max_retries })
+    }
+
+    /// Returns `true` if the webhook is notified of the event `kind` for the account `account_id`.
+    pub fn accepts(&self, kind: WebhookEventKind, account_id: &AccountId) -> bool {
+        (self.events.is_empty() || self.events.contains(&kind))
+            && self.account_ids.as_ref().map(|account_ids| account_ids.contains(account_id)).unwrap_or(true)
+    }
+
+    /// Returns the last confirmation milestone notified to this webhook.
+    pub fn last_confirmation(&self) -> Option<u64> {
+        (self.events.is_empty() || self.events.contains(&WebhookEventKind::Confirmation))
+            .then(|| self.confirmations.last().cloned())
+            .flatten()
+    }
+}
+
+/// Webhook information returned by the Wallet API (the signing secret is not disclosed).
+#[derive(Debug, Clone, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDescriptor {
+    pub id: String,
+    pub url: String,
+    /// `true` if payloads are signed.
+    pub signed: bool,
+    pub events: Vec<WebhookEventKind>,
+    pub account_ids: Option<Vec<AccountId>>,
+    pub confirmations: Vec<u64>,
+    pub max_retries: u32,
+}
+
+impl From<&WebhookConfig> for WebhookDescriptor {
+    fn from(config: &WebhookConfig) -> Self {
+        Self {
+            id: config.id.clone(),
+            url: config.url.clone(),
+            signed: config.signed,
+            events: config.events.clone(),
+            account_ids: config.account_ids.clone(),
+            confirmations: config.confirmations.clone(),
+            max_retries: config.max_retries,
+        }
+    }
+}
+
+/// HMAC-SHA256 payload signing secret of the webhook `id`, stored
+/// in the encrypted wallet data.
+#[derive(Clone, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookSecret {
+    pub id: String,
+    pub secret: String,
+}
+
+impl WebhookSecret {
+    /// Create the secret of the webhook `id`, returning `None` if
+    /// the `secret` is empty (after trimming the whitespace).
+    pub fn new(id: &str, secret: &str) -> Option<Self> {
+        let secret = secret.trim();
+        (!secret.is_empty()).then(|| Self { id: id.to_string(), secret: secret.to_string() })
+    }
+}
+
+impl std::fmt::Debug for WebhookSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookSecret").field("id", &self.id).finish_non_exhaustive()
+    }
+}
+
+impl Zeroize for WebhookSecret {
+    fn zeroize(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+impl Drop for WebhookSecret {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for WebhookSecret {}
+
+/// JSON payload posted to webhooks.
+///
+/// The `data` field contains:
+/// - `balance`: the account [`Balance`]
+/// - `incoming` and `maturity`: the [`TransactionRecord`]
+/// - `confirmation`: `{ transactionId, confirmations }`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookPayload {
+    /// Unique id of the notification, retained across delivery retries.
+    pub id: String,
+    pub event: WebhookEventKind,
+    /// Unix time in milliseconds at which the notification has been created.
+    pub timestamp: u64,
+    pub account_id: AccountId,
+    pub data: Value,
+}
+
+impl WebhookPayload {
+    pub fn new(event: WebhookEventKind, account_id: AccountId, data: Value) -> Self {
+        let mut id = [0u8; 16];
+        rand::thread_rng().fill_bytes(&mut id);
+        Self { id: faster_hex::hex_string(&id), event, timestamp: unixtime_as_millis_u64(), account_id, data }
+    }
+}
+
+/// Creates the hex-encoded HMAC-SHA256 signature of the payload `body`
+/// posted at `timestamp` (the signed message is `<timestamp>.<body>`).
+pub fn sign_payload(secret: &str, timestamp: u64, body: &str) -> Result<String> {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).map_err(|err| Error::Webhook(err.to_string()))?;
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+    Ok(faster_hex::hex_string(&mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_webhook_config() {
+        let account_id = AccountId::from_hex(&"01".repeat(32)).unwrap();
+        let other_id = AccountId::from_hex(&"02".repeat(32)).unwrap();
+
+        assert!(WebhookConfig::try_new("ftp://example.com".to_string(), false, vec![], None, vec![], None).is_err());
+        assert!(WebhookSecret::new("id", " ").is_none());
+        assert_eq!(WebhookSecret::new("id", " secret ").unwrap().secret, "secret");
+
+        let config = WebhookConfig::try_new(
+            " https://example.com/hook ".to_string(),
+            true,
+            vec![WebhookEventKind::Incoming, WebhookEventKind::Confirmation],
+            Some(vec![account_id]),
+            vec![100, 0, 10, 100],
+            Some(100),
+        )
+        .unwrap();
+
+        assert_eq!(config.url, "https://example.com/hook");
+        assert!(WebhookDescriptor::from(&config).signed);
+        // signing secrets are not persisted in the settings
+        assert!(serde_json::to_value(&config).unwrap().get("secret").is_none());
+        assert_eq!(config.confirmations, vec![10, 100]);
+        assert_eq!(config.max_retries, MAX_WEBHOOK_RETRIES);
+        assert_eq!(config.last_confirmation(), Some(100));
+        assert!(config.accepts(WebhookEventKind::Incoming, &account_id));
+        assert!(!config.accepts(WebhookEventKind::Balance, &account_id));
+        assert!(!config.accepts(WebhookEventKind::Incoming, &other_id));
+
+        let config = WebhookConfig::try_new("http://localhost".to_string(), false, vec![], None, vec![10], None).unwrap();
+        assert_eq!(config.max_retries, DEFAULT_WEBHOOK_RETRIES);
+        assert!(config.accepts(WebhookEventKind::Balance, &other_id));
+    }
+
+    #[test]
+    fn test_webhook_payload_signature() {
+        // echo -n '1700000000000.{"event":"balance"}' | openssl dgst -sha256 -hmac 'secret'
+        let signature = sign_payload("secret", 1_700_000_000_000, r#"{"event":"balance"}"#).unwrap();
+        assert_eq!(signature, "4b0f8ca2ab8038fc13239b4d48bcc82c00d38c25534bb0299adc2593cccb2630");
+        assert_ne!(signature, sign_payload("secret", 1_700_000_000_001, r#"{"event":"balance"}"#).unwrap());
+        assert_ne!(signature, sign_payload("other", 1_700_000_000_000, r#"{"event":"balance"}"#).unwrap());
+
+        let account_id = AccountId::from_hex(&"01".repeat(32)).unwrap();
+        let payload = WebhookPayload::new(WebhookEventKind::Confirmation, account_id, serde_json::json!({ "confirmations": 10 }));
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["event"], "confirmation");
+        assert_eq!(json["accountId"], "01".repeat(32));
+        assert_eq!(json["data"]["confirmations"], 10);
+    }
+}
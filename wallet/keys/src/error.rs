@@ -63,6 +63,12 @@ pub enum Error {
 
     #[error("Invalid UTF-8 sequence")]
     Utf8(#[from] std::str::Utf8Error),
+
+    #[error(transparent)]
+    MultisigCreateError(#[from] kaspa_txscript::MultisigCreateError),
+
+    #[error(transparent)]
+    TxScriptError(#[from] kaspa_txscript_errors::TxScriptError),
 }
 
 impl Error {
@@ -3,6 +3,7 @@ pub mod derivation_path;
 pub mod error;
 mod imports;
 pub mod keypair;
+pub mod multisig;
 pub mod prelude;
 pub mod privatekey;
 pub mod privkeygen;
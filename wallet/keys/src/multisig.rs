@@ -0,0 +1,306 @@
+//!
+//! Multi-signature (N-of-M) redeem script and P2SH address generation.
+//!
+
+use crate::derivation::gen1::WalletDerivationManager;
+use crate::derivation::traits::WalletDerivationManagerTrait;
+use crate::imports::*;
+use kaspa_bip32::Prefix as KeyPrefix;
+use kaspa_consensus_core::network::NetworkType;
+use kaspa_txscript::{
+    extract_script_pub_key_address, multisig_redeem_script, multisig_redeem_script_ecdsa, pay_to_script_hash_script,
+};
+
+/// Creates a `minimum_signatures`-of-N multisig redeem script of the supplied public keys
+/// (the keys are used in the supplied order).
+pub fn create_multisig_redeem_script(keys: &[secp256k1::PublicKey], minimum_signatures: usize, ecdsa: bool) -> Result<Vec<u8>> {
+    if minimum_signatures == 0 || minimum_signatures > keys.len() {
+        return Err(Error::custom(format!(
+            "Invalid number of minimum signatures ({minimum_signatures}), must be between 1 and the amount of provided public keys ({})",
+            keys.len()
+        )));
+    }
+
+    let script = if ecdsa {
+        multisig_redeem_script_ecdsa(keys.iter().map(|key| key.serialize()), minimum_signatures)
+    } else {
+        multisig_redeem_script(keys.iter().map(|key| key.x_only_public_key().0.serialize()), minimum_signatures)
+    }?;
+
+    Ok(script)
+}
+
+///
+/// Multi-signature redeem script and the corresponding pay-to-script-hash address.
+///
+/// @see {@link MultisigAddressGenerator}
+/// @category Wallet SDK
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[wasm_bindgen(inspectable)]
+pub struct MultisigAddress {
+    index: Option<u32>,
+    redeem_script: Vec<u8>,
+    address: Address,
+}
+
+impl MultisigAddress {
+    pub fn try_new(
+        index: Option<u32>,
+        keys: &[secp256k1::PublicKey],
+        minimum_signatures: usize,
+        ecdsa: bool,
+        network_type: NetworkType,
+    ) -> Result<Self> {
+        let redeem_script = create_multisig_redeem_script(keys, minimum_signatures, ecdsa)?;
+        let address = extract_script_pub_key_address(&pay_to_script_hash_script(&redeem_script), network_type.into())?;
+        Ok(Self { index, redeem_script, address })
+    }
+
+    pub fn redeem_script(&self) -> &[u8] {
+        &self.redeem_script
+    }
+}
+
+#[wasm_bindgen]
+impl MultisigAddress {
+    /// Create a multisig redeem script and address from public keys (used in the supplied order).
+    #[wasm_bindgen(js_name = fromPublicKeys)]
+    #[allow(non_snake_case)]
+    pub fn from_public_keys(
+        minimumSignatures: usize,
+        keys: PublicKeyArrayT,
+        networkType: &NetworkTypeT,
+        ecdsa: Option<bool>,
+    ) -> Result<MultisigAddress> {
+        Self::try_new(
+            None,
+            &Vec::<secp256k1::PublicKey>::try_from(keys)?,
+            minimumSignatures,
+            ecdsa.unwrap_or(false),
+            networkType.try_into()?,
+        )
+    }
+
+    /// Derivation index of the address (not present for addresses created from public keys).
+    #[wasm_bindgen(getter)]
+    pub fn index(&self) -> Option<u32> {
+        self.index
+    }
+
+    /// Hex-encoded redeem script.
+    #[wasm_bindgen(getter, js_name = redeemScript)]
+    pub fn redeem_script_as_hex(&self) -> String {
+        self.redeem_script.to_hex()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn address(&self) -> Address {
+        self.address.clone()
+    }
+}
+
+///
+/// Generates multisig receive and change addresses from the extended public keys
+/// of all cosigners, matching the addresses of a `multisig` wallet account, allowing
+/// cosigners to independently verify the addresses they are given.
+///
+/// The keys are ordered the same way as in the account (by their `xpub` serialization),
+/// so the order in which they are supplied does not matter. Addresses are derived at
+/// `<cosigner index>/<0|1>/<index>` (or `<0|1>/<index>` if the cosigner index is not
+/// supplied) relative to each extended public key, where the cosigner index is the
+/// position of the account owner's key among the ordered keys.
+///
+/// @see {@link MultisigAddress}, {@link PublicKeyGenerator}, {@link XPub}
+/// @category Wallet SDK
+///
+#[wasm_bindgen]
+pub struct MultisigAddressGenerator {
+    managers: Vec<WalletDerivationManager>,
+    minimum_signatures: usize,
+    ecdsa: bool,
+}
+
+impl MultisigAddressGenerator {
+    pub fn try_new(
+        mut xpubs: Vec<ExtendedPublicKey<secp256k1::PublicKey>>,
+        minimum_signatures: usize,
+        cosigner_index: Option<u32>,
+        ecdsa: bool,
+    ) -> Result<Self> {
+        if minimum_signatures == 0 || minimum_signatures > xpubs.len() {
+            return Err(Error::custom(format!(
+                "Invalid number of minimum signatures ({minimum_signatures}), must be between 1 and the amount of provided keys ({})",
+                xpubs.len()
+            )));
+        }
+
+        xpubs.sort_by_cached_key(|xpub| xpub.to_string(Some(KeyPrefix::XPUB)));
+        let managers = xpubs
+            .into_iter()
+            .map(|xpub| WalletDerivationManager::from_extended_public_key(xpub, cosigner_index))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { managers, minimum_signatures, ecdsa })
+    }
+
+    /// Generate receive addresses for the given `range` of derivation indexes.
+    pub fn receive_addresses(&self, network_type: NetworkType, range: std::ops::Range<u32>) -> Result<Vec<MultisigAddress>> {
+        self.derive_addresses(false, network_type, range)
+    }
+
+    /// Generate change addresses for the given `range` of derivation indexes.
+    pub fn change_addresses(&self, network_type: NetworkType, range: std::ops::Range<u32>) -> Result<Vec<MultisigAddress>> {
+        self.derive_addresses(true, network_type, range)
+    }
+
+    fn derive_addresses(&self, change: bool, network_type: NetworkType, range: std::ops::Range<u32>) -> Result<Vec<MultisigAddress>> {
+        let keys = self
+            .managers
+            .iter()
+            .map(|manager| {
+                let manager = if change { manager.change_pubkey_manager() } else { manager.receive_pubkey_manager() };
+                manager.derive_pubkey_range(range.clone())
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        range
+            .enumerate()
+            .map(|(position, index)| {
+                let keys = keys.iter().map(|keys| keys[position]).collect::<Vec<_>>();
+                MultisigAddress::try_new(Some(index), &keys, self.minimum_signatures, self.ecdsa, network_type)
+            })
+            .collect()
+    }
+}
+
+/// Range containing only the derivation `index`.
+fn single_index_range(index: u32) -> Result<std::ops::Range<u32>> {
+    let end = index.checked_add(1).ok_or_else(|| Error::custom(format!("Invalid derivation index {index}")))?;
+    Ok(index..end)
+}
+
+#[wasm_bindgen]
+impl MultisigAddressGenerator {
+    #[wasm_bindgen(constructor)]
+    #[allow(non_snake_case)]
+    pub fn constructor(
+        xpubs: XPubArrayT,
+        minimumSignatures: usize,
+        cosignerIndex: Option<u32>,
+        ecdsa: Option<bool>,
+    ) -> Result<MultisigAddressGenerator> {
+        if !xpubs.is_array() {
+            return Err(Error::InvalidXPub);
+        }
+        let xpubs = Array::from(&xpubs)
+            .iter()
+            .map(|xpub| Ok(XPub::try_cast_from(&xpub)?.as_ref().inner().clone()))
+            .collect::<Result<Vec<_>>>()?;
+        Self::try_new(xpubs, minimumSignatures, cosignerIndex, ecdsa.unwrap_or(false))
+    }
+
+    /// Generate receive addresses for the given range of derivation indexes.
+    #[wasm_bindgen(js_name = receiveAddresses)]
+    #[allow(non_snake_case)]
+    pub fn receive_addresses_js(&self, networkType: &NetworkTypeT, mut start: u32, mut end: u32) -> Result<MultisigAddressArrayT> {
+        if start > end {
+            (start, end) = (end, start);
+        }
+        let addresses = self.receive_addresses(networkType.try_into()?, start..end)?;
+        Ok(Array::from_iter(addresses.into_iter().map(JsValue::from)).unchecked_into())
+    }
+
+    /// Generate a single receive address at a given derivation index.
+    #[wasm_bindgen(js_name = receiveAddress)]
+    #[allow(non_snake_case)]
+    pub fn receive_address(&self, networkType: &NetworkTypeT, index: u32) -> Result<MultisigAddress> {
+        Ok(self.receive_addresses(networkType.try_into()?, single_index_range(index)?)?.remove(0))
+    }
+
+    /// Generate change addresses for the given range of derivation indexes.
+    #[wasm_bindgen(js_name = changeAddresses)]
+    #[allow(non_snake_case)]
+    pub fn change_addresses_js(&self, networkType: &NetworkTypeT, mut start: u32, mut end: u32) -> Result<MultisigAddressArrayT> {
+        if start > end {
+            (start, end) = (end, start);
+        }
+        let addresses = self.change_addresses(networkType.try_into()?, start..end)?;
+        Ok(Array::from_iter(addresses.into_iter().map(JsValue::from)).unchecked_into())
+    }
+
+    /// Generate a single change address at a given derivation index.
+    #[wasm_bindgen(js_name = changeAddress)]
+    #[allow(non_snake_case)]
+    pub fn change_address(&self, networkType: &NetworkTypeT, index: u32) -> Result<MultisigAddress> {
+        Ok(self.change_addresses(networkType.try_into()?, single_index_range(index)?)?.remove(0))
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "(XPub | string)[]")]
+    pub type XPubArrayT;
+    #[wasm_bindgen(typescript_type = "MultisigAddress[]")]
+    pub type MultisigAddressArrayT;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cosigner_xpubs() -> Result<Vec<ExtendedPublicKey<secp256k1::PublicKey>>> {
+        let path = "m/45'/111111'/0'".parse()?;
+        [0x01u8, 0x02, 0x03]
+            .iter()
+            .map(|seed| Ok(ExtendedPrivateKey::<SecretKey>::new([*seed; 64])?.derive_path(&path)?.public_key()))
+            .collect()
+    }
+
+    #[test]
+    fn test_multisig_address_generator() -> Result<()> {
+        let mut xpubs = cosigner_xpubs()?;
+        let generator = MultisigAddressGenerator::try_new(xpubs.clone(), 2, Some(1), false)?;
+        let addresses = generator.receive_addresses(NetworkType::Mainnet, 0..4)?;
+        assert_eq!(addresses.len(), 4);
+
+        // keys ordered by their `xpub` serialization and derived at `<cosigner>/<0|1>/<index>`
+        xpubs.sort_by_cached_key(|xpub| xpub.to_string(Some(KeyPrefix::XPUB)));
+        for (index, address) in addresses.iter().enumerate() {
+            let keys = xpubs
+                .iter()
+                .map(|xpub| Ok(*xpub.clone().derive_path(&format!("m/1/0/{index}").parse()?)?.public_key()))
+                .collect::<Result<Vec<_>>>()?;
+            let redeem_script = multisig_redeem_script(keys.iter().map(|key| key.x_only_public_key().0.serialize()), 2)?;
+            assert_eq!(address.index(), Some(index as u32));
+            assert_eq!(address.redeem_script(), redeem_script.as_slice());
+            assert_eq!(address, &MultisigAddress::try_new(Some(index as u32), &keys, 2, false, NetworkType::Mainnet)?);
+            assert_eq!(address.address().version, AddressVersion::ScriptHash);
+        }
+
+        // the order of the supplied keys does not matter
+        xpubs.reverse();
+        assert_eq!(
+            MultisigAddressGenerator::try_new(xpubs.clone(), 2, Some(1), false)?.receive_addresses(NetworkType::Mainnet, 0..4)?,
+            addresses
+        );
+
+        // change addresses, cosigner index and ecdsa produce distinct addresses
+        let change = generator.change_addresses(NetworkType::Mainnet, 0..4)?;
+        assert!(change.iter().zip(addresses.iter()).all(|(change, receive)| change.address() != receive.address()));
+        let other =
+            MultisigAddressGenerator::try_new(xpubs.clone(), 2, Some(0), false)?.receive_addresses(NetworkType::Mainnet, 0..1)?;
+        assert_ne!(other[0].address(), addresses[0].address());
+        let ecdsa =
+            MultisigAddressGenerator::try_new(xpubs.clone(), 2, Some(1), true)?.receive_addresses(NetworkType::Mainnet, 0..1)?;
+        assert_ne!(ecdsa[0].redeem_script(), addresses[0].redeem_script());
+
+        assert!(MultisigAddressGenerator::try_new(xpubs.clone(), 0, None, false).is_err());
+        assert!(MultisigAddressGenerator::try_new(xpubs, 4, None, false).is_err());
+
+        assert_eq!(single_index_range(u32::MAX - 1)?, u32::MAX - 1..u32::MAX);
+        assert!(single_index_range(u32::MAX).is_err());
+
+        Ok(())
+    }
+}
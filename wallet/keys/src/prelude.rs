@@ -1,5 +1,6 @@
 pub use crate::derivation_path::*;
 pub use crate::keypair::*;
+pub use crate::multisig::*;
 pub use crate::privatekey::*;
 pub use crate::privkeygen::*;
 pub use crate::pubkeygen::*;
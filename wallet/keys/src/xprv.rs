@@ -70,6 +70,50 @@ impl XPrv {
         let public_key = self.inner.public_key();
         Ok(public_key.into())
     }
+
+    #[wasm_bindgen(js_name = toPrivateKey)]
+    pub fn to_private_key(&self) -> Result<PrivateKey> {
+        Ok(PrivateKey::from(self.inner.private_key()))
+    }
+
+    // ~~~~
+
+    /// Extended private key serialized using the standard BIP-32 `xprv` prefix.
+    #[wasm_bindgen(getter)]
+    pub fn xprv(&self) -> Result<String> {
+        self.into_string("xprv")
+    }
+
+    #[wasm_bindgen(getter, js_name = privateKey)]
+    pub fn private_key_as_hex(&self) -> String {
+        self.inner.private_key().secret_bytes().to_vec().to_hex()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn depth(&self) -> u8 {
+        self.inner.attrs().depth
+    }
+
+    #[wasm_bindgen(getter, js_name = parentFingerprint)]
+    pub fn parent_fingerprint_as_hex(&self) -> String {
+        self.inner.attrs().parent_fingerprint.to_vec().to_hex()
+    }
+
+    #[wasm_bindgen(getter, js_name = childNumber)]
+    pub fn child_number(&self) -> u32 {
+        self.inner.attrs().child_number.into()
+    }
+
+    #[wasm_bindgen(getter, js_name = chainCode)]
+    pub fn chain_code_as_hex(&self) -> String {
+        self.inner.attrs().chain_code.to_vec().to_hex()
+    }
+}
+
+impl From<ExtendedPrivateKey<SecretKey>> for XPrv {
+    fn from(inner: ExtendedPrivateKey<SecretKey>) -> Self {
+        Self { inner }
+    }
 }
 
 impl<'a> From<&'a XPrv> for &'a ExtendedPrivateKey<SecretKey> {
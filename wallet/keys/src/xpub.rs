@@ -1,4 +1,6 @@
 use crate::imports::*;
+use kaspa_addresses::AddressArrayT;
+use kaspa_consensus_core::network::NetworkType;
 
 ///
 /// Extended public key (XPub).
@@ -21,6 +23,16 @@ impl XPub {
     pub fn inner(&self) -> &ExtendedPublicKey<secp256k1::PublicKey> {
         &self.inner
     }
+
+    /// Derive non-hardened child public keys for the given `range` of indexes.
+    pub fn derive_public_keys(&self, range: std::ops::Range<u32>) -> Result<Vec<secp256k1::PublicKey>> {
+        range.map(|index| Ok(*self.inner.derive_child(ChildNumber::new(index, false)?)?.public_key())).collect::<Result<Vec<_>>>()
+    }
+
+    /// Derive addresses of non-hardened child public keys for the given `range` of indexes.
+    pub fn derive_addresses(&self, network_type: NetworkType, range: std::ops::Range<u32>) -> Result<Vec<Address>> {
+        self.derive_public_keys(range)?.into_iter().map(|pk| PublicKey::from(pk).to_address(network_type)).collect()
+    }
 }
 
 #[wasm_bindgen]
@@ -45,16 +57,76 @@ impl XPub {
         Ok(Self { inner })
     }
 
-    //#[wasm_bindgen(js_name = toString)]
     #[wasm_bindgen(js_name = intoString)]
     pub fn to_str(&self, prefix: &str) -> Result<String> {
         Ok(self.inner.to_string(Some(prefix.try_into()?)))
     }
 
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> Result<String> {
+        self.to_str("kpub")
+    }
+
     #[wasm_bindgen(js_name = toPublicKey)]
     pub fn public_key(&self) -> PublicKey {
         self.inner.public_key().into()
     }
+
+    /// Get the address of the public key of this extended key.
+    #[wasm_bindgen(js_name = toAddress)]
+    #[allow(non_snake_case)]
+    pub fn to_address(&self, networkType: &NetworkTypeT) -> Result<Address> {
+        self.public_key().to_address(networkType.try_into()?)
+    }
+
+    /// Derive non-hardened child public keys for the given range of indexes.
+    #[wasm_bindgen(js_name = publicKeys)]
+    pub fn public_keys(&self, mut start: u32, mut end: u32) -> Result<PublicKeyArrayT> {
+        if start > end {
+            (start, end) = (end, start);
+        }
+        let pubkeys = self.derive_public_keys(start..end)?;
+        Ok(Array::from_iter(pubkeys.into_iter().map(|pk| JsValue::from(PublicKey::from(pk)))).unchecked_into())
+    }
+
+    /// Derive addresses of non-hardened child public keys for the given range of indexes.
+    #[wasm_bindgen(js_name = addresses)]
+    #[allow(non_snake_case)]
+    pub fn addresses(&self, networkType: &NetworkTypeT, mut start: u32, mut end: u32) -> Result<AddressArrayT> {
+        if start > end {
+            (start, end) = (end, start);
+        }
+        let addresses = self.derive_addresses(networkType.try_into()?, start..end)?;
+        Ok(Array::from_iter(addresses.into_iter().map(JsValue::from)).unchecked_into())
+    }
+
+    // ~~~~
+
+    /// Extended public key serialized using the standard BIP-32 `xpub` prefix.
+    #[wasm_bindgen(getter)]
+    pub fn xpub(&self) -> Result<String> {
+        self.to_str("xpub")
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn depth(&self) -> u8 {
+        self.inner.attrs().depth
+    }
+
+    #[wasm_bindgen(getter, js_name = parentFingerprint)]
+    pub fn parent_fingerprint_as_hex(&self) -> String {
+        self.inner.attrs().parent_fingerprint.to_vec().to_hex()
+    }
+
+    #[wasm_bindgen(getter, js_name = childNumber)]
+    pub fn child_number(&self) -> u32 {
+        self.inner.attrs().child_number.into()
+    }
+
+    #[wasm_bindgen(getter, js_name = chainCode)]
+    pub fn chain_code_as_hex(&self) -> String {
+        self.inner.attrs().chain_code.to_vec().to_hex()
+    }
 }
 
 impl From<ExtendedPublicKey<secp256k1::PublicKey>> for XPub {
@@ -81,3 +153,29 @@ impl TryCastFromJs for XPub {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xpub_derive_addresses() -> Result<()> {
+        let seed = [0x42u8; 64];
+        let xprv = ExtendedPrivateKey::<SecretKey>::new(seed)?.derive_path(&"m/44'/111111'/0'/0".parse()?)?;
+        let xpub = XPub::from(xprv.public_key());
+
+        let addresses = xpub.derive_addresses(NetworkType::Mainnet, 0..4)?;
+        assert_eq!(addresses.len(), 4);
+        for (index, address) in addresses.iter().enumerate() {
+            let child = xprv.derive_child(ChildNumber::new(index as u32, false)?)?;
+            let expected = PublicKey::from(*child.public_key().public_key()).to_address(NetworkType::Mainnet)?;
+            assert_eq!(address, &expected);
+        }
+
+        let restored = XPub::try_new(&xpub.to_string()?)?;
+        assert_eq!(restored.derive_addresses(NetworkType::Mainnet, 0..4)?, addresses);
+        assert_eq!(XPub::try_new(&xpub.xpub()?)?.derive_addresses(NetworkType::Mainnet, 0..4)?, addresses);
+
+        Ok(())
+    }
+}
@@ -15,12 +15,30 @@ pub fn build_wallet_server_transport_interface(input: TokenStream) -> TokenStrea
     wallet::server::build_transport_interface(input)
 }
 
+#[proc_macro]
+#[proc_macro_error]
+pub fn build_wallet_batch_interface(input: TokenStream) -> TokenStream {
+    wallet::batch::build_batch_interface(input)
+}
+
+#[proc_macro]
+#[proc_macro_error]
+pub fn declare_wasm_batch_handlers(input: TokenStream) -> TokenStream {
+    wallet::wasm::declare_wasm_batch_handlers(input)
+}
+
 #[proc_macro]
 #[proc_macro_error]
 pub fn declare_wasm_handlers(input: TokenStream) -> TokenStream {
     wallet::wasm::declare_wasm_handlers(input)
 }
 
+#[proc_macro]
+#[proc_macro_error]
+pub fn assert_wasm_conversions(input: TokenStream) -> TokenStream {
+    wallet::wasm::assert_wasm_conversions(input)
+}
+
 #[proc_macro]
 #[proc_macro_error]
 pub fn declare_typescript_wasm_interface(input: TokenStream) -> TokenStream {
@@ -0,0 +1,101 @@
+use crate::handler::*;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use std::convert::Into;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    Error, Expr, ExprArray, Result, Token,
+};
+
+#[derive(Debug)]
+struct BatchTable {
+    handlers: ExprArray,
+}
+
+impl Parse for BatchTable {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let parsed = Punctuated::<Expr, Token![,]>::parse_terminated(input).unwrap();
+        if parsed.len() != 1 {
+            return Err(Error::new_spanned(parsed, "usage: build_wallet_batch_interface!([XxxOp, ..])".to_string()));
+        }
+
+        let mut iter = parsed.iter();
+        let handlers = get_handlers(iter.next().unwrap().clone())?;
+
+        Ok(BatchTable { handlers })
+    }
+}
+
+impl ToTokens for BatchTable {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let mut request_variants = Vec::new();
+        let mut response_variants = Vec::new();
+        let mut ops = Vec::new();
+        let mut response_ops = Vec::new();
+        let mut targets = Vec::new();
+
+        for handler in self.handlers.elems.iter() {
+            let Handler { ident, fn_call, request_type, response_type, typename, .. } = Handler::new(handler);
+
+            request_variants.push(quote! { #typename(#request_type) });
+            response_variants.push(quote! { #typename(#response_type) });
+            ops.push(quote! { WalletRequest::#typename(_) => #ident });
+            response_ops.push(quote! { WalletResponse::#typename(_) => #ident });
+            targets.push(quote! {
+                WalletRequest::#typename(request) => Ok(WalletResponse::#typename(wallet_api.#fn_call(request).await?))
+            });
+        }
+
+        quote! {
+
+            /// Wallet API request executed as a part of the [`BatchRequest`].
+            #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+            #[serde(tag = "op", content = "request", rename_all = "kebab-case")]
+            pub enum WalletRequest {
+                #(#request_variants),*
+            }
+
+            /// Response to the [`WalletRequest`] executed as a part of the [`BatchRequest`].
+            #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+            #[serde(tag = "op", content = "response", rename_all = "kebab-case")]
+            pub enum WalletResponse {
+                #(#response_variants),*
+            }
+
+            impl WalletRequest {
+                /// Name of the API method (as accepted by the Serde JSON transport).
+                pub fn op(&self) -> &'static str {
+                    match self {
+                        #(#ops),*
+                    }
+                }
+
+                /// Executes the request using the supplied [`WalletApi`] implementation.
+                pub async fn execute(self, wallet_api: Arc<dyn WalletApi>) -> Result<WalletResponse> {
+                    match self {
+                        #(#targets),*
+                    }
+                }
+            }
+
+            impl WalletResponse {
+                /// Name of the API method (as accepted by the Serde JSON transport).
+                pub fn op(&self) -> &'static str {
+                    match self {
+                        #(#response_ops),*
+                    }
+                }
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
+
+pub fn build_batch_interface(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let batch_table = parse_macro_input!(input as BatchTable);
+    let ts = batch_table.to_token_stream();
+    // println!("MACRO: {}", ts.to_string());
+    ts.into()
+}
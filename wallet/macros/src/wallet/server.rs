@@ -32,12 +32,16 @@ impl ToTokens for RpcTable {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let mut targets_borsh = Vec::new();
         let mut targets_serde = Vec::new();
+        let mut methods = Vec::new();
 
         for handler in self.handlers.elems.iter() {
             let Handler { hash_64, ident, fn_call, request_type, .. } = Handler::new(handler);
 
+            methods.push(ident.clone());
+
             targets_borsh.push(quote! {
                 #hash_64 => {
+                    self.wallet.register_api_activity(#ident);
                     Ok(self.wallet_api().#fn_call(#request_type::try_from_slice(&request)?).await?.try_to_vec()?)
                 }
             });
@@ -45,6 +49,7 @@ impl ToTokens for RpcTable {
             targets_serde.push(quote! {
                 #ident => {
                     let request: #request_type = serde_json::from_str(request)?;
+                    self.wallet.register_api_activity(#ident);
                     let response = self.wallet_api().#fn_call(request).await?;
                     Ok(serde_json::to_string(&response)?)
                 }
@@ -59,14 +64,17 @@ impl ToTokens for RpcTable {
 
         quote! {
 
-                pub async fn call_with_borsh(&self, op: u64, request: &[u8]) -> Result<Vec<u8>> {
+                /// Names of the API methods accepted by the Serde JSON transport.
+                pub const METHODS: &'static [&'static str] = &[#(#methods),*];
+
+                async fn dispatch_with_borsh(&self, op: u64, request: &[u8]) -> Result<Vec<u8>> {
                     match op {
                         #(#targets_borsh)*
                         _ => { Err(Error::NotImplemented) }
                     }
                 }
 
-                pub async fn call_with_serde(&self, op: &str, request: &str) -> Result<String> {
+                async fn dispatch_with_serde(&self, op: &str, request: &str) -> Result<String> {
                     match op {
                         #(#targets_serde)*
                         _ => { Err(Error::NotImplemented) }
@@ -127,7 +127,7 @@ impl ToTokens for ApiHandlers {
         let mut targets = Vec::new();
 
         for handler in self.handlers.elems.iter() {
-            let Handler { fn_call, fn_camel, fn_no_suffix, request_type, ts_request_type, ts_response_type, docs, .. } =
+            let Handler { ident, fn_call, fn_camel, fn_no_suffix, request_type, ts_request_type, ts_response_type, docs, .. } =
                 Handler::new(handler);
             let links = format! {"@see {{@link {ts_request_type}}} {{@link {ts_response_type}}}"};
             let throws = "@throws `string` in case of an error.";
@@ -138,6 +138,7 @@ impl ToTokens for ApiHandlers {
                 #[wasm_bindgen(js_name = #fn_camel)]
                 pub async fn #fn_no_suffix(&self, request : #ts_request_type) -> Result<#ts_response_type> {
                     let request = #request_type::try_from(request)?;
+                    self.wallet().register_api_activity(#ident);
                     let response = self.wallet().clone().#fn_call(request).await?;
                     #ts_response_type::try_from(response)
                 }
@@ -161,6 +162,124 @@ pub fn declare_wasm_handlers(input: proc_macro::TokenStream) -> proc_macro::Toke
     ts.into()
 }
 
+#[derive(Debug)]
+struct BatchHandlers {
+    handlers: ExprArray,
+}
+
+impl Parse for BatchHandlers {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let parsed = Punctuated::<Expr, Token![,]>::parse_terminated(input).unwrap();
+
+        if parsed.len() != 1 {
+            return Err(Error::new_spanned(parsed, "usage: declare_wasm_batch_handlers!([XxxOp, ..])".to_string()));
+        }
+
+        let mut iter = parsed.iter();
+        let handlers = get_handlers(iter.next().unwrap().clone())?;
+
+        Ok(BatchHandlers { handlers })
+    }
+}
+
+impl ToTokens for BatchHandlers {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let mut requests = Vec::new();
+        let mut responses = Vec::new();
+
+        for handler in self.handlers.elems.iter() {
+            let Handler { name, request_type, typename, ts_request_type, ts_response_type, .. } = Handler::new(handler);
+            let op = Literal::string(&name.to_case(Case::Camel));
+            requests.push(quote! {
+                #op => Ok(WalletRequest::#typename(#request_type::try_from(#ts_request_type::from(request))?))
+            });
+            responses.push(quote! {
+                WalletResponse::#typename(response) => Ok((#op, #ts_response_type::try_from(response)?.into()))
+            });
+        }
+
+        quote! {
+            /// Converts a JavaScript batch request item identified by the name
+            /// of the corresponding [`Wallet`] method into a [`WalletRequest`].
+            pub(crate) fn wallet_request_from_js(op: &str, request: JsValue) -> Result<WalletRequest> {
+                match op {
+                    #(#requests,)*
+                    _ => Err(Error::custom(format!("Unsupported batch operation '{op}'"))),
+                }
+            }
+
+            /// Converts a [`WalletResponse`] into the JavaScript response
+            /// returning it along with the name of the [`Wallet`] method.
+            pub(crate) fn wallet_response_to_js(response: WalletResponse) -> Result<(&'static str, JsValue)> {
+                match response {
+                    #(#responses,)*
+                    #[allow(unreachable_patterns)]
+                    response => Err(Error::custom(format!("Unsupported batch operation '{}'", response.op()))),
+                }
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
+
+pub fn declare_wasm_batch_handlers(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let declaration = parse_macro_input!(input as BatchHandlers);
+    let ts = declaration.to_token_stream();
+    // println!("MACRO: {}", ts);
+    ts.into()
+}
+
+#[derive(Debug)]
+struct ConversionAssertions {
+    handlers: ExprArray,
+}
+
+impl Parse for ConversionAssertions {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let parsed = Punctuated::<Expr, Token![,]>::parse_terminated(input).unwrap();
+
+        if parsed.len() != 1 {
+            return Err(Error::new_spanned(parsed, "usage: assert_wasm_conversions!([XxxOp, ..])".to_string()));
+        }
+
+        let mut iter = parsed.iter();
+        let handlers = get_handlers(iter.next().unwrap().clone())?;
+
+        Ok(ConversionAssertions { handlers })
+    }
+}
+
+impl ToTokens for ConversionAssertions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let mut targets = Vec::new();
+
+        for handler in self.handlers.elems.iter() {
+            let Handler { request_type, response_type, ts_request_type, ts_response_type, .. } = Handler::new(handler);
+            targets.push(quote! {
+                convertible::<#ts_request_type, #request_type>();
+                convertible::<#response_type, #ts_response_type>();
+            });
+        }
+
+        quote! {
+            // Fails to compile if any of the listed operations is missing a conversion
+            // from its TypeScript request interface or into its TypeScript response interface.
+            const _: fn() = || {
+                fn convertible<F, T: TryFrom<F>>() {}
+                #(#targets)*
+            };
+        }
+        .to_tokens(tokens);
+    }
+}
+
+pub fn assert_wasm_conversions(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let declaration = parse_macro_input!(input as ConversionAssertions);
+    let ts = declaration.to_token_stream();
+    // println!("MACRO: {}", ts);
+    ts.into()
+}
+
 fn extract_literal(expr: &Expr) -> Result<String> {
     match expr {
         Expr::Lit(expr_lit) => {
@@ -1,7 +1,13 @@
-use kaspa_cli_lib::{kaspa_cli, TerminalOptions};
+use kaspa_cli_lib::{kaspa_cli, kaspa_cli_batch, TerminalOptions};
 
 #[tokio::main]
 async fn main() {
+    // non-interactive mode printing JSON results (see `kaspa_cli_lib::batch`)
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.iter().any(|arg| arg == "--json") {
+        std::process::exit(kaspa_cli_batch(args).await);
+    }
+
     let result = kaspa_cli(TerminalOptions::new().with_prompt("$ "), None).await;
     if let Err(err) = result {
         println!("{err}");
@@ -0,0 +1,121 @@
+use crate::imports::*;
+use clap::{Arg, Command};
+use kaspa_core::kaspad_env::version;
+
+/// Default wallet daemon wRPC listen address.
+pub const DEFAULT_LISTEN_ADDRESS: &str = "127.0.0.1:8082";
+
+/// Environment variable carrying the API authentication secret
+/// (used if `--auth-secret-file` is not supplied).
+pub const AUTH_SECRET_ENV: &str = "KASPA_WALLETD_AUTH_SECRET";
+
+pub struct Args {
+    pub network_id: NetworkId,
+    pub rpc_server: Option<String>,
+    pub listen: String,
+    pub auth_secret: Secret,
+    pub logging: LoggingConfig,
+    /// Write log messages as JSON lines (to the file, if supplied, or the standard output)
+    pub log_json: Option<Option<String>>,
+}
+
+impl Args {
+    pub fn parse() -> Result<Self> {
+        let m = cli().get_matches();
+
+        let network_id = m.get_one::<String>("network").cloned().unwrap().parse::<NetworkId>()?;
+
+        let auth_secret = if let Some(filename) = m.get_one::<String>("auth-secret-file") {
+            Secret::from(trim_line_ending(std::fs::read_to_string(filename)?))
+        } else if let Ok(secret) = std::env::var(AUTH_SECRET_ENV) {
+            Secret::from(secret)
+        } else {
+            return Err(Error::custom(format!("no API authentication secret supplied (use --auth-secret-file or {AUTH_SECRET_ENV})")));
+        };
+
+        if auth_secret.as_ref().is_empty() {
+            return Err(Error::custom("API authentication secret is empty"));
+        }
+
+        Ok(Args {
+            network_id,
+            rpc_server: m.get_one::<String>("rpcserver").cloned(),
+            listen: m.get_one::<String>("listen").cloned().unwrap(),
+            auth_secret,
+            logging: m.get_one::<String>("log-level").unwrap().parse()?,
+            log_json: m.contains_id("log-json").then(|| m.get_one::<String>("log-json").cloned()),
+        })
+    }
+}
+
+/// Strips a single trailing line ending (as left by `echo` or an editor)
+/// from a secret read from a file.
+fn trim_line_ending(mut secret: String) -> String {
+    if secret.ends_with('\n') {
+        secret.pop();
+        if secret.ends_with('\r') {
+            secret.pop();
+        }
+    }
+    secret
+}
+
+pub fn cli() -> Command {
+    Command::new("kaspa-walletd")
+        .about(format!("{} (kaspa-walletd) v{}", env!("CARGO_PKG_DESCRIPTION"), version()))
+        .version(env!("CARGO_PKG_VERSION"))
+        .arg(
+            Arg::new("network")
+                .long("network")
+                .short('n')
+                .value_name("network")
+                .default_value("mainnet")
+                .help("Network id (mainnet, testnet-10, testnet-11)"),
+        )
+        .arg(
+            Arg::new("rpcserver")
+                .long("rpcserver")
+                .short('s')
+                .value_name("url")
+                .help("Node wRPC (Borsh) url; if omitted, a public node is selected using the resolver"),
+        )
+        .arg(
+            Arg::new("listen")
+                .long("listen")
+                .short('l')
+                .value_name("address")
+                .default_value(DEFAULT_LISTEN_ADDRESS)
+                .help("Interface:port for the wallet API wRPC (JSON) server"),
+        )
+        .arg(
+            Arg::new("log-level")
+                .long("log-level")
+                .value_name("filter")
+                .default_value("info")
+                .help("Log level filter comprised of the default level and module overrides (e.g. 'info,utxo=debug,storage=warn'; modules: wallet, utxo, rpc-client, storage)"),
+        )
+        .arg(
+            Arg::new("log-json")
+                .long("log-json")
+                .value_name("file")
+                .num_args(0..=1)
+                .help("Write log messages as JSON lines to the standard output or the supplied file"),
+        )
+        .arg(Arg::new("auth-secret-file").long("auth-secret-file").value_name("file").help(format!(
+            "File containing the secret used to authenticate wallet API requests (alternatively, set {AUTH_SECRET_ENV})"
+        )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim_line_ending() {
+        assert_eq!(trim_line_ending("secret\n".to_string()), "secret");
+        assert_eq!(trim_line_ending("secret\r\n".to_string()), "secret");
+        assert_eq!(trim_line_ending("secret\n\n".to_string()), "secret\n");
+        assert!(trim_line_ending("\n".to_string()).is_empty());
+        assert!(trim_line_ending("\r\n".to_string()).is_empty());
+    }
+}
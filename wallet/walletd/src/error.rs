@@ -0,0 +1,28 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{0}")]
+    Custom(String),
+
+    #[error(transparent)]
+    Wallet(#[from] kaspa_wallet_core::error::Error),
+
+    #[error(transparent)]
+    Wrpc(#[from] kaspa_wrpc_client::error::Error),
+
+    #[error(transparent)]
+    NetworkId(#[from] kaspa_consensus_core::network::NetworkIdError),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("WebSocket error: {0}")]
+    WebSocket(#[from] workflow_rpc::server::WebSocketError),
+}
+
+impl Error {
+    pub fn custom<T: Into<String>>(msg: T) -> Self {
+        Error::Custom(msg.into())
+    }
+}
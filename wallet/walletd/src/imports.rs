@@ -0,0 +1,15 @@
+pub use crate::error::Error;
+pub use crate::result::Result;
+pub use async_trait::async_trait;
+pub use futures::{select_biased, FutureExt};
+pub use kaspa_consensus_core::network::NetworkId;
+pub use kaspa_wallet_core::api::transport::{EventHandler, WalletServer};
+pub use kaspa_wallet_core::api::{ConnectRequest, WalletApi, WalletCloseRequest};
+pub use kaspa_wallet_core::events::Events;
+pub use kaspa_wallet_core::prelude::{LoggingConfig, Secret, Wallet};
+pub use serde::{Deserialize, Serialize};
+pub use std::collections::HashMap;
+pub use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+pub use std::sync::{Arc, Mutex};
+pub use workflow_core::channel::oneshot;
+pub use workflow_log::prelude::*;
@@ -0,0 +1,81 @@
+//!
+//! `kaspa-walletd` - a standalone wallet daemon.
+//!
+//! Runs the wallet runtime and exposes the wallet API (the request and
+//! response messages declared in [`kaspa_wallet_core::api::message`]) over
+//! a local wRPC endpoint using the Serde JSON encoding, allowing applications
+//! written in other languages to drive wallet operations. All requests must
+//! be signed with a secret shared with the daemon (see
+//! [`kaspa_wallet_core::api::auth`]).
+//!
+
+mod args;
+mod error;
+mod imports;
+mod result;
+mod server;
+
+use crate::args::Args;
+use crate::imports::*;
+use crate::server::Server;
+use kaspa_wallet_core::logging::JsonLogSink;
+use kaspa_wrpc_client::Resolver;
+use std::path::Path;
+use workflow_rpc::server::prelude::*;
+
+#[tokio::main]
+async fn main() {
+    if let Err(err) = run().await {
+        log_error!("{err}");
+        std::process::exit(1);
+    }
+}
+
+async fn run() -> Result<()> {
+    let Args { network_id, rpc_server: url, listen, auth_secret, logging, log_json } = Args::parse()?;
+
+    match log_json {
+        Some(Some(filename)) => workflow_log::pipe(Some(Arc::new(JsonLogSink::try_with_file(Path::new(&filename))?))),
+        Some(None) => workflow_log::pipe(Some(Arc::new(JsonLogSink::stdout()))),
+        None => {}
+    }
+    kaspa_wallet_core::logging::configure(logging);
+
+    let resolver = url.is_none().then(Resolver::default);
+    let wallet = Arc::new(Wallet::try_new(Wallet::local_store()?, resolver, Some(network_id))?);
+
+    let server = Arc::new(Server::new(wallet.clone(), auth_secret));
+    let rpc_server = RpcServer::new_with_encoding::<Arc<Server>, server::Connection, String, Id64>(
+        Encoding::SerdeJson,
+        server.connections().clone(),
+        Arc::new(server.interface()),
+        None,
+    );
+
+    let (shutdown_sender, shutdown_receiver) = oneshot::<()>();
+
+    ctrlc::set_handler(move || {
+        log_info!("^SIGTERM - shutting down...");
+        shutdown_sender.try_send(()).expect("Error sending shutdown signal...");
+    })
+    .expect("Unable to set the Ctrl+C signal handler");
+
+    wallet.start().await?;
+    server.wallet_server().start();
+    wallet.clone().connect_call(ConnectRequest { url, network_id }).await?;
+
+    log_info!("Kaspa wallet daemon wRPC (JSON) server is listening on {listen}");
+
+    select_biased! {
+        result = rpc_server.listen(&listen, None).fuse() => result?,
+        _ = shutdown_receiver.recv().fuse() => {
+            rpc_server.stop()?;
+        },
+    }
+
+    wallet.clone().wallet_close_call(WalletCloseRequest {}).await.ok();
+    server.wallet_server().stop_task().await?;
+    wallet.stop().await?;
+
+    Ok(())
+}
@@ -0,0 +1,180 @@
+//!
+//! Wallet API wRPC server.
+//!
+//! Each wallet API method is exposed as a wRPC method named after its
+//! Serde transport identifier (for example `accounts-enumerate`), accepting
+//! the JSON request wrapped into a signed envelope (see
+//! [`auth`](kaspa_wallet_core::api::auth)) and returning the JSON response.
+//! Wallet events are posted to authenticated connections as `event`
+//! notifications.
+//!
+
+use crate::imports::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use kaspa_wallet_core::api::auth::RequestVerifier;
+use kaspa_wallet_core::error::ErrorCode;
+use workflow_rpc::server::prelude::*;
+
+/// Name of the notification carrying wallet events.
+pub const EVENT_NOTIFICATION: &str = "event";
+
+/// Opaque JSON value relaying wallet API requests and responses through the
+/// wRPC interface. The server operates using the Serde JSON encoding only;
+/// the Borsh serialization (required by the wRPC method interface) carries
+/// the JSON text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct JsonValue(serde_json::Value);
+
+impl BorshSerialize for JsonValue {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        BorshSerialize::serialize(&self.0.to_string(), writer)
+    }
+}
+
+impl BorshDeserialize for JsonValue {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let json = <String as BorshDeserialize>::deserialize(buf)?;
+        serde_json::from_str(&json).map(JsonValue).map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+}
+
+struct ConnectionInner {
+    id: u64,
+    peer: SocketAddr,
+    messenger: Arc<Messenger>,
+    // set once the connection has submitted a request carrying a valid signature
+    authenticated: AtomicBool,
+}
+
+/// wRPC connection context.
+#[derive(Clone)]
+pub struct Connection {
+    inner: Arc<ConnectionInner>,
+}
+
+impl Connection {
+    fn new(id: u64, peer: SocketAddr, messenger: Arc<Messenger>) -> Self {
+        Self { inner: Arc::new(ConnectionInner { id, peer, messenger, authenticated: AtomicBool::new(false) }) }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.inner.id
+    }
+
+    pub fn peer(&self) -> &SocketAddr {
+        &self.inner.peer
+    }
+
+    pub fn is_authenticated(&self) -> bool {
+        self.inner.authenticated.load(Ordering::SeqCst)
+    }
+
+    fn authenticate(&self) {
+        self.inner.authenticated.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Registry of the open wRPC connections. Relays wallet events
+/// to the connections that have authenticated.
+#[derive(Default)]
+pub struct Connections {
+    next_id: AtomicU64,
+    connections: Mutex<HashMap<u64, Connection>>,
+}
+
+impl Connections {
+    fn authenticated(&self) -> Vec<Connection> {
+        self.connections.lock().unwrap().values().filter(|connection| connection.is_authenticated()).cloned().collect()
+    }
+}
+
+#[async_trait]
+impl RpcHandler for Connections {
+    type Context = Connection;
+
+    async fn handshake(
+        self: Arc<Self>,
+        peer: &SocketAddr,
+        _sender: &mut WebSocketSender,
+        _receiver: &mut WebSocketReceiver,
+        messenger: Arc<Messenger>,
+    ) -> WebSocketResult<Connection> {
+        let connection = Connection::new(self.next_id.fetch_add(1, Ordering::SeqCst), *peer, messenger);
+        self.connections.lock().unwrap().insert(connection.id(), connection.clone());
+        log_info!("wRPC connection #{} from {peer}", connection.id());
+        Ok(connection)
+    }
+
+    async fn disconnect(self: Arc<Self>, connection: Connection, _result: WebSocketResult<()>) {
+        self.connections.lock().unwrap().remove(&connection.id());
+        log_info!("wRPC connection #{} from {} closed", connection.id(), connection.peer());
+    }
+}
+
+#[async_trait]
+impl EventHandler for Connections {
+    async fn handle_event(&self, event: &Events) {
+        for connection in self.authenticated() {
+            if let Err(err) = connection.inner.messenger.notify(EVENT_NOTIFICATION.to_string(), event.clone()).await {
+                log_warn!("Unable to post event to wRPC connection #{}: {err}", connection.id());
+            }
+        }
+    }
+}
+
+/// Wallet API wRPC server context dispatching wRPC method
+/// calls to the [`WalletServer`].
+pub struct Server {
+    wallet_server: Arc<WalletServer>,
+    connections: Arc<Connections>,
+}
+
+impl Server {
+    pub fn new(wallet: Arc<Wallet>, auth_secret: Secret) -> Self {
+        let connections = Arc::new(Connections::default());
+        let wallet_server =
+            Arc::new(WalletServer::new(wallet, connections.clone()).with_request_verifier(RequestVerifier::new(auth_secret)));
+        Self { wallet_server, connections }
+    }
+
+    pub fn wallet_server(&self) -> &Arc<WalletServer> {
+        &self.wallet_server
+    }
+
+    pub fn connections(&self) -> &Arc<Connections> {
+        &self.connections
+    }
+
+    /// Build the wRPC interface declaring a method for each wallet API method.
+    pub fn interface(self: &Arc<Self>) -> Interface<Arc<Server>, Connection, String> {
+        let mut interface = Interface::new(self.clone());
+        for method in WalletServer::METHODS {
+            let op = method.to_string();
+            interface.method(
+                op.clone(),
+                Method::new(move |server: Arc<Server>, connection: Connection, request: JsonValue| {
+                    let op = op.clone();
+                    Box::pin(async move { server.call(&connection, &op, request).await })
+                }),
+            );
+        }
+        interface
+    }
+
+    async fn call(&self, connection: &Connection, op: &str, request: JsonValue) -> ServerResult<JsonValue> {
+        match self.wallet_server.call_with_serde(op, &request.0.to_string()).await {
+            Ok(response) => {
+                connection.authenticate();
+                serde_json::from_str(&response).map(JsonValue).map_err(|_| ServerError::RespSerialize)
+            }
+            Err(err) => {
+                // errors other than authentication failures are produced by verified requests
+                if err.code() != ErrorCode::Unauthorized {
+                    connection.authenticate();
+                }
+                Err(ServerError::Text(format!("[{}] {err}", err.code() as u32)))
+            }
+        }
+    }
+}
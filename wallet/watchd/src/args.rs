@@ -0,0 +1,130 @@
+use crate::imports::*;
+use clap::{Arg, ArgAction, Command};
+use kaspa_core::kaspad_env::version;
+
+/// Events emitted by default (address UTXO state changes and connectivity).
+const DEFAULT_EVENTS: &[EventKind] = &[
+    EventKind::Connect,
+    EventKind::Disconnect,
+    EventKind::UtxoProcError,
+    EventKind::Discovery,
+    EventKind::Pending,
+    EventKind::Maturity,
+    EventKind::Reorg,
+    EventKind::ReorgedOut,
+    EventKind::Reaccepted,
+    EventKind::Balance,
+];
+
+pub struct Args {
+    pub network_id: NetworkId,
+    pub rpc_server: Option<String>,
+    pub addresses: Vec<Address>,
+    pub webhooks: Vec<String>,
+    pub events: Vec<EventKind>,
+    pub quiet: bool,
+}
+
+impl Args {
+    pub fn parse() -> Result<Self> {
+        let m = cli().get_matches();
+
+        let network_id = m.get_one::<String>("network").cloned().unwrap().parse::<NetworkId>()?;
+
+        let mut addresses = m.get_many::<String>("address").unwrap_or_default().cloned().collect::<Vec<_>>();
+        if let Some(filename) = m.get_one::<String>("addresses") {
+            addresses.extend(
+                std::fs::read_to_string(filename)?
+                    .lines()
+                    .map(|line| line.trim())
+                    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                    .map(String::from),
+            );
+        }
+
+        let addresses = addresses
+            .into_iter()
+            .map(|address| {
+                let address = Address::try_from(address.as_str())?;
+                if address.prefix != network_id.into() {
+                    Err(Error::custom(format!("address {address} does not match network {network_id}")))
+                } else {
+                    Ok(address)
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if addresses.is_empty() {
+            return Err(Error::custom("no addresses supplied (use --address or --addresses)"));
+        }
+
+        let events = if let Some(events) = m.get_one::<String>("events") {
+            events
+                .split(',')
+                .map(|kind| kind.trim().parse::<EventKind>().map_err(|_| Error::custom(format!("unknown event kind: {kind}"))))
+                .collect::<Result<Vec<_>>>()?
+        } else {
+            DEFAULT_EVENTS.to_vec()
+        };
+
+        Ok(Args {
+            network_id,
+            rpc_server: m.get_one::<String>("rpcserver").cloned(),
+            addresses,
+            webhooks: m.get_many::<String>("webhook").unwrap_or_default().cloned().collect(),
+            events,
+            quiet: m.get_flag("quiet"),
+        })
+    }
+}
+
+pub fn cli() -> Command {
+    Command::new("kaspa-watchd")
+        .about(format!("{} (kaspa-watchd) v{}", env!("CARGO_PKG_DESCRIPTION"), version()))
+        .version(env!("CARGO_PKG_VERSION"))
+        .arg(
+            Arg::new("network")
+                .long("network")
+                .short('n')
+                .value_name("network")
+                .default_value("mainnet")
+                .help("Network id (mainnet, testnet-10, testnet-11)"),
+        )
+        .arg(
+            Arg::new("rpcserver")
+                .long("rpcserver")
+                .short('s')
+                .value_name("url")
+                .help("Node wRPC (Borsh) url; if omitted, a public node is selected using the resolver"),
+        )
+        .arg(
+            Arg::new("address")
+                .long("address")
+                .short('a')
+                .value_name("address")
+                .action(ArgAction::Append)
+                .help("Address to monitor (can be specified multiple times)"),
+        )
+        .arg(
+            Arg::new("addresses")
+                .long("addresses")
+                .value_name("file")
+                .help("File containing addresses to monitor (one address per line, '#' comments are ignored)"),
+        )
+        .arg(
+            Arg::new("webhook")
+                .long("webhook")
+                .short('w')
+                .value_name("url")
+                .action(ArgAction::Append)
+                .help("Webhook url receiving events as JSON POST requests (can be specified multiple times)"),
+        )
+        .arg(
+            Arg::new("events")
+                .long("events")
+                .short('e')
+                .value_name("kinds")
+                .help("Comma-separated list of event kinds to emit (default: connect,disconnect,utxo-proc-error,discovery,pending,maturity,reorg,reorged-out,reaccepted,balance)"),
+        )
+        .arg(Arg::new("quiet").long("quiet").short('q').action(ArgAction::SetTrue).help("Do not write events to stdout"))
+}
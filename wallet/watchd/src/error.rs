@@ -0,0 +1,45 @@
+use kaspa_wallet_core::error::Error as WalletError;
+use thiserror::Error;
+use workflow_core::channel::ChannelError;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{0}")]
+    Custom(String),
+
+    #[error(transparent)]
+    WalletError(#[from] WalletError),
+
+    #[error(transparent)]
+    WrpcError(#[from] kaspa_wrpc_client::error::Error),
+
+    #[error(transparent)]
+    AddressError(#[from] kaspa_addresses::AddressError),
+
+    #[error(transparent)]
+    NetworkIdError(#[from] kaspa_consensus_core::network::NetworkIdError),
+
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+
+    #[error("Webhook error: {0}")]
+    WebhookError(#[from] reqwest::Error),
+
+    #[error("Channel error: {0}")]
+    ChannelError(String),
+}
+
+impl Error {
+    pub fn custom<T: Into<String>>(msg: T) -> Self {
+        Error::Custom(msg.into())
+    }
+}
+
+impl<T> From<ChannelError<T>> for Error {
+    fn from(e: ChannelError<T>) -> Error {
+        Error::ChannelError(e.to_string())
+    }
+}
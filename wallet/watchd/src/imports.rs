@@ -0,0 +1,14 @@
+pub use crate::error::Error;
+pub use crate::result::Result;
+pub use futures::{select_biased, FutureExt};
+pub use kaspa_addresses::Address;
+pub use kaspa_wallet_core::events::{EventKind, Events};
+pub use kaspa_wallet_core::rpc::{DynRpcApi, Rpc};
+pub use kaspa_wallet_core::storage::Binding;
+pub use kaspa_wallet_core::utxo::{AddressMonitor, UtxoContextId, UtxoProcessor};
+pub use kaspa_wrpc_client::prelude::*;
+pub use std::collections::{HashMap, HashSet};
+pub use std::sync::Arc;
+pub use workflow_core::channel::{oneshot, DuplexChannel};
+pub use workflow_core::task::spawn;
+pub use workflow_log::prelude::*;
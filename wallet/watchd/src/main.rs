@@ -0,0 +1,49 @@
+//!
+//! `kaspa-watchd` - a standalone address monitor.
+//!
+//! Watches a configured set of addresses using the wallet UTXO tracking
+//! subsystem ([`AddressMonitor`](kaspa_wallet_core::utxo::AddressMonitor))
+//! and emits JSON events (newline-delimited on stdout and/or as webhook
+//! POST requests) when their UTXO state changes. No wallet file or
+//! secrets are required.
+//!
+
+mod args;
+mod error;
+mod imports;
+mod monitor;
+mod result;
+mod sink;
+
+use crate::args::Args;
+use crate::imports::*;
+use crate::monitor::Monitor;
+
+#[tokio::main]
+async fn main() {
+    if let Err(err) = run().await {
+        log_error!("{err}");
+        std::process::exit(1);
+    }
+}
+
+async fn run() -> Result<()> {
+    let args = Args::parse()?;
+    let monitor = Monitor::try_new(args)?;
+
+    let (shutdown_sender, shutdown_receiver) = oneshot::<()>();
+
+    ctrlc::set_handler(move || {
+        log_info!("^SIGTERM - shutting down...");
+        shutdown_sender.try_send(()).expect("Error sending shutdown signal...");
+    })
+    .expect("Unable to set the Ctrl+C signal handler");
+
+    monitor.start().await?;
+
+    shutdown_receiver.recv().await.expect("Error waiting for shutdown signal...");
+
+    monitor.stop().await?;
+
+    Ok(())
+}
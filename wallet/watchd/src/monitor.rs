@@ -0,0 +1,168 @@
+use crate::args::Args;
+use crate::imports::*;
+use crate::sink::Sink;
+
+struct Inner {
+    task_ctl: DuplexChannel<()>,
+    client: Arc<KaspaRpcClient>,
+    processor: UtxoProcessor,
+    // each address is tracked by a dedicated AddressMonitor
+    // so that balance and transaction events can be
+    // attributed to the address they relate to
+    monitors: HashMap<UtxoContextId, (Address, AddressMonitor)>,
+    events: HashSet<EventKind>,
+    sink: Sink,
+}
+
+/// Address monitor: tracks UTXO state of a set of addresses using
+/// [`AddressMonitor`] instances sharing a [`UtxoProcessor`] and emits
+/// address-related events to the [`Sink`].
+#[derive(Clone)]
+pub struct Monitor {
+    inner: Arc<Inner>,
+}
+
+impl Monitor {
+    pub fn try_new(args: Args) -> Result<Self> {
+        let Args { network_id, rpc_server, addresses, webhooks, events, quiet } = args;
+
+        let (resolver, url) = if let Some(url) = rpc_server { (None, Some(url)) } else { (Some(Resolver::default()), None) };
+        let client = Arc::new(KaspaRpcClient::new_with_args(WrpcEncoding::Borsh, url.as_deref(), resolver, Some(network_id), None)?);
+
+        let rpc_ctl = client.ctl().clone();
+        let rpc_api: Arc<DynRpcApi> = client.clone();
+        let processor = UtxoProcessor::new(Some(Rpc::new(rpc_api, rpc_ctl)), Some(network_id), None, None);
+
+        let monitors = addresses
+            .into_iter()
+            .map(|address| {
+                let monitor = AddressMonitor::with_processor(&processor);
+                (monitor.id(), (address, monitor))
+            })
+            .collect();
+
+        let inner = Inner {
+            task_ctl: DuplexChannel::oneshot(),
+            client,
+            processor,
+            monitors,
+            events: events.into_iter().collect(),
+            sink: Sink::try_new(!quiet, webhooks)?,
+        };
+
+        Ok(Self { inner: Arc::new(inner) })
+    }
+
+    pub fn client(&self) -> &Arc<KaspaRpcClient> {
+        &self.inner.client
+    }
+
+    pub fn processor(&self) -> &UtxoProcessor {
+        &self.inner.processor
+    }
+
+    pub async fn start(&self) -> Result<()> {
+        self.start_event_task().await?;
+        self.processor().start().await?;
+
+        // the addresses are scanned by the monitors each time the
+        // processor starts (i.e. on every connection to the node)
+        for (address, monitor) in self.inner.monitors.values() {
+            monitor.track_addresses(std::slice::from_ref(address)).await?;
+            monitor.start().await?;
+        }
+
+        let options = ConnectOptions { block_async_connect: false, ..Default::default() };
+        self.client().connect(Some(options)).await?;
+
+        Ok(())
+    }
+
+    pub async fn stop(&self) -> Result<()> {
+        self.client().disconnect().await?;
+        for (_, monitor) in self.inner.monitors.values() {
+            monitor.stop().await?;
+        }
+        self.processor().stop().await?;
+        self.stop_event_task().await?;
+        Ok(())
+    }
+
+    fn address_of(&self, event: &Events) -> Option<&Address> {
+        let id = match event {
+            Events::Balance { id, .. } => id,
+            Events::Pending { record }
+            | Events::Reorg { record }
+            | Events::ReorgedOut { record }
+            | Events::Reaccepted { record }
+            | Events::Stasis { record }
+            | Events::Maturity { record }
+            | Events::Discovery { record } => match record.binding() {
+                Binding::Custom(id) => id,
+                Binding::Account(_) => return None,
+            },
+            _ => return None,
+        };
+
+        self.inner.monitors.get(id).map(|(address, _)| address)
+    }
+
+    async fn handle_event(&self, event: Box<Events>) -> Result<()> {
+        if matches!(*event, Events::UtxoProcStart) {
+            log_info!("Monitoring {} address(es)", self.inner.monitors.len());
+        }
+
+        let kind = EventKind::from(&*event);
+        if !self.inner.events.contains(&EventKind::All) && !self.inner.events.contains(&kind) {
+            return Ok(());
+        }
+
+        let mut json = serde_json::to_value(&*event)?;
+        if let (Some(address), Some(object)) = (self.address_of(&event), json.as_object_mut()) {
+            object.insert("address".to_string(), address.to_string().into());
+        }
+
+        self.inner.sink.emit(&json).await
+    }
+
+    async fn start_event_task(&self) -> Result<()> {
+        let monitor = self.clone();
+        let events = self.processor().multiplexer().channel();
+        let task_ctl_receiver = self.inner.task_ctl.request.receiver.clone();
+        let task_ctl_sender = self.inner.task_ctl.response.sender.clone();
+
+        spawn(async move {
+            loop {
+                select_biased! {
+                    event = events.receiver.recv().fuse() => {
+                        match event {
+                            Ok(event) => {
+                                if let Err(err) = monitor.handle_event(event).await {
+                                    log_error!("Error while handling event: {err}");
+                                }
+                            }
+                            Err(err) => {
+                                log_error!("Event channel error: {err}");
+                                break;
+                            }
+                        }
+                    },
+
+                    _ = task_ctl_receiver.recv().fuse() => {
+                        break;
+                    },
+                }
+            }
+
+            events.close();
+            task_ctl_sender.send(()).await.unwrap();
+        });
+
+        Ok(())
+    }
+
+    async fn stop_event_task(&self) -> Result<()> {
+        self.inner.task_ctl.signal(()).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::imports::*;
+use std::io::Write;
+use std::time::Duration;
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Event output: newline-delimited JSON on stdout and/or webhook POST requests.
+pub struct Sink {
+    stdout: bool,
+    webhooks: Vec<String>,
+    client: reqwest::Client,
+}
+
+impl Sink {
+    pub fn try_new(stdout: bool, webhooks: Vec<String>) -> Result<Self> {
+        let client = reqwest::Client::builder().timeout(WEBHOOK_TIMEOUT).build()?;
+        Ok(Self { stdout, webhooks, client })
+    }
+
+    pub async fn emit(&self, event: &serde_json::Value) -> Result<()> {
+        let json = serde_json::to_string(event)?;
+
+        if self.stdout {
+            let mut stdout = std::io::stdout().lock();
+            writeln!(stdout, "{json}")?;
+            stdout.flush()?;
+        }
+
+        for url in self.webhooks.iter() {
+            let result = self
+                .client
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(json.clone())
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+
+            // webhook failures must not interrupt monitoring
+            if let Err(err) = result {
+                log_error!("webhook {url}: {err}");
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -84,8 +84,9 @@ const { encoding, networkId, address : destinationAddress } = require("../utils"
         // sequence of transactions
         // for a requested amount of KAS.
         // sign and submit these transactions
-        let pending;
-        while (pending = await generator.next()) {
+        // (`generator.next()` can be used 
+        // instead of the `for await` loop)
+        for await (const pending of generator) {
             await pending.sign([privateKey]);
             let txid = await pending.submit(rpc);
             console.log("txid:", txid);
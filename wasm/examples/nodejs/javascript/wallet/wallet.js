@@ -10,7 +10,8 @@ const {
     AccountKind, Mnemonic, Resolver,
     kaspaToSompi,
     sompiToKaspaString,
-    Address
+    Address,
+    WalletErrorCode
 } = kaspa;
 
 let storageFolder = path.join(__dirname, '../../../data/wallets').normalize();
@@ -251,6 +252,10 @@ setDefaultStorageFolder(storageFolder);
 
         
     } catch(ex) {
-        console.error("Error:", ex);
+        if (ex.code === WalletErrorCode.InsufficientFunds) {
+            console.error("Insufficient funds:", ex.data);
+        } else {
+            console.error("Error:", ex);
+        }
     }
 })();
\ No newline at end of file